@@ -376,9 +376,22 @@
     impl_vec_clone!(AzStyleFilter, AzStyleFilterVec, AzStyleFilterVecDestructor);
     impl_vec!(AzListViewRow, AzListViewRowVec, AzListViewRowVecDestructor, az_list_view_vec_destructor, AzListViewRowVec_delete);
     impl_vec_clone!(AzListViewRow, AzListViewRowVec, AzListViewRowVecDestructor);
+    impl_vec!(AzRibbonTab, AzRibbonTabVec, AzRibbonTabVecDestructor, az_ribbon_tab_vec_destructor, AzRibbonTabVec_delete);
+    impl_vec_clone!(AzRibbonTab, AzRibbonTabVec, AzRibbonTabVecDestructor);
+    impl_vec!(AzRibbonGroup, AzRibbonGroupVec, AzRibbonGroupVecDestructor, az_ribbon_group_vec_destructor, AzRibbonGroupVec_delete);
+    impl_vec_clone!(AzRibbonGroup, AzRibbonGroupVec, AzRibbonGroupVecDestructor);
+    impl_vec!(AzRibbonItem, AzRibbonItemVec, AzRibbonItemVecDestructor, az_ribbon_item_vec_destructor, AzRibbonItemVec_delete);
+    impl_vec_clone!(AzRibbonItem, AzRibbonItemVec, AzRibbonItemVecDestructor);
+    impl_vec!(AzSplitterPane, AzSplitterPaneVec, AzSplitterPaneVecDestructor, az_splitter_pane_vec_destructor, AzSplitterPaneVec_delete);
+    impl_vec_clone!(AzSplitterPane, AzSplitterPaneVec, AzSplitterPaneVecDestructor);
+    impl_vec!(AzSplitterPaneState, AzSplitterPaneStateVec, AzSplitterPaneStateVecDestructor, az_splitter_pane_state_vec_destructor, AzSplitterPaneStateVec_delete);
+    impl_vec_clone!(AzSplitterPaneState, AzSplitterPaneStateVec, AzSplitterPaneStateVecDestructor);
+    impl_vec_serde!(AzSplitterPaneState, AzSplitterPaneStateVec);
 
     impl_vec!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor, az_accessibility_state_vec_destructor, AzAccessibilityStateVec_delete);
     impl_vec_clone!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor);
+    impl_vec!(AzAccessibilityAction,  AzAccessibilityActionVec,  AzAccessibilityActionVecDestructor, az_accessibility_action_vec_destructor, AzAccessibilityActionVec_delete);
+    impl_vec_clone!(AzAccessibilityAction,  AzAccessibilityActionVec,  AzAccessibilityActionVecDestructor);
 
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
     impl_vec_clone!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor);
@@ -58,6 +58,8 @@ struct AzIFrameCallbackReturn;
 typedef struct AzIFrameCallbackReturn AzIFrameCallbackReturn;
 typedef AzIFrameCallbackReturn (*AzIFrameCallbackType)(AzRefAny* restrict A, AzIFrameCallbackInfo* restrict B);
 
+typedef AzStyledDom (*AzTooltipCallbackType)(AzRefAny* restrict A, AzLayoutCallbackInfo* restrict B);
+
 struct AzRenderImageCallbackInfo;
 typedef struct AzRenderImageCallbackInfo AzRenderImageCallbackInfo;
 struct AzImageRef;
@@ -80,6 +82,10 @@ typedef void (*AzThreadCallbackType)(AzRefAny A, AzThreadSender B, AzThreadRecei
 
 typedef void (*AzRefAnyDestructorType)(void* restrict A);
 
+struct AzRibbonItemState;
+typedef struct AzRibbonItemState AzRibbonItemState;
+typedef AzUpdate (*AzRibbonOnItemClickCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzRibbonItemState* const C);
+
 typedef AzUpdate (*AzRibbonOnTabClickedCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, int32_t C);
 
 struct AzFileInputState;
@@ -104,6 +110,10 @@ typedef AzOnTextInputReturn (*AzTextInputOnVirtualKeyDownCallbackType)(AzRefAny*
 
 typedef AzUpdate (*AzTextInputOnFocusLostCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTextInputState* const C);
 
+struct AzTextAreaState;
+typedef struct AzTextAreaState AzTextAreaState;
+typedef AzUpdate (*AzTextAreaOnTextChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTextAreaState* const C);
+
 struct AzNumberInputState;
 typedef struct AzNumberInputState AzNumberInputState;
 typedef AzUpdate (*AzNumberInputOnValueChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzNumberInputState* const C);
@@ -114,6 +124,24 @@ struct AzTabHeaderState;
 typedef struct AzTabHeaderState AzTabHeaderState;
 typedef AzUpdate (*AzTabOnClickCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTabHeaderState* const C);
 
+typedef AzUpdate (*AzTabOnCloseCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C);
+
+typedef AzUpdate (*AzTabOnReorderCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C, size_t D);
+
+typedef AzUpdate (*AzTabOnContextMenuCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C);
+
+struct AzSplitterState;
+typedef struct AzSplitterState AzSplitterState;
+typedef AzUpdate (*AzSplitterOnResizeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzSplitterState* const C);
+
+struct AzSliderState;
+typedef struct AzSliderState AzSliderState;
+typedef AzUpdate (*AzSliderOnValueChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzSliderState* const C);
+
+struct AzRangeSliderState;
+typedef struct AzRangeSliderState AzRangeSliderState;
+typedef AzUpdate (*AzRangeSliderOnValueChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzRangeSliderState* const C);
+
 struct AzNodeTypeId;
 typedef struct AzNodeTypeId AzNodeTypeId;
 struct AzNodeGraphNodeId;
@@ -150,6 +178,26 @@ typedef AzUpdate (*AzListViewOnColumnClickCallbackType)(AzRefAny* restrict A, Az
 
 typedef AzUpdate (*AzListViewOnRowClickCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzListViewState* const C, size_t D);
 
+typedef AzUpdate (*AzListViewOnColumnResizeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzListViewState* const C, size_t D, float E);
+
+struct AzListViewRowRange;
+typedef struct AzListViewRowRange AzListViewRowRange;
+struct AzListViewRowVec;
+typedef struct AzListViewRowVec AzListViewRowVec;
+typedef AzListViewRowVec (*AzListViewRowProviderCallbackType)(AzRefAny* restrict A, AzListViewState* const B, AzListViewRowRange C);
+
+struct AzTreeViewState;
+typedef struct AzTreeViewState AzTreeViewState;
+struct AzTreeViewNodeId;
+typedef struct AzTreeViewNodeId AzTreeViewNodeId;
+typedef AzUpdate (*AzTreeViewOnExpandCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTreeViewState* const C, AzTreeViewNodeId D, bool  E);
+
+typedef AzUpdate (*AzTreeViewOnSelectCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTreeViewState* const C);
+
+typedef AzUpdate (*AzTreeViewOnDoubleClickCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTreeViewState* const C, AzTreeViewNodeId D);
+
+typedef AzUpdate (*AzTreeViewOnContextMenuCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, AzTreeViewState* const C, AzTreeViewNodeId D);
+
 typedef AzUpdate (*AzDropDownOnChoiceChangeCallbackType)(AzRefAny* restrict A, AzCallbackInfo* restrict B, size_t C);
 
 typedef void (*AzParsedFontDestructorFnType)(void* restrict A);
@@ -170,6 +218,20 @@ union AzInstant;
 typedef union AzInstant AzInstant;
 typedef AzInstant (*AzGetSystemTimeFnType)();
 
+union AzRawWindowHandle;
+typedef union AzRawWindowHandle AzRawWindowHandle;
+enum AzClipboardSelection;
+typedef enum AzClipboardSelection AzClipboardSelection;
+struct AzString;
+typedef struct AzString AzString;
+union AzOptionClipboardContent;
+typedef union AzOptionClipboardContent AzOptionClipboardContent;
+typedef AzOptionClipboardContent (*AzGetClipboardFnType)(AzRawWindowHandle* const A, AzClipboardSelection B, AzString C);
+
+struct AzClipboardContent;
+typedef struct AzClipboardContent AzClipboardContent;
+typedef bool (*AzSetClipboardFnType)(AzRawWindowHandle* const A, AzClipboardSelection B, AzClipboardContent C);
+
 typedef bool (*AzCheckThreadFinishedFnType)(const void* A);
 
 union AzThreadSendMsg;
@@ -198,10 +260,40 @@ struct AzStyleFontFamilyVec;
 typedef struct AzStyleFontFamilyVec AzStyleFontFamilyVec;
 typedef void (*AzStyleFontFamilyVecDestructorType)(AzStyleFontFamilyVec* restrict A);
 
-struct AzListViewRowVec;
-typedef struct AzListViewRowVec AzListViewRowVec;
 typedef void (*AzListViewRowVecDestructorType)(AzListViewRowVec* restrict A);
 
+struct AzTreeViewNodeVec;
+typedef struct AzTreeViewNodeVec AzTreeViewNodeVec;
+typedef void (*AzTreeViewNodeVecDestructorType)(AzTreeViewNodeVec* restrict A);
+
+struct AzTreeViewNodeIdVec;
+typedef struct AzTreeViewNodeIdVec AzTreeViewNodeIdVec;
+typedef void (*AzTreeViewNodeIdVecDestructorType)(AzTreeViewNodeIdVec* restrict A);
+
+struct AzRibbonTabVec;
+typedef struct AzRibbonTabVec AzRibbonTabVec;
+typedef void (*AzRibbonTabVecDestructorType)(AzRibbonTabVec* restrict A);
+
+struct AzRibbonGroupVec;
+typedef struct AzRibbonGroupVec AzRibbonGroupVec;
+typedef void (*AzRibbonGroupVecDestructorType)(AzRibbonGroupVec* restrict A);
+
+struct AzRibbonItemVec;
+typedef struct AzRibbonItemVec AzRibbonItemVec;
+typedef void (*AzRibbonItemVecDestructorType)(AzRibbonItemVec* restrict A);
+
+struct AzSplitterPaneVec;
+typedef struct AzSplitterPaneVec AzSplitterPaneVec;
+typedef void (*AzSplitterPaneVecDestructorType)(AzSplitterPaneVec* restrict A);
+
+struct AzSplitterPaneStateVec;
+typedef struct AzSplitterPaneStateVec AzSplitterPaneStateVec;
+typedef void (*AzSplitterPaneStateVecDestructorType)(AzSplitterPaneStateVec* restrict A);
+
+struct AzTextAreaEditVec;
+typedef struct AzTextAreaEditVec AzTextAreaEditVec;
+typedef void (*AzTextAreaEditVecDestructorType)(AzTextAreaEditVec* restrict A);
+
 struct AzStyleFilterVec;
 typedef struct AzStyleFilterVec AzStyleFilterVec;
 typedef void (*AzStyleFilterVecDestructorType)(AzStyleFilterVec* restrict A);
@@ -250,6 +342,10 @@ struct AzAccessibilityStateVec;
 typedef struct AzAccessibilityStateVec AzAccessibilityStateVec;
 typedef void (*AzAccessibilityStateVecDestructorType)(AzAccessibilityStateVec* restrict A);
 
+struct AzAccessibilityActionVec;
+typedef struct AzAccessibilityActionVec AzAccessibilityActionVec;
+typedef void (*AzAccessibilityActionVecDestructorType)(AzAccessibilityActionVec* restrict A);
+
 struct AzMenuItemVec;
 typedef struct AzMenuItemVec AzMenuItemVec;
 typedef void (*AzMenuItemVecDestructorType)(AzMenuItemVec* restrict A);
@@ -322,6 +418,42 @@ struct AzStyleTransformVec;
 typedef struct AzStyleTransformVec AzStyleTransformVec;
 typedef void (*AzStyleTransformVecDestructorType)(AzStyleTransformVec* restrict A);
 
+struct AzStyleTransitionPropertyVec;
+typedef struct AzStyleTransitionPropertyVec AzStyleTransitionPropertyVec;
+typedef void (*AzStyleTransitionPropertyVecDestructorType)(AzStyleTransitionPropertyVec* restrict A);
+
+struct AzStyleTimeVec;
+typedef struct AzStyleTimeVec AzStyleTimeVec;
+typedef void (*AzStyleTimeVecDestructorType)(AzStyleTimeVec* restrict A);
+
+struct AzStyleTimingFunctionVec;
+typedef struct AzStyleTimingFunctionVec AzStyleTimingFunctionVec;
+typedef void (*AzStyleTimingFunctionVecDestructorType)(AzStyleTimingFunctionVec* restrict A);
+
+struct AzStyleAnimationNameVec;
+typedef struct AzStyleAnimationNameVec AzStyleAnimationNameVec;
+typedef void (*AzStyleAnimationNameVecDestructorType)(AzStyleAnimationNameVec* restrict A);
+
+struct AzStyleAnimationIterationCountVec;
+typedef struct AzStyleAnimationIterationCountVec AzStyleAnimationIterationCountVec;
+typedef void (*AzStyleAnimationIterationCountVecDestructorType)(AzStyleAnimationIterationCountVec* restrict A);
+
+struct AzStyleAnimationDirectionVec;
+typedef struct AzStyleAnimationDirectionVec AzStyleAnimationDirectionVec;
+typedef void (*AzStyleAnimationDirectionVecDestructorType)(AzStyleAnimationDirectionVec* restrict A);
+
+struct AzStyleAnimationFillModeVec;
+typedef struct AzStyleAnimationFillModeVec AzStyleAnimationFillModeVec;
+typedef void (*AzStyleAnimationFillModeVecDestructorType)(AzStyleAnimationFillModeVec* restrict A);
+
+struct AzStyleAnimationPlayStateVec;
+typedef struct AzStyleAnimationPlayStateVec AzStyleAnimationPlayStateVec;
+typedef void (*AzStyleAnimationPlayStateVecDestructorType)(AzStyleAnimationPlayStateVec* restrict A);
+
+struct AzGridTrackSizingVec;
+typedef struct AzGridTrackSizingVec AzGridTrackSizingVec;
+typedef void (*AzGridTrackSizingVecDestructorType)(AzGridTrackSizingVec* restrict A);
+
 struct AzCssPropertyVec;
 typedef struct AzCssPropertyVec AzCssPropertyVec;
 typedef void (*AzCssPropertyVecDestructorType)(AzCssPropertyVec* restrict A);
@@ -378,6 +510,26 @@ struct AzStylesheetVec;
 typedef struct AzStylesheetVec AzStylesheetVec;
 typedef void (*AzStylesheetVecDestructorType)(AzStylesheetVec* restrict A);
 
+struct AzCssKeyframesVec;
+typedef struct AzCssKeyframesVec AzCssKeyframesVec;
+typedef void (*AzCssKeyframesVecDestructorType)(AzCssKeyframesVec* restrict A);
+
+struct AzCssKeyframeVec;
+typedef struct AzCssKeyframeVec AzCssKeyframeVec;
+typedef void (*AzCssKeyframeVecDestructorType)(AzCssKeyframeVec* restrict A);
+
+struct AzCssMediaRuleVec;
+typedef struct AzCssMediaRuleVec AzCssMediaRuleVec;
+typedef void (*AzCssMediaRuleVecDestructorType)(AzCssMediaRuleVec* restrict A);
+
+struct AzCssMediaQueryVec;
+typedef struct AzCssMediaQueryVec AzCssMediaQueryVec;
+typedef void (*AzCssMediaQueryVecDestructorType)(AzCssMediaQueryVec* restrict A);
+
+struct AzCssMediaFeatureVec;
+typedef struct AzCssMediaFeatureVec AzCssMediaFeatureVec;
+typedef void (*AzCssMediaFeatureVecDestructorType)(AzCssMediaFeatureVec* restrict A);
+
 struct AzCssRuleBlockVec;
 typedef struct AzCssRuleBlockVec AzCssRuleBlockVec;
 typedef void (*AzCssRuleBlockVecDestructorType)(AzCssRuleBlockVec* restrict A);
@@ -552,6 +704,19 @@ struct AzAndroidHandle {
 };
 typedef struct AzAndroidHandle AzAndroidHandle;
 
+enum AzClipboardSelection {
+   AzClipboardSelection_Clipboard,
+   AzClipboardSelection_Primary,
+};
+typedef enum AzClipboardSelection AzClipboardSelection;
+
+enum AzLinuxBackend {
+   AzLinuxBackend_Auto,
+   AzLinuxBackend_X11,
+   AzLinuxBackend_Wayland,
+};
+typedef enum AzLinuxBackend AzLinuxBackend;
+
 enum AzXWindowType {
    AzXWindowType_Desktop,
    AzXWindowType_Dock,
@@ -953,6 +1118,11 @@ struct AzIFrameCallback {
 };
 typedef struct AzIFrameCallback AzIFrameCallback;
 
+struct AzTooltipCallback {
+    AzTooltipCallbackType cb;
+};
+typedef struct AzTooltipCallback AzTooltipCallback;
+
 struct AzRenderImageCallback {
     AzRenderImageCallbackType cb;
 };
@@ -995,6 +1165,9 @@ enum AzOn {
    AzOn_TextInput,
    AzOn_VirtualKeyDown,
    AzOn_VirtualKeyUp,
+   AzOn_CompositionStart,
+   AzOn_CompositionUpdate,
+   AzOn_CompositionEnd,
    AzOn_HoveredFile,
    AzOn_DroppedFile,
    AzOn_HoveredFileCancelled,
@@ -1021,6 +1194,9 @@ enum AzHoverEventFilter {
    AzHoverEventFilter_TextInput,
    AzHoverEventFilter_VirtualKeyDown,
    AzHoverEventFilter_VirtualKeyUp,
+   AzHoverEventFilter_CompositionStart,
+   AzHoverEventFilter_CompositionUpdate,
+   AzHoverEventFilter_CompositionEnd,
    AzHoverEventFilter_HoveredFile,
    AzHoverEventFilter_DroppedFile,
    AzHoverEventFilter_HoveredFileCancelled,
@@ -1049,6 +1225,9 @@ enum AzFocusEventFilter {
    AzFocusEventFilter_TextInput,
    AzFocusEventFilter_VirtualKeyDown,
    AzFocusEventFilter_VirtualKeyUp,
+   AzFocusEventFilter_CompositionStart,
+   AzFocusEventFilter_CompositionUpdate,
+   AzFocusEventFilter_CompositionEnd,
    AzFocusEventFilter_FocusReceived,
    AzFocusEventFilter_FocusLost,
 };
@@ -1072,6 +1251,9 @@ enum AzWindowEventFilter {
    AzWindowEventFilter_TextInput,
    AzWindowEventFilter_VirtualKeyDown,
    AzWindowEventFilter_VirtualKeyUp,
+   AzWindowEventFilter_CompositionStart,
+   AzWindowEventFilter_CompositionUpdate,
+   AzWindowEventFilter_CompositionEnd,
    AzWindowEventFilter_HoveredFile,
    AzWindowEventFilter_DroppedFile,
    AzWindowEventFilter_HoveredFileCancelled,
@@ -1191,6 +1373,18 @@ enum AzAccessibilityState {
 };
 typedef enum AzAccessibilityState AzAccessibilityState;
 
+enum AzAccessibilityAction {
+   AzAccessibilityAction_Default,
+   AzAccessibilityAction_Focus,
+   AzAccessibilityAction_Toggle,
+   AzAccessibilityAction_Expand,
+   AzAccessibilityAction_Collapse,
+   AzAccessibilityAction_Select,
+   AzAccessibilityAction_Increment,
+   AzAccessibilityAction_Decrement,
+};
+typedef enum AzAccessibilityAction AzAccessibilityAction;
+
 enum AzTabIndexTag {
    AzTabIndexTag_Auto,
    AzTabIndexTag_OverrideInParent,
@@ -1255,11 +1449,34 @@ struct AzCssNthChildPattern {
 };
 typedef struct AzCssNthChildPattern AzCssNthChildPattern;
 
+enum AzCssMediaType {
+   AzCssMediaType_All,
+   AzCssMediaType_Screen,
+   AzCssMediaType_Print,
+};
+typedef enum AzCssMediaType AzCssMediaType;
+
+enum AzCssMediaOrientation {
+   AzCssMediaOrientation_Portrait,
+   AzCssMediaOrientation_Landscape,
+};
+typedef enum AzCssMediaOrientation AzCssMediaOrientation;
+
+enum AzCssColorScheme {
+   AzCssColorScheme_Light,
+   AzCssColorScheme_Dark,
+};
+typedef enum AzCssColorScheme AzCssColorScheme;
+
 enum AzCssPropertyType {
    AzCssPropertyType_TextColor,
    AzCssPropertyType_FontSize,
    AzCssPropertyType_FontFamily,
    AzCssPropertyType_TextAlign,
+   AzCssPropertyType_Direction,
+   AzCssPropertyType_WordBreak,
+   AzCssPropertyType_OverflowWrap,
+   AzCssPropertyType_Hyphens,
    AzCssPropertyType_LetterSpacing,
    AzCssPropertyType_LineHeight,
    AzCssPropertyType_WordSpacing,
@@ -1286,6 +1503,15 @@ enum AzCssPropertyType {
    AzCssPropertyType_JustifyContent,
    AzCssPropertyType_AlignItems,
    AzCssPropertyType_AlignContent,
+   AzCssPropertyType_GridTemplateColumns,
+   AzCssPropertyType_GridTemplateRows,
+   AzCssPropertyType_GridAutoColumns,
+   AzCssPropertyType_GridAutoRows,
+   AzCssPropertyType_GridAutoFlow,
+   AzCssPropertyType_GridColumn,
+   AzCssPropertyType_GridRow,
+   AzCssPropertyType_RowGap,
+   AzCssPropertyType_ColumnGap,
    AzCssPropertyType_BackgroundContent,
    AzCssPropertyType_BackgroundPosition,
    AzCssPropertyType_BackgroundSize,
@@ -1330,9 +1556,41 @@ enum AzCssPropertyType {
    AzCssPropertyType_Filter,
    AzCssPropertyType_BackdropFilter,
    AzCssPropertyType_TextShadow,
+   AzCssPropertyType_TransitionProperty,
+   AzCssPropertyType_TransitionDuration,
+   AzCssPropertyType_TransitionTimingFunction,
+   AzCssPropertyType_TransitionDelay,
+   AzCssPropertyType_AnimationName,
+   AzCssPropertyType_AnimationDuration,
+   AzCssPropertyType_AnimationTimingFunction,
+   AzCssPropertyType_AnimationDelay,
+   AzCssPropertyType_AnimationIterationCount,
+   AzCssPropertyType_AnimationDirection,
+   AzCssPropertyType_AnimationFillMode,
+   AzCssPropertyType_AnimationPlayState,
 };
 typedef enum AzCssPropertyType AzCssPropertyType;
 
+enum AzCombinedCssPropertyType {
+   AzCombinedCssPropertyType_BorderRadius,
+   AzCombinedCssPropertyType_Overflow,
+   AzCombinedCssPropertyType_Margin,
+   AzCombinedCssPropertyType_Border,
+   AzCombinedCssPropertyType_BorderLeft,
+   AzCombinedCssPropertyType_BorderRight,
+   AzCombinedCssPropertyType_BorderTop,
+   AzCombinedCssPropertyType_BorderBottom,
+   AzCombinedCssPropertyType_Padding,
+   AzCombinedCssPropertyType_BoxShadow,
+   AzCombinedCssPropertyType_BackgroundColor,
+   AzCombinedCssPropertyType_BackgroundImage,
+   AzCombinedCssPropertyType_Gap,
+   AzCombinedCssPropertyType_GridGap,
+   AzCombinedCssPropertyType_Transition,
+   AzCombinedCssPropertyType_Animation,
+};
+typedef enum AzCombinedCssPropertyType AzCombinedCssPropertyType;
+
 struct AzColorU {
     uint8_t r;
     uint8_t g;
@@ -1390,6 +1648,34 @@ enum AzLayoutAlignContent {
 };
 typedef enum AzLayoutAlignContent AzLayoutAlignContent;
 
+enum AzLayoutGridAutoFlow {
+   AzLayoutGridAutoFlow_Row,
+   AzLayoutGridAutoFlow_Column,
+   AzLayoutGridAutoFlow_RowDense,
+   AzLayoutGridAutoFlow_ColumnDense,
+};
+typedef enum AzLayoutGridAutoFlow AzLayoutGridAutoFlow;
+
+enum AzGridLineTag {
+   AzGridLineTag_Auto,
+   AzGridLineTag_Line,
+   AzGridLineTag_Span,
+};
+typedef enum AzGridLineTag AzGridLineTag;
+
+struct AzGridLineVariant_Auto { AzGridLineTag tag; };
+typedef struct AzGridLineVariant_Auto AzGridLineVariant_Auto;
+struct AzGridLineVariant_Line { AzGridLineTag tag; int32_t payload; };
+typedef struct AzGridLineVariant_Line AzGridLineVariant_Line;
+struct AzGridLineVariant_Span { AzGridLineTag tag; uint32_t payload; };
+typedef struct AzGridLineVariant_Span AzGridLineVariant_Span;
+union AzGridLine {
+    AzGridLineVariant_Auto Auto;
+    AzGridLineVariant_Line Line;
+    AzGridLineVariant_Span Span;
+};
+typedef union AzGridLine AzGridLine;
+
 enum AzLayoutAlignItems {
    AzLayoutAlignItems_Stretch,
    AzLayoutAlignItems_Center,
@@ -1417,6 +1703,7 @@ enum AzLayoutDisplay {
    AzLayoutDisplay_Flex,
    AzLayoutDisplay_Block,
    AzLayoutDisplay_InlineBlock,
+   AzLayoutDisplay_Grid,
 };
 typedef enum AzLayoutDisplay AzLayoutDisplay;
 
@@ -1561,17 +1848,90 @@ enum AzStyleBackfaceVisibility {
 };
 typedef enum AzStyleBackfaceVisibility AzStyleBackfaceVisibility;
 
+struct AzStyleTime {
+    int32_t ms;
+};
+typedef struct AzStyleTime AzStyleTime;
+
+enum AzStyleStepPosition {
+   AzStyleStepPosition_JumpStart,
+   AzStyleStepPosition_JumpEnd,
+   AzStyleStepPosition_JumpNone,
+   AzStyleStepPosition_JumpBoth,
+};
+typedef enum AzStyleStepPosition AzStyleStepPosition;
+
+enum AzStyleAnimationDirection {
+   AzStyleAnimationDirection_Normal,
+   AzStyleAnimationDirection_Reverse,
+   AzStyleAnimationDirection_Alternate,
+   AzStyleAnimationDirection_AlternateReverse,
+};
+typedef enum AzStyleAnimationDirection AzStyleAnimationDirection;
+
+enum AzStyleAnimationFillMode {
+   AzStyleAnimationFillMode_None,
+   AzStyleAnimationFillMode_Forwards,
+   AzStyleAnimationFillMode_Backwards,
+   AzStyleAnimationFillMode_Both,
+};
+typedef enum AzStyleAnimationFillMode AzStyleAnimationFillMode;
+
+enum AzStyleAnimationPlayState {
+   AzStyleAnimationPlayState_Running,
+   AzStyleAnimationPlayState_Paused,
+};
+typedef enum AzStyleAnimationPlayState AzStyleAnimationPlayState;
+
 enum AzStyleTextAlign {
    AzStyleTextAlign_Left,
    AzStyleTextAlign_Center,
    AzStyleTextAlign_Right,
+   AzStyleTextAlign_Start,
+   AzStyleTextAlign_End,
 };
 typedef enum AzStyleTextAlign AzStyleTextAlign;
 
-struct AzRibbon {
-    int32_t tab_active;
+enum AzStyleDirection {
+   AzStyleDirection_Ltr,
+   AzStyleDirection_Rtl,
 };
-typedef struct AzRibbon AzRibbon;
+typedef enum AzStyleDirection AzStyleDirection;
+
+enum AzStyleWordBreak {
+   AzStyleWordBreak_Normal,
+   AzStyleWordBreak_BreakAll,
+   AzStyleWordBreak_KeepAll,
+};
+typedef enum AzStyleWordBreak AzStyleWordBreak;
+
+enum AzStyleOverflowWrap {
+   AzStyleOverflowWrap_Normal,
+   AzStyleOverflowWrap_Anywhere,
+   AzStyleOverflowWrap_BreakWord,
+};
+typedef enum AzStyleOverflowWrap AzStyleOverflowWrap;
+
+enum AzStyleHyphens {
+   AzStyleHyphens_None,
+   AzStyleHyphens_Manual,
+   AzStyleHyphens_Auto,
+};
+typedef enum AzStyleHyphens AzStyleHyphens;
+
+enum AzRibbonItemKind {
+   AzRibbonItemKind_LargeButton,
+   AzRibbonItemKind_SmallButton,
+   AzRibbonItemKind_Toggle,
+   AzRibbonItemKind_DropDown,
+   AzRibbonItemKind_SplitButton,
+};
+typedef enum AzRibbonItemKind AzRibbonItemKind;
+
+struct AzRibbonOnItemClickCallback {
+    AzRibbonOnItemClickCallbackType cb;
+};
+typedef struct AzRibbonOnItemClickCallback AzRibbonOnItemClickCallback;
 
 struct AzRibbonOnTabClickedCallback {
     AzRibbonOnTabClickedCallbackType cb;
@@ -1625,6 +1985,18 @@ enum AzTextInputValid {
 };
 typedef enum AzTextInputValid AzTextInputValid;
 
+enum AzTextAreaEditKind {
+   AzTextAreaEditKind_Insert,
+   AzTextAreaEditKind_Delete,
+   AzTextAreaEditKind_Replace,
+};
+typedef enum AzTextAreaEditKind AzTextAreaEditKind;
+
+struct AzTextAreaOnTextChangeCallback {
+    AzTextAreaOnTextChangeCallbackType cb;
+};
+typedef struct AzTextAreaOnTextChangeCallback AzTextAreaOnTextChangeCallback;
+
 struct AzNumberInputState {
     float previous;
     float number;
@@ -1643,11 +2015,17 @@ struct AzNumberInputOnFocusLostCallback {
 };
 typedef struct AzNumberInputOnFocusLostCallback AzNumberInputOnFocusLostCallback;
 
-struct AzProgressBarState {
-    float percent_done;
-    bool  display_percentage;
+enum AzProgressBarMode {
+   AzProgressBarMode_Determinate,
+   AzProgressBarMode_Indeterminate,
 };
-typedef struct AzProgressBarState AzProgressBarState;
+typedef enum AzProgressBarMode AzProgressBarMode;
+
+enum AzProgressBarStyle {
+   AzProgressBarStyle_Solid,
+   AzProgressBarStyle_Stripes,
+};
+typedef enum AzProgressBarStyle AzProgressBarStyle;
 
 struct AzTabHeaderState {
     size_t active_tab;
@@ -1659,6 +2037,71 @@ struct AzTabOnClickCallback {
 };
 typedef struct AzTabOnClickCallback AzTabOnClickCallback;
 
+struct AzTabOnCloseCallback {
+    AzTabOnCloseCallbackType cb;
+};
+typedef struct AzTabOnCloseCallback AzTabOnCloseCallback;
+
+struct AzTabOnReorderCallback {
+    AzTabOnReorderCallbackType cb;
+};
+typedef struct AzTabOnReorderCallback AzTabOnReorderCallback;
+
+struct AzTabOnContextMenuCallback {
+    AzTabOnContextMenuCallbackType cb;
+};
+typedef struct AzTabOnContextMenuCallback AzTabOnContextMenuCallback;
+
+enum AzSplitterOrientation {
+   AzSplitterOrientation_Horizontal,
+   AzSplitterOrientation_Vertical,
+};
+typedef enum AzSplitterOrientation AzSplitterOrientation;
+
+struct AzSplitterPaneState {
+    float size;
+    bool  collapsed;
+};
+typedef struct AzSplitterPaneState AzSplitterPaneState;
+
+struct AzSplitterOnResizeCallback {
+    AzSplitterOnResizeCallbackType cb;
+};
+typedef struct AzSplitterOnResizeCallback AzSplitterOnResizeCallback;
+
+enum AzSliderOrientation {
+   AzSliderOrientation_Horizontal,
+   AzSliderOrientation_Vertical,
+};
+typedef enum AzSliderOrientation AzSliderOrientation;
+
+struct AzSliderState {
+    float value;
+    float min;
+    float max;
+    float step;
+};
+typedef struct AzSliderState AzSliderState;
+
+struct AzRangeSliderState {
+    float low;
+    float high;
+    float min;
+    float max;
+    float step;
+};
+typedef struct AzRangeSliderState AzRangeSliderState;
+
+struct AzSliderOnValueChangeCallback {
+    AzSliderOnValueChangeCallbackType cb;
+};
+typedef struct AzSliderOnValueChangeCallback AzSliderOnValueChangeCallback;
+
+struct AzRangeSliderOnValueChangeCallback {
+    AzRangeSliderOnValueChangeCallbackType cb;
+};
+typedef struct AzRangeSliderOnValueChangeCallback AzRangeSliderOnValueChangeCallback;
+
 enum AzNodeGraphStyle {
    AzNodeGraphStyle_Default,
 };
@@ -1752,6 +2195,53 @@ struct AzListViewOnRowClickCallback {
 };
 typedef struct AzListViewOnRowClickCallback AzListViewOnRowClickCallback;
 
+struct AzListViewRowRange {
+    size_t start;
+    size_t end;
+};
+typedef struct AzListViewRowRange AzListViewRowRange;
+
+struct AzListViewOnColumnResizeCallback {
+    AzListViewOnColumnResizeCallbackType cb;
+};
+typedef struct AzListViewOnColumnResizeCallback AzListViewOnColumnResizeCallback;
+
+struct AzListViewRowProviderCallback {
+    AzListViewRowProviderCallbackType cb;
+};
+typedef struct AzListViewRowProviderCallback AzListViewRowProviderCallback;
+
+struct AzTreeViewNodeId {
+    uint64_t inner;
+};
+typedef struct AzTreeViewNodeId AzTreeViewNodeId;
+
+enum AzTreeViewSelectionMode {
+   AzTreeViewSelectionMode_Single,
+   AzTreeViewSelectionMode_Multiple,
+};
+typedef enum AzTreeViewSelectionMode AzTreeViewSelectionMode;
+
+struct AzTreeViewOnExpandCallback {
+    AzTreeViewOnExpandCallbackType cb;
+};
+typedef struct AzTreeViewOnExpandCallback AzTreeViewOnExpandCallback;
+
+struct AzTreeViewOnSelectCallback {
+    AzTreeViewOnSelectCallbackType cb;
+};
+typedef struct AzTreeViewOnSelectCallback AzTreeViewOnSelectCallback;
+
+struct AzTreeViewOnDoubleClickCallback {
+    AzTreeViewOnDoubleClickCallbackType cb;
+};
+typedef struct AzTreeViewOnDoubleClickCallback AzTreeViewOnDoubleClickCallback;
+
+struct AzTreeViewOnContextMenuCallback {
+    AzTreeViewOnContextMenuCallbackType cb;
+};
+typedef struct AzTreeViewOnContextMenuCallback AzTreeViewOnContextMenuCallback;
+
 struct AzDropDownOnChoiceChangeCallback {
     AzDropDownOnChoiceChangeCallbackType cb;
 };
@@ -2238,6 +2728,16 @@ struct AzGetSystemTimeFn {
 };
 typedef struct AzGetSystemTimeFn AzGetSystemTimeFn;
 
+struct AzGetClipboardFn {
+    AzGetClipboardFnType cb;
+};
+typedef struct AzGetClipboardFn AzGetClipboardFn;
+
+struct AzSetClipboardFn {
+    AzSetClipboardFnType cb;
+};
+typedef struct AzSetClipboardFn AzSetClipboardFn;
+
 struct AzCheckThreadFinishedFn {
     AzCheckThreadFinishedFnType cb;
 };
@@ -2318,6 +2818,166 @@ union AzListViewRowVecDestructor {
 };
 typedef union AzListViewRowVecDestructor AzListViewRowVecDestructor;
 
+enum AzTreeViewNodeVecDestructorTag {
+   AzTreeViewNodeVecDestructorTag_DefaultRust,
+   AzTreeViewNodeVecDestructorTag_NoDestructor,
+   AzTreeViewNodeVecDestructorTag_External,
+};
+typedef enum AzTreeViewNodeVecDestructorTag AzTreeViewNodeVecDestructorTag;
+
+struct AzTreeViewNodeVecDestructorVariant_DefaultRust { AzTreeViewNodeVecDestructorTag tag; };
+typedef struct AzTreeViewNodeVecDestructorVariant_DefaultRust AzTreeViewNodeVecDestructorVariant_DefaultRust;
+struct AzTreeViewNodeVecDestructorVariant_NoDestructor { AzTreeViewNodeVecDestructorTag tag; };
+typedef struct AzTreeViewNodeVecDestructorVariant_NoDestructor AzTreeViewNodeVecDestructorVariant_NoDestructor;
+struct AzTreeViewNodeVecDestructorVariant_External { AzTreeViewNodeVecDestructorTag tag; AzTreeViewNodeVecDestructorType payload; };
+typedef struct AzTreeViewNodeVecDestructorVariant_External AzTreeViewNodeVecDestructorVariant_External;
+union AzTreeViewNodeVecDestructor {
+    AzTreeViewNodeVecDestructorVariant_DefaultRust DefaultRust;
+    AzTreeViewNodeVecDestructorVariant_NoDestructor NoDestructor;
+    AzTreeViewNodeVecDestructorVariant_External External;
+};
+typedef union AzTreeViewNodeVecDestructor AzTreeViewNodeVecDestructor;
+
+enum AzTreeViewNodeIdVecDestructorTag {
+   AzTreeViewNodeIdVecDestructorTag_DefaultRust,
+   AzTreeViewNodeIdVecDestructorTag_NoDestructor,
+   AzTreeViewNodeIdVecDestructorTag_External,
+};
+typedef enum AzTreeViewNodeIdVecDestructorTag AzTreeViewNodeIdVecDestructorTag;
+
+struct AzTreeViewNodeIdVecDestructorVariant_DefaultRust { AzTreeViewNodeIdVecDestructorTag tag; };
+typedef struct AzTreeViewNodeIdVecDestructorVariant_DefaultRust AzTreeViewNodeIdVecDestructorVariant_DefaultRust;
+struct AzTreeViewNodeIdVecDestructorVariant_NoDestructor { AzTreeViewNodeIdVecDestructorTag tag; };
+typedef struct AzTreeViewNodeIdVecDestructorVariant_NoDestructor AzTreeViewNodeIdVecDestructorVariant_NoDestructor;
+struct AzTreeViewNodeIdVecDestructorVariant_External { AzTreeViewNodeIdVecDestructorTag tag; AzTreeViewNodeIdVecDestructorType payload; };
+typedef struct AzTreeViewNodeIdVecDestructorVariant_External AzTreeViewNodeIdVecDestructorVariant_External;
+union AzTreeViewNodeIdVecDestructor {
+    AzTreeViewNodeIdVecDestructorVariant_DefaultRust DefaultRust;
+    AzTreeViewNodeIdVecDestructorVariant_NoDestructor NoDestructor;
+    AzTreeViewNodeIdVecDestructorVariant_External External;
+};
+typedef union AzTreeViewNodeIdVecDestructor AzTreeViewNodeIdVecDestructor;
+
+enum AzRibbonTabVecDestructorTag {
+   AzRibbonTabVecDestructorTag_DefaultRust,
+   AzRibbonTabVecDestructorTag_NoDestructor,
+   AzRibbonTabVecDestructorTag_External,
+};
+typedef enum AzRibbonTabVecDestructorTag AzRibbonTabVecDestructorTag;
+
+struct AzRibbonTabVecDestructorVariant_DefaultRust { AzRibbonTabVecDestructorTag tag; };
+typedef struct AzRibbonTabVecDestructorVariant_DefaultRust AzRibbonTabVecDestructorVariant_DefaultRust;
+struct AzRibbonTabVecDestructorVariant_NoDestructor { AzRibbonTabVecDestructorTag tag; };
+typedef struct AzRibbonTabVecDestructorVariant_NoDestructor AzRibbonTabVecDestructorVariant_NoDestructor;
+struct AzRibbonTabVecDestructorVariant_External { AzRibbonTabVecDestructorTag tag; AzRibbonTabVecDestructorType payload; };
+typedef struct AzRibbonTabVecDestructorVariant_External AzRibbonTabVecDestructorVariant_External;
+union AzRibbonTabVecDestructor {
+    AzRibbonTabVecDestructorVariant_DefaultRust DefaultRust;
+    AzRibbonTabVecDestructorVariant_NoDestructor NoDestructor;
+    AzRibbonTabVecDestructorVariant_External External;
+};
+typedef union AzRibbonTabVecDestructor AzRibbonTabVecDestructor;
+
+enum AzRibbonGroupVecDestructorTag {
+   AzRibbonGroupVecDestructorTag_DefaultRust,
+   AzRibbonGroupVecDestructorTag_NoDestructor,
+   AzRibbonGroupVecDestructorTag_External,
+};
+typedef enum AzRibbonGroupVecDestructorTag AzRibbonGroupVecDestructorTag;
+
+struct AzRibbonGroupVecDestructorVariant_DefaultRust { AzRibbonGroupVecDestructorTag tag; };
+typedef struct AzRibbonGroupVecDestructorVariant_DefaultRust AzRibbonGroupVecDestructorVariant_DefaultRust;
+struct AzRibbonGroupVecDestructorVariant_NoDestructor { AzRibbonGroupVecDestructorTag tag; };
+typedef struct AzRibbonGroupVecDestructorVariant_NoDestructor AzRibbonGroupVecDestructorVariant_NoDestructor;
+struct AzRibbonGroupVecDestructorVariant_External { AzRibbonGroupVecDestructorTag tag; AzRibbonGroupVecDestructorType payload; };
+typedef struct AzRibbonGroupVecDestructorVariant_External AzRibbonGroupVecDestructorVariant_External;
+union AzRibbonGroupVecDestructor {
+    AzRibbonGroupVecDestructorVariant_DefaultRust DefaultRust;
+    AzRibbonGroupVecDestructorVariant_NoDestructor NoDestructor;
+    AzRibbonGroupVecDestructorVariant_External External;
+};
+typedef union AzRibbonGroupVecDestructor AzRibbonGroupVecDestructor;
+
+enum AzRibbonItemVecDestructorTag {
+   AzRibbonItemVecDestructorTag_DefaultRust,
+   AzRibbonItemVecDestructorTag_NoDestructor,
+   AzRibbonItemVecDestructorTag_External,
+};
+typedef enum AzRibbonItemVecDestructorTag AzRibbonItemVecDestructorTag;
+
+struct AzRibbonItemVecDestructorVariant_DefaultRust { AzRibbonItemVecDestructorTag tag; };
+typedef struct AzRibbonItemVecDestructorVariant_DefaultRust AzRibbonItemVecDestructorVariant_DefaultRust;
+struct AzRibbonItemVecDestructorVariant_NoDestructor { AzRibbonItemVecDestructorTag tag; };
+typedef struct AzRibbonItemVecDestructorVariant_NoDestructor AzRibbonItemVecDestructorVariant_NoDestructor;
+struct AzRibbonItemVecDestructorVariant_External { AzRibbonItemVecDestructorTag tag; AzRibbonItemVecDestructorType payload; };
+typedef struct AzRibbonItemVecDestructorVariant_External AzRibbonItemVecDestructorVariant_External;
+union AzRibbonItemVecDestructor {
+    AzRibbonItemVecDestructorVariant_DefaultRust DefaultRust;
+    AzRibbonItemVecDestructorVariant_NoDestructor NoDestructor;
+    AzRibbonItemVecDestructorVariant_External External;
+};
+typedef union AzRibbonItemVecDestructor AzRibbonItemVecDestructor;
+
+enum AzSplitterPaneVecDestructorTag {
+   AzSplitterPaneVecDestructorTag_DefaultRust,
+   AzSplitterPaneVecDestructorTag_NoDestructor,
+   AzSplitterPaneVecDestructorTag_External,
+};
+typedef enum AzSplitterPaneVecDestructorTag AzSplitterPaneVecDestructorTag;
+
+struct AzSplitterPaneVecDestructorVariant_DefaultRust { AzSplitterPaneVecDestructorTag tag; };
+typedef struct AzSplitterPaneVecDestructorVariant_DefaultRust AzSplitterPaneVecDestructorVariant_DefaultRust;
+struct AzSplitterPaneVecDestructorVariant_NoDestructor { AzSplitterPaneVecDestructorTag tag; };
+typedef struct AzSplitterPaneVecDestructorVariant_NoDestructor AzSplitterPaneVecDestructorVariant_NoDestructor;
+struct AzSplitterPaneVecDestructorVariant_External { AzSplitterPaneVecDestructorTag tag; AzSplitterPaneVecDestructorType payload; };
+typedef struct AzSplitterPaneVecDestructorVariant_External AzSplitterPaneVecDestructorVariant_External;
+union AzSplitterPaneVecDestructor {
+    AzSplitterPaneVecDestructorVariant_DefaultRust DefaultRust;
+    AzSplitterPaneVecDestructorVariant_NoDestructor NoDestructor;
+    AzSplitterPaneVecDestructorVariant_External External;
+};
+typedef union AzSplitterPaneVecDestructor AzSplitterPaneVecDestructor;
+
+enum AzSplitterPaneStateVecDestructorTag {
+   AzSplitterPaneStateVecDestructorTag_DefaultRust,
+   AzSplitterPaneStateVecDestructorTag_NoDestructor,
+   AzSplitterPaneStateVecDestructorTag_External,
+};
+typedef enum AzSplitterPaneStateVecDestructorTag AzSplitterPaneStateVecDestructorTag;
+
+struct AzSplitterPaneStateVecDestructorVariant_DefaultRust { AzSplitterPaneStateVecDestructorTag tag; };
+typedef struct AzSplitterPaneStateVecDestructorVariant_DefaultRust AzSplitterPaneStateVecDestructorVariant_DefaultRust;
+struct AzSplitterPaneStateVecDestructorVariant_NoDestructor { AzSplitterPaneStateVecDestructorTag tag; };
+typedef struct AzSplitterPaneStateVecDestructorVariant_NoDestructor AzSplitterPaneStateVecDestructorVariant_NoDestructor;
+struct AzSplitterPaneStateVecDestructorVariant_External { AzSplitterPaneStateVecDestructorTag tag; AzSplitterPaneStateVecDestructorType payload; };
+typedef struct AzSplitterPaneStateVecDestructorVariant_External AzSplitterPaneStateVecDestructorVariant_External;
+union AzSplitterPaneStateVecDestructor {
+    AzSplitterPaneStateVecDestructorVariant_DefaultRust DefaultRust;
+    AzSplitterPaneStateVecDestructorVariant_NoDestructor NoDestructor;
+    AzSplitterPaneStateVecDestructorVariant_External External;
+};
+typedef union AzSplitterPaneStateVecDestructor AzSplitterPaneStateVecDestructor;
+
+enum AzTextAreaEditVecDestructorTag {
+   AzTextAreaEditVecDestructorTag_DefaultRust,
+   AzTextAreaEditVecDestructorTag_NoDestructor,
+   AzTextAreaEditVecDestructorTag_External,
+};
+typedef enum AzTextAreaEditVecDestructorTag AzTextAreaEditVecDestructorTag;
+
+struct AzTextAreaEditVecDestructorVariant_DefaultRust { AzTextAreaEditVecDestructorTag tag; };
+typedef struct AzTextAreaEditVecDestructorVariant_DefaultRust AzTextAreaEditVecDestructorVariant_DefaultRust;
+struct AzTextAreaEditVecDestructorVariant_NoDestructor { AzTextAreaEditVecDestructorTag tag; };
+typedef struct AzTextAreaEditVecDestructorVariant_NoDestructor AzTextAreaEditVecDestructorVariant_NoDestructor;
+struct AzTextAreaEditVecDestructorVariant_External { AzTextAreaEditVecDestructorTag tag; AzTextAreaEditVecDestructorType payload; };
+typedef struct AzTextAreaEditVecDestructorVariant_External AzTextAreaEditVecDestructorVariant_External;
+union AzTextAreaEditVecDestructor {
+    AzTextAreaEditVecDestructorVariant_DefaultRust DefaultRust;
+    AzTextAreaEditVecDestructorVariant_NoDestructor NoDestructor;
+    AzTextAreaEditVecDestructorVariant_External External;
+};
+typedef union AzTextAreaEditVecDestructor AzTextAreaEditVecDestructor;
+
 enum AzStyleFilterVecDestructorTag {
    AzStyleFilterVecDestructorTag_DefaultRust,
    AzStyleFilterVecDestructorTag_NoDestructor,
@@ -2558,6 +3218,26 @@ union AzAccessibilityStateVecDestructor {
 };
 typedef union AzAccessibilityStateVecDestructor AzAccessibilityStateVecDestructor;
 
+enum AzAccessibilityActionVecDestructorTag {
+   AzAccessibilityActionVecDestructorTag_DefaultRust,
+   AzAccessibilityActionVecDestructorTag_NoDestructor,
+   AzAccessibilityActionVecDestructorTag_External,
+};
+typedef enum AzAccessibilityActionVecDestructorTag AzAccessibilityActionVecDestructorTag;
+
+struct AzAccessibilityActionVecDestructorVariant_DefaultRust { AzAccessibilityActionVecDestructorTag tag; };
+typedef struct AzAccessibilityActionVecDestructorVariant_DefaultRust AzAccessibilityActionVecDestructorVariant_DefaultRust;
+struct AzAccessibilityActionVecDestructorVariant_NoDestructor { AzAccessibilityActionVecDestructorTag tag; };
+typedef struct AzAccessibilityActionVecDestructorVariant_NoDestructor AzAccessibilityActionVecDestructorVariant_NoDestructor;
+struct AzAccessibilityActionVecDestructorVariant_External { AzAccessibilityActionVecDestructorTag tag; AzAccessibilityActionVecDestructorType payload; };
+typedef struct AzAccessibilityActionVecDestructorVariant_External AzAccessibilityActionVecDestructorVariant_External;
+union AzAccessibilityActionVecDestructor {
+    AzAccessibilityActionVecDestructorVariant_DefaultRust DefaultRust;
+    AzAccessibilityActionVecDestructorVariant_NoDestructor NoDestructor;
+    AzAccessibilityActionVecDestructorVariant_External External;
+};
+typedef union AzAccessibilityActionVecDestructor AzAccessibilityActionVecDestructor;
+
 enum AzMenuItemVecDestructorTag {
    AzMenuItemVecDestructorTag_DefaultRust,
    AzMenuItemVecDestructorTag_NoDestructor,
@@ -2918,6 +3598,186 @@ union AzStyleTransformVecDestructor {
 };
 typedef union AzStyleTransformVecDestructor AzStyleTransformVecDestructor;
 
+enum AzStyleTransitionPropertyVecDestructorTag {
+   AzStyleTransitionPropertyVecDestructorTag_DefaultRust,
+   AzStyleTransitionPropertyVecDestructorTag_NoDestructor,
+   AzStyleTransitionPropertyVecDestructorTag_External,
+};
+typedef enum AzStyleTransitionPropertyVecDestructorTag AzStyleTransitionPropertyVecDestructorTag;
+
+struct AzStyleTransitionPropertyVecDestructorVariant_DefaultRust { AzStyleTransitionPropertyVecDestructorTag tag; };
+typedef struct AzStyleTransitionPropertyVecDestructorVariant_DefaultRust AzStyleTransitionPropertyVecDestructorVariant_DefaultRust;
+struct AzStyleTransitionPropertyVecDestructorVariant_NoDestructor { AzStyleTransitionPropertyVecDestructorTag tag; };
+typedef struct AzStyleTransitionPropertyVecDestructorVariant_NoDestructor AzStyleTransitionPropertyVecDestructorVariant_NoDestructor;
+struct AzStyleTransitionPropertyVecDestructorVariant_External { AzStyleTransitionPropertyVecDestructorTag tag; AzStyleTransitionPropertyVecDestructorType payload; };
+typedef struct AzStyleTransitionPropertyVecDestructorVariant_External AzStyleTransitionPropertyVecDestructorVariant_External;
+union AzStyleTransitionPropertyVecDestructor {
+    AzStyleTransitionPropertyVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleTransitionPropertyVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleTransitionPropertyVecDestructorVariant_External External;
+};
+typedef union AzStyleTransitionPropertyVecDestructor AzStyleTransitionPropertyVecDestructor;
+
+enum AzStyleTimeVecDestructorTag {
+   AzStyleTimeVecDestructorTag_DefaultRust,
+   AzStyleTimeVecDestructorTag_NoDestructor,
+   AzStyleTimeVecDestructorTag_External,
+};
+typedef enum AzStyleTimeVecDestructorTag AzStyleTimeVecDestructorTag;
+
+struct AzStyleTimeVecDestructorVariant_DefaultRust { AzStyleTimeVecDestructorTag tag; };
+typedef struct AzStyleTimeVecDestructorVariant_DefaultRust AzStyleTimeVecDestructorVariant_DefaultRust;
+struct AzStyleTimeVecDestructorVariant_NoDestructor { AzStyleTimeVecDestructorTag tag; };
+typedef struct AzStyleTimeVecDestructorVariant_NoDestructor AzStyleTimeVecDestructorVariant_NoDestructor;
+struct AzStyleTimeVecDestructorVariant_External { AzStyleTimeVecDestructorTag tag; AzStyleTimeVecDestructorType payload; };
+typedef struct AzStyleTimeVecDestructorVariant_External AzStyleTimeVecDestructorVariant_External;
+union AzStyleTimeVecDestructor {
+    AzStyleTimeVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleTimeVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleTimeVecDestructorVariant_External External;
+};
+typedef union AzStyleTimeVecDestructor AzStyleTimeVecDestructor;
+
+enum AzStyleTimingFunctionVecDestructorTag {
+   AzStyleTimingFunctionVecDestructorTag_DefaultRust,
+   AzStyleTimingFunctionVecDestructorTag_NoDestructor,
+   AzStyleTimingFunctionVecDestructorTag_External,
+};
+typedef enum AzStyleTimingFunctionVecDestructorTag AzStyleTimingFunctionVecDestructorTag;
+
+struct AzStyleTimingFunctionVecDestructorVariant_DefaultRust { AzStyleTimingFunctionVecDestructorTag tag; };
+typedef struct AzStyleTimingFunctionVecDestructorVariant_DefaultRust AzStyleTimingFunctionVecDestructorVariant_DefaultRust;
+struct AzStyleTimingFunctionVecDestructorVariant_NoDestructor { AzStyleTimingFunctionVecDestructorTag tag; };
+typedef struct AzStyleTimingFunctionVecDestructorVariant_NoDestructor AzStyleTimingFunctionVecDestructorVariant_NoDestructor;
+struct AzStyleTimingFunctionVecDestructorVariant_External { AzStyleTimingFunctionVecDestructorTag tag; AzStyleTimingFunctionVecDestructorType payload; };
+typedef struct AzStyleTimingFunctionVecDestructorVariant_External AzStyleTimingFunctionVecDestructorVariant_External;
+union AzStyleTimingFunctionVecDestructor {
+    AzStyleTimingFunctionVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleTimingFunctionVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleTimingFunctionVecDestructorVariant_External External;
+};
+typedef union AzStyleTimingFunctionVecDestructor AzStyleTimingFunctionVecDestructor;
+
+enum AzStyleAnimationNameVecDestructorTag {
+   AzStyleAnimationNameVecDestructorTag_DefaultRust,
+   AzStyleAnimationNameVecDestructorTag_NoDestructor,
+   AzStyleAnimationNameVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationNameVecDestructorTag AzStyleAnimationNameVecDestructorTag;
+
+struct AzStyleAnimationNameVecDestructorVariant_DefaultRust { AzStyleAnimationNameVecDestructorTag tag; };
+typedef struct AzStyleAnimationNameVecDestructorVariant_DefaultRust AzStyleAnimationNameVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationNameVecDestructorVariant_NoDestructor { AzStyleAnimationNameVecDestructorTag tag; };
+typedef struct AzStyleAnimationNameVecDestructorVariant_NoDestructor AzStyleAnimationNameVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationNameVecDestructorVariant_External { AzStyleAnimationNameVecDestructorTag tag; AzStyleAnimationNameVecDestructorType payload; };
+typedef struct AzStyleAnimationNameVecDestructorVariant_External AzStyleAnimationNameVecDestructorVariant_External;
+union AzStyleAnimationNameVecDestructor {
+    AzStyleAnimationNameVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationNameVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationNameVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationNameVecDestructor AzStyleAnimationNameVecDestructor;
+
+enum AzStyleAnimationIterationCountVecDestructorTag {
+   AzStyleAnimationIterationCountVecDestructorTag_DefaultRust,
+   AzStyleAnimationIterationCountVecDestructorTag_NoDestructor,
+   AzStyleAnimationIterationCountVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationIterationCountVecDestructorTag AzStyleAnimationIterationCountVecDestructorTag;
+
+struct AzStyleAnimationIterationCountVecDestructorVariant_DefaultRust { AzStyleAnimationIterationCountVecDestructorTag tag; };
+typedef struct AzStyleAnimationIterationCountVecDestructorVariant_DefaultRust AzStyleAnimationIterationCountVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationIterationCountVecDestructorVariant_NoDestructor { AzStyleAnimationIterationCountVecDestructorTag tag; };
+typedef struct AzStyleAnimationIterationCountVecDestructorVariant_NoDestructor AzStyleAnimationIterationCountVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationIterationCountVecDestructorVariant_External { AzStyleAnimationIterationCountVecDestructorTag tag; AzStyleAnimationIterationCountVecDestructorType payload; };
+typedef struct AzStyleAnimationIterationCountVecDestructorVariant_External AzStyleAnimationIterationCountVecDestructorVariant_External;
+union AzStyleAnimationIterationCountVecDestructor {
+    AzStyleAnimationIterationCountVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationIterationCountVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationIterationCountVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationIterationCountVecDestructor AzStyleAnimationIterationCountVecDestructor;
+
+enum AzStyleAnimationDirectionVecDestructorTag {
+   AzStyleAnimationDirectionVecDestructorTag_DefaultRust,
+   AzStyleAnimationDirectionVecDestructorTag_NoDestructor,
+   AzStyleAnimationDirectionVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationDirectionVecDestructorTag AzStyleAnimationDirectionVecDestructorTag;
+
+struct AzStyleAnimationDirectionVecDestructorVariant_DefaultRust { AzStyleAnimationDirectionVecDestructorTag tag; };
+typedef struct AzStyleAnimationDirectionVecDestructorVariant_DefaultRust AzStyleAnimationDirectionVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationDirectionVecDestructorVariant_NoDestructor { AzStyleAnimationDirectionVecDestructorTag tag; };
+typedef struct AzStyleAnimationDirectionVecDestructorVariant_NoDestructor AzStyleAnimationDirectionVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationDirectionVecDestructorVariant_External { AzStyleAnimationDirectionVecDestructorTag tag; AzStyleAnimationDirectionVecDestructorType payload; };
+typedef struct AzStyleAnimationDirectionVecDestructorVariant_External AzStyleAnimationDirectionVecDestructorVariant_External;
+union AzStyleAnimationDirectionVecDestructor {
+    AzStyleAnimationDirectionVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationDirectionVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationDirectionVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationDirectionVecDestructor AzStyleAnimationDirectionVecDestructor;
+
+enum AzStyleAnimationFillModeVecDestructorTag {
+   AzStyleAnimationFillModeVecDestructorTag_DefaultRust,
+   AzStyleAnimationFillModeVecDestructorTag_NoDestructor,
+   AzStyleAnimationFillModeVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationFillModeVecDestructorTag AzStyleAnimationFillModeVecDestructorTag;
+
+struct AzStyleAnimationFillModeVecDestructorVariant_DefaultRust { AzStyleAnimationFillModeVecDestructorTag tag; };
+typedef struct AzStyleAnimationFillModeVecDestructorVariant_DefaultRust AzStyleAnimationFillModeVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationFillModeVecDestructorVariant_NoDestructor { AzStyleAnimationFillModeVecDestructorTag tag; };
+typedef struct AzStyleAnimationFillModeVecDestructorVariant_NoDestructor AzStyleAnimationFillModeVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationFillModeVecDestructorVariant_External { AzStyleAnimationFillModeVecDestructorTag tag; AzStyleAnimationFillModeVecDestructorType payload; };
+typedef struct AzStyleAnimationFillModeVecDestructorVariant_External AzStyleAnimationFillModeVecDestructorVariant_External;
+union AzStyleAnimationFillModeVecDestructor {
+    AzStyleAnimationFillModeVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationFillModeVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationFillModeVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationFillModeVecDestructor AzStyleAnimationFillModeVecDestructor;
+
+enum AzStyleAnimationPlayStateVecDestructorTag {
+   AzStyleAnimationPlayStateVecDestructorTag_DefaultRust,
+   AzStyleAnimationPlayStateVecDestructorTag_NoDestructor,
+   AzStyleAnimationPlayStateVecDestructorTag_External,
+};
+typedef enum AzStyleAnimationPlayStateVecDestructorTag AzStyleAnimationPlayStateVecDestructorTag;
+
+struct AzStyleAnimationPlayStateVecDestructorVariant_DefaultRust { AzStyleAnimationPlayStateVecDestructorTag tag; };
+typedef struct AzStyleAnimationPlayStateVecDestructorVariant_DefaultRust AzStyleAnimationPlayStateVecDestructorVariant_DefaultRust;
+struct AzStyleAnimationPlayStateVecDestructorVariant_NoDestructor { AzStyleAnimationPlayStateVecDestructorTag tag; };
+typedef struct AzStyleAnimationPlayStateVecDestructorVariant_NoDestructor AzStyleAnimationPlayStateVecDestructorVariant_NoDestructor;
+struct AzStyleAnimationPlayStateVecDestructorVariant_External { AzStyleAnimationPlayStateVecDestructorTag tag; AzStyleAnimationPlayStateVecDestructorType payload; };
+typedef struct AzStyleAnimationPlayStateVecDestructorVariant_External AzStyleAnimationPlayStateVecDestructorVariant_External;
+union AzStyleAnimationPlayStateVecDestructor {
+    AzStyleAnimationPlayStateVecDestructorVariant_DefaultRust DefaultRust;
+    AzStyleAnimationPlayStateVecDestructorVariant_NoDestructor NoDestructor;
+    AzStyleAnimationPlayStateVecDestructorVariant_External External;
+};
+typedef union AzStyleAnimationPlayStateVecDestructor AzStyleAnimationPlayStateVecDestructor;
+
+enum AzGridTrackSizingVecDestructorTag {
+   AzGridTrackSizingVecDestructorTag_DefaultRust,
+   AzGridTrackSizingVecDestructorTag_NoDestructor,
+   AzGridTrackSizingVecDestructorTag_External,
+};
+typedef enum AzGridTrackSizingVecDestructorTag AzGridTrackSizingVecDestructorTag;
+
+struct AzGridTrackSizingVecDestructorVariant_DefaultRust { AzGridTrackSizingVecDestructorTag tag; };
+typedef struct AzGridTrackSizingVecDestructorVariant_DefaultRust AzGridTrackSizingVecDestructorVariant_DefaultRust;
+struct AzGridTrackSizingVecDestructorVariant_NoDestructor { AzGridTrackSizingVecDestructorTag tag; };
+typedef struct AzGridTrackSizingVecDestructorVariant_NoDestructor AzGridTrackSizingVecDestructorVariant_NoDestructor;
+struct AzGridTrackSizingVecDestructorVariant_External { AzGridTrackSizingVecDestructorTag tag; AzGridTrackSizingVecDestructorType payload; };
+typedef struct AzGridTrackSizingVecDestructorVariant_External AzGridTrackSizingVecDestructorVariant_External;
+union AzGridTrackSizingVecDestructor {
+    AzGridTrackSizingVecDestructorVariant_DefaultRust DefaultRust;
+    AzGridTrackSizingVecDestructorVariant_NoDestructor NoDestructor;
+    AzGridTrackSizingVecDestructorVariant_External External;
+};
+typedef union AzGridTrackSizingVecDestructor AzGridTrackSizingVecDestructor;
+
 enum AzCssPropertyVecDestructorTag {
    AzCssPropertyVecDestructorTag_DefaultRust,
    AzCssPropertyVecDestructorTag_NoDestructor,
@@ -3198,6 +4058,106 @@ union AzStylesheetVecDestructor {
 };
 typedef union AzStylesheetVecDestructor AzStylesheetVecDestructor;
 
+enum AzCssKeyframesVecDestructorTag {
+   AzCssKeyframesVecDestructorTag_DefaultRust,
+   AzCssKeyframesVecDestructorTag_NoDestructor,
+   AzCssKeyframesVecDestructorTag_External,
+};
+typedef enum AzCssKeyframesVecDestructorTag AzCssKeyframesVecDestructorTag;
+
+struct AzCssKeyframesVecDestructorVariant_DefaultRust { AzCssKeyframesVecDestructorTag tag; };
+typedef struct AzCssKeyframesVecDestructorVariant_DefaultRust AzCssKeyframesVecDestructorVariant_DefaultRust;
+struct AzCssKeyframesVecDestructorVariant_NoDestructor { AzCssKeyframesVecDestructorTag tag; };
+typedef struct AzCssKeyframesVecDestructorVariant_NoDestructor AzCssKeyframesVecDestructorVariant_NoDestructor;
+struct AzCssKeyframesVecDestructorVariant_External { AzCssKeyframesVecDestructorTag tag; AzCssKeyframesVecDestructorType payload; };
+typedef struct AzCssKeyframesVecDestructorVariant_External AzCssKeyframesVecDestructorVariant_External;
+union AzCssKeyframesVecDestructor {
+    AzCssKeyframesVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssKeyframesVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssKeyframesVecDestructorVariant_External External;
+};
+typedef union AzCssKeyframesVecDestructor AzCssKeyframesVecDestructor;
+
+enum AzCssKeyframeVecDestructorTag {
+   AzCssKeyframeVecDestructorTag_DefaultRust,
+   AzCssKeyframeVecDestructorTag_NoDestructor,
+   AzCssKeyframeVecDestructorTag_External,
+};
+typedef enum AzCssKeyframeVecDestructorTag AzCssKeyframeVecDestructorTag;
+
+struct AzCssKeyframeVecDestructorVariant_DefaultRust { AzCssKeyframeVecDestructorTag tag; };
+typedef struct AzCssKeyframeVecDestructorVariant_DefaultRust AzCssKeyframeVecDestructorVariant_DefaultRust;
+struct AzCssKeyframeVecDestructorVariant_NoDestructor { AzCssKeyframeVecDestructorTag tag; };
+typedef struct AzCssKeyframeVecDestructorVariant_NoDestructor AzCssKeyframeVecDestructorVariant_NoDestructor;
+struct AzCssKeyframeVecDestructorVariant_External { AzCssKeyframeVecDestructorTag tag; AzCssKeyframeVecDestructorType payload; };
+typedef struct AzCssKeyframeVecDestructorVariant_External AzCssKeyframeVecDestructorVariant_External;
+union AzCssKeyframeVecDestructor {
+    AzCssKeyframeVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssKeyframeVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssKeyframeVecDestructorVariant_External External;
+};
+typedef union AzCssKeyframeVecDestructor AzCssKeyframeVecDestructor;
+
+enum AzCssMediaRuleVecDestructorTag {
+   AzCssMediaRuleVecDestructorTag_DefaultRust,
+   AzCssMediaRuleVecDestructorTag_NoDestructor,
+   AzCssMediaRuleVecDestructorTag_External,
+};
+typedef enum AzCssMediaRuleVecDestructorTag AzCssMediaRuleVecDestructorTag;
+
+struct AzCssMediaRuleVecDestructorVariant_DefaultRust { AzCssMediaRuleVecDestructorTag tag; };
+typedef struct AzCssMediaRuleVecDestructorVariant_DefaultRust AzCssMediaRuleVecDestructorVariant_DefaultRust;
+struct AzCssMediaRuleVecDestructorVariant_NoDestructor { AzCssMediaRuleVecDestructorTag tag; };
+typedef struct AzCssMediaRuleVecDestructorVariant_NoDestructor AzCssMediaRuleVecDestructorVariant_NoDestructor;
+struct AzCssMediaRuleVecDestructorVariant_External { AzCssMediaRuleVecDestructorTag tag; AzCssMediaRuleVecDestructorType payload; };
+typedef struct AzCssMediaRuleVecDestructorVariant_External AzCssMediaRuleVecDestructorVariant_External;
+union AzCssMediaRuleVecDestructor {
+    AzCssMediaRuleVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssMediaRuleVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssMediaRuleVecDestructorVariant_External External;
+};
+typedef union AzCssMediaRuleVecDestructor AzCssMediaRuleVecDestructor;
+
+enum AzCssMediaQueryVecDestructorTag {
+   AzCssMediaQueryVecDestructorTag_DefaultRust,
+   AzCssMediaQueryVecDestructorTag_NoDestructor,
+   AzCssMediaQueryVecDestructorTag_External,
+};
+typedef enum AzCssMediaQueryVecDestructorTag AzCssMediaQueryVecDestructorTag;
+
+struct AzCssMediaQueryVecDestructorVariant_DefaultRust { AzCssMediaQueryVecDestructorTag tag; };
+typedef struct AzCssMediaQueryVecDestructorVariant_DefaultRust AzCssMediaQueryVecDestructorVariant_DefaultRust;
+struct AzCssMediaQueryVecDestructorVariant_NoDestructor { AzCssMediaQueryVecDestructorTag tag; };
+typedef struct AzCssMediaQueryVecDestructorVariant_NoDestructor AzCssMediaQueryVecDestructorVariant_NoDestructor;
+struct AzCssMediaQueryVecDestructorVariant_External { AzCssMediaQueryVecDestructorTag tag; AzCssMediaQueryVecDestructorType payload; };
+typedef struct AzCssMediaQueryVecDestructorVariant_External AzCssMediaQueryVecDestructorVariant_External;
+union AzCssMediaQueryVecDestructor {
+    AzCssMediaQueryVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssMediaQueryVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssMediaQueryVecDestructorVariant_External External;
+};
+typedef union AzCssMediaQueryVecDestructor AzCssMediaQueryVecDestructor;
+
+enum AzCssMediaFeatureVecDestructorTag {
+   AzCssMediaFeatureVecDestructorTag_DefaultRust,
+   AzCssMediaFeatureVecDestructorTag_NoDestructor,
+   AzCssMediaFeatureVecDestructorTag_External,
+};
+typedef enum AzCssMediaFeatureVecDestructorTag AzCssMediaFeatureVecDestructorTag;
+
+struct AzCssMediaFeatureVecDestructorVariant_DefaultRust { AzCssMediaFeatureVecDestructorTag tag; };
+typedef struct AzCssMediaFeatureVecDestructorVariant_DefaultRust AzCssMediaFeatureVecDestructorVariant_DefaultRust;
+struct AzCssMediaFeatureVecDestructorVariant_NoDestructor { AzCssMediaFeatureVecDestructorTag tag; };
+typedef struct AzCssMediaFeatureVecDestructorVariant_NoDestructor AzCssMediaFeatureVecDestructorVariant_NoDestructor;
+struct AzCssMediaFeatureVecDestructorVariant_External { AzCssMediaFeatureVecDestructorTag tag; AzCssMediaFeatureVecDestructorType payload; };
+typedef struct AzCssMediaFeatureVecDestructorVariant_External AzCssMediaFeatureVecDestructorVariant_External;
+union AzCssMediaFeatureVecDestructor {
+    AzCssMediaFeatureVecDestructorVariant_DefaultRust DefaultRust;
+    AzCssMediaFeatureVecDestructorVariant_NoDestructor NoDestructor;
+    AzCssMediaFeatureVecDestructorVariant_External External;
+};
+typedef union AzCssMediaFeatureVecDestructor AzCssMediaFeatureVecDestructor;
+
 enum AzCssRuleBlockVecDestructorTag {
    AzCssRuleBlockVecDestructorTag_DefaultRust,
    AzCssRuleBlockVecDestructorTag_NoDestructor,
@@ -3711,6 +4671,8 @@ typedef struct AzSvgParseErrorPosition AzSvgParseErrorPosition;
 struct AzSystemCallbacks {
     AzCreateThreadFn create_thread_fn;
     AzGetSystemTimeFn get_system_time_fn;
+    AzGetClipboardFn get_clipboard_fn;
+    AzSetClipboardFn set_clipboard_fn;
 };
 typedef struct AzSystemCallbacks AzSystemCallbacks;
 
@@ -3915,6 +4877,7 @@ struct AzInlineGlyph {
     AzLogicalRect bounds;
     AzOptionChar unicode_codepoint;
     uint32_t glyph_index;
+    size_t font_index;
 };
 typedef struct AzInlineGlyph AzInlineGlyph;
 
@@ -3935,6 +4898,8 @@ struct AzInlineTextHit {
     size_t char_index_relative_to_line;
     size_t glyph_index_relative_to_word;
     size_t char_index_relative_to_word;
+    bool  is_rtl;
+    size_t cursor_index_relative_to_text;
 };
 typedef struct AzInlineTextHit AzInlineTextHit;
 
@@ -3988,6 +4953,12 @@ union AzNotEventFilter {
 };
 typedef union AzNotEventFilter AzNotEventFilter;
 
+struct AzTooltipDom {
+    AzRefAny data;
+    AzTooltipCallback callback;
+};
+typedef struct AzTooltipDom AzTooltipDom;
+
 struct AzMenuCallback {
     AzCallback callback;
     AzRefAny data;
@@ -4034,6 +5005,14 @@ union AzCssNthChildSelector {
 };
 typedef union AzCssNthChildSelector AzCssNthChildSelector;
 
+struct AzCssMediaRange {
+    AzOptionF32 min;
+    bool  min_inclusive;
+    AzOptionF32 max;
+    bool  max_inclusive;
+};
+typedef struct AzCssMediaRange AzCssMediaRange;
+
 struct AzPixelValue {
     AzSizeMetric metric;
     AzFloatValue number;
@@ -4107,6 +5086,56 @@ union AzStyleCompositeFilter {
 };
 typedef union AzStyleCompositeFilter AzStyleCompositeFilter;
 
+enum AzGridTrackBreadthTag {
+   AzGridTrackBreadthTag_Fixed,
+   AzGridTrackBreadthTag_Fraction,
+   AzGridTrackBreadthTag_Auto,
+   AzGridTrackBreadthTag_MinContent,
+   AzGridTrackBreadthTag_MaxContent,
+};
+typedef enum AzGridTrackBreadthTag AzGridTrackBreadthTag;
+
+struct AzGridTrackBreadthVariant_Fixed { AzGridTrackBreadthTag tag; AzPixelValue payload; };
+typedef struct AzGridTrackBreadthVariant_Fixed AzGridTrackBreadthVariant_Fixed;
+struct AzGridTrackBreadthVariant_Fraction { AzGridTrackBreadthTag tag; AzFloatValue payload; };
+typedef struct AzGridTrackBreadthVariant_Fraction AzGridTrackBreadthVariant_Fraction;
+struct AzGridTrackBreadthVariant_Auto { AzGridTrackBreadthTag tag; };
+typedef struct AzGridTrackBreadthVariant_Auto AzGridTrackBreadthVariant_Auto;
+struct AzGridTrackBreadthVariant_MinContent { AzGridTrackBreadthTag tag; };
+typedef struct AzGridTrackBreadthVariant_MinContent AzGridTrackBreadthVariant_MinContent;
+struct AzGridTrackBreadthVariant_MaxContent { AzGridTrackBreadthTag tag; };
+typedef struct AzGridTrackBreadthVariant_MaxContent AzGridTrackBreadthVariant_MaxContent;
+union AzGridTrackBreadth {
+    AzGridTrackBreadthVariant_Fixed Fixed;
+    AzGridTrackBreadthVariant_Fraction Fraction;
+    AzGridTrackBreadthVariant_Auto Auto;
+    AzGridTrackBreadthVariant_MinContent MinContent;
+    AzGridTrackBreadthVariant_MaxContent MaxContent;
+};
+typedef union AzGridTrackBreadth AzGridTrackBreadth;
+
+struct AzGridTrackSizing {
+    AzGridTrackBreadth min;
+    AzGridTrackBreadth max;
+};
+typedef struct AzGridTrackSizing AzGridTrackSizing;
+
+struct AzGridPlacement {
+    AzGridLine start;
+    AzGridLine end;
+};
+typedef struct AzGridPlacement AzGridPlacement;
+
+struct AzLayoutRowGap {
+    AzPixelValue inner;
+};
+typedef struct AzLayoutRowGap AzLayoutRowGap;
+
+struct AzLayoutColumnGap {
+    AzPixelValue inner;
+};
+typedef struct AzLayoutColumnGap AzLayoutColumnGap;
+
 struct AzLayoutBottom {
     AzPixelValue inner;
 };
@@ -4513,6 +5542,92 @@ struct AzStyleTransformSkew2D {
 };
 typedef struct AzStyleTransformSkew2D AzStyleTransformSkew2D;
 
+struct AzStyleCubicBezier {
+    AzFloatValue x1;
+    AzFloatValue y1;
+    AzFloatValue x2;
+    AzFloatValue y2;
+};
+typedef struct AzStyleCubicBezier AzStyleCubicBezier;
+
+struct AzStyleSteps {
+    uint32_t count;
+    AzStyleStepPosition position;
+};
+typedef struct AzStyleSteps AzStyleSteps;
+
+enum AzStyleTimingFunctionTag {
+   AzStyleTimingFunctionTag_Ease,
+   AzStyleTimingFunctionTag_Linear,
+   AzStyleTimingFunctionTag_EaseIn,
+   AzStyleTimingFunctionTag_EaseOut,
+   AzStyleTimingFunctionTag_EaseInOut,
+   AzStyleTimingFunctionTag_CubicBezier,
+   AzStyleTimingFunctionTag_Steps,
+};
+typedef enum AzStyleTimingFunctionTag AzStyleTimingFunctionTag;
+
+struct AzStyleTimingFunctionVariant_Ease { AzStyleTimingFunctionTag tag; };
+typedef struct AzStyleTimingFunctionVariant_Ease AzStyleTimingFunctionVariant_Ease;
+struct AzStyleTimingFunctionVariant_Linear { AzStyleTimingFunctionTag tag; };
+typedef struct AzStyleTimingFunctionVariant_Linear AzStyleTimingFunctionVariant_Linear;
+struct AzStyleTimingFunctionVariant_EaseIn { AzStyleTimingFunctionTag tag; };
+typedef struct AzStyleTimingFunctionVariant_EaseIn AzStyleTimingFunctionVariant_EaseIn;
+struct AzStyleTimingFunctionVariant_EaseOut { AzStyleTimingFunctionTag tag; };
+typedef struct AzStyleTimingFunctionVariant_EaseOut AzStyleTimingFunctionVariant_EaseOut;
+struct AzStyleTimingFunctionVariant_EaseInOut { AzStyleTimingFunctionTag tag; };
+typedef struct AzStyleTimingFunctionVariant_EaseInOut AzStyleTimingFunctionVariant_EaseInOut;
+struct AzStyleTimingFunctionVariant_CubicBezier { AzStyleTimingFunctionTag tag; AzStyleCubicBezier payload; };
+typedef struct AzStyleTimingFunctionVariant_CubicBezier AzStyleTimingFunctionVariant_CubicBezier;
+struct AzStyleTimingFunctionVariant_Steps { AzStyleTimingFunctionTag tag; AzStyleSteps payload; };
+typedef struct AzStyleTimingFunctionVariant_Steps AzStyleTimingFunctionVariant_Steps;
+union AzStyleTimingFunction {
+    AzStyleTimingFunctionVariant_Ease Ease;
+    AzStyleTimingFunctionVariant_Linear Linear;
+    AzStyleTimingFunctionVariant_EaseIn EaseIn;
+    AzStyleTimingFunctionVariant_EaseOut EaseOut;
+    AzStyleTimingFunctionVariant_EaseInOut EaseInOut;
+    AzStyleTimingFunctionVariant_CubicBezier CubicBezier;
+    AzStyleTimingFunctionVariant_Steps Steps;
+};
+typedef union AzStyleTimingFunction AzStyleTimingFunction;
+
+enum AzStyleTransitionPropertyTag {
+   AzStyleTransitionPropertyTag_All,
+   AzStyleTransitionPropertyTag_Property,
+   AzStyleTransitionPropertyTag_Shorthand,
+};
+typedef enum AzStyleTransitionPropertyTag AzStyleTransitionPropertyTag;
+
+struct AzStyleTransitionPropertyVariant_All { AzStyleTransitionPropertyTag tag; };
+typedef struct AzStyleTransitionPropertyVariant_All AzStyleTransitionPropertyVariant_All;
+struct AzStyleTransitionPropertyVariant_Property { AzStyleTransitionPropertyTag tag; AzCssPropertyType payload; };
+typedef struct AzStyleTransitionPropertyVariant_Property AzStyleTransitionPropertyVariant_Property;
+struct AzStyleTransitionPropertyVariant_Shorthand { AzStyleTransitionPropertyTag tag; AzCombinedCssPropertyType payload; };
+typedef struct AzStyleTransitionPropertyVariant_Shorthand AzStyleTransitionPropertyVariant_Shorthand;
+union AzStyleTransitionProperty {
+    AzStyleTransitionPropertyVariant_All All;
+    AzStyleTransitionPropertyVariant_Property Property;
+    AzStyleTransitionPropertyVariant_Shorthand Shorthand;
+};
+typedef union AzStyleTransitionProperty AzStyleTransitionProperty;
+
+enum AzStyleAnimationIterationCountTag {
+   AzStyleAnimationIterationCountTag_Infinite,
+   AzStyleAnimationIterationCountTag_Count,
+};
+typedef enum AzStyleAnimationIterationCountTag AzStyleAnimationIterationCountTag;
+
+struct AzStyleAnimationIterationCountVariant_Infinite { AzStyleAnimationIterationCountTag tag; };
+typedef struct AzStyleAnimationIterationCountVariant_Infinite AzStyleAnimationIterationCountVariant_Infinite;
+struct AzStyleAnimationIterationCountVariant_Count { AzStyleAnimationIterationCountTag tag; AzFloatValue payload; };
+typedef struct AzStyleAnimationIterationCountVariant_Count AzStyleAnimationIterationCountVariant_Count;
+union AzStyleAnimationIterationCount {
+    AzStyleAnimationIterationCountVariant_Infinite Infinite;
+    AzStyleAnimationIterationCountVariant_Count Count;
+};
+typedef union AzStyleAnimationIterationCount AzStyleAnimationIterationCount;
+
 struct AzStyleTextColor {
     AzColorU inner;
 };
@@ -4579,6 +5694,146 @@ union AzLayoutAlignContentValue {
 };
 typedef union AzLayoutAlignContentValue AzLayoutAlignContentValue;
 
+enum AzGridTrackSizingValueTag {
+   AzGridTrackSizingValueTag_Auto,
+   AzGridTrackSizingValueTag_None,
+   AzGridTrackSizingValueTag_Inherit,
+   AzGridTrackSizingValueTag_Initial,
+   AzGridTrackSizingValueTag_Exact,
+};
+typedef enum AzGridTrackSizingValueTag AzGridTrackSizingValueTag;
+
+struct AzGridTrackSizingValueVariant_Auto { AzGridTrackSizingValueTag tag; };
+typedef struct AzGridTrackSizingValueVariant_Auto AzGridTrackSizingValueVariant_Auto;
+struct AzGridTrackSizingValueVariant_None { AzGridTrackSizingValueTag tag; };
+typedef struct AzGridTrackSizingValueVariant_None AzGridTrackSizingValueVariant_None;
+struct AzGridTrackSizingValueVariant_Inherit { AzGridTrackSizingValueTag tag; };
+typedef struct AzGridTrackSizingValueVariant_Inherit AzGridTrackSizingValueVariant_Inherit;
+struct AzGridTrackSizingValueVariant_Initial { AzGridTrackSizingValueTag tag; };
+typedef struct AzGridTrackSizingValueVariant_Initial AzGridTrackSizingValueVariant_Initial;
+struct AzGridTrackSizingValueVariant_Exact { AzGridTrackSizingValueTag tag; AzGridTrackSizing payload; };
+typedef struct AzGridTrackSizingValueVariant_Exact AzGridTrackSizingValueVariant_Exact;
+union AzGridTrackSizingValue {
+    AzGridTrackSizingValueVariant_Auto Auto;
+    AzGridTrackSizingValueVariant_None None;
+    AzGridTrackSizingValueVariant_Inherit Inherit;
+    AzGridTrackSizingValueVariant_Initial Initial;
+    AzGridTrackSizingValueVariant_Exact Exact;
+};
+typedef union AzGridTrackSizingValue AzGridTrackSizingValue;
+
+enum AzLayoutGridAutoFlowValueTag {
+   AzLayoutGridAutoFlowValueTag_Auto,
+   AzLayoutGridAutoFlowValueTag_None,
+   AzLayoutGridAutoFlowValueTag_Inherit,
+   AzLayoutGridAutoFlowValueTag_Initial,
+   AzLayoutGridAutoFlowValueTag_Exact,
+};
+typedef enum AzLayoutGridAutoFlowValueTag AzLayoutGridAutoFlowValueTag;
+
+struct AzLayoutGridAutoFlowValueVariant_Auto { AzLayoutGridAutoFlowValueTag tag; };
+typedef struct AzLayoutGridAutoFlowValueVariant_Auto AzLayoutGridAutoFlowValueVariant_Auto;
+struct AzLayoutGridAutoFlowValueVariant_None { AzLayoutGridAutoFlowValueTag tag; };
+typedef struct AzLayoutGridAutoFlowValueVariant_None AzLayoutGridAutoFlowValueVariant_None;
+struct AzLayoutGridAutoFlowValueVariant_Inherit { AzLayoutGridAutoFlowValueTag tag; };
+typedef struct AzLayoutGridAutoFlowValueVariant_Inherit AzLayoutGridAutoFlowValueVariant_Inherit;
+struct AzLayoutGridAutoFlowValueVariant_Initial { AzLayoutGridAutoFlowValueTag tag; };
+typedef struct AzLayoutGridAutoFlowValueVariant_Initial AzLayoutGridAutoFlowValueVariant_Initial;
+struct AzLayoutGridAutoFlowValueVariant_Exact { AzLayoutGridAutoFlowValueTag tag; AzLayoutGridAutoFlow payload; };
+typedef struct AzLayoutGridAutoFlowValueVariant_Exact AzLayoutGridAutoFlowValueVariant_Exact;
+union AzLayoutGridAutoFlowValue {
+    AzLayoutGridAutoFlowValueVariant_Auto Auto;
+    AzLayoutGridAutoFlowValueVariant_None None;
+    AzLayoutGridAutoFlowValueVariant_Inherit Inherit;
+    AzLayoutGridAutoFlowValueVariant_Initial Initial;
+    AzLayoutGridAutoFlowValueVariant_Exact Exact;
+};
+typedef union AzLayoutGridAutoFlowValue AzLayoutGridAutoFlowValue;
+
+enum AzGridPlacementValueTag {
+   AzGridPlacementValueTag_Auto,
+   AzGridPlacementValueTag_None,
+   AzGridPlacementValueTag_Inherit,
+   AzGridPlacementValueTag_Initial,
+   AzGridPlacementValueTag_Exact,
+};
+typedef enum AzGridPlacementValueTag AzGridPlacementValueTag;
+
+struct AzGridPlacementValueVariant_Auto { AzGridPlacementValueTag tag; };
+typedef struct AzGridPlacementValueVariant_Auto AzGridPlacementValueVariant_Auto;
+struct AzGridPlacementValueVariant_None { AzGridPlacementValueTag tag; };
+typedef struct AzGridPlacementValueVariant_None AzGridPlacementValueVariant_None;
+struct AzGridPlacementValueVariant_Inherit { AzGridPlacementValueTag tag; };
+typedef struct AzGridPlacementValueVariant_Inherit AzGridPlacementValueVariant_Inherit;
+struct AzGridPlacementValueVariant_Initial { AzGridPlacementValueTag tag; };
+typedef struct AzGridPlacementValueVariant_Initial AzGridPlacementValueVariant_Initial;
+struct AzGridPlacementValueVariant_Exact { AzGridPlacementValueTag tag; AzGridPlacement payload; };
+typedef struct AzGridPlacementValueVariant_Exact AzGridPlacementValueVariant_Exact;
+union AzGridPlacementValue {
+    AzGridPlacementValueVariant_Auto Auto;
+    AzGridPlacementValueVariant_None None;
+    AzGridPlacementValueVariant_Inherit Inherit;
+    AzGridPlacementValueVariant_Initial Initial;
+    AzGridPlacementValueVariant_Exact Exact;
+};
+typedef union AzGridPlacementValue AzGridPlacementValue;
+
+enum AzLayoutRowGapValueTag {
+   AzLayoutRowGapValueTag_Auto,
+   AzLayoutRowGapValueTag_None,
+   AzLayoutRowGapValueTag_Inherit,
+   AzLayoutRowGapValueTag_Initial,
+   AzLayoutRowGapValueTag_Exact,
+};
+typedef enum AzLayoutRowGapValueTag AzLayoutRowGapValueTag;
+
+struct AzLayoutRowGapValueVariant_Auto { AzLayoutRowGapValueTag tag; };
+typedef struct AzLayoutRowGapValueVariant_Auto AzLayoutRowGapValueVariant_Auto;
+struct AzLayoutRowGapValueVariant_None { AzLayoutRowGapValueTag tag; };
+typedef struct AzLayoutRowGapValueVariant_None AzLayoutRowGapValueVariant_None;
+struct AzLayoutRowGapValueVariant_Inherit { AzLayoutRowGapValueTag tag; };
+typedef struct AzLayoutRowGapValueVariant_Inherit AzLayoutRowGapValueVariant_Inherit;
+struct AzLayoutRowGapValueVariant_Initial { AzLayoutRowGapValueTag tag; };
+typedef struct AzLayoutRowGapValueVariant_Initial AzLayoutRowGapValueVariant_Initial;
+struct AzLayoutRowGapValueVariant_Exact { AzLayoutRowGapValueTag tag; AzLayoutRowGap payload; };
+typedef struct AzLayoutRowGapValueVariant_Exact AzLayoutRowGapValueVariant_Exact;
+union AzLayoutRowGapValue {
+    AzLayoutRowGapValueVariant_Auto Auto;
+    AzLayoutRowGapValueVariant_None None;
+    AzLayoutRowGapValueVariant_Inherit Inherit;
+    AzLayoutRowGapValueVariant_Initial Initial;
+    AzLayoutRowGapValueVariant_Exact Exact;
+};
+typedef union AzLayoutRowGapValue AzLayoutRowGapValue;
+
+enum AzLayoutColumnGapValueTag {
+   AzLayoutColumnGapValueTag_Auto,
+   AzLayoutColumnGapValueTag_None,
+   AzLayoutColumnGapValueTag_Inherit,
+   AzLayoutColumnGapValueTag_Initial,
+   AzLayoutColumnGapValueTag_Exact,
+};
+typedef enum AzLayoutColumnGapValueTag AzLayoutColumnGapValueTag;
+
+struct AzLayoutColumnGapValueVariant_Auto { AzLayoutColumnGapValueTag tag; };
+typedef struct AzLayoutColumnGapValueVariant_Auto AzLayoutColumnGapValueVariant_Auto;
+struct AzLayoutColumnGapValueVariant_None { AzLayoutColumnGapValueTag tag; };
+typedef struct AzLayoutColumnGapValueVariant_None AzLayoutColumnGapValueVariant_None;
+struct AzLayoutColumnGapValueVariant_Inherit { AzLayoutColumnGapValueTag tag; };
+typedef struct AzLayoutColumnGapValueVariant_Inherit AzLayoutColumnGapValueVariant_Inherit;
+struct AzLayoutColumnGapValueVariant_Initial { AzLayoutColumnGapValueTag tag; };
+typedef struct AzLayoutColumnGapValueVariant_Initial AzLayoutColumnGapValueVariant_Initial;
+struct AzLayoutColumnGapValueVariant_Exact { AzLayoutColumnGapValueTag tag; AzLayoutColumnGap payload; };
+typedef struct AzLayoutColumnGapValueVariant_Exact AzLayoutColumnGapValueVariant_Exact;
+union AzLayoutColumnGapValue {
+    AzLayoutColumnGapValueVariant_Auto Auto;
+    AzLayoutColumnGapValueVariant_None None;
+    AzLayoutColumnGapValueVariant_Inherit Inherit;
+    AzLayoutColumnGapValueVariant_Initial Initial;
+    AzLayoutColumnGapValueVariant_Exact Exact;
+};
+typedef union AzLayoutColumnGapValue AzLayoutColumnGapValue;
+
 enum AzLayoutAlignItemsValueTag {
    AzLayoutAlignItemsValueTag_Auto,
    AzLayoutAlignItemsValueTag_None,
@@ -6007,6 +7262,118 @@ union AzStyleTextAlignValue {
 };
 typedef union AzStyleTextAlignValue AzStyleTextAlignValue;
 
+enum AzStyleDirectionValueTag {
+   AzStyleDirectionValueTag_Auto,
+   AzStyleDirectionValueTag_None,
+   AzStyleDirectionValueTag_Inherit,
+   AzStyleDirectionValueTag_Initial,
+   AzStyleDirectionValueTag_Exact,
+};
+typedef enum AzStyleDirectionValueTag AzStyleDirectionValueTag;
+
+struct AzStyleDirectionValueVariant_Auto { AzStyleDirectionValueTag tag; };
+typedef struct AzStyleDirectionValueVariant_Auto AzStyleDirectionValueVariant_Auto;
+struct AzStyleDirectionValueVariant_None { AzStyleDirectionValueTag tag; };
+typedef struct AzStyleDirectionValueVariant_None AzStyleDirectionValueVariant_None;
+struct AzStyleDirectionValueVariant_Inherit { AzStyleDirectionValueTag tag; };
+typedef struct AzStyleDirectionValueVariant_Inherit AzStyleDirectionValueVariant_Inherit;
+struct AzStyleDirectionValueVariant_Initial { AzStyleDirectionValueTag tag; };
+typedef struct AzStyleDirectionValueVariant_Initial AzStyleDirectionValueVariant_Initial;
+struct AzStyleDirectionValueVariant_Exact { AzStyleDirectionValueTag tag; AzStyleDirection payload; };
+typedef struct AzStyleDirectionValueVariant_Exact AzStyleDirectionValueVariant_Exact;
+union AzStyleDirectionValue {
+    AzStyleDirectionValueVariant_Auto Auto;
+    AzStyleDirectionValueVariant_None None;
+    AzStyleDirectionValueVariant_Inherit Inherit;
+    AzStyleDirectionValueVariant_Initial Initial;
+    AzStyleDirectionValueVariant_Exact Exact;
+};
+typedef union AzStyleDirectionValue AzStyleDirectionValue;
+
+enum AzStyleWordBreakValueTag {
+   AzStyleWordBreakValueTag_Auto,
+   AzStyleWordBreakValueTag_None,
+   AzStyleWordBreakValueTag_Inherit,
+   AzStyleWordBreakValueTag_Initial,
+   AzStyleWordBreakValueTag_Exact,
+};
+typedef enum AzStyleWordBreakValueTag AzStyleWordBreakValueTag;
+
+struct AzStyleWordBreakValueVariant_Auto { AzStyleWordBreakValueTag tag; };
+typedef struct AzStyleWordBreakValueVariant_Auto AzStyleWordBreakValueVariant_Auto;
+struct AzStyleWordBreakValueVariant_None { AzStyleWordBreakValueTag tag; };
+typedef struct AzStyleWordBreakValueVariant_None AzStyleWordBreakValueVariant_None;
+struct AzStyleWordBreakValueVariant_Inherit { AzStyleWordBreakValueTag tag; };
+typedef struct AzStyleWordBreakValueVariant_Inherit AzStyleWordBreakValueVariant_Inherit;
+struct AzStyleWordBreakValueVariant_Initial { AzStyleWordBreakValueTag tag; };
+typedef struct AzStyleWordBreakValueVariant_Initial AzStyleWordBreakValueVariant_Initial;
+struct AzStyleWordBreakValueVariant_Exact { AzStyleWordBreakValueTag tag; AzStyleWordBreak payload; };
+typedef struct AzStyleWordBreakValueVariant_Exact AzStyleWordBreakValueVariant_Exact;
+union AzStyleWordBreakValue {
+    AzStyleWordBreakValueVariant_Auto Auto;
+    AzStyleWordBreakValueVariant_None None;
+    AzStyleWordBreakValueVariant_Inherit Inherit;
+    AzStyleWordBreakValueVariant_Initial Initial;
+    AzStyleWordBreakValueVariant_Exact Exact;
+};
+typedef union AzStyleWordBreakValue AzStyleWordBreakValue;
+
+enum AzStyleOverflowWrapValueTag {
+   AzStyleOverflowWrapValueTag_Auto,
+   AzStyleOverflowWrapValueTag_None,
+   AzStyleOverflowWrapValueTag_Inherit,
+   AzStyleOverflowWrapValueTag_Initial,
+   AzStyleOverflowWrapValueTag_Exact,
+};
+typedef enum AzStyleOverflowWrapValueTag AzStyleOverflowWrapValueTag;
+
+struct AzStyleOverflowWrapValueVariant_Auto { AzStyleOverflowWrapValueTag tag; };
+typedef struct AzStyleOverflowWrapValueVariant_Auto AzStyleOverflowWrapValueVariant_Auto;
+struct AzStyleOverflowWrapValueVariant_None { AzStyleOverflowWrapValueTag tag; };
+typedef struct AzStyleOverflowWrapValueVariant_None AzStyleOverflowWrapValueVariant_None;
+struct AzStyleOverflowWrapValueVariant_Inherit { AzStyleOverflowWrapValueTag tag; };
+typedef struct AzStyleOverflowWrapValueVariant_Inherit AzStyleOverflowWrapValueVariant_Inherit;
+struct AzStyleOverflowWrapValueVariant_Initial { AzStyleOverflowWrapValueTag tag; };
+typedef struct AzStyleOverflowWrapValueVariant_Initial AzStyleOverflowWrapValueVariant_Initial;
+struct AzStyleOverflowWrapValueVariant_Exact { AzStyleOverflowWrapValueTag tag; AzStyleOverflowWrap payload; };
+typedef struct AzStyleOverflowWrapValueVariant_Exact AzStyleOverflowWrapValueVariant_Exact;
+union AzStyleOverflowWrapValue {
+    AzStyleOverflowWrapValueVariant_Auto Auto;
+    AzStyleOverflowWrapValueVariant_None None;
+    AzStyleOverflowWrapValueVariant_Inherit Inherit;
+    AzStyleOverflowWrapValueVariant_Initial Initial;
+    AzStyleOverflowWrapValueVariant_Exact Exact;
+};
+typedef union AzStyleOverflowWrapValue AzStyleOverflowWrapValue;
+
+enum AzStyleHyphensValueTag {
+   AzStyleHyphensValueTag_Auto,
+   AzStyleHyphensValueTag_None,
+   AzStyleHyphensValueTag_Inherit,
+   AzStyleHyphensValueTag_Initial,
+   AzStyleHyphensValueTag_Exact,
+};
+typedef enum AzStyleHyphensValueTag AzStyleHyphensValueTag;
+
+struct AzStyleHyphensValueVariant_Auto { AzStyleHyphensValueTag tag; };
+typedef struct AzStyleHyphensValueVariant_Auto AzStyleHyphensValueVariant_Auto;
+struct AzStyleHyphensValueVariant_None { AzStyleHyphensValueTag tag; };
+typedef struct AzStyleHyphensValueVariant_None AzStyleHyphensValueVariant_None;
+struct AzStyleHyphensValueVariant_Inherit { AzStyleHyphensValueTag tag; };
+typedef struct AzStyleHyphensValueVariant_Inherit AzStyleHyphensValueVariant_Inherit;
+struct AzStyleHyphensValueVariant_Initial { AzStyleHyphensValueTag tag; };
+typedef struct AzStyleHyphensValueVariant_Initial AzStyleHyphensValueVariant_Initial;
+struct AzStyleHyphensValueVariant_Exact { AzStyleHyphensValueTag tag; AzStyleHyphens payload; };
+typedef struct AzStyleHyphensValueVariant_Exact AzStyleHyphensValueVariant_Exact;
+union AzStyleHyphensValue {
+    AzStyleHyphensValueVariant_Auto Auto;
+    AzStyleHyphensValueVariant_None None;
+    AzStyleHyphensValueVariant_Inherit Inherit;
+    AzStyleHyphensValueVariant_Initial Initial;
+    AzStyleHyphensValueVariant_Exact Exact;
+};
+typedef union AzStyleHyphensValue AzStyleHyphensValue;
+
 enum AzStyleTextColorValueTag {
    AzStyleTextColorValueTag_Auto,
    AzStyleTextColorValueTag_None,
@@ -6203,6 +7570,24 @@ union AzStyleMixBlendModeValue {
 };
 typedef union AzStyleMixBlendModeValue AzStyleMixBlendModeValue;
 
+struct AzRibbonItemState {
+    bool  toggled;
+    AzOptionUsize choice;
+};
+typedef struct AzRibbonItemState AzRibbonItemState;
+
+struct AzRibbonOnItemClick {
+    AzRefAny data;
+    AzRibbonOnItemClickCallback callback;
+};
+typedef struct AzRibbonOnItemClick AzRibbonOnItemClick;
+
+struct AzRibbonOnTabClicked {
+    AzRefAny data;
+    AzRibbonOnTabClickedCallback callback;
+};
+typedef struct AzRibbonOnTabClicked AzRibbonOnTabClicked;
+
 struct AzButtonOnClick {
     AzRefAny data;
     AzCallback callback;
@@ -6272,6 +7657,12 @@ struct AzOnTextInputReturn {
 };
 typedef struct AzOnTextInputReturn AzOnTextInputReturn;
 
+struct AzTextAreaOnTextChange {
+    AzRefAny data;
+    AzTextAreaOnTextChangeCallback callback;
+};
+typedef struct AzTextAreaOnTextChange AzTextAreaOnTextChange;
+
 struct AzNumberInputOnValueChange {
     AzRefAny data;
     AzNumberInputOnValueChangeCallback callback;
@@ -6284,12 +7675,63 @@ struct AzNumberInputOnFocusLost {
 };
 typedef struct AzNumberInputOnFocusLost AzNumberInputOnFocusLost;
 
+struct AzProgressBarState {
+    float percent_done;
+    bool  display_percentage;
+    AzProgressBarMode mode;
+};
+typedef struct AzProgressBarState AzProgressBarState;
+
+struct AzSpinner {
+    AzPixelValue size;
+    AzPixelValue thickness;
+    AzColorU color;
+    AzColorU track_color;
+};
+typedef struct AzSpinner AzSpinner;
+
 struct AzTabOnClick {
     AzRefAny data;
     AzTabOnClickCallback callback;
 };
 typedef struct AzTabOnClick AzTabOnClick;
 
+struct AzTabOnClose {
+    AzRefAny data;
+    AzTabOnCloseCallback callback;
+};
+typedef struct AzTabOnClose AzTabOnClose;
+
+struct AzTabOnReorder {
+    AzRefAny data;
+    AzTabOnReorderCallback callback;
+};
+typedef struct AzTabOnReorder AzTabOnReorder;
+
+struct AzTabOnContextMenu {
+    AzRefAny data;
+    AzTabOnContextMenuCallback callback;
+};
+typedef struct AzTabOnContextMenu AzTabOnContextMenu;
+
+struct AzSplitterOnResize {
+    AzRefAny data;
+    AzSplitterOnResizeCallback callback;
+};
+typedef struct AzSplitterOnResize AzSplitterOnResize;
+
+struct AzSliderOnValueChange {
+    AzRefAny data;
+    AzSliderOnValueChangeCallback callback;
+};
+typedef struct AzSliderOnValueChange AzSliderOnValueChange;
+
+struct AzRangeSliderOnValueChange {
+    AzRefAny data;
+    AzRangeSliderOnValueChangeCallback callback;
+};
+typedef struct AzRangeSliderOnValueChange AzRangeSliderOnValueChange;
+
 struct AzNodeGraphOnNodeAdded {
     AzRefAny data;
     AzNodeGraphOnNodeAddedCallback callback;
@@ -6368,6 +7810,42 @@ struct AzListViewOnRowClick {
 };
 typedef struct AzListViewOnRowClick AzListViewOnRowClick;
 
+struct AzListViewOnColumnResize {
+    AzRefAny data;
+    AzListViewOnColumnResizeCallback callback;
+};
+typedef struct AzListViewOnColumnResize AzListViewOnColumnResize;
+
+struct AzListViewRowProvider {
+    AzRefAny data;
+    AzListViewRowProviderCallback callback;
+};
+typedef struct AzListViewRowProvider AzListViewRowProvider;
+
+struct AzTreeViewOnExpand {
+    AzRefAny data;
+    AzTreeViewOnExpandCallback callback;
+};
+typedef struct AzTreeViewOnExpand AzTreeViewOnExpand;
+
+struct AzTreeViewOnSelect {
+    AzRefAny data;
+    AzTreeViewOnSelectCallback callback;
+};
+typedef struct AzTreeViewOnSelect AzTreeViewOnSelect;
+
+struct AzTreeViewOnDoubleClick {
+    AzRefAny data;
+    AzTreeViewOnDoubleClickCallback callback;
+};
+typedef struct AzTreeViewOnDoubleClick AzTreeViewOnDoubleClick;
+
+struct AzTreeViewOnContextMenu {
+    AzRefAny data;
+    AzTreeViewOnContextMenuCallback callback;
+};
+typedef struct AzTreeViewOnContextMenu AzTreeViewOnContextMenu;
+
 struct AzDropDownOnChoiceChange {
     AzRefAny data;
     AzDropDownOnChoiceChangeCallback callback;
@@ -6548,6 +8026,22 @@ struct AzThreadWriteBackMsg {
 };
 typedef struct AzThreadWriteBackMsg AzThreadWriteBackMsg;
 
+struct AzTreeViewNodeIdVec {
+    AzTreeViewNodeId* ptr;
+    size_t len;
+    size_t cap;
+    AzTreeViewNodeIdVecDestructor destructor;
+};
+typedef struct AzTreeViewNodeIdVec AzTreeViewNodeIdVec;
+
+struct AzSplitterPaneStateVec {
+    AzSplitterPaneState* ptr;
+    size_t len;
+    size_t cap;
+    AzSplitterPaneStateVecDestructor destructor;
+};
+typedef struct AzSplitterPaneStateVec AzSplitterPaneStateVec;
+
 struct AzLogicalRectVec {
     AzLogicalRect* ptr;
     size_t len;
@@ -6588,6 +8082,14 @@ struct AzAccessibilityStateVec {
 };
 typedef struct AzAccessibilityStateVec AzAccessibilityStateVec;
 
+struct AzAccessibilityActionVec {
+    AzAccessibilityAction* ptr;
+    size_t len;
+    size_t cap;
+    AzAccessibilityActionVecDestructor destructor;
+};
+typedef struct AzAccessibilityActionVec AzAccessibilityActionVec;
+
 struct AzMenuItem;
 typedef struct AzMenuItem AzMenuItem;
 struct AzMenuItemVec {
@@ -6666,40 +8168,104 @@ struct AzStyleBackgroundSizeVec {
 };
 typedef struct AzStyleBackgroundSizeVec AzStyleBackgroundSizeVec;
 
-struct AzSvgVertexVec {
-    AzSvgVertex* ptr;
+struct AzStyleTransitionPropertyVec {
+    AzStyleTransitionProperty* ptr;
     size_t len;
     size_t cap;
-    AzSvgVertexVecDestructor destructor;
+    AzStyleTransitionPropertyVecDestructor destructor;
 };
-typedef struct AzSvgVertexVec AzSvgVertexVec;
+typedef struct AzStyleTransitionPropertyVec AzStyleTransitionPropertyVec;
 
-struct AzU32Vec {
-    uint32_t* ptr;
+struct AzStyleTimeVec {
+    AzStyleTime* ptr;
     size_t len;
     size_t cap;
-    AzU32VecDestructor destructor;
+    AzStyleTimeVecDestructor destructor;
 };
-typedef struct AzU32Vec AzU32Vec;
+typedef struct AzStyleTimeVec AzStyleTimeVec;
 
-struct AzXWindowTypeVec {
-    AzXWindowType* ptr;
+struct AzStyleTimingFunctionVec {
+    AzStyleTimingFunction* ptr;
     size_t len;
     size_t cap;
-    AzXWindowTypeVecDestructor destructor;
+    AzStyleTimingFunctionVecDestructor destructor;
 };
-typedef struct AzXWindowTypeVec AzXWindowTypeVec;
+typedef struct AzStyleTimingFunctionVec AzStyleTimingFunctionVec;
 
-struct AzVirtualKeyCodeVec {
-    AzVirtualKeyCode* ptr;
+struct AzStyleAnimationIterationCountVec {
+    AzStyleAnimationIterationCount* ptr;
     size_t len;
     size_t cap;
-    AzVirtualKeyCodeVecDestructor destructor;
+    AzStyleAnimationIterationCountVecDestructor destructor;
 };
-typedef struct AzVirtualKeyCodeVec AzVirtualKeyCodeVec;
+typedef struct AzStyleAnimationIterationCountVec AzStyleAnimationIterationCountVec;
 
-struct AzCascadeInfoVec {
-    AzCascadeInfo* ptr;
+struct AzStyleAnimationDirectionVec {
+    AzStyleAnimationDirection* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationDirectionVecDestructor destructor;
+};
+typedef struct AzStyleAnimationDirectionVec AzStyleAnimationDirectionVec;
+
+struct AzStyleAnimationFillModeVec {
+    AzStyleAnimationFillMode* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationFillModeVecDestructor destructor;
+};
+typedef struct AzStyleAnimationFillModeVec AzStyleAnimationFillModeVec;
+
+struct AzStyleAnimationPlayStateVec {
+    AzStyleAnimationPlayState* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationPlayStateVecDestructor destructor;
+};
+typedef struct AzStyleAnimationPlayStateVec AzStyleAnimationPlayStateVec;
+
+struct AzGridTrackSizingVec {
+    AzGridTrackSizing* ptr;
+    size_t len;
+    size_t cap;
+    AzGridTrackSizingVecDestructor destructor;
+};
+typedef struct AzGridTrackSizingVec AzGridTrackSizingVec;
+
+struct AzSvgVertexVec {
+    AzSvgVertex* ptr;
+    size_t len;
+    size_t cap;
+    AzSvgVertexVecDestructor destructor;
+};
+typedef struct AzSvgVertexVec AzSvgVertexVec;
+
+struct AzU32Vec {
+    uint32_t* ptr;
+    size_t len;
+    size_t cap;
+    AzU32VecDestructor destructor;
+};
+typedef struct AzU32Vec AzU32Vec;
+
+struct AzXWindowTypeVec {
+    AzXWindowType* ptr;
+    size_t len;
+    size_t cap;
+    AzXWindowTypeVecDestructor destructor;
+};
+typedef struct AzXWindowTypeVec AzXWindowTypeVec;
+
+struct AzVirtualKeyCodeVec {
+    AzVirtualKeyCode* ptr;
+    size_t len;
+    size_t cap;
+    AzVirtualKeyCodeVecDestructor destructor;
+};
+typedef struct AzVirtualKeyCodeVec AzVirtualKeyCodeVec;
+
+struct AzCascadeInfoVec {
+    AzCascadeInfo* ptr;
     size_t len;
     size_t cap;
     AzCascadeInfoVecDestructor destructor;
@@ -6810,6 +8376,86 @@ union AzOptionListViewOnRowClick {
 };
 typedef union AzOptionListViewOnRowClick AzOptionListViewOnRowClick;
 
+enum AzOptionTreeViewNodeIdTag {
+   AzOptionTreeViewNodeIdTag_None,
+   AzOptionTreeViewNodeIdTag_Some,
+};
+typedef enum AzOptionTreeViewNodeIdTag AzOptionTreeViewNodeIdTag;
+
+struct AzOptionTreeViewNodeIdVariant_None { AzOptionTreeViewNodeIdTag tag; };
+typedef struct AzOptionTreeViewNodeIdVariant_None AzOptionTreeViewNodeIdVariant_None;
+struct AzOptionTreeViewNodeIdVariant_Some { AzOptionTreeViewNodeIdTag tag; AzTreeViewNodeId payload; };
+typedef struct AzOptionTreeViewNodeIdVariant_Some AzOptionTreeViewNodeIdVariant_Some;
+union AzOptionTreeViewNodeId {
+    AzOptionTreeViewNodeIdVariant_None None;
+    AzOptionTreeViewNodeIdVariant_Some Some;
+};
+typedef union AzOptionTreeViewNodeId AzOptionTreeViewNodeId;
+
+enum AzOptionTreeViewOnExpandTag {
+   AzOptionTreeViewOnExpandTag_None,
+   AzOptionTreeViewOnExpandTag_Some,
+};
+typedef enum AzOptionTreeViewOnExpandTag AzOptionTreeViewOnExpandTag;
+
+struct AzOptionTreeViewOnExpandVariant_None { AzOptionTreeViewOnExpandTag tag; };
+typedef struct AzOptionTreeViewOnExpandVariant_None AzOptionTreeViewOnExpandVariant_None;
+struct AzOptionTreeViewOnExpandVariant_Some { AzOptionTreeViewOnExpandTag tag; AzTreeViewOnExpand payload; };
+typedef struct AzOptionTreeViewOnExpandVariant_Some AzOptionTreeViewOnExpandVariant_Some;
+union AzOptionTreeViewOnExpand {
+    AzOptionTreeViewOnExpandVariant_None None;
+    AzOptionTreeViewOnExpandVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnExpand AzOptionTreeViewOnExpand;
+
+enum AzOptionTreeViewOnSelectTag {
+   AzOptionTreeViewOnSelectTag_None,
+   AzOptionTreeViewOnSelectTag_Some,
+};
+typedef enum AzOptionTreeViewOnSelectTag AzOptionTreeViewOnSelectTag;
+
+struct AzOptionTreeViewOnSelectVariant_None { AzOptionTreeViewOnSelectTag tag; };
+typedef struct AzOptionTreeViewOnSelectVariant_None AzOptionTreeViewOnSelectVariant_None;
+struct AzOptionTreeViewOnSelectVariant_Some { AzOptionTreeViewOnSelectTag tag; AzTreeViewOnSelect payload; };
+typedef struct AzOptionTreeViewOnSelectVariant_Some AzOptionTreeViewOnSelectVariant_Some;
+union AzOptionTreeViewOnSelect {
+    AzOptionTreeViewOnSelectVariant_None None;
+    AzOptionTreeViewOnSelectVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnSelect AzOptionTreeViewOnSelect;
+
+enum AzOptionTreeViewOnDoubleClickTag {
+   AzOptionTreeViewOnDoubleClickTag_None,
+   AzOptionTreeViewOnDoubleClickTag_Some,
+};
+typedef enum AzOptionTreeViewOnDoubleClickTag AzOptionTreeViewOnDoubleClickTag;
+
+struct AzOptionTreeViewOnDoubleClickVariant_None { AzOptionTreeViewOnDoubleClickTag tag; };
+typedef struct AzOptionTreeViewOnDoubleClickVariant_None AzOptionTreeViewOnDoubleClickVariant_None;
+struct AzOptionTreeViewOnDoubleClickVariant_Some { AzOptionTreeViewOnDoubleClickTag tag; AzTreeViewOnDoubleClick payload; };
+typedef struct AzOptionTreeViewOnDoubleClickVariant_Some AzOptionTreeViewOnDoubleClickVariant_Some;
+union AzOptionTreeViewOnDoubleClick {
+    AzOptionTreeViewOnDoubleClickVariant_None None;
+    AzOptionTreeViewOnDoubleClickVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnDoubleClick AzOptionTreeViewOnDoubleClick;
+
+enum AzOptionTreeViewOnContextMenuTag {
+   AzOptionTreeViewOnContextMenuTag_None,
+   AzOptionTreeViewOnContextMenuTag_Some,
+};
+typedef enum AzOptionTreeViewOnContextMenuTag AzOptionTreeViewOnContextMenuTag;
+
+struct AzOptionTreeViewOnContextMenuVariant_None { AzOptionTreeViewOnContextMenuTag tag; };
+typedef struct AzOptionTreeViewOnContextMenuVariant_None AzOptionTreeViewOnContextMenuVariant_None;
+struct AzOptionTreeViewOnContextMenuVariant_Some { AzOptionTreeViewOnContextMenuTag tag; AzTreeViewOnContextMenu payload; };
+typedef struct AzOptionTreeViewOnContextMenuVariant_Some AzOptionTreeViewOnContextMenuVariant_Some;
+union AzOptionTreeViewOnContextMenu {
+    AzOptionTreeViewOnContextMenuVariant_None None;
+    AzOptionTreeViewOnContextMenuVariant_Some Some;
+};
+typedef union AzOptionTreeViewOnContextMenu AzOptionTreeViewOnContextMenu;
+
 enum AzOptionListViewOnColumnClickTag {
    AzOptionListViewOnColumnClickTag_None,
    AzOptionListViewOnColumnClickTag_Some,
@@ -6842,6 +8488,38 @@ union AzOptionListViewOnLazyLoadScroll {
 };
 typedef union AzOptionListViewOnLazyLoadScroll AzOptionListViewOnLazyLoadScroll;
 
+enum AzOptionListViewOnColumnResizeTag {
+   AzOptionListViewOnColumnResizeTag_None,
+   AzOptionListViewOnColumnResizeTag_Some,
+};
+typedef enum AzOptionListViewOnColumnResizeTag AzOptionListViewOnColumnResizeTag;
+
+struct AzOptionListViewOnColumnResizeVariant_None { AzOptionListViewOnColumnResizeTag tag; };
+typedef struct AzOptionListViewOnColumnResizeVariant_None AzOptionListViewOnColumnResizeVariant_None;
+struct AzOptionListViewOnColumnResizeVariant_Some { AzOptionListViewOnColumnResizeTag tag; AzListViewOnColumnResize payload; };
+typedef struct AzOptionListViewOnColumnResizeVariant_Some AzOptionListViewOnColumnResizeVariant_Some;
+union AzOptionListViewOnColumnResize {
+    AzOptionListViewOnColumnResizeVariant_None None;
+    AzOptionListViewOnColumnResizeVariant_Some Some;
+};
+typedef union AzOptionListViewOnColumnResize AzOptionListViewOnColumnResize;
+
+enum AzOptionListViewRowProviderTag {
+   AzOptionListViewRowProviderTag_None,
+   AzOptionListViewRowProviderTag_Some,
+};
+typedef enum AzOptionListViewRowProviderTag AzOptionListViewRowProviderTag;
+
+struct AzOptionListViewRowProviderVariant_None { AzOptionListViewRowProviderTag tag; };
+typedef struct AzOptionListViewRowProviderVariant_None AzOptionListViewRowProviderVariant_None;
+struct AzOptionListViewRowProviderVariant_Some { AzOptionListViewRowProviderTag tag; AzListViewRowProvider payload; };
+typedef struct AzOptionListViewRowProviderVariant_Some AzOptionListViewRowProviderVariant_Some;
+union AzOptionListViewRowProvider {
+    AzOptionListViewRowProviderVariant_None None;
+    AzOptionListViewRowProviderVariant_Some Some;
+};
+typedef union AzOptionListViewRowProvider AzOptionListViewRowProvider;
+
 enum AzOptionPixelValueNoPercentTag {
    AzOptionPixelValueNoPercentTag_None,
    AzOptionPixelValueNoPercentTag_Some,
@@ -7050,6 +8728,134 @@ union AzOptionTabOnClick {
 };
 typedef union AzOptionTabOnClick AzOptionTabOnClick;
 
+enum AzOptionTabOnCloseTag {
+   AzOptionTabOnCloseTag_None,
+   AzOptionTabOnCloseTag_Some,
+};
+typedef enum AzOptionTabOnCloseTag AzOptionTabOnCloseTag;
+
+struct AzOptionTabOnCloseVariant_None { AzOptionTabOnCloseTag tag; };
+typedef struct AzOptionTabOnCloseVariant_None AzOptionTabOnCloseVariant_None;
+struct AzOptionTabOnCloseVariant_Some { AzOptionTabOnCloseTag tag; AzTabOnClose payload; };
+typedef struct AzOptionTabOnCloseVariant_Some AzOptionTabOnCloseVariant_Some;
+union AzOptionTabOnClose {
+    AzOptionTabOnCloseVariant_None None;
+    AzOptionTabOnCloseVariant_Some Some;
+};
+typedef union AzOptionTabOnClose AzOptionTabOnClose;
+
+enum AzOptionTabOnReorderTag {
+   AzOptionTabOnReorderTag_None,
+   AzOptionTabOnReorderTag_Some,
+};
+typedef enum AzOptionTabOnReorderTag AzOptionTabOnReorderTag;
+
+struct AzOptionTabOnReorderVariant_None { AzOptionTabOnReorderTag tag; };
+typedef struct AzOptionTabOnReorderVariant_None AzOptionTabOnReorderVariant_None;
+struct AzOptionTabOnReorderVariant_Some { AzOptionTabOnReorderTag tag; AzTabOnReorder payload; };
+typedef struct AzOptionTabOnReorderVariant_Some AzOptionTabOnReorderVariant_Some;
+union AzOptionTabOnReorder {
+    AzOptionTabOnReorderVariant_None None;
+    AzOptionTabOnReorderVariant_Some Some;
+};
+typedef union AzOptionTabOnReorder AzOptionTabOnReorder;
+
+enum AzOptionTabOnContextMenuTag {
+   AzOptionTabOnContextMenuTag_None,
+   AzOptionTabOnContextMenuTag_Some,
+};
+typedef enum AzOptionTabOnContextMenuTag AzOptionTabOnContextMenuTag;
+
+struct AzOptionTabOnContextMenuVariant_None { AzOptionTabOnContextMenuTag tag; };
+typedef struct AzOptionTabOnContextMenuVariant_None AzOptionTabOnContextMenuVariant_None;
+struct AzOptionTabOnContextMenuVariant_Some { AzOptionTabOnContextMenuTag tag; AzTabOnContextMenu payload; };
+typedef struct AzOptionTabOnContextMenuVariant_Some AzOptionTabOnContextMenuVariant_Some;
+union AzOptionTabOnContextMenu {
+    AzOptionTabOnContextMenuVariant_None None;
+    AzOptionTabOnContextMenuVariant_Some Some;
+};
+typedef union AzOptionTabOnContextMenu AzOptionTabOnContextMenu;
+
+enum AzOptionRibbonOnTabClickedTag {
+   AzOptionRibbonOnTabClickedTag_None,
+   AzOptionRibbonOnTabClickedTag_Some,
+};
+typedef enum AzOptionRibbonOnTabClickedTag AzOptionRibbonOnTabClickedTag;
+
+struct AzOptionRibbonOnTabClickedVariant_None { AzOptionRibbonOnTabClickedTag tag; };
+typedef struct AzOptionRibbonOnTabClickedVariant_None AzOptionRibbonOnTabClickedVariant_None;
+struct AzOptionRibbonOnTabClickedVariant_Some { AzOptionRibbonOnTabClickedTag tag; AzRibbonOnTabClicked payload; };
+typedef struct AzOptionRibbonOnTabClickedVariant_Some AzOptionRibbonOnTabClickedVariant_Some;
+union AzOptionRibbonOnTabClicked {
+    AzOptionRibbonOnTabClickedVariant_None None;
+    AzOptionRibbonOnTabClickedVariant_Some Some;
+};
+typedef union AzOptionRibbonOnTabClicked AzOptionRibbonOnTabClicked;
+
+enum AzOptionRibbonOnItemClickTag {
+   AzOptionRibbonOnItemClickTag_None,
+   AzOptionRibbonOnItemClickTag_Some,
+};
+typedef enum AzOptionRibbonOnItemClickTag AzOptionRibbonOnItemClickTag;
+
+struct AzOptionRibbonOnItemClickVariant_None { AzOptionRibbonOnItemClickTag tag; };
+typedef struct AzOptionRibbonOnItemClickVariant_None AzOptionRibbonOnItemClickVariant_None;
+struct AzOptionRibbonOnItemClickVariant_Some { AzOptionRibbonOnItemClickTag tag; AzRibbonOnItemClick payload; };
+typedef struct AzOptionRibbonOnItemClickVariant_Some AzOptionRibbonOnItemClickVariant_Some;
+union AzOptionRibbonOnItemClick {
+    AzOptionRibbonOnItemClickVariant_None None;
+    AzOptionRibbonOnItemClickVariant_Some Some;
+};
+typedef union AzOptionRibbonOnItemClick AzOptionRibbonOnItemClick;
+
+enum AzOptionSplitterOnResizeTag {
+   AzOptionSplitterOnResizeTag_None,
+   AzOptionSplitterOnResizeTag_Some,
+};
+typedef enum AzOptionSplitterOnResizeTag AzOptionSplitterOnResizeTag;
+
+struct AzOptionSplitterOnResizeVariant_None { AzOptionSplitterOnResizeTag tag; };
+typedef struct AzOptionSplitterOnResizeVariant_None AzOptionSplitterOnResizeVariant_None;
+struct AzOptionSplitterOnResizeVariant_Some { AzOptionSplitterOnResizeTag tag; AzSplitterOnResize payload; };
+typedef struct AzOptionSplitterOnResizeVariant_Some AzOptionSplitterOnResizeVariant_Some;
+union AzOptionSplitterOnResize {
+    AzOptionSplitterOnResizeVariant_None None;
+    AzOptionSplitterOnResizeVariant_Some Some;
+};
+typedef union AzOptionSplitterOnResize AzOptionSplitterOnResize;
+
+enum AzOptionSliderOnValueChangeTag {
+   AzOptionSliderOnValueChangeTag_None,
+   AzOptionSliderOnValueChangeTag_Some,
+};
+typedef enum AzOptionSliderOnValueChangeTag AzOptionSliderOnValueChangeTag;
+
+struct AzOptionSliderOnValueChangeVariant_None { AzOptionSliderOnValueChangeTag tag; };
+typedef struct AzOptionSliderOnValueChangeVariant_None AzOptionSliderOnValueChangeVariant_None;
+struct AzOptionSliderOnValueChangeVariant_Some { AzOptionSliderOnValueChangeTag tag; AzSliderOnValueChange payload; };
+typedef struct AzOptionSliderOnValueChangeVariant_Some AzOptionSliderOnValueChangeVariant_Some;
+union AzOptionSliderOnValueChange {
+    AzOptionSliderOnValueChangeVariant_None None;
+    AzOptionSliderOnValueChangeVariant_Some Some;
+};
+typedef union AzOptionSliderOnValueChange AzOptionSliderOnValueChange;
+
+enum AzOptionRangeSliderOnValueChangeTag {
+   AzOptionRangeSliderOnValueChangeTag_None,
+   AzOptionRangeSliderOnValueChangeTag_Some,
+};
+typedef enum AzOptionRangeSliderOnValueChangeTag AzOptionRangeSliderOnValueChangeTag;
+
+struct AzOptionRangeSliderOnValueChangeVariant_None { AzOptionRangeSliderOnValueChangeTag tag; };
+typedef struct AzOptionRangeSliderOnValueChangeVariant_None AzOptionRangeSliderOnValueChangeVariant_None;
+struct AzOptionRangeSliderOnValueChangeVariant_Some { AzOptionRangeSliderOnValueChangeTag tag; AzRangeSliderOnValueChange payload; };
+typedef struct AzOptionRangeSliderOnValueChangeVariant_Some AzOptionRangeSliderOnValueChangeVariant_Some;
+union AzOptionRangeSliderOnValueChange {
+    AzOptionRangeSliderOnValueChangeVariant_None None;
+    AzOptionRangeSliderOnValueChangeVariant_Some Some;
+};
+typedef union AzOptionRangeSliderOnValueChange AzOptionRangeSliderOnValueChange;
+
 enum AzOptionFileInputOnPathChangeTag {
    AzOptionFileInputOnPathChangeTag_None,
    AzOptionFileInputOnPathChangeTag_Some,
@@ -7146,6 +8952,22 @@ union AzOptionTextInputSelection {
 };
 typedef union AzOptionTextInputSelection AzOptionTextInputSelection;
 
+enum AzOptionTextAreaOnTextChangeTag {
+   AzOptionTextAreaOnTextChangeTag_None,
+   AzOptionTextAreaOnTextChangeTag_Some,
+};
+typedef enum AzOptionTextAreaOnTextChangeTag AzOptionTextAreaOnTextChangeTag;
+
+struct AzOptionTextAreaOnTextChangeVariant_None { AzOptionTextAreaOnTextChangeTag tag; };
+typedef struct AzOptionTextAreaOnTextChangeVariant_None AzOptionTextAreaOnTextChangeVariant_None;
+struct AzOptionTextAreaOnTextChangeVariant_Some { AzOptionTextAreaOnTextChangeTag tag; AzTextAreaOnTextChange payload; };
+typedef struct AzOptionTextAreaOnTextChangeVariant_Some AzOptionTextAreaOnTextChangeVariant_Some;
+union AzOptionTextAreaOnTextChange {
+    AzOptionTextAreaOnTextChangeVariant_None None;
+    AzOptionTextAreaOnTextChangeVariant_Some Some;
+};
+typedef union AzOptionTextAreaOnTextChange AzOptionTextAreaOnTextChange;
+
 enum AzOptionNumberInputOnFocusLostTag {
    AzOptionNumberInputOnFocusLostTag_None,
    AzOptionNumberInputOnFocusLostTag_Some,
@@ -7562,6 +9384,22 @@ union AzOptionLogicalPosition {
 };
 typedef union AzOptionLogicalPosition AzOptionLogicalPosition;
 
+enum AzOptionLogicalRectTag {
+   AzOptionLogicalRectTag_None,
+   AzOptionLogicalRectTag_Some,
+};
+typedef enum AzOptionLogicalRectTag AzOptionLogicalRectTag;
+
+struct AzOptionLogicalRectVariant_None { AzOptionLogicalRectTag tag; };
+typedef struct AzOptionLogicalRectVariant_None AzOptionLogicalRectVariant_None;
+struct AzOptionLogicalRectVariant_Some { AzOptionLogicalRectTag tag; AzLogicalRect payload; };
+typedef struct AzOptionLogicalRectVariant_Some AzOptionLogicalRectVariant_Some;
+union AzOptionLogicalRect {
+    AzOptionLogicalRectVariant_None None;
+    AzOptionLogicalRectVariant_Some Some;
+};
+typedef union AzOptionLogicalRect AzOptionLogicalRect;
+
 enum AzOptionPhysicalPositionI32Tag {
    AzOptionPhysicalPositionI32Tag_None,
    AzOptionPhysicalPositionI32Tag_Some,
@@ -7824,14 +9662,6 @@ struct AzWindowSize {
 };
 typedef struct AzWindowSize AzWindowSize;
 
-struct AzKeyboardState {
-    AzOptionChar current_char;
-    AzOptionVirtualKeyCode current_virtual_keycode;
-    AzVirtualKeyCodeVec pressed_virtual_keycodes;
-    AzScanCodeVec pressed_scancodes;
-};
-typedef struct AzKeyboardState AzKeyboardState;
-
 struct AzMouseState {
     AzOptionMouseCursorType mouse_cursor_type;
     AzCursorPosition cursor_position;
@@ -7853,6 +9683,7 @@ typedef struct AzMarshaledLayoutCallback AzMarshaledLayoutCallback;
 struct AzInlineTextContents {
     AzInlineGlyphVec glyphs;
     AzLogicalRect bounds;
+    bool  is_rtl;
 };
 typedef struct AzInlineTextContents AzInlineTextContents;
 
@@ -7865,6 +9696,10 @@ struct AzResolvedTextLayoutOptions {
     AzOptionF32 max_horizontal_width;
     AzOptionF32 leading;
     AzLogicalRectVec holes;
+    AzStyleDirection direction;
+    AzStyleWordBreak word_break;
+    AzStyleOverflowWrap overflow_wrap;
+    AzStyleHyphens hyphens;
 };
 typedef struct AzResolvedTextLayoutOptions AzResolvedTextLayoutOptions;
 
@@ -8003,6 +9838,34 @@ union AzCssPathPseudoSelector {
 };
 typedef union AzCssPathPseudoSelector AzCssPathPseudoSelector;
 
+enum AzCssMediaFeatureTag {
+   AzCssMediaFeatureTag_Width,
+   AzCssMediaFeatureTag_Height,
+   AzCssMediaFeatureTag_Resolution,
+   AzCssMediaFeatureTag_Orientation,
+   AzCssMediaFeatureTag_PrefersColorScheme,
+};
+typedef enum AzCssMediaFeatureTag AzCssMediaFeatureTag;
+
+struct AzCssMediaFeatureVariant_Width { AzCssMediaFeatureTag tag; AzCssMediaRange payload; };
+typedef struct AzCssMediaFeatureVariant_Width AzCssMediaFeatureVariant_Width;
+struct AzCssMediaFeatureVariant_Height { AzCssMediaFeatureTag tag; AzCssMediaRange payload; };
+typedef struct AzCssMediaFeatureVariant_Height AzCssMediaFeatureVariant_Height;
+struct AzCssMediaFeatureVariant_Resolution { AzCssMediaFeatureTag tag; AzCssMediaRange payload; };
+typedef struct AzCssMediaFeatureVariant_Resolution AzCssMediaFeatureVariant_Resolution;
+struct AzCssMediaFeatureVariant_Orientation { AzCssMediaFeatureTag tag; AzCssMediaOrientation payload; };
+typedef struct AzCssMediaFeatureVariant_Orientation AzCssMediaFeatureVariant_Orientation;
+struct AzCssMediaFeatureVariant_PrefersColorScheme { AzCssMediaFeatureTag tag; AzCssColorScheme payload; };
+typedef struct AzCssMediaFeatureVariant_PrefersColorScheme AzCssMediaFeatureVariant_PrefersColorScheme;
+union AzCssMediaFeature {
+    AzCssMediaFeatureVariant_Width Width;
+    AzCssMediaFeatureVariant_Height Height;
+    AzCssMediaFeatureVariant_Resolution Resolution;
+    AzCssMediaFeatureVariant_Orientation Orientation;
+    AzCssMediaFeatureVariant_PrefersColorScheme PrefersColorScheme;
+};
+typedef union AzCssMediaFeature AzCssMediaFeature;
+
 enum AzAnimationInterpolationFunctionTag {
    AzAnimationInterpolationFunctionTag_Ease,
    AzAnimationInterpolationFunctionTag_Linear,
@@ -8204,6 +10067,230 @@ union AzStyleTransform {
 };
 typedef union AzStyleTransform AzStyleTransform;
 
+enum AzGridTrackSizingVecValueTag {
+   AzGridTrackSizingVecValueTag_Auto,
+   AzGridTrackSizingVecValueTag_None,
+   AzGridTrackSizingVecValueTag_Inherit,
+   AzGridTrackSizingVecValueTag_Initial,
+   AzGridTrackSizingVecValueTag_Exact,
+};
+typedef enum AzGridTrackSizingVecValueTag AzGridTrackSizingVecValueTag;
+
+struct AzGridTrackSizingVecValueVariant_Auto { AzGridTrackSizingVecValueTag tag; };
+typedef struct AzGridTrackSizingVecValueVariant_Auto AzGridTrackSizingVecValueVariant_Auto;
+struct AzGridTrackSizingVecValueVariant_None { AzGridTrackSizingVecValueTag tag; };
+typedef struct AzGridTrackSizingVecValueVariant_None AzGridTrackSizingVecValueVariant_None;
+struct AzGridTrackSizingVecValueVariant_Inherit { AzGridTrackSizingVecValueTag tag; };
+typedef struct AzGridTrackSizingVecValueVariant_Inherit AzGridTrackSizingVecValueVariant_Inherit;
+struct AzGridTrackSizingVecValueVariant_Initial { AzGridTrackSizingVecValueTag tag; };
+typedef struct AzGridTrackSizingVecValueVariant_Initial AzGridTrackSizingVecValueVariant_Initial;
+struct AzGridTrackSizingVecValueVariant_Exact { AzGridTrackSizingVecValueTag tag; AzGridTrackSizingVec payload; };
+typedef struct AzGridTrackSizingVecValueVariant_Exact AzGridTrackSizingVecValueVariant_Exact;
+union AzGridTrackSizingVecValue {
+    AzGridTrackSizingVecValueVariant_Auto Auto;
+    AzGridTrackSizingVecValueVariant_None None;
+    AzGridTrackSizingVecValueVariant_Inherit Inherit;
+    AzGridTrackSizingVecValueVariant_Initial Initial;
+    AzGridTrackSizingVecValueVariant_Exact Exact;
+};
+typedef union AzGridTrackSizingVecValue AzGridTrackSizingVecValue;
+
+enum AzStyleTransitionPropertyVecValueTag {
+   AzStyleTransitionPropertyVecValueTag_Auto,
+   AzStyleTransitionPropertyVecValueTag_None,
+   AzStyleTransitionPropertyVecValueTag_Inherit,
+   AzStyleTransitionPropertyVecValueTag_Initial,
+   AzStyleTransitionPropertyVecValueTag_Exact,
+};
+typedef enum AzStyleTransitionPropertyVecValueTag AzStyleTransitionPropertyVecValueTag;
+
+struct AzStyleTransitionPropertyVecValueVariant_Auto { AzStyleTransitionPropertyVecValueTag tag; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_Auto AzStyleTransitionPropertyVecValueVariant_Auto;
+struct AzStyleTransitionPropertyVecValueVariant_None { AzStyleTransitionPropertyVecValueTag tag; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_None AzStyleTransitionPropertyVecValueVariant_None;
+struct AzStyleTransitionPropertyVecValueVariant_Inherit { AzStyleTransitionPropertyVecValueTag tag; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_Inherit AzStyleTransitionPropertyVecValueVariant_Inherit;
+struct AzStyleTransitionPropertyVecValueVariant_Initial { AzStyleTransitionPropertyVecValueTag tag; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_Initial AzStyleTransitionPropertyVecValueVariant_Initial;
+struct AzStyleTransitionPropertyVecValueVariant_Exact { AzStyleTransitionPropertyVecValueTag tag; AzStyleTransitionPropertyVec payload; };
+typedef struct AzStyleTransitionPropertyVecValueVariant_Exact AzStyleTransitionPropertyVecValueVariant_Exact;
+union AzStyleTransitionPropertyVecValue {
+    AzStyleTransitionPropertyVecValueVariant_Auto Auto;
+    AzStyleTransitionPropertyVecValueVariant_None None;
+    AzStyleTransitionPropertyVecValueVariant_Inherit Inherit;
+    AzStyleTransitionPropertyVecValueVariant_Initial Initial;
+    AzStyleTransitionPropertyVecValueVariant_Exact Exact;
+};
+typedef union AzStyleTransitionPropertyVecValue AzStyleTransitionPropertyVecValue;
+
+enum AzStyleTimeVecValueTag {
+   AzStyleTimeVecValueTag_Auto,
+   AzStyleTimeVecValueTag_None,
+   AzStyleTimeVecValueTag_Inherit,
+   AzStyleTimeVecValueTag_Initial,
+   AzStyleTimeVecValueTag_Exact,
+};
+typedef enum AzStyleTimeVecValueTag AzStyleTimeVecValueTag;
+
+struct AzStyleTimeVecValueVariant_Auto { AzStyleTimeVecValueTag tag; };
+typedef struct AzStyleTimeVecValueVariant_Auto AzStyleTimeVecValueVariant_Auto;
+struct AzStyleTimeVecValueVariant_None { AzStyleTimeVecValueTag tag; };
+typedef struct AzStyleTimeVecValueVariant_None AzStyleTimeVecValueVariant_None;
+struct AzStyleTimeVecValueVariant_Inherit { AzStyleTimeVecValueTag tag; };
+typedef struct AzStyleTimeVecValueVariant_Inherit AzStyleTimeVecValueVariant_Inherit;
+struct AzStyleTimeVecValueVariant_Initial { AzStyleTimeVecValueTag tag; };
+typedef struct AzStyleTimeVecValueVariant_Initial AzStyleTimeVecValueVariant_Initial;
+struct AzStyleTimeVecValueVariant_Exact { AzStyleTimeVecValueTag tag; AzStyleTimeVec payload; };
+typedef struct AzStyleTimeVecValueVariant_Exact AzStyleTimeVecValueVariant_Exact;
+union AzStyleTimeVecValue {
+    AzStyleTimeVecValueVariant_Auto Auto;
+    AzStyleTimeVecValueVariant_None None;
+    AzStyleTimeVecValueVariant_Inherit Inherit;
+    AzStyleTimeVecValueVariant_Initial Initial;
+    AzStyleTimeVecValueVariant_Exact Exact;
+};
+typedef union AzStyleTimeVecValue AzStyleTimeVecValue;
+
+enum AzStyleTimingFunctionVecValueTag {
+   AzStyleTimingFunctionVecValueTag_Auto,
+   AzStyleTimingFunctionVecValueTag_None,
+   AzStyleTimingFunctionVecValueTag_Inherit,
+   AzStyleTimingFunctionVecValueTag_Initial,
+   AzStyleTimingFunctionVecValueTag_Exact,
+};
+typedef enum AzStyleTimingFunctionVecValueTag AzStyleTimingFunctionVecValueTag;
+
+struct AzStyleTimingFunctionVecValueVariant_Auto { AzStyleTimingFunctionVecValueTag tag; };
+typedef struct AzStyleTimingFunctionVecValueVariant_Auto AzStyleTimingFunctionVecValueVariant_Auto;
+struct AzStyleTimingFunctionVecValueVariant_None { AzStyleTimingFunctionVecValueTag tag; };
+typedef struct AzStyleTimingFunctionVecValueVariant_None AzStyleTimingFunctionVecValueVariant_None;
+struct AzStyleTimingFunctionVecValueVariant_Inherit { AzStyleTimingFunctionVecValueTag tag; };
+typedef struct AzStyleTimingFunctionVecValueVariant_Inherit AzStyleTimingFunctionVecValueVariant_Inherit;
+struct AzStyleTimingFunctionVecValueVariant_Initial { AzStyleTimingFunctionVecValueTag tag; };
+typedef struct AzStyleTimingFunctionVecValueVariant_Initial AzStyleTimingFunctionVecValueVariant_Initial;
+struct AzStyleTimingFunctionVecValueVariant_Exact { AzStyleTimingFunctionVecValueTag tag; AzStyleTimingFunctionVec payload; };
+typedef struct AzStyleTimingFunctionVecValueVariant_Exact AzStyleTimingFunctionVecValueVariant_Exact;
+union AzStyleTimingFunctionVecValue {
+    AzStyleTimingFunctionVecValueVariant_Auto Auto;
+    AzStyleTimingFunctionVecValueVariant_None None;
+    AzStyleTimingFunctionVecValueVariant_Inherit Inherit;
+    AzStyleTimingFunctionVecValueVariant_Initial Initial;
+    AzStyleTimingFunctionVecValueVariant_Exact Exact;
+};
+typedef union AzStyleTimingFunctionVecValue AzStyleTimingFunctionVecValue;
+
+enum AzStyleAnimationIterationCountVecValueTag {
+   AzStyleAnimationIterationCountVecValueTag_Auto,
+   AzStyleAnimationIterationCountVecValueTag_None,
+   AzStyleAnimationIterationCountVecValueTag_Inherit,
+   AzStyleAnimationIterationCountVecValueTag_Initial,
+   AzStyleAnimationIterationCountVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationIterationCountVecValueTag AzStyleAnimationIterationCountVecValueTag;
+
+struct AzStyleAnimationIterationCountVecValueVariant_Auto { AzStyleAnimationIterationCountVecValueTag tag; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_Auto AzStyleAnimationIterationCountVecValueVariant_Auto;
+struct AzStyleAnimationIterationCountVecValueVariant_None { AzStyleAnimationIterationCountVecValueTag tag; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_None AzStyleAnimationIterationCountVecValueVariant_None;
+struct AzStyleAnimationIterationCountVecValueVariant_Inherit { AzStyleAnimationIterationCountVecValueTag tag; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_Inherit AzStyleAnimationIterationCountVecValueVariant_Inherit;
+struct AzStyleAnimationIterationCountVecValueVariant_Initial { AzStyleAnimationIterationCountVecValueTag tag; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_Initial AzStyleAnimationIterationCountVecValueVariant_Initial;
+struct AzStyleAnimationIterationCountVecValueVariant_Exact { AzStyleAnimationIterationCountVecValueTag tag; AzStyleAnimationIterationCountVec payload; };
+typedef struct AzStyleAnimationIterationCountVecValueVariant_Exact AzStyleAnimationIterationCountVecValueVariant_Exact;
+union AzStyleAnimationIterationCountVecValue {
+    AzStyleAnimationIterationCountVecValueVariant_Auto Auto;
+    AzStyleAnimationIterationCountVecValueVariant_None None;
+    AzStyleAnimationIterationCountVecValueVariant_Inherit Inherit;
+    AzStyleAnimationIterationCountVecValueVariant_Initial Initial;
+    AzStyleAnimationIterationCountVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationIterationCountVecValue AzStyleAnimationIterationCountVecValue;
+
+enum AzStyleAnimationDirectionVecValueTag {
+   AzStyleAnimationDirectionVecValueTag_Auto,
+   AzStyleAnimationDirectionVecValueTag_None,
+   AzStyleAnimationDirectionVecValueTag_Inherit,
+   AzStyleAnimationDirectionVecValueTag_Initial,
+   AzStyleAnimationDirectionVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationDirectionVecValueTag AzStyleAnimationDirectionVecValueTag;
+
+struct AzStyleAnimationDirectionVecValueVariant_Auto { AzStyleAnimationDirectionVecValueTag tag; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_Auto AzStyleAnimationDirectionVecValueVariant_Auto;
+struct AzStyleAnimationDirectionVecValueVariant_None { AzStyleAnimationDirectionVecValueTag tag; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_None AzStyleAnimationDirectionVecValueVariant_None;
+struct AzStyleAnimationDirectionVecValueVariant_Inherit { AzStyleAnimationDirectionVecValueTag tag; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_Inherit AzStyleAnimationDirectionVecValueVariant_Inherit;
+struct AzStyleAnimationDirectionVecValueVariant_Initial { AzStyleAnimationDirectionVecValueTag tag; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_Initial AzStyleAnimationDirectionVecValueVariant_Initial;
+struct AzStyleAnimationDirectionVecValueVariant_Exact { AzStyleAnimationDirectionVecValueTag tag; AzStyleAnimationDirectionVec payload; };
+typedef struct AzStyleAnimationDirectionVecValueVariant_Exact AzStyleAnimationDirectionVecValueVariant_Exact;
+union AzStyleAnimationDirectionVecValue {
+    AzStyleAnimationDirectionVecValueVariant_Auto Auto;
+    AzStyleAnimationDirectionVecValueVariant_None None;
+    AzStyleAnimationDirectionVecValueVariant_Inherit Inherit;
+    AzStyleAnimationDirectionVecValueVariant_Initial Initial;
+    AzStyleAnimationDirectionVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationDirectionVecValue AzStyleAnimationDirectionVecValue;
+
+enum AzStyleAnimationFillModeVecValueTag {
+   AzStyleAnimationFillModeVecValueTag_Auto,
+   AzStyleAnimationFillModeVecValueTag_None,
+   AzStyleAnimationFillModeVecValueTag_Inherit,
+   AzStyleAnimationFillModeVecValueTag_Initial,
+   AzStyleAnimationFillModeVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationFillModeVecValueTag AzStyleAnimationFillModeVecValueTag;
+
+struct AzStyleAnimationFillModeVecValueVariant_Auto { AzStyleAnimationFillModeVecValueTag tag; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_Auto AzStyleAnimationFillModeVecValueVariant_Auto;
+struct AzStyleAnimationFillModeVecValueVariant_None { AzStyleAnimationFillModeVecValueTag tag; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_None AzStyleAnimationFillModeVecValueVariant_None;
+struct AzStyleAnimationFillModeVecValueVariant_Inherit { AzStyleAnimationFillModeVecValueTag tag; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_Inherit AzStyleAnimationFillModeVecValueVariant_Inherit;
+struct AzStyleAnimationFillModeVecValueVariant_Initial { AzStyleAnimationFillModeVecValueTag tag; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_Initial AzStyleAnimationFillModeVecValueVariant_Initial;
+struct AzStyleAnimationFillModeVecValueVariant_Exact { AzStyleAnimationFillModeVecValueTag tag; AzStyleAnimationFillModeVec payload; };
+typedef struct AzStyleAnimationFillModeVecValueVariant_Exact AzStyleAnimationFillModeVecValueVariant_Exact;
+union AzStyleAnimationFillModeVecValue {
+    AzStyleAnimationFillModeVecValueVariant_Auto Auto;
+    AzStyleAnimationFillModeVecValueVariant_None None;
+    AzStyleAnimationFillModeVecValueVariant_Inherit Inherit;
+    AzStyleAnimationFillModeVecValueVariant_Initial Initial;
+    AzStyleAnimationFillModeVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationFillModeVecValue AzStyleAnimationFillModeVecValue;
+
+enum AzStyleAnimationPlayStateVecValueTag {
+   AzStyleAnimationPlayStateVecValueTag_Auto,
+   AzStyleAnimationPlayStateVecValueTag_None,
+   AzStyleAnimationPlayStateVecValueTag_Inherit,
+   AzStyleAnimationPlayStateVecValueTag_Initial,
+   AzStyleAnimationPlayStateVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationPlayStateVecValueTag AzStyleAnimationPlayStateVecValueTag;
+
+struct AzStyleAnimationPlayStateVecValueVariant_Auto { AzStyleAnimationPlayStateVecValueTag tag; };
+typedef struct AzStyleAnimationPlayStateVecValueVariant_Auto AzStyleAnimationPlayStateVecValueVariant_Auto;
+struct AzStyleAnimationPlayStateVecValueVariant_None { AzStyleAnimationPlayStateVecValueTag tag; };
+typedef struct AzStyleAnimationPlayStateVecValueVariant_None AzStyleAnimationPlayStateVecValueVariant_None;
+struct AzStyleAnimationPlayStateVecValueVariant_Inherit { AzStyleAnimationPlayStateVecValueTag tag; };
+typedef struct AzStyleAnimationPlayStateVecValueVariant_Inherit AzStyleAnimationPlayStateVecValueVariant_Inherit;
+struct AzStyleAnimationPlayStateVecValueVariant_Initial { AzStyleAnimationPlayStateVecValueTag tag; };
+typedef struct AzStyleAnimationPlayStateVecValueVariant_Initial AzStyleAnimationPlayStateVecValueVariant_Initial;
+struct AzStyleAnimationPlayStateVecValueVariant_Exact { AzStyleAnimationPlayStateVecValueTag tag; AzStyleAnimationPlayStateVec payload; };
+typedef struct AzStyleAnimationPlayStateVecValueVariant_Exact AzStyleAnimationPlayStateVecValueVariant_Exact;
+union AzStyleAnimationPlayStateVecValue {
+    AzStyleAnimationPlayStateVecValueVariant_Auto Auto;
+    AzStyleAnimationPlayStateVecValueVariant_None None;
+    AzStyleAnimationPlayStateVecValueVariant_Inherit Inherit;
+    AzStyleAnimationPlayStateVecValueVariant_Initial Initial;
+    AzStyleAnimationPlayStateVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationPlayStateVecValue AzStyleAnimationPlayStateVecValue;
+
 enum AzStyleBackgroundPositionVecValueTag {
    AzStyleBackgroundPositionVecValueTag_Auto,
    AzStyleBackgroundPositionVecValueTag_None,
@@ -8294,6 +10381,16 @@ struct AzCheckBoxStateWrapper {
 };
 typedef struct AzCheckBoxStateWrapper AzCheckBoxStateWrapper;
 
+struct AzTextAreaEdit {
+    size_t position;
+    AzU32Vec removed;
+    AzU32Vec inserted;
+    AzTextAreaEditKind kind;
+    size_t cursor_before;
+    AzOptionUsize selection_anchor_before;
+};
+typedef struct AzTextAreaEdit AzTextAreaEdit;
+
 struct AzNumberInputStateWrapper {
     AzNumberInputState inner;
     AzOptionNumberInputOnValueChange on_value_change;
@@ -8301,6 +10398,11 @@ struct AzNumberInputStateWrapper {
 };
 typedef struct AzNumberInputStateWrapper AzNumberInputStateWrapper;
 
+struct AzSplitterState {
+    AzSplitterPaneStateVec panes;
+};
+typedef struct AzSplitterState AzSplitterState;
+
 struct AzNodeGraphCallbacks {
     AzOptionNodeGraphOnNodeAdded on_node_added;
     AzOptionNodeGraphOnNodeRemoved on_node_removed;
@@ -8495,6 +10597,14 @@ struct AzListViewRowVec {
 };
 typedef struct AzListViewRowVec AzListViewRowVec;
 
+struct AzTextAreaEditVec {
+    AzTextAreaEdit* ptr;
+    size_t len;
+    size_t cap;
+    AzTextAreaEditVecDestructor destructor;
+};
+typedef struct AzTextAreaEditVec AzTextAreaEditVec;
+
 struct AzStyleFilterVec {
     AzStyleFilter* ptr;
     size_t len;
@@ -8543,6 +10653,14 @@ struct AzSvgPathElementVec {
 };
 typedef struct AzSvgPathElementVec AzSvgPathElementVec;
 
+struct AzCssMediaFeatureVec {
+    AzCssMediaFeature* ptr;
+    size_t len;
+    size_t cap;
+    AzCssMediaFeatureVecDestructor destructor;
+};
+typedef struct AzCssMediaFeatureVec AzCssMediaFeatureVec;
+
 struct AzStringVec {
     AzString* ptr;
     size_t len;
@@ -8631,22 +10749,6 @@ union AzOptionMouseState {
 };
 typedef union AzOptionMouseState AzOptionMouseState;
 
-enum AzOptionKeyboardStateTag {
-   AzOptionKeyboardStateTag_None,
-   AzOptionKeyboardStateTag_Some,
-};
-typedef enum AzOptionKeyboardStateTag AzOptionKeyboardStateTag;
-
-struct AzOptionKeyboardStateVariant_None { AzOptionKeyboardStateTag tag; };
-typedef struct AzOptionKeyboardStateVariant_None AzOptionKeyboardStateVariant_None;
-struct AzOptionKeyboardStateVariant_Some { AzOptionKeyboardStateTag tag; AzKeyboardState payload; };
-typedef struct AzOptionKeyboardStateVariant_Some AzOptionKeyboardStateVariant_Some;
-union AzOptionKeyboardState {
-    AzOptionKeyboardStateVariant_None None;
-    AzOptionKeyboardStateVariant_Some Some;
-};
-typedef union AzOptionKeyboardState AzOptionKeyboardState;
-
 enum AzOptionStringVecTag {
    AzOptionStringVecTag_None,
    AzOptionStringVecTag_Some,
@@ -8796,6 +10898,18 @@ struct AzInvalidStringError {
 };
 typedef struct AzInvalidStringError AzInvalidStringError;
 
+struct AzClipboardContent {
+    AzString mime_type;
+    AzU8Vec data;
+};
+typedef struct AzClipboardContent AzClipboardContent;
+
+struct AzImeComposition {
+    AzString text;
+    size_t cursor;
+};
+typedef struct AzImeComposition AzImeComposition;
+
 struct AzWindowsWindowOptions {
     bool  allow_drag_drop;
     bool  no_redirection_bitmap;
@@ -8959,6 +11073,22 @@ struct AzAccessibilityInfo {
 };
 typedef struct AzAccessibilityInfo AzAccessibilityInfo;
 
+enum AzTooltipContentTag {
+   AzTooltipContentTag_Text,
+   AzTooltipContentTag_Dom,
+};
+typedef enum AzTooltipContentTag AzTooltipContentTag;
+
+struct AzTooltipContentVariant_Text { AzTooltipContentTag tag; AzString payload; };
+typedef struct AzTooltipContentVariant_Text AzTooltipContentVariant_Text;
+struct AzTooltipContentVariant_Dom { AzTooltipContentTag tag; AzTooltipDom payload; };
+typedef struct AzTooltipContentVariant_Dom AzTooltipContentVariant_Dom;
+union AzTooltipContent {
+    AzTooltipContentVariant_Text Text;
+    AzTooltipContentVariant_Dom Dom;
+};
+typedef union AzTooltipContent AzTooltipContent;
+
 enum AzIdOrClassTag {
    AzIdOrClassTag_Id,
    AzIdOrClassTag_Class,
@@ -9021,6 +11151,13 @@ union AzCssPathSelector {
 };
 typedef union AzCssPathSelector AzCssPathSelector;
 
+struct AzCssMediaQuery {
+    bool  negated;
+    AzCssMediaType media_type;
+    AzCssMediaFeatureVec features;
+};
+typedef struct AzCssMediaQuery AzCssMediaQuery;
+
 enum AzStyleBackgroundContentTag {
    AzStyleBackgroundContentTag_LinearGradient,
    AzStyleBackgroundContentTag_RadialGradient,
@@ -9087,6 +11224,22 @@ union AzStyleFontFamily {
 };
 typedef union AzStyleFontFamily AzStyleFontFamily;
 
+enum AzStyleAnimationNameTag {
+   AzStyleAnimationNameTag_None,
+   AzStyleAnimationNameTag_Name,
+};
+typedef enum AzStyleAnimationNameTag AzStyleAnimationNameTag;
+
+struct AzStyleAnimationNameVariant_None { AzStyleAnimationNameTag tag; };
+typedef struct AzStyleAnimationNameVariant_None AzStyleAnimationNameVariant_None;
+struct AzStyleAnimationNameVariant_Name { AzStyleAnimationNameTag tag; AzString payload; };
+typedef struct AzStyleAnimationNameVariant_Name AzStyleAnimationNameVariant_Name;
+union AzStyleAnimationName {
+    AzStyleAnimationNameVariant_None None;
+    AzStyleAnimationNameVariant_Name Name;
+};
+typedef union AzStyleAnimationName AzStyleAnimationName;
+
 enum AzScrollbarStyleValueTag {
    AzScrollbarStyleValueTag_Auto,
    AzScrollbarStyleValueTag_None,
@@ -9171,6 +11324,19 @@ union AzStyleFilterVecValue {
 };
 typedef union AzStyleFilterVecValue AzStyleFilterVecValue;
 
+struct AzRibbonItem {
+    AzRibbonItemKind kind;
+    AzString label;
+    AzOptionImageRef icon;
+    AzOptionString tooltip;
+    AzOptionString key_tip;
+    bool  enabled;
+    bool  toggled;
+    AzStringVec choices;
+    AzOptionRibbonOnItemClick on_click;
+};
+typedef struct AzRibbonItem AzRibbonItem;
+
 struct AzFileInputState {
     AzOptionString path;
 };
@@ -9192,10 +11358,25 @@ struct AzTextInputState {
 };
 typedef struct AzTextInputState AzTextInputState;
 
+struct AzTextAreaState {
+    AzU32Vec text;
+    size_t cursor;
+    AzOptionUsize selection_anchor;
+    AzOptionF32 preferred_x;
+    float scroll_offset;
+    AzTextAreaEditVec undo_stack;
+    AzTextAreaEditVec redo_stack;
+    AzOptionInstant last_edit_time;
+};
+typedef struct AzTextAreaState AzTextAreaState;
+
 struct AzTabHeader {
     AzStringVec tabs;
     size_t active_tab;
     AzOptionTabOnClick on_click;
+    AzOptionTabOnClose on_close;
+    AzOptionTabOnReorder on_reorder;
+    AzOptionTabOnContextMenu on_context_menu;
 };
 typedef struct AzTabHeader AzTabHeader;
 
@@ -9251,6 +11432,11 @@ struct AzListView {
     AzOptionListViewOnLazyLoadScroll on_lazy_load_scroll;
     AzOptionListViewOnColumnClick on_column_click;
     AzOptionListViewOnRowClick on_row_click;
+    size_t row_count;
+    AzPixelValueNoPercent row_height;
+    AzF32Vec column_widths;
+    AzOptionListViewRowProvider row_provider;
+    AzOptionListViewOnColumnResize on_column_resize;
 };
 typedef struct AzListView AzListView;
 
@@ -9261,17 +11447,34 @@ struct AzListViewState {
     AzPixelValueNoPercent scroll_offset;
     AzLogicalPosition current_scroll_position;
     AzLogicalSize current_content_height;
+    size_t row_count;
+    AzListViewRowRange visible_rows;
+    AzF32Vec column_widths;
 };
 typedef struct AzListViewState AzListViewState;
 
-struct AzTreeView {
-    AzString root;
+struct AzTreeViewNode {
+    AzTreeViewNodeId id;
+    AzOptionTreeViewNodeId parent;
+    AzString label;
+    AzOptionImageRef icon;
+    bool  has_children;
 };
-typedef struct AzTreeView AzTreeView;
+typedef struct AzTreeViewNode AzTreeViewNode;
+
+struct AzTreeViewState {
+    AzTreeViewNodeIdVec expanded;
+    AzTreeViewNodeIdVec selected;
+    AzOptionTreeViewNodeId focused;
+    AzOptionTreeViewNodeId selection_anchor;
+    AzOptionInstant last_click_time;
+};
+typedef struct AzTreeViewState AzTreeViewState;
 
 struct AzDropDown {
     AzStringVec choices;
     size_t selected;
+    AzU32Vec disabled_choices;
     AzOptionDropDownOnChoiceChange on_choice_change;
 };
 typedef struct AzDropDown AzDropDown;
@@ -9451,6 +11654,22 @@ struct AzFmtArg {
 };
 typedef struct AzFmtArg AzFmtArg;
 
+struct AzTreeViewNodeVec {
+    AzTreeViewNode* ptr;
+    size_t len;
+    size_t cap;
+    AzTreeViewNodeVecDestructor destructor;
+};
+typedef struct AzTreeViewNodeVec AzTreeViewNodeVec;
+
+struct AzRibbonItemVec {
+    AzRibbonItem* ptr;
+    size_t len;
+    size_t cap;
+    AzRibbonItemVecDestructor destructor;
+};
+typedef struct AzRibbonItemVec AzRibbonItemVec;
+
 struct AzStyleFontFamilyVec {
     AzStyleFontFamily* ptr;
     size_t len;
@@ -9499,6 +11718,14 @@ struct AzStyleBackgroundContentVec {
 };
 typedef struct AzStyleBackgroundContentVec AzStyleBackgroundContentVec;
 
+struct AzStyleAnimationNameVec {
+    AzStyleAnimationName* ptr;
+    size_t len;
+    size_t cap;
+    AzStyleAnimationNameVecDestructor destructor;
+};
+typedef struct AzStyleAnimationNameVec AzStyleAnimationNameVec;
+
 struct AzSvgPathVec {
     AzSvgPath* ptr;
     size_t len;
@@ -9523,6 +11750,14 @@ struct AzCssPathSelectorVec {
 };
 typedef struct AzCssPathSelectorVec AzCssPathSelectorVec;
 
+struct AzCssMediaQueryVec {
+    AzCssMediaQuery* ptr;
+    size_t len;
+    size_t cap;
+    AzCssMediaQueryVecDestructor destructor;
+};
+typedef struct AzCssMediaQueryVec AzCssMediaQueryVec;
+
 struct AzCallbackDataVec {
     AzCallbackData* ptr;
     size_t len;
@@ -9611,6 +11846,38 @@ union AzOptionWaylandTheme {
 };
 typedef union AzOptionWaylandTheme AzOptionWaylandTheme;
 
+enum AzOptionImeCompositionTag {
+   AzOptionImeCompositionTag_None,
+   AzOptionImeCompositionTag_Some,
+};
+typedef enum AzOptionImeCompositionTag AzOptionImeCompositionTag;
+
+struct AzOptionImeCompositionVariant_None { AzOptionImeCompositionTag tag; };
+typedef struct AzOptionImeCompositionVariant_None AzOptionImeCompositionVariant_None;
+struct AzOptionImeCompositionVariant_Some { AzOptionImeCompositionTag tag; AzImeComposition payload; };
+typedef struct AzOptionImeCompositionVariant_Some AzOptionImeCompositionVariant_Some;
+union AzOptionImeComposition {
+    AzOptionImeCompositionVariant_None None;
+    AzOptionImeCompositionVariant_Some Some;
+};
+typedef union AzOptionImeComposition AzOptionImeComposition;
+
+enum AzOptionClipboardContentTag {
+   AzOptionClipboardContentTag_None,
+   AzOptionClipboardContentTag_Some,
+};
+typedef enum AzOptionClipboardContentTag AzOptionClipboardContentTag;
+
+struct AzOptionClipboardContentVariant_None { AzOptionClipboardContentTag tag; };
+typedef struct AzOptionClipboardContentVariant_None AzOptionClipboardContentVariant_None;
+struct AzOptionClipboardContentVariant_Some { AzOptionClipboardContentTag tag; AzClipboardContent payload; };
+typedef struct AzOptionClipboardContentVariant_Some AzOptionClipboardContentVariant_Some;
+union AzOptionClipboardContent {
+    AzOptionClipboardContentVariant_None None;
+    AzOptionClipboardContentVariant_Some Some;
+};
+typedef union AzOptionClipboardContent AzOptionClipboardContent;
+
 enum AzResultRawImageDecodeImageErrorTag {
    AzResultRawImageDecodeImageErrorTag_Ok,
    AzResultRawImageDecodeImageErrorTag_Err,
@@ -9687,7 +11954,17 @@ union AzXmlStreamError {
 };
 typedef union AzXmlStreamError AzXmlStreamError;
 
+struct AzKeyboardState {
+    AzOptionChar current_char;
+    AzOptionVirtualKeyCode current_virtual_keycode;
+    AzVirtualKeyCodeVec pressed_virtual_keycodes;
+    AzScanCodeVec pressed_scancodes;
+    AzOptionImeComposition ime_composition;
+};
+typedef struct AzKeyboardState AzKeyboardState;
+
 struct AzLinuxWindowOptions {
+    AzLinuxBackend backend;
     AzOptionX11Visual x11_visual;
     AzOptionI32 x11_screen;
     AzStringPairVec x11_wm_classes;
@@ -9709,6 +11986,12 @@ struct AzInlineLine {
 };
 typedef struct AzInlineLine AzInlineLine;
 
+struct AzTooltip {
+    AzTooltipContent content;
+    AzDuration delay;
+};
+typedef struct AzTooltip AzTooltip;
+
 enum AzMenuItemTag {
    AzMenuItemTag_String,
    AzMenuItemTag_Separator,
@@ -9734,6 +12017,34 @@ struct AzCssPath {
 };
 typedef struct AzCssPath AzCssPath;
 
+enum AzStyleAnimationNameVecValueTag {
+   AzStyleAnimationNameVecValueTag_Auto,
+   AzStyleAnimationNameVecValueTag_None,
+   AzStyleAnimationNameVecValueTag_Inherit,
+   AzStyleAnimationNameVecValueTag_Initial,
+   AzStyleAnimationNameVecValueTag_Exact,
+};
+typedef enum AzStyleAnimationNameVecValueTag AzStyleAnimationNameVecValueTag;
+
+struct AzStyleAnimationNameVecValueVariant_Auto { AzStyleAnimationNameVecValueTag tag; };
+typedef struct AzStyleAnimationNameVecValueVariant_Auto AzStyleAnimationNameVecValueVariant_Auto;
+struct AzStyleAnimationNameVecValueVariant_None { AzStyleAnimationNameVecValueTag tag; };
+typedef struct AzStyleAnimationNameVecValueVariant_None AzStyleAnimationNameVecValueVariant_None;
+struct AzStyleAnimationNameVecValueVariant_Inherit { AzStyleAnimationNameVecValueTag tag; };
+typedef struct AzStyleAnimationNameVecValueVariant_Inherit AzStyleAnimationNameVecValueVariant_Inherit;
+struct AzStyleAnimationNameVecValueVariant_Initial { AzStyleAnimationNameVecValueTag tag; };
+typedef struct AzStyleAnimationNameVecValueVariant_Initial AzStyleAnimationNameVecValueVariant_Initial;
+struct AzStyleAnimationNameVecValueVariant_Exact { AzStyleAnimationNameVecValueTag tag; AzStyleAnimationNameVec payload; };
+typedef struct AzStyleAnimationNameVecValueVariant_Exact AzStyleAnimationNameVecValueVariant_Exact;
+union AzStyleAnimationNameVecValue {
+    AzStyleAnimationNameVecValueVariant_Auto Auto;
+    AzStyleAnimationNameVecValueVariant_None None;
+    AzStyleAnimationNameVecValueVariant_Inherit Inherit;
+    AzStyleAnimationNameVecValueVariant_Initial Initial;
+    AzStyleAnimationNameVecValueVariant_Exact Exact;
+};
+typedef union AzStyleAnimationNameVecValue AzStyleAnimationNameVecValue;
+
 enum AzStyleBackgroundContentVecValueTag {
    AzStyleBackgroundContentVecValueTag_Auto,
    AzStyleBackgroundContentVecValueTag_None,
@@ -9795,6 +12106,10 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_FontSize,
    AzCssPropertyTag_FontFamily,
    AzCssPropertyTag_TextAlign,
+   AzCssPropertyTag_Direction,
+   AzCssPropertyTag_WordBreak,
+   AzCssPropertyTag_OverflowWrap,
+   AzCssPropertyTag_Hyphens,
    AzCssPropertyTag_LetterSpacing,
    AzCssPropertyTag_LineHeight,
    AzCssPropertyTag_WordSpacing,
@@ -9821,6 +12136,15 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_JustifyContent,
    AzCssPropertyTag_AlignItems,
    AzCssPropertyTag_AlignContent,
+   AzCssPropertyTag_GridTemplateColumns,
+   AzCssPropertyTag_GridTemplateRows,
+   AzCssPropertyTag_GridAutoColumns,
+   AzCssPropertyTag_GridAutoRows,
+   AzCssPropertyTag_GridAutoFlow,
+   AzCssPropertyTag_GridColumn,
+   AzCssPropertyTag_GridRow,
+   AzCssPropertyTag_RowGap,
+   AzCssPropertyTag_ColumnGap,
    AzCssPropertyTag_BackgroundContent,
    AzCssPropertyTag_BackgroundPosition,
    AzCssPropertyTag_BackgroundSize,
@@ -9865,6 +12189,18 @@ enum AzCssPropertyTag {
    AzCssPropertyTag_Filter,
    AzCssPropertyTag_BackdropFilter,
    AzCssPropertyTag_TextShadow,
+   AzCssPropertyTag_TransitionProperty,
+   AzCssPropertyTag_TransitionDuration,
+   AzCssPropertyTag_TransitionTimingFunction,
+   AzCssPropertyTag_TransitionDelay,
+   AzCssPropertyTag_AnimationName,
+   AzCssPropertyTag_AnimationDuration,
+   AzCssPropertyTag_AnimationTimingFunction,
+   AzCssPropertyTag_AnimationDelay,
+   AzCssPropertyTag_AnimationIterationCount,
+   AzCssPropertyTag_AnimationDirection,
+   AzCssPropertyTag_AnimationFillMode,
+   AzCssPropertyTag_AnimationPlayState,
 };
 typedef enum AzCssPropertyTag AzCssPropertyTag;
 
@@ -9876,6 +12212,14 @@ struct AzCssPropertyVariant_FontFamily { AzCssPropertyTag tag; AzStyleFontFamily
 typedef struct AzCssPropertyVariant_FontFamily AzCssPropertyVariant_FontFamily;
 struct AzCssPropertyVariant_TextAlign { AzCssPropertyTag tag; AzStyleTextAlignValue payload; };
 typedef struct AzCssPropertyVariant_TextAlign AzCssPropertyVariant_TextAlign;
+struct AzCssPropertyVariant_Direction { AzCssPropertyTag tag; AzStyleDirectionValue payload; };
+typedef struct AzCssPropertyVariant_Direction AzCssPropertyVariant_Direction;
+struct AzCssPropertyVariant_WordBreak { AzCssPropertyTag tag; AzStyleWordBreakValue payload; };
+typedef struct AzCssPropertyVariant_WordBreak AzCssPropertyVariant_WordBreak;
+struct AzCssPropertyVariant_OverflowWrap { AzCssPropertyTag tag; AzStyleOverflowWrapValue payload; };
+typedef struct AzCssPropertyVariant_OverflowWrap AzCssPropertyVariant_OverflowWrap;
+struct AzCssPropertyVariant_Hyphens { AzCssPropertyTag tag; AzStyleHyphensValue payload; };
+typedef struct AzCssPropertyVariant_Hyphens AzCssPropertyVariant_Hyphens;
 struct AzCssPropertyVariant_LetterSpacing { AzCssPropertyTag tag; AzStyleLetterSpacingValue payload; };
 typedef struct AzCssPropertyVariant_LetterSpacing AzCssPropertyVariant_LetterSpacing;
 struct AzCssPropertyVariant_LineHeight { AzCssPropertyTag tag; AzStyleLineHeightValue payload; };
@@ -9928,6 +12272,24 @@ struct AzCssPropertyVariant_AlignItems { AzCssPropertyTag tag; AzLayoutAlignItem
 typedef struct AzCssPropertyVariant_AlignItems AzCssPropertyVariant_AlignItems;
 struct AzCssPropertyVariant_AlignContent { AzCssPropertyTag tag; AzLayoutAlignContentValue payload; };
 typedef struct AzCssPropertyVariant_AlignContent AzCssPropertyVariant_AlignContent;
+struct AzCssPropertyVariant_GridTemplateColumns { AzCssPropertyTag tag; AzGridTrackSizingVecValue payload; };
+typedef struct AzCssPropertyVariant_GridTemplateColumns AzCssPropertyVariant_GridTemplateColumns;
+struct AzCssPropertyVariant_GridTemplateRows { AzCssPropertyTag tag; AzGridTrackSizingVecValue payload; };
+typedef struct AzCssPropertyVariant_GridTemplateRows AzCssPropertyVariant_GridTemplateRows;
+struct AzCssPropertyVariant_GridAutoColumns { AzCssPropertyTag tag; AzGridTrackSizingValue payload; };
+typedef struct AzCssPropertyVariant_GridAutoColumns AzCssPropertyVariant_GridAutoColumns;
+struct AzCssPropertyVariant_GridAutoRows { AzCssPropertyTag tag; AzGridTrackSizingValue payload; };
+typedef struct AzCssPropertyVariant_GridAutoRows AzCssPropertyVariant_GridAutoRows;
+struct AzCssPropertyVariant_GridAutoFlow { AzCssPropertyTag tag; AzLayoutGridAutoFlowValue payload; };
+typedef struct AzCssPropertyVariant_GridAutoFlow AzCssPropertyVariant_GridAutoFlow;
+struct AzCssPropertyVariant_GridColumn { AzCssPropertyTag tag; AzGridPlacementValue payload; };
+typedef struct AzCssPropertyVariant_GridColumn AzCssPropertyVariant_GridColumn;
+struct AzCssPropertyVariant_GridRow { AzCssPropertyTag tag; AzGridPlacementValue payload; };
+typedef struct AzCssPropertyVariant_GridRow AzCssPropertyVariant_GridRow;
+struct AzCssPropertyVariant_RowGap { AzCssPropertyTag tag; AzLayoutRowGapValue payload; };
+typedef struct AzCssPropertyVariant_RowGap AzCssPropertyVariant_RowGap;
+struct AzCssPropertyVariant_ColumnGap { AzCssPropertyTag tag; AzLayoutColumnGapValue payload; };
+typedef struct AzCssPropertyVariant_ColumnGap AzCssPropertyVariant_ColumnGap;
 struct AzCssPropertyVariant_BackgroundContent { AzCssPropertyTag tag; AzStyleBackgroundContentVecValue payload; };
 typedef struct AzCssPropertyVariant_BackgroundContent AzCssPropertyVariant_BackgroundContent;
 struct AzCssPropertyVariant_BackgroundPosition { AzCssPropertyTag tag; AzStyleBackgroundPositionVecValue payload; };
@@ -10016,11 +12378,39 @@ struct AzCssPropertyVariant_BackdropFilter { AzCssPropertyTag tag; AzStyleFilter
 typedef struct AzCssPropertyVariant_BackdropFilter AzCssPropertyVariant_BackdropFilter;
 struct AzCssPropertyVariant_TextShadow { AzCssPropertyTag tag; AzStyleBoxShadowValue payload; };
 typedef struct AzCssPropertyVariant_TextShadow AzCssPropertyVariant_TextShadow;
+struct AzCssPropertyVariant_TransitionProperty { AzCssPropertyTag tag; AzStyleTransitionPropertyVecValue payload; };
+typedef struct AzCssPropertyVariant_TransitionProperty AzCssPropertyVariant_TransitionProperty;
+struct AzCssPropertyVariant_TransitionDuration { AzCssPropertyTag tag; AzStyleTimeVecValue payload; };
+typedef struct AzCssPropertyVariant_TransitionDuration AzCssPropertyVariant_TransitionDuration;
+struct AzCssPropertyVariant_TransitionTimingFunction { AzCssPropertyTag tag; AzStyleTimingFunctionVecValue payload; };
+typedef struct AzCssPropertyVariant_TransitionTimingFunction AzCssPropertyVariant_TransitionTimingFunction;
+struct AzCssPropertyVariant_TransitionDelay { AzCssPropertyTag tag; AzStyleTimeVecValue payload; };
+typedef struct AzCssPropertyVariant_TransitionDelay AzCssPropertyVariant_TransitionDelay;
+struct AzCssPropertyVariant_AnimationName { AzCssPropertyTag tag; AzStyleAnimationNameVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationName AzCssPropertyVariant_AnimationName;
+struct AzCssPropertyVariant_AnimationDuration { AzCssPropertyTag tag; AzStyleTimeVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationDuration AzCssPropertyVariant_AnimationDuration;
+struct AzCssPropertyVariant_AnimationTimingFunction { AzCssPropertyTag tag; AzStyleTimingFunctionVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationTimingFunction AzCssPropertyVariant_AnimationTimingFunction;
+struct AzCssPropertyVariant_AnimationDelay { AzCssPropertyTag tag; AzStyleTimeVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationDelay AzCssPropertyVariant_AnimationDelay;
+struct AzCssPropertyVariant_AnimationIterationCount { AzCssPropertyTag tag; AzStyleAnimationIterationCountVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationIterationCount AzCssPropertyVariant_AnimationIterationCount;
+struct AzCssPropertyVariant_AnimationDirection { AzCssPropertyTag tag; AzStyleAnimationDirectionVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationDirection AzCssPropertyVariant_AnimationDirection;
+struct AzCssPropertyVariant_AnimationFillMode { AzCssPropertyTag tag; AzStyleAnimationFillModeVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationFillMode AzCssPropertyVariant_AnimationFillMode;
+struct AzCssPropertyVariant_AnimationPlayState { AzCssPropertyTag tag; AzStyleAnimationPlayStateVecValue payload; };
+typedef struct AzCssPropertyVariant_AnimationPlayState AzCssPropertyVariant_AnimationPlayState;
 union AzCssProperty {
     AzCssPropertyVariant_TextColor TextColor;
     AzCssPropertyVariant_FontSize FontSize;
     AzCssPropertyVariant_FontFamily FontFamily;
     AzCssPropertyVariant_TextAlign TextAlign;
+    AzCssPropertyVariant_Direction Direction;
+    AzCssPropertyVariant_WordBreak WordBreak;
+    AzCssPropertyVariant_OverflowWrap OverflowWrap;
+    AzCssPropertyVariant_Hyphens Hyphens;
     AzCssPropertyVariant_LetterSpacing LetterSpacing;
     AzCssPropertyVariant_LineHeight LineHeight;
     AzCssPropertyVariant_WordSpacing WordSpacing;
@@ -10047,6 +12437,15 @@ union AzCssProperty {
     AzCssPropertyVariant_JustifyContent JustifyContent;
     AzCssPropertyVariant_AlignItems AlignItems;
     AzCssPropertyVariant_AlignContent AlignContent;
+    AzCssPropertyVariant_GridTemplateColumns GridTemplateColumns;
+    AzCssPropertyVariant_GridTemplateRows GridTemplateRows;
+    AzCssPropertyVariant_GridAutoColumns GridAutoColumns;
+    AzCssPropertyVariant_GridAutoRows GridAutoRows;
+    AzCssPropertyVariant_GridAutoFlow GridAutoFlow;
+    AzCssPropertyVariant_GridColumn GridColumn;
+    AzCssPropertyVariant_GridRow GridRow;
+    AzCssPropertyVariant_RowGap RowGap;
+    AzCssPropertyVariant_ColumnGap ColumnGap;
     AzCssPropertyVariant_BackgroundContent BackgroundContent;
     AzCssPropertyVariant_BackgroundPosition BackgroundPosition;
     AzCssPropertyVariant_BackgroundSize BackgroundSize;
@@ -10091,9 +12490,28 @@ union AzCssProperty {
     AzCssPropertyVariant_Filter Filter;
     AzCssPropertyVariant_BackdropFilter BackdropFilter;
     AzCssPropertyVariant_TextShadow TextShadow;
+    AzCssPropertyVariant_TransitionProperty TransitionProperty;
+    AzCssPropertyVariant_TransitionDuration TransitionDuration;
+    AzCssPropertyVariant_TransitionTimingFunction TransitionTimingFunction;
+    AzCssPropertyVariant_TransitionDelay TransitionDelay;
+    AzCssPropertyVariant_AnimationName AnimationName;
+    AzCssPropertyVariant_AnimationDuration AnimationDuration;
+    AzCssPropertyVariant_AnimationTimingFunction AnimationTimingFunction;
+    AzCssPropertyVariant_AnimationDelay AnimationDelay;
+    AzCssPropertyVariant_AnimationIterationCount AnimationIterationCount;
+    AzCssPropertyVariant_AnimationDirection AnimationDirection;
+    AzCssPropertyVariant_AnimationFillMode AnimationFillMode;
+    AzCssPropertyVariant_AnimationPlayState AnimationPlayState;
 };
 typedef union AzCssProperty AzCssProperty;
 
+struct AzRibbonGroup {
+    AzString label;
+    AzOptionImageRef icon;
+    AzRibbonItemVec items;
+};
+typedef struct AzRibbonGroup AzRibbonGroup;
+
 struct AzFileInputStateWrapper {
     AzFileInputState inner;
     AzOptionFileInputOnPathChange on_file_path_change;
@@ -10114,11 +12532,19 @@ struct AzTextInputStateWrapper {
 };
 typedef struct AzTextInputStateWrapper AzTextInputStateWrapper;
 
+struct AzTextArea {
+    AzTextAreaState state;
+    AzOptionTextAreaOnTextChange on_text_change;
+};
+typedef struct AzTextArea AzTextArea;
+
 struct AzProgressBar {
     AzProgressBarState state;
     AzPixelValue height;
     AzStyleBackgroundContentVec bar_background;
     AzStyleBackgroundContentVec container_background;
+    AzProgressBarStyle style;
+    AzOptionString text_format;
 };
 typedef struct AzProgressBar AzProgressBar;
 
@@ -10140,6 +12566,17 @@ struct AzNodeTypeField {
 };
 typedef struct AzNodeTypeField AzNodeTypeField;
 
+struct AzTreeView {
+    AzTreeViewNodeVec nodes;
+    AzTreeViewState state;
+    AzTreeViewSelectionMode selection_mode;
+    AzOptionTreeViewOnExpand on_expand;
+    AzOptionTreeViewOnSelect on_select;
+    AzOptionTreeViewOnDoubleClick on_double_click;
+    AzOptionTreeViewOnContextMenu on_context_menu;
+};
+typedef struct AzTreeView AzTreeView;
+
 enum AzCssPropertySourceTag {
    AzCssPropertySourceTag_Css,
    AzCssPropertySourceTag_Inline,
@@ -10200,6 +12637,14 @@ struct AzXmlNode {
 };
 typedef struct AzXmlNode AzXmlNode;
 
+struct AzRibbonGroupVec {
+    AzRibbonGroup* ptr;
+    size_t len;
+    size_t cap;
+    AzRibbonGroupVecDestructor destructor;
+};
+typedef struct AzRibbonGroupVec AzRibbonGroupVec;
+
 struct AzNodeTypeIdInfoMapVec {
     AzNodeTypeIdInfoMap* ptr;
     size_t len;
@@ -10264,6 +12709,22 @@ union AzOptionCssProperty {
 };
 typedef union AzOptionCssProperty AzOptionCssProperty;
 
+enum AzOptionKeyboardStateTag {
+   AzOptionKeyboardStateTag_None,
+   AzOptionKeyboardStateTag_Some,
+};
+typedef enum AzOptionKeyboardStateTag AzOptionKeyboardStateTag;
+
+struct AzOptionKeyboardStateVariant_None { AzOptionKeyboardStateTag tag; };
+typedef struct AzOptionKeyboardStateVariant_None AzOptionKeyboardStateVariant_None;
+struct AzOptionKeyboardStateVariant_Some { AzOptionKeyboardStateTag tag; AzKeyboardState payload; };
+typedef struct AzOptionKeyboardStateVariant_Some AzOptionKeyboardStateVariant_Some;
+union AzOptionKeyboardState {
+    AzOptionKeyboardStateVariant_None None;
+    AzOptionKeyboardStateVariant_Some Some;
+};
+typedef union AzOptionKeyboardState AzOptionKeyboardState;
+
 struct AzXmlTextError {
     AzXmlStreamError stream_error;
     AzSvgParseErrorPosition pos;
@@ -10398,6 +12859,19 @@ struct AzDynamicCssProperty {
 };
 typedef struct AzDynamicCssProperty AzDynamicCssProperty;
 
+struct AzCssKeyframe {
+    AzPercentageValue offset;
+    AzCssPropertyVec properties;
+};
+typedef struct AzCssKeyframe AzCssKeyframe;
+
+struct AzRibbonTab {
+    AzString label;
+    AzOptionString key_tip;
+    AzRibbonGroupVec groups;
+};
+typedef struct AzRibbonTab AzRibbonTab;
+
 struct AzNode {
     AzNodeTypeId node_type;
     AzNodePosition position;
@@ -10441,14 +12915,30 @@ struct AzSvgStyledNode {
 };
 typedef struct AzSvgStyledNode AzSvgStyledNode;
 
-struct AzNodeDataInlineCssPropertyVec {
-    AzNodeDataInlineCssProperty* ptr;
+struct AzRibbonTabVec {
+    AzRibbonTab* ptr;
+    size_t len;
+    size_t cap;
+    AzRibbonTabVecDestructor destructor;
+};
+typedef struct AzRibbonTabVec AzRibbonTabVec;
+
+struct AzNodeDataInlineCssPropertyVec {
+    AzNodeDataInlineCssProperty* ptr;
     size_t len;
     size_t cap;
     AzNodeDataInlineCssPropertyVecDestructor destructor;
 };
 typedef struct AzNodeDataInlineCssPropertyVec AzNodeDataInlineCssPropertyVec;
 
+struct AzCssKeyframeVec {
+    AzCssKeyframe* ptr;
+    size_t len;
+    size_t cap;
+    AzCssKeyframeVecDestructor destructor;
+};
+typedef struct AzCssKeyframeVec AzCssKeyframeVec;
+
 enum AzOptionWindowStateTag {
    AzOptionWindowStateTag_None,
    AzOptionWindowStateTag_Some,
@@ -10602,6 +13092,20 @@ union AzCssDeclaration {
 };
 typedef union AzCssDeclaration AzCssDeclaration;
 
+struct AzCssKeyframes {
+    AzString name;
+    AzCssKeyframeVec frames;
+};
+typedef struct AzCssKeyframes AzCssKeyframes;
+
+struct AzRibbon {
+    AzRibbonTabVec tabs;
+    int32_t tab_active;
+    AzOptionF32 available_width;
+    AzOptionRibbonOnTabClicked on_tab_clicked;
+};
+typedef struct AzRibbon AzRibbon;
+
 struct AzButton {
     AzString label;
     AzOptionImageRef image;
@@ -10655,6 +13159,34 @@ struct AzNumberInput {
 };
 typedef struct AzNumberInput AzNumberInput;
 
+struct AzSlider {
+    AzSliderOrientation orientation;
+    AzSliderState state;
+    float tick_interval;
+    AzOptionSliderOnValueChange on_value_change;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec track_style;
+    AzNodeDataInlineCssPropertyVec fill_style;
+    AzNodeDataInlineCssPropertyVec rail_style;
+    AzNodeDataInlineCssPropertyVec thumb_style;
+    AzNodeDataInlineCssPropertyVec tick_style;
+};
+typedef struct AzSlider AzSlider;
+
+struct AzRangeSlider {
+    AzSliderOrientation orientation;
+    AzRangeSliderState state;
+    float tick_interval;
+    AzOptionRangeSliderOnValueChange on_value_change;
+    AzNodeDataInlineCssPropertyVec container_style;
+    AzNodeDataInlineCssPropertyVec track_style;
+    AzNodeDataInlineCssPropertyVec fill_style;
+    AzNodeDataInlineCssPropertyVec rail_style;
+    AzNodeDataInlineCssPropertyVec thumb_style;
+    AzNodeDataInlineCssPropertyVec tick_style;
+};
+typedef struct AzRangeSlider AzRangeSlider;
+
 struct AzNodeIdNodeMap {
     AzNodeGraphNodeId node_id;
     AzNode node;
@@ -10677,6 +13209,14 @@ struct AzCssDeclarationVec {
 };
 typedef struct AzCssDeclarationVec AzCssDeclarationVec;
 
+struct AzCssKeyframesVec {
+    AzCssKeyframes* ptr;
+    size_t len;
+    size_t cap;
+    AzCssKeyframesVecDestructor destructor;
+};
+typedef struct AzCssKeyframesVec AzCssKeyframesVec;
+
 struct AzNodeDataVec {
     AzNodeData* ptr;
     size_t len;
@@ -10787,6 +13327,15 @@ struct AzFrame {
 };
 typedef struct AzFrame AzFrame;
 
+struct AzSplitterPane {
+    AzDom content;
+    AzOptionF32 size;
+    float min_size;
+    AzOptionF32 max_size;
+    bool  collapsible;
+};
+typedef struct AzSplitterPane AzSplitterPane;
+
 struct AzNodeGraph {
     AzNodeTypeIdInfoMapVec node_types;
     AzInputOutputTypeIdInfoMapVec input_output_types;
@@ -10815,6 +13364,14 @@ struct AzStyledDom {
 };
 typedef struct AzStyledDom AzStyledDom;
 
+struct AzSplitterPaneVec {
+    AzSplitterPane* ptr;
+    size_t len;
+    size_t cap;
+    AzSplitterPaneVecDestructor destructor;
+};
+typedef struct AzSplitterPaneVec AzSplitterPaneVec;
+
 struct AzCssRuleBlockVec {
     AzCssRuleBlock* ptr;
     size_t len;
@@ -10896,18 +13453,28 @@ struct AzIFrameCallbackReturn {
 };
 typedef struct AzIFrameCallbackReturn AzIFrameCallbackReturn;
 
-struct AzStylesheet {
+struct AzCssMediaRule {
+    AzCssMediaQueryVec queries;
+    size_t insert_index;
     AzCssRuleBlockVec rules;
 };
-typedef struct AzStylesheet AzStylesheet;
+typedef struct AzCssMediaRule AzCssMediaRule;
 
-struct AzStylesheetVec {
-    AzStylesheet* ptr;
+struct AzSplitter {
+    AzSplitterOrientation orientation;
+    AzSplitterPaneVec panes;
+    AzSplitterState state;
+    AzOptionSplitterOnResize on_resize;
+};
+typedef struct AzSplitter AzSplitter;
+
+struct AzCssMediaRuleVec {
+    AzCssMediaRule* ptr;
     size_t len;
     size_t cap;
-    AzStylesheetVecDestructor destructor;
+    AzCssMediaRuleVecDestructor destructor;
 };
-typedef struct AzStylesheetVec AzStylesheetVec;
+typedef struct AzCssMediaRuleVec AzCssMediaRuleVec;
 
 enum AzResultSvgXmlNodeSvgParseErrorTag {
    AzResultSvgXmlNodeSvgParseErrorTag_Ok,
@@ -10941,6 +13508,21 @@ union AzResultSvgSvgParseError {
 };
 typedef union AzResultSvgSvgParseError AzResultSvgSvgParseError;
 
+struct AzStylesheet {
+    AzCssRuleBlockVec rules;
+    AzCssKeyframesVec keyframes;
+    AzCssMediaRuleVec media_rules;
+};
+typedef struct AzStylesheet AzStylesheet;
+
+struct AzStylesheetVec {
+    AzStylesheet* ptr;
+    size_t len;
+    size_t cap;
+    AzStylesheetVecDestructor destructor;
+};
+typedef struct AzStylesheetVec AzStylesheetVec;
+
 struct AzCss {
     AzStylesheetVec stylesheets;
 };
@@ -10951,6 +13533,9 @@ typedef struct AzCss AzCss;
 #define AzTabIndex_Auto { .Auto = { .tag = AzTabIndexTag_Auto } }
 #define AzTabIndex_OverrideInParent(v) { .OverrideInParent = { .tag = AzTabIndexTag_OverrideInParent, .payload = v } }
 #define AzTabIndex_NoKeyboardFocus { .NoKeyboardFocus = { .tag = AzTabIndexTag_NoKeyboardFocus } }
+#define AzGridLine_Auto { .Auto = { .tag = AzGridLineTag_Auto } }
+#define AzGridLine_Line(v) { .Line = { .tag = AzGridLineTag_Line, .payload = v } }
+#define AzGridLine_Span(v) { .Span = { .tag = AzGridLineTag_Span, .payload = v } }
 #define AzIndent_None { .None = { .tag = AzIndentTag_None } }
 #define AzIndent_Spaces(v) { .Spaces = { .tag = AzIndentTag_Spaces, .payload = v } }
 #define AzIndent_Tabs { .Tabs = { .tag = AzIndentTag_Tabs } }
@@ -10964,6 +13549,30 @@ typedef struct AzCss AzCss;
 #define AzListViewRowVecDestructor_DefaultRust { .DefaultRust = { .tag = AzListViewRowVecDestructorTag_DefaultRust } }
 #define AzListViewRowVecDestructor_NoDestructor { .NoDestructor = { .tag = AzListViewRowVecDestructorTag_NoDestructor } }
 #define AzListViewRowVecDestructor_External(v) { .External = { .tag = AzListViewRowVecDestructorTag_External, .payload = v } }
+#define AzTreeViewNodeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTreeViewNodeVecDestructorTag_DefaultRust } }
+#define AzTreeViewNodeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTreeViewNodeVecDestructorTag_NoDestructor } }
+#define AzTreeViewNodeVecDestructor_External(v) { .External = { .tag = AzTreeViewNodeVecDestructorTag_External, .payload = v } }
+#define AzTreeViewNodeIdVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTreeViewNodeIdVecDestructorTag_DefaultRust } }
+#define AzTreeViewNodeIdVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTreeViewNodeIdVecDestructorTag_NoDestructor } }
+#define AzTreeViewNodeIdVecDestructor_External(v) { .External = { .tag = AzTreeViewNodeIdVecDestructorTag_External, .payload = v } }
+#define AzRibbonTabVecDestructor_DefaultRust { .DefaultRust = { .tag = AzRibbonTabVecDestructorTag_DefaultRust } }
+#define AzRibbonTabVecDestructor_NoDestructor { .NoDestructor = { .tag = AzRibbonTabVecDestructorTag_NoDestructor } }
+#define AzRibbonTabVecDestructor_External(v) { .External = { .tag = AzRibbonTabVecDestructorTag_External, .payload = v } }
+#define AzRibbonGroupVecDestructor_DefaultRust { .DefaultRust = { .tag = AzRibbonGroupVecDestructorTag_DefaultRust } }
+#define AzRibbonGroupVecDestructor_NoDestructor { .NoDestructor = { .tag = AzRibbonGroupVecDestructorTag_NoDestructor } }
+#define AzRibbonGroupVecDestructor_External(v) { .External = { .tag = AzRibbonGroupVecDestructorTag_External, .payload = v } }
+#define AzRibbonItemVecDestructor_DefaultRust { .DefaultRust = { .tag = AzRibbonItemVecDestructorTag_DefaultRust } }
+#define AzRibbonItemVecDestructor_NoDestructor { .NoDestructor = { .tag = AzRibbonItemVecDestructorTag_NoDestructor } }
+#define AzRibbonItemVecDestructor_External(v) { .External = { .tag = AzRibbonItemVecDestructorTag_External, .payload = v } }
+#define AzSplitterPaneVecDestructor_DefaultRust { .DefaultRust = { .tag = AzSplitterPaneVecDestructorTag_DefaultRust } }
+#define AzSplitterPaneVecDestructor_NoDestructor { .NoDestructor = { .tag = AzSplitterPaneVecDestructorTag_NoDestructor } }
+#define AzSplitterPaneVecDestructor_External(v) { .External = { .tag = AzSplitterPaneVecDestructorTag_External, .payload = v } }
+#define AzSplitterPaneStateVecDestructor_DefaultRust { .DefaultRust = { .tag = AzSplitterPaneStateVecDestructorTag_DefaultRust } }
+#define AzSplitterPaneStateVecDestructor_NoDestructor { .NoDestructor = { .tag = AzSplitterPaneStateVecDestructorTag_NoDestructor } }
+#define AzSplitterPaneStateVecDestructor_External(v) { .External = { .tag = AzSplitterPaneStateVecDestructorTag_External, .payload = v } }
+#define AzTextAreaEditVecDestructor_DefaultRust { .DefaultRust = { .tag = AzTextAreaEditVecDestructorTag_DefaultRust } }
+#define AzTextAreaEditVecDestructor_NoDestructor { .NoDestructor = { .tag = AzTextAreaEditVecDestructorTag_NoDestructor } }
+#define AzTextAreaEditVecDestructor_External(v) { .External = { .tag = AzTextAreaEditVecDestructorTag_External, .payload = v } }
 #define AzStyleFilterVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleFilterVecDestructorTag_DefaultRust } }
 #define AzStyleFilterVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleFilterVecDestructorTag_NoDestructor } }
 #define AzStyleFilterVecDestructor_External(v) { .External = { .tag = AzStyleFilterVecDestructorTag_External, .payload = v } }
@@ -11000,6 +13609,9 @@ typedef struct AzCss AzCss;
 #define AzAccessibilityStateVecDestructor_DefaultRust { .DefaultRust = { .tag = AzAccessibilityStateVecDestructorTag_DefaultRust } }
 #define AzAccessibilityStateVecDestructor_NoDestructor { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor } }
 #define AzAccessibilityStateVecDestructor_External(v) { .External = { .tag = AzAccessibilityStateVecDestructorTag_External, .payload = v } }
+#define AzAccessibilityActionVecDestructor_DefaultRust { .DefaultRust = { .tag = AzAccessibilityActionVecDestructorTag_DefaultRust } }
+#define AzAccessibilityActionVecDestructor_NoDestructor { .NoDestructor = { .tag = AzAccessibilityActionVecDestructorTag_NoDestructor } }
+#define AzAccessibilityActionVecDestructor_External(v) { .External = { .tag = AzAccessibilityActionVecDestructorTag_External, .payload = v } }
 #define AzMenuItemVecDestructor_DefaultRust { .DefaultRust = { .tag = AzMenuItemVecDestructorTag_DefaultRust } }
 #define AzMenuItemVecDestructor_NoDestructor { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor } }
 #define AzMenuItemVecDestructor_External(v) { .External = { .tag = AzMenuItemVecDestructorTag_External, .payload = v } }
@@ -11054,6 +13666,33 @@ typedef struct AzCss AzCss;
 #define AzStyleTransformVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleTransformVecDestructorTag_DefaultRust } }
 #define AzStyleTransformVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleTransformVecDestructorTag_NoDestructor } }
 #define AzStyleTransformVecDestructor_External(v) { .External = { .tag = AzStyleTransformVecDestructorTag_External, .payload = v } }
+#define AzStyleTransitionPropertyVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleTransitionPropertyVecDestructorTag_DefaultRust } }
+#define AzStyleTransitionPropertyVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleTransitionPropertyVecDestructorTag_NoDestructor } }
+#define AzStyleTransitionPropertyVecDestructor_External(v) { .External = { .tag = AzStyleTransitionPropertyVecDestructorTag_External, .payload = v } }
+#define AzStyleTimeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleTimeVecDestructorTag_DefaultRust } }
+#define AzStyleTimeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleTimeVecDestructorTag_NoDestructor } }
+#define AzStyleTimeVecDestructor_External(v) { .External = { .tag = AzStyleTimeVecDestructorTag_External, .payload = v } }
+#define AzStyleTimingFunctionVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleTimingFunctionVecDestructorTag_DefaultRust } }
+#define AzStyleTimingFunctionVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleTimingFunctionVecDestructorTag_NoDestructor } }
+#define AzStyleTimingFunctionVecDestructor_External(v) { .External = { .tag = AzStyleTimingFunctionVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationNameVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationNameVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationNameVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationNameVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationNameVecDestructor_External(v) { .External = { .tag = AzStyleAnimationNameVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationIterationCountVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationIterationCountVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationIterationCountVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationIterationCountVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationIterationCountVecDestructor_External(v) { .External = { .tag = AzStyleAnimationIterationCountVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationDirectionVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationDirectionVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationDirectionVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationDirectionVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationDirectionVecDestructor_External(v) { .External = { .tag = AzStyleAnimationDirectionVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationFillModeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationFillModeVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationFillModeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationFillModeVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationFillModeVecDestructor_External(v) { .External = { .tag = AzStyleAnimationFillModeVecDestructorTag_External, .payload = v } }
+#define AzStyleAnimationPlayStateVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStyleAnimationPlayStateVecDestructorTag_DefaultRust } }
+#define AzStyleAnimationPlayStateVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStyleAnimationPlayStateVecDestructorTag_NoDestructor } }
+#define AzStyleAnimationPlayStateVecDestructor_External(v) { .External = { .tag = AzStyleAnimationPlayStateVecDestructorTag_External, .payload = v } }
+#define AzGridTrackSizingVecDestructor_DefaultRust { .DefaultRust = { .tag = AzGridTrackSizingVecDestructorTag_DefaultRust } }
+#define AzGridTrackSizingVecDestructor_NoDestructor { .NoDestructor = { .tag = AzGridTrackSizingVecDestructorTag_NoDestructor } }
+#define AzGridTrackSizingVecDestructor_External(v) { .External = { .tag = AzGridTrackSizingVecDestructorTag_External, .payload = v } }
 #define AzCssPropertyVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssPropertyVecDestructorTag_DefaultRust } }
 #define AzCssPropertyVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssPropertyVecDestructorTag_NoDestructor } }
 #define AzCssPropertyVecDestructor_External(v) { .External = { .tag = AzCssPropertyVecDestructorTag_External, .payload = v } }
@@ -11096,6 +13735,21 @@ typedef struct AzCss AzCss;
 #define AzStylesheetVecDestructor_DefaultRust { .DefaultRust = { .tag = AzStylesheetVecDestructorTag_DefaultRust } }
 #define AzStylesheetVecDestructor_NoDestructor { .NoDestructor = { .tag = AzStylesheetVecDestructorTag_NoDestructor } }
 #define AzStylesheetVecDestructor_External(v) { .External = { .tag = AzStylesheetVecDestructorTag_External, .payload = v } }
+#define AzCssKeyframesVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssKeyframesVecDestructorTag_DefaultRust } }
+#define AzCssKeyframesVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssKeyframesVecDestructorTag_NoDestructor } }
+#define AzCssKeyframesVecDestructor_External(v) { .External = { .tag = AzCssKeyframesVecDestructorTag_External, .payload = v } }
+#define AzCssKeyframeVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssKeyframeVecDestructorTag_DefaultRust } }
+#define AzCssKeyframeVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssKeyframeVecDestructorTag_NoDestructor } }
+#define AzCssKeyframeVecDestructor_External(v) { .External = { .tag = AzCssKeyframeVecDestructorTag_External, .payload = v } }
+#define AzCssMediaRuleVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssMediaRuleVecDestructorTag_DefaultRust } }
+#define AzCssMediaRuleVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssMediaRuleVecDestructorTag_NoDestructor } }
+#define AzCssMediaRuleVecDestructor_External(v) { .External = { .tag = AzCssMediaRuleVecDestructorTag_External, .payload = v } }
+#define AzCssMediaQueryVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssMediaQueryVecDestructorTag_DefaultRust } }
+#define AzCssMediaQueryVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssMediaQueryVecDestructorTag_NoDestructor } }
+#define AzCssMediaQueryVecDestructor_External(v) { .External = { .tag = AzCssMediaQueryVecDestructorTag_External, .payload = v } }
+#define AzCssMediaFeatureVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssMediaFeatureVecDestructorTag_DefaultRust } }
+#define AzCssMediaFeatureVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssMediaFeatureVecDestructorTag_NoDestructor } }
+#define AzCssMediaFeatureVecDestructor_External(v) { .External = { .tag = AzCssMediaFeatureVecDestructorTag_External, .payload = v } }
 #define AzCssRuleBlockVecDestructor_DefaultRust { .DefaultRust = { .tag = AzCssRuleBlockVecDestructorTag_DefaultRust } }
 #define AzCssRuleBlockVecDestructor_NoDestructor { .NoDestructor = { .tag = AzCssRuleBlockVecDestructorTag_NoDestructor } }
 #define AzCssRuleBlockVecDestructor_External(v) { .External = { .tag = AzCssRuleBlockVecDestructorTag_External, .payload = v } }
@@ -11207,6 +13861,11 @@ typedef struct AzCss AzCss;
 #define AzStyleCompositeFilter_Xor { .Xor = { .tag = AzStyleCompositeFilterTag_Xor } }
 #define AzStyleCompositeFilter_Lighter { .Lighter = { .tag = AzStyleCompositeFilterTag_Lighter } }
 #define AzStyleCompositeFilter_Arithmetic(v) { .Arithmetic = { .tag = AzStyleCompositeFilterTag_Arithmetic, .payload = v } }
+#define AzGridTrackBreadth_Fixed(v) { .Fixed = { .tag = AzGridTrackBreadthTag_Fixed, .payload = v } }
+#define AzGridTrackBreadth_Fraction(v) { .Fraction = { .tag = AzGridTrackBreadthTag_Fraction, .payload = v } }
+#define AzGridTrackBreadth_Auto { .Auto = { .tag = AzGridTrackBreadthTag_Auto } }
+#define AzGridTrackBreadth_MinContent { .MinContent = { .tag = AzGridTrackBreadthTag_MinContent } }
+#define AzGridTrackBreadth_MaxContent { .MaxContent = { .tag = AzGridTrackBreadthTag_MaxContent } }
 #define AzDirection_Angle(v) { .Angle = { .tag = AzDirectionTag_Angle, .payload = v } }
 #define AzDirection_FromTo(v) { .FromTo = { .tag = AzDirectionTag_FromTo, .payload = v } }
 #define AzBackgroundPositionHorizontal_Left { .Left = { .tag = AzBackgroundPositionHorizontalTag_Left } }
@@ -11220,6 +13879,18 @@ typedef struct AzCss AzCss;
 #define AzStyleBackgroundSize_ExactSize(v) { .ExactSize = { .tag = AzStyleBackgroundSizeTag_ExactSize, .payload = v } }
 #define AzStyleBackgroundSize_Contain { .Contain = { .tag = AzStyleBackgroundSizeTag_Contain } }
 #define AzStyleBackgroundSize_Cover { .Cover = { .tag = AzStyleBackgroundSizeTag_Cover } }
+#define AzStyleTimingFunction_Ease { .Ease = { .tag = AzStyleTimingFunctionTag_Ease } }
+#define AzStyleTimingFunction_Linear { .Linear = { .tag = AzStyleTimingFunctionTag_Linear } }
+#define AzStyleTimingFunction_EaseIn { .EaseIn = { .tag = AzStyleTimingFunctionTag_EaseIn } }
+#define AzStyleTimingFunction_EaseOut { .EaseOut = { .tag = AzStyleTimingFunctionTag_EaseOut } }
+#define AzStyleTimingFunction_EaseInOut { .EaseInOut = { .tag = AzStyleTimingFunctionTag_EaseInOut } }
+#define AzStyleTimingFunction_CubicBezier(v) { .CubicBezier = { .tag = AzStyleTimingFunctionTag_CubicBezier, .payload = v } }
+#define AzStyleTimingFunction_Steps(v) { .Steps = { .tag = AzStyleTimingFunctionTag_Steps, .payload = v } }
+#define AzStyleTransitionProperty_All { .All = { .tag = AzStyleTransitionPropertyTag_All } }
+#define AzStyleTransitionProperty_Property(v) { .Property = { .tag = AzStyleTransitionPropertyTag_Property, .payload = v } }
+#define AzStyleTransitionProperty_Shorthand(v) { .Shorthand = { .tag = AzStyleTransitionPropertyTag_Shorthand, .payload = v } }
+#define AzStyleAnimationIterationCount_Infinite { .Infinite = { .tag = AzStyleAnimationIterationCountTag_Infinite } }
+#define AzStyleAnimationIterationCount_Count(v) { .Count = { .tag = AzStyleAnimationIterationCountTag_Count, .payload = v } }
 #define AzStyleBoxShadowValue_Auto { .Auto = { .tag = AzStyleBoxShadowValueTag_Auto } }
 #define AzStyleBoxShadowValue_None { .None = { .tag = AzStyleBoxShadowValueTag_None } }
 #define AzStyleBoxShadowValue_Inherit { .Inherit = { .tag = AzStyleBoxShadowValueTag_Inherit } }
@@ -11230,6 +13901,31 @@ typedef struct AzCss AzCss;
 #define AzLayoutAlignContentValue_Inherit { .Inherit = { .tag = AzLayoutAlignContentValueTag_Inherit } }
 #define AzLayoutAlignContentValue_Initial { .Initial = { .tag = AzLayoutAlignContentValueTag_Initial } }
 #define AzLayoutAlignContentValue_Exact(v) { .Exact = { .tag = AzLayoutAlignContentValueTag_Exact, .payload = v } }
+#define AzGridTrackSizingValue_Auto { .Auto = { .tag = AzGridTrackSizingValueTag_Auto } }
+#define AzGridTrackSizingValue_None { .None = { .tag = AzGridTrackSizingValueTag_None } }
+#define AzGridTrackSizingValue_Inherit { .Inherit = { .tag = AzGridTrackSizingValueTag_Inherit } }
+#define AzGridTrackSizingValue_Initial { .Initial = { .tag = AzGridTrackSizingValueTag_Initial } }
+#define AzGridTrackSizingValue_Exact(v) { .Exact = { .tag = AzGridTrackSizingValueTag_Exact, .payload = v } }
+#define AzLayoutGridAutoFlowValue_Auto { .Auto = { .tag = AzLayoutGridAutoFlowValueTag_Auto } }
+#define AzLayoutGridAutoFlowValue_None { .None = { .tag = AzLayoutGridAutoFlowValueTag_None } }
+#define AzLayoutGridAutoFlowValue_Inherit { .Inherit = { .tag = AzLayoutGridAutoFlowValueTag_Inherit } }
+#define AzLayoutGridAutoFlowValue_Initial { .Initial = { .tag = AzLayoutGridAutoFlowValueTag_Initial } }
+#define AzLayoutGridAutoFlowValue_Exact(v) { .Exact = { .tag = AzLayoutGridAutoFlowValueTag_Exact, .payload = v } }
+#define AzGridPlacementValue_Auto { .Auto = { .tag = AzGridPlacementValueTag_Auto } }
+#define AzGridPlacementValue_None { .None = { .tag = AzGridPlacementValueTag_None } }
+#define AzGridPlacementValue_Inherit { .Inherit = { .tag = AzGridPlacementValueTag_Inherit } }
+#define AzGridPlacementValue_Initial { .Initial = { .tag = AzGridPlacementValueTag_Initial } }
+#define AzGridPlacementValue_Exact(v) { .Exact = { .tag = AzGridPlacementValueTag_Exact, .payload = v } }
+#define AzLayoutRowGapValue_Auto { .Auto = { .tag = AzLayoutRowGapValueTag_Auto } }
+#define AzLayoutRowGapValue_None { .None = { .tag = AzLayoutRowGapValueTag_None } }
+#define AzLayoutRowGapValue_Inherit { .Inherit = { .tag = AzLayoutRowGapValueTag_Inherit } }
+#define AzLayoutRowGapValue_Initial { .Initial = { .tag = AzLayoutRowGapValueTag_Initial } }
+#define AzLayoutRowGapValue_Exact(v) { .Exact = { .tag = AzLayoutRowGapValueTag_Exact, .payload = v } }
+#define AzLayoutColumnGapValue_Auto { .Auto = { .tag = AzLayoutColumnGapValueTag_Auto } }
+#define AzLayoutColumnGapValue_None { .None = { .tag = AzLayoutColumnGapValueTag_None } }
+#define AzLayoutColumnGapValue_Inherit { .Inherit = { .tag = AzLayoutColumnGapValueTag_Inherit } }
+#define AzLayoutColumnGapValue_Initial { .Initial = { .tag = AzLayoutColumnGapValueTag_Initial } }
+#define AzLayoutColumnGapValue_Exact(v) { .Exact = { .tag = AzLayoutColumnGapValueTag_Exact, .payload = v } }
 #define AzLayoutAlignItemsValue_Auto { .Auto = { .tag = AzLayoutAlignItemsValueTag_Auto } }
 #define AzLayoutAlignItemsValue_None { .None = { .tag = AzLayoutAlignItemsValueTag_None } }
 #define AzLayoutAlignItemsValue_Inherit { .Inherit = { .tag = AzLayoutAlignItemsValueTag_Inherit } }
@@ -11485,6 +14181,26 @@ typedef struct AzCss AzCss;
 #define AzStyleTextAlignValue_Inherit { .Inherit = { .tag = AzStyleTextAlignValueTag_Inherit } }
 #define AzStyleTextAlignValue_Initial { .Initial = { .tag = AzStyleTextAlignValueTag_Initial } }
 #define AzStyleTextAlignValue_Exact(v) { .Exact = { .tag = AzStyleTextAlignValueTag_Exact, .payload = v } }
+#define AzStyleDirectionValue_Auto { .Auto = { .tag = AzStyleDirectionValueTag_Auto } }
+#define AzStyleDirectionValue_None { .None = { .tag = AzStyleDirectionValueTag_None } }
+#define AzStyleDirectionValue_Inherit { .Inherit = { .tag = AzStyleDirectionValueTag_Inherit } }
+#define AzStyleDirectionValue_Initial { .Initial = { .tag = AzStyleDirectionValueTag_Initial } }
+#define AzStyleDirectionValue_Exact(v) { .Exact = { .tag = AzStyleDirectionValueTag_Exact, .payload = v } }
+#define AzStyleWordBreakValue_Auto { .Auto = { .tag = AzStyleWordBreakValueTag_Auto } }
+#define AzStyleWordBreakValue_None { .None = { .tag = AzStyleWordBreakValueTag_None } }
+#define AzStyleWordBreakValue_Inherit { .Inherit = { .tag = AzStyleWordBreakValueTag_Inherit } }
+#define AzStyleWordBreakValue_Initial { .Initial = { .tag = AzStyleWordBreakValueTag_Initial } }
+#define AzStyleWordBreakValue_Exact(v) { .Exact = { .tag = AzStyleWordBreakValueTag_Exact, .payload = v } }
+#define AzStyleOverflowWrapValue_Auto { .Auto = { .tag = AzStyleOverflowWrapValueTag_Auto } }
+#define AzStyleOverflowWrapValue_None { .None = { .tag = AzStyleOverflowWrapValueTag_None } }
+#define AzStyleOverflowWrapValue_Inherit { .Inherit = { .tag = AzStyleOverflowWrapValueTag_Inherit } }
+#define AzStyleOverflowWrapValue_Initial { .Initial = { .tag = AzStyleOverflowWrapValueTag_Initial } }
+#define AzStyleOverflowWrapValue_Exact(v) { .Exact = { .tag = AzStyleOverflowWrapValueTag_Exact, .payload = v } }
+#define AzStyleHyphensValue_Auto { .Auto = { .tag = AzStyleHyphensValueTag_Auto } }
+#define AzStyleHyphensValue_None { .None = { .tag = AzStyleHyphensValueTag_None } }
+#define AzStyleHyphensValue_Inherit { .Inherit = { .tag = AzStyleHyphensValueTag_Inherit } }
+#define AzStyleHyphensValue_Initial { .Initial = { .tag = AzStyleHyphensValueTag_Initial } }
+#define AzStyleHyphensValue_Exact(v) { .Exact = { .tag = AzStyleHyphensValueTag_Exact, .payload = v } }
 #define AzStyleTextColorValue_Auto { .Auto = { .tag = AzStyleTextColorValueTag_Auto } }
 #define AzStyleTextColorValue_None { .None = { .tag = AzStyleTextColorValueTag_None } }
 #define AzStyleTextColorValue_Inherit { .Inherit = { .tag = AzStyleTextColorValueTag_Inherit } }
@@ -11529,10 +14245,24 @@ typedef struct AzCss AzCss;
 #define AzThreadSendMsg_Custom(v) { .Custom = { .tag = AzThreadSendMsgTag_Custom, .payload = v } }
 #define AzOptionListViewOnRowClick_None { .None = { .tag = AzOptionListViewOnRowClickTag_None } }
 #define AzOptionListViewOnRowClick_Some(v) { .Some = { .tag = AzOptionListViewOnRowClickTag_Some, .payload = v } }
+#define AzOptionTreeViewNodeId_None { .None = { .tag = AzOptionTreeViewNodeIdTag_None } }
+#define AzOptionTreeViewNodeId_Some(v) { .Some = { .tag = AzOptionTreeViewNodeIdTag_Some, .payload = v } }
+#define AzOptionTreeViewOnExpand_None { .None = { .tag = AzOptionTreeViewOnExpandTag_None } }
+#define AzOptionTreeViewOnExpand_Some(v) { .Some = { .tag = AzOptionTreeViewOnExpandTag_Some, .payload = v } }
+#define AzOptionTreeViewOnSelect_None { .None = { .tag = AzOptionTreeViewOnSelectTag_None } }
+#define AzOptionTreeViewOnSelect_Some(v) { .Some = { .tag = AzOptionTreeViewOnSelectTag_Some, .payload = v } }
+#define AzOptionTreeViewOnDoubleClick_None { .None = { .tag = AzOptionTreeViewOnDoubleClickTag_None } }
+#define AzOptionTreeViewOnDoubleClick_Some(v) { .Some = { .tag = AzOptionTreeViewOnDoubleClickTag_Some, .payload = v } }
+#define AzOptionTreeViewOnContextMenu_None { .None = { .tag = AzOptionTreeViewOnContextMenuTag_None } }
+#define AzOptionTreeViewOnContextMenu_Some(v) { .Some = { .tag = AzOptionTreeViewOnContextMenuTag_Some, .payload = v } }
 #define AzOptionListViewOnColumnClick_None { .None = { .tag = AzOptionListViewOnColumnClickTag_None } }
 #define AzOptionListViewOnColumnClick_Some(v) { .Some = { .tag = AzOptionListViewOnColumnClickTag_Some, .payload = v } }
 #define AzOptionListViewOnLazyLoadScroll_None { .None = { .tag = AzOptionListViewOnLazyLoadScrollTag_None } }
 #define AzOptionListViewOnLazyLoadScroll_Some(v) { .Some = { .tag = AzOptionListViewOnLazyLoadScrollTag_Some, .payload = v } }
+#define AzOptionListViewOnColumnResize_None { .None = { .tag = AzOptionListViewOnColumnResizeTag_None } }
+#define AzOptionListViewOnColumnResize_Some(v) { .Some = { .tag = AzOptionListViewOnColumnResizeTag_Some, .payload = v } }
+#define AzOptionListViewRowProvider_None { .None = { .tag = AzOptionListViewRowProviderTag_None } }
+#define AzOptionListViewRowProvider_Some(v) { .Some = { .tag = AzOptionListViewRowProviderTag_Some, .payload = v } }
 #define AzOptionPixelValueNoPercent_None { .None = { .tag = AzOptionPixelValueNoPercentTag_None } }
 #define AzOptionPixelValueNoPercent_Some(v) { .Some = { .tag = AzOptionPixelValueNoPercentTag_Some, .payload = v } }
 #define AzOptionDropDownOnChoiceChange_None { .None = { .tag = AzOptionDropDownOnChoiceChangeTag_None } }
@@ -11559,6 +14289,22 @@ typedef struct AzCss AzCss;
 #define AzOptionButtonOnClick_Some(v) { .Some = { .tag = AzOptionButtonOnClickTag_Some, .payload = v } }
 #define AzOptionTabOnClick_None { .None = { .tag = AzOptionTabOnClickTag_None } }
 #define AzOptionTabOnClick_Some(v) { .Some = { .tag = AzOptionTabOnClickTag_Some, .payload = v } }
+#define AzOptionTabOnClose_None { .None = { .tag = AzOptionTabOnCloseTag_None } }
+#define AzOptionTabOnClose_Some(v) { .Some = { .tag = AzOptionTabOnCloseTag_Some, .payload = v } }
+#define AzOptionTabOnReorder_None { .None = { .tag = AzOptionTabOnReorderTag_None } }
+#define AzOptionTabOnReorder_Some(v) { .Some = { .tag = AzOptionTabOnReorderTag_Some, .payload = v } }
+#define AzOptionTabOnContextMenu_None { .None = { .tag = AzOptionTabOnContextMenuTag_None } }
+#define AzOptionTabOnContextMenu_Some(v) { .Some = { .tag = AzOptionTabOnContextMenuTag_Some, .payload = v } }
+#define AzOptionRibbonOnTabClicked_None { .None = { .tag = AzOptionRibbonOnTabClickedTag_None } }
+#define AzOptionRibbonOnTabClicked_Some(v) { .Some = { .tag = AzOptionRibbonOnTabClickedTag_Some, .payload = v } }
+#define AzOptionRibbonOnItemClick_None { .None = { .tag = AzOptionRibbonOnItemClickTag_None } }
+#define AzOptionRibbonOnItemClick_Some(v) { .Some = { .tag = AzOptionRibbonOnItemClickTag_Some, .payload = v } }
+#define AzOptionSplitterOnResize_None { .None = { .tag = AzOptionSplitterOnResizeTag_None } }
+#define AzOptionSplitterOnResize_Some(v) { .Some = { .tag = AzOptionSplitterOnResizeTag_Some, .payload = v } }
+#define AzOptionSliderOnValueChange_None { .None = { .tag = AzOptionSliderOnValueChangeTag_None } }
+#define AzOptionSliderOnValueChange_Some(v) { .Some = { .tag = AzOptionSliderOnValueChangeTag_Some, .payload = v } }
+#define AzOptionRangeSliderOnValueChange_None { .None = { .tag = AzOptionRangeSliderOnValueChangeTag_None } }
+#define AzOptionRangeSliderOnValueChange_Some(v) { .Some = { .tag = AzOptionRangeSliderOnValueChangeTag_Some, .payload = v } }
 #define AzOptionFileInputOnPathChange_None { .None = { .tag = AzOptionFileInputOnPathChangeTag_None } }
 #define AzOptionFileInputOnPathChange_Some(v) { .Some = { .tag = AzOptionFileInputOnPathChangeTag_Some, .payload = v } }
 #define AzOptionCheckBoxOnToggle_None { .None = { .tag = AzOptionCheckBoxOnToggleTag_None } }
@@ -11571,6 +14317,8 @@ typedef struct AzCss AzCss;
 #define AzOptionTextInputOnFocusLost_Some(v) { .Some = { .tag = AzOptionTextInputOnFocusLostTag_Some, .payload = v } }
 #define AzOptionTextInputSelection_None { .None = { .tag = AzOptionTextInputSelectionTag_None } }
 #define AzOptionTextInputSelection_Some(v) { .Some = { .tag = AzOptionTextInputSelectionTag_Some, .payload = v } }
+#define AzOptionTextAreaOnTextChange_None { .None = { .tag = AzOptionTextAreaOnTextChangeTag_None } }
+#define AzOptionTextAreaOnTextChange_Some(v) { .Some = { .tag = AzOptionTextAreaOnTextChangeTag_Some, .payload = v } }
 #define AzOptionNumberInputOnFocusLost_None { .None = { .tag = AzOptionNumberInputOnFocusLostTag_None } }
 #define AzOptionNumberInputOnFocusLost_Some(v) { .Some = { .tag = AzOptionNumberInputOnFocusLostTag_Some, .payload = v } }
 #define AzOptionNumberInputOnValueChange_None { .None = { .tag = AzOptionNumberInputOnValueChangeTag_None } }
@@ -11623,6 +14371,8 @@ typedef struct AzCss AzCss;
 #define AzOptionSvgDashPattern_Some(v) { .Some = { .tag = AzOptionSvgDashPatternTag_Some, .payload = v } }
 #define AzOptionLogicalPosition_None { .None = { .tag = AzOptionLogicalPositionTag_None } }
 #define AzOptionLogicalPosition_Some(v) { .Some = { .tag = AzOptionLogicalPositionTag_Some, .payload = v } }
+#define AzOptionLogicalRect_None { .None = { .tag = AzOptionLogicalRectTag_None } }
+#define AzOptionLogicalRect_Some(v) { .Some = { .tag = AzOptionLogicalRectTag_Some, .payload = v } }
 #define AzOptionPhysicalPositionI32_None { .None = { .tag = AzOptionPhysicalPositionI32Tag_None } }
 #define AzOptionPhysicalPositionI32_Some(v) { .Some = { .tag = AzOptionPhysicalPositionI32Tag_Some, .payload = v } }
 #define AzOptionMouseCursorType_None { .None = { .tag = AzOptionMouseCursorTypeTag_None } }
@@ -11665,6 +14415,11 @@ typedef struct AzCss AzCss;
 #define AzCssPathPseudoSelector_Hover { .Hover = { .tag = AzCssPathPseudoSelectorTag_Hover } }
 #define AzCssPathPseudoSelector_Active { .Active = { .tag = AzCssPathPseudoSelectorTag_Active } }
 #define AzCssPathPseudoSelector_Focus { .Focus = { .tag = AzCssPathPseudoSelectorTag_Focus } }
+#define AzCssMediaFeature_Width(v) { .Width = { .tag = AzCssMediaFeatureTag_Width, .payload = v } }
+#define AzCssMediaFeature_Height(v) { .Height = { .tag = AzCssMediaFeatureTag_Height, .payload = v } }
+#define AzCssMediaFeature_Resolution(v) { .Resolution = { .tag = AzCssMediaFeatureTag_Resolution, .payload = v } }
+#define AzCssMediaFeature_Orientation(v) { .Orientation = { .tag = AzCssMediaFeatureTag_Orientation, .payload = v } }
+#define AzCssMediaFeature_PrefersColorScheme(v) { .PrefersColorScheme = { .tag = AzCssMediaFeatureTag_PrefersColorScheme, .payload = v } }
 #define AzAnimationInterpolationFunction_Ease { .Ease = { .tag = AzAnimationInterpolationFunctionTag_Ease } }
 #define AzAnimationInterpolationFunction_Linear { .Linear = { .tag = AzAnimationInterpolationFunctionTag_Linear } }
 #define AzAnimationInterpolationFunction_EaseIn { .EaseIn = { .tag = AzAnimationInterpolationFunctionTag_EaseIn } }
@@ -11701,6 +14456,46 @@ typedef struct AzCss AzCss;
 #define AzStyleTransform_SkewX(v) { .SkewX = { .tag = AzStyleTransformTag_SkewX, .payload = v } }
 #define AzStyleTransform_SkewY(v) { .SkewY = { .tag = AzStyleTransformTag_SkewY, .payload = v } }
 #define AzStyleTransform_Perspective(v) { .Perspective = { .tag = AzStyleTransformTag_Perspective, .payload = v } }
+#define AzGridTrackSizingVecValue_Auto { .Auto = { .tag = AzGridTrackSizingVecValueTag_Auto } }
+#define AzGridTrackSizingVecValue_None { .None = { .tag = AzGridTrackSizingVecValueTag_None } }
+#define AzGridTrackSizingVecValue_Inherit { .Inherit = { .tag = AzGridTrackSizingVecValueTag_Inherit } }
+#define AzGridTrackSizingVecValue_Initial { .Initial = { .tag = AzGridTrackSizingVecValueTag_Initial } }
+#define AzGridTrackSizingVecValue_Exact(v) { .Exact = { .tag = AzGridTrackSizingVecValueTag_Exact, .payload = v } }
+#define AzStyleTransitionPropertyVecValue_Auto { .Auto = { .tag = AzStyleTransitionPropertyVecValueTag_Auto } }
+#define AzStyleTransitionPropertyVecValue_None { .None = { .tag = AzStyleTransitionPropertyVecValueTag_None } }
+#define AzStyleTransitionPropertyVecValue_Inherit { .Inherit = { .tag = AzStyleTransitionPropertyVecValueTag_Inherit } }
+#define AzStyleTransitionPropertyVecValue_Initial { .Initial = { .tag = AzStyleTransitionPropertyVecValueTag_Initial } }
+#define AzStyleTransitionPropertyVecValue_Exact(v) { .Exact = { .tag = AzStyleTransitionPropertyVecValueTag_Exact, .payload = v } }
+#define AzStyleTimeVecValue_Auto { .Auto = { .tag = AzStyleTimeVecValueTag_Auto } }
+#define AzStyleTimeVecValue_None { .None = { .tag = AzStyleTimeVecValueTag_None } }
+#define AzStyleTimeVecValue_Inherit { .Inherit = { .tag = AzStyleTimeVecValueTag_Inherit } }
+#define AzStyleTimeVecValue_Initial { .Initial = { .tag = AzStyleTimeVecValueTag_Initial } }
+#define AzStyleTimeVecValue_Exact(v) { .Exact = { .tag = AzStyleTimeVecValueTag_Exact, .payload = v } }
+#define AzStyleTimingFunctionVecValue_Auto { .Auto = { .tag = AzStyleTimingFunctionVecValueTag_Auto } }
+#define AzStyleTimingFunctionVecValue_None { .None = { .tag = AzStyleTimingFunctionVecValueTag_None } }
+#define AzStyleTimingFunctionVecValue_Inherit { .Inherit = { .tag = AzStyleTimingFunctionVecValueTag_Inherit } }
+#define AzStyleTimingFunctionVecValue_Initial { .Initial = { .tag = AzStyleTimingFunctionVecValueTag_Initial } }
+#define AzStyleTimingFunctionVecValue_Exact(v) { .Exact = { .tag = AzStyleTimingFunctionVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationIterationCountVecValue_Auto { .Auto = { .tag = AzStyleAnimationIterationCountVecValueTag_Auto } }
+#define AzStyleAnimationIterationCountVecValue_None { .None = { .tag = AzStyleAnimationIterationCountVecValueTag_None } }
+#define AzStyleAnimationIterationCountVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationIterationCountVecValueTag_Inherit } }
+#define AzStyleAnimationIterationCountVecValue_Initial { .Initial = { .tag = AzStyleAnimationIterationCountVecValueTag_Initial } }
+#define AzStyleAnimationIterationCountVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationIterationCountVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationDirectionVecValue_Auto { .Auto = { .tag = AzStyleAnimationDirectionVecValueTag_Auto } }
+#define AzStyleAnimationDirectionVecValue_None { .None = { .tag = AzStyleAnimationDirectionVecValueTag_None } }
+#define AzStyleAnimationDirectionVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationDirectionVecValueTag_Inherit } }
+#define AzStyleAnimationDirectionVecValue_Initial { .Initial = { .tag = AzStyleAnimationDirectionVecValueTag_Initial } }
+#define AzStyleAnimationDirectionVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationDirectionVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationFillModeVecValue_Auto { .Auto = { .tag = AzStyleAnimationFillModeVecValueTag_Auto } }
+#define AzStyleAnimationFillModeVecValue_None { .None = { .tag = AzStyleAnimationFillModeVecValueTag_None } }
+#define AzStyleAnimationFillModeVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationFillModeVecValueTag_Inherit } }
+#define AzStyleAnimationFillModeVecValue_Initial { .Initial = { .tag = AzStyleAnimationFillModeVecValueTag_Initial } }
+#define AzStyleAnimationFillModeVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationFillModeVecValueTag_Exact, .payload = v } }
+#define AzStyleAnimationPlayStateVecValue_Auto { .Auto = { .tag = AzStyleAnimationPlayStateVecValueTag_Auto } }
+#define AzStyleAnimationPlayStateVecValue_None { .None = { .tag = AzStyleAnimationPlayStateVecValueTag_None } }
+#define AzStyleAnimationPlayStateVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationPlayStateVecValueTag_Inherit } }
+#define AzStyleAnimationPlayStateVecValue_Initial { .Initial = { .tag = AzStyleAnimationPlayStateVecValueTag_Initial } }
+#define AzStyleAnimationPlayStateVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationPlayStateVecValueTag_Exact, .payload = v } }
 #define AzStyleBackgroundPositionVecValue_Auto { .Auto = { .tag = AzStyleBackgroundPositionVecValueTag_Auto } }
 #define AzStyleBackgroundPositionVecValue_None { .None = { .tag = AzStyleBackgroundPositionVecValueTag_None } }
 #define AzStyleBackgroundPositionVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundPositionVecValueTag_Inherit } }
@@ -11734,8 +14529,6 @@ typedef struct AzCss AzCss;
 #define AzOptionVirtualKeyCodeCombo_Some(v) { .Some = { .tag = AzOptionVirtualKeyCodeComboTag_Some, .payload = v } }
 #define AzOptionMouseState_None { .None = { .tag = AzOptionMouseStateTag_None } }
 #define AzOptionMouseState_Some(v) { .Some = { .tag = AzOptionMouseStateTag_Some, .payload = v } }
-#define AzOptionKeyboardState_None { .None = { .tag = AzOptionKeyboardStateTag_None } }
-#define AzOptionKeyboardState_Some(v) { .Some = { .tag = AzOptionKeyboardStateTag_Some, .payload = v } }
 #define AzOptionStringVec_None { .None = { .tag = AzOptionStringVecTag_None } }
 #define AzOptionStringVec_Some(v) { .Some = { .tag = AzOptionStringVecTag_Some, .payload = v } }
 #define AzOptionThreadReceiveMsg_None { .None = { .tag = AzOptionThreadReceiveMsgTag_None } }
@@ -11762,6 +14555,8 @@ typedef struct AzCss AzCss;
 #define AzNodeType_Text(v) { .Text = { .tag = AzNodeTypeTag_Text, .payload = v } }
 #define AzNodeType_Image(v) { .Image = { .tag = AzNodeTypeTag_Image, .payload = v } }
 #define AzNodeType_IFrame(v) { .IFrame = { .tag = AzNodeTypeTag_IFrame, .payload = v } }
+#define AzTooltipContent_Text(v) { .Text = { .tag = AzTooltipContentTag_Text, .payload = v } }
+#define AzTooltipContent_Dom(v) { .Dom = { .tag = AzTooltipContentTag_Dom, .payload = v } }
 #define AzIdOrClass_Id(v) { .Id = { .tag = AzIdOrClassTag_Id, .payload = v } }
 #define AzIdOrClass_Class(v) { .Class = { .tag = AzIdOrClassTag_Class, .payload = v } }
 #define AzCssPathSelector_Global { .Global = { .tag = AzCssPathSelectorTag_Global } }
@@ -11779,6 +14574,8 @@ typedef struct AzCss AzCss;
 #define AzStyleFontFamily_System(v) { .System = { .tag = AzStyleFontFamilyTag_System, .payload = v } }
 #define AzStyleFontFamily_File(v) { .File = { .tag = AzStyleFontFamilyTag_File, .payload = v } }
 #define AzStyleFontFamily_Ref(v) { .Ref = { .tag = AzStyleFontFamilyTag_Ref, .payload = v } }
+#define AzStyleAnimationName_None { .None = { .tag = AzStyleAnimationNameTag_None } }
+#define AzStyleAnimationName_Name(v) { .Name = { .tag = AzStyleAnimationNameTag_Name, .payload = v } }
 #define AzScrollbarStyleValue_Auto { .Auto = { .tag = AzScrollbarStyleValueTag_Auto } }
 #define AzScrollbarStyleValue_None { .None = { .tag = AzScrollbarStyleValueTag_None } }
 #define AzScrollbarStyleValue_Inherit { .Inherit = { .tag = AzScrollbarStyleValueTag_Inherit } }
@@ -11824,6 +14621,10 @@ typedef struct AzCss AzCss;
 #define AzOptionRawImage_Some(v) { .Some = { .tag = AzOptionRawImageTag_Some, .payload = v } }
 #define AzOptionWaylandTheme_None { .None = { .tag = AzOptionWaylandThemeTag_None } }
 #define AzOptionWaylandTheme_Some(v) { .Some = { .tag = AzOptionWaylandThemeTag_Some, .payload = v } }
+#define AzOptionImeComposition_None { .None = { .tag = AzOptionImeCompositionTag_None } }
+#define AzOptionImeComposition_Some(v) { .Some = { .tag = AzOptionImeCompositionTag_Some, .payload = v } }
+#define AzOptionClipboardContent_None { .None = { .tag = AzOptionClipboardContentTag_None } }
+#define AzOptionClipboardContent_Some(v) { .Some = { .tag = AzOptionClipboardContentTag_Some, .payload = v } }
 #define AzResultRawImageDecodeImageError_Ok(v) { .Ok = { .tag = AzResultRawImageDecodeImageErrorTag_Ok, .payload = v } }
 #define AzResultRawImageDecodeImageError_Err(v) { .Err = { .tag = AzResultRawImageDecodeImageErrorTag_Err, .payload = v } }
 #define AzXmlStreamError_UnexpectedEndOfStream { .UnexpectedEndOfStream = { .tag = AzXmlStreamErrorTag_UnexpectedEndOfStream } }
@@ -11842,6 +14643,11 @@ typedef struct AzCss AzCss;
 #define AzMenuItem_String(v) { .String = { .tag = AzMenuItemTag_String, .payload = v } }
 #define AzMenuItem_Separator { .Separator = { .tag = AzMenuItemTag_Separator } }
 #define AzMenuItem_BreakLine { .BreakLine = { .tag = AzMenuItemTag_BreakLine } }
+#define AzStyleAnimationNameVecValue_Auto { .Auto = { .tag = AzStyleAnimationNameVecValueTag_Auto } }
+#define AzStyleAnimationNameVecValue_None { .None = { .tag = AzStyleAnimationNameVecValueTag_None } }
+#define AzStyleAnimationNameVecValue_Inherit { .Inherit = { .tag = AzStyleAnimationNameVecValueTag_Inherit } }
+#define AzStyleAnimationNameVecValue_Initial { .Initial = { .tag = AzStyleAnimationNameVecValueTag_Initial } }
+#define AzStyleAnimationNameVecValue_Exact(v) { .Exact = { .tag = AzStyleAnimationNameVecValueTag_Exact, .payload = v } }
 #define AzStyleBackgroundContentVecValue_Auto { .Auto = { .tag = AzStyleBackgroundContentVecValueTag_Auto } }
 #define AzStyleBackgroundContentVecValue_None { .None = { .tag = AzStyleBackgroundContentVecValueTag_None } }
 #define AzStyleBackgroundContentVecValue_Inherit { .Inherit = { .tag = AzStyleBackgroundContentVecValueTag_Inherit } }
@@ -11856,6 +14662,10 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_FontSize(v) { .FontSize = { .tag = AzCssPropertyTag_FontSize, .payload = v } }
 #define AzCssProperty_FontFamily(v) { .FontFamily = { .tag = AzCssPropertyTag_FontFamily, .payload = v } }
 #define AzCssProperty_TextAlign(v) { .TextAlign = { .tag = AzCssPropertyTag_TextAlign, .payload = v } }
+#define AzCssProperty_Direction(v) { .Direction = { .tag = AzCssPropertyTag_Direction, .payload = v } }
+#define AzCssProperty_WordBreak(v) { .WordBreak = { .tag = AzCssPropertyTag_WordBreak, .payload = v } }
+#define AzCssProperty_OverflowWrap(v) { .OverflowWrap = { .tag = AzCssPropertyTag_OverflowWrap, .payload = v } }
+#define AzCssProperty_Hyphens(v) { .Hyphens = { .tag = AzCssPropertyTag_Hyphens, .payload = v } }
 #define AzCssProperty_LetterSpacing(v) { .LetterSpacing = { .tag = AzCssPropertyTag_LetterSpacing, .payload = v } }
 #define AzCssProperty_LineHeight(v) { .LineHeight = { .tag = AzCssPropertyTag_LineHeight, .payload = v } }
 #define AzCssProperty_WordSpacing(v) { .WordSpacing = { .tag = AzCssPropertyTag_WordSpacing, .payload = v } }
@@ -11882,6 +14692,15 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_JustifyContent(v) { .JustifyContent = { .tag = AzCssPropertyTag_JustifyContent, .payload = v } }
 #define AzCssProperty_AlignItems(v) { .AlignItems = { .tag = AzCssPropertyTag_AlignItems, .payload = v } }
 #define AzCssProperty_AlignContent(v) { .AlignContent = { .tag = AzCssPropertyTag_AlignContent, .payload = v } }
+#define AzCssProperty_GridTemplateColumns(v) { .GridTemplateColumns = { .tag = AzCssPropertyTag_GridTemplateColumns, .payload = v } }
+#define AzCssProperty_GridTemplateRows(v) { .GridTemplateRows = { .tag = AzCssPropertyTag_GridTemplateRows, .payload = v } }
+#define AzCssProperty_GridAutoColumns(v) { .GridAutoColumns = { .tag = AzCssPropertyTag_GridAutoColumns, .payload = v } }
+#define AzCssProperty_GridAutoRows(v) { .GridAutoRows = { .tag = AzCssPropertyTag_GridAutoRows, .payload = v } }
+#define AzCssProperty_GridAutoFlow(v) { .GridAutoFlow = { .tag = AzCssPropertyTag_GridAutoFlow, .payload = v } }
+#define AzCssProperty_GridColumn(v) { .GridColumn = { .tag = AzCssPropertyTag_GridColumn, .payload = v } }
+#define AzCssProperty_GridRow(v) { .GridRow = { .tag = AzCssPropertyTag_GridRow, .payload = v } }
+#define AzCssProperty_RowGap(v) { .RowGap = { .tag = AzCssPropertyTag_RowGap, .payload = v } }
+#define AzCssProperty_ColumnGap(v) { .ColumnGap = { .tag = AzCssPropertyTag_ColumnGap, .payload = v } }
 #define AzCssProperty_BackgroundContent(v) { .BackgroundContent = { .tag = AzCssPropertyTag_BackgroundContent, .payload = v } }
 #define AzCssProperty_BackgroundPosition(v) { .BackgroundPosition = { .tag = AzCssPropertyTag_BackgroundPosition, .payload = v } }
 #define AzCssProperty_BackgroundSize(v) { .BackgroundSize = { .tag = AzCssPropertyTag_BackgroundSize, .payload = v } }
@@ -11926,10 +14745,24 @@ typedef struct AzCss AzCss;
 #define AzCssProperty_Filter(v) { .Filter = { .tag = AzCssPropertyTag_Filter, .payload = v } }
 #define AzCssProperty_BackdropFilter(v) { .BackdropFilter = { .tag = AzCssPropertyTag_BackdropFilter, .payload = v } }
 #define AzCssProperty_TextShadow(v) { .TextShadow = { .tag = AzCssPropertyTag_TextShadow, .payload = v } }
+#define AzCssProperty_TransitionProperty(v) { .TransitionProperty = { .tag = AzCssPropertyTag_TransitionProperty, .payload = v } }
+#define AzCssProperty_TransitionDuration(v) { .TransitionDuration = { .tag = AzCssPropertyTag_TransitionDuration, .payload = v } }
+#define AzCssProperty_TransitionTimingFunction(v) { .TransitionTimingFunction = { .tag = AzCssPropertyTag_TransitionTimingFunction, .payload = v } }
+#define AzCssProperty_TransitionDelay(v) { .TransitionDelay = { .tag = AzCssPropertyTag_TransitionDelay, .payload = v } }
+#define AzCssProperty_AnimationName(v) { .AnimationName = { .tag = AzCssPropertyTag_AnimationName, .payload = v } }
+#define AzCssProperty_AnimationDuration(v) { .AnimationDuration = { .tag = AzCssPropertyTag_AnimationDuration, .payload = v } }
+#define AzCssProperty_AnimationTimingFunction(v) { .AnimationTimingFunction = { .tag = AzCssPropertyTag_AnimationTimingFunction, .payload = v } }
+#define AzCssProperty_AnimationDelay(v) { .AnimationDelay = { .tag = AzCssPropertyTag_AnimationDelay, .payload = v } }
+#define AzCssProperty_AnimationIterationCount(v) { .AnimationIterationCount = { .tag = AzCssPropertyTag_AnimationIterationCount, .payload = v } }
+#define AzCssProperty_AnimationDirection(v) { .AnimationDirection = { .tag = AzCssPropertyTag_AnimationDirection, .payload = v } }
+#define AzCssProperty_AnimationFillMode(v) { .AnimationFillMode = { .tag = AzCssPropertyTag_AnimationFillMode, .payload = v } }
+#define AzCssProperty_AnimationPlayState(v) { .AnimationPlayState = { .tag = AzCssPropertyTag_AnimationPlayState, .payload = v } }
 #define AzCssPropertySource_Css(v) { .Css = { .tag = AzCssPropertySourceTag_Css, .payload = v } }
 #define AzCssPropertySource_Inline { .Inline = { .tag = AzCssPropertySourceTag_Inline } }
 #define AzOptionCssProperty_None { .None = { .tag = AzOptionCssPropertyTag_None } }
 #define AzOptionCssProperty_Some(v) { .Some = { .tag = AzOptionCssPropertyTag_Some, .payload = v } }
+#define AzOptionKeyboardState_None { .None = { .tag = AzOptionKeyboardStateTag_None } }
+#define AzOptionKeyboardState_Some(v) { .Some = { .tag = AzOptionKeyboardStateTag_Some, .payload = v } }
 #define AzNodeDataInlineCssProperty_Normal(v) { .Normal = { .tag = AzNodeDataInlineCssPropertyTag_Normal, .payload = v } }
 #define AzNodeDataInlineCssProperty_Active(v) { .Active = { .tag = AzNodeDataInlineCssPropertyTag_Active, .payload = v } }
 #define AzNodeDataInlineCssProperty_Focus(v) { .Focus = { .tag = AzNodeDataInlineCssPropertyTag_Focus, .payload = v } }
@@ -11997,6 +14830,38 @@ AzListViewRow AzListViewRowVecArray[] = {};
 #define AzListViewRowVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzListViewRow), .cap = sizeof(v) / sizeof(AzListViewRow), .destructor = { .NoDestructor = { .tag = AzListViewRowVecDestructorTag_NoDestructor, }, }, }
 #define AzListViewRowVec_empty { .ptr = &AzListViewRowVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzListViewRowVecDestructorTag_NoDestructor, }, }, }
 
+AzTreeViewNode AzTreeViewNodeVecArray[] = {};
+#define AzTreeViewNodeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTreeViewNode), .cap = sizeof(v) / sizeof(AzTreeViewNode), .destructor = { .NoDestructor = { .tag = AzTreeViewNodeVecDestructorTag_NoDestructor, }, }, }
+#define AzTreeViewNodeVec_empty { .ptr = &AzTreeViewNodeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTreeViewNodeVecDestructorTag_NoDestructor, }, }, }
+
+AzTreeViewNodeId AzTreeViewNodeIdVecArray[] = {};
+#define AzTreeViewNodeIdVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTreeViewNodeId), .cap = sizeof(v) / sizeof(AzTreeViewNodeId), .destructor = { .NoDestructor = { .tag = AzTreeViewNodeIdVecDestructorTag_NoDestructor, }, }, }
+#define AzTreeViewNodeIdVec_empty { .ptr = &AzTreeViewNodeIdVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTreeViewNodeIdVecDestructorTag_NoDestructor, }, }, }
+
+AzRibbonTab AzRibbonTabVecArray[] = {};
+#define AzRibbonTabVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzRibbonTab), .cap = sizeof(v) / sizeof(AzRibbonTab), .destructor = { .NoDestructor = { .tag = AzRibbonTabVecDestructorTag_NoDestructor, }, }, }
+#define AzRibbonTabVec_empty { .ptr = &AzRibbonTabVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzRibbonTabVecDestructorTag_NoDestructor, }, }, }
+
+AzRibbonGroup AzRibbonGroupVecArray[] = {};
+#define AzRibbonGroupVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzRibbonGroup), .cap = sizeof(v) / sizeof(AzRibbonGroup), .destructor = { .NoDestructor = { .tag = AzRibbonGroupVecDestructorTag_NoDestructor, }, }, }
+#define AzRibbonGroupVec_empty { .ptr = &AzRibbonGroupVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzRibbonGroupVecDestructorTag_NoDestructor, }, }, }
+
+AzRibbonItem AzRibbonItemVecArray[] = {};
+#define AzRibbonItemVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzRibbonItem), .cap = sizeof(v) / sizeof(AzRibbonItem), .destructor = { .NoDestructor = { .tag = AzRibbonItemVecDestructorTag_NoDestructor, }, }, }
+#define AzRibbonItemVec_empty { .ptr = &AzRibbonItemVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzRibbonItemVecDestructorTag_NoDestructor, }, }, }
+
+AzSplitterPane AzSplitterPaneVecArray[] = {};
+#define AzSplitterPaneVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzSplitterPane), .cap = sizeof(v) / sizeof(AzSplitterPane), .destructor = { .NoDestructor = { .tag = AzSplitterPaneVecDestructorTag_NoDestructor, }, }, }
+#define AzSplitterPaneVec_empty { .ptr = &AzSplitterPaneVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzSplitterPaneVecDestructorTag_NoDestructor, }, }, }
+
+AzSplitterPaneState AzSplitterPaneStateVecArray[] = {};
+#define AzSplitterPaneStateVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzSplitterPaneState), .cap = sizeof(v) / sizeof(AzSplitterPaneState), .destructor = { .NoDestructor = { .tag = AzSplitterPaneStateVecDestructorTag_NoDestructor, }, }, }
+#define AzSplitterPaneStateVec_empty { .ptr = &AzSplitterPaneStateVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzSplitterPaneStateVecDestructorTag_NoDestructor, }, }, }
+
+AzTextAreaEdit AzTextAreaEditVecArray[] = {};
+#define AzTextAreaEditVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzTextAreaEdit), .cap = sizeof(v) / sizeof(AzTextAreaEdit), .destructor = { .NoDestructor = { .tag = AzTextAreaEditVecDestructorTag_NoDestructor, }, }, }
+#define AzTextAreaEditVec_empty { .ptr = &AzTextAreaEditVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzTextAreaEditVecDestructorTag_NoDestructor, }, }, }
+
 AzStyleFilter AzStyleFilterVecArray[] = {};
 #define AzStyleFilterVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleFilter), .cap = sizeof(v) / sizeof(AzStyleFilter), .destructor = { .NoDestructor = { .tag = AzStyleFilterVecDestructorTag_NoDestructor, }, }, }
 #define AzStyleFilterVec_empty { .ptr = &AzStyleFilterVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleFilterVecDestructorTag_NoDestructor, }, }, }
@@ -12045,6 +14910,10 @@ AzAccessibilityState AzAccessibilityStateVecArray[] = {};
 #define AzAccessibilityStateVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzAccessibilityState), .cap = sizeof(v) / sizeof(AzAccessibilityState), .destructor = { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor, }, }, }
 #define AzAccessibilityStateVec_empty { .ptr = &AzAccessibilityStateVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzAccessibilityStateVecDestructorTag_NoDestructor, }, }, }
 
+AzAccessibilityAction AzAccessibilityActionVecArray[] = {};
+#define AzAccessibilityActionVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzAccessibilityAction), .cap = sizeof(v) / sizeof(AzAccessibilityAction), .destructor = { .NoDestructor = { .tag = AzAccessibilityActionVecDestructorTag_NoDestructor, }, }, }
+#define AzAccessibilityActionVec_empty { .ptr = &AzAccessibilityActionVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzAccessibilityActionVecDestructorTag_NoDestructor, }, }, }
+
 AzMenuItem AzMenuItemVecArray[] = {};
 #define AzMenuItemVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzMenuItem), .cap = sizeof(v) / sizeof(AzMenuItem), .destructor = { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor, }, }, }
 #define AzMenuItemVec_empty { .ptr = &AzMenuItemVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzMenuItemVecDestructorTag_NoDestructor, }, }, }
@@ -12121,6 +14990,42 @@ AzStyleTransform AzStyleTransformVecArray[] = {};
 #define AzStyleTransformVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleTransform), .cap = sizeof(v) / sizeof(AzStyleTransform), .destructor = { .NoDestructor = { .tag = AzStyleTransformVecDestructorTag_NoDestructor, }, }, }
 #define AzStyleTransformVec_empty { .ptr = &AzStyleTransformVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleTransformVecDestructorTag_NoDestructor, }, }, }
 
+AzStyleTransitionProperty AzStyleTransitionPropertyVecArray[] = {};
+#define AzStyleTransitionPropertyVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleTransitionProperty), .cap = sizeof(v) / sizeof(AzStyleTransitionProperty), .destructor = { .NoDestructor = { .tag = AzStyleTransitionPropertyVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleTransitionPropertyVec_empty { .ptr = &AzStyleTransitionPropertyVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleTransitionPropertyVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleTime AzStyleTimeVecArray[] = {};
+#define AzStyleTimeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleTime), .cap = sizeof(v) / sizeof(AzStyleTime), .destructor = { .NoDestructor = { .tag = AzStyleTimeVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleTimeVec_empty { .ptr = &AzStyleTimeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleTimeVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleTimingFunction AzStyleTimingFunctionVecArray[] = {};
+#define AzStyleTimingFunctionVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleTimingFunction), .cap = sizeof(v) / sizeof(AzStyleTimingFunction), .destructor = { .NoDestructor = { .tag = AzStyleTimingFunctionVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleTimingFunctionVec_empty { .ptr = &AzStyleTimingFunctionVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleTimingFunctionVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationName AzStyleAnimationNameVecArray[] = {};
+#define AzStyleAnimationNameVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationName), .cap = sizeof(v) / sizeof(AzStyleAnimationName), .destructor = { .NoDestructor = { .tag = AzStyleAnimationNameVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationNameVec_empty { .ptr = &AzStyleAnimationNameVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationNameVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationIterationCount AzStyleAnimationIterationCountVecArray[] = {};
+#define AzStyleAnimationIterationCountVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationIterationCount), .cap = sizeof(v) / sizeof(AzStyleAnimationIterationCount), .destructor = { .NoDestructor = { .tag = AzStyleAnimationIterationCountVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationIterationCountVec_empty { .ptr = &AzStyleAnimationIterationCountVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationIterationCountVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationDirection AzStyleAnimationDirectionVecArray[] = {};
+#define AzStyleAnimationDirectionVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationDirection), .cap = sizeof(v) / sizeof(AzStyleAnimationDirection), .destructor = { .NoDestructor = { .tag = AzStyleAnimationDirectionVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationDirectionVec_empty { .ptr = &AzStyleAnimationDirectionVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationDirectionVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationFillMode AzStyleAnimationFillModeVecArray[] = {};
+#define AzStyleAnimationFillModeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationFillMode), .cap = sizeof(v) / sizeof(AzStyleAnimationFillMode), .destructor = { .NoDestructor = { .tag = AzStyleAnimationFillModeVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationFillModeVec_empty { .ptr = &AzStyleAnimationFillModeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationFillModeVecDestructorTag_NoDestructor, }, }, }
+
+AzStyleAnimationPlayState AzStyleAnimationPlayStateVecArray[] = {};
+#define AzStyleAnimationPlayStateVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStyleAnimationPlayState), .cap = sizeof(v) / sizeof(AzStyleAnimationPlayState), .destructor = { .NoDestructor = { .tag = AzStyleAnimationPlayStateVecDestructorTag_NoDestructor, }, }, }
+#define AzStyleAnimationPlayStateVec_empty { .ptr = &AzStyleAnimationPlayStateVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStyleAnimationPlayStateVecDestructorTag_NoDestructor, }, }, }
+
+AzGridTrackSizing AzGridTrackSizingVecArray[] = {};
+#define AzGridTrackSizingVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzGridTrackSizing), .cap = sizeof(v) / sizeof(AzGridTrackSizing), .destructor = { .NoDestructor = { .tag = AzGridTrackSizingVecDestructorTag_NoDestructor, }, }, }
+#define AzGridTrackSizingVec_empty { .ptr = &AzGridTrackSizingVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzGridTrackSizingVecDestructorTag_NoDestructor, }, }, }
+
 AzCssProperty AzCssPropertyVecArray[] = {};
 #define AzCssPropertyVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssProperty), .cap = sizeof(v) / sizeof(AzCssProperty), .destructor = { .NoDestructor = { .tag = AzCssPropertyVecDestructorTag_NoDestructor, }, }, }
 #define AzCssPropertyVec_empty { .ptr = &AzCssPropertyVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssPropertyVecDestructorTag_NoDestructor, }, }, }
@@ -12177,6 +15082,26 @@ AzStylesheet AzStylesheetVecArray[] = {};
 #define AzStylesheetVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzStylesheet), .cap = sizeof(v) / sizeof(AzStylesheet), .destructor = { .NoDestructor = { .tag = AzStylesheetVecDestructorTag_NoDestructor, }, }, }
 #define AzStylesheetVec_empty { .ptr = &AzStylesheetVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzStylesheetVecDestructorTag_NoDestructor, }, }, }
 
+AzCssKeyframes AzCssKeyframesVecArray[] = {};
+#define AzCssKeyframesVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssKeyframes), .cap = sizeof(v) / sizeof(AzCssKeyframes), .destructor = { .NoDestructor = { .tag = AzCssKeyframesVecDestructorTag_NoDestructor, }, }, }
+#define AzCssKeyframesVec_empty { .ptr = &AzCssKeyframesVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssKeyframesVecDestructorTag_NoDestructor, }, }, }
+
+AzCssKeyframe AzCssKeyframeVecArray[] = {};
+#define AzCssKeyframeVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssKeyframe), .cap = sizeof(v) / sizeof(AzCssKeyframe), .destructor = { .NoDestructor = { .tag = AzCssKeyframeVecDestructorTag_NoDestructor, }, }, }
+#define AzCssKeyframeVec_empty { .ptr = &AzCssKeyframeVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssKeyframeVecDestructorTag_NoDestructor, }, }, }
+
+AzCssMediaRule AzCssMediaRuleVecArray[] = {};
+#define AzCssMediaRuleVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssMediaRule), .cap = sizeof(v) / sizeof(AzCssMediaRule), .destructor = { .NoDestructor = { .tag = AzCssMediaRuleVecDestructorTag_NoDestructor, }, }, }
+#define AzCssMediaRuleVec_empty { .ptr = &AzCssMediaRuleVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssMediaRuleVecDestructorTag_NoDestructor, }, }, }
+
+AzCssMediaQuery AzCssMediaQueryVecArray[] = {};
+#define AzCssMediaQueryVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssMediaQuery), .cap = sizeof(v) / sizeof(AzCssMediaQuery), .destructor = { .NoDestructor = { .tag = AzCssMediaQueryVecDestructorTag_NoDestructor, }, }, }
+#define AzCssMediaQueryVec_empty { .ptr = &AzCssMediaQueryVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssMediaQueryVecDestructorTag_NoDestructor, }, }, }
+
+AzCssMediaFeature AzCssMediaFeatureVecArray[] = {};
+#define AzCssMediaFeatureVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssMediaFeature), .cap = sizeof(v) / sizeof(AzCssMediaFeature), .destructor = { .NoDestructor = { .tag = AzCssMediaFeatureVecDestructorTag_NoDestructor, }, }, }
+#define AzCssMediaFeatureVec_empty { .ptr = &AzCssMediaFeatureVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssMediaFeatureVecDestructorTag_NoDestructor, }, }, }
+
 AzCssRuleBlock AzCssRuleBlockVecArray[] = {};
 #define AzCssRuleBlockVec_fromConstArray(v) { .ptr = &v, .len = sizeof(v) / sizeof(AzCssRuleBlock), .cap = sizeof(v) / sizeof(AzCssRuleBlock), .destructor = { .NoDestructor = { .tag = AzCssRuleBlockVecDestructorTag_NoDestructor, }, }, }
 #define AzCssRuleBlockVec_empty { .ptr = &AzCssRuleBlockVecArray, .len = 0, .cap = 0, .destructor = { .NoDestructor = { .tag = AzCssRuleBlockVecDestructorTag_NoDestructor, }, }, }
@@ -12262,6 +15187,13 @@ extern DLLIMPORT AzAppConfig AzAppConfig_new(AzLayoutSolver  layout_solver);
 extern DLLIMPORT AzSystemCallbacks AzSystemCallbacks_libraryInternal();
 extern DLLIMPORT AzWindowCreateOptions AzWindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
 extern DLLIMPORT void AzWindowCreateOptions_delete(AzWindowCreateOptions* restrict instance);
+extern DLLIMPORT AzClipboardContent AzClipboardContent_text(AzString  text);
+extern DLLIMPORT AzClipboardContent AzClipboardContent_uriList(AzStringVec  uris);
+extern DLLIMPORT AzClipboardContent AzClipboardContent_png(AzU8Vec  data);
+extern DLLIMPORT bool  AzClipboardContent_isText(const AzClipboardContent* clipboardcontent);
+extern DLLIMPORT AzOptionString AzClipboardContent_getText(const AzClipboardContent* clipboardcontent);
+extern DLLIMPORT AzStringVec AzClipboardContent_getUriList(const AzClipboardContent* clipboardcontent);
+extern DLLIMPORT void AzClipboardContent_delete(AzClipboardContent* restrict instance);
 extern DLLIMPORT AzLogicalPosition AzLogicalPosition_new(float x, float y);
 extern DLLIMPORT AzLogicalPosition AzLogicalPosition_zero();
 extern DLLIMPORT AzPhysicalSizeU32 AzLogicalSize_toPhysical(const AzLogicalSize* logicalsize, float hidpi_factor);
@@ -12275,6 +15207,7 @@ extern DLLIMPORT bool  AzKeyboardState_altDown(const AzKeyboardState* keyboardst
 extern DLLIMPORT bool  AzKeyboardState_superDown(const AzKeyboardState* keyboardstate);
 extern DLLIMPORT bool  AzKeyboardState_isKeyDown(const AzKeyboardState* keyboardstate, AzVirtualKeyCode  key);
 extern DLLIMPORT void AzKeyboardState_delete(AzKeyboardState* restrict instance);
+extern DLLIMPORT void AzImeComposition_delete(AzImeComposition* restrict instance);
 extern DLLIMPORT AzOptionLogicalPosition AzCursorPosition_getPosition(const AzCursorPosition* cursorposition);
 extern DLLIMPORT void AzPlatformSpecificOptions_delete(AzPlatformSpecificOptions* restrict instance);
 extern DLLIMPORT void AzWindowsWindowOptions_delete(AzWindowsWindowOptions* restrict instance);
@@ -12297,6 +15230,10 @@ extern DLLIMPORT AzMouseState AzCallbackInfo_getCurrentMouseState(const AzCallba
 extern DLLIMPORT AzOptionWindowState AzCallbackInfo_getPreviousWindowState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionKeyboardState AzCallbackInfo_getPreviousKeyboardState(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionMouseState AzCallbackInfo_getPreviousMouseState(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionString AzCallbackInfo_getHoveredFile(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionString AzCallbackInfo_getDroppedFile(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionString AzCallbackInfo_getHoveredText(const AzCallbackInfo* callbackinfo);
+extern DLLIMPORT AzOptionString AzCallbackInfo_getDroppedText(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzRawWindowHandle AzCallbackInfo_getCurrentWindowHandle(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionGl AzCallbackInfo_getGlContext(const AzCallbackInfo* callbackinfo);
 extern DLLIMPORT AzOptionLogicalPosition AzCallbackInfo_getScrollPosition(const AzCallbackInfo* callbackinfo, AzDomNodeId  node_id);
@@ -12329,6 +15266,9 @@ extern DLLIMPORT void AzCallbackInfo_deleteImage(AzCallbackInfo* restrict callba
 extern DLLIMPORT void AzCallbackInfo_updateImageMask(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageMask  new_mask);
 extern DLLIMPORT void AzCallbackInfo_stopPropagation(AzCallbackInfo* restrict callbackinfo);
 extern DLLIMPORT void AzCallbackInfo_createWindow(AzCallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
+extern DLLIMPORT AzOptionClipboardContent AzCallbackInfo_getClipboardContent(const AzCallbackInfo* callbackinfo, AzClipboardSelection  selection, AzString  mime_type);
+extern DLLIMPORT AzOptionString AzCallbackInfo_getClipboardText(const AzCallbackInfo* callbackinfo, AzClipboardSelection  selection);
+extern DLLIMPORT bool  AzCallbackInfo_setClipboardContent(AzCallbackInfo* restrict callbackinfo, AzClipboardSelection  selection, AzClipboardContent  content);
 extern DLLIMPORT AzTimerId AzCallbackInfo_startTimer(AzCallbackInfo* restrict callbackinfo, AzTimer  timer);
 extern DLLIMPORT AzOptionTimerId AzCallbackInfo_startAnimation(AzCallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
 extern DLLIMPORT bool  AzCallbackInfo_stopTimer(AzCallbackInfo* restrict callbackinfo, AzTimerId  timer_id);
@@ -12343,6 +15283,8 @@ extern DLLIMPORT AzLogicalSize AzHidpiAdjustedBounds_getLogicalSize(const AzHidp
 extern DLLIMPORT AzPhysicalSizeU32 AzHidpiAdjustedBounds_getPhysicalSize(const AzHidpiAdjustedBounds* hidpiadjustedbounds);
 extern DLLIMPORT float AzHidpiAdjustedBounds_getHidpiFactor(const AzHidpiAdjustedBounds* hidpiadjustedbounds);
 extern DLLIMPORT AzInlineTextHitVec AzInlineText_hitTest(const AzInlineText* inlinetext, AzLogicalPosition  position);
+extern DLLIMPORT AzOptionLogicalRect AzInlineText_getCursorRect(const AzInlineText* inlinetext, size_t char_index);
+extern DLLIMPORT AzLogicalRectVec AzInlineText_getSelectionRects(const AzInlineText* inlinetext, size_t start, size_t end);
 extern DLLIMPORT void AzInlineText_delete(AzInlineText* restrict instance);
 extern DLLIMPORT void AzInlineLine_delete(AzInlineLine* restrict instance);
 extern DLLIMPORT void AzInlineWord_delete(AzInlineWord* restrict instance);
@@ -12431,10 +15373,14 @@ extern DLLIMPORT void AzDom_setTabIndex(AzDom* restrict dom, AzTabIndex  tab_ind
 extern DLLIMPORT AzDom AzDom_withTabIndex(AzDom* restrict dom, AzTabIndex  tab_index);
 extern DLLIMPORT void AzDom_setAccessibilityInfo(AzDom* restrict dom, AzAccessibilityInfo  accessibility_info);
 extern DLLIMPORT AzDom AzDom_withAccessibilityInfo(AzDom* restrict dom, AzAccessibilityInfo  accessibility_info);
+extern DLLIMPORT void AzDom_setAccessibilityActions(AzDom* restrict dom, AzAccessibilityActionVec  actions);
+extern DLLIMPORT AzDom AzDom_withAccessibilityActions(AzDom* restrict dom, AzAccessibilityActionVec  actions);
 extern DLLIMPORT void AzDom_setMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT AzDom AzDom_withMenuBar(AzDom* restrict dom, AzMenu  menu_bar);
 extern DLLIMPORT void AzDom_setContextMenu(AzDom* restrict dom, AzMenu  context_menu);
 extern DLLIMPORT AzDom AzDom_withContextMenu(AzDom* restrict dom, AzMenu  context_menu);
+extern DLLIMPORT void AzDom_setTooltip(AzDom* restrict dom, AzTooltip  tooltip);
+extern DLLIMPORT AzDom AzDom_withTooltip(AzDom* restrict dom, AzTooltip  tooltip);
 extern DLLIMPORT uint64_t AzDom_hash(const AzDom* dom);
 extern DLLIMPORT size_t AzDom_nodeCount(const AzDom* dom);
 extern DLLIMPORT AzString AzDom_getHtmlString(AzDom* restrict dom);
@@ -12473,13 +15419,24 @@ extern DLLIMPORT AzNodeData AzNodeData_withInlineFocusStyle(AzNodeData* restrict
 extern DLLIMPORT void AzNodeData_setClipMask(AzNodeData* restrict nodedata, AzImageMask  image_mask);
 extern DLLIMPORT void AzNodeData_setTabIndex(AzNodeData* restrict nodedata, AzTabIndex  tab_index);
 extern DLLIMPORT void AzNodeData_setAccessibilityInfo(AzNodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
+extern DLLIMPORT void AzNodeData_setAccessibilityActions(AzNodeData* restrict nodedata, AzAccessibilityActionVec  actions);
+extern DLLIMPORT void AzNodeData_addAccessibilityAction(AzNodeData* restrict nodedata, AzAccessibilityAction  action);
+extern DLLIMPORT AzNodeData AzNodeData_withAccessibilityActions(AzNodeData* restrict nodedata, AzAccessibilityActionVec  actions);
 extern DLLIMPORT void AzNodeData_setMenuBar(AzNodeData* restrict nodedata, AzMenu  menu_bar);
 extern DLLIMPORT void AzNodeData_setContextMenu(AzNodeData* restrict nodedata, AzMenu  context_menu);
+extern DLLIMPORT void AzNodeData_setTooltip(AzNodeData* restrict nodedata, AzTooltip  tooltip);
 extern DLLIMPORT uint64_t AzNodeData_hash(const AzNodeData* nodedata);
 extern DLLIMPORT void AzNodeData_delete(AzNodeData* restrict instance);
 extern DLLIMPORT void AzNodeType_delete(AzNodeType* restrict instance);
 extern DLLIMPORT AzEventFilter AzOn_intoEventFilter(const AzOn on);
 extern DLLIMPORT void AzAccessibilityInfo_delete(AzAccessibilityInfo* restrict instance);
+extern DLLIMPORT AzTooltip AzTooltip_text(AzString  text);
+extern DLLIMPORT AzTooltip AzTooltip_dom(AzRefAny  data, AzTooltipCallbackType  callback);
+extern DLLIMPORT void AzTooltip_setDelay(AzTooltip* restrict tooltip, AzDuration  delay);
+extern DLLIMPORT AzTooltip AzTooltip_withDelay(AzTooltip* restrict tooltip, AzDuration  delay);
+extern DLLIMPORT void AzTooltip_delete(AzTooltip* restrict instance);
+extern DLLIMPORT void AzTooltipContent_delete(AzTooltipContent* restrict instance);
+extern DLLIMPORT void AzTooltipDom_delete(AzTooltipDom* restrict instance);
 extern DLLIMPORT void AzIdOrClass_delete(AzIdOrClass* restrict instance);
 extern DLLIMPORT void AzNodeDataInlineCssProperty_delete(AzNodeDataInlineCssProperty* restrict instance);
 extern DLLIMPORT AzMenu AzMenu_new(AzMenuItemVec  items);
@@ -12505,6 +15462,10 @@ extern DLLIMPORT void AzDynamicCssProperty_delete(AzDynamicCssProperty* restrict
 extern DLLIMPORT void AzCssPath_delete(AzCssPath* restrict instance);
 extern DLLIMPORT void AzCssPathSelector_delete(AzCssPathSelector* restrict instance);
 extern DLLIMPORT void AzStylesheet_delete(AzStylesheet* restrict instance);
+extern DLLIMPORT void AzCssKeyframes_delete(AzCssKeyframes* restrict instance);
+extern DLLIMPORT void AzCssKeyframe_delete(AzCssKeyframe* restrict instance);
+extern DLLIMPORT void AzCssMediaRule_delete(AzCssMediaRule* restrict instance);
+extern DLLIMPORT void AzCssMediaQuery_delete(AzCssMediaQuery* restrict instance);
 extern DLLIMPORT AzCss AzCss_empty();
 extern DLLIMPORT AzCss AzCss_fromString(AzString  s);
 extern DLLIMPORT void AzCss_delete(AzCss* restrict instance);
@@ -12521,6 +15482,16 @@ extern DLLIMPORT void AzStyleBackgroundContent_delete(AzStyleBackgroundContent*
 extern DLLIMPORT void AzScrollbarInfo_delete(AzScrollbarInfo* restrict instance);
 extern DLLIMPORT void AzScrollbarStyle_delete(AzScrollbarStyle* restrict instance);
 extern DLLIMPORT void AzStyleFontFamily_delete(AzStyleFontFamily* restrict instance);
+extern DLLIMPORT void AzStyleAnimationName_delete(AzStyleAnimationName* restrict instance);
+extern DLLIMPORT void AzGridTrackSizingVecValue_delete(AzGridTrackSizingVecValue* restrict instance);
+extern DLLIMPORT void AzStyleTransitionPropertyVecValue_delete(AzStyleTransitionPropertyVecValue* restrict instance);
+extern DLLIMPORT void AzStyleTimeVecValue_delete(AzStyleTimeVecValue* restrict instance);
+extern DLLIMPORT void AzStyleTimingFunctionVecValue_delete(AzStyleTimingFunctionVecValue* restrict instance);
+extern DLLIMPORT void AzStyleAnimationNameVecValue_delete(AzStyleAnimationNameVecValue* restrict instance);
+extern DLLIMPORT void AzStyleAnimationIterationCountVecValue_delete(AzStyleAnimationIterationCountVecValue* restrict instance);
+extern DLLIMPORT void AzStyleAnimationDirectionVecValue_delete(AzStyleAnimationDirectionVecValue* restrict instance);
+extern DLLIMPORT void AzStyleAnimationFillModeVecValue_delete(AzStyleAnimationFillModeVecValue* restrict instance);
+extern DLLIMPORT void AzStyleAnimationPlayStateVecValue_delete(AzStyleAnimationPlayStateVecValue* restrict instance);
 extern DLLIMPORT void AzScrollbarStyleValue_delete(AzScrollbarStyleValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundContentVecValue_delete(AzStyleBackgroundContentVecValue* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundPositionVecValue_delete(AzStyleBackgroundPositionVecValue* restrict instance);
@@ -12534,7 +15505,41 @@ extern DLLIMPORT AzString AzCssProperty_getValueString(const AzCssProperty* cssp
 extern DLLIMPORT AzString AzCssProperty_getKeyValueString(const AzCssProperty* cssproperty);
 extern DLLIMPORT AzCssProperty AzCssProperty_interpolate(const AzCssProperty* cssproperty, AzCssProperty  other, float t, AzInterpolateContext  context);
 extern DLLIMPORT void AzCssProperty_delete(AzCssProperty* restrict instance);
-extern DLLIMPORT AzDom AzRibbon_dom(AzRibbon* restrict ribbon, AzRibbonOnTabClickedCallback  callback, AzRefAny  data);
+extern DLLIMPORT AzRibbon AzRibbon_new(AzRibbonTabVec  tabs);
+extern DLLIMPORT void AzRibbon_setTabActive(AzRibbon* restrict ribbon, int32_t tab_active);
+extern DLLIMPORT AzRibbon AzRibbon_withTabActive(AzRibbon* restrict ribbon, int32_t tab_active);
+extern DLLIMPORT void AzRibbon_setAvailableWidth(AzRibbon* restrict ribbon, float available_width);
+extern DLLIMPORT AzRibbon AzRibbon_withAvailableWidth(AzRibbon* restrict ribbon, float available_width);
+extern DLLIMPORT void AzRibbon_setOnTabClicked(AzRibbon* restrict ribbon, AzRefAny  data, AzRibbonOnTabClickedCallbackType  callback);
+extern DLLIMPORT AzRibbon AzRibbon_withOnTabClicked(AzRibbon* restrict ribbon, AzRefAny  data, AzRibbonOnTabClickedCallbackType  callback);
+extern DLLIMPORT AzDom AzRibbon_dom(AzRibbon* restrict ribbon);
+extern DLLIMPORT void AzRibbon_delete(AzRibbon* restrict instance);
+extern DLLIMPORT AzRibbonTab AzRibbonTab_new(AzString  label, AzRibbonGroupVec  groups);
+extern DLLIMPORT void AzRibbonTab_setKeyTip(AzRibbonTab* restrict ribbontab, AzString  key_tip);
+extern DLLIMPORT AzRibbonTab AzRibbonTab_withKeyTip(AzRibbonTab* restrict ribbontab, AzString  key_tip);
+extern DLLIMPORT void AzRibbonTab_delete(AzRibbonTab* restrict instance);
+extern DLLIMPORT AzRibbonGroup AzRibbonGroup_new(AzString  label, AzRibbonItemVec  items);
+extern DLLIMPORT void AzRibbonGroup_setIcon(AzRibbonGroup* restrict ribbongroup, AzImageRef  icon);
+extern DLLIMPORT AzRibbonGroup AzRibbonGroup_withIcon(AzRibbonGroup* restrict ribbongroup, AzImageRef  icon);
+extern DLLIMPORT void AzRibbonGroup_delete(AzRibbonGroup* restrict instance);
+extern DLLIMPORT AzRibbonItem AzRibbonItem_new(AzRibbonItemKind  kind, AzString  label);
+extern DLLIMPORT void AzRibbonItem_setIcon(AzRibbonItem* restrict ribbonitem, AzImageRef  icon);
+extern DLLIMPORT AzRibbonItem AzRibbonItem_withIcon(AzRibbonItem* restrict ribbonitem, AzImageRef  icon);
+extern DLLIMPORT void AzRibbonItem_setTooltip(AzRibbonItem* restrict ribbonitem, AzString  tooltip);
+extern DLLIMPORT AzRibbonItem AzRibbonItem_withTooltip(AzRibbonItem* restrict ribbonitem, AzString  tooltip);
+extern DLLIMPORT void AzRibbonItem_setKeyTip(AzRibbonItem* restrict ribbonitem, AzString  key_tip);
+extern DLLIMPORT AzRibbonItem AzRibbonItem_withKeyTip(AzRibbonItem* restrict ribbonitem, AzString  key_tip);
+extern DLLIMPORT void AzRibbonItem_setEnabled(AzRibbonItem* restrict ribbonitem, bool  enabled);
+extern DLLIMPORT AzRibbonItem AzRibbonItem_withEnabled(AzRibbonItem* restrict ribbonitem, bool  enabled);
+extern DLLIMPORT void AzRibbonItem_setToggled(AzRibbonItem* restrict ribbonitem, bool  toggled);
+extern DLLIMPORT AzRibbonItem AzRibbonItem_withToggled(AzRibbonItem* restrict ribbonitem, bool  toggled);
+extern DLLIMPORT void AzRibbonItem_setChoices(AzRibbonItem* restrict ribbonitem, AzStringVec  choices);
+extern DLLIMPORT AzRibbonItem AzRibbonItem_withChoices(AzRibbonItem* restrict ribbonitem, AzStringVec  choices);
+extern DLLIMPORT void AzRibbonItem_setOnClick(AzRibbonItem* restrict ribbonitem, AzRefAny  data, AzRibbonOnItemClickCallbackType  callback);
+extern DLLIMPORT AzRibbonItem AzRibbonItem_withOnClick(AzRibbonItem* restrict ribbonitem, AzRefAny  data, AzRibbonOnItemClickCallbackType  callback);
+extern DLLIMPORT void AzRibbonItem_delete(AzRibbonItem* restrict instance);
+extern DLLIMPORT void AzRibbonOnItemClick_delete(AzRibbonOnItemClick* restrict instance);
+extern DLLIMPORT void AzRibbonOnTabClicked_delete(AzRibbonOnTabClicked* restrict instance);
 extern DLLIMPORT AzButton AzButton_new(AzString  label);
 extern DLLIMPORT void AzButton_setOnClick(AzButton* restrict button, AzRefAny  data, AzCallbackType  callback);
 extern DLLIMPORT AzButton AzButton_withOnClick(AzButton* restrict button, AzRefAny  data, AzCallbackType  callback);
@@ -12593,6 +15598,27 @@ extern DLLIMPORT void AzTextInputState_delete(AzTextInputState* restrict instanc
 extern DLLIMPORT void AzTextInputOnTextInput_delete(AzTextInputOnTextInput* restrict instance);
 extern DLLIMPORT void AzTextInputOnVirtualKeyDown_delete(AzTextInputOnVirtualKeyDown* restrict instance);
 extern DLLIMPORT void AzTextInputOnFocusLost_delete(AzTextInputOnFocusLost* restrict instance);
+extern DLLIMPORT AzTextArea AzTextArea_new();
+extern DLLIMPORT void AzTextArea_setText(AzTextArea* restrict textarea, AzString  text);
+extern DLLIMPORT AzTextArea AzTextArea_withText(AzTextArea* restrict textarea, AzString  text);
+extern DLLIMPORT void AzTextArea_setState(AzTextArea* restrict textarea, AzTextAreaState  state);
+extern DLLIMPORT AzTextArea AzTextArea_withState(AzTextArea* restrict textarea, AzTextAreaState  state);
+extern DLLIMPORT void AzTextArea_setOnTextChange(AzTextArea* restrict textarea, AzRefAny  data, AzTextAreaOnTextChangeCallbackType  callback);
+extern DLLIMPORT AzTextArea AzTextArea_withOnTextChange(AzTextArea* restrict textarea, AzRefAny  data, AzTextAreaOnTextChangeCallbackType  callback);
+extern DLLIMPORT AzTextArea AzTextArea_swapWithDefault(AzTextArea* restrict textarea);
+extern DLLIMPORT AzDom AzTextArea_dom(AzTextArea* restrict textarea);
+extern DLLIMPORT void AzTextArea_delete(AzTextArea* restrict instance);
+extern DLLIMPORT AzString AzTextAreaState_getText(const AzTextAreaState* textareastate);
+extern DLLIMPORT void AzTextAreaState_setText(AzTextAreaState* restrict textareastate, AzString  text);
+extern DLLIMPORT AzString AzTextAreaState_getSelectedText(const AzTextAreaState* textareastate);
+extern DLLIMPORT void AzTextAreaState_selectAll(AzTextAreaState* restrict textareastate);
+extern DLLIMPORT bool  AzTextAreaState_canUndo(const AzTextAreaState* textareastate);
+extern DLLIMPORT bool  AzTextAreaState_canRedo(const AzTextAreaState* textareastate);
+extern DLLIMPORT bool  AzTextAreaState_undo(AzTextAreaState* restrict textareastate);
+extern DLLIMPORT bool  AzTextAreaState_redo(AzTextAreaState* restrict textareastate);
+extern DLLIMPORT void AzTextAreaState_delete(AzTextAreaState* restrict instance);
+extern DLLIMPORT void AzTextAreaEdit_delete(AzTextAreaEdit* restrict instance);
+extern DLLIMPORT void AzTextAreaOnTextChange_delete(AzTextAreaOnTextChange* restrict instance);
 extern DLLIMPORT AzNumberInput AzNumberInput_new(float number);
 extern DLLIMPORT void AzNumberInput_setOnTextInput(AzNumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
 extern DLLIMPORT AzNumberInput AzNumberInput_withOnTextInput(AzNumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
@@ -12614,6 +15640,7 @@ extern DLLIMPORT void AzNumberInputStateWrapper_delete(AzNumberInputStateWrapper
 extern DLLIMPORT void AzNumberInputOnValueChange_delete(AzNumberInputOnValueChange* restrict instance);
 extern DLLIMPORT void AzNumberInputOnFocusLost_delete(AzNumberInputOnFocusLost* restrict instance);
 extern DLLIMPORT AzProgressBar AzProgressBar_new(float percent_done);
+extern DLLIMPORT AzProgressBar AzProgressBar_indeterminate();
 extern DLLIMPORT void AzProgressBar_setHeight(AzProgressBar* restrict progressbar, AzPixelValue  height);
 extern DLLIMPORT AzProgressBar AzProgressBar_withHeight(AzProgressBar* restrict progressbar, AzPixelValue  height);
 extern DLLIMPORT void AzProgressBar_setContainerBackground(AzProgressBar* restrict progressbar, AzStyleBackgroundContentVec  background);
@@ -12621,12 +15648,36 @@ extern DLLIMPORT AzProgressBar AzProgressBar_withContainerStyle(AzProgressBar* r
 extern DLLIMPORT void AzProgressBar_setBarBackground(AzProgressBar* restrict progressbar, AzStyleBackgroundContentVec  background);
 extern DLLIMPORT AzProgressBar AzProgressBar_withBarBackground(AzProgressBar* restrict progressbar, AzStyleBackgroundContentVec  background);
 extern DLLIMPORT AzDom AzProgressBar_dom(AzProgressBar* restrict progressbar);
+extern DLLIMPORT void AzProgressBar_setMode(AzProgressBar* restrict progressbar, AzProgressBarMode  mode);
+extern DLLIMPORT AzProgressBar AzProgressBar_withMode(AzProgressBar* restrict progressbar, AzProgressBarMode  mode);
+extern DLLIMPORT void AzProgressBar_setStyle(AzProgressBar* restrict progressbar, AzProgressBarStyle  style);
+extern DLLIMPORT AzProgressBar AzProgressBar_withStyle(AzProgressBar* restrict progressbar, AzProgressBarStyle  style);
+extern DLLIMPORT void AzProgressBar_setDisplayPercentage(AzProgressBar* restrict progressbar, bool  display_percentage);
+extern DLLIMPORT AzProgressBar AzProgressBar_withDisplayPercentage(AzProgressBar* restrict progressbar, bool  display_percentage);
+extern DLLIMPORT void AzProgressBar_setTextFormat(AzProgressBar* restrict progressbar, AzString  text_format);
+extern DLLIMPORT AzProgressBar AzProgressBar_withTextFormat(AzProgressBar* restrict progressbar, AzString  text_format);
 extern DLLIMPORT void AzProgressBar_delete(AzProgressBar* restrict instance);
+extern DLLIMPORT AzSpinner AzSpinner_new();
+extern DLLIMPORT void AzSpinner_setSize(AzSpinner* restrict spinner, AzPixelValue  size);
+extern DLLIMPORT AzSpinner AzSpinner_withSize(AzSpinner* restrict spinner, AzPixelValue  size);
+extern DLLIMPORT void AzSpinner_setThickness(AzSpinner* restrict spinner, AzPixelValue  thickness);
+extern DLLIMPORT AzSpinner AzSpinner_withThickness(AzSpinner* restrict spinner, AzPixelValue  thickness);
+extern DLLIMPORT void AzSpinner_setColor(AzSpinner* restrict spinner, AzColorU  color);
+extern DLLIMPORT AzSpinner AzSpinner_withColor(AzSpinner* restrict spinner, AzColorU  color);
+extern DLLIMPORT void AzSpinner_setTrackColor(AzSpinner* restrict spinner, AzColorU  track_color);
+extern DLLIMPORT AzSpinner AzSpinner_withTrackColor(AzSpinner* restrict spinner, AzColorU  track_color);
+extern DLLIMPORT AzDom AzSpinner_dom(AzSpinner* restrict spinner);
 extern DLLIMPORT AzTabHeader AzTabHeader_new(AzStringVec  tabs);
 extern DLLIMPORT void AzTabHeader_setActiveTab(AzTabHeader* restrict tabheader, size_t active_tab);
 extern DLLIMPORT AzTabHeader AzTabHeader_withActiveTab(AzTabHeader* restrict tabheader, size_t active_tab);
 extern DLLIMPORT void AzTabHeader_setOnClick(AzTabHeader* restrict tabheader, AzRefAny  data, AzTabOnClickCallbackType  callback);
 extern DLLIMPORT AzTabHeader AzTabHeader_withOnClick(AzTabHeader* restrict tabheader, AzRefAny  data, AzTabOnClickCallbackType  callback);
+extern DLLIMPORT void AzTabHeader_setOnClose(AzTabHeader* restrict tabheader, AzRefAny  data, AzTabOnCloseCallbackType  callback);
+extern DLLIMPORT AzTabHeader AzTabHeader_withOnClose(AzTabHeader* restrict tabheader, AzRefAny  data, AzTabOnCloseCallbackType  callback);
+extern DLLIMPORT void AzTabHeader_setOnReorder(AzTabHeader* restrict tabheader, AzRefAny  data, AzTabOnReorderCallbackType  callback);
+extern DLLIMPORT AzTabHeader AzTabHeader_withOnReorder(AzTabHeader* restrict tabheader, AzRefAny  data, AzTabOnReorderCallbackType  callback);
+extern DLLIMPORT void AzTabHeader_setOnContextMenu(AzTabHeader* restrict tabheader, AzRefAny  data, AzTabOnContextMenuCallbackType  callback);
+extern DLLIMPORT AzTabHeader AzTabHeader_withOnContextMenu(AzTabHeader* restrict tabheader, AzRefAny  data, AzTabOnContextMenuCallbackType  callback);
 extern DLLIMPORT AzDom AzTabHeader_dom(AzTabHeader* restrict tabheader);
 extern DLLIMPORT void AzTabHeader_delete(AzTabHeader* restrict instance);
 extern DLLIMPORT AzTabContent AzTabContent_new(AzDom  content);
@@ -12635,11 +15686,53 @@ extern DLLIMPORT AzTabContent AzTabContent_withPadding(AzTabContent* restrict ta
 extern DLLIMPORT AzDom AzTabContent_dom(AzTabContent* restrict tabcontent);
 extern DLLIMPORT void AzTabContent_delete(AzTabContent* restrict instance);
 extern DLLIMPORT void AzTabOnClick_delete(AzTabOnClick* restrict instance);
+extern DLLIMPORT void AzTabOnClose_delete(AzTabOnClose* restrict instance);
+extern DLLIMPORT void AzTabOnReorder_delete(AzTabOnReorder* restrict instance);
+extern DLLIMPORT void AzTabOnContextMenu_delete(AzTabOnContextMenu* restrict instance);
 extern DLLIMPORT AzFrame AzFrame_new(AzString  title, AzDom  dom);
 extern DLLIMPORT void AzFrame_setFlexGrow(AzFrame* restrict frame, float flex_grow);
 extern DLLIMPORT AzFrame AzFrame_withFlexGrow(AzFrame* restrict frame, float flex_grow);
 extern DLLIMPORT AzDom AzFrame_dom(AzFrame* restrict frame);
 extern DLLIMPORT void AzFrame_delete(AzFrame* restrict instance);
+extern DLLIMPORT AzSplitter AzSplitter_new(AzSplitterOrientation  orientation, AzSplitterPaneVec  panes);
+extern DLLIMPORT void AzSplitter_setState(AzSplitter* restrict splitter, AzSplitterState  state);
+extern DLLIMPORT AzSplitter AzSplitter_withState(AzSplitter* restrict splitter, AzSplitterState  state);
+extern DLLIMPORT void AzSplitter_setOnResize(AzSplitter* restrict splitter, AzRefAny  data, AzSplitterOnResizeCallbackType  callback);
+extern DLLIMPORT AzSplitter AzSplitter_withOnResize(AzSplitter* restrict splitter, AzRefAny  data, AzSplitterOnResizeCallbackType  callback);
+extern DLLIMPORT AzDom AzSplitter_dom(AzSplitter* restrict splitter);
+extern DLLIMPORT void AzSplitter_delete(AzSplitter* restrict instance);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_new(AzDom  content);
+extern DLLIMPORT void AzSplitterPane_setSize(AzSplitterPane* restrict splitterpane, float size);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_withSize(AzSplitterPane* restrict splitterpane, float size);
+extern DLLIMPORT void AzSplitterPane_setMinSize(AzSplitterPane* restrict splitterpane, float min_size);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_withMinSize(AzSplitterPane* restrict splitterpane, float min_size);
+extern DLLIMPORT void AzSplitterPane_setMaxSize(AzSplitterPane* restrict splitterpane, float max_size);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_withMaxSize(AzSplitterPane* restrict splitterpane, float max_size);
+extern DLLIMPORT void AzSplitterPane_setCollapsible(AzSplitterPane* restrict splitterpane, bool  collapsible);
+extern DLLIMPORT AzSplitterPane AzSplitterPane_withCollapsible(AzSplitterPane* restrict splitterpane, bool  collapsible);
+extern DLLIMPORT void AzSplitterPane_delete(AzSplitterPane* restrict instance);
+extern DLLIMPORT void AzSplitterState_delete(AzSplitterState* restrict instance);
+extern DLLIMPORT void AzSplitterOnResize_delete(AzSplitterOnResize* restrict instance);
+extern DLLIMPORT AzSlider AzSlider_new(AzSliderOrientation  orientation, float value, float min, float max);
+extern DLLIMPORT void AzSlider_setStep(AzSlider* restrict slider, float step);
+extern DLLIMPORT AzSlider AzSlider_withStep(AzSlider* restrict slider, float step);
+extern DLLIMPORT void AzSlider_setTickInterval(AzSlider* restrict slider, float tick_interval);
+extern DLLIMPORT AzSlider AzSlider_withTickInterval(AzSlider* restrict slider, float tick_interval);
+extern DLLIMPORT void AzSlider_setOnValueChange(AzSlider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+extern DLLIMPORT AzSlider AzSlider_withOnValueChange(AzSlider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+extern DLLIMPORT AzDom AzSlider_dom(AzSlider* restrict slider);
+extern DLLIMPORT void AzSlider_delete(AzSlider* restrict instance);
+extern DLLIMPORT AzRangeSlider AzRangeSlider_new(AzSliderOrientation  orientation, float low, float high, float min, float max);
+extern DLLIMPORT void AzRangeSlider_setStep(AzRangeSlider* restrict rangeslider, float step);
+extern DLLIMPORT AzRangeSlider AzRangeSlider_withStep(AzRangeSlider* restrict rangeslider, float step);
+extern DLLIMPORT void AzRangeSlider_setTickInterval(AzRangeSlider* restrict rangeslider, float tick_interval);
+extern DLLIMPORT AzRangeSlider AzRangeSlider_withTickInterval(AzRangeSlider* restrict rangeslider, float tick_interval);
+extern DLLIMPORT void AzRangeSlider_setOnValueChange(AzRangeSlider* restrict rangeslider, AzRefAny  data, AzRangeSliderOnValueChangeCallbackType  callback);
+extern DLLIMPORT AzRangeSlider AzRangeSlider_withOnValueChange(AzRangeSlider* restrict rangeslider, AzRefAny  data, AzRangeSliderOnValueChangeCallbackType  callback);
+extern DLLIMPORT AzDom AzRangeSlider_dom(AzRangeSlider* restrict rangeslider);
+extern DLLIMPORT void AzRangeSlider_delete(AzRangeSlider* restrict instance);
+extern DLLIMPORT void AzSliderOnValueChange_delete(AzSliderOnValueChange* restrict instance);
+extern DLLIMPORT void AzRangeSliderOnValueChange_delete(AzRangeSliderOnValueChange* restrict instance);
 extern DLLIMPORT AzDom AzNodeGraph_dom(AzNodeGraph* restrict nodegraph);
 extern DLLIMPORT void AzNodeGraph_delete(AzNodeGraph* restrict instance);
 extern DLLIMPORT void AzNodeTypeIdInfoMap_delete(AzNodeTypeIdInfoMap* restrict instance);
@@ -12663,6 +15756,24 @@ extern DLLIMPORT void AzNodeTypeInfo_delete(AzNodeTypeInfo* restrict instance);
 extern DLLIMPORT void AzInputOutputInfo_delete(AzInputOutputInfo* restrict instance);
 extern DLLIMPORT AzListView AzListView_new(AzStringVec  columns);
 extern DLLIMPORT AzListView AzListView_withRows(AzListView* restrict listview, AzListViewRowVec  rows);
+extern DLLIMPORT void AzListView_setSortedBy(AzListView* restrict listview, AzOptionUsize  sorted_by);
+extern DLLIMPORT AzListView AzListView_withSortedBy(AzListView* restrict listview, AzOptionUsize  sorted_by);
+extern DLLIMPORT void AzListView_setScrollOffset(AzListView* restrict listview, AzPixelValueNoPercent  scroll_offset);
+extern DLLIMPORT AzListView AzListView_withScrollOffset(AzListView* restrict listview, AzPixelValueNoPercent  scroll_offset);
+extern DLLIMPORT void AzListView_setRowHeight(AzListView* restrict listview, AzPixelValueNoPercent  row_height);
+extern DLLIMPORT AzListView AzListView_withRowHeight(AzListView* restrict listview, AzPixelValueNoPercent  row_height);
+extern DLLIMPORT void AzListView_setColumnWidths(AzListView* restrict listview, AzF32Vec  column_widths);
+extern DLLIMPORT AzListView AzListView_withColumnWidths(AzListView* restrict listview, AzF32Vec  column_widths);
+extern DLLIMPORT void AzListView_setRowProvider(AzListView* restrict listview, size_t row_count, AzRefAny  data, AzListViewRowProviderCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withRowProvider(AzListView* restrict listview, size_t row_count, AzRefAny  data, AzListViewRowProviderCallbackType  callback);
+extern DLLIMPORT void AzListView_setOnLazyLoadScroll(AzListView* restrict listview, AzRefAny  data, AzListViewOnLazyLoadScrollCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withOnLazyLoadScroll(AzListView* restrict listview, AzRefAny  data, AzListViewOnLazyLoadScrollCallbackType  callback);
+extern DLLIMPORT void AzListView_setOnColumnClick(AzListView* restrict listview, AzRefAny  data, AzListViewOnColumnClickCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withOnColumnClick(AzListView* restrict listview, AzRefAny  data, AzListViewOnColumnClickCallbackType  callback);
+extern DLLIMPORT void AzListView_setOnColumnResize(AzListView* restrict listview, AzRefAny  data, AzListViewOnColumnResizeCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withOnColumnResize(AzListView* restrict listview, AzRefAny  data, AzListViewOnColumnResizeCallbackType  callback);
+extern DLLIMPORT void AzListView_setOnRowClick(AzListView* restrict listview, AzRefAny  data, AzListViewOnRowClickCallbackType  callback);
+extern DLLIMPORT AzListView AzListView_withOnRowClick(AzListView* restrict listview, AzRefAny  data, AzListViewOnRowClickCallbackType  callback);
 extern DLLIMPORT AzDom AzListView_dom(AzListView* restrict listview);
 extern DLLIMPORT void AzListView_delete(AzListView* restrict instance);
 extern DLLIMPORT void AzListViewRow_delete(AzListViewRow* restrict instance);
@@ -12670,10 +15781,40 @@ extern DLLIMPORT void AzListViewState_delete(AzListViewState* restrict instance)
 extern DLLIMPORT void AzListViewOnLazyLoadScroll_delete(AzListViewOnLazyLoadScroll* restrict instance);
 extern DLLIMPORT void AzListViewOnColumnClick_delete(AzListViewOnColumnClick* restrict instance);
 extern DLLIMPORT void AzListViewOnRowClick_delete(AzListViewOnRowClick* restrict instance);
-extern DLLIMPORT AzTreeView AzTreeView_new(AzString  root);
+extern DLLIMPORT void AzListViewOnColumnResize_delete(AzListViewOnColumnResize* restrict instance);
+extern DLLIMPORT void AzListViewRowProvider_delete(AzListViewRowProvider* restrict instance);
+extern DLLIMPORT AzTreeView AzTreeView_new(AzTreeViewNodeVec  nodes);
+extern DLLIMPORT void AzTreeView_setNodes(AzTreeView* restrict treeview, AzTreeViewNodeVec  nodes);
+extern DLLIMPORT AzTreeView AzTreeView_withNodes(AzTreeView* restrict treeview, AzTreeViewNodeVec  nodes);
+extern DLLIMPORT void AzTreeView_setState(AzTreeView* restrict treeview, AzTreeViewState  state);
+extern DLLIMPORT AzTreeView AzTreeView_withState(AzTreeView* restrict treeview, AzTreeViewState  state);
+extern DLLIMPORT void AzTreeView_setSelectionMode(AzTreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+extern DLLIMPORT AzTreeView AzTreeView_withSelectionMode(AzTreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+extern DLLIMPORT void AzTreeView_setOnExpand(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnExpandCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnExpand(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnExpandCallbackType  callback);
+extern DLLIMPORT void AzTreeView_setOnSelect(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnSelect(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectCallbackType  callback);
+extern DLLIMPORT void AzTreeView_setOnDoubleClick(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnDoubleClickCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnDoubleClick(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnDoubleClickCallbackType  callback);
+extern DLLIMPORT void AzTreeView_setOnContextMenu(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnContextMenuCallbackType  callback);
+extern DLLIMPORT AzTreeView AzTreeView_withOnContextMenu(AzTreeView* restrict treeview, AzRefAny  data, AzTreeViewOnContextMenuCallbackType  callback);
 extern DLLIMPORT AzDom AzTreeView_dom(AzTreeView* restrict treeview);
 extern DLLIMPORT void AzTreeView_delete(AzTreeView* restrict instance);
+extern DLLIMPORT AzTreeViewNode AzTreeViewNode_new(AzTreeViewNodeId  id, AzOptionTreeViewNodeId  parent, AzString  label);
+extern DLLIMPORT void AzTreeViewNode_delete(AzTreeViewNode* restrict instance);
+extern DLLIMPORT void AzTreeViewState_delete(AzTreeViewState* restrict instance);
+extern DLLIMPORT void AzTreeViewOnExpand_delete(AzTreeViewOnExpand* restrict instance);
+extern DLLIMPORT void AzTreeViewOnSelect_delete(AzTreeViewOnSelect* restrict instance);
+extern DLLIMPORT void AzTreeViewOnDoubleClick_delete(AzTreeViewOnDoubleClick* restrict instance);
+extern DLLIMPORT void AzTreeViewOnContextMenu_delete(AzTreeViewOnContextMenu* restrict instance);
 extern DLLIMPORT AzDropDown AzDropDown_new(AzStringVec  choices);
+extern DLLIMPORT void AzDropDown_setSelected(AzDropDown* restrict dropdown, size_t selected);
+extern DLLIMPORT AzDropDown AzDropDown_withSelected(AzDropDown* restrict dropdown, size_t selected);
+extern DLLIMPORT void AzDropDown_setDisabledChoices(AzDropDown* restrict dropdown, AzU32Vec  disabled_choices);
+extern DLLIMPORT AzDropDown AzDropDown_withDisabledChoices(AzDropDown* restrict dropdown, AzU32Vec  disabled_choices);
+extern DLLIMPORT void AzDropDown_setOnChoiceChange(AzDropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+extern DLLIMPORT AzDropDown AzDropDown_withOnChoiceChange(AzDropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+extern DLLIMPORT AzDropDown AzDropDown_swapWithDefault(AzDropDown* restrict dropdown);
 extern DLLIMPORT AzDom AzDropDown_dom(AzDropDown* restrict dropdown);
 extern DLLIMPORT void AzDropDown_delete(AzDropDown* restrict instance);
 extern DLLIMPORT void AzDropDownOnChoiceChange_delete(AzDropDownOnChoiceChange* restrict instance);
@@ -13154,6 +16295,14 @@ extern DLLIMPORT AzString AzString_trim(const AzString* string);
 extern DLLIMPORT AzRefstr AzString_asRefstr(const AzString* string);
 extern DLLIMPORT void AzString_delete(AzString* restrict instance);
 extern DLLIMPORT void AzListViewRowVec_delete(AzListViewRowVec* restrict instance);
+extern DLLIMPORT void AzTreeViewNodeVec_delete(AzTreeViewNodeVec* restrict instance);
+extern DLLIMPORT void AzTreeViewNodeIdVec_delete(AzTreeViewNodeIdVec* restrict instance);
+extern DLLIMPORT void AzRibbonTabVec_delete(AzRibbonTabVec* restrict instance);
+extern DLLIMPORT void AzRibbonGroupVec_delete(AzRibbonGroupVec* restrict instance);
+extern DLLIMPORT void AzRibbonItemVec_delete(AzRibbonItemVec* restrict instance);
+extern DLLIMPORT void AzSplitterPaneVec_delete(AzSplitterPaneVec* restrict instance);
+extern DLLIMPORT void AzSplitterPaneStateVec_delete(AzSplitterPaneStateVec* restrict instance);
+extern DLLIMPORT void AzTextAreaEditVec_delete(AzTextAreaEditVec* restrict instance);
 extern DLLIMPORT void AzStyleFilterVec_delete(AzStyleFilterVec* restrict instance);
 extern DLLIMPORT void AzLogicalRectVec_delete(AzLogicalRectVec* restrict instance);
 extern DLLIMPORT void AzNodeTypeIdInfoMapVec_delete(AzNodeTypeIdInfoMapVec* restrict instance);
@@ -13166,6 +16315,7 @@ extern DLLIMPORT void AzOutputNodeAndIndexVec_delete(AzOutputNodeAndIndexVec* re
 extern DLLIMPORT void AzOutputConnectionVec_delete(AzOutputConnectionVec* restrict instance);
 extern DLLIMPORT void AzInputNodeAndIndexVec_delete(AzInputNodeAndIndexVec* restrict instance);
 extern DLLIMPORT void AzAccessibilityStateVec_delete(AzAccessibilityStateVec* restrict instance);
+extern DLLIMPORT void AzAccessibilityActionVec_delete(AzAccessibilityActionVec* restrict instance);
 extern DLLIMPORT void AzMenuItemVec_delete(AzMenuItemVec* restrict instance);
 extern DLLIMPORT AzTessellatedSvgNodeVecRef AzTessellatedSvgNodeVec_asRefVec(const AzTessellatedSvgNodeVec* tessellatedsvgnodevec);
 extern DLLIMPORT void AzTessellatedSvgNodeVec_delete(AzTessellatedSvgNodeVec* restrict instance);
@@ -13186,6 +16336,15 @@ extern DLLIMPORT void AzStyleBackgroundPositionVec_delete(AzStyleBackgroundPosit
 extern DLLIMPORT void AzStyleBackgroundRepeatVec_delete(AzStyleBackgroundRepeatVec* restrict instance);
 extern DLLIMPORT void AzStyleBackgroundSizeVec_delete(AzStyleBackgroundSizeVec* restrict instance);
 extern DLLIMPORT void AzStyleTransformVec_delete(AzStyleTransformVec* restrict instance);
+extern DLLIMPORT void AzStyleTransitionPropertyVec_delete(AzStyleTransitionPropertyVec* restrict instance);
+extern DLLIMPORT void AzStyleTimeVec_delete(AzStyleTimeVec* restrict instance);
+extern DLLIMPORT void AzStyleTimingFunctionVec_delete(AzStyleTimingFunctionVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationNameVec_delete(AzStyleAnimationNameVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationIterationCountVec_delete(AzStyleAnimationIterationCountVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationDirectionVec_delete(AzStyleAnimationDirectionVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationFillModeVec_delete(AzStyleAnimationFillModeVec* restrict instance);
+extern DLLIMPORT void AzStyleAnimationPlayStateVec_delete(AzStyleAnimationPlayStateVec* restrict instance);
+extern DLLIMPORT void AzGridTrackSizingVec_delete(AzGridTrackSizingVec* restrict instance);
 extern DLLIMPORT void AzCssPropertyVec_delete(AzCssPropertyVec* restrict instance);
 extern DLLIMPORT void AzSvgMultiPolygonVec_delete(AzSvgMultiPolygonVec* restrict instance);
 extern DLLIMPORT void AzSvgPathVec_delete(AzSvgPathVec* restrict instance);
@@ -13200,6 +16359,11 @@ extern DLLIMPORT void AzScanCodeVec_delete(AzScanCodeVec* restrict instance);
 extern DLLIMPORT void AzCssDeclarationVec_delete(AzCssDeclarationVec* restrict instance);
 extern DLLIMPORT void AzCssPathSelectorVec_delete(AzCssPathSelectorVec* restrict instance);
 extern DLLIMPORT void AzStylesheetVec_delete(AzStylesheetVec* restrict instance);
+extern DLLIMPORT void AzCssKeyframesVec_delete(AzCssKeyframesVec* restrict instance);
+extern DLLIMPORT void AzCssKeyframeVec_delete(AzCssKeyframeVec* restrict instance);
+extern DLLIMPORT void AzCssMediaRuleVec_delete(AzCssMediaRuleVec* restrict instance);
+extern DLLIMPORT void AzCssMediaQueryVec_delete(AzCssMediaQueryVec* restrict instance);
+extern DLLIMPORT void AzCssMediaFeatureVec_delete(AzCssMediaFeatureVec* restrict instance);
 extern DLLIMPORT void AzCssRuleBlockVec_delete(AzCssRuleBlockVec* restrict instance);
 extern DLLIMPORT void AzU16Vec_delete(AzU16Vec* restrict instance);
 extern DLLIMPORT void AzF32Vec_delete(AzF32Vec* restrict instance);
@@ -13221,8 +16385,14 @@ extern DLLIMPORT void AzTagIdToNodeIdMappingVec_delete(AzTagIdToNodeIdMappingVec
 extern DLLIMPORT void AzParentWithNodeDepthVec_delete(AzParentWithNodeDepthVec* restrict instance);
 extern DLLIMPORT void AzNodeDataVec_delete(AzNodeDataVec* restrict instance);
 extern DLLIMPORT void AzOptionListViewOnRowClick_delete(AzOptionListViewOnRowClick* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnExpand_delete(AzOptionTreeViewOnExpand* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnSelect_delete(AzOptionTreeViewOnSelect* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnDoubleClick_delete(AzOptionTreeViewOnDoubleClick* restrict instance);
+extern DLLIMPORT void AzOptionTreeViewOnContextMenu_delete(AzOptionTreeViewOnContextMenu* restrict instance);
 extern DLLIMPORT void AzOptionListViewOnColumnClick_delete(AzOptionListViewOnColumnClick* restrict instance);
 extern DLLIMPORT void AzOptionListViewOnLazyLoadScroll_delete(AzOptionListViewOnLazyLoadScroll* restrict instance);
+extern DLLIMPORT void AzOptionListViewOnColumnResize_delete(AzOptionListViewOnColumnResize* restrict instance);
+extern DLLIMPORT void AzOptionListViewRowProvider_delete(AzOptionListViewRowProvider* restrict instance);
 extern DLLIMPORT void AzOptionMenu_delete(AzOptionMenu* restrict instance);
 extern DLLIMPORT void AzOptionDropDownOnChoiceChange_delete(AzOptionDropDownOnChoiceChange* restrict instance);
 extern DLLIMPORT void AzOptionResolvedTextLayoutOptions_delete(AzOptionResolvedTextLayoutOptions* restrict instance);
@@ -13237,11 +16407,20 @@ extern DLLIMPORT void AzOptionNodeGraphOnNodeFieldEdited_delete(AzOptionNodeGrap
 extern DLLIMPORT void AzOptionColorInputOnValueChange_delete(AzOptionColorInputOnValueChange* restrict instance);
 extern DLLIMPORT void AzOptionButtonOnClick_delete(AzOptionButtonOnClick* restrict instance);
 extern DLLIMPORT void AzOptionTabOnClick_delete(AzOptionTabOnClick* restrict instance);
+extern DLLIMPORT void AzOptionTabOnClose_delete(AzOptionTabOnClose* restrict instance);
+extern DLLIMPORT void AzOptionTabOnReorder_delete(AzOptionTabOnReorder* restrict instance);
+extern DLLIMPORT void AzOptionTabOnContextMenu_delete(AzOptionTabOnContextMenu* restrict instance);
+extern DLLIMPORT void AzOptionRibbonOnTabClicked_delete(AzOptionRibbonOnTabClicked* restrict instance);
+extern DLLIMPORT void AzOptionRibbonOnItemClick_delete(AzOptionRibbonOnItemClick* restrict instance);
+extern DLLIMPORT void AzOptionSplitterOnResize_delete(AzOptionSplitterOnResize* restrict instance);
+extern DLLIMPORT void AzOptionSliderOnValueChange_delete(AzOptionSliderOnValueChange* restrict instance);
+extern DLLIMPORT void AzOptionRangeSliderOnValueChange_delete(AzOptionRangeSliderOnValueChange* restrict instance);
 extern DLLIMPORT void AzOptionFileInputOnPathChange_delete(AzOptionFileInputOnPathChange* restrict instance);
 extern DLLIMPORT void AzOptionCheckBoxOnToggle_delete(AzOptionCheckBoxOnToggle* restrict instance);
 extern DLLIMPORT void AzOptionTextInputOnTextInput_delete(AzOptionTextInputOnTextInput* restrict instance);
 extern DLLIMPORT void AzOptionTextInputOnVirtualKeyDown_delete(AzOptionTextInputOnVirtualKeyDown* restrict instance);
 extern DLLIMPORT void AzOptionTextInputOnFocusLost_delete(AzOptionTextInputOnFocusLost* restrict instance);
+extern DLLIMPORT void AzOptionTextAreaOnTextChange_delete(AzOptionTextAreaOnTextChange* restrict instance);
 extern DLLIMPORT void AzOptionNumberInputOnFocusLost_delete(AzOptionNumberInputOnFocusLost* restrict instance);
 extern DLLIMPORT void AzOptionNumberInputOnValueChange_delete(AzOptionNumberInputOnValueChange* restrict instance);
 extern DLLIMPORT void AzOptionMenuItemIcon_delete(AzOptionMenuItemIcon* restrict instance);
@@ -13264,6 +16443,8 @@ extern DLLIMPORT void AzOptionInlineText_delete(AzOptionInlineText* restrict ins
 extern DLLIMPORT void AzOptionRawImage_delete(AzOptionRawImage* restrict instance);
 extern DLLIMPORT void AzOptionWaylandTheme_delete(AzOptionWaylandTheme* restrict instance);
 extern DLLIMPORT void AzOptionTaskBarIcon_delete(AzOptionTaskBarIcon* restrict instance);
+extern DLLIMPORT void AzOptionImeComposition_delete(AzOptionImeComposition* restrict instance);
+extern DLLIMPORT void AzOptionClipboardContent_delete(AzOptionClipboardContent* restrict instance);
 extern DLLIMPORT void AzOptionWindowIcon_delete(AzOptionWindowIcon* restrict instance);
 extern DLLIMPORT void AzOptionString_delete(AzOptionString* restrict instance);
 extern DLLIMPORT void AzOptionDom_delete(AzOptionDom* restrict instance);
@@ -15246,6 +18427,34 @@ bool AzNotEventFilter_matchMutFocus(AzNotEventFilter* restrict value, AzFocusEve
     return valid;
 }
 
+bool AzTooltipContent_matchRefText(const AzTooltipContent* value, const AzString** restrict out) {
+    const AzTooltipContentVariant_Text* casted = (const AzTooltipContentVariant_Text*)value;
+    bool valid = casted->tag == AzTooltipContentTag_Text;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTooltipContent_matchMutText(AzTooltipContent* restrict value, AzString* restrict * restrict out) {
+    AzTooltipContentVariant_Text* restrict casted = (AzTooltipContentVariant_Text* restrict)value;
+    bool valid = casted->tag == AzTooltipContentTag_Text;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTooltipContent_matchRefDom(const AzTooltipContent* value, const AzTooltipDom** restrict out) {
+    const AzTooltipContentVariant_Dom* casted = (const AzTooltipContentVariant_Dom*)value;
+    bool valid = casted->tag == AzTooltipContentTag_Dom;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTooltipContent_matchMutDom(AzTooltipContent* restrict value, AzTooltipDom* restrict * restrict out) {
+    AzTooltipContentVariant_Dom* restrict casted = (AzTooltipContentVariant_Dom* restrict)value;
+    bool valid = casted->tag == AzTooltipContentTag_Dom;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzTabIndex_matchRefOverrideInParent(const AzTabIndex* value, const Azu32** restrict out) {
     const AzTabIndexVariant_OverrideInParent* casted = (const AzTabIndexVariant_OverrideInParent*)value;
     bool valid = casted->tag == AzTabIndexTag_OverrideInParent;
@@ -15512,6 +18721,76 @@ bool AzCssNthChildSelector_matchMutPattern(AzCssNthChildSelector* restrict value
     return valid;
 }
 
+bool AzCssMediaFeature_matchRefWidth(const AzCssMediaFeature* value, const AzCssMediaRange** restrict out) {
+    const AzCssMediaFeatureVariant_Width* casted = (const AzCssMediaFeatureVariant_Width*)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_Width;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeature_matchMutWidth(AzCssMediaFeature* restrict value, AzCssMediaRange* restrict * restrict out) {
+    AzCssMediaFeatureVariant_Width* restrict casted = (AzCssMediaFeatureVariant_Width* restrict)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_Width;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeature_matchRefHeight(const AzCssMediaFeature* value, const AzCssMediaRange** restrict out) {
+    const AzCssMediaFeatureVariant_Height* casted = (const AzCssMediaFeatureVariant_Height*)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_Height;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeature_matchMutHeight(AzCssMediaFeature* restrict value, AzCssMediaRange* restrict * restrict out) {
+    AzCssMediaFeatureVariant_Height* restrict casted = (AzCssMediaFeatureVariant_Height* restrict)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_Height;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeature_matchRefResolution(const AzCssMediaFeature* value, const AzCssMediaRange** restrict out) {
+    const AzCssMediaFeatureVariant_Resolution* casted = (const AzCssMediaFeatureVariant_Resolution*)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_Resolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeature_matchMutResolution(AzCssMediaFeature* restrict value, AzCssMediaRange* restrict * restrict out) {
+    AzCssMediaFeatureVariant_Resolution* restrict casted = (AzCssMediaFeatureVariant_Resolution* restrict)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_Resolution;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeature_matchRefOrientation(const AzCssMediaFeature* value, const AzCssMediaOrientation** restrict out) {
+    const AzCssMediaFeatureVariant_Orientation* casted = (const AzCssMediaFeatureVariant_Orientation*)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_Orientation;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeature_matchMutOrientation(AzCssMediaFeature* restrict value, AzCssMediaOrientation* restrict * restrict out) {
+    AzCssMediaFeatureVariant_Orientation* restrict casted = (AzCssMediaFeatureVariant_Orientation* restrict)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_Orientation;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeature_matchRefPrefersColorScheme(const AzCssMediaFeature* value, const AzCssColorScheme** restrict out) {
+    const AzCssMediaFeatureVariant_PrefersColorScheme* casted = (const AzCssMediaFeatureVariant_PrefersColorScheme*)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_PrefersColorScheme;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeature_matchMutPrefersColorScheme(AzCssMediaFeature* restrict value, AzCssColorScheme* restrict * restrict out) {
+    AzCssMediaFeatureVariant_PrefersColorScheme* restrict casted = (AzCssMediaFeatureVariant_PrefersColorScheme* restrict)value;
+    bool valid = casted->tag == AzCssMediaFeatureTag_PrefersColorScheme;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzAnimationInterpolationFunction_matchRefCubicBezier(const AzAnimationInterpolationFunction* value, const AzSvgCubicCurve** restrict out) {
     const AzAnimationInterpolationFunctionVariant_CubicBezier* casted = (const AzAnimationInterpolationFunctionVariant_CubicBezier*)value;
     bool valid = casted->tag == AzAnimationInterpolationFunctionTag_CubicBezier;
@@ -15652,6 +18931,62 @@ bool AzStyleCompositeFilter_matchMutArithmetic(AzStyleCompositeFilter* restrict
     return valid;
 }
 
+bool AzGridTrackBreadth_matchRefFixed(const AzGridTrackBreadth* value, const AzPixelValue** restrict out) {
+    const AzGridTrackBreadthVariant_Fixed* casted = (const AzGridTrackBreadthVariant_Fixed*)value;
+    bool valid = casted->tag == AzGridTrackBreadthTag_Fixed;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrackBreadth_matchMutFixed(AzGridTrackBreadth* restrict value, AzPixelValue* restrict * restrict out) {
+    AzGridTrackBreadthVariant_Fixed* restrict casted = (AzGridTrackBreadthVariant_Fixed* restrict)value;
+    bool valid = casted->tag == AzGridTrackBreadthTag_Fixed;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrackBreadth_matchRefFraction(const AzGridTrackBreadth* value, const AzFloatValue** restrict out) {
+    const AzGridTrackBreadthVariant_Fraction* casted = (const AzGridTrackBreadthVariant_Fraction*)value;
+    bool valid = casted->tag == AzGridTrackBreadthTag_Fraction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridTrackBreadth_matchMutFraction(AzGridTrackBreadth* restrict value, AzFloatValue* restrict * restrict out) {
+    AzGridTrackBreadthVariant_Fraction* restrict casted = (AzGridTrackBreadthVariant_Fraction* restrict)value;
+    bool valid = casted->tag == AzGridTrackBreadthTag_Fraction;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchRefLine(const AzGridLine* value, const Azi32** restrict out) {
+    const AzGridLineVariant_Line* casted = (const AzGridLineVariant_Line*)value;
+    bool valid = casted->tag == AzGridLineTag_Line;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchMutLine(AzGridLine* restrict value, Azi32* restrict * restrict out) {
+    AzGridLineVariant_Line* restrict casted = (AzGridLineVariant_Line* restrict)value;
+    bool valid = casted->tag == AzGridLineTag_Line;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchRefSpan(const AzGridLine* value, const Azu32** restrict out) {
+    const AzGridLineVariant_Span* casted = (const AzGridLineVariant_Span*)value;
+    bool valid = casted->tag == AzGridLineTag_Span;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzGridLine_matchMutSpan(AzGridLine* restrict value, Azu32* restrict * restrict out) {
+    AzGridLineVariant_Span* restrict casted = (AzGridLineVariant_Span* restrict)value;
+    bool valid = casted->tag == AzGridLineTag_Span;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzDirection_matchRefAngle(const AzDirection* value, const AzAngleValue** restrict out) {
     const AzDirectionVariant_Angle* casted = (const AzDirectionVariant_Angle*)value;
     bool valid = casted->tag == AzDirectionTag_Angle;
@@ -16128,177 +19463,457 @@ bool AzStyleTransform_matchMutPerspective(AzStyleTransform* restrict value, AzPi
     return valid;
 }
 
-bool AzStyleBoxShadowValue_matchRefExact(const AzStyleBoxShadowValue* value, const AzStyleBoxShadow** restrict out) {
-    const AzStyleBoxShadowValueVariant_Exact* casted = (const AzStyleBoxShadowValueVariant_Exact*)value;
-    bool valid = casted->tag == AzStyleBoxShadowValueTag_Exact;
+bool AzStyleTimingFunction_matchRefCubicBezier(const AzStyleTimingFunction* value, const AzStyleCubicBezier** restrict out) {
+    const AzStyleTimingFunctionVariant_CubicBezier* casted = (const AzStyleTimingFunctionVariant_CubicBezier*)value;
+    bool valid = casted->tag == AzStyleTimingFunctionTag_CubicBezier;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBoxShadowValue_matchMutExact(AzStyleBoxShadowValue* restrict value, AzStyleBoxShadow* restrict * restrict out) {
-    AzStyleBoxShadowValueVariant_Exact* restrict casted = (AzStyleBoxShadowValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzStyleBoxShadowValueTag_Exact;
+bool AzStyleTimingFunction_matchMutCubicBezier(AzStyleTimingFunction* restrict value, AzStyleCubicBezier* restrict * restrict out) {
+    AzStyleTimingFunctionVariant_CubicBezier* restrict casted = (AzStyleTimingFunctionVariant_CubicBezier* restrict)value;
+    bool valid = casted->tag == AzStyleTimingFunctionTag_CubicBezier;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutAlignContentValue_matchRefExact(const AzLayoutAlignContentValue* value, const AzLayoutAlignContent** restrict out) {
-    const AzLayoutAlignContentValueVariant_Exact* casted = (const AzLayoutAlignContentValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutAlignContentValueTag_Exact;
+bool AzStyleTimingFunction_matchRefSteps(const AzStyleTimingFunction* value, const AzStyleSteps** restrict out) {
+    const AzStyleTimingFunctionVariant_Steps* casted = (const AzStyleTimingFunctionVariant_Steps*)value;
+    bool valid = casted->tag == AzStyleTimingFunctionTag_Steps;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutAlignContentValue_matchMutExact(AzLayoutAlignContentValue* restrict value, AzLayoutAlignContent* restrict * restrict out) {
-    AzLayoutAlignContentValueVariant_Exact* restrict casted = (AzLayoutAlignContentValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutAlignContentValueTag_Exact;
+bool AzStyleTimingFunction_matchMutSteps(AzStyleTimingFunction* restrict value, AzStyleSteps* restrict * restrict out) {
+    AzStyleTimingFunctionVariant_Steps* restrict casted = (AzStyleTimingFunctionVariant_Steps* restrict)value;
+    bool valid = casted->tag == AzStyleTimingFunctionTag_Steps;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutAlignItemsValue_matchRefExact(const AzLayoutAlignItemsValue* value, const AzLayoutAlignItems** restrict out) {
-    const AzLayoutAlignItemsValueVariant_Exact* casted = (const AzLayoutAlignItemsValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutAlignItemsValueTag_Exact;
+bool AzStyleTransitionProperty_matchRefProperty(const AzStyleTransitionProperty* value, const AzCssPropertyType** restrict out) {
+    const AzStyleTransitionPropertyVariant_Property* casted = (const AzStyleTransitionPropertyVariant_Property*)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyTag_Property;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutAlignItemsValue_matchMutExact(AzLayoutAlignItemsValue* restrict value, AzLayoutAlignItems* restrict * restrict out) {
-    AzLayoutAlignItemsValueVariant_Exact* restrict casted = (AzLayoutAlignItemsValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutAlignItemsValueTag_Exact;
+bool AzStyleTransitionProperty_matchMutProperty(AzStyleTransitionProperty* restrict value, AzCssPropertyType* restrict * restrict out) {
+    AzStyleTransitionPropertyVariant_Property* restrict casted = (AzStyleTransitionPropertyVariant_Property* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyTag_Property;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutBottomValue_matchRefExact(const AzLayoutBottomValue* value, const AzLayoutBottom** restrict out) {
-    const AzLayoutBottomValueVariant_Exact* casted = (const AzLayoutBottomValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutBottomValueTag_Exact;
+bool AzStyleTransitionProperty_matchRefShorthand(const AzStyleTransitionProperty* value, const AzCombinedCssPropertyType** restrict out) {
+    const AzStyleTransitionPropertyVariant_Shorthand* casted = (const AzStyleTransitionPropertyVariant_Shorthand*)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyTag_Shorthand;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutBottomValue_matchMutExact(AzLayoutBottomValue* restrict value, AzLayoutBottom* restrict * restrict out) {
-    AzLayoutBottomValueVariant_Exact* restrict casted = (AzLayoutBottomValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutBottomValueTag_Exact;
+bool AzStyleTransitionProperty_matchMutShorthand(AzStyleTransitionProperty* restrict value, AzCombinedCssPropertyType* restrict * restrict out) {
+    AzStyleTransitionPropertyVariant_Shorthand* restrict casted = (AzStyleTransitionPropertyVariant_Shorthand* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyTag_Shorthand;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutBoxSizingValue_matchRefExact(const AzLayoutBoxSizingValue* value, const AzLayoutBoxSizing** restrict out) {
-    const AzLayoutBoxSizingValueVariant_Exact* casted = (const AzLayoutBoxSizingValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutBoxSizingValueTag_Exact;
+bool AzStyleAnimationName_matchRefName(const AzStyleAnimationName* value, const AzString** restrict out) {
+    const AzStyleAnimationNameVariant_Name* casted = (const AzStyleAnimationNameVariant_Name*)value;
+    bool valid = casted->tag == AzStyleAnimationNameTag_Name;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutBoxSizingValue_matchMutExact(AzLayoutBoxSizingValue* restrict value, AzLayoutBoxSizing* restrict * restrict out) {
-    AzLayoutBoxSizingValueVariant_Exact* restrict casted = (AzLayoutBoxSizingValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutBoxSizingValueTag_Exact;
+bool AzStyleAnimationName_matchMutName(AzStyleAnimationName* restrict value, AzString* restrict * restrict out) {
+    AzStyleAnimationNameVariant_Name* restrict casted = (AzStyleAnimationNameVariant_Name* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationNameTag_Name;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutFlexDirectionValue_matchRefExact(const AzLayoutFlexDirectionValue* value, const AzLayoutFlexDirection** restrict out) {
-    const AzLayoutFlexDirectionValueVariant_Exact* casted = (const AzLayoutFlexDirectionValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutFlexDirectionValueTag_Exact;
+bool AzStyleAnimationIterationCount_matchRefCount(const AzStyleAnimationIterationCount* value, const AzFloatValue** restrict out) {
+    const AzStyleAnimationIterationCountVariant_Count* casted = (const AzStyleAnimationIterationCountVariant_Count*)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountTag_Count;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutFlexDirectionValue_matchMutExact(AzLayoutFlexDirectionValue* restrict value, AzLayoutFlexDirection* restrict * restrict out) {
-    AzLayoutFlexDirectionValueVariant_Exact* restrict casted = (AzLayoutFlexDirectionValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutFlexDirectionValueTag_Exact;
+bool AzStyleAnimationIterationCount_matchMutCount(AzStyleAnimationIterationCount* restrict value, AzFloatValue* restrict * restrict out) {
+    AzStyleAnimationIterationCountVariant_Count* restrict casted = (AzStyleAnimationIterationCountVariant_Count* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountTag_Count;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutDisplayValue_matchRefExact(const AzLayoutDisplayValue* value, const AzLayoutDisplay** restrict out) {
-    const AzLayoutDisplayValueVariant_Exact* casted = (const AzLayoutDisplayValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutDisplayValueTag_Exact;
+bool AzStyleBoxShadowValue_matchRefExact(const AzStyleBoxShadowValue* value, const AzStyleBoxShadow** restrict out) {
+    const AzStyleBoxShadowValueVariant_Exact* casted = (const AzStyleBoxShadowValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleBoxShadowValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutDisplayValue_matchMutExact(AzLayoutDisplayValue* restrict value, AzLayoutDisplay* restrict * restrict out) {
-    AzLayoutDisplayValueVariant_Exact* restrict casted = (AzLayoutDisplayValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutDisplayValueTag_Exact;
+bool AzStyleBoxShadowValue_matchMutExact(AzStyleBoxShadowValue* restrict value, AzStyleBoxShadow* restrict * restrict out) {
+    AzStyleBoxShadowValueVariant_Exact* restrict casted = (AzStyleBoxShadowValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleBoxShadowValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutFlexGrowValue_matchRefExact(const AzLayoutFlexGrowValue* value, const AzLayoutFlexGrow** restrict out) {
-    const AzLayoutFlexGrowValueVariant_Exact* casted = (const AzLayoutFlexGrowValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutFlexGrowValueTag_Exact;
+bool AzLayoutAlignContentValue_matchRefExact(const AzLayoutAlignContentValue* value, const AzLayoutAlignContent** restrict out) {
+    const AzLayoutAlignContentValueVariant_Exact* casted = (const AzLayoutAlignContentValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutAlignContentValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutFlexGrowValue_matchMutExact(AzLayoutFlexGrowValue* restrict value, AzLayoutFlexGrow* restrict * restrict out) {
-    AzLayoutFlexGrowValueVariant_Exact* restrict casted = (AzLayoutFlexGrowValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutFlexGrowValueTag_Exact;
+bool AzLayoutAlignContentValue_matchMutExact(AzLayoutAlignContentValue* restrict value, AzLayoutAlignContent* restrict * restrict out) {
+    AzLayoutAlignContentValueVariant_Exact* restrict casted = (AzLayoutAlignContentValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutAlignContentValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutFlexShrinkValue_matchRefExact(const AzLayoutFlexShrinkValue* value, const AzLayoutFlexShrink** restrict out) {
-    const AzLayoutFlexShrinkValueVariant_Exact* casted = (const AzLayoutFlexShrinkValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutFlexShrinkValueTag_Exact;
+bool AzGridTrackSizingVecValue_matchRefExact(const AzGridTrackSizingVecValue* value, const AzGridTrackSizingVec** restrict out) {
+    const AzGridTrackSizingVecValueVariant_Exact* casted = (const AzGridTrackSizingVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzGridTrackSizingVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutFlexShrinkValue_matchMutExact(AzLayoutFlexShrinkValue* restrict value, AzLayoutFlexShrink* restrict * restrict out) {
-    AzLayoutFlexShrinkValueVariant_Exact* restrict casted = (AzLayoutFlexShrinkValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutFlexShrinkValueTag_Exact;
+bool AzGridTrackSizingVecValue_matchMutExact(AzGridTrackSizingVecValue* restrict value, AzGridTrackSizingVec* restrict * restrict out) {
+    AzGridTrackSizingVecValueVariant_Exact* restrict casted = (AzGridTrackSizingVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzGridTrackSizingVecValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutFloatValue_matchRefExact(const AzLayoutFloatValue* value, const AzLayoutFloat** restrict out) {
-    const AzLayoutFloatValueVariant_Exact* casted = (const AzLayoutFloatValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutFloatValueTag_Exact;
+bool AzGridTrackSizingValue_matchRefExact(const AzGridTrackSizingValue* value, const AzGridTrackSizing** restrict out) {
+    const AzGridTrackSizingValueVariant_Exact* casted = (const AzGridTrackSizingValueVariant_Exact*)value;
+    bool valid = casted->tag == AzGridTrackSizingValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutFloatValue_matchMutExact(AzLayoutFloatValue* restrict value, AzLayoutFloat* restrict * restrict out) {
-    AzLayoutFloatValueVariant_Exact* restrict casted = (AzLayoutFloatValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutFloatValueTag_Exact;
+bool AzGridTrackSizingValue_matchMutExact(AzGridTrackSizingValue* restrict value, AzGridTrackSizing* restrict * restrict out) {
+    AzGridTrackSizingValueVariant_Exact* restrict casted = (AzGridTrackSizingValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzGridTrackSizingValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutHeightValue_matchRefExact(const AzLayoutHeightValue* value, const AzLayoutHeight** restrict out) {
-    const AzLayoutHeightValueVariant_Exact* casted = (const AzLayoutHeightValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutHeightValueTag_Exact;
+bool AzLayoutGridAutoFlowValue_matchRefExact(const AzLayoutGridAutoFlowValue* value, const AzLayoutGridAutoFlow** restrict out) {
+    const AzLayoutGridAutoFlowValueVariant_Exact* casted = (const AzLayoutGridAutoFlowValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutGridAutoFlowValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutHeightValue_matchMutExact(AzLayoutHeightValue* restrict value, AzLayoutHeight* restrict * restrict out) {
-    AzLayoutHeightValueVariant_Exact* restrict casted = (AzLayoutHeightValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutHeightValueTag_Exact;
+bool AzLayoutGridAutoFlowValue_matchMutExact(AzLayoutGridAutoFlowValue* restrict value, AzLayoutGridAutoFlow* restrict * restrict out) {
+    AzLayoutGridAutoFlowValueVariant_Exact* restrict casted = (AzLayoutGridAutoFlowValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutGridAutoFlowValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutJustifyContentValue_matchRefExact(const AzLayoutJustifyContentValue* value, const AzLayoutJustifyContent** restrict out) {
-    const AzLayoutJustifyContentValueVariant_Exact* casted = (const AzLayoutJustifyContentValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutJustifyContentValueTag_Exact;
+bool AzGridPlacementValue_matchRefExact(const AzGridPlacementValue* value, const AzGridPlacement** restrict out) {
+    const AzGridPlacementValueVariant_Exact* casted = (const AzGridPlacementValueVariant_Exact*)value;
+    bool valid = casted->tag == AzGridPlacementValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutJustifyContentValue_matchMutExact(AzLayoutJustifyContentValue* restrict value, AzLayoutJustifyContent* restrict * restrict out) {
-    AzLayoutJustifyContentValueVariant_Exact* restrict casted = (AzLayoutJustifyContentValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzLayoutJustifyContentValueTag_Exact;
+bool AzGridPlacementValue_matchMutExact(AzGridPlacementValue* restrict value, AzGridPlacement* restrict * restrict out) {
+    AzGridPlacementValueVariant_Exact* restrict casted = (AzGridPlacementValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzGridPlacementValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzLayoutLeftValue_matchRefExact(const AzLayoutLeftValue* value, const AzLayoutLeft** restrict out) {
-    const AzLayoutLeftValueVariant_Exact* casted = (const AzLayoutLeftValueVariant_Exact*)value;
-    bool valid = casted->tag == AzLayoutLeftValueTag_Exact;
+bool AzLayoutRowGapValue_matchRefExact(const AzLayoutRowGapValue* value, const AzLayoutRowGap** restrict out) {
+    const AzLayoutRowGapValueVariant_Exact* casted = (const AzLayoutRowGapValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutRowGapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutRowGapValue_matchMutExact(AzLayoutRowGapValue* restrict value, AzLayoutRowGap* restrict * restrict out) {
+    AzLayoutRowGapValueVariant_Exact* restrict casted = (AzLayoutRowGapValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutRowGapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutColumnGapValue_matchRefExact(const AzLayoutColumnGapValue* value, const AzLayoutColumnGap** restrict out) {
+    const AzLayoutColumnGapValueVariant_Exact* casted = (const AzLayoutColumnGapValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutColumnGapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutColumnGapValue_matchMutExact(AzLayoutColumnGapValue* restrict value, AzLayoutColumnGap* restrict * restrict out) {
+    AzLayoutColumnGapValueVariant_Exact* restrict casted = (AzLayoutColumnGapValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutColumnGapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionPropertyVecValue_matchRefExact(const AzStyleTransitionPropertyVecValue* value, const AzStyleTransitionPropertyVec** restrict out) {
+    const AzStyleTransitionPropertyVecValueVariant_Exact* casted = (const AzStyleTransitionPropertyVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransitionPropertyVecValue_matchMutExact(AzStyleTransitionPropertyVecValue* restrict value, AzStyleTransitionPropertyVec* restrict * restrict out) {
+    AzStyleTransitionPropertyVecValueVariant_Exact* restrict casted = (AzStyleTransitionPropertyVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTimeVecValue_matchRefExact(const AzStyleTimeVecValue* value, const AzStyleTimeVec** restrict out) {
+    const AzStyleTimeVecValueVariant_Exact* casted = (const AzStyleTimeVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTimeVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTimeVecValue_matchMutExact(AzStyleTimeVecValue* restrict value, AzStyleTimeVec* restrict * restrict out) {
+    AzStyleTimeVecValueVariant_Exact* restrict casted = (AzStyleTimeVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTimeVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTimingFunctionVecValue_matchRefExact(const AzStyleTimingFunctionVecValue* value, const AzStyleTimingFunctionVec** restrict out) {
+    const AzStyleTimingFunctionVecValueVariant_Exact* casted = (const AzStyleTimingFunctionVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleTimingFunctionVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTimingFunctionVecValue_matchMutExact(AzStyleTimingFunctionVecValue* restrict value, AzStyleTimingFunctionVec* restrict * restrict out) {
+    AzStyleTimingFunctionVecValueVariant_Exact* restrict casted = (AzStyleTimingFunctionVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleTimingFunctionVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationNameVecValue_matchRefExact(const AzStyleAnimationNameVecValue* value, const AzStyleAnimationNameVec** restrict out) {
+    const AzStyleAnimationNameVecValueVariant_Exact* casted = (const AzStyleAnimationNameVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationNameVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationNameVecValue_matchMutExact(AzStyleAnimationNameVecValue* restrict value, AzStyleAnimationNameVec* restrict * restrict out) {
+    AzStyleAnimationNameVecValueVariant_Exact* restrict casted = (AzStyleAnimationNameVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationNameVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationIterationCountVecValue_matchRefExact(const AzStyleAnimationIterationCountVecValue* value, const AzStyleAnimationIterationCountVec** restrict out) {
+    const AzStyleAnimationIterationCountVecValueVariant_Exact* casted = (const AzStyleAnimationIterationCountVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationIterationCountVecValue_matchMutExact(AzStyleAnimationIterationCountVecValue* restrict value, AzStyleAnimationIterationCountVec* restrict * restrict out) {
+    AzStyleAnimationIterationCountVecValueVariant_Exact* restrict casted = (AzStyleAnimationIterationCountVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDirectionVecValue_matchRefExact(const AzStyleAnimationDirectionVecValue* value, const AzStyleAnimationDirectionVec** restrict out) {
+    const AzStyleAnimationDirectionVecValueVariant_Exact* casted = (const AzStyleAnimationDirectionVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationDirectionVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationDirectionVecValue_matchMutExact(AzStyleAnimationDirectionVecValue* restrict value, AzStyleAnimationDirectionVec* restrict * restrict out) {
+    AzStyleAnimationDirectionVecValueVariant_Exact* restrict casted = (AzStyleAnimationDirectionVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationDirectionVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationFillModeVecValue_matchRefExact(const AzStyleAnimationFillModeVecValue* value, const AzStyleAnimationFillModeVec** restrict out) {
+    const AzStyleAnimationFillModeVecValueVariant_Exact* casted = (const AzStyleAnimationFillModeVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationFillModeVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationFillModeVecValue_matchMutExact(AzStyleAnimationFillModeVecValue* restrict value, AzStyleAnimationFillModeVec* restrict * restrict out) {
+    AzStyleAnimationFillModeVecValueVariant_Exact* restrict casted = (AzStyleAnimationFillModeVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationFillModeVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationPlayStateVecValue_matchRefExact(const AzStyleAnimationPlayStateVecValue* value, const AzStyleAnimationPlayStateVec** restrict out) {
+    const AzStyleAnimationPlayStateVecValueVariant_Exact* casted = (const AzStyleAnimationPlayStateVecValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleAnimationPlayStateVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleAnimationPlayStateVecValue_matchMutExact(AzStyleAnimationPlayStateVecValue* restrict value, AzStyleAnimationPlayStateVec* restrict * restrict out) {
+    AzStyleAnimationPlayStateVecValueVariant_Exact* restrict casted = (AzStyleAnimationPlayStateVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationPlayStateVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutAlignItemsValue_matchRefExact(const AzLayoutAlignItemsValue* value, const AzLayoutAlignItems** restrict out) {
+    const AzLayoutAlignItemsValueVariant_Exact* casted = (const AzLayoutAlignItemsValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutAlignItemsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutAlignItemsValue_matchMutExact(AzLayoutAlignItemsValue* restrict value, AzLayoutAlignItems* restrict * restrict out) {
+    AzLayoutAlignItemsValueVariant_Exact* restrict casted = (AzLayoutAlignItemsValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutAlignItemsValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutBottomValue_matchRefExact(const AzLayoutBottomValue* value, const AzLayoutBottom** restrict out) {
+    const AzLayoutBottomValueVariant_Exact* casted = (const AzLayoutBottomValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutBottomValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutBottomValue_matchMutExact(AzLayoutBottomValue* restrict value, AzLayoutBottom* restrict * restrict out) {
+    AzLayoutBottomValueVariant_Exact* restrict casted = (AzLayoutBottomValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutBottomValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutBoxSizingValue_matchRefExact(const AzLayoutBoxSizingValue* value, const AzLayoutBoxSizing** restrict out) {
+    const AzLayoutBoxSizingValueVariant_Exact* casted = (const AzLayoutBoxSizingValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutBoxSizingValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutBoxSizingValue_matchMutExact(AzLayoutBoxSizingValue* restrict value, AzLayoutBoxSizing* restrict * restrict out) {
+    AzLayoutBoxSizingValueVariant_Exact* restrict casted = (AzLayoutBoxSizingValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutBoxSizingValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutFlexDirectionValue_matchRefExact(const AzLayoutFlexDirectionValue* value, const AzLayoutFlexDirection** restrict out) {
+    const AzLayoutFlexDirectionValueVariant_Exact* casted = (const AzLayoutFlexDirectionValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutFlexDirectionValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutFlexDirectionValue_matchMutExact(AzLayoutFlexDirectionValue* restrict value, AzLayoutFlexDirection* restrict * restrict out) {
+    AzLayoutFlexDirectionValueVariant_Exact* restrict casted = (AzLayoutFlexDirectionValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutFlexDirectionValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutDisplayValue_matchRefExact(const AzLayoutDisplayValue* value, const AzLayoutDisplay** restrict out) {
+    const AzLayoutDisplayValueVariant_Exact* casted = (const AzLayoutDisplayValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutDisplayValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutDisplayValue_matchMutExact(AzLayoutDisplayValue* restrict value, AzLayoutDisplay* restrict * restrict out) {
+    AzLayoutDisplayValueVariant_Exact* restrict casted = (AzLayoutDisplayValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutDisplayValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutFlexGrowValue_matchRefExact(const AzLayoutFlexGrowValue* value, const AzLayoutFlexGrow** restrict out) {
+    const AzLayoutFlexGrowValueVariant_Exact* casted = (const AzLayoutFlexGrowValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutFlexGrowValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutFlexGrowValue_matchMutExact(AzLayoutFlexGrowValue* restrict value, AzLayoutFlexGrow* restrict * restrict out) {
+    AzLayoutFlexGrowValueVariant_Exact* restrict casted = (AzLayoutFlexGrowValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutFlexGrowValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutFlexShrinkValue_matchRefExact(const AzLayoutFlexShrinkValue* value, const AzLayoutFlexShrink** restrict out) {
+    const AzLayoutFlexShrinkValueVariant_Exact* casted = (const AzLayoutFlexShrinkValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutFlexShrinkValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutFlexShrinkValue_matchMutExact(AzLayoutFlexShrinkValue* restrict value, AzLayoutFlexShrink* restrict * restrict out) {
+    AzLayoutFlexShrinkValueVariant_Exact* restrict casted = (AzLayoutFlexShrinkValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutFlexShrinkValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutFloatValue_matchRefExact(const AzLayoutFloatValue* value, const AzLayoutFloat** restrict out) {
+    const AzLayoutFloatValueVariant_Exact* casted = (const AzLayoutFloatValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutFloatValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutFloatValue_matchMutExact(AzLayoutFloatValue* restrict value, AzLayoutFloat* restrict * restrict out) {
+    AzLayoutFloatValueVariant_Exact* restrict casted = (AzLayoutFloatValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutFloatValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutHeightValue_matchRefExact(const AzLayoutHeightValue* value, const AzLayoutHeight** restrict out) {
+    const AzLayoutHeightValueVariant_Exact* casted = (const AzLayoutHeightValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutHeightValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutHeightValue_matchMutExact(AzLayoutHeightValue* restrict value, AzLayoutHeight* restrict * restrict out) {
+    AzLayoutHeightValueVariant_Exact* restrict casted = (AzLayoutHeightValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutHeightValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutJustifyContentValue_matchRefExact(const AzLayoutJustifyContentValue* value, const AzLayoutJustifyContent** restrict out) {
+    const AzLayoutJustifyContentValueVariant_Exact* casted = (const AzLayoutJustifyContentValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutJustifyContentValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutJustifyContentValue_matchMutExact(AzLayoutJustifyContentValue* restrict value, AzLayoutJustifyContent* restrict * restrict out) {
+    AzLayoutJustifyContentValueVariant_Exact* restrict casted = (AzLayoutJustifyContentValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzLayoutJustifyContentValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzLayoutLeftValue_matchRefExact(const AzLayoutLeftValue* value, const AzLayoutLeft** restrict out) {
+    const AzLayoutLeftValueVariant_Exact* casted = (const AzLayoutLeftValueVariant_Exact*)value;
+    bool valid = casted->tag == AzLayoutLeftValueTag_Exact;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
@@ -16954,6 +20569,62 @@ bool AzStyleTextAlignValue_matchMutExact(AzStyleTextAlignValue* restrict value,
     return valid;
 }
 
+bool AzStyleDirectionValue_matchRefExact(const AzStyleDirectionValue* value, const AzStyleDirection** restrict out) {
+    const AzStyleDirectionValueVariant_Exact* casted = (const AzStyleDirectionValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleDirectionValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleDirectionValue_matchMutExact(AzStyleDirectionValue* restrict value, AzStyleDirection* restrict * restrict out) {
+    AzStyleDirectionValueVariant_Exact* restrict casted = (AzStyleDirectionValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleDirectionValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleWordBreakValue_matchRefExact(const AzStyleWordBreakValue* value, const AzStyleWordBreak** restrict out) {
+    const AzStyleWordBreakValueVariant_Exact* casted = (const AzStyleWordBreakValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleWordBreakValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleWordBreakValue_matchMutExact(AzStyleWordBreakValue* restrict value, AzStyleWordBreak* restrict * restrict out) {
+    AzStyleWordBreakValueVariant_Exact* restrict casted = (AzStyleWordBreakValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleWordBreakValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleOverflowWrapValue_matchRefExact(const AzStyleOverflowWrapValue* value, const AzStyleOverflowWrap** restrict out) {
+    const AzStyleOverflowWrapValueVariant_Exact* casted = (const AzStyleOverflowWrapValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleOverflowWrapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleOverflowWrapValue_matchMutExact(AzStyleOverflowWrapValue* restrict value, AzStyleOverflowWrap* restrict * restrict out) {
+    AzStyleOverflowWrapValueVariant_Exact* restrict casted = (AzStyleOverflowWrapValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleOverflowWrapValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleHyphensValue_matchRefExact(const AzStyleHyphensValue* value, const AzStyleHyphens** restrict out) {
+    const AzStyleHyphensValueVariant_Exact* casted = (const AzStyleHyphensValueVariant_Exact*)value;
+    bool valid = casted->tag == AzStyleHyphensValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleHyphensValue_matchMutExact(AzStyleHyphensValue* restrict value, AzStyleHyphens* restrict * restrict out) {
+    AzStyleHyphensValueVariant_Exact* restrict casted = (AzStyleHyphensValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleHyphensValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleTextColorValue_matchRefExact(const AzStyleTextColorValue* value, const AzStyleTextColor** restrict out) {
     const AzStyleTextColorValueVariant_Exact* casted = (const AzStyleTextColorValueVariant_Exact*)value;
     bool valid = casted->tag == AzStyleTextColorValueTag_Exact;
@@ -17073,65 +20744,121 @@ bool AzStyleFilterVecValue_matchRefExact(const AzStyleFilterVecValue* value, con
     return valid;
 }
 
-bool AzStyleFilterVecValue_matchMutExact(AzStyleFilterVecValue* restrict value, AzStyleFilterVec* restrict * restrict out) {
-    AzStyleFilterVecValueVariant_Exact* restrict casted = (AzStyleFilterVecValueVariant_Exact* restrict)value;
-    bool valid = casted->tag == AzStyleFilterVecValueTag_Exact;
+bool AzStyleFilterVecValue_matchMutExact(AzStyleFilterVecValue* restrict value, AzStyleFilterVec* restrict * restrict out) {
+    AzStyleFilterVecValueVariant_Exact* restrict casted = (AzStyleFilterVecValueVariant_Exact* restrict)value;
+    bool valid = casted->tag == AzStyleFilterVecValueTag_Exact;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTextColor(const AzCssProperty* value, const AzStyleTextColorValue** restrict out) {
+    const AzCssPropertyVariant_TextColor* casted = (const AzCssPropertyVariant_TextColor*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TextColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTextColor(AzCssProperty* restrict value, AzStyleTextColorValue* restrict * restrict out) {
+    AzCssPropertyVariant_TextColor* restrict casted = (AzCssPropertyVariant_TextColor* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TextColor;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefFontSize(const AzCssProperty* value, const AzStyleFontSizeValue** restrict out) {
+    const AzCssPropertyVariant_FontSize* casted = (const AzCssPropertyVariant_FontSize*)value;
+    bool valid = casted->tag == AzCssPropertyTag_FontSize;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutFontSize(AzCssProperty* restrict value, AzStyleFontSizeValue* restrict * restrict out) {
+    AzCssPropertyVariant_FontSize* restrict casted = (AzCssPropertyVariant_FontSize* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_FontSize;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefFontFamily(const AzCssProperty* value, const AzStyleFontFamilyVecValue** restrict out) {
+    const AzCssPropertyVariant_FontFamily* casted = (const AzCssPropertyVariant_FontFamily*)value;
+    bool valid = casted->tag == AzCssPropertyTag_FontFamily;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutFontFamily(AzCssProperty* restrict value, AzStyleFontFamilyVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_FontFamily* restrict casted = (AzCssPropertyVariant_FontFamily* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_FontFamily;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTextAlign(const AzCssProperty* value, const AzStyleTextAlignValue** restrict out) {
+    const AzCssPropertyVariant_TextAlign* casted = (const AzCssPropertyVariant_TextAlign*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TextAlign;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTextAlign(AzCssProperty* restrict value, AzStyleTextAlignValue* restrict * restrict out) {
+    AzCssPropertyVariant_TextAlign* restrict casted = (AzCssPropertyVariant_TextAlign* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TextAlign;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefTextColor(const AzCssProperty* value, const AzStyleTextColorValue** restrict out) {
-    const AzCssPropertyVariant_TextColor* casted = (const AzCssPropertyVariant_TextColor*)value;
-    bool valid = casted->tag == AzCssPropertyTag_TextColor;
+bool AzCssProperty_matchRefDirection(const AzCssProperty* value, const AzStyleDirectionValue** restrict out) {
+    const AzCssPropertyVariant_Direction* casted = (const AzCssPropertyVariant_Direction*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Direction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutTextColor(AzCssProperty* restrict value, AzStyleTextColorValue* restrict * restrict out) {
-    AzCssPropertyVariant_TextColor* restrict casted = (AzCssPropertyVariant_TextColor* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_TextColor;
+bool AzCssProperty_matchMutDirection(AzCssProperty* restrict value, AzStyleDirectionValue* restrict * restrict out) {
+    AzCssPropertyVariant_Direction* restrict casted = (AzCssPropertyVariant_Direction* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Direction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefFontSize(const AzCssProperty* value, const AzStyleFontSizeValue** restrict out) {
-    const AzCssPropertyVariant_FontSize* casted = (const AzCssPropertyVariant_FontSize*)value;
-    bool valid = casted->tag == AzCssPropertyTag_FontSize;
+bool AzCssProperty_matchRefWordBreak(const AzCssProperty* value, const AzStyleWordBreakValue** restrict out) {
+    const AzCssPropertyVariant_WordBreak* casted = (const AzCssPropertyVariant_WordBreak*)value;
+    bool valid = casted->tag == AzCssPropertyTag_WordBreak;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutFontSize(AzCssProperty* restrict value, AzStyleFontSizeValue* restrict * restrict out) {
-    AzCssPropertyVariant_FontSize* restrict casted = (AzCssPropertyVariant_FontSize* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_FontSize;
+bool AzCssProperty_matchMutWordBreak(AzCssProperty* restrict value, AzStyleWordBreakValue* restrict * restrict out) {
+    AzCssPropertyVariant_WordBreak* restrict casted = (AzCssPropertyVariant_WordBreak* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_WordBreak;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefFontFamily(const AzCssProperty* value, const AzStyleFontFamilyVecValue** restrict out) {
-    const AzCssPropertyVariant_FontFamily* casted = (const AzCssPropertyVariant_FontFamily*)value;
-    bool valid = casted->tag == AzCssPropertyTag_FontFamily;
+bool AzCssProperty_matchRefOverflowWrap(const AzCssProperty* value, const AzStyleOverflowWrapValue** restrict out) {
+    const AzCssPropertyVariant_OverflowWrap* casted = (const AzCssPropertyVariant_OverflowWrap*)value;
+    bool valid = casted->tag == AzCssPropertyTag_OverflowWrap;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutFontFamily(AzCssProperty* restrict value, AzStyleFontFamilyVecValue* restrict * restrict out) {
-    AzCssPropertyVariant_FontFamily* restrict casted = (AzCssPropertyVariant_FontFamily* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_FontFamily;
+bool AzCssProperty_matchMutOverflowWrap(AzCssProperty* restrict value, AzStyleOverflowWrapValue* restrict * restrict out) {
+    AzCssPropertyVariant_OverflowWrap* restrict casted = (AzCssPropertyVariant_OverflowWrap* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_OverflowWrap;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefTextAlign(const AzCssProperty* value, const AzStyleTextAlignValue** restrict out) {
-    const AzCssPropertyVariant_TextAlign* casted = (const AzCssPropertyVariant_TextAlign*)value;
-    bool valid = casted->tag == AzCssPropertyTag_TextAlign;
+bool AzCssProperty_matchRefHyphens(const AzCssProperty* value, const AzStyleHyphensValue** restrict out) {
+    const AzCssPropertyVariant_Hyphens* casted = (const AzCssPropertyVariant_Hyphens*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Hyphens;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutTextAlign(AzCssProperty* restrict value, AzStyleTextAlignValue* restrict * restrict out) {
-    AzCssPropertyVariant_TextAlign* restrict casted = (AzCssPropertyVariant_TextAlign* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_TextAlign;
+bool AzCssProperty_matchMutHyphens(AzCssProperty* restrict value, AzStyleHyphensValue* restrict * restrict out) {
+    AzCssPropertyVariant_Hyphens* restrict casted = (AzCssPropertyVariant_Hyphens* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Hyphens;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
@@ -17500,6 +21227,132 @@ bool AzCssProperty_matchMutAlignContent(AzCssProperty* restrict value, AzLayoutA
     return valid;
 }
 
+bool AzCssProperty_matchRefGridTemplateColumns(const AzCssProperty* value, const AzGridTrackSizingVecValue** restrict out) {
+    const AzCssPropertyVariant_GridTemplateColumns* casted = (const AzCssPropertyVariant_GridTemplateColumns*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateColumns;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridTemplateColumns(AzCssProperty* restrict value, AzGridTrackSizingVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridTemplateColumns* restrict casted = (AzCssPropertyVariant_GridTemplateColumns* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateColumns;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefGridTemplateRows(const AzCssProperty* value, const AzGridTrackSizingVecValue** restrict out) {
+    const AzCssPropertyVariant_GridTemplateRows* casted = (const AzCssPropertyVariant_GridTemplateRows*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateRows;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridTemplateRows(AzCssProperty* restrict value, AzGridTrackSizingVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridTemplateRows* restrict casted = (AzCssPropertyVariant_GridTemplateRows* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridTemplateRows;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefGridAutoColumns(const AzCssProperty* value, const AzGridTrackSizingValue** restrict out) {
+    const AzCssPropertyVariant_GridAutoColumns* casted = (const AzCssPropertyVariant_GridAutoColumns*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridAutoColumns;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridAutoColumns(AzCssProperty* restrict value, AzGridTrackSizingValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridAutoColumns* restrict casted = (AzCssPropertyVariant_GridAutoColumns* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridAutoColumns;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefGridAutoRows(const AzCssProperty* value, const AzGridTrackSizingValue** restrict out) {
+    const AzCssPropertyVariant_GridAutoRows* casted = (const AzCssPropertyVariant_GridAutoRows*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridAutoRows;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridAutoRows(AzCssProperty* restrict value, AzGridTrackSizingValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridAutoRows* restrict casted = (AzCssPropertyVariant_GridAutoRows* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridAutoRows;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefGridAutoFlow(const AzCssProperty* value, const AzLayoutGridAutoFlowValue** restrict out) {
+    const AzCssPropertyVariant_GridAutoFlow* casted = (const AzCssPropertyVariant_GridAutoFlow*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridAutoFlow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridAutoFlow(AzCssProperty* restrict value, AzLayoutGridAutoFlowValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridAutoFlow* restrict casted = (AzCssPropertyVariant_GridAutoFlow* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridAutoFlow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefGridColumn(const AzCssProperty* value, const AzGridPlacementValue** restrict out) {
+    const AzCssPropertyVariant_GridColumn* casted = (const AzCssPropertyVariant_GridColumn*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridColumn;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridColumn(AzCssProperty* restrict value, AzGridPlacementValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridColumn* restrict casted = (AzCssPropertyVariant_GridColumn* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridColumn;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefGridRow(const AzCssProperty* value, const AzGridPlacementValue** restrict out) {
+    const AzCssPropertyVariant_GridRow* casted = (const AzCssPropertyVariant_GridRow*)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridRow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutGridRow(AzCssProperty* restrict value, AzGridPlacementValue* restrict * restrict out) {
+    AzCssPropertyVariant_GridRow* restrict casted = (AzCssPropertyVariant_GridRow* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_GridRow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefRowGap(const AzCssProperty* value, const AzLayoutRowGapValue** restrict out) {
+    const AzCssPropertyVariant_RowGap* casted = (const AzCssPropertyVariant_RowGap*)value;
+    bool valid = casted->tag == AzCssPropertyTag_RowGap;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutRowGap(AzCssProperty* restrict value, AzLayoutRowGapValue* restrict * restrict out) {
+    AzCssPropertyVariant_RowGap* restrict casted = (AzCssPropertyVariant_RowGap* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_RowGap;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefColumnGap(const AzCssProperty* value, const AzLayoutColumnGapValue** restrict out) {
+    const AzCssPropertyVariant_ColumnGap* casted = (const AzCssPropertyVariant_ColumnGap*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ColumnGap;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutColumnGap(AzCssProperty* restrict value, AzLayoutColumnGapValue* restrict * restrict out) {
+    AzCssPropertyVariant_ColumnGap* restrict casted = (AzCssPropertyVariant_ColumnGap* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ColumnGap;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssProperty_matchRefBackgroundContent(const AzCssProperty* value, const AzStyleBackgroundContentVecValue** restrict out) {
     const AzCssPropertyVariant_BackgroundContent* casted = (const AzCssPropertyVariant_BackgroundContent*)value;
     bool valid = casted->tag == AzCssPropertyTag_BackgroundContent;
@@ -17976,142 +21829,310 @@ bool AzCssProperty_matchMutBoxShadowBottom(AzCssProperty* restrict value, AzStyl
     return valid;
 }
 
-bool AzCssProperty_matchRefScrollbarStyle(const AzCssProperty* value, const AzScrollbarStyleValue** restrict out) {
-    const AzCssPropertyVariant_ScrollbarStyle* casted = (const AzCssPropertyVariant_ScrollbarStyle*)value;
-    bool valid = casted->tag == AzCssPropertyTag_ScrollbarStyle;
+bool AzCssProperty_matchRefScrollbarStyle(const AzCssProperty* value, const AzScrollbarStyleValue** restrict out) {
+    const AzCssPropertyVariant_ScrollbarStyle* casted = (const AzCssPropertyVariant_ScrollbarStyle*)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarStyle;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutScrollbarStyle(AzCssProperty* restrict value, AzScrollbarStyleValue* restrict * restrict out) {
+    AzCssPropertyVariant_ScrollbarStyle* restrict casted = (AzCssPropertyVariant_ScrollbarStyle* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_ScrollbarStyle;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefOpacity(const AzCssProperty* value, const AzStyleOpacityValue** restrict out) {
+    const AzCssPropertyVariant_Opacity* casted = (const AzCssPropertyVariant_Opacity*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Opacity;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutOpacity(AzCssProperty* restrict value, AzStyleOpacityValue* restrict * restrict out) {
+    AzCssPropertyVariant_Opacity* restrict casted = (AzCssPropertyVariant_Opacity* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Opacity;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTransform(const AzCssProperty* value, const AzStyleTransformVecValue** restrict out) {
+    const AzCssPropertyVariant_Transform* casted = (const AzCssPropertyVariant_Transform*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Transform;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTransform(AzCssProperty* restrict value, AzStyleTransformVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_Transform* restrict casted = (AzCssPropertyVariant_Transform* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Transform;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTransformOrigin(const AzCssProperty* value, const AzStyleTransformOriginValue** restrict out) {
+    const AzCssPropertyVariant_TransformOrigin* casted = (const AzCssPropertyVariant_TransformOrigin*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransformOrigin;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTransformOrigin(AzCssProperty* restrict value, AzStyleTransformOriginValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransformOrigin* restrict casted = (AzCssPropertyVariant_TransformOrigin* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransformOrigin;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefPerspectiveOrigin(const AzCssProperty* value, const AzStylePerspectiveOriginValue** restrict out) {
+    const AzCssPropertyVariant_PerspectiveOrigin* casted = (const AzCssPropertyVariant_PerspectiveOrigin*)value;
+    bool valid = casted->tag == AzCssPropertyTag_PerspectiveOrigin;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutPerspectiveOrigin(AzCssProperty* restrict value, AzStylePerspectiveOriginValue* restrict * restrict out) {
+    AzCssPropertyVariant_PerspectiveOrigin* restrict casted = (AzCssPropertyVariant_PerspectiveOrigin* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_PerspectiveOrigin;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefBackfaceVisibility(const AzCssProperty* value, const AzStyleBackfaceVisibilityValue** restrict out) {
+    const AzCssPropertyVariant_BackfaceVisibility* casted = (const AzCssPropertyVariant_BackfaceVisibility*)value;
+    bool valid = casted->tag == AzCssPropertyTag_BackfaceVisibility;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutBackfaceVisibility(AzCssProperty* restrict value, AzStyleBackfaceVisibilityValue* restrict * restrict out) {
+    AzCssPropertyVariant_BackfaceVisibility* restrict casted = (AzCssPropertyVariant_BackfaceVisibility* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_BackfaceVisibility;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefMixBlendMode(const AzCssProperty* value, const AzStyleMixBlendModeValue** restrict out) {
+    const AzCssPropertyVariant_MixBlendMode* casted = (const AzCssPropertyVariant_MixBlendMode*)value;
+    bool valid = casted->tag == AzCssPropertyTag_MixBlendMode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutMixBlendMode(AzCssProperty* restrict value, AzStyleMixBlendModeValue* restrict * restrict out) {
+    AzCssPropertyVariant_MixBlendMode* restrict casted = (AzCssPropertyVariant_MixBlendMode* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_MixBlendMode;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefFilter(const AzCssProperty* value, const AzStyleFilterVecValue** restrict out) {
+    const AzCssPropertyVariant_Filter* casted = (const AzCssPropertyVariant_Filter*)value;
+    bool valid = casted->tag == AzCssPropertyTag_Filter;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutFilter(AzCssProperty* restrict value, AzStyleFilterVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_Filter* restrict casted = (AzCssPropertyVariant_Filter* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_Filter;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefBackdropFilter(const AzCssProperty* value, const AzStyleFilterVecValue** restrict out) {
+    const AzCssPropertyVariant_BackdropFilter* casted = (const AzCssPropertyVariant_BackdropFilter*)value;
+    bool valid = casted->tag == AzCssPropertyTag_BackdropFilter;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutBackdropFilter(AzCssProperty* restrict value, AzStyleFilterVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_BackdropFilter* restrict casted = (AzCssPropertyVariant_BackdropFilter* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_BackdropFilter;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTextShadow(const AzCssProperty* value, const AzStyleBoxShadowValue** restrict out) {
+    const AzCssPropertyVariant_TextShadow* casted = (const AzCssPropertyVariant_TextShadow*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TextShadow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTextShadow(AzCssProperty* restrict value, AzStyleBoxShadowValue* restrict * restrict out) {
+    AzCssPropertyVariant_TextShadow* restrict casted = (AzCssPropertyVariant_TextShadow* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TextShadow;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTransitionProperty(const AzCssProperty* value, const AzStyleTransitionPropertyVecValue** restrict out) {
+    const AzCssPropertyVariant_TransitionProperty* casted = (const AzCssPropertyVariant_TransitionProperty*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionProperty;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTransitionProperty(AzCssProperty* restrict value, AzStyleTransitionPropertyVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionProperty* restrict casted = (AzCssPropertyVariant_TransitionProperty* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionProperty;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTransitionDuration(const AzCssProperty* value, const AzStyleTimeVecValue** restrict out) {
+    const AzCssPropertyVariant_TransitionDuration* casted = (const AzCssPropertyVariant_TransitionDuration*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchMutTransitionDuration(AzCssProperty* restrict value, AzStyleTimeVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionDuration* restrict casted = (AzCssPropertyVariant_TransitionDuration* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDuration;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssProperty_matchRefTransitionTimingFunction(const AzCssProperty* value, const AzStyleTimingFunctionVecValue** restrict out) {
+    const AzCssPropertyVariant_TransitionTimingFunction* casted = (const AzCssPropertyVariant_TransitionTimingFunction*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionTimingFunction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutScrollbarStyle(AzCssProperty* restrict value, AzScrollbarStyleValue* restrict * restrict out) {
-    AzCssPropertyVariant_ScrollbarStyle* restrict casted = (AzCssPropertyVariant_ScrollbarStyle* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_ScrollbarStyle;
+bool AzCssProperty_matchMutTransitionTimingFunction(AzCssProperty* restrict value, AzStyleTimingFunctionVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionTimingFunction* restrict casted = (AzCssPropertyVariant_TransitionTimingFunction* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionTimingFunction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefOpacity(const AzCssProperty* value, const AzStyleOpacityValue** restrict out) {
-    const AzCssPropertyVariant_Opacity* casted = (const AzCssPropertyVariant_Opacity*)value;
-    bool valid = casted->tag == AzCssPropertyTag_Opacity;
+bool AzCssProperty_matchRefTransitionDelay(const AzCssProperty* value, const AzStyleTimeVecValue** restrict out) {
+    const AzCssPropertyVariant_TransitionDelay* casted = (const AzCssPropertyVariant_TransitionDelay*)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDelay;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutOpacity(AzCssProperty* restrict value, AzStyleOpacityValue* restrict * restrict out) {
-    AzCssPropertyVariant_Opacity* restrict casted = (AzCssPropertyVariant_Opacity* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_Opacity;
+bool AzCssProperty_matchMutTransitionDelay(AzCssProperty* restrict value, AzStyleTimeVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_TransitionDelay* restrict casted = (AzCssPropertyVariant_TransitionDelay* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_TransitionDelay;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefTransform(const AzCssProperty* value, const AzStyleTransformVecValue** restrict out) {
-    const AzCssPropertyVariant_Transform* casted = (const AzCssPropertyVariant_Transform*)value;
-    bool valid = casted->tag == AzCssPropertyTag_Transform;
+bool AzCssProperty_matchRefAnimationName(const AzCssProperty* value, const AzStyleAnimationNameVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationName* casted = (const AzCssPropertyVariant_AnimationName*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationName;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutTransform(AzCssProperty* restrict value, AzStyleTransformVecValue* restrict * restrict out) {
-    AzCssPropertyVariant_Transform* restrict casted = (AzCssPropertyVariant_Transform* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_Transform;
+bool AzCssProperty_matchMutAnimationName(AzCssProperty* restrict value, AzStyleAnimationNameVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationName* restrict casted = (AzCssPropertyVariant_AnimationName* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationName;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefTransformOrigin(const AzCssProperty* value, const AzStyleTransformOriginValue** restrict out) {
-    const AzCssPropertyVariant_TransformOrigin* casted = (const AzCssPropertyVariant_TransformOrigin*)value;
-    bool valid = casted->tag == AzCssPropertyTag_TransformOrigin;
+bool AzCssProperty_matchRefAnimationDuration(const AzCssProperty* value, const AzStyleTimeVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationDuration* casted = (const AzCssPropertyVariant_AnimationDuration*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDuration;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutTransformOrigin(AzCssProperty* restrict value, AzStyleTransformOriginValue* restrict * restrict out) {
-    AzCssPropertyVariant_TransformOrigin* restrict casted = (AzCssPropertyVariant_TransformOrigin* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_TransformOrigin;
+bool AzCssProperty_matchMutAnimationDuration(AzCssProperty* restrict value, AzStyleTimeVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationDuration* restrict casted = (AzCssPropertyVariant_AnimationDuration* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDuration;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefPerspectiveOrigin(const AzCssProperty* value, const AzStylePerspectiveOriginValue** restrict out) {
-    const AzCssPropertyVariant_PerspectiveOrigin* casted = (const AzCssPropertyVariant_PerspectiveOrigin*)value;
-    bool valid = casted->tag == AzCssPropertyTag_PerspectiveOrigin;
+bool AzCssProperty_matchRefAnimationTimingFunction(const AzCssProperty* value, const AzStyleTimingFunctionVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationTimingFunction* casted = (const AzCssPropertyVariant_AnimationTimingFunction*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationTimingFunction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutPerspectiveOrigin(AzCssProperty* restrict value, AzStylePerspectiveOriginValue* restrict * restrict out) {
-    AzCssPropertyVariant_PerspectiveOrigin* restrict casted = (AzCssPropertyVariant_PerspectiveOrigin* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_PerspectiveOrigin;
+bool AzCssProperty_matchMutAnimationTimingFunction(AzCssProperty* restrict value, AzStyleTimingFunctionVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationTimingFunction* restrict casted = (AzCssPropertyVariant_AnimationTimingFunction* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationTimingFunction;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefBackfaceVisibility(const AzCssProperty* value, const AzStyleBackfaceVisibilityValue** restrict out) {
-    const AzCssPropertyVariant_BackfaceVisibility* casted = (const AzCssPropertyVariant_BackfaceVisibility*)value;
-    bool valid = casted->tag == AzCssPropertyTag_BackfaceVisibility;
+bool AzCssProperty_matchRefAnimationDelay(const AzCssProperty* value, const AzStyleTimeVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationDelay* casted = (const AzCssPropertyVariant_AnimationDelay*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDelay;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutBackfaceVisibility(AzCssProperty* restrict value, AzStyleBackfaceVisibilityValue* restrict * restrict out) {
-    AzCssPropertyVariant_BackfaceVisibility* restrict casted = (AzCssPropertyVariant_BackfaceVisibility* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_BackfaceVisibility;
+bool AzCssProperty_matchMutAnimationDelay(AzCssProperty* restrict value, AzStyleTimeVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationDelay* restrict casted = (AzCssPropertyVariant_AnimationDelay* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDelay;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefMixBlendMode(const AzCssProperty* value, const AzStyleMixBlendModeValue** restrict out) {
-    const AzCssPropertyVariant_MixBlendMode* casted = (const AzCssPropertyVariant_MixBlendMode*)value;
-    bool valid = casted->tag == AzCssPropertyTag_MixBlendMode;
+bool AzCssProperty_matchRefAnimationIterationCount(const AzCssProperty* value, const AzStyleAnimationIterationCountVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationIterationCount* casted = (const AzCssPropertyVariant_AnimationIterationCount*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationIterationCount;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutMixBlendMode(AzCssProperty* restrict value, AzStyleMixBlendModeValue* restrict * restrict out) {
-    AzCssPropertyVariant_MixBlendMode* restrict casted = (AzCssPropertyVariant_MixBlendMode* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_MixBlendMode;
+bool AzCssProperty_matchMutAnimationIterationCount(AzCssProperty* restrict value, AzStyleAnimationIterationCountVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationIterationCount* restrict casted = (AzCssPropertyVariant_AnimationIterationCount* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationIterationCount;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefFilter(const AzCssProperty* value, const AzStyleFilterVecValue** restrict out) {
-    const AzCssPropertyVariant_Filter* casted = (const AzCssPropertyVariant_Filter*)value;
-    bool valid = casted->tag == AzCssPropertyTag_Filter;
+bool AzCssProperty_matchRefAnimationDirection(const AzCssProperty* value, const AzStyleAnimationDirectionVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationDirection* casted = (const AzCssPropertyVariant_AnimationDirection*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDirection;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutFilter(AzCssProperty* restrict value, AzStyleFilterVecValue* restrict * restrict out) {
-    AzCssPropertyVariant_Filter* restrict casted = (AzCssPropertyVariant_Filter* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_Filter;
+bool AzCssProperty_matchMutAnimationDirection(AzCssProperty* restrict value, AzStyleAnimationDirectionVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationDirection* restrict casted = (AzCssPropertyVariant_AnimationDirection* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationDirection;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefBackdropFilter(const AzCssProperty* value, const AzStyleFilterVecValue** restrict out) {
-    const AzCssPropertyVariant_BackdropFilter* casted = (const AzCssPropertyVariant_BackdropFilter*)value;
-    bool valid = casted->tag == AzCssPropertyTag_BackdropFilter;
+bool AzCssProperty_matchRefAnimationFillMode(const AzCssProperty* value, const AzStyleAnimationFillModeVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationFillMode* casted = (const AzCssPropertyVariant_AnimationFillMode*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationFillMode;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutBackdropFilter(AzCssProperty* restrict value, AzStyleFilterVecValue* restrict * restrict out) {
-    AzCssPropertyVariant_BackdropFilter* restrict casted = (AzCssPropertyVariant_BackdropFilter* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_BackdropFilter;
+bool AzCssProperty_matchMutAnimationFillMode(AzCssProperty* restrict value, AzStyleAnimationFillModeVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationFillMode* restrict casted = (AzCssPropertyVariant_AnimationFillMode* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationFillMode;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchRefTextShadow(const AzCssProperty* value, const AzStyleBoxShadowValue** restrict out) {
-    const AzCssPropertyVariant_TextShadow* casted = (const AzCssPropertyVariant_TextShadow*)value;
-    bool valid = casted->tag == AzCssPropertyTag_TextShadow;
+bool AzCssProperty_matchRefAnimationPlayState(const AzCssProperty* value, const AzStyleAnimationPlayStateVecValue** restrict out) {
+    const AzCssPropertyVariant_AnimationPlayState* casted = (const AzCssPropertyVariant_AnimationPlayState*)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationPlayState;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzCssProperty_matchMutTextShadow(AzCssProperty* restrict value, AzStyleBoxShadowValue* restrict * restrict out) {
-    AzCssPropertyVariant_TextShadow* restrict casted = (AzCssPropertyVariant_TextShadow* restrict)value;
-    bool valid = casted->tag == AzCssPropertyTag_TextShadow;
+bool AzCssProperty_matchMutAnimationPlayState(AzCssProperty* restrict value, AzStyleAnimationPlayStateVecValue* restrict * restrict out) {
+    AzCssPropertyVariant_AnimationPlayState* restrict casted = (AzCssPropertyVariant_AnimationPlayState* restrict)value;
+    bool valid = casted->tag == AzCssPropertyTag_AnimationPlayState;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
@@ -18788,6 +22809,118 @@ bool AzListViewRowVecDestructor_matchMutExternal(AzListViewRowVecDestructor* res
     return valid;
 }
 
+bool AzTreeViewNodeVecDestructor_matchRefExternal(const AzTreeViewNodeVecDestructor* value, const AzTreeViewNodeVecDestructorType** restrict out) {
+    const AzTreeViewNodeVecDestructorVariant_External* casted = (const AzTreeViewNodeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTreeViewNodeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewNodeVecDestructor_matchMutExternal(AzTreeViewNodeVecDestructor* restrict value, AzTreeViewNodeVecDestructorType* restrict * restrict out) {
+    AzTreeViewNodeVecDestructorVariant_External* restrict casted = (AzTreeViewNodeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTreeViewNodeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewNodeIdVecDestructor_matchRefExternal(const AzTreeViewNodeIdVecDestructor* value, const AzTreeViewNodeIdVecDestructorType** restrict out) {
+    const AzTreeViewNodeIdVecDestructorVariant_External* casted = (const AzTreeViewNodeIdVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTreeViewNodeIdVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTreeViewNodeIdVecDestructor_matchMutExternal(AzTreeViewNodeIdVecDestructor* restrict value, AzTreeViewNodeIdVecDestructorType* restrict * restrict out) {
+    AzTreeViewNodeIdVecDestructorVariant_External* restrict casted = (AzTreeViewNodeIdVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTreeViewNodeIdVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzRibbonTabVecDestructor_matchRefExternal(const AzRibbonTabVecDestructor* value, const AzRibbonTabVecDestructorType** restrict out) {
+    const AzRibbonTabVecDestructorVariant_External* casted = (const AzRibbonTabVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzRibbonTabVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzRibbonTabVecDestructor_matchMutExternal(AzRibbonTabVecDestructor* restrict value, AzRibbonTabVecDestructorType* restrict * restrict out) {
+    AzRibbonTabVecDestructorVariant_External* restrict casted = (AzRibbonTabVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzRibbonTabVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzRibbonGroupVecDestructor_matchRefExternal(const AzRibbonGroupVecDestructor* value, const AzRibbonGroupVecDestructorType** restrict out) {
+    const AzRibbonGroupVecDestructorVariant_External* casted = (const AzRibbonGroupVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzRibbonGroupVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzRibbonGroupVecDestructor_matchMutExternal(AzRibbonGroupVecDestructor* restrict value, AzRibbonGroupVecDestructorType* restrict * restrict out) {
+    AzRibbonGroupVecDestructorVariant_External* restrict casted = (AzRibbonGroupVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzRibbonGroupVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzRibbonItemVecDestructor_matchRefExternal(const AzRibbonItemVecDestructor* value, const AzRibbonItemVecDestructorType** restrict out) {
+    const AzRibbonItemVecDestructorVariant_External* casted = (const AzRibbonItemVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzRibbonItemVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzRibbonItemVecDestructor_matchMutExternal(AzRibbonItemVecDestructor* restrict value, AzRibbonItemVecDestructorType* restrict * restrict out) {
+    AzRibbonItemVecDestructorVariant_External* restrict casted = (AzRibbonItemVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzRibbonItemVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSplitterPaneVecDestructor_matchRefExternal(const AzSplitterPaneVecDestructor* value, const AzSplitterPaneVecDestructorType** restrict out) {
+    const AzSplitterPaneVecDestructorVariant_External* casted = (const AzSplitterPaneVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzSplitterPaneVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSplitterPaneVecDestructor_matchMutExternal(AzSplitterPaneVecDestructor* restrict value, AzSplitterPaneVecDestructorType* restrict * restrict out) {
+    AzSplitterPaneVecDestructorVariant_External* restrict casted = (AzSplitterPaneVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzSplitterPaneVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSplitterPaneStateVecDestructor_matchRefExternal(const AzSplitterPaneStateVecDestructor* value, const AzSplitterPaneStateVecDestructorType** restrict out) {
+    const AzSplitterPaneStateVecDestructorVariant_External* casted = (const AzSplitterPaneStateVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzSplitterPaneStateVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzSplitterPaneStateVecDestructor_matchMutExternal(AzSplitterPaneStateVecDestructor* restrict value, AzSplitterPaneStateVecDestructorType* restrict * restrict out) {
+    AzSplitterPaneStateVecDestructorVariant_External* restrict casted = (AzSplitterPaneStateVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzSplitterPaneStateVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextAreaEditVecDestructor_matchRefExternal(const AzTextAreaEditVecDestructor* value, const AzTextAreaEditVecDestructorType** restrict out) {
+    const AzTextAreaEditVecDestructorVariant_External* casted = (const AzTextAreaEditVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzTextAreaEditVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzTextAreaEditVecDestructor_matchMutExternal(AzTextAreaEditVecDestructor* restrict value, AzTextAreaEditVecDestructorType* restrict * restrict out) {
+    AzTextAreaEditVecDestructorVariant_External* restrict casted = (AzTextAreaEditVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzTextAreaEditVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzStyleFilterVecDestructor_matchRefExternal(const AzStyleFilterVecDestructor* value, const AzStyleFilterVecDestructorType** restrict out) {
     const AzStyleFilterVecDestructorVariant_External* casted = (const AzStyleFilterVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzStyleFilterVecDestructorTag_External;
@@ -18956,6 +23089,20 @@ bool AzAccessibilityStateVecDestructor_matchMutExternal(AzAccessibilityStateVecD
     return valid;
 }
 
+bool AzAccessibilityActionVecDestructor_matchRefExternal(const AzAccessibilityActionVecDestructor* value, const AzAccessibilityActionVecDestructorType** restrict out) {
+    const AzAccessibilityActionVecDestructorVariant_External* casted = (const AzAccessibilityActionVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzAccessibilityActionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzAccessibilityActionVecDestructor_matchMutExternal(AzAccessibilityActionVecDestructor* restrict value, AzAccessibilityActionVecDestructorType* restrict * restrict out) {
+    AzAccessibilityActionVecDestructorVariant_External* restrict casted = (AzAccessibilityActionVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzAccessibilityActionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzMenuItemVecDestructor_matchRefExternal(const AzMenuItemVecDestructor* value, const AzMenuItemVecDestructorType** restrict out) {
     const AzMenuItemVecDestructorVariant_External* casted = (const AzMenuItemVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzMenuItemVecDestructorTag_External;
@@ -19075,135 +23222,261 @@ bool AzMonitorVecDestructor_matchRefExternal(const AzMonitorVecDestructor* value
     return valid;
 }
 
-bool AzMonitorVecDestructor_matchMutExternal(AzMonitorVecDestructor* restrict value, AzMonitorVecDestructorType* restrict * restrict out) {
-    AzMonitorVecDestructorVariant_External* restrict casted = (AzMonitorVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzMonitorVecDestructorTag_External;
+bool AzMonitorVecDestructor_matchMutExternal(AzMonitorVecDestructor* restrict value, AzMonitorVecDestructorType* restrict * restrict out) {
+    AzMonitorVecDestructorVariant_External* restrict casted = (AzMonitorVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzMonitorVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzVideoModeVecDestructor_matchRefExternal(const AzVideoModeVecDestructor* value, const AzVideoModeVecDestructorType** restrict out) {
+    const AzVideoModeVecDestructorVariant_External* casted = (const AzVideoModeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzVideoModeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzVideoModeVecDestructor_matchMutExternal(AzVideoModeVecDestructor* restrict value, AzVideoModeVecDestructorType* restrict * restrict out) {
+    AzVideoModeVecDestructorVariant_External* restrict casted = (AzVideoModeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzVideoModeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzDomVecDestructor_matchRefExternal(const AzDomVecDestructor* value, const AzDomVecDestructorType** restrict out) {
+    const AzDomVecDestructorVariant_External* casted = (const AzDomVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzDomVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzDomVecDestructor_matchMutExternal(AzDomVecDestructor* restrict value, AzDomVecDestructorType* restrict * restrict out) {
+    AzDomVecDestructorVariant_External* restrict casted = (AzDomVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzDomVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzIdOrClassVecDestructor_matchRefExternal(const AzIdOrClassVecDestructor* value, const AzIdOrClassVecDestructorType** restrict out) {
+    const AzIdOrClassVecDestructorVariant_External* casted = (const AzIdOrClassVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzIdOrClassVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzIdOrClassVecDestructor_matchMutExternal(AzIdOrClassVecDestructor* restrict value, AzIdOrClassVecDestructorType* restrict * restrict out) {
+    AzIdOrClassVecDestructorVariant_External* restrict casted = (AzIdOrClassVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzIdOrClassVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeDataInlineCssPropertyVecDestructor_matchRefExternal(const AzNodeDataInlineCssPropertyVecDestructor* value, const AzNodeDataInlineCssPropertyVecDestructorType** restrict out) {
+    const AzNodeDataInlineCssPropertyVecDestructorVariant_External* casted = (const AzNodeDataInlineCssPropertyVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzNodeDataInlineCssPropertyVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzNodeDataInlineCssPropertyVecDestructor_matchMutExternal(AzNodeDataInlineCssPropertyVecDestructor* restrict value, AzNodeDataInlineCssPropertyVecDestructorType* restrict * restrict out) {
+    AzNodeDataInlineCssPropertyVecDestructorVariant_External* restrict casted = (AzNodeDataInlineCssPropertyVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzNodeDataInlineCssPropertyVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundContentVecDestructor_matchRefExternal(const AzStyleBackgroundContentVecDestructor* value, const AzStyleBackgroundContentVecDestructorType** restrict out) {
+    const AzStyleBackgroundContentVecDestructorVariant_External* casted = (const AzStyleBackgroundContentVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleBackgroundContentVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundContentVecDestructor_matchMutExternal(AzStyleBackgroundContentVecDestructor* restrict value, AzStyleBackgroundContentVecDestructorType* restrict * restrict out) {
+    AzStyleBackgroundContentVecDestructorVariant_External* restrict casted = (AzStyleBackgroundContentVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleBackgroundContentVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundPositionVecDestructor_matchRefExternal(const AzStyleBackgroundPositionVecDestructor* value, const AzStyleBackgroundPositionVecDestructorType** restrict out) {
+    const AzStyleBackgroundPositionVecDestructorVariant_External* casted = (const AzStyleBackgroundPositionVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleBackgroundPositionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundPositionVecDestructor_matchMutExternal(AzStyleBackgroundPositionVecDestructor* restrict value, AzStyleBackgroundPositionVecDestructorType* restrict * restrict out) {
+    AzStyleBackgroundPositionVecDestructorVariant_External* restrict casted = (AzStyleBackgroundPositionVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleBackgroundPositionVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundRepeatVecDestructor_matchRefExternal(const AzStyleBackgroundRepeatVecDestructor* value, const AzStyleBackgroundRepeatVecDestructorType** restrict out) {
+    const AzStyleBackgroundRepeatVecDestructorVariant_External* casted = (const AzStyleBackgroundRepeatVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleBackgroundRepeatVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundRepeatVecDestructor_matchMutExternal(AzStyleBackgroundRepeatVecDestructor* restrict value, AzStyleBackgroundRepeatVecDestructorType* restrict * restrict out) {
+    AzStyleBackgroundRepeatVecDestructorVariant_External* restrict casted = (AzStyleBackgroundRepeatVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleBackgroundRepeatVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundSizeVecDestructor_matchRefExternal(const AzStyleBackgroundSizeVecDestructor* value, const AzStyleBackgroundSizeVecDestructorType** restrict out) {
+    const AzStyleBackgroundSizeVecDestructorVariant_External* casted = (const AzStyleBackgroundSizeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleBackgroundSizeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleBackgroundSizeVecDestructor_matchMutExternal(AzStyleBackgroundSizeVecDestructor* restrict value, AzStyleBackgroundSizeVecDestructorType* restrict * restrict out) {
+    AzStyleBackgroundSizeVecDestructorVariant_External* restrict casted = (AzStyleBackgroundSizeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleBackgroundSizeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransformVecDestructor_matchRefExternal(const AzStyleTransformVecDestructor* value, const AzStyleTransformVecDestructorType** restrict out) {
+    const AzStyleTransformVecDestructorVariant_External* casted = (const AzStyleTransformVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleTransformVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzStyleTransformVecDestructor_matchMutExternal(AzStyleTransformVecDestructor* restrict value, AzStyleTransformVecDestructorType* restrict * restrict out) {
+    AzStyleTransformVecDestructorVariant_External* restrict casted = (AzStyleTransformVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleTransformVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzVideoModeVecDestructor_matchRefExternal(const AzVideoModeVecDestructor* value, const AzVideoModeVecDestructorType** restrict out) {
-    const AzVideoModeVecDestructorVariant_External* casted = (const AzVideoModeVecDestructorVariant_External*)value;
-    bool valid = casted->tag == AzVideoModeVecDestructorTag_External;
+bool AzStyleTransitionPropertyVecDestructor_matchRefExternal(const AzStyleTransitionPropertyVecDestructor* value, const AzStyleTransitionPropertyVecDestructorType** restrict out) {
+    const AzStyleTransitionPropertyVecDestructorVariant_External* casted = (const AzStyleTransitionPropertyVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzVideoModeVecDestructor_matchMutExternal(AzVideoModeVecDestructor* restrict value, AzVideoModeVecDestructorType* restrict * restrict out) {
-    AzVideoModeVecDestructorVariant_External* restrict casted = (AzVideoModeVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzVideoModeVecDestructorTag_External;
+bool AzStyleTransitionPropertyVecDestructor_matchMutExternal(AzStyleTransitionPropertyVecDestructor* restrict value, AzStyleTransitionPropertyVecDestructorType* restrict * restrict out) {
+    AzStyleTransitionPropertyVecDestructorVariant_External* restrict casted = (AzStyleTransitionPropertyVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleTransitionPropertyVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzDomVecDestructor_matchRefExternal(const AzDomVecDestructor* value, const AzDomVecDestructorType** restrict out) {
-    const AzDomVecDestructorVariant_External* casted = (const AzDomVecDestructorVariant_External*)value;
-    bool valid = casted->tag == AzDomVecDestructorTag_External;
+bool AzStyleTimeVecDestructor_matchRefExternal(const AzStyleTimeVecDestructor* value, const AzStyleTimeVecDestructorType** restrict out) {
+    const AzStyleTimeVecDestructorVariant_External* casted = (const AzStyleTimeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleTimeVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzDomVecDestructor_matchMutExternal(AzDomVecDestructor* restrict value, AzDomVecDestructorType* restrict * restrict out) {
-    AzDomVecDestructorVariant_External* restrict casted = (AzDomVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzDomVecDestructorTag_External;
+bool AzStyleTimeVecDestructor_matchMutExternal(AzStyleTimeVecDestructor* restrict value, AzStyleTimeVecDestructorType* restrict * restrict out) {
+    AzStyleTimeVecDestructorVariant_External* restrict casted = (AzStyleTimeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleTimeVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzIdOrClassVecDestructor_matchRefExternal(const AzIdOrClassVecDestructor* value, const AzIdOrClassVecDestructorType** restrict out) {
-    const AzIdOrClassVecDestructorVariant_External* casted = (const AzIdOrClassVecDestructorVariant_External*)value;
-    bool valid = casted->tag == AzIdOrClassVecDestructorTag_External;
+bool AzStyleTimingFunctionVecDestructor_matchRefExternal(const AzStyleTimingFunctionVecDestructor* value, const AzStyleTimingFunctionVecDestructorType** restrict out) {
+    const AzStyleTimingFunctionVecDestructorVariant_External* casted = (const AzStyleTimingFunctionVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleTimingFunctionVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzIdOrClassVecDestructor_matchMutExternal(AzIdOrClassVecDestructor* restrict value, AzIdOrClassVecDestructorType* restrict * restrict out) {
-    AzIdOrClassVecDestructorVariant_External* restrict casted = (AzIdOrClassVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzIdOrClassVecDestructorTag_External;
+bool AzStyleTimingFunctionVecDestructor_matchMutExternal(AzStyleTimingFunctionVecDestructor* restrict value, AzStyleTimingFunctionVecDestructorType* restrict * restrict out) {
+    AzStyleTimingFunctionVecDestructorVariant_External* restrict casted = (AzStyleTimingFunctionVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleTimingFunctionVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzNodeDataInlineCssPropertyVecDestructor_matchRefExternal(const AzNodeDataInlineCssPropertyVecDestructor* value, const AzNodeDataInlineCssPropertyVecDestructorType** restrict out) {
-    const AzNodeDataInlineCssPropertyVecDestructorVariant_External* casted = (const AzNodeDataInlineCssPropertyVecDestructorVariant_External*)value;
-    bool valid = casted->tag == AzNodeDataInlineCssPropertyVecDestructorTag_External;
+bool AzStyleAnimationNameVecDestructor_matchRefExternal(const AzStyleAnimationNameVecDestructor* value, const AzStyleAnimationNameVecDestructorType** restrict out) {
+    const AzStyleAnimationNameVecDestructorVariant_External* casted = (const AzStyleAnimationNameVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationNameVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzNodeDataInlineCssPropertyVecDestructor_matchMutExternal(AzNodeDataInlineCssPropertyVecDestructor* restrict value, AzNodeDataInlineCssPropertyVecDestructorType* restrict * restrict out) {
-    AzNodeDataInlineCssPropertyVecDestructorVariant_External* restrict casted = (AzNodeDataInlineCssPropertyVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzNodeDataInlineCssPropertyVecDestructorTag_External;
+bool AzStyleAnimationNameVecDestructor_matchMutExternal(AzStyleAnimationNameVecDestructor* restrict value, AzStyleAnimationNameVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationNameVecDestructorVariant_External* restrict casted = (AzStyleAnimationNameVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationNameVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundContentVecDestructor_matchRefExternal(const AzStyleBackgroundContentVecDestructor* value, const AzStyleBackgroundContentVecDestructorType** restrict out) {
-    const AzStyleBackgroundContentVecDestructorVariant_External* casted = (const AzStyleBackgroundContentVecDestructorVariant_External*)value;
-    bool valid = casted->tag == AzStyleBackgroundContentVecDestructorTag_External;
+bool AzStyleAnimationIterationCountVecDestructor_matchRefExternal(const AzStyleAnimationIterationCountVecDestructor* value, const AzStyleAnimationIterationCountVecDestructorType** restrict out) {
+    const AzStyleAnimationIterationCountVecDestructorVariant_External* casted = (const AzStyleAnimationIterationCountVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundContentVecDestructor_matchMutExternal(AzStyleBackgroundContentVecDestructor* restrict value, AzStyleBackgroundContentVecDestructorType* restrict * restrict out) {
-    AzStyleBackgroundContentVecDestructorVariant_External* restrict casted = (AzStyleBackgroundContentVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzStyleBackgroundContentVecDestructorTag_External;
+bool AzStyleAnimationIterationCountVecDestructor_matchMutExternal(AzStyleAnimationIterationCountVecDestructor* restrict value, AzStyleAnimationIterationCountVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationIterationCountVecDestructorVariant_External* restrict casted = (AzStyleAnimationIterationCountVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationIterationCountVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundPositionVecDestructor_matchRefExternal(const AzStyleBackgroundPositionVecDestructor* value, const AzStyleBackgroundPositionVecDestructorType** restrict out) {
-    const AzStyleBackgroundPositionVecDestructorVariant_External* casted = (const AzStyleBackgroundPositionVecDestructorVariant_External*)value;
-    bool valid = casted->tag == AzStyleBackgroundPositionVecDestructorTag_External;
+bool AzStyleAnimationDirectionVecDestructor_matchRefExternal(const AzStyleAnimationDirectionVecDestructor* value, const AzStyleAnimationDirectionVecDestructorType** restrict out) {
+    const AzStyleAnimationDirectionVecDestructorVariant_External* casted = (const AzStyleAnimationDirectionVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationDirectionVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundPositionVecDestructor_matchMutExternal(AzStyleBackgroundPositionVecDestructor* restrict value, AzStyleBackgroundPositionVecDestructorType* restrict * restrict out) {
-    AzStyleBackgroundPositionVecDestructorVariant_External* restrict casted = (AzStyleBackgroundPositionVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzStyleBackgroundPositionVecDestructorTag_External;
+bool AzStyleAnimationDirectionVecDestructor_matchMutExternal(AzStyleAnimationDirectionVecDestructor* restrict value, AzStyleAnimationDirectionVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationDirectionVecDestructorVariant_External* restrict casted = (AzStyleAnimationDirectionVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationDirectionVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundRepeatVecDestructor_matchRefExternal(const AzStyleBackgroundRepeatVecDestructor* value, const AzStyleBackgroundRepeatVecDestructorType** restrict out) {
-    const AzStyleBackgroundRepeatVecDestructorVariant_External* casted = (const AzStyleBackgroundRepeatVecDestructorVariant_External*)value;
-    bool valid = casted->tag == AzStyleBackgroundRepeatVecDestructorTag_External;
+bool AzStyleAnimationFillModeVecDestructor_matchRefExternal(const AzStyleAnimationFillModeVecDestructor* value, const AzStyleAnimationFillModeVecDestructorType** restrict out) {
+    const AzStyleAnimationFillModeVecDestructorVariant_External* casted = (const AzStyleAnimationFillModeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationFillModeVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundRepeatVecDestructor_matchMutExternal(AzStyleBackgroundRepeatVecDestructor* restrict value, AzStyleBackgroundRepeatVecDestructorType* restrict * restrict out) {
-    AzStyleBackgroundRepeatVecDestructorVariant_External* restrict casted = (AzStyleBackgroundRepeatVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzStyleBackgroundRepeatVecDestructorTag_External;
+bool AzStyleAnimationFillModeVecDestructor_matchMutExternal(AzStyleAnimationFillModeVecDestructor* restrict value, AzStyleAnimationFillModeVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationFillModeVecDestructorVariant_External* restrict casted = (AzStyleAnimationFillModeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationFillModeVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundSizeVecDestructor_matchRefExternal(const AzStyleBackgroundSizeVecDestructor* value, const AzStyleBackgroundSizeVecDestructorType** restrict out) {
-    const AzStyleBackgroundSizeVecDestructorVariant_External* casted = (const AzStyleBackgroundSizeVecDestructorVariant_External*)value;
-    bool valid = casted->tag == AzStyleBackgroundSizeVecDestructorTag_External;
+bool AzStyleAnimationPlayStateVecDestructor_matchRefExternal(const AzStyleAnimationPlayStateVecDestructor* value, const AzStyleAnimationPlayStateVecDestructorType** restrict out) {
+    const AzStyleAnimationPlayStateVecDestructorVariant_External* casted = (const AzStyleAnimationPlayStateVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzStyleAnimationPlayStateVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleBackgroundSizeVecDestructor_matchMutExternal(AzStyleBackgroundSizeVecDestructor* restrict value, AzStyleBackgroundSizeVecDestructorType* restrict * restrict out) {
-    AzStyleBackgroundSizeVecDestructorVariant_External* restrict casted = (AzStyleBackgroundSizeVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzStyleBackgroundSizeVecDestructorTag_External;
+bool AzStyleAnimationPlayStateVecDestructor_matchMutExternal(AzStyleAnimationPlayStateVecDestructor* restrict value, AzStyleAnimationPlayStateVecDestructorType* restrict * restrict out) {
+    AzStyleAnimationPlayStateVecDestructorVariant_External* restrict casted = (AzStyleAnimationPlayStateVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzStyleAnimationPlayStateVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleTransformVecDestructor_matchRefExternal(const AzStyleTransformVecDestructor* value, const AzStyleTransformVecDestructorType** restrict out) {
-    const AzStyleTransformVecDestructorVariant_External* casted = (const AzStyleTransformVecDestructorVariant_External*)value;
-    bool valid = casted->tag == AzStyleTransformVecDestructorTag_External;
+bool AzGridTrackSizingVecDestructor_matchRefExternal(const AzGridTrackSizingVecDestructor* value, const AzGridTrackSizingVecDestructorType** restrict out) {
+    const AzGridTrackSizingVecDestructorVariant_External* casted = (const AzGridTrackSizingVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzGridTrackSizingVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
 
-bool AzStyleTransformVecDestructor_matchMutExternal(AzStyleTransformVecDestructor* restrict value, AzStyleTransformVecDestructorType* restrict * restrict out) {
-    AzStyleTransformVecDestructorVariant_External* restrict casted = (AzStyleTransformVecDestructorVariant_External* restrict)value;
-    bool valid = casted->tag == AzStyleTransformVecDestructorTag_External;
+bool AzGridTrackSizingVecDestructor_matchMutExternal(AzGridTrackSizingVecDestructor* restrict value, AzGridTrackSizingVecDestructorType* restrict * restrict out) {
+    AzGridTrackSizingVecDestructorVariant_External* restrict casted = (AzGridTrackSizingVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzGridTrackSizingVecDestructorTag_External;
     if (valid) { *out = &casted->payload; } else { *out = 0; }
     return valid;
 }
@@ -19404,6 +23677,76 @@ bool AzStylesheetVecDestructor_matchMutExternal(AzStylesheetVecDestructor* restr
     return valid;
 }
 
+bool AzCssKeyframesVecDestructor_matchRefExternal(const AzCssKeyframesVecDestructor* value, const AzCssKeyframesVecDestructorType** restrict out) {
+    const AzCssKeyframesVecDestructorVariant_External* casted = (const AzCssKeyframesVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssKeyframesVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssKeyframesVecDestructor_matchMutExternal(AzCssKeyframesVecDestructor* restrict value, AzCssKeyframesVecDestructorType* restrict * restrict out) {
+    AzCssKeyframesVecDestructorVariant_External* restrict casted = (AzCssKeyframesVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssKeyframesVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssKeyframeVecDestructor_matchRefExternal(const AzCssKeyframeVecDestructor* value, const AzCssKeyframeVecDestructorType** restrict out) {
+    const AzCssKeyframeVecDestructorVariant_External* casted = (const AzCssKeyframeVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssKeyframeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssKeyframeVecDestructor_matchMutExternal(AzCssKeyframeVecDestructor* restrict value, AzCssKeyframeVecDestructorType* restrict * restrict out) {
+    AzCssKeyframeVecDestructorVariant_External* restrict casted = (AzCssKeyframeVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssKeyframeVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaRuleVecDestructor_matchRefExternal(const AzCssMediaRuleVecDestructor* value, const AzCssMediaRuleVecDestructorType** restrict out) {
+    const AzCssMediaRuleVecDestructorVariant_External* casted = (const AzCssMediaRuleVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssMediaRuleVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaRuleVecDestructor_matchMutExternal(AzCssMediaRuleVecDestructor* restrict value, AzCssMediaRuleVecDestructorType* restrict * restrict out) {
+    AzCssMediaRuleVecDestructorVariant_External* restrict casted = (AzCssMediaRuleVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssMediaRuleVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaQueryVecDestructor_matchRefExternal(const AzCssMediaQueryVecDestructor* value, const AzCssMediaQueryVecDestructorType** restrict out) {
+    const AzCssMediaQueryVecDestructorVariant_External* casted = (const AzCssMediaQueryVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssMediaQueryVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaQueryVecDestructor_matchMutExternal(AzCssMediaQueryVecDestructor* restrict value, AzCssMediaQueryVecDestructorType* restrict * restrict out) {
+    AzCssMediaQueryVecDestructorVariant_External* restrict casted = (AzCssMediaQueryVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssMediaQueryVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeatureVecDestructor_matchRefExternal(const AzCssMediaFeatureVecDestructor* value, const AzCssMediaFeatureVecDestructorType** restrict out) {
+    const AzCssMediaFeatureVecDestructorVariant_External* casted = (const AzCssMediaFeatureVecDestructorVariant_External*)value;
+    bool valid = casted->tag == AzCssMediaFeatureVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzCssMediaFeatureVecDestructor_matchMutExternal(AzCssMediaFeatureVecDestructor* restrict value, AzCssMediaFeatureVecDestructorType* restrict * restrict out) {
+    AzCssMediaFeatureVecDestructorVariant_External* restrict casted = (AzCssMediaFeatureVecDestructorVariant_External* restrict)value;
+    bool valid = casted->tag == AzCssMediaFeatureVecDestructorTag_External;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzCssRuleBlockVecDestructor_matchRefExternal(const AzCssRuleBlockVecDestructor* value, const AzCssRuleBlockVecDestructorType** restrict out) {
     const AzCssRuleBlockVecDestructorVariant_External* casted = (const AzCssRuleBlockVecDestructorVariant_External*)value;
     bool valid = casted->tag == AzCssRuleBlockVecDestructorTag_External;
@@ -19670,6 +24013,76 @@ bool AzOptionListViewOnRowClick_matchMutSome(AzOptionListViewOnRowClick* restric
     return valid;
 }
 
+bool AzOptionTreeViewNodeId_matchRefSome(const AzOptionTreeViewNodeId* value, const AzTreeViewNodeId** restrict out) {
+    const AzOptionTreeViewNodeIdVariant_Some* casted = (const AzOptionTreeViewNodeIdVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewNodeIdTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewNodeId_matchMutSome(AzOptionTreeViewNodeId* restrict value, AzTreeViewNodeId* restrict * restrict out) {
+    AzOptionTreeViewNodeIdVariant_Some* restrict casted = (AzOptionTreeViewNodeIdVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewNodeIdTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnExpand_matchRefSome(const AzOptionTreeViewOnExpand* value, const AzTreeViewOnExpand** restrict out) {
+    const AzOptionTreeViewOnExpandVariant_Some* casted = (const AzOptionTreeViewOnExpandVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnExpandTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnExpand_matchMutSome(AzOptionTreeViewOnExpand* restrict value, AzTreeViewOnExpand* restrict * restrict out) {
+    AzOptionTreeViewOnExpandVariant_Some* restrict casted = (AzOptionTreeViewOnExpandVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnExpandTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnSelect_matchRefSome(const AzOptionTreeViewOnSelect* value, const AzTreeViewOnSelect** restrict out) {
+    const AzOptionTreeViewOnSelectVariant_Some* casted = (const AzOptionTreeViewOnSelectVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnSelectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnSelect_matchMutSome(AzOptionTreeViewOnSelect* restrict value, AzTreeViewOnSelect* restrict * restrict out) {
+    AzOptionTreeViewOnSelectVariant_Some* restrict casted = (AzOptionTreeViewOnSelectVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnSelectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnDoubleClick_matchRefSome(const AzOptionTreeViewOnDoubleClick* value, const AzTreeViewOnDoubleClick** restrict out) {
+    const AzOptionTreeViewOnDoubleClickVariant_Some* casted = (const AzOptionTreeViewOnDoubleClickVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnDoubleClickTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnDoubleClick_matchMutSome(AzOptionTreeViewOnDoubleClick* restrict value, AzTreeViewOnDoubleClick* restrict * restrict out) {
+    AzOptionTreeViewOnDoubleClickVariant_Some* restrict casted = (AzOptionTreeViewOnDoubleClickVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnDoubleClickTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnContextMenu_matchRefSome(const AzOptionTreeViewOnContextMenu* value, const AzTreeViewOnContextMenu** restrict out) {
+    const AzOptionTreeViewOnContextMenuVariant_Some* casted = (const AzOptionTreeViewOnContextMenuVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTreeViewOnContextMenuTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTreeViewOnContextMenu_matchMutSome(AzOptionTreeViewOnContextMenu* restrict value, AzTreeViewOnContextMenu* restrict * restrict out) {
+    AzOptionTreeViewOnContextMenuVariant_Some* restrict casted = (AzOptionTreeViewOnContextMenuVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTreeViewOnContextMenuTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionListViewOnColumnClick_matchRefSome(const AzOptionListViewOnColumnClick* value, const AzListViewOnColumnClick** restrict out) {
     const AzOptionListViewOnColumnClickVariant_Some* casted = (const AzOptionListViewOnColumnClickVariant_Some*)value;
     bool valid = casted->tag == AzOptionListViewOnColumnClickTag_Some;
@@ -19698,6 +24111,34 @@ bool AzOptionListViewOnLazyLoadScroll_matchMutSome(AzOptionListViewOnLazyLoadScr
     return valid;
 }
 
+bool AzOptionListViewOnColumnResize_matchRefSome(const AzOptionListViewOnColumnResize* value, const AzListViewOnColumnResize** restrict out) {
+    const AzOptionListViewOnColumnResizeVariant_Some* casted = (const AzOptionListViewOnColumnResizeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionListViewOnColumnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionListViewOnColumnResize_matchMutSome(AzOptionListViewOnColumnResize* restrict value, AzListViewOnColumnResize* restrict * restrict out) {
+    AzOptionListViewOnColumnResizeVariant_Some* restrict casted = (AzOptionListViewOnColumnResizeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionListViewOnColumnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionListViewRowProvider_matchRefSome(const AzOptionListViewRowProvider* value, const AzListViewRowProvider** restrict out) {
+    const AzOptionListViewRowProviderVariant_Some* casted = (const AzOptionListViewRowProviderVariant_Some*)value;
+    bool valid = casted->tag == AzOptionListViewRowProviderTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionListViewRowProvider_matchMutSome(AzOptionListViewRowProvider* restrict value, AzListViewRowProvider* restrict * restrict out) {
+    AzOptionListViewRowProviderVariant_Some* restrict casted = (AzOptionListViewRowProviderVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionListViewRowProviderTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionMenu_matchRefSome(const AzOptionMenu* value, const AzMenu** restrict out) {
     const AzOptionMenuVariant_Some* casted = (const AzOptionMenuVariant_Some*)value;
     bool valid = casted->tag == AzOptionMenuTag_Some;
@@ -19908,6 +24349,118 @@ bool AzOptionTabOnClick_matchMutSome(AzOptionTabOnClick* restrict value, AzTabOn
     return valid;
 }
 
+bool AzOptionTabOnClose_matchRefSome(const AzOptionTabOnClose* value, const AzTabOnClose** restrict out) {
+    const AzOptionTabOnCloseVariant_Some* casted = (const AzOptionTabOnCloseVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTabOnCloseTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabOnClose_matchMutSome(AzOptionTabOnClose* restrict value, AzTabOnClose* restrict * restrict out) {
+    AzOptionTabOnCloseVariant_Some* restrict casted = (AzOptionTabOnCloseVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTabOnCloseTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabOnReorder_matchRefSome(const AzOptionTabOnReorder* value, const AzTabOnReorder** restrict out) {
+    const AzOptionTabOnReorderVariant_Some* casted = (const AzOptionTabOnReorderVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTabOnReorderTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabOnReorder_matchMutSome(AzOptionTabOnReorder* restrict value, AzTabOnReorder* restrict * restrict out) {
+    AzOptionTabOnReorderVariant_Some* restrict casted = (AzOptionTabOnReorderVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTabOnReorderTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabOnContextMenu_matchRefSome(const AzOptionTabOnContextMenu* value, const AzTabOnContextMenu** restrict out) {
+    const AzOptionTabOnContextMenuVariant_Some* casted = (const AzOptionTabOnContextMenuVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTabOnContextMenuTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTabOnContextMenu_matchMutSome(AzOptionTabOnContextMenu* restrict value, AzTabOnContextMenu* restrict * restrict out) {
+    AzOptionTabOnContextMenuVariant_Some* restrict casted = (AzOptionTabOnContextMenuVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTabOnContextMenuTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionRibbonOnTabClicked_matchRefSome(const AzOptionRibbonOnTabClicked* value, const AzRibbonOnTabClicked** restrict out) {
+    const AzOptionRibbonOnTabClickedVariant_Some* casted = (const AzOptionRibbonOnTabClickedVariant_Some*)value;
+    bool valid = casted->tag == AzOptionRibbonOnTabClickedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionRibbonOnTabClicked_matchMutSome(AzOptionRibbonOnTabClicked* restrict value, AzRibbonOnTabClicked* restrict * restrict out) {
+    AzOptionRibbonOnTabClickedVariant_Some* restrict casted = (AzOptionRibbonOnTabClickedVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionRibbonOnTabClickedTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionRibbonOnItemClick_matchRefSome(const AzOptionRibbonOnItemClick* value, const AzRibbonOnItemClick** restrict out) {
+    const AzOptionRibbonOnItemClickVariant_Some* casted = (const AzOptionRibbonOnItemClickVariant_Some*)value;
+    bool valid = casted->tag == AzOptionRibbonOnItemClickTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionRibbonOnItemClick_matchMutSome(AzOptionRibbonOnItemClick* restrict value, AzRibbonOnItemClick* restrict * restrict out) {
+    AzOptionRibbonOnItemClickVariant_Some* restrict casted = (AzOptionRibbonOnItemClickVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionRibbonOnItemClickTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSplitterOnResize_matchRefSome(const AzOptionSplitterOnResize* value, const AzSplitterOnResize** restrict out) {
+    const AzOptionSplitterOnResizeVariant_Some* casted = (const AzOptionSplitterOnResizeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSplitterOnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSplitterOnResize_matchMutSome(AzOptionSplitterOnResize* restrict value, AzSplitterOnResize* restrict * restrict out) {
+    AzOptionSplitterOnResizeVariant_Some* restrict casted = (AzOptionSplitterOnResizeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSplitterOnResizeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSliderOnValueChange_matchRefSome(const AzOptionSliderOnValueChange* value, const AzSliderOnValueChange** restrict out) {
+    const AzOptionSliderOnValueChangeVariant_Some* casted = (const AzOptionSliderOnValueChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionSliderOnValueChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionSliderOnValueChange_matchMutSome(AzOptionSliderOnValueChange* restrict value, AzSliderOnValueChange* restrict * restrict out) {
+    AzOptionSliderOnValueChangeVariant_Some* restrict casted = (AzOptionSliderOnValueChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionSliderOnValueChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionRangeSliderOnValueChange_matchRefSome(const AzOptionRangeSliderOnValueChange* value, const AzRangeSliderOnValueChange** restrict out) {
+    const AzOptionRangeSliderOnValueChangeVariant_Some* casted = (const AzOptionRangeSliderOnValueChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionRangeSliderOnValueChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionRangeSliderOnValueChange_matchMutSome(AzOptionRangeSliderOnValueChange* restrict value, AzRangeSliderOnValueChange* restrict * restrict out) {
+    AzOptionRangeSliderOnValueChangeVariant_Some* restrict casted = (AzOptionRangeSliderOnValueChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionRangeSliderOnValueChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionFileInputOnPathChange_matchRefSome(const AzOptionFileInputOnPathChange* value, const AzFileInputOnPathChange** restrict out) {
     const AzOptionFileInputOnPathChangeVariant_Some* casted = (const AzOptionFileInputOnPathChangeVariant_Some*)value;
     bool valid = casted->tag == AzOptionFileInputOnPathChangeTag_Some;
@@ -19992,6 +24545,20 @@ bool AzOptionTextInputSelection_matchMutSome(AzOptionTextInputSelection* restric
     return valid;
 }
 
+bool AzOptionTextAreaOnTextChange_matchRefSome(const AzOptionTextAreaOnTextChange* value, const AzTextAreaOnTextChange** restrict out) {
+    const AzOptionTextAreaOnTextChangeVariant_Some* casted = (const AzOptionTextAreaOnTextChangeVariant_Some*)value;
+    bool valid = casted->tag == AzOptionTextAreaOnTextChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionTextAreaOnTextChange_matchMutSome(AzOptionTextAreaOnTextChange* restrict value, AzTextAreaOnTextChange* restrict * restrict out) {
+    AzOptionTextAreaOnTextChangeVariant_Some* restrict casted = (AzOptionTextAreaOnTextChangeVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionTextAreaOnTextChangeTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionNumberInputOnFocusLost_matchRefSome(const AzOptionNumberInputOnFocusLost* value, const AzNumberInputOnFocusLost** restrict out) {
     const AzOptionNumberInputOnFocusLostVariant_Some* casted = (const AzOptionNumberInputOnFocusLostVariant_Some*)value;
     bool valid = casted->tag == AzOptionNumberInputOnFocusLostTag_Some;
@@ -20594,6 +25161,48 @@ bool AzOptionLogicalPosition_matchMutSome(AzOptionLogicalPosition* restrict valu
     return valid;
 }
 
+bool AzOptionImeComposition_matchRefSome(const AzOptionImeComposition* value, const AzImeComposition** restrict out) {
+    const AzOptionImeCompositionVariant_Some* casted = (const AzOptionImeCompositionVariant_Some*)value;
+    bool valid = casted->tag == AzOptionImeCompositionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionImeComposition_matchMutSome(AzOptionImeComposition* restrict value, AzImeComposition* restrict * restrict out) {
+    AzOptionImeCompositionVariant_Some* restrict casted = (AzOptionImeCompositionVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionImeCompositionTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionClipboardContent_matchRefSome(const AzOptionClipboardContent* value, const AzClipboardContent** restrict out) {
+    const AzOptionClipboardContentVariant_Some* casted = (const AzOptionClipboardContentVariant_Some*)value;
+    bool valid = casted->tag == AzOptionClipboardContentTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionClipboardContent_matchMutSome(AzOptionClipboardContent* restrict value, AzClipboardContent* restrict * restrict out) {
+    AzOptionClipboardContentVariant_Some* restrict casted = (AzOptionClipboardContentVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionClipboardContentTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionLogicalRect_matchRefSome(const AzOptionLogicalRect* value, const AzLogicalRect** restrict out) {
+    const AzOptionLogicalRectVariant_Some* casted = (const AzOptionLogicalRectVariant_Some*)value;
+    bool valid = casted->tag == AzOptionLogicalRectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
+bool AzOptionLogicalRect_matchMutSome(AzOptionLogicalRect* restrict value, AzLogicalRect* restrict * restrict out) {
+    AzOptionLogicalRectVariant_Some* restrict casted = (AzOptionLogicalRectVariant_Some* restrict)value;
+    bool valid = casted->tag == AzOptionLogicalRectTag_Some;
+    if (valid) { *out = &casted->payload; } else { *out = 0; }
+    return valid;
+}
+
 bool AzOptionPhysicalPositionI32_matchRefSome(const AzOptionPhysicalPositionI32* value, const AzPhysicalPositionI32** restrict out) {
     const AzOptionPhysicalPositionI32Variant_Some* casted = (const AzOptionPhysicalPositionI32Variant_Some*)value;
     bool valid = casted->tag == AzOptionPhysicalPositionI32Tag_Some;
@@ -21,6 +21,8 @@ namespace dll {
     struct IFrameCallbackReturn;
     using IFrameCallbackType = IFrameCallbackReturn(*)(RefAny* restrict, IFrameCallbackInfo* restrict);
     
+    using TooltipCallbackType = StyledDom(*)(RefAny* restrict, LayoutCallbackInfo* restrict);
+    
     struct RenderImageCallbackInfo;
     struct ImageRef;
     using RenderImageCallbackType = ImageRef(*)(RefAny* restrict, RenderImageCallbackInfo* restrict);
@@ -37,6 +39,9 @@ namespace dll {
     
     using RefAnyDestructorType = void(*)(void* restrict);
     
+    struct RibbonItemState;
+    using RibbonOnItemClickCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, RibbonItemState* const);
+    
     using RibbonOnTabClickedCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, int32_t);
     
     struct FileInputState;
@@ -56,6 +61,9 @@ namespace dll {
     
     using TextInputOnFocusLostCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, TextInputState* const);
     
+    struct TextAreaState;
+    using TextAreaOnTextChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, TextAreaState* const);
+    
     struct NumberInputState;
     using NumberInputOnValueChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, NumberInputState* const);
     
@@ -64,6 +72,21 @@ namespace dll {
     struct TabHeaderState;
     using TabOnClickCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, TabHeaderState* const);
     
+    using TabOnCloseCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t);
+    
+    using TabOnReorderCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t, size_t);
+    
+    using TabOnContextMenuCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t);
+    
+    struct SplitterState;
+    using SplitterOnResizeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, SplitterState* const);
+    
+    struct SliderState;
+    using SliderOnValueChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, SliderState* const);
+    
+    struct RangeSliderState;
+    using RangeSliderOnValueChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, RangeSliderState* const);
+    
     struct NodeTypeId;
     struct NodeGraphNodeId;
     struct NodePosition;
@@ -93,6 +116,22 @@ namespace dll {
     
     using ListViewOnRowClickCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, ListViewState* const, size_t);
     
+    using ListViewOnColumnResizeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, ListViewState* const, size_t, float);
+    
+    struct ListViewRowRange;
+    struct ListViewRowVec;
+    using ListViewRowProviderCallbackType = ListViewRowVec(*)(RefAny* restrict, ListViewState* const, ListViewRowRange);
+    
+    struct TreeViewState;
+    struct TreeViewNodeId;
+    using TreeViewOnExpandCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, TreeViewState* const, TreeViewNodeId, bool );
+    
+    using TreeViewOnSelectCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, TreeViewState* const);
+    
+    using TreeViewOnDoubleClickCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, TreeViewState* const, TreeViewNodeId);
+    
+    using TreeViewOnContextMenuCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, TreeViewState* const, TreeViewNodeId);
+    
     using DropDownOnChoiceChangeCallbackType = Update(*)(RefAny* restrict, CallbackInfo* restrict, size_t);
     
     using ParsedFontDestructorFnType = void(*)(void* restrict);
@@ -109,6 +148,15 @@ namespace dll {
     union Instant;
     using GetSystemTimeFnType = Instant(*)();
     
+    union RawWindowHandle;
+    enum ClipboardSelection;
+    struct String;
+    union OptionClipboardContent;
+    using GetClipboardFnType = OptionClipboardContent(*)(RawWindowHandle* const, ClipboardSelection, String);
+    
+    struct ClipboardContent;
+    using SetClipboardFnType = bool(*)(RawWindowHandle* const, ClipboardSelection, ClipboardContent);
+    
     using CheckThreadFinishedFnType = bool(*)(const void*);
     
     union ThreadSendMsg;
@@ -132,9 +180,32 @@ namespace dll {
     struct StyleFontFamilyVec;
     using StyleFontFamilyVecDestructorType = void(*)(StyleFontFamilyVec* restrict);
     
-    struct ListViewRowVec;
     using ListViewRowVecDestructorType = void(*)(ListViewRowVec* restrict);
     
+    struct TreeViewNodeVec;
+    using TreeViewNodeVecDestructorType = void(*)(TreeViewNodeVec* restrict);
+    
+    struct TreeViewNodeIdVec;
+    using TreeViewNodeIdVecDestructorType = void(*)(TreeViewNodeIdVec* restrict);
+    
+    struct RibbonTabVec;
+    using RibbonTabVecDestructorType = void(*)(RibbonTabVec* restrict);
+    
+    struct RibbonGroupVec;
+    using RibbonGroupVecDestructorType = void(*)(RibbonGroupVec* restrict);
+    
+    struct RibbonItemVec;
+    using RibbonItemVecDestructorType = void(*)(RibbonItemVec* restrict);
+    
+    struct SplitterPaneVec;
+    using SplitterPaneVecDestructorType = void(*)(SplitterPaneVec* restrict);
+    
+    struct SplitterPaneStateVec;
+    using SplitterPaneStateVecDestructorType = void(*)(SplitterPaneStateVec* restrict);
+    
+    struct TextAreaEditVec;
+    using TextAreaEditVecDestructorType = void(*)(TextAreaEditVec* restrict);
+    
     struct StyleFilterVec;
     using StyleFilterVecDestructorType = void(*)(StyleFilterVec* restrict);
     
@@ -171,6 +242,9 @@ namespace dll {
     struct AccessibilityStateVec;
     using AccessibilityStateVecDestructorType = void(*)(AccessibilityStateVec* restrict);
     
+    struct AccessibilityActionVec;
+    using AccessibilityActionVecDestructorType = void(*)(AccessibilityActionVec* restrict);
+    
     struct MenuItemVec;
     using MenuItemVecDestructorType = void(*)(MenuItemVec* restrict);
     
@@ -225,6 +299,33 @@ namespace dll {
     struct StyleTransformVec;
     using StyleTransformVecDestructorType = void(*)(StyleTransformVec* restrict);
     
+    struct StyleTransitionPropertyVec;
+    using StyleTransitionPropertyVecDestructorType = void(*)(StyleTransitionPropertyVec* restrict);
+    
+    struct StyleTimeVec;
+    using StyleTimeVecDestructorType = void(*)(StyleTimeVec* restrict);
+    
+    struct StyleTimingFunctionVec;
+    using StyleTimingFunctionVecDestructorType = void(*)(StyleTimingFunctionVec* restrict);
+    
+    struct StyleAnimationNameVec;
+    using StyleAnimationNameVecDestructorType = void(*)(StyleAnimationNameVec* restrict);
+    
+    struct StyleAnimationIterationCountVec;
+    using StyleAnimationIterationCountVecDestructorType = void(*)(StyleAnimationIterationCountVec* restrict);
+    
+    struct StyleAnimationDirectionVec;
+    using StyleAnimationDirectionVecDestructorType = void(*)(StyleAnimationDirectionVec* restrict);
+    
+    struct StyleAnimationFillModeVec;
+    using StyleAnimationFillModeVecDestructorType = void(*)(StyleAnimationFillModeVec* restrict);
+    
+    struct StyleAnimationPlayStateVec;
+    using StyleAnimationPlayStateVecDestructorType = void(*)(StyleAnimationPlayStateVec* restrict);
+    
+    struct GridTrackSizingVec;
+    using GridTrackSizingVecDestructorType = void(*)(GridTrackSizingVec* restrict);
+    
     struct CssPropertyVec;
     using CssPropertyVecDestructorType = void(*)(CssPropertyVec* restrict);
     
@@ -267,6 +368,21 @@ namespace dll {
     struct StylesheetVec;
     using StylesheetVecDestructorType = void(*)(StylesheetVec* restrict);
     
+    struct CssKeyframesVec;
+    using CssKeyframesVecDestructorType = void(*)(CssKeyframesVec* restrict);
+    
+    struct CssKeyframeVec;
+    using CssKeyframeVecDestructorType = void(*)(CssKeyframeVec* restrict);
+    
+    struct CssMediaRuleVec;
+    using CssMediaRuleVecDestructorType = void(*)(CssMediaRuleVec* restrict);
+    
+    struct CssMediaQueryVec;
+    using CssMediaQueryVecDestructorType = void(*)(CssMediaQueryVec* restrict);
+    
+    struct CssMediaFeatureVec;
+    using CssMediaFeatureVecDestructorType = void(*)(CssMediaFeatureVec* restrict);
+    
     struct CssRuleBlockVec;
     using CssRuleBlockVecDestructorType = void(*)(CssRuleBlockVec* restrict);
     
@@ -430,6 +546,17 @@ namespace dll {
         AndroidHandle() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class ClipboardSelection {
+       Clipboard,
+       Primary,
+    };
+    
+    enum class LinuxBackend {
+       Auto,
+       X11,
+       Wayland,
+    };
+    
     enum class XWindowType {
        Desktop,
        Dock,
@@ -835,6 +962,12 @@ namespace dll {
         IFrameCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TooltipCallback {
+        TooltipCallbackType cb;
+        TooltipCallback& operator=(const TooltipCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TooltipCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct RenderImageCallback {
         RenderImageCallbackType cb;
         RenderImageCallback& operator=(const RenderImageCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -885,6 +1018,9 @@ namespace dll {
        TextInput,
        VirtualKeyDown,
        VirtualKeyUp,
+       CompositionStart,
+       CompositionUpdate,
+       CompositionEnd,
        HoveredFile,
        DroppedFile,
        HoveredFileCancelled,
@@ -910,6 +1046,9 @@ namespace dll {
        TextInput,
        VirtualKeyDown,
        VirtualKeyUp,
+       CompositionStart,
+       CompositionUpdate,
+       CompositionEnd,
        HoveredFile,
        DroppedFile,
        HoveredFileCancelled,
@@ -937,6 +1076,9 @@ namespace dll {
        TextInput,
        VirtualKeyDown,
        VirtualKeyUp,
+       CompositionStart,
+       CompositionUpdate,
+       CompositionEnd,
        FocusReceived,
        FocusLost,
     };
@@ -959,6 +1101,9 @@ namespace dll {
        TextInput,
        VirtualKeyDown,
        VirtualKeyUp,
+       CompositionStart,
+       CompositionUpdate,
+       CompositionEnd,
        HoveredFile,
        DroppedFile,
        HoveredFileCancelled,
@@ -1073,6 +1218,17 @@ namespace dll {
        Protected,
     };
     
+    enum class AccessibilityAction {
+       Default,
+       Focus,
+       Toggle,
+       Expand,
+       Collapse,
+       Select,
+       Increment,
+       Decrement,
+    };
+    
     enum class TabIndexTag {
        Auto,
        OverrideInParent,
@@ -1130,11 +1286,31 @@ namespace dll {
         CssNthChildPattern() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class CssMediaType {
+       All,
+       Screen,
+       Print,
+    };
+    
+    enum class CssMediaOrientation {
+       Portrait,
+       Landscape,
+    };
+    
+    enum class CssColorScheme {
+       Light,
+       Dark,
+    };
+    
     enum class CssPropertyType {
        TextColor,
        FontSize,
        FontFamily,
        TextAlign,
+       Direction,
+       WordBreak,
+       OverflowWrap,
+       Hyphens,
        LetterSpacing,
        LineHeight,
        WordSpacing,
@@ -1161,6 +1337,15 @@ namespace dll {
        JustifyContent,
        AlignItems,
        AlignContent,
+       GridTemplateColumns,
+       GridTemplateRows,
+       GridAutoColumns,
+       GridAutoRows,
+       GridAutoFlow,
+       GridColumn,
+       GridRow,
+       RowGap,
+       ColumnGap,
        BackgroundContent,
        BackgroundPosition,
        BackgroundSize,
@@ -1205,6 +1390,37 @@ namespace dll {
        Filter,
        BackdropFilter,
        TextShadow,
+       TransitionProperty,
+       TransitionDuration,
+       TransitionTimingFunction,
+       TransitionDelay,
+       AnimationName,
+       AnimationDuration,
+       AnimationTimingFunction,
+       AnimationDelay,
+       AnimationIterationCount,
+       AnimationDirection,
+       AnimationFillMode,
+       AnimationPlayState,
+    };
+    
+    enum class CombinedCssPropertyType {
+       BorderRadius,
+       Overflow,
+       Margin,
+       Border,
+       BorderLeft,
+       BorderRight,
+       BorderTop,
+       BorderBottom,
+       Padding,
+       BoxShadow,
+       BackgroundColor,
+       BackgroundImage,
+       Gap,
+       GridGap,
+       Transition,
+       Animation,
     };
     
     struct ColorU {
@@ -1262,6 +1478,29 @@ namespace dll {
        SpaceAround,
     };
     
+    enum class LayoutGridAutoFlow {
+       Row,
+       Column,
+       RowDense,
+       ColumnDense,
+    };
+    
+    enum class GridLineTag {
+       Auto,
+       Line,
+       Span,
+    };
+    
+    struct GridLineVariant_Auto { GridLineTag tag; };
+    struct GridLineVariant_Line { GridLineTag tag; int32_t payload; };
+    struct GridLineVariant_Span { GridLineTag tag; uint32_t payload; };
+    union GridLine {
+        GridLineVariant_Auto Auto;
+        GridLineVariant_Line Line;
+        GridLineVariant_Span Span;
+    };
+    
+    
     enum class LayoutAlignItems {
        Stretch,
        Center,
@@ -1286,6 +1525,7 @@ namespace dll {
        Flex,
        Block,
        InlineBlock,
+       Grid,
     };
     
     enum class LayoutFloat {
@@ -1415,17 +1655,82 @@ namespace dll {
        Visible,
     };
     
+    struct StyleTime {
+        int32_t ms;
+        StyleTime& operator=(const StyleTime&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTime() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class StyleStepPosition {
+       JumpStart,
+       JumpEnd,
+       JumpNone,
+       JumpBoth,
+    };
+    
+    enum class StyleAnimationDirection {
+       Normal,
+       Reverse,
+       Alternate,
+       AlternateReverse,
+    };
+    
+    enum class StyleAnimationFillMode {
+       None,
+       Forwards,
+       Backwards,
+       Both,
+    };
+    
+    enum class StyleAnimationPlayState {
+       Running,
+       Paused,
+    };
+    
     enum class StyleTextAlign {
        Left,
        Center,
        Right,
+       Start,
+       End,
     };
     
-    struct Ribbon {
-        int32_t tab_active;
-        Ribbon& operator=(const Ribbon&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        Ribbon(const Ribbon&) = delete; /* disable copy constructor, use explicit .clone() */
-        Ribbon() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    enum class StyleDirection {
+       Ltr,
+       Rtl,
+    };
+    
+    enum class StyleWordBreak {
+       Normal,
+       BreakAll,
+       KeepAll,
+    };
+    
+    enum class StyleOverflowWrap {
+       Normal,
+       Anywhere,
+       BreakWord,
+    };
+    
+    enum class StyleHyphens {
+       None,
+       Manual,
+       Auto,
+    };
+    
+    enum class RibbonItemKind {
+       LargeButton,
+       SmallButton,
+       Toggle,
+       DropDown,
+       SplitButton,
+    };
+    
+    struct RibbonOnItemClickCallback {
+        RibbonOnItemClickCallbackType cb;
+        RibbonOnItemClickCallback& operator=(const RibbonOnItemClickCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonOnItemClickCallback(const RibbonOnItemClickCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        RibbonOnItemClickCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct RibbonOnTabClickedCallback {
@@ -1496,6 +1801,19 @@ namespace dll {
        No,
     };
     
+    enum class TextAreaEditKind {
+       Insert,
+       Delete,
+       Replace,
+    };
+    
+    struct TextAreaOnTextChangeCallback {
+        TextAreaOnTextChangeCallbackType cb;
+        TextAreaOnTextChangeCallback& operator=(const TextAreaOnTextChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextAreaOnTextChangeCallback(const TextAreaOnTextChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextAreaOnTextChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NumberInputState {
         float previous;
         float number;
@@ -1520,12 +1838,14 @@ namespace dll {
         NumberInputOnFocusLostCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct ProgressBarState {
-        float percent_done;
-        bool  display_percentage;
-        ProgressBarState& operator=(const ProgressBarState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        ProgressBarState(const ProgressBarState&) = delete; /* disable copy constructor, use explicit .clone() */
-        ProgressBarState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    enum class ProgressBarMode {
+       Determinate,
+       Indeterminate,
+    };
+    
+    enum class ProgressBarStyle {
+       Solid,
+       Stripes,
     };
     
     struct TabHeaderState {
@@ -1542,6 +1862,84 @@ namespace dll {
         TabOnClickCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TabOnCloseCallback {
+        TabOnCloseCallbackType cb;
+        TabOnCloseCallback& operator=(const TabOnCloseCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabOnCloseCallback(const TabOnCloseCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabOnCloseCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabOnReorderCallback {
+        TabOnReorderCallbackType cb;
+        TabOnReorderCallback& operator=(const TabOnReorderCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabOnReorderCallback(const TabOnReorderCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabOnReorderCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabOnContextMenuCallback {
+        TabOnContextMenuCallbackType cb;
+        TabOnContextMenuCallback& operator=(const TabOnContextMenuCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabOnContextMenuCallback(const TabOnContextMenuCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabOnContextMenuCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class SplitterOrientation {
+       Horizontal,
+       Vertical,
+    };
+    
+    struct SplitterPaneState {
+        float size;
+        bool  collapsed;
+        SplitterPaneState& operator=(const SplitterPaneState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterPaneState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SplitterOnResizeCallback {
+        SplitterOnResizeCallbackType cb;
+        SplitterOnResizeCallback& operator=(const SplitterOnResizeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterOnResizeCallback(const SplitterOnResizeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterOnResizeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class SliderOrientation {
+       Horizontal,
+       Vertical,
+    };
+    
+    struct SliderState {
+        float value;
+        float min;
+        float max;
+        float step;
+        SliderState& operator=(const SliderState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct RangeSliderState {
+        float low;
+        float high;
+        float min;
+        float max;
+        float step;
+        RangeSliderState& operator=(const RangeSliderState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RangeSliderState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SliderOnValueChangeCallback {
+        SliderOnValueChangeCallbackType cb;
+        SliderOnValueChangeCallback& operator=(const SliderOnValueChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderOnValueChangeCallback(const SliderOnValueChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderOnValueChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct RangeSliderOnValueChangeCallback {
+        RangeSliderOnValueChangeCallbackType cb;
+        RangeSliderOnValueChangeCallback& operator=(const RangeSliderOnValueChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RangeSliderOnValueChangeCallback(const RangeSliderOnValueChangeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        RangeSliderOnValueChangeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class NodeGraphStyle {
        Default,
     };
@@ -1662,6 +2060,66 @@ namespace dll {
         ListViewOnRowClickCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ListViewRowRange {
+        size_t start;
+        size_t end;
+        ListViewRowRange& operator=(const ListViewRowRange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewRowRange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ListViewOnColumnResizeCallback {
+        ListViewOnColumnResizeCallbackType cb;
+        ListViewOnColumnResizeCallback& operator=(const ListViewOnColumnResizeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewOnColumnResizeCallback(const ListViewOnColumnResizeCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewOnColumnResizeCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ListViewRowProviderCallback {
+        ListViewRowProviderCallbackType cb;
+        ListViewRowProviderCallback& operator=(const ListViewRowProviderCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewRowProviderCallback(const ListViewRowProviderCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewRowProviderCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewNodeId {
+        uint64_t inner;
+        TreeViewNodeId& operator=(const TreeViewNodeId&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewNodeId() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class TreeViewSelectionMode {
+       Single,
+       Multiple,
+    };
+    
+    struct TreeViewOnExpandCallback {
+        TreeViewOnExpandCallbackType cb;
+        TreeViewOnExpandCallback& operator=(const TreeViewOnExpandCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnExpandCallback(const TreeViewOnExpandCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnExpandCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnSelectCallback {
+        TreeViewOnSelectCallbackType cb;
+        TreeViewOnSelectCallback& operator=(const TreeViewOnSelectCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnSelectCallback(const TreeViewOnSelectCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnSelectCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnDoubleClickCallback {
+        TreeViewOnDoubleClickCallbackType cb;
+        TreeViewOnDoubleClickCallback& operator=(const TreeViewOnDoubleClickCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnDoubleClickCallback(const TreeViewOnDoubleClickCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnDoubleClickCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnContextMenuCallback {
+        TreeViewOnContextMenuCallbackType cb;
+        TreeViewOnContextMenuCallback& operator=(const TreeViewOnContextMenuCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnContextMenuCallback(const TreeViewOnContextMenuCallback&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnContextMenuCallback() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct DropDownOnChoiceChangeCallback {
         DropDownOnChoiceChangeCallbackType cb;
         DropDownOnChoiceChangeCallback& operator=(const DropDownOnChoiceChangeCallback&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -2201,6 +2659,20 @@ namespace dll {
         GetSystemTimeFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct GetClipboardFn {
+        GetClipboardFnType cb;
+        GetClipboardFn& operator=(const GetClipboardFn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GetClipboardFn(const GetClipboardFn&) = delete; /* disable copy constructor, use explicit .clone() */
+        GetClipboardFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SetClipboardFn {
+        SetClipboardFnType cb;
+        SetClipboardFn& operator=(const SetClipboardFn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SetClipboardFn(const SetClipboardFn&) = delete; /* disable copy constructor, use explicit .clone() */
+        SetClipboardFn() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CheckThreadFinishedFn {
         CheckThreadFinishedFnType cb;
         CheckThreadFinishedFn& operator=(const CheckThreadFinishedFn&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -2289,6 +2761,134 @@ namespace dll {
     };
     
     
+    enum class TreeViewNodeVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TreeViewNodeVecDestructorVariant_DefaultRust { TreeViewNodeVecDestructorTag tag; };
+    struct TreeViewNodeVecDestructorVariant_NoDestructor { TreeViewNodeVecDestructorTag tag; };
+    struct TreeViewNodeVecDestructorVariant_External { TreeViewNodeVecDestructorTag tag; TreeViewNodeVecDestructorType payload; };
+    union TreeViewNodeVecDestructor {
+        TreeViewNodeVecDestructorVariant_DefaultRust DefaultRust;
+        TreeViewNodeVecDestructorVariant_NoDestructor NoDestructor;
+        TreeViewNodeVecDestructorVariant_External External;
+    };
+    
+    
+    enum class TreeViewNodeIdVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TreeViewNodeIdVecDestructorVariant_DefaultRust { TreeViewNodeIdVecDestructorTag tag; };
+    struct TreeViewNodeIdVecDestructorVariant_NoDestructor { TreeViewNodeIdVecDestructorTag tag; };
+    struct TreeViewNodeIdVecDestructorVariant_External { TreeViewNodeIdVecDestructorTag tag; TreeViewNodeIdVecDestructorType payload; };
+    union TreeViewNodeIdVecDestructor {
+        TreeViewNodeIdVecDestructorVariant_DefaultRust DefaultRust;
+        TreeViewNodeIdVecDestructorVariant_NoDestructor NoDestructor;
+        TreeViewNodeIdVecDestructorVariant_External External;
+    };
+    
+    
+    enum class RibbonTabVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct RibbonTabVecDestructorVariant_DefaultRust { RibbonTabVecDestructorTag tag; };
+    struct RibbonTabVecDestructorVariant_NoDestructor { RibbonTabVecDestructorTag tag; };
+    struct RibbonTabVecDestructorVariant_External { RibbonTabVecDestructorTag tag; RibbonTabVecDestructorType payload; };
+    union RibbonTabVecDestructor {
+        RibbonTabVecDestructorVariant_DefaultRust DefaultRust;
+        RibbonTabVecDestructorVariant_NoDestructor NoDestructor;
+        RibbonTabVecDestructorVariant_External External;
+    };
+    
+    
+    enum class RibbonGroupVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct RibbonGroupVecDestructorVariant_DefaultRust { RibbonGroupVecDestructorTag tag; };
+    struct RibbonGroupVecDestructorVariant_NoDestructor { RibbonGroupVecDestructorTag tag; };
+    struct RibbonGroupVecDestructorVariant_External { RibbonGroupVecDestructorTag tag; RibbonGroupVecDestructorType payload; };
+    union RibbonGroupVecDestructor {
+        RibbonGroupVecDestructorVariant_DefaultRust DefaultRust;
+        RibbonGroupVecDestructorVariant_NoDestructor NoDestructor;
+        RibbonGroupVecDestructorVariant_External External;
+    };
+    
+    
+    enum class RibbonItemVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct RibbonItemVecDestructorVariant_DefaultRust { RibbonItemVecDestructorTag tag; };
+    struct RibbonItemVecDestructorVariant_NoDestructor { RibbonItemVecDestructorTag tag; };
+    struct RibbonItemVecDestructorVariant_External { RibbonItemVecDestructorTag tag; RibbonItemVecDestructorType payload; };
+    union RibbonItemVecDestructor {
+        RibbonItemVecDestructorVariant_DefaultRust DefaultRust;
+        RibbonItemVecDestructorVariant_NoDestructor NoDestructor;
+        RibbonItemVecDestructorVariant_External External;
+    };
+    
+    
+    enum class SplitterPaneVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct SplitterPaneVecDestructorVariant_DefaultRust { SplitterPaneVecDestructorTag tag; };
+    struct SplitterPaneVecDestructorVariant_NoDestructor { SplitterPaneVecDestructorTag tag; };
+    struct SplitterPaneVecDestructorVariant_External { SplitterPaneVecDestructorTag tag; SplitterPaneVecDestructorType payload; };
+    union SplitterPaneVecDestructor {
+        SplitterPaneVecDestructorVariant_DefaultRust DefaultRust;
+        SplitterPaneVecDestructorVariant_NoDestructor NoDestructor;
+        SplitterPaneVecDestructorVariant_External External;
+    };
+    
+    
+    enum class SplitterPaneStateVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct SplitterPaneStateVecDestructorVariant_DefaultRust { SplitterPaneStateVecDestructorTag tag; };
+    struct SplitterPaneStateVecDestructorVariant_NoDestructor { SplitterPaneStateVecDestructorTag tag; };
+    struct SplitterPaneStateVecDestructorVariant_External { SplitterPaneStateVecDestructorTag tag; SplitterPaneStateVecDestructorType payload; };
+    union SplitterPaneStateVecDestructor {
+        SplitterPaneStateVecDestructorVariant_DefaultRust DefaultRust;
+        SplitterPaneStateVecDestructorVariant_NoDestructor NoDestructor;
+        SplitterPaneStateVecDestructorVariant_External External;
+    };
+    
+    
+    enum class TextAreaEditVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct TextAreaEditVecDestructorVariant_DefaultRust { TextAreaEditVecDestructorTag tag; };
+    struct TextAreaEditVecDestructorVariant_NoDestructor { TextAreaEditVecDestructorTag tag; };
+    struct TextAreaEditVecDestructorVariant_External { TextAreaEditVecDestructorTag tag; TextAreaEditVecDestructorType payload; };
+    union TextAreaEditVecDestructor {
+        TextAreaEditVecDestructorVariant_DefaultRust DefaultRust;
+        TextAreaEditVecDestructorVariant_NoDestructor NoDestructor;
+        TextAreaEditVecDestructorVariant_External External;
+    };
+    
+    
     enum class StyleFilterVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2481,6 +3081,22 @@ namespace dll {
     };
     
     
+    enum class AccessibilityActionVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct AccessibilityActionVecDestructorVariant_DefaultRust { AccessibilityActionVecDestructorTag tag; };
+    struct AccessibilityActionVecDestructorVariant_NoDestructor { AccessibilityActionVecDestructorTag tag; };
+    struct AccessibilityActionVecDestructorVariant_External { AccessibilityActionVecDestructorTag tag; AccessibilityActionVecDestructorType payload; };
+    union AccessibilityActionVecDestructor {
+        AccessibilityActionVecDestructorVariant_DefaultRust DefaultRust;
+        AccessibilityActionVecDestructorVariant_NoDestructor NoDestructor;
+        AccessibilityActionVecDestructorVariant_External External;
+    };
+    
+    
     enum class MenuItemVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -2769,30 +3385,174 @@ namespace dll {
     };
     
     
-    enum class CssPropertyVecDestructorTag {
+    enum class StyleTransitionPropertyVecDestructorTag {
        DefaultRust,
        NoDestructor,
        External,
     };
     
-    struct CssPropertyVecDestructorVariant_DefaultRust { CssPropertyVecDestructorTag tag; };
-    struct CssPropertyVecDestructorVariant_NoDestructor { CssPropertyVecDestructorTag tag; };
-    struct CssPropertyVecDestructorVariant_External { CssPropertyVecDestructorTag tag; CssPropertyVecDestructorType payload; };
-    union CssPropertyVecDestructor {
-        CssPropertyVecDestructorVariant_DefaultRust DefaultRust;
-        CssPropertyVecDestructorVariant_NoDestructor NoDestructor;
-        CssPropertyVecDestructorVariant_External External;
+    struct StyleTransitionPropertyVecDestructorVariant_DefaultRust { StyleTransitionPropertyVecDestructorTag tag; };
+    struct StyleTransitionPropertyVecDestructorVariant_NoDestructor { StyleTransitionPropertyVecDestructorTag tag; };
+    struct StyleTransitionPropertyVecDestructorVariant_External { StyleTransitionPropertyVecDestructorTag tag; StyleTransitionPropertyVecDestructorType payload; };
+    union StyleTransitionPropertyVecDestructor {
+        StyleTransitionPropertyVecDestructorVariant_DefaultRust DefaultRust;
+        StyleTransitionPropertyVecDestructorVariant_NoDestructor NoDestructor;
+        StyleTransitionPropertyVecDestructorVariant_External External;
     };
     
     
-    enum class SvgMultiPolygonVecDestructorTag {
+    enum class StyleTimeVecDestructorTag {
        DefaultRust,
        NoDestructor,
        External,
     };
     
-    struct SvgMultiPolygonVecDestructorVariant_DefaultRust { SvgMultiPolygonVecDestructorTag tag; };
-    struct SvgMultiPolygonVecDestructorVariant_NoDestructor { SvgMultiPolygonVecDestructorTag tag; };
+    struct StyleTimeVecDestructorVariant_DefaultRust { StyleTimeVecDestructorTag tag; };
+    struct StyleTimeVecDestructorVariant_NoDestructor { StyleTimeVecDestructorTag tag; };
+    struct StyleTimeVecDestructorVariant_External { StyleTimeVecDestructorTag tag; StyleTimeVecDestructorType payload; };
+    union StyleTimeVecDestructor {
+        StyleTimeVecDestructorVariant_DefaultRust DefaultRust;
+        StyleTimeVecDestructorVariant_NoDestructor NoDestructor;
+        StyleTimeVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleTimingFunctionVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleTimingFunctionVecDestructorVariant_DefaultRust { StyleTimingFunctionVecDestructorTag tag; };
+    struct StyleTimingFunctionVecDestructorVariant_NoDestructor { StyleTimingFunctionVecDestructorTag tag; };
+    struct StyleTimingFunctionVecDestructorVariant_External { StyleTimingFunctionVecDestructorTag tag; StyleTimingFunctionVecDestructorType payload; };
+    union StyleTimingFunctionVecDestructor {
+        StyleTimingFunctionVecDestructorVariant_DefaultRust DefaultRust;
+        StyleTimingFunctionVecDestructorVariant_NoDestructor NoDestructor;
+        StyleTimingFunctionVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationNameVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationNameVecDestructorVariant_DefaultRust { StyleAnimationNameVecDestructorTag tag; };
+    struct StyleAnimationNameVecDestructorVariant_NoDestructor { StyleAnimationNameVecDestructorTag tag; };
+    struct StyleAnimationNameVecDestructorVariant_External { StyleAnimationNameVecDestructorTag tag; StyleAnimationNameVecDestructorType payload; };
+    union StyleAnimationNameVecDestructor {
+        StyleAnimationNameVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationNameVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationNameVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationIterationCountVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationIterationCountVecDestructorVariant_DefaultRust { StyleAnimationIterationCountVecDestructorTag tag; };
+    struct StyleAnimationIterationCountVecDestructorVariant_NoDestructor { StyleAnimationIterationCountVecDestructorTag tag; };
+    struct StyleAnimationIterationCountVecDestructorVariant_External { StyleAnimationIterationCountVecDestructorTag tag; StyleAnimationIterationCountVecDestructorType payload; };
+    union StyleAnimationIterationCountVecDestructor {
+        StyleAnimationIterationCountVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationIterationCountVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationIterationCountVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationDirectionVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationDirectionVecDestructorVariant_DefaultRust { StyleAnimationDirectionVecDestructorTag tag; };
+    struct StyleAnimationDirectionVecDestructorVariant_NoDestructor { StyleAnimationDirectionVecDestructorTag tag; };
+    struct StyleAnimationDirectionVecDestructorVariant_External { StyleAnimationDirectionVecDestructorTag tag; StyleAnimationDirectionVecDestructorType payload; };
+    union StyleAnimationDirectionVecDestructor {
+        StyleAnimationDirectionVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationDirectionVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationDirectionVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationFillModeVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationFillModeVecDestructorVariant_DefaultRust { StyleAnimationFillModeVecDestructorTag tag; };
+    struct StyleAnimationFillModeVecDestructorVariant_NoDestructor { StyleAnimationFillModeVecDestructorTag tag; };
+    struct StyleAnimationFillModeVecDestructorVariant_External { StyleAnimationFillModeVecDestructorTag tag; StyleAnimationFillModeVecDestructorType payload; };
+    union StyleAnimationFillModeVecDestructor {
+        StyleAnimationFillModeVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationFillModeVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationFillModeVecDestructorVariant_External External;
+    };
+    
+    
+    enum class StyleAnimationPlayStateVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct StyleAnimationPlayStateVecDestructorVariant_DefaultRust { StyleAnimationPlayStateVecDestructorTag tag; };
+    struct StyleAnimationPlayStateVecDestructorVariant_NoDestructor { StyleAnimationPlayStateVecDestructorTag tag; };
+    struct StyleAnimationPlayStateVecDestructorVariant_External { StyleAnimationPlayStateVecDestructorTag tag; StyleAnimationPlayStateVecDestructorType payload; };
+    union StyleAnimationPlayStateVecDestructor {
+        StyleAnimationPlayStateVecDestructorVariant_DefaultRust DefaultRust;
+        StyleAnimationPlayStateVecDestructorVariant_NoDestructor NoDestructor;
+        StyleAnimationPlayStateVecDestructorVariant_External External;
+    };
+    
+    
+    enum class GridTrackSizingVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct GridTrackSizingVecDestructorVariant_DefaultRust { GridTrackSizingVecDestructorTag tag; };
+    struct GridTrackSizingVecDestructorVariant_NoDestructor { GridTrackSizingVecDestructorTag tag; };
+    struct GridTrackSizingVecDestructorVariant_External { GridTrackSizingVecDestructorTag tag; GridTrackSizingVecDestructorType payload; };
+    union GridTrackSizingVecDestructor {
+        GridTrackSizingVecDestructorVariant_DefaultRust DefaultRust;
+        GridTrackSizingVecDestructorVariant_NoDestructor NoDestructor;
+        GridTrackSizingVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssPropertyVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssPropertyVecDestructorVariant_DefaultRust { CssPropertyVecDestructorTag tag; };
+    struct CssPropertyVecDestructorVariant_NoDestructor { CssPropertyVecDestructorTag tag; };
+    struct CssPropertyVecDestructorVariant_External { CssPropertyVecDestructorTag tag; CssPropertyVecDestructorType payload; };
+    union CssPropertyVecDestructor {
+        CssPropertyVecDestructorVariant_DefaultRust DefaultRust;
+        CssPropertyVecDestructorVariant_NoDestructor NoDestructor;
+        CssPropertyVecDestructorVariant_External External;
+    };
+    
+    
+    enum class SvgMultiPolygonVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct SvgMultiPolygonVecDestructorVariant_DefaultRust { SvgMultiPolygonVecDestructorTag tag; };
+    struct SvgMultiPolygonVecDestructorVariant_NoDestructor { SvgMultiPolygonVecDestructorTag tag; };
     struct SvgMultiPolygonVecDestructorVariant_External { SvgMultiPolygonVecDestructorTag tag; SvgMultiPolygonVecDestructorType payload; };
     union SvgMultiPolygonVecDestructor {
         SvgMultiPolygonVecDestructorVariant_DefaultRust DefaultRust;
@@ -2993,6 +3753,86 @@ namespace dll {
     };
     
     
+    enum class CssKeyframesVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssKeyframesVecDestructorVariant_DefaultRust { CssKeyframesVecDestructorTag tag; };
+    struct CssKeyframesVecDestructorVariant_NoDestructor { CssKeyframesVecDestructorTag tag; };
+    struct CssKeyframesVecDestructorVariant_External { CssKeyframesVecDestructorTag tag; CssKeyframesVecDestructorType payload; };
+    union CssKeyframesVecDestructor {
+        CssKeyframesVecDestructorVariant_DefaultRust DefaultRust;
+        CssKeyframesVecDestructorVariant_NoDestructor NoDestructor;
+        CssKeyframesVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssKeyframeVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssKeyframeVecDestructorVariant_DefaultRust { CssKeyframeVecDestructorTag tag; };
+    struct CssKeyframeVecDestructorVariant_NoDestructor { CssKeyframeVecDestructorTag tag; };
+    struct CssKeyframeVecDestructorVariant_External { CssKeyframeVecDestructorTag tag; CssKeyframeVecDestructorType payload; };
+    union CssKeyframeVecDestructor {
+        CssKeyframeVecDestructorVariant_DefaultRust DefaultRust;
+        CssKeyframeVecDestructorVariant_NoDestructor NoDestructor;
+        CssKeyframeVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssMediaRuleVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssMediaRuleVecDestructorVariant_DefaultRust { CssMediaRuleVecDestructorTag tag; };
+    struct CssMediaRuleVecDestructorVariant_NoDestructor { CssMediaRuleVecDestructorTag tag; };
+    struct CssMediaRuleVecDestructorVariant_External { CssMediaRuleVecDestructorTag tag; CssMediaRuleVecDestructorType payload; };
+    union CssMediaRuleVecDestructor {
+        CssMediaRuleVecDestructorVariant_DefaultRust DefaultRust;
+        CssMediaRuleVecDestructorVariant_NoDestructor NoDestructor;
+        CssMediaRuleVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssMediaQueryVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssMediaQueryVecDestructorVariant_DefaultRust { CssMediaQueryVecDestructorTag tag; };
+    struct CssMediaQueryVecDestructorVariant_NoDestructor { CssMediaQueryVecDestructorTag tag; };
+    struct CssMediaQueryVecDestructorVariant_External { CssMediaQueryVecDestructorTag tag; CssMediaQueryVecDestructorType payload; };
+    union CssMediaQueryVecDestructor {
+        CssMediaQueryVecDestructorVariant_DefaultRust DefaultRust;
+        CssMediaQueryVecDestructorVariant_NoDestructor NoDestructor;
+        CssMediaQueryVecDestructorVariant_External External;
+    };
+    
+    
+    enum class CssMediaFeatureVecDestructorTag {
+       DefaultRust,
+       NoDestructor,
+       External,
+    };
+    
+    struct CssMediaFeatureVecDestructorVariant_DefaultRust { CssMediaFeatureVecDestructorTag tag; };
+    struct CssMediaFeatureVecDestructorVariant_NoDestructor { CssMediaFeatureVecDestructorTag tag; };
+    struct CssMediaFeatureVecDestructorVariant_External { CssMediaFeatureVecDestructorTag tag; CssMediaFeatureVecDestructorType payload; };
+    union CssMediaFeatureVecDestructor {
+        CssMediaFeatureVecDestructorVariant_DefaultRust DefaultRust;
+        CssMediaFeatureVecDestructorVariant_NoDestructor NoDestructor;
+        CssMediaFeatureVecDestructorVariant_External External;
+    };
+    
+    
     enum class CssRuleBlockVecDestructorTag {
        DefaultRust,
        NoDestructor,
@@ -3408,6 +4248,8 @@ namespace dll {
     struct SystemCallbacks {
         CreateThreadFn create_thread_fn;
         GetSystemTimeFn get_system_time_fn;
+        GetClipboardFn get_clipboard_fn;
+        SetClipboardFn set_clipboard_fn;
         SystemCallbacks& operator=(const SystemCallbacks&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         SystemCallbacks(const SystemCallbacks&) = delete; /* disable copy constructor, use explicit .clone() */
         SystemCallbacks() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -3591,6 +4433,7 @@ namespace dll {
         LogicalRect bounds;
         OptionChar unicode_codepoint;
         uint32_t glyph_index;
+        size_t font_index;
         InlineGlyph& operator=(const InlineGlyph&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         InlineGlyph(const InlineGlyph&) = delete; /* disable copy constructor, use explicit .clone() */
         InlineGlyph() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -3613,6 +4456,8 @@ namespace dll {
         size_t char_index_relative_to_line;
         size_t glyph_index_relative_to_word;
         size_t char_index_relative_to_word;
+        bool  is_rtl;
+        size_t cursor_index_relative_to_text;
         InlineTextHit& operator=(const InlineTextHit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         InlineTextHit(const InlineTextHit&) = delete; /* disable copy constructor, use explicit .clone() */
         InlineTextHit() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -3673,6 +4518,14 @@ namespace dll {
     };
     
     
+    struct TooltipDom {
+        RefAny data;
+        TooltipCallback callback;
+        TooltipDom& operator=(const TooltipDom&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TooltipDom(const TooltipDom&) = delete; /* disable copy constructor, use explicit .clone() */
+        TooltipDom() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct MenuCallback {
         Callback callback;
         RefAny data;
@@ -3713,6 +4566,15 @@ namespace dll {
     };
     
     
+    struct CssMediaRange {
+        OptionF32 min;
+        bool  min_inclusive;
+        OptionF32 max;
+        bool  max_inclusive;
+        CssMediaRange& operator=(const CssMediaRange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaRange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct PixelValue {
         SizeMetric metric;
         FloatValue number;
@@ -3784,6 +4646,54 @@ namespace dll {
     };
     
     
+    enum class GridTrackBreadthTag {
+       Fixed,
+       Fraction,
+       Auto,
+       MinContent,
+       MaxContent,
+    };
+    
+    struct GridTrackBreadthVariant_Fixed { GridTrackBreadthTag tag; PixelValue payload; };
+    struct GridTrackBreadthVariant_Fraction { GridTrackBreadthTag tag; FloatValue payload; };
+    struct GridTrackBreadthVariant_Auto { GridTrackBreadthTag tag; };
+    struct GridTrackBreadthVariant_MinContent { GridTrackBreadthTag tag; };
+    struct GridTrackBreadthVariant_MaxContent { GridTrackBreadthTag tag; };
+    union GridTrackBreadth {
+        GridTrackBreadthVariant_Fixed Fixed;
+        GridTrackBreadthVariant_Fraction Fraction;
+        GridTrackBreadthVariant_Auto Auto;
+        GridTrackBreadthVariant_MinContent MinContent;
+        GridTrackBreadthVariant_MaxContent MaxContent;
+    };
+    
+    
+    struct GridTrackSizing {
+        GridTrackBreadth min;
+        GridTrackBreadth max;
+        GridTrackSizing& operator=(const GridTrackSizing&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GridTrackSizing() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct GridPlacement {
+        GridLine start;
+        GridLine end;
+        GridPlacement& operator=(const GridPlacement&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GridPlacement() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutRowGap {
+        PixelValue inner;
+        LayoutRowGap& operator=(const LayoutRowGap&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutRowGap() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct LayoutColumnGap {
+        PixelValue inner;
+        LayoutColumnGap& operator=(const LayoutColumnGap&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        LayoutColumnGap() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LayoutBottom {
         PixelValue inner;
         LayoutBottom& operator=(const LayoutBottom&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -4230,6 +5140,79 @@ namespace dll {
         StyleTransformSkew2D() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StyleCubicBezier {
+        FloatValue x1;
+        FloatValue y1;
+        FloatValue x2;
+        FloatValue y2;
+        StyleCubicBezier& operator=(const StyleCubicBezier&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleCubicBezier() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleSteps {
+        uint32_t count;
+        StyleStepPosition position;
+        StyleSteps& operator=(const StyleSteps&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleSteps() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    enum class StyleTimingFunctionTag {
+       Ease,
+       Linear,
+       EaseIn,
+       EaseOut,
+       EaseInOut,
+       CubicBezier,
+       Steps,
+    };
+    
+    struct StyleTimingFunctionVariant_Ease { StyleTimingFunctionTag tag; };
+    struct StyleTimingFunctionVariant_Linear { StyleTimingFunctionTag tag; };
+    struct StyleTimingFunctionVariant_EaseIn { StyleTimingFunctionTag tag; };
+    struct StyleTimingFunctionVariant_EaseOut { StyleTimingFunctionTag tag; };
+    struct StyleTimingFunctionVariant_EaseInOut { StyleTimingFunctionTag tag; };
+    struct StyleTimingFunctionVariant_CubicBezier { StyleTimingFunctionTag tag; StyleCubicBezier payload; };
+    struct StyleTimingFunctionVariant_Steps { StyleTimingFunctionTag tag; StyleSteps payload; };
+    union StyleTimingFunction {
+        StyleTimingFunctionVariant_Ease Ease;
+        StyleTimingFunctionVariant_Linear Linear;
+        StyleTimingFunctionVariant_EaseIn EaseIn;
+        StyleTimingFunctionVariant_EaseOut EaseOut;
+        StyleTimingFunctionVariant_EaseInOut EaseInOut;
+        StyleTimingFunctionVariant_CubicBezier CubicBezier;
+        StyleTimingFunctionVariant_Steps Steps;
+    };
+    
+    
+    enum class StyleTransitionPropertyTag {
+       All,
+       Property,
+       Shorthand,
+    };
+    
+    struct StyleTransitionPropertyVariant_All { StyleTransitionPropertyTag tag; };
+    struct StyleTransitionPropertyVariant_Property { StyleTransitionPropertyTag tag; CssPropertyType payload; };
+    struct StyleTransitionPropertyVariant_Shorthand { StyleTransitionPropertyTag tag; CombinedCssPropertyType payload; };
+    union StyleTransitionProperty {
+        StyleTransitionPropertyVariant_All All;
+        StyleTransitionPropertyVariant_Property Property;
+        StyleTransitionPropertyVariant_Shorthand Shorthand;
+    };
+    
+    
+    enum class StyleAnimationIterationCountTag {
+       Infinite,
+       Count,
+    };
+    
+    struct StyleAnimationIterationCountVariant_Infinite { StyleAnimationIterationCountTag tag; };
+    struct StyleAnimationIterationCountVariant_Count { StyleAnimationIterationCountTag tag; FloatValue payload; };
+    union StyleAnimationIterationCount {
+        StyleAnimationIterationCountVariant_Infinite Infinite;
+        StyleAnimationIterationCountVariant_Count Count;
+    };
+    
+    
     struct StyleTextColor {
         ColorU inner;
         StyleTextColor& operator=(const StyleTextColor&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -4286,6 +5269,116 @@ namespace dll {
     };
     
     
+    enum class GridTrackSizingValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct GridTrackSizingValueVariant_Auto { GridTrackSizingValueTag tag; };
+    struct GridTrackSizingValueVariant_None { GridTrackSizingValueTag tag; };
+    struct GridTrackSizingValueVariant_Inherit { GridTrackSizingValueTag tag; };
+    struct GridTrackSizingValueVariant_Initial { GridTrackSizingValueTag tag; };
+    struct GridTrackSizingValueVariant_Exact { GridTrackSizingValueTag tag; GridTrackSizing payload; };
+    union GridTrackSizingValue {
+        GridTrackSizingValueVariant_Auto Auto;
+        GridTrackSizingValueVariant_None None;
+        GridTrackSizingValueVariant_Inherit Inherit;
+        GridTrackSizingValueVariant_Initial Initial;
+        GridTrackSizingValueVariant_Exact Exact;
+    };
+    
+    
+    enum class LayoutGridAutoFlowValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutGridAutoFlowValueVariant_Auto { LayoutGridAutoFlowValueTag tag; };
+    struct LayoutGridAutoFlowValueVariant_None { LayoutGridAutoFlowValueTag tag; };
+    struct LayoutGridAutoFlowValueVariant_Inherit { LayoutGridAutoFlowValueTag tag; };
+    struct LayoutGridAutoFlowValueVariant_Initial { LayoutGridAutoFlowValueTag tag; };
+    struct LayoutGridAutoFlowValueVariant_Exact { LayoutGridAutoFlowValueTag tag; LayoutGridAutoFlow payload; };
+    union LayoutGridAutoFlowValue {
+        LayoutGridAutoFlowValueVariant_Auto Auto;
+        LayoutGridAutoFlowValueVariant_None None;
+        LayoutGridAutoFlowValueVariant_Inherit Inherit;
+        LayoutGridAutoFlowValueVariant_Initial Initial;
+        LayoutGridAutoFlowValueVariant_Exact Exact;
+    };
+    
+    
+    enum class GridPlacementValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct GridPlacementValueVariant_Auto { GridPlacementValueTag tag; };
+    struct GridPlacementValueVariant_None { GridPlacementValueTag tag; };
+    struct GridPlacementValueVariant_Inherit { GridPlacementValueTag tag; };
+    struct GridPlacementValueVariant_Initial { GridPlacementValueTag tag; };
+    struct GridPlacementValueVariant_Exact { GridPlacementValueTag tag; GridPlacement payload; };
+    union GridPlacementValue {
+        GridPlacementValueVariant_Auto Auto;
+        GridPlacementValueVariant_None None;
+        GridPlacementValueVariant_Inherit Inherit;
+        GridPlacementValueVariant_Initial Initial;
+        GridPlacementValueVariant_Exact Exact;
+    };
+    
+    
+    enum class LayoutRowGapValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutRowGapValueVariant_Auto { LayoutRowGapValueTag tag; };
+    struct LayoutRowGapValueVariant_None { LayoutRowGapValueTag tag; };
+    struct LayoutRowGapValueVariant_Inherit { LayoutRowGapValueTag tag; };
+    struct LayoutRowGapValueVariant_Initial { LayoutRowGapValueTag tag; };
+    struct LayoutRowGapValueVariant_Exact { LayoutRowGapValueTag tag; LayoutRowGap payload; };
+    union LayoutRowGapValue {
+        LayoutRowGapValueVariant_Auto Auto;
+        LayoutRowGapValueVariant_None None;
+        LayoutRowGapValueVariant_Inherit Inherit;
+        LayoutRowGapValueVariant_Initial Initial;
+        LayoutRowGapValueVariant_Exact Exact;
+    };
+    
+    
+    enum class LayoutColumnGapValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct LayoutColumnGapValueVariant_Auto { LayoutColumnGapValueTag tag; };
+    struct LayoutColumnGapValueVariant_None { LayoutColumnGapValueTag tag; };
+    struct LayoutColumnGapValueVariant_Inherit { LayoutColumnGapValueTag tag; };
+    struct LayoutColumnGapValueVariant_Initial { LayoutColumnGapValueTag tag; };
+    struct LayoutColumnGapValueVariant_Exact { LayoutColumnGapValueTag tag; LayoutColumnGap payload; };
+    union LayoutColumnGapValue {
+        LayoutColumnGapValueVariant_Auto Auto;
+        LayoutColumnGapValueVariant_None None;
+        LayoutColumnGapValueVariant_Inherit Inherit;
+        LayoutColumnGapValueVariant_Initial Initial;
+        LayoutColumnGapValueVariant_Exact Exact;
+    };
+    
+    
     enum class LayoutAlignItemsValueTag {
        Auto,
        None,
@@ -5408,7 +6501,7 @@ namespace dll {
     };
     
     
-    enum class StyleTextColorValueTag {
+    enum class StyleDirectionValueTag {
        Auto,
        None,
        Inherit,
@@ -5416,21 +6509,21 @@ namespace dll {
        Exact,
     };
     
-    struct StyleTextColorValueVariant_Auto { StyleTextColorValueTag tag; };
-    struct StyleTextColorValueVariant_None { StyleTextColorValueTag tag; };
-    struct StyleTextColorValueVariant_Inherit { StyleTextColorValueTag tag; };
-    struct StyleTextColorValueVariant_Initial { StyleTextColorValueTag tag; };
-    struct StyleTextColorValueVariant_Exact { StyleTextColorValueTag tag; StyleTextColor payload; };
-    union StyleTextColorValue {
-        StyleTextColorValueVariant_Auto Auto;
-        StyleTextColorValueVariant_None None;
-        StyleTextColorValueVariant_Inherit Inherit;
-        StyleTextColorValueVariant_Initial Initial;
-        StyleTextColorValueVariant_Exact Exact;
+    struct StyleDirectionValueVariant_Auto { StyleDirectionValueTag tag; };
+    struct StyleDirectionValueVariant_None { StyleDirectionValueTag tag; };
+    struct StyleDirectionValueVariant_Inherit { StyleDirectionValueTag tag; };
+    struct StyleDirectionValueVariant_Initial { StyleDirectionValueTag tag; };
+    struct StyleDirectionValueVariant_Exact { StyleDirectionValueTag tag; StyleDirection payload; };
+    union StyleDirectionValue {
+        StyleDirectionValueVariant_Auto Auto;
+        StyleDirectionValueVariant_None None;
+        StyleDirectionValueVariant_Inherit Inherit;
+        StyleDirectionValueVariant_Initial Initial;
+        StyleDirectionValueVariant_Exact Exact;
     };
     
     
-    enum class StyleWordSpacingValueTag {
+    enum class StyleWordBreakValueTag {
        Auto,
        None,
        Inherit,
@@ -5438,21 +6531,21 @@ namespace dll {
        Exact,
     };
     
-    struct StyleWordSpacingValueVariant_Auto { StyleWordSpacingValueTag tag; };
-    struct StyleWordSpacingValueVariant_None { StyleWordSpacingValueTag tag; };
-    struct StyleWordSpacingValueVariant_Inherit { StyleWordSpacingValueTag tag; };
-    struct StyleWordSpacingValueVariant_Initial { StyleWordSpacingValueTag tag; };
-    struct StyleWordSpacingValueVariant_Exact { StyleWordSpacingValueTag tag; StyleWordSpacing payload; };
-    union StyleWordSpacingValue {
-        StyleWordSpacingValueVariant_Auto Auto;
-        StyleWordSpacingValueVariant_None None;
-        StyleWordSpacingValueVariant_Inherit Inherit;
-        StyleWordSpacingValueVariant_Initial Initial;
-        StyleWordSpacingValueVariant_Exact Exact;
+    struct StyleWordBreakValueVariant_Auto { StyleWordBreakValueTag tag; };
+    struct StyleWordBreakValueVariant_None { StyleWordBreakValueTag tag; };
+    struct StyleWordBreakValueVariant_Inherit { StyleWordBreakValueTag tag; };
+    struct StyleWordBreakValueVariant_Initial { StyleWordBreakValueTag tag; };
+    struct StyleWordBreakValueVariant_Exact { StyleWordBreakValueTag tag; StyleWordBreak payload; };
+    union StyleWordBreakValue {
+        StyleWordBreakValueVariant_Auto Auto;
+        StyleWordBreakValueVariant_None None;
+        StyleWordBreakValueVariant_Inherit Inherit;
+        StyleWordBreakValueVariant_Initial Initial;
+        StyleWordBreakValueVariant_Exact Exact;
     };
     
     
-    enum class StyleOpacityValueTag {
+    enum class StyleOverflowWrapValueTag {
        Auto,
        None,
        Inherit,
@@ -5460,15 +6553,103 @@ namespace dll {
        Exact,
     };
     
-    struct StyleOpacityValueVariant_Auto { StyleOpacityValueTag tag; };
-    struct StyleOpacityValueVariant_None { StyleOpacityValueTag tag; };
-    struct StyleOpacityValueVariant_Inherit { StyleOpacityValueTag tag; };
-    struct StyleOpacityValueVariant_Initial { StyleOpacityValueTag tag; };
-    struct StyleOpacityValueVariant_Exact { StyleOpacityValueTag tag; StyleOpacity payload; };
-    union StyleOpacityValue {
-        StyleOpacityValueVariant_Auto Auto;
-        StyleOpacityValueVariant_None None;
-        StyleOpacityValueVariant_Inherit Inherit;
+    struct StyleOverflowWrapValueVariant_Auto { StyleOverflowWrapValueTag tag; };
+    struct StyleOverflowWrapValueVariant_None { StyleOverflowWrapValueTag tag; };
+    struct StyleOverflowWrapValueVariant_Inherit { StyleOverflowWrapValueTag tag; };
+    struct StyleOverflowWrapValueVariant_Initial { StyleOverflowWrapValueTag tag; };
+    struct StyleOverflowWrapValueVariant_Exact { StyleOverflowWrapValueTag tag; StyleOverflowWrap payload; };
+    union StyleOverflowWrapValue {
+        StyleOverflowWrapValueVariant_Auto Auto;
+        StyleOverflowWrapValueVariant_None None;
+        StyleOverflowWrapValueVariant_Inherit Inherit;
+        StyleOverflowWrapValueVariant_Initial Initial;
+        StyleOverflowWrapValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleHyphensValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleHyphensValueVariant_Auto { StyleHyphensValueTag tag; };
+    struct StyleHyphensValueVariant_None { StyleHyphensValueTag tag; };
+    struct StyleHyphensValueVariant_Inherit { StyleHyphensValueTag tag; };
+    struct StyleHyphensValueVariant_Initial { StyleHyphensValueTag tag; };
+    struct StyleHyphensValueVariant_Exact { StyleHyphensValueTag tag; StyleHyphens payload; };
+    union StyleHyphensValue {
+        StyleHyphensValueVariant_Auto Auto;
+        StyleHyphensValueVariant_None None;
+        StyleHyphensValueVariant_Inherit Inherit;
+        StyleHyphensValueVariant_Initial Initial;
+        StyleHyphensValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTextColorValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTextColorValueVariant_Auto { StyleTextColorValueTag tag; };
+    struct StyleTextColorValueVariant_None { StyleTextColorValueTag tag; };
+    struct StyleTextColorValueVariant_Inherit { StyleTextColorValueTag tag; };
+    struct StyleTextColorValueVariant_Initial { StyleTextColorValueTag tag; };
+    struct StyleTextColorValueVariant_Exact { StyleTextColorValueTag tag; StyleTextColor payload; };
+    union StyleTextColorValue {
+        StyleTextColorValueVariant_Auto Auto;
+        StyleTextColorValueVariant_None None;
+        StyleTextColorValueVariant_Inherit Inherit;
+        StyleTextColorValueVariant_Initial Initial;
+        StyleTextColorValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleWordSpacingValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleWordSpacingValueVariant_Auto { StyleWordSpacingValueTag tag; };
+    struct StyleWordSpacingValueVariant_None { StyleWordSpacingValueTag tag; };
+    struct StyleWordSpacingValueVariant_Inherit { StyleWordSpacingValueTag tag; };
+    struct StyleWordSpacingValueVariant_Initial { StyleWordSpacingValueTag tag; };
+    struct StyleWordSpacingValueVariant_Exact { StyleWordSpacingValueTag tag; StyleWordSpacing payload; };
+    union StyleWordSpacingValue {
+        StyleWordSpacingValueVariant_Auto Auto;
+        StyleWordSpacingValueVariant_None None;
+        StyleWordSpacingValueVariant_Inherit Inherit;
+        StyleWordSpacingValueVariant_Initial Initial;
+        StyleWordSpacingValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleOpacityValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleOpacityValueVariant_Auto { StyleOpacityValueTag tag; };
+    struct StyleOpacityValueVariant_None { StyleOpacityValueTag tag; };
+    struct StyleOpacityValueVariant_Inherit { StyleOpacityValueTag tag; };
+    struct StyleOpacityValueVariant_Initial { StyleOpacityValueTag tag; };
+    struct StyleOpacityValueVariant_Exact { StyleOpacityValueTag tag; StyleOpacity payload; };
+    union StyleOpacityValue {
+        StyleOpacityValueVariant_Auto Auto;
+        StyleOpacityValueVariant_None None;
+        StyleOpacityValueVariant_Inherit Inherit;
         StyleOpacityValueVariant_Initial Initial;
         StyleOpacityValueVariant_Exact Exact;
     };
@@ -5562,6 +6743,29 @@ namespace dll {
     };
     
     
+    struct RibbonItemState {
+        bool  toggled;
+        OptionUsize choice;
+        RibbonItemState& operator=(const RibbonItemState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonItemState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct RibbonOnItemClick {
+        RefAny data;
+        RibbonOnItemClickCallback callback;
+        RibbonOnItemClick& operator=(const RibbonOnItemClick&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonOnItemClick(const RibbonOnItemClick&) = delete; /* disable copy constructor, use explicit .clone() */
+        RibbonOnItemClick() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct RibbonOnTabClicked {
+        RefAny data;
+        RibbonOnTabClickedCallback callback;
+        RibbonOnTabClicked& operator=(const RibbonOnTabClicked&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonOnTabClicked(const RibbonOnTabClicked&) = delete; /* disable copy constructor, use explicit .clone() */
+        RibbonOnTabClicked() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ButtonOnClick {
         RefAny data;
         Callback callback;
@@ -5646,6 +6850,14 @@ namespace dll {
         OnTextInputReturn() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextAreaOnTextChange {
+        RefAny data;
+        TextAreaOnTextChangeCallback callback;
+        TextAreaOnTextChange& operator=(const TextAreaOnTextChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextAreaOnTextChange(const TextAreaOnTextChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextAreaOnTextChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NumberInputOnValueChange {
         RefAny data;
         NumberInputOnValueChangeCallback callback;
@@ -5662,6 +6874,25 @@ namespace dll {
         NumberInputOnFocusLost() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ProgressBarState {
+        float percent_done;
+        bool  display_percentage;
+        ProgressBarMode mode;
+        ProgressBarState& operator=(const ProgressBarState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ProgressBarState(const ProgressBarState&) = delete; /* disable copy constructor, use explicit .clone() */
+        ProgressBarState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct Spinner {
+        PixelValue size;
+        PixelValue thickness;
+        ColorU color;
+        ColorU track_color;
+        Spinner& operator=(const Spinner&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Spinner(const Spinner&) = delete; /* disable copy constructor, use explicit .clone() */
+        Spinner() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct TabOnClick {
         RefAny data;
         TabOnClickCallback callback;
@@ -5670,6 +6901,54 @@ namespace dll {
         TabOnClick() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TabOnClose {
+        RefAny data;
+        TabOnCloseCallback callback;
+        TabOnClose& operator=(const TabOnClose&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabOnClose(const TabOnClose&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabOnClose() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabOnReorder {
+        RefAny data;
+        TabOnReorderCallback callback;
+        TabOnReorder& operator=(const TabOnReorder&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabOnReorder(const TabOnReorder&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabOnReorder() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TabOnContextMenu {
+        RefAny data;
+        TabOnContextMenuCallback callback;
+        TabOnContextMenu& operator=(const TabOnContextMenu&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TabOnContextMenu(const TabOnContextMenu&) = delete; /* disable copy constructor, use explicit .clone() */
+        TabOnContextMenu() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SplitterOnResize {
+        RefAny data;
+        SplitterOnResizeCallback callback;
+        SplitterOnResize& operator=(const SplitterOnResize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterOnResize(const SplitterOnResize&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterOnResize() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SliderOnValueChange {
+        RefAny data;
+        SliderOnValueChangeCallback callback;
+        SliderOnValueChange& operator=(const SliderOnValueChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SliderOnValueChange(const SliderOnValueChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        SliderOnValueChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct RangeSliderOnValueChange {
+        RefAny data;
+        RangeSliderOnValueChangeCallback callback;
+        RangeSliderOnValueChange& operator=(const RangeSliderOnValueChange&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RangeSliderOnValueChange(const RangeSliderOnValueChange&) = delete; /* disable copy constructor, use explicit .clone() */
+        RangeSliderOnValueChange() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeGraphOnNodeAdded {
         RefAny data;
         NodeGraphOnNodeAddedCallback callback;
@@ -5774,6 +7053,54 @@ namespace dll {
         ListViewOnRowClick() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ListViewOnColumnResize {
+        RefAny data;
+        ListViewOnColumnResizeCallback callback;
+        ListViewOnColumnResize& operator=(const ListViewOnColumnResize&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewOnColumnResize(const ListViewOnColumnResize&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewOnColumnResize() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ListViewRowProvider {
+        RefAny data;
+        ListViewRowProviderCallback callback;
+        ListViewRowProvider& operator=(const ListViewRowProvider&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ListViewRowProvider(const ListViewRowProvider&) = delete; /* disable copy constructor, use explicit .clone() */
+        ListViewRowProvider() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnExpand {
+        RefAny data;
+        TreeViewOnExpandCallback callback;
+        TreeViewOnExpand& operator=(const TreeViewOnExpand&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnExpand(const TreeViewOnExpand&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnExpand() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnSelect {
+        RefAny data;
+        TreeViewOnSelectCallback callback;
+        TreeViewOnSelect& operator=(const TreeViewOnSelect&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnSelect(const TreeViewOnSelect&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnSelect() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnDoubleClick {
+        RefAny data;
+        TreeViewOnDoubleClickCallback callback;
+        TreeViewOnDoubleClick& operator=(const TreeViewOnDoubleClick&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnDoubleClick(const TreeViewOnDoubleClick&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnDoubleClick() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewOnContextMenu {
+        RefAny data;
+        TreeViewOnContextMenuCallback callback;
+        TreeViewOnContextMenu& operator=(const TreeViewOnContextMenu&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewOnContextMenu(const TreeViewOnContextMenu&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewOnContextMenu() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct DropDownOnChoiceChange {
         RefAny data;
         DropDownOnChoiceChangeCallback callback;
@@ -5968,6 +7295,26 @@ namespace dll {
         ThreadWriteBackMsg() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TreeViewNodeIdVec {
+        TreeViewNodeId* ptr;
+        size_t len;
+        size_t cap;
+        TreeViewNodeIdVecDestructor destructor;
+        TreeViewNodeIdVec& operator=(const TreeViewNodeIdVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewNodeIdVec(const TreeViewNodeIdVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewNodeIdVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct SplitterPaneStateVec {
+        SplitterPaneState* ptr;
+        size_t len;
+        size_t cap;
+        SplitterPaneStateVecDestructor destructor;
+        SplitterPaneStateVec& operator=(const SplitterPaneStateVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterPaneStateVec(const SplitterPaneStateVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterPaneStateVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LogicalRectVec {
         LogicalRect* ptr;
         size_t len;
@@ -6018,6 +7365,16 @@ namespace dll {
         AccessibilityStateVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct AccessibilityActionVec {
+        AccessibilityAction* ptr;
+        size_t len;
+        size_t cap;
+        AccessibilityActionVecDestructor destructor;
+        AccessibilityActionVec& operator=(const AccessibilityActionVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        AccessibilityActionVec(const AccessibilityActionVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        AccessibilityActionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct AzMenuItem;
     struct MenuItemVec {
         MenuItem* ptr;
@@ -6111,6 +7468,86 @@ namespace dll {
         StyleBackgroundSizeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StyleTransitionPropertyVec {
+        StyleTransitionProperty* ptr;
+        size_t len;
+        size_t cap;
+        StyleTransitionPropertyVecDestructor destructor;
+        StyleTransitionPropertyVec& operator=(const StyleTransitionPropertyVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTransitionPropertyVec(const StyleTransitionPropertyVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTransitionPropertyVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleTimeVec {
+        StyleTime* ptr;
+        size_t len;
+        size_t cap;
+        StyleTimeVecDestructor destructor;
+        StyleTimeVec& operator=(const StyleTimeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTimeVec(const StyleTimeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTimeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleTimingFunctionVec {
+        StyleTimingFunction* ptr;
+        size_t len;
+        size_t cap;
+        StyleTimingFunctionVecDestructor destructor;
+        StyleTimingFunctionVec& operator=(const StyleTimingFunctionVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleTimingFunctionVec(const StyleTimingFunctionVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleTimingFunctionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationIterationCountVec {
+        StyleAnimationIterationCount* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationIterationCountVecDestructor destructor;
+        StyleAnimationIterationCountVec& operator=(const StyleAnimationIterationCountVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationIterationCountVec(const StyleAnimationIterationCountVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationIterationCountVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationDirectionVec {
+        StyleAnimationDirection* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationDirectionVecDestructor destructor;
+        StyleAnimationDirectionVec& operator=(const StyleAnimationDirectionVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationDirectionVec(const StyleAnimationDirectionVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationDirectionVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationFillModeVec {
+        StyleAnimationFillMode* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationFillModeVecDestructor destructor;
+        StyleAnimationFillModeVec& operator=(const StyleAnimationFillModeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationFillModeVec(const StyleAnimationFillModeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationFillModeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StyleAnimationPlayStateVec {
+        StyleAnimationPlayState* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationPlayStateVecDestructor destructor;
+        StyleAnimationPlayStateVec& operator=(const StyleAnimationPlayStateVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationPlayStateVec(const StyleAnimationPlayStateVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationPlayStateVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct GridTrackSizingVec {
+        GridTrackSizing* ptr;
+        size_t len;
+        size_t cap;
+        GridTrackSizingVecDestructor destructor;
+        GridTrackSizingVec& operator=(const GridTrackSizingVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        GridTrackSizingVec(const GridTrackSizingVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        GridTrackSizingVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct SvgVertexVec {
         SvgVertex* ptr;
         size_t len;
@@ -6284,6 +7721,71 @@ namespace dll {
     };
     
     
+    enum class OptionTreeViewNodeIdTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewNodeIdVariant_None { OptionTreeViewNodeIdTag tag; };
+    struct OptionTreeViewNodeIdVariant_Some { OptionTreeViewNodeIdTag tag; TreeViewNodeId payload; };
+    union OptionTreeViewNodeId {
+        OptionTreeViewNodeIdVariant_None None;
+        OptionTreeViewNodeIdVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnExpandTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnExpandVariant_None { OptionTreeViewOnExpandTag tag; };
+    struct OptionTreeViewOnExpandVariant_Some { OptionTreeViewOnExpandTag tag; TreeViewOnExpand payload; };
+    union OptionTreeViewOnExpand {
+        OptionTreeViewOnExpandVariant_None None;
+        OptionTreeViewOnExpandVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnSelectTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnSelectVariant_None { OptionTreeViewOnSelectTag tag; };
+    struct OptionTreeViewOnSelectVariant_Some { OptionTreeViewOnSelectTag tag; TreeViewOnSelect payload; };
+    union OptionTreeViewOnSelect {
+        OptionTreeViewOnSelectVariant_None None;
+        OptionTreeViewOnSelectVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnDoubleClickTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnDoubleClickVariant_None { OptionTreeViewOnDoubleClickTag tag; };
+    struct OptionTreeViewOnDoubleClickVariant_Some { OptionTreeViewOnDoubleClickTag tag; TreeViewOnDoubleClick payload; };
+    union OptionTreeViewOnDoubleClick {
+        OptionTreeViewOnDoubleClickVariant_None None;
+        OptionTreeViewOnDoubleClickVariant_Some Some;
+    };
+    
+    
+    enum class OptionTreeViewOnContextMenuTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTreeViewOnContextMenuVariant_None { OptionTreeViewOnContextMenuTag tag; };
+    struct OptionTreeViewOnContextMenuVariant_Some { OptionTreeViewOnContextMenuTag tag; TreeViewOnContextMenu payload; };
+    union OptionTreeViewOnContextMenu {
+        OptionTreeViewOnContextMenuVariant_None None;
+        OptionTreeViewOnContextMenuVariant_Some Some;
+    };
+    
+    
     enum class OptionListViewOnColumnClickTag {
        None,
        Some,
@@ -6310,6 +7812,32 @@ namespace dll {
     };
     
     
+    enum class OptionListViewOnColumnResizeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionListViewOnColumnResizeVariant_None { OptionListViewOnColumnResizeTag tag; };
+    struct OptionListViewOnColumnResizeVariant_Some { OptionListViewOnColumnResizeTag tag; ListViewOnColumnResize payload; };
+    union OptionListViewOnColumnResize {
+        OptionListViewOnColumnResizeVariant_None None;
+        OptionListViewOnColumnResizeVariant_Some Some;
+    };
+    
+    
+    enum class OptionListViewRowProviderTag {
+       None,
+       Some,
+    };
+    
+    struct OptionListViewRowProviderVariant_None { OptionListViewRowProviderTag tag; };
+    struct OptionListViewRowProviderVariant_Some { OptionListViewRowProviderTag tag; ListViewRowProvider payload; };
+    union OptionListViewRowProvider {
+        OptionListViewRowProviderVariant_None None;
+        OptionListViewRowProviderVariant_Some Some;
+    };
+    
+    
     enum class OptionPixelValueNoPercentTag {
        None,
        Some,
@@ -6479,6 +8007,110 @@ namespace dll {
     };
     
     
+    enum class OptionTabOnCloseTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTabOnCloseVariant_None { OptionTabOnCloseTag tag; };
+    struct OptionTabOnCloseVariant_Some { OptionTabOnCloseTag tag; TabOnClose payload; };
+    union OptionTabOnClose {
+        OptionTabOnCloseVariant_None None;
+        OptionTabOnCloseVariant_Some Some;
+    };
+    
+    
+    enum class OptionTabOnReorderTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTabOnReorderVariant_None { OptionTabOnReorderTag tag; };
+    struct OptionTabOnReorderVariant_Some { OptionTabOnReorderTag tag; TabOnReorder payload; };
+    union OptionTabOnReorder {
+        OptionTabOnReorderVariant_None None;
+        OptionTabOnReorderVariant_Some Some;
+    };
+    
+    
+    enum class OptionTabOnContextMenuTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTabOnContextMenuVariant_None { OptionTabOnContextMenuTag tag; };
+    struct OptionTabOnContextMenuVariant_Some { OptionTabOnContextMenuTag tag; TabOnContextMenu payload; };
+    union OptionTabOnContextMenu {
+        OptionTabOnContextMenuVariant_None None;
+        OptionTabOnContextMenuVariant_Some Some;
+    };
+    
+    
+    enum class OptionRibbonOnTabClickedTag {
+       None,
+       Some,
+    };
+    
+    struct OptionRibbonOnTabClickedVariant_None { OptionRibbonOnTabClickedTag tag; };
+    struct OptionRibbonOnTabClickedVariant_Some { OptionRibbonOnTabClickedTag tag; RibbonOnTabClicked payload; };
+    union OptionRibbonOnTabClicked {
+        OptionRibbonOnTabClickedVariant_None None;
+        OptionRibbonOnTabClickedVariant_Some Some;
+    };
+    
+    
+    enum class OptionRibbonOnItemClickTag {
+       None,
+       Some,
+    };
+    
+    struct OptionRibbonOnItemClickVariant_None { OptionRibbonOnItemClickTag tag; };
+    struct OptionRibbonOnItemClickVariant_Some { OptionRibbonOnItemClickTag tag; RibbonOnItemClick payload; };
+    union OptionRibbonOnItemClick {
+        OptionRibbonOnItemClickVariant_None None;
+        OptionRibbonOnItemClickVariant_Some Some;
+    };
+    
+    
+    enum class OptionSplitterOnResizeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionSplitterOnResizeVariant_None { OptionSplitterOnResizeTag tag; };
+    struct OptionSplitterOnResizeVariant_Some { OptionSplitterOnResizeTag tag; SplitterOnResize payload; };
+    union OptionSplitterOnResize {
+        OptionSplitterOnResizeVariant_None None;
+        OptionSplitterOnResizeVariant_Some Some;
+    };
+    
+    
+    enum class OptionSliderOnValueChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionSliderOnValueChangeVariant_None { OptionSliderOnValueChangeTag tag; };
+    struct OptionSliderOnValueChangeVariant_Some { OptionSliderOnValueChangeTag tag; SliderOnValueChange payload; };
+    union OptionSliderOnValueChange {
+        OptionSliderOnValueChangeVariant_None None;
+        OptionSliderOnValueChangeVariant_Some Some;
+    };
+    
+    
+    enum class OptionRangeSliderOnValueChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionRangeSliderOnValueChangeVariant_None { OptionRangeSliderOnValueChangeTag tag; };
+    struct OptionRangeSliderOnValueChangeVariant_Some { OptionRangeSliderOnValueChangeTag tag; RangeSliderOnValueChange payload; };
+    union OptionRangeSliderOnValueChange {
+        OptionRangeSliderOnValueChangeVariant_None None;
+        OptionRangeSliderOnValueChangeVariant_Some Some;
+    };
+    
+    
     enum class OptionFileInputOnPathChangeTag {
        None,
        Some,
@@ -6557,6 +8189,19 @@ namespace dll {
     };
     
     
+    enum class OptionTextAreaOnTextChangeTag {
+       None,
+       Some,
+    };
+    
+    struct OptionTextAreaOnTextChangeVariant_None { OptionTextAreaOnTextChangeTag tag; };
+    struct OptionTextAreaOnTextChangeVariant_Some { OptionTextAreaOnTextChangeTag tag; TextAreaOnTextChange payload; };
+    union OptionTextAreaOnTextChange {
+        OptionTextAreaOnTextChangeVariant_None None;
+        OptionTextAreaOnTextChangeVariant_Some Some;
+    };
+    
+    
     enum class OptionNumberInputOnFocusLostTag {
        None,
        Some,
@@ -6895,6 +8540,19 @@ namespace dll {
     };
     
     
+    enum class OptionLogicalRectTag {
+       None,
+       Some,
+    };
+    
+    struct OptionLogicalRectVariant_None { OptionLogicalRectTag tag; };
+    struct OptionLogicalRectVariant_Some { OptionLogicalRectTag tag; LogicalRect payload; };
+    union OptionLogicalRect {
+        OptionLogicalRectVariant_None None;
+        OptionLogicalRectVariant_Some Some;
+    };
+    
+    
     enum class OptionPhysicalPositionI32Tag {
        None,
        Some,
@@ -7136,16 +8794,6 @@ namespace dll {
         WindowSize() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct KeyboardState {
-        OptionChar current_char;
-        OptionVirtualKeyCode current_virtual_keycode;
-        VirtualKeyCodeVec pressed_virtual_keycodes;
-        ScanCodeVec pressed_scancodes;
-        KeyboardState& operator=(const KeyboardState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        KeyboardState(const KeyboardState&) = delete; /* disable copy constructor, use explicit .clone() */
-        KeyboardState() = delete; /* disable default constructor, use C++20 designated initializer instead */
-    };
-    
     struct MouseState {
         OptionMouseCursorType mouse_cursor_type;
         CursorPosition cursor_position;
@@ -7171,6 +8819,7 @@ namespace dll {
     struct InlineTextContents {
         InlineGlyphVec glyphs;
         LogicalRect bounds;
+        bool  is_rtl;
         InlineTextContents& operator=(const InlineTextContents&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         InlineTextContents(const InlineTextContents&) = delete; /* disable copy constructor, use explicit .clone() */
         InlineTextContents() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -7185,6 +8834,10 @@ namespace dll {
         OptionF32 max_horizontal_width;
         OptionF32 leading;
         LogicalRectVec holes;
+        StyleDirection direction;
+        StyleWordBreak word_break;
+        StyleOverflowWrap overflow_wrap;
+        StyleHyphens hyphens;
         ResolvedTextLayoutOptions& operator=(const ResolvedTextLayoutOptions&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         ResolvedTextLayoutOptions(const ResolvedTextLayoutOptions&) = delete; /* disable copy constructor, use explicit .clone() */
         ResolvedTextLayoutOptions() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -7312,6 +8965,28 @@ namespace dll {
     };
     
     
+    enum class CssMediaFeatureTag {
+       Width,
+       Height,
+       Resolution,
+       Orientation,
+       PrefersColorScheme,
+    };
+    
+    struct CssMediaFeatureVariant_Width { CssMediaFeatureTag tag; CssMediaRange payload; };
+    struct CssMediaFeatureVariant_Height { CssMediaFeatureTag tag; CssMediaRange payload; };
+    struct CssMediaFeatureVariant_Resolution { CssMediaFeatureTag tag; CssMediaRange payload; };
+    struct CssMediaFeatureVariant_Orientation { CssMediaFeatureTag tag; CssMediaOrientation payload; };
+    struct CssMediaFeatureVariant_PrefersColorScheme { CssMediaFeatureTag tag; CssColorScheme payload; };
+    union CssMediaFeature {
+        CssMediaFeatureVariant_Width Width;
+        CssMediaFeatureVariant_Height Height;
+        CssMediaFeatureVariant_Resolution Resolution;
+        CssMediaFeatureVariant_Orientation Orientation;
+        CssMediaFeatureVariant_PrefersColorScheme PrefersColorScheme;
+    };
+    
+    
     enum class AnimationInterpolationFunctionTag {
        Ease,
        Linear,
@@ -7435,49 +9110,225 @@ namespace dll {
        Perspective,
     };
     
-    struct StyleTransformVariant_Matrix { StyleTransformTag tag; StyleTransformMatrix2D payload; };
-    struct StyleTransformVariant_Matrix3D { StyleTransformTag tag; StyleTransformMatrix3D payload; };
-    struct StyleTransformVariant_Translate { StyleTransformTag tag; StyleTransformTranslate2D payload; };
-    struct StyleTransformVariant_Translate3D { StyleTransformTag tag; StyleTransformTranslate3D payload; };
-    struct StyleTransformVariant_TranslateX { StyleTransformTag tag; PixelValue payload; };
-    struct StyleTransformVariant_TranslateY { StyleTransformTag tag; PixelValue payload; };
-    struct StyleTransformVariant_TranslateZ { StyleTransformTag tag; PixelValue payload; };
-    struct StyleTransformVariant_Rotate { StyleTransformTag tag; AngleValue payload; };
-    struct StyleTransformVariant_Rotate3D { StyleTransformTag tag; StyleTransformRotate3D payload; };
-    struct StyleTransformVariant_RotateX { StyleTransformTag tag; AngleValue payload; };
-    struct StyleTransformVariant_RotateY { StyleTransformTag tag; AngleValue payload; };
-    struct StyleTransformVariant_RotateZ { StyleTransformTag tag; AngleValue payload; };
-    struct StyleTransformVariant_Scale { StyleTransformTag tag; StyleTransformScale2D payload; };
-    struct StyleTransformVariant_Scale3D { StyleTransformTag tag; StyleTransformScale3D payload; };
-    struct StyleTransformVariant_ScaleX { StyleTransformTag tag; PercentageValue payload; };
-    struct StyleTransformVariant_ScaleY { StyleTransformTag tag; PercentageValue payload; };
-    struct StyleTransformVariant_ScaleZ { StyleTransformTag tag; PercentageValue payload; };
-    struct StyleTransformVariant_Skew { StyleTransformTag tag; StyleTransformSkew2D payload; };
-    struct StyleTransformVariant_SkewX { StyleTransformTag tag; PercentageValue payload; };
-    struct StyleTransformVariant_SkewY { StyleTransformTag tag; PercentageValue payload; };
-    struct StyleTransformVariant_Perspective { StyleTransformTag tag; PixelValue payload; };
-    union StyleTransform {
-        StyleTransformVariant_Matrix Matrix;
-        StyleTransformVariant_Matrix3D Matrix3D;
-        StyleTransformVariant_Translate Translate;
-        StyleTransformVariant_Translate3D Translate3D;
-        StyleTransformVariant_TranslateX TranslateX;
-        StyleTransformVariant_TranslateY TranslateY;
-        StyleTransformVariant_TranslateZ TranslateZ;
-        StyleTransformVariant_Rotate Rotate;
-        StyleTransformVariant_Rotate3D Rotate3D;
-        StyleTransformVariant_RotateX RotateX;
-        StyleTransformVariant_RotateY RotateY;
-        StyleTransformVariant_RotateZ RotateZ;
-        StyleTransformVariant_Scale Scale;
-        StyleTransformVariant_Scale3D Scale3D;
-        StyleTransformVariant_ScaleX ScaleX;
-        StyleTransformVariant_ScaleY ScaleY;
-        StyleTransformVariant_ScaleZ ScaleZ;
-        StyleTransformVariant_Skew Skew;
-        StyleTransformVariant_SkewX SkewX;
-        StyleTransformVariant_SkewY SkewY;
-        StyleTransformVariant_Perspective Perspective;
+    struct StyleTransformVariant_Matrix { StyleTransformTag tag; StyleTransformMatrix2D payload; };
+    struct StyleTransformVariant_Matrix3D { StyleTransformTag tag; StyleTransformMatrix3D payload; };
+    struct StyleTransformVariant_Translate { StyleTransformTag tag; StyleTransformTranslate2D payload; };
+    struct StyleTransformVariant_Translate3D { StyleTransformTag tag; StyleTransformTranslate3D payload; };
+    struct StyleTransformVariant_TranslateX { StyleTransformTag tag; PixelValue payload; };
+    struct StyleTransformVariant_TranslateY { StyleTransformTag tag; PixelValue payload; };
+    struct StyleTransformVariant_TranslateZ { StyleTransformTag tag; PixelValue payload; };
+    struct StyleTransformVariant_Rotate { StyleTransformTag tag; AngleValue payload; };
+    struct StyleTransformVariant_Rotate3D { StyleTransformTag tag; StyleTransformRotate3D payload; };
+    struct StyleTransformVariant_RotateX { StyleTransformTag tag; AngleValue payload; };
+    struct StyleTransformVariant_RotateY { StyleTransformTag tag; AngleValue payload; };
+    struct StyleTransformVariant_RotateZ { StyleTransformTag tag; AngleValue payload; };
+    struct StyleTransformVariant_Scale { StyleTransformTag tag; StyleTransformScale2D payload; };
+    struct StyleTransformVariant_Scale3D { StyleTransformTag tag; StyleTransformScale3D payload; };
+    struct StyleTransformVariant_ScaleX { StyleTransformTag tag; PercentageValue payload; };
+    struct StyleTransformVariant_ScaleY { StyleTransformTag tag; PercentageValue payload; };
+    struct StyleTransformVariant_ScaleZ { StyleTransformTag tag; PercentageValue payload; };
+    struct StyleTransformVariant_Skew { StyleTransformTag tag; StyleTransformSkew2D payload; };
+    struct StyleTransformVariant_SkewX { StyleTransformTag tag; PercentageValue payload; };
+    struct StyleTransformVariant_SkewY { StyleTransformTag tag; PercentageValue payload; };
+    struct StyleTransformVariant_Perspective { StyleTransformTag tag; PixelValue payload; };
+    union StyleTransform {
+        StyleTransformVariant_Matrix Matrix;
+        StyleTransformVariant_Matrix3D Matrix3D;
+        StyleTransformVariant_Translate Translate;
+        StyleTransformVariant_Translate3D Translate3D;
+        StyleTransformVariant_TranslateX TranslateX;
+        StyleTransformVariant_TranslateY TranslateY;
+        StyleTransformVariant_TranslateZ TranslateZ;
+        StyleTransformVariant_Rotate Rotate;
+        StyleTransformVariant_Rotate3D Rotate3D;
+        StyleTransformVariant_RotateX RotateX;
+        StyleTransformVariant_RotateY RotateY;
+        StyleTransformVariant_RotateZ RotateZ;
+        StyleTransformVariant_Scale Scale;
+        StyleTransformVariant_Scale3D Scale3D;
+        StyleTransformVariant_ScaleX ScaleX;
+        StyleTransformVariant_ScaleY ScaleY;
+        StyleTransformVariant_ScaleZ ScaleZ;
+        StyleTransformVariant_Skew Skew;
+        StyleTransformVariant_SkewX SkewX;
+        StyleTransformVariant_SkewY SkewY;
+        StyleTransformVariant_Perspective Perspective;
+    };
+    
+    
+    enum class GridTrackSizingVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct GridTrackSizingVecValueVariant_Auto { GridTrackSizingVecValueTag tag; };
+    struct GridTrackSizingVecValueVariant_None { GridTrackSizingVecValueTag tag; };
+    struct GridTrackSizingVecValueVariant_Inherit { GridTrackSizingVecValueTag tag; };
+    struct GridTrackSizingVecValueVariant_Initial { GridTrackSizingVecValueTag tag; };
+    struct GridTrackSizingVecValueVariant_Exact { GridTrackSizingVecValueTag tag; GridTrackSizingVec payload; };
+    union GridTrackSizingVecValue {
+        GridTrackSizingVecValueVariant_Auto Auto;
+        GridTrackSizingVecValueVariant_None None;
+        GridTrackSizingVecValueVariant_Inherit Inherit;
+        GridTrackSizingVecValueVariant_Initial Initial;
+        GridTrackSizingVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTransitionPropertyVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTransitionPropertyVecValueVariant_Auto { StyleTransitionPropertyVecValueTag tag; };
+    struct StyleTransitionPropertyVecValueVariant_None { StyleTransitionPropertyVecValueTag tag; };
+    struct StyleTransitionPropertyVecValueVariant_Inherit { StyleTransitionPropertyVecValueTag tag; };
+    struct StyleTransitionPropertyVecValueVariant_Initial { StyleTransitionPropertyVecValueTag tag; };
+    struct StyleTransitionPropertyVecValueVariant_Exact { StyleTransitionPropertyVecValueTag tag; StyleTransitionPropertyVec payload; };
+    union StyleTransitionPropertyVecValue {
+        StyleTransitionPropertyVecValueVariant_Auto Auto;
+        StyleTransitionPropertyVecValueVariant_None None;
+        StyleTransitionPropertyVecValueVariant_Inherit Inherit;
+        StyleTransitionPropertyVecValueVariant_Initial Initial;
+        StyleTransitionPropertyVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTimeVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTimeVecValueVariant_Auto { StyleTimeVecValueTag tag; };
+    struct StyleTimeVecValueVariant_None { StyleTimeVecValueTag tag; };
+    struct StyleTimeVecValueVariant_Inherit { StyleTimeVecValueTag tag; };
+    struct StyleTimeVecValueVariant_Initial { StyleTimeVecValueTag tag; };
+    struct StyleTimeVecValueVariant_Exact { StyleTimeVecValueTag tag; StyleTimeVec payload; };
+    union StyleTimeVecValue {
+        StyleTimeVecValueVariant_Auto Auto;
+        StyleTimeVecValueVariant_None None;
+        StyleTimeVecValueVariant_Inherit Inherit;
+        StyleTimeVecValueVariant_Initial Initial;
+        StyleTimeVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleTimingFunctionVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleTimingFunctionVecValueVariant_Auto { StyleTimingFunctionVecValueTag tag; };
+    struct StyleTimingFunctionVecValueVariant_None { StyleTimingFunctionVecValueTag tag; };
+    struct StyleTimingFunctionVecValueVariant_Inherit { StyleTimingFunctionVecValueTag tag; };
+    struct StyleTimingFunctionVecValueVariant_Initial { StyleTimingFunctionVecValueTag tag; };
+    struct StyleTimingFunctionVecValueVariant_Exact { StyleTimingFunctionVecValueTag tag; StyleTimingFunctionVec payload; };
+    union StyleTimingFunctionVecValue {
+        StyleTimingFunctionVecValueVariant_Auto Auto;
+        StyleTimingFunctionVecValueVariant_None None;
+        StyleTimingFunctionVecValueVariant_Inherit Inherit;
+        StyleTimingFunctionVecValueVariant_Initial Initial;
+        StyleTimingFunctionVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleAnimationIterationCountVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationIterationCountVecValueVariant_Auto { StyleAnimationIterationCountVecValueTag tag; };
+    struct StyleAnimationIterationCountVecValueVariant_None { StyleAnimationIterationCountVecValueTag tag; };
+    struct StyleAnimationIterationCountVecValueVariant_Inherit { StyleAnimationIterationCountVecValueTag tag; };
+    struct StyleAnimationIterationCountVecValueVariant_Initial { StyleAnimationIterationCountVecValueTag tag; };
+    struct StyleAnimationIterationCountVecValueVariant_Exact { StyleAnimationIterationCountVecValueTag tag; StyleAnimationIterationCountVec payload; };
+    union StyleAnimationIterationCountVecValue {
+        StyleAnimationIterationCountVecValueVariant_Auto Auto;
+        StyleAnimationIterationCountVecValueVariant_None None;
+        StyleAnimationIterationCountVecValueVariant_Inherit Inherit;
+        StyleAnimationIterationCountVecValueVariant_Initial Initial;
+        StyleAnimationIterationCountVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleAnimationDirectionVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationDirectionVecValueVariant_Auto { StyleAnimationDirectionVecValueTag tag; };
+    struct StyleAnimationDirectionVecValueVariant_None { StyleAnimationDirectionVecValueTag tag; };
+    struct StyleAnimationDirectionVecValueVariant_Inherit { StyleAnimationDirectionVecValueTag tag; };
+    struct StyleAnimationDirectionVecValueVariant_Initial { StyleAnimationDirectionVecValueTag tag; };
+    struct StyleAnimationDirectionVecValueVariant_Exact { StyleAnimationDirectionVecValueTag tag; StyleAnimationDirectionVec payload; };
+    union StyleAnimationDirectionVecValue {
+        StyleAnimationDirectionVecValueVariant_Auto Auto;
+        StyleAnimationDirectionVecValueVariant_None None;
+        StyleAnimationDirectionVecValueVariant_Inherit Inherit;
+        StyleAnimationDirectionVecValueVariant_Initial Initial;
+        StyleAnimationDirectionVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleAnimationFillModeVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationFillModeVecValueVariant_Auto { StyleAnimationFillModeVecValueTag tag; };
+    struct StyleAnimationFillModeVecValueVariant_None { StyleAnimationFillModeVecValueTag tag; };
+    struct StyleAnimationFillModeVecValueVariant_Inherit { StyleAnimationFillModeVecValueTag tag; };
+    struct StyleAnimationFillModeVecValueVariant_Initial { StyleAnimationFillModeVecValueTag tag; };
+    struct StyleAnimationFillModeVecValueVariant_Exact { StyleAnimationFillModeVecValueTag tag; StyleAnimationFillModeVec payload; };
+    union StyleAnimationFillModeVecValue {
+        StyleAnimationFillModeVecValueVariant_Auto Auto;
+        StyleAnimationFillModeVecValueVariant_None None;
+        StyleAnimationFillModeVecValueVariant_Inherit Inherit;
+        StyleAnimationFillModeVecValueVariant_Initial Initial;
+        StyleAnimationFillModeVecValueVariant_Exact Exact;
+    };
+    
+    
+    enum class StyleAnimationPlayStateVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationPlayStateVecValueVariant_Auto { StyleAnimationPlayStateVecValueTag tag; };
+    struct StyleAnimationPlayStateVecValueVariant_None { StyleAnimationPlayStateVecValueTag tag; };
+    struct StyleAnimationPlayStateVecValueVariant_Inherit { StyleAnimationPlayStateVecValueTag tag; };
+    struct StyleAnimationPlayStateVecValueVariant_Initial { StyleAnimationPlayStateVecValueTag tag; };
+    struct StyleAnimationPlayStateVecValueVariant_Exact { StyleAnimationPlayStateVecValueTag tag; StyleAnimationPlayStateVec payload; };
+    union StyleAnimationPlayStateVecValue {
+        StyleAnimationPlayStateVecValueVariant_Auto Auto;
+        StyleAnimationPlayStateVecValueVariant_None None;
+        StyleAnimationPlayStateVecValueVariant_Inherit Inherit;
+        StyleAnimationPlayStateVecValueVariant_Initial Initial;
+        StyleAnimationPlayStateVecValueVariant_Exact Exact;
     };
     
     
@@ -7555,6 +9406,18 @@ namespace dll {
         CheckBoxStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextAreaEdit {
+        size_t position;
+        U32Vec removed;
+        U32Vec inserted;
+        TextAreaEditKind kind;
+        size_t cursor_before;
+        OptionUsize selection_anchor_before;
+        TextAreaEdit& operator=(const TextAreaEdit&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextAreaEdit(const TextAreaEdit&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextAreaEdit() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NumberInputStateWrapper {
         NumberInputState inner;
         OptionNumberInputOnValueChange on_value_change;
@@ -7564,6 +9427,13 @@ namespace dll {
         NumberInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SplitterState {
+        SplitterPaneStateVec panes;
+        SplitterState& operator=(const SplitterState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterState(const SplitterState&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeGraphCallbacks {
         OptionNodeGraphOnNodeAdded on_node_added;
         OptionNodeGraphOnNodeRemoved on_node_removed;
@@ -7775,6 +9645,16 @@ namespace dll {
         ListViewRowVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextAreaEditVec {
+        TextAreaEdit* ptr;
+        size_t len;
+        size_t cap;
+        TextAreaEditVecDestructor destructor;
+        TextAreaEditVec& operator=(const TextAreaEditVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextAreaEditVec(const TextAreaEditVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextAreaEditVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleFilterVec {
         StyleFilter* ptr;
         size_t len;
@@ -7835,6 +9715,16 @@ namespace dll {
         SvgPathElementVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssMediaFeatureVec {
+        CssMediaFeature* ptr;
+        size_t len;
+        size_t cap;
+        CssMediaFeatureVecDestructor destructor;
+        CssMediaFeatureVec& operator=(const CssMediaFeatureVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaFeatureVec(const CssMediaFeatureVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssMediaFeatureVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StringVec {
         String* ptr;
         size_t len;
@@ -7917,19 +9807,6 @@ namespace dll {
     };
     
     
-    enum class OptionKeyboardStateTag {
-       None,
-       Some,
-    };
-    
-    struct OptionKeyboardStateVariant_None { OptionKeyboardStateTag tag; };
-    struct OptionKeyboardStateVariant_Some { OptionKeyboardStateTag tag; KeyboardState payload; };
-    union OptionKeyboardState {
-        OptionKeyboardStateVariant_None None;
-        OptionKeyboardStateVariant_Some Some;
-    };
-    
-    
     enum class OptionStringVecTag {
        None,
        Some,
@@ -8070,6 +9947,22 @@ namespace dll {
         InvalidStringError() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct ClipboardContent {
+        String mime_type;
+        U8Vec data;
+        ClipboardContent& operator=(const ClipboardContent&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ClipboardContent(const ClipboardContent&) = delete; /* disable copy constructor, use explicit .clone() */
+        ClipboardContent() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct ImeComposition {
+        String text;
+        size_t cursor;
+        ImeComposition& operator=(const ImeComposition&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        ImeComposition(const ImeComposition&) = delete; /* disable copy constructor, use explicit .clone() */
+        ImeComposition() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct WindowsWindowOptions {
         bool  allow_drag_drop;
         bool  no_redirection_bitmap;
@@ -8230,6 +10123,19 @@ namespace dll {
         AccessibilityInfo() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class TooltipContentTag {
+       Text,
+       Dom,
+    };
+    
+    struct TooltipContentVariant_Text { TooltipContentTag tag; String payload; };
+    struct TooltipContentVariant_Dom { TooltipContentTag tag; TooltipDom payload; };
+    union TooltipContent {
+        TooltipContentVariant_Text Text;
+        TooltipContentVariant_Dom Dom;
+    };
+    
+    
     enum class IdOrClassTag {
        Id,
        Class,
@@ -8283,6 +10189,15 @@ namespace dll {
     };
     
     
+    struct CssMediaQuery {
+        bool  negated;
+        CssMediaType media_type;
+        CssMediaFeatureVec features;
+        CssMediaQuery& operator=(const CssMediaQuery&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaQuery(const CssMediaQuery&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssMediaQuery() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class StyleBackgroundContentTag {
        LinearGradient,
        RadialGradient,
@@ -8343,6 +10258,19 @@ namespace dll {
     };
     
     
+    enum class StyleAnimationNameTag {
+       None,
+       Name,
+    };
+    
+    struct StyleAnimationNameVariant_None { StyleAnimationNameTag tag; };
+    struct StyleAnimationNameVariant_Name { StyleAnimationNameTag tag; String payload; };
+    union StyleAnimationName {
+        StyleAnimationNameVariant_None None;
+        StyleAnimationNameVariant_Name Name;
+    };
+    
+    
     enum class ScrollbarStyleValueTag {
        Auto,
        None,
@@ -8409,6 +10337,21 @@ namespace dll {
     };
     
     
+    struct RibbonItem {
+        RibbonItemKind kind;
+        String label;
+        OptionImageRef icon;
+        OptionString tooltip;
+        OptionString key_tip;
+        bool  enabled;
+        bool  toggled;
+        StringVec choices;
+        OptionRibbonOnItemClick on_click;
+        RibbonItem& operator=(const RibbonItem&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonItem(const RibbonItem&) = delete; /* disable copy constructor, use explicit .clone() */
+        RibbonItem() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct FileInputState {
         OptionString path;
         FileInputState& operator=(const FileInputState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -8436,10 +10379,27 @@ namespace dll {
         TextInputState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextAreaState {
+        U32Vec text;
+        size_t cursor;
+        OptionUsize selection_anchor;
+        OptionF32 preferred_x;
+        float scroll_offset;
+        TextAreaEditVec undo_stack;
+        TextAreaEditVec redo_stack;
+        OptionInstant last_edit_time;
+        TextAreaState& operator=(const TextAreaState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextAreaState(const TextAreaState&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextAreaState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct TabHeader {
         StringVec tabs;
         size_t active_tab;
         OptionTabOnClick on_click;
+        OptionTabOnClose on_close;
+        OptionTabOnReorder on_reorder;
+        OptionTabOnContextMenu on_context_menu;
         TabHeader& operator=(const TabHeader&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         TabHeader(const TabHeader&) = delete; /* disable copy constructor, use explicit .clone() */
         TabHeader() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8495,6 +10455,11 @@ namespace dll {
         OptionListViewOnLazyLoadScroll on_lazy_load_scroll;
         OptionListViewOnColumnClick on_column_click;
         OptionListViewOnRowClick on_row_click;
+        size_t row_count;
+        PixelValueNoPercent row_height;
+        F32Vec column_widths;
+        OptionListViewRowProvider row_provider;
+        OptionListViewOnColumnResize on_column_resize;
         ListView& operator=(const ListView&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         ListView(const ListView&) = delete; /* disable copy constructor, use explicit .clone() */
         ListView() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -8507,21 +10472,40 @@ namespace dll {
         PixelValueNoPercent scroll_offset;
         LogicalPosition current_scroll_position;
         LogicalSize current_content_height;
+        size_t row_count;
+        ListViewRowRange visible_rows;
+        F32Vec column_widths;
         ListViewState& operator=(const ListViewState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         ListViewState(const ListViewState&) = delete; /* disable copy constructor, use explicit .clone() */
         ListViewState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct TreeView {
-        String root;
-        TreeView& operator=(const TreeView&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        TreeView(const TreeView&) = delete; /* disable copy constructor, use explicit .clone() */
-        TreeView() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    struct TreeViewNode {
+        TreeViewNodeId id;
+        OptionTreeViewNodeId parent;
+        String label;
+        OptionImageRef icon;
+        bool  has_children;
+        TreeViewNode& operator=(const TreeViewNode&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewNode(const TreeViewNode&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewNode() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct TreeViewState {
+        TreeViewNodeIdVec expanded;
+        TreeViewNodeIdVec selected;
+        OptionTreeViewNodeId focused;
+        OptionTreeViewNodeId selection_anchor;
+        OptionInstant last_click_time;
+        TreeViewState& operator=(const TreeViewState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewState(const TreeViewState&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewState() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     struct DropDown {
         StringVec choices;
         size_t selected;
+        U32Vec disabled_choices;
         OptionDropDownOnChoiceChange on_choice_change;
         DropDown& operator=(const DropDown&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         DropDown(const DropDown&) = delete; /* disable copy constructor, use explicit .clone() */
@@ -8706,6 +10690,26 @@ namespace dll {
         FmtArg() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TreeViewNodeVec {
+        TreeViewNode* ptr;
+        size_t len;
+        size_t cap;
+        TreeViewNodeVecDestructor destructor;
+        TreeViewNodeVec& operator=(const TreeViewNodeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeViewNodeVec(const TreeViewNodeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeViewNodeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct RibbonItemVec {
+        RibbonItem* ptr;
+        size_t len;
+        size_t cap;
+        RibbonItemVecDestructor destructor;
+        RibbonItemVec& operator=(const RibbonItemVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonItemVec(const RibbonItemVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        RibbonItemVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct StyleFontFamilyVec {
         StyleFontFamily* ptr;
         size_t len;
@@ -8766,6 +10770,16 @@ namespace dll {
         StyleBackgroundContentVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct StyleAnimationNameVec {
+        StyleAnimationName* ptr;
+        size_t len;
+        size_t cap;
+        StyleAnimationNameVecDestructor destructor;
+        StyleAnimationNameVec& operator=(const StyleAnimationNameVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StyleAnimationNameVec(const StyleAnimationNameVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StyleAnimationNameVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct SvgPathVec {
         SvgPath* ptr;
         size_t len;
@@ -8796,6 +10810,16 @@ namespace dll {
         CssPathSelectorVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssMediaQueryVec {
+        CssMediaQuery* ptr;
+        size_t len;
+        size_t cap;
+        CssMediaQueryVecDestructor destructor;
+        CssMediaQueryVec& operator=(const CssMediaQueryVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaQueryVec(const CssMediaQueryVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssMediaQueryVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CallbackDataVec {
         CallbackData* ptr;
         size_t len;
@@ -8878,6 +10902,32 @@ namespace dll {
     };
     
     
+    enum class OptionImeCompositionTag {
+       None,
+       Some,
+    };
+    
+    struct OptionImeCompositionVariant_None { OptionImeCompositionTag tag; };
+    struct OptionImeCompositionVariant_Some { OptionImeCompositionTag tag; ImeComposition payload; };
+    union OptionImeComposition {
+        OptionImeCompositionVariant_None None;
+        OptionImeCompositionVariant_Some Some;
+    };
+    
+    
+    enum class OptionClipboardContentTag {
+       None,
+       Some,
+    };
+    
+    struct OptionClipboardContentVariant_None { OptionClipboardContentTag tag; };
+    struct OptionClipboardContentVariant_Some { OptionClipboardContentTag tag; ClipboardContent payload; };
+    union OptionClipboardContent {
+        OptionClipboardContentVariant_None None;
+        OptionClipboardContentVariant_Some Some;
+    };
+    
+    
     enum class ResultRawImageDecodeImageErrorTag {
        Ok,
        Err,
@@ -8937,7 +10987,19 @@ namespace dll {
     };
     
     
+    struct KeyboardState {
+        OptionChar current_char;
+        OptionVirtualKeyCode current_virtual_keycode;
+        VirtualKeyCodeVec pressed_virtual_keycodes;
+        ScanCodeVec pressed_scancodes;
+        OptionImeComposition ime_composition;
+        KeyboardState& operator=(const KeyboardState&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        KeyboardState(const KeyboardState&) = delete; /* disable copy constructor, use explicit .clone() */
+        KeyboardState() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct LinuxWindowOptions {
+        LinuxBackend backend;
         OptionX11Visual x11_visual;
         OptionI32 x11_screen;
         StringPairVec x11_wm_classes;
@@ -8963,6 +11025,14 @@ namespace dll {
         InlineLine() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct Tooltip {
+        TooltipContent content;
+        Duration delay;
+        Tooltip& operator=(const Tooltip&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Tooltip(const Tooltip&) = delete; /* disable copy constructor, use explicit .clone() */
+        Tooltip() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class MenuItemTag {
        String,
        Separator,
@@ -8986,6 +11056,28 @@ namespace dll {
         CssPath() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    enum class StyleAnimationNameVecValueTag {
+       Auto,
+       None,
+       Inherit,
+       Initial,
+       Exact,
+    };
+    
+    struct StyleAnimationNameVecValueVariant_Auto { StyleAnimationNameVecValueTag tag; };
+    struct StyleAnimationNameVecValueVariant_None { StyleAnimationNameVecValueTag tag; };
+    struct StyleAnimationNameVecValueVariant_Inherit { StyleAnimationNameVecValueTag tag; };
+    struct StyleAnimationNameVecValueVariant_Initial { StyleAnimationNameVecValueTag tag; };
+    struct StyleAnimationNameVecValueVariant_Exact { StyleAnimationNameVecValueTag tag; StyleAnimationNameVec payload; };
+    union StyleAnimationNameVecValue {
+        StyleAnimationNameVecValueVariant_Auto Auto;
+        StyleAnimationNameVecValueVariant_None None;
+        StyleAnimationNameVecValueVariant_Inherit Inherit;
+        StyleAnimationNameVecValueVariant_Initial Initial;
+        StyleAnimationNameVecValueVariant_Exact Exact;
+    };
+    
+    
     enum class StyleBackgroundContentVecValueTag {
        Auto,
        None,
@@ -9035,6 +11127,10 @@ namespace dll {
        FontSize,
        FontFamily,
        TextAlign,
+       Direction,
+       WordBreak,
+       OverflowWrap,
+       Hyphens,
        LetterSpacing,
        LineHeight,
        WordSpacing,
@@ -9061,6 +11157,15 @@ namespace dll {
        JustifyContent,
        AlignItems,
        AlignContent,
+       GridTemplateColumns,
+       GridTemplateRows,
+       GridAutoColumns,
+       GridAutoRows,
+       GridAutoFlow,
+       GridColumn,
+       GridRow,
+       RowGap,
+       ColumnGap,
        BackgroundContent,
        BackgroundPosition,
        BackgroundSize,
@@ -9105,12 +11210,28 @@ namespace dll {
        Filter,
        BackdropFilter,
        TextShadow,
+       TransitionProperty,
+       TransitionDuration,
+       TransitionTimingFunction,
+       TransitionDelay,
+       AnimationName,
+       AnimationDuration,
+       AnimationTimingFunction,
+       AnimationDelay,
+       AnimationIterationCount,
+       AnimationDirection,
+       AnimationFillMode,
+       AnimationPlayState,
     };
     
     struct CssPropertyVariant_TextColor { CssPropertyTag tag; StyleTextColorValue payload; };
     struct CssPropertyVariant_FontSize { CssPropertyTag tag; StyleFontSizeValue payload; };
     struct CssPropertyVariant_FontFamily { CssPropertyTag tag; StyleFontFamilyVecValue payload; };
     struct CssPropertyVariant_TextAlign { CssPropertyTag tag; StyleTextAlignValue payload; };
+    struct CssPropertyVariant_Direction { CssPropertyTag tag; StyleDirectionValue payload; };
+    struct CssPropertyVariant_WordBreak { CssPropertyTag tag; StyleWordBreakValue payload; };
+    struct CssPropertyVariant_OverflowWrap { CssPropertyTag tag; StyleOverflowWrapValue payload; };
+    struct CssPropertyVariant_Hyphens { CssPropertyTag tag; StyleHyphensValue payload; };
     struct CssPropertyVariant_LetterSpacing { CssPropertyTag tag; StyleLetterSpacingValue payload; };
     struct CssPropertyVariant_LineHeight { CssPropertyTag tag; StyleLineHeightValue payload; };
     struct CssPropertyVariant_WordSpacing { CssPropertyTag tag; StyleWordSpacingValue payload; };
@@ -9137,6 +11258,15 @@ namespace dll {
     struct CssPropertyVariant_JustifyContent { CssPropertyTag tag; LayoutJustifyContentValue payload; };
     struct CssPropertyVariant_AlignItems { CssPropertyTag tag; LayoutAlignItemsValue payload; };
     struct CssPropertyVariant_AlignContent { CssPropertyTag tag; LayoutAlignContentValue payload; };
+    struct CssPropertyVariant_GridTemplateColumns { CssPropertyTag tag; GridTrackSizingVecValue payload; };
+    struct CssPropertyVariant_GridTemplateRows { CssPropertyTag tag; GridTrackSizingVecValue payload; };
+    struct CssPropertyVariant_GridAutoColumns { CssPropertyTag tag; GridTrackSizingValue payload; };
+    struct CssPropertyVariant_GridAutoRows { CssPropertyTag tag; GridTrackSizingValue payload; };
+    struct CssPropertyVariant_GridAutoFlow { CssPropertyTag tag; LayoutGridAutoFlowValue payload; };
+    struct CssPropertyVariant_GridColumn { CssPropertyTag tag; GridPlacementValue payload; };
+    struct CssPropertyVariant_GridRow { CssPropertyTag tag; GridPlacementValue payload; };
+    struct CssPropertyVariant_RowGap { CssPropertyTag tag; LayoutRowGapValue payload; };
+    struct CssPropertyVariant_ColumnGap { CssPropertyTag tag; LayoutColumnGapValue payload; };
     struct CssPropertyVariant_BackgroundContent { CssPropertyTag tag; StyleBackgroundContentVecValue payload; };
     struct CssPropertyVariant_BackgroundPosition { CssPropertyTag tag; StyleBackgroundPositionVecValue payload; };
     struct CssPropertyVariant_BackgroundSize { CssPropertyTag tag; StyleBackgroundSizeVecValue payload; };
@@ -9181,11 +11311,27 @@ namespace dll {
     struct CssPropertyVariant_Filter { CssPropertyTag tag; StyleFilterVecValue payload; };
     struct CssPropertyVariant_BackdropFilter { CssPropertyTag tag; StyleFilterVecValue payload; };
     struct CssPropertyVariant_TextShadow { CssPropertyTag tag; StyleBoxShadowValue payload; };
+    struct CssPropertyVariant_TransitionProperty { CssPropertyTag tag; StyleTransitionPropertyVecValue payload; };
+    struct CssPropertyVariant_TransitionDuration { CssPropertyTag tag; StyleTimeVecValue payload; };
+    struct CssPropertyVariant_TransitionTimingFunction { CssPropertyTag tag; StyleTimingFunctionVecValue payload; };
+    struct CssPropertyVariant_TransitionDelay { CssPropertyTag tag; StyleTimeVecValue payload; };
+    struct CssPropertyVariant_AnimationName { CssPropertyTag tag; StyleAnimationNameVecValue payload; };
+    struct CssPropertyVariant_AnimationDuration { CssPropertyTag tag; StyleTimeVecValue payload; };
+    struct CssPropertyVariant_AnimationTimingFunction { CssPropertyTag tag; StyleTimingFunctionVecValue payload; };
+    struct CssPropertyVariant_AnimationDelay { CssPropertyTag tag; StyleTimeVecValue payload; };
+    struct CssPropertyVariant_AnimationIterationCount { CssPropertyTag tag; StyleAnimationIterationCountVecValue payload; };
+    struct CssPropertyVariant_AnimationDirection { CssPropertyTag tag; StyleAnimationDirectionVecValue payload; };
+    struct CssPropertyVariant_AnimationFillMode { CssPropertyTag tag; StyleAnimationFillModeVecValue payload; };
+    struct CssPropertyVariant_AnimationPlayState { CssPropertyTag tag; StyleAnimationPlayStateVecValue payload; };
     union CssProperty {
         CssPropertyVariant_TextColor TextColor;
         CssPropertyVariant_FontSize FontSize;
         CssPropertyVariant_FontFamily FontFamily;
         CssPropertyVariant_TextAlign TextAlign;
+        CssPropertyVariant_Direction Direction;
+        CssPropertyVariant_WordBreak WordBreak;
+        CssPropertyVariant_OverflowWrap OverflowWrap;
+        CssPropertyVariant_Hyphens Hyphens;
         CssPropertyVariant_LetterSpacing LetterSpacing;
         CssPropertyVariant_LineHeight LineHeight;
         CssPropertyVariant_WordSpacing WordSpacing;
@@ -9212,6 +11358,15 @@ namespace dll {
         CssPropertyVariant_JustifyContent JustifyContent;
         CssPropertyVariant_AlignItems AlignItems;
         CssPropertyVariant_AlignContent AlignContent;
+        CssPropertyVariant_GridTemplateColumns GridTemplateColumns;
+        CssPropertyVariant_GridTemplateRows GridTemplateRows;
+        CssPropertyVariant_GridAutoColumns GridAutoColumns;
+        CssPropertyVariant_GridAutoRows GridAutoRows;
+        CssPropertyVariant_GridAutoFlow GridAutoFlow;
+        CssPropertyVariant_GridColumn GridColumn;
+        CssPropertyVariant_GridRow GridRow;
+        CssPropertyVariant_RowGap RowGap;
+        CssPropertyVariant_ColumnGap ColumnGap;
         CssPropertyVariant_BackgroundContent BackgroundContent;
         CssPropertyVariant_BackgroundPosition BackgroundPosition;
         CssPropertyVariant_BackgroundSize BackgroundSize;
@@ -9256,9 +11411,30 @@ namespace dll {
         CssPropertyVariant_Filter Filter;
         CssPropertyVariant_BackdropFilter BackdropFilter;
         CssPropertyVariant_TextShadow TextShadow;
+        CssPropertyVariant_TransitionProperty TransitionProperty;
+        CssPropertyVariant_TransitionDuration TransitionDuration;
+        CssPropertyVariant_TransitionTimingFunction TransitionTimingFunction;
+        CssPropertyVariant_TransitionDelay TransitionDelay;
+        CssPropertyVariant_AnimationName AnimationName;
+        CssPropertyVariant_AnimationDuration AnimationDuration;
+        CssPropertyVariant_AnimationTimingFunction AnimationTimingFunction;
+        CssPropertyVariant_AnimationDelay AnimationDelay;
+        CssPropertyVariant_AnimationIterationCount AnimationIterationCount;
+        CssPropertyVariant_AnimationDirection AnimationDirection;
+        CssPropertyVariant_AnimationFillMode AnimationFillMode;
+        CssPropertyVariant_AnimationPlayState AnimationPlayState;
+    };
+    
+    
+    struct RibbonGroup {
+        String label;
+        OptionImageRef icon;
+        RibbonItemVec items;
+        RibbonGroup& operator=(const RibbonGroup&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonGroup(const RibbonGroup&) = delete; /* disable copy constructor, use explicit .clone() */
+        RibbonGroup() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    
     struct FileInputStateWrapper {
         FileInputState inner;
         OptionFileInputOnPathChange on_file_path_change;
@@ -9283,11 +11459,21 @@ namespace dll {
         TextInputStateWrapper() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TextArea {
+        TextAreaState state;
+        OptionTextAreaOnTextChange on_text_change;
+        TextArea& operator=(const TextArea&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TextArea(const TextArea&) = delete; /* disable copy constructor, use explicit .clone() */
+        TextArea() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct ProgressBar {
         ProgressBarState state;
         PixelValue height;
         StyleBackgroundContentVec bar_background;
         StyleBackgroundContentVec container_background;
+        ProgressBarStyle style;
+        OptionString text_format;
         ProgressBar& operator=(const ProgressBar&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
         ProgressBar(const ProgressBar&) = delete; /* disable copy constructor, use explicit .clone() */
         ProgressBar() = delete; /* disable default constructor, use C++20 designated initializer instead */
@@ -9317,6 +11503,19 @@ namespace dll {
         NodeTypeField() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct TreeView {
+        TreeViewNodeVec nodes;
+        TreeViewState state;
+        TreeViewSelectionMode selection_mode;
+        OptionTreeViewOnExpand on_expand;
+        OptionTreeViewOnSelect on_select;
+        OptionTreeViewOnDoubleClick on_double_click;
+        OptionTreeViewOnContextMenu on_context_menu;
+        TreeView& operator=(const TreeView&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        TreeView(const TreeView&) = delete; /* disable copy constructor, use explicit .clone() */
+        TreeView() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class CssPropertySourceTag {
        Css,
        Inline,
@@ -9386,6 +11585,16 @@ namespace dll {
         XmlNode() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct RibbonGroupVec {
+        RibbonGroup* ptr;
+        size_t len;
+        size_t cap;
+        RibbonGroupVecDestructor destructor;
+        RibbonGroupVec& operator=(const RibbonGroupVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonGroupVec(const RibbonGroupVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        RibbonGroupVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeTypeIdInfoMapVec {
         NodeTypeIdInfoMap* ptr;
         size_t len;
@@ -9459,6 +11668,19 @@ namespace dll {
     };
     
     
+    enum class OptionKeyboardStateTag {
+       None,
+       Some,
+    };
+    
+    struct OptionKeyboardStateVariant_None { OptionKeyboardStateTag tag; };
+    struct OptionKeyboardStateVariant_Some { OptionKeyboardStateTag tag; KeyboardState payload; };
+    union OptionKeyboardState {
+        OptionKeyboardStateVariant_None None;
+        OptionKeyboardStateVariant_Some Some;
+    };
+    
+    
     struct XmlTextError {
         XmlStreamError stream_error;
         SvgParseErrorPosition pos;
@@ -9606,6 +11828,23 @@ namespace dll {
         DynamicCssProperty() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssKeyframe {
+        PercentageValue offset;
+        CssPropertyVec properties;
+        CssKeyframe& operator=(const CssKeyframe&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssKeyframe(const CssKeyframe&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssKeyframe() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct RibbonTab {
+        String label;
+        OptionString key_tip;
+        RibbonGroupVec groups;
+        RibbonTab& operator=(const RibbonTab&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonTab(const RibbonTab&) = delete; /* disable copy constructor, use explicit .clone() */
+        RibbonTab() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Node {
         NodeTypeId node_type;
         NodePosition position;
@@ -9647,6 +11886,16 @@ namespace dll {
         SvgStyledNode() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct RibbonTabVec {
+        RibbonTab* ptr;
+        size_t len;
+        size_t cap;
+        RibbonTabVecDestructor destructor;
+        RibbonTabVec& operator=(const RibbonTabVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RibbonTabVec(const RibbonTabVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        RibbonTabVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeDataInlineCssPropertyVec {
         NodeDataInlineCssProperty* ptr;
         size_t len;
@@ -9657,6 +11906,16 @@ namespace dll {
         NodeDataInlineCssPropertyVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssKeyframeVec {
+        CssKeyframe* ptr;
+        size_t len;
+        size_t cap;
+        CssKeyframeVecDestructor destructor;
+        CssKeyframeVec& operator=(const CssKeyframeVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssKeyframeVec(const CssKeyframeVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssKeyframeVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     enum class OptionWindowStateTag {
        None,
        Some,
@@ -9786,6 +12045,24 @@ namespace dll {
     };
     
     
+    struct CssKeyframes {
+        String name;
+        CssKeyframeVec frames;
+        CssKeyframes& operator=(const CssKeyframes&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssKeyframes(const CssKeyframes&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssKeyframes() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct Ribbon {
+        RibbonTabVec tabs;
+        int32_t tab_active;
+        OptionF32 available_width;
+        OptionRibbonOnTabClicked on_tab_clicked;
+        Ribbon& operator=(const Ribbon&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Ribbon(const Ribbon&) = delete; /* disable copy constructor, use explicit .clone() */
+        Ribbon() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Button {
         String label;
         OptionImageRef image;
@@ -9853,6 +12130,38 @@ namespace dll {
         NumberInput() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct Slider {
+        SliderOrientation orientation;
+        SliderState state;
+        float tick_interval;
+        OptionSliderOnValueChange on_value_change;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec track_style;
+        NodeDataInlineCssPropertyVec fill_style;
+        NodeDataInlineCssPropertyVec rail_style;
+        NodeDataInlineCssPropertyVec thumb_style;
+        NodeDataInlineCssPropertyVec tick_style;
+        Slider& operator=(const Slider&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Slider(const Slider&) = delete; /* disable copy constructor, use explicit .clone() */
+        Slider() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct RangeSlider {
+        SliderOrientation orientation;
+        RangeSliderState state;
+        float tick_interval;
+        OptionRangeSliderOnValueChange on_value_change;
+        NodeDataInlineCssPropertyVec container_style;
+        NodeDataInlineCssPropertyVec track_style;
+        NodeDataInlineCssPropertyVec fill_style;
+        NodeDataInlineCssPropertyVec rail_style;
+        NodeDataInlineCssPropertyVec thumb_style;
+        NodeDataInlineCssPropertyVec tick_style;
+        RangeSlider& operator=(const RangeSlider&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        RangeSlider(const RangeSlider&) = delete; /* disable copy constructor, use explicit .clone() */
+        RangeSlider() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeIdNodeMap {
         NodeGraphNodeId node_id;
         Node node;
@@ -9881,6 +12190,16 @@ namespace dll {
         CssDeclarationVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct CssKeyframesVec {
+        CssKeyframes* ptr;
+        size_t len;
+        size_t cap;
+        CssKeyframesVecDestructor destructor;
+        CssKeyframesVec& operator=(const CssKeyframesVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssKeyframesVec(const CssKeyframesVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssKeyframesVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeDataVec {
         NodeData* ptr;
         size_t len;
@@ -9983,6 +12302,17 @@ namespace dll {
         Frame() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SplitterPane {
+        Dom content;
+        OptionF32 size;
+        float min_size;
+        OptionF32 max_size;
+        bool  collapsible;
+        SplitterPane& operator=(const SplitterPane&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterPane(const SplitterPane&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterPane() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct NodeGraph {
         NodeTypeIdInfoMapVec node_types;
         InputOutputTypeIdInfoMapVec input_output_types;
@@ -10015,6 +12345,16 @@ namespace dll {
         StyledDom() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
+    struct SplitterPaneVec {
+        SplitterPane* ptr;
+        size_t len;
+        size_t cap;
+        SplitterPaneVecDestructor destructor;
+        SplitterPaneVec& operator=(const SplitterPaneVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        SplitterPaneVec(const SplitterPaneVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        SplitterPaneVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct CssRuleBlockVec {
         CssRuleBlock* ptr;
         size_t len;
@@ -10087,21 +12427,33 @@ namespace dll {
         IFrameCallbackReturn() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct Stylesheet {
+    struct CssMediaRule {
+        CssMediaQueryVec queries;
+        size_t insert_index;
         CssRuleBlockVec rules;
-        Stylesheet& operator=(const Stylesheet&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        Stylesheet(const Stylesheet&) = delete; /* disable copy constructor, use explicit .clone() */
-        Stylesheet() = delete; /* disable default constructor, use C++20 designated initializer instead */
+        CssMediaRule& operator=(const CssMediaRule&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaRule(const CssMediaRule&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssMediaRule() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
-    struct StylesheetVec {
-        Stylesheet* ptr;
+    struct Splitter {
+        SplitterOrientation orientation;
+        SplitterPaneVec panes;
+        SplitterState state;
+        OptionSplitterOnResize on_resize;
+        Splitter& operator=(const Splitter&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Splitter(const Splitter&) = delete; /* disable copy constructor, use explicit .clone() */
+        Splitter() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct CssMediaRuleVec {
+        CssMediaRule* ptr;
         size_t len;
         size_t cap;
-        StylesheetVecDestructor destructor;
-        StylesheetVec& operator=(const StylesheetVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
-        StylesheetVec(const StylesheetVec&) = delete; /* disable copy constructor, use explicit .clone() */
-        StylesheetVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+        CssMediaRuleVecDestructor destructor;
+        CssMediaRuleVec& operator=(const CssMediaRuleVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        CssMediaRuleVec(const CssMediaRuleVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        CssMediaRuleVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
     };
     
     enum class ResultSvgXmlNodeSvgParseErrorTag {
@@ -10130,6 +12482,25 @@ namespace dll {
     };
     
     
+    struct Stylesheet {
+        CssRuleBlockVec rules;
+        CssKeyframesVec keyframes;
+        CssMediaRuleVec media_rules;
+        Stylesheet& operator=(const Stylesheet&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        Stylesheet(const Stylesheet&) = delete; /* disable copy constructor, use explicit .clone() */
+        Stylesheet() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
+    struct StylesheetVec {
+        Stylesheet* ptr;
+        size_t len;
+        size_t cap;
+        StylesheetVecDestructor destructor;
+        StylesheetVec& operator=(const StylesheetVec&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
+        StylesheetVec(const StylesheetVec&) = delete; /* disable copy constructor, use explicit .clone() */
+        StylesheetVec() = delete; /* disable default constructor, use C++20 designated initializer instead */
+    };
+    
     struct Css {
         StylesheetVec stylesheets;
         Css& operator=(const Css&) = delete; /* disable assignment operator, use std::move (default) or .clone() */
@@ -10151,6 +12522,13 @@ namespace dll {
         SystemCallbacks SystemCallbacks_libraryInternal();
         WindowCreateOptions WindowCreateOptions_new(AzLayoutCallbackType  layout_callback);
         void WindowCreateOptions_delete(WindowCreateOptions* restrict instance);
+        ClipboardContent ClipboardContent_text(AzString  text);
+        ClipboardContent ClipboardContent_uriList(AzStringVec  uris);
+        ClipboardContent ClipboardContent_png(AzU8Vec  data);
+        bool  ClipboardContent_isText(const ClipboardContent* clipboardcontent);
+        OptionString ClipboardContent_getText(const ClipboardContent* clipboardcontent);
+        StringVec ClipboardContent_getUriList(const ClipboardContent* clipboardcontent);
+        void ClipboardContent_delete(ClipboardContent* restrict instance);
         LogicalPosition LogicalPosition_new(float x, float y);
         LogicalPosition LogicalPosition_zero();
         PhysicalSizeU32 LogicalSize_toPhysical(const LogicalSize* logicalsize, float hidpi_factor);
@@ -10164,6 +12542,7 @@ namespace dll {
         bool  KeyboardState_superDown(const KeyboardState* keyboardstate);
         bool  KeyboardState_isKeyDown(const KeyboardState* keyboardstate, AzVirtualKeyCode  key);
         void KeyboardState_delete(KeyboardState* restrict instance);
+        void ImeComposition_delete(ImeComposition* restrict instance);
         OptionLogicalPosition CursorPosition_getPosition(const CursorPosition* cursorposition);
         void PlatformSpecificOptions_delete(PlatformSpecificOptions* restrict instance);
         void WindowsWindowOptions_delete(WindowsWindowOptions* restrict instance);
@@ -10186,6 +12565,10 @@ namespace dll {
         OptionWindowState CallbackInfo_getPreviousWindowState(const CallbackInfo* callbackinfo);
         OptionKeyboardState CallbackInfo_getPreviousKeyboardState(const CallbackInfo* callbackinfo);
         OptionMouseState CallbackInfo_getPreviousMouseState(const CallbackInfo* callbackinfo);
+        OptionString CallbackInfo_getHoveredFile(const CallbackInfo* callbackinfo);
+        OptionString CallbackInfo_getDroppedFile(const CallbackInfo* callbackinfo);
+        OptionString CallbackInfo_getHoveredText(const CallbackInfo* callbackinfo);
+        OptionString CallbackInfo_getDroppedText(const CallbackInfo* callbackinfo);
         RawWindowHandle CallbackInfo_getCurrentWindowHandle(const CallbackInfo* callbackinfo);
         OptionGl CallbackInfo_getGlContext(const CallbackInfo* callbackinfo);
         OptionLogicalPosition CallbackInfo_getScrollPosition(const CallbackInfo* callbackinfo, AzDomNodeId  node_id);
@@ -10218,6 +12601,9 @@ namespace dll {
         void CallbackInfo_updateImageMask(CallbackInfo* restrict callbackinfo, AzDomNodeId  node_id, AzImageMask  new_mask);
         void CallbackInfo_stopPropagation(CallbackInfo* restrict callbackinfo);
         void CallbackInfo_createWindow(CallbackInfo* restrict callbackinfo, AzWindowCreateOptions  new_window);
+        OptionClipboardContent CallbackInfo_getClipboardContent(const CallbackInfo* callbackinfo, AzClipboardSelection  selection, AzString  mime_type);
+        OptionString CallbackInfo_getClipboardText(const CallbackInfo* callbackinfo, AzClipboardSelection  selection);
+        bool  CallbackInfo_setClipboardContent(CallbackInfo* restrict callbackinfo, AzClipboardSelection  selection, AzClipboardContent  content);
         TimerId CallbackInfo_startTimer(CallbackInfo* restrict callbackinfo, AzTimer  timer);
         OptionTimerId CallbackInfo_startAnimation(CallbackInfo* restrict callbackinfo, AzDomNodeId  node, AzAnimation  animation);
         bool  CallbackInfo_stopTimer(CallbackInfo* restrict callbackinfo, AzTimerId  timer_id);
@@ -10232,6 +12618,8 @@ namespace dll {
         PhysicalSizeU32 HidpiAdjustedBounds_getPhysicalSize(const HidpiAdjustedBounds* hidpiadjustedbounds);
         float HidpiAdjustedBounds_getHidpiFactor(const HidpiAdjustedBounds* hidpiadjustedbounds);
         InlineTextHitVec InlineText_hitTest(const InlineText* inlinetext, AzLogicalPosition  position);
+        OptionLogicalRect InlineText_getCursorRect(const InlineText* inlinetext, size_t char_index);
+        LogicalRectVec InlineText_getSelectionRects(const InlineText* inlinetext, size_t start, size_t end);
         void InlineText_delete(InlineText* restrict instance);
         void InlineLine_delete(InlineLine* restrict instance);
         void InlineWord_delete(InlineWord* restrict instance);
@@ -10320,10 +12708,14 @@ namespace dll {
         Dom Dom_withTabIndex(Dom* restrict dom, AzTabIndex  tab_index);
         void Dom_setAccessibilityInfo(Dom* restrict dom, AzAccessibilityInfo  accessibility_info);
         Dom Dom_withAccessibilityInfo(Dom* restrict dom, AzAccessibilityInfo  accessibility_info);
+        void Dom_setAccessibilityActions(Dom* restrict dom, AzAccessibilityActionVec  actions);
+        Dom Dom_withAccessibilityActions(Dom* restrict dom, AzAccessibilityActionVec  actions);
         void Dom_setMenuBar(Dom* restrict dom, AzMenu  menu_bar);
         Dom Dom_withMenuBar(Dom* restrict dom, AzMenu  menu_bar);
         void Dom_setContextMenu(Dom* restrict dom, AzMenu  context_menu);
         Dom Dom_withContextMenu(Dom* restrict dom, AzMenu  context_menu);
+        void Dom_setTooltip(Dom* restrict dom, AzTooltip  tooltip);
+        Dom Dom_withTooltip(Dom* restrict dom, AzTooltip  tooltip);
         uint64_t Dom_hash(const Dom* dom);
         size_t Dom_nodeCount(const Dom* dom);
         String Dom_getHtmlString(Dom* restrict dom);
@@ -10362,13 +12754,24 @@ namespace dll {
         void NodeData_setClipMask(NodeData* restrict nodedata, AzImageMask  image_mask);
         void NodeData_setTabIndex(NodeData* restrict nodedata, AzTabIndex  tab_index);
         void NodeData_setAccessibilityInfo(NodeData* restrict nodedata, AzAccessibilityInfo  accessibility_info);
+        void NodeData_setAccessibilityActions(NodeData* restrict nodedata, AzAccessibilityActionVec  actions);
+        void NodeData_addAccessibilityAction(NodeData* restrict nodedata, AzAccessibilityAction  action);
+        NodeData NodeData_withAccessibilityActions(NodeData* restrict nodedata, AzAccessibilityActionVec  actions);
         void NodeData_setMenuBar(NodeData* restrict nodedata, AzMenu  menu_bar);
         void NodeData_setContextMenu(NodeData* restrict nodedata, AzMenu  context_menu);
+        void NodeData_setTooltip(NodeData* restrict nodedata, AzTooltip  tooltip);
         uint64_t NodeData_hash(const NodeData* nodedata);
         void NodeData_delete(NodeData* restrict instance);
         void NodeType_delete(NodeType* restrict instance);
         EventFilter On_intoEventFilter(const On on);
         void AccessibilityInfo_delete(AccessibilityInfo* restrict instance);
+        Tooltip Tooltip_text(AzString  text);
+        Tooltip Tooltip_dom(AzRefAny  data, AzTooltipCallbackType  callback);
+        void Tooltip_setDelay(Tooltip* restrict tooltip, AzDuration  delay);
+        Tooltip Tooltip_withDelay(Tooltip* restrict tooltip, AzDuration  delay);
+        void Tooltip_delete(Tooltip* restrict instance);
+        void TooltipContent_delete(TooltipContent* restrict instance);
+        void TooltipDom_delete(TooltipDom* restrict instance);
         void IdOrClass_delete(IdOrClass* restrict instance);
         void NodeDataInlineCssProperty_delete(NodeDataInlineCssProperty* restrict instance);
         Menu Menu_new(AzMenuItemVec  items);
@@ -10394,6 +12797,10 @@ namespace dll {
         void CssPath_delete(CssPath* restrict instance);
         void CssPathSelector_delete(CssPathSelector* restrict instance);
         void Stylesheet_delete(Stylesheet* restrict instance);
+        void CssKeyframes_delete(CssKeyframes* restrict instance);
+        void CssKeyframe_delete(CssKeyframe* restrict instance);
+        void CssMediaRule_delete(CssMediaRule* restrict instance);
+        void CssMediaQuery_delete(CssMediaQuery* restrict instance);
         Css Css_empty();
         Css Css_fromString(AzString  s);
         void Css_delete(Css* restrict instance);
@@ -10410,6 +12817,16 @@ namespace dll {
         void ScrollbarInfo_delete(ScrollbarInfo* restrict instance);
         void ScrollbarStyle_delete(ScrollbarStyle* restrict instance);
         void StyleFontFamily_delete(StyleFontFamily* restrict instance);
+        void StyleAnimationName_delete(StyleAnimationName* restrict instance);
+        void GridTrackSizingVecValue_delete(GridTrackSizingVecValue* restrict instance);
+        void StyleTransitionPropertyVecValue_delete(StyleTransitionPropertyVecValue* restrict instance);
+        void StyleTimeVecValue_delete(StyleTimeVecValue* restrict instance);
+        void StyleTimingFunctionVecValue_delete(StyleTimingFunctionVecValue* restrict instance);
+        void StyleAnimationNameVecValue_delete(StyleAnimationNameVecValue* restrict instance);
+        void StyleAnimationIterationCountVecValue_delete(StyleAnimationIterationCountVecValue* restrict instance);
+        void StyleAnimationDirectionVecValue_delete(StyleAnimationDirectionVecValue* restrict instance);
+        void StyleAnimationFillModeVecValue_delete(StyleAnimationFillModeVecValue* restrict instance);
+        void StyleAnimationPlayStateVecValue_delete(StyleAnimationPlayStateVecValue* restrict instance);
         void ScrollbarStyleValue_delete(ScrollbarStyleValue* restrict instance);
         void StyleBackgroundContentVecValue_delete(StyleBackgroundContentVecValue* restrict instance);
         void StyleBackgroundPositionVecValue_delete(StyleBackgroundPositionVecValue* restrict instance);
@@ -10423,7 +12840,41 @@ namespace dll {
         String CssProperty_getKeyValueString(const CssProperty* cssproperty);
         CssProperty CssProperty_interpolate(const CssProperty* cssproperty, AzCssProperty  other, float t, AzInterpolateContext  context);
         void CssProperty_delete(CssProperty* restrict instance);
-        Dom Ribbon_dom(Ribbon* restrict ribbon, AzRibbonOnTabClickedCallback  callback, AzRefAny  data);
+        Ribbon Ribbon_new(AzRibbonTabVec  tabs);
+        void Ribbon_setTabActive(Ribbon* restrict ribbon, int32_t tab_active);
+        Ribbon Ribbon_withTabActive(Ribbon* restrict ribbon, int32_t tab_active);
+        void Ribbon_setAvailableWidth(Ribbon* restrict ribbon, float available_width);
+        Ribbon Ribbon_withAvailableWidth(Ribbon* restrict ribbon, float available_width);
+        void Ribbon_setOnTabClicked(Ribbon* restrict ribbon, AzRefAny  data, AzRibbonOnTabClickedCallbackType  callback);
+        Ribbon Ribbon_withOnTabClicked(Ribbon* restrict ribbon, AzRefAny  data, AzRibbonOnTabClickedCallbackType  callback);
+        Dom Ribbon_dom(Ribbon* restrict ribbon);
+        void Ribbon_delete(Ribbon* restrict instance);
+        RibbonTab RibbonTab_new(AzString  label, AzRibbonGroupVec  groups);
+        void RibbonTab_setKeyTip(RibbonTab* restrict ribbontab, AzString  key_tip);
+        RibbonTab RibbonTab_withKeyTip(RibbonTab* restrict ribbontab, AzString  key_tip);
+        void RibbonTab_delete(RibbonTab* restrict instance);
+        RibbonGroup RibbonGroup_new(AzString  label, AzRibbonItemVec  items);
+        void RibbonGroup_setIcon(RibbonGroup* restrict ribbongroup, AzImageRef  icon);
+        RibbonGroup RibbonGroup_withIcon(RibbonGroup* restrict ribbongroup, AzImageRef  icon);
+        void RibbonGroup_delete(RibbonGroup* restrict instance);
+        RibbonItem RibbonItem_new(AzRibbonItemKind  kind, AzString  label);
+        void RibbonItem_setIcon(RibbonItem* restrict ribbonitem, AzImageRef  icon);
+        RibbonItem RibbonItem_withIcon(RibbonItem* restrict ribbonitem, AzImageRef  icon);
+        void RibbonItem_setTooltip(RibbonItem* restrict ribbonitem, AzString  tooltip);
+        RibbonItem RibbonItem_withTooltip(RibbonItem* restrict ribbonitem, AzString  tooltip);
+        void RibbonItem_setKeyTip(RibbonItem* restrict ribbonitem, AzString  key_tip);
+        RibbonItem RibbonItem_withKeyTip(RibbonItem* restrict ribbonitem, AzString  key_tip);
+        void RibbonItem_setEnabled(RibbonItem* restrict ribbonitem, bool  enabled);
+        RibbonItem RibbonItem_withEnabled(RibbonItem* restrict ribbonitem, bool  enabled);
+        void RibbonItem_setToggled(RibbonItem* restrict ribbonitem, bool  toggled);
+        RibbonItem RibbonItem_withToggled(RibbonItem* restrict ribbonitem, bool  toggled);
+        void RibbonItem_setChoices(RibbonItem* restrict ribbonitem, AzStringVec  choices);
+        RibbonItem RibbonItem_withChoices(RibbonItem* restrict ribbonitem, AzStringVec  choices);
+        void RibbonItem_setOnClick(RibbonItem* restrict ribbonitem, AzRefAny  data, AzRibbonOnItemClickCallbackType  callback);
+        RibbonItem RibbonItem_withOnClick(RibbonItem* restrict ribbonitem, AzRefAny  data, AzRibbonOnItemClickCallbackType  callback);
+        void RibbonItem_delete(RibbonItem* restrict instance);
+        void RibbonOnItemClick_delete(RibbonOnItemClick* restrict instance);
+        void RibbonOnTabClicked_delete(RibbonOnTabClicked* restrict instance);
         Button Button_new(AzString  label);
         void Button_setOnClick(Button* restrict button, AzRefAny  data, AzCallbackType  callback);
         Button Button_withOnClick(Button* restrict button, AzRefAny  data, AzCallbackType  callback);
@@ -10482,6 +12933,27 @@ namespace dll {
         void TextInputOnTextInput_delete(TextInputOnTextInput* restrict instance);
         void TextInputOnVirtualKeyDown_delete(TextInputOnVirtualKeyDown* restrict instance);
         void TextInputOnFocusLost_delete(TextInputOnFocusLost* restrict instance);
+        TextArea TextArea_new();
+        void TextArea_setText(TextArea* restrict textarea, AzString  text);
+        TextArea TextArea_withText(TextArea* restrict textarea, AzString  text);
+        void TextArea_setState(TextArea* restrict textarea, AzTextAreaState  state);
+        TextArea TextArea_withState(TextArea* restrict textarea, AzTextAreaState  state);
+        void TextArea_setOnTextChange(TextArea* restrict textarea, AzRefAny  data, AzTextAreaOnTextChangeCallbackType  callback);
+        TextArea TextArea_withOnTextChange(TextArea* restrict textarea, AzRefAny  data, AzTextAreaOnTextChangeCallbackType  callback);
+        TextArea TextArea_swapWithDefault(TextArea* restrict textarea);
+        Dom TextArea_dom(TextArea* restrict textarea);
+        void TextArea_delete(TextArea* restrict instance);
+        String TextAreaState_getText(const TextAreaState* textareastate);
+        void TextAreaState_setText(TextAreaState* restrict textareastate, AzString  text);
+        String TextAreaState_getSelectedText(const TextAreaState* textareastate);
+        void TextAreaState_selectAll(TextAreaState* restrict textareastate);
+        bool  TextAreaState_canUndo(const TextAreaState* textareastate);
+        bool  TextAreaState_canRedo(const TextAreaState* textareastate);
+        bool  TextAreaState_undo(TextAreaState* restrict textareastate);
+        bool  TextAreaState_redo(TextAreaState* restrict textareastate);
+        void TextAreaState_delete(TextAreaState* restrict instance);
+        void TextAreaEdit_delete(TextAreaEdit* restrict instance);
+        void TextAreaOnTextChange_delete(TextAreaOnTextChange* restrict instance);
         NumberInput NumberInput_new(float number);
         void NumberInput_setOnTextInput(NumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
         NumberInput NumberInput_withOnTextInput(NumberInput* restrict numberinput, AzRefAny  data, AzTextInputOnTextInputCallbackType  callback);
@@ -10503,6 +12975,7 @@ namespace dll {
         void NumberInputOnValueChange_delete(NumberInputOnValueChange* restrict instance);
         void NumberInputOnFocusLost_delete(NumberInputOnFocusLost* restrict instance);
         ProgressBar ProgressBar_new(float percent_done);
+        ProgressBar ProgressBar_indeterminate();
         void ProgressBar_setHeight(ProgressBar* restrict progressbar, AzPixelValue  height);
         ProgressBar ProgressBar_withHeight(ProgressBar* restrict progressbar, AzPixelValue  height);
         void ProgressBar_setContainerBackground(ProgressBar* restrict progressbar, AzStyleBackgroundContentVec  background);
@@ -10510,12 +12983,36 @@ namespace dll {
         void ProgressBar_setBarBackground(ProgressBar* restrict progressbar, AzStyleBackgroundContentVec  background);
         ProgressBar ProgressBar_withBarBackground(ProgressBar* restrict progressbar, AzStyleBackgroundContentVec  background);
         Dom ProgressBar_dom(ProgressBar* restrict progressbar);
+        void ProgressBar_setMode(ProgressBar* restrict progressbar, AzProgressBarMode  mode);
+        ProgressBar ProgressBar_withMode(ProgressBar* restrict progressbar, AzProgressBarMode  mode);
+        void ProgressBar_setStyle(ProgressBar* restrict progressbar, AzProgressBarStyle  style);
+        ProgressBar ProgressBar_withStyle(ProgressBar* restrict progressbar, AzProgressBarStyle  style);
+        void ProgressBar_setDisplayPercentage(ProgressBar* restrict progressbar, bool  display_percentage);
+        ProgressBar ProgressBar_withDisplayPercentage(ProgressBar* restrict progressbar, bool  display_percentage);
+        void ProgressBar_setTextFormat(ProgressBar* restrict progressbar, AzString  text_format);
+        ProgressBar ProgressBar_withTextFormat(ProgressBar* restrict progressbar, AzString  text_format);
         void ProgressBar_delete(ProgressBar* restrict instance);
+        Spinner Spinner_new();
+        void Spinner_setSize(Spinner* restrict spinner, AzPixelValue  size);
+        Spinner Spinner_withSize(Spinner* restrict spinner, AzPixelValue  size);
+        void Spinner_setThickness(Spinner* restrict spinner, AzPixelValue  thickness);
+        Spinner Spinner_withThickness(Spinner* restrict spinner, AzPixelValue  thickness);
+        void Spinner_setColor(Spinner* restrict spinner, AzColorU  color);
+        Spinner Spinner_withColor(Spinner* restrict spinner, AzColorU  color);
+        void Spinner_setTrackColor(Spinner* restrict spinner, AzColorU  track_color);
+        Spinner Spinner_withTrackColor(Spinner* restrict spinner, AzColorU  track_color);
+        Dom Spinner_dom(Spinner* restrict spinner);
         TabHeader TabHeader_new(AzStringVec  tabs);
         void TabHeader_setActiveTab(TabHeader* restrict tabheader, size_t active_tab);
         TabHeader TabHeader_withActiveTab(TabHeader* restrict tabheader, size_t active_tab);
         void TabHeader_setOnClick(TabHeader* restrict tabheader, AzRefAny  data, AzTabOnClickCallbackType  callback);
         TabHeader TabHeader_withOnClick(TabHeader* restrict tabheader, AzRefAny  data, AzTabOnClickCallbackType  callback);
+        void TabHeader_setOnClose(TabHeader* restrict tabheader, AzRefAny  data, AzTabOnCloseCallbackType  callback);
+        TabHeader TabHeader_withOnClose(TabHeader* restrict tabheader, AzRefAny  data, AzTabOnCloseCallbackType  callback);
+        void TabHeader_setOnReorder(TabHeader* restrict tabheader, AzRefAny  data, AzTabOnReorderCallbackType  callback);
+        TabHeader TabHeader_withOnReorder(TabHeader* restrict tabheader, AzRefAny  data, AzTabOnReorderCallbackType  callback);
+        void TabHeader_setOnContextMenu(TabHeader* restrict tabheader, AzRefAny  data, AzTabOnContextMenuCallbackType  callback);
+        TabHeader TabHeader_withOnContextMenu(TabHeader* restrict tabheader, AzRefAny  data, AzTabOnContextMenuCallbackType  callback);
         Dom TabHeader_dom(TabHeader* restrict tabheader);
         void TabHeader_delete(TabHeader* restrict instance);
         TabContent TabContent_new(AzDom  content);
@@ -10524,11 +13021,53 @@ namespace dll {
         Dom TabContent_dom(TabContent* restrict tabcontent);
         void TabContent_delete(TabContent* restrict instance);
         void TabOnClick_delete(TabOnClick* restrict instance);
+        void TabOnClose_delete(TabOnClose* restrict instance);
+        void TabOnReorder_delete(TabOnReorder* restrict instance);
+        void TabOnContextMenu_delete(TabOnContextMenu* restrict instance);
         Frame Frame_new(AzString  title, AzDom  dom);
         void Frame_setFlexGrow(Frame* restrict frame, float flex_grow);
         Frame Frame_withFlexGrow(Frame* restrict frame, float flex_grow);
         Dom Frame_dom(Frame* restrict frame);
         void Frame_delete(Frame* restrict instance);
+        Splitter Splitter_new(AzSplitterOrientation  orientation, AzSplitterPaneVec  panes);
+        void Splitter_setState(Splitter* restrict splitter, AzSplitterState  state);
+        Splitter Splitter_withState(Splitter* restrict splitter, AzSplitterState  state);
+        void Splitter_setOnResize(Splitter* restrict splitter, AzRefAny  data, AzSplitterOnResizeCallbackType  callback);
+        Splitter Splitter_withOnResize(Splitter* restrict splitter, AzRefAny  data, AzSplitterOnResizeCallbackType  callback);
+        Dom Splitter_dom(Splitter* restrict splitter);
+        void Splitter_delete(Splitter* restrict instance);
+        SplitterPane SplitterPane_new(AzDom  content);
+        void SplitterPane_setSize(SplitterPane* restrict splitterpane, float size);
+        SplitterPane SplitterPane_withSize(SplitterPane* restrict splitterpane, float size);
+        void SplitterPane_setMinSize(SplitterPane* restrict splitterpane, float min_size);
+        SplitterPane SplitterPane_withMinSize(SplitterPane* restrict splitterpane, float min_size);
+        void SplitterPane_setMaxSize(SplitterPane* restrict splitterpane, float max_size);
+        SplitterPane SplitterPane_withMaxSize(SplitterPane* restrict splitterpane, float max_size);
+        void SplitterPane_setCollapsible(SplitterPane* restrict splitterpane, bool  collapsible);
+        SplitterPane SplitterPane_withCollapsible(SplitterPane* restrict splitterpane, bool  collapsible);
+        void SplitterPane_delete(SplitterPane* restrict instance);
+        void SplitterState_delete(SplitterState* restrict instance);
+        void SplitterOnResize_delete(SplitterOnResize* restrict instance);
+        Slider Slider_new(AzSliderOrientation  orientation, float value, float min, float max);
+        void Slider_setStep(Slider* restrict slider, float step);
+        Slider Slider_withStep(Slider* restrict slider, float step);
+        void Slider_setTickInterval(Slider* restrict slider, float tick_interval);
+        Slider Slider_withTickInterval(Slider* restrict slider, float tick_interval);
+        void Slider_setOnValueChange(Slider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+        Slider Slider_withOnValueChange(Slider* restrict slider, AzRefAny  data, AzSliderOnValueChangeCallbackType  callback);
+        Dom Slider_dom(Slider* restrict slider);
+        void Slider_delete(Slider* restrict instance);
+        RangeSlider RangeSlider_new(AzSliderOrientation  orientation, float low, float high, float min, float max);
+        void RangeSlider_setStep(RangeSlider* restrict rangeslider, float step);
+        RangeSlider RangeSlider_withStep(RangeSlider* restrict rangeslider, float step);
+        void RangeSlider_setTickInterval(RangeSlider* restrict rangeslider, float tick_interval);
+        RangeSlider RangeSlider_withTickInterval(RangeSlider* restrict rangeslider, float tick_interval);
+        void RangeSlider_setOnValueChange(RangeSlider* restrict rangeslider, AzRefAny  data, AzRangeSliderOnValueChangeCallbackType  callback);
+        RangeSlider RangeSlider_withOnValueChange(RangeSlider* restrict rangeslider, AzRefAny  data, AzRangeSliderOnValueChangeCallbackType  callback);
+        Dom RangeSlider_dom(RangeSlider* restrict rangeslider);
+        void RangeSlider_delete(RangeSlider* restrict instance);
+        void SliderOnValueChange_delete(SliderOnValueChange* restrict instance);
+        void RangeSliderOnValueChange_delete(RangeSliderOnValueChange* restrict instance);
         Dom NodeGraph_dom(NodeGraph* restrict nodegraph);
         void NodeGraph_delete(NodeGraph* restrict instance);
         void NodeTypeIdInfoMap_delete(NodeTypeIdInfoMap* restrict instance);
@@ -10552,6 +13091,24 @@ namespace dll {
         void InputOutputInfo_delete(InputOutputInfo* restrict instance);
         ListView ListView_new(AzStringVec  columns);
         ListView ListView_withRows(ListView* restrict listview, AzListViewRowVec  rows);
+        void ListView_setSortedBy(ListView* restrict listview, AzOptionUsize  sorted_by);
+        ListView ListView_withSortedBy(ListView* restrict listview, AzOptionUsize  sorted_by);
+        void ListView_setScrollOffset(ListView* restrict listview, AzPixelValueNoPercent  scroll_offset);
+        ListView ListView_withScrollOffset(ListView* restrict listview, AzPixelValueNoPercent  scroll_offset);
+        void ListView_setRowHeight(ListView* restrict listview, AzPixelValueNoPercent  row_height);
+        ListView ListView_withRowHeight(ListView* restrict listview, AzPixelValueNoPercent  row_height);
+        void ListView_setColumnWidths(ListView* restrict listview, AzF32Vec  column_widths);
+        ListView ListView_withColumnWidths(ListView* restrict listview, AzF32Vec  column_widths);
+        void ListView_setRowProvider(ListView* restrict listview, size_t row_count, AzRefAny  data, AzListViewRowProviderCallbackType  callback);
+        ListView ListView_withRowProvider(ListView* restrict listview, size_t row_count, AzRefAny  data, AzListViewRowProviderCallbackType  callback);
+        void ListView_setOnLazyLoadScroll(ListView* restrict listview, AzRefAny  data, AzListViewOnLazyLoadScrollCallbackType  callback);
+        ListView ListView_withOnLazyLoadScroll(ListView* restrict listview, AzRefAny  data, AzListViewOnLazyLoadScrollCallbackType  callback);
+        void ListView_setOnColumnClick(ListView* restrict listview, AzRefAny  data, AzListViewOnColumnClickCallbackType  callback);
+        ListView ListView_withOnColumnClick(ListView* restrict listview, AzRefAny  data, AzListViewOnColumnClickCallbackType  callback);
+        void ListView_setOnColumnResize(ListView* restrict listview, AzRefAny  data, AzListViewOnColumnResizeCallbackType  callback);
+        ListView ListView_withOnColumnResize(ListView* restrict listview, AzRefAny  data, AzListViewOnColumnResizeCallbackType  callback);
+        void ListView_setOnRowClick(ListView* restrict listview, AzRefAny  data, AzListViewOnRowClickCallbackType  callback);
+        ListView ListView_withOnRowClick(ListView* restrict listview, AzRefAny  data, AzListViewOnRowClickCallbackType  callback);
         Dom ListView_dom(ListView* restrict listview);
         void ListView_delete(ListView* restrict instance);
         void ListViewRow_delete(ListViewRow* restrict instance);
@@ -10559,10 +13116,40 @@ namespace dll {
         void ListViewOnLazyLoadScroll_delete(ListViewOnLazyLoadScroll* restrict instance);
         void ListViewOnColumnClick_delete(ListViewOnColumnClick* restrict instance);
         void ListViewOnRowClick_delete(ListViewOnRowClick* restrict instance);
-        TreeView TreeView_new(AzString  root);
+        void ListViewOnColumnResize_delete(ListViewOnColumnResize* restrict instance);
+        void ListViewRowProvider_delete(ListViewRowProvider* restrict instance);
+        TreeView TreeView_new(AzTreeViewNodeVec  nodes);
+        void TreeView_setNodes(TreeView* restrict treeview, AzTreeViewNodeVec  nodes);
+        TreeView TreeView_withNodes(TreeView* restrict treeview, AzTreeViewNodeVec  nodes);
+        void TreeView_setState(TreeView* restrict treeview, AzTreeViewState  state);
+        TreeView TreeView_withState(TreeView* restrict treeview, AzTreeViewState  state);
+        void TreeView_setSelectionMode(TreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+        TreeView TreeView_withSelectionMode(TreeView* restrict treeview, AzTreeViewSelectionMode  selection_mode);
+        void TreeView_setOnExpand(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnExpandCallbackType  callback);
+        TreeView TreeView_withOnExpand(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnExpandCallbackType  callback);
+        void TreeView_setOnSelect(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectCallbackType  callback);
+        TreeView TreeView_withOnSelect(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnSelectCallbackType  callback);
+        void TreeView_setOnDoubleClick(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnDoubleClickCallbackType  callback);
+        TreeView TreeView_withOnDoubleClick(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnDoubleClickCallbackType  callback);
+        void TreeView_setOnContextMenu(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnContextMenuCallbackType  callback);
+        TreeView TreeView_withOnContextMenu(TreeView* restrict treeview, AzRefAny  data, AzTreeViewOnContextMenuCallbackType  callback);
         Dom TreeView_dom(TreeView* restrict treeview);
         void TreeView_delete(TreeView* restrict instance);
+        TreeViewNode TreeViewNode_new(AzTreeViewNodeId  id, AzOptionTreeViewNodeId  parent, AzString  label);
+        void TreeViewNode_delete(TreeViewNode* restrict instance);
+        void TreeViewState_delete(TreeViewState* restrict instance);
+        void TreeViewOnExpand_delete(TreeViewOnExpand* restrict instance);
+        void TreeViewOnSelect_delete(TreeViewOnSelect* restrict instance);
+        void TreeViewOnDoubleClick_delete(TreeViewOnDoubleClick* restrict instance);
+        void TreeViewOnContextMenu_delete(TreeViewOnContextMenu* restrict instance);
         DropDown DropDown_new(AzStringVec  choices);
+        void DropDown_setSelected(DropDown* restrict dropdown, size_t selected);
+        DropDown DropDown_withSelected(DropDown* restrict dropdown, size_t selected);
+        void DropDown_setDisabledChoices(DropDown* restrict dropdown, AzU32Vec  disabled_choices);
+        DropDown DropDown_withDisabledChoices(DropDown* restrict dropdown, AzU32Vec  disabled_choices);
+        void DropDown_setOnChoiceChange(DropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+        DropDown DropDown_withOnChoiceChange(DropDown* restrict dropdown, AzRefAny  data, AzDropDownOnChoiceChangeCallbackType  callback);
+        DropDown DropDown_swapWithDefault(DropDown* restrict dropdown);
         Dom DropDown_dom(DropDown* restrict dropdown);
         void DropDown_delete(DropDown* restrict instance);
         void DropDownOnChoiceChange_delete(DropDownOnChoiceChange* restrict instance);
@@ -11043,6 +13630,14 @@ namespace dll {
         Refstr String_asRefstr(const String* string);
         void String_delete(String* restrict instance);
         void ListViewRowVec_delete(ListViewRowVec* restrict instance);
+        void TreeViewNodeVec_delete(TreeViewNodeVec* restrict instance);
+        void TreeViewNodeIdVec_delete(TreeViewNodeIdVec* restrict instance);
+        void RibbonTabVec_delete(RibbonTabVec* restrict instance);
+        void RibbonGroupVec_delete(RibbonGroupVec* restrict instance);
+        void RibbonItemVec_delete(RibbonItemVec* restrict instance);
+        void SplitterPaneVec_delete(SplitterPaneVec* restrict instance);
+        void SplitterPaneStateVec_delete(SplitterPaneStateVec* restrict instance);
+        void TextAreaEditVec_delete(TextAreaEditVec* restrict instance);
         void StyleFilterVec_delete(StyleFilterVec* restrict instance);
         void LogicalRectVec_delete(LogicalRectVec* restrict instance);
         void NodeTypeIdInfoMapVec_delete(NodeTypeIdInfoMapVec* restrict instance);
@@ -11055,6 +13650,7 @@ namespace dll {
         void OutputConnectionVec_delete(OutputConnectionVec* restrict instance);
         void InputNodeAndIndexVec_delete(InputNodeAndIndexVec* restrict instance);
         void AccessibilityStateVec_delete(AccessibilityStateVec* restrict instance);
+        void AccessibilityActionVec_delete(AccessibilityActionVec* restrict instance);
         void MenuItemVec_delete(MenuItemVec* restrict instance);
         TessellatedSvgNodeVecRef TessellatedSvgNodeVec_asRefVec(const TessellatedSvgNodeVec* tessellatedsvgnodevec);
         void TessellatedSvgNodeVec_delete(TessellatedSvgNodeVec* restrict instance);
@@ -11075,6 +13671,15 @@ namespace dll {
         void StyleBackgroundRepeatVec_delete(StyleBackgroundRepeatVec* restrict instance);
         void StyleBackgroundSizeVec_delete(StyleBackgroundSizeVec* restrict instance);
         void StyleTransformVec_delete(StyleTransformVec* restrict instance);
+        void StyleTransitionPropertyVec_delete(StyleTransitionPropertyVec* restrict instance);
+        void StyleTimeVec_delete(StyleTimeVec* restrict instance);
+        void StyleTimingFunctionVec_delete(StyleTimingFunctionVec* restrict instance);
+        void StyleAnimationNameVec_delete(StyleAnimationNameVec* restrict instance);
+        void StyleAnimationIterationCountVec_delete(StyleAnimationIterationCountVec* restrict instance);
+        void StyleAnimationDirectionVec_delete(StyleAnimationDirectionVec* restrict instance);
+        void StyleAnimationFillModeVec_delete(StyleAnimationFillModeVec* restrict instance);
+        void StyleAnimationPlayStateVec_delete(StyleAnimationPlayStateVec* restrict instance);
+        void GridTrackSizingVec_delete(GridTrackSizingVec* restrict instance);
         void CssPropertyVec_delete(CssPropertyVec* restrict instance);
         void SvgMultiPolygonVec_delete(SvgMultiPolygonVec* restrict instance);
         void SvgPathVec_delete(SvgPathVec* restrict instance);
@@ -11089,6 +13694,11 @@ namespace dll {
         void CssDeclarationVec_delete(CssDeclarationVec* restrict instance);
         void CssPathSelectorVec_delete(CssPathSelectorVec* restrict instance);
         void StylesheetVec_delete(StylesheetVec* restrict instance);
+        void CssKeyframesVec_delete(CssKeyframesVec* restrict instance);
+        void CssKeyframeVec_delete(CssKeyframeVec* restrict instance);
+        void CssMediaRuleVec_delete(CssMediaRuleVec* restrict instance);
+        void CssMediaQueryVec_delete(CssMediaQueryVec* restrict instance);
+        void CssMediaFeatureVec_delete(CssMediaFeatureVec* restrict instance);
         void CssRuleBlockVec_delete(CssRuleBlockVec* restrict instance);
         void U16Vec_delete(U16Vec* restrict instance);
         void F32Vec_delete(F32Vec* restrict instance);
@@ -11110,8 +13720,14 @@ namespace dll {
         void ParentWithNodeDepthVec_delete(ParentWithNodeDepthVec* restrict instance);
         void NodeDataVec_delete(NodeDataVec* restrict instance);
         void OptionListViewOnRowClick_delete(OptionListViewOnRowClick* restrict instance);
+        void OptionTreeViewOnExpand_delete(OptionTreeViewOnExpand* restrict instance);
+        void OptionTreeViewOnSelect_delete(OptionTreeViewOnSelect* restrict instance);
+        void OptionTreeViewOnDoubleClick_delete(OptionTreeViewOnDoubleClick* restrict instance);
+        void OptionTreeViewOnContextMenu_delete(OptionTreeViewOnContextMenu* restrict instance);
         void OptionListViewOnColumnClick_delete(OptionListViewOnColumnClick* restrict instance);
         void OptionListViewOnLazyLoadScroll_delete(OptionListViewOnLazyLoadScroll* restrict instance);
+        void OptionListViewOnColumnResize_delete(OptionListViewOnColumnResize* restrict instance);
+        void OptionListViewRowProvider_delete(OptionListViewRowProvider* restrict instance);
         void OptionMenu_delete(OptionMenu* restrict instance);
         void OptionDropDownOnChoiceChange_delete(OptionDropDownOnChoiceChange* restrict instance);
         void OptionResolvedTextLayoutOptions_delete(OptionResolvedTextLayoutOptions* restrict instance);
@@ -11126,11 +13742,20 @@ namespace dll {
         void OptionColorInputOnValueChange_delete(OptionColorInputOnValueChange* restrict instance);
         void OptionButtonOnClick_delete(OptionButtonOnClick* restrict instance);
         void OptionTabOnClick_delete(OptionTabOnClick* restrict instance);
+        void OptionTabOnClose_delete(OptionTabOnClose* restrict instance);
+        void OptionTabOnReorder_delete(OptionTabOnReorder* restrict instance);
+        void OptionTabOnContextMenu_delete(OptionTabOnContextMenu* restrict instance);
+        void OptionRibbonOnTabClicked_delete(OptionRibbonOnTabClicked* restrict instance);
+        void OptionRibbonOnItemClick_delete(OptionRibbonOnItemClick* restrict instance);
+        void OptionSplitterOnResize_delete(OptionSplitterOnResize* restrict instance);
+        void OptionSliderOnValueChange_delete(OptionSliderOnValueChange* restrict instance);
+        void OptionRangeSliderOnValueChange_delete(OptionRangeSliderOnValueChange* restrict instance);
         void OptionFileInputOnPathChange_delete(OptionFileInputOnPathChange* restrict instance);
         void OptionCheckBoxOnToggle_delete(OptionCheckBoxOnToggle* restrict instance);
         void OptionTextInputOnTextInput_delete(OptionTextInputOnTextInput* restrict instance);
         void OptionTextInputOnVirtualKeyDown_delete(OptionTextInputOnVirtualKeyDown* restrict instance);
         void OptionTextInputOnFocusLost_delete(OptionTextInputOnFocusLost* restrict instance);
+        void OptionTextAreaOnTextChange_delete(OptionTextAreaOnTextChange* restrict instance);
         void OptionNumberInputOnFocusLost_delete(OptionNumberInputOnFocusLost* restrict instance);
         void OptionNumberInputOnValueChange_delete(OptionNumberInputOnValueChange* restrict instance);
         void OptionMenuItemIcon_delete(OptionMenuItemIcon* restrict instance);
@@ -11153,6 +13778,8 @@ namespace dll {
         void OptionRawImage_delete(OptionRawImage* restrict instance);
         void OptionWaylandTheme_delete(OptionWaylandTheme* restrict instance);
         void OptionTaskBarIcon_delete(OptionTaskBarIcon* restrict instance);
+        void OptionImeComposition_delete(OptionImeComposition* restrict instance);
+        void OptionClipboardContent_delete(OptionClipboardContent* restrict instance);
         void OptionWindowIcon_delete(OptionWindowIcon* restrict instance);
         void OptionString_delete(OptionString* restrict instance);
         void OptionDom_delete(OptionDom* restrict instance);
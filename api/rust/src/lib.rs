@@ -861,6 +861,29 @@ mod dll {
         pub a_native_window: *mut c_void,
     }
 
+    /// Which system clipboard to read from / write to
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzClipboardSelection {
+        Clipboard,
+        Primary,
+    }
+
+    /// Which windowing system the window should be created with on Linux
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLinuxBackend {
+        Auto,
+        X11,
+        Wayland,
+    }
+
     /// X11 window hint: Type of window
     #[repr(C)]
     #[derive(Debug)]
@@ -1405,6 +1428,17 @@ mod dll {
     /// `AzIFrameCallbackType` struct
     pub type AzIFrameCallbackType = extern "C" fn(&mut AzRefAny, &mut AzIFrameCallbackInfo) -> AzIFrameCallbackReturn;
 
+    /// C-ABI wrapper over a `TooltipCallbackType`
+    #[repr(C)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub struct AzTooltipCallback {
+        pub cb: AzTooltipCallbackType,
+    }
+
+    /// `AzTooltipCallbackType` struct
+    pub type AzTooltipCallbackType = extern "C" fn(&mut AzRefAny, &mut AzLayoutCallbackInfo) -> AzStyledDom;
+
     /// Re-export of rust-allocated (stack based) `RenderImageCallback` struct
     #[repr(C)]
     #[derive(Clone)]
@@ -1481,6 +1515,9 @@ mod dll {
         TextInput,
         VirtualKeyDown,
         VirtualKeyUp,
+        CompositionStart,
+        CompositionUpdate,
+        CompositionEnd,
         HoveredFile,
         DroppedFile,
         HoveredFileCancelled,
@@ -1512,6 +1549,9 @@ mod dll {
         TextInput,
         VirtualKeyDown,
         VirtualKeyUp,
+        CompositionStart,
+        CompositionUpdate,
+        CompositionEnd,
         HoveredFile,
         DroppedFile,
         HoveredFileCancelled,
@@ -1545,6 +1585,9 @@ mod dll {
         TextInput,
         VirtualKeyDown,
         VirtualKeyUp,
+        CompositionStart,
+        CompositionUpdate,
+        CompositionEnd,
         FocusReceived,
         FocusLost,
     }
@@ -1573,6 +1616,9 @@ mod dll {
         TextInput,
         VirtualKeyDown,
         VirtualKeyUp,
+        CompositionStart,
+        CompositionUpdate,
+        CompositionEnd,
         HoveredFile,
         DroppedFile,
         HoveredFileCancelled,
@@ -1709,6 +1755,23 @@ mod dll {
         Protected,
     }
 
+    /// Action that an assistive technology (screen reader, voice control) can invoke on a node. See `NodeData::set_accessibility_actions`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzAccessibilityAction {
+        Default,
+        Focus,
+        Toggle,
+        Expand,
+        Collapse,
+        Select,
+        Increment,
+        Decrement,
+    }
+
     /// Re-export of rust-allocated (stack based) `TabIndex` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -1789,6 +1852,40 @@ mod dll {
         pub offset: u32,
     }
 
+    /// Media type of a `CssMediaQuery`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCssMediaType {
+        All,
+        Screen,
+        Print,
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssMediaOrientation` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCssMediaOrientation {
+        Portrait,
+        Landscape,
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssColorScheme` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCssColorScheme {
+        Light,
+        Dark,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -1800,6 +1897,10 @@ mod dll {
         FontSize,
         FontFamily,
         TextAlign,
+        Direction,
+        WordBreak,
+        OverflowWrap,
+        Hyphens,
         LetterSpacing,
         LineHeight,
         WordSpacing,
@@ -1826,6 +1927,15 @@ mod dll {
         JustifyContent,
         AlignItems,
         AlignContent,
+        GridTemplateColumns,
+        GridTemplateRows,
+        GridAutoColumns,
+        GridAutoRows,
+        GridAutoFlow,
+        GridColumn,
+        GridRow,
+        RowGap,
+        ColumnGap,
         BackgroundContent,
         BackgroundPosition,
         BackgroundSize,
@@ -1870,6 +1980,43 @@ mod dll {
         Filter,
         BackdropFilter,
         TextShadow,
+        TransitionProperty,
+        TransitionDuration,
+        TransitionTimingFunction,
+        TransitionDelay,
+        AnimationName,
+        AnimationDuration,
+        AnimationTimingFunction,
+        AnimationDelay,
+        AnimationIterationCount,
+        AnimationDirection,
+        AnimationFillMode,
+        AnimationPlayState,
+    }
+
+    /// Represents a CSS shorthand key that expands to multiple properties, for example `margin`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCombinedCssPropertyType {
+        BorderRadius,
+        Overflow,
+        Margin,
+        Border,
+        BorderLeft,
+        BorderRight,
+        BorderTop,
+        BorderBottom,
+        Padding,
+        BoxShadow,
+        BackgroundColor,
+        BackgroundImage,
+        Gap,
+        GridGap,
+        Transition,
+        Animation,
     }
 
     /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -1963,6 +2110,31 @@ mod dll {
         SpaceAround,
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutGridAutoFlow` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutGridAutoFlow {
+        Row,
+        Column,
+        RowDense,
+        ColumnDense,
+    }
+
+    /// Start or end line of a `grid-column` or `grid-row` attribute
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzGridLine {
+        Auto,
+        Line(i32),
+        Span(u32),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutAlignItems` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -2011,6 +2183,7 @@ mod dll {
         Flex,
         Block,
         InlineBlock,
+        Grid,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -2229,6 +2402,66 @@ mod dll {
         Visible,
     }
 
+    /// Represents a `<time>` value in a `transition-duration`, `transition-delay`, `animation-duration` or `animation-delay` attribute, stored in milliseconds
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzStyleTime {
+        pub ms: i32,
+    }
+
+    /// Position of the jumps in a `steps(n, position)` easing function
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleStepPosition {
+        JumpStart,
+        JumpEnd,
+        JumpNone,
+        JumpBoth,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirection` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationDirection {
+        Normal,
+        Reverse,
+        Alternate,
+        AlternateReverse,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillMode` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationFillMode {
+        None,
+        Forwards,
+        Backwards,
+        Both,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationPlayState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationPlayState {
+        Running,
+        Paused,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -2239,17 +2472,81 @@ mod dll {
         Left,
         Center,
         Right,
+        Start,
+        End,
     }
 
-    /// Re-export of rust-allocated (stack based) `Ribbon` struct
+    /// Base writing direction of a text (`direction: ltr | rtl`)
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzRibbon {
-        pub tab_active: i32,
+    #[derive(Copy)]
+    pub enum AzStyleDirection {
+        Ltr,
+        Rtl,
+    }
+
+    /// Where lines may break inside of words (`word-break: normal | break-all | keep-all`)
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleWordBreak {
+        Normal,
+        BreakAll,
+        KeepAll,
+    }
+
+    /// Whether words that are wider than the line may be broken at arbitrary points (`overflow-wrap: normal | anywhere | break-word`)
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleOverflowWrap {
+        Normal,
+        Anywhere,
+        BreakWord,
+    }
+
+    /// Whether words are hyphenated when breaking lines (`hyphens: none | manual | auto`)
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleHyphens {
+        None,
+        Manual,
+        Auto,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonItemKind` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzRibbonItemKind {
+        LargeButton,
+        SmallButton,
+        Toggle,
+        DropDown,
+        SplitButton,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonOnItemClickCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzRibbonOnItemClickCallback {
+        pub cb: AzRibbonOnItemClickCallbackType,
     }
 
+    /// `AzRibbonOnItemClickCallbackType` struct
+    pub type AzRibbonOnItemClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzRibbonItemState) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `RibbonOnTabClickedCallback` struct
     #[repr(C)]
     #[derive(Clone)]
@@ -2350,6 +2647,28 @@ mod dll {
         No,
     }
 
+    /// What kind of change a `TextAreaEdit` made, consecutive edits of the same kind can be merged into one undo step
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzTextAreaEditKind {
+        Insert,
+        Delete,
+        Replace,
+    }
+
+    /// `AzTextAreaOnTextChangeCallbackType` struct
+    pub type AzTextAreaOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextAreaState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TextAreaOnTextChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTextAreaOnTextChangeCallback {
+        pub cb: AzTextAreaOnTextChangeCallbackType,
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputState` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -2382,14 +2701,26 @@ mod dll {
     /// `AzNumberInputOnFocusLostCallbackType` struct
     pub type AzNumberInputOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzNumberInputState) -> AzUpdate;
 
-    /// Re-export of rust-allocated (stack based) `ProgressBarState` struct
+    /// Re-export of rust-allocated (stack based) `ProgressBarMode` struct
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzProgressBarState {
-        pub percent_done: f32,
-        pub display_percentage: bool,
+    #[derive(Copy)]
+    pub enum AzProgressBarMode {
+        Determinate,
+        Indeterminate,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ProgressBarStyle` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzProgressBarStyle {
+        Solid,
+        Stripes,
     }
 
     /// Re-export of rust-allocated (stack based) `TabHeaderState` struct
@@ -2411,97 +2742,218 @@ mod dll {
     /// `AzTabOnClickCallbackType` struct
     pub type AzTabOnClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTabHeaderState) -> AzUpdate;
 
-    /// Re-export of rust-allocated (stack based) `NodeGraphStyle` struct
+    /// Re-export of rust-allocated (stack based) `TabOnCloseCallback` struct
     #[repr(C)]
-    #[derive(Debug)]
     #[derive(Clone)]
-    #[derive(PartialEq, PartialOrd)]
-    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
-    pub enum AzNodeGraphStyle {
-        Default,
+    pub struct AzTabOnCloseCallback {
+        pub cb: AzTabOnCloseCallbackType,
     }
 
-    /// `AzNodeGraphOnNodeAddedCallbackType` struct
-    pub type AzNodeGraphOnNodeAddedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeTypeId, AzNodeGraphNodeId, AzNodePosition) -> AzUpdate;
+    /// `AzTabOnCloseCallbackType` struct
+    pub type AzTabOnCloseCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
 
-    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeAddedCallback` struct
+    /// Re-export of rust-allocated (stack based) `TabOnReorderCallback` struct
     #[repr(C)]
     #[derive(Clone)]
-    pub struct AzNodeGraphOnNodeAddedCallback {
-        pub cb: AzNodeGraphOnNodeAddedCallbackType,
+    pub struct AzTabOnReorderCallback {
+        pub cb: AzTabOnReorderCallbackType,
     }
 
-    /// `AzNodeGraphOnNodeRemovedCallbackType` struct
-    pub type AzNodeGraphOnNodeRemovedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId) -> AzUpdate;
+    /// `AzTabOnReorderCallbackType` struct
+    pub type AzTabOnReorderCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize) -> AzUpdate;
 
-    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeRemovedCallback` struct
+    /// Re-export of rust-allocated (stack based) `TabOnContextMenuCallback` struct
     #[repr(C)]
     #[derive(Clone)]
-    pub struct AzNodeGraphOnNodeRemovedCallback {
-        pub cb: AzNodeGraphOnNodeRemovedCallbackType,
+    pub struct AzTabOnContextMenuCallback {
+        pub cb: AzTabOnContextMenuCallbackType,
     }
 
-    /// `AzNodeGraphOnNodeGraphDraggedCallbackType` struct
-    pub type AzNodeGraphOnNodeGraphDraggedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzGraphDragAmount) -> AzUpdate;
+    /// `AzTabOnContextMenuCallbackType` struct
+    pub type AzTabOnContextMenuCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
 
-    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphDraggedCallback` struct
+    /// Re-export of rust-allocated (stack based) `SplitterOrientation` struct
     #[repr(C)]
+    #[derive(Debug)]
     #[derive(Clone)]
-    pub struct AzNodeGraphOnNodeGraphDraggedCallback {
-        pub cb: AzNodeGraphOnNodeGraphDraggedCallbackType,
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzSplitterOrientation {
+        Horizontal,
+        Vertical,
     }
 
-    /// `AzNodeGraphOnNodeDraggedCallbackType` struct
-    pub type AzNodeGraphOnNodeDraggedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, AzNodeDragAmount) -> AzUpdate;
-
-    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeDraggedCallback` struct
+    /// Re-export of rust-allocated (stack based) `SplitterPaneState` struct
     #[repr(C)]
+    #[derive(Debug)]
     #[derive(Clone)]
-    pub struct AzNodeGraphOnNodeDraggedCallback {
-        pub cb: AzNodeGraphOnNodeDraggedCallbackType,
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub struct AzSplitterPaneState {
+        pub size: f32,
+        pub collapsed: bool,
     }
 
-    /// `AzNodeGraphOnNodeConnectedCallbackType` struct
-    pub type AzNodeGraphOnNodeConnectedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize, AzNodeGraphNodeId, usize) -> AzUpdate;
-
-    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeConnectedCallback` struct
+    /// Re-export of rust-allocated (stack based) `SplitterOnResizeCallback` struct
     #[repr(C)]
     #[derive(Clone)]
-    pub struct AzNodeGraphOnNodeConnectedCallback {
-        pub cb: AzNodeGraphOnNodeConnectedCallbackType,
+    pub struct AzSplitterOnResizeCallback {
+        pub cb: AzSplitterOnResizeCallbackType,
     }
 
-    /// `AzNodeGraphOnNodeInputDisconnectedCallbackType` struct
-    pub type AzNodeGraphOnNodeInputDisconnectedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize) -> AzUpdate;
+    /// `AzSplitterOnResizeCallbackType` struct
+    pub type AzSplitterOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSplitterState) -> AzUpdate;
 
-    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeInputDisconnectedCallback` struct
+    /// Re-export of rust-allocated (stack based) `SliderOrientation` struct
     #[repr(C)]
+    #[derive(Debug)]
     #[derive(Clone)]
-    pub struct AzNodeGraphOnNodeInputDisconnectedCallback {
-        pub cb: AzNodeGraphOnNodeInputDisconnectedCallbackType,
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzSliderOrientation {
+        Horizontal,
+        Vertical,
     }
 
-    /// `AzNodeGraphOnNodeOutputDisconnectedCallbackType` struct
-    pub type AzNodeGraphOnNodeOutputDisconnectedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize) -> AzUpdate;
-
-    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeOutputDisconnectedCallback` struct
+    /// Re-export of rust-allocated (stack based) `SliderState` struct
     #[repr(C)]
+    #[derive(Debug)]
     #[derive(Clone)]
-    pub struct AzNodeGraphOnNodeOutputDisconnectedCallback {
-        pub cb: AzNodeGraphOnNodeOutputDisconnectedCallbackType,
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzSliderState {
+        pub value: f32,
+        pub min: f32,
+        pub max: f32,
+        pub step: f32,
     }
 
-    /// `AzNodeGraphOnNodeFieldEditedCallbackType` struct
-    pub type AzNodeGraphOnNodeFieldEditedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize, AzNodeTypeId, AzNodeTypeFieldValue) -> AzUpdate;
-
-    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeFieldEditedCallback` struct
+    /// Re-export of rust-allocated (stack based) `RangeSliderState` struct
     #[repr(C)]
+    #[derive(Debug)]
     #[derive(Clone)]
-    pub struct AzNodeGraphOnNodeFieldEditedCallback {
-        pub cb: AzNodeGraphOnNodeFieldEditedCallbackType,
-    }
-
-    /// Re-export of rust-allocated (stack based) `InputOutputTypeId` struct
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzRangeSliderState {
+        pub low: f32,
+        pub high: f32,
+        pub min: f32,
+        pub max: f32,
+        pub step: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzSliderOnValueChangeCallback {
+        pub cb: AzSliderOnValueChangeCallbackType,
+    }
+
+    /// `AzSliderOnValueChangeCallbackType` struct
+    pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSliderState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `RangeSliderOnValueChangeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzRangeSliderOnValueChangeCallback {
+        pub cb: AzRangeSliderOnValueChangeCallbackType,
+    }
+
+    /// `AzRangeSliderOnValueChangeCallbackType` struct
+    pub type AzRangeSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzRangeSliderState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphStyle` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub enum AzNodeGraphStyle {
+        Default,
+    }
+
+    /// `AzNodeGraphOnNodeAddedCallbackType` struct
+    pub type AzNodeGraphOnNodeAddedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeTypeId, AzNodeGraphNodeId, AzNodePosition) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeAddedCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzNodeGraphOnNodeAddedCallback {
+        pub cb: AzNodeGraphOnNodeAddedCallbackType,
+    }
+
+    /// `AzNodeGraphOnNodeRemovedCallbackType` struct
+    pub type AzNodeGraphOnNodeRemovedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeRemovedCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzNodeGraphOnNodeRemovedCallback {
+        pub cb: AzNodeGraphOnNodeRemovedCallbackType,
+    }
+
+    /// `AzNodeGraphOnNodeGraphDraggedCallbackType` struct
+    pub type AzNodeGraphOnNodeGraphDraggedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzGraphDragAmount) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeGraphDraggedCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzNodeGraphOnNodeGraphDraggedCallback {
+        pub cb: AzNodeGraphOnNodeGraphDraggedCallbackType,
+    }
+
+    /// `AzNodeGraphOnNodeDraggedCallbackType` struct
+    pub type AzNodeGraphOnNodeDraggedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, AzNodeDragAmount) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeDraggedCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzNodeGraphOnNodeDraggedCallback {
+        pub cb: AzNodeGraphOnNodeDraggedCallbackType,
+    }
+
+    /// `AzNodeGraphOnNodeConnectedCallbackType` struct
+    pub type AzNodeGraphOnNodeConnectedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize, AzNodeGraphNodeId, usize) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeConnectedCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzNodeGraphOnNodeConnectedCallback {
+        pub cb: AzNodeGraphOnNodeConnectedCallbackType,
+    }
+
+    /// `AzNodeGraphOnNodeInputDisconnectedCallbackType` struct
+    pub type AzNodeGraphOnNodeInputDisconnectedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeInputDisconnectedCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzNodeGraphOnNodeInputDisconnectedCallback {
+        pub cb: AzNodeGraphOnNodeInputDisconnectedCallbackType,
+    }
+
+    /// `AzNodeGraphOnNodeOutputDisconnectedCallbackType` struct
+    pub type AzNodeGraphOnNodeOutputDisconnectedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeOutputDisconnectedCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzNodeGraphOnNodeOutputDisconnectedCallback {
+        pub cb: AzNodeGraphOnNodeOutputDisconnectedCallbackType,
+    }
+
+    /// `AzNodeGraphOnNodeFieldEditedCallbackType` struct
+    pub type AzNodeGraphOnNodeFieldEditedCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, AzNodeGraphNodeId, usize, AzNodeTypeId, AzNodeTypeFieldValue) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeFieldEditedCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzNodeGraphOnNodeFieldEditedCallback {
+        pub cb: AzNodeGraphOnNodeFieldEditedCallbackType,
+    }
+
+    /// Re-export of rust-allocated (stack based) `InputOutputTypeId` struct
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
@@ -2612,6 +3064,101 @@ mod dll {
         pub cb: AzListViewOnRowClickCallbackType,
     }
 
+    /// Range of row indices, `start` is inclusive, `end` is exclusive
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzListViewRowRange {
+        pub start: usize,
+        pub end: usize,
+    }
+
+    /// `AzListViewOnColumnResizeCallbackType` struct
+    pub type AzListViewOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, f32) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `ListViewOnColumnResizeCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzListViewOnColumnResizeCallback {
+        pub cb: AzListViewOnColumnResizeCallbackType,
+    }
+
+    /// `AzListViewRowProviderCallbackType` struct
+    pub type AzListViewRowProviderCallbackType = extern "C" fn(&mut AzRefAny, &AzListViewState, AzListViewRowRange) -> AzListViewRowVec;
+
+    /// Re-export of rust-allocated (stack based) `ListViewRowProviderCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzListViewRowProviderCallback {
+        pub cb: AzListViewRowProviderCallbackType,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeId` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    #[derive(Eq)]
+    #[derive(Ord)]
+    #[derive(Hash)]
+    pub struct AzTreeViewNodeId {
+        pub inner: u64,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzTreeViewSelectionMode {
+        Single,
+        Multiple,
+    }
+
+    /// `AzTreeViewOnExpandCallbackType` struct
+    pub type AzTreeViewOnExpandCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId, bool) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnExpandCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTreeViewOnExpandCallback {
+        pub cb: AzTreeViewOnExpandCallbackType,
+    }
+
+    /// `AzTreeViewOnSelectCallbackType` struct
+    pub type AzTreeViewOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelectCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTreeViewOnSelectCallback {
+        pub cb: AzTreeViewOnSelectCallbackType,
+    }
+
+    /// `AzTreeViewOnDoubleClickCallbackType` struct
+    pub type AzTreeViewOnDoubleClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnDoubleClickCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTreeViewOnDoubleClickCallback {
+        pub cb: AzTreeViewOnDoubleClickCallbackType,
+    }
+
+    /// `AzTreeViewOnContextMenuCallbackType` struct
+    pub type AzTreeViewOnContextMenuCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnContextMenuCallback` struct
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzTreeViewOnContextMenuCallback {
+        pub cb: AzTreeViewOnContextMenuCallbackType,
+    }
+
     /// `AzDropDownOnChoiceChangeCallbackType` struct
     pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
 
@@ -3381,6 +3928,26 @@ mod dll {
         pub cb: AzGetSystemTimeFnType,
     }
 
+    /// `AzGetClipboardFnType` struct
+    pub type AzGetClipboardFnType = extern "C" fn(&AzRawWindowHandle, AzClipboardSelection, AzString) -> AzOptionClipboardContent;
+
+    /// Reads the content of the system clipboard in the given MIME type
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzGetClipboardFn {
+        pub cb: AzGetClipboardFnType,
+    }
+
+    /// `AzSetClipboardFnType` struct
+    pub type AzSetClipboardFnType = extern "C" fn(&AzRawWindowHandle, AzClipboardSelection, AzClipboardContent) -> bool;
+
+    /// Sets the content of the system clipboard, returns whether the clipboard could be set
+    #[repr(C)]
+    #[derive(Clone)]
+    pub struct AzSetClipboardFn {
+        pub cb: AzSetClipboardFnType,
+    }
+
     /// `AzCheckThreadFinishedFnType` struct
     pub type AzCheckThreadFinishedFnType = extern "C" fn(&c_void) -> bool;
 
@@ -3487,6 +4054,110 @@ mod dll {
     /// `AzListViewRowVecDestructorType` struct
     pub type AzListViewRowVecDestructorType = extern "C" fn(&mut AzListViewRowVec);
 
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTreeViewNodeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewNodeVecDestructorType),
+    }
+
+    /// `AzTreeViewNodeVecDestructorType` struct
+    pub type AzTreeViewNodeVecDestructorType = extern "C" fn(&mut AzTreeViewNodeVec);
+
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeIdVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTreeViewNodeIdVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTreeViewNodeIdVecDestructorType),
+    }
+
+    /// `AzTreeViewNodeIdVecDestructorType` struct
+    pub type AzTreeViewNodeIdVecDestructorType = extern "C" fn(&mut AzTreeViewNodeIdVec);
+
+    /// Re-export of rust-allocated (stack based) `RibbonTabVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzRibbonTabVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzRibbonTabVecDestructorType),
+    }
+
+    /// `AzRibbonTabVecDestructorType` struct
+    pub type AzRibbonTabVecDestructorType = extern "C" fn(&mut AzRibbonTabVec);
+
+    /// Re-export of rust-allocated (stack based) `RibbonGroupVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzRibbonGroupVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzRibbonGroupVecDestructorType),
+    }
+
+    /// `AzRibbonGroupVecDestructorType` struct
+    pub type AzRibbonGroupVecDestructorType = extern "C" fn(&mut AzRibbonGroupVec);
+
+    /// Re-export of rust-allocated (stack based) `RibbonItemVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzRibbonItemVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzRibbonItemVecDestructorType),
+    }
+
+    /// `AzRibbonItemVecDestructorType` struct
+    pub type AzRibbonItemVecDestructorType = extern "C" fn(&mut AzRibbonItemVec);
+
+    /// Re-export of rust-allocated (stack based) `SplitterPaneVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzSplitterPaneVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSplitterPaneVecDestructorType),
+    }
+
+    /// `AzSplitterPaneVecDestructorType` struct
+    pub type AzSplitterPaneVecDestructorType = extern "C" fn(&mut AzSplitterPaneVec);
+
+    /// Re-export of rust-allocated (stack based) `SplitterPaneStateVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzSplitterPaneStateVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSplitterPaneStateVecDestructorType),
+    }
+
+    /// `AzSplitterPaneStateVecDestructorType` struct
+    pub type AzSplitterPaneStateVecDestructorType = extern "C" fn(&mut AzSplitterPaneStateVec);
+
+    /// Re-export of rust-allocated (stack based) `TextAreaEditVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzTextAreaEditVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTextAreaEditVecDestructorType),
+    }
+
+    /// `AzTextAreaEditVecDestructorType` struct
+    pub type AzTextAreaEditVecDestructorType = extern "C" fn(&mut AzTextAreaEditVec);
+
     /// Re-export of rust-allocated (stack based) `StyleFilterVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
@@ -3643,6 +4314,19 @@ mod dll {
     /// `AzAccessibilityStateVecDestructorType` struct
     pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+    /// Re-export of rust-allocated (stack based) `AccessibilityActionVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzAccessibilityActionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzAccessibilityActionVecDestructorType),
+    }
+
+    /// `AzAccessibilityActionVecDestructorType` struct
+    pub type AzAccessibilityActionVecDestructorType = extern "C" fn(&mut AzAccessibilityActionVec);
+
     /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
@@ -3877,37 +4561,154 @@ mod dll {
     /// `AzStyleTransformVecDestructorType` struct
     pub type AzStyleTransformVecDestructorType = extern "C" fn(&mut AzStyleTransformVec);
 
-    /// Re-export of rust-allocated (stack based) `CssPropertyVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzCssPropertyVecDestructor {
+    pub enum AzStyleTransitionPropertyVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzCssPropertyVecDestructorType),
+        External(AzStyleTransitionPropertyVecDestructorType),
     }
 
-    /// `AzCssPropertyVecDestructorType` struct
-    pub type AzCssPropertyVecDestructorType = extern "C" fn(&mut AzCssPropertyVec);
+    /// `AzStyleTransitionPropertyVecDestructorType` struct
+    pub type AzStyleTransitionPropertyVecDestructorType = extern "C" fn(&mut AzStyleTransitionPropertyVec);
 
-    /// Re-export of rust-allocated (stack based) `SvgMultiPolygonVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTimeVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzSvgMultiPolygonVecDestructor {
+    pub enum AzStyleTimeVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzSvgMultiPolygonVecDestructorType),
+        External(AzStyleTimeVecDestructorType),
     }
 
-    /// `AzSvgMultiPolygonVecDestructorType` struct
-    pub type AzSvgMultiPolygonVecDestructorType = extern "C" fn(&mut AzSvgMultiPolygonVec);
+    /// `AzStyleTimeVecDestructorType` struct
+    pub type AzStyleTimeVecDestructorType = extern "C" fn(&mut AzStyleTimeVec);
 
-    /// Re-export of rust-allocated (stack based) `SvgPathVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `StyleTimingFunctionVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
     #[derive(Copy)]
-    pub enum AzSvgPathVecDestructor {
+    pub enum AzStyleTimingFunctionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleTimingFunctionVecDestructorType),
+    }
+
+    /// `AzStyleTimingFunctionVecDestructorType` struct
+    pub type AzStyleTimingFunctionVecDestructorType = extern "C" fn(&mut AzStyleTimingFunctionVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationNameVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationNameVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationNameVecDestructorType),
+    }
+
+    /// `AzStyleAnimationNameVecDestructorType` struct
+    pub type AzStyleAnimationNameVecDestructorType = extern "C" fn(&mut AzStyleAnimationNameVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationIterationCountVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationIterationCountVecDestructorType),
+    }
+
+    /// `AzStyleAnimationIterationCountVecDestructorType` struct
+    pub type AzStyleAnimationIterationCountVecDestructorType = extern "C" fn(&mut AzStyleAnimationIterationCountVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationDirectionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationDirectionVecDestructorType),
+    }
+
+    /// `AzStyleAnimationDirectionVecDestructorType` struct
+    pub type AzStyleAnimationDirectionVecDestructorType = extern "C" fn(&mut AzStyleAnimationDirectionVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationFillModeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationFillModeVecDestructorType),
+    }
+
+    /// `AzStyleAnimationFillModeVecDestructorType` struct
+    pub type AzStyleAnimationFillModeVecDestructorType = extern "C" fn(&mut AzStyleAnimationFillModeVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationPlayStateVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationPlayStateVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationPlayStateVecDestructorType),
+    }
+
+    /// `AzStyleAnimationPlayStateVecDestructorType` struct
+    pub type AzStyleAnimationPlayStateVecDestructorType = extern "C" fn(&mut AzStyleAnimationPlayStateVec);
+
+    /// Re-export of rust-allocated (stack based) `GridTrackSizingVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzGridTrackSizingVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzGridTrackSizingVecDestructorType),
+    }
+
+    /// `AzGridTrackSizingVecDestructorType` struct
+    pub type AzGridTrackSizingVecDestructorType = extern "C" fn(&mut AzGridTrackSizingVec);
+
+    /// Re-export of rust-allocated (stack based) `CssPropertyVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCssPropertyVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssPropertyVecDestructorType),
+    }
+
+    /// `AzCssPropertyVecDestructorType` struct
+    pub type AzCssPropertyVecDestructorType = extern "C" fn(&mut AzCssPropertyVec);
+
+    /// Re-export of rust-allocated (stack based) `SvgMultiPolygonVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzSvgMultiPolygonVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSvgMultiPolygonVecDestructorType),
+    }
+
+    /// `AzSvgMultiPolygonVecDestructorType` struct
+    pub type AzSvgMultiPolygonVecDestructorType = extern "C" fn(&mut AzSvgMultiPolygonVec);
+
+    /// Re-export of rust-allocated (stack based) `SvgPathVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzSvgPathVecDestructor {
         DefaultRust,
         NoDestructor,
         External(AzSvgPathVecDestructorType),
@@ -4059,6 +4860,71 @@ mod dll {
     /// `AzStylesheetVecDestructorType` struct
     pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
 
+    /// Re-export of rust-allocated (stack based) `CssKeyframesVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCssKeyframesVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssKeyframesVecDestructorType),
+    }
+
+    /// `AzCssKeyframesVecDestructorType` struct
+    pub type AzCssKeyframesVecDestructorType = extern "C" fn(&mut AzCssKeyframesVec);
+
+    /// Re-export of rust-allocated (stack based) `CssKeyframeVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCssKeyframeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssKeyframeVecDestructorType),
+    }
+
+    /// `AzCssKeyframeVecDestructorType` struct
+    pub type AzCssKeyframeVecDestructorType = extern "C" fn(&mut AzCssKeyframeVec);
+
+    /// Re-export of rust-allocated (stack based) `CssMediaRuleVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCssMediaRuleVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssMediaRuleVecDestructorType),
+    }
+
+    /// `AzCssMediaRuleVecDestructorType` struct
+    pub type AzCssMediaRuleVecDestructorType = extern "C" fn(&mut AzCssMediaRuleVec);
+
+    /// Re-export of rust-allocated (stack based) `CssMediaQueryVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCssMediaQueryVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssMediaQueryVecDestructorType),
+    }
+
+    /// `AzCssMediaQueryVecDestructorType` struct
+    pub type AzCssMediaQueryVecDestructorType = extern "C" fn(&mut AzCssMediaQueryVec);
+
+    /// Re-export of rust-allocated (stack based) `CssMediaFeatureVecDestructor` struct
+    #[repr(C, u8)]
+    #[derive(Clone)]
+    #[derive(Copy)]
+    pub enum AzCssMediaFeatureVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssMediaFeatureVecDestructorType),
+    }
+
+    /// `AzCssMediaFeatureVecDestructorType` struct
+    pub type AzCssMediaFeatureVecDestructorType = extern "C" fn(&mut AzCssMediaFeatureVec);
+
     /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
     #[repr(C, u8)]
     #[derive(Clone)]
@@ -4408,6 +5274,8 @@ mod dll {
     pub struct AzSystemCallbacks {
         pub create_thread_fn: AzCreateThreadFn,
         pub get_system_time_fn: AzGetSystemTimeFn,
+        pub get_clipboard_fn: AzGetClipboardFn,
+        pub set_clipboard_fn: AzSetClipboardFn,
     }
 
     /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
@@ -4594,6 +5462,7 @@ mod dll {
         pub bounds: AzLogicalRect,
         pub unicode_codepoint: AzOptionChar,
         pub glyph_index: u32,
+        pub font_index: usize,
     }
 
     /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
@@ -4618,6 +5487,8 @@ mod dll {
         pub char_index_relative_to_line: usize,
         pub glyph_index_relative_to_word: usize,
         pub char_index_relative_to_word: usize,
+        pub is_rtl: bool,
+        pub cursor_index_relative_to_text: usize,
     }
 
     /// Re-export of rust-allocated (stack based) `IFrameCallbackInfo` struct
@@ -4680,6 +5551,16 @@ mod dll {
         Focus(AzFocusEventFilter),
     }
 
+    /// Re-export of rust-allocated (stack based) `TooltipDom` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTooltipDom {
+        pub data: AzRefAny,
+        pub callback: AzTooltipCallback,
+    }
+
     /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
     #[repr(C)]
     #[derive(Debug)]
@@ -4713,6 +5594,19 @@ mod dll {
         Pattern(AzCssNthChildPattern),
     }
 
+    /// Range of a numeric media feature, i.e. `(min-width: 600px)` or `(400px < width <= 700px)`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzCssMediaRange {
+        pub min: AzOptionF32,
+        pub min_inclusive: bool,
+        pub max: AzOptionF32,
+        pub max_inclusive: bool,
+    }
+
     /// Re-export of rust-allocated (stack based) `PixelValue` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -4798,6 +5692,62 @@ mod dll {
         Arithmetic([AzFloatValue;4]),
     }
 
+    /// One end of a grid track size, i.e. the `min` or `max` of a `minmax(min, max)`
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzGridTrackBreadth {
+        Fixed(AzPixelValue),
+        Fraction(AzFloatValue),
+        Auto,
+        MinContent,
+        MaxContent,
+    }
+
+    /// Single track size in a `grid-template-columns`, `grid-template-rows`, `grid-auto-columns` or `grid-auto-rows` attribute
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzGridTrackSizing {
+        pub min: AzGridTrackBreadth,
+        pub max: AzGridTrackBreadth,
+    }
+
+    /// Re-export of rust-allocated (stack based) `GridPlacement` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzGridPlacement {
+        pub start: AzGridLine,
+        pub end: AzGridLine,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutRowGap` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzLayoutRowGap {
+        pub inner: AzPixelValue,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutColumnGap` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzLayoutColumnGap {
+        pub inner: AzPixelValue,
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutBottom` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -5467,6 +6417,69 @@ mod dll {
         pub y: AzPercentageValue,
     }
 
+    /// Control points of a `cubic-bezier(x1, y1, x2, y2)` easing function
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzStyleCubicBezier {
+        pub x1: AzFloatValue,
+        pub y1: AzFloatValue,
+        pub x2: AzFloatValue,
+        pub y2: AzFloatValue,
+    }
+
+    /// Represents a `steps(n, position)` easing function
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzStyleSteps {
+        pub count: u32,
+        pub position: AzStyleStepPosition,
+    }
+
+    /// Represents an `<easing-function>` in a `transition-timing-function` or `animation-timing-function` attribute
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleTimingFunction {
+        Ease,
+        Linear,
+        EaseIn,
+        EaseOut,
+        EaseInOut,
+        CubicBezier(AzStyleCubicBezier),
+        Steps(AzStyleSteps),
+    }
+
+    /// Single entry in a `transition-property` attribute
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleTransitionProperty {
+        All,
+        Property(AzCssPropertyType),
+        Shorthand(AzCombinedCssPropertyType),
+    }
+
+    /// Single entry in an `animation-iteration-count` attribute
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleAnimationIterationCount {
+        Infinite,
+        Count(AzFloatValue),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -5515,130 +6528,200 @@ mod dll {
         Exact(AzLayoutAlignContent),
     }
 
-    /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
+    /// Re-export of rust-allocated (stack based) `GridTrackSizingValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     #[derive(Copy)]
-    pub enum AzLayoutAlignItemsValue {
+    pub enum AzGridTrackSizingValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzLayoutAlignItems),
+        Exact(AzGridTrackSizing),
     }
 
-    /// Re-export of rust-allocated (stack based) `LayoutBottomValue` struct
+    /// Re-export of rust-allocated (stack based) `LayoutGridAutoFlowValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     #[derive(Copy)]
-    pub enum AzLayoutBottomValue {
+    pub enum AzLayoutGridAutoFlowValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzLayoutBottom),
+        Exact(AzLayoutGridAutoFlow),
     }
 
-    /// Re-export of rust-allocated (stack based) `LayoutBoxSizingValue` struct
+    /// Re-export of rust-allocated (stack based) `GridPlacementValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     #[derive(Copy)]
-    pub enum AzLayoutBoxSizingValue {
+    pub enum AzGridPlacementValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzLayoutBoxSizing),
+        Exact(AzGridPlacement),
     }
 
-    /// Re-export of rust-allocated (stack based) `LayoutFlexDirectionValue` struct
+    /// Re-export of rust-allocated (stack based) `LayoutRowGapValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     #[derive(Copy)]
-    pub enum AzLayoutFlexDirectionValue {
+    pub enum AzLayoutRowGapValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzLayoutFlexDirection),
+        Exact(AzLayoutRowGap),
     }
 
-    /// Re-export of rust-allocated (stack based) `LayoutDisplayValue` struct
+    /// Re-export of rust-allocated (stack based) `LayoutColumnGapValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     #[derive(Copy)]
-    pub enum AzLayoutDisplayValue {
+    pub enum AzLayoutColumnGapValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzLayoutDisplay),
+        Exact(AzLayoutColumnGap),
     }
 
-    /// Re-export of rust-allocated (stack based) `LayoutFlexGrowValue` struct
+    /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     #[derive(Copy)]
-    pub enum AzLayoutFlexGrowValue {
+    pub enum AzLayoutAlignItemsValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzLayoutFlexGrow),
+        Exact(AzLayoutAlignItems),
     }
 
-    /// Re-export of rust-allocated (stack based) `LayoutFlexShrinkValue` struct
+    /// Re-export of rust-allocated (stack based) `LayoutBottomValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     #[derive(Copy)]
-    pub enum AzLayoutFlexShrinkValue {
+    pub enum AzLayoutBottomValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzLayoutFlexShrink),
+        Exact(AzLayoutBottom),
     }
 
-    /// Re-export of rust-allocated (stack based) `LayoutFloatValue` struct
+    /// Re-export of rust-allocated (stack based) `LayoutBoxSizingValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     #[derive(Copy)]
-    pub enum AzLayoutFloatValue {
+    pub enum AzLayoutBoxSizingValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzLayoutFloat),
+        Exact(AzLayoutBoxSizing),
     }
 
-    /// Re-export of rust-allocated (stack based) `LayoutHeightValue` struct
+    /// Re-export of rust-allocated (stack based) `LayoutFlexDirectionValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     #[derive(Copy)]
-    pub enum AzLayoutHeightValue {
+    pub enum AzLayoutFlexDirectionValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzLayoutHeight),
+        Exact(AzLayoutFlexDirection),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutDisplayValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutDisplayValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutDisplay),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutFlexGrowValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutFlexGrowValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutFlexGrow),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutFlexShrinkValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutFlexShrinkValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutFlexShrink),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutFloatValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutFloatValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutFloat),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutHeightValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzLayoutHeightValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutHeight),
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutJustifyContentValue` struct
@@ -6229,6 +7312,62 @@ mod dll {
         Exact(AzStyleTextAlign),
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleDirectionValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleDirectionValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleDirection),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleWordBreakValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleWordBreakValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleWordBreak),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleOverflowWrapValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleOverflowWrapValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleOverflowWrap),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleHyphensValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzStyleHyphensValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleHyphens),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -6327,6 +7466,37 @@ mod dll {
         Exact(AzStyleMixBlendMode),
     }
 
+    /// Re-export of rust-allocated (stack based) `RibbonItemState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub struct AzRibbonItemState {
+        pub toggled: bool,
+        pub choice: AzOptionUsize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonOnItemClick` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzRibbonOnItemClick {
+        pub data: AzRefAny,
+        pub callback: AzRibbonOnItemClickCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonOnTabClicked` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzRibbonOnTabClicked {
+        pub data: AzRefAny,
+        pub callback: AzRibbonOnTabClickedCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `ButtonOnClick` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -6426,6 +7596,16 @@ mod dll {
         pub valid: AzTextInputValid,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextAreaOnTextChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextAreaOnTextChange {
+        pub data: AzRefAny,
+        pub callback: AzTextAreaOnTextChangeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputOnValueChange` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -6446,6 +7626,29 @@ mod dll {
         pub callback: AzNumberInputOnFocusLostCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `ProgressBarState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzProgressBarState {
+        pub percent_done: f32,
+        pub display_percentage: bool,
+        pub mode: AzProgressBarMode,
+    }
+
+    /// Circular, endlessly rotating "busy" indicator for operations without a known total
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSpinner {
+        pub size: AzPixelValue,
+        pub thickness: AzPixelValue,
+        pub color: AzColorU,
+        pub track_color: AzColorU,
+    }
+
     /// Re-export of rust-allocated (stack based) `TabOnClick` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -6456,6 +7659,66 @@ mod dll {
         pub callback: AzTabOnClickCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `TabOnClose` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabOnClose {
+        pub data: AzRefAny,
+        pub callback: AzTabOnCloseCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabOnReorder` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabOnReorder {
+        pub data: AzRefAny,
+        pub callback: AzTabOnReorderCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabOnContextMenu` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTabOnContextMenu {
+        pub data: AzRefAny,
+        pub callback: AzTabOnContextMenuCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitterOnResize` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSplitterOnResize {
+        pub data: AzRefAny,
+        pub callback: AzSplitterOnResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSliderOnValueChange {
+        pub data: AzRefAny,
+        pub callback: AzSliderOnValueChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RangeSliderOnValueChange` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzRangeSliderOnValueChange {
+        pub data: AzRefAny,
+        pub callback: AzRangeSliderOnValueChangeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeAdded` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -6586,6 +7849,66 @@ mod dll {
         pub callback: AzListViewOnRowClickCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `ListViewOnColumnResize` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzListViewOnColumnResize {
+        pub data: AzRefAny,
+        pub callback: AzListViewOnColumnResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ListViewRowProvider` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzListViewRowProvider {
+        pub data: AzRefAny,
+        pub callback: AzListViewRowProviderCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnExpand` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewOnExpand {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnExpandCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelect` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewOnSelect {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnSelectCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnDoubleClick` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewOnDoubleClick {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnDoubleClickCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnContextMenu` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewOnContextMenu {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnContextMenuCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -6810,6 +8133,24 @@ mod dll {
         pub callback: AzWriteBackCallback,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TreeViewNodeId>`
+    #[repr(C)]
+    pub struct AzTreeViewNodeIdVec {
+        pub(crate) ptr: *const AzTreeViewNodeId,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewNodeIdVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+    #[repr(C)]
+    pub struct AzSplitterPaneStateVec {
+        pub(crate) ptr: *const AzSplitterPaneState,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSplitterPaneStateVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
     #[repr(C)]
     pub struct AzLogicalRectVec {
@@ -6855,6 +8196,15 @@ mod dll {
         pub destructor: AzAccessibilityStateVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<AccessibilityAction>`
+    #[repr(C)]
+    pub struct AzAccessibilityActionVec {
+        pub(crate) ptr: *const AzAccessibilityAction,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzAccessibilityActionVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<MenuItem>`
     #[repr(C)]
     pub struct AzMenuItemVec {
@@ -6936,6 +8286,78 @@ mod dll {
         pub destructor: AzStyleBackgroundSizeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+    #[repr(C)]
+    pub struct AzStyleTransitionPropertyVec {
+        pub(crate) ptr: *const AzStyleTransitionProperty,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionPropertyVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTime>`
+    #[repr(C)]
+    pub struct AzStyleTimeVec {
+        pub(crate) ptr: *const AzStyleTime,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTimeVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTimingFunction>`
+    #[repr(C)]
+    pub struct AzStyleTimingFunctionVec {
+        pub(crate) ptr: *const AzStyleTimingFunction,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTimingFunctionVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+    #[repr(C)]
+    pub struct AzStyleAnimationIterationCountVec {
+        pub(crate) ptr: *const AzStyleAnimationIterationCount,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationIterationCountVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+    #[repr(C)]
+    pub struct AzStyleAnimationDirectionVec {
+        pub(crate) ptr: *const AzStyleAnimationDirection,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationDirectionVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+    #[repr(C)]
+    pub struct AzStyleAnimationFillModeVec {
+        pub(crate) ptr: *const AzStyleAnimationFillMode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationFillModeVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationPlayState>`
+    #[repr(C)]
+    pub struct AzStyleAnimationPlayStateVec {
+        pub(crate) ptr: *const AzStyleAnimationPlayState,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationPlayStateVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
+    #[repr(C)]
+    pub struct AzGridTrackSizingVec {
+        pub(crate) ptr: *const AzGridTrackSizing,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzGridTrackSizingVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `SvgVertex`
     #[repr(C)]
     pub struct AzSvgVertexVec {
@@ -7071,23 +8493,74 @@ mod dll {
         pub destructor: AzNodeHierarchyItemVecDestructor,
     }
 
-    /// Wrapper over a Rust-allocated `ParentWithNodeDepthVec`
-    #[repr(C)]
-    pub struct AzParentWithNodeDepthVec {
-        pub(crate) ptr: *const AzParentWithNodeDepth,
-        pub len: usize,
-        pub cap: usize,
-        pub destructor: AzParentWithNodeDepthVecDestructor,
+    /// Wrapper over a Rust-allocated `ParentWithNodeDepthVec`
+    #[repr(C)]
+    pub struct AzParentWithNodeDepthVec {
+        pub(crate) ptr: *const AzParentWithNodeDepth,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzParentWithNodeDepthVecDestructor,
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionListViewOnRowClick` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionListViewOnRowClick {
+        None,
+        Some(AzListViewOnRowClick),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewNodeId` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzOptionTreeViewNodeId {
+        None,
+        Some(AzTreeViewNodeId),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnExpand` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTreeViewOnExpand {
+        None,
+        Some(AzTreeViewOnExpand),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelect` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTreeViewOnSelect {
+        None,
+        Some(AzTreeViewOnSelect),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnDoubleClick` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTreeViewOnDoubleClick {
+        None,
+        Some(AzTreeViewOnDoubleClick),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionListViewOnRowClick` struct
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnContextMenu` struct
     #[repr(C, u8)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub enum AzOptionListViewOnRowClick {
+    pub enum AzOptionTreeViewOnContextMenu {
         None,
-        Some(AzListViewOnRowClick),
+        Some(AzTreeViewOnContextMenu),
     }
 
     /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnClick` struct
@@ -7110,6 +8583,26 @@ mod dll {
         Some(AzListViewOnLazyLoadScroll),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnResize` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionListViewOnColumnResize {
+        None,
+        Some(AzListViewOnColumnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionListViewRowProvider` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionListViewRowProvider {
+        None,
+        Some(AzListViewRowProvider),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionPixelValueNoPercent` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7240,6 +8733,86 @@ mod dll {
         Some(AzTabOnClick),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTabOnClose` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTabOnClose {
+        None,
+        Some(AzTabOnClose),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabOnReorder` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTabOnReorder {
+        None,
+        Some(AzTabOnReorder),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabOnContextMenu` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTabOnContextMenu {
+        None,
+        Some(AzTabOnContextMenu),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionRibbonOnTabClicked` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionRibbonOnTabClicked {
+        None,
+        Some(AzRibbonOnTabClicked),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionRibbonOnItemClick` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionRibbonOnItemClick {
+        None,
+        Some(AzRibbonOnItemClick),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSplitterOnResize` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSplitterOnResize {
+        None,
+        Some(AzSplitterOnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionSliderOnValueChange {
+        None,
+        Some(AzSliderOnValueChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionRangeSliderOnValueChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionRangeSliderOnValueChange {
+        None,
+        Some(AzRangeSliderOnValueChange),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionFileInputOnPathChange` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7300,6 +8873,16 @@ mod dll {
         Some(AzTextInputSelection),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextAreaOnTextChange` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionTextAreaOnTextChange {
+        None,
+        Some(AzTextAreaOnTextChange),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionNumberInputOnFocusLost` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7573,6 +9156,17 @@ mod dll {
         Some(AzLogicalPosition),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzOptionLogicalRect {
+        None,
+        Some(AzLogicalRect),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionPhysicalPositionI32` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -7818,18 +9412,6 @@ mod dll {
         pub max_dimensions: AzOptionLogicalSize,
     }
 
-    /// Current keyboard state, stores what keys / characters have been pressed
-    #[repr(C)]
-    #[derive(Debug)]
-    #[derive(Clone)]
-    #[derive(PartialEq, PartialOrd)]
-    pub struct AzKeyboardState {
-        pub current_char: AzOptionChar,
-        pub current_virtual_keycode: AzOptionVirtualKeyCode,
-        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
-        pub pressed_scancodes: AzScanCodeVec,
-    }
-
     /// Current mouse / cursor state
     #[repr(C)]
     #[derive(Debug)]
@@ -7864,6 +9446,7 @@ mod dll {
     pub struct AzInlineTextContents {
         pub glyphs: AzInlineGlyphVec,
         pub bounds: AzLogicalRect,
+        pub is_rtl: bool,
     }
 
     /// Re-export of rust-allocated (stack based) `ResolvedTextLayoutOptions` struct
@@ -7880,6 +9463,10 @@ mod dll {
         pub max_horizontal_width: AzOptionF32,
         pub leading: AzOptionF32,
         pub holes: AzLogicalRectVec,
+        pub direction: AzStyleDirection,
+        pub word_break: AzStyleWordBreak,
+        pub overflow_wrap: AzStyleOverflowWrap,
+        pub hyphens: AzStyleHyphens,
     }
 
     /// Easing function of the animation (ease-in, ease-out, ease-in-out, custom)
@@ -7983,6 +9570,20 @@ mod dll {
         Focus,
     }
 
+    /// One media feature, i.e. `(min-width: 600px)` or `(prefers-color-scheme: dark)`
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[derive(Copy)]
+    pub enum AzCssMediaFeature {
+        Width(AzCssMediaRange),
+        Height(AzCssMediaRange),
+        Resolution(AzCssMediaRange),
+        Orientation(AzCssMediaOrientation),
+        PrefersColorScheme(AzCssColorScheme),
+    }
+
     /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8096,6 +9697,110 @@ mod dll {
         Perspective(AzPixelValue),
     }
 
+    /// Re-export of rust-allocated (stack based) `GridTrackSizingVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzGridTrackSizingVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzGridTrackSizingVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleTransitionPropertyVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionPropertyVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTimeVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleTimeVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTimeVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTimingFunctionVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleTimingFunctionVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTimingFunctionVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationIterationCountVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationIterationCountVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationDirectionVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationDirectionVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationFillModeVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationFillModeVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationPlayStateVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationPlayStateVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationPlayStateVec),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8145,6 +9850,20 @@ mod dll {
         pub on_toggle: AzOptionCheckBoxOnToggle,
     }
 
+    /// Single step in the undo / redo history of a `TextArea`: `removed` was replaced with `inserted` at `position`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextAreaEdit {
+        pub position: usize,
+        pub removed: AzU32Vec,
+        pub inserted: AzU32Vec,
+        pub kind: AzTextAreaEditKind,
+        pub cursor_before: usize,
+        pub selection_anchor_before: AzOptionUsize,
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -8156,6 +9875,16 @@ mod dll {
         pub on_focus_lost: AzOptionNumberInputOnFocusLost,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitterState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub struct AzSplitterState {
+        pub panes: AzSplitterPaneStateVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeGraphCallbacks` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -8382,6 +10111,15 @@ mod dll {
         pub destructor: AzListViewRowVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TextAreaEdit>`
+    #[repr(C)]
+    pub struct AzTextAreaEditVec {
+        pub(crate) ptr: *const AzTextAreaEdit,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextAreaEditVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<StyleFilter>`
     #[repr(C)]
     pub struct AzStyleFilterVec {
@@ -8436,6 +10174,15 @@ mod dll {
         pub destructor: AzSvgPathElementVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssMediaFeature>`
+    #[repr(C)]
+    pub struct AzCssMediaFeatureVec {
+        pub(crate) ptr: *const AzCssMediaFeature,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssMediaFeatureVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `StringVec`
     #[repr(C)]
     pub struct AzStringVec {
@@ -8503,16 +10250,6 @@ mod dll {
         Some(AzMouseState),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
-    #[repr(C, u8)]
-    #[derive(Debug)]
-    #[derive(Clone)]
-    #[derive(PartialEq, PartialOrd)]
-    pub enum AzOptionKeyboardState {
-        None,
-        Some(AzKeyboardState),
-    }
-
     /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8645,6 +10382,26 @@ mod dll {
         pub pos: AzSvgParseErrorPosition,
     }
 
+    /// Content of the system clipboard, tagged with its MIME type
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzClipboardContent {
+        pub mime_type: AzString,
+        pub data: AzU8Vec,
+    }
+
+    /// Preedit text of an input method, i.e. the pinyin or kana that was typed before the user selected one of the candidates
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzImeComposition {
+        pub text: AzString,
+        pub cursor: usize,
+    }
+
     /// Window configuration specific to Win32
     #[repr(C)]
     #[derive(Debug)]
@@ -8797,6 +10554,16 @@ mod dll {
         pub default_action: AzOptionString,
     }
 
+    /// Re-export of rust-allocated (stack based) `TooltipContent` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzTooltipContent {
+        Text(AzString),
+        Dom(AzTooltipDom),
+    }
+
     /// Re-export of rust-allocated (stack based) `IdOrClass` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8836,6 +10603,17 @@ mod dll {
         Children,
     }
 
+    /// One query of a media query list, i.e. `screen and (min-width: 600px)`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssMediaQuery {
+        pub negated: bool,
+        pub media_type: AzCssMediaType,
+        pub features: AzCssMediaFeatureVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8886,6 +10664,16 @@ mod dll {
         Ref(AzFontRef),
     }
 
+    /// Single entry in an `animation-name` attribute, refers to a `@keyframes` block
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationName {
+        None,
+        Name(AzString),
+    }
+
     /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -8925,6 +10713,23 @@ mod dll {
         Exact(AzStyleFilterVec),
     }
 
+    /// Re-export of rust-allocated (stack based) `RibbonItem` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzRibbonItem {
+        pub kind: AzRibbonItemKind,
+        pub label: AzString,
+        pub icon: AzOptionImageRef,
+        pub tooltip: AzOptionString,
+        pub key_tip: AzOptionString,
+        pub enabled: bool,
+        pub toggled: bool,
+        pub choices: AzStringVec,
+        pub on_click: AzOptionRibbonOnItemClick,
+    }
+
     /// Re-export of rust-allocated (stack based) `FileInputState` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -8958,6 +10763,22 @@ mod dll {
         pub cursor_pos: usize,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextAreaState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextAreaState {
+        pub text: AzU32Vec,
+        pub cursor: usize,
+        pub selection_anchor: AzOptionUsize,
+        pub preferred_x: AzOptionF32,
+        pub scroll_offset: f32,
+        pub undo_stack: AzTextAreaEditVec,
+        pub redo_stack: AzTextAreaEditVec,
+        pub last_edit_time: AzOptionInstant,
+    }
+
     /// Re-export of rust-allocated (stack based) `TabHeader` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -8967,6 +10788,9 @@ mod dll {
         pub tabs: AzStringVec,
         pub active_tab: usize,
         pub on_click: AzOptionTabOnClick,
+        pub on_close: AzOptionTabOnClose,
+        pub on_reorder: AzOptionTabOnReorder,
+        pub on_context_menu: AzOptionTabOnContextMenu,
     }
 
     /// Re-export of rust-allocated (stack based) `NodeTypeFieldValue` struct
@@ -9022,6 +10846,11 @@ mod dll {
         pub on_lazy_load_scroll: AzOptionListViewOnLazyLoadScroll,
         pub on_column_click: AzOptionListViewOnColumnClick,
         pub on_row_click: AzOptionListViewOnRowClick,
+        pub row_count: usize,
+        pub row_height: AzPixelValueNoPercent,
+        pub column_widths: AzF32Vec,
+        pub row_provider: AzOptionListViewRowProvider,
+        pub on_column_resize: AzOptionListViewOnColumnResize,
     }
 
     /// Re-export of rust-allocated (stack based) `ListViewState` struct
@@ -9036,15 +10865,35 @@ mod dll {
         pub scroll_offset: AzPixelValueNoPercent,
         pub current_scroll_position: AzLogicalPosition,
         pub current_content_height: AzLogicalSize,
+        pub row_count: usize,
+        pub visible_rows: AzListViewRowRange,
+        pub column_widths: AzF32Vec,
     }
 
-    /// Re-export of rust-allocated (stack based) `TreeView` struct
+    /// Re-export of rust-allocated (stack based) `TreeViewNode` struct
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzTreeView {
-        pub root: AzString,
+    pub struct AzTreeViewNode {
+        pub id: AzTreeViewNodeId,
+        pub parent: AzOptionTreeViewNodeId,
+        pub label: AzString,
+        pub icon: AzOptionImageRef,
+        pub has_children: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewState` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeViewState {
+        pub expanded: AzTreeViewNodeIdVec,
+        pub selected: AzTreeViewNodeIdVec,
+        pub focused: AzOptionTreeViewNodeId,
+        pub selection_anchor: AzOptionTreeViewNodeId,
+        pub last_click_time: AzOptionInstant,
     }
 
     /// Re-export of rust-allocated (stack based) `DropDown` struct
@@ -9055,6 +10904,7 @@ mod dll {
     pub struct AzDropDown {
         pub choices: AzStringVec,
         pub selected: usize,
+        pub disabled_choices: AzU32Vec,
         pub on_choice_change: AzOptionDropDownOnChoiceChange,
     }
 
@@ -9227,6 +11077,24 @@ mod dll {
         pub value: AzFmtValue,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TreeViewNode>`
+    #[repr(C)]
+    pub struct AzTreeViewNodeVec {
+        pub(crate) ptr: *const AzTreeViewNode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewNodeVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<RibbonItem>`
+    #[repr(C)]
+    pub struct AzRibbonItemVec {
+        pub(crate) ptr: *const AzRibbonItem,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzRibbonItemVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<StyleFontFamily>`
     #[repr(C)]
     pub struct AzStyleFontFamilyVec {
@@ -9281,6 +11149,15 @@ mod dll {
         pub destructor: AzStyleBackgroundContentVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+    #[repr(C)]
+    pub struct AzStyleAnimationNameVec {
+        pub(crate) ptr: *const AzStyleAnimationName,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationNameVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<SvgPath>`
     #[repr(C)]
     pub struct AzSvgPathVec {
@@ -9308,6 +11185,15 @@ mod dll {
         pub destructor: AzCssPathSelectorVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssMediaQuery>`
+    #[repr(C)]
+    pub struct AzCssMediaQueryVec {
+        pub(crate) ptr: *const AzCssMediaQuery,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssMediaQueryVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `CallbackData`
     #[repr(C)]
     pub struct AzCallbackDataVec {
@@ -9375,6 +11261,26 @@ mod dll {
         Some(AzWaylandTheme),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionImeComposition` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionImeComposition {
+        None,
+        Some(AzImeComposition),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionClipboardContent` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionClipboardContent {
+        None,
+        Some(AzClipboardContent),
+    }
+
     /// Re-export of rust-allocated (stack based) `ResultRawImageDecodeImageError` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -9406,12 +11312,26 @@ mod dll {
         InvalidCharacterData,
     }
 
+    /// Current keyboard state, stores what keys / characters have been pressed
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzKeyboardState {
+        pub current_char: AzOptionChar,
+        pub current_virtual_keycode: AzOptionVirtualKeyCode,
+        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
+        pub pressed_scancodes: AzScanCodeVec,
+        pub ime_composition: AzOptionImeComposition,
+    }
+
     /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
     pub struct AzLinuxWindowOptions {
+        pub backend: AzLinuxBackend,
         pub x11_visual: AzOptionX11Visual,
         pub x11_screen: AzOptionI32,
         pub x11_wm_classes: AzStringPairVec,
@@ -9436,6 +11356,16 @@ mod dll {
         pub bounds: AzLogicalRect,
     }
 
+    /// Tooltip of a node, shown in an always-on-top popup window once the mouse has rested on the node for `delay`. See `NodeData::set_tooltip`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTooltip {
+        pub content: AzTooltipContent,
+        pub delay: AzDuration,
+    }
+
     /// Item entry in a menu or menu bar
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -9456,6 +11386,19 @@ mod dll {
         pub selectors: AzCssPathSelectorVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleAnimationNameVecValue` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzStyleAnimationNameVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationNameVec),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -9492,6 +11435,10 @@ mod dll {
         FontSize(AzStyleFontSizeValue),
         FontFamily(AzStyleFontFamilyVecValue),
         TextAlign(AzStyleTextAlignValue),
+        Direction(AzStyleDirectionValue),
+        WordBreak(AzStyleWordBreakValue),
+        OverflowWrap(AzStyleOverflowWrapValue),
+        Hyphens(AzStyleHyphensValue),
         LetterSpacing(AzStyleLetterSpacingValue),
         LineHeight(AzStyleLineHeightValue),
         WordSpacing(AzStyleWordSpacingValue),
@@ -9518,6 +11465,15 @@ mod dll {
         JustifyContent(AzLayoutJustifyContentValue),
         AlignItems(AzLayoutAlignItemsValue),
         AlignContent(AzLayoutAlignContentValue),
+        GridTemplateColumns(AzGridTrackSizingVecValue),
+        GridTemplateRows(AzGridTrackSizingVecValue),
+        GridAutoColumns(AzGridTrackSizingValue),
+        GridAutoRows(AzGridTrackSizingValue),
+        GridAutoFlow(AzLayoutGridAutoFlowValue),
+        GridColumn(AzGridPlacementValue),
+        GridRow(AzGridPlacementValue),
+        RowGap(AzLayoutRowGapValue),
+        ColumnGap(AzLayoutColumnGapValue),
         BackgroundContent(AzStyleBackgroundContentVecValue),
         BackgroundPosition(AzStyleBackgroundPositionVecValue),
         BackgroundSize(AzStyleBackgroundSizeVecValue),
@@ -9562,6 +11518,29 @@ mod dll {
         Filter(AzStyleFilterVecValue),
         BackdropFilter(AzStyleFilterVecValue),
         TextShadow(AzStyleBoxShadowValue),
+        TransitionProperty(AzStyleTransitionPropertyVecValue),
+        TransitionDuration(AzStyleTimeVecValue),
+        TransitionTimingFunction(AzStyleTimingFunctionVecValue),
+        TransitionDelay(AzStyleTimeVecValue),
+        AnimationName(AzStyleAnimationNameVecValue),
+        AnimationDuration(AzStyleTimeVecValue),
+        AnimationTimingFunction(AzStyleTimingFunctionVecValue),
+        AnimationDelay(AzStyleTimeVecValue),
+        AnimationIterationCount(AzStyleAnimationIterationCountVecValue),
+        AnimationDirection(AzStyleAnimationDirectionVecValue),
+        AnimationFillMode(AzStyleAnimationFillModeVecValue),
+        AnimationPlayState(AzStyleAnimationPlayStateVecValue),
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonGroup` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzRibbonGroup {
+        pub label: AzString,
+        pub icon: AzOptionImageRef,
+        pub items: AzRibbonItemVec,
     }
 
     /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
@@ -9592,6 +11571,16 @@ mod dll {
         pub cursor_animation: AzOptionTimerId,
     }
 
+    /// Multi-line text input with soft wrapping, scrolling and an undo history
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTextArea {
+        pub state: AzTextAreaState,
+        pub on_text_change: AzOptionTextAreaOnTextChange,
+    }
+
     /// Re-export of rust-allocated (stack based) `ProgressBar` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -9602,6 +11591,8 @@ mod dll {
         pub height: AzPixelValue,
         pub bar_background: AzStyleBackgroundContentVec,
         pub container_background: AzStyleBackgroundContentVec,
+        pub style: AzProgressBarStyle,
+        pub text_format: AzOptionString,
     }
 
     /// Re-export of rust-allocated (stack based) `NodeTypeIdInfoMap` struct
@@ -9637,6 +11628,21 @@ mod dll {
         pub value: AzNodeTypeFieldValue,
     }
 
+    /// Re-export of rust-allocated (stack based) `TreeView` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzTreeView {
+        pub nodes: AzTreeViewNodeVec,
+        pub state: AzTreeViewState,
+        pub selection_mode: AzTreeViewSelectionMode,
+        pub on_expand: AzOptionTreeViewOnExpand,
+        pub on_select: AzOptionTreeViewOnSelect,
+        pub on_double_click: AzOptionTreeViewOnDoubleClick,
+        pub on_context_menu: AzOptionTreeViewOnContextMenu,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -9713,6 +11719,15 @@ mod dll {
         pub text: AzOptionString,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<RibbonGroup>`
+    #[repr(C)]
+    pub struct AzRibbonGroupVec {
+        pub(crate) ptr: *const AzRibbonGroup,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzRibbonGroupVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<NodeTypeIdInfoMap>`
     #[repr(C)]
     pub struct AzNodeTypeIdInfoMapVec {
@@ -9777,6 +11792,16 @@ mod dll {
         Some(AzCssProperty),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+    #[repr(C, u8)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub enum AzOptionKeyboardState {
+        None,
+        Some(AzKeyboardState),
+    }
+
     /// Re-export of rust-allocated (stack based) `XmlTextError` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -9935,6 +11960,27 @@ mod dll {
         pub default_value: AzCssProperty,
     }
 
+    /// One keyframe inside of a `@keyframes` block, i.e. `50% { opacity: 0.5; }`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssKeyframe {
+        pub offset: AzPercentageValue,
+        pub properties: AzCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonTab` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzRibbonTab {
+        pub label: AzString,
+        pub key_tip: AzOptionString,
+        pub groups: AzRibbonGroupVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `Node` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -9971,6 +12017,15 @@ mod dll {
         pub style: AzSvgStyle,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<RibbonTab>`
+    #[repr(C)]
+    pub struct AzRibbonTabVec {
+        pub(crate) ptr: *const AzRibbonTab,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzRibbonTabVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<NodeDataInlineCssProperty>`
     #[repr(C)]
     pub struct AzNodeDataInlineCssPropertyVec {
@@ -9980,6 +12035,15 @@ mod dll {
         pub destructor: AzNodeDataInlineCssPropertyVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+    #[repr(C)]
+    pub struct AzCssKeyframeVec {
+        pub(crate) ptr: *const AzCssKeyframe,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssKeyframeVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
     #[repr(C, u8)]
     #[derive(Debug)]
@@ -10072,6 +12136,28 @@ mod dll {
         Dynamic(AzDynamicCssProperty),
     }
 
+    /// One `@keyframes` block, i.e. `@keyframes fade { from { opacity: 0; } to { opacity: 1; } }`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzCssKeyframes {
+        pub name: AzString,
+        pub frames: AzCssKeyframeVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `Ribbon` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzRibbon {
+        pub tabs: AzRibbonTabVec,
+        pub tab_active: i32,
+        pub available_width: AzOptionF32,
+        pub on_tab_clicked: AzOptionRibbonOnTabClicked,
+    }
+
     /// Re-export of rust-allocated (stack based) `Button` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -10153,6 +12239,42 @@ mod dll {
         pub state: AzNumberInputStateWrapper,
     }
 
+    /// Thumb that can be dragged along a track to select a value between `min` and `max`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSlider {
+        pub orientation: AzSliderOrientation,
+        pub state: AzSliderState,
+        pub tick_interval: f32,
+        pub on_value_change: AzOptionSliderOnValueChange,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub track_style: AzNodeDataInlineCssPropertyVec,
+        pub fill_style: AzNodeDataInlineCssPropertyVec,
+        pub rail_style: AzNodeDataInlineCssPropertyVec,
+        pub thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub tick_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Slider with two thumbs to select a range (`low..=high`) between `min` and `max`
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzRangeSlider {
+        pub orientation: AzSliderOrientation,
+        pub state: AzRangeSliderState,
+        pub tick_interval: f32,
+        pub on_value_change: AzOptionRangeSliderOnValueChange,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub track_style: AzNodeDataInlineCssPropertyVec,
+        pub fill_style: AzNodeDataInlineCssPropertyVec,
+        pub rail_style: AzNodeDataInlineCssPropertyVec,
+        pub thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub tick_style: AzNodeDataInlineCssPropertyVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeIdNodeMap` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -10181,6 +12303,15 @@ mod dll {
         pub destructor: AzCssDeclarationVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+    #[repr(C)]
+    pub struct AzCssKeyframesVec {
+        pub(crate) ptr: *const AzCssKeyframes,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssKeyframesVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `NodeDataVec`
     #[repr(C)]
     pub struct AzNodeDataVec {
@@ -10257,6 +12388,19 @@ mod dll {
         pub content: AzDom,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitterPane` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSplitterPane {
+        pub content: AzDom,
+        pub size: AzOptionF32,
+        pub min_size: f32,
+        pub max_size: AzOptionF32,
+        pub collapsible: bool,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeGraph` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -10293,6 +12437,15 @@ mod dll {
         pub css_property_cache: AzCssPropertyCache,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+    #[repr(C)]
+    pub struct AzSplitterPaneVec {
+        pub(crate) ptr: *const AzSplitterPane,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSplitterPaneVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `CssRuleBlock`
     #[repr(C)]
     pub struct AzCssRuleBlockVec {
@@ -10349,22 +12502,36 @@ mod dll {
         pub virtual_scroll_offset: AzLogicalPosition,
     }
 
-    /// Re-export of rust-allocated (stack based) `Stylesheet` struct
+    /// One `@media` block, i.e. `@media (prefers-color-scheme: dark) { body { color: white; } }`
     #[repr(C)]
     #[derive(Debug)]
     #[derive(Clone)]
     #[derive(PartialEq, PartialOrd)]
-    pub struct AzStylesheet {
+    pub struct AzCssMediaRule {
+        pub queries: AzCssMediaQueryVec,
+        pub insert_index: usize,
         pub rules: AzCssRuleBlockVec,
     }
 
-    /// Wrapper over a Rust-allocated `Stylesheet`
+    /// Container with resizable panes, separated by draggable handles
     #[repr(C)]
-    pub struct AzStylesheetVec {
-        pub(crate) ptr: *const AzStylesheet,
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzSplitter {
+        pub orientation: AzSplitterOrientation,
+        pub panes: AzSplitterPaneVec,
+        pub state: AzSplitterState,
+        pub on_resize: AzOptionSplitterOnResize,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<CssMediaRule>`
+    #[repr(C)]
+    pub struct AzCssMediaRuleVec {
+        pub(crate) ptr: *const AzCssMediaRule,
         pub len: usize,
         pub cap: usize,
-        pub destructor: AzStylesheetVecDestructor,
+        pub destructor: AzCssMediaRuleVecDestructor,
     }
 
     /// Re-export of rust-allocated (stack based) `ResultSvgXmlNodeSvgParseError` struct
@@ -10387,6 +12554,26 @@ mod dll {
         Err(AzSvgParseError),
     }
 
+    /// Re-export of rust-allocated (stack based) `Stylesheet` struct
+    #[repr(C)]
+    #[derive(Debug)]
+    #[derive(Clone)]
+    #[derive(PartialEq, PartialOrd)]
+    pub struct AzStylesheet {
+        pub rules: AzCssRuleBlockVec,
+        pub keyframes: AzCssKeyframesVec,
+        pub media_rules: AzCssMediaRuleVec,
+    }
+
+    /// Wrapper over a Rust-allocated `Stylesheet`
+    #[repr(C)]
+    pub struct AzStylesheetVec {
+        pub(crate) ptr: *const AzStylesheet,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStylesheetVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `Css` struct
     #[repr(C)]
     #[derive(Debug)]
@@ -10409,6 +12596,12 @@ mod dll {
         pub(crate) fn AzAppConfig_new(_:  AzLayoutSolver) -> AzAppConfig;
         pub(crate) fn AzSystemCallbacks_libraryInternal() -> AzSystemCallbacks;
         pub(crate) fn AzWindowCreateOptions_new(_:  AzLayoutCallbackType) -> AzWindowCreateOptions;
+        pub(crate) fn AzClipboardContent_text(_:  AzString) -> AzClipboardContent;
+        pub(crate) fn AzClipboardContent_uriList(_:  AzStringVec) -> AzClipboardContent;
+        pub(crate) fn AzClipboardContent_png(_:  AzU8Vec) -> AzClipboardContent;
+        pub(crate) fn AzClipboardContent_isText(_:  &AzClipboardContent) -> bool;
+        pub(crate) fn AzClipboardContent_getText(_:  &AzClipboardContent) -> AzOptionString;
+        pub(crate) fn AzClipboardContent_getUriList(_:  &AzClipboardContent) -> AzStringVec;
         pub(crate) fn AzLogicalPosition_new(_:  f32, _:  f32) -> AzLogicalPosition;
         pub(crate) fn AzLogicalPosition_zero() -> AzLogicalPosition;
         pub(crate) fn AzLogicalSize_toPhysical(_:  &AzLogicalSize, _:  f32) -> AzPhysicalSizeU32;
@@ -10430,6 +12623,10 @@ mod dll {
         pub(crate) fn AzCallbackInfo_getPreviousWindowState(_:  &AzCallbackInfo) -> AzOptionWindowState;
         pub(crate) fn AzCallbackInfo_getPreviousKeyboardState(_:  &AzCallbackInfo) -> AzOptionKeyboardState;
         pub(crate) fn AzCallbackInfo_getPreviousMouseState(_:  &AzCallbackInfo) -> AzOptionMouseState;
+        pub(crate) fn AzCallbackInfo_getHoveredFile(_:  &AzCallbackInfo) -> AzOptionString;
+        pub(crate) fn AzCallbackInfo_getDroppedFile(_:  &AzCallbackInfo) -> AzOptionString;
+        pub(crate) fn AzCallbackInfo_getHoveredText(_:  &AzCallbackInfo) -> AzOptionString;
+        pub(crate) fn AzCallbackInfo_getDroppedText(_:  &AzCallbackInfo) -> AzOptionString;
         pub(crate) fn AzCallbackInfo_getCurrentWindowHandle(_:  &AzCallbackInfo) -> AzRawWindowHandle;
         pub(crate) fn AzCallbackInfo_getGlContext(_:  &AzCallbackInfo) -> AzOptionGl;
         pub(crate) fn AzCallbackInfo_getScrollPosition(_:  &AzCallbackInfo, _:  AzDomNodeId) -> AzOptionLogicalPosition;
@@ -10462,6 +12659,9 @@ mod dll {
         pub(crate) fn AzCallbackInfo_updateImageMask(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzImageMask);
         pub(crate) fn AzCallbackInfo_stopPropagation(_:  &mut AzCallbackInfo);
         pub(crate) fn AzCallbackInfo_createWindow(_:  &mut AzCallbackInfo, _:  AzWindowCreateOptions);
+        pub(crate) fn AzCallbackInfo_getClipboardContent(_:  &AzCallbackInfo, _:  AzClipboardSelection, _:  AzString) -> AzOptionClipboardContent;
+        pub(crate) fn AzCallbackInfo_getClipboardText(_:  &AzCallbackInfo, _:  AzClipboardSelection) -> AzOptionString;
+        pub(crate) fn AzCallbackInfo_setClipboardContent(_:  &mut AzCallbackInfo, _:  AzClipboardSelection, _:  AzClipboardContent) -> bool;
         pub(crate) fn AzCallbackInfo_startTimer(_:  &mut AzCallbackInfo, _:  AzTimer) -> AzTimerId;
         pub(crate) fn AzCallbackInfo_startAnimation(_:  &mut AzCallbackInfo, _:  AzDomNodeId, _:  AzAnimation) -> AzOptionTimerId;
         pub(crate) fn AzCallbackInfo_stopTimer(_:  &mut AzCallbackInfo, _:  AzTimerId) -> bool;
@@ -10475,6 +12675,8 @@ mod dll {
         pub(crate) fn AzHidpiAdjustedBounds_getPhysicalSize(_:  &AzHidpiAdjustedBounds) -> AzPhysicalSizeU32;
         pub(crate) fn AzHidpiAdjustedBounds_getHidpiFactor(_:  &AzHidpiAdjustedBounds) -> f32;
         pub(crate) fn AzInlineText_hitTest(_:  &AzInlineText, _:  AzLogicalPosition) -> AzInlineTextHitVec;
+        pub(crate) fn AzInlineText_getCursorRect(_:  &AzInlineText, _:  usize) -> AzOptionLogicalRect;
+        pub(crate) fn AzInlineText_getSelectionRects(_:  &AzInlineText, _:  usize, _:  usize) -> AzLogicalRectVec;
         pub(crate) fn AzResolvedTextLayoutOptions_default() -> AzResolvedTextLayoutOptions;
         pub(crate) fn AzRenderImageCallbackInfo_getGlContext(_:  &AzRenderImageCallbackInfo) -> AzOptionGl;
         pub(crate) fn AzRenderImageCallbackInfo_getBounds(_:  &AzRenderImageCallbackInfo) -> AzHidpiAdjustedBounds;
@@ -10551,10 +12753,14 @@ mod dll {
         pub(crate) fn AzDom_withTabIndex(_:  &mut AzDom, _:  AzTabIndex) -> AzDom;
         pub(crate) fn AzDom_setAccessibilityInfo(_:  &mut AzDom, _:  AzAccessibilityInfo);
         pub(crate) fn AzDom_withAccessibilityInfo(_:  &mut AzDom, _:  AzAccessibilityInfo) -> AzDom;
+        pub(crate) fn AzDom_setAccessibilityActions(_:  &mut AzDom, _:  AzAccessibilityActionVec);
+        pub(crate) fn AzDom_withAccessibilityActions(_:  &mut AzDom, _:  AzAccessibilityActionVec) -> AzDom;
         pub(crate) fn AzDom_setMenuBar(_:  &mut AzDom, _:  AzMenu);
         pub(crate) fn AzDom_withMenuBar(_:  &mut AzDom, _:  AzMenu) -> AzDom;
         pub(crate) fn AzDom_setContextMenu(_:  &mut AzDom, _:  AzMenu);
         pub(crate) fn AzDom_withContextMenu(_:  &mut AzDom, _:  AzMenu) -> AzDom;
+        pub(crate) fn AzDom_setTooltip(_:  &mut AzDom, _:  AzTooltip);
+        pub(crate) fn AzDom_withTooltip(_:  &mut AzDom, _:  AzTooltip) -> AzDom;
         pub(crate) fn AzDom_hash(_:  &AzDom) -> u64;
         pub(crate) fn AzDom_nodeCount(_:  &AzDom) -> usize;
         pub(crate) fn AzDom_getHtmlString(_:  &mut AzDom) -> AzString;
@@ -10590,10 +12796,18 @@ mod dll {
         pub(crate) fn AzNodeData_setClipMask(_:  &mut AzNodeData, _:  AzImageMask);
         pub(crate) fn AzNodeData_setTabIndex(_:  &mut AzNodeData, _:  AzTabIndex);
         pub(crate) fn AzNodeData_setAccessibilityInfo(_:  &mut AzNodeData, _:  AzAccessibilityInfo);
+        pub(crate) fn AzNodeData_setAccessibilityActions(_:  &mut AzNodeData, _:  AzAccessibilityActionVec);
+        pub(crate) fn AzNodeData_addAccessibilityAction(_:  &mut AzNodeData, _:  AzAccessibilityAction);
+        pub(crate) fn AzNodeData_withAccessibilityActions(_:  &mut AzNodeData, _:  AzAccessibilityActionVec) -> AzNodeData;
         pub(crate) fn AzNodeData_setMenuBar(_:  &mut AzNodeData, _:  AzMenu);
         pub(crate) fn AzNodeData_setContextMenu(_:  &mut AzNodeData, _:  AzMenu);
+        pub(crate) fn AzNodeData_setTooltip(_:  &mut AzNodeData, _:  AzTooltip);
         pub(crate) fn AzNodeData_hash(_:  &AzNodeData) -> u64;
         pub(crate) fn AzOn_intoEventFilter(_:  AzOn) -> AzEventFilter;
+        pub(crate) fn AzTooltip_text(_:  AzString) -> AzTooltip;
+        pub(crate) fn AzTooltip_dom(_:  AzRefAny, _:  AzTooltipCallbackType) -> AzTooltip;
+        pub(crate) fn AzTooltip_setDelay(_:  &mut AzTooltip, _:  AzDuration);
+        pub(crate) fn AzTooltip_withDelay(_:  &mut AzTooltip, _:  AzDuration) -> AzTooltip;
         pub(crate) fn AzMenu_new(_:  AzMenuItemVec) -> AzMenu;
         pub(crate) fn AzMenu_setPopupPosition(_:  &mut AzMenu, _:  AzMenuPopupPosition);
         pub(crate) fn AzMenu_withPopupPosition(_:  &mut AzMenu, _:  AzMenuPopupPosition) -> AzMenu;
@@ -10617,7 +12831,35 @@ mod dll {
         pub(crate) fn AzCssProperty_getValueString(_:  &AzCssProperty) -> AzString;
         pub(crate) fn AzCssProperty_getKeyValueString(_:  &AzCssProperty) -> AzString;
         pub(crate) fn AzCssProperty_interpolate(_:  &AzCssProperty, _:  AzCssProperty, _:  f32, _:  AzInterpolateContext) -> AzCssProperty;
-        pub(crate) fn AzRibbon_dom(_:  &mut AzRibbon, _:  AzRibbonOnTabClickedCallback, _:  AzRefAny) -> AzDom;
+        pub(crate) fn AzRibbon_new(_:  AzRibbonTabVec) -> AzRibbon;
+        pub(crate) fn AzRibbon_setTabActive(_:  &mut AzRibbon, _:  i32);
+        pub(crate) fn AzRibbon_withTabActive(_:  &mut AzRibbon, _:  i32) -> AzRibbon;
+        pub(crate) fn AzRibbon_setAvailableWidth(_:  &mut AzRibbon, _:  f32);
+        pub(crate) fn AzRibbon_withAvailableWidth(_:  &mut AzRibbon, _:  f32) -> AzRibbon;
+        pub(crate) fn AzRibbon_setOnTabClicked(_:  &mut AzRibbon, _:  AzRefAny, _:  AzRibbonOnTabClickedCallbackType);
+        pub(crate) fn AzRibbon_withOnTabClicked(_:  &mut AzRibbon, _:  AzRefAny, _:  AzRibbonOnTabClickedCallbackType) -> AzRibbon;
+        pub(crate) fn AzRibbon_dom(_:  &mut AzRibbon) -> AzDom;
+        pub(crate) fn AzRibbonTab_new(_:  AzString, _:  AzRibbonGroupVec) -> AzRibbonTab;
+        pub(crate) fn AzRibbonTab_setKeyTip(_:  &mut AzRibbonTab, _:  AzString);
+        pub(crate) fn AzRibbonTab_withKeyTip(_:  &mut AzRibbonTab, _:  AzString) -> AzRibbonTab;
+        pub(crate) fn AzRibbonGroup_new(_:  AzString, _:  AzRibbonItemVec) -> AzRibbonGroup;
+        pub(crate) fn AzRibbonGroup_setIcon(_:  &mut AzRibbonGroup, _:  AzImageRef);
+        pub(crate) fn AzRibbonGroup_withIcon(_:  &mut AzRibbonGroup, _:  AzImageRef) -> AzRibbonGroup;
+        pub(crate) fn AzRibbonItem_new(_:  AzRibbonItemKind, _:  AzString) -> AzRibbonItem;
+        pub(crate) fn AzRibbonItem_setIcon(_:  &mut AzRibbonItem, _:  AzImageRef);
+        pub(crate) fn AzRibbonItem_withIcon(_:  &mut AzRibbonItem, _:  AzImageRef) -> AzRibbonItem;
+        pub(crate) fn AzRibbonItem_setTooltip(_:  &mut AzRibbonItem, _:  AzString);
+        pub(crate) fn AzRibbonItem_withTooltip(_:  &mut AzRibbonItem, _:  AzString) -> AzRibbonItem;
+        pub(crate) fn AzRibbonItem_setKeyTip(_:  &mut AzRibbonItem, _:  AzString);
+        pub(crate) fn AzRibbonItem_withKeyTip(_:  &mut AzRibbonItem, _:  AzString) -> AzRibbonItem;
+        pub(crate) fn AzRibbonItem_setEnabled(_:  &mut AzRibbonItem, _:  bool);
+        pub(crate) fn AzRibbonItem_withEnabled(_:  &mut AzRibbonItem, _:  bool) -> AzRibbonItem;
+        pub(crate) fn AzRibbonItem_setToggled(_:  &mut AzRibbonItem, _:  bool);
+        pub(crate) fn AzRibbonItem_withToggled(_:  &mut AzRibbonItem, _:  bool) -> AzRibbonItem;
+        pub(crate) fn AzRibbonItem_setChoices(_:  &mut AzRibbonItem, _:  AzStringVec);
+        pub(crate) fn AzRibbonItem_withChoices(_:  &mut AzRibbonItem, _:  AzStringVec) -> AzRibbonItem;
+        pub(crate) fn AzRibbonItem_setOnClick(_:  &mut AzRibbonItem, _:  AzRefAny, _:  AzRibbonOnItemClickCallbackType);
+        pub(crate) fn AzRibbonItem_withOnClick(_:  &mut AzRibbonItem, _:  AzRefAny, _:  AzRibbonOnItemClickCallbackType) -> AzRibbonItem;
         pub(crate) fn AzButton_new(_:  AzString) -> AzButton;
         pub(crate) fn AzButton_setOnClick(_:  &mut AzButton, _:  AzRefAny, _:  AzCallbackType);
         pub(crate) fn AzButton_withOnClick(_:  &mut AzButton, _:  AzRefAny, _:  AzCallbackType) -> AzButton;
@@ -10657,6 +12899,23 @@ mod dll {
         pub(crate) fn AzTextInput_withLabelStyle(_:  &mut AzTextInput, _:  AzNodeDataInlineCssPropertyVec) -> AzTextInput;
         pub(crate) fn AzTextInput_dom(_:  &mut AzTextInput) -> AzDom;
         pub(crate) fn AzTextInputState_getText(_:  &AzTextInputState) -> AzString;
+        pub(crate) fn AzTextArea_new() -> AzTextArea;
+        pub(crate) fn AzTextArea_setText(_:  &mut AzTextArea, _:  AzString);
+        pub(crate) fn AzTextArea_withText(_:  &mut AzTextArea, _:  AzString) -> AzTextArea;
+        pub(crate) fn AzTextArea_setState(_:  &mut AzTextArea, _:  AzTextAreaState);
+        pub(crate) fn AzTextArea_withState(_:  &mut AzTextArea, _:  AzTextAreaState) -> AzTextArea;
+        pub(crate) fn AzTextArea_setOnTextChange(_:  &mut AzTextArea, _:  AzRefAny, _:  AzTextAreaOnTextChangeCallbackType);
+        pub(crate) fn AzTextArea_withOnTextChange(_:  &mut AzTextArea, _:  AzRefAny, _:  AzTextAreaOnTextChangeCallbackType) -> AzTextArea;
+        pub(crate) fn AzTextArea_swapWithDefault(_:  &mut AzTextArea) -> AzTextArea;
+        pub(crate) fn AzTextArea_dom(_:  &mut AzTextArea) -> AzDom;
+        pub(crate) fn AzTextAreaState_getText(_:  &AzTextAreaState) -> AzString;
+        pub(crate) fn AzTextAreaState_setText(_:  &mut AzTextAreaState, _:  AzString);
+        pub(crate) fn AzTextAreaState_getSelectedText(_:  &AzTextAreaState) -> AzString;
+        pub(crate) fn AzTextAreaState_selectAll(_:  &mut AzTextAreaState);
+        pub(crate) fn AzTextAreaState_canUndo(_:  &AzTextAreaState) -> bool;
+        pub(crate) fn AzTextAreaState_canRedo(_:  &AzTextAreaState) -> bool;
+        pub(crate) fn AzTextAreaState_undo(_:  &mut AzTextAreaState) -> bool;
+        pub(crate) fn AzTextAreaState_redo(_:  &mut AzTextAreaState) -> bool;
         pub(crate) fn AzNumberInput_new(_:  f32) -> AzNumberInput;
         pub(crate) fn AzNumberInput_setOnTextInput(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzTextInputOnTextInputCallbackType);
         pub(crate) fn AzNumberInput_withOnTextInput(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzTextInputOnTextInputCallbackType) -> AzNumberInput;
@@ -10674,6 +12933,7 @@ mod dll {
         pub(crate) fn AzNumberInput_withOnValueChange(_:  &mut AzNumberInput, _:  AzRefAny, _:  AzNumberInputOnValueChangeCallbackType) -> AzNumberInput;
         pub(crate) fn AzNumberInput_dom(_:  &mut AzNumberInput) -> AzDom;
         pub(crate) fn AzProgressBar_new(_:  f32) -> AzProgressBar;
+        pub(crate) fn AzProgressBar_indeterminate() -> AzProgressBar;
         pub(crate) fn AzProgressBar_setHeight(_:  &mut AzProgressBar, _:  AzPixelValue);
         pub(crate) fn AzProgressBar_withHeight(_:  &mut AzProgressBar, _:  AzPixelValue) -> AzProgressBar;
         pub(crate) fn AzProgressBar_setContainerBackground(_:  &mut AzProgressBar, _:  AzStyleBackgroundContentVec);
@@ -10681,11 +12941,35 @@ mod dll {
         pub(crate) fn AzProgressBar_setBarBackground(_:  &mut AzProgressBar, _:  AzStyleBackgroundContentVec);
         pub(crate) fn AzProgressBar_withBarBackground(_:  &mut AzProgressBar, _:  AzStyleBackgroundContentVec) -> AzProgressBar;
         pub(crate) fn AzProgressBar_dom(_:  &mut AzProgressBar) -> AzDom;
+        pub(crate) fn AzProgressBar_setMode(_:  &mut AzProgressBar, _:  AzProgressBarMode);
+        pub(crate) fn AzProgressBar_withMode(_:  &mut AzProgressBar, _:  AzProgressBarMode) -> AzProgressBar;
+        pub(crate) fn AzProgressBar_setStyle(_:  &mut AzProgressBar, _:  AzProgressBarStyle);
+        pub(crate) fn AzProgressBar_withStyle(_:  &mut AzProgressBar, _:  AzProgressBarStyle) -> AzProgressBar;
+        pub(crate) fn AzProgressBar_setDisplayPercentage(_:  &mut AzProgressBar, _:  bool);
+        pub(crate) fn AzProgressBar_withDisplayPercentage(_:  &mut AzProgressBar, _:  bool) -> AzProgressBar;
+        pub(crate) fn AzProgressBar_setTextFormat(_:  &mut AzProgressBar, _:  AzString);
+        pub(crate) fn AzProgressBar_withTextFormat(_:  &mut AzProgressBar, _:  AzString) -> AzProgressBar;
+        pub(crate) fn AzSpinner_new() -> AzSpinner;
+        pub(crate) fn AzSpinner_setSize(_:  &mut AzSpinner, _:  AzPixelValue);
+        pub(crate) fn AzSpinner_withSize(_:  &mut AzSpinner, _:  AzPixelValue) -> AzSpinner;
+        pub(crate) fn AzSpinner_setThickness(_:  &mut AzSpinner, _:  AzPixelValue);
+        pub(crate) fn AzSpinner_withThickness(_:  &mut AzSpinner, _:  AzPixelValue) -> AzSpinner;
+        pub(crate) fn AzSpinner_setColor(_:  &mut AzSpinner, _:  AzColorU);
+        pub(crate) fn AzSpinner_withColor(_:  &mut AzSpinner, _:  AzColorU) -> AzSpinner;
+        pub(crate) fn AzSpinner_setTrackColor(_:  &mut AzSpinner, _:  AzColorU);
+        pub(crate) fn AzSpinner_withTrackColor(_:  &mut AzSpinner, _:  AzColorU) -> AzSpinner;
+        pub(crate) fn AzSpinner_dom(_:  &mut AzSpinner) -> AzDom;
         pub(crate) fn AzTabHeader_new(_:  AzStringVec) -> AzTabHeader;
         pub(crate) fn AzTabHeader_setActiveTab(_:  &mut AzTabHeader, _:  usize);
         pub(crate) fn AzTabHeader_withActiveTab(_:  &mut AzTabHeader, _:  usize) -> AzTabHeader;
         pub(crate) fn AzTabHeader_setOnClick(_:  &mut AzTabHeader, _:  AzRefAny, _:  AzTabOnClickCallbackType);
         pub(crate) fn AzTabHeader_withOnClick(_:  &mut AzTabHeader, _:  AzRefAny, _:  AzTabOnClickCallbackType) -> AzTabHeader;
+        pub(crate) fn AzTabHeader_setOnClose(_:  &mut AzTabHeader, _:  AzRefAny, _:  AzTabOnCloseCallbackType);
+        pub(crate) fn AzTabHeader_withOnClose(_:  &mut AzTabHeader, _:  AzRefAny, _:  AzTabOnCloseCallbackType) -> AzTabHeader;
+        pub(crate) fn AzTabHeader_setOnReorder(_:  &mut AzTabHeader, _:  AzRefAny, _:  AzTabOnReorderCallbackType);
+        pub(crate) fn AzTabHeader_withOnReorder(_:  &mut AzTabHeader, _:  AzRefAny, _:  AzTabOnReorderCallbackType) -> AzTabHeader;
+        pub(crate) fn AzTabHeader_setOnContextMenu(_:  &mut AzTabHeader, _:  AzRefAny, _:  AzTabOnContextMenuCallbackType);
+        pub(crate) fn AzTabHeader_withOnContextMenu(_:  &mut AzTabHeader, _:  AzRefAny, _:  AzTabOnContextMenuCallbackType) -> AzTabHeader;
         pub(crate) fn AzTabHeader_dom(_:  &mut AzTabHeader) -> AzDom;
         pub(crate) fn AzTabContent_new(_:  AzDom) -> AzTabContent;
         pub(crate) fn AzTabContent_setPadding(_:  &mut AzTabContent, _:  bool);
@@ -10695,13 +12979,84 @@ mod dll {
         pub(crate) fn AzFrame_setFlexGrow(_:  &mut AzFrame, _:  f32);
         pub(crate) fn AzFrame_withFlexGrow(_:  &mut AzFrame, _:  f32) -> AzFrame;
         pub(crate) fn AzFrame_dom(_:  &mut AzFrame) -> AzDom;
+        pub(crate) fn AzSplitter_new(_:  AzSplitterOrientation, _:  AzSplitterPaneVec) -> AzSplitter;
+        pub(crate) fn AzSplitter_setState(_:  &mut AzSplitter, _:  AzSplitterState);
+        pub(crate) fn AzSplitter_withState(_:  &mut AzSplitter, _:  AzSplitterState) -> AzSplitter;
+        pub(crate) fn AzSplitter_setOnResize(_:  &mut AzSplitter, _:  AzRefAny, _:  AzSplitterOnResizeCallbackType);
+        pub(crate) fn AzSplitter_withOnResize(_:  &mut AzSplitter, _:  AzRefAny, _:  AzSplitterOnResizeCallbackType) -> AzSplitter;
+        pub(crate) fn AzSplitter_dom(_:  &mut AzSplitter) -> AzDom;
+        pub(crate) fn AzSplitterPane_new(_:  AzDom) -> AzSplitterPane;
+        pub(crate) fn AzSplitterPane_setSize(_:  &mut AzSplitterPane, _:  f32);
+        pub(crate) fn AzSplitterPane_withSize(_:  &mut AzSplitterPane, _:  f32) -> AzSplitterPane;
+        pub(crate) fn AzSplitterPane_setMinSize(_:  &mut AzSplitterPane, _:  f32);
+        pub(crate) fn AzSplitterPane_withMinSize(_:  &mut AzSplitterPane, _:  f32) -> AzSplitterPane;
+        pub(crate) fn AzSplitterPane_setMaxSize(_:  &mut AzSplitterPane, _:  f32);
+        pub(crate) fn AzSplitterPane_withMaxSize(_:  &mut AzSplitterPane, _:  f32) -> AzSplitterPane;
+        pub(crate) fn AzSplitterPane_setCollapsible(_:  &mut AzSplitterPane, _:  bool);
+        pub(crate) fn AzSplitterPane_withCollapsible(_:  &mut AzSplitterPane, _:  bool) -> AzSplitterPane;
+        pub(crate) fn AzSlider_new(_:  AzSliderOrientation, _:  f32, _:  f32, _:  f32) -> AzSlider;
+        pub(crate) fn AzSlider_setStep(_:  &mut AzSlider, _:  f32);
+        pub(crate) fn AzSlider_withStep(_:  &mut AzSlider, _:  f32) -> AzSlider;
+        pub(crate) fn AzSlider_setTickInterval(_:  &mut AzSlider, _:  f32);
+        pub(crate) fn AzSlider_withTickInterval(_:  &mut AzSlider, _:  f32) -> AzSlider;
+        pub(crate) fn AzSlider_setOnValueChange(_:  &mut AzSlider, _:  AzRefAny, _:  AzSliderOnValueChangeCallbackType);
+        pub(crate) fn AzSlider_withOnValueChange(_:  &mut AzSlider, _:  AzRefAny, _:  AzSliderOnValueChangeCallbackType) -> AzSlider;
+        pub(crate) fn AzSlider_dom(_:  &mut AzSlider) -> AzDom;
+        pub(crate) fn AzRangeSlider_new(_:  AzSliderOrientation, _:  f32, _:  f32, _:  f32, _:  f32) -> AzRangeSlider;
+        pub(crate) fn AzRangeSlider_setStep(_:  &mut AzRangeSlider, _:  f32);
+        pub(crate) fn AzRangeSlider_withStep(_:  &mut AzRangeSlider, _:  f32) -> AzRangeSlider;
+        pub(crate) fn AzRangeSlider_setTickInterval(_:  &mut AzRangeSlider, _:  f32);
+        pub(crate) fn AzRangeSlider_withTickInterval(_:  &mut AzRangeSlider, _:  f32) -> AzRangeSlider;
+        pub(crate) fn AzRangeSlider_setOnValueChange(_:  &mut AzRangeSlider, _:  AzRefAny, _:  AzRangeSliderOnValueChangeCallbackType);
+        pub(crate) fn AzRangeSlider_withOnValueChange(_:  &mut AzRangeSlider, _:  AzRefAny, _:  AzRangeSliderOnValueChangeCallbackType) -> AzRangeSlider;
+        pub(crate) fn AzRangeSlider_dom(_:  &mut AzRangeSlider) -> AzDom;
         pub(crate) fn AzNodeGraph_dom(_:  &mut AzNodeGraph) -> AzDom;
         pub(crate) fn AzListView_new(_:  AzStringVec) -> AzListView;
         pub(crate) fn AzListView_withRows(_:  &mut AzListView, _:  AzListViewRowVec) -> AzListView;
+        pub(crate) fn AzListView_setSortedBy(_:  &mut AzListView, _:  AzOptionUsize);
+        pub(crate) fn AzListView_withSortedBy(_:  &mut AzListView, _:  AzOptionUsize) -> AzListView;
+        pub(crate) fn AzListView_setScrollOffset(_:  &mut AzListView, _:  AzPixelValueNoPercent);
+        pub(crate) fn AzListView_withScrollOffset(_:  &mut AzListView, _:  AzPixelValueNoPercent) -> AzListView;
+        pub(crate) fn AzListView_setRowHeight(_:  &mut AzListView, _:  AzPixelValueNoPercent);
+        pub(crate) fn AzListView_withRowHeight(_:  &mut AzListView, _:  AzPixelValueNoPercent) -> AzListView;
+        pub(crate) fn AzListView_setColumnWidths(_:  &mut AzListView, _:  AzF32Vec);
+        pub(crate) fn AzListView_withColumnWidths(_:  &mut AzListView, _:  AzF32Vec) -> AzListView;
+        pub(crate) fn AzListView_setRowProvider(_:  &mut AzListView, _:  usize, _:  AzRefAny, _:  AzListViewRowProviderCallbackType);
+        pub(crate) fn AzListView_withRowProvider(_:  &mut AzListView, _:  usize, _:  AzRefAny, _:  AzListViewRowProviderCallbackType) -> AzListView;
+        pub(crate) fn AzListView_setOnLazyLoadScroll(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnLazyLoadScrollCallbackType);
+        pub(crate) fn AzListView_withOnLazyLoadScroll(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnLazyLoadScrollCallbackType) -> AzListView;
+        pub(crate) fn AzListView_setOnColumnClick(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnColumnClickCallbackType);
+        pub(crate) fn AzListView_withOnColumnClick(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnColumnClickCallbackType) -> AzListView;
+        pub(crate) fn AzListView_setOnColumnResize(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnColumnResizeCallbackType);
+        pub(crate) fn AzListView_withOnColumnResize(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnColumnResizeCallbackType) -> AzListView;
+        pub(crate) fn AzListView_setOnRowClick(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnRowClickCallbackType);
+        pub(crate) fn AzListView_withOnRowClick(_:  &mut AzListView, _:  AzRefAny, _:  AzListViewOnRowClickCallbackType) -> AzListView;
         pub(crate) fn AzListView_dom(_:  &mut AzListView) -> AzDom;
-        pub(crate) fn AzTreeView_new(_:  AzString) -> AzTreeView;
+        pub(crate) fn AzTreeView_new(_:  AzTreeViewNodeVec) -> AzTreeView;
+        pub(crate) fn AzTreeView_setNodes(_:  &mut AzTreeView, _:  AzTreeViewNodeVec);
+        pub(crate) fn AzTreeView_withNodes(_:  &mut AzTreeView, _:  AzTreeViewNodeVec) -> AzTreeView;
+        pub(crate) fn AzTreeView_setState(_:  &mut AzTreeView, _:  AzTreeViewState);
+        pub(crate) fn AzTreeView_withState(_:  &mut AzTreeView, _:  AzTreeViewState) -> AzTreeView;
+        pub(crate) fn AzTreeView_setSelectionMode(_:  &mut AzTreeView, _:  AzTreeViewSelectionMode);
+        pub(crate) fn AzTreeView_withSelectionMode(_:  &mut AzTreeView, _:  AzTreeViewSelectionMode) -> AzTreeView;
+        pub(crate) fn AzTreeView_setOnExpand(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnExpandCallbackType);
+        pub(crate) fn AzTreeView_withOnExpand(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnExpandCallbackType) -> AzTreeView;
+        pub(crate) fn AzTreeView_setOnSelect(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnSelectCallbackType);
+        pub(crate) fn AzTreeView_withOnSelect(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnSelectCallbackType) -> AzTreeView;
+        pub(crate) fn AzTreeView_setOnDoubleClick(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnDoubleClickCallbackType);
+        pub(crate) fn AzTreeView_withOnDoubleClick(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnDoubleClickCallbackType) -> AzTreeView;
+        pub(crate) fn AzTreeView_setOnContextMenu(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnContextMenuCallbackType);
+        pub(crate) fn AzTreeView_withOnContextMenu(_:  &mut AzTreeView, _:  AzRefAny, _:  AzTreeViewOnContextMenuCallbackType) -> AzTreeView;
         pub(crate) fn AzTreeView_dom(_:  &mut AzTreeView) -> AzDom;
+        pub(crate) fn AzTreeViewNode_new(_:  AzTreeViewNodeId, _:  AzOptionTreeViewNodeId, _:  AzString) -> AzTreeViewNode;
         pub(crate) fn AzDropDown_new(_:  AzStringVec) -> AzDropDown;
+        pub(crate) fn AzDropDown_setSelected(_:  &mut AzDropDown, _:  usize);
+        pub(crate) fn AzDropDown_withSelected(_:  &mut AzDropDown, _:  usize) -> AzDropDown;
+        pub(crate) fn AzDropDown_setDisabledChoices(_:  &mut AzDropDown, _:  AzU32Vec);
+        pub(crate) fn AzDropDown_withDisabledChoices(_:  &mut AzDropDown, _:  AzU32Vec) -> AzDropDown;
+        pub(crate) fn AzDropDown_setOnChoiceChange(_:  &mut AzDropDown, _:  AzRefAny, _:  AzDropDownOnChoiceChangeCallbackType);
+        pub(crate) fn AzDropDown_withOnChoiceChange(_:  &mut AzDropDown, _:  AzRefAny, _:  AzDropDownOnChoiceChangeCallbackType) -> AzDropDown;
+        pub(crate) fn AzDropDown_swapWithDefault(_:  &mut AzDropDown) -> AzDropDown;
         pub(crate) fn AzDropDown_dom(_:  &mut AzDropDown) -> AzDom;
         pub(crate) fn AzCssPropertyCache_delete(_:  &mut AzCssPropertyCache);
         pub(crate) fn AzCssPropertyCache_deepCopy(_:  &AzCssPropertyCache) -> AzCssPropertyCache;
@@ -11148,6 +13503,14 @@ mod dll {
         pub(crate) fn AzString_trim(_:  &AzString) -> AzString;
         pub(crate) fn AzString_asRefstr(_:  &AzString) -> AzRefstr;
         pub(crate) fn AzListViewRowVec_delete(_:  &mut AzListViewRowVec);
+        pub(crate) fn AzTreeViewNodeVec_delete(_:  &mut AzTreeViewNodeVec);
+        pub(crate) fn AzTreeViewNodeIdVec_delete(_:  &mut AzTreeViewNodeIdVec);
+        pub(crate) fn AzRibbonTabVec_delete(_:  &mut AzRibbonTabVec);
+        pub(crate) fn AzRibbonGroupVec_delete(_:  &mut AzRibbonGroupVec);
+        pub(crate) fn AzRibbonItemVec_delete(_:  &mut AzRibbonItemVec);
+        pub(crate) fn AzSplitterPaneVec_delete(_:  &mut AzSplitterPaneVec);
+        pub(crate) fn AzSplitterPaneStateVec_delete(_:  &mut AzSplitterPaneStateVec);
+        pub(crate) fn AzTextAreaEditVec_delete(_:  &mut AzTextAreaEditVec);
         pub(crate) fn AzStyleFilterVec_delete(_:  &mut AzStyleFilterVec);
         pub(crate) fn AzLogicalRectVec_delete(_:  &mut AzLogicalRectVec);
         pub(crate) fn AzNodeTypeIdInfoMapVec_delete(_:  &mut AzNodeTypeIdInfoMapVec);
@@ -11160,6 +13523,7 @@ mod dll {
         pub(crate) fn AzOutputConnectionVec_delete(_:  &mut AzOutputConnectionVec);
         pub(crate) fn AzInputNodeAndIndexVec_delete(_:  &mut AzInputNodeAndIndexVec);
         pub(crate) fn AzAccessibilityStateVec_delete(_:  &mut AzAccessibilityStateVec);
+        pub(crate) fn AzAccessibilityActionVec_delete(_:  &mut AzAccessibilityActionVec);
         pub(crate) fn AzMenuItemVec_delete(_:  &mut AzMenuItemVec);
         pub(crate) fn AzTessellatedSvgNodeVec_asRefVec(_:  &AzTessellatedSvgNodeVec) -> AzTessellatedSvgNodeVecRef;
         pub(crate) fn AzTessellatedSvgNodeVec_delete(_:  &mut AzTessellatedSvgNodeVec);
@@ -11180,6 +13544,15 @@ mod dll {
         pub(crate) fn AzStyleBackgroundRepeatVec_delete(_:  &mut AzStyleBackgroundRepeatVec);
         pub(crate) fn AzStyleBackgroundSizeVec_delete(_:  &mut AzStyleBackgroundSizeVec);
         pub(crate) fn AzStyleTransformVec_delete(_:  &mut AzStyleTransformVec);
+        pub(crate) fn AzStyleTransitionPropertyVec_delete(_:  &mut AzStyleTransitionPropertyVec);
+        pub(crate) fn AzStyleTimeVec_delete(_:  &mut AzStyleTimeVec);
+        pub(crate) fn AzStyleTimingFunctionVec_delete(_:  &mut AzStyleTimingFunctionVec);
+        pub(crate) fn AzStyleAnimationNameVec_delete(_:  &mut AzStyleAnimationNameVec);
+        pub(crate) fn AzStyleAnimationIterationCountVec_delete(_:  &mut AzStyleAnimationIterationCountVec);
+        pub(crate) fn AzStyleAnimationDirectionVec_delete(_:  &mut AzStyleAnimationDirectionVec);
+        pub(crate) fn AzStyleAnimationFillModeVec_delete(_:  &mut AzStyleAnimationFillModeVec);
+        pub(crate) fn AzStyleAnimationPlayStateVec_delete(_:  &mut AzStyleAnimationPlayStateVec);
+        pub(crate) fn AzGridTrackSizingVec_delete(_:  &mut AzGridTrackSizingVec);
         pub(crate) fn AzCssPropertyVec_delete(_:  &mut AzCssPropertyVec);
         pub(crate) fn AzSvgMultiPolygonVec_delete(_:  &mut AzSvgMultiPolygonVec);
         pub(crate) fn AzSvgPathVec_delete(_:  &mut AzSvgPathVec);
@@ -11194,6 +13567,11 @@ mod dll {
         pub(crate) fn AzCssDeclarationVec_delete(_:  &mut AzCssDeclarationVec);
         pub(crate) fn AzCssPathSelectorVec_delete(_:  &mut AzCssPathSelectorVec);
         pub(crate) fn AzStylesheetVec_delete(_:  &mut AzStylesheetVec);
+        pub(crate) fn AzCssKeyframesVec_delete(_:  &mut AzCssKeyframesVec);
+        pub(crate) fn AzCssKeyframeVec_delete(_:  &mut AzCssKeyframeVec);
+        pub(crate) fn AzCssMediaRuleVec_delete(_:  &mut AzCssMediaRuleVec);
+        pub(crate) fn AzCssMediaQueryVec_delete(_:  &mut AzCssMediaQueryVec);
+        pub(crate) fn AzCssMediaFeatureVec_delete(_:  &mut AzCssMediaFeatureVec);
         pub(crate) fn AzCssRuleBlockVec_delete(_:  &mut AzCssRuleBlockVec);
         pub(crate) fn AzU16Vec_delete(_:  &mut AzU16Vec);
         pub(crate) fn AzF32Vec_delete(_:  &mut AzF32Vec);
@@ -11346,6 +13724,12 @@ pub mod window {
     #[cfg(feature = "link_static")]
     pub use azul::AzAndroidHandle as AndroidHandle;
     #[cfg(feature = "link_static")]
+    pub use azul::AzClipboardSelection as ClipboardSelection;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzClipboardContent as ClipboardContent;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzLinuxBackend as LinuxBackend;
+    #[cfg(feature = "link_static")]
     pub use azul::AzXWindowType as XWindowType;
     #[cfg(feature = "link_static")]
     pub use azul::AzPhysicalPositionI32 as PhysicalPositionI32;
@@ -11382,6 +13766,8 @@ pub mod window {
     #[cfg(feature = "link_static")]
     pub use azul::AzKeyboardState as KeyboardState;
     #[cfg(feature = "link_static")]
+    pub use azul::AzImeComposition as ImeComposition;
+    #[cfg(feature = "link_static")]
     pub use azul::AzMouseCursorType as MouseCursorType;
     #[cfg(feature = "link_static")]
     pub use azul::AzCursorPosition as CursorPosition;
@@ -11507,6 +13893,16 @@ pub mod window {
     use crate::callbacks::LayoutCallbackType;
     #[cfg(feature = "link_static")]
     use azul::AzLayoutCallbackType as LayoutCallbackType;
+    #[cfg(not(feature = "link_static"))]
+    use crate::str::String;
+    #[cfg(feature = "link_static")]
+    use azul::AzString as String;
+    #[cfg(not(feature = "link_static"))]
+    use crate::vec::{StringVec, U8Vec};
+    #[cfg(feature = "link_static")]
+    use azul::AzStringVec as StringVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzU8Vec as U8Vec;
     /// Options on how to initially create the window
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzWindowCreateOptions as WindowCreateOptions;
@@ -11564,7 +13960,33 @@ pub mod window {
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzWebHandle as WebHandle;
     /// `AndroidHandle` struct
     
-    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAndroidHandle as AndroidHandle;
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAndroidHandle as AndroidHandle;
+    /// Which system clipboard to read from / write to
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzClipboardSelection as ClipboardSelection;
+    /// Content of the system clipboard, tagged with its MIME type
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzClipboardContent as ClipboardContent;
+    #[cfg(not(feature = "link_static"))]
+    impl ClipboardContent {
+
+        /// Creates a new `ClipboardContent` instance.
+        pub fn text(text: String) -> Self { unsafe { crate::dll::AzClipboardContent_text(text) } }
+        /// Creates a new `ClipboardContent` instance.
+        pub fn uri_list(uris: StringVec) -> Self { unsafe { crate::dll::AzClipboardContent_uriList(uris) } }
+        /// Creates a new `ClipboardContent` instance.
+        pub fn png(data: U8Vec) -> Self { unsafe { crate::dll::AzClipboardContent_png(data) } }
+        /// Calls the `ClipboardContent::is_text` function.
+        pub fn is_text(&self)  -> bool { unsafe { crate::dll::AzClipboardContent_isText(self) } }
+        /// Calls the `ClipboardContent::get_text` function.
+        pub fn get_text(&self)  -> crate::option::OptionString { unsafe { crate::dll::AzClipboardContent_getText(self) } }
+        /// Calls the `ClipboardContent::get_uri_list` function.
+        pub fn get_uri_list(&self)  -> crate::vec::StringVec { unsafe { crate::dll::AzClipboardContent_getUriList(self) } }
+    }
+
+    /// Which windowing system the window should be created with on Linux
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLinuxBackend as LinuxBackend;
     /// X11 window hint: Type of window
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzXWindowType as XWindowType;
@@ -11650,6 +14072,9 @@ pub mod window {
         pub fn is_key_down(&self, key: VirtualKeyCode)  -> bool { unsafe { crate::dll::AzKeyboardState_isKeyDown(self, key) } }
     }
 
+    /// Preedit text of an input method, i.e. the pinyin or kana that was typed before the user selected one of the candidates
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzImeComposition as ImeComposition;
     /// Current icon of the mouse cursor
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzMouseCursorType as MouseCursorType;
@@ -11797,6 +14222,10 @@ pub mod callbacks {
     #[cfg(feature = "link_static")]
     pub use azul::AzIFrameCallbackInfo as IFrameCallbackInfo;
     #[cfg(feature = "link_static")]
+    pub use azul::AzTooltipCallback as TooltipCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTooltipCallbackType as TooltipCallbackType;
+    #[cfg(feature = "link_static")]
     pub use azul::AzIFrameCallbackReturn as IFrameCallbackReturn;
     #[cfg(feature = "link_static")]
     pub use azul::AzRenderImageCallback as RenderImageCallback;
@@ -11983,7 +14412,11 @@ pub mod callbacks {
     #[cfg(feature = "link_static")]
     use azul::AzCssPropertyType as CssPropertyType;
     #[cfg(not(feature = "link_static"))]
-    use crate::window::{LogicalPosition, WindowCreateOptions, WindowState};
+    use crate::window::{ClipboardContent, ClipboardSelection, LogicalPosition, WindowCreateOptions, WindowState};
+    #[cfg(feature = "link_static")]
+    use azul::AzClipboardContent as ClipboardContent;
+    #[cfg(feature = "link_static")]
+    use azul::AzClipboardSelection as ClipboardSelection;
     #[cfg(feature = "link_static")]
     use azul::AzLogicalPosition as LogicalPosition;
     #[cfg(feature = "link_static")]
@@ -12056,6 +14489,14 @@ pub mod callbacks {
         pub fn get_previous_keyboard_state(&self)  -> crate::option::OptionKeyboardState { unsafe { crate::dll::AzCallbackInfo_getPreviousKeyboardState(self) } }
         /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
         pub fn get_previous_mouse_state(&self)  -> crate::option::OptionMouseState { unsafe { crate::dll::AzCallbackInfo_getPreviousMouseState(self) } }
+        /// Returns the path of the file that is currently dragged over the window
+        pub fn get_hovered_file(&self)  -> crate::option::OptionString { unsafe { crate::dll::AzCallbackInfo_getHoveredFile(self) } }
+        /// Returns the path of the file that was dropped on the window (during `On::DroppedFile`)
+        pub fn get_dropped_file(&self)  -> crate::option::OptionString { unsafe { crate::dll::AzCallbackInfo_getDroppedFile(self) } }
+        /// Returns the text that is currently dragged over the window
+        pub fn get_hovered_text(&self)  -> crate::option::OptionString { unsafe { crate::dll::AzCallbackInfo_getHoveredText(self) } }
+        /// Returns the text that was dropped on the window (during `On::DroppedFile`)
+        pub fn get_dropped_text(&self)  -> crate::option::OptionString { unsafe { crate::dll::AzCallbackInfo_getDroppedText(self) } }
         /// Returns a copy of the current windows `RawWindowHandle`.
         pub fn get_current_window_handle(&self)  -> crate::window::RawWindowHandle { unsafe { crate::dll::AzCallbackInfo_getCurrentWindowHandle(self) } }
         /// Returns a **reference-counted copy** of the current windows' `Gl` (context). You can use this to render OpenGL textures.
@@ -12120,6 +14561,12 @@ pub mod callbacks {
         pub fn stop_propagation(&mut self)  { unsafe { crate::dll::AzCallbackInfo_stopPropagation(self) } }
         /// Spawns a new window with the given `WindowCreateOptions`.
         pub fn create_window(&mut self, new_window: WindowCreateOptions)  { unsafe { crate::dll::AzCallbackInfo_createWindow(self, new_window) } }
+        /// Reads the system clipboard (or the X11 PRIMARY selection) in the requested MIME type
+        pub fn get_clipboard_content(&self, selection: ClipboardSelection, mime_type: String)  -> crate::option::OptionClipboardContent { unsafe { crate::dll::AzCallbackInfo_getClipboardContent(self, selection, mime_type) } }
+        /// Shortcut for reading the clipboard as `text/plain;charset=utf-8`
+        pub fn get_clipboard_text(&self, selection: ClipboardSelection)  -> crate::option::OptionString { unsafe { crate::dll::AzCallbackInfo_getClipboardText(self, selection) } }
+        /// Sets the system clipboard (or the X11 PRIMARY selection), returns whether the clipboard could be set
+        pub fn set_clipboard_content(&mut self, selection: ClipboardSelection, content: ClipboardContent)  -> bool { unsafe { crate::dll::AzCallbackInfo_setClipboardContent(self, selection, content) } }
         /// Adds a new `Timer` to the runtime. See the documentation for `Timer` for more information.
         pub fn start_timer(&mut self, timer: Timer)  -> crate::task::TimerId { unsafe { crate::dll::AzCallbackInfo_startTimer(self, timer) } }
         /// Starts an animation timer on a give NodeId - same as a `Timer`, but uses a pre-configured interpolation function to drive the animation timer
@@ -12188,6 +14635,10 @@ pub mod callbacks {
 
         /// Hit-tests the inline text, returns detailed information about which glyph / word / line, etc. the position (usually the mouse cursor) is currently over. Result may be empty (no hits) or contain more than one result (cursor is hovering over multiple overlapping glyphs at once).
         pub fn hit_test(&self, position: LogicalPosition)  -> crate::vec::InlineTextHitVec { unsafe { crate::dll::AzInlineText_hitTest(self, position) } }
+        /// Returns the rectangle (zero width, height of the line) of a text cursor placed in front of the character at `char_index`, relative to the top left corner of the inline text. In right-to-left words, the front of a character is its right edge. If the index is past the last character, the cursor is placed behind the last character.
+        pub fn get_cursor_rect(&self, char_index: usize)  -> crate::option::OptionLogicalRect { unsafe { crate::dll::AzInlineText_getCursorRect(self, char_index) } }
+        /// Returns the rectangles covering the characters in the logical range `start..end`, relative to the top left corner of the inline text. A logical range of mixed-direction text is not necessarily visually continuous, so the result can contain more than one rectangle per line.
+        pub fn get_selection_rects(&self, start: usize, end: usize)  -> crate::vec::LogicalRectVec { unsafe { crate::dll::AzInlineText_getSelectionRects(self, start, end) } }
     }
 
     /// `InlineLine` struct
@@ -12242,6 +14693,12 @@ pub mod callbacks {
     /// `IFrameCallbackInfo` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzIFrameCallbackInfo as IFrameCallbackInfo;
+    /// C-ABI wrapper over a `TooltipCallbackType`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTooltipCallback as TooltipCallback;
+    /// Renders the content of a `Tooltip`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTooltipCallbackType as TooltipCallbackType;
     /// <img src="../images/scrollbounds.png"/>
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzIFrameCallbackReturn as IFrameCallbackReturn;
@@ -12394,10 +14851,18 @@ pub mod dom {
     #[cfg(feature = "link_static")]
     pub use azul::AzAccessibilityInfo as AccessibilityInfo;
     #[cfg(feature = "link_static")]
+    pub use azul::AzTooltip as Tooltip;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTooltipContent as TooltipContent;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTooltipDom as TooltipDom;
+    #[cfg(feature = "link_static")]
     pub use azul::AzAccessibilityRole as AccessibilityRole;
     #[cfg(feature = "link_static")]
     pub use azul::AzAccessibilityState as AccessibilityState;
     #[cfg(feature = "link_static")]
+    pub use azul::AzAccessibilityAction as AccessibilityAction;
+    #[cfg(feature = "link_static")]
     pub use azul::AzTabIndex as TabIndex;
     #[cfg(feature = "link_static")]
     pub use azul::AzIdOrClass as IdOrClass;
@@ -12542,15 +15007,19 @@ pub mod dom {
     #[cfg(feature = "link_static")]
     use azul::AzImageRef as ImageRef;
     #[cfg(not(feature = "link_static"))]
-    use crate::callbacks::{CallbackType, IFrameCallbackType, RefAny};
+    use crate::callbacks::{CallbackType, IFrameCallbackType, RefAny, TooltipCallbackType};
     #[cfg(feature = "link_static")]
     use azul::AzCallbackType as CallbackType;
     #[cfg(feature = "link_static")]
     use azul::AzIFrameCallbackType as IFrameCallbackType;
     #[cfg(feature = "link_static")]
     use azul::AzRefAny as RefAny;
+    #[cfg(feature = "link_static")]
+    use azul::AzTooltipCallbackType as TooltipCallbackType;
     #[cfg(not(feature = "link_static"))]
-    use crate::vec::{CallbackDataVec, DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec};
+    use crate::vec::{AccessibilityActionVec, CallbackDataVec, DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec};
+    #[cfg(feature = "link_static")]
+    use azul::AzAccessibilityActionVec as AccessibilityActionVec;
     #[cfg(feature = "link_static")]
     use azul::AzCallbackDataVec as CallbackDataVec;
     #[cfg(feature = "link_static")]
@@ -12569,6 +15038,10 @@ pub mod dom {
     use crate::menu::Menu;
     #[cfg(feature = "link_static")]
     use azul::AzMenu as Menu;
+    #[cfg(not(feature = "link_static"))]
+    use crate::time::Duration;
+    #[cfg(feature = "link_static")]
+    use azul::AzDuration as Duration;
     /// `Dom` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzDom as Dom;
@@ -12673,6 +15146,10 @@ pub mod dom {
         pub fn set_accessibility_info(&mut self, accessibility_info: AccessibilityInfo)  { unsafe { crate::dll::AzDom_setAccessibilityInfo(self, accessibility_info) } }
         /// Same as set_accessibility_info, but as a builder method
         pub fn with_accessibility_info(&mut self, accessibility_info: AccessibilityInfo)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withAccessibilityInfo(self, accessibility_info) } }
+        /// Sets the actions that assistive technologies can invoke on the DOM root node. See `NodeData::set_accessibility_actions` for more information.
+        pub fn set_accessibility_actions(&mut self, actions: AccessibilityActionVec)  { unsafe { crate::dll::AzDom_setAccessibilityActions(self, actions) } }
+        /// Same as set_accessibility_actions, but as a builder method
+        pub fn with_accessibility_actions(&mut self, actions: AccessibilityActionVec)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withAccessibilityActions(self, actions) } }
         /// Sets the menu bar for the DOM root node. See `NodeData::set_menu_bar` for more information.
         pub fn set_menu_bar(&mut self, menu_bar: Menu)  { unsafe { crate::dll::AzDom_setMenuBar(self, menu_bar) } }
         /// Same as set_accessibility_info, but as a builder method
@@ -12681,6 +15158,10 @@ pub mod dom {
         pub fn set_context_menu(&mut self, context_menu: Menu)  { unsafe { crate::dll::AzDom_setContextMenu(self, context_menu) } }
         /// Same as set_context_menu, but as a builder method
         pub fn with_context_menu(&mut self, context_menu: Menu)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withContextMenu(self, context_menu) } }
+        /// Sets the tooltip for the DOM root node. See `NodeData::set_tooltip` for more information.
+        pub fn set_tooltip(&mut self, tooltip: Tooltip)  { unsafe { crate::dll::AzDom_setTooltip(self, tooltip) } }
+        /// Same as set_tooltip, but as a builder method
+        pub fn with_tooltip(&mut self, tooltip: Tooltip)  -> crate::dom::Dom { unsafe { crate::dll::AzDom_withTooltip(self, tooltip) } }
         /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
         pub fn hash(&self)  -> u64 { unsafe { crate::dll::AzDom_hash(self) } }
         /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
@@ -12765,10 +15246,18 @@ pub mod dom {
         pub fn set_tab_index(&mut self, tab_index: TabIndex)  { unsafe { crate::dll::AzNodeData_setTabIndex(self, tab_index) } }
         /// Sets accessibility attributes for this node
         pub fn set_accessibility_info(&mut self, accessibility_info: AccessibilityInfo)  { unsafe { crate::dll::AzNodeData_setAccessibilityInfo(self, accessibility_info) } }
+        /// Sets the actions that assistive technologies can invoke on this node. Actions are dispatched as callbacks on the node, i.e. `Default` invokes the `MouseUp` callbacks
+        pub fn set_accessibility_actions(&mut self, actions: AccessibilityActionVec)  { unsafe { crate::dll::AzNodeData_setAccessibilityActions(self, actions) } }
+        /// Adds an action that assistive technologies can invoke on this node
+        pub fn add_accessibility_action(&mut self, action: AccessibilityAction)  { unsafe { crate::dll::AzNodeData_addAccessibilityAction(self, action) } }
+        /// Same as set_accessibility_actions, but as a builder method
+        pub fn with_accessibility_actions(&mut self, actions: AccessibilityActionVec)  -> crate::dom::NodeData { unsafe { crate::dll::AzNodeData_withAccessibilityActions(self, actions) } }
         /// Adds a (native) menu bar: If this node is the root node the menu bar will be added to the window, else it will be displayed using the width and position of the bounding rectangle
         pub fn set_menu_bar(&mut self, menu_bar: Menu)  { unsafe { crate::dll::AzNodeData_setMenuBar(self, menu_bar) } }
         /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
         pub fn set_context_menu(&mut self, context_menu: Menu)  { unsafe { crate::dll::AzNodeData_setContextMenu(self, context_menu) } }
+        /// Shows a tooltip once the mouse has rested on this node for `tooltip.delay`. The tooltip is hidden when the mouse leaves the node or a mouse button or key is pressed.
+        pub fn set_tooltip(&mut self, tooltip: Tooltip)  { unsafe { crate::dll::AzNodeData_setTooltip(self, tooltip) } }
         /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
         pub fn hash(&self)  -> u64 { unsafe { crate::dll::AzNodeData_hash(self) } }
     }
@@ -12810,12 +15299,37 @@ pub mod dom {
     /// Accessibility information (MSAA wrapper). See `NodeData.set_accessibility_info()`
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAccessibilityInfo as AccessibilityInfo;
+    /// Tooltip of a node, shown in an always-on-top popup window once the mouse has rested on the node for `delay`. See `NodeData::set_tooltip`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTooltip as Tooltip;
+    #[cfg(not(feature = "link_static"))]
+    impl Tooltip {
+
+        /// Creates a new `Tooltip` instance.
+        pub fn text(text: String) -> Self { unsafe { crate::dll::AzTooltip_text(text) } }
+        /// Creates a new `Tooltip` instance.
+        pub fn dom(data: RefAny, callback: TooltipCallbackType) -> Self { unsafe { crate::dll::AzTooltip_dom(data, callback) } }
+        /// Calls the `Tooltip::set_delay` function.
+        pub fn set_delay(&mut self, delay: Duration)  { unsafe { crate::dll::AzTooltip_setDelay(self, delay) } }
+        /// Calls the `Tooltip::with_delay` function.
+        pub fn with_delay(&mut self, delay: Duration)  -> crate::dom::Tooltip { unsafe { crate::dll::AzTooltip_withDelay(self, delay) } }
+    }
+
+    /// `TooltipContent` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTooltipContent as TooltipContent;
+    /// `TooltipDom` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTooltipDom as TooltipDom;
     /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAccessibilityRole as AccessibilityRole;
     /// MSAA accessibility state. For information on what each state does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-state-constants">MSDN State Constants page</a>.
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAccessibilityState as AccessibilityState;
+    /// Action that an assistive technology (screen reader, voice control) can invoke on a node. See `NodeData::set_accessibility_actions`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAccessibilityAction as AccessibilityAction;
     /// `TabIndex` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabIndex as TabIndex;
@@ -12956,10 +15470,30 @@ pub mod css {
     #[cfg(feature = "link_static")]
     pub use azul::AzStylesheet as Stylesheet;
     #[cfg(feature = "link_static")]
+    pub use azul::AzCssKeyframes as CssKeyframes;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssKeyframe as CssKeyframe;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaRule as CssMediaRule;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaQuery as CssMediaQuery;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaType as CssMediaType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaFeature as CssMediaFeature;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaRange as CssMediaRange;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaOrientation as CssMediaOrientation;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssColorScheme as CssColorScheme;
+    #[cfg(feature = "link_static")]
     pub use azul::AzCss as Css;
     #[cfg(feature = "link_static")]
     pub use azul::AzCssPropertyType as CssPropertyType;
     #[cfg(feature = "link_static")]
+    pub use azul::AzCombinedCssPropertyType as CombinedCssPropertyType;
+    #[cfg(feature = "link_static")]
     pub use azul::AzAnimationInterpolationFunction as AnimationInterpolationFunction;
     #[cfg(feature = "link_static")]
     pub use azul::AzInterpolateContext as InterpolateContext;
@@ -12992,6 +15526,20 @@ pub mod css {
     #[cfg(feature = "link_static")]
     pub use azul::AzLayoutAlignContent as LayoutAlignContent;
     #[cfg(feature = "link_static")]
+    pub use azul::AzGridTrackBreadth as GridTrackBreadth;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzGridTrackSizing as GridTrackSizing;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzLayoutGridAutoFlow as LayoutGridAutoFlow;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzGridLine as GridLine;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzGridPlacement as GridPlacement;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzLayoutRowGap as LayoutRowGap;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzLayoutColumnGap as LayoutColumnGap;
+    #[cfg(feature = "link_static")]
     pub use azul::AzLayoutAlignItems as LayoutAlignItems;
     #[cfg(feature = "link_static")]
     pub use azul::AzLayoutBottom as LayoutBottom;
@@ -13166,8 +15714,38 @@ pub mod css {
     #[cfg(feature = "link_static")]
     pub use azul::AzStyleTransformSkew2D as StyleTransformSkew2D;
     #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTime as StyleTime;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleCubicBezier as StyleCubicBezier;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleStepPosition as StyleStepPosition;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleSteps as StyleSteps;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTimingFunction as StyleTimingFunction;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTransitionProperty as StyleTransitionProperty;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationName as StyleAnimationName;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationIterationCount as StyleAnimationIterationCount;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationDirection as StyleAnimationDirection;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationFillMode as StyleAnimationFillMode;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationPlayState as StyleAnimationPlayState;
+    #[cfg(feature = "link_static")]
     pub use azul::AzStyleTextAlign as StyleTextAlign;
     #[cfg(feature = "link_static")]
+    pub use azul::AzStyleDirection as StyleDirection;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleWordBreak as StyleWordBreak;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleOverflowWrap as StyleOverflowWrap;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleHyphens as StyleHyphens;
+    #[cfg(feature = "link_static")]
     pub use azul::AzStyleTextColor as StyleTextColor;
     #[cfg(feature = "link_static")]
     pub use azul::AzStyleWordSpacing as StyleWordSpacing;
@@ -13176,6 +15754,34 @@ pub mod css {
     #[cfg(feature = "link_static")]
     pub use azul::AzLayoutAlignContentValue as LayoutAlignContentValue;
     #[cfg(feature = "link_static")]
+    pub use azul::AzGridTrackSizingVecValue as GridTrackSizingVecValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzGridTrackSizingValue as GridTrackSizingValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzLayoutGridAutoFlowValue as LayoutGridAutoFlowValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzGridPlacementValue as GridPlacementValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzLayoutRowGapValue as LayoutRowGapValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzLayoutColumnGapValue as LayoutColumnGapValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTransitionPropertyVecValue as StyleTransitionPropertyVecValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTimeVecValue as StyleTimeVecValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTimingFunctionVecValue as StyleTimingFunctionVecValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationNameVecValue as StyleAnimationNameVecValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationIterationCountVecValue as StyleAnimationIterationCountVecValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationDirectionVecValue as StyleAnimationDirectionVecValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationFillModeVecValue as StyleAnimationFillModeVecValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationPlayStateVecValue as StyleAnimationPlayStateVecValue;
+    #[cfg(feature = "link_static")]
     pub use azul::AzLayoutAlignItemsValue as LayoutAlignItemsValue;
     #[cfg(feature = "link_static")]
     pub use azul::AzLayoutBottomValue as LayoutBottomValue;
@@ -13290,6 +15896,14 @@ pub mod css {
     #[cfg(feature = "link_static")]
     pub use azul::AzStyleTextAlignValue as StyleTextAlignValue;
     #[cfg(feature = "link_static")]
+    pub use azul::AzStyleDirectionValue as StyleDirectionValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleWordBreakValue as StyleWordBreakValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleOverflowWrapValue as StyleOverflowWrapValue;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleHyphensValue as StyleHyphensValue;
+    #[cfg(feature = "link_static")]
     pub use azul::AzStyleTextColorValue as StyleTextColorValue;
     #[cfg(feature = "link_static")]
     pub use azul::AzStyleWordSpacingValue as StyleWordSpacingValue;
@@ -14007,6 +16621,33 @@ pub mod css {
     /// `Stylesheet` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStylesheet as Stylesheet;
+    /// One `@keyframes` block, i.e. `@keyframes fade { from { opacity: 0; } to { opacity: 1; } }`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssKeyframes as CssKeyframes;
+    /// One keyframe inside of a `@keyframes` block, i.e. `50% { opacity: 0.5; }`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssKeyframe as CssKeyframe;
+    /// One `@media` block, i.e. `@media (prefers-color-scheme: dark) { body { color: white; } }`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaRule as CssMediaRule;
+    /// One query of a media query list, i.e. `screen and (min-width: 600px)`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaQuery as CssMediaQuery;
+    /// Media type of a `CssMediaQuery`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaType as CssMediaType;
+    /// One media feature, i.e. `(min-width: 600px)` or `(prefers-color-scheme: dark)`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaFeature as CssMediaFeature;
+    /// Range of a numeric media feature, i.e. `(min-width: 600px)` or `(400px < width <= 700px)`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaRange as CssMediaRange;
+    /// `CssMediaOrientation` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaOrientation as CssMediaOrientation;
+    /// `CssColorScheme` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssColorScheme as CssColorScheme;
     /// `Css` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCss as Css;
@@ -14022,6 +16663,9 @@ pub mod css {
     /// `CssPropertyType` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssPropertyType as CssPropertyType;
+    /// Represents a CSS shorthand key that expands to multiple properties, for example `margin`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCombinedCssPropertyType as CombinedCssPropertyType;
     /// `AnimationInterpolationFunction` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAnimationInterpolationFunction as AnimationInterpolationFunction;
@@ -14085,6 +16729,27 @@ pub mod css {
     /// `LayoutAlignContent` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutAlignContent as LayoutAlignContent;
+    /// One end of a grid track size, i.e. the `min` or `max` of a `minmax(min, max)`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridTrackBreadth as GridTrackBreadth;
+    /// Single track size in a `grid-template-columns`, `grid-template-rows`, `grid-auto-columns` or `grid-auto-rows` attribute
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridTrackSizing as GridTrackSizing;
+    /// `LayoutGridAutoFlow` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutGridAutoFlow as LayoutGridAutoFlow;
+    /// Start or end line of a `grid-column` or `grid-row` attribute
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridLine as GridLine;
+    /// `GridPlacement` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridPlacement as GridPlacement;
+    /// `LayoutRowGap` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutRowGap as LayoutRowGap;
+    /// `LayoutColumnGap` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutColumnGap as LayoutColumnGap;
     /// `LayoutAlignItems` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutAlignItems as LayoutAlignItems;
@@ -14353,9 +17018,54 @@ pub mod css {
     /// `StyleTransformSkew2D` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTransformSkew2D as StyleTransformSkew2D;
+    /// Represents a `<time>` value in a `transition-duration`, `transition-delay`, `animation-duration` or `animation-delay` attribute, stored in milliseconds
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTime as StyleTime;
+    /// Control points of a `cubic-bezier(x1, y1, x2, y2)` easing function
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleCubicBezier as StyleCubicBezier;
+    /// Position of the jumps in a `steps(n, position)` easing function
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleStepPosition as StyleStepPosition;
+    /// Represents a `steps(n, position)` easing function
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleSteps as StyleSteps;
+    /// Represents an `<easing-function>` in a `transition-timing-function` or `animation-timing-function` attribute
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTimingFunction as StyleTimingFunction;
+    /// Single entry in a `transition-property` attribute
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTransitionProperty as StyleTransitionProperty;
+    /// Single entry in an `animation-name` attribute, refers to a `@keyframes` block
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationName as StyleAnimationName;
+    /// Single entry in an `animation-iteration-count` attribute
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCount as StyleAnimationIterationCount;
+    /// `StyleAnimationDirection` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationDirection as StyleAnimationDirection;
+    /// `StyleAnimationFillMode` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationFillMode as StyleAnimationFillMode;
+    /// `StyleAnimationPlayState` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationPlayState as StyleAnimationPlayState;
     /// `StyleTextAlign` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTextAlign as StyleTextAlign;
+    /// Base writing direction of a text (`direction: ltr | rtl`)
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleDirection as StyleDirection;
+    /// Where lines may break inside of words (`word-break: normal | break-all | keep-all`)
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleWordBreak as StyleWordBreak;
+    /// Whether words that are wider than the line may be broken at arbitrary points (`overflow-wrap: normal | anywhere | break-word`)
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleOverflowWrap as StyleOverflowWrap;
+    /// Whether words are hyphenated when breaking lines (`hyphens: none | manual | auto`)
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleHyphens as StyleHyphens;
     /// `StyleTextColor` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTextColor as StyleTextColor;
@@ -14368,6 +17078,48 @@ pub mod css {
     /// `LayoutAlignContentValue` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutAlignContentValue as LayoutAlignContentValue;
+    /// `GridTrackSizingVecValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridTrackSizingVecValue as GridTrackSizingVecValue;
+    /// `GridTrackSizingValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridTrackSizingValue as GridTrackSizingValue;
+    /// `LayoutGridAutoFlowValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutGridAutoFlowValue as LayoutGridAutoFlowValue;
+    /// `GridPlacementValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridPlacementValue as GridPlacementValue;
+    /// `LayoutRowGapValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutRowGapValue as LayoutRowGapValue;
+    /// `LayoutColumnGapValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutColumnGapValue as LayoutColumnGapValue;
+    /// `StyleTransitionPropertyVecValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyVecValue as StyleTransitionPropertyVecValue;
+    /// `StyleTimeVecValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTimeVecValue as StyleTimeVecValue;
+    /// `StyleTimingFunctionVecValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTimingFunctionVecValue as StyleTimingFunctionVecValue;
+    /// `StyleAnimationNameVecValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationNameVecValue as StyleAnimationNameVecValue;
+    /// `StyleAnimationIterationCountVecValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCountVecValue as StyleAnimationIterationCountVecValue;
+    /// `StyleAnimationDirectionVecValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationDirectionVecValue as StyleAnimationDirectionVecValue;
+    /// `StyleAnimationFillModeVecValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationFillModeVecValue as StyleAnimationFillModeVecValue;
+    /// `StyleAnimationPlayStateVecValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationPlayStateVecValue as StyleAnimationPlayStateVecValue;
     /// `LayoutAlignItemsValue` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzLayoutAlignItemsValue as LayoutAlignItemsValue;
@@ -14539,6 +17291,18 @@ pub mod css {
     /// `StyleTextAlignValue` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTextAlignValue as StyleTextAlignValue;
+    /// `StyleDirectionValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleDirectionValue as StyleDirectionValue;
+    /// `StyleWordBreakValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleWordBreakValue as StyleWordBreakValue;
+    /// `StyleOverflowWrapValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleOverflowWrapValue as StyleOverflowWrapValue;
+    /// `StyleHyphensValue` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleHyphensValue as StyleHyphensValue;
     /// `StyleTextColorValue` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTextColorValue as StyleTextColorValue;
@@ -14592,6 +17356,24 @@ pub mod widgets {
     #[cfg(feature = "link_static")]
     pub use azul::AzRibbon as Ribbon;
     #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonTab as RibbonTab;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonGroup as RibbonGroup;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonItem as RibbonItem;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonItemKind as RibbonItemKind;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonItemState as RibbonItemState;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonOnItemClick as RibbonOnItemClick;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonOnItemClickCallback as RibbonOnItemClickCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonOnItemClickCallbackType as RibbonOnItemClickCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonOnTabClicked as RibbonOnTabClicked;
+    #[cfg(feature = "link_static")]
     pub use azul::AzRibbonOnTabClickedCallback as RibbonOnTabClickedCallback;
     #[cfg(feature = "link_static")]
     pub use azul::AzRibbonOnTabClickedCallbackType as RibbonOnTabClickedCallbackType;
@@ -14670,6 +17452,20 @@ pub mod widgets {
     #[cfg(feature = "link_static")]
     pub use azul::AzTextInputValid as TextInputValid;
     #[cfg(feature = "link_static")]
+    pub use azul::AzTextArea as TextArea;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTextAreaState as TextAreaState;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTextAreaEdit as TextAreaEdit;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTextAreaEditKind as TextAreaEditKind;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTextAreaOnTextChangeCallbackType as TextAreaOnTextChangeCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTextAreaOnTextChangeCallback as TextAreaOnTextChangeCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTextAreaOnTextChange as TextAreaOnTextChange;
+    #[cfg(feature = "link_static")]
     pub use azul::AzNumberInput as NumberInput;
     #[cfg(feature = "link_static")]
     pub use azul::AzNumberInputStateWrapper as NumberInputStateWrapper;
@@ -14692,6 +17488,12 @@ pub mod widgets {
     #[cfg(feature = "link_static")]
     pub use azul::AzProgressBarState as ProgressBarState;
     #[cfg(feature = "link_static")]
+    pub use azul::AzProgressBarMode as ProgressBarMode;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzProgressBarStyle as ProgressBarStyle;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSpinner as Spinner;
+    #[cfg(feature = "link_static")]
     pub use azul::AzTabHeader as TabHeader;
     #[cfg(feature = "link_static")]
     pub use azul::AzTabHeaderState as TabHeaderState;
@@ -14704,8 +17506,64 @@ pub mod widgets {
     #[cfg(feature = "link_static")]
     pub use azul::AzTabOnClickCallbackType as TabOnClickCallbackType;
     #[cfg(feature = "link_static")]
+    pub use azul::AzTabOnClose as TabOnClose;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTabOnCloseCallback as TabOnCloseCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTabOnCloseCallbackType as TabOnCloseCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTabOnReorder as TabOnReorder;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTabOnReorderCallback as TabOnReorderCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTabOnReorderCallbackType as TabOnReorderCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTabOnContextMenu as TabOnContextMenu;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTabOnContextMenuCallback as TabOnContextMenuCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTabOnContextMenuCallbackType as TabOnContextMenuCallbackType;
+    #[cfg(feature = "link_static")]
     pub use azul::AzFrame as Frame;
     #[cfg(feature = "link_static")]
+    pub use azul::AzSplitter as Splitter;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterOrientation as SplitterOrientation;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterPane as SplitterPane;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterState as SplitterState;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterPaneState as SplitterPaneState;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterOnResize as SplitterOnResize;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterOnResizeCallback as SplitterOnResizeCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterOnResizeCallbackType as SplitterOnResizeCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSlider as Slider;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRangeSlider as RangeSlider;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSliderOrientation as SliderOrientation;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSliderState as SliderState;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRangeSliderState as RangeSliderState;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSliderOnValueChange as SliderOnValueChange;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSliderOnValueChangeCallback as SliderOnValueChangeCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSliderOnValueChangeCallbackType as SliderOnValueChangeCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRangeSliderOnValueChange as RangeSliderOnValueChange;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRangeSliderOnValueChangeCallback as RangeSliderOnValueChangeCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRangeSliderOnValueChangeCallbackType as RangeSliderOnValueChangeCallbackType;
+    #[cfg(feature = "link_static")]
     pub use azul::AzNodeGraph as NodeGraph;
     #[cfg(feature = "link_static")]
     pub use azul::AzNodeTypeIdInfoMap as NodeTypeIdInfoMap;
@@ -14820,8 +17678,54 @@ pub mod widgets {
     #[cfg(feature = "link_static")]
     pub use azul::AzListViewOnRowClick as ListViewOnRowClick;
     #[cfg(feature = "link_static")]
+    pub use azul::AzListViewRowRange as ListViewRowRange;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzListViewOnColumnResizeCallbackType as ListViewOnColumnResizeCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzListViewOnColumnResizeCallback as ListViewOnColumnResizeCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzListViewOnColumnResize as ListViewOnColumnResize;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzListViewRowProviderCallbackType as ListViewRowProviderCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzListViewRowProviderCallback as ListViewRowProviderCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzListViewRowProvider as ListViewRowProvider;
+    #[cfg(feature = "link_static")]
     pub use azul::AzTreeView as TreeView;
     #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewNodeId as TreeViewNodeId;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewNode as TreeViewNode;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewSelectionMode as TreeViewSelectionMode;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewState as TreeViewState;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnExpandCallbackType as TreeViewOnExpandCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnExpandCallback as TreeViewOnExpandCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnExpand as TreeViewOnExpand;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnSelectCallbackType as TreeViewOnSelectCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnSelectCallback as TreeViewOnSelectCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnSelect as TreeViewOnSelect;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnDoubleClickCallbackType as TreeViewOnDoubleClickCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnDoubleClickCallback as TreeViewOnDoubleClickCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnDoubleClick as TreeViewOnDoubleClick;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnContextMenuCallbackType as TreeViewOnContextMenuCallbackType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnContextMenuCallback as TreeViewOnContextMenuCallback;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewOnContextMenu as TreeViewOnContextMenu;
+    #[cfg(feature = "link_static")]
     pub use azul::AzDropDown as DropDown;
     #[cfg(feature = "link_static")]
     pub use azul::AzDropDownOnChoiceChangeCallbackType as DropDownOnChoiceChangeCallbackType;
@@ -14831,6 +17735,30 @@ pub mod widgets {
     pub use azul::AzDropDownOnChoiceChange as DropDownOnChoiceChange;
     use core::ffi::c_void;
     #[cfg(not(feature = "link_static"))]
+    use crate::vec::{F32Vec, ListViewRowVec, NodeDataInlineCssPropertyVec, RibbonGroupVec, RibbonItemVec, RibbonTabVec, SplitterPaneVec, StringVec, StyleBackgroundContentVec, TreeViewNodeVec, U32Vec};
+    #[cfg(feature = "link_static")]
+    use azul::AzF32Vec as F32Vec;
+    #[cfg(feature = "link_static")]
+    use azul::AzListViewRowVec as ListViewRowVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzNodeDataInlineCssPropertyVec as NodeDataInlineCssPropertyVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzRibbonGroupVec as RibbonGroupVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzRibbonItemVec as RibbonItemVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzRibbonTabVec as RibbonTabVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzSplitterPaneVec as SplitterPaneVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzStringVec as StringVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzStyleBackgroundContentVec as StyleBackgroundContentVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzTreeViewNodeVec as TreeViewNodeVec;
+    #[cfg(feature = "link_static")]
+    use azul::AzU32Vec as U32Vec;
+    #[cfg(not(feature = "link_static"))]
     use crate::callbacks::{CallbackType, RefAny};
     #[cfg(feature = "link_static")]
     use azul::AzCallbackType as CallbackType;
@@ -14841,25 +17769,25 @@ pub mod widgets {
     #[cfg(feature = "link_static")]
     use azul::AzString as String;
     #[cfg(not(feature = "link_static"))]
-    use crate::option::OptionString;
+    use crate::image::ImageRef;
     #[cfg(feature = "link_static")]
-    use azul::AzOptionString as OptionString;
+    use azul::AzImageRef as ImageRef;
     #[cfg(not(feature = "link_static"))]
-    use crate::css::{ColorU, PixelValue};
+    use crate::option::{OptionString, OptionTreeViewNodeId, OptionUsize};
     #[cfg(feature = "link_static")]
-    use azul::AzColorU as ColorU;
+    use azul::AzOptionString as OptionString;
     #[cfg(feature = "link_static")]
-    use azul::AzPixelValue as PixelValue;
-    #[cfg(not(feature = "link_static"))]
-    use crate::vec::{ListViewRowVec, NodeDataInlineCssPropertyVec, StringVec, StyleBackgroundContentVec};
+    use azul::AzOptionTreeViewNodeId as OptionTreeViewNodeId;
     #[cfg(feature = "link_static")]
-    use azul::AzListViewRowVec as ListViewRowVec;
+    use azul::AzOptionUsize as OptionUsize;
+    #[cfg(not(feature = "link_static"))]
+    use crate::css::{ColorU, PixelValue, PixelValueNoPercent};
     #[cfg(feature = "link_static")]
-    use azul::AzNodeDataInlineCssPropertyVec as NodeDataInlineCssPropertyVec;
+    use azul::AzColorU as ColorU;
     #[cfg(feature = "link_static")]
-    use azul::AzStringVec as StringVec;
+    use azul::AzPixelValue as PixelValue;
     #[cfg(feature = "link_static")]
-    use azul::AzStyleBackgroundContentVec as StyleBackgroundContentVec;
+    use azul::AzPixelValueNoPercent as PixelValueNoPercent;
     #[cfg(not(feature = "link_static"))]
     use crate::dom::Dom;
     #[cfg(feature = "link_static")]
@@ -14870,10 +17798,108 @@ pub mod widgets {
     #[cfg(not(feature = "link_static"))]
     impl Ribbon {
 
+        /// Creates a new `Ribbon` instance.
+        pub fn new(tabs: RibbonTabVec) -> Self { unsafe { crate::dll::AzRibbon_new(tabs) } }
+        /// Calls the `Ribbon::set_tab_active` function.
+        pub fn set_tab_active(&mut self, tab_active: i32)  { unsafe { crate::dll::AzRibbon_setTabActive(self, tab_active) } }
+        /// Calls the `Ribbon::with_tab_active` function.
+        pub fn with_tab_active(&mut self, tab_active: i32)  -> crate::widgets::Ribbon { unsafe { crate::dll::AzRibbon_withTabActive(self, tab_active) } }
+        /// Calls the `Ribbon::set_available_width` function.
+        pub fn set_available_width(&mut self, available_width: f32)  { unsafe { crate::dll::AzRibbon_setAvailableWidth(self, available_width) } }
+        /// Calls the `Ribbon::with_available_width` function.
+        pub fn with_available_width(&mut self, available_width: f32)  -> crate::widgets::Ribbon { unsafe { crate::dll::AzRibbon_withAvailableWidth(self, available_width) } }
+        /// Calls the `Ribbon::set_on_tab_clicked` function.
+        pub fn set_on_tab_clicked(&mut self, data: RefAny, callback: RibbonOnTabClickedCallbackType)  { unsafe { crate::dll::AzRibbon_setOnTabClicked(self, data, callback) } }
+        /// Calls the `Ribbon::with_on_tab_clicked` function.
+        pub fn with_on_tab_clicked(&mut self, data: RefAny, callback: RibbonOnTabClickedCallbackType)  -> crate::widgets::Ribbon { unsafe { crate::dll::AzRibbon_withOnTabClicked(self, data, callback) } }
         /// Calls the `Ribbon::dom` function.
-        pub fn dom(&mut self, callback: RibbonOnTabClickedCallback, data: RefAny)  -> crate::dom::Dom { unsafe { crate::dll::AzRibbon_dom(self, callback, data) } }
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzRibbon_dom(self) } }
+    }
+
+    /// `RibbonTab` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonTab as RibbonTab;
+    #[cfg(not(feature = "link_static"))]
+    impl RibbonTab {
+
+        /// Creates a new `RibbonTab` instance.
+        pub fn new(label: String, groups: RibbonGroupVec) -> Self { unsafe { crate::dll::AzRibbonTab_new(label, groups) } }
+        /// Calls the `RibbonTab::set_key_tip` function.
+        pub fn set_key_tip(&mut self, key_tip: String)  { unsafe { crate::dll::AzRibbonTab_setKeyTip(self, key_tip) } }
+        /// Calls the `RibbonTab::with_key_tip` function.
+        pub fn with_key_tip(&mut self, key_tip: String)  -> crate::widgets::RibbonTab { unsafe { crate::dll::AzRibbonTab_withKeyTip(self, key_tip) } }
+    }
+
+    /// `RibbonGroup` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonGroup as RibbonGroup;
+    #[cfg(not(feature = "link_static"))]
+    impl RibbonGroup {
+
+        /// Creates a new `RibbonGroup` instance.
+        pub fn new(label: String, items: RibbonItemVec) -> Self { unsafe { crate::dll::AzRibbonGroup_new(label, items) } }
+        /// Calls the `RibbonGroup::set_icon` function.
+        pub fn set_icon(&mut self, icon: ImageRef)  { unsafe { crate::dll::AzRibbonGroup_setIcon(self, icon) } }
+        /// Calls the `RibbonGroup::with_icon` function.
+        pub fn with_icon(&mut self, icon: ImageRef)  -> crate::widgets::RibbonGroup { unsafe { crate::dll::AzRibbonGroup_withIcon(self, icon) } }
     }
 
+    /// `RibbonItem` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonItem as RibbonItem;
+    #[cfg(not(feature = "link_static"))]
+    impl RibbonItem {
+
+        /// Creates a new `RibbonItem` instance.
+        pub fn new(kind: RibbonItemKind, label: String) -> Self { unsafe { crate::dll::AzRibbonItem_new(kind, label) } }
+        /// Calls the `RibbonItem::set_icon` function.
+        pub fn set_icon(&mut self, icon: ImageRef)  { unsafe { crate::dll::AzRibbonItem_setIcon(self, icon) } }
+        /// Calls the `RibbonItem::with_icon` function.
+        pub fn with_icon(&mut self, icon: ImageRef)  -> crate::widgets::RibbonItem { unsafe { crate::dll::AzRibbonItem_withIcon(self, icon) } }
+        /// Calls the `RibbonItem::set_tooltip` function.
+        pub fn set_tooltip(&mut self, tooltip: String)  { unsafe { crate::dll::AzRibbonItem_setTooltip(self, tooltip) } }
+        /// Calls the `RibbonItem::with_tooltip` function.
+        pub fn with_tooltip(&mut self, tooltip: String)  -> crate::widgets::RibbonItem { unsafe { crate::dll::AzRibbonItem_withTooltip(self, tooltip) } }
+        /// Calls the `RibbonItem::set_key_tip` function.
+        pub fn set_key_tip(&mut self, key_tip: String)  { unsafe { crate::dll::AzRibbonItem_setKeyTip(self, key_tip) } }
+        /// Calls the `RibbonItem::with_key_tip` function.
+        pub fn with_key_tip(&mut self, key_tip: String)  -> crate::widgets::RibbonItem { unsafe { crate::dll::AzRibbonItem_withKeyTip(self, key_tip) } }
+        /// Calls the `RibbonItem::set_enabled` function.
+        pub fn set_enabled(&mut self, enabled: bool)  { unsafe { crate::dll::AzRibbonItem_setEnabled(self, enabled) } }
+        /// Calls the `RibbonItem::with_enabled` function.
+        pub fn with_enabled(&mut self, enabled: bool)  -> crate::widgets::RibbonItem { unsafe { crate::dll::AzRibbonItem_withEnabled(self, enabled) } }
+        /// Calls the `RibbonItem::set_toggled` function.
+        pub fn set_toggled(&mut self, toggled: bool)  { unsafe { crate::dll::AzRibbonItem_setToggled(self, toggled) } }
+        /// Calls the `RibbonItem::with_toggled` function.
+        pub fn with_toggled(&mut self, toggled: bool)  -> crate::widgets::RibbonItem { unsafe { crate::dll::AzRibbonItem_withToggled(self, toggled) } }
+        /// Calls the `RibbonItem::set_choices` function.
+        pub fn set_choices(&mut self, choices: StringVec)  { unsafe { crate::dll::AzRibbonItem_setChoices(self, choices) } }
+        /// Calls the `RibbonItem::with_choices` function.
+        pub fn with_choices(&mut self, choices: StringVec)  -> crate::widgets::RibbonItem { unsafe { crate::dll::AzRibbonItem_withChoices(self, choices) } }
+        /// Calls the `RibbonItem::set_on_click` function.
+        pub fn set_on_click(&mut self, data: RefAny, callback: RibbonOnItemClickCallbackType)  { unsafe { crate::dll::AzRibbonItem_setOnClick(self, data, callback) } }
+        /// Calls the `RibbonItem::with_on_click` function.
+        pub fn with_on_click(&mut self, data: RefAny, callback: RibbonOnItemClickCallbackType)  -> crate::widgets::RibbonItem { unsafe { crate::dll::AzRibbonItem_withOnClick(self, data, callback) } }
+    }
+
+    /// `RibbonItemKind` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonItemKind as RibbonItemKind;
+    /// `RibbonItemState` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonItemState as RibbonItemState;
+    /// `RibbonOnItemClick` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonOnItemClick as RibbonOnItemClick;
+    /// `RibbonOnItemClickCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonOnItemClickCallback as RibbonOnItemClickCallback;
+    /// `RibbonOnItemClickCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonOnItemClickCallbackType as RibbonOnItemClickCallbackType;
+    /// `RibbonOnTabClicked` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonOnTabClicked as RibbonOnTabClicked;
     /// `RibbonOnTabClickedCallback` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonOnTabClickedCallback as RibbonOnTabClickedCallback;
@@ -15094,16 +18120,81 @@ pub mod widgets {
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextInputOnFocusLost as TextInputOnFocusLost;
     /// `TextInputOnFocusLostCallback` struct
     
-    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextInputOnFocusLostCallback as TextInputOnFocusLostCallback;
-    /// `TextInputOnFocusLostCallbackType` struct
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextInputOnFocusLostCallback as TextInputOnFocusLostCallback;
+    /// `TextInputOnFocusLostCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextInputOnFocusLostCallbackType as TextInputOnFocusLostCallbackType;
+    /// `OnTextInputReturn` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOnTextInputReturn as OnTextInputReturn;
+    /// `TextInputValid` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextInputValid as TextInputValid;
+    /// Multi-line text input with soft wrapping, scrolling and an undo history
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextArea as TextArea;
+    #[cfg(not(feature = "link_static"))]
+    impl TextArea {
+
+        /// Creates a new `TextArea` instance.
+        pub fn new() -> Self { unsafe { crate::dll::AzTextArea_new() } }
+        /// Calls the `TextArea::set_text` function.
+        pub fn set_text(&mut self, text: String)  { unsafe { crate::dll::AzTextArea_setText(self, text) } }
+        /// Calls the `TextArea::with_text` function.
+        pub fn with_text(&mut self, text: String)  -> crate::widgets::TextArea { unsafe { crate::dll::AzTextArea_withText(self, text) } }
+        /// Calls the `TextArea::set_state` function.
+        pub fn set_state(&mut self, state: TextAreaState)  { unsafe { crate::dll::AzTextArea_setState(self, state) } }
+        /// Calls the `TextArea::with_state` function.
+        pub fn with_state(&mut self, state: TextAreaState)  -> crate::widgets::TextArea { unsafe { crate::dll::AzTextArea_withState(self, state) } }
+        /// Calls the `TextArea::set_on_text_change` function.
+        pub fn set_on_text_change(&mut self, data: RefAny, callback: TextAreaOnTextChangeCallbackType)  { unsafe { crate::dll::AzTextArea_setOnTextChange(self, data, callback) } }
+        /// Calls the `TextArea::with_on_text_change` function.
+        pub fn with_on_text_change(&mut self, data: RefAny, callback: TextAreaOnTextChangeCallbackType)  -> crate::widgets::TextArea { unsafe { crate::dll::AzTextArea_withOnTextChange(self, data, callback) } }
+        /// Calls the `TextArea::swap_with_default` function.
+        pub fn swap_with_default(&mut self)  -> crate::widgets::TextArea { unsafe { crate::dll::AzTextArea_swapWithDefault(self) } }
+        /// Calls the `TextArea::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTextArea_dom(self) } }
+    }
+
+    /// `TextAreaState` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextAreaState as TextAreaState;
+    #[cfg(not(feature = "link_static"))]
+    impl TextAreaState {
+
+        /// Calls the `TextAreaState::get_text` function.
+        pub fn get_text(&self)  -> crate::str::String { unsafe { crate::dll::AzTextAreaState_getText(self) } }
+        /// Calls the `TextAreaState::set_text` function.
+        pub fn set_text(&mut self, text: String)  { unsafe { crate::dll::AzTextAreaState_setText(self, text) } }
+        /// Calls the `TextAreaState::get_selected_text` function.
+        pub fn get_selected_text(&self)  -> crate::str::String { unsafe { crate::dll::AzTextAreaState_getSelectedText(self) } }
+        /// Calls the `TextAreaState::select_all` function.
+        pub fn select_all(&mut self)  { unsafe { crate::dll::AzTextAreaState_selectAll(self) } }
+        /// Calls the `TextAreaState::can_undo` function.
+        pub fn can_undo(&self)  -> bool { unsafe { crate::dll::AzTextAreaState_canUndo(self) } }
+        /// Calls the `TextAreaState::can_redo` function.
+        pub fn can_redo(&self)  -> bool { unsafe { crate::dll::AzTextAreaState_canRedo(self) } }
+        /// Calls the `TextAreaState::undo` function.
+        pub fn undo(&mut self)  -> bool { unsafe { crate::dll::AzTextAreaState_undo(self) } }
+        /// Calls the `TextAreaState::redo` function.
+        pub fn redo(&mut self)  -> bool { unsafe { crate::dll::AzTextAreaState_redo(self) } }
+    }
+
+    /// Single step in the undo / redo history of a `TextArea`: `removed` was replaced with `inserted` at `position`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextAreaEdit as TextAreaEdit;
+    /// What kind of change a `TextAreaEdit` made, consecutive edits of the same kind can be merged into one undo step
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextAreaEditKind as TextAreaEditKind;
+    /// `TextAreaOnTextChangeCallbackType` struct
     
-    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextInputOnFocusLostCallbackType as TextInputOnFocusLostCallbackType;
-    /// `OnTextInputReturn` struct
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextAreaOnTextChangeCallbackType as TextAreaOnTextChangeCallbackType;
+    /// `TextAreaOnTextChangeCallback` struct
     
-    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOnTextInputReturn as OnTextInputReturn;
-    /// `TextInputValid` struct
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextAreaOnTextChangeCallback as TextAreaOnTextChangeCallback;
+    /// `TextAreaOnTextChange` struct
     
-    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextInputValid as TextInputValid;
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextAreaOnTextChange as TextAreaOnTextChange;
     /// `NumberInput` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzNumberInput as NumberInput;
@@ -15176,6 +18267,8 @@ pub mod widgets {
 
         /// Creates a new `ProgressBar` instance.
         pub fn new(percent_done: f32) -> Self { unsafe { crate::dll::AzProgressBar_new(percent_done) } }
+        /// Creates a new `ProgressBar` instance.
+        pub fn indeterminate() -> Self { unsafe { crate::dll::AzProgressBar_indeterminate() } }
         /// Calls the `ProgressBar::set_height` function.
         pub fn set_height(&mut self, height: PixelValue)  { unsafe { crate::dll::AzProgressBar_setHeight(self, height) } }
         /// Calls the `ProgressBar::with_height` function.
@@ -15190,11 +18283,61 @@ pub mod widgets {
         pub fn with_bar_background(&mut self, background: StyleBackgroundContentVec)  -> crate::widgets::ProgressBar { unsafe { crate::dll::AzProgressBar_withBarBackground(self, background) } }
         /// Calls the `ProgressBar::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzProgressBar_dom(self) } }
+        /// Calls the `ProgressBar::set_mode` function.
+        pub fn set_mode(&mut self, mode: ProgressBarMode)  { unsafe { crate::dll::AzProgressBar_setMode(self, mode) } }
+        /// Calls the `ProgressBar::with_mode` function.
+        pub fn with_mode(&mut self, mode: ProgressBarMode)  -> crate::widgets::ProgressBar { unsafe { crate::dll::AzProgressBar_withMode(self, mode) } }
+        /// Calls the `ProgressBar::set_style` function.
+        pub fn set_style(&mut self, style: ProgressBarStyle)  { unsafe { crate::dll::AzProgressBar_setStyle(self, style) } }
+        /// Calls the `ProgressBar::with_style` function.
+        pub fn with_style(&mut self, style: ProgressBarStyle)  -> crate::widgets::ProgressBar { unsafe { crate::dll::AzProgressBar_withStyle(self, style) } }
+        /// Calls the `ProgressBar::set_display_percentage` function.
+        pub fn set_display_percentage(&mut self, display_percentage: bool)  { unsafe { crate::dll::AzProgressBar_setDisplayPercentage(self, display_percentage) } }
+        /// Calls the `ProgressBar::with_display_percentage` function.
+        pub fn with_display_percentage(&mut self, display_percentage: bool)  -> crate::widgets::ProgressBar { unsafe { crate::dll::AzProgressBar_withDisplayPercentage(self, display_percentage) } }
+        /// Calls the `ProgressBar::set_text_format` function.
+        pub fn set_text_format(&mut self, text_format: String)  { unsafe { crate::dll::AzProgressBar_setTextFormat(self, text_format) } }
+        /// Calls the `ProgressBar::with_text_format` function.
+        pub fn with_text_format(&mut self, text_format: String)  -> crate::widgets::ProgressBar { unsafe { crate::dll::AzProgressBar_withTextFormat(self, text_format) } }
     }
 
     /// `ProgressBarState` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzProgressBarState as ProgressBarState;
+    /// `ProgressBarMode` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzProgressBarMode as ProgressBarMode;
+    /// `ProgressBarStyle` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzProgressBarStyle as ProgressBarStyle;
+    /// Circular, endlessly rotating "busy" indicator for operations without a known total
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSpinner as Spinner;
+    #[cfg(not(feature = "link_static"))]
+    impl Spinner {
+
+        /// Creates a new `Spinner` instance.
+        pub fn new() -> Self { unsafe { crate::dll::AzSpinner_new() } }
+        /// Calls the `Spinner::set_size` function.
+        pub fn set_size(&mut self, size: PixelValue)  { unsafe { crate::dll::AzSpinner_setSize(self, size) } }
+        /// Calls the `Spinner::with_size` function.
+        pub fn with_size(&mut self, size: PixelValue)  -> crate::widgets::Spinner { unsafe { crate::dll::AzSpinner_withSize(self, size) } }
+        /// Calls the `Spinner::set_thickness` function.
+        pub fn set_thickness(&mut self, thickness: PixelValue)  { unsafe { crate::dll::AzSpinner_setThickness(self, thickness) } }
+        /// Calls the `Spinner::with_thickness` function.
+        pub fn with_thickness(&mut self, thickness: PixelValue)  -> crate::widgets::Spinner { unsafe { crate::dll::AzSpinner_withThickness(self, thickness) } }
+        /// Calls the `Spinner::set_color` function.
+        pub fn set_color(&mut self, color: ColorU)  { unsafe { crate::dll::AzSpinner_setColor(self, color) } }
+        /// Calls the `Spinner::with_color` function.
+        pub fn with_color(&mut self, color: ColorU)  -> crate::widgets::Spinner { unsafe { crate::dll::AzSpinner_withColor(self, color) } }
+        /// Calls the `Spinner::set_track_color` function.
+        pub fn set_track_color(&mut self, track_color: ColorU)  { unsafe { crate::dll::AzSpinner_setTrackColor(self, track_color) } }
+        /// Calls the `Spinner::with_track_color` function.
+        pub fn with_track_color(&mut self, track_color: ColorU)  -> crate::widgets::Spinner { unsafe { crate::dll::AzSpinner_withTrackColor(self, track_color) } }
+        /// Calls the `Spinner::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzSpinner_dom(self) } }
+    }
+
     /// `TabHeader` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabHeader as TabHeader;
@@ -15211,6 +18354,18 @@ pub mod widgets {
         pub fn set_on_click(&mut self, data: RefAny, callback: TabOnClickCallbackType)  { unsafe { crate::dll::AzTabHeader_setOnClick(self, data, callback) } }
         /// Calls the `TabHeader::with_on_click` function.
         pub fn with_on_click(&mut self, data: RefAny, callback: TabOnClickCallbackType)  -> crate::widgets::TabHeader { unsafe { crate::dll::AzTabHeader_withOnClick(self, data, callback) } }
+        /// Calls the `TabHeader::set_on_close` function.
+        pub fn set_on_close(&mut self, data: RefAny, callback: TabOnCloseCallbackType)  { unsafe { crate::dll::AzTabHeader_setOnClose(self, data, callback) } }
+        /// Calls the `TabHeader::with_on_close` function.
+        pub fn with_on_close(&mut self, data: RefAny, callback: TabOnCloseCallbackType)  -> crate::widgets::TabHeader { unsafe { crate::dll::AzTabHeader_withOnClose(self, data, callback) } }
+        /// Calls the `TabHeader::set_on_reorder` function.
+        pub fn set_on_reorder(&mut self, data: RefAny, callback: TabOnReorderCallbackType)  { unsafe { crate::dll::AzTabHeader_setOnReorder(self, data, callback) } }
+        /// Calls the `TabHeader::with_on_reorder` function.
+        pub fn with_on_reorder(&mut self, data: RefAny, callback: TabOnReorderCallbackType)  -> crate::widgets::TabHeader { unsafe { crate::dll::AzTabHeader_withOnReorder(self, data, callback) } }
+        /// Calls the `TabHeader::set_on_context_menu` function.
+        pub fn set_on_context_menu(&mut self, data: RefAny, callback: TabOnContextMenuCallbackType)  { unsafe { crate::dll::AzTabHeader_setOnContextMenu(self, data, callback) } }
+        /// Calls the `TabHeader::with_on_context_menu` function.
+        pub fn with_on_context_menu(&mut self, data: RefAny, callback: TabOnContextMenuCallbackType)  -> crate::widgets::TabHeader { unsafe { crate::dll::AzTabHeader_withOnContextMenu(self, data, callback) } }
         /// Calls the `TabHeader::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTabHeader_dom(self) } }
     }
@@ -15243,6 +18398,33 @@ pub mod widgets {
     /// `TabOnClickCallbackType` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnClickCallbackType as TabOnClickCallbackType;
+    /// `TabOnClose` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnClose as TabOnClose;
+    /// `TabOnCloseCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnCloseCallback as TabOnCloseCallback;
+    /// `TabOnCloseCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnCloseCallbackType as TabOnCloseCallbackType;
+    /// `TabOnReorder` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnReorder as TabOnReorder;
+    /// `TabOnReorderCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnReorderCallback as TabOnReorderCallback;
+    /// `TabOnReorderCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnReorderCallbackType as TabOnReorderCallbackType;
+    /// `TabOnContextMenu` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnContextMenu as TabOnContextMenu;
+    /// `TabOnContextMenuCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnContextMenuCallback as TabOnContextMenuCallback;
+    /// `TabOnContextMenuCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTabOnContextMenuCallbackType as TabOnContextMenuCallbackType;
     /// `Frame` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzFrame as Frame;
@@ -15259,6 +18441,145 @@ pub mod widgets {
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzFrame_dom(self) } }
     }
 
+    /// Container with resizable panes, separated by draggable handles
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitter as Splitter;
+    #[cfg(not(feature = "link_static"))]
+    impl Splitter {
+
+        /// Creates a new `Splitter` instance.
+        pub fn new(orientation: SplitterOrientation, panes: SplitterPaneVec) -> Self { unsafe { crate::dll::AzSplitter_new(orientation, panes) } }
+        /// Calls the `Splitter::set_state` function.
+        pub fn set_state(&mut self, state: SplitterState)  { unsafe { crate::dll::AzSplitter_setState(self, state) } }
+        /// Calls the `Splitter::with_state` function.
+        pub fn with_state(&mut self, state: SplitterState)  -> crate::widgets::Splitter { unsafe { crate::dll::AzSplitter_withState(self, state) } }
+        /// Calls the `Splitter::set_on_resize` function.
+        pub fn set_on_resize(&mut self, data: RefAny, callback: SplitterOnResizeCallbackType)  { unsafe { crate::dll::AzSplitter_setOnResize(self, data, callback) } }
+        /// Calls the `Splitter::with_on_resize` function.
+        pub fn with_on_resize(&mut self, data: RefAny, callback: SplitterOnResizeCallbackType)  -> crate::widgets::Splitter { unsafe { crate::dll::AzSplitter_withOnResize(self, data, callback) } }
+        /// Calls the `Splitter::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzSplitter_dom(self) } }
+    }
+
+    /// `SplitterOrientation` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterOrientation as SplitterOrientation;
+    /// `SplitterPane` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterPane as SplitterPane;
+    #[cfg(not(feature = "link_static"))]
+    impl SplitterPane {
+
+        /// Creates a new `SplitterPane` instance.
+        pub fn new(content: Dom) -> Self { unsafe { crate::dll::AzSplitterPane_new(content) } }
+        /// Calls the `SplitterPane::set_size` function.
+        pub fn set_size(&mut self, size: f32)  { unsafe { crate::dll::AzSplitterPane_setSize(self, size) } }
+        /// Calls the `SplitterPane::with_size` function.
+        pub fn with_size(&mut self, size: f32)  -> crate::widgets::SplitterPane { unsafe { crate::dll::AzSplitterPane_withSize(self, size) } }
+        /// Calls the `SplitterPane::set_min_size` function.
+        pub fn set_min_size(&mut self, min_size: f32)  { unsafe { crate::dll::AzSplitterPane_setMinSize(self, min_size) } }
+        /// Calls the `SplitterPane::with_min_size` function.
+        pub fn with_min_size(&mut self, min_size: f32)  -> crate::widgets::SplitterPane { unsafe { crate::dll::AzSplitterPane_withMinSize(self, min_size) } }
+        /// Calls the `SplitterPane::set_max_size` function.
+        pub fn set_max_size(&mut self, max_size: f32)  { unsafe { crate::dll::AzSplitterPane_setMaxSize(self, max_size) } }
+        /// Calls the `SplitterPane::with_max_size` function.
+        pub fn with_max_size(&mut self, max_size: f32)  -> crate::widgets::SplitterPane { unsafe { crate::dll::AzSplitterPane_withMaxSize(self, max_size) } }
+        /// Calls the `SplitterPane::set_collapsible` function.
+        pub fn set_collapsible(&mut self, collapsible: bool)  { unsafe { crate::dll::AzSplitterPane_setCollapsible(self, collapsible) } }
+        /// Calls the `SplitterPane::with_collapsible` function.
+        pub fn with_collapsible(&mut self, collapsible: bool)  -> crate::widgets::SplitterPane { unsafe { crate::dll::AzSplitterPane_withCollapsible(self, collapsible) } }
+    }
+
+    /// `SplitterState` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterState as SplitterState;
+    /// `SplitterPaneState` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterPaneState as SplitterPaneState;
+    /// `SplitterOnResize` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterOnResize as SplitterOnResize;
+    /// `SplitterOnResizeCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterOnResizeCallback as SplitterOnResizeCallback;
+    /// `SplitterOnResizeCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterOnResizeCallbackType as SplitterOnResizeCallbackType;
+    /// Thumb that can be dragged along a track to select a value between `min` and `max`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSlider as Slider;
+    #[cfg(not(feature = "link_static"))]
+    impl Slider {
+
+        /// Creates a new `Slider` instance.
+        pub fn new(orientation: SliderOrientation, value: f32, min: f32, max: f32) -> Self { unsafe { crate::dll::AzSlider_new(orientation, value, min, max) } }
+        /// Calls the `Slider::set_step` function.
+        pub fn set_step(&mut self, step: f32)  { unsafe { crate::dll::AzSlider_setStep(self, step) } }
+        /// Calls the `Slider::with_step` function.
+        pub fn with_step(&mut self, step: f32)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withStep(self, step) } }
+        /// Calls the `Slider::set_tick_interval` function.
+        pub fn set_tick_interval(&mut self, tick_interval: f32)  { unsafe { crate::dll::AzSlider_setTickInterval(self, tick_interval) } }
+        /// Calls the `Slider::with_tick_interval` function.
+        pub fn with_tick_interval(&mut self, tick_interval: f32)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withTickInterval(self, tick_interval) } }
+        /// Calls the `Slider::set_on_value_change` function.
+        pub fn set_on_value_change(&mut self, data: RefAny, callback: SliderOnValueChangeCallbackType)  { unsafe { crate::dll::AzSlider_setOnValueChange(self, data, callback) } }
+        /// Calls the `Slider::with_on_value_change` function.
+        pub fn with_on_value_change(&mut self, data: RefAny, callback: SliderOnValueChangeCallbackType)  -> crate::widgets::Slider { unsafe { crate::dll::AzSlider_withOnValueChange(self, data, callback) } }
+        /// Calls the `Slider::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzSlider_dom(self) } }
+    }
+
+    /// Slider with two thumbs to select a range (`low..=high`) between `min` and `max`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRangeSlider as RangeSlider;
+    #[cfg(not(feature = "link_static"))]
+    impl RangeSlider {
+
+        /// Creates a new `RangeSlider` instance.
+        pub fn new(orientation: SliderOrientation, low: f32, high: f32, min: f32, max: f32) -> Self { unsafe { crate::dll::AzRangeSlider_new(orientation, low, high, min, max) } }
+        /// Calls the `RangeSlider::set_step` function.
+        pub fn set_step(&mut self, step: f32)  { unsafe { crate::dll::AzRangeSlider_setStep(self, step) } }
+        /// Calls the `RangeSlider::with_step` function.
+        pub fn with_step(&mut self, step: f32)  -> crate::widgets::RangeSlider { unsafe { crate::dll::AzRangeSlider_withStep(self, step) } }
+        /// Calls the `RangeSlider::set_tick_interval` function.
+        pub fn set_tick_interval(&mut self, tick_interval: f32)  { unsafe { crate::dll::AzRangeSlider_setTickInterval(self, tick_interval) } }
+        /// Calls the `RangeSlider::with_tick_interval` function.
+        pub fn with_tick_interval(&mut self, tick_interval: f32)  -> crate::widgets::RangeSlider { unsafe { crate::dll::AzRangeSlider_withTickInterval(self, tick_interval) } }
+        /// Calls the `RangeSlider::set_on_value_change` function.
+        pub fn set_on_value_change(&mut self, data: RefAny, callback: RangeSliderOnValueChangeCallbackType)  { unsafe { crate::dll::AzRangeSlider_setOnValueChange(self, data, callback) } }
+        /// Calls the `RangeSlider::with_on_value_change` function.
+        pub fn with_on_value_change(&mut self, data: RefAny, callback: RangeSliderOnValueChangeCallbackType)  -> crate::widgets::RangeSlider { unsafe { crate::dll::AzRangeSlider_withOnValueChange(self, data, callback) } }
+        /// Calls the `RangeSlider::dom` function.
+        pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzRangeSlider_dom(self) } }
+    }
+
+    /// `SliderOrientation` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSliderOrientation as SliderOrientation;
+    /// `SliderState` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSliderState as SliderState;
+    /// `RangeSliderState` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRangeSliderState as RangeSliderState;
+    /// `SliderOnValueChange` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSliderOnValueChange as SliderOnValueChange;
+    /// `SliderOnValueChangeCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSliderOnValueChangeCallback as SliderOnValueChangeCallback;
+    /// `SliderOnValueChangeCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSliderOnValueChangeCallbackType as SliderOnValueChangeCallbackType;
+    /// `RangeSliderOnValueChange` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRangeSliderOnValueChange as RangeSliderOnValueChange;
+    /// `RangeSliderOnValueChangeCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRangeSliderOnValueChangeCallback as RangeSliderOnValueChangeCallback;
+    /// `RangeSliderOnValueChangeCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRangeSliderOnValueChangeCallbackType as RangeSliderOnValueChangeCallbackType;
     /// `NodeGraph` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzNodeGraph as NodeGraph;
@@ -15411,6 +18732,42 @@ pub mod widgets {
         pub fn new(columns: StringVec) -> Self { unsafe { crate::dll::AzListView_new(columns) } }
         /// Calls the `ListView::with_rows` function.
         pub fn with_rows(&mut self, rows: ListViewRowVec)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withRows(self, rows) } }
+        /// Calls the `ListView::set_sorted_by` function.
+        pub fn set_sorted_by(&mut self, sorted_by: OptionUsize)  { unsafe { crate::dll::AzListView_setSortedBy(self, sorted_by) } }
+        /// Calls the `ListView::with_sorted_by` function.
+        pub fn with_sorted_by(&mut self, sorted_by: OptionUsize)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withSortedBy(self, sorted_by) } }
+        /// Calls the `ListView::set_scroll_offset` function.
+        pub fn set_scroll_offset(&mut self, scroll_offset: PixelValueNoPercent)  { unsafe { crate::dll::AzListView_setScrollOffset(self, scroll_offset) } }
+        /// Calls the `ListView::with_scroll_offset` function.
+        pub fn with_scroll_offset(&mut self, scroll_offset: PixelValueNoPercent)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withScrollOffset(self, scroll_offset) } }
+        /// Calls the `ListView::set_row_height` function.
+        pub fn set_row_height(&mut self, row_height: PixelValueNoPercent)  { unsafe { crate::dll::AzListView_setRowHeight(self, row_height) } }
+        /// Calls the `ListView::with_row_height` function.
+        pub fn with_row_height(&mut self, row_height: PixelValueNoPercent)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withRowHeight(self, row_height) } }
+        /// Calls the `ListView::set_column_widths` function.
+        pub fn set_column_widths(&mut self, column_widths: F32Vec)  { unsafe { crate::dll::AzListView_setColumnWidths(self, column_widths) } }
+        /// Calls the `ListView::with_column_widths` function.
+        pub fn with_column_widths(&mut self, column_widths: F32Vec)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withColumnWidths(self, column_widths) } }
+        /// Calls the `ListView::set_row_provider` function.
+        pub fn set_row_provider(&mut self, row_count: usize, data: RefAny, callback: ListViewRowProviderCallbackType)  { unsafe { crate::dll::AzListView_setRowProvider(self, row_count, data, callback) } }
+        /// Calls the `ListView::with_row_provider` function.
+        pub fn with_row_provider(&mut self, row_count: usize, data: RefAny, callback: ListViewRowProviderCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withRowProvider(self, row_count, data, callback) } }
+        /// Calls the `ListView::set_on_lazy_load_scroll` function.
+        pub fn set_on_lazy_load_scroll(&mut self, data: RefAny, callback: ListViewOnLazyLoadScrollCallbackType)  { unsafe { crate::dll::AzListView_setOnLazyLoadScroll(self, data, callback) } }
+        /// Calls the `ListView::with_on_lazy_load_scroll` function.
+        pub fn with_on_lazy_load_scroll(&mut self, data: RefAny, callback: ListViewOnLazyLoadScrollCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withOnLazyLoadScroll(self, data, callback) } }
+        /// Calls the `ListView::set_on_column_click` function.
+        pub fn set_on_column_click(&mut self, data: RefAny, callback: ListViewOnColumnClickCallbackType)  { unsafe { crate::dll::AzListView_setOnColumnClick(self, data, callback) } }
+        /// Calls the `ListView::with_on_column_click` function.
+        pub fn with_on_column_click(&mut self, data: RefAny, callback: ListViewOnColumnClickCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withOnColumnClick(self, data, callback) } }
+        /// Calls the `ListView::set_on_column_resize` function.
+        pub fn set_on_column_resize(&mut self, data: RefAny, callback: ListViewOnColumnResizeCallbackType)  { unsafe { crate::dll::AzListView_setOnColumnResize(self, data, callback) } }
+        /// Calls the `ListView::with_on_column_resize` function.
+        pub fn with_on_column_resize(&mut self, data: RefAny, callback: ListViewOnColumnResizeCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withOnColumnResize(self, data, callback) } }
+        /// Calls the `ListView::set_on_row_click` function.
+        pub fn set_on_row_click(&mut self, data: RefAny, callback: ListViewOnRowClickCallbackType)  { unsafe { crate::dll::AzListView_setOnRowClick(self, data, callback) } }
+        /// Calls the `ListView::with_on_row_click` function.
+        pub fn with_on_row_click(&mut self, data: RefAny, callback: ListViewOnRowClickCallbackType)  -> crate::widgets::ListView { unsafe { crate::dll::AzListView_withOnRowClick(self, data, callback) } }
         /// Calls the `ListView::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzListView_dom(self) } }
     }
@@ -15448,6 +18805,27 @@ pub mod widgets {
     /// `ListViewOnRowClick` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewOnRowClick as ListViewOnRowClick;
+    /// Range of row indices, `start` is inclusive, `end` is exclusive
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewRowRange as ListViewRowRange;
+    /// `ListViewOnColumnResizeCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewOnColumnResizeCallbackType as ListViewOnColumnResizeCallbackType;
+    /// `ListViewOnColumnResizeCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewOnColumnResizeCallback as ListViewOnColumnResizeCallback;
+    /// `ListViewOnColumnResize` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewOnColumnResize as ListViewOnColumnResize;
+    /// Returns the rows in the given range, in the order given by `ListViewState::sorted_by`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewRowProviderCallbackType as ListViewRowProviderCallbackType;
+    /// `ListViewRowProviderCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewRowProviderCallback as ListViewRowProviderCallback;
+    /// `ListViewRowProvider` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewRowProvider as ListViewRowProvider;
     /// `TreeView` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeView as TreeView;
@@ -15455,11 +18833,94 @@ pub mod widgets {
     impl TreeView {
 
         /// Creates a new `TreeView` instance.
-        pub fn new(root: String) -> Self { unsafe { crate::dll::AzTreeView_new(root) } }
+        pub fn new(nodes: TreeViewNodeVec) -> Self { unsafe { crate::dll::AzTreeView_new(nodes) } }
+        /// Calls the `TreeView::set_nodes` function.
+        pub fn set_nodes(&mut self, nodes: TreeViewNodeVec)  { unsafe { crate::dll::AzTreeView_setNodes(self, nodes) } }
+        /// Calls the `TreeView::with_nodes` function.
+        pub fn with_nodes(&mut self, nodes: TreeViewNodeVec)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withNodes(self, nodes) } }
+        /// Calls the `TreeView::set_state` function.
+        pub fn set_state(&mut self, state: TreeViewState)  { unsafe { crate::dll::AzTreeView_setState(self, state) } }
+        /// Calls the `TreeView::with_state` function.
+        pub fn with_state(&mut self, state: TreeViewState)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withState(self, state) } }
+        /// Calls the `TreeView::set_selection_mode` function.
+        pub fn set_selection_mode(&mut self, selection_mode: TreeViewSelectionMode)  { unsafe { crate::dll::AzTreeView_setSelectionMode(self, selection_mode) } }
+        /// Calls the `TreeView::with_selection_mode` function.
+        pub fn with_selection_mode(&mut self, selection_mode: TreeViewSelectionMode)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withSelectionMode(self, selection_mode) } }
+        /// Calls the `TreeView::set_on_expand` function.
+        pub fn set_on_expand(&mut self, data: RefAny, callback: TreeViewOnExpandCallbackType)  { unsafe { crate::dll::AzTreeView_setOnExpand(self, data, callback) } }
+        /// Calls the `TreeView::with_on_expand` function.
+        pub fn with_on_expand(&mut self, data: RefAny, callback: TreeViewOnExpandCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnExpand(self, data, callback) } }
+        /// Calls the `TreeView::set_on_select` function.
+        pub fn set_on_select(&mut self, data: RefAny, callback: TreeViewOnSelectCallbackType)  { unsafe { crate::dll::AzTreeView_setOnSelect(self, data, callback) } }
+        /// Calls the `TreeView::with_on_select` function.
+        pub fn with_on_select(&mut self, data: RefAny, callback: TreeViewOnSelectCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnSelect(self, data, callback) } }
+        /// Calls the `TreeView::set_on_double_click` function.
+        pub fn set_on_double_click(&mut self, data: RefAny, callback: TreeViewOnDoubleClickCallbackType)  { unsafe { crate::dll::AzTreeView_setOnDoubleClick(self, data, callback) } }
+        /// Calls the `TreeView::with_on_double_click` function.
+        pub fn with_on_double_click(&mut self, data: RefAny, callback: TreeViewOnDoubleClickCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnDoubleClick(self, data, callback) } }
+        /// Calls the `TreeView::set_on_context_menu` function.
+        pub fn set_on_context_menu(&mut self, data: RefAny, callback: TreeViewOnContextMenuCallbackType)  { unsafe { crate::dll::AzTreeView_setOnContextMenu(self, data, callback) } }
+        /// Calls the `TreeView::with_on_context_menu` function.
+        pub fn with_on_context_menu(&mut self, data: RefAny, callback: TreeViewOnContextMenuCallbackType)  -> crate::widgets::TreeView { unsafe { crate::dll::AzTreeView_withOnContextMenu(self, data, callback) } }
         /// Calls the `TreeView::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzTreeView_dom(self) } }
     }
 
+    /// `TreeViewNodeId` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewNodeId as TreeViewNodeId;
+    /// `TreeViewNode` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewNode as TreeViewNode;
+    #[cfg(not(feature = "link_static"))]
+    impl TreeViewNode {
+
+        /// Creates a new `TreeViewNode` instance.
+        pub fn new(id: TreeViewNodeId, parent: OptionTreeViewNodeId, label: String) -> Self { unsafe { crate::dll::AzTreeViewNode_new(id, parent, label) } }
+    }
+
+    /// `TreeViewSelectionMode` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewSelectionMode as TreeViewSelectionMode;
+    /// `TreeViewState` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewState as TreeViewState;
+    /// `TreeViewOnExpandCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnExpandCallbackType as TreeViewOnExpandCallbackType;
+    /// `TreeViewOnExpandCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnExpandCallback as TreeViewOnExpandCallback;
+    /// `TreeViewOnExpand` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnExpand as TreeViewOnExpand;
+    /// `TreeViewOnSelectCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnSelectCallbackType as TreeViewOnSelectCallbackType;
+    /// `TreeViewOnSelectCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnSelectCallback as TreeViewOnSelectCallback;
+    /// `TreeViewOnSelect` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnSelect as TreeViewOnSelect;
+    /// `TreeViewOnDoubleClickCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnDoubleClickCallbackType as TreeViewOnDoubleClickCallbackType;
+    /// `TreeViewOnDoubleClickCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnDoubleClickCallback as TreeViewOnDoubleClickCallback;
+    /// `TreeViewOnDoubleClick` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnDoubleClick as TreeViewOnDoubleClick;
+    /// `TreeViewOnContextMenuCallbackType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnContextMenuCallbackType as TreeViewOnContextMenuCallbackType;
+    /// `TreeViewOnContextMenuCallback` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnContextMenuCallback as TreeViewOnContextMenuCallback;
+    /// `TreeViewOnContextMenu` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewOnContextMenu as TreeViewOnContextMenu;
     /// `DropDown` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzDropDown as DropDown;
@@ -15468,6 +18929,20 @@ pub mod widgets {
 
         /// Creates a new `DropDown` instance.
         pub fn new(choices: StringVec) -> Self { unsafe { crate::dll::AzDropDown_new(choices) } }
+        /// Calls the `DropDown::set_selected` function.
+        pub fn set_selected(&mut self, selected: usize)  { unsafe { crate::dll::AzDropDown_setSelected(self, selected) } }
+        /// Calls the `DropDown::with_selected` function.
+        pub fn with_selected(&mut self, selected: usize)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withSelected(self, selected) } }
+        /// Calls the `DropDown::set_disabled_choices` function.
+        pub fn set_disabled_choices(&mut self, disabled_choices: U32Vec)  { unsafe { crate::dll::AzDropDown_setDisabledChoices(self, disabled_choices) } }
+        /// Calls the `DropDown::with_disabled_choices` function.
+        pub fn with_disabled_choices(&mut self, disabled_choices: U32Vec)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withDisabledChoices(self, disabled_choices) } }
+        /// Calls the `DropDown::set_on_choice_change` function.
+        pub fn set_on_choice_change(&mut self, data: RefAny, callback: DropDownOnChoiceChangeCallbackType)  { unsafe { crate::dll::AzDropDown_setOnChoiceChange(self, data, callback) } }
+        /// Calls the `DropDown::with_on_choice_change` function.
+        pub fn with_on_choice_change(&mut self, data: RefAny, callback: DropDownOnChoiceChangeCallbackType)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_withOnChoiceChange(self, data, callback) } }
+        /// Calls the `DropDown::swap_with_default` function.
+        pub fn swap_with_default(&mut self)  -> crate::widgets::DropDown { unsafe { crate::dll::AzDropDown_swapWithDefault(self) } }
         /// Calls the `DropDown::dom` function.
         pub fn dom(&mut self)  -> crate::dom::Dom { unsafe { crate::dll::AzDropDown_dom(self) } }
     }
@@ -18973,6 +22448,14 @@ pub mod task {
     #[cfg(feature = "link_static")]
     pub use azul::AzGetSystemTimeFn as GetSystemTimeFn;
     #[cfg(feature = "link_static")]
+    pub use azul::AzGetClipboardFnType as GetClipboardFnType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzGetClipboardFn as GetClipboardFn;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSetClipboardFnType as SetClipboardFnType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSetClipboardFn as SetClipboardFn;
+    #[cfg(feature = "link_static")]
     pub use azul::AzCheckThreadFinishedFnType as CheckThreadFinishedFnType;
     #[cfg(feature = "link_static")]
     pub use azul::AzCheckThreadFinishedFn as CheckThreadFinishedFn;
@@ -19090,6 +22573,18 @@ pub mod task {
     /// Get the current system time, equivalent to `std::time::Instant::now()`, except it also works on systems that work with "ticks" instead of timers
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGetSystemTimeFn as GetSystemTimeFn;
+    /// `GetClipboardFnType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGetClipboardFnType as GetClipboardFnType;
+    /// Reads the content of the system clipboard in the given MIME type
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGetClipboardFn as GetClipboardFn;
+    /// `SetClipboardFnType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSetClipboardFnType as SetClipboardFnType;
+    /// Sets the content of the system clipboard, returns whether the clipboard could be set
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSetClipboardFn as SetClipboardFn;
     /// Callback that checks whether the thread has finished - the input argument is the `dropcheck` field on the Thread.
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCheckThreadFinishedFnType as CheckThreadFinishedFnType;
@@ -19268,6 +22763,22 @@ pub mod vec {
     #[cfg(feature = "link_static")]
     pub use azul::AzListViewRowVec as ListViewRowVec;
     #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewNodeVec as TreeViewNodeVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewNodeIdVec as TreeViewNodeIdVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonTabVec as RibbonTabVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonGroupVec as RibbonGroupVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonItemVec as RibbonItemVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterPaneVec as SplitterPaneVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterPaneStateVec as SplitterPaneStateVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTextAreaEditVec as TextAreaEditVec;
+    #[cfg(feature = "link_static")]
     pub use azul::AzStyleFilterVec as StyleFilterVec;
     #[cfg(feature = "link_static")]
     pub use azul::AzLogicalRectVec as LogicalRectVec;
@@ -19292,6 +22803,8 @@ pub mod vec {
     #[cfg(feature = "link_static")]
     pub use azul::AzAccessibilityStateVec as AccessibilityStateVec;
     #[cfg(feature = "link_static")]
+    pub use azul::AzAccessibilityActionVec as AccessibilityActionVec;
+    #[cfg(feature = "link_static")]
     pub use azul::AzMenuItemVec as MenuItemVec;
     #[cfg(feature = "link_static")]
     pub use azul::AzTessellatedSvgNodeVec as TessellatedSvgNodeVec;
@@ -19330,6 +22843,24 @@ pub mod vec {
     #[cfg(feature = "link_static")]
     pub use azul::AzStyleTransformVec as StyleTransformVec;
     #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTransitionPropertyVec as StyleTransitionPropertyVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTimeVec as StyleTimeVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTimingFunctionVec as StyleTimingFunctionVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationNameVec as StyleAnimationNameVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationIterationCountVec as StyleAnimationIterationCountVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationDirectionVec as StyleAnimationDirectionVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationFillModeVec as StyleAnimationFillModeVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationPlayStateVec as StyleAnimationPlayStateVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzGridTrackSizingVec as GridTrackSizingVec;
+    #[cfg(feature = "link_static")]
     pub use azul::AzCssPropertyVec as CssPropertyVec;
     #[cfg(feature = "link_static")]
     pub use azul::AzSvgMultiPolygonVec as SvgMultiPolygonVec;
@@ -19358,6 +22889,16 @@ pub mod vec {
     #[cfg(feature = "link_static")]
     pub use azul::AzStylesheetVec as StylesheetVec;
     #[cfg(feature = "link_static")]
+    pub use azul::AzCssKeyframesVec as CssKeyframesVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssKeyframeVec as CssKeyframeVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaRuleVec as CssMediaRuleVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaQueryVec as CssMediaQueryVec;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaFeatureVec as CssMediaFeatureVec;
+    #[cfg(feature = "link_static")]
     pub use azul::AzCssRuleBlockVec as CssRuleBlockVec;
     #[cfg(feature = "link_static")]
     pub use azul::AzU16Vec as U16Vec;
@@ -19402,6 +22943,38 @@ pub mod vec {
     #[cfg(feature = "link_static")]
     pub use azul::AzListViewRowVecDestructorType as ListViewRowVecDestructorType;
     #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewNodeVecDestructor as TreeViewNodeVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewNodeVecDestructorType as TreeViewNodeVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewNodeIdVecDestructor as TreeViewNodeIdVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTreeViewNodeIdVecDestructorType as TreeViewNodeIdVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonTabVecDestructor as RibbonTabVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonTabVecDestructorType as RibbonTabVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonGroupVecDestructor as RibbonGroupVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonGroupVecDestructorType as RibbonGroupVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonItemVecDestructor as RibbonItemVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzRibbonItemVecDestructorType as RibbonItemVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterPaneVecDestructor as SplitterPaneVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterPaneVecDestructorType as SplitterPaneVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterPaneStateVecDestructor as SplitterPaneStateVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzSplitterPaneStateVecDestructorType as SplitterPaneStateVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTextAreaEditVecDestructor as TextAreaEditVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzTextAreaEditVecDestructorType as TextAreaEditVecDestructorType;
+    #[cfg(feature = "link_static")]
     pub use azul::AzStyleFilterVecDestructor as StyleFilterVecDestructor;
     #[cfg(feature = "link_static")]
     pub use azul::AzStyleFilterVecDestructorType as StyleFilterVecDestructorType;
@@ -19450,6 +23023,10 @@ pub mod vec {
     #[cfg(feature = "link_static")]
     pub use azul::AzAccessibilityStateVecDestructorType as AccessibilityStateVecDestructorType;
     #[cfg(feature = "link_static")]
+    pub use azul::AzAccessibilityActionVecDestructor as AccessibilityActionVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzAccessibilityActionVecDestructorType as AccessibilityActionVecDestructorType;
+    #[cfg(feature = "link_static")]
     pub use azul::AzMenuItemVecDestructor as MenuItemVecDestructor;
     #[cfg(feature = "link_static")]
     pub use azul::AzMenuItemVecDestructorType as MenuItemVecDestructorType;
@@ -19522,6 +23099,42 @@ pub mod vec {
     #[cfg(feature = "link_static")]
     pub use azul::AzStyleTransformVecDestructorType as StyleTransformVecDestructorType;
     #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTransitionPropertyVecDestructor as StyleTransitionPropertyVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTransitionPropertyVecDestructorType as StyleTransitionPropertyVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTimeVecDestructor as StyleTimeVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTimeVecDestructorType as StyleTimeVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTimingFunctionVecDestructor as StyleTimingFunctionVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleTimingFunctionVecDestructorType as StyleTimingFunctionVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationNameVecDestructor as StyleAnimationNameVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationNameVecDestructorType as StyleAnimationNameVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationIterationCountVecDestructor as StyleAnimationIterationCountVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationIterationCountVecDestructorType as StyleAnimationIterationCountVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationDirectionVecDestructor as StyleAnimationDirectionVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationDirectionVecDestructorType as StyleAnimationDirectionVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationFillModeVecDestructor as StyleAnimationFillModeVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationFillModeVecDestructorType as StyleAnimationFillModeVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationPlayStateVecDestructor as StyleAnimationPlayStateVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzStyleAnimationPlayStateVecDestructorType as StyleAnimationPlayStateVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzGridTrackSizingVecDestructor as GridTrackSizingVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzGridTrackSizingVecDestructorType as GridTrackSizingVecDestructorType;
+    #[cfg(feature = "link_static")]
     pub use azul::AzCssPropertyVecDestructor as CssPropertyVecDestructor;
     #[cfg(feature = "link_static")]
     pub use azul::AzCssPropertyVecDestructorType as CssPropertyVecDestructorType;
@@ -19578,6 +23191,26 @@ pub mod vec {
     #[cfg(feature = "link_static")]
     pub use azul::AzStylesheetVecDestructorType as StylesheetVecDestructorType;
     #[cfg(feature = "link_static")]
+    pub use azul::AzCssKeyframesVecDestructor as CssKeyframesVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssKeyframesVecDestructorType as CssKeyframesVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssKeyframeVecDestructor as CssKeyframeVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssKeyframeVecDestructorType as CssKeyframeVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaRuleVecDestructor as CssMediaRuleVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaRuleVecDestructorType as CssMediaRuleVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaQueryVecDestructor as CssMediaQueryVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaQueryVecDestructorType as CssMediaQueryVecDestructorType;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaFeatureVecDestructor as CssMediaFeatureVecDestructor;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzCssMediaFeatureVecDestructorType as CssMediaFeatureVecDestructorType;
+    #[cfg(feature = "link_static")]
     pub use azul::AzCssRuleBlockVecDestructor as CssRuleBlockVecDestructor;
     #[cfg(feature = "link_static")]
     pub use azul::AzCssRuleBlockVecDestructorType as CssRuleBlockVecDestructorType;
@@ -20028,9 +23661,22 @@ pub mod vec {
     impl_vec_clone!(AzStyleFilter, AzStyleFilterVec, AzStyleFilterVecDestructor);
     impl_vec!(AzListViewRow, AzListViewRowVec, AzListViewRowVecDestructor, az_list_view_vec_destructor, AzListViewRowVec_delete);
     impl_vec_clone!(AzListViewRow, AzListViewRowVec, AzListViewRowVecDestructor);
+    impl_vec!(AzRibbonTab, AzRibbonTabVec, AzRibbonTabVecDestructor, az_ribbon_tab_vec_destructor, AzRibbonTabVec_delete);
+    impl_vec_clone!(AzRibbonTab, AzRibbonTabVec, AzRibbonTabVecDestructor);
+    impl_vec!(AzRibbonGroup, AzRibbonGroupVec, AzRibbonGroupVecDestructor, az_ribbon_group_vec_destructor, AzRibbonGroupVec_delete);
+    impl_vec_clone!(AzRibbonGroup, AzRibbonGroupVec, AzRibbonGroupVecDestructor);
+    impl_vec!(AzRibbonItem, AzRibbonItemVec, AzRibbonItemVecDestructor, az_ribbon_item_vec_destructor, AzRibbonItemVec_delete);
+    impl_vec_clone!(AzRibbonItem, AzRibbonItemVec, AzRibbonItemVecDestructor);
+    impl_vec!(AzSplitterPane, AzSplitterPaneVec, AzSplitterPaneVecDestructor, az_splitter_pane_vec_destructor, AzSplitterPaneVec_delete);
+    impl_vec_clone!(AzSplitterPane, AzSplitterPaneVec, AzSplitterPaneVecDestructor);
+    impl_vec!(AzSplitterPaneState, AzSplitterPaneStateVec, AzSplitterPaneStateVecDestructor, az_splitter_pane_state_vec_destructor, AzSplitterPaneStateVec_delete);
+    impl_vec_clone!(AzSplitterPaneState, AzSplitterPaneStateVec, AzSplitterPaneStateVecDestructor);
+    impl_vec_serde!(AzSplitterPaneState, AzSplitterPaneStateVec);
 
     impl_vec!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor, az_accessibility_state_vec_destructor, AzAccessibilityStateVec_delete);
     impl_vec_clone!(AzAccessibilityState,  AzAccessibilityStateVec,  AzAccessibilityStateVecDestructor);
+    impl_vec!(AzAccessibilityAction,  AzAccessibilityActionVec,  AzAccessibilityActionVecDestructor, az_accessibility_action_vec_destructor, AzAccessibilityActionVec_delete);
+    impl_vec_clone!(AzAccessibilityAction,  AzAccessibilityActionVec,  AzAccessibilityActionVecDestructor);
 
     impl_vec!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor, az_menu_item_vec_destructor, AzMenuItemVec_delete);
     impl_vec_clone!(AzMenuItem,  AzMenuItemVec,  AzMenuItemVecDestructor);
@@ -20064,6 +23710,30 @@ pub mod vec {
     }    /// Wrapper over a Rust-allocated `Vec<ListViewRow>`
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewRowVec as ListViewRowVec;
+    /// Wrapper over a Rust-allocated `Vec<TreeViewNode>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewNodeVec as TreeViewNodeVec;
+    /// Wrapper over a Rust-allocated `Vec<TreeViewNodeId>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewNodeIdVec as TreeViewNodeIdVec;
+    /// Wrapper over a Rust-allocated `Vec<RibbonTab>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonTabVec as RibbonTabVec;
+    /// Wrapper over a Rust-allocated `Vec<RibbonGroup>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonGroupVec as RibbonGroupVec;
+    /// Wrapper over a Rust-allocated `Vec<RibbonItem>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonItemVec as RibbonItemVec;
+    /// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterPaneVec as SplitterPaneVec;
+    /// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterPaneStateVec as SplitterPaneStateVec;
+    /// Wrapper over a Rust-allocated `Vec<TextAreaEdit>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextAreaEditVec as TextAreaEditVec;
     /// Wrapper over a Rust-allocated `Vec<StyleFilter>`
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleFilterVec as StyleFilterVec;
@@ -20100,6 +23770,9 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<AccessibilityState>`
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAccessibilityStateVec as AccessibilityStateVec;
+    /// Wrapper over a Rust-allocated `Vec<AccessibilityAction>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAccessibilityActionVec as AccessibilityActionVec;
     /// Wrapper over a Rust-allocated `Vec<MenuItem>`
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzMenuItemVec as MenuItemVec;
@@ -20164,6 +23837,33 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Vec<StyleTransform>`
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTransformVec as StyleTransformVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyVec as StyleTransitionPropertyVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleTime>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTimeVec as StyleTimeVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleTimingFunction>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTimingFunctionVec as StyleTimingFunctionVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationNameVec as StyleAnimationNameVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCountVec as StyleAnimationIterationCountVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationDirectionVec as StyleAnimationDirectionVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationFillModeVec as StyleAnimationFillModeVec;
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationPlayState>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationPlayStateVec as StyleAnimationPlayStateVec;
+    /// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridTrackSizingVec as GridTrackSizingVec;
     /// Wrapper over a Rust-allocated `Vec<CssProperty>`
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssPropertyVec as CssPropertyVec;
@@ -20206,6 +23906,21 @@ pub mod vec {
     /// Wrapper over a Rust-allocated `Stylesheet`
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStylesheetVec as StylesheetVec;
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssKeyframesVec as CssKeyframesVec;
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssKeyframeVec as CssKeyframeVec;
+    /// Wrapper over a Rust-allocated `Vec<CssMediaRule>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaRuleVec as CssMediaRuleVec;
+    /// Wrapper over a Rust-allocated `Vec<CssMediaQuery>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaQueryVec as CssMediaQueryVec;
+    /// Wrapper over a Rust-allocated `Vec<CssMediaFeature>`
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaFeatureVec as CssMediaFeatureVec;
     /// Wrapper over a Rust-allocated `CssRuleBlock`
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssRuleBlockVec as CssRuleBlockVec;
@@ -20281,6 +23996,54 @@ pub mod vec {
     /// `ListViewRowVecDestructorType` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzListViewRowVecDestructorType as ListViewRowVecDestructorType;
+    /// `TreeViewNodeVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewNodeVecDestructor as TreeViewNodeVecDestructor;
+    /// `TreeViewNodeVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewNodeVecDestructorType as TreeViewNodeVecDestructorType;
+    /// `TreeViewNodeIdVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewNodeIdVecDestructor as TreeViewNodeIdVecDestructor;
+    /// `TreeViewNodeIdVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTreeViewNodeIdVecDestructorType as TreeViewNodeIdVecDestructorType;
+    /// `RibbonTabVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonTabVecDestructor as RibbonTabVecDestructor;
+    /// `RibbonTabVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonTabVecDestructorType as RibbonTabVecDestructorType;
+    /// `RibbonGroupVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonGroupVecDestructor as RibbonGroupVecDestructor;
+    /// `RibbonGroupVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonGroupVecDestructorType as RibbonGroupVecDestructorType;
+    /// `RibbonItemVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonItemVecDestructor as RibbonItemVecDestructor;
+    /// `RibbonItemVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzRibbonItemVecDestructorType as RibbonItemVecDestructorType;
+    /// `SplitterPaneVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterPaneVecDestructor as SplitterPaneVecDestructor;
+    /// `SplitterPaneVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterPaneVecDestructorType as SplitterPaneVecDestructorType;
+    /// `SplitterPaneStateVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterPaneStateVecDestructor as SplitterPaneStateVecDestructor;
+    /// `SplitterPaneStateVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzSplitterPaneStateVecDestructorType as SplitterPaneStateVecDestructorType;
+    /// `TextAreaEditVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextAreaEditVecDestructor as TextAreaEditVecDestructor;
+    /// `TextAreaEditVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzTextAreaEditVecDestructorType as TextAreaEditVecDestructorType;
     /// `StyleFilterVecDestructor` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleFilterVecDestructor as StyleFilterVecDestructor;
@@ -20353,6 +24116,12 @@ pub mod vec {
     /// `AccessibilityStateVecDestructorType` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAccessibilityStateVecDestructorType as AccessibilityStateVecDestructorType;
+    /// `AccessibilityActionVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAccessibilityActionVecDestructor as AccessibilityActionVecDestructor;
+    /// `AccessibilityActionVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzAccessibilityActionVecDestructorType as AccessibilityActionVecDestructorType;
     /// `MenuItemVecDestructor` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzMenuItemVecDestructor as MenuItemVecDestructor;
@@ -20461,6 +24230,60 @@ pub mod vec {
     /// `StyleTransformVecDestructorType` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTransformVecDestructorType as StyleTransformVecDestructorType;
+    /// `StyleTransitionPropertyVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyVecDestructor as StyleTransitionPropertyVecDestructor;
+    /// `StyleTransitionPropertyVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTransitionPropertyVecDestructorType as StyleTransitionPropertyVecDestructorType;
+    /// `StyleTimeVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTimeVecDestructor as StyleTimeVecDestructor;
+    /// `StyleTimeVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTimeVecDestructorType as StyleTimeVecDestructorType;
+    /// `StyleTimingFunctionVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTimingFunctionVecDestructor as StyleTimingFunctionVecDestructor;
+    /// `StyleTimingFunctionVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleTimingFunctionVecDestructorType as StyleTimingFunctionVecDestructorType;
+    /// `StyleAnimationNameVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationNameVecDestructor as StyleAnimationNameVecDestructor;
+    /// `StyleAnimationNameVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationNameVecDestructorType as StyleAnimationNameVecDestructorType;
+    /// `StyleAnimationIterationCountVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCountVecDestructor as StyleAnimationIterationCountVecDestructor;
+    /// `StyleAnimationIterationCountVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationIterationCountVecDestructorType as StyleAnimationIterationCountVecDestructorType;
+    /// `StyleAnimationDirectionVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationDirectionVecDestructor as StyleAnimationDirectionVecDestructor;
+    /// `StyleAnimationDirectionVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationDirectionVecDestructorType as StyleAnimationDirectionVecDestructorType;
+    /// `StyleAnimationFillModeVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationFillModeVecDestructor as StyleAnimationFillModeVecDestructor;
+    /// `StyleAnimationFillModeVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationFillModeVecDestructorType as StyleAnimationFillModeVecDestructorType;
+    /// `StyleAnimationPlayStateVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationPlayStateVecDestructor as StyleAnimationPlayStateVecDestructor;
+    /// `StyleAnimationPlayStateVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStyleAnimationPlayStateVecDestructorType as StyleAnimationPlayStateVecDestructorType;
+    /// `GridTrackSizingVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridTrackSizingVecDestructor as GridTrackSizingVecDestructor;
+    /// `GridTrackSizingVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzGridTrackSizingVecDestructorType as GridTrackSizingVecDestructorType;
     /// `CssPropertyVecDestructor` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssPropertyVecDestructor as CssPropertyVecDestructor;
@@ -20545,6 +24368,36 @@ pub mod vec {
     /// `StylesheetVecDestructorType` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzStylesheetVecDestructorType as StylesheetVecDestructorType;
+    /// `CssKeyframesVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssKeyframesVecDestructor as CssKeyframesVecDestructor;
+    /// `CssKeyframesVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssKeyframesVecDestructorType as CssKeyframesVecDestructorType;
+    /// `CssKeyframeVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssKeyframeVecDestructor as CssKeyframeVecDestructor;
+    /// `CssKeyframeVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssKeyframeVecDestructorType as CssKeyframeVecDestructorType;
+    /// `CssMediaRuleVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaRuleVecDestructor as CssMediaRuleVecDestructor;
+    /// `CssMediaRuleVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaRuleVecDestructorType as CssMediaRuleVecDestructorType;
+    /// `CssMediaQueryVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaQueryVecDestructor as CssMediaQueryVecDestructor;
+    /// `CssMediaQueryVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaQueryVecDestructorType as CssMediaQueryVecDestructorType;
+    /// `CssMediaFeatureVecDestructor` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaFeatureVecDestructor as CssMediaFeatureVecDestructor;
+    /// `CssMediaFeatureVecDestructorType` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssMediaFeatureVecDestructorType as CssMediaFeatureVecDestructorType;
     /// `CssRuleBlockVecDestructor` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzCssRuleBlockVecDestructor as CssRuleBlockVecDestructor;
@@ -20663,10 +24516,24 @@ pub mod option {
     #[cfg(feature = "link_static")]
     pub use azul::AzOptionListViewOnRowClick as OptionListViewOnRowClick;
     #[cfg(feature = "link_static")]
+    pub use azul::AzOptionTreeViewNodeId as OptionTreeViewNodeId;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionTreeViewOnExpand as OptionTreeViewOnExpand;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionTreeViewOnSelect as OptionTreeViewOnSelect;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionTreeViewOnDoubleClick as OptionTreeViewOnDoubleClick;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionTreeViewOnContextMenu as OptionTreeViewOnContextMenu;
+    #[cfg(feature = "link_static")]
     pub use azul::AzOptionListViewOnColumnClick as OptionListViewOnColumnClick;
     #[cfg(feature = "link_static")]
     pub use azul::AzOptionListViewOnLazyLoadScroll as OptionListViewOnLazyLoadScroll;
     #[cfg(feature = "link_static")]
+    pub use azul::AzOptionListViewOnColumnResize as OptionListViewOnColumnResize;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionListViewRowProvider as OptionListViewRowProvider;
+    #[cfg(feature = "link_static")]
     pub use azul::AzOptionMenu as OptionMenu;
     #[cfg(feature = "link_static")]
     pub use azul::AzOptionPixelValueNoPercent as OptionPixelValueNoPercent;
@@ -20697,6 +24564,22 @@ pub mod option {
     #[cfg(feature = "link_static")]
     pub use azul::AzOptionTabOnClick as OptionTabOnClick;
     #[cfg(feature = "link_static")]
+    pub use azul::AzOptionTabOnClose as OptionTabOnClose;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionTabOnReorder as OptionTabOnReorder;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionTabOnContextMenu as OptionTabOnContextMenu;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionRibbonOnTabClicked as OptionRibbonOnTabClicked;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionRibbonOnItemClick as OptionRibbonOnItemClick;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionSplitterOnResize as OptionSplitterOnResize;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionSliderOnValueChange as OptionSliderOnValueChange;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionRangeSliderOnValueChange as OptionRangeSliderOnValueChange;
+    #[cfg(feature = "link_static")]
     pub use azul::AzOptionFileInputOnPathChange as OptionFileInputOnPathChange;
     #[cfg(feature = "link_static")]
     pub use azul::AzOptionCheckBoxOnToggle as OptionCheckBoxOnToggle;
@@ -20709,6 +24592,8 @@ pub mod option {
     #[cfg(feature = "link_static")]
     pub use azul::AzOptionTextInputSelection as OptionTextInputSelection;
     #[cfg(feature = "link_static")]
+    pub use azul::AzOptionTextAreaOnTextChange as OptionTextAreaOnTextChange;
+    #[cfg(feature = "link_static")]
     pub use azul::AzOptionNumberInputOnFocusLost as OptionNumberInputOnFocusLost;
     #[cfg(feature = "link_static")]
     pub use azul::AzOptionNumberInputOnValueChange as OptionNumberInputOnValueChange;
@@ -20795,6 +24680,12 @@ pub mod option {
     #[cfg(feature = "link_static")]
     pub use azul::AzOptionLogicalPosition as OptionLogicalPosition;
     #[cfg(feature = "link_static")]
+    pub use azul::AzOptionImeComposition as OptionImeComposition;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionClipboardContent as OptionClipboardContent;
+    #[cfg(feature = "link_static")]
+    pub use azul::AzOptionLogicalRect as OptionLogicalRect;
+    #[cfg(feature = "link_static")]
     pub use azul::AzOptionPhysicalPositionI32 as OptionPhysicalPositionI32;
     #[cfg(feature = "link_static")]
     pub use azul::AzOptionWindowIcon as OptionWindowIcon;
@@ -21025,12 +24916,33 @@ pub mod option {
     /// `OptionListViewOnRowClick` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionListViewOnRowClick as OptionListViewOnRowClick;
+    /// `OptionTreeViewNodeId` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTreeViewNodeId as OptionTreeViewNodeId;
+    /// `OptionTreeViewOnExpand` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTreeViewOnExpand as OptionTreeViewOnExpand;
+    /// `OptionTreeViewOnSelect` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTreeViewOnSelect as OptionTreeViewOnSelect;
+    /// `OptionTreeViewOnDoubleClick` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTreeViewOnDoubleClick as OptionTreeViewOnDoubleClick;
+    /// `OptionTreeViewOnContextMenu` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTreeViewOnContextMenu as OptionTreeViewOnContextMenu;
     /// `OptionListViewOnColumnClick` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionListViewOnColumnClick as OptionListViewOnColumnClick;
     /// `OptionListViewOnLazyLoadScroll` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionListViewOnLazyLoadScroll as OptionListViewOnLazyLoadScroll;
+    /// `OptionListViewOnColumnResize` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionListViewOnColumnResize as OptionListViewOnColumnResize;
+    /// `OptionListViewRowProvider` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionListViewRowProvider as OptionListViewRowProvider;
     /// `OptionMenu` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionMenu as OptionMenu;
@@ -21076,6 +24988,30 @@ pub mod option {
     /// `OptionTabOnClick` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTabOnClick as OptionTabOnClick;
+    /// `OptionTabOnClose` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTabOnClose as OptionTabOnClose;
+    /// `OptionTabOnReorder` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTabOnReorder as OptionTabOnReorder;
+    /// `OptionTabOnContextMenu` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTabOnContextMenu as OptionTabOnContextMenu;
+    /// `OptionRibbonOnTabClicked` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionRibbonOnTabClicked as OptionRibbonOnTabClicked;
+    /// `OptionRibbonOnItemClick` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionRibbonOnItemClick as OptionRibbonOnItemClick;
+    /// `OptionSplitterOnResize` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionSplitterOnResize as OptionSplitterOnResize;
+    /// `OptionSliderOnValueChange` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionSliderOnValueChange as OptionSliderOnValueChange;
+    /// `OptionRangeSliderOnValueChange` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionRangeSliderOnValueChange as OptionRangeSliderOnValueChange;
     /// `OptionFileInputOnPathChange` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionFileInputOnPathChange as OptionFileInputOnPathChange;
@@ -21094,6 +25030,9 @@ pub mod option {
     /// `OptionTextInputSelection` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTextInputSelection as OptionTextInputSelection;
+    /// `OptionTextAreaOnTextChange` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionTextAreaOnTextChange as OptionTextAreaOnTextChange;
     /// `OptionNumberInputOnFocusLost` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionNumberInputOnFocusLost as OptionNumberInputOnFocusLost;
@@ -21223,6 +25162,15 @@ pub mod option {
     /// `OptionLogicalPosition` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionLogicalPosition as OptionLogicalPosition;
+    /// `OptionImeComposition` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionImeComposition as OptionImeComposition;
+    /// `OptionClipboardContent` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionClipboardContent as OptionClipboardContent;
+    /// `OptionLogicalRect` struct
+    
+    #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionLogicalRect as OptionLogicalRect;
     /// `OptionPhysicalPositionI32` struct
     
     #[cfg(not(feature = "link_static"))] #[doc(inline)] pub use crate::dll::AzOptionPhysicalPositionI32 as OptionPhysicalPositionI32;
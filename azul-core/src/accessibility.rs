@@ -0,0 +1,299 @@
+//! Platform-independent accessibility tree, built from the `StyledDom`
+//! and the `LayoutResult` of a window. The platform backends (AT-SPI on
+//! Linux, ...) only translate this tree into the OS-specific protocol.
+//!
+//! Not every DOM node ends up in the accessibility tree: plain layout
+//! containers are flattened away, so that the tree only contains nodes with
+//! explicit `AccessibilityInfo`, focusable nodes and text.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use azul_css::AzString;
+use crate::{
+    callbacks::DomNodeId,
+    dom::{
+        AccessibilityAction, AccessibilityRole, AccessibilityState,
+        EventFilter, NodeData, NodeType,
+    },
+    id_tree::NodeId,
+    styled_dom::{DomId, NodeHierarchyItemId},
+    ui_solver::LayoutResult,
+    window::{LogicalPosition, LogicalRect, LogicalSize},
+};
+
+/// Single node of the `AccessibilityTree`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityNode {
+    /// DOM node that this accessibility node was created from
+    pub dom_node_id: DomNodeId,
+    /// Index of the parent node in `AccessibilityTree.nodes`,
+    /// `None` for the top-level nodes of the window
+    pub parent: Option<usize>,
+    /// Indices of the child nodes in `AccessibilityTree.nodes`
+    pub children: Vec<usize>,
+    pub role: AccessibilityRole,
+    /// Either the explicit name or (for buttons, list items, etc.) the
+    /// text content of the node
+    pub name: Option<AzString>,
+    pub value: Option<AzString>,
+    /// Description of the default action, if any
+    pub description: Option<AzString>,
+    pub states: Vec<AccessibilityState>,
+    pub actions: Vec<AccessibilityAction>,
+    /// Bounds of the node, relative to the top left corner of the window
+    pub bounds: LogicalRect,
+}
+
+impl AccessibilityNode {
+    #[inline]
+    pub fn has_state(&self, state: AccessibilityState) -> bool {
+        self.states.contains(&state)
+    }
+}
+
+/// Action that an assistive technology requested on a node, has to be executed
+/// by the platform backend via `WindowInternal::invoke_accessibility_action()`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AccessibilityActionRequest {
+    pub node: DomNodeId,
+    pub action: AccessibilityAction,
+}
+
+/// Flattened accessibility tree of a window
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AccessibilityTree {
+    /// All nodes, in depth-first order
+    pub nodes: Vec<AccessibilityNode>,
+    /// Indices of the top-level nodes (direct children of the window)
+    pub root_children: Vec<usize>,
+}
+
+impl AccessibilityTree {
+
+    /// Builds the accessibility tree from the layout results of a window
+    /// (`layout_results[0]` has to be the root DOM, see `WindowInternal`)
+    pub fn new(layout_results: &[LayoutResult], focused_node: Option<DomNodeId>) -> Self {
+        let mut tree = Self::default();
+        if let Some(root) = layout_results.first() {
+            if let Some(root_id) = root.styled_dom.root.into_crate_internal() {
+                let mut builder = TreeBuilder { layout_results, focused_node, tree: &mut tree };
+                builder.visit_children(root.dom_id, root_id, LogicalPosition::zero(), None);
+            }
+        }
+        tree
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the children of the given node or the top-level nodes if `node` is `None`
+    pub fn get_children(&self, node: Option<usize>) -> &[usize] {
+        match node {
+            Some(n) => self.nodes.get(n).map(|n| n.children.as_slice()).unwrap_or(&[]),
+            None => &self.root_children,
+        }
+    }
+
+    /// Returns the index of the node within the children of its parent
+    pub fn get_index_in_parent(&self, node: usize) -> Option<usize> {
+        let parent = self.nodes.get(node)?.parent;
+        self.get_children(parent).iter().position(|c| *c == node)
+    }
+
+    /// Looks up the accessibility node created from the given DOM node
+    pub fn find_node(&self, dom_node_id: DomNodeId) -> Option<usize> {
+        self.nodes.iter().position(|n| n.dom_node_id == dom_node_id)
+    }
+
+    /// Returns the node that is currently focused
+    pub fn get_focused_node(&self) -> Option<usize> {
+        self.nodes.iter().position(|n| n.has_state(AccessibilityState::Focused))
+    }
+
+    /// Returns the deepest descendant of `root` (or of the window, if `root` is `None`)
+    /// that contains the given point (in window coordinates)
+    pub fn hit_test(&self, root: Option<usize>, point: LogicalPosition) -> Option<usize> {
+        let mut current = root;
+        loop {
+            let hit = self.get_children(current).iter().rev().copied()
+                .find(|c| self.nodes[*c].bounds.hit_test(&point).is_some());
+            match hit {
+                Some(h) => current = Some(h),
+                None => return current,
+            }
+        }
+    }
+}
+
+struct TreeBuilder<'a, 'b> {
+    layout_results: &'a [LayoutResult],
+    focused_node: Option<DomNodeId>,
+    tree: &'b mut AccessibilityTree,
+}
+
+impl<'a, 'b> TreeBuilder<'a, 'b> {
+
+    fn visit_children(&mut self, dom_id: DomId, node_id: NodeId, offset: LogicalPosition, parent: Option<usize>) {
+        let layout_result = &self.layout_results[dom_id.inner];
+
+        // IFrames: continue with the root of the child DOM
+        if let Some(child_dom_id) = layout_result.iframe_mapping.get(&node_id) {
+            let child_offset = offset + layout_result.rects.as_ref()[node_id].position.get_static_offset();
+            if let Some(child_root) = self.layout_results.get(child_dom_id.inner).and_then(|lr| lr.styled_dom.root.into_crate_internal()) {
+                self.visit_node(*child_dom_id, child_root, child_offset, parent);
+            }
+            return;
+        }
+
+        let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+        for child_id in node_id.az_children(&node_hierarchy) {
+            self.visit_node(dom_id, child_id, offset, parent);
+        }
+    }
+
+    fn visit_node(&mut self, dom_id: DomId, node_id: NodeId, offset: LogicalPosition, parent: Option<usize>) {
+
+        let layout_result = &self.layout_results[dom_id.inner];
+        let node_data = &layout_result.styled_dom.node_data.as_container()[node_id];
+
+        // text inside of buttons, checkboxes, etc. is only used as the name of the parent
+        if let Some(p) = parent {
+            if role_has_presentational_children(self.tree.nodes[p].role) {
+                return;
+            }
+        }
+
+        let role = match get_role(node_data) {
+            Some(s) => s,
+            None => {
+                self.visit_children(dom_id, node_id, offset, parent);
+                return;
+            },
+        };
+
+        let dom_node_id = DomNodeId { dom: dom_id, node: NodeHierarchyItemId::from_crate_internal(Some(node_id)) };
+        let rect = &layout_result.rects.as_ref()[node_id];
+        let bounds = LogicalRect::new(offset + rect.position.get_static_offset(), rect.size);
+        let info = node_data.get_accessibility_info();
+
+        let mut name = info.and_then(|i| i.name.as_ref().cloned());
+        if name.is_none() {
+            if let NodeType::Text(t) = node_data.get_node_type() {
+                name = Some(t.clone());
+            } else if role_has_name_from_contents(role) {
+                let mut text = String::new();
+                collect_text(layout_result, node_id, &mut text);
+                let text = text.trim();
+                if !text.is_empty() {
+                    name = Some(text.to_string().into());
+                }
+            }
+        }
+
+        let mut states = info.map(|i| i.states.as_ref().to_vec()).unwrap_or_default();
+        if node_data.is_focusable() && !states.contains(&AccessibilityState::Focusable) {
+            states.push(AccessibilityState::Focusable);
+        }
+        if self.focused_node == Some(dom_node_id) && !states.contains(&AccessibilityState::Focused) {
+            states.push(AccessibilityState::Focused);
+        }
+        if rect.size == LogicalSize::zero() && !states.contains(&AccessibilityState::Offscreen) {
+            states.push(AccessibilityState::Offscreen);
+        }
+
+        let mut actions = node_data.get_accessibility_actions().to_vec();
+        if actions.is_empty() && has_callback(node_data, AccessibilityAction::Default.get_event_filters()) {
+            actions.push(AccessibilityAction::Default);
+        }
+        if states.contains(&AccessibilityState::Focusable) && !actions.contains(&AccessibilityAction::Focus) {
+            actions.push(AccessibilityAction::Focus);
+        }
+
+        let index = self.tree.nodes.len();
+        self.tree.nodes.push(AccessibilityNode {
+            dom_node_id,
+            parent,
+            children: Vec::new(),
+            role,
+            name,
+            value: info.and_then(|i| i.value.as_ref().cloned()),
+            description: info.and_then(|i| i.default_action.as_ref().cloned()),
+            states,
+            actions,
+            bounds,
+        });
+
+        match parent {
+            Some(p) => self.tree.nodes[p].children.push(index),
+            None => self.tree.root_children.push(index),
+        }
+
+        self.visit_children(dom_id, node_id, offset, Some(index));
+    }
+}
+
+/// Returns the role of the node, `None` if the node should be flattened away
+fn get_role(node_data: &NodeData) -> Option<AccessibilityRole> {
+    if let Some(info) = node_data.get_accessibility_info() {
+        return match info.role {
+            AccessibilityRole::Nothing => None,
+            r => Some(r),
+        };
+    }
+    match node_data.get_node_type() {
+        NodeType::Text(t) if !t.as_str().trim().is_empty() => Some(AccessibilityRole::StaticText),
+        NodeType::Image(_) => Some(AccessibilityRole::Graphic),
+        _ if node_data.is_focusable() => Some(AccessibilityRole::Grouping),
+        _ => None,
+    }
+}
+
+/// Whether the name of the node can be computed from its text content
+fn role_has_name_from_contents(role: AccessibilityRole) -> bool {
+    use crate::dom::AccessibilityRole::*;
+    match role {
+        PushButton | CheckButton | RadioButton | Pagetab | Link |
+        MenuItem | ListItem | OutlineItem | Cell | ColumnHeader |
+        RowHeader | Tooltip | ButtonDropdown | ButtonMenu | SplitButton => true,
+        _ => false,
+    }
+}
+
+/// Whether the children of the node are only "decoration" and should not be
+/// exposed, see https://www.w3.org/TR/wai-aria-1.1/#childrenArePresentational
+fn role_has_presentational_children(role: AccessibilityRole) -> bool {
+    use crate::dom::AccessibilityRole::*;
+    match role {
+        PushButton | CheckButton | RadioButton | Pagetab | Link |
+        MenuItem | Text | StaticText | ProgressBar | Slider |
+        Separator | Graphic | ScrollBar => true,
+        _ => false,
+    }
+}
+
+fn has_callback(node_data: &NodeData, filters: &[EventFilter]) -> bool {
+    node_data.get_callbacks().iter().any(|cb| filters.contains(&cb.event))
+}
+
+fn collect_text(layout_result: &LayoutResult, node_id: NodeId, text: &mut String) {
+    let node_data = layout_result.styled_dom.node_data.as_container();
+    let node_hierarchy = layout_result.styled_dom.node_hierarchy.as_container();
+    for child_id in node_id.az_children(&node_hierarchy) {
+        match node_data[child_id].get_node_type() {
+            NodeType::Text(t) => {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(t.as_str().trim());
+            },
+            _ => collect_text(layout_result, child_id, text),
+        }
+    }
+}
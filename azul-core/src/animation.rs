@@ -0,0 +1,615 @@
+//! CSS transitions and `@keyframes` animations
+//!
+//! The `AnimationScheduler` lives in the `WindowInternal`. Whenever a restyle
+//! changes an animatable property of a node that has a matching
+//! `transition-property`, a transition is started. Nodes with an
+//! `animation-name` that refers to a `@keyframes` block get a running
+//! keyframe animation. Every frame, the scheduler interpolates the current
+//! values and writes them into the `animated_properties` of the
+//! `CssPropertyCache`, which overrides all other values of the node.
+//!
+//! The scheduler itself does not own a timer: as long as
+//! `has_running_animations()` returns true, the windowing backend has to call
+//! `StyleAndLayoutChanges::new` once per frame, which calls `update()`.
+
+use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
+use alloc::vec::Vec;
+use azul_css::{
+    AzString, CssKeyframes, CssProperty, CssPropertyType,
+    AnimationInterpolationFunction, InterpolateResolver,
+    StyleTimingFunction, StyleAnimationName,
+    StyleAnimationIterationCount, StyleAnimationDirection,
+    StyleAnimationFillMode, StyleAnimationPlayState, StyleTime,
+};
+use crate::{
+    id_tree::NodeId,
+    styled_dom::{ChangedCssProperty, DomId, StyledDom},
+    task::{Duration, GetSystemTimeCallback, Instant},
+    ui_solver::LayoutResult,
+};
+
+/// Changed CSS properties, per DOM and per node
+pub type AnimationChanges = BTreeMap<DomId, BTreeMap<NodeId, Vec<ChangedCssProperty>>>;
+
+/// Transition of a single property of a single node
+#[derive(Debug, Clone)]
+pub struct RunningTransition {
+    /// Time at which the property changed
+    pub start: Instant,
+    pub delay_ms: f32,
+    pub duration_ms: f32,
+    pub timing_function: StyleTimingFunction,
+    /// Value of the property when the transition started
+    pub from: CssProperty,
+    /// Value that the property will have once the transition has finished
+    pub to: CssProperty,
+}
+
+/// `@keyframes` animation that is applied to a single node
+#[derive(Debug, Clone)]
+pub struct RunningAnimation {
+    pub name: AzString,
+    pub keyframes: CssKeyframes,
+    pub delay_ms: f32,
+    pub duration_ms: f32,
+    pub timing_function: StyleTimingFunction,
+    pub iteration_count: StyleAnimationIterationCount,
+    pub direction: StyleAnimationDirection,
+    pub fill_mode: StyleAnimationFillMode,
+    pub play_state: StyleAnimationPlayState,
+    /// Time elapsed since the animation was started, excluding the time it was paused
+    pub elapsed_ms: f32,
+    /// Time at which `elapsed_ms` was last updated
+    pub last_update: Instant,
+}
+
+impl RunningAnimation {
+
+    /// Returns the total active duration of all iterations, `None` for `infinite` animations
+    fn get_active_duration_ms(&self) -> Option<f32> {
+        match self.iteration_count {
+            StyleAnimationIterationCount::Infinite => None,
+            StyleAnimationIterationCount::Count(c) => Some(self.duration_ms * c.get().max(0.0)),
+        }
+    }
+
+    /// Returns whether the animation has reached its end (i.e. doesn't need any more frames)
+    pub fn is_finished(&self) -> bool {
+        match self.get_active_duration_ms() {
+            None => false,
+            Some(d) => self.elapsed_ms >= self.delay_ms + d,
+        }
+    }
+
+    /// Returns the progress inside of the `@keyframes` block (0.0 - 1.0)
+    /// for the current elapsed time, `None` if the animation currently has no effect
+    fn get_keyframe_progress(&self) -> Option<f32> {
+
+        let iteration_count = match self.iteration_count {
+            StyleAnimationIterationCount::Infinite => f32::INFINITY,
+            StyleAnimationIterationCount::Count(c) => c.get().max(0.0),
+        };
+
+        let active_time = self.elapsed_ms - self.delay_ms;
+
+        // see https://drafts.csswg.org/css-animations-1/#animation-fill-mode
+        let (iteration, iteration_progress) = if active_time < 0.0 {
+            if !self.fill_mode.fills_backwards() {
+                return None;
+            }
+            (0.0, 0.0)
+        } else if self.is_finished() {
+            if !self.fill_mode.fills_forwards() {
+                return None;
+            }
+            let fract = iteration_count - libm::floorf(iteration_count);
+            if fract == 0.0 && iteration_count > 0.0 {
+                (iteration_count - 1.0, 1.0)
+            } else {
+                (libm::floorf(iteration_count), fract)
+            }
+        } else if self.duration_ms <= 0.0 {
+            (0.0, 1.0)
+        } else {
+            let iteration = libm::floorf(active_time / self.duration_ms);
+            (iteration, (active_time / self.duration_ms) - iteration)
+        };
+
+        let is_odd_iteration = (iteration as u64) % 2 == 1;
+        let reversed = match self.direction {
+            StyleAnimationDirection::Normal => false,
+            StyleAnimationDirection::Reverse => true,
+            StyleAnimationDirection::Alternate => is_odd_iteration,
+            StyleAnimationDirection::AlternateReverse => !is_odd_iteration,
+        };
+
+        Some(if reversed { 1.0 - iteration_progress } else { iteration_progress })
+    }
+
+    /// Returns the value of `prop_type` at the keyframe progress `progress`,
+    /// `base` is the value that the node would have without the animation
+    fn get_value(
+        &self,
+        prop_type: CssPropertyType,
+        progress: f32,
+        base: &CssProperty,
+        resolver: &InterpolateResolver,
+    ) -> CssProperty {
+
+        // (offset, value, timing function of the segment starting at this keyframe)
+        let mut stops = self.keyframes.frames
+        .iter()
+        .filter_map(|frame| {
+            let value = frame.properties.iter().find(|p| p.get_type() == prop_type)?;
+            let timing_function = frame.properties.iter().find_map(|p| {
+                p.as_animation_timing_function()?.get_property()?.as_ref().first().copied()
+            }).unwrap_or(self.timing_function);
+            Some((frame.offset.normalized(), value.clone(), timing_function))
+        })
+        .collect::<Vec<_>>();
+
+        // missing 0% and 100% keyframes default to the non-animated value
+        if stops.first().map(|s| s.0 > 0.0).unwrap_or(true) {
+            stops.insert(0, (0.0, base.clone(), self.timing_function));
+        }
+        if stops.last().map(|s| s.0 < 1.0).unwrap_or(true) {
+            stops.push((1.0, base.clone(), self.timing_function));
+        }
+
+        let end = stops.iter().position(|s| s.0 > progress).unwrap_or(stops.len() - 1).max(1);
+        let (start_offset, start_value, timing_function) = &stops[end - 1];
+        let (end_offset, end_value, _) = &stops[end];
+
+        let segment_length = end_offset - start_offset;
+        let t = if segment_length <= 0.0 { 1.0 } else { (progress - start_offset) / segment_length };
+
+        start_value.interpolate(end_value, timing_function.evaluate(t), resolver)
+    }
+}
+
+/// Schedules and interpolates the CSS transitions and animations of one window
+#[derive(Debug)]
+pub struct AnimationScheduler {
+    /// Function to get the current time, usually `ExternalSystemCallbacks::get_system_time_fn`
+    pub get_system_time_fn: GetSystemTimeCallback,
+    /// Running transitions, one per (DOM, node, property)
+    pub transitions: BTreeMap<(DomId, NodeId, CssPropertyType), RunningTransition>,
+    /// Keyframe animations, in the order of the nodes `animation-name` list
+    pub animations: BTreeMap<(DomId, NodeId), Vec<RunningAnimation>>,
+    /// Properties that were written into the `animated_properties` of the nodes in the last frame
+    applied_properties: BTreeMap<(DomId, NodeId), BTreeSet<CssPropertyType>>,
+    /// Set after a new DOM was created: the initial style of a DOM does not start transitions
+    skip_transitions: bool,
+}
+
+impl AnimationScheduler {
+
+    pub fn new(get_system_time_fn: GetSystemTimeCallback) -> Self {
+        Self {
+            get_system_time_fn,
+            transitions: BTreeMap::new(),
+            animations: BTreeMap::new(),
+            applied_properties: BTreeMap::new(),
+            skip_transitions: true,
+        }
+    }
+
+    /// Returns whether any transition or animation needs to be updated in the next frame
+    pub fn has_running_animations(&self) -> bool {
+        !self.transitions.is_empty() ||
+        self.animations.values().flat_map(|a| a.iter()).any(|a| {
+            a.play_state == StyleAnimationPlayState::Running && !a.is_finished()
+        })
+    }
+
+    /// Called when the layout callback created new `LayoutResult`s: drops the
+    /// transitions and animations of nodes that do not exist anymore and starts
+    /// the animations of new nodes. Animations with the same name on the same
+    /// node continue running.
+    ///
+    /// The next call to `update()` does not start any transitions, since the
+    /// changes it sees are just the initial style of the new DOM.
+    pub fn reset(&mut self, layout_results: &[LayoutResult]) {
+
+        let node_exists = |dom_id: &DomId, node_id: &NodeId| {
+            layout_results.get(dom_id.inner)
+            .map(|lr| node_id.index() < lr.styled_dom.node_data.len())
+            .unwrap_or(false)
+        };
+
+        self.transitions.retain(|(dom_id, node_id, _), _| node_exists(dom_id, node_id));
+        self.animations.retain(|(dom_id, node_id), _| node_exists(dom_id, node_id));
+        self.applied_properties.clear();
+        self.skip_transitions = true;
+
+        let now = (self.get_system_time_fn.cb)();
+
+        for layout_result in layout_results.iter() {
+            if layout_result.styled_dom.get_css_property_cache().keyframes.is_empty() {
+                continue;
+            }
+            for node_id in 0..layout_result.styled_dom.node_data.len() {
+                self.update_node_animations(layout_result.dom_id, NodeId::new(node_id), &layout_result.styled_dom, &now);
+            }
+        }
+    }
+
+    /// Starts transitions for the changed properties, advances all running transitions
+    /// and animations to the current time and writes the interpolated values into the
+    /// `StyledDom`s. Returns the resulting changes, so that they can be relayouted / redrawn.
+    pub fn update(
+        &mut self,
+        layout_results: &mut [LayoutResult],
+        style_changes: Option<&AnimationChanges>,
+        layout_changes: Option<&AnimationChanges>,
+    ) -> AnimationChanges {
+
+        let now = (self.get_system_time_fn.cb)();
+
+        // start or cancel transitions, restart animations if an animation-* property changed
+        let changes = style_changes.into_iter().chain(layout_changes.into_iter())
+        .flat_map(|c| c.iter())
+        .flat_map(|(dom_id, nodes)| nodes.iter().map(move |(node_id, props)| (*dom_id, *node_id, props)));
+
+        let mut nodes_with_changed_animations = BTreeSet::new();
+
+        for (dom_id, node_id, changed_props) in changes {
+
+            let layout_result = match layout_results.get(dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            for changed_prop in changed_props.iter() {
+
+                let prop_type = changed_prop.current_prop.get_type();
+
+                if is_animation_property(prop_type) {
+                    nodes_with_changed_animations.insert((dom_id, node_id));
+                    continue;
+                }
+
+                if self.skip_transitions || !prop_type.is_animatable() {
+                    continue;
+                }
+
+                self.start_transition(
+                    dom_id,
+                    node_id,
+                    &layout_result.styled_dom,
+                    changed_prop,
+                    &now
+                );
+            }
+        }
+
+        self.skip_transitions = false;
+
+        for (dom_id, node_id) in nodes_with_changed_animations {
+            if let Some(layout_result) = layout_results.get(dom_id.inner) {
+                self.update_node_animations(dom_id, node_id, &layout_result.styled_dom, &now);
+            }
+        }
+
+        // calculate the current values: animations first, transitions override animations
+        let mut new_values = BTreeMap::<(DomId, NodeId), BTreeMap<CssPropertyType, CssProperty>>::new();
+
+        for ((dom_id, node_id), animations) in self.animations.iter_mut() {
+
+            let layout_result = match layout_results.get(dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let resolver = get_interpolate_resolver(layout_result, *node_id);
+
+            for animation in animations.iter_mut() {
+
+                if animation.play_state == StyleAnimationPlayState::Running {
+                    animation.elapsed_ms += duration_to_ms(&now.duration_since(&animation.last_update));
+                }
+                animation.last_update = now.clone();
+
+                let progress = match animation.get_keyframe_progress() {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                let animated_types = animation.keyframes.frames
+                .iter()
+                .flat_map(|f| f.properties.iter())
+                .map(|p| p.get_type())
+                .filter(|t| t.is_animatable())
+                .collect::<BTreeSet<_>>();
+
+                for prop_type in animated_types {
+                    let base = get_base_value(&layout_result.styled_dom, *node_id, prop_type);
+                    let value = animation.get_value(prop_type, progress, &base, &resolver);
+                    new_values.entry((*dom_id, *node_id)).or_insert_with(|| BTreeMap::new()).insert(prop_type, value);
+                }
+            }
+        }
+
+        let mut finished_transitions = Vec::new();
+
+        for ((dom_id, node_id, prop_type), transition) in self.transitions.iter() {
+
+            let layout_result = match layout_results.get(dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let elapsed_ms = duration_to_ms(&now.duration_since(&transition.start)) - transition.delay_ms;
+
+            let value = if elapsed_ms < 0.0 {
+                transition.from.clone()
+            } else if transition.duration_ms <= 0.0 || elapsed_ms >= transition.duration_ms {
+                finished_transitions.push((*dom_id, *node_id, *prop_type));
+                continue;
+            } else {
+                let t = transition.timing_function.evaluate(elapsed_ms / transition.duration_ms);
+                transition.from.interpolate(&transition.to, t, &get_interpolate_resolver(layout_result, *node_id))
+            };
+
+            new_values.entry((*dom_id, *node_id)).or_insert_with(|| BTreeMap::new()).insert(*prop_type, value);
+        }
+
+        for key in finished_transitions {
+            self.transitions.remove(&key);
+        }
+
+        // properties that were animated in the last frame, but aren't anymore, are reset
+        for (key, previous) in self.applied_properties.iter() {
+            let current = new_values.entry(*key).or_insert_with(|| BTreeMap::new());
+            for prop_type in previous.iter() {
+                current.entry(*prop_type).or_insert_with(|| CssProperty::initial(*prop_type));
+            }
+        }
+
+        let mut result = AnimationChanges::new();
+        self.applied_properties.clear();
+
+        for ((dom_id, node_id), values) in new_values.into_iter() {
+
+            let layout_result = match layout_results.get_mut(dom_id.inner) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let applied = values.iter()
+                .filter(|(_, v)| !v.is_initial())
+                .map(|(k, _)| *k)
+                .collect::<BTreeSet<_>>();
+
+            if !applied.is_empty() {
+                self.applied_properties.insert((dom_id, node_id), applied);
+            }
+
+            let values = values.into_iter().map(|(_, v)| v).collect::<Vec<_>>();
+            let changes = layout_result.styled_dom.restyle_animated_properties(&node_id, &values);
+
+            if !changes.is_empty() {
+                result
+                .entry(dom_id)
+                .or_insert_with(|| BTreeMap::new())
+                .extend(changes.into_iter());
+            }
+        }
+
+        result
+    }
+
+    /// Starts (or cancels) the transition of a property that changed from
+    /// `changed_prop.previous_prop` to `changed_prop.current_prop`
+    fn start_transition(
+        &mut self,
+        dom_id: DomId,
+        node_id: NodeId,
+        styled_dom: &StyledDom,
+        changed_prop: &ChangedCssProperty,
+        now: &Instant,
+    ) {
+
+        let prop_type = changed_prop.current_prop.get_type();
+        let key = (dom_id, node_id, prop_type);
+
+        let css_property_cache = styled_dom.get_css_property_cache();
+        let node_data = &styled_dom.node_data.as_container()[node_id];
+        let node_state = &changed_prop.current_state;
+
+        let transition_properties = css_property_cache
+            .get_transition_property(node_data, &node_id, node_state)
+            .and_then(|p| p.get_property())
+            .map(|p| p.as_ref().to_vec())
+            .unwrap_or_default();
+
+        // if a property is listed more than once, the last entry wins
+        let index = match transition_properties.iter().rposition(|p| p.matches(&prop_type)) {
+            Some(s) => s,
+            None => {
+                self.transitions.remove(&key);
+                return;
+            },
+        };
+
+        let duration = css_property_cache
+            .get_transition_duration(node_data, &node_id, node_state)
+            .and_then(|p| p.get_property())
+            .and_then(|p| get_list_item(p.as_ref(), index).copied())
+            .unwrap_or(StyleTime::zero());
+
+        let delay = css_property_cache
+            .get_transition_delay(node_data, &node_id, node_state)
+            .and_then(|p| p.get_property())
+            .and_then(|p| get_list_item(p.as_ref(), index).copied())
+            .unwrap_or(StyleTime::zero());
+
+        let timing_function = css_property_cache
+            .get_transition_timing_function(node_data, &node_id, node_state)
+            .and_then(|p| p.get_property())
+            .and_then(|p| get_list_item(p.as_ref(), index).copied())
+            .unwrap_or_default();
+
+        if duration.ms <= 0 && delay.ms <= 0 {
+            self.transitions.remove(&key);
+            return;
+        }
+
+        // if the property is currently being animated, start from the current
+        // (interpolated) value, so that reversing a transition doesn't jump
+        let from = css_property_cache.animated_properties
+            .get(&node_id)
+            .and_then(|p| p.get(&prop_type))
+            .cloned()
+            .unwrap_or_else(|| changed_prop.previous_prop.clone());
+
+        // the changed property may be a removed user override (`CssProperty::Initial`),
+        // so the target value has to be looked up again
+        let to = get_base_value(styled_dom, node_id, prop_type);
+
+        if from == to {
+            self.transitions.remove(&key);
+            return;
+        }
+
+        self.transitions.insert(key, RunningTransition {
+            start: now.clone(),
+            delay_ms: delay.ms as f32,
+            duration_ms: duration.ms as f32,
+            timing_function,
+            from,
+            to,
+        });
+    }
+
+    /// Synchronizes the running animations of a node with its `animation-*` properties
+    fn update_node_animations(&mut self, dom_id: DomId, node_id: NodeId, styled_dom: &StyledDom, now: &Instant) {
+
+        let css_property_cache = styled_dom.get_css_property_cache();
+        let node_data = &styled_dom.node_data.as_container()[node_id];
+        let node_state = &styled_dom.styled_nodes.as_container()[node_id].state;
+
+        let names = css_property_cache
+            .get_animation_name(node_data, &node_id, node_state)
+            .and_then(|p| p.get_property())
+            .map(|p| p.as_ref().to_vec())
+            .unwrap_or_default();
+
+        let mut previous = self.animations.remove(&(dom_id, node_id)).unwrap_or_default();
+
+        macro_rules! get_item {($getter:ident, $index:expr) => {
+            css_property_cache
+            .$getter(node_data, &node_id, node_state)
+            .and_then(|p| p.get_property())
+            .and_then(|p| get_list_item(p.as_ref(), $index).cloned())
+            .unwrap_or_default()
+        };}
+
+        let mut animations = Vec::new();
+
+        for (index, name) in names.iter().enumerate() {
+
+            let name = match name {
+                StyleAnimationName::None => continue,
+                StyleAnimationName::Name(n) => n,
+            };
+
+            let keyframes = match css_property_cache.keyframes.get(name) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let duration: StyleTime = get_item!(get_animation_duration, index);
+            let delay: StyleTime = get_item!(get_animation_delay, index);
+
+            let mut animation = RunningAnimation {
+                name: name.clone(),
+                keyframes: keyframes.clone(),
+                delay_ms: delay.ms as f32,
+                duration_ms: duration.ms.max(0) as f32,
+                timing_function: get_item!(get_animation_timing_function, index),
+                iteration_count: get_item!(get_animation_iteration_count, index),
+                direction: get_item!(get_animation_direction, index),
+                fill_mode: get_item!(get_animation_fill_mode, index),
+                play_state: get_item!(get_animation_play_state, index),
+                elapsed_ms: 0.0,
+                last_update: now.clone(),
+            };
+
+            // an animation that is still listed keeps its progress
+            if let Some(pos) = previous.iter().position(|a| a.name == animation.name) {
+                let prev = previous.remove(pos);
+                animation.elapsed_ms = prev.elapsed_ms;
+                animation.last_update = prev.last_update;
+            }
+
+            animations.push(animation);
+        }
+
+        if !animations.is_empty() {
+            self.animations.insert((dom_id, node_id), animations);
+        }
+    }
+}
+
+/// Returns whether the property configures transitions or animations
+/// (as opposed to being a property that can be transitioned / animated)
+fn is_animation_property(prop_type: CssPropertyType) -> bool {
+    use azul_css::CssPropertyType::*;
+    match prop_type {
+        AnimationName | AnimationDuration | AnimationTimingFunction |
+        AnimationDelay | AnimationIterationCount | AnimationDirection |
+        AnimationFillMode | AnimationPlayState => true,
+        _ => false,
+    }
+}
+
+/// Lists of `transition-*` / `animation-*` values are repeated if they are
+/// shorter than the list of `transition-property` / `animation-name`
+fn get_list_item<T>(list: &[T], index: usize) -> Option<&T> {
+    if list.is_empty() {
+        None
+    } else {
+        list.get(index % list.len())
+    }
+}
+
+/// Value of the property without any transitions or animations applied
+fn get_base_value(styled_dom: &StyledDom, node_id: NodeId, prop_type: CssPropertyType) -> CssProperty {
+    let node_data = &styled_dom.node_data.as_container()[node_id];
+    let node_state = &styled_dom.styled_nodes.as_container()[node_id].state;
+    styled_dom.get_css_property_cache()
+    .get_non_animated_property(node_data, &node_id, node_state, &prop_type)
+    .cloned()
+    .unwrap_or(CssProperty::auto(prop_type))
+}
+
+fn get_interpolate_resolver(layout_result: &LayoutResult, node_id: NodeId) -> InterpolateResolver {
+
+    let rects = layout_result.rects.as_ref();
+    let current_rect = rects.get(node_id).map(|r| r.size).unwrap_or_default();
+    let parent_rect = layout_result.styled_dom.node_hierarchy.as_container()
+        .get(node_id)
+        .and_then(|n| n.parent_id())
+        .and_then(|p| rects.get(p))
+        .map(|r| r.size)
+        .unwrap_or(current_rect);
+
+    // the easing has already been applied by the scheduler
+    InterpolateResolver {
+        interpolate_func: AnimationInterpolationFunction::Linear,
+        parent_rect_width: parent_rect.width,
+        parent_rect_height: parent_rect.height,
+        current_rect_width: current_rect.width,
+        current_rect_height: current_rect.height,
+    }
+}
+
+fn duration_to_ms(duration: &Duration) -> f32 {
+    match duration {
+        Duration::System(s) => s.secs as f32 * 1000.0 + s.nanos as f32 / 1_000_000.0,
+        // ticks have no defined length, count every tick as one millisecond
+        Duration::Tick(t) => t.tick_diff as f32,
+    }
+}
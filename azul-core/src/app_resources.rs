@@ -572,6 +572,9 @@ pub struct RendererResources {
     /// This is because when the frame is being built, we do not know
     /// whether the font will actually be successfully loaded
     last_frame_registered_fonts: FastHashMap<FontKey, FastHashMap<Au, FontInstanceKey>>,
+    /// Reverse map of the font instances in `currently_registered_fonts`,
+    /// i.e. FontInstanceKey -> (FontKey, font size)
+    font_instance_map: FastHashMap<FontInstanceKey, (FontKey, Au)>,
     /// Map from the calculated families vec (["Arial", "Helvectia"])
     /// to the final loaded font that could be loaded
     /// (in this case "Arial" on Windows and "Helvetica" on Mac,
@@ -579,6 +582,21 @@ pub struct RendererResources {
     font_families_map: FastHashMap<StyleFontFamiliesHash, StyleFontFamilyHash>,
     /// Same as AzString -> ImageId, but for fonts, i.e. "Roboto" -> FontId(9)
     font_id_map: FastHashMap<StyleFontFamilyHash, FontKey>,
+    /// Fonts that are searched for the characters which are not contained in
+    /// the font of a font families vec (i.e. emoji or CJK characters)
+    font_fallback_chains: FastHashMap<StyleFontFamiliesHash, FontFallbackChain>,
+}
+
+/// Fallback fonts of a font families vec, for characters that
+/// are not contained in the first font that could be loaded
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FontFallbackChain {
+    /// Fallback fonts in the order they are searched: first the remaining
+    /// fonts of the font families vec, then the system fonts
+    pub fonts: Vec<StyleFontFamilyHash>,
+    /// Characters that are not contained in any font (so that
+    /// the system fonts don't get searched again on every frame)
+    pub missing_chars: FastBTreeSet<u32>,
 }
 
 impl fmt::Debug for RendererResources {
@@ -588,11 +606,13 @@ impl fmt::Debug for RendererResources {
                 currently_registered_fonts: {:#?},
                 font_families_map: {:#?},
                 font_id_map: {:#?},
+                font_fallback_chains: {:#?},
             }}",
            self.currently_registered_images.keys().collect::<Vec<_>>(),
            self.currently_registered_fonts.keys().collect::<Vec<_>>(),
            self.font_families_map.keys().collect::<Vec<_>>(),
            self.font_id_map.keys().collect::<Vec<_>>(),
+           self.font_fallback_chains.keys().collect::<Vec<_>>(),
         )
     }
 }
@@ -603,8 +623,10 @@ impl Default for RendererResources {
             currently_registered_images: FastHashMap::default(),
             currently_registered_fonts: FastHashMap::default(),
             last_frame_registered_fonts: FastHashMap::default(),
+            font_instance_map: FastHashMap::default(),
             font_families_map: FastHashMap::default(),
             font_id_map: FastHashMap::default(),
+            font_fallback_chains: FastHashMap::default(),
         }
     }
 }
@@ -627,6 +649,46 @@ impl RendererResources {
         self.currently_registered_fonts.get(font_key)
     }
 
+    pub fn get_font_fallback_chain(&self, style_font_families_hash: &StyleFontFamiliesHash) -> Option<&FontFallbackChain> {
+        self.font_fallback_chains.get(style_font_families_hash)
+    }
+
+    /// Returns the primary font of a font families vec, followed by its fallback fonts -
+    /// the position of a font in the returned list is the `font_index` of its glyphs
+    pub fn get_font_fallback_keys(&self, style_font_families_hash: &StyleFontFamiliesHash) -> Vec<FontKey> {
+
+        let primary_font_key = match self.get_font_family(style_font_families_hash).and_then(|f| self.get_font_key(f)) {
+            Some(s) => *s,
+            None => return Vec::new(),
+        };
+
+        let fallback_font_keys = self.get_font_fallback_chain(style_font_families_hash)
+            .into_iter()
+            .flat_map(|chain| chain.fonts.iter())
+            .filter_map(|font_family_hash| self.get_font_key(font_family_hash).copied());
+
+        core::iter::once(primary_font_key).chain(fallback_font_keys).collect()
+    }
+
+    /// Same as `get_font_fallback_keys`, but returns the font instances for the given font size
+    pub fn get_font_fallback_instance_keys(&self, style_font_families_hash: &StyleFontFamiliesHash, font_size: Au) -> Vec<Option<FontInstanceKey>> {
+        self.get_font_fallback_keys(style_font_families_hash)
+        .iter()
+        .map(|font_key| {
+            self.get_registered_font(font_key)
+            .and_then(|(_, font_instances)| font_instances.get(&font_size).copied())
+        })
+        .collect()
+    }
+
+    /// Returns the font and the font size of a font instance key, i.e. resolves
+    /// a `FontInstanceKey` from the display list back to the font it was created from
+    pub fn get_font_instance(&self, font_instance_key: &FontInstanceKey) -> Option<(&FontRef, Au)> {
+        let (font_key, font_size) = self.font_instance_map.get(font_instance_key)?;
+        let (font_ref, _) = self.currently_registered_fonts.get(font_key)?;
+        Some((font_ref, *font_size))
+    }
+
     pub fn update_image(&mut self, image_ref_hash: &ImageRefHash, descriptor: ImageDescriptor) {
         if let Some(s) = self.currently_registered_images.get_mut(image_ref_hash) {
             s.descriptor = descriptor; // key stays the same, only descriptor changes
@@ -738,6 +800,14 @@ impl RendererResources {
         for f in font_families_to_delete {
             self.font_families_map.remove(&f); // font family does not exist anymore
         }
+
+        // Delete the fallback fonts that do not have a font key anymore
+        let font_id_map = &self.font_id_map;
+        let font_families_map = &self.font_families_map;
+        self.font_fallback_chains.retain(|font_families, chain| {
+            chain.fonts.retain(|font_family| font_id_map.contains_key(font_family));
+            font_families_map.contains_key(font_families)
+        });
     }
 
     // Re-invokes the RenderImageCallback on the given node (if there is any),
@@ -1808,6 +1878,10 @@ pub struct Word {
     pub start: usize,
     pub end: usize,
     pub word_type: WordType,
+    /// Whether the line may be broken between the previous item and this item.
+    /// Lines can always be broken after whitespace, so this is only relevant
+    /// for words that directly follow another word.
+    pub break_before: LineBreakOpportunity,
 }
 
 impl_vec!(Word, WordVec, WordVecDestructor);
@@ -1833,6 +1907,26 @@ pub enum WordType {
     Space,
 }
 
+/// Soft hyphen (`&shy;`): invisible, unless the line is broken at the soft hyphen
+pub const SOFT_HYPHEN: char = '\u{ad}';
+
+/// Type of the line break opportunity between two items of a text
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum LineBreakOpportunity {
+    /// Line can't be broken here (i.e. the words were only split
+    /// because of a change in the text direction)
+    None,
+    /// Regular break opportunity (UAX #14), i.e. after a hyphen or between ideographs
+    Allowed,
+    /// Line can be broken here, but a hyphen has to be inserted at the end of the line
+    /// (soft hyphen or dictionary-based hyphenation)
+    Hyphenated,
+    /// Line can only be broken here if the text would overflow
+    /// otherwise (`overflow-wrap: anywhere` / `break-word`)
+    Emergency,
+}
+
 /// A paragraph of words that are shaped and scaled (* but not yet layouted / positioned*!)
 /// according to their final size in pixels.
 #[derive(Debug, Clone)]
@@ -1851,9 +1945,17 @@ pub struct ShapedWords {
     pub font_metrics_ascender: i16,
     pub font_metrics_descender: i16,
     pub font_metrics_line_gap: i16,
+    /// Shaped hyphen character, inserted at the end of a line if a word is hyphenated
+    pub hyphen: Option<ShapedWord>,
 }
 
 impl ShapedWords {
+    /// Returns the width of the hyphen that is inserted at the end of hyphenated lines
+    pub fn get_hyphen_width_px(&self, target_font_size: f32) -> f32 {
+        self.hyphen.as_ref()
+        .map(|h| h.get_word_width(self.font_metrics_units_per_em, target_font_size))
+        .unwrap_or(0.0)
+    }
     pub fn get_longest_word_width_px(&self, target_font_size: f32) -> f32 {
         self.longest_word_width as f32 / self.font_metrics_units_per_em as f32 * target_font_size
     }
@@ -2002,6 +2104,9 @@ pub struct GlyphInfo {
     pub size: Advance,
     pub kerning: i16,
     pub placement: Placement,
+    /// Font that this glyph was shaped with: 0 is the primary font of the text,
+    /// fallback fonts start at 1 (see `RendererResources::get_font_fallback_keys`)
+    pub font_index: usize,
 }
 
 #[cfg(feature = "multithreading")]
@@ -2052,7 +2157,10 @@ pub fn get_inline_text(words: &Words, shaped_words: &ShapedWords, word_positions
 
                     // most words are less than 16 chars, avg length of an english word is 4.7 chars
                     let mut all_glyphs_in_this_word = Vec::<InlineGlyph>::with_capacity(16);
+                    // horizontal advance of each glyph (marks use the advance of their base glyph)
+                    let mut glyph_advances = Vec::<f32>::with_capacity(16);
                     let mut x_pos_in_word_px = 0.0;
+                    let is_rtl = word_position.bidi_level % 2 == 1;
 
                     // all words only store the unscaled horizontal advance + horizontal kerning
                     for glyph_info in shaped_word.glyph_infos.iter() {
@@ -2062,9 +2170,12 @@ pub fn get_inline_text(words: &Words, shaped_words: &ShapedWords, word_positions
 
                         // if the character is a mark, the mark displacement has to be added ON TOP OF the existing displacement
                         // the origin should be relative to the word, not the final text
-                        let (letter_spacing_for_glyph, origin) = match glyph_info.placement {
+                        let glyph_advance_x = glyph_info.size.get_x_advance_scaled(units_per_em, font_size_px);
+                        let kerning_x = glyph_info.size.get_kerning_scaled(units_per_em, font_size_px);
+
+                        let (letter_spacing_for_glyph, origin, mirror_advance) = match glyph_info.placement {
                             Placement::None => {
-                                (letter_spacing_px, LogicalPosition::new(x_pos_in_word_px + displacement.x, displacement.y))
+                                (letter_spacing_px, LogicalPosition::new(x_pos_in_word_px + displacement.x, displacement.y), glyph_advance_x + kerning_x)
                             },
                             Placement::Distance(PlacementDistance { x, y }) => {
                                 let font_metrics_divisor = units_per_em as f32 / font_size_px;
@@ -2072,45 +2183,78 @@ pub fn get_inline_text(words: &Words, shaped_words: &ShapedWords, word_positions
                                     x: x as f32 / font_metrics_divisor,
                                     y: y as f32 / font_metrics_divisor,
                                 };
-                                (letter_spacing_px, LogicalPosition::new(x_pos_in_word_px + displacement.x, displacement.y))
+                                (letter_spacing_px, LogicalPosition::new(x_pos_in_word_px + displacement.x, displacement.y), glyph_advance_x + kerning_x)
                             },
                             Placement::MarkAnchor(MarkAnchorPlacement { base_glyph_index, .. }) => {
                                 let anchor = &all_glyphs_in_this_word[base_glyph_index];
-                                (0.0, anchor.bounds.origin + displacement) // TODO: wrong
+                                (0.0, anchor.bounds.origin + displacement, glyph_advances[base_glyph_index]) // TODO: wrong
                             },
                             Placement::MarkOverprint(index) => {
                                 let anchor = &all_glyphs_in_this_word[index];
-                                (0.0, anchor.bounds.origin + displacement)
+                                (0.0, anchor.bounds.origin + displacement, glyph_advances[index])
                             },
                             Placement::CursiveAnchor(CursiveAnchorPlacement { exit_glyph_index, .. }) => {
                                 let anchor = &all_glyphs_in_this_word[exit_glyph_index];
-                                (0.0, anchor.bounds.origin + displacement) // TODO: wrong
+                                (0.0, anchor.bounds.origin + displacement, glyph_advances[exit_glyph_index]) // TODO: wrong
                             },
                         };
 
                         let glyph_scale_x = glyph_info.size.get_x_size_scaled(units_per_em, font_size_px);
                         let glyph_scale_y = glyph_info.size.get_y_size_scaled(units_per_em, font_size_px);
 
-                        let glyph_advance_x = glyph_info.size.get_x_advance_scaled(units_per_em, font_size_px);
-                        let kerning_x = glyph_info.size.get_kerning_scaled(units_per_em, font_size_px);
-
                         let inline_char = InlineGlyph {
                             bounds: LogicalRect::new(origin, LogicalSize::new(glyph_scale_x, glyph_scale_y)),
                             unicode_codepoint: glyph_info.glyph.unicode_codepoint,
                             glyph_index: glyph_info.glyph.glyph_index as u32,
+                            font_index: glyph_info.font_index,
                         };
 
                         x_pos_in_word_px += glyph_advance_x + kerning_x + letter_spacing_for_glyph;
 
                         all_glyphs_in_this_word.push(inline_char);
+                        glyph_advances.push(mirror_advance);
                     }
 
+                    // the line is broken after this word by hyphenating it: append the hyphen glyph
+                    if word_position.hyphenated {
+                        for glyph_info in shaped_words.hyphen.iter().flat_map(|h| h.glyph_infos.iter()) {
+                            let glyph_advance_x = glyph_info.size.get_x_advance_scaled(units_per_em, font_size_px);
+                            let kerning_x = glyph_info.size.get_kerning_scaled(units_per_em, font_size_px);
+                            let glyph_scale_x = glyph_info.size.get_x_size_scaled(units_per_em, font_size_px);
+                            let glyph_scale_y = glyph_info.size.get_y_size_scaled(units_per_em, font_size_px);
+                            all_glyphs_in_this_word.push(InlineGlyph {
+                                bounds: LogicalRect::new(
+                                    LogicalPosition::new(x_pos_in_word_px, 0.0),
+                                    LogicalSize::new(glyph_scale_x, glyph_scale_y)
+                                ),
+                                unicode_codepoint: None.into(), // not part of the text
+                                glyph_index: glyph_info.glyph.glyph_index as u32,
+                                font_index: glyph_info.font_index,
+                            });
+                            glyph_advances.push(glyph_advance_x + kerning_x);
+                            x_pos_in_word_px += glyph_advance_x + kerning_x;
+                        }
+                    }
+
+                    // glyphs are shaped in logical order: on right-to-left levels the first
+                    // glyph has to be at the right edge of the word, the last one at the left edge
+                    if is_rtl {
+                        let word_width = word_position.size.width;
+                        for (glyph, advance) in all_glyphs_in_this_word.iter_mut().zip(glyph_advances.iter()) {
+                            glyph.bounds.origin.x = word_width - glyph.bounds.origin.x - advance;
+                        }
+                    }
+
+                    // soft hyphens are invisible, unless the line is broken at the soft hyphen
+                    all_glyphs_in_this_word.retain(|g| g.unicode_codepoint.into_option() != Some(SOFT_HYPHEN as u32));
+
                     let inline_word = InlineWord::Word(InlineTextContents {
                         glyphs: all_glyphs_in_this_word.into(),
                         bounds: LogicalRect::new(
                             word_position.position,
                             word_position.size
                         ),
+                        is_rtl,
                     });
 
                     Some(inline_word)
@@ -2252,6 +2396,12 @@ pub struct WordPosition {
     pub shaped_word_index: Option<usize>,
     pub position: LogicalPosition,
     pub size: LogicalSize,
+    /// Bidi embedding level of the word - the glyphs of words on
+    /// odd (right-to-left) levels are positioned from right to left
+    pub bidi_level: u8,
+    /// Whether the line is broken after this word by hyphenating
+    /// the word, a hyphen glyph is appended to the word
+    pub hyphenated: bool,
 }
 
 /// Returns the layouted glyph instances
@@ -2275,6 +2425,7 @@ pub fn add_fonts_and_images(
     styled_dom: &StyledDom,
     load_font_fn: LoadFontFn,
     parse_font_fn: ParseFontFn,
+    font_has_glyph_fn: FontHasGlyphFn,
     insert_into_active_gl_textures: GlStoreImageFn,
 ) {
     let new_image_keys = styled_dom.scan_for_image_keys(&image_cache);
@@ -2304,6 +2455,24 @@ pub fn add_fonts_and_images(
         add_font_resource_updates,
         add_image_resource_updates
     );
+
+    // fallback fonts can only be searched once the primary fonts are loaded
+    let add_fallback_font_resource_updates = build_add_fallback_font_resource_updates(
+        renderer_resources,
+        fc_cache,
+        render_api_namespace,
+        styled_dom,
+        load_font_fn,
+        parse_font_fn,
+        font_has_glyph_fn,
+    );
+
+    add_resources(
+        renderer_resources,
+        all_resource_updates,
+        add_fallback_font_resource_updates,
+        Vec::new(),
+    );
 }
 
 pub fn font_size_to_au(font_size: StyleFontSize) -> Au {
@@ -2682,6 +2851,48 @@ pub type LoadFontFn = fn(&StyleFontFamily, &FcFontCache) -> Option<LoadedFontSou
 // function to parse the font given the loaded font source
 pub type ParseFontFn = fn(LoadedFontSource) -> Option<FontRef>; // = Option<Box<azul_text_layout::Font>>
 
+// function to check whether a parsed font contains a glyph for the given unicode codepoint
+pub type FontHasGlyphFn = fn(&FontRef, u32) -> bool;
+
+/// Creates a new font instance (font key + font size) with the default rendering options of the platform
+fn build_add_font_instance(id_namespace: IdNamespace, font_key: FontKey, font_size: Au) -> AddFontInstance {
+
+    // For some reason the gamma is way to low on Windows
+    #[cfg(target_os = "windows")]
+    let platform_options = FontInstancePlatformOptions {
+        gamma: 300,
+        contrast: 100,
+        cleartype_level: 100,
+    };
+
+    #[cfg(target_os = "linux")]
+    let platform_options = FontInstancePlatformOptions {
+        lcd_filter: FontLCDFilter::Default,
+        hinting: FontHinting::Normal,
+    };
+
+    #[cfg(target_os = "macos")]
+    let platform_options = FontInstancePlatformOptions::default();
+
+    #[cfg(target_arch = "wasm32")]
+    let platform_options = FontInstancePlatformOptions::default();
+
+    let options = FontInstanceOptions {
+        render_mode: FontRenderMode::Subpixel,
+        flags: 0 | FONT_INSTANCE_FLAG_NO_AUTOHINT,
+        .. Default::default()
+    };
+
+    AddFontInstance {
+        key: FontInstanceKey::unique(id_namespace),
+        font_key,
+        glyph_size: font_size,
+        options: Some(options),
+        platform_options: Some(platform_options),
+        variations: alloc::vec::Vec::new(),
+    }
+}
+
 /// Given the fonts of the current frame, returns `AddFont` and `AddFontInstance`s of
 /// which fonts / instances are currently not in the `current_registered_fonts` and
 /// need to be added.
@@ -2712,44 +2923,11 @@ pub fn build_add_font_resource_updates(
                 .is_some() || font_instances_added_this_frame.contains(&($font_key, $font_size));
 
             if !font_instance_key_exists {
-
-                let font_instance_key = FontInstanceKey::unique(id_namespace);
-
-                // For some reason the gamma is way to low on Windows
-                #[cfg(target_os = "windows")]
-                let platform_options = FontInstancePlatformOptions {
-                    gamma: 300,
-                    contrast: 100,
-                    cleartype_level: 100,
-                };
-
-                #[cfg(target_os = "linux")]
-                let platform_options = FontInstancePlatformOptions {
-                    lcd_filter: FontLCDFilter::Default,
-                    hinting: FontHinting::Normal,
-                };
-
-                #[cfg(target_os = "macos")]
-                let platform_options = FontInstancePlatformOptions::default();
-
-                #[cfg(target_arch = "wasm32")]
-                let platform_options = FontInstancePlatformOptions::default();
-
-                let options = FontInstanceOptions {
-                    render_mode: FontRenderMode::Subpixel,
-                    flags: 0 | FONT_INSTANCE_FLAG_NO_AUTOHINT,
-                    .. Default::default()
-                };
-
                 font_instances_added_this_frame.insert(($font_key, $font_size));
-                resource_updates.push(($font_family_hash, AddFontMsg::Instance(AddFontInstance {
-                    key: font_instance_key,
-                    font_key: $font_key,
-                    glyph_size: $font_size,
-                    options: Some(options),
-                    platform_options: Some(platform_options),
-                    variations: alloc::vec::Vec::new(),
-                }, $font_size)));
+                resource_updates.push(($font_family_hash, AddFontMsg::Instance(
+                    build_add_font_instance(id_namespace, $font_key, $font_size),
+                    $font_size
+                )));
             }
         })}
 
@@ -2836,6 +3014,159 @@ pub fn build_add_font_resource_updates(
     resource_updates
 }
 
+/// Searches fallback fonts for the characters of the text nodes that are not contained
+/// in the primary font of their font families: first the remaining fonts of the font
+/// families vec are tried, then all regular (not bold / italic) fonts of the system
+/// font cache. Returns the `AddFont` and `AddFontInstance` messages for the new fallback
+/// fonts and the font sizes that the fallback fonts don't have an instance for yet.
+///
+/// Must be called after the primary fonts of the DOM have been added
+/// to the renderer resources (see `build_add_font_resource_updates`).
+///
+/// NOTE: EXPENSIVE if a character is not contained in any font, since every system
+/// font has to be loaded. The missing characters are remembered in the `FontFallbackChain`,
+/// so that the system fonts are only searched once per character.
+#[cfg(feature = "multithreading")]
+pub fn build_add_fallback_font_resource_updates(
+    renderer_resources: &mut RendererResources,
+    fc_cache: &FcFontCache,
+    id_namespace: IdNamespace,
+    styled_dom: &StyledDom,
+    font_source_load_fn: LoadFontFn,
+    parse_font_fn: ParseFontFn,
+    font_has_glyph_fn: FontHasGlyphFn,
+) -> Vec<(StyleFontFamilyHash, AddFontMsg)> {
+
+    use rust_fontconfig::PatternMatch;
+
+    let mut resource_updates = alloc::vec::Vec::new();
+    let mut font_instances_added_this_frame = FastBTreeSet::new();
+    // fonts that were loaded during this frame, but were not a fallback font for any text
+    let mut fonts_without_coverage = FastBTreeSet::new();
+
+    // system fonts, regular fonts are preferred over bold / italic fonts
+    let system_fonts = fc_cache.list()
+        .iter()
+        .filter(|(pattern, _)| {
+            pattern.bold != PatternMatch::True &&
+            pattern.italic != PatternMatch::True &&
+            pattern.oblique != PatternMatch::True
+        })
+        .map(|(_, path)| StyleFontFamily::File(path.path.clone().into()))
+        .collect::<Vec<_>>();
+
+    for (font_families_hash, (font_families, font_sizes, chars)) in styled_dom.scan_for_font_fallback_chars() {
+
+        let primary_font_family = match renderer_resources.get_font_family(&font_families_hash) {
+            Some(s) => *s,
+            None => continue, // font is not loaded yet
+        };
+
+        let mut chain = renderer_resources.get_font_fallback_chain(&font_families_hash).cloned().unwrap_or_default();
+
+        let fonts = renderer_resources.get_font_fallback_keys(&font_families_hash)
+            .iter()
+            .filter_map(|font_key| renderer_resources.get_registered_font(font_key).map(|f| f.0.clone()))
+            .collect::<Vec<_>>();
+
+        let mut missing_chars = chars.into_iter()
+            .filter(|c| !chain.missing_chars.contains(c))
+            .filter(|c| !fonts.iter().any(|font| (font_has_glyph_fn)(font, *c)))
+            .collect::<FastBTreeSet<u32>>();
+
+        let candidates = font_families.as_ref().iter().chain(system_fonts.iter());
+
+        for candidate in candidates {
+
+            if missing_chars.is_empty() {
+                break;
+            }
+
+            let candidate_hash = StyleFontFamilyHash::new(candidate);
+
+            if candidate_hash == primary_font_family ||
+               chain.fonts.contains(&candidate_hash) ||
+               fonts_without_coverage.contains(&candidate_hash) {
+                continue;
+            }
+
+            let existing_font = renderer_resources.get_font_key(&candidate_hash)
+                .and_then(|font_key| renderer_resources.get_registered_font(font_key))
+                .map(|(font_ref, _)| font_ref.clone());
+
+            let font_ref = match existing_font {
+                Some(s) => s,
+                None => {
+                    let font_ref = match candidate {
+                        StyleFontFamily::Ref(r) => Some(r.clone()),
+                        other => (font_source_load_fn)(other, fc_cache).and_then(|source| (parse_font_fn)(source)),
+                    };
+                    match font_ref {
+                        Some(s) => s,
+                        None => {
+                            fonts_without_coverage.insert(candidate_hash);
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            let covered_chars = missing_chars.iter()
+                .filter(|c| (font_has_glyph_fn)(&font_ref, **c))
+                .copied()
+                .collect::<Vec<_>>();
+
+            if covered_chars.is_empty() {
+                if renderer_resources.get_font_key(&candidate_hash).is_none() {
+                    fonts_without_coverage.insert(candidate_hash);
+                }
+                continue;
+            }
+
+            for c in covered_chars {
+                missing_chars.remove(&c);
+            }
+
+            if renderer_resources.get_font_key(&candidate_hash).is_none() {
+                let font_key = FontKey::unique(id_namespace);
+                renderer_resources.font_id_map.insert(candidate_hash, font_key);
+                renderer_resources.currently_registered_fonts.insert(font_key, (font_ref.clone(), FastHashMap::default()));
+                resource_updates.push((candidate_hash, AddFontMsg::Font(font_key, candidate_hash, font_ref)));
+            }
+
+            chain.fonts.push(candidate_hash);
+        }
+
+        chain.missing_chars.extend(missing_chars.into_iter());
+
+        // Insert the missing font instances for all fallback fonts
+        for font_family_hash in chain.fonts.iter() {
+            let font_key = match renderer_resources.get_font_key(font_family_hash) {
+                Some(s) => *s,
+                None => continue,
+            };
+            for font_size in font_sizes.iter() {
+                let font_instance_key_exists = renderer_resources.currently_registered_fonts
+                    .get(&font_key)
+                    .and_then(|(_, font_instances)| font_instances.get(font_size))
+                    .is_some() || font_instances_added_this_frame.contains(&(font_key, *font_size));
+
+                if !font_instance_key_exists {
+                    font_instances_added_this_frame.insert((font_key, *font_size));
+                    resource_updates.push((*font_family_hash, AddFontMsg::Instance(
+                        build_add_font_instance(id_namespace, font_key, *font_size),
+                        *font_size
+                    )));
+                }
+            }
+        }
+
+        renderer_resources.font_fallback_chains.insert(font_families_hash, chain);
+    }
+
+    resource_updates
+}
+
 /// Given the images of the current frame, returns `AddImage`s of
 /// which image keys are currently not in the `current_registered_images` and
 /// need to be added.
@@ -2953,6 +3284,7 @@ pub fn add_resources(
             Instance(fi, size) => {
                 if let Some((_, instances)) = renderer_resources.currently_registered_fonts.get_mut(&fi.font_key) {
                     instances.insert(size, fi.key);
+                    renderer_resources.font_instance_map.insert(fi.key, (fi.font_key, size));
                 }
             },
         }
@@ -14,7 +14,7 @@ use std::hash::Hash;
 use azul_css::{
     CssProperty, LayoutSize, CssPath, InterpolateResolver,
     AzString, LayoutRect, AnimationInterpolationFunction,
-    CssPropertyType, FontRef,
+    CssPropertyType, FontRef, OptionAzString,
 };
 use rust_fontconfig::FcFontCache;
 use crate::{
@@ -42,7 +42,7 @@ use crate::{
         WindowSize, WindowState, FullWindowState, LogicalPosition, OptionChar,
         LogicalSize, PhysicalSize, UpdateFocusWarning, WindowCreateOptions,
         RawWindowHandle, KeyboardState, MouseState, LogicalRect, WindowTheme,
-        WindowFlags,
+        WindowFlags, ClipboardSelection, ClipboardContent, OptionClipboardContent,
     }
 };
 use crate::gl::OptionGlContextPtr;
@@ -698,6 +698,16 @@ pub struct MarshaledLayoutCallbackInner {
 
 impl_callback!(MarshaledLayoutCallbackInner);
 
+// -- tooltip callback
+
+/// Renders the content of a `Tooltip`. The `LayoutCallbackInfo` belongs
+/// to the popup window that the tooltip is displayed in.
+pub type TooltipCallbackType = extern "C" fn(&mut RefAny, &mut LayoutCallbackInfo) -> StyledDom;
+
+#[repr(C)]
+pub struct TooltipCallback { pub cb: TooltipCallbackType }
+impl_callback!(TooltipCallback);
+
 // -- normal callback
 
 /// Stores a function pointer that is executed when the given UI element is hit
@@ -740,6 +750,13 @@ pub struct InlineTextHit {
     // relative to text content (word)
     pub glyph_index_relative_to_word: usize,
     pub char_index_relative_to_word: usize,
+
+    // whether the hit glyph is part of a right-to-left word
+    pub is_rtl: bool,
+    // index of the character in front of which a text cursor should be placed:
+    // the hit character if its leading half was hit (left half for LTR,
+    // right half for RTL words), otherwise the following character
+    pub cursor_index_relative_to_text: usize,
 }
 
 impl_vec!(InlineTextHit, InlineTextHitVec, InlineTextHitVecDestructor);
@@ -812,6 +829,7 @@ impl InlineText {
                     .map(move |glyph| {
                         GlyphInstance {
                             index: glyph.glyph_index,
+                            font_index: glyph.font_index,
                             point: {
                                 line_origin +
                                 baseline_descender_px +
@@ -896,6 +914,10 @@ impl InlineText {
                                 let result = glyph_bounds
                                 .hit_test(&hit_relative_to_text_content)
                                 .map(|hit_relative_to_glyph| {
+
+                                    let hit_trailing_half = (hit_relative_to_glyph.x > glyph.bounds.size.width / 2.0) != text_content.is_rtl;
+                                    let cursor_offset = if glyph.has_codepoint() && hit_trailing_half { 1 } else { 0 };
+
                                     InlineTextHit {
                                         unicode_codepoint: glyph.unicode_codepoint,
 
@@ -917,6 +939,9 @@ impl InlineText {
 
                                         glyph_index_relative_to_word: global_glyph_hit - glyph_at_text_content_start,
                                         char_index_relative_to_word: global_char_hit - char_at_text_content_start,
+
+                                        is_rtl: text_content.is_rtl,
+                                        cursor_index_relative_to_text: global_char_hit + cursor_offset,
                                     }
                                 });
 
@@ -948,6 +973,110 @@ impl InlineText {
         })
         .collect::<Vec<_>>()
     }
+
+    /// Returns the rectangle (zero width, height of the line) of a text cursor that is placed
+    /// in front of the character at `char_index` (same index as `InlineTextHit::char_index_relative_to_text`),
+    /// relative to the top left corner of the inline text.
+    ///
+    /// In right-to-left words, the front of the character is its right edge. If the index is
+    /// past the last character, the cursor is placed behind the last character.
+    pub fn get_cursor_rect(&self, char_index: usize) -> Option<LogicalRect> {
+
+        let mut global_char = 0;
+        let mut cursor_after_last_char = None;
+
+        for line in self.lines.iter() {
+            let line_top = line.bounds.origin.y - line.bounds.size.height;
+            let cursor_rect = |x: f32| LogicalRect::new(
+                LogicalPosition::new(x, line_top),
+                LogicalSize::new(0.0, line.bounds.size.height)
+            );
+            for text_content in line.words.iter().filter_map(|w| w.get_text_content()) {
+                for glyph in text_content.glyphs.iter().filter(|g| g.has_codepoint()) {
+                    let glyph_left = line.bounds.origin.x + text_content.bounds.origin.x + glyph.bounds.origin.x;
+                    let glyph_right = glyph_left + glyph.bounds.size.width;
+                    let (leading, trailing) = if text_content.is_rtl {
+                        (glyph_right, glyph_left)
+                    } else {
+                        (glyph_left, glyph_right)
+                    };
+                    if global_char == char_index {
+                        return Some(cursor_rect(leading));
+                    }
+                    cursor_after_last_char = Some(cursor_rect(trailing));
+                    global_char += 1;
+                }
+            }
+        }
+
+        cursor_after_last_char
+    }
+
+    /// Returns the rectangles that cover the characters in the logical range `start..end`
+    /// (see `get_cursor_rect`), relative to the top left corner of the inline text.
+    ///
+    /// A logical range of mixed-direction text is not necessarily visually continuous,
+    /// so the result can contain more than one rectangle per line.
+    pub fn get_selection_rects(&self, start: usize, end: usize) -> Vec<LogicalRect> {
+
+        let (start, end) = (start.min(end), start.max(end));
+        let mut global_char = 0;
+        let mut rects = Vec::new();
+
+        for line in self.lines.iter() {
+
+            let line_top = line.bounds.origin.y - line.bounds.size.height;
+            let selection_rect = |(left, right): (f32, f32)| LogicalRect::new(
+                LogicalPosition::new(left, line_top),
+                LogicalSize::new(right - left, line.bounds.size.height)
+            );
+
+            // horizontal extent of the selected / unselected glyphs in this line
+            let mut selected = Vec::new();
+            let mut unselected = Vec::new();
+
+            for text_content in line.words.iter().filter_map(|w| w.get_text_content()) {
+                for glyph in text_content.glyphs.iter().filter(|g| g.has_codepoint()) {
+                    let glyph_left = line.bounds.origin.x + text_content.bounds.origin.x + glyph.bounds.origin.x;
+                    let glyph_right = glyph_left + glyph.bounds.size.width;
+                    if global_char >= start && global_char < end {
+                        selected.push((glyph_left, glyph_right));
+                    } else {
+                        unselected.push((glyph_left, glyph_right));
+                    }
+                    global_char += 1;
+                }
+            }
+
+            // merge visually adjacent selected glyphs, unless an unselected glyph is in between
+            selected.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(core::cmp::Ordering::Equal));
+
+            let mut current: Option<(f32, f32)> = None;
+            for (left, right) in selected {
+                current = match current {
+                    None => Some((left, right)),
+                    Some((current_left, current_right)) => {
+                        let is_interrupted = unselected.iter().any(|(u_left, u_right)| {
+                            let center = (u_left + u_right) / 2.0;
+                            center > current_right && center < left
+                        });
+                        if is_interrupted {
+                            rects.push(selection_rect((current_left, current_right)));
+                            Some((left, right))
+                        } else {
+                            Some((current_left, current_right.max(right)))
+                        }
+                    },
+                };
+            }
+
+            if let Some(current) = current {
+                rects.push(selection_rect(current));
+            }
+        }
+
+        rects
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -995,6 +1124,8 @@ impl_vec_partialord!(InlineWord, InlineWordVec);
 pub struct InlineTextContents {
     pub glyphs: InlineGlyphVec,
     pub bounds: LogicalRect,
+    /// Whether the glyphs of this word are laid out from right to left
+    pub is_rtl: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -1003,6 +1134,8 @@ pub struct InlineGlyph {
     pub bounds: LogicalRect,
     pub unicode_codepoint: OptionChar,
     pub glyph_index: u32,
+    /// Font of the glyph: 0 is the primary font, fallback fonts start at 1
+    pub font_index: usize,
 }
 
 impl InlineGlyph {
@@ -1154,8 +1287,8 @@ impl CallbackInfo {
     fn internal_get_layout_results<'a>(&'a self) -> &'a [LayoutResult] { unsafe { core::slice::from_raw_parts(self.layout_results, self.layout_results_count) } }
     fn internal_get_renderer_resources<'a>(&'a self) -> &'a RendererResources { unsafe { &*self.renderer_resources } }
     fn internal_get_previous_window_state<'a>(&'a self) -> &'a Option<FullWindowState> { unsafe { &*self.previous_window_state } }
-    fn internal_get_current_window_state<'a>(&'a self) -> &'a FullWindowState { unsafe { &*self.current_window_state } }
-    fn internal_get_modifiable_window_state<'a>(&'a mut self)-> &'a mut WindowState { unsafe { &mut *self.modifiable_window_state } }
+    pub(crate) fn internal_get_current_window_state<'a>(&'a self) -> &'a FullWindowState { unsafe { &*self.current_window_state } }
+    pub(crate) fn internal_get_modifiable_window_state<'a>(&'a mut self)-> &'a mut WindowState { unsafe { &mut *self.modifiable_window_state } }
     fn internal_get_gl_context<'a>(&'a self) -> &'a OptionGlContextPtr { unsafe { &*self.gl_context } }
     fn internal_get_image_cache<'a>(&'a mut self) -> &'a mut ImageCache { unsafe { &mut *self.image_cache } }
     fn internal_get_image_cache_ref<'a>(&'a self) -> &'a ImageCache { unsafe { &*self.image_cache } }
@@ -1192,6 +1325,10 @@ impl CallbackInfo {
     pub fn get_previous_window_state(&self) -> Option<WindowState> { Some(self.internal_get_previous_window_state().as_ref()?.clone().into()) }
     pub fn get_previous_keyboard_state(&self) -> Option<KeyboardState> { Some(self.internal_get_previous_window_state().as_ref()?.keyboard_state.clone()) }
     pub fn get_previous_mouse_state(&self) -> Option<MouseState> { Some(self.internal_get_previous_window_state().as_ref()?.mouse_state.clone()) }
+    pub fn get_hovered_file(&self) -> OptionAzString { self.internal_get_current_window_state().hovered_file.clone().into() }
+    pub fn get_dropped_file(&self) -> OptionAzString { self.internal_get_current_window_state().dropped_file.clone().into() }
+    pub fn get_hovered_text(&self) -> OptionAzString { self.internal_get_current_window_state().hovered_text.clone().into() }
+    pub fn get_dropped_text(&self) -> OptionAzString { self.internal_get_current_window_state().dropped_text.clone().into() }
     pub fn get_current_window_handle(&self) -> RawWindowHandle { self.internal_get_current_window_handle().clone() }
     pub fn get_current_time(&self) -> Instant { (self.internal_get_extern_system_callbacks().get_system_time_fn.cb)() }
     pub fn get_gl_context(&self) -> OptionGlContextPtr { self.internal_get_gl_context().clone() }
@@ -1413,6 +1550,28 @@ impl CallbackInfo {
         self.internal_get_new_windows().push(window);
     }
 
+    /// Reads the system clipboard (or the X11 PRIMARY selection) in the requested MIME type,
+    /// returns `None` if the clipboard is empty or the content can't be converted
+    pub fn get_clipboard_content(&self, selection: ClipboardSelection, mime_type: AzString) -> OptionClipboardContent {
+        let window_handle = self.internal_get_current_window_handle();
+        (self.internal_get_extern_system_callbacks().get_clipboard_fn.cb)(window_handle, selection, mime_type)
+    }
+
+    /// Shortcut for reading the clipboard as `text/plain;charset=utf-8`
+    pub fn get_clipboard_text(&self, selection: ClipboardSelection) -> OptionAzString {
+        self.get_clipboard_content(selection, AzString::from_const_str(ClipboardContent::MIME_TYPE_TEXT))
+        .into_option()
+        .and_then(|c| c.get_text())
+        .into()
+    }
+
+    /// Sets the system clipboard (or the X11 PRIMARY selection), the content
+    /// is owned by the current window until another application takes it over
+    pub fn set_clipboard_content(&mut self, selection: ClipboardSelection, content: ClipboardContent) -> bool {
+        let window_handle = self.internal_get_current_window_handle();
+        (self.internal_get_extern_system_callbacks().set_clipboard_fn.cb)(window_handle, selection, content)
+    }
+
     /// Starts a thread, returns Some(thread_id) if the `thread_initialize_data` is the only copy
     pub fn start_thread(&mut self, thread_initialize_data: RefAny, writeback_data: RefAny, callback: ThreadCallbackType) -> Option<ThreadId> {
         if thread_initialize_data.has_no_copies() {
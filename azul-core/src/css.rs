@@ -33,6 +33,15 @@ pub struct VecContents {
     style_background_contents: BTreeMap<u64, StyleBackgroundContentVec>,
     style_background_positions: BTreeMap<u64, StyleBackgroundPositionVec>,
     style_transforms: BTreeMap<u64, StyleTransformVec>,
+    grid_track_sizings: BTreeMap<u64, GridTrackSizingVec>,
+    style_transition_properties: BTreeMap<u64, StyleTransitionPropertyVec>,
+    style_times: BTreeMap<u64, StyleTimeVec>,
+    style_timing_functions: BTreeMap<u64, StyleTimingFunctionVec>,
+    style_animation_names: BTreeMap<u64, StyleAnimationNameVec>,
+    style_animation_iteration_counts: BTreeMap<u64, StyleAnimationIterationCountVec>,
+    style_animation_directions: BTreeMap<u64, StyleAnimationDirectionVec>,
+    style_animation_fill_modes: BTreeMap<u64, StyleAnimationFillModeVec>,
+    style_animation_play_states: BTreeMap<u64, StyleAnimationPlayStateVec>,
     font_families: BTreeMap<u64, StyleFontFamilyVec>,
     linear_color_stops: BTreeMap<u64, NormalizedLinearColorStopVec>,
     radial_color_stops: BTreeMap<u64, NormalizedRadialColorStopVec>,
@@ -110,6 +119,36 @@ impl VecContents {
             key, t2, val, t));
         }
 
+        for (key, item) in self.grid_track_sizings.iter() {
+
+            let val = format_grid_track_sizings(item.as_ref(), tabs + 1);
+
+            result.push_str(&format!("\r\n    const GRID_TRACK_SIZING_{}_ITEMS: &[GridTrackSizing] = &[\r\n{}{}\r\n{}];",
+            key, t2, val, t));
+        }
+
+        macro_rules! format_animation_items {($field:ident, $const_name:expr, $item_type:expr) => (
+            for (key, item) in self.$field.iter() {
+
+                let val = item.iter()
+                    .map(|i| i.format_as_rust_code(tabs + 1))
+                    .collect::<Vec<_>>()
+                    .join(&format!(",\r\n{}", t));
+
+                result.push_str(&format!("\r\n    const {}_{}_ITEMS: &[{}] = &[\r\n{}{}\r\n{}];",
+                $const_name, key, $item_type, t2, val, t));
+            }
+        )}
+
+        format_animation_items!(style_transition_properties, "STYLE_TRANSITION_PROPERTY", "StyleTransitionProperty");
+        format_animation_items!(style_times, "STYLE_TIME", "StyleTime");
+        format_animation_items!(style_timing_functions, "STYLE_TIMING_FUNCTION", "StyleTimingFunction");
+        format_animation_items!(style_animation_names, "STYLE_ANIMATION_NAME", "StyleAnimationName");
+        format_animation_items!(style_animation_iteration_counts, "STYLE_ANIMATION_ITERATION_COUNT", "StyleAnimationIterationCount");
+        format_animation_items!(style_animation_directions, "STYLE_ANIMATION_DIRECTION", "StyleAnimationDirection");
+        format_animation_items!(style_animation_fill_modes, "STYLE_ANIMATION_FILL_MODE", "StyleAnimationFillMode");
+        format_animation_items!(style_animation_play_states, "STYLE_ANIMATION_PLAY_STATE", "StyleAnimationPlayState");
+
         for (key, item) in self.font_families.iter() {
 
             let val = format_font_ids(item.as_ref(), tabs + 1);
@@ -171,6 +210,8 @@ impl VecContents {
                 self.font_families.insert(v.get_hash(), v.clone());
             },
             CssProperty::Transform(CssPropertyValue::Exact(v)) => { self.style_transforms.insert(v.get_hash(), v.clone()); },
+            CssProperty::GridTemplateColumns(CssPropertyValue::Exact(v)) => { self.grid_track_sizings.insert(v.get_hash(), v.clone()); },
+            CssProperty::GridTemplateRows(CssPropertyValue::Exact(v)) => { self.grid_track_sizings.insert(v.get_hash(), v.clone()); },
             CssProperty::BackgroundRepeat(CssPropertyValue::Exact(v)) => { self.style_background_repeats.insert(v.get_hash(), v.clone()); },
             CssProperty::BackgroundSize(CssPropertyValue::Exact(v)) => { self.style_background_sizes.insert(v.get_hash(), v.clone()); },
             CssProperty::BackgroundPosition(CssPropertyValue::Exact(v)) => { self.style_background_positions.insert(v.get_hash(), v.clone()); },
@@ -191,7 +232,26 @@ impl VecContents {
             },
             CssProperty::BackdropFilter(CssPropertyValue::Exact(v)) => {
                 self.style_filters.insert(v.get_hash(), v.clone());
-            }
+            },
+            CssProperty::TransitionProperty(CssPropertyValue::Exact(v)) => { self.style_transition_properties.insert(v.get_hash(), v.clone()); },
+            CssProperty::TransitionDuration(CssPropertyValue::Exact(v)) |
+            CssProperty::TransitionDelay(CssPropertyValue::Exact(v)) |
+            CssProperty::AnimationDuration(CssPropertyValue::Exact(v)) |
+            CssProperty::AnimationDelay(CssPropertyValue::Exact(v)) => { self.style_times.insert(v.get_hash(), v.clone()); },
+            CssProperty::TransitionTimingFunction(CssPropertyValue::Exact(v)) |
+            CssProperty::AnimationTimingFunction(CssPropertyValue::Exact(v)) => { self.style_timing_functions.insert(v.get_hash(), v.clone()); },
+            CssProperty::AnimationName(CssPropertyValue::Exact(v)) => {
+                for name in v.iter() {
+                    if let StyleAnimationName::Name(s) = name {
+                        self.strings.insert(s.get_hash(), s.clone());
+                    }
+                }
+                self.style_animation_names.insert(v.get_hash(), v.clone());
+            },
+            CssProperty::AnimationIterationCount(CssPropertyValue::Exact(v)) => { self.style_animation_iteration_counts.insert(v.get_hash(), v.clone()); },
+            CssProperty::AnimationDirection(CssPropertyValue::Exact(v)) => { self.style_animation_directions.insert(v.get_hash(), v.clone()); },
+            CssProperty::AnimationFillMode(CssPropertyValue::Exact(v)) => { self.style_animation_fill_modes.insert(v.get_hash(), v.clone()); },
+            CssProperty::AnimationPlayState(CssPropertyValue::Exact(v)) => { self.style_animation_play_states.insert(v.get_hash(), v.clone()); },
             _ => { }
         }
     }
@@ -225,6 +285,55 @@ pub fn css_to_rust_code(css: &Css) -> String {
             output.push_str("\t\t\t\t},\r\n");
         }
 
+        output.push_str("\t\t\t],\r\n");
+        output.push_str("\t\t\tkeyframes: [\r\n");
+
+        for keyframes in stylesheet.keyframes.iter() {
+
+            output.push_str("\t\t\t\tCssKeyframes {\r\n");
+            output.push_str(&format!("\t\t\t\t\tname: {:?},\r\n", keyframes.name.as_str()));
+            output.push_str("\t\t\t\t\tframes: [\r\n");
+
+            for frame in keyframes.frames.iter() {
+                output.push_str("\t\t\t\t\t\tCssKeyframe {\r\n");
+                output.push_str(&format!("\t\t\t\t\t\t\toffset: {},\r\n", format_percentage_value(&frame.offset)));
+                output.push_str("\t\t\t\t\t\t\tproperties: [\r\n");
+                for prop in frame.properties.iter() {
+                    output.push_str(&format!("\t\t\t\t\t\t\t\t{},\r\n", format_static_css_prop(prop, 8)));
+                }
+                output.push_str("\t\t\t\t\t\t\t]\r\n");
+                output.push_str("\t\t\t\t\t\t},\r\n");
+            }
+
+            output.push_str("\t\t\t\t\t]\r\n");
+            output.push_str("\t\t\t\t},\r\n");
+        }
+
+        output.push_str("\t\t\t],\r\n");
+        output.push_str("\t\t\tmedia_rules: [\r\n");
+
+        for media_rule in stylesheet.media_rules.iter() {
+
+            output.push_str("\t\t\t\tCssMediaRule {\r\n");
+            output.push_str(&format!("\t\t\t\t\tqueries: {:?},\r\n", media_rule.queries.as_ref()));
+            output.push_str(&format!("\t\t\t\t\tinsert_index: {},\r\n", media_rule.insert_index));
+            output.push_str("\t\t\t\t\trules: [\r\n");
+
+            for block in media_rule.rules.iter() {
+                output.push_str("\t\t\t\t\t\tCssRuleBlock: {\r\n");
+                output.push_str(&format!("\t\t\t\t\t\t\tpath: {},\r\n", print_block_path(&block.path, 7)));
+                output.push_str("\t\t\t\t\t\t\tdeclarations: [\r\n");
+                for declaration in block.declarations.iter() {
+                    output.push_str(&format!("\t\t\t\t\t\t\t\t{},\r\n", print_declaraction(declaration, 8)));
+                }
+                output.push_str("\t\t\t\t\t\t\t]\r\n");
+                output.push_str("\t\t\t\t\t\t},\r\n");
+            }
+
+            output.push_str("\t\t\t\t\t]\r\n");
+            output.push_str("\t\t\t\t},\r\n");
+        }
+
         output.push_str("\t\t\t]\r\n");
         output.push_str("\t\t},\r\n");
     }
@@ -330,6 +439,10 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
         CssProperty::FontSize(p) => format!("CssProperty::FontSize({})", print_css_property_value(p, tabs, "StyleFontSize")),
         CssProperty::FontFamily(p) => format!("CssProperty::FontFamily({})", print_css_property_value(p, tabs, "StyleFontFamilyVec")),
         CssProperty::TextAlign(p) => format!("CssProperty::TextAlign({})", print_css_property_value(p, tabs, "StyleTextAlign")),
+        CssProperty::Direction(p) => format!("CssProperty::Direction({})", print_css_property_value(p, tabs, "StyleDirection")),
+        CssProperty::WordBreak(p) => format!("CssProperty::WordBreak({})", print_css_property_value(p, tabs, "StyleWordBreak")),
+        CssProperty::OverflowWrap(p) => format!("CssProperty::OverflowWrap({})", print_css_property_value(p, tabs, "StyleOverflowWrap")),
+        CssProperty::Hyphens(p) => format!("CssProperty::Hyphens({})", print_css_property_value(p, tabs, "StyleHyphens")),
         CssProperty::LetterSpacing(p) => format!("CssProperty::LetterSpacing({})", print_css_property_value(p, tabs, "StyleLetterSpacing")),
         CssProperty::LineHeight(p) => format!("CssProperty::LineHeight({})", print_css_property_value(p, tabs, "StyleLineHeight")),
         CssProperty::WordSpacing(p) => format!("CssProperty::WordSpacing({})", print_css_property_value(p, tabs, "StyleWordSpacing")),
@@ -356,6 +469,15 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
         CssProperty::JustifyContent(p) => format!("CssProperty::JustifyContent({})", print_css_property_value(p, tabs, "LayoutJustifyContent")),
         CssProperty::AlignItems(p) => format!("CssProperty::AlignItems({})", print_css_property_value(p, tabs, "LayoutAlignItems")),
         CssProperty::AlignContent(p) => format!("CssProperty::AlignContent({})", print_css_property_value(p, tabs, "LayoutAlignContent")),
+        CssProperty::GridTemplateColumns(p) => format!("CssProperty::GridTemplateColumns({})", print_css_property_value(p, tabs, "GridTrackSizingVec")),
+        CssProperty::GridTemplateRows(p) => format!("CssProperty::GridTemplateRows({})", print_css_property_value(p, tabs, "GridTrackSizingVec")),
+        CssProperty::GridAutoColumns(p) => format!("CssProperty::GridAutoColumns({})", print_css_property_value(p, tabs, "GridTrackSizing")),
+        CssProperty::GridAutoRows(p) => format!("CssProperty::GridAutoRows({})", print_css_property_value(p, tabs, "GridTrackSizing")),
+        CssProperty::GridAutoFlow(p) => format!("CssProperty::GridAutoFlow({})", print_css_property_value(p, tabs, "LayoutGridAutoFlow")),
+        CssProperty::GridColumn(p) => format!("CssProperty::GridColumn({})", print_css_property_value(p, tabs, "GridPlacement")),
+        CssProperty::GridRow(p) => format!("CssProperty::GridRow({})", print_css_property_value(p, tabs, "GridPlacement")),
+        CssProperty::RowGap(p) => format!("CssProperty::RowGap({})", print_css_property_value(p, tabs, "LayoutRowGap")),
+        CssProperty::ColumnGap(p) => format!("CssProperty::ColumnGap({})", print_css_property_value(p, tabs, "LayoutColumnGap")),
         CssProperty::BackgroundContent(p) => format!("CssProperty::BackgroundContent({})", print_css_property_value(p, tabs, "StyleBackgroundContentVec")),
         CssProperty::BackgroundPosition(p) => format!("CssProperty::BackgroundPosition({})", print_css_property_value(p, tabs, "StyleBackgroundPositionVec")),
         CssProperty::BackgroundSize(p) => format!("CssProperty::BackgroundSize({})", print_css_property_value(p, tabs, "StyleBackgroundSizeVec")),
@@ -400,6 +522,18 @@ pub(crate) fn format_static_css_prop(prop: &CssProperty, tabs: usize) -> String
         CssProperty::Filter(p) => format!("CssProperty::Filter({})", print_css_property_value(p, tabs, "StyleFilterVec")),
         CssProperty::BackdropFilter(p) => format!("CssProperty::Filter({})", print_css_property_value(p, tabs, "StyleFilterVec")),
         CssProperty::TextShadow(p) => format!("CssProperty::TextShadow({})", print_css_property_value(p, tabs, "StyleBoxShadow")),
+        CssProperty::TransitionProperty(p) => format!("CssProperty::TransitionProperty({})", print_css_property_value(p, tabs, "StyleTransitionPropertyVec")),
+        CssProperty::TransitionDuration(p) => format!("CssProperty::TransitionDuration({})", print_css_property_value(p, tabs, "StyleTimeVec")),
+        CssProperty::TransitionTimingFunction(p) => format!("CssProperty::TransitionTimingFunction({})", print_css_property_value(p, tabs, "StyleTimingFunctionVec")),
+        CssProperty::TransitionDelay(p) => format!("CssProperty::TransitionDelay({})", print_css_property_value(p, tabs, "StyleTimeVec")),
+        CssProperty::AnimationName(p) => format!("CssProperty::AnimationName({})", print_css_property_value(p, tabs, "StyleAnimationNameVec")),
+        CssProperty::AnimationDuration(p) => format!("CssProperty::AnimationDuration({})", print_css_property_value(p, tabs, "StyleTimeVec")),
+        CssProperty::AnimationTimingFunction(p) => format!("CssProperty::AnimationTimingFunction({})", print_css_property_value(p, tabs, "StyleTimingFunctionVec")),
+        CssProperty::AnimationDelay(p) => format!("CssProperty::AnimationDelay({})", print_css_property_value(p, tabs, "StyleTimeVec")),
+        CssProperty::AnimationIterationCount(p) => format!("CssProperty::AnimationIterationCount({})", print_css_property_value(p, tabs, "StyleAnimationIterationCountVec")),
+        CssProperty::AnimationDirection(p) => format!("CssProperty::AnimationDirection({})", print_css_property_value(p, tabs, "StyleAnimationDirectionVec")),
+        CssProperty::AnimationFillMode(p) => format!("CssProperty::AnimationFillMode({})", print_css_property_value(p, tabs, "StyleAnimationFillModeVec")),
+        CssProperty::AnimationPlayState(p) => format!("CssProperty::AnimationPlayState({})", print_css_property_value(p, tabs, "StyleAnimationPlayStateVec")),
     }
 }
 
@@ -513,6 +647,9 @@ impl_pixel_value_fmt!(LayoutBottom);
 impl_pixel_value_fmt!(LayoutRight);
 impl_pixel_value_fmt!(LayoutLeft);
 
+impl_pixel_value_fmt!(LayoutRowGap);
+impl_pixel_value_fmt!(LayoutColumnGap);
+
 macro_rules! impl_color_value_fmt {($struct_name:ty) => (
     impl FormatAsRustCode for $struct_name {
         fn format_as_rust_code(&self, _tabs: usize) -> String {
@@ -673,7 +810,8 @@ impl_enum_fmt!(LayoutDisplay,
     None,
     Flex,
     Block,
-    InlineBlock
+    InlineBlock,
+    Grid
 );
 
 impl_enum_fmt!(LayoutFloat,
@@ -723,6 +861,13 @@ impl_enum_fmt!(LayoutAlignContent,
     SpaceAround
 );
 
+impl_enum_fmt!(LayoutGridAutoFlow,
+    Row,
+    Column,
+    RowDense,
+    ColumnDense
+);
+
 impl_enum_fmt!(Shape,
     Circle,
     Ellipse
@@ -745,7 +890,32 @@ impl_enum_fmt!(LayoutOverflow,
 impl_enum_fmt!(StyleTextAlign,
     Center,
     Left,
-    Right
+    Right,
+    Start,
+    End
+);
+
+impl_enum_fmt!(StyleDirection,
+    Ltr,
+    Rtl
+);
+
+impl_enum_fmt!(StyleWordBreak,
+    Normal,
+    BreakAll,
+    KeepAll
+);
+
+impl_enum_fmt!(StyleOverflowWrap,
+    Normal,
+    Anywhere,
+    BreakWord
+);
+
+impl_enum_fmt!(StyleHyphens,
+    None,
+    Manual,
+    Auto
 );
 
 impl_enum_fmt!(DirectionCorner,
@@ -865,6 +1035,167 @@ impl FormatAsRustCode for StyleTransformVec {
     }
 }
 
+impl FormatAsRustCode for GridTrackSizingVec {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!("GridTrackSizingVec::from_const_slice(GRID_TRACK_SIZING_{}_ITEMS)", self.get_hash())
+    }
+}
+
+impl FormatAsRustCode for GridTrackSizing {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!("GridTrackSizing {{ min: {}, max: {} }}", format_grid_track_breadth(&self.min), format_grid_track_breadth(&self.max))
+    }
+}
+
+impl FormatAsRustCode for GridPlacement {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!("GridPlacement {{ start: {}, end: {} }}", format_grid_line(&self.start), format_grid_line(&self.end))
+    }
+}
+
+fn format_grid_track_sizings(tracks: &[GridTrackSizing], tabs: usize) -> String {
+    let t = String::from("    ").repeat(tabs);
+    tracks.iter()
+        .map(|s| s.format_as_rust_code(tabs))
+        .collect::<Vec<_>>()
+        .join(&format!(",\r\n{}", t))
+}
+
+fn format_grid_track_breadth(b: &GridTrackBreadth) -> String {
+    match b {
+        GridTrackBreadth::Fixed(p) => format!("GridTrackBreadth::Fixed({})", format_pixel_value(p)),
+        GridTrackBreadth::Fraction(f) => format!("GridTrackBreadth::Fraction({})", format_float_value(f)),
+        GridTrackBreadth::Auto => format!("GridTrackBreadth::Auto"),
+        GridTrackBreadth::MinContent => format!("GridTrackBreadth::MinContent"),
+        GridTrackBreadth::MaxContent => format!("GridTrackBreadth::MaxContent"),
+    }
+}
+
+fn format_grid_line(l: &GridLine) -> String {
+    match l {
+        GridLine::Auto => format!("GridLine::Auto"),
+        GridLine::Line(i) => format!("GridLine::Line({})", i),
+        GridLine::Span(n) => format!("GridLine::Span({})", n),
+    }
+}
+
+macro_rules! impl_animation_vec_fmt {($vec_type:ident, $const_name:expr) => (
+    impl FormatAsRustCode for $vec_type {
+        fn format_as_rust_code(&self, _tabs: usize) -> String {
+            format!("{}::from_const_slice({}_{}_ITEMS)", stringify!($vec_type), $const_name, self.get_hash())
+        }
+    }
+)}
+
+impl_animation_vec_fmt!(StyleTransitionPropertyVec, "STYLE_TRANSITION_PROPERTY");
+impl_animation_vec_fmt!(StyleTimeVec, "STYLE_TIME");
+impl_animation_vec_fmt!(StyleTimingFunctionVec, "STYLE_TIMING_FUNCTION");
+impl_animation_vec_fmt!(StyleAnimationNameVec, "STYLE_ANIMATION_NAME");
+impl_animation_vec_fmt!(StyleAnimationIterationCountVec, "STYLE_ANIMATION_ITERATION_COUNT");
+impl_animation_vec_fmt!(StyleAnimationDirectionVec, "STYLE_ANIMATION_DIRECTION");
+impl_animation_vec_fmt!(StyleAnimationFillModeVec, "STYLE_ANIMATION_FILL_MODE");
+impl_animation_vec_fmt!(StyleAnimationPlayStateVec, "STYLE_ANIMATION_PLAY_STATE");
+
+impl_enum_fmt!(StyleAnimationDirection,
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse
+);
+
+impl_enum_fmt!(StyleAnimationFillMode,
+    None,
+    Forwards,
+    Backwards,
+    Both
+);
+
+impl_enum_fmt!(StyleAnimationPlayState,
+    Running,
+    Paused
+);
+
+impl_enum_fmt!(StyleStepPosition,
+    JumpStart,
+    JumpEnd,
+    JumpNone,
+    JumpBoth
+);
+
+impl FormatAsRustCode for StyleTime {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        format!("StyleTime {{ ms: {} }}", self.ms)
+    }
+}
+
+impl FormatAsRustCode for StyleTimingFunction {
+    fn format_as_rust_code(&self, tabs: usize) -> String {
+        match self {
+            StyleTimingFunction::Ease => format!("StyleTimingFunction::Ease"),
+            StyleTimingFunction::Linear => format!("StyleTimingFunction::Linear"),
+            StyleTimingFunction::EaseIn => format!("StyleTimingFunction::EaseIn"),
+            StyleTimingFunction::EaseOut => format!("StyleTimingFunction::EaseOut"),
+            StyleTimingFunction::EaseInOut => format!("StyleTimingFunction::EaseInOut"),
+            // control points are usually fractional, so print the exact fixed-point number
+            StyleTimingFunction::CubicBezier(c) => format!(
+                "StyleTimingFunction::CubicBezier(StyleCubicBezier {{ x1: FloatValue {{ number: {} }}, y1: FloatValue {{ number: {} }}, x2: FloatValue {{ number: {} }}, y2: FloatValue {{ number: {} }} }})",
+                c.x1.number, c.y1.number, c.x2.number, c.y2.number
+            ),
+            StyleTimingFunction::Steps(s) => format!(
+                "StyleTimingFunction::Steps(StyleSteps {{ count: {}, position: {} }})",
+                s.count, s.position.format_as_rust_code(tabs)
+            ),
+        }
+    }
+}
+
+impl FormatAsRustCode for StyleTransitionProperty {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        match self {
+            StyleTransitionProperty::All => format!("StyleTransitionProperty::All"),
+            StyleTransitionProperty::Property(p) => format!("StyleTransitionProperty::Property(CssPropertyType::{})", format_css_property_type(p)),
+            StyleTransitionProperty::Shorthand(s) => format!("StyleTransitionProperty::Shorthand(CombinedCssPropertyType::{:?})", s),
+        }
+    }
+}
+
+impl FormatAsRustCode for StyleAnimationName {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        match self {
+            StyleAnimationName::None => format!("StyleAnimationName::None"),
+            StyleAnimationName::Name(s) => format!("StyleAnimationName::Name(STRING_{})", s.get_hash()),
+        }
+    }
+}
+
+impl FormatAsRustCode for StyleAnimationIterationCount {
+    fn format_as_rust_code(&self, _tabs: usize) -> String {
+        match self {
+            StyleAnimationIterationCount::Infinite => format!("StyleAnimationIterationCount::Infinite"),
+            StyleAnimationIterationCount::Count(c) => format!("StyleAnimationIterationCount::Count(FloatValue {{ number: {} }})", c.number),
+        }
+    }
+}
+
+/// Returns the name of the enum variant, i.e. `"transition-delay"` => `"TransitionDelay"`
+/// (the `Debug` impl of `CssPropertyType` prints the CSS key instead)
+fn format_css_property_type(p: &CssPropertyType) -> String {
+    match p {
+        CssPropertyType::TextColor => String::from("TextColor"),
+        CssPropertyType::BackgroundContent => String::from("BackgroundContent"),
+        other => other.to_str()
+            .trim_start_matches("-azul-")
+            .split('-')
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                    None => String::new(),
+                }
+            })
+            .collect(),
+    }
+}
 
 fn format_style_filter(st: &StyleFilter, tabs: usize) -> String {
     let tabs_minus_one = String::from("    ").repeat(tabs);
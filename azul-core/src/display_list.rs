@@ -21,7 +21,7 @@ use crate::{
     app_resources::{
         ImageCache, RendererResources, AddImageMsg, ImageDescriptor,
         ImageKey, FontInstanceKey, PrimitiveFlags, GlTextureCache,
-        Epoch, ExternalImageId, GlyphOptions, LoadFontFn, ParseFontFn,
+        Epoch, ExternalImageId, GlyphOptions, LoadFontFn, ParseFontFn, FontHasGlyphFn,
         ResourceUpdate, IdNamespace, TransformKey, OpacityKey,
     },
     styled_dom::{DomId, NodeHierarchyItemId, StyledDom, ContentGroup},
@@ -36,6 +36,8 @@ pub type GlyphIndex = u32;
 #[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
 pub struct GlyphInstance {
     pub index: GlyphIndex,
+    /// Font of the glyph, see `RendererResources::get_font_fallback_keys`
+    pub font_index: usize,
     pub point: LogicalPosition,
     pub size: LogicalSize,
 }
@@ -595,6 +597,7 @@ pub struct RenderCallbacks {
     pub layout_fn: LayoutFn,
     pub load_font_fn: LoadFontFn,
     pub parse_font_fn: ParseFontFn,
+    pub font_has_glyph_fn: FontHasGlyphFn,
 }
 
 impl SolvedLayout {
@@ -892,23 +895,62 @@ pub fn displaylist_handle_rect<'a>(
 
                 if !layouted_glyphs.glyphs.is_empty() {
 
-                    let font_instance_key = word_positions.1;
-                    let text_color = layout_result.styled_dom.get_css_property_cache()
+                    use crate::app_resources::font_size_to_au;
+                    use crate::styled_dom::StyleFontFamiliesHash;
+
+                    let css_property_cache = layout_result.styled_dom.get_css_property_cache();
+
+                    let text_color = css_property_cache
                     .get_text_color_or_default(&html_node, &rect_idx, &styled_node.state);
 
-                    let text_shadow = layout_result.styled_dom.get_css_property_cache()
+                    let text_shadow = css_property_cache
                     .get_text_shadow(&html_node, &rect_idx, &styled_node.state)
                     .and_then(|p| p.get_property())
                     .cloned();
 
-                    frame.content.push(LayoutRectContent::Text {
-                        text_shadow,
-                        glyphs: layouted_glyphs.glyphs,
-                        font_instance_key,
-                        color: text_color.inner,
-                        glyph_options: None,
-                        overflow: (overflow_horizontal_visible, overflow_vertical_visible),
-                    });
+                    // glyphs of fallback fonts have to be pushed as a separate text item
+                    // with the font instance of the fallback font
+                    let max_font_index = layouted_glyphs.glyphs.iter().map(|g| g.font_index).max().unwrap_or(0);
+
+                    let glyphs_by_font = if max_font_index == 0 {
+                        vec![(word_positions.1, layouted_glyphs.glyphs)]
+                    } else {
+                        let css_font_families = css_property_cache.get_font_id_or_default(&html_node, &rect_idx, &styled_node.state);
+                        let font_size = css_property_cache.get_font_size_or_default(&html_node, &rect_idx, &styled_node.state);
+                        let font_instance_keys = renderer_resources.get_font_fallback_instance_keys(
+                            &StyleFontFamiliesHash::new(css_font_families.as_ref()),
+                            font_size_to_au(font_size)
+                        );
+
+                        (0..=max_font_index).filter_map(|font_index| {
+                            // if the fallback font has no instance for the font size (i.e. the
+                            // font size was changed by a restyle), the glyphs are drawn with the
+                            // primary font - as .notdef, the glyph indices belong to the fallback font
+                            let (font_instance_key, instance_missing) = match font_index {
+                                0 => (word_positions.1, false),
+                                _ => match font_instance_keys.get(font_index).copied().flatten() {
+                                    Some(s) => (s, false),
+                                    None => (word_positions.1, true),
+                                },
+                            };
+                            let glyphs = layouted_glyphs.glyphs.iter()
+                                .filter(|g| g.font_index == font_index)
+                                .map(|g| if instance_missing { GlyphInstance { index: 0, font_index: 0, .. *g } } else { *g })
+                                .collect::<Vec<_>>();
+                            if glyphs.is_empty() { None } else { Some((font_instance_key, glyphs)) }
+                        }).collect()
+                    };
+
+                    for (font_instance_key, glyphs) in glyphs_by_font {
+                        frame.content.push(LayoutRectContent::Text {
+                            text_shadow: text_shadow.clone(),
+                            glyphs,
+                            font_instance_key,
+                            color: text_color.inner,
+                            glyph_options: None,
+                            overflow: (overflow_horizontal_visible, overflow_vertical_visible),
+                        });
+                    }
                 }
             }
         },
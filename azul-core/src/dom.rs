@@ -19,6 +19,7 @@ use crate::{
     callbacks::{
         Callback, CallbackType,
         IFrameCallback, IFrameCallbackType,
+        TooltipCallback, TooltipCallbackType,
         RefAny, OptionRefAny,
     },
     app_resources::{ImageMask, RendererResources, ImageCallback},
@@ -28,6 +29,7 @@ use crate::{
     },
     window::{Menu, OptionVirtualKeyCodeCombo},
     styled_dom::{StyledDom, NodeHierarchyItemId},
+    task::{Duration, SystemTimeDiff},
 };
 use azul_css::{
     FontRef, Css, OptionAzString,
@@ -210,11 +212,19 @@ pub enum On {
     VirtualKeyDown,
     /// A **virtual keycode** was release. See `VirtualKeyDown` for more info.
     VirtualKeyUp,
-    /// A file has been dropped on the element
+    /// The input method (IME) started composing text, equivalent to `oncompositionstart`.
+    /// Check `keyboard_state.ime_composition` to get the (not yet committed) preedit text.
+    CompositionStart,
+    /// The preedit text of the input method changed, equivalent to `oncompositionupdate`
+    CompositionUpdate,
+    /// The input method finished or cancelled the composition, equivalent to `oncompositionend`.
+    /// The committed text is sent as `TextInput` events.
+    CompositionEnd,
+    /// A file (or text) is being dragged over the element
     HoveredFile,
-    /// A file is being hovered on the element
+    /// A file (or text) has been dropped on the element
     DroppedFile,
-    /// A file was hovered, but has exited the window
+    /// A file (or text) was hovered, but has exited the window
     HoveredFileCancelled,
     /// Equivalent to `onfocus`
     FocusReceived,
@@ -331,6 +341,9 @@ impl From<On> for EventFilter {
             TextInput            => EventFilter::Focus(FocusEventFilter::TextInput),            // focus!
             VirtualKeyDown       => EventFilter::Window(WindowEventFilter::VirtualKeyDown),     // window!
             VirtualKeyUp         => EventFilter::Window(WindowEventFilter::VirtualKeyUp),       // window!
+            CompositionStart     => EventFilter::Focus(FocusEventFilter::CompositionStart),     // focus!
+            CompositionUpdate    => EventFilter::Focus(FocusEventFilter::CompositionUpdate),    // focus!
+            CompositionEnd       => EventFilter::Focus(FocusEventFilter::CompositionEnd),       // focus!
             HoveredFile          => EventFilter::Hover(HoverEventFilter::HoveredFile),
             DroppedFile          => EventFilter::Hover(HoverEventFilter::DroppedFile),
             HoveredFileCancelled => EventFilter::Hover(HoverEventFilter::HoveredFileCancelled),
@@ -361,6 +374,9 @@ pub enum HoverEventFilter {
     TextInput,
     VirtualKeyDown,
     VirtualKeyUp,
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
     HoveredFile,
     DroppedFile,
     HoveredFileCancelled,
@@ -390,6 +406,9 @@ impl HoverEventFilter {
             HoverEventFilter::TextInput => Some(FocusEventFilter::TextInput),
             HoverEventFilter::VirtualKeyDown => Some(FocusEventFilter::VirtualKeyDown),
             HoverEventFilter::VirtualKeyUp => Some(FocusEventFilter::VirtualKeyDown),
+            HoverEventFilter::CompositionStart => Some(FocusEventFilter::CompositionStart),
+            HoverEventFilter::CompositionUpdate => Some(FocusEventFilter::CompositionUpdate),
+            HoverEventFilter::CompositionEnd => Some(FocusEventFilter::CompositionEnd),
             HoverEventFilter::HoveredFile => None,
             HoverEventFilter::DroppedFile => None,
             HoverEventFilter::HoveredFileCancelled => None,
@@ -444,6 +463,9 @@ pub enum FocusEventFilter {
     TextInput,
     VirtualKeyDown,
     VirtualKeyUp,
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
     FocusReceived,
     FocusLost,
 }
@@ -470,6 +492,9 @@ pub enum WindowEventFilter {
     TextInput,
     VirtualKeyDown,
     VirtualKeyUp,
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
     HoveredFile,
     DroppedFile,
     HoveredFileCancelled,
@@ -505,6 +530,9 @@ impl WindowEventFilter {
             WindowEventFilter::TextInput => Some(HoverEventFilter::TextInput),
             WindowEventFilter::VirtualKeyDown => Some(HoverEventFilter::VirtualKeyDown),
             WindowEventFilter::VirtualKeyUp => Some(HoverEventFilter::VirtualKeyDown),
+            WindowEventFilter::CompositionStart => Some(HoverEventFilter::CompositionStart),
+            WindowEventFilter::CompositionUpdate => Some(HoverEventFilter::CompositionUpdate),
+            WindowEventFilter::CompositionEnd => Some(HoverEventFilter::CompositionEnd),
             WindowEventFilter::HoveredFile => Some(HoverEventFilter::HoveredFile),
             WindowEventFilter::DroppedFile => Some(HoverEventFilter::DroppedFile),
             WindowEventFilter::HoveredFileCancelled => Some(HoverEventFilter::HoveredFileCancelled),
@@ -768,6 +796,8 @@ impl Hash for NodeData {
             if let Some(c) = ext.accessibility.as_ref() { c.hash(state); }
             if let Some(c) = ext.menu_bar.as_ref() { c.hash(state); }
             if let Some(c) = ext.context_menu.as_ref() { c.hash(state); }
+            if let Some(c) = ext.accessibility_actions.as_ref() { c.hash(state); }
+            if let Some(c) = ext.tooltip.as_ref() { c.hash(state); }
         }
     }
 }
@@ -786,10 +816,73 @@ pub struct NodeDataExt {
     pub(crate) menu_bar: Option<Box<Menu>>,
     /// Context menu that should be opened when the item is left-clicked
     pub(crate) context_menu: Option<Box<Menu>>,
+    /// Actions that assistive technologies can invoke on this node (AT-SPI "Action" interface)
+    pub(crate) accessibility_actions: Option<AccessibilityActionVec>,
+    /// Tooltip that is shown when the mouse rests on this node
+    pub(crate) tooltip: Option<Box<Tooltip>>,
 
     // ... insert further API extensions here...
 }
 
+/// Tooltip of a node, see `NodeData.set_tooltip()`
+///
+/// The tooltip is shown once the mouse has rested on the node for `delay`
+/// and hidden again when the mouse leaves the node, a mouse button or a key
+/// is pressed. It is displayed in a separate, always-on-top popup window,
+/// so it renders above all content of the window.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub struct Tooltip {
+    /// What to display in the tooltip
+    pub content: TooltipContent,
+    /// How long the mouse has to rest on the node before the tooltip is shown
+    pub delay: Duration,
+}
+
+impl Tooltip {
+
+    /// Default delay before a tooltip is shown (500ms)
+    pub const DEFAULT_DELAY: Duration = Duration::System(SystemTimeDiff::from_millis(500));
+
+    /// Creates a tooltip displaying plain text in the default tooltip style
+    #[inline]
+    pub fn text(text: AzString) -> Self {
+        Self { content: TooltipContent::Text(text), delay: Self::DEFAULT_DELAY }
+    }
+
+    /// Creates a tooltip whose content is rendered by a callback
+    #[inline]
+    pub fn dom(data: RefAny, callback: TooltipCallbackType) -> Self {
+        Self {
+            content: TooltipContent::Dom(TooltipDom { data, callback: TooltipCallback { cb: callback } }),
+            delay: Self::DEFAULT_DELAY,
+        }
+    }
+
+    #[inline]
+    pub fn set_delay(&mut self, delay: Duration) { self.delay = delay; }
+    #[inline]
+    pub fn with_delay(mut self, delay: Duration) -> Self { self.set_delay(delay); self }
+}
+
+/// Content of a `Tooltip`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C, u8)]
+pub enum TooltipContent {
+    /// Plain text, rendered in the default tooltip style
+    Text(AzString),
+    /// Custom content, rendered by a callback
+    Dom(TooltipDom),
+}
+
+/// Callback + data that renders the content of a `Tooltip`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub struct TooltipDom {
+    pub data: RefAny,
+    pub callback: TooltipCallback,
+}
+
 /// Accessibility information (MSAA wrapper). See `NodeData.set_accessibility_info()`
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[repr(C)]
@@ -920,6 +1013,103 @@ impl_vec_eq!(AccessibilityState, AccessibilityStateVec);
 impl_vec_ord!(AccessibilityState, AccessibilityStateVec);
 impl_vec_hash!(AccessibilityState, AccessibilityStateVec);
 
+impl AccessibilityInfo {
+    /// Creates an empty `AccessibilityInfo` with the given role
+    pub fn new(role: AccessibilityRole) -> Self {
+        Self {
+            name: None.into(),
+            value: None.into(),
+            role,
+            states: AccessibilityStateVec::from_const_slice(&[]),
+            accelerator: None.into(),
+            default_action: None.into(),
+        }
+    }
+    #[inline]
+    pub fn with_name(mut self, name: AzString) -> Self { self.name = Some(name).into(); self }
+    #[inline]
+    pub fn with_value(mut self, value: AzString) -> Self { self.value = Some(value).into(); self }
+    #[inline]
+    pub fn with_states(mut self, states: AccessibilityStateVec) -> Self { self.states = states; self }
+    #[inline]
+    pub fn with_default_action(mut self, default_action: AzString) -> Self { self.default_action = Some(default_action).into(); self }
+    #[inline]
+    pub fn has_state(&self, state: AccessibilityState) -> bool { self.states.as_ref().contains(&state) }
+}
+
+/// Action that an assistive technology (screen reader, switch control, etc.)
+/// can trigger on a node. Actions are dispatched as callbacks on the node,
+/// see `WindowInternal::invoke_accessibility_action()`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[repr(C)]
+pub enum AccessibilityAction {
+    /// Activates the node, i.e. "click" for buttons, links and menu items
+    Default,
+    /// Moves the keyboard focus to this node
+    Focus,
+    /// Toggles a checkbox or a switch
+    Toggle,
+    /// Expands a tree item or a combo box
+    Expand,
+    /// Collapses a tree item or a combo box
+    Collapse,
+    /// Selects an item in a list, a tree or a tab bar
+    Select,
+    /// Increments the value of a slider or a spin button
+    Increment,
+    /// Decrements the value of a slider or a spin button
+    Decrement,
+}
+
+impl AccessibilityAction {
+    /// Returns the non-localized action name, as used by AT-SPI and UIA
+    pub fn get_name(&self) -> &'static str {
+        use self::AccessibilityAction::*;
+        match self {
+            Default => "click",
+            Focus => "focus",
+            Toggle => "toggle",
+            Expand => "expand",
+            Collapse => "collapse",
+            Select => "select",
+            Increment => "increment",
+            Decrement => "decrement",
+        }
+    }
+
+    /// Returns the callbacks that should be invoked for this action, in order of
+    /// preference: if the node has a callback for the first filter, the later
+    /// filters are ignored. Focus changes are handled by the window itself.
+    pub fn get_event_filters(&self) -> &'static [EventFilter] {
+        use self::AccessibilityAction::*;
+        const ACTIVATE: &[EventFilter] = &[
+            EventFilter::Component(ComponentEventFilter::DefaultAction),
+            EventFilter::Hover(HoverEventFilter::MouseUp),
+            EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+        ];
+        const SELECT: &[EventFilter] = &[
+            EventFilter::Component(ComponentEventFilter::Selected),
+            EventFilter::Component(ComponentEventFilter::DefaultAction),
+            EventFilter::Hover(HoverEventFilter::MouseUp),
+            EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+        ];
+        match self {
+            Default | Toggle | Expand | Collapse => ACTIVATE,
+            Select => SELECT,
+            Focus | Increment | Decrement => &[],
+        }
+    }
+}
+
+impl_vec!(AccessibilityAction, AccessibilityActionVec, AccessibilityActionVecDestructor);
+impl_vec_clone!(AccessibilityAction, AccessibilityActionVec, AccessibilityActionVecDestructor);
+impl_vec_debug!(AccessibilityAction, AccessibilityActionVec);
+impl_vec_partialeq!(AccessibilityAction, AccessibilityActionVec);
+impl_vec_partialord!(AccessibilityAction, AccessibilityActionVec);
+impl_vec_eq!(AccessibilityAction, AccessibilityActionVec);
+impl_vec_ord!(AccessibilityAction, AccessibilityActionVec);
+impl_vec_hash!(AccessibilityAction, AccessibilityActionVec);
+
 impl Clone for NodeData {
     #[inline]
     fn clone(&self) -> Self {
@@ -1168,9 +1358,13 @@ impl NodeData {
     #[inline]
     pub fn get_accessibility_info(&self) -> Option<&Box<AccessibilityInfo>> { self.extra.as_ref().and_then(|e| e.accessibility.as_ref()) }
     #[inline]
+    pub fn get_accessibility_actions(&self) -> &[AccessibilityAction] { self.extra.as_ref().and_then(|e| e.accessibility_actions.as_ref()).map(|a| a.as_ref()).unwrap_or(&[]) }
+    #[inline]
     pub fn get_menu_bar(&self) -> Option<&Box<Menu>> { self.extra.as_ref().and_then(|e| e.menu_bar.as_ref()) }
     #[inline]
     pub fn get_context_menu(&self) -> Option<&Box<Menu>> { self.extra.as_ref().and_then(|e| e.context_menu.as_ref()) }
+    #[inline]
+    pub fn get_tooltip(&self) -> Option<&Box<Tooltip>> { self.extra.as_ref().and_then(|e| e.tooltip.as_ref()) }
 
     #[inline(always)]
     pub fn set_node_type(&mut self, node_type: NodeType) { self.node_type = node_type; }
@@ -1195,6 +1389,20 @@ impl NodeData {
         .accessibility = Some(Box::new(accessibility_info));
     }
     #[inline]
+    pub fn set_accessibility_actions(&mut self, actions: AccessibilityActionVec) {
+        self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()))
+        .accessibility_actions = Some(actions);
+    }
+    #[inline]
+    pub fn add_accessibility_action(&mut self, action: AccessibilityAction) {
+        let ext = self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()));
+        let mut v = ext.accessibility_actions.take().map(|v| v.into_library_owned_vec()).unwrap_or_default();
+        if !v.contains(&action) {
+            v.push(action);
+        }
+        ext.accessibility_actions = Some(v.into());
+    }
+    #[inline]
     pub fn set_menu_bar(&mut self, menu_bar: Menu) {
         self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()))
         .menu_bar = Some(Box::new(menu_bar));
@@ -1204,12 +1412,32 @@ impl NodeData {
         self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()))
         .context_menu = Some(Box::new(context_menu));
     }
+    #[inline]
+    pub fn set_tooltip(&mut self, tooltip: Tooltip) {
+        self.extra.get_or_insert_with(|| Box::new(NodeDataExt::default()))
+        .tooltip = Some(Box::new(tooltip));
+    }
 
     #[inline]
     pub fn with_context_menu(mut self, context_menu: Menu) -> Self {
         self.set_context_menu(context_menu);
         self
     }
+    #[inline]
+    pub fn with_tooltip(mut self, tooltip: Tooltip) -> Self {
+        self.set_tooltip(tooltip);
+        self
+    }
+    #[inline]
+    pub fn with_accessibility_info(mut self, accessibility_info: AccessibilityInfo) -> Self {
+        self.set_accessibility_info(accessibility_info);
+        self
+    }
+    #[inline]
+    pub fn with_accessibility_actions(mut self, actions: AccessibilityActionVec) -> Self {
+        self.set_accessibility_actions(actions);
+        self
+    }
 
     #[inline]
     pub fn add_callback(&mut self, event: EventFilter, data: RefAny, callback: CallbackType) {
@@ -1477,6 +1705,21 @@ impl Dom {
         self.root.set_context_menu(context_menu);
         self
     }
+    #[inline]
+    pub fn with_tooltip(mut self, tooltip: Tooltip) -> Self {
+        self.root.set_tooltip(tooltip);
+        self
+    }
+    #[inline]
+    pub fn with_accessibility_info(mut self, accessibility_info: AccessibilityInfo) -> Self {
+        self.root.set_accessibility_info(accessibility_info);
+        self
+    }
+    #[inline]
+    pub fn with_accessibility_actions(mut self, actions: AccessibilityActionVec) -> Self {
+        self.root.set_accessibility_actions(actions);
+        self
+    }
 
     fn fixup_children_estimated(&mut self) -> usize {
         if self.children.is_empty() {
@@ -56,6 +56,12 @@ pub mod ui_solver;
 pub mod window;
 /// Window state handling / synchronization
 pub mod window_state;
+/// Accessibility tree, built from the layout results of a window (for screen readers)
+pub mod accessibility;
+/// CSS transitions and `@keyframes` animations, interpolated once per frame
+pub mod animation;
+/// Tooltips, shown in a popup window once the mouse rests on a node
+pub mod tooltip;
 
 // Typedef for possible faster implementation of hashing
 pub type FastHashMap<T, U> = alloc::collections::BTreeMap<T, U>;
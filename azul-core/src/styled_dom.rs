@@ -5,12 +5,13 @@ use alloc::string::String;
 use alloc::collections::btree_map::BTreeMap;
 use azul_css_parser::CssApiWrapper;
 use azul_css::{
-    Css, CssPath, CssProperty, CssPropertyType, AzString,
+    Css, CssPath, CssProperty, CssPropertyType, AzString, CssMediaEnvironment,
 
     StyleBackgroundContentVecValue, StyleBackgroundPositionVecValue,
     StyleBackgroundSizeVecValue, StyleBackgroundRepeatVecValue,
     StyleFontSizeValue, StyleFontFamily, StyleFontFamilyVec, StyleFontFamilyVecValue,
-    StyleTextAlignValue, StyleLineHeightValue, StyleLetterSpacingValue,
+    StyleTextAlignValue, StyleDirectionValue, StyleWordBreakValue,
+    StyleOverflowWrapValue, StyleHyphensValue, StyleLineHeightValue, StyleLetterSpacingValue,
     StyleWordSpacingValue, StyleTabWidthValue, StyleCursorValue,
     StyleBoxShadowValue, StyleBorderTopColorValue, StyleBorderLeftColorValue,
     StyleBorderRightColorValue, StyleBorderBottomColorValue,
@@ -34,6 +35,12 @@ use azul_css::{
     LayoutOverflowValue, LayoutFlexDirectionValue, LayoutFlexWrapValue,
     LayoutFlexGrowValue, LayoutFlexShrinkValue, LayoutJustifyContentValue,
     LayoutAlignItemsValue, LayoutAlignContentValue,
+    GridTrackSizingVecValue, GridTrackSizingValue, LayoutGridAutoFlowValue,
+    GridPlacementValue, LayoutRowGapValue, LayoutColumnGapValue,
+    StyleTransitionPropertyVecValue, StyleTimeVecValue, StyleTimingFunctionVecValue,
+    StyleAnimationNameVecValue, StyleAnimationIterationCountVecValue,
+    StyleAnimationDirectionVecValue, StyleAnimationFillModeVecValue,
+    StyleAnimationPlayStateVecValue, CssKeyframes,
 };
 use crate::{
     FastBTreeSet, FastHashMap,
@@ -189,6 +196,20 @@ pub struct CssPropertyCache {
     // properties that were overridden in callbacks (not specific to any node state)
     pub user_overridden_properties: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
 
+    // interpolated values of running transitions / animations (override everything else)
+    pub animated_properties: BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
+
+    // @keyframes blocks of the current stylesheet, indexed by name
+    pub keyframes: BTreeMap<AzString, CssKeyframes>,
+
+    // stylesheet of the last restyle if it contains @media blocks, necessary to
+    // re-evaluate the @media blocks when the window size, DPI or theme changes
+    pub media_css: Css,
+    // environment that the @media blocks are evaluated against
+    pub media_environment: CssMediaEnvironment,
+    // which @media blocks matched during the last restyle
+    pub media_query_results: Vec<bool>,
+
     // non-default CSS properties that were cascaded from the parent
     pub cascaded_normal_props:    BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
     pub cascaded_hover_props:     BTreeMap<NodeId, BTreeMap<CssPropertyType, CssProperty>>,
@@ -220,6 +241,31 @@ impl CssPropertyCache {
         use azul_css::CssPathPseudoSelector::*;
         use rayon::prelude::*;
 
+        if css.has_media_rules() {
+            self.media_css = css.clone();
+            self.media_query_results = css.evaluate_media_queries(&self.media_environment);
+            css.apply_media_queries(&self.media_environment);
+        } else {
+            self.media_css = Css::empty();
+            self.media_query_results = Vec::new();
+        }
+
+        // restyling replaces all styles of the previous stylesheet
+        self.css_normal_props.clear();
+        self.css_hover_props.clear();
+        self.css_active_props.clear();
+        self.css_focus_props.clear();
+        self.cascaded_normal_props.clear();
+        self.cascaded_hover_props.clear();
+        self.cascaded_active_props.clear();
+        self.cascaded_focus_props.clear();
+
+        self.keyframes = css.stylesheets
+        .iter()
+        .flat_map(|s| s.keyframes.iter())
+        .map(|k| (k.name.clone(), k.clone()))
+        .collect();
+
         let css_is_empty = css.is_empty();
 
         if !css_is_empty {
@@ -461,6 +507,12 @@ impl CssPropertyCache {
                     break;
                 }
 
+                // check for tooltip
+                if node_data.get_tooltip().is_some() {
+                    node_should_have_tag = true;
+                    break;
+                }
+
                 // check for :hover
                 let node_has_hover_props = node_data.inline_css_props.as_ref().iter()
                 .any(|p| match p { NodeDataInlineCssProperty::Hover(_) => true, _ => false }) ||
@@ -551,6 +603,10 @@ impl CssPropertyCache {
         if let Some(p) = self.get_font_family(&node_data, node_id, node_state) { s.push_str(&format!("font-family: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_text_color(&node_data, node_id, node_state) { s.push_str(&format!("color: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_text_align(&node_data, node_id, node_state) { s.push_str(&format!("text-align: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_direction(&node_data, node_id, node_state) { s.push_str(&format!("direction: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_word_break(&node_data, node_id, node_state) { s.push_str(&format!("word-break: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_overflow_wrap(&node_data, node_id, node_state) { s.push_str(&format!("overflow-wrap: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_hyphens(&node_data, node_id, node_state) { s.push_str(&format!("hyphens: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_line_height(&node_data, node_id, node_state) { s.push_str(&format!("line-height: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_letter_spacing(&node_data, node_id, node_state) { s.push_str(&format!("letter-spacing: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_word_spacing(&node_data, node_id, node_state) { s.push_str(&format!("word-spacing: {};", p.get_css_value_fmt())); }
@@ -612,6 +668,27 @@ impl CssPropertyCache {
         if let Some(p) = self.get_justify_content(&node_data, node_id, node_state) { s.push_str(&format!("justify-content: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_align_items(&node_data, node_id, node_state) { s.push_str(&format!("align-items: {};", p.get_css_value_fmt())); }
         if let Some(p) = self.get_align_content(&node_data, node_id, node_state) { s.push_str(&format!("align-content: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_template_columns(&node_data, node_id, node_state) { s.push_str(&format!("grid-template-columns: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_template_rows(&node_data, node_id, node_state) { s.push_str(&format!("grid-template-rows: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_auto_columns(&node_data, node_id, node_state) { s.push_str(&format!("grid-auto-columns: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_auto_rows(&node_data, node_id, node_state) { s.push_str(&format!("grid-auto-rows: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_auto_flow(&node_data, node_id, node_state) { s.push_str(&format!("grid-auto-flow: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_column(&node_data, node_id, node_state) { s.push_str(&format!("grid-column: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_grid_row(&node_data, node_id, node_state) { s.push_str(&format!("grid-row: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_row_gap(&node_data, node_id, node_state) { s.push_str(&format!("row-gap: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_column_gap(&node_data, node_id, node_state) { s.push_str(&format!("column-gap: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_transition_property(&node_data, node_id, node_state) { s.push_str(&format!("transition-property: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_transition_duration(&node_data, node_id, node_state) { s.push_str(&format!("transition-duration: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_transition_timing_function(&node_data, node_id, node_state) { s.push_str(&format!("transition-timing-function: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_transition_delay(&node_data, node_id, node_state) { s.push_str(&format!("transition-delay: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_name(&node_data, node_id, node_state) { s.push_str(&format!("animation-name: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_duration(&node_data, node_id, node_state) { s.push_str(&format!("animation-duration: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_timing_function(&node_data, node_id, node_state) { s.push_str(&format!("animation-timing-function: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_delay(&node_data, node_id, node_state) { s.push_str(&format!("animation-delay: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_iteration_count(&node_data, node_id, node_state) { s.push_str(&format!("animation-iteration-count: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_direction(&node_data, node_id, node_state) { s.push_str(&format!("animation-direction: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_fill_mode(&node_data, node_id, node_state) { s.push_str(&format!("animation-fill-mode: {};", p.get_css_value_fmt())); }
+        if let Some(p) = self.get_animation_play_state(&node_data, node_id, node_state) { s.push_str(&format!("animation-play-state: {};", p.get_css_value_fmt())); }
         s
     }
 }
@@ -662,6 +739,11 @@ impl CssPropertyCache {
         Self {
             node_count,
             user_overridden_properties: BTreeMap::new(),
+            animated_properties: BTreeMap::new(),
+            keyframes: BTreeMap::new(),
+            media_css: Css::empty(),
+            media_environment: CssMediaEnvironment::default(),
+            media_query_results: Vec::new(),
 
             cascaded_normal_props: BTreeMap::new(),
             cascaded_hover_props: BTreeMap::new(),
@@ -686,6 +768,7 @@ impl CssPropertyCache {
         }};}
 
         append_css_property_vec!(user_overridden_properties);
+        append_css_property_vec!(animated_properties);
         append_css_property_vec!(cascaded_normal_props);
         append_css_property_vec!(cascaded_hover_props);
         append_css_property_vec!(cascaded_active_props);
@@ -695,6 +778,21 @@ impl CssPropertyCache {
         append_css_property_vec!(css_active_props);
         append_css_property_vec!(css_focus_props);
 
+        let mut keyframes = BTreeMap::new();
+        core::mem::swap(&mut keyframes, &mut other.keyframes);
+        for (name, k) in keyframes.into_iter() {
+            self.keyframes.entry(name).or_insert(k);
+        }
+
+        // the stylesheets of both DOMs are re-applied to the whole DOM when the media changes
+        if other.media_css.has_media_rules() {
+            let mut stylesheets = core::mem::replace(&mut self.media_css.stylesheets, Vec::new().into()).into_library_owned_vec();
+            let other_stylesheets = core::mem::replace(&mut other.media_css.stylesheets, Vec::new().into()).into_library_owned_vec();
+            stylesheets.extend(other_stylesheets);
+            self.media_css.stylesheets = stylesheets.into();
+            self.media_query_results.append(&mut other.media_query_results);
+        }
+
         self.node_count += other.node_count;
     }
 
@@ -758,6 +856,26 @@ impl CssPropertyCache {
         node_state: &StyledNodeState,
         css_property_type: &CssPropertyType
     ) -> Option<&CssProperty> {
+
+        // Running transitions and animations override all other values
+        if let Some(p) = self.animated_properties
+            .get(node_id)
+            .and_then(|n| n.get(css_property_type)) {
+            return Some(p);
+        }
+
+        self.get_non_animated_property(node_data, node_id, node_state, css_property_type)
+    }
+
+    /// Same as `get_property`, but ignores the values of running transitions and animations,
+    /// i.e. returns the value that the property will have once all animations have finished
+    pub fn get_non_animated_property<'a>(
+        &'a self,
+        node_data: &'a NodeData,
+        node_id: &NodeId,
+        node_state: &StyledNodeState,
+        css_property_type: &CssPropertyType
+    ) -> Option<&CssProperty> {
         // NOTE: This function is slow, but it is going to be called on every
         // node in parallel, so it should be rather fast in the end
 
@@ -896,6 +1014,18 @@ impl CssPropertyCache {
     pub fn get_text_align<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTextAlignValue> {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::TextAlign).and_then(|p| p.as_text_align())
     }
+    pub fn get_direction<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleDirectionValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::Direction).and_then(|p| p.as_text_direction())
+    }
+    pub fn get_word_break<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleWordBreakValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::WordBreak).and_then(|p| p.as_word_break())
+    }
+    pub fn get_overflow_wrap<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleOverflowWrapValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::OverflowWrap).and_then(|p| p.as_overflow_wrap())
+    }
+    pub fn get_hyphens<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleHyphensValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::Hyphens).and_then(|p| p.as_hyphens())
+    }
     pub fn get_line_height<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleLineHeightValue> {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::LineHeight).and_then(|p| p.as_line_height())
     }
@@ -1079,6 +1209,69 @@ impl CssPropertyCache {
     pub fn get_align_content<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutAlignContentValue> {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::AlignContent).and_then(|p| p.as_align_content())
     }
+    pub fn get_grid_template_columns<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a GridTrackSizingVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridTemplateColumns).and_then(|p| p.as_grid_template_columns())
+    }
+    pub fn get_grid_template_rows<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a GridTrackSizingVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridTemplateRows).and_then(|p| p.as_grid_template_rows())
+    }
+    pub fn get_grid_auto_columns<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a GridTrackSizingValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridAutoColumns).and_then(|p| p.as_grid_auto_columns())
+    }
+    pub fn get_grid_auto_rows<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a GridTrackSizingValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridAutoRows).and_then(|p| p.as_grid_auto_rows())
+    }
+    pub fn get_grid_auto_flow<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutGridAutoFlowValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridAutoFlow).and_then(|p| p.as_grid_auto_flow())
+    }
+    pub fn get_grid_column<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a GridPlacementValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridColumn).and_then(|p| p.as_grid_column())
+    }
+    pub fn get_grid_row<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a GridPlacementValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::GridRow).and_then(|p| p.as_grid_row())
+    }
+    pub fn get_row_gap<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutRowGapValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::RowGap).and_then(|p| p.as_row_gap())
+    }
+    pub fn get_column_gap<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a LayoutColumnGapValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::ColumnGap).and_then(|p| p.as_column_gap())
+    }
+    pub fn get_transition_property<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTransitionPropertyVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionProperty).and_then(|p| p.as_transition_property())
+    }
+    pub fn get_transition_duration<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTimeVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionDuration).and_then(|p| p.as_transition_duration())
+    }
+    pub fn get_transition_timing_function<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTimingFunctionVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionTimingFunction).and_then(|p| p.as_transition_timing_function())
+    }
+    pub fn get_transition_delay<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTimeVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::TransitionDelay).and_then(|p| p.as_transition_delay())
+    }
+    pub fn get_animation_name<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationNameVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationName).and_then(|p| p.as_animation_name())
+    }
+    pub fn get_animation_duration<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTimeVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationDuration).and_then(|p| p.as_animation_duration())
+    }
+    pub fn get_animation_timing_function<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTimingFunctionVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationTimingFunction).and_then(|p| p.as_animation_timing_function())
+    }
+    pub fn get_animation_delay<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleTimeVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationDelay).and_then(|p| p.as_animation_delay())
+    }
+    pub fn get_animation_iteration_count<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationIterationCountVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationIterationCount).and_then(|p| p.as_animation_iteration_count())
+    }
+    pub fn get_animation_direction<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationDirectionVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationDirection).and_then(|p| p.as_animation_direction())
+    }
+    pub fn get_animation_fill_mode<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationFillModeVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationFillMode).and_then(|p| p.as_animation_fill_mode())
+    }
+    pub fn get_animation_play_state<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleAnimationPlayStateVecValue> {
+        self.get_property(node_data, node_id, node_state, &CssPropertyType::AnimationPlayState).and_then(|p| p.as_animation_play_state())
+    }
     pub fn get_mix_blend_mode<'a>(&'a self, node_data: &'a NodeData, node_id: &NodeId, node_state: &StyledNodeState) -> Option<&'a StyleMixBlendModeValue> {
         self.get_property(node_data, node_id, node_state, &CssPropertyType::MixBlendMode).and_then(|p| p.as_mix_blend_mode())
     }
@@ -1867,6 +2060,50 @@ impl StyledDom {
         map
     }
 
+    /// Scans the text nodes for all characters that are rendered with each set of font families
+    /// (together with the font sizes of the font families), used for searching fallback fonts
+    #[cfg(feature = "multithreading")]
+    pub(crate) fn scan_for_font_fallback_chars(
+        &self,
+    ) -> FastHashMap<StyleFontFamiliesHash, (StyleFontFamilyVec, FastBTreeSet<Au>, FastBTreeSet<u32>)> {
+
+        use crate::dom::NodeType::*;
+        use crate::app_resources::font_size_to_au;
+
+        let mut map = FastHashMap::default();
+
+        for (node_id, node_data) in self.node_data.as_ref().iter().enumerate() {
+            let node_id = NodeId::new(node_id);
+            let text = match node_data.get_node_type() {
+                Text(t) => t,
+                _ => continue,
+            };
+
+            let node_state = &self.styled_nodes.as_container()[node_id].state;
+
+            let css_font_ids = self.get_css_property_cache()
+            .get_font_id_or_default(&node_data, &node_id, node_state);
+
+            let font_size = self.get_css_property_cache()
+            .get_font_size_or_default(&node_data, &node_id, node_state);
+
+            let style_font_families_hash = StyleFontFamiliesHash::new(css_font_ids.as_ref());
+
+            let (_, font_sizes, chars) = map
+            .entry(style_font_families_hash)
+            .or_insert_with(|| (css_font_ids, FastBTreeSet::default(), FastBTreeSet::default()));
+
+            font_sizes.insert(font_size_to_au(font_size));
+            chars.extend(
+                text.as_str().chars()
+                .filter(|c| !c.is_whitespace() && !c.is_control())
+                .map(|c| c as u32)
+            );
+        }
+
+        map
+    }
+
     /// Scans the display list for all image keys
     #[cfg(feature = "multithreading")]
     pub(crate) fn scan_for_image_keys(
@@ -1994,8 +2231,8 @@ impl StyledDom {
             .into_iter()
             .filter_map(|prop| {
                 // calculate both the old and the new state
-                let old = css_property_cache.get_property(node_data, node_id, old_node_state, prop);
-                let new = css_property_cache.get_property(node_data, node_id, new_node_state, prop);
+                let old = css_property_cache.get_non_animated_property(node_data, node_id, old_node_state, prop);
+                let new = css_property_cache.get_non_animated_property(node_data, node_id, new_node_state, prop);
                 if old == new {
                     None
                 } else {
@@ -2076,8 +2313,8 @@ impl StyledDom {
             .into_iter()
             .filter_map(|prop| {
                 // calculate both the old and the new state
-                let old = css_property_cache.get_property(node_data, node_id, old_node_state, prop);
-                let new = css_property_cache.get_property(node_data, node_id, new_node_state, prop);
+                let old = css_property_cache.get_non_animated_property(node_data, node_id, old_node_state, prop);
+                let new = css_property_cache.get_non_animated_property(node_data, node_id, new_node_state, prop);
                 if old == new {
                     None
                 } else {
@@ -2158,8 +2395,8 @@ impl StyledDom {
             .into_iter()
             .filter_map(|prop| {
                 // calculate both the old and the new state
-                let old = css_property_cache.get_property(node_data, node_id, old_node_state, prop);
-                let new = css_property_cache.get_property(node_data, node_id, new_node_state, prop);
+                let old = css_property_cache.get_non_animated_property(node_data, node_id, old_node_state, prop);
+                let new = css_property_cache.get_non_animated_property(node_data, node_id, new_node_state, prop);
                 if old == new {
                     None
                 } else {
@@ -2211,7 +2448,7 @@ impl StyledDom {
             .par_iter()
             .filter_map(|new_prop| {
 
-                let old_prop = css_property_cache.get_property(
+                let old_prop = css_property_cache.get_non_animated_property(
                     node_data,
                     node_id,
                     old_node_state,
@@ -2265,6 +2502,160 @@ impl StyledDom {
         map
     }
 
+    // Inserts the current values of running transitions / animations into
+    // self.animated_properties, a `CssProperty::Initial` removes the animated value again
+    #[must_use]
+    pub fn restyle_animated_properties(&mut self, node_id: &NodeId, new_properties: &[CssProperty])
+    -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+
+        let mut map = BTreeMap::default();
+
+        if new_properties.is_empty() {
+            return map;
+        }
+
+        let node_data = self.node_data.as_container();
+        let node_data = &node_data[*node_id];
+
+        let node_states = &self.styled_nodes.as_container();
+        let node_state = &node_states[*node_id].state;
+
+        let changes: Vec<ChangedCssProperty> = {
+            let css_property_cache = self.get_css_property_cache();
+
+            new_properties
+            .iter()
+            .filter_map(|new_prop| {
+
+                let prop_type = new_prop.get_type();
+                let old_prop = css_property_cache
+                    .get_property(node_data, node_id, node_state, &prop_type)
+                    .cloned()
+                    .unwrap_or(CssProperty::auto(prop_type));
+
+                // removing the animated value reveals the non-animated value
+                let new_prop = if new_prop.is_initial() {
+                    css_property_cache
+                    .get_non_animated_property(node_data, node_id, node_state, &prop_type)
+                    .cloned()
+                    .unwrap_or(CssProperty::auto(prop_type))
+                } else {
+                    new_prop.clone()
+                };
+
+                if old_prop == new_prop {
+                    None
+                } else {
+                    Some(ChangedCssProperty {
+                        previous_state: node_state.clone(),
+                        previous_prop: old_prop,
+                        current_state: node_state.clone(),
+                        current_prop: new_prop,
+                    })
+                }
+            }).collect()
+        };
+
+        let css_property_cache_mut = self.get_css_property_cache_mut();
+
+        for new_prop in new_properties.iter() {
+            if new_prop.is_initial() {
+                let mut should_remove_map = false;
+                if let Some(map) = css_property_cache_mut.animated_properties.get_mut(node_id) {
+                    map.remove(&new_prop.get_type());
+                    should_remove_map = map.is_empty();
+                }
+                if should_remove_map {
+                    css_property_cache_mut.animated_properties.remove(node_id);
+                }
+            } else {
+                css_property_cache_mut.animated_properties
+                .entry(*node_id)
+                .or_insert_with(|| BTreeMap::new())
+                .insert(new_prop.get_type(), new_prop.clone());
+            }
+        }
+
+        if !changes.is_empty() {
+            map.insert(*node_id, changes);
+        }
+
+        map
+    }
+
+    // Re-evaluates the @media blocks of the stylesheet against a new environment
+    // (window size, DPI, theme) and restyles the DOM if a different set of @media
+    // blocks matches now, returns the properties that changed because of the restyle
+    #[must_use]
+    pub fn restyle_media(&mut self, environment: &CssMediaEnvironment)
+    -> BTreeMap<NodeId, Vec<ChangedCssProperty>> {
+
+        use alloc::collections::btree_set::BTreeSet;
+
+        let mut map = BTreeMap::default();
+
+        let css_property_cache_mut = self.get_css_property_cache_mut();
+        css_property_cache_mut.media_environment = *environment;
+
+        if !css_property_cache_mut.media_css.has_media_rules() ||
+           css_property_cache_mut.media_css.evaluate_media_queries(environment) == css_property_cache_mut.media_query_results {
+            return map;
+        }
+
+        let old_cache = self.get_css_property_cache().clone();
+        self.restyle(CssApiWrapper { css: old_cache.media_css.clone() });
+
+        let new_cache = self.get_css_property_cache();
+        let node_data = self.node_data.as_container();
+        let node_states = self.styled_nodes.as_container();
+
+        for node_id in node_data.linear_iter() {
+
+            let node_state = &node_states[node_id].state;
+
+            let property_types = [&old_cache, new_cache].iter()
+            .flat_map(|cache| [
+                &cache.css_normal_props, &cache.css_hover_props,
+                &cache.css_active_props, &cache.css_focus_props,
+                &cache.cascaded_normal_props, &cache.cascaded_hover_props,
+                &cache.cascaded_active_props, &cache.cascaded_focus_props,
+            ])
+            .filter_map(|props| props.get(&node_id))
+            .flat_map(|props| props.keys().copied())
+            .collect::<BTreeSet<CssPropertyType>>();
+
+            let changes = property_types.into_iter().filter_map(|prop_type| {
+
+                let old_prop = old_cache
+                    .get_property(&node_data[node_id], &node_id, node_state, &prop_type)
+                    .cloned()
+                    .unwrap_or(CssProperty::auto(prop_type));
+
+                let new_prop = new_cache
+                    .get_property(&node_data[node_id], &node_id, node_state, &prop_type)
+                    .cloned()
+                    .unwrap_or(CssProperty::auto(prop_type));
+
+                if old_prop == new_prop {
+                    None
+                } else {
+                    Some(ChangedCssProperty {
+                        previous_state: node_state.clone(),
+                        previous_prop: old_prop,
+                        current_state: node_state.clone(),
+                        current_prop: new_prop,
+                    })
+                }
+            }).collect::<Vec<_>>();
+
+            if !changes.is_empty() {
+                map.insert(node_id, changes);
+            }
+        }
+
+        map
+    }
+
     /// Scans the `StyledDom` for iframe callbacks
     #[cfg(feature = "multithreading")]
     pub fn scan_for_iframe_callbacks(&self) -> Vec<NodeId> {
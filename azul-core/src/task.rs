@@ -31,7 +31,8 @@ use crate::{
     window::{
         FullWindowState, LogicalPosition,
         OptionLogicalPosition, RawWindowHandle, WindowState,
-        WindowCreateOptions
+        WindowCreateOptions, ClipboardSelection, ClipboardContent,
+        OptionClipboardContent,
     },
     styled_dom::{DomId, NodeHierarchyItemId},
     id_tree::NodeId,
@@ -917,14 +918,20 @@ impl Drop for ThreadReceiverInner {
 pub struct ExternalSystemCallbacks {
     pub create_thread_fn: CreateThreadCallback,
     pub get_system_time_fn: GetSystemTimeCallback,
+    pub get_clipboard_fn: GetClipboardCallback,
+    pub set_clipboard_fn: SetClipboardCallback,
 }
 
 #[cfg(feature = "std")]
 impl ExternalSystemCallbacks {
+    /// Note: the clipboard functions are placeholders, they are
+    /// replaced by the windowing backend when the `App` is created
     pub fn rust_internal() -> Self {
         Self {
             create_thread_fn: CreateThreadCallback { cb: create_thread_libstd },
             get_system_time_fn: GetSystemTimeCallback { cb: get_system_time_libstd },
+            get_clipboard_fn: GetClipboardCallback { cb: get_clipboard_unsupported },
+            set_clipboard_fn: SetClipboardCallback { cb: set_clipboard_unsupported },
         }
     }
 }
@@ -940,6 +947,25 @@ pub type GetSystemTimeCallbackType = extern "C" fn() -> Instant;
 #[repr(C)] pub struct GetSystemTimeCallback { pub cb: GetSystemTimeCallbackType }
 impl_callback!(GetSystemTimeCallback);
 
+/// Reads the content of the system clipboard in the given MIME type, the window handle
+/// is necessary because X11 sends the content to a window of the requesting application
+pub type GetClipboardCallbackType = extern "C" fn(&RawWindowHandle, ClipboardSelection, AzString) -> OptionClipboardContent;
+#[repr(C)] pub struct GetClipboardCallback { pub cb: GetClipboardCallbackType }
+impl_callback!(GetClipboardCallback);
+
+/// Sets the content of the system clipboard, returns whether the clipboard could be set
+pub type SetClipboardCallbackType = extern "C" fn(&RawWindowHandle, ClipboardSelection, ClipboardContent) -> bool;
+#[repr(C)] pub struct SetClipboardCallback { pub cb: SetClipboardCallbackType }
+impl_callback!(SetClipboardCallback);
+
+extern "C" fn get_clipboard_unsupported(_: &RawWindowHandle, _: ClipboardSelection, _: AzString) -> OptionClipboardContent {
+    OptionClipboardContent::None
+}
+
+extern "C" fn set_clipboard_unsupported(_: &RawWindowHandle, _: ClipboardSelection, _: ClipboardContent) -> bool {
+    false
+}
+
 // function called to check if the thread has finished
 pub type CheckThreadFinishedCallbackType = extern "C" fn(/* dropcheck */ *const c_void) -> bool;
 #[repr(C)] pub struct CheckThreadFinishedCallback { pub cb: CheckThreadFinishedCallbackType }
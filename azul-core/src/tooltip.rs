@@ -0,0 +1,525 @@
+//! Tooltips of DOM nodes, see `NodeData::set_tooltip()`
+//!
+//! When the mouse enters a node with a `Tooltip`, `CallbacksOfHitTest::call`
+//! starts a `Timer` that is attached to the node and polls the window state.
+//! Once the mouse has rested on the node for the tooltip delay, the timer
+//! opens the tooltip in a borderless, always-on-top popup window, so that
+//! the tooltip renders above all content, even inside of clipped scroll frames.
+//! Leaving the node, pressing a mouse button or pressing a key closes it again.
+//!
+//! Timers that are attached to a node are stopped when the DOM is recreated,
+//! so the popup window runs its own timer and closes itself as soon as the
+//! node timer stops polling.
+
+use alloc::vec::Vec;
+use azul_css::AzString;
+use azul_css_parser::CssApiWrapper;
+use crate::{
+    callbacks::{
+        Callback, CallbackInfo, DomNodeId, LayoutCallback, LayoutCallbackInfo,
+        MarshaledLayoutCallback, MarshaledLayoutCallbackInner,
+        RefAny, TimerCallbackInfo, TimerCallbackReturn, Update,
+    },
+    dom::{Dom, Tooltip, TooltipContent},
+    styled_dom::StyledDom,
+    task::{Duration, Instant, SystemTimeDiff, TerminateTimer, Timer},
+    window::{
+        CursorPosition, FullWindowState, Monitor, PhysicalPositionI32,
+        VirtualKeyCode, WindowCreateOptions, WindowPosition,
+    },
+};
+
+/// How often the node timer checks whether the tooltip should be shown or hidden
+const TOOLTIP_POLL_MS: u64 = 50;
+/// The popup closes itself if the node timer hasn't polled for this long
+const TOOLTIP_HEARTBEAT_TIMEOUT_MS: u64 = 500;
+/// Distance between the cursor and the top edge of the tooltip (logical pixels)
+const TOOLTIP_CURSOR_OFFSET: f32 = 20.0;
+/// Initial size of the popup window, corrected once the content is laid out
+const TOOLTIP_DEFAULT_WIDTH: f32 = 200.0;
+const TOOLTIP_DEFAULT_HEIGHT: f32 = 24.0;
+
+const TOOLTIP_TEXT_STYLE: &str = "
+    padding: 3px 6px;
+    font-size: 12px;
+    font-family: sans-serif;
+    color: #000000;
+    background: #ffffe1;
+    border: 1px solid #767676;
+";
+
+/// State of the timer that is attached to the hovered node
+struct TooltipTimerState {
+    tooltip: Tooltip,
+    node: DomNodeId,
+    /// When the mouse entered the node
+    hover_start: Instant,
+    /// Keys that were already held down when the mouse entered the node
+    keys_at_start: Vec<VirtualKeyCode>,
+    /// Shared with the popup window (`TooltipPopup`), `None` while the tooltip is hidden
+    popup: Option<RefAny>,
+}
+
+/// State shared between the node timer and the popup window
+struct TooltipPopup {
+    content: TooltipContent,
+    /// Position of the cursor on the screen, in physical pixels
+    cursor: PhysicalPositionI32,
+    /// Set to false by the node timer when the tooltip should be hidden
+    is_open: bool,
+    /// Last time the node timer polled
+    heartbeat: Instant,
+}
+
+/// Starts the tooltip timer for the node that the mouse just entered
+pub(crate) fn start_tooltip_timer(info: &mut CallbackInfo, tooltip: &Tooltip) {
+
+    let window_state = info.internal_get_current_window_state();
+    if window_state.mouse_state.mouse_down() {
+        return;
+    }
+
+    let keys_at_start = window_state.keyboard_state.pressed_virtual_keycodes.as_ref().to_vec();
+
+    let state = TooltipTimerState {
+        tooltip: tooltip.clone(),
+        node: info.get_hit_node(),
+        hover_start: info.get_current_time(),
+        keys_at_start,
+        popup: None,
+    };
+
+    let mut timer = Timer::new(RefAny::new(state), on_tooltip_timer, info.get_system_time_fn())
+        .with_interval(Duration::System(SystemTimeDiff::from_millis(TOOLTIP_POLL_MS)));
+    timer.node_id = Some(info.get_hit_node()).into();
+
+    info.start_timer(timer);
+}
+
+extern "C"
+fn on_tooltip_timer(data: &mut RefAny, info: &mut TimerCallbackInfo) -> TimerCallbackReturn {
+
+    let terminate = TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: TerminateTimer::Terminate,
+    };
+
+    let mut state = match data.downcast_mut::<TooltipTimerState>() {
+        Some(s) => s,
+        None => return terminate,
+    };
+
+    let now = info.callback_info.get_current_time();
+
+    let window_state = info.callback_info.internal_get_current_window_state();
+    if should_dismiss_tooltip(window_state, state.node, &state.keys_at_start) {
+        if let Some(mut popup) = state.popup.take() {
+            if let Some(mut popup) = popup.downcast_mut::<TooltipPopup>() {
+                popup.is_open = false;
+            }
+        }
+        return terminate;
+    }
+
+    match state.popup.as_mut() {
+        Some(popup) => {
+            if let Some(mut popup) = popup.downcast_mut::<TooltipPopup>() {
+                popup.heartbeat = now;
+            }
+        },
+        None => {
+            if now.duration_since(&state.hover_start).greater_than(&state.tooltip.delay) {
+                match open_tooltip_popup(&state.tooltip, now, &mut info.callback_info) {
+                    Some(popup) => state.popup = Some(popup),
+                    None => return terminate,
+                }
+            }
+        },
+    }
+
+    TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: TerminateTimer::Continue,
+    }
+}
+
+/// Returns whether the mouse left the node, a mouse button or a new key was pressed
+fn should_dismiss_tooltip(
+    window_state: &FullWindowState,
+    node: DomNodeId,
+    keys_at_start: &[VirtualKeyCode],
+) -> bool {
+
+    let node_is_hovered = node.node.into_crate_internal().map(|node_id| {
+        window_state.last_hit_test.hovered_nodes
+        .get(&node.dom)
+        .map(|ht| ht.regular_hit_test_nodes.contains_key(&node_id))
+        .unwrap_or(false)
+    }).unwrap_or(false);
+
+    let new_key_pressed = window_state.keyboard_state.pressed_virtual_keycodes
+        .iter()
+        .any(|k| !keys_at_start.contains(k));
+
+    !node_is_hovered ||
+    window_state.mouse_state.mouse_down() ||
+    new_key_pressed
+}
+
+/// Opens the popup window below the cursor, returns the state shared with the popup
+fn open_tooltip_popup(tooltip: &Tooltip, now: Instant, info: &mut CallbackInfo) -> Option<RefAny> {
+
+    let mut popup_window_state = info.get_current_window_state();
+    let hidpi_factor = popup_window_state.size.hidpi_factor;
+
+    let cursor = match popup_window_state.mouse_state.cursor_position {
+        CursorPosition::InWindow(p) => p,
+        _ => return None,
+    };
+
+    let cursor = match popup_window_state.position {
+        WindowPosition::Uninitialized => return None,
+        WindowPosition::Initialized(window_top_left_corner) => PhysicalPositionI32 {
+            x: window_top_left_corner.x + (cursor.x * hidpi_factor) as i32,
+            y: window_top_left_corner.y + (cursor.y * hidpi_factor) as i32,
+        },
+    };
+
+    let offset = (TOOLTIP_CURSOR_OFFSET * hidpi_factor) as i32;
+    let size = (
+        (TOOLTIP_DEFAULT_WIDTH * hidpi_factor) as i32,
+        (TOOLTIP_DEFAULT_HEIGHT * hidpi_factor) as i32,
+    );
+
+    popup_window_state.position = WindowPosition::Initialized(
+        get_tooltip_position(cursor, size, offset, &popup_window_state.monitor)
+    );
+
+    #[cfg(target_os = "windows")] {
+        use crate::window::{OptionHwndHandle, RawWindowHandle, WindowsHandle};
+        if let RawWindowHandle::Windows(WindowsHandle { hwnd, .. }) = info.get_current_window_handle() {
+            // parent window = this window
+            popup_window_state.platform_specific_options.windows_options.parent_window = OptionHwndHandle::Some(hwnd);
+        }
+    }
+
+    #[cfg(target_os = "linux")] {
+        use crate::window::XWindowType;
+        // X11: not managed by the window manager, so the tooltip doesn't take the focus,
+        // Wayland: a popup without a grab, so it doesn't take the keyboard and pointer
+        let linux_options = &mut popup_window_state.platform_specific_options.linux_options;
+        linux_options.x11_override_redirect = true;
+        linux_options.x11_window_types = vec![XWindowType::Tooltip].into();
+        popup_window_state.flags.has_focus = false;
+    }
+
+    let popup = RefAny::new(TooltipPopup {
+        content: tooltip.content.clone(),
+        cursor,
+        is_open: true,
+        heartbeat: now,
+    });
+
+    popup_window_state.title = AzString::from_const_str("");
+    popup_window_state.size.dimensions.width = TOOLTIP_DEFAULT_WIDTH;
+    popup_window_state.size.dimensions.height = TOOLTIP_DEFAULT_HEIGHT;
+    popup_window_state.flags.has_decorations = false;
+    popup_window_state.flags.is_always_on_top = true;
+    popup_window_state.flags.is_resizable = false;
+    popup_window_state.flags.is_about_to_close = false;
+    popup_window_state.close_callback = None.into();
+    popup_window_state.layout_callback = LayoutCallback::Marshaled(MarshaledLayoutCallback {
+        marshal_data: popup.clone(),
+        cb: MarshaledLayoutCallbackInner { cb: tooltipWindowLayoutFn },
+    });
+
+    // the popup window needs its own timer to move and close itself, which is
+    // started from the create callback, once the popup window exists
+    info.create_window(WindowCreateOptions {
+        state: popup_window_state,
+        size_to_content: true,
+        renderer: None.into(),
+        theme: None.into(),
+        create_callback: Some(Callback { cb: on_tooltip_popup_created }).into(),
+        hot_reload: false,
+    });
+
+    Some(popup)
+}
+
+extern "C"
+fn on_tooltip_popup_created(_: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    // the data of the create callback is the application data,
+    // the shared popup state is the marshal data of the layout callback
+    let popup = match &info.internal_get_current_window_state().layout_callback {
+        LayoutCallback::Marshaled(m) => m.marshal_data.clone(),
+        LayoutCallback::Raw(_) => return Update::DoNothing,
+    };
+
+    let timer = Timer::new(popup, on_tooltip_popup_timer, info.get_system_time_fn())
+        .with_interval(Duration::System(SystemTimeDiff::from_millis(TOOLTIP_POLL_MS)));
+    info.start_timer(timer);
+
+    Update::DoNothing
+}
+
+extern "C"
+fn on_tooltip_popup_timer(data: &mut RefAny, info: &mut TimerCallbackInfo) -> TimerCallbackReturn {
+
+    let now = info.callback_info.get_current_time();
+    let heartbeat_timeout = Duration::System(SystemTimeDiff::from_millis(TOOLTIP_HEARTBEAT_TIMEOUT_MS));
+
+    let cursor = match data.downcast_ref::<TooltipPopup>() {
+        Some(popup) if popup.is_open && !now.duration_since(&popup.heartbeat).greater_than(&heartbeat_timeout) => popup.cursor,
+        _ => {
+            info.callback_info.internal_get_modifiable_window_state().flags.is_about_to_close = true;
+            return TimerCallbackReturn {
+                should_update: Update::DoNothing,
+                should_terminate: TerminateTimer::Terminate,
+            };
+        },
+    };
+
+    // keep the popup on the screen, now that the size of the content is known
+    let window_state = info.callback_info.internal_get_current_window_state();
+    let hidpi_factor = window_state.size.hidpi_factor;
+    let size = (
+        (window_state.size.dimensions.width * hidpi_factor) as i32,
+        (window_state.size.dimensions.height * hidpi_factor) as i32,
+    );
+    let offset = (TOOLTIP_CURSOR_OFFSET * hidpi_factor) as i32;
+    let position = WindowPosition::Initialized(get_tooltip_position(cursor, size, offset, &window_state.monitor));
+
+    if window_state.position != position {
+        info.callback_info.internal_get_modifiable_window_state().position = position;
+    }
+
+    TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: TerminateTimer::Continue,
+    }
+}
+
+#[allow(non_snake_case)]
+extern "C"
+fn tooltipWindowLayoutFn(data: &mut RefAny, _: &mut RefAny, info: &mut LayoutCallbackInfo) -> StyledDom {
+
+    let content = match data.downcast_ref::<TooltipPopup>() {
+        Some(s) => s.content.clone(),
+        None => return StyledDom::default(),
+    };
+
+    match content {
+        TooltipContent::Text(text) => {
+            Dom::body()
+            .with_child(Dom::text(text).with_inline_style(TOOLTIP_TEXT_STYLE))
+            .style(CssApiWrapper::empty())
+        },
+        TooltipContent::Dom(mut dom) => {
+            (dom.callback.cb)(&mut dom.data, info)
+        },
+    }
+}
+
+/// Returns the top left corner of a tooltip of the given `size` (physical pixels):
+/// `offset` pixels below the cursor, but moved to the left and / or above the
+/// cursor where it would otherwise extend past the edges of the `monitor`.
+///
+/// The tooltip never covers the cursor (it would be dismissed immediately),
+/// if it fits neither below nor above, it extends past the monitor instead.
+pub(crate) fn get_tooltip_position(
+    cursor: PhysicalPositionI32,
+    size: (i32, i32),
+    offset: i32,
+    monitor: &Monitor,
+) -> PhysicalPositionI32 {
+
+    let (width, height) = size;
+    let mut x = cursor.x;
+    let mut y = cursor.y + offset;
+
+    // monitor not (yet) known
+    if monitor.size.width <= 0 || monitor.size.height <= 0 {
+        return PhysicalPositionI32 { x, y };
+    }
+
+    let left = monitor.position.x as i32;
+    let top = monitor.position.y as i32;
+    let right = left + monitor.size.width as i32;
+    let bottom = top + monitor.size.height as i32;
+
+    if x + width > right {
+        x = right - width;
+    }
+
+    if y + height > bottom {
+        let above = cursor.y - height;
+        // flip above the cursor if there is more space there
+        if above >= top || cursor.y - top > bottom - y {
+            y = above;
+        }
+    }
+
+    PhysicalPositionI32 {
+        x: x.max(left),
+        y,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use azul_css::{LayoutPoint, LayoutSize};
+    use crate::{
+        callbacks::HitTestItem,
+        id_tree::NodeId,
+        styled_dom::{DomId, NodeHierarchyItemId},
+        ui_solver::HitTest,
+        window::LogicalPosition,
+    };
+
+    fn monitor(x: isize, y: isize, width: isize, height: isize) -> Monitor {
+        Monitor {
+            size: LayoutSize { width, height },
+            position: LayoutPoint { x, y },
+            .. Monitor::default()
+        }
+    }
+
+    fn pos(x: i32, y: i32) -> PhysicalPositionI32 {
+        PhysicalPositionI32 { x, y }
+    }
+
+    #[test]
+    fn tooltip_position_below_cursor() {
+        let m = monitor(0, 0, 1920, 1080);
+        assert_eq!(get_tooltip_position(pos(100, 100), (200, 24), 20, &m), pos(100, 120));
+    }
+
+    #[test]
+    fn tooltip_position_unknown_monitor() {
+        // no clamping, even at the (unknown) edges of the screen
+        let m = Monitor::default();
+        assert_eq!(get_tooltip_position(pos(1900, 1070), (200, 24), 20, &m), pos(1900, 1090));
+        assert_eq!(get_tooltip_position(pos(-50, -50), (200, 24), 20, &m), pos(-50, -30));
+    }
+
+    #[test]
+    fn tooltip_position_right_edge() {
+        let m = monitor(0, 0, 1920, 1080);
+        // moved to the left, so that the right edge touches the edge of the monitor
+        assert_eq!(get_tooltip_position(pos(1800, 100), (200, 24), 20, &m), pos(1720, 120));
+        // exactly fitting tooltips are not moved
+        assert_eq!(get_tooltip_position(pos(1720, 100), (200, 24), 20, &m), pos(1720, 120));
+    }
+
+    #[test]
+    fn tooltip_position_bottom_edge() {
+        let m = monitor(0, 0, 1920, 1080);
+        // flipped above the cursor
+        assert_eq!(get_tooltip_position(pos(100, 1060), (200, 24), 20, &m), pos(100, 1036));
+        // bottom right corner: moved to the left and flipped above the cursor
+        assert_eq!(get_tooltip_position(pos(1910, 1075), (200, 24), 20, &m), pos(1720, 1051));
+    }
+
+    #[test]
+    fn tooltip_position_larger_than_monitor() {
+        // the left edge of the tooltip stays on the monitor, but the
+        // tooltip is never moved under the cursor
+        let m = monitor(0, 0, 150, 30);
+        assert_eq!(get_tooltip_position(pos(100, 20), (200, 24), 20, &m), pos(0, -4));
+        assert_eq!(get_tooltip_position(pos(100, 5), (200, 24), 20, &m), pos(0, 25));
+    }
+
+    #[test]
+    fn tooltip_never_covers_the_cursor() {
+        let m = monitor(0, 0, 150, 30);
+        let size = (200, 24);
+        for cursor_y in 0..30 {
+            let cursor = pos(100, cursor_y);
+            let p = get_tooltip_position(cursor, size, 20, &m);
+            assert!(p.y >= cursor.y || p.y + size.1 <= cursor.y, "tooltip at {:?} covers the cursor at {:?}", p, cursor);
+        }
+    }
+
+    #[test]
+    fn tooltip_position_second_monitor() {
+        // monitor to the right of the primary monitor
+        let m = monitor(1920, 0, 1280, 1024);
+        assert_eq!(get_tooltip_position(pos(2000, 100), (200, 24), 20, &m), pos(2000, 120));
+        assert_eq!(get_tooltip_position(pos(3150, 1000), (200, 24), 20, &m), pos(3000, 976));
+        // monitor to the left of the primary monitor
+        let m = monitor(-1280, 0, 1280, 1024);
+        assert_eq!(get_tooltip_position(pos(-100, 100), (200, 24), 20, &m), pos(-200, 120));
+    }
+
+    fn tooltip_node() -> DomNodeId {
+        DomNodeId {
+            dom: DomId::ROOT_ID,
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(1))),
+        }
+    }
+
+    /// Window state with the mouse resting on the tooltip node
+    fn hovered_window_state() -> FullWindowState {
+
+        let mut hit_test = HitTest::empty();
+        hit_test.regular_hit_test_nodes.insert(NodeId::new(1), HitTestItem {
+            point_in_viewport: LogicalPosition::new(10.0, 10.0),
+            point_relative_to_item: LogicalPosition::new(5.0, 5.0),
+            is_focusable: false,
+            is_iframe_hit: None,
+        });
+
+        let mut window_state = FullWindowState::default();
+        window_state.last_hit_test.hovered_nodes.insert(DomId::ROOT_ID, hit_test);
+        window_state
+    }
+
+    #[test]
+    fn tooltip_stays_while_hovered() {
+        let window_state = hovered_window_state();
+        assert!(!should_dismiss_tooltip(&window_state, tooltip_node(), &[]));
+    }
+
+    #[test]
+    fn tooltip_dismissed_on_mouse_out() {
+        let mut window_state = hovered_window_state();
+        window_state.last_hit_test.hovered_nodes.clear();
+        assert!(should_dismiss_tooltip(&window_state, tooltip_node(), &[]));
+
+        // the mouse moved to a different node
+        let mut window_state = hovered_window_state();
+        let hit_test = window_state.last_hit_test.hovered_nodes.get_mut(&DomId::ROOT_ID).unwrap();
+        let item = hit_test.regular_hit_test_nodes.remove(&NodeId::new(1)).unwrap();
+        hit_test.regular_hit_test_nodes.insert(NodeId::new(2), item);
+        assert!(should_dismiss_tooltip(&window_state, tooltip_node(), &[]));
+    }
+
+    #[test]
+    fn tooltip_dismissed_on_click() {
+        let mut window_state = hovered_window_state();
+        window_state.mouse_state.left_down = true;
+        assert!(should_dismiss_tooltip(&window_state, tooltip_node(), &[]));
+
+        let mut window_state = hovered_window_state();
+        window_state.mouse_state.right_down = true;
+        assert!(should_dismiss_tooltip(&window_state, tooltip_node(), &[]));
+    }
+
+    #[test]
+    fn tooltip_dismissed_on_keypress() {
+        let mut window_state = hovered_window_state();
+        window_state.keyboard_state.pressed_virtual_keycodes = vec![VirtualKeyCode::A].into();
+        assert!(should_dismiss_tooltip(&window_state, tooltip_node(), &[]));
+
+        // keys that were already held down when the mouse entered the node are ignored
+        window_state.keyboard_state.pressed_virtual_keycodes = vec![VirtualKeyCode::LShift].into();
+        assert!(!should_dismiss_tooltip(&window_state, tooltip_node(), &[VirtualKeyCode::LShift]));
+
+        window_state.keyboard_state.pressed_virtual_keycodes = vec![VirtualKeyCode::LShift, VirtualKeyCode::A].into();
+        assert!(should_dismiss_tooltip(&window_state, tooltip_node(), &[VirtualKeyCode::LShift]));
+    }
+}
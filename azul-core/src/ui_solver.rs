@@ -9,7 +9,7 @@ use alloc::boxed::Box;
 use azul_css::{
     LayoutRect, LayoutRectVec, LayoutPoint, LayoutSize, PixelValue, StyleFontSize,
     StyleTextColor, ColorU as StyleColorU, OptionF32, LayoutOverflow, LayoutDisplay,
-    StyleTextAlign, StyleVerticalAlign, LayoutPosition,
+    StyleTextAlign, StyleDirection, StyleWordBreak, StyleOverflowWrap, StyleHyphens, StyleVerticalAlign, LayoutPosition,
     CssPropertyValue, LayoutMarginTop, LayoutMarginRight, LayoutMarginLeft, LayoutMarginBottom,
     LayoutPaddingTop, LayoutPaddingLeft, LayoutPaddingRight, LayoutPaddingBottom,
     LayoutLeft, LayoutRight, LayoutTop, LayoutBottom, LayoutFlexDirection, LayoutJustifyContent,
@@ -153,9 +153,11 @@ impl InlineTextLayout {
 pub fn calculate_horizontal_shift_multiplier(horizontal_alignment: StyleTextAlign) -> Option<f32> {
     use azul_css::StyleTextAlign::*;
     match horizontal_alignment {
-        Left => None,
+        // start / end should be resolved against the direction
+        // before calling this function, assume LTR otherwise
+        Left | Start => None,
         Center => Some(0.5), // move the line by the half width
-        Right => Some(1.0), // move the line by the full width
+        Right | End => Some(1.0), // move the line by the full width
     }
 }
 
@@ -739,6 +741,12 @@ impl LayoutResult {
 
                 let layout_size = new_size.to_layout_rect();
 
+                // the new size may activate different @media blocks
+                let mut media_environment = window_size.get_media_environment(window_theme);
+                media_environment.width = new_size.size.width;
+                media_environment.height = new_size.size.height;
+                let media_changes = layout_results[dom_id.inner].styled_dom.restyle_media(&media_environment);
+
                 // Call the relayout function on the DOM to get the resized DOM
                 let mut resized_nodes = (relayout_fn)(
                     *dom_id,
@@ -747,7 +755,7 @@ impl LayoutResult {
                     image_cache,
                     renderer_resources,
                     &document_id,
-                    None, // no new nodes to relayout
+                    if media_changes.is_empty() { None } else { Some(&media_changes) },
                     None, // no text changes
                 );
 
@@ -1193,6 +1201,15 @@ pub struct ResolvedTextLayoutOptions {
     ///
     /// TODO: Currently unused!
     pub holes: LogicalRectVec,
+    /// Base direction of the paragraph (`direction` CSS property), determines the
+    /// paragraph embedding level for the bidirectional algorithm
+    pub direction: StyleDirection,
+    /// Break opportunities between letters (`word-break` CSS property)
+    pub word_break: StyleWordBreak,
+    /// Whether words that are wider than the line may be broken (`overflow-wrap` CSS property)
+    pub overflow_wrap: StyleOverflowWrap,
+    /// Whether words are hyphenated at soft hyphens / using a dictionary (`hyphens` CSS property)
+    pub hyphens: StyleHyphens,
 }
 
 impl_option!(ResolvedTextLayoutOptions, OptionResolvedTextLayoutOptions, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
@@ -6,13 +6,14 @@ use core::{
     ffi::c_void,
 };
 use alloc::vec::Vec;
+use alloc::string::String;
 use alloc::boxed::Box;
 use alloc::collections::btree_map::BTreeMap;
 use alloc::collections::btree_set::BTreeSet;
 use azul_css::{
     CssProperty, LayoutSize, U8Vec, ColorU, OptionF32,
     AzString, OptionAzString, LayoutPoint, LayoutRect,
-    CssPath, OptionI32,
+    CssPath, OptionI32, CssMediaEnvironment, CssColorScheme,
 };
 use crate::{
     FastBTreeSet,
@@ -28,8 +29,9 @@ use crate::{
         DomNodeId, ScrollPosition, Update, CallbackType,
         LayoutCallback, LayoutCallbackType,
     },
+    animation::AnimationScheduler,
     display_list::RenderCallbacks,
-    dom::NodeHierarchy,
+    dom::{NodeHierarchy, AccessibilityAction, CallbackData, EventFilter, ComponentEventFilter},
     id_tree::NodeId,
     styled_dom::{DomId, NodeHierarchyItemId},
     task::{
@@ -199,6 +201,103 @@ pub struct AndroidHandle {
     pub a_native_window: *mut c_void,
 }
 
+/// Which selection of the system clipboard to read from / write to
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum ClipboardSelection {
+    /// Regular clipboard (Ctrl+C / Ctrl+V)
+    Clipboard,
+    /// X11 PRIMARY selection: the last selected text, pasted with a middle click.
+    /// Only available on X11, ignored on all other platforms.
+    Primary,
+}
+
+/// Content of the system clipboard, tagged with its MIME type
+/// (`text/plain;charset=utf-8`, `text/uri-list`, `image/png`, ...)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct ClipboardContent {
+    pub mime_type: AzString,
+    pub data: U8Vec,
+}
+
+impl_option!(ClipboardContent, OptionClipboardContent, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+
+impl ClipboardContent {
+
+    pub const MIME_TYPE_TEXT: &'static str = "text/plain;charset=utf-8";
+    pub const MIME_TYPE_URI_LIST: &'static str = "text/uri-list";
+    pub const MIME_TYPE_PNG: &'static str = "image/png";
+
+    /// Creates UTF-8 encoded text content
+    pub fn text(text: AzString) -> Self {
+        Self {
+            mime_type: AzString::from_const_str(Self::MIME_TYPE_TEXT),
+            data: text.into_library_owned_string().into_bytes().into(),
+        }
+    }
+
+    /// Creates a `text/uri-list` from a list of URIs (`file:///home/...`)
+    pub fn uri_list(uris: &[AzString]) -> Self {
+        let mut list = String::new();
+        for uri in uris {
+            list.push_str(uri.as_str());
+            list.push_str("\r\n");
+        }
+        Self {
+            mime_type: AzString::from_const_str(Self::MIME_TYPE_URI_LIST),
+            data: list.into_bytes().into(),
+        }
+    }
+
+    /// Creates PNG-encoded image content
+    pub fn png(data: U8Vec) -> Self {
+        Self {
+            mime_type: AzString::from_const_str(Self::MIME_TYPE_PNG),
+            data,
+        }
+    }
+
+    /// Returns whether the content is text (`text/plain`, `UTF8_STRING`, ...)
+    pub fn is_text(&self) -> bool {
+        is_text_mime_type(self.mime_type.as_str())
+    }
+
+    /// Returns the content as a string if it is valid UTF-8 text
+    pub fn get_text(&self) -> Option<AzString> {
+        if !self.is_text() && self.mime_type.as_str() != Self::MIME_TYPE_URI_LIST {
+            return None;
+        }
+        core::str::from_utf8(self.data.as_ref()).ok().map(|s| AzString::from(String::from(s)))
+    }
+
+    /// Returns the URIs of a `text/uri-list`, comments (lines starting with `#`) are skipped
+    pub fn get_uri_list(&self) -> Vec<AzString> {
+        if self.mime_type.as_str() != Self::MIME_TYPE_URI_LIST {
+            return Vec::new();
+        }
+        core::str::from_utf8(self.data.as_ref()).ok()
+        .map(|s| {
+            s.lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(|l| AzString::from(String::from(l)))
+            .collect()
+        })
+        .unwrap_or_default()
+    }
+}
+
+/// Returns whether the MIME type (or X11 target name) is a
+/// plain-text type that can be converted to / from UTF-8
+pub fn is_text_mime_type(mime_type: &str) -> bool {
+    match mime_type {
+        "text/plain" | "text/plain;charset=utf-8" | "text/plain;charset=UTF-8" |
+        "UTF8_STRING" | "STRING" | "TEXT" => true,
+        _ => false,
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum MouseCursorType {
@@ -273,8 +372,24 @@ pub struct KeyboardState {
     /// Use when the physical location of the key is more important than the key's host GUI semantics,
     /// such as for movement controls in a first-person game (German keyboard: Z key, UK keyboard: Y key, etc.)
     pub pressed_scancodes: ScanCodeVec,
+    /// Text that is currently composed with an input method (IME), but not committed yet
+    /// (READONLY) - see `On::CompositionStart` / `On::CompositionUpdate` / `On::CompositionEnd`
+    pub ime_composition: OptionImeComposition,
 }
 
+/// Preedit text of an input method, i.e. the pinyin or kana that was typed
+/// before the user selected one of the candidates of the input method
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ImeComposition {
+    /// Preedit text, not part of the text of the focused node yet
+    pub text: AzString,
+    /// Position of the cursor in the preedit text, in characters
+    pub cursor: usize,
+}
+
+impl_option!(ImeComposition, OptionImeComposition, copy = false, [Debug, Clone, PartialEq, PartialOrd]);
+
 impl KeyboardState {
     pub fn shift_down(&self) -> bool { self.is_key_down(VirtualKeyCode::LShift) || self.is_key_down(VirtualKeyCode::RShift) }
     pub fn ctrl_down(&self) -> bool { self.is_key_down(VirtualKeyCode::LControl) || self.is_key_down(VirtualKeyCode::RControl) }
@@ -601,6 +716,8 @@ pub struct WindowInternal {
     pub timers: BTreeMap<TimerId, Timer>,
     /// List of threads running in the background
     pub threads: BTreeMap<ThreadId, Thread>,
+    /// Running CSS transitions and animations, updated every frame
+    pub animations: AnimationScheduler,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -742,6 +859,8 @@ impl WindowInternal {
             /*window_state: */ &init.window_create_options.state,
             /*dropped_file: */ None,
             /*hovered_file: */ None,
+            /*dropped_text: */ None,
+            /*hovered_text: */ None,
             /*focused_node: */ None,
             /*last_hit_test: */ FullHitTest::empty(/*current_focus*/ None),
         );
@@ -761,6 +880,9 @@ impl WindowInternal {
 
         let scroll_states = ScrollStates::default();
 
+        let mut animations = AnimationScheduler::new(ExternalSystemCallbacks::rust_internal().get_system_time_fn);
+        animations.reset(&layout_results);
+
         // apply the changes for the first frame:
         // simulate an event as if the cursor has moved over the hovered elements
         let ht = hit_test_func(&current_window_state, &scroll_states, &layout_results);
@@ -777,11 +899,13 @@ impl WindowInternal {
             &mut layout_results,
             &image_cache,
             &mut inital_renderer_resources,
-            current_window_state.size.get_layout_size(),
+            &current_window_state.size,
+            current_window_state.theme,
             &init.document_id,
             Some(&BTreeMap::new()),
             Some(&BTreeMap::new()),
             &None,
+            &mut animations,
             relayout_fn,
         );
 
@@ -812,6 +936,7 @@ impl WindowInternal {
             timers: BTreeMap::new(),
             threads: BTreeMap::new(),
             scroll_states,
+            animations,
         }
     }
 
@@ -877,6 +1002,9 @@ impl WindowInternal {
             &mut self.renderer_resources,
         );
 
+        // keep the animations of nodes that still exist, start the ones of new nodes
+        self.animations.reset(&layout_results);
+
         // apply the changes for the first frame
         let ht = hit_test_func(&self.current_window_state, &self.scroll_states, &layout_results);
         self.current_window_state.last_hit_test = ht.clone();
@@ -893,11 +1021,13 @@ impl WindowInternal {
             &mut layout_results,
             &image_cache,
             &mut self.renderer_resources,
-            self.current_window_state.size.get_layout_size(),
+            &self.current_window_state.size,
+            self.current_window_state.theme,
             &self.document_id,
             Some(&BTreeMap::new()),
             Some(&BTreeMap::new()),
             &None,
+            &mut self.animations,
             relayout_fn,
         );
 
@@ -1528,6 +1658,226 @@ impl WindowInternal {
 
         return ret;
     }
+
+    /// Invokes an action that was requested by an assistive technology (screen reader)
+    /// on the given node, see `AccessibilityAction::get_event_filters()`
+    pub fn invoke_accessibility_action(
+        &mut self,
+        hit_dom_node: DomNodeId,
+        action: AccessibilityAction,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> CallCallbacksResult {
+
+        let mut ret = CallCallbacksResult {
+            should_scroll_render: false,
+            callbacks_update_screen: Update::DoNothing,
+            modified_window_state: None,
+            css_properties_changed: None,
+            words_changed: None,
+            images_changed: None,
+            image_masks_changed: None,
+            nodes_scrolled_in_callbacks: None,
+            update_focused_node: None,
+            timers: None,
+            threads: None,
+            timers_removed: None,
+            threads_removed: None,
+            windows_created: Vec::new(),
+            cursor_changed: false,
+        };
+
+        if action == AccessibilityAction::Focus {
+            if self.current_window_state.focused_node != Some(hit_dom_node) {
+                ret.update_focused_node = Some(Some(hit_dom_node));
+            }
+            return ret;
+        }
+
+        // clone the callbacks, so that the layout results can be borrowed by the CallbackInfo
+        let mut callbacks = {
+            let node_data = hit_dom_node.node.into_crate_internal().and_then(|node_id| {
+                let layout_result = self.layout_results.get(hit_dom_node.dom.inner)?;
+                layout_result.styled_dom.node_data.as_container().get(node_id).cloned()
+            });
+            let node_data = match node_data {
+                Some(s) => s,
+                None => return ret,
+            };
+            action.get_event_filters().iter()
+            .map(|filter| {
+                node_data.get_callbacks().iter()
+                .filter(|cb| cb.event == *filter)
+                .map(|cb| (hit_dom_node, cb.clone()))
+                .collect::<Vec<_>>()
+            })
+            .find(|cbs| !cbs.is_empty())
+            .unwrap_or_default()
+        };
+
+        self.invoke_node_callbacks(
+            &mut callbacks,
+            current_window_handle,
+            gl_context,
+            image_cache,
+            system_fonts,
+            system_callbacks,
+        )
+    }
+
+    /// Invokes all `ComponentEventFilter::AfterMount` callbacks of the current
+    /// DOM - called once after the DOM has been (re-)generated, so that widgets
+    /// can start timers that are attached to their (new) node IDs
+    pub fn invoke_after_mount_callbacks(
+        &mut self,
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> CallCallbacksResult {
+
+        // clone the callbacks, so that the layout results can be borrowed by the CallbackInfo
+        let mut callbacks = self.layout_results.iter().enumerate().flat_map(|(dom_id, layout_result)| {
+            layout_result.styled_dom.node_data.as_container().internal.iter().enumerate().flat_map(move |(node_id, node_data)| {
+                let hit_dom_node = DomNodeId {
+                    dom: DomId { inner: dom_id },
+                    node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(node_id))),
+                };
+                node_data.get_callbacks().iter()
+                .filter(|cb| cb.event == EventFilter::Component(ComponentEventFilter::AfterMount))
+                .map(move |cb| (hit_dom_node, cb.clone()))
+            })
+        }).collect::<Vec<_>>();
+
+        self.invoke_node_callbacks(
+            &mut callbacks,
+            current_window_handle,
+            gl_context,
+            image_cache,
+            system_fonts,
+            system_callbacks,
+        )
+    }
+
+    /// Invokes the (cloned) callbacks of the given nodes in order - same as in
+    /// `CallbacksOfHitTest::call()`, a callback that stops the propagation of
+    /// the event prevents the remaining callbacks from being invoked
+    fn invoke_node_callbacks(
+        &mut self,
+        callbacks: &mut [(DomNodeId, CallbackData)],
+        current_window_handle: &RawWindowHandle,
+        gl_context: &OptionGlContextPtr,
+        image_cache: &mut ImageCache,
+        system_fonts: &mut FcFontCache,
+        system_callbacks: &ExternalSystemCallbacks,
+    ) -> CallCallbacksResult {
+
+        use crate::callbacks::CallbackInfo;
+
+        let mut ret = CallCallbacksResult {
+            should_scroll_render: false,
+            callbacks_update_screen: Update::DoNothing,
+            modified_window_state: None,
+            css_properties_changed: None,
+            words_changed: None,
+            images_changed: None,
+            image_masks_changed: None,
+            nodes_scrolled_in_callbacks: None,
+            update_focused_node: None,
+            timers: None,
+            threads: None,
+            timers_removed: None,
+            threads_removed: None,
+            windows_created: Vec::new(),
+            cursor_changed: false,
+        };
+
+        if callbacks.is_empty() {
+            return ret;
+        }
+
+        let mut ret_modified_window_state: WindowState = self.current_window_state.clone().into();
+        let ret_window_state = ret_modified_window_state.clone();
+        let mut ret_timers = FastHashMap::new();
+        let mut ret_timers_removed = FastBTreeSet::new();
+        let mut ret_threads = FastHashMap::new();
+        let mut ret_threads_removed = FastBTreeSet::new();
+        let mut ret_words_changed = BTreeMap::new();
+        let mut ret_images_changed = BTreeMap::new();
+        let mut ret_image_masks_changed = BTreeMap::new();
+        let mut ret_css_properties_changed = BTreeMap::new();
+        let mut ret_nodes_scrolled_in_callbacks = BTreeMap::new();
+        let mut new_focus_target = None;
+        let current_scroll_states = self.get_current_scroll_states();
+
+        for (hit_dom_node, callback_data) in callbacks.iter_mut() {
+
+            let mut stop_propagation = false;
+            let cursor_relative_to_item = OptionLogicalPosition::None;
+            let cursor_in_viewport = OptionLogicalPosition::None;
+
+            let mut callback_info = CallbackInfo::new(
+                &self.layout_results,
+                &self.renderer_resources,
+                &self.previous_window_state,
+                &self.current_window_state,
+                &mut ret_modified_window_state,
+                gl_context,
+                image_cache,
+                system_fonts,
+                &mut ret_timers,
+                &mut ret_threads,
+                &mut ret_timers_removed,
+                &mut ret_threads_removed,
+                current_window_handle,
+                &mut ret.windows_created,
+                system_callbacks,
+                &mut stop_propagation,
+                &mut new_focus_target,
+                &mut ret_words_changed,
+                &mut ret_images_changed,
+                &mut ret_image_masks_changed,
+                &mut ret_css_properties_changed,
+                &current_scroll_states,
+                &mut ret_nodes_scrolled_in_callbacks,
+                *hit_dom_node,
+                cursor_relative_to_item,
+                cursor_in_viewport,
+            );
+
+            let callback_update = (callback_data.callback.cb)(&mut callback_data.data, &mut callback_info);
+            ret.callbacks_update_screen.max_self(callback_update);
+
+            if stop_propagation {
+                break;
+            }
+        }
+
+        if !ret_timers.is_empty() { ret.timers = Some(ret_timers); }
+        if !ret_threads.is_empty() { ret.threads = Some(ret_threads); }
+        if ret_modified_window_state != ret_window_state {
+            ret.modified_window_state = Some(ret_modified_window_state);
+        }
+        if !ret_threads_removed.is_empty() { ret.threads_removed = Some(ret_threads_removed); }
+        if !ret_timers_removed.is_empty() { ret.timers_removed = Some(ret_timers_removed); }
+        if !ret_words_changed.is_empty() { ret.words_changed = Some(ret_words_changed); }
+        if !ret_images_changed.is_empty() { ret.images_changed = Some(ret_images_changed); }
+        if !ret_image_masks_changed.is_empty() { ret.image_masks_changed = Some(ret_image_masks_changed); }
+        if !ret_css_properties_changed.is_empty() { ret.css_properties_changed = Some(ret_css_properties_changed); }
+        if !ret_nodes_scrolled_in_callbacks.is_empty() { ret.nodes_scrolled_in_callbacks = Some(ret_nodes_scrolled_in_callbacks); }
+
+        if let Some(ft) = new_focus_target {
+            if let Ok(new_focus_node) = ft.resolve(&self.layout_results, self.current_window_state.focused_node) {
+                ret.update_focused_node = Some(new_focus_node);
+            }
+        }
+
+        return ret;
+    }
 }
 
 #[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd, Hash, Ord, Eq)]
@@ -1739,6 +2089,10 @@ pub struct FullWindowState {
     pub hovered_file: Option<AzString>, // Option<PathBuf>
     /// Whether there was a file currently dropped on the window
     pub dropped_file: Option<AzString>, // Option<PathBuf>
+    /// Text that is currently dragged over the window from another application
+    pub hovered_text: Option<AzString>,
+    /// Text that was dropped on the window
+    pub dropped_text: Option<AzString>,
     /// What node is currently hovered over, default to None. Only necessary internal
     /// to the crate, for emitting `On::FocusReceived` and `On::FocusLost` events,
     /// as well as styling `:focus` elements
@@ -1772,6 +2126,8 @@ impl Default for FullWindowState {
 
             hovered_file: None,
             dropped_file: None,
+            hovered_text: None,
+            dropped_text: None,
             focused_node: None,
             last_hit_test: FullHitTest::empty(None),
         }
@@ -1796,6 +2152,14 @@ impl FullWindowState {
         self.dropped_file.as_ref()
     }
 
+    pub fn get_hovered_text(&self) -> Option<&AzString> {
+        self.hovered_text.as_ref()
+    }
+
+    pub fn get_dropped_text(&self) -> Option<&AzString> {
+        self.dropped_text.as_ref()
+    }
+
     pub fn get_scroll_amount(&self) -> Option<(f32, f32)> {
         self.mouse_state.get_scroll_amount()
     }
@@ -1816,6 +2180,8 @@ impl FullWindowState {
         window_state: &WindowState,
         dropped_file: Option<AzString>,
         hovered_file: Option<AzString>,
+        dropped_text: Option<AzString>,
+        hovered_text: Option<AzString>,
         focused_node: Option<DomNodeId>,
         last_hit_test: FullHitTest,
     ) -> Self {
@@ -1838,6 +2204,8 @@ impl FullWindowState {
             renderer_options: window_state.renderer_options,
             dropped_file,
             hovered_file,
+            dropped_text,
+            hovered_text,
             focused_node,
             last_hit_test,
         }
@@ -2084,9 +2452,31 @@ impl Default for UserAttentionType {
     }
 }
 
+/// Which windowing system the window should be created with on Linux
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[repr(C)]
+pub enum LinuxBackend {
+    /// Use Wayland if `WAYLAND_DISPLAY` is set and libwayland-client can be loaded,
+    /// otherwise fall back to X11
+    Auto,
+    /// Always use X11 (or XWayland)
+    X11,
+    /// Always use Wayland, fails if no Wayland compositor is running
+    Wayland,
+}
+
+impl Default for LinuxBackend {
+    fn default() -> LinuxBackend {
+        LinuxBackend::Auto
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct LinuxWindowOptions {
+    /// Windowing system to use, defaults to `Auto`. The backend of the root window
+    /// is used for the entire application. Can only be set at window creation.
+    pub backend: LinuxBackend,
     /// (Unimplemented) - Can only be set at window creation, can't be changed in callbacks.
     pub x11_visual: OptionX11Visual,
     /// (Unimplemented) - Can only be set at window creation, can't be changed in callbacks.
@@ -2331,6 +2721,19 @@ impl WindowSize {
             self.dimensions.height * self.hidpi_factor / self.system_hidpi_factor,
         )
     }
+
+    /// Returns the values that `@media` queries are evaluated against
+    pub fn get_media_environment(&self, theme: WindowTheme) -> CssMediaEnvironment {
+        CssMediaEnvironment {
+            width: self.dimensions.width,
+            height: self.dimensions.height,
+            resolution: self.hidpi_factor,
+            color_scheme: match theme {
+                WindowTheme::DarkMode => CssColorScheme::Dark,
+                WindowTheme::LightMode => CssColorScheme::Light,
+            },
+        }
+    }
 }
 
 impl Default for WindowSize {
@@ -2527,6 +2930,8 @@ impl_vec_ord!(LogicalRect, LogicalRectVec);
 impl_vec_hash!(LogicalRect, LogicalRectVec);
 impl_vec_eq!(LogicalRect, LogicalRectVec);
 
+impl_option!(LogicalRect, OptionLogicalRect, [Debug, Copy, Clone, PartialEq, PartialOrd]);
+
 use core::ops::SubAssign;
 use core::ops::AddAssign;
 
@@ -76,7 +76,8 @@ use crate::{
     styled_dom::{DomId, ChangedCssProperty, NodeHierarchyItemId},
     ui_solver::{LayoutResult, RelayoutChanges, GpuEventChanges},
     task::ExternalSystemCallbacks,
-    window::{FullHitTest, RawWindowHandle, FullWindowState, ScrollStates, CallCallbacksResult},
+    animation::AnimationScheduler,
+    window::{FullHitTest, RawWindowHandle, FullWindowState, ScrollStates, CallCallbacksResult, WindowSize, WindowTheme},
 };
 use azul_css::{
     AzString, LayoutSize, CssProperty,
@@ -325,11 +326,13 @@ impl StyleAndLayoutChanges {
         layout_results: &mut [LayoutResult],
         image_cache: &ImageCache,
         renderer_resources: &mut RendererResources,
-        window_size: LayoutSize,
+        window_size: &WindowSize,
+        window_theme: WindowTheme,
         document_id: &DocumentId,
         css_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, Vec<CssProperty>>>>,
         word_changes: Option<&BTreeMap<DomId, BTreeMap<NodeId, AzString>>>,
         callbacks_new_focus: &Option<Option<DomNodeId>>,
+        animations: &mut AnimationScheduler,
         relayout_cb: RelayoutFn,
     ) -> StyleAndLayoutChanges {
 
@@ -338,6 +341,9 @@ impl StyleAndLayoutChanges {
         let mut style_changes = None;
         let mut layout_changes = None;
 
+        let media_environment = window_size.get_media_environment(window_theme);
+        let window_size = window_size.get_layout_size();
+
         let is_mouse_down = nodes.current_window_state_mouse_is_down;
         let nodes_that_changed_text_content = word_changes.and_then(|word_changes| {
             if word_changes.is_empty() {
@@ -371,6 +377,18 @@ impl StyleAndLayoutChanges {
             }
         }};}
 
+        // re-evaluate the @media blocks, in case the window size, DPI or theme changed
+        for (dom_id, layout_result) in layout_results.iter_mut().enumerate() {
+            let mut media_environment = media_environment;
+            if dom_id != DomId::ROOT_ID.inner {
+                // iframes evaluate the @media blocks against their own size
+                media_environment.width = layout_result.root_size.width as f32;
+                media_environment.height = layout_result.root_size.height as f32;
+            }
+            let media_changes = layout_result.styled_dom.restyle_media(&media_environment);
+            insert_props!(DomId { inner: dom_id }, media_changes);
+        }
+
         for (dom_id, onmouseenter_nodes) in nodes.onmouseenter_nodes.iter() {
 
             let layout_result = &mut layout_results[dom_id.inner];
@@ -432,6 +450,14 @@ impl StyleAndLayoutChanges {
             }
         }
 
+        // start the transitions for the changed properties and advance all running
+        // animations before the relayout, so that the changed nodes don't flash
+        // their final style for one frame
+        let animation_changes = animations.update(layout_results, style_changes.as_ref(), layout_changes.as_ref());
+        for (dom_id, animation_changes) in animation_changes.into_iter() {
+            insert_props!(dom_id, animation_changes);
+        }
+
         let mut nodes_that_changed_size = None;
         let mut gpu_key_change_events = None;
 
@@ -607,6 +633,8 @@ pub struct CallbacksOfHitTest {
     /// meaning in order to get the proper callbacks, you simply have to iterate through
     /// all node IDs
     pub nodes_with_callbacks: BTreeMap<DomId, Vec<CallbackToCall>>,
+    /// Nodes with a tooltip that the mouse just entered (the tooltip timer has to be started)
+    pub nodes_with_tooltips: BTreeMap<DomId, Vec<NodeId>>,
 }
 
 impl CallbacksOfHitTest {
@@ -620,9 +648,10 @@ impl CallbacksOfHitTest {
         use rayon::prelude::*;
 
         let mut nodes_with_callbacks = BTreeMap::new();
+        let mut nodes_with_tooltips = BTreeMap::new();
 
         if events.is_empty() {
-            return Self { nodes_with_callbacks };
+            return Self { nodes_with_callbacks, nodes_with_tooltips };
         }
 
         let default_map = BTreeMap::new();
@@ -772,6 +801,18 @@ impl CallbacksOfHitTest {
             if !window_callbacks_this_dom.is_empty() {
                 nodes_with_callbacks.insert(dom_id, window_callbacks_this_dom);
             }
+
+            let tooltips_this_dom = nodes_to_check.onmouseenter_nodes
+                .get(&dom_id)
+                .unwrap_or(&default_map)
+                .keys()
+                .filter(|node_id| layout_result.styled_dom.node_data.as_container()[**node_id].get_tooltip().is_some())
+                .copied()
+                .collect::<Vec<_>>();
+
+            if !tooltips_this_dom.is_empty() {
+                nodes_with_tooltips.insert(dom_id, tooltips_this_dom);
+            }
         }
 
         // Final: insert Not:: event filters
@@ -807,6 +848,7 @@ impl CallbacksOfHitTest {
 
         CallbacksOfHitTest {
             nodes_with_callbacks,
+            nodes_with_tooltips,
         }
     }
 
@@ -851,7 +893,7 @@ impl CallbacksOfHitTest {
 
         let current_cursor = full_window_state.mouse_state.mouse_cursor_type.clone();
 
-        if self.nodes_with_callbacks.is_empty() {
+        if self.nodes_with_callbacks.is_empty() && self.nodes_with_tooltips.is_empty() {
             // common case
             return ret;
         }
@@ -1027,6 +1069,54 @@ impl CallbacksOfHitTest {
 
         }
 
+        // Start the timers of tooltips that the mouse just entered
+        for (dom_id, tooltip_nodes) in self.nodes_with_tooltips.iter() {
+            for node_id in tooltip_nodes.iter() {
+
+                let tooltip = match layout_results.get(dom_id.inner)
+                    .and_then(|lr| lr.styled_dom.node_data.as_container().get(*node_id).and_then(|nd| nd.get_tooltip().cloned())) {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                let mut new_focus = None;
+                let mut stop_propagation = false;
+
+                let mut callback_info = CallbackInfo::new(
+                    /*layout_results:*/ &layout_results,
+                    /*renderer_resources:*/ renderer_resources,
+                    /*previous_window_state:*/ &previous_window_state,
+                    /*current_window_state:*/ &full_window_state,
+                    /*modifiable_window_state:*/ &mut ret_modified_window_state,
+                    /*gl_context,*/ gl_context,
+                    /*image_cache,*/ image_cache,
+                    /*system_fonts,*/ system_fonts,
+                    /*timers:*/ &mut ret_timers,
+                    /*threads:*/ &mut ret_threads,
+                    /*timers_removed:*/ &mut ret_timers_removed,
+                    /*threads_removed:*/ &mut ret_threads_removed,
+
+                    /*current_window_handle:*/ raw_window_handle,
+                    /*new_windows:*/ &mut ret.windows_created,
+                    /*system_callbacks*/ system_callbacks,
+
+                    /*stop_propagation:*/ &mut stop_propagation,
+                    /*focus_target:*/ &mut new_focus,
+                    /*words_changed_in_callbacks:*/ &mut ret_words_changed,
+                    /*images_changed_in_callbacks:*/ &mut ret_images_changed,
+                    /*image_masks_changed_in_callbacks:*/ &mut ret_image_masks_changed,
+                    /*css_properties_changed_in_callbacks:*/ &mut ret_css_properties_changed,
+                    /*current_scroll_states:*/ scroll_states,
+                    /*nodes_scrolled_in_callback:*/ &mut ret_nodes_scrolled_in_callbacks,
+                    /*hit_dom_node:*/ DomNodeId { dom: *dom_id, node: NodeHierarchyItemId::from_crate_internal(Some(*node_id)) },
+                    /*cursor_relative_to_item:*/ None.into(),
+                    /*cursor_in_viewport:*/ None.into(),
+                );
+
+                crate::tooltip::start_tooltip_timer(&mut callback_info, &tooltip);
+            }
+        }
+
         // Scroll nodes from programmatic callbacks
         for (dom_id, callback_scrolled_nodes) in ret_nodes_scrolled_in_callbacks.iter() {
             let scrollable_nodes = &layout_results[dom_id.inner].scrollable_nodes;
@@ -1209,15 +1299,34 @@ fn get_window_events(current_window_state: &FullWindowState, previous_window_sta
         events.push(WindowEventFilter::VirtualKeyUp);
     }
 
+    // input method composition, the committed text is sent as TextInput events
+    let previous_composition = &previous_window_state.keyboard_state.ime_composition;
+    let current_composition = &current_window_state.keyboard_state.ime_composition;
+
+    if previous_composition.is_none() && current_composition.is_some() {
+        events.push(WindowEventFilter::CompositionStart);
+    }
+
+    if current_composition.is_some() && previous_composition != current_composition {
+        events.push(WindowEventFilter::CompositionUpdate);
+    }
+
+    if previous_composition.is_some() && current_composition.is_none() {
+        events.push(WindowEventFilter::CompositionEnd);
+    }
+
     // misc events
 
-    let hovered_file_equals = previous_window_state.hovered_file == current_window_state.hovered_file;
-    if previous_window_state.hovered_file.is_none() && current_window_state.hovered_file.is_some() && !hovered_file_equals {
+    // dragged text fires the same events as dragged files
+    let is_hovering_previous = previous_window_state.hovered_file.is_some() || previous_window_state.hovered_text.is_some();
+    let is_hovering_now = current_window_state.hovered_file.is_some() || current_window_state.hovered_text.is_some();
+
+    if !is_hovering_previous && is_hovering_now {
         events.push(WindowEventFilter::HoveredFile);
     }
 
-    if previous_window_state.hovered_file.is_some() && current_window_state.hovered_file.is_none() {
-        if current_window_state.dropped_file.is_some() {
+    if is_hovering_previous && !is_hovering_now {
+        if current_window_state.dropped_file.is_some() || current_window_state.dropped_text.is_some() {
             events.push(WindowEventFilter::DroppedFile);
         } else {
             events.push(WindowEventFilter::HoveredFileCancelled);
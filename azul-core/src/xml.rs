@@ -18,7 +18,7 @@ use azul_css::{
 use crate::window::{AzStringPair, StringPairVec};
 use crate::styled_dom::StyledDom;
 use crate::css::VecContents;
-use crate::dom::Dom;
+use crate::dom::{Dom, TabIndex};
 #[cfg(feature = "css_parser")]
 use azul_css_parser::{CssParseError, CssApiWrapper};
 
@@ -1027,19 +1027,16 @@ pub fn str_to_dom<'a>(
     ).map_err(|e| e.into())
 }
 
-/// Parses an XML string and returns a `String`, which contains the Rust source code
-/// (i.e. it compiles the XML to valid Rust)
-pub fn str_to_rust_code<'a>(
-    root_nodes: &'a [XmlNode],
-    imports: &str,
-    component_map: &'a mut XmlComponentMap
-) -> Result<String, CompileError<'a>> {
+/// Registers all `<component />` nodes of the `<head>` in the `component_map` and
+/// returns the (sorted) global stylesheet of the `<head><style>` node, if present
+pub fn compile_head_node<'a>(
+    html_node: &'a XmlNode,
+    component_map: &mut XmlComponentMap
+) -> Result<Css, CompileError<'a>> {
 
-    let html_node = get_html_node(&root_nodes)?;
-    let body_node = get_body_node(html_node.children.as_ref())?;
     let mut global_style = Css::empty();
 
-    if let Some(head_node) = html_node.children.as_ref().iter().find(|n| normalize_casing(&n.node_type).as_str() == "head") {
+    if let Some(head_node) = find_node_by_type(html_node.children.as_ref(), "head") {
         for node in head_node.children.as_ref() {
             match DynamicXmlComponent::new(node) {
                 Ok(node) => {
@@ -1061,6 +1058,21 @@ pub fn str_to_rust_code<'a>(
 
     global_style.sort_by_specificity();
 
+    Ok(global_style)
+}
+
+/// Parses an XML string and returns a `String`, which contains the Rust source code
+/// (i.e. it compiles the XML to valid Rust)
+pub fn str_to_rust_code<'a>(
+    root_nodes: &'a [XmlNode],
+    imports: &str,
+    component_map: &'a mut XmlComponentMap
+) -> Result<String, CompileError<'a>> {
+
+    let html_node = get_html_node(&root_nodes)?;
+    let body_node = get_body_node(html_node.children.as_ref())?;
+    let global_style = compile_head_node(html_node, component_map)?;
+
     let mut css_blocks = BTreeMap::new();
     let mut extra_blocks = VecContents::default();
     let app_source = compile_body_node_to_rust_code(
@@ -2222,6 +2234,10 @@ impl XmlComponent for DynamicXmlComponent {
         let mut dom = StyledDom::default();
 
         for child_node in self.root.children.as_ref() {
+            // the <style> of the component is applied below
+            if normalize_casing(&child_node.node_type).as_str() == "style" {
+                continue;
+            }
             dom.append_child(render_dom_from_body_node_inner(child_node, components, arguments)?);
         }
 
@@ -2242,6 +2258,1004 @@ impl XmlComponent for DynamicXmlComponent {
     }
 }
 
+// --- Compilation of XML to C, C++ and Python source code
+
+/// Parses an XML string and returns a `String`, which contains the C source code
+/// (written against the `azul.h` header)
+pub fn str_to_c_code<'a>(
+    root_nodes: &'a [XmlNode],
+    component_map: &'a mut XmlComponentMap
+) -> Result<String, CompileError<'a>> {
+    let ui = compile_xml_to_foreign_ui(root_nodes, component_map)?;
+    Ok(format_foreign_ui_as_c(&ui))
+}
+
+/// Parses an XML string and returns a `String`, which contains the C++ source code
+/// (written against the free functions in the `dll` namespace of the `azul.hpp` header)
+pub fn str_to_cpp_code<'a>(
+    root_nodes: &'a [XmlNode],
+    component_map: &'a mut XmlComponentMap
+) -> Result<String, CompileError<'a>> {
+    let ui = compile_xml_to_foreign_ui(root_nodes, component_map)?;
+    Ok(format_foreign_ui_as_cpp(&ui))
+}
+
+/// Parses an XML string and returns a `String`, which contains the Python source code
+/// (written against the `azul` Python module)
+pub fn str_to_python_code<'a>(
+    root_nodes: &'a [XmlNode],
+    component_map: &'a mut XmlComponentMap
+) -> Result<String, CompileError<'a>> {
+    let ui = compile_xml_to_foreign_ui(root_nodes, component_map)?;
+    Ok(format_foreign_ui_as_python(&ui))
+}
+
+/// Language-independent representation of the compiled XML, which is
+/// then formatted as C, C++ or Python source code
+struct ForeignUi {
+    /// CSS constant name => CSS string, i.e. `CSS_MATCH_000012345 => "width: 5px;"`
+    css_blocks: BTreeMap<String, String>,
+    components: Vec<ForeignComponent>,
+    body: ForeignNode,
+}
+
+/// `<component />` that gets compiled to a function returning a `Dom`
+struct ForeignComponent {
+    name: String,
+    accepts_text: bool,
+    /// Arguments of the component as (name, type), in the order of the `args="..."`
+    args: Vec<(String, String)>,
+    root: ForeignNode,
+}
+
+struct ForeignNode {
+    node_type: ForeignNodeType,
+    ids: Vec<Vec<ForeignStringItem>>,
+    classes: Vec<Vec<ForeignStringItem>>,
+    tab_index: Option<TabIndex>,
+    /// (`""`, `"Hover"`, `"Active"` or `"Focus"`, name of the CSS constant)
+    inline_styles: Vec<(&'static str, String)>,
+    children: Vec<ForeignNode>,
+}
+
+enum ForeignNodeType {
+    Body,
+    Div,
+    Text(Vec<ForeignStringItem>),
+    /// Call to a compiled component function with the (type, value) of each argument
+    Component {
+        name: String,
+        text: Option<Vec<ForeignStringItem>>,
+        args: Vec<(String, Vec<ForeignStringItem>)>,
+    },
+}
+
+impl ForeignNodeType {
+    /// Prefix of the variable name that the node gets assigned to
+    fn get_variable_prefix(&self) -> &str {
+        match self {
+            ForeignNodeType::Body => "body",
+            ForeignNodeType::Div => "div",
+            ForeignNodeType::Text(_) => "text",
+            ForeignNodeType::Component { name, .. } => name.as_str(),
+        }
+    }
+}
+
+/// Same as `DynamicItem`, but the variable is resolved to a function argument
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ForeignStringItem {
+    /// (variable name, variable type)
+    Var(String, String),
+    Str(String),
+}
+
+/// Type of a component argument - Rust types other than `bool` and
+/// numbers are passed as strings to the generated functions
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ForeignArgType {
+    String,
+    Bool,
+    Int,
+    UInt,
+    Float,
+}
+
+impl ForeignArgType {
+    fn new(rust_type: &str) -> Self {
+        match rust_type.trim() {
+            "bool" => ForeignArgType::Bool,
+            "i8" | "i16" | "i32" | "i64" | "isize" => ForeignArgType::Int,
+            "u8" | "u16" | "u32" | "u64" | "usize" => ForeignArgType::UInt,
+            "f32" | "f64" => ForeignArgType::Float,
+            _ => ForeignArgType::String,
+        }
+    }
+}
+
+/// Value of a non-string argument: either a variable or a validated literal
+enum ForeignScalar {
+    Var(String),
+    Literal(String),
+}
+
+impl ForeignScalar {
+    fn new(arg_type: ForeignArgType, value: &[ForeignStringItem]) -> Self {
+
+        if let [ForeignStringItem::Var(name, _)] = value {
+            return ForeignScalar::Var(name.clone());
+        }
+
+        let literal = value.iter().map(|item| match item {
+            ForeignStringItem::Var(name, _) => name.as_str(),
+            ForeignStringItem::Str(s) => s.as_str(),
+        }).collect::<String>();
+        let literal = literal.trim();
+
+        ForeignScalar::Literal(match arg_type {
+            ForeignArgType::Bool => parse_bool(literal).unwrap_or(false).to_string(),
+            ForeignArgType::Int => literal.parse::<i64>().unwrap_or(0).to_string(),
+            ForeignArgType::UInt => literal.parse::<u64>().unwrap_or(0).to_string(),
+            ForeignArgType::Float => match literal.parse::<f64>() {
+                Ok(f) if f.is_finite() => format!("{:?}", f),
+                _ => String::from("0.0"),
+            },
+            ForeignArgType::String => literal.to_string(),
+        })
+    }
+}
+
+/// Splits the string into its dynamic items and resolves the `{var}` items against
+/// the arguments of the current component - unknown variables are kept as-is
+fn split_foreign_dynamic_string(input: &str, scope: &ComponentArgumentsMap) -> Vec<ForeignStringItem> {
+
+    let mut items: Vec<ForeignStringItem> = Vec::new();
+
+    for item in split_dynamic_string(input) {
+        let item = match item {
+            DynamicItem::Var(v) => {
+                let variable_name = normalize_casing(v.trim());
+                match scope.get(&variable_name) {
+                    Some((variable_type, _)) => ForeignStringItem::Var(variable_name, variable_type.clone()),
+                    None => ForeignStringItem::Str(format!("{{{}}}", v)),
+                }
+            },
+            DynamicItem::Str(s) => ForeignStringItem::Str(s),
+        };
+
+        // merge adjacent strings
+        match (items.last_mut(), item) {
+            (Some(ForeignStringItem::Str(last)), ForeignStringItem::Str(s)) => last.push_str(&s),
+            (_, item) => items.push(item),
+        }
+    }
+
+    items
+}
+
+/// Escapes and quotes a string, the output is a valid C, C++ and Python string literal
+fn format_foreign_string_literal(input: &str) -> String {
+    let mut s = String::from("\"");
+    for c in input.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
+
+fn indent_foreign_lines(lines: &[String], tabs: usize) -> String {
+    let t = String::from("    ").repeat(tabs);
+    lines.iter().map(|l| format!("{}{}", t, l)).collect::<Vec<String>>().join("\r\n")
+}
+
+/// Parses the `<head>` and compiles all components and the `<body>`
+/// into a language-independent form
+fn compile_xml_to_foreign_ui<'a>(
+    root_nodes: &'a [XmlNode],
+    component_map: &'a mut XmlComponentMap
+) -> Result<ForeignUi, CompileError<'a>> {
+
+    let html_node = get_html_node(&root_nodes)?;
+    let body_node = get_body_node(html_node.children.as_ref())?;
+    let global_style = compile_head_node(html_node, component_map)?;
+    let component_map = &*component_map;
+
+    let mut css_blocks = BTreeMap::new();
+    let mut components = Vec::new();
+
+    for (component_name, (renderer, _)) in component_map.components.iter() {
+
+        // builtin and Rust-registered components have no XML to compile
+        let xml_node = renderer.get_xml_node();
+        if normalize_casing(&xml_node.node_type).as_str() != "component" {
+            continue;
+        }
+
+        let mut css = match find_node_by_type(xml_node.children.as_ref(), "style")
+        .and_then(|style_node| style_node.text.as_ref().map(|s| s.as_str())) {
+            Some(text) => azul_css_parser::new_from_str(&text)?,
+            None => Css::empty(),
+        };
+
+        css.sort_by_specificity();
+
+        let arguments = renderer.get_available_arguments();
+        let mut scope = arguments.args.clone();
+        if arguments.accepts_text {
+            scope.insert("text".to_string(), ("String".to_string(), 0));
+        }
+
+        // the component renders as a div wrapping the component children
+        let matcher = CssMatcher {
+            path: vec![CssPathSelector::Type(NodeTypeTag::Div)],
+            indices_in_parent: vec![0],
+            children_length: vec![1],
+        };
+
+        let root = ForeignNode {
+            node_type: ForeignNodeType::Div,
+            ids: Vec::new(),
+            classes: Vec::new(),
+            tab_index: None,
+            inline_styles: compile_foreign_inline_styles(&css, &matcher, &mut css_blocks),
+            children: compile_foreign_children(xml_node, component_map, &scope, &css, &mut css_blocks, &matcher)?,
+        };
+
+        let mut args = arguments.args.iter()
+        .map(|(arg_name, (arg_type, arg_index))| (*arg_index, arg_name.clone(), arg_type.clone()))
+        .collect::<Vec<_>>();
+        args.sort_by(|a, b| a.0.cmp(&b.0));
+
+        components.push(ForeignComponent {
+            name: component_name.clone(),
+            accepts_text: arguments.accepts_text,
+            args: args.into_iter().map(|(_, name, ty)| (name, ty)).collect(),
+            root,
+        });
+    }
+
+    let body = compile_node_to_foreign_node(
+        body_node,
+        component_map,
+        &ComponentArgumentsMap::default(),
+        &global_style,
+        &mut css_blocks,
+        CssMatcher {
+            path: Vec::new(),
+            indices_in_parent: vec![0],
+            children_length: vec![body_node.children.as_ref().len()],
+        }
+    )?;
+
+    Ok(ForeignUi { css_blocks, components, body })
+}
+
+fn compile_foreign_children<'a>(
+    node: &'a XmlNode,
+    component_map: &'a XmlComponentMap,
+    scope: &ComponentArgumentsMap,
+    css: &Css,
+    css_blocks: &mut BTreeMap<String, String>,
+    matcher: &CssMatcher,
+) -> Result<Vec<ForeignNode>, CompileError<'a>> {
+
+    let children = node.children.as_ref().iter()
+    .filter(|c| normalize_casing(&c.node_type).as_str() != "style")
+    .collect::<Vec<_>>();
+
+    children.iter().enumerate().map(|(child_idx, child_node)| {
+        let mut matcher = matcher.clone();
+        matcher.path.push(CssPathSelector::Children);
+        matcher.indices_in_parent.push(child_idx);
+        matcher.children_length.push(children.len());
+        compile_node_to_foreign_node(child_node, component_map, scope, css, css_blocks, matcher)
+    }).collect()
+}
+
+fn compile_node_to_foreign_node<'a>(
+    node: &'a XmlNode,
+    component_map: &'a XmlComponentMap,
+    scope: &ComponentArgumentsMap,
+    css: &Css,
+    css_blocks: &mut BTreeMap<String, String>,
+    mut matcher: CssMatcher,
+) -> Result<ForeignNode, CompileError<'a>> {
+
+    let component_name = normalize_casing(&node.node_type);
+
+    let (renderer, _) = component_map.components.get(&component_name)
+        .ok_or(ComponentError::UnknownComponent(component_name.clone().into()))?;
+
+    let available_function_args = renderer.get_available_arguments();
+    validate_and_filter_component_args(&node.attributes, &available_function_args)?;
+
+    let node_text = node.text.as_ref().map(|t| prepare_string(t.as_str())).unwrap_or_default();
+
+    let (node_type, node_type_tag) = match component_name.as_str() {
+        "body" => (ForeignNodeType::Body, NodeTypeTag::Body),
+        "div" => (ForeignNodeType::Div, NodeTypeTag::Div),
+        "p" => (ForeignNodeType::Text(split_foreign_dynamic_string(&node_text, scope)), NodeTypeTag::P),
+        _ if normalize_casing(&renderer.get_xml_node().node_type).as_str() == "component" => {
+
+            let text = if available_function_args.accepts_text {
+                Some(split_foreign_dynamic_string(&node_text, scope))
+            } else {
+                None
+            };
+
+            let mut args = available_function_args.args.iter().map(|(arg_name, (arg_type, arg_index))| {
+                let value = node.attributes.get_key(arg_name).map(|s| s.as_str()).unwrap_or("");
+                (*arg_index, arg_type.clone(), split_foreign_dynamic_string(value, scope))
+            }).collect::<Vec<_>>();
+            args.sort_by(|a, b| a.0.cmp(&b.0));
+
+            let node_type = ForeignNodeType::Component {
+                name: component_name.clone(),
+                text,
+                args: args.into_iter().map(|(_, ty, value)| (ty, value)).collect(),
+            };
+
+            (node_type, NodeTypeTag::Div)
+        },
+        other => return Err(ComponentError::UnknownComponent(other.to_string().into()).into()),
+    };
+
+    let ids = node.attributes.get_key("id").map(|s| s.split_whitespace().collect::<Vec<_>>()).unwrap_or_default();
+    let classes = node.attributes.get_key("class").map(|s| s.split_whitespace().collect::<Vec<_>>()).unwrap_or_default();
+
+    matcher.path.push(CssPathSelector::Type(node_type_tag));
+    matcher.path.extend(ids.iter().map(|id| CssPathSelector::Id(id.to_string().into())));
+    matcher.path.extend(classes.iter().map(|class| CssPathSelector::Class(class.to_string().into())));
+
+    let focusable = node.attributes.get_key("focusable")
+        .and_then(|f| parse_bool(f.as_str()))
+        .map(|f| if f { TabIndex::Auto } else { TabIndex::NoKeyboardFocus });
+
+    let tab_index = node.attributes.get_key("tabindex")
+        .and_then(|val| val.as_str().parse::<isize>().ok())
+        .map(|i| match i {
+            0 => TabIndex::Auto,
+            i if i > 0 => TabIndex::OverrideInParent(i as u32),
+            _ => TabIndex::NoKeyboardFocus,
+        });
+
+    Ok(ForeignNode {
+        node_type,
+        ids: ids.iter().map(|id| split_foreign_dynamic_string(id, scope)).collect(),
+        classes: classes.iter().map(|class| split_foreign_dynamic_string(class, scope)).collect(),
+        tab_index: tab_index.or(focusable),
+        inline_styles: compile_foreign_inline_styles(css, &matcher, css_blocks),
+        children: compile_foreign_children(node, component_map, scope, css, css_blocks, &matcher)?,
+    })
+}
+
+/// Formats all CSS blocks matching the node as inline CSS strings and inserts
+/// them into the `css_blocks` - the most specific property comes first
+fn compile_foreign_inline_styles(
+    css: &Css,
+    matcher: &CssMatcher,
+    css_blocks: &mut BTreeMap<String, String>,
+) -> Vec<(&'static str, String)> {
+
+    use azul_css::CssDeclaration;
+    use crate::css::GetHash;
+
+    let mut styles: [(&'static str, Vec<String>);4] = [
+        ("", Vec::new()),
+        ("Hover", Vec::new()),
+        ("Active", Vec::new()),
+        ("Focus", Vec::new()),
+    ];
+
+    for css_block in get_css_blocks(css, matcher).iter().rev() {
+
+        let target = match css_block.ending {
+            Some(CssPathPseudoSelector::Hover) => 1,
+            Some(CssPathPseudoSelector::Active) => 2,
+            Some(CssPathPseudoSelector::Focus) => 3,
+            _ => 0,
+        };
+
+        for declaration in css_block.block.declarations.as_ref().iter().rev() {
+            let prop = match declaration {
+                CssDeclaration::Static(s) => s,
+                CssDeclaration::Dynamic(d) => &d.default_value,
+            };
+            styles[target].1.push(prop.format_css());
+        }
+    }
+
+    styles.iter()
+    .filter(|(_, props)| !props.is_empty())
+    .map(|(wrapper, props)| {
+        let style = props.join(" ");
+        let name = if wrapper.is_empty() {
+            format!("CSS_MATCH_{:09}", style.get_hash())
+        } else {
+            format!("CSS_MATCH_{:09}_{}", style.get_hash(), wrapper.to_uppercase())
+        };
+        css_blocks.insert(name.clone(), style);
+        (*wrapper, name)
+    })
+    .collect()
+}
+
+/// Body of a generated C, C++ or Python function, the nodes get assigned to numbered variables
+#[derive(Default)]
+struct ForeignFunctionBody {
+    lines: Vec<String>,
+    variable_count: usize,
+    /// C: heap-allocated string buffers, freed before the function returns
+    buffers: Vec<String>,
+}
+
+impl ForeignFunctionBody {
+    fn new_variable(&mut self, prefix: &str) -> String {
+        let name = format!("{}_{}", prefix, self.variable_count);
+        self.variable_count += 1;
+        name
+    }
+}
+
+// -- C
+
+fn get_c_type(rust_type: &str) -> &'static str {
+    match rust_type.trim() {
+        "bool" => "bool",
+        "i8" => "int8_t",
+        "i16" => "int16_t",
+        "i32" => "int32_t",
+        "i64" => "int64_t",
+        "isize" => "intptr_t",
+        "u8" => "uint8_t",
+        "u16" => "uint16_t",
+        "u32" => "uint32_t",
+        "u64" => "uint64_t",
+        "usize" => "size_t",
+        "f32" => "float",
+        "f64" => "double",
+        _ => "const char*",
+    }
+}
+
+/// Returns a `const char*` expression, formats the string into a buffer
+/// (sized by a first `snprintf` call) if the string has to be formatted at runtime
+fn format_c_str(input: &[ForeignStringItem], f: &mut ForeignFunctionBody) -> String {
+
+    match input {
+        [] => return String::from("\"\""),
+        [ForeignStringItem::Str(s)] => return format_foreign_string_literal(s),
+        [ForeignStringItem::Var(name, ty)] if ForeignArgType::new(ty) == ForeignArgType::String => return name.clone(),
+        _ => { },
+    }
+
+    let mut format_string = String::new();
+    let mut format_args = Vec::new();
+
+    for item in input {
+        match item {
+            ForeignStringItem::Str(s) => format_string.push_str(&s.replace("%", "%%")),
+            ForeignStringItem::Var(name, ty) => match ForeignArgType::new(ty) {
+                ForeignArgType::String => {
+                    format_string.push_str("%s");
+                    format_args.push(name.clone());
+                },
+                ForeignArgType::Bool => {
+                    format_string.push_str("%s");
+                    format_args.push(format!("{} ? \"true\" : \"false\"", name));
+                },
+                ForeignArgType::Int => {
+                    format_string.push_str("%lld");
+                    format_args.push(format!("(long long){}", name));
+                },
+                ForeignArgType::UInt => {
+                    format_string.push_str("%llu");
+                    format_args.push(format!("(unsigned long long){}", name));
+                },
+                ForeignArgType::Float => {
+                    format_string.push_str("%g");
+                    format_args.push(format!("(double){}", name));
+                },
+            },
+        }
+    }
+
+    let format_string = format_foreign_string_literal(&format_string);
+    let format_args = format_args.join(", ");
+
+    let buffer = f.new_variable("buffer");
+    f.lines.push(format!("int {}_len = snprintf(NULL, 0, {}, {});", buffer, format_string, format_args));
+    f.lines.push(format!("char* {} = (char*)malloc({}_len + 1);", buffer, buffer));
+    f.lines.push(format!("snprintf({}, {}_len + 1, {}, {});", buffer, buffer, format_string, format_args));
+    f.buffers.push(buffer.clone());
+
+    buffer
+}
+
+fn format_c_arg(arg_type: &str, value: &[ForeignStringItem], f: &mut ForeignFunctionBody) -> String {
+    match ForeignArgType::new(arg_type) {
+        ForeignArgType::String => format_c_str(value, f),
+        other => match ForeignScalar::new(other, value) {
+            ForeignScalar::Var(name) => name,
+            ForeignScalar::Literal(l) => l,
+        },
+    }
+}
+
+fn format_c_tab_index(tab_index: &TabIndex) -> String {
+    match tab_index {
+        TabIndex::Auto => String::from("AzTabIndex_Auto"),
+        TabIndex::OverrideInParent(i) => format!("AzTabIndex_OverrideInParent({})", i),
+        TabIndex::NoKeyboardFocus => String::from("AzTabIndex_NoKeyboardFocus"),
+    }
+}
+
+/// Writes the statements creating the node into the function body and returns the variable name
+fn compile_foreign_node_to_c(node: &ForeignNode, f: &mut ForeignFunctionBody) -> String {
+
+    let constructor = match &node.node_type {
+        ForeignNodeType::Body => String::from("AzDom_body()"),
+        ForeignNodeType::Div => String::from("AzDom_div()"),
+        ForeignNodeType::Text(t) => format!("AzDom_text(az_string({}))", format_c_str(t, f)),
+        ForeignNodeType::Component { name, text, args } => {
+            let mut call_args = Vec::new();
+            if let Some(t) = text {
+                call_args.push(format_c_str(t, f));
+            }
+            for (arg_type, value) in args {
+                call_args.push(format_c_arg(arg_type, value, f));
+            }
+            format!("{}_render({})", name, call_args.join(", "))
+        },
+    };
+
+    let var = f.new_variable(node.node_type.get_variable_prefix());
+    f.lines.push(format!("AzDom {} = {};", var, constructor));
+
+    for id in node.ids.iter() {
+        let id = format_c_str(id, f);
+        f.lines.push(format!("AzDom_addId(&{}, az_string({}));", var, id));
+    }
+
+    for class in node.classes.iter() {
+        let class = format_c_str(class, f);
+        f.lines.push(format!("AzDom_addClass(&{}, az_string({}));", var, class));
+    }
+
+    for (wrapper, css_const) in node.inline_styles.iter() {
+        f.lines.push(format!("AzDom_setInline{}Style(&{}, az_string({}));", wrapper, var, css_const));
+    }
+
+    if let Some(tab_index) = node.tab_index.as_ref() {
+        f.lines.push(format!("AzTabIndex {}_tab_index = {};", var, format_c_tab_index(tab_index)));
+        f.lines.push(format!("AzDom_setTabIndex(&{}, {}_tab_index);", var, var));
+    }
+
+    for child in node.children.iter() {
+        let child_var = compile_foreign_node_to_c(child, f);
+        f.lines.push(format!("AzDom_addChild(&{}, {});", var, child_var));
+    }
+
+    var
+}
+
+fn format_c_function(signature: &str, root: &ForeignNode) -> String {
+    let mut f = ForeignFunctionBody::default();
+    let var = compile_foreign_node_to_c(root, &mut f);
+    // the strings are copied into the DOM, the buffers aren't needed anymore
+    for buffer in f.buffers.iter() {
+        f.lines.push(format!("free({});", buffer));
+    }
+    f.lines.push(format!("return {};", var));
+    format!("{} {{\r\n{}\r\n}}", signature, indent_foreign_lines(&f.lines, 1))
+}
+
+fn format_foreign_ui_as_c(ui: &ForeignUi) -> String {
+
+    let css_blocks = ui.css_blocks.iter()
+    .map(|(k, v)| format!("#define {} {}", k, format_foreign_string_literal(v)))
+    .collect::<Vec<String>>()
+    .join("\r\n");
+
+    let signatures = ui.components.iter().map(|c| {
+        let mut args = Vec::new();
+        if c.accepts_text {
+            args.push(String::from("const char* text"));
+        }
+        args.extend(c.args.iter().map(|(name, ty)| format!("{} {}", get_c_type(ty), name)));
+        if args.is_empty() {
+            args.push(String::from("void"));
+        }
+        format!("AzDom {}_render({})", c.name, args.join(", "))
+    }).collect::<Vec<String>>();
+
+    let declarations = signatures.iter().map(|s| format!("{};", s)).collect::<Vec<String>>().join("\r\n");
+
+    let components = ui.components.iter().zip(signatures.iter())
+    .map(|(c, signature)| format_c_function(signature, &c.root))
+    .collect::<Vec<String>>()
+    .join("\r\n\r\n");
+
+    let ui_render = format_c_function("AzDom ui_render(void)", &ui.body);
+
+    let main_func = "\
+typedef struct {
+    uint32_t unused;
+} Data;
+
+void Data_destructor(Data* restrict data) { }
+AZ_REFLECT(Data, Data_destructor);
+
+AzStyledDom layout(AzRefAny* restrict data, AzLayoutCallbackInfo* restrict info) {
+    AzDom dom = ui_render();
+    return AzDom_style(&dom, AzCss_empty()); // styles are applied inline
+}
+
+int main() {
+    Data data = { .unused = 0 };
+    AzApp app = AzApp_new(Data_upcast(data), AzAppConfig_new(AzLayoutSolver_Default));
+    AzApp_run(&app, AzWindowCreateOptions_new(layout));
+    AzApp_delete(&app);
+    return 0;
+}";
+
+    let helpers = "\
+static AzString az_string(const char* s) {
+    return AzString_copyFromBytes((uint8_t*)s, 0, strlen(s));
+}";
+
+    [
+        "// Auto-generated UI source code\r\n#include \"azul.h\"\r\n#include <stdbool.h>\r\n#include <stdint.h>\r\n#include <stdio.h>\r\n#include <stdlib.h>\r\n#include <string.h>",
+        &css_blocks,
+        helpers,
+        &declarations,
+        &components,
+        &ui_render,
+        main_func,
+    ].iter()
+    .filter(|s| !s.is_empty())
+    .map(|s| s.to_string())
+    .collect::<Vec<String>>()
+    .join("\r\n\r\n")
+}
+
+// -- C++
+
+fn get_cpp_type(rust_type: &str) -> &'static str {
+    match ForeignArgType::new(rust_type) {
+        ForeignArgType::String => "std::string const&",
+        _ => get_c_type(rust_type),
+    }
+}
+
+/// Returns an expression that can be converted to a `std::string`
+fn format_cpp_str(input: &[ForeignStringItem]) -> String {
+
+    let format_var = |name: &str, ty: &str| match ForeignArgType::new(ty) {
+        ForeignArgType::String => name.to_string(),
+        ForeignArgType::Bool => format!("std::string({} ? \"true\" : \"false\")", name),
+        _ => format!("std::to_string({})", name),
+    };
+
+    match input {
+        [] => String::from("\"\""),
+        [ForeignStringItem::Str(s)] => format_foreign_string_literal(s),
+        [ForeignStringItem::Var(name, ty)] => format_var(name, ty),
+        _ => input.iter().enumerate().map(|(i, item)| match item {
+            ForeignStringItem::Str(s) if i == 0 => format!("std::string({})", format_foreign_string_literal(s)),
+            ForeignStringItem::Str(s) => format_foreign_string_literal(s),
+            ForeignStringItem::Var(name, ty) => format_var(name, ty),
+        }).collect::<Vec<String>>().join(" + "),
+    }
+}
+
+fn format_cpp_string(input: &[ForeignStringItem]) -> String {
+    format!("az_string({})", format_cpp_str(input))
+}
+
+fn format_cpp_tab_index(tab_index: &TabIndex) -> String {
+    match tab_index {
+        TabIndex::Auto => String::from("TabIndex { .Auto = { .tag = TabIndexTag::Auto } }"),
+        TabIndex::OverrideInParent(i) => format!("TabIndex {{ .OverrideInParent = {{ .tag = TabIndexTag::OverrideInParent, .payload = {} }} }}", i),
+        TabIndex::NoKeyboardFocus => String::from("TabIndex { .NoKeyboardFocus = { .tag = TabIndexTag::NoKeyboardFocus } }"),
+    }
+}
+
+/// Writes the statements creating the node into the function body and returns the variable name
+fn compile_foreign_node_to_cpp(node: &ForeignNode, f: &mut ForeignFunctionBody) -> String {
+
+    let constructor = match &node.node_type {
+        ForeignNodeType::Body => String::from("Dom_body()"),
+        ForeignNodeType::Div => String::from("Dom_div()"),
+        ForeignNodeType::Text(text) => format!("Dom_text({})", format_cpp_string(text)),
+        ForeignNodeType::Component { name, text, args } => {
+            let mut call_args = Vec::new();
+            if let Some(text) = text {
+                call_args.push(format_cpp_str(text));
+            }
+            for (arg_type, value) in args {
+                call_args.push(match ForeignArgType::new(arg_type) {
+                    ForeignArgType::String => format_cpp_str(value),
+                    other => match ForeignScalar::new(other, value) {
+                        ForeignScalar::Var(name) => name,
+                        ForeignScalar::Literal(l) => l,
+                    },
+                });
+            }
+            format!("{}_render({})", name, call_args.join(", "))
+        },
+    };
+
+    let var = f.new_variable(node.node_type.get_variable_prefix());
+    f.lines.push(format!("Dom {} = {};", var, constructor));
+
+    for id in node.ids.iter() {
+        f.lines.push(format!("Dom_addId(&{}, {});", var, format_cpp_string(id)));
+    }
+
+    for class in node.classes.iter() {
+        f.lines.push(format!("Dom_addClass(&{}, {});", var, format_cpp_string(class)));
+    }
+
+    for (wrapper, css_const) in node.inline_styles.iter() {
+        f.lines.push(format!("Dom_setInline{}Style(&{}, az_string({}));", wrapper, var, css_const));
+    }
+
+    if let Some(tab_index) = node.tab_index.as_ref() {
+        f.lines.push(format!("Dom_setTabIndex(&{}, {});", var, format_cpp_tab_index(tab_index)));
+    }
+
+    for child in node.children.iter() {
+        let child_var = compile_foreign_node_to_cpp(child, f);
+        f.lines.push(format!("Dom_addChild(&{}, std::move({}));", var, child_var));
+    }
+
+    var
+}
+
+fn format_cpp_function(signature: &str, root: &ForeignNode) -> String {
+    let mut f = ForeignFunctionBody::default();
+    let var = compile_foreign_node_to_cpp(root, &mut f);
+    f.lines.push(format!("return {};", var));
+    format!("{} {{\r\n{}\r\n}}", signature, indent_foreign_lines(&f.lines, 1))
+}
+
+fn format_foreign_ui_as_cpp(ui: &ForeignUi) -> String {
+
+    let css_blocks = ui.css_blocks.iter()
+    .map(|(k, v)| format!("static constexpr const char {}[] = {};", k, format_foreign_string_literal(v)))
+    .collect::<Vec<String>>()
+    .join("\r\n");
+
+    let signatures = ui.components.iter().map(|c| {
+        let mut args = Vec::new();
+        if c.accepts_text {
+            args.push(String::from("std::string const& text"));
+        }
+        args.extend(c.args.iter().map(|(name, ty)| format!("{} {}", get_cpp_type(ty), name)));
+        format!("Dom {}_render({})", c.name, args.join(", "))
+    }).collect::<Vec<String>>();
+
+    let declarations = signatures.iter().map(|s| format!("{};", s)).collect::<Vec<String>>().join("\r\n");
+
+    let components = ui.components.iter().zip(signatures.iter())
+    .map(|(c, signature)| format_cpp_function(signature, &c.root))
+    .collect::<Vec<String>>()
+    .join("\r\n\r\n");
+
+    let ui_render = format_cpp_function("Dom ui_render()", &ui.body);
+
+    let main_func = "\
+struct Data { };
+
+// all statics have a unique address, use that address as the type ID
+static uint64_t const Data_RttiTypePtrId = 0;
+
+void Data_destructor(void* data) { }
+
+StyledDom layout(RefAny* data, LayoutCallbackInfo* info) {
+    Dom dom = ui_render();
+    return Dom_style(&dom, Css_empty()); // styles are applied inline
+}
+
+int main() {
+    Data data { };
+    uint64_t type_id = (uint64_t)(uintptr_t)&Data_RttiTypePtrId;
+    RefAny refany = RefAny_newC(&data, sizeof(Data), type_id, az_string(\"Data\"), Data_destructor);
+    App app = App_new(std::move(refany), AppConfig_new(LayoutSolver::Default));
+    App_run(&app, WindowCreateOptions_new(layout));
+    App_delete(&app);
+    return 0;
+}";
+
+    let helpers = "\
+static String az_string(std::string const& s) {
+    return String_copyFromBytes((uint8_t*)s.data(), 0, s.size());
+}";
+
+    [
+        "// Auto-generated UI source code\r\n#include \"azul.hpp\"\r\n#include <cstdint>\r\n#include <string>\r\n#include <utility>\r\n\r\nusing namespace dll;",
+        &css_blocks,
+        helpers,
+        &declarations,
+        &components,
+        &ui_render,
+        main_func,
+    ].iter()
+    .filter(|s| !s.is_empty())
+    .map(|s| s.to_string())
+    .collect::<Vec<String>>()
+    .join("\r\n\r\n")
+}
+
+// -- Python
+
+fn get_python_type(rust_type: &str) -> (&'static str, &'static str) {
+    match ForeignArgType::new(rust_type) {
+        ForeignArgType::String => ("str", "\"\""),
+        ForeignArgType::Bool => ("bool", "False"),
+        ForeignArgType::Int | ForeignArgType::UInt => ("int", "0"),
+        ForeignArgType::Float => ("float", "0.0"),
+    }
+}
+
+fn format_python_str(input: &[ForeignStringItem]) -> String {
+
+    match input {
+        [] => return String::from("\"\""),
+        [ForeignStringItem::Str(s)] => return format_foreign_string_literal(s),
+        [ForeignStringItem::Var(name, ty)] => return match ForeignArgType::new(ty) {
+            ForeignArgType::String => name.clone(),
+            ForeignArgType::Bool => format!("(\"true\" if {} else \"false\")", name),
+            _ => format!("str({})", name),
+        },
+        _ => { },
+    }
+
+    // build an f-string
+    let mut s = String::from("f");
+    let formatted = input.iter().map(|item| match item {
+        ForeignStringItem::Str(s) => s.replace("{", "{{").replace("}", "}}"),
+        ForeignStringItem::Var(name, ty) => match ForeignArgType::new(ty) {
+            ForeignArgType::Bool => format!("{{'true' if {} else 'false'}}", name),
+            _ => format!("{{{}}}", name),
+        },
+    }).collect::<String>();
+    s.push_str(&format_foreign_string_literal(&formatted));
+    s
+}
+
+fn format_python_arg(arg_type: &str, value: &[ForeignStringItem]) -> String {
+    match ForeignArgType::new(arg_type) {
+        ForeignArgType::String => format_python_str(value),
+        other => match ForeignScalar::new(other, value) {
+            ForeignScalar::Var(name) => name,
+            ForeignScalar::Literal(l) if other == ForeignArgType::Bool => {
+                if l == "true" { String::from("True") } else { String::from("False") }
+            },
+            ForeignScalar::Literal(l) => l,
+        },
+    }
+}
+
+fn format_python_tab_index(tab_index: &TabIndex) -> String {
+    match tab_index {
+        TabIndex::Auto => String::from("TabIndex.Auto()"),
+        TabIndex::OverrideInParent(i) => format!("TabIndex.OverrideInParent({})", i),
+        TabIndex::NoKeyboardFocus => String::from("TabIndex.NoKeyboardFocus()"),
+    }
+}
+
+/// Writes the statements creating the node into the function body and returns the variable name
+fn compile_foreign_node_to_python(node: &ForeignNode, f: &mut ForeignFunctionBody) -> String {
+
+    let constructor = match &node.node_type {
+        ForeignNodeType::Body => String::from("Dom.body()"),
+        ForeignNodeType::Div => String::from("Dom.div()"),
+        ForeignNodeType::Text(t) => format!("Dom.text({})", format_python_str(t)),
+        ForeignNodeType::Component { name, text, args } => {
+            let mut call_args = Vec::new();
+            if let Some(t) = text {
+                call_args.push(format_python_str(t));
+            }
+            for (arg_type, value) in args {
+                call_args.push(format_python_arg(arg_type, value));
+            }
+            format!("{}_render({})", name, call_args.join(", "))
+        },
+    };
+
+    let var = f.new_variable(node.node_type.get_variable_prefix());
+    f.lines.push(format!("{} = {}", var, constructor));
+
+    for id in node.ids.iter() {
+        f.lines.push(format!("{}.add_id({})", var, format_python_str(id)));
+    }
+
+    for class in node.classes.iter() {
+        f.lines.push(format!("{}.add_class({})", var, format_python_str(class)));
+    }
+
+    for (wrapper, css_const) in node.inline_styles.iter() {
+        let method = if wrapper.is_empty() {
+            String::from("set_inline_style")
+        } else {
+            format!("set_inline_{}_style", wrapper.to_lowercase())
+        };
+        f.lines.push(format!("{}.{}({})", var, method, css_const));
+    }
+
+    if let Some(tab_index) = node.tab_index.as_ref() {
+        f.lines.push(format!("{}.set_tab_index({})", var, format_python_tab_index(tab_index)));
+    }
+
+    for child in node.children.iter() {
+        let child_var = compile_foreign_node_to_python(child, f);
+        f.lines.push(format!("{}.add_child({})", var, child_var));
+    }
+
+    var
+}
+
+fn format_python_function(signature: &str, root: &ForeignNode) -> String {
+    let mut f = ForeignFunctionBody::default();
+    let var = compile_foreign_node_to_python(root, &mut f);
+    f.lines.push(format!("return {}", var));
+    format!("{}\r\n{}", signature, indent_foreign_lines(&f.lines, 1))
+}
+
+fn format_foreign_ui_as_python(ui: &ForeignUi) -> String {
+
+    let css_blocks = ui.css_blocks.iter()
+    .map(|(k, v)| format!("{} = {}", k, format_foreign_string_literal(v)))
+    .collect::<Vec<String>>()
+    .join("\r\n");
+
+    let components = ui.components.iter().map(|c| {
+        let mut args = Vec::new();
+        if c.accepts_text {
+            args.push(String::from("text: str = \"\""));
+        }
+        args.extend(c.args.iter().map(|(name, ty)| {
+            let (python_type, default) = get_python_type(ty);
+            format!("{}: {} = {}", name, python_type, default)
+        }));
+        format_python_function(&format!("def {}_render({}):", c.name, args.join(", ")), &c.root)
+    }).collect::<Vec<String>>()
+    .join("\r\n\r\n");
+
+    let ui_render = format_python_function("def ui_render():", &ui.body);
+
+    let main_func = "\
+class Data:
+    pass
+
+def layout(data, info):
+    return ui_render().style(Css.empty()) # styles are applied inline
+
+app = App(Data(), AppConfig(LayoutSolver.Default))
+app.run(WindowCreateOptions(layout))";
+
+    [
+        "# Auto-generated UI source code\r\nfrom azul import *",
+        &css_blocks,
+        &components,
+        &ui_render,
+        main_func,
+    ].iter()
+    .filter(|s| !s.is_empty())
+    .map(|s| s.to_string())
+    .collect::<Vec<String>>()
+    .join("\r\n\r\n")
+}
+
 // -- Tests
 #[cfg(test)] mod tests {
 
@@ -16,6 +16,9 @@ use azul_css::{
     CssPropertyType, CssRuleBlock, CssPath, CssPathSelector,
     CssNthChildSelector, CssPathPseudoSelector, CssNthChildSelector::*,
     NodeTypeTag, NodeTypeTagParseError, CombinedCssPropertyType, CssKeyMap,
+    CssKeyframes, CssKeyframe, PercentageValue, CssMediaRule, CssMediaQuery,
+    CssMediaType, CssMediaFeature, CssMediaRange, CssMediaOrientation, CssColorScheme,
+    OptionF32,
 };
 
 #[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
@@ -75,6 +78,14 @@ pub enum CssParseErrorInner<'a> {
     /// when setting the variable, whether all sides should be set, instead, you have to use `margin-top: var(--blah)`,
     /// `margin-bottom: var(--baz)` in order to work around this limitation.
     VarOnShorthandProperty { key: CombinedCssPropertyType, value: &'a str },
+    /// `@keyframes` without a name, i.e. `@keyframes { ... }`
+    MissingKeyframesName,
+    /// Keyframe selector that isn't `from`, `to` or a percentage, for example `@keyframes x { 50px { ... } }`
+    InvalidKeyframeSelector(&'a str),
+    /// Media query that can't be parsed, for example `@media (min-width: 50%) { ... }`
+    InvalidMediaQuery(&'a str),
+    /// `@media` block inside of another `@media` block
+    NestedMediaRule,
 }
 
 impl_display!{ CssParseErrorInner<'a>, {
@@ -89,6 +100,10 @@ impl_display!{ CssParseErrorInner<'a>, {
         "Error while parsing: \"{}: {};\": var() cannot be used on shorthand properties - use `{}-top` or `{}-x` as the key instead: ",
         key, value, key, key
     ),
+    MissingKeyframesName => "@keyframes block has no name",
+    InvalidKeyframeSelector(e) => format!("Invalid keyframe selector: \"{}\" - expected \"from\", \"to\" or a percentage", e),
+    InvalidMediaQuery(e) => format!("Invalid media query: \"{}\"", e),
+    NestedMediaRule => "@media blocks can't be nested",
 }}
 
 impl<'a> From<CssSyntaxError> for CssParseErrorInner<'a> {
@@ -311,11 +326,609 @@ impl<'a> fmt::Display for CssParseError<'a> {
 }
 
 pub fn new_from_str<'a>(css_string: &'a str) -> Result<Css, CssParseError<'a>> {
-    let mut tokenizer = Tokenizer::new(css_string);
-    let (stylesheet, _warnings) = new_from_str_inner(css_string, &mut tokenizer)?;
+    let (stylesheet, _warnings) = new_stylesheet_from_str(css_string)?;
     Ok(Css { stylesheets: vec![stylesheet].into() })
 }
 
+/// Parses a CSS string into one stylesheet, including the `@keyframes` and `@media` blocks
+///
+/// The tokenizer can't parse the keyframe selectors (`from`, `50%`) or the media
+/// queries, so the at-rule blocks are cut out of the string first and parsed separately,
+/// the remaining segments are tokenized one by one.
+fn new_stylesheet_from_str<'a>(css_string: &'a str)
+-> Result<(Stylesheet, Vec<CssParseWarnMsg<'a>>), CssParseError<'a>> {
+    let mut warnings = Vec::new();
+    let css_key_map = azul_css::get_css_key_map();
+    let (rules, keyframes, media_rules) = parse_rules_in_range(css_string, (0, css_string.len()), true, &css_key_map, &mut warnings)?;
+    Ok((Stylesheet { rules: rules.into(), keyframes: keyframes.into(), media_rules: media_rules.into() }, warnings))
+}
+
+/// Parses the rules, `@keyframes` and `@media` blocks in the given byte range of the CSS string
+///
+/// `@keyframes` blocks inside of a `@media` block are not conditional,
+/// they are added to the stylesheet like top-level `@keyframes` blocks.
+fn parse_rules_in_range<'a>(
+    css_string: &'a str,
+    range: (usize, usize),
+    allow_media_rules: bool,
+    css_key_map: &CssKeyMap,
+    warnings: &mut Vec<CssParseWarnMsg<'a>>,
+) -> Result<(Vec<CssRuleBlock>, Vec<CssKeyframes>, Vec<CssMediaRule>), CssParseError<'a>> {
+
+    let (segments, unparsed_keyframes, unparsed_media) = split_top_level_at_rules(css_string, range)?;
+
+    let mut rules = Vec::new();
+    // number of rules before each segment, used to restore the source order of @media blocks
+    let mut rules_before_segment = Vec::with_capacity(segments.len() + 1);
+
+    for (start, end) in segments {
+        rules_before_segment.push(rules.len());
+        if css_string[start..end].trim().is_empty() {
+            continue;
+        }
+        let mut tokenizer = Tokenizer::new_bound(css_string, start, end);
+        let (stylesheet, segment_warnings) = new_from_str_inner(css_string, &mut tokenizer)?;
+        rules.extend(stylesheet.rules.into_library_owned_vec());
+        warnings.extend(segment_warnings);
+    }
+
+    rules_before_segment.push(rules.len());
+
+    let mut keyframes = unparsed_keyframes.into_iter()
+        .map(|k| parse_keyframes_block(css_string, k, css_key_map, warnings))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut media_rules = Vec::new();
+
+    for media in unparsed_media {
+
+        let error = |error| CssParseError {
+            css_string,
+            error,
+            location: (ErrorLocation { original_pos: media.start }, ErrorLocation { original_pos: media.body.0 }),
+        };
+
+        if !allow_media_rules {
+            return Err(error(CssParseErrorInner::NestedMediaRule));
+        }
+
+        let queries = parse_media_query_list(media.query).map_err(error)?;
+        let (media_block_rules, media_keyframes, _) = parse_rules_in_range(css_string, media.body, false, css_key_map, warnings)?;
+        keyframes.extend(media_keyframes);
+
+        media_rules.push(CssMediaRule {
+            queries: queries.into(),
+            insert_index: rules_before_segment[media.segment_index],
+            rules: media_block_rules.into(),
+        });
+    }
+
+    Ok((rules, keyframes, media_rules))
+}
+
+/// `@keyframes name { ... }` block, cut out of the CSS string, but not yet parsed
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct UnparsedKeyframes<'a> {
+    name: &'a str,
+    /// Position of the `@`
+    start: usize,
+    /// Byte range of the block contents, without the outer braces
+    body: (usize, usize),
+}
+
+/// `@media query { ... }` block, cut out of the CSS string, but not yet parsed
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct UnparsedMedia<'a> {
+    query: &'a str,
+    /// Position of the `@`
+    start: usize,
+    /// Byte range of the block contents, without the outer braces
+    body: (usize, usize),
+    /// Number of segments in front of this block
+    segment_index: usize,
+}
+
+/// Returns the position of the first byte after the comment or string starting at `pos`,
+/// or `None` if there is no comment or string at `pos`
+fn skip_comment_or_string(bytes: &[u8], pos: usize) -> Option<usize> {
+    match bytes[pos] {
+        b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+            let mut i = pos + 2;
+            while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                i += 1;
+            }
+            Some((i + 2).min(bytes.len()))
+        },
+        quote @ b'"' | quote @ b'\'' => {
+            let mut i = pos + 1;
+            while i < bytes.len() && bytes[i] != quote {
+                if bytes[i] == b'\\' { i += 1; }
+                i += 1;
+            }
+            Some((i + 1).min(bytes.len()))
+        },
+        _ => None,
+    }
+}
+
+/// Returns the position of the `}` matching the `{` at `open_brace`
+fn find_closing_brace(bytes: &[u8], open_brace: usize) -> Option<usize> {
+    let mut depth = 0_usize;
+    let mut i = open_brace;
+    while i < bytes.len() {
+        if let Some(next) = skip_comment_or_string(bytes, i) {
+            i = next;
+            continue;
+        }
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            },
+            _ => { },
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Splits the given range of the CSS string at the top-level `@keyframes` and `@media` blocks,
+/// returns the byte ranges of the segments in between and the unparsed at-rule blocks
+fn split_top_level_at_rules<'a>(css_string: &'a str, range: (usize, usize))
+-> Result<(Vec<(usize, usize)>, Vec<UnparsedKeyframes<'a>>, Vec<UnparsedMedia<'a>>), CssParseError<'a>> {
+
+    const KEYFRAMES: &[&str] = &["@keyframes", "@-webkit-keyframes"];
+    const MEDIA: &str = "@media";
+
+    let (range_start, range_end) = range;
+    let bytes = &css_string.as_bytes()[..range_end];
+    let mut segments = Vec::new();
+    let mut keyframes = Vec::new();
+    let mut media_rules = Vec::new();
+    let mut segment_start = range_start;
+    let mut depth = 0_usize;
+    let mut i = range_start;
+
+    while i < bytes.len() {
+
+        if let Some(next) = skip_comment_or_string(bytes, i) {
+            i = next;
+            continue;
+        }
+
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => depth = depth.saturating_sub(1),
+            b'@' if depth == 0 => {
+                let rest = &css_string[i..range_end];
+                let is_at_keyword = |k: &str| {
+                    rest.starts_with(k) &&
+                    rest[k.len()..].chars().next().map(|c| c.is_whitespace() || c == '{' || c == '(').unwrap_or(false)
+                };
+                let error = |error, end| CssParseError {
+                    css_string,
+                    error,
+                    location: (ErrorLocation { original_pos: i }, ErrorLocation { original_pos: end }),
+                };
+
+                if is_at_keyword(MEDIA) {
+                    let open_brace = rest.find('{')
+                        .map(|b| i + b)
+                        .ok_or(error(CssParseErrorInner::UnclosedBlock, bytes.len()))?;
+                    let close_brace = find_closing_brace(bytes, open_brace)
+                        .ok_or(error(CssParseErrorInner::UnclosedBlock, bytes.len()))?;
+
+                    segments.push((segment_start, i));
+                    media_rules.push(UnparsedMedia {
+                        query: css_string[(i + MEDIA.len())..open_brace].trim(),
+                        start: i,
+                        body: (open_brace + 1, close_brace),
+                        segment_index: segments.len(),
+                    });
+                    segment_start = close_brace + 1;
+                    i = close_brace + 1;
+                    continue;
+                }
+
+                if let Some(at_keyword) = KEYFRAMES.iter().find(|k| is_at_keyword(k)) {
+                    let open_brace = rest.find('{')
+                        .map(|b| i + b)
+                        .ok_or(error(CssParseErrorInner::UnclosedBlock, bytes.len()))?;
+                    let close_brace = find_closing_brace(bytes, open_brace)
+                        .ok_or(error(CssParseErrorInner::UnclosedBlock, bytes.len()))?;
+                    let name = css_string[(i + at_keyword.len())..open_brace].trim();
+                    let name = css_parser::strip_quotes(name).map(|q| q.0).unwrap_or(name);
+                    if name.is_empty() {
+                        return Err(error(CssParseErrorInner::MissingKeyframesName, open_brace));
+                    }
+
+                    segments.push((segment_start, i));
+                    keyframes.push(UnparsedKeyframes { name, start: i, body: (open_brace + 1, close_brace) });
+                    segment_start = close_brace + 1;
+                    i = close_brace + 1;
+                    continue;
+                }
+            },
+            _ => { },
+        }
+
+        i += 1;
+    }
+
+    segments.push((segment_start, bytes.len()));
+
+    Ok((segments, keyframes, media_rules))
+}
+
+/// Parses the contents of a `@keyframes` block, i.e. `from { opacity: 0; } 50%, 75% { opacity: 0.8; }`
+fn parse_keyframes_block<'a>(
+    css_string: &'a str,
+    unparsed: UnparsedKeyframes<'a>,
+    css_key_map: &CssKeyMap,
+    warnings: &mut Vec<CssParseWarnMsg<'a>>,
+) -> Result<CssKeyframes, CssParseError<'a>> {
+
+    let bytes = css_string.as_bytes();
+    let (body_start, body_end) = unparsed.body;
+    let mut frames = Vec::new();
+    let mut i = body_start;
+
+    let error = |error, start, end| CssParseError {
+        css_string,
+        error,
+        location: (ErrorLocation { original_pos: start }, ErrorLocation { original_pos: end }),
+    };
+
+    while i < body_end {
+
+        if bytes[i].is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if let Some(next) = skip_comment_or_string(bytes, i) {
+            i = next;
+            continue;
+        }
+
+        let open_brace = css_string[i..body_end].find('{')
+            .map(|b| i + b)
+            .ok_or(error(CssParseErrorInner::MalformedCss, i, body_end))?;
+        let close_brace = find_closing_brace(&bytes[..body_end], open_brace)
+            .ok_or(error(CssParseErrorInner::UnclosedBlock, i, body_end))?;
+
+        let mut offsets = Vec::new();
+        for selector in css_string[i..open_brace].split(',') {
+            let selector = selector.trim();
+            let offset = match selector {
+                "from" => Some(0.0),
+                "to" => Some(100.0),
+                s if s.ends_with('%') => s[..s.len() - 1].trim().parse::<f32>().ok().filter(|p| *p >= 0.0 && *p <= 100.0),
+                _ => None,
+            };
+            let offset = offset.ok_or(error(CssParseErrorInner::InvalidKeyframeSelector(selector), i, open_brace))?;
+            offsets.push(PercentageValue::new(offset));
+        }
+
+        let mut declarations = Vec::new();
+        for declaration in split_declarations(css_string, open_brace + 1, close_brace) {
+            let (start, end) = declaration;
+            let text = &css_string[start..end];
+            let colon = text.find(':').ok_or(error(CssParseErrorInner::MalformedCss, start, end))?;
+            let location = (ErrorLocation { original_pos: start }, ErrorLocation { original_pos: end });
+            parse_css_declaration(
+                text[..colon].trim(),
+                text[(colon + 1)..].trim(),
+                location,
+                css_key_map,
+                warnings,
+                &mut declarations,
+            ).map_err(|e| error(e, start, end))?;
+        }
+
+        let properties = declarations.into_iter().map(|d| match d {
+            CssDeclaration::Static(s) => s,
+            CssDeclaration::Dynamic(d) => d.default_value,
+        }).collect::<Vec<_>>();
+
+        frames.extend(offsets.into_iter().map(|offset| CssKeyframe {
+            offset,
+            properties: properties.clone().into(),
+        }));
+
+        i = close_brace + 1;
+    }
+
+    // stable sort, so that keyframes with the same offset keep their order
+    frames.sort_by(|a, b| a.offset.partial_cmp(&b.offset).unwrap_or(core::cmp::Ordering::Equal));
+
+    Ok(CssKeyframes {
+        name: unparsed.name.to_string().into(),
+        frames: frames.into(),
+    })
+}
+
+/// Parses a comma-separated media query list, i.e. `screen and (min-width: 600px), print`
+fn parse_media_query_list<'a>(input: &'a str) -> Result<Vec<CssMediaQuery>, CssParseErrorInner<'a>> {
+    input.split(',').map(|q| parse_media_query(q.trim())).collect()
+}
+
+/// Parses one media query, i.e. `not screen and (orientation: portrait)`
+fn parse_media_query<'a>(input: &'a str) -> Result<CssMediaQuery, CssParseErrorInner<'a>> {
+
+    let error = || CssParseErrorInner::InvalidMediaQuery(input);
+
+    let mut negated = false;
+    let mut media_type = CssMediaType::All;
+    let mut features = Vec::new();
+    let mut rest = input;
+
+    let (first_word, remaining) = split_first_word(rest);
+    if first_word.eq_ignore_ascii_case("not") || first_word.eq_ignore_ascii_case("only") {
+        negated = first_word.eq_ignore_ascii_case("not");
+        rest = remaining;
+    }
+
+    if !rest.starts_with('(') {
+        let (word, remaining) = split_first_word(rest);
+        media_type = match word.to_ascii_lowercase().as_str() {
+            "all" => CssMediaType::All,
+            "screen" => CssMediaType::Screen,
+            "print" => CssMediaType::Print,
+            _ => return Err(error()),
+        };
+        rest = remaining;
+        if !rest.is_empty() {
+            let (and, remaining) = split_first_word(rest);
+            if !and.eq_ignore_ascii_case("and") || remaining.is_empty() {
+                return Err(error());
+            }
+            rest = remaining;
+        }
+    }
+
+    while !rest.is_empty() {
+        if !rest.starts_with('(') {
+            return Err(error());
+        }
+        let close = rest.find(')').ok_or_else(error)?;
+        features.push(parse_media_feature(&rest[1..close]).ok_or_else(error)?);
+        rest = rest[(close + 1)..].trim_start();
+        if !rest.is_empty() {
+            let (and, remaining) = split_first_word(rest);
+            if !and.eq_ignore_ascii_case("and") || remaining.is_empty() {
+                return Err(error());
+            }
+            rest = remaining;
+        }
+    }
+
+    Ok(CssMediaQuery { negated, media_type, features: features.into() })
+}
+
+/// Splits `"screen and (...)"` into `("screen", "and (...)")`
+fn split_first_word(input: &str) -> (&str, &str) {
+    let input = input.trim_start();
+    let end = input.find(|c: char| c.is_whitespace() || c == '(').unwrap_or(input.len());
+    (&input[..end], input[end..].trim_start())
+}
+
+/// Numeric media features that can be used in ranges
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum MediaRangeFeature {
+    Width,
+    Height,
+    Resolution,
+}
+
+impl MediaRangeFeature {
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "width" => Some(MediaRangeFeature::Width),
+            "height" => Some(MediaRangeFeature::Height),
+            "resolution" => Some(MediaRangeFeature::Resolution),
+            _ => None,
+        }
+    }
+
+    /// Parses the value into logical pixels (width / height) or dppx (resolution)
+    fn parse_value(&self, value: &str) -> Option<f32> {
+        let value = value.trim();
+        match self {
+            MediaRangeFeature::Width | MediaRangeFeature::Height => {
+                css_parser::parse_pixel_value_no_percent(value).ok().map(|p| p.to_pixels())
+            },
+            MediaRangeFeature::Resolution => {
+                const UNITS: &[(&str, f32)] = &[("dppx", 1.0), ("dpcm", 2.54 / 96.0), ("dpi", 1.0 / 96.0), ("x", 1.0)];
+                let (unit, factor) = UNITS.iter().find(|(unit, _)| value.ends_with(unit))?;
+                value[..(value.len() - unit.len())].trim().parse::<f32>().ok().map(|v| v * factor)
+            },
+        }
+    }
+
+    fn into_feature(self, range: CssMediaRange) -> CssMediaFeature {
+        match self {
+            MediaRangeFeature::Width => CssMediaFeature::Width(range),
+            MediaRangeFeature::Height => CssMediaFeature::Height(range),
+            MediaRangeFeature::Resolution => CssMediaFeature::Resolution(range),
+        }
+    }
+}
+
+/// Parses the inside of a media feature, i.e. `min-width: 600px`,
+/// `prefers-color-scheme: dark` or `400px <= width < 700px`
+fn parse_media_feature(input: &str) -> Option<CssMediaFeature> {
+
+    if let Some(colon) = input.find(':') {
+        let name = input[..colon].trim().to_ascii_lowercase();
+        let value = input[(colon + 1)..].trim();
+        return match name.as_str() {
+            "orientation" => match value.to_ascii_lowercase().as_str() {
+                "portrait" => Some(CssMediaFeature::Orientation(CssMediaOrientation::Portrait)),
+                "landscape" => Some(CssMediaFeature::Orientation(CssMediaOrientation::Landscape)),
+                _ => None,
+            },
+            "prefers-color-scheme" => match value.to_ascii_lowercase().as_str() {
+                "light" => Some(CssMediaFeature::PrefersColorScheme(CssColorScheme::Light)),
+                "dark" => Some(CssMediaFeature::PrefersColorScheme(CssColorScheme::Dark)),
+                _ => None,
+            },
+            n if n.starts_with("min-") => {
+                let feature = MediaRangeFeature::from_name(&n[4..])?;
+                Some(feature.into_feature(CssMediaRange::at_least(feature.parse_value(value)?, true)))
+            },
+            n if n.starts_with("max-") => {
+                let feature = MediaRangeFeature::from_name(&n[4..])?;
+                Some(feature.into_feature(CssMediaRange::at_most(feature.parse_value(value)?, true)))
+            },
+            n => {
+                let feature = MediaRangeFeature::from_name(n)?;
+                Some(feature.into_feature(CssMediaRange::exact(feature.parse_value(value)?)))
+            },
+        };
+    }
+
+    // range syntax: "width >= 600px", "600px <= width" or "400px < width <= 700px"
+    let mut operands = Vec::new();
+    let mut operators = Vec::new();
+    let mut rest = input;
+    while let Some(pos) = rest.find(|c| c == '<' || c == '>' || c == '=') {
+        let op_len = if rest[(pos + 1)..].starts_with('=') { 2 } else { 1 };
+        operands.push(rest[..pos].trim());
+        operators.push(MediaRangeOperator::from_str(&rest[pos..(pos + op_len)])?);
+        rest = &rest[(pos + op_len)..];
+    }
+    operands.push(rest.trim());
+
+    match (operands.as_slice(), operators.as_slice()) {
+        ([name, value], [op]) => {
+            if let Some(feature) = MediaRangeFeature::from_name(name) {
+                let value = feature.parse_value(value)?;
+                Some(feature.into_feature(op.into_range(value)))
+            } else {
+                // "600px <= width" is the same as "width >= 600px"
+                let feature = MediaRangeFeature::from_name(value)?;
+                let value = feature.parse_value(name)?;
+                Some(feature.into_feature(op.flip().into_range(value)))
+            }
+        },
+        ([low, name, high], [low_op, high_op]) => {
+            let feature = MediaRangeFeature::from_name(name)?;
+            let low_range = low_op.flip().into_range(feature.parse_value(low)?);
+            let high_range = high_op.into_range(feature.parse_value(high)?);
+            // both operators have to point in the same direction
+            match (low_range.min, high_range.max, low_range.max, high_range.min) {
+                (OptionF32::Some(_), OptionF32::Some(_), OptionF32::None, OptionF32::None) => {
+                    Some(feature.into_feature(CssMediaRange {
+                        min: low_range.min,
+                        min_inclusive: low_range.min_inclusive,
+                        max: high_range.max,
+                        max_inclusive: high_range.max_inclusive,
+                    }))
+                },
+                (OptionF32::None, OptionF32::None, OptionF32::Some(_), OptionF32::Some(_)) => {
+                    Some(feature.into_feature(CssMediaRange {
+                        min: high_range.min,
+                        min_inclusive: high_range.min_inclusive,
+                        max: low_range.max,
+                        max_inclusive: low_range.max_inclusive,
+                    }))
+                },
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum MediaRangeOperator {
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Equal,
+}
+
+impl MediaRangeOperator {
+
+    fn from_str(input: &str) -> Option<Self> {
+        match input {
+            "<" => Some(MediaRangeOperator::Less),
+            "<=" => Some(MediaRangeOperator::LessEqual),
+            ">" => Some(MediaRangeOperator::Greater),
+            ">=" => Some(MediaRangeOperator::GreaterEqual),
+            "=" => Some(MediaRangeOperator::Equal),
+            _ => None,
+        }
+    }
+
+    /// Swaps the operands: `a < b` becomes `b > a`
+    fn flip(self) -> Self {
+        use self::MediaRangeOperator::*;
+        match self {
+            Less => Greater,
+            LessEqual => GreaterEqual,
+            Greater => Less,
+            GreaterEqual => LessEqual,
+            Equal => Equal,
+        }
+    }
+
+    /// Range of all values `x` for which `x <op> value` is true
+    fn into_range(self, value: f32) -> CssMediaRange {
+        use self::MediaRangeOperator::*;
+        match self {
+            Less => CssMediaRange::at_most(value, false),
+            LessEqual => CssMediaRange::at_most(value, true),
+            Greater => CssMediaRange::at_least(value, false),
+            GreaterEqual => CssMediaRange::at_least(value, true),
+            Equal => CssMediaRange::exact(value),
+        }
+    }
+}
+
+/// Splits the contents of a declaration block at `;`, skipping comments and empty declarations,
+/// returns the byte range of each declaration
+fn split_declarations(css_string: &str, start: usize, end: usize) -> Vec<(usize, usize)> {
+
+    let bytes = css_string.as_bytes();
+    let mut declarations = Vec::new();
+    let mut declaration_start = None;
+    let mut depth = 0_usize;
+    let mut i = start;
+
+    while i < end {
+        if let Some(next) = skip_comment_or_string(bytes, i) {
+            if bytes[i] != b'/' && declaration_start.is_none() {
+                declaration_start = Some(i);
+            }
+            i = next;
+            continue;
+        }
+        match bytes[i] {
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            b';' if depth == 0 => {
+                declarations.extend(declaration_start.take().map(|s| (s, i)));
+            },
+            c if !c.is_ascii_whitespace() && declaration_start.is_none() => {
+                declaration_start = Some(i);
+            },
+            _ => { },
+        }
+        i += 1;
+    }
+
+    declarations.extend(declaration_start.map(|s| (s, end)));
+
+    declarations
+}
+
 /// Returns the location of where the parser is currently in the document
 fn get_error_location(tokenizer: &Tokenizer) -> ErrorLocation {
     ErrorLocation {
@@ -587,7 +1200,8 @@ fn new_from_str_inner<'a>(css_string: &'a str, tokenizer: &mut Tokenizer<'a>)
                 break;
             },
             _ => {
-                // attributes, lang-attributes and @keyframes are not supported
+                // attributes and lang-attributes are not supported,
+                // @keyframes are split off before tokenizing (see `split_top_level_at_rules`)
             }
         }
 
@@ -784,6 +1398,8 @@ fn test_css_simple_selector_parse() {
                 path: CssPath { selectors: parsed.into() },
                 declarations: Vec::new().into(),
             }].into(),
+            keyframes: Vec::new().into(),
+            media_rules: Vec::new().into(),
         }].into(),
     });
 }
@@ -943,4 +1559,128 @@ fn test_case_issue_93() {
     ];
 
     assert_eq!(parsed_css, Css { stylesheets: vec![expected_rules.into()].into() });
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_css_parse_keyframes() {
+
+    use azul_css::*;
+
+    let parsed_css = new_from_str("
+        .a { opacity: 0.5; }
+        @keyframes fade-in {
+            /* comment */
+            from { opacity: 0; }
+            50%, 75% { opacity: 0.8; animation-timing-function: ease-in; }
+            to { opacity: 1 }
+        }
+        .b { animation: fade-in 200ms; }
+    ").unwrap();
+
+    let stylesheet = &parsed_css.stylesheets.as_ref()[0];
+    assert_eq!(stylesheet.rules.len(), 2);
+
+    let keyframes = parsed_css.get_keyframes("fade-in").unwrap();
+    let offsets = keyframes.frames.iter().map(|f| f.offset).collect::<Vec<_>>();
+    assert_eq!(offsets, vec![
+        PercentageValue::new(0.0),
+        PercentageValue::new(50.0),
+        PercentageValue::new(75.0),
+        PercentageValue::new(100.0),
+    ]);
+    assert_eq!(
+        keyframes.frames.as_ref()[1].properties.as_ref(),
+        &[
+            CssProperty::Opacity(CssPropertyValue::Exact(StyleOpacity { inner: PercentageValue::new(80.0) })),
+            CssProperty::AnimationTimingFunction(CssPropertyValue::Exact(vec![StyleTimingFunction::EaseIn].into())),
+        ][..]
+    );
+
+    assert_eq!(
+        new_from_str("@keyframes x { 50px { opacity: 0; } }").unwrap_err().error,
+        CssParseErrorInner::InvalidKeyframeSelector("50px")
+    );
+}
+
+#[test]
+fn test_css_parse_media_queries() {
+
+    use azul_css::*;
+
+    let mut parsed_css = new_from_str("
+        .a { color: black; }
+        @media screen and (min-width: 600px), print {
+            .a { color: red; }
+        }
+        .b { color: blue; }
+        @media (prefers-color-scheme: dark) and (400px < width <= 700px) {
+            .a { color: white; }
+            .b { color: white; }
+        }
+        @media not all and (orientation: portrait) and (resolution >= 2dppx) { }
+    ").unwrap();
+
+    let stylesheet = &parsed_css.stylesheets.as_ref()[0];
+    assert_eq!(stylesheet.rules.len(), 2);
+
+    let media_rules = stylesheet.media_rules.as_ref();
+    assert_eq!(media_rules.len(), 3);
+    assert_eq!(media_rules[0].insert_index, 1);
+    assert_eq!(media_rules[1].insert_index, 2);
+    assert_eq!(media_rules[0].queries.as_ref(), &[
+        CssMediaQuery {
+            negated: false,
+            media_type: CssMediaType::Screen,
+            features: vec![CssMediaFeature::Width(CssMediaRange::at_least(600.0, true))].into(),
+        },
+        CssMediaQuery {
+            negated: false,
+            media_type: CssMediaType::Print,
+            features: Vec::new().into(),
+        },
+    ][..]);
+    assert_eq!(media_rules[1].queries.as_ref()[0].features.as_ref(), &[
+        CssMediaFeature::PrefersColorScheme(CssColorScheme::Dark),
+        CssMediaFeature::Width(CssMediaRange {
+            min: OptionF32::Some(400.0),
+            min_inclusive: false,
+            max: OptionF32::Some(700.0),
+            max_inclusive: true,
+        }),
+    ][..]);
+    assert_eq!(media_rules[2].queries.as_ref()[0].negated, true);
+
+    let dark_env = CssMediaEnvironment {
+        width: 650.0,
+        height: 400.0,
+        resolution: 1.0,
+        color_scheme: CssColorScheme::Dark,
+    };
+    assert_eq!(parsed_css.evaluate_media_queries(&dark_env), vec![true, true, true]);
+    assert_eq!(parsed_css.evaluate_media_queries(&CssMediaEnvironment::default()), vec![true, false, true]);
+
+    // matching rules are inserted at their source position
+    parsed_css.apply_media_queries(&dark_env);
+    let stylesheet = &parsed_css.stylesheets.as_ref()[0];
+    assert!(stylesheet.media_rules.as_ref().is_empty());
+    let colors = stylesheet.rules.iter().map(|r| match r.declarations.as_ref()[0] {
+        CssDeclaration::Static(CssProperty::TextColor(CssPropertyValue::Exact(c))) => c.inner,
+        _ => panic!("expected text color"),
+    }).collect::<Vec<_>>();
+    assert_eq!(colors, vec![
+        ColorU { r: 0, g: 0, b: 0, a: 255 },
+        ColorU { r: 255, g: 0, b: 0, a: 255 },
+        ColorU { r: 0, g: 0, b: 255, a: 255 },
+        ColorU { r: 255, g: 255, b: 255, a: 255 },
+        ColorU { r: 255, g: 255, b: 255, a: 255 },
+    ]);
+
+    assert_eq!(
+        new_from_str("@media (min-width: 50%) { .a { color: red; } }").unwrap_err().error,
+        CssParseErrorInner::InvalidMediaQuery("(min-width: 50%)")
+    );
+    assert_eq!(
+        new_from_str("@media screen { @media print { } }").unwrap_err().error,
+        CssParseErrorInner::NestedMediaRule
+    );
+}
@@ -17,7 +17,8 @@ use azul_css::{
     RadialGradientSize, AzString, NormalizedLinearColorStop, NormalizedRadialColorStop,
 
     StyleFilter, StyleMixBlendMode,
-    StyleTextColor, StyleFontSize, StyleFontFamily, StyleTextAlign,
+    StyleTextColor, StyleFontSize, StyleFontFamily, StyleTextAlign, StyleDirection,
+    StyleWordBreak, StyleOverflowWrap, StyleHyphens,
     StyleLetterSpacing, StyleLineHeight, StyleWordSpacing, StyleTabWidth,
     StyleCursor, StyleBackgroundContent, StyleBackgroundPosition, StyleBackgroundSize,
     StyleBackgroundRepeat, StyleBorderTopLeftRadius, StyleBorderTopRightRadius,
@@ -37,6 +38,16 @@ use azul_css::{
     LayoutAlignItems, LayoutAlignContent, LayoutPaddingRight, LayoutPaddingBottom,
     LayoutMarginTop, LayoutMarginLeft, LayoutMarginRight, LayoutMarginBottom,
     LayoutPaddingTop, LayoutPaddingLeft,
+    LayoutGridAutoFlow, LayoutRowGap, LayoutColumnGap, GridTrackBreadth,
+    GridTrackSizing, GridTrackSizingVec, GridLine, GridPlacement,
+
+    StyleTime, StyleTimeVec, StyleCubicBezier, StyleStepPosition, StyleSteps,
+    StyleTimingFunction, StyleTimingFunctionVec, StyleTransitionProperty,
+    StyleTransitionPropertyVec, StyleAnimationName, StyleAnimationNameVec,
+    StyleAnimationIterationCount, StyleAnimationIterationCountVec,
+    StyleAnimationDirection, StyleAnimationDirectionVec, StyleAnimationFillMode,
+    StyleAnimationFillModeVec, StyleAnimationPlayState, StyleAnimationPlayStateVec,
+    get_css_key_map,
 };
 
 pub trait FormatAsCssValue {
@@ -210,6 +221,10 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             FontSize                    => parse_style_font_size(value)?.into(),
             FontFamily                  => parse_style_font_family(value)?.into(),
             TextAlign                   => parse_layout_text_align(value)?.into(),
+            Direction                   => parse_style_direction(value)?.into(),
+            WordBreak                   => parse_style_word_break(value)?.into(),
+            OverflowWrap                => parse_style_overflow_wrap(value)?.into(),
+            Hyphens                     => parse_style_hyphens(value)?.into(),
             LetterSpacing               => parse_style_letter_spacing(value)?.into(),
             LineHeight                  => parse_style_line_height(value)?.into(),
             WordSpacing                 => parse_style_word_spacing(value)?.into(),
@@ -238,6 +253,16 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             AlignItems                  => parse_layout_align_items(value)?.into(),
             AlignContent                => parse_layout_align_content(value)?.into(),
 
+            GridTemplateColumns         => CssProperty::GridTemplateColumns(CssPropertyValue::Exact(parse_grid_track_sizing_vec(value)?)).into(),
+            GridTemplateRows            => CssProperty::GridTemplateRows(CssPropertyValue::Exact(parse_grid_track_sizing_vec(value)?)).into(),
+            GridAutoColumns             => CssProperty::GridAutoColumns(CssPropertyValue::Exact(parse_grid_track_sizing(value)?)).into(),
+            GridAutoRows                => CssProperty::GridAutoRows(CssPropertyValue::Exact(parse_grid_track_sizing(value)?)).into(),
+            GridAutoFlow                => parse_layout_grid_auto_flow(value)?.into(),
+            GridColumn                  => CssProperty::GridColumn(CssPropertyValue::Exact(parse_grid_placement(value)?)).into(),
+            GridRow                     => CssProperty::GridRow(CssPropertyValue::Exact(parse_grid_placement(value)?)).into(),
+            RowGap                      => parse_layout_row_gap(value)?.into(),
+            ColumnGap                   => parse_layout_column_gap(value)?.into(),
+
             BackgroundContent           => parse_style_background_content_multiple(value)?.into(),
             BackgroundPosition          => parse_style_background_position_multiple(value)?.into(),
             BackgroundSize              => parse_style_background_size_multiple(value)?.into(),
@@ -293,6 +318,19 @@ pub fn parse_css_property<'a>(key: CssPropertyType, value: &'a str) -> Result<Cs
             Filter                      => CssProperty::Filter(CssPropertyValue::Exact(parse_style_filter_vec(value)?)).into(),
             BackdropFilter              => CssProperty::BackdropFilter(CssPropertyValue::Exact(parse_style_filter_vec(value)?)).into(),
             TextShadow                  => CssProperty::TextShadow(CssPropertyValue::Exact(parse_style_box_shadow(value)?)).into(),
+
+            TransitionProperty          => CssProperty::TransitionProperty(CssPropertyValue::Exact(parse_style_transition_property_vec(value)?)).into(),
+            TransitionDuration          => CssProperty::TransitionDuration(CssPropertyValue::Exact(parse_style_duration_vec(value)?)).into(),
+            TransitionTimingFunction    => CssProperty::TransitionTimingFunction(CssPropertyValue::Exact(parse_style_timing_function_vec(value)?)).into(),
+            TransitionDelay             => CssProperty::TransitionDelay(CssPropertyValue::Exact(parse_style_time_vec(value)?)).into(),
+            AnimationName               => CssProperty::AnimationName(CssPropertyValue::Exact(parse_style_animation_name_vec(value)?)).into(),
+            AnimationDuration           => CssProperty::AnimationDuration(CssPropertyValue::Exact(parse_style_duration_vec(value)?)).into(),
+            AnimationTimingFunction     => CssProperty::AnimationTimingFunction(CssPropertyValue::Exact(parse_style_timing_function_vec(value)?)).into(),
+            AnimationDelay              => CssProperty::AnimationDelay(CssPropertyValue::Exact(parse_style_time_vec(value)?)).into(),
+            AnimationIterationCount     => CssProperty::AnimationIterationCount(CssPropertyValue::Exact(parse_style_animation_iteration_count_vec(value)?)).into(),
+            AnimationDirection          => CssProperty::AnimationDirection(CssPropertyValue::Exact(parse_style_animation_direction_vec(value)?)).into(),
+            AnimationFillMode           => CssProperty::AnimationFillMode(CssPropertyValue::Exact(parse_style_animation_fill_mode_vec(value)?)).into(),
+            AnimationPlayState          => CssProperty::AnimationPlayState(CssPropertyValue::Exact(parse_style_animation_play_state_vec(value)?)).into(),
         }
     })
 }
@@ -423,7 +461,14 @@ pub fn parse_combined_css_property<'a>(key: CombinedCssPropertyType, value: &'a
             vec![
                 CssPropertyType::BackgroundContent,
             ]
-        }
+        },
+        Gap | GridGap => {
+            vec![
+                CssPropertyType::RowGap,
+                CssPropertyType::ColumnGap,
+            ]
+        },
+        Transition | Animation => key.get_longhand_types().to_vec(),
     };
 
     match value {
@@ -540,7 +585,35 @@ pub fn parse_combined_css_property<'a>(key: CombinedCssPropertyType, value: &'a
             Ok(vec![
                 CssProperty::BackgroundContent(vec.into()),
             ])
-        }
+        },
+        Gap | GridGap => {
+            let (row_gap, column_gap) = parse_layout_gap(value)?;
+            Ok(vec![
+                CssProperty::RowGap(row_gap.into()),
+                CssProperty::ColumnGap(column_gap.into()),
+            ])
+        },        Transition => {
+            let t = parse_style_transition_shorthand(value)?;
+            Ok(vec![
+                CssProperty::TransitionProperty(CssPropertyValue::Exact(t.property)),
+                CssProperty::TransitionDuration(CssPropertyValue::Exact(t.duration)),
+                CssProperty::TransitionTimingFunction(CssPropertyValue::Exact(t.timing_function)),
+                CssProperty::TransitionDelay(CssPropertyValue::Exact(t.delay)),
+            ])
+        },
+        Animation => {
+            let a = parse_style_animation_shorthand(value)?;
+            Ok(vec![
+                CssProperty::AnimationName(CssPropertyValue::Exact(a.name)),
+                CssProperty::AnimationDuration(CssPropertyValue::Exact(a.duration)),
+                CssProperty::AnimationTimingFunction(CssPropertyValue::Exact(a.timing_function)),
+                CssProperty::AnimationDelay(CssPropertyValue::Exact(a.delay)),
+                CssProperty::AnimationIterationCount(CssPropertyValue::Exact(a.iteration_count)),
+                CssProperty::AnimationDirection(CssPropertyValue::Exact(a.direction)),
+                CssProperty::AnimationFillMode(CssPropertyValue::Exact(a.fill_mode)),
+                CssProperty::AnimationPlayState(CssPropertyValue::Exact(a.play_state)),
+            ])
+        },
     }
 }
 
@@ -570,6 +643,8 @@ pub enum CssParsingError<'a> {
     Opacity(OpacityParseError<'a>),
     Scrollbar(CssScrollbarStyleParseError<'a>),
     Filter(CssStyleFilterParseError<'a>),
+    Grid(CssGridParseError<'a>),
+    Animation(CssAnimationParseError<'a>),
 }
 
 impl_debug_as_display!(CssParsingError<'a>);
@@ -595,6 +670,8 @@ impl_display!{ CssParsingError<'a>, {
     Opacity(e) => format!("{}", e),
     Scrollbar(e) => format!("{}", e),
     Filter(e) => format!("{}", e),
+    Grid(e) => format!("{}", e),
+    Animation(e) => format!("{}", e),
 }}
 
 impl_from!(CssBorderParseError<'a>, CssParsingError::CssBorderParseError);
@@ -617,6 +694,8 @@ impl_from!(CssStylePerspectiveOriginParseError<'a>, CssParsingError::Perspective
 impl_from!(OpacityParseError<'a>, CssParsingError::Opacity);
 impl_from!(CssScrollbarStyleParseError<'a>, CssParsingError::Scrollbar);
 impl_from!(CssStyleFilterParseError<'a>, CssParsingError::Filter);
+impl_from!(CssGridParseError<'a>, CssParsingError::Grid);
+impl_from!(CssAnimationParseError<'a>, CssParsingError::Animation);
 
 impl<'a> From<PercentageParseError> for CssParsingError<'a> {
     fn from(e: PercentageParseError) -> Self {
@@ -891,6 +970,11 @@ fn parse_pixel_value_inner<'a>(input: &'a str, match_values: &[(&'static str, Si
         return Err(CssPixelValueParseError::EmptyString);
     }
 
+    // zero lengths don't need a unit ("margin: 0 auto")
+    if input == "0" {
+        return Ok(PixelValue::const_px(0));
+    }
+
     for (match_val, metric) in match_values {
         if input.ends_with(match_val) {
             let value = &input[..input.len() - match_val.len()];
@@ -2955,6 +3039,600 @@ pub fn parse_layout_flex_shrink<'a>(input: &'a str) -> Result<LayoutFlexShrink,
     }
 }
 
+typed_pixel_value_parser!(parse_layout_row_gap, LayoutRowGap);
+typed_pixel_value_parser!(parse_layout_column_gap, LayoutColumnGap);
+
+/// Parses a `gap` or `grid-gap` shorthand (`"10px"` or `"10px 20px"`) into the `(row-gap, column-gap)`
+pub fn parse_layout_gap<'a>(input: &'a str)
+-> Result<(LayoutRowGap, LayoutColumnGap), CssPixelValueParseError<'a>>
+{
+    let mut iter = input.split_whitespace();
+    let row_gap = parse_pixel_value(iter.next().ok_or(CssPixelValueParseError::EmptyString)?)?;
+    let column_gap = match iter.next() {
+        Some(s) => parse_pixel_value(s)?,
+        None => row_gap,
+    };
+    if iter.next().is_some() {
+        return Err(CssPixelValueParseError::InvalidPixelValue(input));
+    }
+    Ok((LayoutRowGap { inner: row_gap }, LayoutColumnGap { inner: column_gap }))
+}
+
+#[derive(Clone, PartialEq)]
+pub enum CssGridParseError<'a> {
+    InvalidTrackSize(&'a str),
+    InvalidRepeat(&'a str),
+    InvalidLine(&'a str),
+    InvalidAutoFlow(&'a str),
+    InvalidParenthesis(ParenthesisParseError<'a>),
+    PixelValueParseError(CssPixelValueParseError<'a>),
+}
+
+impl_debug_as_display!(CssGridParseError<'a>);
+impl_display!{ CssGridParseError<'a>, {
+    InvalidTrackSize(e) => format!("Invalid grid track size: \"{}\"", e),
+    InvalidRepeat(e) => format!("Invalid repeat(): \"{}\" - expected \"repeat(count, tracks)\"", e),
+    InvalidLine(e) => format!("Invalid grid line: \"{}\"", e),
+    InvalidAutoFlow(e) => format!("Invalid grid-auto-flow: \"{}\"", e),
+    InvalidParenthesis(e) => format!("Invalid grid track size - parenthesis error: {}", e),
+    PixelValueParseError(e) => format!("Invalid pixel value: {}", e),
+}}
+
+impl_from!(ParenthesisParseError<'a>, CssGridParseError::InvalidParenthesis);
+impl_from!(CssPixelValueParseError<'a>, CssGridParseError::PixelValueParseError);
+
+/// Splits a string at whitespace, but ignores whitespace inside of parentheses
+///
+/// `"repeat(2, 1fr) minmax(10px, auto)"` => `["repeat(2, 1fr)", "minmax(10px, auto)"]`
+fn split_string_respect_whitespace<'a>(input: &'a str) -> Vec<&'a str> {
+
+    let mut items = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = None;
+
+    for (idx, ch) in input.char_indices() {
+        match ch {
+            '(' => { depth += 1; if start.is_none() { start = Some(idx); } },
+            ')' => { depth = depth.saturating_sub(1); },
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(s) = start.take() {
+                    items.push(&input[s..idx]);
+                }
+            },
+            _ => { if start.is_none() { start = Some(idx); } },
+        }
+    }
+
+    if let Some(s) = start {
+        items.push(&input[s..]);
+    }
+
+    items
+}
+
+/// Parses a single grid track breadth, such as `"100px"`, `"1fr"`, `"auto"` or `"min-content"`
+pub fn parse_grid_track_breadth<'a>(input: &'a str)
+-> Result<GridTrackBreadth, CssGridParseError<'a>>
+{
+    let input = input.trim();
+    match input {
+        "auto" => Ok(GridTrackBreadth::Auto),
+        "min-content" => Ok(GridTrackBreadth::MinContent),
+        "max-content" => Ok(GridTrackBreadth::MaxContent),
+        other if other.ends_with("fr") => {
+            parse_float_value(&other[..other.len() - 2])
+            .ok()
+            .filter(|f| f.get() >= 0.0)
+            .map(GridTrackBreadth::Fraction)
+            .ok_or(CssGridParseError::InvalidTrackSize(input))
+        },
+        other => Ok(GridTrackBreadth::Fixed(parse_pixel_value(other)?)),
+    }
+}
+
+/// Parses a single grid track size, such as `"1fr"` or `"minmax(100px, 1fr)"`
+pub fn parse_grid_track_sizing<'a>(input: &'a str)
+-> Result<GridTrackSizing, CssGridParseError<'a>>
+{
+    let input = input.trim();
+
+    if !input.starts_with("minmax(") {
+        return match parse_grid_track_breadth(input)? {
+            // a single "1fr" is a shorthand for "minmax(auto, 1fr)"
+            GridTrackBreadth::Fraction(f) => Ok(GridTrackSizing::fraction(f)),
+            other => Ok(GridTrackSizing::minmax(other, other)),
+        };
+    }
+
+    let (_, minmax_values) = parse_parentheses(input, &["minmax"])?;
+    let mut iter = minmax_values.split(',');
+    let min = iter.next().ok_or(CssGridParseError::InvalidTrackSize(input))?;
+    let max = iter.next().ok_or(CssGridParseError::InvalidTrackSize(input))?;
+    if iter.next().is_some() {
+        return Err(CssGridParseError::InvalidTrackSize(input));
+    }
+
+    let min = parse_grid_track_breadth(min)?;
+    let max = parse_grid_track_breadth(max)?;
+
+    // the minimum of a minmax() can't be flexible
+    if min.is_fraction() {
+        return Err(CssGridParseError::InvalidTrackSize(input));
+    }
+
+    Ok(GridTrackSizing::minmax(min, max))
+}
+
+/// Parses a `grid-template-columns` / `grid-template-rows` track list,
+/// such as `"100px repeat(2, 1fr) minmax(50px, auto)"`, expanding all `repeat()` calls
+pub fn parse_grid_track_sizing_vec<'a>(input: &'a str)
+-> Result<GridTrackSizingVec, CssGridParseError<'a>>
+{
+    let mut tracks = Vec::new();
+
+    for item in split_string_respect_whitespace(input) {
+        if item.starts_with("repeat(") {
+            let (_, repeat_values) = parse_parentheses(item, &["repeat"])?;
+            let comma = repeat_values.find(',').ok_or(CssGridParseError::InvalidRepeat(item))?;
+            let count = repeat_values[..comma].trim().parse::<usize>()
+                .ok()
+                .filter(|c| *c > 0)
+                .ok_or(CssGridParseError::InvalidRepeat(item))?;
+            let repeated = split_string_respect_whitespace(&repeat_values[(comma + 1)..])
+                .into_iter()
+                .map(|t| parse_grid_track_sizing(t))
+                .collect::<Result<Vec<_>, _>>()?;
+            if repeated.is_empty() {
+                return Err(CssGridParseError::InvalidRepeat(item));
+            }
+            for _ in 0..count {
+                tracks.extend(repeated.iter().copied());
+            }
+        } else {
+            tracks.push(parse_grid_track_sizing(item)?);
+        }
+    }
+
+    if tracks.is_empty() {
+        return Err(CssGridParseError::InvalidTrackSize(input));
+    }
+
+    Ok(tracks.into())
+}
+
+/// Parses a single grid line, such as `"2"`, `"-1"`, `"span 2"` or `"auto"`
+pub fn parse_grid_line<'a>(input: &'a str)
+-> Result<GridLine, CssGridParseError<'a>>
+{
+    let input = input.trim();
+    let mut iter = input.split_whitespace();
+    let line = match (iter.next(), iter.next(), iter.next()) {
+        (Some("auto"), None, None) => Some(GridLine::Auto),
+        (Some("span"), Some(n), None) => n.parse::<u32>().ok().filter(|n| *n > 0).map(GridLine::Span),
+        (Some(n), None, None) => n.parse::<i32>().ok().filter(|n| *n != 0).map(GridLine::Line),
+        _ => None,
+    };
+    line.ok_or(CssGridParseError::InvalidLine(input))
+}
+
+/// Parses a `grid-column` / `grid-row` attribute, such as `"1 / 3"`, `"2 / span 2"` or `"span 3"`
+pub fn parse_grid_placement<'a>(input: &'a str)
+-> Result<GridPlacement, CssGridParseError<'a>>
+{
+    let mut iter = input.split('/');
+    let start = parse_grid_line(iter.next().ok_or(CssGridParseError::InvalidLine(input))?)?;
+    let end = match iter.next() {
+        Some(s) => parse_grid_line(s)?,
+        None => GridLine::Auto,
+    };
+    if iter.next().is_some() {
+        return Err(CssGridParseError::InvalidLine(input));
+    }
+    Ok(GridPlacement { start, end })
+}
+
+/// Parses a `grid-auto-flow` attribute, such as `"row"` or `"column dense"`
+pub fn parse_layout_grid_auto_flow<'a>(input: &'a str)
+-> Result<LayoutGridAutoFlow, CssGridParseError<'a>>
+{
+    let input = input.trim();
+    let mut words = input.split_whitespace().collect::<Vec<_>>();
+    words.sort();
+    match words.as_slice() {
+        ["row"] => Ok(LayoutGridAutoFlow::Row),
+        ["column"] => Ok(LayoutGridAutoFlow::Column),
+        ["dense"] | ["dense", "row"] => Ok(LayoutGridAutoFlow::RowDense),
+        ["column", "dense"] => Ok(LayoutGridAutoFlow::ColumnDense),
+        _ => Err(CssGridParseError::InvalidAutoFlow(input)),
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub enum CssAnimationParseError<'a> {
+    InvalidTime(&'a str),
+    NegativeDuration(&'a str),
+    InvalidTimingFunction(&'a str),
+    InvalidCubicBezier(&'a str),
+    InvalidSteps(&'a str),
+    InvalidTransitionProperty(&'a str),
+    InvalidAnimationName(&'a str),
+    InvalidIterationCount(&'a str),
+    InvalidDirection(&'a str),
+    InvalidFillMode(&'a str),
+    InvalidPlayState(&'a str),
+    InvalidShorthand(&'a str),
+    InvalidParenthesis(ParenthesisParseError<'a>),
+}
+
+impl_debug_as_display!(CssAnimationParseError<'a>);
+impl_display!{ CssAnimationParseError<'a>, {
+    InvalidTime(e) => format!("Invalid time: \"{}\" - expected a number followed by \"s\" or \"ms\"", e),
+    NegativeDuration(e) => format!("Invalid duration: \"{}\" - durations can't be negative", e),
+    InvalidTimingFunction(e) => format!("Invalid easing function: \"{}\"", e),
+    InvalidCubicBezier(e) => format!("Invalid cubic-bezier(): \"{}\" - expected four numbers, x1 and x2 between 0 and 1", e),
+    InvalidSteps(e) => format!("Invalid steps(): \"{}\" - expected \"steps(count[, position])\"", e),
+    InvalidTransitionProperty(e) => format!("Invalid transition-property: \"{}\"", e),
+    InvalidAnimationName(e) => format!("Invalid animation-name: \"{}\"", e),
+    InvalidIterationCount(e) => format!("Invalid animation-iteration-count: \"{}\"", e),
+    InvalidDirection(e) => format!("Invalid animation-direction: \"{}\"", e),
+    InvalidFillMode(e) => format!("Invalid animation-fill-mode: \"{}\"", e),
+    InvalidPlayState(e) => format!("Invalid animation-play-state: \"{}\"", e),
+    InvalidShorthand(e) => format!("Invalid value in shorthand: \"{}\"", e),
+    InvalidParenthesis(e) => format!("Invalid easing function - parenthesis error: {}", e),
+}}
+
+impl_from!(ParenthesisParseError<'a>, CssAnimationParseError::InvalidParenthesis);
+
+/// Parses a `<time>` value, such as `"200ms"`, `"0.5s"` or `"-1s"`
+pub fn parse_style_time<'a>(input: &'a str)
+-> Result<StyleTime, CssAnimationParseError<'a>>
+{
+    let input = input.trim();
+    let (number, factor) = if input.ends_with("ms") {
+        (&input[..input.len() - 2], 1.0)
+    } else if input.ends_with('s') {
+        (&input[..input.len() - 1], 1000.0)
+    } else {
+        return Err(CssAnimationParseError::InvalidTime(input));
+    };
+    let number = number.parse::<f32>().map_err(|_| CssAnimationParseError::InvalidTime(input))?;
+    if !number.is_finite() {
+        return Err(CssAnimationParseError::InvalidTime(input));
+    }
+    Ok(StyleTime::ms((number * factor).round() as i32))
+}
+
+/// Parses a `<time>` value that can't be negative (`transition-duration`, `animation-duration`)
+pub fn parse_style_duration<'a>(input: &'a str)
+-> Result<StyleTime, CssAnimationParseError<'a>>
+{
+    let time = parse_style_time(input)?;
+    if time.ms < 0 {
+        return Err(CssAnimationParseError::NegativeDuration(input.trim()));
+    }
+    Ok(time)
+}
+
+/// Parses a single `<easing-function>`, such as `"ease-in"`,
+/// `"cubic-bezier(0.1, 0.7, 1.0, 0.1)"` or `"steps(4, jump-end)"`
+pub fn parse_style_timing_function<'a>(input: &'a str)
+-> Result<StyleTimingFunction, CssAnimationParseError<'a>>
+{
+    let input = input.trim();
+    match input {
+        "ease" => return Ok(StyleTimingFunction::Ease),
+        "linear" => return Ok(StyleTimingFunction::Linear),
+        "ease-in" => return Ok(StyleTimingFunction::EaseIn),
+        "ease-out" => return Ok(StyleTimingFunction::EaseOut),
+        "ease-in-out" => return Ok(StyleTimingFunction::EaseInOut),
+        "step-start" => return Ok(StyleTimingFunction::Steps(StyleSteps { count: 1, position: StyleStepPosition::JumpStart })),
+        "step-end" => return Ok(StyleTimingFunction::Steps(StyleSteps { count: 1, position: StyleStepPosition::JumpEnd })),
+        _ => { },
+    }
+
+    if !input.contains('(') {
+        return Err(CssAnimationParseError::InvalidTimingFunction(input));
+    }
+
+    let (function, values) = parse_parentheses(input, &["cubic-bezier", "steps"])?;
+
+    match function {
+        "cubic-bezier" => {
+            let numbers = values.split(',')
+                .map(|v| v.trim().parse::<f32>().ok().filter(|f| f.is_finite()))
+                .collect::<Option<Vec<_>>>()
+                .ok_or(CssAnimationParseError::InvalidCubicBezier(input))?;
+            match numbers.as_slice() {
+                &[x1, y1, x2, y2] if x1 >= 0.0 && x1 <= 1.0 && x2 >= 0.0 && x2 <= 1.0 => {
+                    Ok(StyleTimingFunction::CubicBezier(StyleCubicBezier {
+                        x1: FloatValue::new(x1),
+                        y1: FloatValue::new(y1),
+                        x2: FloatValue::new(x2),
+                        y2: FloatValue::new(y2),
+                    }))
+                },
+                _ => Err(CssAnimationParseError::InvalidCubicBezier(input)),
+            }
+        },
+        _ => {
+            let mut iter = values.split(',');
+            let count = iter.next()
+                .and_then(|c| c.trim().parse::<u32>().ok())
+                .ok_or(CssAnimationParseError::InvalidSteps(input))?;
+            let position = match iter.next().map(|p| p.trim()) {
+                None => StyleStepPosition::JumpEnd,
+                Some("jump-start") | Some("start") => StyleStepPosition::JumpStart,
+                Some("jump-end") | Some("end") => StyleStepPosition::JumpEnd,
+                Some("jump-none") => StyleStepPosition::JumpNone,
+                Some("jump-both") => StyleStepPosition::JumpBoth,
+                Some(_) => return Err(CssAnimationParseError::InvalidSteps(input)),
+            };
+            let min_count = if position == StyleStepPosition::JumpNone { 2 } else { 1 };
+            if iter.next().is_some() || count < min_count {
+                return Err(CssAnimationParseError::InvalidSteps(input));
+            }
+            Ok(StyleTimingFunction::Steps(StyleSteps { count, position }))
+        },
+    }
+}
+
+/// Parses a single `transition-property` entry, such as `"all"`, `"opacity"` or `"margin"`
+pub fn parse_style_transition_property<'a>(input: &'a str)
+-> Result<StyleTransitionProperty, CssAnimationParseError<'a>>
+{
+    let input = input.trim();
+    if input == "all" {
+        return Ok(StyleTransitionProperty::All);
+    }
+    let map = get_css_key_map();
+    if let Some(p) = CssPropertyType::from_str(input, &map) {
+        return Ok(StyleTransitionProperty::Property(p));
+    }
+    if let Some(s) = CombinedCssPropertyType::from_str(input, &map) {
+        return Ok(StyleTransitionProperty::Shorthand(s));
+    }
+    Err(CssAnimationParseError::InvalidTransitionProperty(input))
+}
+
+/// Parses a single `animation-name` entry, such as `"none"`, `"fade-in"` or `"\"slide\""`
+pub fn parse_style_animation_name<'a>(input: &'a str)
+-> Result<StyleAnimationName, CssAnimationParseError<'a>>
+{
+    let input = input.trim();
+    if input == "none" {
+        return Ok(StyleAnimationName::None);
+    }
+    let name = strip_quotes(input).map(|q| q.0).unwrap_or(input);
+    let is_ident = !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    if !is_ident || name == "initial" || name == "inherit" {
+        return Err(CssAnimationParseError::InvalidAnimationName(input));
+    }
+    Ok(StyleAnimationName::Name(String::from(name).into()))
+}
+
+/// Parses a single `animation-iteration-count` entry, such as `"infinite"` or `"2.5"`
+pub fn parse_style_animation_iteration_count<'a>(input: &'a str)
+-> Result<StyleAnimationIterationCount, CssAnimationParseError<'a>>
+{
+    let input = input.trim();
+    if input == "infinite" {
+        return Ok(StyleAnimationIterationCount::Infinite);
+    }
+    input.parse::<f32>().ok()
+    .filter(|f| f.is_finite() && *f >= 0.0)
+    .map(|f| StyleAnimationIterationCount::Count(FloatValue::new(f)))
+    .ok_or(CssAnimationParseError::InvalidIterationCount(input))
+}
+
+/// Parses a single `animation-direction` entry, such as `"alternate-reverse"`
+pub fn parse_style_animation_direction<'a>(input: &'a str)
+-> Result<StyleAnimationDirection, CssAnimationParseError<'a>>
+{
+    let input = input.trim();
+    match input {
+        "normal" => Ok(StyleAnimationDirection::Normal),
+        "reverse" => Ok(StyleAnimationDirection::Reverse),
+        "alternate" => Ok(StyleAnimationDirection::Alternate),
+        "alternate-reverse" => Ok(StyleAnimationDirection::AlternateReverse),
+        _ => Err(CssAnimationParseError::InvalidDirection(input)),
+    }
+}
+
+/// Parses a single `animation-fill-mode` entry, such as `"forwards"`
+pub fn parse_style_animation_fill_mode<'a>(input: &'a str)
+-> Result<StyleAnimationFillMode, CssAnimationParseError<'a>>
+{
+    let input = input.trim();
+    match input {
+        "none" => Ok(StyleAnimationFillMode::None),
+        "forwards" => Ok(StyleAnimationFillMode::Forwards),
+        "backwards" => Ok(StyleAnimationFillMode::Backwards),
+        "both" => Ok(StyleAnimationFillMode::Both),
+        _ => Err(CssAnimationParseError::InvalidFillMode(input)),
+    }
+}
+
+/// Parses a single `animation-play-state` entry, such as `"paused"`
+pub fn parse_style_animation_play_state<'a>(input: &'a str)
+-> Result<StyleAnimationPlayState, CssAnimationParseError<'a>>
+{
+    let input = input.trim();
+    match input {
+        "running" => Ok(StyleAnimationPlayState::Running),
+        "paused" => Ok(StyleAnimationPlayState::Paused),
+        _ => Err(CssAnimationParseError::InvalidPlayState(input)),
+    }
+}
+
+macro_rules! comma_separated_list_parser {($fn:ident, $item_fn:ident, $vec:ident) => (
+    /// Parses a comma-separated list, i.e. `"a, b, c"`
+    pub fn $fn<'a>(input: &'a str) -> Result<$vec, CssAnimationParseError<'a>> {
+        split_string_respect_comma(input)
+        .into_iter()
+        .map(|item| $item_fn(item))
+        .collect::<Result<Vec<_>, _>>()
+        .map(|v| v.into())
+    }
+)}
+
+comma_separated_list_parser!(parse_style_time_vec, parse_style_time, StyleTimeVec);
+comma_separated_list_parser!(parse_style_duration_vec, parse_style_duration, StyleTimeVec);
+comma_separated_list_parser!(parse_style_timing_function_vec, parse_style_timing_function, StyleTimingFunctionVec);
+comma_separated_list_parser!(parse_style_transition_property_vec, parse_style_transition_property, StyleTransitionPropertyVec);
+comma_separated_list_parser!(parse_style_animation_name_vec, parse_style_animation_name, StyleAnimationNameVec);
+comma_separated_list_parser!(parse_style_animation_iteration_count_vec, parse_style_animation_iteration_count, StyleAnimationIterationCountVec);
+comma_separated_list_parser!(parse_style_animation_direction_vec, parse_style_animation_direction, StyleAnimationDirectionVec);
+comma_separated_list_parser!(parse_style_animation_fill_mode_vec, parse_style_animation_fill_mode, StyleAnimationFillModeVec);
+comma_separated_list_parser!(parse_style_animation_play_state_vec, parse_style_animation_play_state, StyleAnimationPlayStateVec);
+
+/// Parsed `transition` shorthand, one entry per comma-separated transition
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedTransitionShorthand {
+    pub property: StyleTransitionPropertyVec,
+    pub duration: StyleTimeVec,
+    pub timing_function: StyleTimingFunctionVec,
+    pub delay: StyleTimeVec,
+}
+
+/// Parses a `transition` shorthand, such as `"opacity 200ms ease-in, transform 1s"`
+///
+/// The order of the values in one transition doesn't matter, except that
+/// the first `<time>` is the duration and the second one is the delay.
+pub fn parse_style_transition_shorthand<'a>(input: &'a str)
+-> Result<ParsedTransitionShorthand, CssAnimationParseError<'a>>
+{
+    let mut property = Vec::new();
+    let mut duration = Vec::new();
+    let mut timing_function = Vec::new();
+    let mut delay = Vec::new();
+
+    for transition in split_string_respect_comma(input) {
+        let mut p = None;
+        let mut times = Vec::new();
+        let mut t = None;
+
+        for token in split_string_respect_whitespace(transition) {
+            if let Ok(time) = parse_style_time(token) {
+                if times.len() == 2 {
+                    return Err(CssAnimationParseError::InvalidShorthand(token));
+                }
+                times.push(time);
+            } else if let (None, Ok(f)) = (t, parse_style_timing_function(token)) {
+                t = Some(f);
+            } else if let (None, Ok(prop)) = (p, parse_style_transition_property(token)) {
+                p = Some(prop);
+            } else {
+                return Err(CssAnimationParseError::InvalidShorthand(token));
+            }
+        }
+
+        let d = times.get(0).copied().unwrap_or_default();
+        if d.ms < 0 {
+            return Err(CssAnimationParseError::NegativeDuration(transition.trim()));
+        }
+
+        property.push(p.unwrap_or(StyleTransitionProperty::All));
+        duration.push(d);
+        timing_function.push(t.unwrap_or_default());
+        delay.push(times.get(1).copied().unwrap_or_default());
+    }
+
+    Ok(ParsedTransitionShorthand {
+        property: property.into(),
+        duration: duration.into(),
+        timing_function: timing_function.into(),
+        delay: delay.into(),
+    })
+}
+
+/// Parsed `animation` shorthand, one entry per comma-separated animation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedAnimationShorthand {
+    pub name: StyleAnimationNameVec,
+    pub duration: StyleTimeVec,
+    pub timing_function: StyleTimingFunctionVec,
+    pub delay: StyleTimeVec,
+    pub iteration_count: StyleAnimationIterationCountVec,
+    pub direction: StyleAnimationDirectionVec,
+    pub fill_mode: StyleAnimationFillModeVec,
+    pub play_state: StyleAnimationPlayStateVec,
+}
+
+/// Parses an `animation` shorthand, such as `"spin 1s linear infinite, fade-in 200ms"`
+///
+/// Keywords are matched before the animation name, so a `@keyframes` block named
+/// i.e. `"reverse"` can only be referenced via `animation-name`.
+pub fn parse_style_animation_shorthand<'a>(input: &'a str)
+-> Result<ParsedAnimationShorthand, CssAnimationParseError<'a>>
+{
+    let mut name = Vec::new();
+    let mut duration = Vec::new();
+    let mut timing_function = Vec::new();
+    let mut delay = Vec::new();
+    let mut iteration_count = Vec::new();
+    let mut direction = Vec::new();
+    let mut fill_mode = Vec::new();
+    let mut play_state = Vec::new();
+
+    for animation in split_string_respect_comma(input) {
+        let mut n = None;
+        let mut times = Vec::new();
+        let mut t = None;
+        let mut i = None;
+        let mut d = None;
+        let mut f = None;
+        let mut p = None;
+
+        for token in split_string_respect_whitespace(animation) {
+            if let Ok(time) = parse_style_time(token) {
+                if times.len() == 2 {
+                    return Err(CssAnimationParseError::InvalidShorthand(token));
+                }
+                times.push(time);
+            } else if let (None, Ok(v)) = (t, parse_style_timing_function(token)) {
+                t = Some(v);
+            } else if let (None, Ok(v)) = (i, parse_style_animation_iteration_count(token)) {
+                i = Some(v);
+            } else if let (None, Ok(v)) = (d, parse_style_animation_direction(token)) {
+                d = Some(v);
+            } else if let (None, Ok(v)) = (f, parse_style_animation_fill_mode(token)) {
+                f = Some(v);
+            } else if let (None, Ok(v)) = (p, parse_style_animation_play_state(token)) {
+                p = Some(v);
+            } else if n.is_none() {
+                n = Some(parse_style_animation_name(token)?);
+            } else {
+                return Err(CssAnimationParseError::InvalidShorthand(token));
+            }
+        }
+
+        let dur = times.get(0).copied().unwrap_or_default();
+        if dur.ms < 0 {
+            return Err(CssAnimationParseError::NegativeDuration(animation.trim()));
+        }
+
+        name.push(n.unwrap_or(StyleAnimationName::None));
+        duration.push(dur);
+        timing_function.push(t.unwrap_or_default());
+        delay.push(times.get(1).copied().unwrap_or_default());
+        iteration_count.push(i.unwrap_or_default());
+        direction.push(d.unwrap_or_default());
+        fill_mode.push(f.unwrap_or_default());
+        play_state.push(p.unwrap_or_default());
+    }
+
+    Ok(ParsedAnimationShorthand {
+        name: name.into(),
+        duration: duration.into(),
+        timing_function: timing_function.into(),
+        delay: delay.into(),
+        iteration_count: iteration_count.into(),
+        direction: direction.into(),
+        fill_mode: fill_mode.into(),
+        play_state: play_state.into(),
+    })
+}
+
 pub fn parse_style_tab_width(input: &str)
 -> Result<StyleTabWidth, PercentageParseError>
 {
@@ -3205,7 +3883,8 @@ multi_type_parser!(parse_layout_display, LayoutDisplay,
                     ["none", None],
                     ["flex", Flex],
                     ["block", Block],
-                    ["inline-block", InlineBlock]);
+                    ["inline-block", InlineBlock],
+                    ["grid", Grid]);
 
 multi_type_parser!(parse_layout_float, LayoutFloat,
                     ["left", Left],
@@ -3266,7 +3945,28 @@ multi_type_parser!(parse_layout_overflow, LayoutOverflow,
 multi_type_parser!(parse_layout_text_align, StyleTextAlign,
                     ["center", Center],
                     ["left", Left],
-                    ["right", Right]);
+                    ["right", Right],
+                    ["start", Start],
+                    ["end", End]);
+
+multi_type_parser!(parse_style_direction, StyleDirection,
+                    ["ltr", Ltr],
+                    ["rtl", Rtl]);
+
+multi_type_parser!(parse_style_word_break, StyleWordBreak,
+                    ["normal", Normal],
+                    ["break-all", BreakAll],
+                    ["keep-all", KeepAll]);
+
+multi_type_parser!(parse_style_overflow_wrap, StyleOverflowWrap,
+                    ["normal", Normal],
+                    ["anywhere", Anywhere],
+                    ["break-word", BreakWord]);
+
+multi_type_parser!(parse_style_hyphens, StyleHyphens,
+                    ["none", None],
+                    ["manual", Manual],
+                    ["auto", Auto]);
 
 #[cfg(test)]
 mod css_tests {
@@ -4000,6 +4700,26 @@ mod css_tests {
         assert_eq!(parse_float_value("60.9"), Ok(FloatValue::new(60.9)));
     }
 
+    #[test]
+    fn test_parse_style_direction() {
+        assert_eq!(parse_style_direction("rtl"), Ok(StyleDirection::Rtl));
+        assert_eq!(parse_style_direction("ltr"), Ok(StyleDirection::Ltr));
+        assert!(parse_style_direction("auto").is_err());
+        assert_eq!(parse_layout_text_align("start"), Ok(StyleTextAlign::Start));
+        assert_eq!(parse_layout_text_align("end"), Ok(StyleTextAlign::End));
+    }
+
+    #[test]
+    fn test_parse_line_breaking_properties() {
+        assert_eq!(parse_style_word_break("break-all"), Ok(StyleWordBreak::BreakAll));
+        assert_eq!(parse_style_word_break("keep-all"), Ok(StyleWordBreak::KeepAll));
+        assert_eq!(parse_style_overflow_wrap("anywhere"), Ok(StyleOverflowWrap::Anywhere));
+        assert_eq!(parse_style_overflow_wrap("break-word"), Ok(StyleOverflowWrap::BreakWord));
+        assert_eq!(parse_style_hyphens("auto"), Ok(StyleHyphens::Auto));
+        assert_eq!(parse_style_hyphens("none"), Ok(StyleHyphens::None));
+        assert!(parse_style_hyphens("all").is_err());
+    }
+
     #[test]
     fn test_parse_css_color_27() {
         assert_eq!(parse_css_color("hsla(240, 0%, 0%, 0.5)"), Ok(ColorU { r: 0, g: 0, b: 0, a: 128 }));
@@ -4070,6 +4790,13 @@ mod css_tests {
         assert_eq!(parse_pixel_value("aslkfdjasdflk"), Err(CssPixelValueParseError::InvalidPixelValue("aslkfdjasdflk")));
     }
 
+    #[test]
+    fn test_parse_pixel_value_unitless_zero() {
+        assert_eq!(parse_pixel_value("0"), Ok(PixelValue::px(0.0)));
+        assert_eq!(parse_pixel_value_no_percent("0"), Ok(PixelValueNoPercent { inner: PixelValue::px(0.0) }));
+        assert_eq!(parse_pixel_value("5"), Err(CssPixelValueParseError::InvalidPixelValue("5")));
+    }
+
     #[test]
     fn test_parse_style_border_radius_1() {
         assert_eq!(
@@ -4245,4 +4972,187 @@ mod css_tests {
             Ok(AngleValue::grad(20.4))
         );
     }
+
+    #[test]
+    fn test_parse_grid_track_sizing_vec_1() {
+        assert_eq!(
+            parse_grid_track_sizing_vec("100px 1fr auto"),
+            Ok(vec![
+                GridTrackSizing::fixed(PixelValue::px(100.0)),
+                GridTrackSizing::fraction(FloatValue::new(1.0)),
+                GridTrackSizing::auto(),
+            ].into())
+        );
+    }
+
+    #[test]
+    fn test_parse_grid_track_sizing_vec_2() {
+        assert_eq!(
+            parse_grid_track_sizing_vec("repeat(2, minmax(50px, 1fr)) 20%"),
+            Ok(vec![
+                GridTrackSizing::minmax(GridTrackBreadth::Fixed(PixelValue::px(50.0)), GridTrackBreadth::Fraction(FloatValue::new(1.0))),
+                GridTrackSizing::minmax(GridTrackBreadth::Fixed(PixelValue::px(50.0)), GridTrackBreadth::Fraction(FloatValue::new(1.0))),
+                GridTrackSizing::fixed(PixelValue::percent(20.0)),
+            ].into())
+        );
+    }
+
+    #[test]
+    fn test_parse_grid_track_sizing_vec_3() {
+        assert_eq!(
+            parse_grid_track_sizing_vec("minmax(1fr, 100px)"),
+            Err(CssGridParseError::InvalidTrackSize("minmax(1fr, 100px)"))
+        );
+        assert_eq!(
+            parse_grid_track_sizing_vec("repeat(0, 1fr)"),
+            Err(CssGridParseError::InvalidRepeat("repeat(0, 1fr)"))
+        );
+    }
+
+    #[test]
+    fn test_parse_grid_placement_1() {
+        assert_eq!(
+            parse_grid_placement("1 / 3"),
+            Ok(GridPlacement { start: GridLine::Line(1), end: GridLine::Line(3) })
+        );
+        assert_eq!(
+            parse_grid_placement("2 / span 2"),
+            Ok(GridPlacement { start: GridLine::Line(2), end: GridLine::Span(2) })
+        );
+        assert_eq!(
+            parse_grid_placement("span 3"),
+            Ok(GridPlacement { start: GridLine::Span(3), end: GridLine::Auto })
+        );
+        assert_eq!(
+            parse_grid_placement("1 / -1"),
+            Ok(GridPlacement { start: GridLine::Line(1), end: GridLine::Line(-1) })
+        );
+        assert_eq!(
+            parse_grid_placement("0"),
+            Err(CssGridParseError::InvalidLine("0"))
+        );
+    }
+
+    #[test]
+    fn test_parse_layout_grid_auto_flow_1() {
+        assert_eq!(parse_layout_grid_auto_flow("row"), Ok(LayoutGridAutoFlow::Row));
+        assert_eq!(parse_layout_grid_auto_flow("dense column"), Ok(LayoutGridAutoFlow::ColumnDense));
+        assert_eq!(parse_layout_grid_auto_flow("dense"), Ok(LayoutGridAutoFlow::RowDense));
+    }
+
+    #[test]
+    fn test_parse_layout_gap_1() {
+        assert_eq!(
+            parse_combined_css_property(CombinedCssPropertyType::Gap, "10px 20px"),
+            Ok(vec![
+                CssProperty::RowGap(CssPropertyValue::Exact(LayoutRowGap::px(10.0))),
+                CssProperty::ColumnGap(CssPropertyValue::Exact(LayoutColumnGap::px(20.0))),
+            ])
+        );
+        assert_eq!(
+            parse_combined_css_property(CombinedCssPropertyType::GridGap, "5px"),
+            Ok(vec![
+                CssProperty::RowGap(CssPropertyValue::Exact(LayoutRowGap::px(5.0))),
+                CssProperty::ColumnGap(CssPropertyValue::Exact(LayoutColumnGap::px(5.0))),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_style_time_1() {
+        assert_eq!(parse_style_time("200ms"), Ok(StyleTime::ms(200)));
+        assert_eq!(parse_style_time("0.5s"), Ok(StyleTime::ms(500)));
+        assert_eq!(parse_style_time("-1s"), Ok(StyleTime::ms(-1000)));
+        assert_eq!(parse_style_time("200"), Err(CssAnimationParseError::InvalidTime("200")));
+        assert_eq!(parse_style_duration("-1s"), Err(CssAnimationParseError::NegativeDuration("-1s")));
+    }
+
+    #[test]
+    fn test_parse_style_timing_function_1() {
+        assert_eq!(parse_style_timing_function("ease-in-out"), Ok(StyleTimingFunction::EaseInOut));
+        assert_eq!(
+            parse_style_timing_function("cubic-bezier(0.1, 0.7, 1.0, 0.1)"),
+            Ok(StyleTimingFunction::CubicBezier(StyleCubicBezier {
+                x1: FloatValue::new(0.1),
+                y1: FloatValue::new(0.7),
+                x2: FloatValue::new(1.0),
+                y2: FloatValue::new(0.1),
+            }))
+        );
+        assert_eq!(
+            parse_style_timing_function("cubic-bezier(1.5, 0, 0, 1)"),
+            Err(CssAnimationParseError::InvalidCubicBezier("cubic-bezier(1.5, 0, 0, 1)"))
+        );
+    }
+
+    #[test]
+    fn test_parse_style_timing_function_2() {
+        assert_eq!(
+            parse_style_timing_function("steps(4, jump-start)"),
+            Ok(StyleTimingFunction::Steps(StyleSteps { count: 4, position: StyleStepPosition::JumpStart }))
+        );
+        assert_eq!(
+            parse_style_timing_function("steps(3)"),
+            Ok(StyleTimingFunction::Steps(StyleSteps { count: 3, position: StyleStepPosition::JumpEnd }))
+        );
+        assert_eq!(
+            parse_style_timing_function("step-start"),
+            Ok(StyleTimingFunction::Steps(StyleSteps { count: 1, position: StyleStepPosition::JumpStart }))
+        );
+        assert_eq!(
+            parse_style_timing_function("steps(1, jump-none)"),
+            Err(CssAnimationParseError::InvalidSteps("steps(1, jump-none)"))
+        );
+    }
+
+    #[test]
+    fn test_parse_style_transition_property_1() {
+        assert_eq!(
+            parse_style_transition_property_vec("opacity, margin, all"),
+            Ok(vec![
+                StyleTransitionProperty::Property(CssPropertyType::Opacity),
+                StyleTransitionProperty::Shorthand(CombinedCssPropertyType::Margin),
+                StyleTransitionProperty::All,
+            ].into())
+        );
+        assert_eq!(
+            parse_style_transition_property("colour"),
+            Err(CssAnimationParseError::InvalidTransitionProperty("colour"))
+        );
+    }
+
+    #[test]
+    fn test_parse_transition_shorthand_1() {
+        assert_eq!(
+            parse_combined_css_property(CombinedCssPropertyType::Transition, "opacity 200ms ease-in 1s, 0.5s transform"),
+            Ok(vec![
+                CssProperty::TransitionProperty(CssPropertyValue::Exact(vec![
+                    StyleTransitionProperty::Property(CssPropertyType::Opacity),
+                    StyleTransitionProperty::Property(CssPropertyType::Transform),
+                ].into())),
+                CssProperty::TransitionDuration(CssPropertyValue::Exact(vec![StyleTime::ms(200), StyleTime::ms(500)].into())),
+                CssProperty::TransitionTimingFunction(CssPropertyValue::Exact(vec![
+                    StyleTimingFunction::EaseIn,
+                    StyleTimingFunction::Ease,
+                ].into())),
+                CssProperty::TransitionDelay(CssPropertyValue::Exact(vec![StyleTime::ms(1000), StyleTime::zero()].into())),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_animation_shorthand_1() {
+        let a = parse_style_animation_shorthand("spin 1s linear infinite alternate both").unwrap();
+        assert_eq!(a.name, vec![StyleAnimationName::Name("spin".into())].into());
+        assert_eq!(a.duration, vec![StyleTime::ms(1000)].into());
+        assert_eq!(a.timing_function, vec![StyleTimingFunction::Linear].into());
+        assert_eq!(a.iteration_count, vec![StyleAnimationIterationCount::Infinite].into());
+        assert_eq!(a.direction, vec![StyleAnimationDirection::Alternate].into());
+        assert_eq!(a.fill_mode, vec![StyleAnimationFillMode::Both].into());
+        assert_eq!(a.play_state, vec![StyleAnimationPlayState::Running].into());
+        assert_eq!(
+            parse_style_animation_shorthand("spin fade"),
+            Err(CssAnimationParseError::InvalidShorthand("fade"))
+        );
+    }
 }
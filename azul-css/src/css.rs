@@ -1,6 +1,6 @@
 //! Types and methods used to describe the style of an application
-use crate::css_properties::{CssProperty, CssPropertyType};
-use crate::AzString;
+use crate::css_properties::{CssProperty, CssPropertyType, CssPropertyVec, PercentageValue};
+use crate::{AzString, OptionF32};
 use core::fmt;
 use alloc::vec::Vec;
 use alloc::string::String;
@@ -39,6 +39,10 @@ impl Css {
 pub struct Stylesheet {
     /// The style rules making up the document - for example, de-duplicated CSS rules
     pub rules: CssRuleBlockVec,
+    /// The `@keyframes` blocks, referenced by name from `animation-name`
+    pub keyframes: CssKeyframesVec,
+    /// The `@media` blocks, applied depending on the window size, DPI and theme
+    pub media_rules: CssMediaRuleVec,
 }
 
 impl_vec!(CssRuleBlock, CssRuleBlockVec, CssRuleBlockVecDestructor);
@@ -50,13 +54,302 @@ impl_vec_partialeq!(CssRuleBlock, CssRuleBlockVec);
 
 impl Stylesheet {
     pub fn new(rules: Vec<CssRuleBlock>) -> Self {
-        Self { rules: rules.into() }
+        Self { rules: rules.into(), keyframes: Vec::new().into(), media_rules: Vec::new().into() }
     }
 }
 
 impl From<Vec<CssRuleBlock>> for Stylesheet {
     fn from(rules: Vec<CssRuleBlock>) -> Self {
-        Self { rules: rules.into() }
+        Self { rules: rules.into(), keyframes: Vec::new().into(), media_rules: Vec::new().into() }
+    }
+}
+
+/// One `@keyframes` block, i.e. `@keyframes fade { from { opacity: 0; } to { opacity: 1; } }`
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
+#[repr(C)]
+pub struct CssKeyframes {
+    /// Name of the animation, as referenced by `animation-name`
+    pub name: AzString,
+    /// Keyframes, sorted by their offset
+    pub frames: CssKeyframeVec,
+}
+
+impl_vec!(CssKeyframes, CssKeyframesVec, CssKeyframesVecDestructor);
+impl_vec_debug!(CssKeyframes, CssKeyframesVec);
+impl_vec_partialord!(CssKeyframes, CssKeyframesVec);
+impl_vec_clone!(CssKeyframes, CssKeyframesVec, CssKeyframesVecDestructor);
+impl_vec_partialeq!(CssKeyframes, CssKeyframesVec);
+
+/// One keyframe inside of a `@keyframes` block, i.e. `50% { opacity: 0.5; }`
+///
+/// `from` and `to` are stored as `0%` and `100%`, a keyframe with multiple
+/// selectors (`0%, 100% { ... }`) is stored once per selector.
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
+#[repr(C)]
+pub struct CssKeyframe {
+    /// Position of the keyframe in the animation (0% - 100%)
+    pub offset: PercentageValue,
+    /// Properties set at this keyframe. An `animation-timing-function` in here
+    /// sets the easing from this keyframe to the next one.
+    pub properties: CssPropertyVec,
+}
+
+impl_vec!(CssKeyframe, CssKeyframeVec, CssKeyframeVecDestructor);
+impl_vec_debug!(CssKeyframe, CssKeyframeVec);
+impl_vec_partialord!(CssKeyframe, CssKeyframeVec);
+impl_vec_clone!(CssKeyframe, CssKeyframeVec, CssKeyframeVecDestructor);
+impl_vec_partialeq!(CssKeyframe, CssKeyframeVec);
+
+impl Css {
+    /// Returns the `@keyframes` block with the given name - later stylesheets
+    /// override earlier ones, just like in the browser
+    pub fn get_keyframes(&self, name: &str) -> Option<&CssKeyframes> {
+        self.stylesheets.iter().rev()
+        .flat_map(|s| s.keyframes.iter().rev())
+        .find(|k| k.name.as_str() == name)
+    }
+}
+
+/// One `@media` block, i.e. `@media (prefers-color-scheme: dark) { body { color: white; } }`
+///
+/// The rules inside the block only apply when at least one of the
+/// `queries` matches the current `CssMediaEnvironment`.
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
+#[repr(C)]
+pub struct CssMediaRule {
+    /// Comma-separated media query list - the block applies if any query matches
+    pub queries: CssMediaQueryVec,
+    /// Number of normal rules in the stylesheet that come before this block in the
+    /// source file, used to insert the rules at the correct position (source order
+    /// matters for rules with the same specificity)
+    pub insert_index: usize,
+    /// The style rules inside the `@media` block
+    pub rules: CssRuleBlockVec,
+}
+
+impl_vec!(CssMediaRule, CssMediaRuleVec, CssMediaRuleVecDestructor);
+impl_vec_debug!(CssMediaRule, CssMediaRuleVec);
+impl_vec_partialord!(CssMediaRule, CssMediaRuleVec);
+impl_vec_clone!(CssMediaRule, CssMediaRuleVec, CssMediaRuleVecDestructor);
+impl_vec_partialeq!(CssMediaRule, CssMediaRuleVec);
+
+/// One query of a media query list, i.e. `screen and (min-width: 600px)`
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone)]
+#[repr(C)]
+pub struct CssMediaQuery {
+    /// Whether the query was prefixed with `not`
+    pub negated: bool,
+    /// Media type (`all`, `screen` or `print`), `all` if omitted
+    pub media_type: CssMediaType,
+    /// `and`-joined media features, all of them have to match
+    pub features: CssMediaFeatureVec,
+}
+
+impl_vec!(CssMediaQuery, CssMediaQueryVec, CssMediaQueryVecDestructor);
+impl_vec_debug!(CssMediaQuery, CssMediaQueryVec);
+impl_vec_partialord!(CssMediaQuery, CssMediaQueryVec);
+impl_vec_clone!(CssMediaQuery, CssMediaQueryVec, CssMediaQueryVecDestructor);
+impl_vec_partialeq!(CssMediaQuery, CssMediaQueryVec);
+
+impl CssMediaQuery {
+    /// Returns whether the query matches the given environment
+    pub fn matches(&self, env: &CssMediaEnvironment) -> bool {
+        let type_matches = match self.media_type {
+            CssMediaType::All | CssMediaType::Screen => true,
+            CssMediaType::Print => false,
+        };
+        let matches = type_matches && self.features.iter().all(|f| f.matches(env));
+        matches != self.negated
+    }
+}
+
+/// Media type of a `CssMediaQuery`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub enum CssMediaType {
+    All,
+    Screen,
+    Print,
+}
+
+impl Default for CssMediaType {
+    fn default() -> Self { CssMediaType::All }
+}
+
+/// One media feature, i.e. `(min-width: 600px)` or `(prefers-color-scheme: dark)`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C, u8)]
+pub enum CssMediaFeature {
+    /// Logical width of the window in pixels
+    Width(CssMediaRange),
+    /// Logical height of the window in pixels
+    Height(CssMediaRange),
+    /// Resolution of the window in `dppx` (= the HiDPI factor)
+    Resolution(CssMediaRange),
+    Orientation(CssMediaOrientation),
+    PrefersColorScheme(CssColorScheme),
+}
+
+impl_vec!(CssMediaFeature, CssMediaFeatureVec, CssMediaFeatureVecDestructor);
+impl_vec_debug!(CssMediaFeature, CssMediaFeatureVec);
+impl_vec_partialord!(CssMediaFeature, CssMediaFeatureVec);
+impl_vec_clone!(CssMediaFeature, CssMediaFeatureVec, CssMediaFeatureVecDestructor);
+impl_vec_partialeq!(CssMediaFeature, CssMediaFeatureVec);
+
+impl CssMediaFeature {
+    /// Returns whether the feature matches the given environment
+    pub fn matches(&self, env: &CssMediaEnvironment) -> bool {
+        use self::CssMediaFeature::*;
+        match self {
+            Width(r) => r.contains(env.width),
+            Height(r) => r.contains(env.height),
+            Resolution(r) => r.contains(env.resolution),
+            Orientation(o) => *o == env.get_orientation(),
+            PrefersColorScheme(c) => *c == env.color_scheme,
+        }
+    }
+}
+
+/// Range of a numeric media feature: `(min-width: 600px)` is stored as
+/// `min: Some(600.0), max: None`, `(400px < width <= 700px)` as
+/// `min: Some(400.0), min_inclusive: false, max: Some(700.0), max_inclusive: true`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct CssMediaRange {
+    pub min: OptionF32,
+    pub min_inclusive: bool,
+    pub max: OptionF32,
+    pub max_inclusive: bool,
+}
+
+impl CssMediaRange {
+
+    /// Range that only contains `value`, i.e. `(width: 600px)`
+    pub const fn exact(value: f32) -> Self {
+        Self {
+            min: OptionF32::Some(value),
+            min_inclusive: true,
+            max: OptionF32::Some(value),
+            max_inclusive: true,
+        }
+    }
+
+    /// `value >= min` (or `value > min` if `inclusive` is false)
+    pub const fn at_least(min: f32, inclusive: bool) -> Self {
+        Self { min: OptionF32::Some(min), min_inclusive: inclusive, max: OptionF32::None, max_inclusive: true }
+    }
+
+    /// `value <= max` (or `value < max` if `inclusive` is false)
+    pub const fn at_most(max: f32, inclusive: bool) -> Self {
+        Self { min: OptionF32::None, min_inclusive: true, max: OptionF32::Some(max), max_inclusive: inclusive }
+    }
+
+    pub fn contains(&self, value: f32) -> bool {
+        let above_min = match self.min {
+            OptionF32::Some(min) => if self.min_inclusive { value >= min } else { value > min },
+            OptionF32::None => true,
+        };
+        let below_max = match self.max {
+            OptionF32::Some(max) => if self.max_inclusive { value <= max } else { value < max },
+            OptionF32::None => true,
+        };
+        above_min && below_max
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub enum CssMediaOrientation {
+    Portrait,
+    Landscape,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub enum CssColorScheme {
+    Light,
+    Dark,
+}
+
+/// Values that `@media` queries are evaluated against, usually
+/// created from the window size, DPI and theme
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct CssMediaEnvironment {
+    /// Logical width of the window
+    pub width: f32,
+    /// Logical height of the window
+    pub height: f32,
+    /// HiDPI factor of the window (`1dppx` = 96 DPI)
+    pub resolution: f32,
+    pub color_scheme: CssColorScheme,
+}
+
+impl Default for CssMediaEnvironment {
+    fn default() -> Self {
+        Self {
+            width: 800.0,
+            height: 600.0,
+            resolution: 1.0,
+            color_scheme: CssColorScheme::Light,
+        }
+    }
+}
+
+impl CssMediaEnvironment {
+    /// `portrait` if the height is greater than or equal to the width, as defined in the spec
+    pub fn get_orientation(&self) -> CssMediaOrientation {
+        if self.height >= self.width {
+            CssMediaOrientation::Portrait
+        } else {
+            CssMediaOrientation::Landscape
+        }
+    }
+}
+
+impl Css {
+
+    /// Returns whether any stylesheet contains `@media` blocks
+    pub fn has_media_rules(&self) -> bool {
+        self.stylesheets.iter().any(|s| !s.media_rules.as_ref().is_empty())
+    }
+
+    /// Evaluates every `@media` block against the environment, in order
+    /// (first all blocks of the first stylesheet, then the second, etc.)
+    pub fn evaluate_media_queries(&self, env: &CssMediaEnvironment) -> Vec<bool> {
+        self.stylesheets.iter()
+        .flat_map(|s| s.media_rules.iter())
+        .map(|m| m.queries.iter().any(|q| q.matches(env)))
+        .collect()
+    }
+
+    /// Inserts the rules of all matching `@media` blocks into the normal rules
+    /// (at their original source position) and removes all `@media` blocks
+    pub fn apply_media_queries(&mut self, env: &CssMediaEnvironment) {
+        for stylesheet in self.stylesheets.as_mut().iter_mut() {
+
+            if stylesheet.media_rules.as_ref().is_empty() {
+                continue;
+            }
+
+            let media_rules = core::mem::replace(&mut stylesheet.media_rules, Vec::new().into()).into_library_owned_vec();
+            let old_rules = core::mem::replace(&mut stylesheet.rules, Vec::new().into()).into_library_owned_vec();
+            let mut new_rules = Vec::with_capacity(old_rules.len());
+            let mut media_rules = media_rules.into_iter().filter(|m| m.queries.iter().any(|q| q.matches(env))).peekable();
+
+            for (rule_idx, rule) in old_rules.into_iter().enumerate() {
+                while let Some(m) = media_rules.next_if(|m| m.insert_index <= rule_idx) {
+                    new_rules.extend(m.rules.into_library_owned_vec());
+                }
+                new_rules.push(rule);
+            }
+
+            for m in media_rules {
+                new_rules.extend(m.rules.into_library_owned_vec());
+            }
+
+            stylesheet.rules = new_rules.into();
+        }
     }
 }
 
@@ -614,6 +907,8 @@ fn test_specificity_sort() {
             CssRuleBlock { path: CssPath { selectors: vec![Global, Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
             CssRuleBlock { path: CssPath { selectors: vec![Type(Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
         ].into(),
+        keyframes: Vec::new().into(),
+        media_rules: Vec::new().into(),
     }.sort_by_specificity();
 
     let expected_style = Stylesheet {
@@ -625,6 +920,8 @@ fn test_specificity_sort() {
             CssRuleBlock { path: CssPath { selectors: vec![Global, Type(Div), Class("my_class".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
             CssRuleBlock { path: CssPath { selectors: vec![Type(Div), Class("my_class".to_string().into()), Class("specific".to_string().into()), Id("my_id".to_string().into())].into() }, declarations: Vec::new().into() },
         ].into(),
+        keyframes: Vec::new().into(),
+        media_rules: Vec::new().into(),
     };
 
     assert_eq!(input_style, expected_style);
@@ -19,7 +19,7 @@ use crate::{
 pub const EM_HEIGHT: f32 = 16.0;
 pub const PT_TO_PX: f32 = 96.0 / 72.0;
 
-const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str);12] = [
+const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str);16] = [
     (CombinedCssPropertyType::BorderRadius,         "border-radius"),
     (CombinedCssPropertyType::Overflow,             "overflow"),
     (CombinedCssPropertyType::Padding,              "padding"),
@@ -32,10 +32,14 @@ const COMBINED_CSS_PROPERTIES_KEY_MAP: [(CombinedCssPropertyType, &'static str);
     (CombinedCssPropertyType::BoxShadow,            "box-shadow"),
     (CombinedCssPropertyType::BackgroundColor,      "background-color"),
     (CombinedCssPropertyType::BackgroundImage,      "background-image"),
+    (CombinedCssPropertyType::Gap,                  "gap"),
+    (CombinedCssPropertyType::GridGap,              "grid-gap"),
+    (CombinedCssPropertyType::Transition,           "transition"),
+    (CombinedCssPropertyType::Animation,            "animation"),
 ];
 
 /// Map between CSS keys and a statically typed enum
-const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str);74] = [
+const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str);99] = [
 
     (CssPropertyType::Display,              "display"),
     (CssPropertyType::Float,                "float"),
@@ -45,6 +49,10 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str);74] = [
     (CssPropertyType::FontSize,             "font-size"),
     (CssPropertyType::FontFamily,           "font-family"),
     (CssPropertyType::TextAlign,            "text-align"),
+    (CssPropertyType::Direction,            "direction"),
+    (CssPropertyType::WordBreak,            "word-break"),
+    (CssPropertyType::OverflowWrap,         "overflow-wrap"),
+    (CssPropertyType::Hyphens,              "hyphens"),
 
     (CssPropertyType::LetterSpacing,        "letter-spacing"),
     (CssPropertyType::LineHeight,           "line-height"),
@@ -73,6 +81,16 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str);74] = [
     (CssPropertyType::AlignItems,           "align-items"),
     (CssPropertyType::AlignContent,         "align-content"),
 
+    (CssPropertyType::GridTemplateColumns,  "grid-template-columns"),
+    (CssPropertyType::GridTemplateRows,     "grid-template-rows"),
+    (CssPropertyType::GridAutoColumns,      "grid-auto-columns"),
+    (CssPropertyType::GridAutoRows,         "grid-auto-rows"),
+    (CssPropertyType::GridAutoFlow,         "grid-auto-flow"),
+    (CssPropertyType::GridColumn,           "grid-column"),
+    (CssPropertyType::GridRow,              "grid-row"),
+    (CssPropertyType::RowGap,               "row-gap"),
+    (CssPropertyType::ColumnGap,            "column-gap"),
+
     (CssPropertyType::OverflowX,            "overflow-x"),
     (CssPropertyType::OverflowY,            "overflow-y"),
 
@@ -128,6 +146,19 @@ const CSS_PROPERTY_KEY_MAP: [(CssPropertyType, &'static str);74] = [
     (CssPropertyType::Filter, "filter"),
     (CssPropertyType::BackdropFilter, "backdrop-filter"),
     (CssPropertyType::TextShadow, "text-shadow"),
+
+    (CssPropertyType::TransitionProperty, "transition-property"),
+    (CssPropertyType::TransitionDuration, "transition-duration"),
+    (CssPropertyType::TransitionTimingFunction, "transition-timing-function"),
+    (CssPropertyType::TransitionDelay, "transition-delay"),
+    (CssPropertyType::AnimationName, "animation-name"),
+    (CssPropertyType::AnimationDuration, "animation-duration"),
+    (CssPropertyType::AnimationTimingFunction, "animation-timing-function"),
+    (CssPropertyType::AnimationDelay, "animation-delay"),
+    (CssPropertyType::AnimationIterationCount, "animation-iteration-count"),
+    (CssPropertyType::AnimationDirection, "animation-direction"),
+    (CssPropertyType::AnimationFillMode, "animation-fill-mode"),
+    (CssPropertyType::AnimationPlayState, "animation-play-state"),
 ];
 
 // The following types are present in webrender, however, azul-css should not
@@ -749,6 +780,7 @@ macro_rules! impl_float_value{($struct:ident) => (
 )}
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
 pub enum CombinedCssPropertyType {
     BorderRadius,
     Overflow,
@@ -762,6 +794,10 @@ pub enum CombinedCssPropertyType {
     BoxShadow,
     BackgroundColor, // BackgroundContent::Colo
     BackgroundImage, // BackgroundContent::Colo
+    Gap,
+    GridGap,
+    Transition,
+    Animation,
 }
 
 impl fmt::Display for CombinedCssPropertyType {
@@ -791,6 +827,36 @@ impl CombinedCssPropertyType {
     pub fn to_str(&self, map: &CssKeyMap) -> &'static str {
         map.shorthands.iter().find(|(_, v)| *v == self).map(|(k, _)| k).unwrap()
     }
+
+    /// Returns the longhand properties that this shorthand expands to
+    pub fn get_longhand_types(&self) -> &'static [CssPropertyType] {
+        use self::CssPropertyType::*;
+        match self {
+            CombinedCssPropertyType::BorderRadius => &[BorderTopLeftRadius, BorderTopRightRadius, BorderBottomLeftRadius, BorderBottomRightRadius],
+            CombinedCssPropertyType::Overflow => &[OverflowX, OverflowY],
+            CombinedCssPropertyType::Margin => &[MarginTop, MarginRight, MarginBottom, MarginLeft],
+            CombinedCssPropertyType::Border => &[
+                BorderTopWidth, BorderRightWidth, BorderBottomWidth, BorderLeftWidth,
+                BorderTopStyle, BorderRightStyle, BorderBottomStyle, BorderLeftStyle,
+                BorderTopColor, BorderRightColor, BorderBottomColor, BorderLeftColor,
+            ],
+            CombinedCssPropertyType::BorderLeft => &[BorderLeftWidth, BorderLeftStyle, BorderLeftColor],
+            CombinedCssPropertyType::BorderRight => &[BorderRightWidth, BorderRightStyle, BorderRightColor],
+            CombinedCssPropertyType::BorderTop => &[BorderTopWidth, BorderTopStyle, BorderTopColor],
+            CombinedCssPropertyType::BorderBottom => &[BorderBottomWidth, BorderBottomStyle, BorderBottomColor],
+            CombinedCssPropertyType::Padding => &[PaddingTop, PaddingRight, PaddingBottom, PaddingLeft],
+            CombinedCssPropertyType::BoxShadow => &[BoxShadowLeft, BoxShadowRight, BoxShadowTop, BoxShadowBottom],
+            CombinedCssPropertyType::BackgroundColor => &[BackgroundContent],
+            CombinedCssPropertyType::BackgroundImage => &[BackgroundContent],
+            CombinedCssPropertyType::Gap => &[RowGap, ColumnGap],
+            CombinedCssPropertyType::GridGap => &[RowGap, ColumnGap],
+            CombinedCssPropertyType::Transition => &[TransitionProperty, TransitionDuration, TransitionTimingFunction, TransitionDelay],
+            CombinedCssPropertyType::Animation => &[
+                AnimationName, AnimationDuration, AnimationTimingFunction, AnimationDelay,
+                AnimationIterationCount, AnimationDirection, AnimationFillMode, AnimationPlayState,
+            ],
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -824,6 +890,10 @@ pub enum CssPropertyType {
     FontSize,
     FontFamily,
     TextAlign,
+    Direction,
+    WordBreak,
+    OverflowWrap,
+    Hyphens,
     LetterSpacing,
     LineHeight,
     WordSpacing,
@@ -850,6 +920,15 @@ pub enum CssPropertyType {
     JustifyContent,
     AlignItems,
     AlignContent,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridAutoColumns,
+    GridAutoRows,
+    GridAutoFlow,
+    GridColumn,
+    GridRow,
+    RowGap,
+    ColumnGap,
     BackgroundContent,
     BackgroundPosition,
     BackgroundSize,
@@ -894,6 +973,18 @@ pub enum CssPropertyType {
     Filter,
     BackdropFilter,
     TextShadow,
+    TransitionProperty,
+    TransitionDuration,
+    TransitionTimingFunction,
+    TransitionDelay,
+    AnimationName,
+    AnimationDuration,
+    AnimationTimingFunction,
+    AnimationDelay,
+    AnimationIterationCount,
+    AnimationDirection,
+    AnimationFillMode,
+    AnimationPlayState,
 }
 
 impl CssPropertyType {
@@ -921,6 +1012,10 @@ impl CssPropertyType {
             CssPropertyType::FontSize => "font-size",
             CssPropertyType::FontFamily => "font-family",
             CssPropertyType::TextAlign => "text-align",
+            CssPropertyType::Direction => "direction",
+            CssPropertyType::WordBreak => "word-break",
+            CssPropertyType::OverflowWrap => "overflow-wrap",
+            CssPropertyType::Hyphens => "hyphens",
             CssPropertyType::LetterSpacing => "letter-spacing",
             CssPropertyType::LineHeight => "line-height",
             CssPropertyType::WordSpacing => "word-spacing",
@@ -947,6 +1042,15 @@ impl CssPropertyType {
             CssPropertyType::JustifyContent => "justify-content",
             CssPropertyType::AlignItems => "align-items",
             CssPropertyType::AlignContent => "align-content",
+            CssPropertyType::GridTemplateColumns => "grid-template-columns",
+            CssPropertyType::GridTemplateRows => "grid-template-rows",
+            CssPropertyType::GridAutoColumns => "grid-auto-columns",
+            CssPropertyType::GridAutoRows => "grid-auto-rows",
+            CssPropertyType::GridAutoFlow => "grid-auto-flow",
+            CssPropertyType::GridColumn => "grid-column",
+            CssPropertyType::GridRow => "grid-row",
+            CssPropertyType::RowGap => "row-gap",
+            CssPropertyType::ColumnGap => "column-gap",
             CssPropertyType::BackgroundContent => "background",
             CssPropertyType::BackgroundPosition => "background-position",
             CssPropertyType::BackgroundSize => "background-size",
@@ -991,6 +1095,18 @@ impl CssPropertyType {
             CssPropertyType::Filter => "filter",
             CssPropertyType::BackdropFilter => "backdrop-filter",
             CssPropertyType::TextShadow => "text-shadow",
+            CssPropertyType::TransitionProperty => "transition-property",
+            CssPropertyType::TransitionDuration => "transition-duration",
+            CssPropertyType::TransitionTimingFunction => "transition-timing-function",
+            CssPropertyType::TransitionDelay => "transition-delay",
+            CssPropertyType::AnimationName => "animation-name",
+            CssPropertyType::AnimationDuration => "animation-duration",
+            CssPropertyType::AnimationTimingFunction => "animation-timing-function",
+            CssPropertyType::AnimationDelay => "animation-delay",
+            CssPropertyType::AnimationIterationCount => "animation-iteration-count",
+            CssPropertyType::AnimationDirection => "animation-direction",
+            CssPropertyType::AnimationFillMode => "animation-fill-mode",
+            CssPropertyType::AnimationPlayState => "animation-play-state",
         }
     }
 
@@ -1002,7 +1118,11 @@ impl CssPropertyType {
             | FontFamily
             | FontSize
             | LineHeight
-            | TextAlign => true,
+            | TextAlign
+            | Direction
+            | WordBreak
+            | OverflowWrap
+            | Hyphens => true,
             _ => false,
         }
     }
@@ -1051,6 +1171,18 @@ impl CssPropertyType {
             | Filter
             | BackdropFilter
             | TextShadow
+            | TransitionProperty
+            | TransitionDuration
+            | TransitionTimingFunction
+            | TransitionDelay
+            | AnimationName
+            | AnimationDuration
+            | AnimationTimingFunction
+            | AnimationDelay
+            | AnimationIterationCount
+            | AnimationDirection
+            | AnimationFillMode
+            | AnimationPlayState
             => false,
             _ => true,
         }
@@ -1064,6 +1196,60 @@ impl CssPropertyType {
             _ => false
         }
     }
+
+    /// Returns whether `CssProperty::interpolate` can produce in-between values for this
+    /// property - all other properties switch from the start to the end value at t = 0.5
+    pub fn is_animatable(&self) -> bool {
+        use self::CssPropertyType::*;
+        match self {
+            | TextColor
+            | FontSize
+            | LetterSpacing
+            | LineHeight
+            | WordSpacing
+            | TabWidth
+            | Width
+            | Height
+            | MinWidth
+            | MinHeight
+            | MaxWidth
+            | MaxHeight
+            | Top
+            | Right
+            | Left
+            | Bottom
+            | FlexGrow
+            | FlexShrink
+            | RowGap
+            | ColumnGap
+            | BackgroundContent
+            | PaddingTop
+            | PaddingLeft
+            | PaddingRight
+            | PaddingBottom
+            | MarginTop
+            | MarginLeft
+            | MarginRight
+            | MarginBottom
+            | BorderTopLeftRadius
+            | BorderTopRightRadius
+            | BorderBottomLeftRadius
+            | BorderBottomRightRadius
+            | BorderTopColor
+            | BorderRightColor
+            | BorderLeftColor
+            | BorderBottomColor
+            | BorderTopWidth
+            | BorderRightWidth
+            | BorderLeftWidth
+            | BorderBottomWidth
+            | Opacity
+            | TransformOrigin
+            | PerspectiveOrigin
+            => true,
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Debug for CssPropertyType {
@@ -1086,6 +1272,10 @@ pub enum CssProperty {
     FontSize(StyleFontSizeValue),
     FontFamily(StyleFontFamilyVecValue),
     TextAlign(StyleTextAlignValue),
+    Direction(StyleDirectionValue),
+    WordBreak(StyleWordBreakValue),
+    OverflowWrap(StyleOverflowWrapValue),
+    Hyphens(StyleHyphensValue),
     LetterSpacing(StyleLetterSpacingValue),
     LineHeight(StyleLineHeightValue),
     WordSpacing(StyleWordSpacingValue),
@@ -1112,6 +1302,15 @@ pub enum CssProperty {
     JustifyContent(LayoutJustifyContentValue),
     AlignItems(LayoutAlignItemsValue),
     AlignContent(LayoutAlignContentValue),
+    GridTemplateColumns(GridTrackSizingVecValue),
+    GridTemplateRows(GridTrackSizingVecValue),
+    GridAutoColumns(GridTrackSizingValue),
+    GridAutoRows(GridTrackSizingValue),
+    GridAutoFlow(LayoutGridAutoFlowValue),
+    GridColumn(GridPlacementValue),
+    GridRow(GridPlacementValue),
+    RowGap(LayoutRowGapValue),
+    ColumnGap(LayoutColumnGapValue),
     BackgroundContent(StyleBackgroundContentVecValue),
     BackgroundPosition(StyleBackgroundPositionVecValue),
     BackgroundSize(StyleBackgroundSizeVecValue),
@@ -1156,6 +1355,18 @@ pub enum CssProperty {
     Filter(StyleFilterVecValue),
     BackdropFilter(StyleFilterVecValue),
     TextShadow(StyleBoxShadowValue),
+    TransitionProperty(StyleTransitionPropertyVecValue),
+    TransitionDuration(StyleTimeVecValue),
+    TransitionTimingFunction(StyleTimingFunctionVecValue),
+    TransitionDelay(StyleTimeVecValue),
+    AnimationName(StyleAnimationNameVecValue),
+    AnimationDuration(StyleTimeVecValue),
+    AnimationTimingFunction(StyleTimingFunctionVecValue),
+    AnimationDelay(StyleTimeVecValue),
+    AnimationIterationCount(StyleAnimationIterationCountVecValue),
+    AnimationDirection(StyleAnimationDirectionVecValue),
+    AnimationFillMode(StyleAnimationFillModeVecValue),
+    AnimationPlayState(StyleAnimationPlayStateVecValue),
 }
 
 impl_option!(CssProperty, OptionCssProperty, copy = false, [Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord]);
@@ -1166,6 +1377,10 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::FontSize => CssProperty::FontSize(StyleFontSizeValue::$content_type),
         CssPropertyType::FontFamily => CssProperty::FontFamily(StyleFontFamilyVecValue::$content_type),
         CssPropertyType::TextAlign => CssProperty::TextAlign(StyleTextAlignValue::$content_type),
+        CssPropertyType::Direction => CssProperty::Direction(StyleDirectionValue::$content_type),
+        CssPropertyType::WordBreak => CssProperty::WordBreak(StyleWordBreakValue::$content_type),
+        CssPropertyType::OverflowWrap => CssProperty::OverflowWrap(StyleOverflowWrapValue::$content_type),
+        CssPropertyType::Hyphens => CssProperty::Hyphens(StyleHyphensValue::$content_type),
         CssPropertyType::LetterSpacing => CssProperty::LetterSpacing(StyleLetterSpacingValue::$content_type),
         CssPropertyType::LineHeight => CssProperty::LineHeight(StyleLineHeightValue::$content_type),
         CssPropertyType::WordSpacing => CssProperty::WordSpacing(StyleWordSpacingValue::$content_type),
@@ -1192,6 +1407,15 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::JustifyContent => CssProperty::JustifyContent(LayoutJustifyContentValue::$content_type),
         CssPropertyType::AlignItems => CssProperty::AlignItems(LayoutAlignItemsValue::$content_type),
         CssPropertyType::AlignContent => CssProperty::AlignContent(LayoutAlignContentValue::$content_type),
+        CssPropertyType::GridTemplateColumns => CssProperty::GridTemplateColumns(GridTrackSizingVecValue::$content_type),
+        CssPropertyType::GridTemplateRows => CssProperty::GridTemplateRows(GridTrackSizingVecValue::$content_type),
+        CssPropertyType::GridAutoColumns => CssProperty::GridAutoColumns(GridTrackSizingValue::$content_type),
+        CssPropertyType::GridAutoRows => CssProperty::GridAutoRows(GridTrackSizingValue::$content_type),
+        CssPropertyType::GridAutoFlow => CssProperty::GridAutoFlow(LayoutGridAutoFlowValue::$content_type),
+        CssPropertyType::GridColumn => CssProperty::GridColumn(GridPlacementValue::$content_type),
+        CssPropertyType::GridRow => CssProperty::GridRow(GridPlacementValue::$content_type),
+        CssPropertyType::RowGap => CssProperty::RowGap(LayoutRowGapValue::$content_type),
+        CssPropertyType::ColumnGap => CssProperty::ColumnGap(LayoutColumnGapValue::$content_type),
         CssPropertyType::BackgroundContent => CssProperty::BackgroundContent(StyleBackgroundContentVecValue::$content_type),
         CssPropertyType::BackgroundPosition => CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::$content_type),
         CssPropertyType::BackgroundSize => CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::$content_type),
@@ -1236,6 +1460,18 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::Filter => CssProperty::Filter(StyleFilterVecValue::$content_type),
         CssPropertyType::BackdropFilter => CssProperty::BackdropFilter(StyleFilterVecValue::$content_type),
         CssPropertyType::TextShadow => CssProperty::TextShadow(StyleBoxShadowValue::$content_type),
+        CssPropertyType::TransitionProperty => CssProperty::TransitionProperty(StyleTransitionPropertyVecValue::$content_type),
+        CssPropertyType::TransitionDuration => CssProperty::TransitionDuration(StyleTimeVecValue::$content_type),
+        CssPropertyType::TransitionTimingFunction => CssProperty::TransitionTimingFunction(StyleTimingFunctionVecValue::$content_type),
+        CssPropertyType::TransitionDelay => CssProperty::TransitionDelay(StyleTimeVecValue::$content_type),
+        CssPropertyType::AnimationName => CssProperty::AnimationName(StyleAnimationNameVecValue::$content_type),
+        CssPropertyType::AnimationDuration => CssProperty::AnimationDuration(StyleTimeVecValue::$content_type),
+        CssPropertyType::AnimationTimingFunction => CssProperty::AnimationTimingFunction(StyleTimingFunctionVecValue::$content_type),
+        CssPropertyType::AnimationDelay => CssProperty::AnimationDelay(StyleTimeVecValue::$content_type),
+        CssPropertyType::AnimationIterationCount => CssProperty::AnimationIterationCount(StyleAnimationIterationCountVecValue::$content_type),
+        CssPropertyType::AnimationDirection => CssProperty::AnimationDirection(StyleAnimationDirectionVecValue::$content_type),
+        CssPropertyType::AnimationFillMode => CssProperty::AnimationFillMode(StyleAnimationFillModeVecValue::$content_type),
+        CssPropertyType::AnimationPlayState => CssProperty::AnimationPlayState(StyleAnimationPlayStateVecValue::$content_type),
     }
 })}
 
@@ -1247,6 +1483,10 @@ impl CssProperty {
             FontSize(c) => c.is_initial(),
             FontFamily(c) => c.is_initial(),
             TextAlign(c) => c.is_initial(),
+            Direction(c) => c.is_initial(),
+            WordBreak(c) => c.is_initial(),
+            OverflowWrap(c) => c.is_initial(),
+            Hyphens(c) => c.is_initial(),
             LetterSpacing(c) => c.is_initial(),
             LineHeight(c) => c.is_initial(),
             WordSpacing(c) => c.is_initial(),
@@ -1273,6 +1513,15 @@ impl CssProperty {
             JustifyContent(c) => c.is_initial(),
             AlignItems(c) => c.is_initial(),
             AlignContent(c) => c.is_initial(),
+            GridTemplateColumns(c) => c.is_initial(),
+            GridTemplateRows(c) => c.is_initial(),
+            GridAutoColumns(c) => c.is_initial(),
+            GridAutoRows(c) => c.is_initial(),
+            GridAutoFlow(c) => c.is_initial(),
+            GridColumn(c) => c.is_initial(),
+            GridRow(c) => c.is_initial(),
+            RowGap(c) => c.is_initial(),
+            ColumnGap(c) => c.is_initial(),
             BackgroundContent(c) => c.is_initial(),
             BackgroundPosition(c) => c.is_initial(),
             BackgroundSize(c) => c.is_initial(),
@@ -1317,6 +1566,18 @@ impl CssProperty {
             Filter(c) => c.is_initial(),
             BackdropFilter(c) => c.is_initial(),
             TextShadow(c) => c.is_initial(),
+            TransitionProperty(c) => c.is_initial(),
+            TransitionDuration(c) => c.is_initial(),
+            TransitionTimingFunction(c) => c.is_initial(),
+            TransitionDelay(c) => c.is_initial(),
+            AnimationName(c) => c.is_initial(),
+            AnimationDuration(c) => c.is_initial(),
+            AnimationTimingFunction(c) => c.is_initial(),
+            AnimationDelay(c) => c.is_initial(),
+            AnimationIterationCount(c) => c.is_initial(),
+            AnimationDirection(c) => c.is_initial(),
+            AnimationFillMode(c) => c.is_initial(),
+            AnimationPlayState(c) => c.is_initial(),
         }
     }
 
@@ -1329,6 +1590,10 @@ impl CssProperty {
     pub const fn const_font_size(input: StyleFontSize) -> Self { CssProperty::FontSize(StyleFontSizeValue::Exact(input)) }
     pub const fn const_font_family(input: StyleFontFamilyVec) -> Self { CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(input)) }
     pub const fn const_text_align(input: StyleTextAlign) -> Self { CssProperty::TextAlign(StyleTextAlignValue::Exact(input)) }
+    pub const fn const_direction(input: StyleDirection) -> Self { CssProperty::Direction(StyleDirectionValue::Exact(input)) }
+    pub const fn const_word_break(input: StyleWordBreak) -> Self { CssProperty::WordBreak(StyleWordBreakValue::Exact(input)) }
+    pub const fn const_overflow_wrap(input: StyleOverflowWrap) -> Self { CssProperty::OverflowWrap(StyleOverflowWrapValue::Exact(input)) }
+    pub const fn const_hyphens(input: StyleHyphens) -> Self { CssProperty::Hyphens(StyleHyphensValue::Exact(input)) }
     pub const fn const_letter_spacing(input: StyleLetterSpacing) -> Self { CssProperty::LetterSpacing(StyleLetterSpacingValue::Exact(input)) }
     pub const fn const_line_height(input: StyleLineHeight) -> Self { CssProperty::LineHeight(StyleLineHeightValue::Exact(input)) }
     pub const fn const_word_spacing(input: StyleWordSpacing) -> Self { CssProperty::WordSpacing(StyleWordSpacingValue::Exact(input)) }
@@ -1355,6 +1620,15 @@ impl CssProperty {
     pub const fn const_justify_content(input: LayoutJustifyContent) -> Self { CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(input)) }
     pub const fn const_align_items(input: LayoutAlignItems) -> Self { CssProperty::AlignItems(LayoutAlignItemsValue::Exact(input)) }
     pub const fn const_align_content(input: LayoutAlignContent) -> Self { CssProperty::AlignContent(LayoutAlignContentValue::Exact(input)) }
+    pub const fn const_grid_template_columns(input: GridTrackSizingVec) -> Self { CssProperty::GridTemplateColumns(GridTrackSizingVecValue::Exact(input)) }
+    pub const fn const_grid_template_rows(input: GridTrackSizingVec) -> Self { CssProperty::GridTemplateRows(GridTrackSizingVecValue::Exact(input)) }
+    pub const fn const_grid_auto_columns(input: GridTrackSizing) -> Self { CssProperty::GridAutoColumns(GridTrackSizingValue::Exact(input)) }
+    pub const fn const_grid_auto_rows(input: GridTrackSizing) -> Self { CssProperty::GridAutoRows(GridTrackSizingValue::Exact(input)) }
+    pub const fn const_grid_auto_flow(input: LayoutGridAutoFlow) -> Self { CssProperty::GridAutoFlow(LayoutGridAutoFlowValue::Exact(input)) }
+    pub const fn const_grid_column(input: GridPlacement) -> Self { CssProperty::GridColumn(GridPlacementValue::Exact(input)) }
+    pub const fn const_grid_row(input: GridPlacement) -> Self { CssProperty::GridRow(GridPlacementValue::Exact(input)) }
+    pub const fn const_row_gap(input: LayoutRowGap) -> Self { CssProperty::RowGap(LayoutRowGapValue::Exact(input)) }
+    pub const fn const_column_gap(input: LayoutColumnGap) -> Self { CssProperty::ColumnGap(LayoutColumnGapValue::Exact(input)) }
     pub const fn const_background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(input)) }
     pub const fn const_background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(StyleBackgroundPositionVecValue::Exact(input)) }
     pub const fn const_background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(StyleBackgroundSizeVecValue::Exact(input)) }
@@ -1394,6 +1668,18 @@ impl CssProperty {
     pub const fn const_transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(StyleTransformOriginValue::Exact(input)) }
     pub const fn const_perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(StylePerspectiveOriginValue::Exact(input)) }
     pub const fn const_backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(StyleBackfaceVisibilityValue::Exact(input)) }
+    pub const fn const_transition_property(input: StyleTransitionPropertyVec) -> Self { CssProperty::TransitionProperty(StyleTransitionPropertyVecValue::Exact(input)) }
+    pub const fn const_transition_duration(input: StyleTimeVec) -> Self { CssProperty::TransitionDuration(StyleTimeVecValue::Exact(input)) }
+    pub const fn const_transition_timing_function(input: StyleTimingFunctionVec) -> Self { CssProperty::TransitionTimingFunction(StyleTimingFunctionVecValue::Exact(input)) }
+    pub const fn const_transition_delay(input: StyleTimeVec) -> Self { CssProperty::TransitionDelay(StyleTimeVecValue::Exact(input)) }
+    pub const fn const_animation_name(input: StyleAnimationNameVec) -> Self { CssProperty::AnimationName(StyleAnimationNameVecValue::Exact(input)) }
+    pub const fn const_animation_duration(input: StyleTimeVec) -> Self { CssProperty::AnimationDuration(StyleTimeVecValue::Exact(input)) }
+    pub const fn const_animation_timing_function(input: StyleTimingFunctionVec) -> Self { CssProperty::AnimationTimingFunction(StyleTimingFunctionVecValue::Exact(input)) }
+    pub const fn const_animation_delay(input: StyleTimeVec) -> Self { CssProperty::AnimationDelay(StyleTimeVecValue::Exact(input)) }
+    pub const fn const_animation_iteration_count(input: StyleAnimationIterationCountVec) -> Self { CssProperty::AnimationIterationCount(StyleAnimationIterationCountVecValue::Exact(input)) }
+    pub const fn const_animation_direction(input: StyleAnimationDirectionVec) -> Self { CssProperty::AnimationDirection(StyleAnimationDirectionVecValue::Exact(input)) }
+    pub const fn const_animation_fill_mode(input: StyleAnimationFillModeVec) -> Self { CssProperty::AnimationFillMode(StyleAnimationFillModeVecValue::Exact(input)) }
+    pub const fn const_animation_play_state(input: StyleAnimationPlayStateVec) -> Self { CssProperty::AnimationPlayState(StyleAnimationPlayStateVecValue::Exact(input)) }
 
 }
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -1557,6 +1843,53 @@ impl SvgCubicCurve {
         self.start.x as f64
     }
 
+    /// Inverse of `get_x_at_t` for curves where x is monotonically increasing
+    /// (such as easing curves): returns the curve parameter `t` at which the curve reaches `x`
+    pub fn get_t_at_x(&self, x: f64) -> f64 {
+
+        const EPSILON: f64 = 0.000_001;
+
+        let c_x = 3.0 * (self.ctrl_1.x as f64 - self.start.x as f64);
+        let b_x = 3.0 * (self.ctrl_2.x as f64 - self.ctrl_1.x as f64) - c_x;
+        let a_x = self.end.x as f64 - self.start.x as f64 - c_x - b_x;
+
+        // Newton-Raphson converges quickly for most curves...
+        let mut t = x;
+        for _ in 0..8 {
+            let error = self.get_x_at_t(t) - x;
+            if error.abs() < EPSILON {
+                return t;
+            }
+            let derivative = (3.0 * a_x * t + 2.0 * b_x) * t + c_x;
+            if derivative.abs() < EPSILON {
+                break;
+            }
+            t -= error / derivative;
+        }
+
+        // ... but fails for flat tangents, in which case fall back to bisection
+        let (mut lo, mut hi) = (0.0, 1.0);
+        t = x.max(lo).min(hi);
+        while lo < hi {
+            let current_x = self.get_x_at_t(t);
+            if (current_x - x).abs() < EPSILON {
+                break;
+            }
+            if x > current_x {
+                lo = t;
+            } else {
+                hi = t;
+            }
+            let next = (hi - lo) * 0.5 + lo;
+            if next == t {
+                break;
+            }
+            t = next;
+        }
+
+        t
+    }
+
     pub fn get_y_at_t(&self, t: f64) -> f64 {
         let c_x = 3.0 * (self.ctrl_1.y as f64 - self.start.y as f64);
         let b_x = 3.0 * (self.ctrl_2.y as f64 - self.ctrl_1.y as f64) - c_x;
@@ -1806,8 +2139,13 @@ impl AnimationInterpolationFunction {
         }
     }
 
+    /// Returns the eased progress for the linear progress `t` (0.0 - 1.0)
+    ///
+    /// The curve maps time (x) to progress (y), so the curve parameter
+    /// for x = t has to be solved for first.
     pub fn evaluate(self, t: f64) -> f32 {
-        self.get_curve().get_y_at_t(t) as f32
+        let curve = self.get_curve();
+        curve.get_y_at_t(curve.get_t_at_x(t)) as f32
     }
 }
 
@@ -1833,6 +2171,10 @@ impl CssProperty {
             CssProperty::FontSize(v) => v.get_css_value_fmt(),
             CssProperty::FontFamily(v) => v.get_css_value_fmt(),
             CssProperty::TextAlign(v) => v.get_css_value_fmt(),
+            CssProperty::Direction(v) => v.get_css_value_fmt(),
+            CssProperty::WordBreak(v) => v.get_css_value_fmt(),
+            CssProperty::OverflowWrap(v) => v.get_css_value_fmt(),
+            CssProperty::Hyphens(v) => v.get_css_value_fmt(),
             CssProperty::LetterSpacing(v) => v.get_css_value_fmt(),
             CssProperty::LineHeight(v) => v.get_css_value_fmt(),
             CssProperty::WordSpacing(v) => v.get_css_value_fmt(),
@@ -1859,6 +2201,15 @@ impl CssProperty {
             CssProperty::JustifyContent(v) => v.get_css_value_fmt(),
             CssProperty::AlignItems(v) => v.get_css_value_fmt(),
             CssProperty::AlignContent(v) => v.get_css_value_fmt(),
+            CssProperty::GridTemplateColumns(v) => v.get_css_value_fmt(),
+            CssProperty::GridTemplateRows(v) => v.get_css_value_fmt(),
+            CssProperty::GridAutoColumns(v) => v.get_css_value_fmt(),
+            CssProperty::GridAutoRows(v) => v.get_css_value_fmt(),
+            CssProperty::GridAutoFlow(v) => v.get_css_value_fmt(),
+            CssProperty::GridColumn(v) => v.get_css_value_fmt(),
+            CssProperty::GridRow(v) => v.get_css_value_fmt(),
+            CssProperty::RowGap(v) => v.get_css_value_fmt(),
+            CssProperty::ColumnGap(v) => v.get_css_value_fmt(),
             CssProperty::BackgroundContent(v) => v.get_css_value_fmt(),
             CssProperty::BackgroundPosition(v) => v.get_css_value_fmt(),
             CssProperty::BackgroundSize(v) => v.get_css_value_fmt(),
@@ -1903,6 +2254,18 @@ impl CssProperty {
             CssProperty::Filter(v) => v.get_css_value_fmt(),
             CssProperty::BackdropFilter(v) => v.get_css_value_fmt(),
             CssProperty::TextShadow(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionProperty(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionDuration(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionTimingFunction(v) => v.get_css_value_fmt(),
+            CssProperty::TransitionDelay(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationName(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationDuration(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationTimingFunction(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationDelay(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationIterationCount(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationDirection(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationFillMode(v) => v.get_css_value_fmt(),
+            CssProperty::AnimationPlayState(v) => v.get_css_value_fmt(),
         }
     }
 
@@ -2021,6 +2384,16 @@ impl CssProperty {
                 let end = end.get_property().copied().unwrap_or_default();
                 CssProperty::FlexShrink(CssPropertyValue::Exact(start.interpolate(&end, t)))
             },
+            (CssProperty::RowGap(start), CssProperty::RowGap(end)) => {
+                let start = start.get_property().copied().unwrap_or_default();
+                let end = end.get_property().copied().unwrap_or_default();
+                CssProperty::RowGap(CssPropertyValue::Exact(start.interpolate(&end, t)))
+            },
+            (CssProperty::ColumnGap(start), CssProperty::ColumnGap(end)) => {
+                let start = start.get_property().copied().unwrap_or_default();
+                let end = end.get_property().copied().unwrap_or_default();
+                CssProperty::ColumnGap(CssPropertyValue::Exact(start.interpolate(&end, t)))
+            },
             (CssProperty::PaddingTop(start), CssProperty::PaddingTop(end)) => {
                 let start = start.get_property().copied().unwrap_or_default();
                 let end = end.get_property().copied().unwrap_or_default();
@@ -2136,6 +2509,24 @@ impl CssProperty {
                 let end = end.get_property().copied().unwrap_or_default();
                 CssProperty::PerspectiveOrigin(CssPropertyValue::Exact(start.interpolate(&end, t)))
             },
+            (CssProperty::BackgroundContent(start), CssProperty::BackgroundContent(end)) => {
+                // only plain background colors can be interpolated
+                let start_col = start.get_property().and_then(|s| match s.as_ref() {
+                    [StyleBackgroundContent::Color(c)] => Some(*c),
+                    _ => None,
+                });
+                let end_col = end.get_property().and_then(|s| match s.as_ref() {
+                    [StyleBackgroundContent::Color(c)] => Some(*c),
+                    _ => None,
+                });
+                match (start_col, end_col) {
+                    (Some(start_col), Some(end_col)) => {
+                        let v: StyleBackgroundContentVec = vec![StyleBackgroundContent::Color(start_col.interpolate(&end_col, t))].into();
+                        CssProperty::BackgroundContent(CssPropertyValue::Exact(v))
+                    },
+                    _ => if t > 0.5 { other.clone() } else { self.clone() },
+                }
+            },
             /*
             animate transform:
             CssProperty::Transform(CssPropertyValue<StyleTransformVec>),
@@ -2178,6 +2569,10 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::FontSize => CssProperty::FontSize(CssPropertyValue::$content_type),
         CssPropertyType::FontFamily => CssProperty::FontFamily(CssPropertyValue::$content_type),
         CssPropertyType::TextAlign => CssProperty::TextAlign(CssPropertyValue::$content_type),
+        CssPropertyType::Direction => CssProperty::Direction(CssPropertyValue::$content_type),
+        CssPropertyType::WordBreak => CssProperty::WordBreak(CssPropertyValue::$content_type),
+        CssPropertyType::OverflowWrap => CssProperty::OverflowWrap(CssPropertyValue::$content_type),
+        CssPropertyType::Hyphens => CssProperty::Hyphens(CssPropertyValue::$content_type),
         CssPropertyType::LetterSpacing => CssProperty::LetterSpacing(CssPropertyValue::$content_type),
         CssPropertyType::LineHeight => CssProperty::LineHeight(CssPropertyValue::$content_type),
         CssPropertyType::WordSpacing => CssProperty::WordSpacing(CssPropertyValue::$content_type),
@@ -2204,6 +2599,15 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::JustifyContent => CssProperty::JustifyContent(CssPropertyValue::$content_type),
         CssPropertyType::AlignItems => CssProperty::AlignItems(CssPropertyValue::$content_type),
         CssPropertyType::AlignContent => CssProperty::AlignContent(CssPropertyValue::$content_type),
+        CssPropertyType::GridTemplateColumns => CssProperty::GridTemplateColumns(CssPropertyValue::$content_type),
+        CssPropertyType::GridTemplateRows => CssProperty::GridTemplateRows(CssPropertyValue::$content_type),
+        CssPropertyType::GridAutoColumns => CssProperty::GridAutoColumns(CssPropertyValue::$content_type),
+        CssPropertyType::GridAutoRows => CssProperty::GridAutoRows(CssPropertyValue::$content_type),
+        CssPropertyType::GridAutoFlow => CssProperty::GridAutoFlow(CssPropertyValue::$content_type),
+        CssPropertyType::GridColumn => CssProperty::GridColumn(CssPropertyValue::$content_type),
+        CssPropertyType::GridRow => CssProperty::GridRow(CssPropertyValue::$content_type),
+        CssPropertyType::RowGap => CssProperty::RowGap(CssPropertyValue::$content_type),
+        CssPropertyType::ColumnGap => CssProperty::ColumnGap(CssPropertyValue::$content_type),
         CssPropertyType::OverflowX => CssProperty::OverflowX(CssPropertyValue::$content_type),
         CssPropertyType::OverflowY => CssProperty::OverflowY(CssPropertyValue::$content_type),
         CssPropertyType::PaddingTop => CssProperty::PaddingTop(CssPropertyValue::$content_type),
@@ -2248,6 +2652,18 @@ macro_rules! css_property_from_type {($prop_type:expr, $content_type:ident) => (
         CssPropertyType::Filter => CssProperty::Filter(CssPropertyValue::$content_type),
         CssPropertyType::BackdropFilter => CssProperty::BackdropFilter(CssPropertyValue::$content_type),
         CssPropertyType::TextShadow => CssProperty::TextShadow(CssPropertyValue::$content_type),
+        CssPropertyType::TransitionProperty => CssProperty::TransitionProperty(CssPropertyValue::$content_type),
+        CssPropertyType::TransitionDuration => CssProperty::TransitionDuration(CssPropertyValue::$content_type),
+        CssPropertyType::TransitionTimingFunction => CssProperty::TransitionTimingFunction(CssPropertyValue::$content_type),
+        CssPropertyType::TransitionDelay => CssProperty::TransitionDelay(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationName => CssProperty::AnimationName(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationDuration => CssProperty::AnimationDuration(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationTimingFunction => CssProperty::AnimationTimingFunction(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationDelay => CssProperty::AnimationDelay(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationIterationCount => CssProperty::AnimationIterationCount(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationDirection => CssProperty::AnimationDirection(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationFillMode => CssProperty::AnimationFillMode(CssPropertyValue::$content_type),
+        CssPropertyType::AnimationPlayState => CssProperty::AnimationPlayState(CssPropertyValue::$content_type),
     }
 })}
 
@@ -2260,6 +2676,10 @@ impl CssProperty {
             CssProperty::FontSize(_) => CssPropertyType::FontSize,
             CssProperty::FontFamily(_) => CssPropertyType::FontFamily,
             CssProperty::TextAlign(_) => CssPropertyType::TextAlign,
+            CssProperty::Direction(_) => CssPropertyType::Direction,
+            CssProperty::WordBreak(_) => CssPropertyType::WordBreak,
+            CssProperty::OverflowWrap(_) => CssPropertyType::OverflowWrap,
+            CssProperty::Hyphens(_) => CssPropertyType::Hyphens,
             CssProperty::LetterSpacing(_) => CssPropertyType::LetterSpacing,
             CssProperty::LineHeight(_) => CssPropertyType::LineHeight,
             CssProperty::WordSpacing(_) => CssPropertyType::WordSpacing,
@@ -2286,6 +2706,15 @@ impl CssProperty {
             CssProperty::JustifyContent(_) => CssPropertyType::JustifyContent,
             CssProperty::AlignItems(_) => CssPropertyType::AlignItems,
             CssProperty::AlignContent(_) => CssPropertyType::AlignContent,
+            CssProperty::GridTemplateColumns(_) => CssPropertyType::GridTemplateColumns,
+            CssProperty::GridTemplateRows(_) => CssPropertyType::GridTemplateRows,
+            CssProperty::GridAutoColumns(_) => CssPropertyType::GridAutoColumns,
+            CssProperty::GridAutoRows(_) => CssPropertyType::GridAutoRows,
+            CssProperty::GridAutoFlow(_) => CssPropertyType::GridAutoFlow,
+            CssProperty::GridColumn(_) => CssPropertyType::GridColumn,
+            CssProperty::GridRow(_) => CssPropertyType::GridRow,
+            CssProperty::RowGap(_) => CssPropertyType::RowGap,
+            CssProperty::ColumnGap(_) => CssPropertyType::ColumnGap,
             CssProperty::BackgroundContent(_) => CssPropertyType::BackgroundContent,
             CssProperty::BackgroundPosition(_) => CssPropertyType::BackgroundPosition,
             CssProperty::BackgroundSize(_) => CssPropertyType::BackgroundSize,
@@ -2330,6 +2759,18 @@ impl CssProperty {
             CssProperty::Filter(_) => CssPropertyType::Filter,
             CssProperty::BackdropFilter(_) => CssPropertyType::BackdropFilter,
             CssProperty::TextShadow(_) => CssPropertyType::TextShadow,
+            CssProperty::TransitionProperty(_) => CssPropertyType::TransitionProperty,
+            CssProperty::TransitionDuration(_) => CssPropertyType::TransitionDuration,
+            CssProperty::TransitionTimingFunction(_) => CssPropertyType::TransitionTimingFunction,
+            CssProperty::TransitionDelay(_) => CssPropertyType::TransitionDelay,
+            CssProperty::AnimationName(_) => CssPropertyType::AnimationName,
+            CssProperty::AnimationDuration(_) => CssPropertyType::AnimationDuration,
+            CssProperty::AnimationTimingFunction(_) => CssPropertyType::AnimationTimingFunction,
+            CssProperty::AnimationDelay(_) => CssPropertyType::AnimationDelay,
+            CssProperty::AnimationIterationCount(_) => CssPropertyType::AnimationIterationCount,
+            CssProperty::AnimationDirection(_) => CssPropertyType::AnimationDirection,
+            CssProperty::AnimationFillMode(_) => CssPropertyType::AnimationFillMode,
+            CssProperty::AnimationPlayState(_) => CssPropertyType::AnimationPlayState,
         }
     }
 
@@ -2344,6 +2785,10 @@ impl CssProperty {
     pub const fn font_size(input: StyleFontSize) -> Self { CssProperty::FontSize(CssPropertyValue::Exact(input)) }
     pub const fn font_family(input: StyleFontFamilyVec) -> Self { CssProperty::FontFamily(CssPropertyValue::Exact(input)) }
     pub const fn text_align(input: StyleTextAlign) -> Self { CssProperty::TextAlign(CssPropertyValue::Exact(input)) }
+    pub const fn direction(input: StyleDirection) -> Self { CssProperty::Direction(CssPropertyValue::Exact(input)) }
+    pub const fn word_break(input: StyleWordBreak) -> Self { CssProperty::WordBreak(CssPropertyValue::Exact(input)) }
+    pub const fn overflow_wrap(input: StyleOverflowWrap) -> Self { CssProperty::OverflowWrap(CssPropertyValue::Exact(input)) }
+    pub const fn hyphens(input: StyleHyphens) -> Self { CssProperty::Hyphens(CssPropertyValue::Exact(input)) }
     pub const fn letter_spacing(input: StyleLetterSpacing) -> Self { CssProperty::LetterSpacing(CssPropertyValue::Exact(input)) }
     pub const fn line_height(input: StyleLineHeight) -> Self { CssProperty::LineHeight(CssPropertyValue::Exact(input)) }
     pub const fn word_spacing(input: StyleWordSpacing) -> Self { CssProperty::WordSpacing(CssPropertyValue::Exact(input)) }
@@ -2370,6 +2815,15 @@ impl CssProperty {
     pub const fn justify_content(input: LayoutJustifyContent) -> Self { CssProperty::JustifyContent(CssPropertyValue::Exact(input)) }
     pub const fn align_items(input: LayoutAlignItems) -> Self { CssProperty::AlignItems(CssPropertyValue::Exact(input)) }
     pub const fn align_content(input: LayoutAlignContent) -> Self { CssProperty::AlignContent(CssPropertyValue::Exact(input)) }
+    pub const fn grid_template_columns(input: GridTrackSizingVec) -> Self { CssProperty::GridTemplateColumns(CssPropertyValue::Exact(input)) }
+    pub const fn grid_template_rows(input: GridTrackSizingVec) -> Self { CssProperty::GridTemplateRows(CssPropertyValue::Exact(input)) }
+    pub const fn grid_auto_columns(input: GridTrackSizing) -> Self { CssProperty::GridAutoColumns(CssPropertyValue::Exact(input)) }
+    pub const fn grid_auto_rows(input: GridTrackSizing) -> Self { CssProperty::GridAutoRows(CssPropertyValue::Exact(input)) }
+    pub const fn grid_auto_flow(input: LayoutGridAutoFlow) -> Self { CssProperty::GridAutoFlow(CssPropertyValue::Exact(input)) }
+    pub const fn grid_column(input: GridPlacement) -> Self { CssProperty::GridColumn(CssPropertyValue::Exact(input)) }
+    pub const fn grid_row(input: GridPlacement) -> Self { CssProperty::GridRow(CssPropertyValue::Exact(input)) }
+    pub const fn row_gap(input: LayoutRowGap) -> Self { CssProperty::RowGap(CssPropertyValue::Exact(input)) }
+    pub const fn column_gap(input: LayoutColumnGap) -> Self { CssProperty::ColumnGap(CssPropertyValue::Exact(input)) }
     pub const fn background_content(input: StyleBackgroundContentVec) -> Self { CssProperty::BackgroundContent(CssPropertyValue::Exact(input)) }
     pub const fn background_position(input: StyleBackgroundPositionVec) -> Self { CssProperty::BackgroundPosition(CssPropertyValue::Exact(input)) }
     pub const fn background_size(input: StyleBackgroundSizeVec) -> Self { CssProperty::BackgroundSize(CssPropertyValue::Exact(input)) }
@@ -2409,6 +2863,18 @@ impl CssProperty {
     pub const fn transform_origin(input: StyleTransformOrigin) -> Self { CssProperty::TransformOrigin(CssPropertyValue::Exact(input)) }
     pub const fn perspective_origin(input: StylePerspectiveOrigin) -> Self { CssProperty::PerspectiveOrigin(CssPropertyValue::Exact(input)) }
     pub const fn backface_visiblity(input: StyleBackfaceVisibility) -> Self { CssProperty::BackfaceVisibility(CssPropertyValue::Exact(input)) }
+    pub const fn transition_property(input: StyleTransitionPropertyVec) -> Self { CssProperty::TransitionProperty(CssPropertyValue::Exact(input)) }
+    pub const fn transition_duration(input: StyleTimeVec) -> Self { CssProperty::TransitionDuration(CssPropertyValue::Exact(input)) }
+    pub const fn transition_timing_function(input: StyleTimingFunctionVec) -> Self { CssProperty::TransitionTimingFunction(CssPropertyValue::Exact(input)) }
+    pub const fn transition_delay(input: StyleTimeVec) -> Self { CssProperty::TransitionDelay(CssPropertyValue::Exact(input)) }
+    pub const fn animation_name(input: StyleAnimationNameVec) -> Self { CssProperty::AnimationName(CssPropertyValue::Exact(input)) }
+    pub const fn animation_duration(input: StyleTimeVec) -> Self { CssProperty::AnimationDuration(CssPropertyValue::Exact(input)) }
+    pub const fn animation_timing_function(input: StyleTimingFunctionVec) -> Self { CssProperty::AnimationTimingFunction(CssPropertyValue::Exact(input)) }
+    pub const fn animation_delay(input: StyleTimeVec) -> Self { CssProperty::AnimationDelay(CssPropertyValue::Exact(input)) }
+    pub const fn animation_iteration_count(input: StyleAnimationIterationCountVec) -> Self { CssProperty::AnimationIterationCount(CssPropertyValue::Exact(input)) }
+    pub const fn animation_direction(input: StyleAnimationDirectionVec) -> Self { CssProperty::AnimationDirection(CssPropertyValue::Exact(input)) }
+    pub const fn animation_fill_mode(input: StyleAnimationFillModeVec) -> Self { CssProperty::AnimationFillMode(CssPropertyValue::Exact(input)) }
+    pub const fn animation_play_state(input: StyleAnimationPlayStateVec) -> Self { CssProperty::AnimationPlayState(CssPropertyValue::Exact(input)) }
 
     // functions that downcast to the concrete CSS type (style)
 
@@ -2420,6 +2886,10 @@ impl CssProperty {
     pub const fn as_font_family(&self) -> Option<&StyleFontFamilyVecValue> { match self { CssProperty::FontFamily(f) => Some(f), _ => None, } }
     pub const fn as_text_color(&self) -> Option<&StyleTextColorValue> { match self { CssProperty::TextColor(f) => Some(f), _ => None, } }
     pub const fn as_text_align(&self) -> Option<&StyleTextAlignValue> { match self { CssProperty::TextAlign(f) => Some(f), _ => None, } }
+    pub const fn as_text_direction(&self) -> Option<&StyleDirectionValue> { match self { CssProperty::Direction(f) => Some(f), _ => None, } }
+    pub const fn as_word_break(&self) -> Option<&StyleWordBreakValue> { match self { CssProperty::WordBreak(f) => Some(f), _ => None, } }
+    pub const fn as_overflow_wrap(&self) -> Option<&StyleOverflowWrapValue> { match self { CssProperty::OverflowWrap(f) => Some(f), _ => None, } }
+    pub const fn as_hyphens(&self) -> Option<&StyleHyphensValue> { match self { CssProperty::Hyphens(f) => Some(f), _ => None, } }
     pub const fn as_line_height(&self) -> Option<&StyleLineHeightValue> { match self { CssProperty::LineHeight(f) => Some(f), _ => None, } }
     pub const fn as_letter_spacing(&self) -> Option<&StyleLetterSpacingValue> { match self { CssProperty::LetterSpacing(f) => Some(f), _ => None, } }
     pub const fn as_word_spacing(&self) -> Option<&StyleWordSpacingValue> { match self { CssProperty::WordSpacing(f) => Some(f), _ => None, } }
@@ -2488,6 +2958,27 @@ impl CssProperty {
     pub const fn as_justify_content(&self) -> Option<&LayoutJustifyContentValue> { match self { CssProperty::JustifyContent(f) => Some(f), _ => None, } }
     pub const fn as_align_items(&self) -> Option<&LayoutAlignItemsValue> { match self { CssProperty::AlignItems(f) => Some(f), _ => None, } }
     pub const fn as_align_content(&self) -> Option<&LayoutAlignContentValue> { match self { CssProperty::AlignContent(f) => Some(f), _ => None, } }
+    pub const fn as_grid_template_columns(&self) -> Option<&GridTrackSizingVecValue> { match self { CssProperty::GridTemplateColumns(f) => Some(f), _ => None, } }
+    pub const fn as_grid_template_rows(&self) -> Option<&GridTrackSizingVecValue> { match self { CssProperty::GridTemplateRows(f) => Some(f), _ => None, } }
+    pub const fn as_grid_auto_columns(&self) -> Option<&GridTrackSizingValue> { match self { CssProperty::GridAutoColumns(f) => Some(f), _ => None, } }
+    pub const fn as_grid_auto_rows(&self) -> Option<&GridTrackSizingValue> { match self { CssProperty::GridAutoRows(f) => Some(f), _ => None, } }
+    pub const fn as_grid_auto_flow(&self) -> Option<&LayoutGridAutoFlowValue> { match self { CssProperty::GridAutoFlow(f) => Some(f), _ => None, } }
+    pub const fn as_grid_column(&self) -> Option<&GridPlacementValue> { match self { CssProperty::GridColumn(f) => Some(f), _ => None, } }
+    pub const fn as_grid_row(&self) -> Option<&GridPlacementValue> { match self { CssProperty::GridRow(f) => Some(f), _ => None, } }
+    pub const fn as_row_gap(&self) -> Option<&LayoutRowGapValue> { match self { CssProperty::RowGap(f) => Some(f), _ => None, } }
+    pub const fn as_column_gap(&self) -> Option<&LayoutColumnGapValue> { match self { CssProperty::ColumnGap(f) => Some(f), _ => None, } }
+    pub const fn as_transition_property(&self) -> Option<&StyleTransitionPropertyVecValue> { match self { CssProperty::TransitionProperty(f) => Some(f), _ => None, } }
+    pub const fn as_transition_duration(&self) -> Option<&StyleTimeVecValue> { match self { CssProperty::TransitionDuration(f) => Some(f), _ => None, } }
+    pub const fn as_transition_timing_function(&self) -> Option<&StyleTimingFunctionVecValue> { match self { CssProperty::TransitionTimingFunction(f) => Some(f), _ => None, } }
+    pub const fn as_transition_delay(&self) -> Option<&StyleTimeVecValue> { match self { CssProperty::TransitionDelay(f) => Some(f), _ => None, } }
+    pub const fn as_animation_name(&self) -> Option<&StyleAnimationNameVecValue> { match self { CssProperty::AnimationName(f) => Some(f), _ => None, } }
+    pub const fn as_animation_duration(&self) -> Option<&StyleTimeVecValue> { match self { CssProperty::AnimationDuration(f) => Some(f), _ => None, } }
+    pub const fn as_animation_timing_function(&self) -> Option<&StyleTimingFunctionVecValue> { match self { CssProperty::AnimationTimingFunction(f) => Some(f), _ => None, } }
+    pub const fn as_animation_delay(&self) -> Option<&StyleTimeVecValue> { match self { CssProperty::AnimationDelay(f) => Some(f), _ => None, } }
+    pub const fn as_animation_iteration_count(&self) -> Option<&StyleAnimationIterationCountVecValue> { match self { CssProperty::AnimationIterationCount(f) => Some(f), _ => None, } }
+    pub const fn as_animation_direction(&self) -> Option<&StyleAnimationDirectionVecValue> { match self { CssProperty::AnimationDirection(f) => Some(f), _ => None, } }
+    pub const fn as_animation_fill_mode(&self) -> Option<&StyleAnimationFillModeVecValue> { match self { CssProperty::AnimationFillMode(f) => Some(f), _ => None, } }
+    pub const fn as_animation_play_state(&self) -> Option<&StyleAnimationPlayStateVecValue> { match self { CssProperty::AnimationPlayState(f) => Some(f), _ => None, } }
 }
 
 macro_rules! impl_from_css_prop {
@@ -2504,6 +2995,10 @@ impl_from_css_prop!(StyleTextColor, CssProperty::TextColor);
 impl_from_css_prop!(StyleFontSize, CssProperty::FontSize);
 impl_from_css_prop!(StyleFontFamilyVec, CssProperty::FontFamily);
 impl_from_css_prop!(StyleTextAlign, CssProperty::TextAlign);
+impl_from_css_prop!(StyleDirection, CssProperty::Direction);
+impl_from_css_prop!(StyleWordBreak, CssProperty::WordBreak);
+impl_from_css_prop!(StyleOverflowWrap, CssProperty::OverflowWrap);
+impl_from_css_prop!(StyleHyphens, CssProperty::Hyphens);
 impl_from_css_prop!(StyleLetterSpacing, CssProperty::LetterSpacing);
 impl_from_css_prop!(StyleLineHeight, CssProperty::LineHeight);
 impl_from_css_prop!(StyleWordSpacing, CssProperty::WordSpacing);
@@ -2530,6 +3025,9 @@ impl_from_css_prop!(LayoutFlexShrink, CssProperty::FlexShrink);
 impl_from_css_prop!(LayoutJustifyContent, CssProperty::JustifyContent);
 impl_from_css_prop!(LayoutAlignItems, CssProperty::AlignItems);
 impl_from_css_prop!(LayoutAlignContent, CssProperty::AlignContent);
+impl_from_css_prop!(LayoutGridAutoFlow, CssProperty::GridAutoFlow);
+impl_from_css_prop!(LayoutRowGap, CssProperty::RowGap);
+impl_from_css_prop!(LayoutColumnGap, CssProperty::ColumnGap);
 impl_from_css_prop!(StyleBackgroundContentVec, CssProperty::BackgroundContent);
 impl_from_css_prop!(StyleBackgroundPositionVec, CssProperty::BackgroundPosition);
 impl_from_css_prop!(StyleBackgroundSizeVec, CssProperty::BackgroundSize);
@@ -2565,6 +3063,12 @@ impl_from_css_prop!(StyleTransformOrigin, CssProperty::TransformOrigin);
 impl_from_css_prop!(StylePerspectiveOrigin, CssProperty::PerspectiveOrigin);
 impl_from_css_prop!(StyleBackfaceVisibility, CssProperty::BackfaceVisibility);
 impl_from_css_prop!(StyleMixBlendMode, CssProperty::MixBlendMode);
+impl_from_css_prop!(StyleTransitionPropertyVec, CssProperty::TransitionProperty);
+impl_from_css_prop!(StyleAnimationNameVec, CssProperty::AnimationName);
+impl_from_css_prop!(StyleAnimationIterationCountVec, CssProperty::AnimationIterationCount);
+impl_from_css_prop!(StyleAnimationDirectionVec, CssProperty::AnimationDirection);
+impl_from_css_prop!(StyleAnimationFillModeVec, CssProperty::AnimationFillMode);
+impl_from_css_prop!(StyleAnimationPlayStateVec, CssProperty::AnimationPlayState);
 
 /// Multiplier for floating point accuracy. Elements such as px or %
 /// are only accurate until a certain number of decimal points, therefore
@@ -4016,6 +4520,7 @@ pub enum LayoutDisplay {
     Flex,
     Block,
     InlineBlock,
+    Grid,
 }
 
 impl Default for LayoutDisplay {
@@ -4146,6 +4651,144 @@ impl Default for LayoutAlignContent {
     }
 }
 
+/// Represents one end of a grid track size, i.e. the `min` or `max` of a `minmax(min, max)`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum GridTrackBreadth {
+    /// Fixed size in `px`, `em`, `pt` or `%` (relative to the size of the grid container)
+    Fixed(PixelValue),
+    /// Fraction of the remaining space (`1fr`)
+    Fraction(FloatValue),
+    /// Size of the largest item in the track
+    Auto,
+    MinContent,
+    MaxContent,
+}
+
+impl GridTrackBreadth {
+    pub fn is_fraction(&self) -> bool {
+        match self { GridTrackBreadth::Fraction(_) => true, _ => false }
+    }
+    /// Returns whether the size of the track depends on the size of its items
+    pub fn is_intrinsic(&self) -> bool {
+        match self { GridTrackBreadth::Auto | GridTrackBreadth::MinContent | GridTrackBreadth::MaxContent => true, _ => false }
+    }
+}
+
+/// Represents a single track size in a `grid-template-columns`, `grid-template-rows`,
+/// `grid-auto-columns` or `grid-auto-rows` attribute.
+///
+/// Every track is stored as a `minmax(min, max)` pair: `100px` is stored as
+/// `minmax(100px, 100px)`, `1fr` as `minmax(auto, 1fr)` and `auto` as `minmax(auto, auto)`.
+/// `repeat()` is expanded while parsing.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GridTrackSizing {
+    pub min: GridTrackBreadth,
+    pub max: GridTrackBreadth,
+}
+
+impl Default for GridTrackSizing {
+    fn default() -> Self {
+        GridTrackSizing::auto()
+    }
+}
+
+impl GridTrackSizing {
+    pub const fn auto() -> Self { GridTrackSizing { min: GridTrackBreadth::Auto, max: GridTrackBreadth::Auto } }
+    pub const fn fixed(value: PixelValue) -> Self { GridTrackSizing { min: GridTrackBreadth::Fixed(value), max: GridTrackBreadth::Fixed(value) } }
+    pub const fn fraction(value: FloatValue) -> Self { GridTrackSizing { min: GridTrackBreadth::Auto, max: GridTrackBreadth::Fraction(value) } }
+    pub const fn minmax(min: GridTrackBreadth, max: GridTrackBreadth) -> Self { GridTrackSizing { min, max } }
+}
+
+impl_vec!(GridTrackSizing, GridTrackSizingVec, GridTrackSizingVecDestructor);
+impl_vec_debug!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_partialord!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_ord!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_clone!(GridTrackSizing, GridTrackSizingVec, GridTrackSizingVecDestructor);
+impl_vec_partialeq!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_eq!(GridTrackSizing, GridTrackSizingVec);
+impl_vec_hash!(GridTrackSizing, GridTrackSizingVec);
+
+/// Represents a `grid-auto-flow` attribute - default: `Row`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum LayoutGridAutoFlow {
+    /// Auto-placed items fill each row in turn, adding new rows as necessary
+    Row,
+    /// Auto-placed items fill each column in turn, adding new columns as necessary
+    Column,
+    /// Same as `Row`, but fills holes earlier in the grid
+    RowDense,
+    /// Same as `Column`, but fills holes earlier in the grid
+    ColumnDense,
+}
+
+impl Default for LayoutGridAutoFlow {
+    fn default() -> Self {
+        LayoutGridAutoFlow::Row
+    }
+}
+
+impl LayoutGridAutoFlow {
+    pub fn is_dense(&self) -> bool {
+        match self {
+            LayoutGridAutoFlow::RowDense | LayoutGridAutoFlow::ColumnDense => true,
+            _ => false,
+        }
+    }
+    pub fn get_axis(&self) -> LayoutAxis {
+        match self {
+            LayoutGridAutoFlow::Row | LayoutGridAutoFlow::RowDense => LayoutAxis::Horizontal,
+            LayoutGridAutoFlow::Column | LayoutGridAutoFlow::ColumnDense => LayoutAxis::Vertical,
+        }
+    }
+}
+
+/// Start or end line of a `grid-column` or `grid-row` attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum GridLine {
+    Auto,
+    /// 1-based line number, negative numbers count from the end of the explicit grid
+    Line(i32),
+    /// `span 2`
+    Span(u32),
+}
+
+impl Default for GridLine {
+    fn default() -> Self {
+        GridLine::Auto
+    }
+}
+
+/// Represents a `grid-column` or `grid-row` attribute, i.e. `1 / 3` or `2 / span 2`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct GridPlacement {
+    pub start: GridLine,
+    pub end: GridLine,
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        GridPlacement { start: GridLine::Auto, end: GridLine::Auto }
+    }
+}
+
+/// Represents a `row-gap` attribute
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutRowGap { pub inner: PixelValue }
+
+/// Represents a `column-gap` attribute
+#[derive(Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct LayoutColumnGap { pub inner: PixelValue }
+
+impl_pixel_value!(LayoutRowGap);
+impl_pixel_value!(LayoutColumnGap);
+
 /// Represents a `overflow-x` or `overflow-y` property, see
 /// [`TextOverflowBehaviour`](./struct.TextOverflowBehaviour.html) - default: `Auto`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -4194,13 +4837,18 @@ impl LayoutOverflow {
     }
 }
 
-/// Horizontal text alignment enum (left, center, right) - default: `Center`
+/// Horizontal text alignment enum (left, center, right, start, end) - default: `Left`
+///
+/// `Start` and `End` are resolved against the `direction` of the node,
+/// i.e. `Start` is `Left` in `direction: ltr` and `Right` in `direction: rtl`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub enum StyleTextAlign {
     Left,
     Center,
     Right,
+    Start,
+    End,
 }
 
 impl Default for StyleTextAlign {
@@ -4209,6 +4857,97 @@ impl Default for StyleTextAlign {
     }
 }
 
+impl StyleTextAlign {
+    /// Resolves `Start` / `End` to a physical `Left` / `Right` alignment
+    pub fn resolve(&self, direction: StyleDirection) -> Self {
+        match (self, direction) {
+            (StyleTextAlign::Start, StyleDirection::Ltr) |
+            (StyleTextAlign::End, StyleDirection::Rtl) => StyleTextAlign::Left,
+            (StyleTextAlign::Start, StyleDirection::Rtl) |
+            (StyleTextAlign::End, StyleDirection::Ltr) => StyleTextAlign::Right,
+            (other, _) => *other,
+        }
+    }
+}
+
+/// Base direction of the text in a node (`direction: ltr | rtl`) - default: `Ltr`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleDirection {
+    Ltr,
+    Rtl,
+}
+
+impl Default for StyleDirection {
+    fn default() -> Self {
+        StyleDirection::Ltr
+    }
+}
+
+impl StyleDirection {
+    pub fn is_rtl(&self) -> bool {
+        *self == StyleDirection::Rtl
+    }
+}
+
+/// Where lines may break inside of words (`word-break: normal | break-all | keep-all`) - default: `Normal`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleWordBreak {
+    /// Default line breaking rules (Unicode line breaking algorithm)
+    Normal,
+    /// Lines may break between any two letters (except in CJK text, where this is already the default)
+    BreakAll,
+    /// Lines may not break between CJK characters, only at spaces and punctuation
+    KeepAll,
+}
+
+impl Default for StyleWordBreak {
+    fn default() -> Self {
+        StyleWordBreak::Normal
+    }
+}
+
+/// Whether a word that is too long for the line may be broken at an arbitrary
+/// position (`overflow-wrap: normal | anywhere | break-word`) - default: `Normal`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleOverflowWrap {
+    /// Long words overflow the line
+    Normal,
+    /// Long words are broken at an arbitrary position if there is no other break opportunity
+    Anywhere,
+    /// Same as `Anywhere`, but the extra break opportunities are not considered
+    /// when calculating the min-content width of the text
+    BreakWord,
+}
+
+impl Default for StyleOverflowWrap {
+    fn default() -> Self {
+        StyleOverflowWrap::Normal
+    }
+}
+
+/// Whether words are hyphenated when the line breaks inside of them
+/// (`hyphens: none | manual | auto`) - default: `Manual`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleHyphens {
+    /// Words are never hyphenated, not even at soft hyphens (`U+00AD`)
+    None,
+    /// Words are only hyphenated at soft hyphens (`U+00AD`)
+    Manual,
+    /// Words are hyphenated at soft hyphens and at the hyphenation points
+    /// of the registered hyphenation dictionaries
+    Auto,
+}
+
+impl Default for StyleHyphens {
+    fn default() -> Self {
+        StyleHyphens::Manual
+    }
+}
+
 /// Vertical text alignment enum (top, center, bottom) - default: `Center`
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -4413,6 +5152,287 @@ pub struct StyleTransformSkew2D {
     pub y: PercentageValue,
 }
 
+/// Represents a `<time>` value in a `transition-duration`, `transition-delay`,
+/// `animation-duration` or `animation-delay` attribute, stored in milliseconds
+/// (negative delays start the animation part-way through)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleTime {
+    pub ms: i32,
+}
+
+impl StyleTime {
+    pub const fn zero() -> Self { Self { ms: 0 } }
+    pub const fn ms(ms: i32) -> Self { Self { ms } }
+    pub const fn is_zero(&self) -> bool { self.ms == 0 }
+}
+
+impl_vec!(StyleTime, StyleTimeVec, StyleTimeVecDestructor);
+impl_vec_debug!(StyleTime, StyleTimeVec);
+impl_vec_partialord!(StyleTime, StyleTimeVec);
+impl_vec_ord!(StyleTime, StyleTimeVec);
+impl_vec_clone!(StyleTime, StyleTimeVec, StyleTimeVecDestructor);
+impl_vec_partialeq!(StyleTime, StyleTimeVec);
+impl_vec_eq!(StyleTime, StyleTimeVec);
+impl_vec_hash!(StyleTime, StyleTimeVec);
+
+/// Control points of a `cubic-bezier(x1, y1, x2, y2)` easing function
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleCubicBezier {
+    pub x1: FloatValue,
+    pub y1: FloatValue,
+    pub x2: FloatValue,
+    pub y2: FloatValue,
+}
+
+/// Position of the jumps in a `steps(n, position)` easing function
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleStepPosition {
+    /// `jump-start` or `start`: the first jump happens when the animation begins
+    JumpStart,
+    /// `jump-end` or `end`: the last jump happens when the animation ends
+    JumpEnd,
+    /// `jump-none`: no jump at either end, holds the start and end value for 1/n each
+    JumpNone,
+    /// `jump-both`: jumps at both the start and the end
+    JumpBoth,
+}
+
+/// Represents a `steps(n, position)` easing function
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct StyleSteps {
+    pub count: u32,
+    pub position: StyleStepPosition,
+}
+
+/// Represents an `<easing-function>` in a `transition-timing-function`
+/// or `animation-timing-function` attribute - default: `Ease`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleTimingFunction {
+    Ease,
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(StyleCubicBezier),
+    Steps(StyleSteps),
+}
+
+impl Default for StyleTimingFunction {
+    fn default() -> Self {
+        StyleTimingFunction::Ease
+    }
+}
+
+impl StyleTimingFunction {
+    /// Maps the linear progress `t` (0.0 - 1.0) of an animation to the eased progress.
+    ///
+    /// Note that the result can be outside of 0.0 - 1.0 for cubic-bezier curves
+    /// with control points that over- or undershoot.
+    pub fn evaluate(&self, t: f32) -> f32 {
+        let t = t.max(0.0).min(1.0);
+        match self {
+            StyleTimingFunction::Ease => AnimationInterpolationFunction::Ease.evaluate(t as f64),
+            StyleTimingFunction::Linear => t,
+            StyleTimingFunction::EaseIn => AnimationInterpolationFunction::EaseIn.evaluate(t as f64),
+            StyleTimingFunction::EaseOut => AnimationInterpolationFunction::EaseOut.evaluate(t as f64),
+            StyleTimingFunction::EaseInOut => AnimationInterpolationFunction::EaseInOut.evaluate(t as f64),
+            StyleTimingFunction::CubicBezier(c) => AnimationInterpolationFunction::CubicBezier(SvgCubicCurve {
+                start: SvgPoint { x: 0.0, y: 0.0 },
+                ctrl_1: SvgPoint { x: c.x1.get(), y: c.y1.get() },
+                ctrl_2: SvgPoint { x: c.x2.get(), y: c.y2.get() },
+                end: SvgPoint { x: 1.0, y: 1.0 },
+            }).evaluate(t as f64),
+            StyleTimingFunction::Steps(s) => {
+                // see https://drafts.csswg.org/css-easing/#step-easing-algo
+                let steps = s.count.max(1) as f32;
+                let mut current_step = (t * steps).floor();
+                if s.position == StyleStepPosition::JumpStart || s.position == StyleStepPosition::JumpBoth {
+                    current_step += 1.0;
+                }
+                let jumps = match s.position {
+                    StyleStepPosition::JumpStart | StyleStepPosition::JumpEnd => steps,
+                    StyleStepPosition::JumpNone => (steps - 1.0).max(1.0),
+                    StyleStepPosition::JumpBoth => steps + 1.0,
+                };
+                (current_step.min(jumps) / jumps).max(0.0).min(1.0)
+            }
+        }
+    }
+}
+
+impl_vec!(StyleTimingFunction, StyleTimingFunctionVec, StyleTimingFunctionVecDestructor);
+impl_vec_debug!(StyleTimingFunction, StyleTimingFunctionVec);
+impl_vec_partialord!(StyleTimingFunction, StyleTimingFunctionVec);
+impl_vec_ord!(StyleTimingFunction, StyleTimingFunctionVec);
+impl_vec_clone!(StyleTimingFunction, StyleTimingFunctionVec, StyleTimingFunctionVecDestructor);
+impl_vec_partialeq!(StyleTimingFunction, StyleTimingFunctionVec);
+impl_vec_eq!(StyleTimingFunction, StyleTimingFunctionVec);
+impl_vec_hash!(StyleTimingFunction, StyleTimingFunctionVec);
+
+/// Single entry in a `transition-property` attribute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleTransitionProperty {
+    /// `all`: every animatable property transitions
+    All,
+    /// A single property, i.e. `opacity`
+    Property(CssPropertyType),
+    /// A shorthand that transitions all of its longhands, i.e. `margin`
+    Shorthand(CombinedCssPropertyType),
+}
+
+impl StyleTransitionProperty {
+    /// Returns whether a change of `prop` should be animated by this entry
+    pub fn matches(&self, prop: &CssPropertyType) -> bool {
+        match self {
+            StyleTransitionProperty::All => true,
+            StyleTransitionProperty::Property(p) => p == prop,
+            StyleTransitionProperty::Shorthand(s) => s.get_longhand_types().contains(prop),
+        }
+    }
+}
+
+impl_vec!(StyleTransitionProperty, StyleTransitionPropertyVec, StyleTransitionPropertyVecDestructor);
+impl_vec_debug!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_partialord!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_ord!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_clone!(StyleTransitionProperty, StyleTransitionPropertyVec, StyleTransitionPropertyVecDestructor);
+impl_vec_partialeq!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_eq!(StyleTransitionProperty, StyleTransitionPropertyVec);
+impl_vec_hash!(StyleTransitionProperty, StyleTransitionPropertyVec);
+
+/// Single entry in an `animation-name` attribute, refers to a `@keyframes` block
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleAnimationName {
+    /// `none`: keeps the position in the list, but doesn't run an animation
+    None,
+    Name(AzString),
+}
+
+impl_vec!(StyleAnimationName, StyleAnimationNameVec, StyleAnimationNameVecDestructor);
+impl_vec_debug!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_partialord!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_ord!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_clone!(StyleAnimationName, StyleAnimationNameVec, StyleAnimationNameVecDestructor);
+impl_vec_partialeq!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_eq!(StyleAnimationName, StyleAnimationNameVec);
+impl_vec_hash!(StyleAnimationName, StyleAnimationNameVec);
+
+/// Single entry in an `animation-iteration-count` attribute - default: `Count(1)`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, u8)]
+pub enum StyleAnimationIterationCount {
+    Infinite,
+    /// Number of iterations, may be fractional (`0.5` stops halfway)
+    Count(FloatValue),
+}
+
+impl Default for StyleAnimationIterationCount {
+    fn default() -> Self {
+        StyleAnimationIterationCount::Count(FloatValue::const_new(1))
+    }
+}
+
+impl_vec!(StyleAnimationIterationCount, StyleAnimationIterationCountVec, StyleAnimationIterationCountVecDestructor);
+impl_vec_debug!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_partialord!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_ord!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_clone!(StyleAnimationIterationCount, StyleAnimationIterationCountVec, StyleAnimationIterationCountVecDestructor);
+impl_vec_partialeq!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_eq!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+impl_vec_hash!(StyleAnimationIterationCount, StyleAnimationIterationCountVec);
+
+/// Single entry in an `animation-direction` attribute - default: `Normal`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleAnimationDirection {
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+impl Default for StyleAnimationDirection {
+    fn default() -> Self {
+        StyleAnimationDirection::Normal
+    }
+}
+
+impl_vec!(StyleAnimationDirection, StyleAnimationDirectionVec, StyleAnimationDirectionVecDestructor);
+impl_vec_debug!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_partialord!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_ord!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_clone!(StyleAnimationDirection, StyleAnimationDirectionVec, StyleAnimationDirectionVecDestructor);
+impl_vec_partialeq!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_eq!(StyleAnimationDirection, StyleAnimationDirectionVec);
+impl_vec_hash!(StyleAnimationDirection, StyleAnimationDirectionVec);
+
+/// Single entry in an `animation-fill-mode` attribute - default: `None`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleAnimationFillMode {
+    /// The animation has no effect before it starts or after it ends
+    None,
+    /// The last keyframe stays applied after the animation ends
+    Forwards,
+    /// The first keyframe is applied during the `animation-delay`
+    Backwards,
+    Both,
+}
+
+impl Default for StyleAnimationFillMode {
+    fn default() -> Self {
+        StyleAnimationFillMode::None
+    }
+}
+
+impl StyleAnimationFillMode {
+    pub fn fills_forwards(&self) -> bool {
+        match self { StyleAnimationFillMode::Forwards | StyleAnimationFillMode::Both => true, _ => false }
+    }
+    pub fn fills_backwards(&self) -> bool {
+        match self { StyleAnimationFillMode::Backwards | StyleAnimationFillMode::Both => true, _ => false }
+    }
+}
+
+impl_vec!(StyleAnimationFillMode, StyleAnimationFillModeVec, StyleAnimationFillModeVecDestructor);
+impl_vec_debug!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_partialord!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_ord!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_clone!(StyleAnimationFillMode, StyleAnimationFillModeVec, StyleAnimationFillModeVecDestructor);
+impl_vec_partialeq!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_eq!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+impl_vec_hash!(StyleAnimationFillMode, StyleAnimationFillModeVec);
+
+/// Single entry in an `animation-play-state` attribute - default: `Running`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum StyleAnimationPlayState {
+    Running,
+    Paused,
+}
+
+impl Default for StyleAnimationPlayState {
+    fn default() -> Self {
+        StyleAnimationPlayState::Running
+    }
+}
+
+impl_vec!(StyleAnimationPlayState, StyleAnimationPlayStateVec, StyleAnimationPlayStateVecDestructor);
+impl_vec_debug!(StyleAnimationPlayState, StyleAnimationPlayStateVec);
+impl_vec_partialord!(StyleAnimationPlayState, StyleAnimationPlayStateVec);
+impl_vec_ord!(StyleAnimationPlayState, StyleAnimationPlayStateVec);
+impl_vec_clone!(StyleAnimationPlayState, StyleAnimationPlayStateVec, StyleAnimationPlayStateVecDestructor);
+impl_vec_partialeq!(StyleAnimationPlayState, StyleAnimationPlayStateVec);
+impl_vec_eq!(StyleAnimationPlayState, StyleAnimationPlayStateVec);
+impl_vec_hash!(StyleAnimationPlayState, StyleAnimationPlayStateVec);
+
 pub type StyleBackgroundContentVecValue = CssPropertyValue<StyleBackgroundContentVec>;
 pub type StyleBackgroundPositionVecValue = CssPropertyValue<StyleBackgroundPositionVec>;
 pub type StyleBackgroundSizeVecValue = CssPropertyValue<StyleBackgroundSizeVec>;
@@ -4421,6 +5441,10 @@ pub type StyleFontSizeValue = CssPropertyValue<StyleFontSize>;
 pub type StyleFontFamilyVecValue = CssPropertyValue<StyleFontFamilyVec>;
 pub type StyleTextColorValue = CssPropertyValue<StyleTextColor>;
 pub type StyleTextAlignValue = CssPropertyValue<StyleTextAlign>;
+pub type StyleDirectionValue = CssPropertyValue<StyleDirection>;
+pub type StyleWordBreakValue = CssPropertyValue<StyleWordBreak>;
+pub type StyleOverflowWrapValue = CssPropertyValue<StyleOverflowWrap>;
+pub type StyleHyphensValue = CssPropertyValue<StyleHyphens>;
 pub type StyleLineHeightValue = CssPropertyValue<StyleLineHeight>;
 pub type StyleLetterSpacingValue = CssPropertyValue<StyleLetterSpacing>;
 pub type StyleWordSpacingValue = CssPropertyValue<StyleWordSpacing>;
@@ -4511,6 +5535,34 @@ pub type LayoutAlignItemsValue = CssPropertyValue<LayoutAlignItems>;
 impl_option!(LayoutAlignItemsValue, OptionLayoutAlignItemsValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
 pub type LayoutAlignContentValue = CssPropertyValue<LayoutAlignContent>;
 impl_option!(LayoutAlignContentValue, OptionLayoutAlignContentValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type GridTrackSizingVecValue = CssPropertyValue<GridTrackSizingVec>;
+impl_option!(GridTrackSizingVecValue, OptionGridTrackSizingVecValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type GridTrackSizingValue = CssPropertyValue<GridTrackSizing>;
+impl_option!(GridTrackSizingValue, OptionGridTrackSizingValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type LayoutGridAutoFlowValue = CssPropertyValue<LayoutGridAutoFlow>;
+impl_option!(LayoutGridAutoFlowValue, OptionLayoutGridAutoFlowValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type GridPlacementValue = CssPropertyValue<GridPlacement>;
+impl_option!(GridPlacementValue, OptionGridPlacementValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type LayoutRowGapValue = CssPropertyValue<LayoutRowGap>;
+impl_option!(LayoutRowGapValue, OptionLayoutRowGapValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type LayoutColumnGapValue = CssPropertyValue<LayoutColumnGap>;
+impl_option!(LayoutColumnGapValue, OptionLayoutColumnGapValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type StyleTransitionPropertyVecValue = CssPropertyValue<StyleTransitionPropertyVec>;
+impl_option!(StyleTransitionPropertyVecValue, OptionStyleTransitionPropertyVecValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type StyleTimeVecValue = CssPropertyValue<StyleTimeVec>;
+impl_option!(StyleTimeVecValue, OptionStyleTimeVecValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type StyleTimingFunctionVecValue = CssPropertyValue<StyleTimingFunctionVec>;
+impl_option!(StyleTimingFunctionVecValue, OptionStyleTimingFunctionVecValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type StyleAnimationNameVecValue = CssPropertyValue<StyleAnimationNameVec>;
+impl_option!(StyleAnimationNameVecValue, OptionStyleAnimationNameVecValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type StyleAnimationIterationCountVecValue = CssPropertyValue<StyleAnimationIterationCountVec>;
+impl_option!(StyleAnimationIterationCountVecValue, OptionStyleAnimationIterationCountVecValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type StyleAnimationDirectionVecValue = CssPropertyValue<StyleAnimationDirectionVec>;
+impl_option!(StyleAnimationDirectionVecValue, OptionStyleAnimationDirectionVecValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type StyleAnimationFillModeVecValue = CssPropertyValue<StyleAnimationFillModeVec>;
+impl_option!(StyleAnimationFillModeVecValue, OptionStyleAnimationFillModeVecValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+pub type StyleAnimationPlayStateVecValue = CssPropertyValue<StyleAnimationPlayStateVec>;
+impl_option!(StyleAnimationPlayStateVecValue, OptionStyleAnimationPlayStateVecValue, copy = false, [Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
 
 /// Holds info necessary for layouting / styling scrollbars (-webkit-scrollbar)
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
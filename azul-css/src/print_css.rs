@@ -64,6 +64,47 @@ impl PrintAsCssValue for StyleTextAlign {
             StyleTextAlign::Left => "left",
             StyleTextAlign::Center => "center",
             StyleTextAlign::Right => "right",
+            StyleTextAlign::Start => "start",
+            StyleTextAlign::End => "end",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleDirection {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleDirection::Ltr => "ltr",
+            StyleDirection::Rtl => "rtl",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleWordBreak {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleWordBreak::Normal => "normal",
+            StyleWordBreak::BreakAll => "break-all",
+            StyleWordBreak::KeepAll => "keep-all",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleOverflowWrap {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleOverflowWrap::Normal => "normal",
+            StyleOverflowWrap::Anywhere => "anywhere",
+            StyleOverflowWrap::BreakWord => "break-word",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleHyphens {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleHyphens::None => "none",
+            StyleHyphens::Manual => "manual",
+            StyleHyphens::Auto => "auto",
         })
     }
 }
@@ -136,6 +177,7 @@ impl PrintAsCssValue for LayoutDisplay {
             LayoutDisplay::Flex => "flex",
             LayoutDisplay::Block => "block",
             LayoutDisplay::InlineBlock => "inline-block",
+            LayoutDisplay::Grid => "grid",
         })
     }
 }
@@ -298,6 +340,214 @@ impl PrintAsCssValue for LayoutAlignContent {
     }
 }
 
+impl PrintAsCssValue for GridTrackBreadth {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            GridTrackBreadth::Fixed(p) => format!("{}", p),
+            GridTrackBreadth::Fraction(f) => format!("{}fr", f),
+            GridTrackBreadth::Auto => String::from("auto"),
+            GridTrackBreadth::MinContent => String::from("min-content"),
+            GridTrackBreadth::MaxContent => String::from("max-content"),
+        }
+    }
+}
+
+impl PrintAsCssValue for GridTrackSizing {
+    fn print_as_css_value(&self) -> String {
+        match (self.min, self.max) {
+            (min, max) if min == max => min.print_as_css_value(),
+            (GridTrackBreadth::Auto, GridTrackBreadth::Fraction(f)) => format!("{}fr", f),
+            (min, max) => format!("minmax({}, {})", min.print_as_css_value(), max.print_as_css_value()),
+        }
+    }
+}
+
+impl PrintAsCssValue for GridTrackSizingVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(" ")
+    }
+}
+
+impl PrintAsCssValue for LayoutGridAutoFlow {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            LayoutGridAutoFlow::Row => "row",
+            LayoutGridAutoFlow::Column => "column",
+            LayoutGridAutoFlow::RowDense => "row dense",
+            LayoutGridAutoFlow::ColumnDense => "column dense",
+        })
+    }
+}
+
+impl PrintAsCssValue for GridLine {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            GridLine::Auto => String::from("auto"),
+            GridLine::Line(l) => format!("{}", l),
+            GridLine::Span(s) => format!("span {}", s),
+        }
+    }
+}
+
+impl PrintAsCssValue for GridPlacement {
+    fn print_as_css_value(&self) -> String {
+        format!("{} / {}", self.start.print_as_css_value(), self.end.print_as_css_value())
+    }
+}
+
+impl PrintAsCssValue for LayoutRowGap {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self.inner)
+    }
+}
+
+impl PrintAsCssValue for LayoutColumnGap {
+    fn print_as_css_value(&self) -> String {
+        format!("{}", self.inner)
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionProperty {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            StyleTransitionProperty::All => String::from("all"),
+            StyleTransitionProperty::Property(p) => String::from(p.to_str()),
+            StyleTransitionProperty::Shorthand(s) => format!("{}", s),
+        }
+    }
+}
+
+impl PrintAsCssValue for StyleTransitionPropertyVec {
+    fn print_as_css_value(&self) -> String {
+        if self.as_ref().is_empty() {
+            return String::from("none");
+        }
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleTime {
+    fn print_as_css_value(&self) -> String {
+        format!("{}ms", self.ms)
+    }
+}
+
+impl PrintAsCssValue for StyleTimeVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleStepPosition {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleStepPosition::JumpStart => "jump-start",
+            StyleStepPosition::JumpEnd => "jump-end",
+            StyleStepPosition::JumpNone => "jump-none",
+            StyleStepPosition::JumpBoth => "jump-both",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleTimingFunction {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            StyleTimingFunction::Ease => String::from("ease"),
+            StyleTimingFunction::Linear => String::from("linear"),
+            StyleTimingFunction::EaseIn => String::from("ease-in"),
+            StyleTimingFunction::EaseOut => String::from("ease-out"),
+            StyleTimingFunction::EaseInOut => String::from("ease-in-out"),
+            StyleTimingFunction::CubicBezier(c) => format!("cubic-bezier({}, {}, {}, {})", c.x1, c.y1, c.x2, c.y2),
+            StyleTimingFunction::Steps(s) => format!("steps({}, {})", s.count, s.position.print_as_css_value()),
+        }
+    }
+}
+
+impl PrintAsCssValue for StyleTimingFunctionVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationName {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            StyleAnimationName::None => String::from("none"),
+            StyleAnimationName::Name(n) => String::from(n.as_str()),
+        }
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationNameVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationIterationCount {
+    fn print_as_css_value(&self) -> String {
+        match self {
+            StyleAnimationIterationCount::Infinite => String::from("infinite"),
+            StyleAnimationIterationCount::Count(c) => format!("{}", c),
+        }
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationIterationCountVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationDirection {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleAnimationDirection::Normal => "normal",
+            StyleAnimationDirection::Reverse => "reverse",
+            StyleAnimationDirection::Alternate => "alternate",
+            StyleAnimationDirection::AlternateReverse => "alternate-reverse",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationDirectionVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationFillMode {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleAnimationFillMode::None => "none",
+            StyleAnimationFillMode::Forwards => "forwards",
+            StyleAnimationFillMode::Backwards => "backwards",
+            StyleAnimationFillMode::Both => "both",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationFillModeVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationPlayState {
+    fn print_as_css_value(&self) -> String {
+        String::from(match self {
+            StyleAnimationPlayState::Running => "running",
+            StyleAnimationPlayState::Paused => "paused",
+        })
+    }
+}
+
+impl PrintAsCssValue for StyleAnimationPlayStateVec {
+    fn print_as_css_value(&self) -> String {
+        self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
+    }
+}
+
 impl PrintAsCssValue for StyleFilterVec {
     fn print_as_css_value(&self) -> String {
         self.as_ref().iter().map(|f| f.print_as_css_value()).collect::<Vec<_>>().join(", ")
@@ -3,8 +3,12 @@ use azul_core::{
     app_resources::{AppConfig, ImageCache, ImageRef},
     callbacks::{RefAny, Update},
     display_list::RenderCallbacks,
-    task::{Timer, TimerId},
-    window::{MonitorVec, WindowCreateOptions},
+    task::{Timer, TimerId, ExternalSystemCallbacks, GetClipboardCallback, SetClipboardCallback},
+    window::{
+        MonitorVec, WindowCreateOptions, RawWindowHandle,
+        ClipboardSelection, ClipboardContent, OptionClipboardContent,
+        is_text_mime_type,
+    },
 };
 use azul_css::AzString;
 use clipboard2::{Clipboard as _, ClipboardError, SystemClipboard};
@@ -18,6 +22,7 @@ pub(crate) const CALLBACKS: RenderCallbacks = RenderCallbacks {
     layout_fn: azul_layout::do_the_layout,
     load_font_fn: azulc_lib::font_loading::font_source_get_bytes,
     parse_font_fn: azul_text_layout::parse_font_fn,
+    font_has_glyph_fn: azul_text_layout::font_has_glyph_fn,
 };
 
 #[derive(Debug, Clone)]
@@ -99,9 +104,18 @@ impl App {
     ///
     /// This does not open any windows, but it starts the event loop
     /// to the display server
-    pub fn new(initial_data: RefAny, app_config: AppConfig) -> Self {
+    pub fn new(initial_data: RefAny, mut app_config: AppConfig) -> Self {
         use std::thread;
 
+        // azul-core has no access to the clipboard, only placeholder functions
+        let placeholders = ExternalSystemCallbacks::rust_internal();
+        if app_config.system_callbacks.get_clipboard_fn == placeholders.get_clipboard_fn {
+            app_config.system_callbacks.get_clipboard_fn = GetClipboardCallback { cb: get_clipboard_content };
+        }
+        if app_config.system_callbacks.set_clipboard_fn == placeholders.set_clipboard_fn {
+            app_config.system_callbacks.set_clipboard_fn = SetClipboardCallback { cb: set_clipboard_content };
+        }
+
         #[cfg(not(miri))]
         let fc_cache = LazyFcCache::InProgress(Some(thread::spawn(move || FcFontCache::build())));
         #[cfg(miri)]
@@ -172,7 +186,19 @@ impl App {
         let err = crate::shell::win32::run(self, root_window);
 
         #[cfg(target_os = "linux")]
-        let err = crate::shell::x11::run(self, root_window);
+        let err = {
+            use azul_core::window::LinuxBackend;
+            let use_wayland = match root_window.state.platform_specific_options.linux_options.backend {
+                LinuxBackend::X11 => false,
+                LinuxBackend::Wayland => true,
+                LinuxBackend::Auto => crate::shell::wayland::is_available(),
+            };
+            if use_wayland {
+                crate::shell::wayland::run(self, root_window)
+            } else {
+                crate::shell::x11::run(self, root_window)
+            }
+        };
 
         #[cfg(target_os = "macos")]
         let err = crate::shell::cocoa::run(self, root_window);
@@ -257,6 +283,15 @@ impl Clipboard {
 
     /// Returns the contents of the system clipboard
     pub fn get_clipboard_string(&self) -> Option<AzString> {
+        #[cfg(target_os = "linux")] {
+            // the selection is owned by a window of the running application
+            if crate::shell::x11::is_running() {
+                return crate::shell::x11::get_clipboard_string().map(|s| s.into());
+            }
+            if crate::shell::wayland::is_connected() {
+                return crate::shell::wayland::get_clipboard_string().map(|s| s.into());
+            }
+        }
         self._native
             .lock()
             .ok()?
@@ -267,6 +302,14 @@ impl Clipboard {
 
     /// Sets the contents of the system clipboard
     pub fn set_clipboard_string(&mut self, contents: AzString) -> Option<()> {
+        #[cfg(target_os = "linux")] {
+            if crate::shell::x11::is_running() {
+                return crate::shell::x11::set_clipboard_string(contents.into_library_owned_string());
+            }
+            if crate::shell::wayland::is_connected() {
+                return crate::shell::wayland::set_clipboard_string(contents.into_library_owned_string());
+            }
+        }
         Arc::get_mut(&mut *self._native)?
             .get_mut()
             .ok()?
@@ -282,6 +325,73 @@ impl Drop for Clipboard {
     }
 }
 
+/// Implementation of `CallbackInfo::get_clipboard_content`
+///
+/// Only X11 supports the PRIMARY selection and non-text contents,
+/// on all other platforms the clipboard is read as text
+extern "C" fn get_clipboard_content(
+    window: &RawWindowHandle,
+    selection: ClipboardSelection,
+    mime_type: AzString,
+) -> OptionClipboardContent {
+
+    #[cfg(target_os = "linux")] {
+        if let RawWindowHandle::Xlib(handle) = window {
+            return crate::shell::x11::get_clipboard_content(handle, selection, mime_type.as_str()).into();
+        }
+    }
+
+    if selection != ClipboardSelection::Clipboard || !is_text_mime_type(mime_type.as_str()) {
+        return None.into();
+    }
+
+    #[cfg(target_os = "linux")] {
+        if crate::shell::wayland::is_connected() {
+            return crate::shell::wayland::get_clipboard_string()
+                .map(|s| ClipboardContent::text(s.into()))
+                .into();
+        }
+    }
+
+    Clipboard::new()
+    .and_then(|c| c.get_clipboard_string())
+    .map(ClipboardContent::text)
+    .into()
+}
+
+/// Implementation of `CallbackInfo::set_clipboard_content`
+extern "C" fn set_clipboard_content(
+    window: &RawWindowHandle,
+    selection: ClipboardSelection,
+    content: ClipboardContent,
+) -> bool {
+
+    #[cfg(target_os = "linux")] {
+        if let RawWindowHandle::Xlib(handle) = window {
+            return crate::shell::x11::set_clipboard_content(handle, selection, content);
+        }
+    }
+
+    if selection != ClipboardSelection::Clipboard {
+        return false;
+    }
+
+    let text = match content.get_text() {
+        Some(s) if content.is_text() => s,
+        _ => return false,
+    };
+
+    #[cfg(target_os = "linux")] {
+        if crate::shell::wayland::is_connected() {
+            return crate::shell::wayland::set_clipboard_string(text.into_library_owned_string()).is_some();
+        }
+    }
+
+    Clipboard::new()
+    .and_then(|mut c| c.set_clipboard_string(text))
+    .is_some()
+}
+
 pub mod extra {
 
     use azul_core::dom::{Dom, NodeType};
@@ -14,6 +14,10 @@
 //! | `font-size`                                        |              |             |            |                  |
 //! | `font-family`                                      |              |             |            |                  |
 //! | `text-align`                                       |              |             |            |                  |
+//! | `direction`                                        |              |             |            |                  |
+//! | `word-break`                                       |              |             |            |                  |
+//! | `overflow-wrap`                                    |              |             |            |                  |
+//! | `hyphens`                                          |              |             |            |                  |
 //! | `letter-spacing`                                   |              |             |            |                  |
 //! | `line-height`                                      |              |             |            |                  |
 //! | `word-spacing`                                     |              |             |            |                  |
@@ -0,0 +1,1650 @@
+//! AT-SPI2 bridge: publishes the `AccessibilityTree` of a window on the
+//! accessibility bus, so that screen readers (Orca, ...) can read and control the UI.
+//!
+//! `libdbus-1.so.3` is loaded at runtime (same as Xlib / EGL), if it can't be
+//! loaded, the window simply has no accessibility support.
+//!
+//! Objects are exported under `/org/a11y/atspi/accessible/`:
+//!
+//! - `root`: the application (`org.a11y.atspi.Application`)
+//! - `window`: the window frame, parent of all top-level nodes
+//! - `{dom}_{node}`: one object per node in the `AccessibilityTree`
+
+use super::x11::Library;
+use alloc::collections::BTreeMap;
+use azul_core::{
+    accessibility::{AccessibilityActionRequest, AccessibilityTree, AccessibilityNode},
+    callbacks::DomNodeId,
+    dom::{AccessibilityAction, AccessibilityRole, AccessibilityState},
+    styled_dom::{DomId, NodeHierarchyItemId},
+    window::{LogicalPosition, LogicalSize},
+};
+use core::{fmt, mem, ptr};
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_uint, c_void};
+
+const ROOT_PATH: &str = "/org/a11y/atspi/accessible/root";
+const WINDOW_PATH: &str = "/org/a11y/atspi/accessible/window";
+const NODE_PATH_PREFIX: &str = "/org/a11y/atspi/accessible/";
+const NULL_PATH: &str = "/org/a11y/atspi/null";
+
+const TOOLKIT_NAME: &str = "azul";
+const ATSPI_VERSION: &str = "2.1";
+
+// timeout for blocking calls to the registry daemon (in milliseconds)
+const CALL_TIMEOUT_MS: c_int = 1000;
+
+#[derive(Debug)]
+pub enum AtSpiError {
+    /// libdbus-1.so.3 could not be loaded or is missing a function
+    Library(String),
+    /// Could not connect to the session or accessibility bus
+    Connect(String),
+}
+
+impl fmt::Display for AtSpiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AtSpiError::Library(e) => write!(f, "AT-SPI: could not load libdbus: {}", e),
+            AtSpiError::Connect(e) => write!(f, "AT-SPI: could not connect to accessibility bus: {}", e),
+        }
+    }
+}
+
+// ---- libdbus FFI
+
+type DBusConnection = c_void;
+type DBusMessage = c_void;
+type dbus_bool_t = c_uint;
+
+#[repr(C)]
+struct DBusError {
+    name: *const c_char,
+    message: *const c_char,
+    dummy: c_uint,
+    padding1: *mut c_void,
+}
+
+impl DBusError {
+    fn new(dbus: &DBus) -> Self {
+        let mut e = DBusError { name: ptr::null(), message: ptr::null(), dummy: 0, padding1: ptr::null_mut() };
+        unsafe { (dbus.dbus_error_init)(&mut e) };
+        e
+    }
+
+    fn take(&mut self, dbus: &DBus) -> Option<String> {
+        if unsafe { (dbus.dbus_error_is_set)(self) } == 0 {
+            return None;
+        }
+        let msg = unsafe { cstr_to_string(self.message) }.unwrap_or_default();
+        unsafe { (dbus.dbus_error_free)(self) };
+        Some(msg)
+    }
+}
+
+// opaque, but has to be allocated on the stack (sizeof == 72 on 64-bit platforms)
+#[repr(C)]
+struct DBusMessageIter {
+    dummy: [*mut c_void; 9],
+}
+
+impl DBusMessageIter {
+    fn new() -> Self {
+        DBusMessageIter { dummy: [ptr::null_mut(); 9] }
+    }
+}
+
+const DBUS_BUS_SESSION: c_int = 0;
+
+const DBUS_MESSAGE_TYPE_METHOD_CALL: c_int = 1;
+
+const DBUS_TYPE_INVALID: c_int = 0;
+const DBUS_TYPE_BOOLEAN: c_int = b'b' as c_int;
+const DBUS_TYPE_INT16: c_int = b'n' as c_int;
+const DBUS_TYPE_INT32: c_int = b'i' as c_int;
+const DBUS_TYPE_UINT32: c_int = b'u' as c_int;
+const DBUS_TYPE_DOUBLE: c_int = b'd' as c_int;
+const DBUS_TYPE_STRING: c_int = b's' as c_int;
+const DBUS_TYPE_OBJECT_PATH: c_int = b'o' as c_int;
+const DBUS_TYPE_ARRAY: c_int = b'a' as c_int;
+const DBUS_TYPE_VARIANT: c_int = b'v' as c_int;
+const DBUS_TYPE_STRUCT: c_int = b'r' as c_int;
+const DBUS_TYPE_DICT_ENTRY: c_int = b'e' as c_int;
+
+type dbus_error_initFuncType = unsafe extern "C" fn(*mut DBusError);
+type dbus_error_freeFuncType = unsafe extern "C" fn(*mut DBusError);
+type dbus_error_is_setFuncType = unsafe extern "C" fn(*const DBusError) -> dbus_bool_t;
+type dbus_bus_get_privateFuncType = unsafe extern "C" fn(c_int, *mut DBusError) -> *mut DBusConnection;
+type dbus_connection_open_privateFuncType = unsafe extern "C" fn(*const c_char, *mut DBusError) -> *mut DBusConnection;
+type dbus_bus_registerFuncType = unsafe extern "C" fn(*mut DBusConnection, *mut DBusError) -> dbus_bool_t;
+type dbus_bus_get_unique_nameFuncType = unsafe extern "C" fn(*mut DBusConnection) -> *const c_char;
+type dbus_connection_set_exit_on_disconnectFuncType = unsafe extern "C" fn(*mut DBusConnection, dbus_bool_t);
+type dbus_connection_closeFuncType = unsafe extern "C" fn(*mut DBusConnection);
+type dbus_connection_unrefFuncType = unsafe extern "C" fn(*mut DBusConnection);
+type dbus_connection_read_writeFuncType = unsafe extern "C" fn(*mut DBusConnection, c_int) -> dbus_bool_t;
+type dbus_connection_pop_messageFuncType = unsafe extern "C" fn(*mut DBusConnection) -> *mut DBusMessage;
+type dbus_connection_sendFuncType = unsafe extern "C" fn(*mut DBusConnection, *mut DBusMessage, *mut u32) -> dbus_bool_t;
+type dbus_connection_flushFuncType = unsafe extern "C" fn(*mut DBusConnection);
+type dbus_connection_send_with_reply_and_blockFuncType = unsafe extern "C" fn(*mut DBusConnection, *mut DBusMessage, c_int, *mut DBusError) -> *mut DBusMessage;
+type dbus_message_new_method_callFuncType = unsafe extern "C" fn(*const c_char, *const c_char, *const c_char, *const c_char) -> *mut DBusMessage;
+type dbus_message_new_method_returnFuncType = unsafe extern "C" fn(*mut DBusMessage) -> *mut DBusMessage;
+type dbus_message_new_errorFuncType = unsafe extern "C" fn(*mut DBusMessage, *const c_char, *const c_char) -> *mut DBusMessage;
+type dbus_message_new_signalFuncType = unsafe extern "C" fn(*const c_char, *const c_char, *const c_char) -> *mut DBusMessage;
+type dbus_message_unrefFuncType = unsafe extern "C" fn(*mut DBusMessage);
+type dbus_message_get_typeFuncType = unsafe extern "C" fn(*mut DBusMessage) -> c_int;
+type dbus_message_get_pathFuncType = unsafe extern "C" fn(*mut DBusMessage) -> *const c_char;
+type dbus_message_get_interfaceFuncType = unsafe extern "C" fn(*mut DBusMessage) -> *const c_char;
+type dbus_message_get_memberFuncType = unsafe extern "C" fn(*mut DBusMessage) -> *const c_char;
+type dbus_message_get_no_replyFuncType = unsafe extern "C" fn(*mut DBusMessage) -> dbus_bool_t;
+type dbus_message_iter_initFuncType = unsafe extern "C" fn(*mut DBusMessage, *mut DBusMessageIter) -> dbus_bool_t;
+type dbus_message_iter_init_appendFuncType = unsafe extern "C" fn(*mut DBusMessage, *mut DBusMessageIter);
+type dbus_message_iter_append_basicFuncType = unsafe extern "C" fn(*mut DBusMessageIter, c_int, *const c_void) -> dbus_bool_t;
+type dbus_message_iter_open_containerFuncType = unsafe extern "C" fn(*mut DBusMessageIter, c_int, *const c_char, *mut DBusMessageIter) -> dbus_bool_t;
+type dbus_message_iter_close_containerFuncType = unsafe extern "C" fn(*mut DBusMessageIter, *mut DBusMessageIter) -> dbus_bool_t;
+type dbus_message_iter_get_arg_typeFuncType = unsafe extern "C" fn(*mut DBusMessageIter) -> c_int;
+type dbus_message_iter_get_basicFuncType = unsafe extern "C" fn(*mut DBusMessageIter, *mut c_void);
+type dbus_message_iter_nextFuncType = unsafe extern "C" fn(*mut DBusMessageIter) -> dbus_bool_t;
+type dbus_message_iter_recurseFuncType = unsafe extern "C" fn(*mut DBusMessageIter, *mut DBusMessageIter);
+
+struct DBus {
+    pub library: Library,
+    pub dbus_error_init: dbus_error_initFuncType,
+    pub dbus_error_free: dbus_error_freeFuncType,
+    pub dbus_error_is_set: dbus_error_is_setFuncType,
+    pub dbus_bus_get_private: dbus_bus_get_privateFuncType,
+    pub dbus_connection_open_private: dbus_connection_open_privateFuncType,
+    pub dbus_bus_register: dbus_bus_registerFuncType,
+    pub dbus_bus_get_unique_name: dbus_bus_get_unique_nameFuncType,
+    pub dbus_connection_set_exit_on_disconnect: dbus_connection_set_exit_on_disconnectFuncType,
+    pub dbus_connection_close: dbus_connection_closeFuncType,
+    pub dbus_connection_unref: dbus_connection_unrefFuncType,
+    pub dbus_connection_read_write: dbus_connection_read_writeFuncType,
+    pub dbus_connection_pop_message: dbus_connection_pop_messageFuncType,
+    pub dbus_connection_send: dbus_connection_sendFuncType,
+    pub dbus_connection_flush: dbus_connection_flushFuncType,
+    pub dbus_connection_send_with_reply_and_block: dbus_connection_send_with_reply_and_blockFuncType,
+    pub dbus_message_new_method_call: dbus_message_new_method_callFuncType,
+    pub dbus_message_new_method_return: dbus_message_new_method_returnFuncType,
+    pub dbus_message_new_error: dbus_message_new_errorFuncType,
+    pub dbus_message_new_signal: dbus_message_new_signalFuncType,
+    pub dbus_message_unref: dbus_message_unrefFuncType,
+    pub dbus_message_get_type: dbus_message_get_typeFuncType,
+    pub dbus_message_get_path: dbus_message_get_pathFuncType,
+    pub dbus_message_get_interface: dbus_message_get_interfaceFuncType,
+    pub dbus_message_get_member: dbus_message_get_memberFuncType,
+    pub dbus_message_get_no_reply: dbus_message_get_no_replyFuncType,
+    pub dbus_message_iter_init: dbus_message_iter_initFuncType,
+    pub dbus_message_iter_init_append: dbus_message_iter_init_appendFuncType,
+    pub dbus_message_iter_append_basic: dbus_message_iter_append_basicFuncType,
+    pub dbus_message_iter_open_container: dbus_message_iter_open_containerFuncType,
+    pub dbus_message_iter_close_container: dbus_message_iter_close_containerFuncType,
+    pub dbus_message_iter_get_arg_type: dbus_message_iter_get_arg_typeFuncType,
+    pub dbus_message_iter_get_basic: dbus_message_iter_get_basicFuncType,
+    pub dbus_message_iter_next: dbus_message_iter_nextFuncType,
+    pub dbus_message_iter_recurse: dbus_message_iter_recurseFuncType,
+}
+
+impl DBus {
+    fn new() -> Result<Self, AtSpiError> {
+
+        let dbus = Library::load("libdbus-1.so.3")
+            .map_err(|e| AtSpiError::Library(format!("Could not load libdbus-1: {}", e)))?;
+
+        // the type of the function pointer is inferred from the field of the DBus struct
+        macro_rules! load {($name:ident) => {
+            dbus.get(stringify!($name))
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(AtSpiError::Library(format!("libdbus: no function {}", stringify!($name))))?
+        }}
+
+        Ok(DBus {
+            dbus_error_init: load!(dbus_error_init),
+            dbus_error_free: load!(dbus_error_free),
+            dbus_error_is_set: load!(dbus_error_is_set),
+            dbus_bus_get_private: load!(dbus_bus_get_private),
+            dbus_connection_open_private: load!(dbus_connection_open_private),
+            dbus_bus_register: load!(dbus_bus_register),
+            dbus_bus_get_unique_name: load!(dbus_bus_get_unique_name),
+            dbus_connection_set_exit_on_disconnect: load!(dbus_connection_set_exit_on_disconnect),
+            dbus_connection_close: load!(dbus_connection_close),
+            dbus_connection_unref: load!(dbus_connection_unref),
+            dbus_connection_read_write: load!(dbus_connection_read_write),
+            dbus_connection_pop_message: load!(dbus_connection_pop_message),
+            dbus_connection_send: load!(dbus_connection_send),
+            dbus_connection_flush: load!(dbus_connection_flush),
+            dbus_connection_send_with_reply_and_block: load!(dbus_connection_send_with_reply_and_block),
+            dbus_message_new_method_call: load!(dbus_message_new_method_call),
+            dbus_message_new_method_return: load!(dbus_message_new_method_return),
+            dbus_message_new_error: load!(dbus_message_new_error),
+            dbus_message_new_signal: load!(dbus_message_new_signal),
+            dbus_message_unref: load!(dbus_message_unref),
+            dbus_message_get_type: load!(dbus_message_get_type),
+            dbus_message_get_path: load!(dbus_message_get_path),
+            dbus_message_get_interface: load!(dbus_message_get_interface),
+            dbus_message_get_member: load!(dbus_message_get_member),
+            dbus_message_get_no_reply: load!(dbus_message_get_no_reply),
+            dbus_message_iter_init: load!(dbus_message_iter_init),
+            dbus_message_iter_init_append: load!(dbus_message_iter_init_append),
+            dbus_message_iter_append_basic: load!(dbus_message_iter_append_basic),
+            dbus_message_iter_open_container: load!(dbus_message_iter_open_container),
+            dbus_message_iter_close_container: load!(dbus_message_iter_close_container),
+            dbus_message_iter_get_arg_type: load!(dbus_message_iter_get_arg_type),
+            dbus_message_iter_get_basic: load!(dbus_message_iter_get_basic),
+            dbus_message_iter_next: load!(dbus_message_iter_next),
+            dbus_message_iter_recurse: load!(dbus_message_iter_recurse),
+            library: dbus,
+        })
+    }
+}
+
+unsafe fn cstr_to_string(s: *const c_char) -> Option<String> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s).to_string_lossy().into_owned())
+    }
+}
+
+fn to_cstring(s: &str) -> CString {
+    CString::new(s.replace('\0', "")).unwrap_or_default()
+}
+
+// ---- D-Bus values
+
+/// Argument or return value of a D-Bus method call
+#[derive(Debug, Clone, PartialEq)]
+enum DBusValue {
+    Bool(bool),
+    Int16(i16),
+    Int32(i32),
+    UInt32(u32),
+    Double(f64),
+    Str(String),
+    ObjectPath(String),
+    /// Signature of the elements + elements
+    Array(String, Vec<DBusValue>),
+    Struct(Vec<DBusValue>),
+    DictEntry(Box<DBusValue>, Box<DBusValue>),
+    Variant(Box<DBusValue>),
+}
+
+impl DBusValue {
+
+    fn signature(&self) -> String {
+        use self::DBusValue::*;
+        match self {
+            Bool(_) => "b".to_string(),
+            Int16(_) => "n".to_string(),
+            Int32(_) => "i".to_string(),
+            UInt32(_) => "u".to_string(),
+            Double(_) => "d".to_string(),
+            Str(_) => "s".to_string(),
+            ObjectPath(_) => "o".to_string(),
+            Array(s, _) => format!("a{}", s),
+            Struct(v) => format!("({})", v.iter().map(|v| v.signature()).collect::<String>()),
+            DictEntry(k, v) => format!("{{{}{}}}", k.signature(), v.signature()),
+            Variant(_) => "v".to_string(),
+        }
+    }
+
+    /// AT-SPI object reference: `(so)` = (bus name, object path)
+    fn object_ref(bus_name: &str, path: &str) -> Self {
+        DBusValue::Struct(vec![DBusValue::Str(bus_name.to_string()), DBusValue::ObjectPath(path.to_string())])
+    }
+
+    fn null_ref() -> Self {
+        DBusValue::object_ref("", NULL_PATH)
+    }
+
+    fn as_i32(&self) -> Option<i32> {
+        match self {
+            DBusValue::Int32(i) => Some(*i),
+            DBusValue::UInt32(u) => Some(*u as i32),
+            DBusValue::Int16(i) => Some(*i as i32),
+            DBusValue::Variant(v) => v.as_i32(),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            DBusValue::Str(s) | DBusValue::ObjectPath(s) => Some(s.as_str()),
+            DBusValue::Variant(v) => v.as_str(),
+            _ => None,
+        }
+    }
+}
+
+unsafe fn append_value(dbus: &DBus, iter: *mut DBusMessageIter, value: &DBusValue) {
+
+    use self::DBusValue::*;
+
+    match value {
+        Bool(b) => {
+            let v: dbus_bool_t = if *b { 1 } else { 0 };
+            (dbus.dbus_message_iter_append_basic)(iter, DBUS_TYPE_BOOLEAN, &v as *const _ as *const c_void);
+        },
+        Int16(i) => { (dbus.dbus_message_iter_append_basic)(iter, DBUS_TYPE_INT16, i as *const _ as *const c_void); },
+        Int32(i) => { (dbus.dbus_message_iter_append_basic)(iter, DBUS_TYPE_INT32, i as *const _ as *const c_void); },
+        UInt32(u) => { (dbus.dbus_message_iter_append_basic)(iter, DBUS_TYPE_UINT32, u as *const _ as *const c_void); },
+        Double(d) => { (dbus.dbus_message_iter_append_basic)(iter, DBUS_TYPE_DOUBLE, d as *const _ as *const c_void); },
+        Str(s) | ObjectPath(s) => {
+            let ty = if let ObjectPath(_) = value { DBUS_TYPE_OBJECT_PATH } else { DBUS_TYPE_STRING };
+            let c = to_cstring(s);
+            let p = c.as_ptr();
+            (dbus.dbus_message_iter_append_basic)(iter, ty, &p as *const *const c_char as *const c_void);
+        },
+        Array(signature, items) => {
+            let signature = to_cstring(signature);
+            let mut sub = DBusMessageIter::new();
+            (dbus.dbus_message_iter_open_container)(iter, DBUS_TYPE_ARRAY, signature.as_ptr(), &mut sub);
+            for i in items.iter() {
+                append_value(dbus, &mut sub, i);
+            }
+            (dbus.dbus_message_iter_close_container)(iter, &mut sub);
+        },
+        Struct(items) => {
+            let mut sub = DBusMessageIter::new();
+            (dbus.dbus_message_iter_open_container)(iter, DBUS_TYPE_STRUCT, ptr::null(), &mut sub);
+            for i in items.iter() {
+                append_value(dbus, &mut sub, i);
+            }
+            (dbus.dbus_message_iter_close_container)(iter, &mut sub);
+        },
+        DictEntry(k, v) => {
+            let mut sub = DBusMessageIter::new();
+            (dbus.dbus_message_iter_open_container)(iter, DBUS_TYPE_DICT_ENTRY, ptr::null(), &mut sub);
+            append_value(dbus, &mut sub, k);
+            append_value(dbus, &mut sub, v);
+            (dbus.dbus_message_iter_close_container)(iter, &mut sub);
+        },
+        Variant(v) => {
+            let signature = to_cstring(&v.signature());
+            let mut sub = DBusMessageIter::new();
+            (dbus.dbus_message_iter_open_container)(iter, DBUS_TYPE_VARIANT, signature.as_ptr(), &mut sub);
+            append_value(dbus, &mut sub, v);
+            (dbus.dbus_message_iter_close_container)(iter, &mut sub);
+        },
+    }
+}
+
+unsafe fn read_value(dbus: &DBus, iter: *mut DBusMessageIter) -> Option<DBusValue> {
+
+    use self::DBusValue::*;
+
+    let value = match (dbus.dbus_message_iter_get_arg_type)(iter) {
+        DBUS_TYPE_BOOLEAN => {
+            let mut v: dbus_bool_t = 0;
+            (dbus.dbus_message_iter_get_basic)(iter, &mut v as *mut _ as *mut c_void);
+            Bool(v != 0)
+        },
+        DBUS_TYPE_INT16 => {
+            let mut v: i16 = 0;
+            (dbus.dbus_message_iter_get_basic)(iter, &mut v as *mut _ as *mut c_void);
+            Int16(v)
+        },
+        DBUS_TYPE_INT32 => {
+            let mut v: i32 = 0;
+            (dbus.dbus_message_iter_get_basic)(iter, &mut v as *mut _ as *mut c_void);
+            Int32(v)
+        },
+        DBUS_TYPE_UINT32 => {
+            let mut v: u32 = 0;
+            (dbus.dbus_message_iter_get_basic)(iter, &mut v as *mut _ as *mut c_void);
+            UInt32(v)
+        },
+        DBUS_TYPE_DOUBLE => {
+            let mut v: f64 = 0.0;
+            (dbus.dbus_message_iter_get_basic)(iter, &mut v as *mut _ as *mut c_void);
+            Double(v)
+        },
+        t @ DBUS_TYPE_STRING | t @ DBUS_TYPE_OBJECT_PATH => {
+            let mut v: *const c_char = ptr::null();
+            (dbus.dbus_message_iter_get_basic)(iter, &mut v as *mut _ as *mut c_void);
+            let s = cstr_to_string(v).unwrap_or_default();
+            if t == DBUS_TYPE_STRING { Str(s) } else { ObjectPath(s) }
+        },
+        DBUS_TYPE_VARIANT => {
+            let mut sub = DBusMessageIter::new();
+            (dbus.dbus_message_iter_recurse)(iter, &mut sub);
+            Variant(Box::new(read_value(dbus, &mut sub)?))
+        },
+        t @ DBUS_TYPE_STRUCT | t @ DBUS_TYPE_ARRAY => {
+            let mut sub = DBusMessageIter::new();
+            (dbus.dbus_message_iter_recurse)(iter, &mut sub);
+            let mut items = Vec::new();
+            while (dbus.dbus_message_iter_get_arg_type)(&mut sub) != DBUS_TYPE_INVALID {
+                items.push(read_value(dbus, &mut sub)?);
+                (dbus.dbus_message_iter_next)(&mut sub);
+            }
+            if t == DBUS_TYPE_STRUCT {
+                Struct(items)
+            } else {
+                let signature = items.first().map(|i| i.signature()).unwrap_or_default();
+                Array(signature, items)
+            }
+        },
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+unsafe fn read_args(dbus: &DBus, message: *mut DBusMessage) -> Vec<DBusValue> {
+    let mut args = Vec::new();
+    let mut iter = DBusMessageIter::new();
+    if (dbus.dbus_message_iter_init)(message, &mut iter) == 0 {
+        return args;
+    }
+    loop {
+        match read_value(dbus, &mut iter) {
+            Some(s) => args.push(s),
+            None => break,
+        }
+        if (dbus.dbus_message_iter_next)(&mut iter) == 0 {
+            break;
+        }
+    }
+    args
+}
+
+// ---- AT-SPI constants
+
+const ATSPI_COORD_TYPE_SCREEN: u32 = 0;
+const ATSPI_COORD_TYPE_WINDOW: u32 = 1;
+
+const ATSPI_LAYER_WIDGET: u32 = 3;
+const ATSPI_LAYER_WINDOW: u32 = 7;
+
+const ATSPI_ROLE_FRAME: u32 = 23;
+const ATSPI_ROLE_APPLICATION: u32 = 75;
+
+const ATSPI_STATE_ACTIVE: u32 = 1;
+const ATSPI_STATE_BUSY: u32 = 3;
+const ATSPI_STATE_CHECKED: u32 = 4;
+const ATSPI_STATE_COLLAPSED: u32 = 5;
+const ATSPI_STATE_EDITABLE: u32 = 7;
+const ATSPI_STATE_ENABLED: u32 = 8;
+const ATSPI_STATE_EXPANDABLE: u32 = 9;
+const ATSPI_STATE_EXPANDED: u32 = 10;
+const ATSPI_STATE_FOCUSABLE: u32 = 11;
+const ATSPI_STATE_FOCUSED: u32 = 12;
+const ATSPI_STATE_MULTISELECTABLE: u32 = 18;
+const ATSPI_STATE_RESIZABLE: u32 = 21;
+const ATSPI_STATE_SELECTABLE: u32 = 22;
+const ATSPI_STATE_SELECTED: u32 = 23;
+const ATSPI_STATE_SENSITIVE: u32 = 24;
+const ATSPI_STATE_SHOWING: u32 = 25;
+const ATSPI_STATE_VISIBLE: u32 = 30;
+const ATSPI_STATE_IS_DEFAULT: u32 = 39;
+const ATSPI_STATE_VISITED: u32 = 40;
+const ATSPI_STATE_CHECKABLE: u32 = 41;
+const ATSPI_STATE_READ_ONLY: u32 = 43;
+
+const ATSPI_STATE_NAMES: &[(u32, &str)] = &[
+    (ATSPI_STATE_ACTIVE, "active"),
+    (ATSPI_STATE_BUSY, "busy"),
+    (ATSPI_STATE_CHECKED, "checked"),
+    (ATSPI_STATE_COLLAPSED, "collapsed"),
+    (ATSPI_STATE_EDITABLE, "editable"),
+    (ATSPI_STATE_ENABLED, "enabled"),
+    (ATSPI_STATE_EXPANDABLE, "expandable"),
+    (ATSPI_STATE_EXPANDED, "expanded"),
+    (ATSPI_STATE_FOCUSABLE, "focusable"),
+    (ATSPI_STATE_FOCUSED, "focused"),
+    (ATSPI_STATE_MULTISELECTABLE, "multiselectable"),
+    (ATSPI_STATE_RESIZABLE, "resizable"),
+    (ATSPI_STATE_SELECTABLE, "selectable"),
+    (ATSPI_STATE_SELECTED, "selected"),
+    (ATSPI_STATE_SENSITIVE, "sensitive"),
+    (ATSPI_STATE_SHOWING, "showing"),
+    (ATSPI_STATE_VISIBLE, "visible"),
+    (ATSPI_STATE_IS_DEFAULT, "is-default"),
+    (ATSPI_STATE_VISITED, "visited"),
+    (ATSPI_STATE_CHECKABLE, "checkable"),
+    (ATSPI_STATE_READ_ONLY, "read-only"),
+];
+
+const IFACE_ACCESSIBLE: &str = "org.a11y.atspi.Accessible";
+const IFACE_ACTION: &str = "org.a11y.atspi.Action";
+const IFACE_APPLICATION: &str = "org.a11y.atspi.Application";
+const IFACE_COMPONENT: &str = "org.a11y.atspi.Component";
+const IFACE_TEXT: &str = "org.a11y.atspi.Text";
+const IFACE_PROPERTIES: &str = "org.freedesktop.DBus.Properties";
+const IFACE_EVENT_OBJECT: &str = "org.a11y.atspi.Event.Object";
+
+const ERROR_UNKNOWN_METHOD: &str = "org.freedesktop.DBus.Error.UnknownMethod";
+const ERROR_UNKNOWN_OBJECT: &str = "org.freedesktop.DBus.Error.UnknownObject";
+const ERROR_UNKNOWN_PROPERTY: &str = "org.freedesktop.DBus.Error.UnknownProperty";
+const ERROR_INVALID_ARGS: &str = "org.freedesktop.DBus.Error.InvalidArgs";
+
+/// Translates the MSAA role into the AT-SPI role (number + name)
+fn translate_role(role: AccessibilityRole, protected: bool) -> (u32, &'static str) {
+    use azul_core::dom::AccessibilityRole::*;
+    match role {
+        TitleBar => (104, "title bar"),
+        MenuBar => (34, "menu bar"),
+        ScrollBar => (48, "scroll bar"),
+        Sound => (106, "audio"),
+        Alert => (2, "alert"),
+        Window => (69, "window"),
+        Client | Pane | Grouping | Column | PropertyPage => (39, "panel"),
+        MenuPopup => (41, "popup menu"),
+        MenuItem => (35, "menu item"),
+        Tooltip | HelpBalloon => (64, "tool tip"),
+        Application => (75, "application"),
+        Document => (82, "document frame"),
+        Chart => (80, "chart"),
+        Dialog => (16, "dialog"),
+        Border | Whitespace => (20, "filler"),
+        Separator => (50, "separator"),
+        Toolbar => (63, "tool bar"),
+        StatusBar => (54, "status bar"),
+        Table => (55, "table"),
+        ColumnHeader => (57, "table column header"),
+        RowHeader => (58, "table row header"),
+        Row => (90, "table row"),
+        Cell => (56, "table cell"),
+        Link => (88, "link"),
+        List => (31, "list"),
+        ListItem => (32, "list item"),
+        Outline => (65, "tree"),
+        OutlineItem => (91, "tree item"),
+        Pagetab => (37, "page tab"),
+        PageTabList => (38, "page tab list"),
+        Graphic | Diagram => (27, "image"),
+        StaticText | Clock => (29, "label"),
+        Text | HotkeyField | IpAddress => if protected { (40, "password text") } else { (61, "text") },
+        PushButton | ButtonDropdown | ButtonMenu | ButtonDropdownGrid | SplitButton => (43, "push button"),
+        CheckButton => (7, "check box"),
+        RadioButton => (44, "radio button"),
+        ComboBox | DropList => (11, "combo box"),
+        ProgressBar => (42, "progress bar"),
+        Dial => (15, "dial"),
+        Slider => (51, "slider"),
+        SpinButton => (52, "spin button"),
+        Animation => (3, "animation"),
+        Equation => (113, "math"),
+        Grip | Cursor | Caret | Character | Indicator | Nothing => (67, "unknown"),
+    }
+}
+
+/// Translates the states of the node into AT-SPI states, sorted
+fn translate_states(node: &AccessibilityNode) -> Vec<u32> {
+
+    use azul_core::dom::AccessibilityState::*;
+
+    let mut states = Vec::new();
+
+    if !node.has_state(Unavailable) {
+        states.push(ATSPI_STATE_ENABLED);
+        states.push(ATSPI_STATE_SENSITIVE);
+    }
+    if !node.has_state(Offscreen) {
+        states.push(ATSPI_STATE_SHOWING);
+        states.push(ATSPI_STATE_VISIBLE);
+    }
+
+    match node.role {
+        AccessibilityRole::CheckButton | AccessibilityRole::RadioButton => states.push(ATSPI_STATE_CHECKABLE),
+        AccessibilityRole::Text if !node.has_state(Readonly) => states.push(ATSPI_STATE_EDITABLE),
+        _ => { },
+    }
+
+    for s in node.states.iter() {
+        match s {
+            Selected => states.push(ATSPI_STATE_SELECTED),
+            Focused => states.push(ATSPI_STATE_FOCUSED),
+            Checked => states.push(ATSPI_STATE_CHECKED),
+            Readonly => states.push(ATSPI_STATE_READ_ONLY),
+            Default => states.push(ATSPI_STATE_IS_DEFAULT),
+            Expanded => { states.push(ATSPI_STATE_EXPANDABLE); states.push(ATSPI_STATE_EXPANDED); },
+            Collapsed => { states.push(ATSPI_STATE_EXPANDABLE); states.push(ATSPI_STATE_COLLAPSED); },
+            Busy => states.push(ATSPI_STATE_BUSY),
+            Focusable => states.push(ATSPI_STATE_FOCUSABLE),
+            Selectable => states.push(ATSPI_STATE_SELECTABLE),
+            Traversed => states.push(ATSPI_STATE_VISITED),
+            Multiselectable => states.push(ATSPI_STATE_MULTISELECTABLE),
+            Unavailable | Offscreen | Linked | Protected => { },
+        }
+    }
+
+    states.sort();
+    states.dedup();
+    states
+}
+
+fn state_set_to_value(states: &[u32]) -> DBusValue {
+    let mut bits = [0_u32; 2];
+    for s in states {
+        bits[(*s / 32) as usize] |= 1 << (*s % 32);
+    }
+    DBusValue::Array("u".to_string(), vec![DBusValue::UInt32(bits[0]), DBusValue::UInt32(bits[1])])
+}
+
+/// Returns the interface that defines the method, for method calls without an interface
+fn get_interface_of_method(member: &str) -> &'static str {
+    match member {
+        "Get" | "GetAll" | "Set" => IFACE_PROPERTIES,
+        "Contains" | "GetAccessibleAtPoint" | "GetExtents" | "GetPosition" | "GetSize" |
+        "GetLayer" | "GetMDIZOrder" | "GrabFocus" | "GetAlpha" | "ScrollTo" | "ScrollToPoint" => IFACE_COMPONENT,
+        "GetDescription" | "GetName" | "GetLocalizedName" | "GetKeyBinding" | "GetActions" | "DoAction" => IFACE_ACTION,
+        "GetLocale" | "GetApplicationBusAddress" => IFACE_APPLICATION,
+        "GetText" | "GetCharacterAtOffset" => IFACE_TEXT,
+        _ => IFACE_ACCESSIBLE,
+    }
+}
+
+// ---- bridge
+
+/// Information about the window that is exported on the accessibility bus
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibleWindow {
+    pub title: String,
+    /// Position of the window on the screen, in physical pixels
+    pub position: (i32, i32),
+    pub size: LogicalSize,
+    pub hidpi_factor: f32,
+    pub is_focused: bool,
+}
+
+impl Default for AccessibleWindow {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            position: (0, 0),
+            size: LogicalSize::zero(),
+            hidpi_factor: 1.0,
+            is_focused: false,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum AtSpiObject {
+    Application,
+    Window,
+    /// Index into the `AccessibilityTree.nodes`
+    Node(usize),
+}
+
+/// Connection to the AT-SPI accessibility bus for a single window
+pub struct AtSpiBridge {
+    dbus: DBus,
+    connection: *mut DBusConnection,
+    /// Unique name of the connection on the accessibility bus (":1.42")
+    bus_name: String,
+    /// Desktop object of the registry daemon = parent of the application
+    desktop: Option<(String, String)>,
+    /// Application ID, assigned by the registry daemon
+    app_id: i32,
+    app_name: String,
+    window: AccessibleWindow,
+    tree: AccessibilityTree,
+    pending_actions: Vec<AccessibilityActionRequest>,
+}
+
+impl fmt::Debug for AtSpiBridge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AtSpiBridge {{ bus_name: {:?}, nodes: {} }}", self.bus_name, self.tree.len())
+    }
+}
+
+impl AtSpiBridge {
+
+    /// Connects to the accessibility bus of the current session (either
+    /// `$AT_SPI_BUS_ADDRESS` or the address returned by `org.a11y.Bus`)
+    /// and registers the application with the AT-SPI registry
+    pub fn new(app_name: &str) -> Result<Self, AtSpiError> {
+        let dbus = DBus::new()?;
+        let address = match std::env::var("AT_SPI_BUS_ADDRESS") {
+            Ok(s) if !s.is_empty() => s,
+            _ => get_accessibility_bus_address(&dbus)?,
+        };
+        let mut bridge = Self::connect(dbus, &address, app_name)?;
+        bridge.embed();
+        Ok(bridge)
+    }
+
+    /// Connects to a D-Bus daemon at the given address, without registering
+    /// with the AT-SPI registry (useful for testing on a private bus)
+    pub fn connect_to_address(address: &str, app_name: &str) -> Result<Self, AtSpiError> {
+        let dbus = DBus::new()?;
+        Self::connect(dbus, address, app_name)
+    }
+
+    fn connect(dbus: DBus, address: &str, app_name: &str) -> Result<Self, AtSpiError> {
+
+        let mut error = DBusError::new(&dbus);
+        let address_c = to_cstring(address);
+
+        let connection = unsafe { (dbus.dbus_connection_open_private)(address_c.as_ptr(), &mut error) };
+        if let Some(e) = error.take(&dbus) {
+            return Err(AtSpiError::Connect(e));
+        }
+        if connection.is_null() {
+            return Err(AtSpiError::Connect(format!("dbus_connection_open_private({}) failed", address)));
+        }
+
+        unsafe { (dbus.dbus_connection_set_exit_on_disconnect)(connection, 0) };
+
+        unsafe { (dbus.dbus_bus_register)(connection, &mut error) };
+        if let Some(e) = error.take(&dbus) {
+            unsafe {
+                (dbus.dbus_connection_close)(connection);
+                (dbus.dbus_connection_unref)(connection);
+            }
+            return Err(AtSpiError::Connect(e));
+        }
+
+        let bus_name = unsafe { cstr_to_string((dbus.dbus_bus_get_unique_name)(connection)) }.unwrap_or_default();
+
+        Ok(Self {
+            dbus,
+            connection,
+            bus_name,
+            desktop: None,
+            app_id: 0,
+            app_name: app_name.to_string(),
+            window: AccessibleWindow::default(),
+            tree: AccessibilityTree::default(),
+            pending_actions: Vec::new(),
+        })
+    }
+
+    /// Returns the unique name of the connection on the accessibility bus
+    pub fn get_bus_name(&self) -> &str {
+        &self.bus_name
+    }
+
+    /// Registers the application with the registry daemon, so that it shows up
+    /// as a child of the desktop. Fails silently if there is no registry.
+    fn embed(&mut self) {
+
+        let message = unsafe {
+            (self.dbus.dbus_message_new_method_call)(
+                to_cstring("org.a11y.atspi.Registry").as_ptr(),
+                to_cstring(ROOT_PATH).as_ptr(),
+                to_cstring("org.a11y.atspi.Socket").as_ptr(),
+                to_cstring("Embed").as_ptr(),
+            )
+        };
+
+        if message.is_null() {
+            return;
+        }
+
+        let args = [DBusValue::object_ref(&self.bus_name, ROOT_PATH)];
+        let reply = self.call_blocking(message, &args);
+
+        if let Some(DBusValue::Struct(r)) = reply.and_then(|r| r.into_iter().next()) {
+            if let (Some(name), Some(path)) = (r.get(0).and_then(|s| s.as_str()), r.get(1).and_then(|s| s.as_str())) {
+                self.desktop = Some((name.to_string(), path.to_string()));
+            }
+        }
+    }
+
+    // sends the message, waits for the reply and returns the reply arguments, consumes the message
+    fn call_blocking(&self, message: *mut DBusMessage, args: &[DBusValue]) -> Option<Vec<DBusValue>> {
+        let dbus = &self.dbus;
+        let mut error = DBusError::new(dbus);
+        unsafe {
+            let mut iter = DBusMessageIter::new();
+            (dbus.dbus_message_iter_init_append)(message, &mut iter);
+            for a in args {
+                append_value(dbus, &mut iter, a);
+            }
+            let reply = (dbus.dbus_connection_send_with_reply_and_block)(self.connection, message, CALL_TIMEOUT_MS, &mut error);
+            (dbus.dbus_message_unref)(message);
+            if error.take(dbus).is_some() || reply.is_null() {
+                return None;
+            }
+            let reply_args = read_args(dbus, reply);
+            (dbus.dbus_message_unref)(reply);
+            Some(reply_args)
+        }
+    }
+
+    /// Replaces the exported tree and notifies the screen readers about what changed
+    pub fn publish(&mut self, window: AccessibleWindow, tree: AccessibilityTree) {
+        let old_window = mem::replace(&mut self.window, window);
+        let old_tree = mem::replace(&mut self.tree, tree);
+        self.emit_changes(&old_window, &old_tree);
+        unsafe { (self.dbus.dbus_connection_flush)(self.connection) };
+    }
+
+    /// Answers all pending requests from the accessibility bus, waiting at most `timeout_ms`
+    /// milliseconds for new requests. Returns the actions requested by screen readers.
+    pub fn dispatch(&mut self, timeout_ms: i32) -> Vec<AccessibilityActionRequest> {
+        let connected = unsafe { (self.dbus.dbus_connection_read_write)(self.connection, timeout_ms) } != 0;
+        if connected {
+            loop {
+                let message = unsafe { (self.dbus.dbus_connection_pop_message)(self.connection) };
+                if message.is_null() {
+                    break;
+                }
+                if unsafe { (self.dbus.dbus_message_get_type)(message) } == DBUS_MESSAGE_TYPE_METHOD_CALL {
+                    self.handle_method_call(message);
+                }
+                unsafe { (self.dbus.dbus_message_unref)(message) };
+            }
+            unsafe { (self.dbus.dbus_connection_flush)(self.connection) };
+        }
+        mem::replace(&mut self.pending_actions, Vec::new())
+    }
+
+    // -- object model
+
+    fn get_path(&self, object: AtSpiObject) -> String {
+        match object {
+            AtSpiObject::Application => ROOT_PATH.to_string(),
+            AtSpiObject::Window => WINDOW_PATH.to_string(),
+            AtSpiObject::Node(n) => {
+                let id = self.tree.nodes[n].dom_node_id;
+                let node = id.node.into_crate_internal().map(|n| n.index()).unwrap_or(0);
+                format!("{}{}_{}", NODE_PATH_PREFIX, id.dom.inner, node)
+            },
+        }
+    }
+
+    fn resolve_path(&self, path: &str) -> Option<AtSpiObject> {
+        use azul_core::id_tree::NodeId;
+        match path {
+            ROOT_PATH => Some(AtSpiObject::Application),
+            WINDOW_PATH => Some(AtSpiObject::Window),
+            _ => {
+                let mut ids = path.strip_prefix(NODE_PATH_PREFIX)?.splitn(2, '_');
+                let dom = ids.next()?.parse::<usize>().ok()?;
+                let node = ids.next()?.parse::<usize>().ok()?;
+                let id = DomNodeId {
+                    dom: DomId { inner: dom },
+                    node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(node))),
+                };
+                self.tree.find_node(id).map(AtSpiObject::Node)
+            }
+        }
+    }
+
+    fn get_ref(&self, object: AtSpiObject) -> DBusValue {
+        DBusValue::object_ref(&self.bus_name, &self.get_path(object))
+    }
+
+    fn get_parent(&self, object: AtSpiObject) -> Option<AtSpiObject> {
+        match object {
+            AtSpiObject::Application => None,
+            AtSpiObject::Window => Some(AtSpiObject::Application),
+            AtSpiObject::Node(n) => Some(self.tree.nodes[n].parent.map(AtSpiObject::Node).unwrap_or(AtSpiObject::Window)),
+        }
+    }
+
+    fn get_children(&self, object: AtSpiObject) -> Vec<AtSpiObject> {
+        match object {
+            AtSpiObject::Application => vec![AtSpiObject::Window],
+            AtSpiObject::Window => self.tree.get_children(None).iter().map(|c| AtSpiObject::Node(*c)).collect(),
+            AtSpiObject::Node(n) => self.tree.get_children(Some(n)).iter().map(|c| AtSpiObject::Node(*c)).collect(),
+        }
+    }
+
+    fn get_role(&self, object: AtSpiObject) -> (u32, &'static str) {
+        match object {
+            AtSpiObject::Application => (ATSPI_ROLE_APPLICATION, "application"),
+            AtSpiObject::Window => (ATSPI_ROLE_FRAME, "frame"),
+            AtSpiObject::Node(n) => {
+                let node = &self.tree.nodes[n];
+                translate_role(node.role, node.has_state(AccessibilityState::Protected))
+            },
+        }
+    }
+
+    fn get_name(&self, object: AtSpiObject) -> String {
+        match object {
+            AtSpiObject::Application => self.app_name.clone(),
+            AtSpiObject::Window => self.window.title.clone(),
+            AtSpiObject::Node(n) => self.tree.nodes[n].name.as_ref().map(|s| s.as_str().to_string()).unwrap_or_default(),
+        }
+    }
+
+    fn get_description(&self, object: AtSpiObject) -> String {
+        match object {
+            AtSpiObject::Node(n) => self.tree.nodes[n].description.as_ref().map(|s| s.as_str().to_string()).unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+
+    // text of the Text interface: value of text inputs, name of labels
+    fn get_text(&self, object: AtSpiObject) -> Option<String> {
+        match object {
+            AtSpiObject::Node(n) => {
+                let node = &self.tree.nodes[n];
+                match node.role {
+                    AccessibilityRole::Text => Some(node.value.as_ref().map(|s| s.as_str().to_string()).unwrap_or_default()),
+                    AccessibilityRole::StaticText => Some(self.get_name(object)),
+                    _ => None,
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn get_states(&self, object: AtSpiObject) -> Vec<u32> {
+        match object {
+            AtSpiObject::Application => Vec::new(),
+            AtSpiObject::Window => {
+                let mut states = vec![
+                    ATSPI_STATE_ENABLED, ATSPI_STATE_RESIZABLE,
+                    ATSPI_STATE_SENSITIVE, ATSPI_STATE_SHOWING,
+                    ATSPI_STATE_VISIBLE,
+                ];
+                if self.window.is_focused {
+                    states.insert(0, ATSPI_STATE_ACTIVE);
+                }
+                states
+            },
+            AtSpiObject::Node(n) => translate_states(&self.tree.nodes[n]),
+        }
+    }
+
+    fn get_actions(&self, object: AtSpiObject) -> &[AccessibilityAction] {
+        match object {
+            AtSpiObject::Node(n) => &self.tree.nodes[n].actions,
+            _ => &[],
+        }
+    }
+
+    fn get_interfaces(&self, object: AtSpiObject) -> Vec<&'static str> {
+        let mut interfaces = vec![IFACE_ACCESSIBLE];
+        match object {
+            AtSpiObject::Application => interfaces.push(IFACE_APPLICATION),
+            AtSpiObject::Window => interfaces.push(IFACE_COMPONENT),
+            AtSpiObject::Node(_) => {
+                interfaces.push(IFACE_COMPONENT);
+                if !self.get_actions(object).is_empty() {
+                    interfaces.push(IFACE_ACTION);
+                }
+                if self.get_text(object).is_some() {
+                    interfaces.push(IFACE_TEXT);
+                }
+            },
+        }
+        interfaces
+    }
+
+    /// Returns the (x, y, width, height) of the object in physical pixels
+    fn get_extents(&self, object: AtSpiObject, coord_type: u32) -> (i32, i32, i32, i32) {
+
+        let scale = self.window.hidpi_factor;
+
+        let (x, y, w, h) = match object {
+            AtSpiObject::Application => return (0, 0, 0, 0),
+            AtSpiObject::Window => (0.0, 0.0, self.window.size.width, self.window.size.height),
+            AtSpiObject::Node(n) => {
+                let b = self.tree.nodes[n].bounds;
+                (b.origin.x, b.origin.y, b.size.width, b.size.height)
+            },
+        };
+
+        let (x, y, w, h) = (
+            libm::roundf(x * scale) as i32,
+            libm::roundf(y * scale) as i32,
+            libm::roundf(w * scale) as i32,
+            libm::roundf(h * scale) as i32,
+        );
+
+        match coord_type {
+            ATSPI_COORD_TYPE_SCREEN => (x + self.window.position.0, y + self.window.position.1, w, h),
+            ATSPI_COORD_TYPE_WINDOW => (x, y, w, h),
+            _ => {
+                // relative to the parent
+                let (px, py, _, _) = match self.get_parent(object) {
+                    Some(p) => self.get_extents(p, ATSPI_COORD_TYPE_WINDOW),
+                    None => (0, 0, 0, 0),
+                };
+                (x - px, y - py, w, h)
+            }
+        }
+    }
+
+    fn get_accessible_at_point(&self, object: AtSpiObject, x: i32, y: i32, coord_type: u32) -> Option<AtSpiObject> {
+
+        let (ox, oy, ow, oh) = self.get_extents(object, coord_type);
+        let (ex, ey, _, _) = self.get_extents(object, ATSPI_COORD_TYPE_WINDOW);
+
+        if x < ox || y < oy || x >= ox + ow || y >= oy + oh {
+            return None;
+        }
+
+        // translate into logical window coordinates
+        let scale = self.window.hidpi_factor;
+        let point = LogicalPosition::new(
+            (x - ox + ex) as f32 / scale,
+            (y - oy + ey) as f32 / scale,
+        );
+
+        let start = match object {
+            AtSpiObject::Node(n) => Some(n),
+            _ => None,
+        };
+
+        match self.tree.hit_test(start, point) {
+            Some(n) if Some(n) != start => Some(AtSpiObject::Node(n)),
+            _ => None,
+        }
+    }
+
+    fn get_properties(&self, object: AtSpiObject, interface: &str) -> Vec<(&'static str, DBusValue)> {
+        use self::DBusValue::*;
+        match interface {
+            IFACE_ACCESSIBLE => vec![
+                ("Name", Str(self.get_name(object))),
+                ("Description", Str(self.get_description(object))),
+                ("Parent", match self.get_parent(object) {
+                    Some(p) => self.get_ref(p),
+                    None => match self.desktop.as_ref() {
+                        Some((name, path)) => DBusValue::object_ref(name, path),
+                        None => DBusValue::null_ref(),
+                    },
+                }),
+                ("ChildCount", Int32(self.get_children(object).len() as i32)),
+                ("Locale", Str(get_locale())),
+                ("AccessibleId", Str(match object {
+                    AtSpiObject::Node(_) => self.get_path(object).trim_start_matches(NODE_PATH_PREFIX).to_string(),
+                    _ => String::new(),
+                })),
+            ],
+            IFACE_APPLICATION if object == AtSpiObject::Application => vec![
+                ("ToolkitName", Str(TOOLKIT_NAME.to_string())),
+                ("Version", Str(env!("CARGO_PKG_VERSION").to_string())),
+                ("AtspiVersion", Str(ATSPI_VERSION.to_string())),
+                ("Id", Int32(self.app_id)),
+            ],
+            IFACE_ACTION if !self.get_actions(object).is_empty() => vec![
+                ("NActions", Int32(self.get_actions(object).len() as i32)),
+            ],
+            IFACE_TEXT => match self.get_text(object) {
+                Some(t) => {
+                    let len = t.chars().count() as i32;
+                    vec![("CharacterCount", Int32(len)), ("CaretOffset", Int32(len))]
+                },
+                None => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    // -- method calls
+
+    fn handle_method_call(&mut self, message: *mut DBusMessage) {
+
+        let dbus = &self.dbus;
+
+        let (path, interface, member, args, no_reply) = unsafe {(
+            cstr_to_string((dbus.dbus_message_get_path)(message)).unwrap_or_default(),
+            cstr_to_string((dbus.dbus_message_get_interface)(message)),
+            cstr_to_string((dbus.dbus_message_get_member)(message)).unwrap_or_default(),
+            read_args(dbus, message),
+            (dbus.dbus_message_get_no_reply)(message) != 0,
+        )};
+
+        let result = match self.resolve_path(&path) {
+            Some(object) => {
+                let interface = interface.as_ref().map(|s| s.as_str()).unwrap_or_else(|| get_interface_of_method(&member));
+                self.call_method(object, interface, &member, &args)
+            },
+            None => Err((ERROR_UNKNOWN_OBJECT, format!("No such object: {}", path))),
+        };
+
+        if no_reply {
+            return;
+        }
+
+        let dbus = &self.dbus;
+
+        unsafe {
+            let reply = match result {
+                Ok(values) => {
+                    let reply = (dbus.dbus_message_new_method_return)(message);
+                    if reply.is_null() {
+                        return;
+                    }
+                    let mut iter = DBusMessageIter::new();
+                    (dbus.dbus_message_iter_init_append)(reply, &mut iter);
+                    for v in values.iter() {
+                        append_value(dbus, &mut iter, v);
+                    }
+                    reply
+                },
+                Err((name, text)) => {
+                    (dbus.dbus_message_new_error)(message, to_cstring(name).as_ptr(), to_cstring(&text).as_ptr())
+                },
+            };
+
+            if !reply.is_null() {
+                (dbus.dbus_connection_send)(self.connection, reply, ptr::null_mut());
+                (dbus.dbus_message_unref)(reply);
+            }
+        }
+    }
+
+    fn call_method(
+        &mut self,
+        object: AtSpiObject,
+        interface: &str,
+        member: &str,
+        args: &[DBusValue],
+    ) -> Result<Vec<DBusValue>, (&'static str, String)> {
+
+        use self::DBusValue::*;
+
+        let arg_i32 = |i: usize| args.get(i).and_then(|a| a.as_i32()).ok_or((ERROR_INVALID_ARGS, format!("{}: expected integer argument {}", member, i)));
+        let arg_str = |i: usize| args.get(i).and_then(|a| a.as_str()).ok_or((ERROR_INVALID_ARGS, format!("{}: expected string argument {}", member, i)));
+
+        if !self.get_interfaces(object).contains(&interface) && interface != IFACE_PROPERTIES {
+            return Err((ERROR_UNKNOWN_METHOD, format!("Object does not implement {}", interface)));
+        }
+
+        let ret = match (interface, member) {
+
+            (IFACE_PROPERTIES, "Get") => {
+                let (iface, prop) = (arg_str(0)?, arg_str(1)?);
+                let value = self.get_properties(object, iface).into_iter()
+                    .find(|(k, _)| *k == prop)
+                    .map(|(_, v)| v)
+                    .ok_or((ERROR_UNKNOWN_PROPERTY, format!("Unknown property {}.{}", iface, prop)))?;
+                vec![Variant(Box::new(value))]
+            },
+            (IFACE_PROPERTIES, "GetAll") => {
+                let entries = self.get_properties(object, arg_str(0)?).into_iter()
+                    .map(|(k, v)| DictEntry(Box::new(Str(k.to_string())), Box::new(Variant(Box::new(v)))))
+                    .collect();
+                vec![Array("{sv}".to_string(), entries)]
+            },
+            (IFACE_PROPERTIES, "Set") => {
+                // only the application ID can be set (by the registry daemon)
+                match (object, arg_str(0)?, arg_str(1)?) {
+                    (AtSpiObject::Application, IFACE_APPLICATION, "Id") => { self.app_id = arg_i32(2)?; },
+                    (_, iface, prop) => return Err((ERROR_UNKNOWN_PROPERTY, format!("Property {}.{} is read-only", iface, prop))),
+                }
+                Vec::new()
+            },
+
+            (IFACE_ACCESSIBLE, "GetChildAtIndex") => {
+                let index = arg_i32(0)?;
+                let child = self.get_children(object).get(index as usize).copied();
+                vec![child.map(|c| self.get_ref(c)).unwrap_or_else(DBusValue::null_ref)]
+            },
+            (IFACE_ACCESSIBLE, "GetChildren") => {
+                let children = self.get_children(object).into_iter().map(|c| self.get_ref(c)).collect();
+                vec![Array("(so)".to_string(), children)]
+            },
+            (IFACE_ACCESSIBLE, "GetIndexInParent") => {
+                let index = match self.get_parent(object) {
+                    Some(p) => self.get_children(p).iter().position(|c| *c == object).map(|i| i as i32).unwrap_or(-1),
+                    None => -1,
+                };
+                vec![Int32(index)]
+            },
+            (IFACE_ACCESSIBLE, "GetRelationSet") => vec![Array("(ua(so))".to_string(), Vec::new())],
+            (IFACE_ACCESSIBLE, "GetRole") => vec![UInt32(self.get_role(object).0)],
+            (IFACE_ACCESSIBLE, "GetRoleName") |
+            (IFACE_ACCESSIBLE, "GetLocalizedRoleName") => vec![Str(self.get_role(object).1.to_string())],
+            (IFACE_ACCESSIBLE, "GetState") => vec![state_set_to_value(&self.get_states(object))],
+            (IFACE_ACCESSIBLE, "GetAttributes") => {
+                let mut attributes = vec![("toolkit", TOOLKIT_NAME.to_string())];
+                if let AtSpiObject::Node(n) = object {
+                    if let Some(v) = self.tree.nodes[n].value.as_ref() {
+                        attributes.push(("value", v.as_str().to_string()));
+                    }
+                }
+                let entries = attributes.into_iter()
+                    .map(|(k, v)| DictEntry(Box::new(Str(k.to_string())), Box::new(Str(v))))
+                    .collect();
+                vec![Array("{ss}".to_string(), entries)]
+            },
+            (IFACE_ACCESSIBLE, "GetApplication") => vec![self.get_ref(AtSpiObject::Application)],
+            (IFACE_ACCESSIBLE, "GetInterfaces") => {
+                let interfaces = self.get_interfaces(object).into_iter().map(|i| Str(i.to_string())).collect();
+                vec![Array("s".to_string(), interfaces)]
+            },
+
+            (IFACE_COMPONENT, "Contains") => {
+                let (x, y, coord_type) = (arg_i32(0)?, arg_i32(1)?, arg_i32(2)? as u32);
+                let (ox, oy, w, h) = self.get_extents(object, coord_type);
+                vec![Bool(x >= ox && y >= oy && x < ox + w && y < oy + h)]
+            },
+            (IFACE_COMPONENT, "GetAccessibleAtPoint") => {
+                let (x, y, coord_type) = (arg_i32(0)?, arg_i32(1)?, arg_i32(2)? as u32);
+                let hit = self.get_accessible_at_point(object, x, y, coord_type);
+                vec![hit.map(|h| self.get_ref(h)).unwrap_or_else(DBusValue::null_ref)]
+            },
+            (IFACE_COMPONENT, "GetExtents") => {
+                let (x, y, w, h) = self.get_extents(object, arg_i32(0)? as u32);
+                vec![Struct(vec![Int32(x), Int32(y), Int32(w), Int32(h)])]
+            },
+            (IFACE_COMPONENT, "GetPosition") => {
+                let (x, y, _, _) = self.get_extents(object, arg_i32(0)? as u32);
+                vec![Int32(x), Int32(y)]
+            },
+            (IFACE_COMPONENT, "GetSize") => {
+                let (_, _, w, h) = self.get_extents(object, ATSPI_COORD_TYPE_WINDOW);
+                vec![Int32(w), Int32(h)]
+            },
+            (IFACE_COMPONENT, "GetLayer") => vec![UInt32(if object == AtSpiObject::Window { ATSPI_LAYER_WINDOW } else { ATSPI_LAYER_WIDGET })],
+            (IFACE_COMPONENT, "GetMDIZOrder") => vec![Int16(0)],
+            (IFACE_COMPONENT, "GetAlpha") => vec![Double(1.0)],
+            (IFACE_COMPONENT, "GrabFocus") => {
+                let focusable = self.get_actions(object).contains(&AccessibilityAction::Focus);
+                if let (AtSpiObject::Node(n), true) = (object, focusable) {
+                    self.pending_actions.push(AccessibilityActionRequest {
+                        node: self.tree.nodes[n].dom_node_id,
+                        action: AccessibilityAction::Focus,
+                    });
+                }
+                vec![Bool(focusable)]
+            },
+            (IFACE_COMPONENT, "ScrollTo") |
+            (IFACE_COMPONENT, "ScrollToPoint") => vec![Bool(false)],
+
+            (IFACE_ACTION, "GetName") |
+            (IFACE_ACTION, "GetLocalizedName") => {
+                let action = self.get_actions(object).get(arg_i32(0)? as usize).copied();
+                vec![Str(action.map(|a| a.get_name().to_string()).unwrap_or_default())]
+            },
+            (IFACE_ACTION, "GetDescription") => {
+                let action = self.get_actions(object).get(arg_i32(0)? as usize).copied();
+                vec![Str(match action {
+                    Some(AccessibilityAction::Default) => self.get_description(object),
+                    _ => String::new(),
+                })]
+            },
+            (IFACE_ACTION, "GetKeyBinding") => vec![Str(String::new())],
+            (IFACE_ACTION, "GetActions") => {
+                let description = self.get_description(object);
+                let actions = self.get_actions(object).iter().map(|a| Struct(vec![
+                    Str(a.get_name().to_string()),
+                    Str(if *a == AccessibilityAction::Default { description.clone() } else { String::new() }),
+                    Str(String::new()),
+                ])).collect();
+                vec![Array("(sss)".to_string(), actions)]
+            },
+            (IFACE_ACTION, "DoAction") => {
+                let action = self.get_actions(object).get(arg_i32(0)? as usize).copied();
+                match (object, action) {
+                    (AtSpiObject::Node(n), Some(action)) => {
+                        self.pending_actions.push(AccessibilityActionRequest {
+                            node: self.tree.nodes[n].dom_node_id,
+                            action,
+                        });
+                        vec![Bool(true)]
+                    },
+                    _ => vec![Bool(false)],
+                }
+            },
+
+            (IFACE_APPLICATION, "GetLocale") => vec![Str(get_locale())],
+            (IFACE_APPLICATION, "GetApplicationBusAddress") => vec![Str(String::new())],
+
+            (IFACE_TEXT, "GetText") => {
+                let text = self.get_text(object).unwrap_or_default();
+                let len = text.chars().count() as i32;
+                let start = arg_i32(0)?.max(0).min(len);
+                let end = match arg_i32(1)? { e if e < 0 => len, e => e.min(len) };
+                vec![Str(text.chars().skip(start as usize).take((end - start).max(0) as usize).collect())]
+            },
+            (IFACE_TEXT, "GetCharacterAtOffset") => {
+                let text = self.get_text(object).unwrap_or_default();
+                let c = text.chars().nth(arg_i32(0)?.max(0) as usize).map(|c| c as i32).unwrap_or(0);
+                vec![Int32(c)]
+            },
+
+            _ => return Err((ERROR_UNKNOWN_METHOD, format!("Unknown method {}.{}", interface, member))),
+        };
+
+        Ok(ret)
+    }
+
+    // -- events
+
+    fn emit_event(&self, object_path: &str, member: &str, kind: &str, detail1: i32, detail2: i32, data: DBusValue) {
+        use self::DBusValue::*;
+        let dbus = &self.dbus;
+        unsafe {
+            let signal = (dbus.dbus_message_new_signal)(
+                to_cstring(object_path).as_ptr(),
+                to_cstring(IFACE_EVENT_OBJECT).as_ptr(),
+                to_cstring(member).as_ptr(),
+            );
+            if signal.is_null() {
+                return;
+            }
+            let mut iter = DBusMessageIter::new();
+            (dbus.dbus_message_iter_init_append)(signal, &mut iter);
+            for a in [
+                Str(kind.to_string()),
+                Int32(detail1),
+                Int32(detail2),
+                Variant(Box::new(data)),
+                Array("{sv}".to_string(), Vec::new()),
+            ].iter() {
+                append_value(dbus, &mut iter, a);
+            }
+            (dbus.dbus_connection_send)(self.connection, signal, ptr::null_mut());
+            (dbus.dbus_message_unref)(signal);
+        }
+    }
+
+    fn emit_state_changes(&self, path: &str, old: &[u32], new: &[u32]) {
+        for (state, name) in ATSPI_STATE_NAMES.iter() {
+            match (old.contains(state), new.contains(state)) {
+                (false, true) => self.emit_event(path, "StateChanged", name, 1, 0, DBusValue::Int32(0)),
+                (true, false) => self.emit_event(path, "StateChanged", name, 0, 0, DBusValue::Int32(0)),
+                _ => { },
+            }
+        }
+    }
+
+    // compares the old and the new tree and emits ChildrenChanged,
+    // PropertyChange and StateChanged events for the differences
+    fn emit_changes(&self, old_window: &AccessibleWindow, old_tree: &AccessibilityTree) {
+
+        let old_nodes = old_tree.nodes.iter().enumerate()
+            .map(|(i, n)| (n.dom_node_id, i))
+            .collect::<BTreeMap<DomNodeId, usize>>();
+
+        let children_ids = |tree: &AccessibilityTree, node: Option<usize>| {
+            tree.get_children(node).iter().map(|c| tree.nodes[*c].dom_node_id).collect::<Vec<_>>()
+        };
+
+        let node_path = |id: DomNodeId| {
+            let node = id.node.into_crate_internal().map(|n| n.index()).unwrap_or(0);
+            format!("{}{}_{}", NODE_PATH_PREFIX, id.dom.inner, node)
+        };
+
+        let emit_children_changed = |path: &str, old: &[DomNodeId], new: &[DomNodeId]| {
+            for (i, removed) in old.iter().enumerate().filter(|(_, c)| !new.contains(c)) {
+                let child = DBusValue::object_ref(&self.bus_name, &node_path(*removed));
+                self.emit_event(path, "ChildrenChanged", "remove", i as i32, 0, child);
+            }
+            for (i, added) in new.iter().enumerate().filter(|(_, c)| !old.contains(c)) {
+                let child = DBusValue::object_ref(&self.bus_name, &node_path(*added));
+                self.emit_event(path, "ChildrenChanged", "add", i as i32, 0, child);
+            }
+        };
+
+        // window
+        if old_window.title != self.window.title {
+            self.emit_event(WINDOW_PATH, "PropertyChange", "accessible-name", 0, 0, DBusValue::Str(self.window.title.clone()));
+        }
+        if old_window.is_focused != self.window.is_focused {
+            self.emit_event(WINDOW_PATH, "StateChanged", "active", self.window.is_focused as i32, 0, DBusValue::Int32(0));
+        }
+        emit_children_changed(WINDOW_PATH, &children_ids(old_tree, None), &children_ids(&self.tree, None));
+
+        // nodes
+        for (index, node) in self.tree.nodes.iter().enumerate() {
+
+            let path = node_path(node.dom_node_id);
+            let new_states = translate_states(node);
+
+            let old_index = match old_nodes.get(&node.dom_node_id) {
+                Some(s) => *s,
+                None => {
+                    // new node: only announce the focus
+                    if node.has_state(AccessibilityState::Focused) {
+                        self.emit_state_changes(&path, &[], &[ATSPI_STATE_FOCUSED]);
+                    }
+                    continue;
+                },
+            };
+
+            let old_node = &old_tree.nodes[old_index];
+
+            if old_node.name != node.name {
+                let name = node.name.as_ref().map(|s| s.as_str().to_string()).unwrap_or_default();
+                self.emit_event(&path, "PropertyChange", "accessible-name", 0, 0, DBusValue::Str(name));
+            }
+
+            self.emit_state_changes(&path, &translate_states(old_node), &new_states);
+            emit_children_changed(&path, &children_ids(old_tree, Some(old_index)), &children_ids(&self.tree, Some(index)));
+        }
+    }
+}
+
+impl Drop for AtSpiBridge {
+    fn drop(&mut self) {
+        unsafe {
+            (self.dbus.dbus_connection_close)(self.connection);
+            (self.dbus.dbus_connection_unref)(self.connection);
+        }
+    }
+}
+
+fn get_locale() -> String {
+    std::env::var("LC_ALL").ok()
+    .or_else(|| std::env::var("LC_MESSAGES").ok())
+    .or_else(|| std::env::var("LANG").ok())
+    .filter(|s| !s.is_empty())
+    .unwrap_or_else(|| "C".to_string())
+}
+
+/// Asks the `org.a11y.Bus` service on the session bus for the address of the accessibility bus
+fn get_accessibility_bus_address(dbus: &DBus) -> Result<String, AtSpiError> {
+
+    let mut error = DBusError::new(dbus);
+
+    let session = unsafe { (dbus.dbus_bus_get_private)(DBUS_BUS_SESSION, &mut error) };
+    if let Some(e) = error.take(dbus) {
+        return Err(AtSpiError::Connect(e));
+    }
+    if session.is_null() {
+        return Err(AtSpiError::Connect(format!("could not connect to the session bus")));
+    }
+
+    let address = unsafe {
+        (dbus.dbus_connection_set_exit_on_disconnect)(session, 0);
+
+        let message = (dbus.dbus_message_new_method_call)(
+            to_cstring("org.a11y.Bus").as_ptr(),
+            to_cstring("/org/a11y/bus").as_ptr(),
+            to_cstring("org.a11y.Bus").as_ptr(),
+            to_cstring("GetAddress").as_ptr(),
+        );
+
+        let reply = if message.is_null() {
+            ptr::null_mut()
+        } else {
+            let reply = (dbus.dbus_connection_send_with_reply_and_block)(session, message, CALL_TIMEOUT_MS, &mut error);
+            (dbus.dbus_message_unref)(message);
+            reply
+        };
+
+        let address = if reply.is_null() {
+            None
+        } else {
+            let args = read_args(dbus, reply);
+            (dbus.dbus_message_unref)(reply);
+            args.first().and_then(|a| a.as_str()).map(|s| s.to_string())
+        };
+
+        (dbus.dbus_connection_close)(session);
+        (dbus.dbus_connection_unref)(session);
+
+        address
+    };
+
+    match (error.take(dbus), address) {
+        (Some(e), _) => Err(AtSpiError::Connect(e)),
+        (None, Some(a)) if !a.is_empty() => Ok(a),
+        _ => Err(AtSpiError::Connect(format!("org.a11y.Bus.GetAddress returned no address"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use azul_core::id_tree::NodeId;
+    use azul_core::window::LogicalRect;
+    use std::io::{BufRead, BufReader, Read};
+    use std::process::{Child, Command, Stdio};
+
+    // private session bus, killed when the test ends
+    struct TestBus {
+        daemon: Child,
+        address: String,
+    }
+
+    impl TestBus {
+        fn start() -> Option<Self> {
+            let mut daemon = Command::new("dbus-daemon")
+                .args(&["--session", "--nofork", "--print-address=1"])
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .ok()?;
+            let mut address = String::new();
+            BufReader::new(daemon.stdout.as_mut()?).read_line(&mut address).ok()?;
+            Some(Self { daemon, address: address.trim().to_string() })
+        }
+    }
+
+    impl Drop for TestBus {
+        fn drop(&mut self) {
+            let _ = self.daemon.kill();
+            let _ = self.daemon.wait();
+        }
+    }
+
+    fn node_id(node: usize) -> DomNodeId {
+        DomNodeId {
+            dom: DomId { inner: 0 },
+            node: NodeHierarchyItemId::from_crate_internal(Some(NodeId::new(node))),
+        }
+    }
+
+    fn node(node: usize, parent: Option<usize>, children: Vec<usize>, role: AccessibilityRole, name: &str) -> AccessibilityNode {
+        AccessibilityNode {
+            dom_node_id: node_id(node),
+            parent,
+            children,
+            role,
+            name: Some(name.to_string().into()),
+            value: None,
+            description: None,
+            states: Vec::new(),
+            actions: Vec::new(),
+            bounds: LogicalRect::new(LogicalPosition::new(0.0, 0.0), LogicalSize::new(100.0, 20.0)),
+        }
+    }
+
+    // window > panel > [button "OK", check box "Remember me"]
+    fn test_tree() -> AccessibilityTree {
+        let mut button = node(2, Some(0), Vec::new(), AccessibilityRole::PushButton, "OK");
+        button.actions = vec![AccessibilityAction::Default, AccessibilityAction::Focus];
+        button.states = vec![AccessibilityState::Focusable];
+        let mut check_box = node(3, Some(0), Vec::new(), AccessibilityRole::CheckButton, "Remember me");
+        check_box.states = vec![AccessibilityState::Checked];
+        check_box.bounds.origin.y = 20.0;
+        AccessibilityTree {
+            nodes: vec![
+                node(1, None, vec![1, 2], AccessibilityRole::Grouping, ""),
+                button,
+                check_box,
+            ],
+            root_children: vec![0],
+        }
+    }
+
+    // runs dbus-send against the bridge, answering requests until dbus-send exits
+    fn call(bus: &TestBus, bridge: &mut AtSpiBridge, path: &str, method: &str, args: &[&str]) -> (String, Vec<AccessibilityActionRequest>) {
+        let mut child = Command::new("dbus-send")
+            .arg(format!("--bus={}", bus.address))
+            .arg("--print-reply")
+            .arg(format!("--dest={}", bridge.get_bus_name()))
+            .arg(path)
+            .arg(method)
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut requests = Vec::new();
+        while child.try_wait().unwrap().is_none() {
+            requests.extend(bridge.dispatch(10));
+        }
+
+        let mut output = String::new();
+        child.stdout.take().unwrap().read_to_string(&mut output).unwrap();
+        child.stderr.take().unwrap().read_to_string(&mut output).unwrap();
+        (output, requests)
+    }
+
+    fn setup() -> Option<(TestBus, AtSpiBridge)> {
+        let bus = TestBus::start()?;
+        let mut bridge = AtSpiBridge::connect_to_address(&bus.address, "test").ok()?;
+        let window = AccessibleWindow { title: "Test window".to_string(), .. Default::default() };
+        bridge.publish(window, test_tree());
+        Some((bus, bridge))
+    }
+
+    #[test]
+    fn test_atspi_tree() {
+
+        let (bus, mut bridge) = match setup() {
+            Some(s) => s,
+            None => return, // no dbus-daemon or libdbus installed
+        };
+
+        let (out, _) = call(&bus, &mut bridge, ROOT_PATH, "org.a11y.atspi.Accessible.GetChildren", &[]);
+        assert!(out.contains(WINDOW_PATH), "{}", out);
+
+        let (out, _) = call(&bus, &mut bridge, WINDOW_PATH, "org.freedesktop.DBus.Properties.Get", &["string:org.a11y.atspi.Accessible", "string:Name"]);
+        assert!(out.contains("\"Test window\""), "{}", out);
+
+        let (out, _) = call(&bus, &mut bridge, "/org/a11y/atspi/accessible/0_1", "org.a11y.atspi.Accessible.GetChildren", &[]);
+        assert!(out.contains("/org/a11y/atspi/accessible/0_2"), "{}", out);
+        assert!(out.contains("/org/a11y/atspi/accessible/0_3"), "{}", out);
+
+        let (out, _) = call(&bus, &mut bridge, "/org/a11y/atspi/accessible/0_2", "org.a11y.atspi.Accessible.GetRole", &[]);
+        assert!(out.contains("uint32 43"), "{}", out);
+
+        let (out, _) = call(&bus, &mut bridge, "/org/a11y/atspi/accessible/0_3", "org.a11y.atspi.Accessible.GetIndexInParent", &[]);
+        assert!(out.contains("int32 1"), "{}", out);
+
+        // CHECKED (4) | CHECKABLE (41)
+        let (out, _) = call(&bus, &mut bridge, "/org/a11y/atspi/accessible/0_3", "org.a11y.atspi.Accessible.GetState", &[]);
+        let checked = 1 << ATSPI_STATE_CHECKED;
+        let checkable = 1 << (ATSPI_STATE_CHECKABLE - 32);
+        assert!(out.split_whitespace().any(|w| w.parse::<u32>().map(|u| u & checked != 0).unwrap_or(false)), "{}", out);
+        assert!(out.split_whitespace().any(|w| w.parse::<u32>().map(|u| u & checkable != 0).unwrap_or(false)), "{}", out);
+
+        let (out, _) = call(&bus, &mut bridge, "/org/a11y/atspi/accessible/0_9", "org.a11y.atspi.Accessible.GetRole", &[]);
+        assert!(out.contains("UnknownObject"), "{}", out);
+    }
+
+    #[test]
+    fn test_atspi_actions() {
+
+        let (bus, mut bridge) = match setup() {
+            Some(s) => s,
+            None => return, // no dbus-daemon or libdbus installed
+        };
+
+        let (out, requests) = call(&bus, &mut bridge, "/org/a11y/atspi/accessible/0_2", "org.a11y.atspi.Action.GetName", &["int32:0"]);
+        assert!(out.contains("\"click\""), "{}", out);
+        assert!(requests.is_empty());
+
+        let (out, requests) = call(&bus, &mut bridge, "/org/a11y/atspi/accessible/0_2", "org.a11y.atspi.Action.DoAction", &["int32:0"]);
+        assert!(out.contains("boolean true"), "{}", out);
+        assert_eq!(requests, vec![AccessibilityActionRequest { node: node_id(2), action: AccessibilityAction::Default }]);
+
+        let (out, requests) = call(&bus, &mut bridge, "/org/a11y/atspi/accessible/0_2", "org.a11y.atspi.Component.GrabFocus", &[]);
+        assert!(out.contains("boolean true"), "{}", out);
+        assert_eq!(requests, vec![AccessibilityActionRequest { node: node_id(2), action: AccessibilityAction::Focus }]);
+
+        // check box has no actions
+        let (out, requests) = call(&bus, &mut bridge, "/org/a11y/atspi/accessible/0_3", "org.a11y.atspi.Action.DoAction", &["int32:0"]);
+        assert!(out.contains("UnknownMethod"), "{}", out);
+        assert!(requests.is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+//! Translation of XKB keysyms, shared between the X11 and the Wayland shell
+
+use azul_core::window::VirtualKeyCode;
+
+/// Translates an XKB keysym (see `xkbcommon-keysyms.h`) to a `VirtualKeyCode`
+pub(crate) fn translate_keysym(keysym: u32) -> Option<VirtualKeyCode> {
+
+    use azul_core::window::VirtualKeyCode::*;
+
+    const DIGITS: [VirtualKeyCode; 10] = [Key0, Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9];
+    const LETTERS: [VirtualKeyCode; 26] = [A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z];
+    const FUNCTION_KEYS: [VirtualKeyCode; 24] = [
+        F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
+        F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24,
+    ];
+    const NUMPAD: [VirtualKeyCode; 10] = [
+        Numpad0, Numpad1, Numpad2, Numpad3, Numpad4,
+        Numpad5, Numpad6, Numpad7, Numpad8, Numpad9,
+    ];
+
+    let vk = match keysym {
+        0x0030..=0x0039 => DIGITS[(keysym - 0x0030) as usize],
+        0x0041..=0x005a => LETTERS[(keysym - 0x0041) as usize],
+        0x0061..=0x007a => LETTERS[(keysym - 0x0061) as usize],
+        0xffbe..=0xffd5 => FUNCTION_KEYS[(keysym - 0xffbe) as usize],
+        0xffb0..=0xffb9 => NUMPAD[(keysym - 0xffb0) as usize],
+
+        0x0020 => Space,
+        0x0027 => Apostrophe,
+        0x002a => Asterisk,
+        0x002b => Plus,
+        0x002c => Comma,
+        0x002d => Minus,
+        0x002e => Period,
+        0x002f => Slash,
+        0x003a => Colon,
+        0x003b => Semicolon,
+        0x003d => Equals,
+        0x0040 => At,
+        0x005b => LBracket,
+        0x005c => Backslash,
+        0x005d => RBracket,
+        0x005e => Caret,
+        0x005f => Underline,
+        0x0060 => Grave,
+        0x00a5 => Yen,
+
+        0xfe20 => Tab, // ISO_Left_Tab (Shift + Tab)
+        0xff08 => Back,
+        0xff09 => Tab,
+        0xff0d => Return,
+        0xff13 => Pause,
+        0xff14 => Scroll,
+        0xff15 => Sysrq,
+        0xff1b => Escape,
+        0xff20 => Compose,
+        0xff21 => Kanji,
+        0xff22 => NoConvert,
+        0xff23 => Convert,
+        0xff27 => Kana,
+        0xff50 => Home,
+        0xff51 => Left,
+        0xff52 => Up,
+        0xff53 => Right,
+        0xff54 => Down,
+        0xff55 => PageUp,
+        0xff56 => PageDown,
+        0xff57 => End,
+        0xff61 => Snapshot,
+        0xff63 => Insert,
+        0xff67 => Apps,
+        0xff7f => Numlock,
+
+        0xff8d => NumpadEnter,
+        0xff95 => Home,
+        0xff96 => Left,
+        0xff97 => Up,
+        0xff98 => Right,
+        0xff99 => Down,
+        0xff9a => PageUp,
+        0xff9b => PageDown,
+        0xff9c => End,
+        0xff9e => Insert,
+        0xff9f => Delete,
+        0xffaa => NumpadMultiply,
+        0xffab => NumpadAdd,
+        0xffac => NumpadComma,
+        0xffad => NumpadSubtract,
+        0xffae => NumpadDecimal,
+        0xffaf => NumpadDivide,
+        0xffbd => NumpadEquals,
+
+        0xffe1 => LShift,
+        0xffe2 => RShift,
+        0xffe3 => LControl,
+        0xffe4 => RControl,
+        0xffe5 => Capital,
+        0xffe9 => LAlt,
+        0xffea => RAlt,
+        0xffeb => LWin,
+        0xffec => RWin,
+        0xffff => Delete,
+
+        0x1008ff11 => VolumeDown,
+        0x1008ff12 => Mute,
+        0x1008ff13 => VolumeUp,
+        0x1008ff14 => PlayPause,
+        0x1008ff15 => MediaStop,
+        0x1008ff16 => PrevTrack,
+        0x1008ff17 => NextTrack,
+        0x1008ff18 => WebHome,
+        0x1008ff19 => Mail,
+        0x1008ff1b => WebSearch,
+        0x1008ff1d => Calculator,
+        0x1008ff26 => WebBack,
+        0x1008ff27 => WebForward,
+        0x1008ff28 => WebStop,
+        0x1008ff29 => WebRefresh,
+        0x1008ff2a => Power,
+        0x1008ff2b => Wake,
+        0x1008ff2f => Sleep,
+        0x1008ff30 => WebFavorites,
+        0x1008ff33 => MyComputer,
+        0x1008ff57 => Copy,
+        0x1008ff58 => Cut,
+        0x1008ff6d => Paste,
+
+        _ => return None,
+    };
+
+    Some(vk)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn translate_keysyms() {
+        assert_eq!(translate_keysym(0x0061), Some(VirtualKeyCode::A));
+        assert_eq!(translate_keysym(0x005a), Some(VirtualKeyCode::Z));
+        assert_eq!(translate_keysym(0x0030), Some(VirtualKeyCode::Key0));
+        assert_eq!(translate_keysym(0x0039), Some(VirtualKeyCode::Key9));
+        assert_eq!(translate_keysym(0xffbe), Some(VirtualKeyCode::F1));
+        assert_eq!(translate_keysym(0xffd5), Some(VirtualKeyCode::F24));
+        assert_eq!(translate_keysym(0xffb5), Some(VirtualKeyCode::Numpad5));
+        assert_eq!(translate_keysym(0xff0d), Some(VirtualKeyCode::Return));
+        assert_eq!(translate_keysym(0xff51), Some(VirtualKeyCode::Left));
+        assert_eq!(translate_keysym(0xffe1), Some(VirtualKeyCode::LShift));
+        assert_eq!(translate_keysym(0x1008ff57), Some(VirtualKeyCode::Copy));
+        assert_eq!(translate_keysym(0x00e4), None); // adiaeresis: only a character
+    }
+}
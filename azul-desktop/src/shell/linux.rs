@@ -0,0 +1,536 @@
+//! Window code that is shared between the X11 and the Wayland shell
+
+use crate::{
+    shell::x11::ApplicationData,
+    wr_translate::{
+        rebuild_display_list,
+        generate_frame,
+        AsyncHitTester,
+    },
+};
+use alloc::collections::BTreeMap;
+use azul_core::{
+    FastBTreeSet, FastHashMap,
+    app_resources::{ImageCache, ResourceUpdate},
+    callbacks::OptionCallback,
+    gl::OptionGlContextPtr,
+    task::{Thread, ThreadId, Timer, TimerId},
+    window::{
+        CallCallbacksResult, RawWindowHandle, WindowCreateOptions,
+        WindowInternal, WindowState,
+    },
+    window_state::{NodesToCheck, StyleAndLayoutChanges},
+};
+use std::os::raw::{c_int, c_short, c_ulong};
+use std::time::{Duration as StdDuration, Instant as StdInstant};
+use webrender::render_api::RenderApi as WrRenderApi;
+
+// How often the running threads are checked for new messages (same as the thread timer on Win32)
+const THREAD_TICK_MS: u64 = 16;
+
+extern { // syscalls
+    pub(crate) fn poll(fds: *mut PollFd, nfds: c_ulong, timeout: c_int) -> c_int;
+}
+
+#[repr(C)]
+pub(crate) struct PollFd {
+    pub fd: c_int,
+    pub events: c_short,
+    pub revents: c_short,
+}
+
+pub(crate) const POLLIN: c_short = 0x001;
+
+/// When the timers and threads of a window have to run next - the event loop
+/// wakes up for the earliest tick (same as the `SetTimer` timers on Win32)
+#[derive(Debug, Default)]
+pub(crate) struct LinuxTimers {
+    /// Timers tick every `Timer::tick_millis()`, `Timer::invoke()`
+    /// decides whether the callback of the timer is invoked
+    timers: BTreeMap<TimerId, StdInstant>,
+    /// `None` if no thread is running
+    threads: Option<StdInstant>,
+}
+
+impl LinuxTimers {
+    /// Returns when the next timer or thread tick is due
+    pub(crate) fn get_next_tick(&self) -> Option<StdInstant> {
+        self.timers.values().chain(self.threads.iter()).min().copied()
+    }
+}
+
+/// Returns how long the event loop can wait for new events (in milliseconds, -1 = forever):
+/// at most `max_timeout_ms`, but only until the `next_tick` of a timer or thread
+pub(crate) fn get_poll_timeout_ms(max_timeout_ms: c_int, next_tick: Option<StdInstant>) -> c_int {
+
+    let next_tick = match next_tick {
+        Some(s) => s,
+        None => return max_timeout_ms,
+    };
+
+    // rounded up, so that the tick is due when the event loop wakes up
+    let until_next_tick = next_tick.saturating_duration_since(StdInstant::now());
+    let until_next_tick_ms = ((until_next_tick.as_micros() + 999) / 1000).min(c_int::MAX as u128) as c_int;
+
+    if max_timeout_ms < 0 {
+        until_next_tick_ms
+    } else {
+        until_next_tick_ms.min(max_timeout_ms)
+    }
+}
+
+/// Parts of a window that the shared window code has to borrow at the same time
+pub(crate) struct LinuxWindowParts<'a> {
+    pub internal: &'a mut WindowInternal,
+    pub gl_context_ptr: &'a OptionGlContextPtr,
+    pub render_api: &'a mut WrRenderApi,
+    pub hit_tester: &'a mut AsyncHitTester,
+    pub timers: &'a mut LinuxTimers,
+    pub windows_created: &'a mut Vec<WindowCreateOptions>,
+}
+
+/// Window of the X11 or the Wayland shell
+pub(crate) trait LinuxWindow {
+
+    /// Makes the OpenGL context of the window the current context
+    fn make_current(&self);
+
+    /// Returns the parts of the window that the shared window code operates on
+    fn parts(&mut self) -> LinuxWindowParts<'_>;
+
+    /// Returns the handle of the window that is passed to the callbacks
+    fn get_raw_window_handle(&self) -> RawWindowHandle;
+
+    /// Takes over the window state that the callbacks modified,
+    /// by default the modified window state is ignored
+    fn apply_modified_window_state(&mut self, _modified_window_state: &WindowState) { }
+
+    /// Restyles / relayouts the window after callbacks have been invoked,
+    /// returns whether the window needs to be re-rendered
+    fn process_callback_results(
+        &mut self,
+        callback_results: CallCallbacksResult,
+        nodes_to_check: &NodesToCheck,
+        appdata: &mut ApplicationData,
+    ) -> bool {
+
+        use azul_core::callbacks::Update;
+
+        if let Some(modified_window_state) = callback_results.modified_window_state.as_ref() {
+            // the window is closed by the event loop
+            if modified_window_state.flags.is_about_to_close {
+                self.parts().internal.current_window_state.flags.is_about_to_close = true;
+            }
+            self.apply_modified_window_state(modified_window_state);
+        }
+
+        // the new windows are created by the event loop, after the events of all windows are processed
+        self.parts().windows_created.extend(callback_results.windows_created.into_iter());
+
+        self.start_stop_timers(
+            callback_results.timers.unwrap_or_default(),
+            callback_results.timers_removed.unwrap_or_default()
+        );
+        self.start_stop_threads(
+            callback_results.threads.unwrap_or_default(),
+            callback_results.threads_removed.unwrap_or_default()
+        );
+
+        match callback_results.callbacks_update_screen {
+            Update::RefreshDom | Update::RefreshDomAllWindows => {
+                self.regenerate_dom(appdata);
+                return true;
+            },
+            Update::DoNothing => { },
+        }
+
+        let window = self.parts();
+
+        let style_layout_changes = StyleAndLayoutChanges::new(
+            nodes_to_check,
+            &mut window.internal.layout_results,
+            &appdata.image_cache,
+            &mut window.internal.renderer_resources,
+            &window.internal.current_window_state.size,
+            window.internal.current_window_state.theme,
+            &window.internal.document_id,
+            callback_results.css_properties_changed.as_ref(),
+            callback_results.words_changed.as_ref(),
+            &callback_results.update_focused_node,
+            &mut window.internal.animations,
+            azul_layout::do_the_relayout,
+        );
+
+        self.apply_style_layout_changes(&style_layout_changes, appdata)
+    }
+
+    /// Advances the running CSS transitions and animations by one frame,
+    /// returns whether the window needs to be re-rendered
+    fn run_animation_frame(&mut self, appdata: &mut ApplicationData) -> bool {
+
+        let window = self.parts();
+
+        let nodes_to_check = NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        );
+
+        let style_layout_changes = StyleAndLayoutChanges::new(
+            &nodes_to_check,
+            &mut window.internal.layout_results,
+            &appdata.image_cache,
+            &mut window.internal.renderer_resources,
+            &window.internal.current_window_state.size,
+            window.internal.current_window_state.theme,
+            &window.internal.document_id,
+            None,
+            None,
+            &None,
+            &mut window.internal.animations,
+            azul_layout::do_the_relayout,
+        );
+
+        self.apply_style_layout_changes(&style_layout_changes, appdata)
+    }
+
+    /// Updates the focus and the display list after a restyle,
+    /// returns whether the window needs to be re-rendered
+    fn apply_style_layout_changes(
+        &mut self,
+        style_layout_changes: &StyleAndLayoutChanges,
+        appdata: &mut ApplicationData,
+    ) -> bool {
+
+        let window = self.parts();
+
+        if let Some(focus_change) = style_layout_changes.focus_change.clone() {
+            window.internal.current_window_state.focused_node = focus_change.new;
+        }
+
+        if style_layout_changes.did_resize_nodes() || style_layout_changes.need_regenerate_display_list() {
+            self.rebuild_display_list(&appdata.image_cache, Vec::new());
+            true
+        } else if style_layout_changes.need_redraw() {
+            generate_frame(window.internal, window.render_api, false);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Calls the layout callback again and rebuilds the display list
+    fn regenerate_dom(&mut self, appdata: &mut ApplicationData) {
+
+        self.make_current();
+
+        let LinuxWindowParts { internal, gl_context_ptr, hit_tester, .. } = self.parts();
+        let document_id = internal.document_id;
+        let data = &mut appdata.data;
+        let image_cache = &appdata.image_cache;
+
+        // unset the focus
+        internal.current_window_state.focused_node = None;
+
+        let mut resource_updates = Vec::new();
+        appdata.fc_cache.apply_closure(|fc_cache| {
+            internal.regenerate_styled_dom(
+                data,
+                image_cache,
+                gl_context_ptr,
+                &mut resource_updates,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    crate::wr_translate::fullhittest_new_webrender(
+                        &*hit_tester.resolve(),
+                        document_id,
+                        window_state.focused_node,
+                        layout_results,
+                        &window_state.mouse_state.cursor_position,
+                        window_state.size.hidpi_factor,
+                    )
+                }
+            );
+        });
+
+        // stop timers that have a DomNodeId attached to them
+        self.stop_timers_with_node_ids();
+
+        self.rebuild_display_list(&appdata.image_cache, resource_updates);
+
+        // the DOM is now mounted: let the nodes start their timers, etc.
+        self.run_after_mount(appdata);
+    }
+
+    /// Rebuilds the display list and requests a new hit-tester for it
+    fn rebuild_display_list(&mut self, image_cache: &ImageCache, resource_updates: Vec<ResourceUpdate>) {
+
+        use crate::wr_translate::wr_translate_document_id;
+
+        let window = self.parts();
+
+        rebuild_display_list(
+            window.internal,
+            window.render_api,
+            image_cache,
+            resource_updates,
+        );
+
+        window.render_api.flush_scene_builder();
+
+        generate_frame(
+            window.internal,
+            window.render_api,
+            true,
+        );
+
+        window.render_api.flush_scene_builder();
+
+        let wr_document_id = wr_translate_document_id(window.internal.document_id);
+        *window.hit_tester = AsyncHitTester::Requested(window.render_api.request_hit_tester(wr_document_id));
+    }
+
+    fn start_stop_timers(
+        &mut self,
+        added: FastHashMap<TimerId, Timer>,
+        removed: FastBTreeSet<TimerId>
+    ) {
+
+        let window = self.parts();
+        let now = StdInstant::now();
+
+        for (id, timer) in added {
+            window.timers.timers.insert(id, now + StdDuration::from_millis(timer.tick_millis()));
+            window.internal.timers.insert(id, timer);
+        }
+
+        for id in removed {
+            window.internal.timers.remove(&id);
+            window.timers.timers.remove(&id);
+        }
+    }
+
+    fn start_stop_threads(
+        &mut self,
+        mut added: FastHashMap<ThreadId, Thread>,
+        removed: FastBTreeSet<ThreadId>
+    ) {
+
+        let window = self.parts();
+
+        window.internal.threads.append(&mut added);
+        window.internal.threads.retain(|r, _| !removed.contains(r));
+
+        if window.internal.threads.is_empty() {
+            window.timers.threads = None;
+        } else if window.timers.threads.is_none() {
+            window.timers.threads = Some(StdInstant::now() + StdDuration::from_millis(THREAD_TICK_MS));
+        }
+    }
+
+    // Stop all timers that have a NodeId attached to them because in the next
+    // frame the NodeId would be invalid, leading to crashes / panics
+    fn stop_timers_with_node_ids(&mut self) {
+
+        let timers_to_remove = self.parts().internal.timers
+        .iter()
+        .filter_map(|(id, timer)| timer.node_id.as_ref().map(|_| *id))
+        .collect();
+
+        self.start_stop_timers(FastHashMap::default(), timers_to_remove);
+    }
+
+    /// Invokes the timers and checks the threads whose tick is due,
+    /// returns whether the window needs to be re-rendered
+    fn run_timers_and_threads(&mut self, appdata: &mut ApplicationData) -> bool {
+
+        let now = StdInstant::now();
+        let window_handle = self.get_raw_window_handle();
+        let mut should_render = false;
+
+        let due_timers = self.parts().timers.timers
+        .iter()
+        .filter(|(_, tick)| **tick <= now)
+        .map(|(id, _)| *id)
+        .collect::<Vec<_>>();
+
+        for timer_id in due_timers {
+
+            let window = self.parts();
+
+            // the timer may have been stopped by a timer that ran before
+            let tick_millis = match window.internal.timers.get(&timer_id) {
+                Some(s) => s.tick_millis(),
+                None => continue,
+            };
+            window.timers.timers.insert(timer_id, now + StdDuration::from_millis(tick_millis));
+
+            let nodes_to_check = NodesToCheck::empty(
+                window.internal.current_window_state.mouse_state.mouse_down(),
+                window.internal.current_window_state.focused_node,
+            );
+
+            let internal = window.internal;
+            let gl_context_ptr = window.gl_context_ptr;
+            let image_cache = &mut appdata.image_cache;
+            let system_callbacks = &appdata.config.system_callbacks;
+
+            let callback_results = appdata.fc_cache.apply_closure(|fc_cache| {
+                let frame_start = (system_callbacks.get_system_time_fn.cb)();
+                internal.run_single_timer(
+                    timer_id.id,
+                    frame_start,
+                    &window_handle,
+                    gl_context_ptr,
+                    image_cache,
+                    fc_cache,
+                    system_callbacks,
+                )
+            });
+
+            should_render = self.process_callback_results(callback_results, &nodes_to_check, appdata) || should_render;
+        }
+
+        let window = self.parts();
+
+        if window.timers.threads.map(|tick| tick <= now) != Some(true) {
+            return should_render;
+        }
+
+        window.timers.threads = Some(now + StdDuration::from_millis(THREAD_TICK_MS));
+
+        let nodes_to_check = NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        );
+
+        let internal = window.internal;
+        let gl_context_ptr = window.gl_context_ptr;
+        let data = &mut appdata.data;
+        let image_cache = &mut appdata.image_cache;
+        let system_callbacks = &appdata.config.system_callbacks;
+
+        let callback_results = appdata.fc_cache.apply_closure(|fc_cache| {
+            internal.run_all_threads(
+                data,
+                &window_handle,
+                gl_context_ptr,
+                image_cache,
+                fc_cache,
+                system_callbacks,
+            )
+        });
+
+        self.process_callback_results(callback_results, &nodes_to_check, appdata) || should_render
+    }
+
+    /// Invokes the `AfterMount` callbacks of the initial DOM and the create callback
+    /// (`WindowCreateOptions::create_callback`) of a window that was just created,
+    /// returns whether the window needs to be re-rendered
+    fn run_window_created(&mut self, create_callback: &mut OptionCallback, appdata: &mut ApplicationData) -> bool {
+
+        let should_render = self.run_after_mount(appdata);
+
+        let create_callback = match create_callback.as_mut() {
+            Some(s) => s,
+            None => return should_render,
+        };
+
+        let window_handle = self.get_raw_window_handle();
+        let window = self.parts();
+
+        let nodes_to_check = NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        );
+
+        let internal = window.internal;
+        let gl_context_ptr = window.gl_context_ptr;
+        let data = &mut appdata.data;
+        let image_cache = &mut appdata.image_cache;
+        let system_callbacks = &appdata.config.system_callbacks;
+
+        let callback_results = appdata.fc_cache.apply_closure(|fc_cache| {
+            internal.invoke_single_callback(
+                create_callback,
+                data,
+                &window_handle,
+                gl_context_ptr,
+                image_cache,
+                fc_cache,
+                system_callbacks,
+            )
+        });
+
+        self.process_callback_results(callback_results, &nodes_to_check, appdata) || should_render
+    }
+
+    /// Invokes the `AfterMount` callbacks of the current DOM,
+    /// returns whether the window needs to be re-rendered
+    fn run_after_mount(&mut self, appdata: &mut ApplicationData) -> bool {
+
+        let window_handle = self.get_raw_window_handle();
+        let window = self.parts();
+
+        let nodes_to_check = NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        );
+
+        let internal = window.internal;
+        let gl_context_ptr = window.gl_context_ptr;
+        let image_cache = &mut appdata.image_cache;
+        let system_callbacks = &appdata.config.system_callbacks;
+
+        let callback_results = appdata.fc_cache.apply_closure(|fc_cache| {
+            internal.invoke_after_mount_callbacks(
+                &window_handle,
+                gl_context_ptr,
+                image_cache,
+                fc_cache,
+                system_callbacks,
+            )
+        });
+
+        self.process_callback_results(callback_results, &nodes_to_check, appdata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn poll_timeout_waits_until_the_next_tick() {
+
+        let now = StdInstant::now();
+
+        assert_eq!(get_poll_timeout_ms(-1, None), -1);
+        assert_eq!(get_poll_timeout_ms(16, None), 16);
+
+        // ticks in the past are due immediately
+        assert_eq!(get_poll_timeout_ms(-1, Some(now)), 0);
+        assert_eq!(get_poll_timeout_ms(16, Some(now)), 0);
+
+        let timeout_ms = get_poll_timeout_ms(-1, Some(now + StdDuration::from_millis(500)));
+        assert!(timeout_ms > 400 && timeout_ms <= 500, "{}", timeout_ms);
+        assert_eq!(get_poll_timeout_ms(16, Some(now + StdDuration::from_millis(500))), 16);
+    }
+
+    #[test]
+    fn next_tick_is_the_earliest_timer_or_thread_tick() {
+
+        let now = StdInstant::now();
+        let mut timers = LinuxTimers::default();
+        assert_eq!(timers.get_next_tick(), None);
+
+        timers.timers.insert(TimerId { id: 1 }, now + StdDuration::from_millis(100));
+        timers.timers.insert(TimerId { id: 2 }, now + StdDuration::from_millis(50));
+        assert_eq!(timers.get_next_tick(), Some(now + StdDuration::from_millis(50)));
+
+        timers.threads = Some(now + StdDuration::from_millis(16));
+        assert_eq!(timers.get_next_tick(), Some(now + StdDuration::from_millis(16)));
+    }
+}
@@ -2,5 +2,13 @@
 pub mod win32;
 #[cfg(target_os = "linux")]
 pub mod x11;
+#[cfg(target_os = "linux")]
+pub mod wayland;
+#[cfg(target_os = "linux")]
+pub mod linux;
+#[cfg(target_os = "linux")]
+pub mod keysym;
+#[cfg(target_os = "linux")]
+pub mod atspi;
 #[cfg(target_os = "macos")]
 pub mod cocoa;
\ No newline at end of file
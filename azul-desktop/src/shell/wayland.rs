@@ -0,0 +1,3176 @@
+//! Native Wayland shell: xdg-shell toplevel windows rendered with EGL, used
+//! instead of the X11 shell if `LinuxWindowOptions.backend` is `Wayland`
+//! (or `Auto` and a Wayland compositor is running).
+//!
+//! `libwayland-client.so.0`, `libwayland-egl.so.1`, `libEGL.so` and
+//! `libxkbcommon.so.0` are loaded at runtime (same as Xlib), the interface
+//! tables of the extension protocols (xdg-shell, viewporter, fractional-scale-v1,
+//! cursor-shape-v1) are defined in this file, so no generated code is needed.
+//!
+//! Protocol listeners only push a `WaylandEvent` into the `EventQueue` of the
+//! connection, the events are handled in the main loop after dispatching:
+//!
+//! - ping, seat capabilities, keymap and clipboard events are handled by the `WaylandDisplay`
+//! - configure / close / scale events go to the window owning the xdg_surface
+//!
+//! The compositor doesn't tell clients where their windows are: the position of a toplevel
+//! window is reported as (0, 0), so that positions are relative to the toplevel window.
+//! Undecorated windows that a callback creates at a position (i.e. tooltips and dropdown
+//! lists) are xdg_popups of the window that created them.
+//! - pointer and keyboard events go to the window that has the pointer or keyboard focus
+//!   and are then processed like on Win32 (hit-test, `Events::new`, callbacks, restyle)
+
+use crate::{
+    app::{App, LazyFcCache},
+    shell::{
+        atspi::{AtSpiBridge, AccessibleWindow},
+        keysym::translate_keysym,
+        linux::{LinuxWindow, LinuxWindowParts, LinuxTimers, PollFd, POLLIN, poll, get_poll_timeout_ms},
+        x11::{
+            Library, Egl, GlFunctions, Notifier,
+            LinuxStartupError, LinuxWindowCreateError,
+            SharedApplicationData, ApplicationData,
+            EGLConfig, EGLContext, EGLDisplay, EGLSurface, EGLint,
+            EGL_NO_DISPLAY, EGL_OPENGL_API, EGL_SURFACE_TYPE, EGL_WINDOW_BIT,
+            EGL_CONFORMANT, EGL_OPENGL_BIT, EGL_RENDERABLE_TYPE, EGL_COLOR_BUFFER_TYPE,
+            EGL_RGB_BUFFER, EGL_BLUE_SIZE, EGL_GREEN_SIZE, EGL_RED_SIZE, EGL_DEPTH_SIZE,
+            EGL_STENCIL_SIZE, EGL_NONE, EGL_GL_COLORSPACE, EGL_GL_COLORSPACE_LINEAR,
+            EGL_RENDER_BUFFER, EGL_BACK_BUFFER, EGL_NO_SURFACE, EGL_NO_CONTEXT, EGL_TRUE,
+            EGL_CONTEXT_MAJOR_VERSION, EGL_CONTEXT_MINOR_VERSION,
+            EGL_CONTEXT_OPENGL_PROFILE_MASK, EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT,
+            WR_SHADER_CACHE, display_egl_status,
+        },
+    },
+    wr_translate::{
+        rebuild_display_list,
+        generate_frame,
+        wr_synchronize_updated_images,
+        AsyncHitTester,
+    },
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+};
+use azul_core::{
+    gl::OptionGlContextPtr,
+    window::{
+        FullWindowState, LogicalSize, MouseCursorType, PhysicalPositionI32, RawWindowHandle,
+        WaylandHandle, VirtualKeyCode, WindowCreateOptions, WindowFrame, WindowInternal,
+        WindowPosition, WindowSize,
+    },
+    window_state::NodesToCheck,
+    accessibility::AccessibilityTree,
+};
+use azul_css::OptionAzString;
+use core::{cell::RefCell, fmt, mem, ptr};
+use gl_context_loader::gl;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use webrender::{
+    api::units::{
+        DeviceIntRect as WrDeviceIntRect,
+        DeviceIntSize as WrDeviceIntSize,
+    },
+    render_api::RenderApi as WrRenderApi,
+    Renderer as WrRenderer, RendererOptions as WrRendererOptions,
+    ShaderPrecacheFlags as WrShaderPrecacheFlags, Transaction as WrTransaction,
+};
+
+// How long to wait for Wayland events before answering the requests of the screen reader
+const ACCESSIBILITY_POLL_TIMEOUT_MS: i32 = 16;
+
+// How long to wait for Wayland events before advancing the running CSS animations
+const ANIMATION_FRAME_TIME_MS: i32 = 16;
+
+// wp_fractional_scale_v1.preferred_scale is sent as a fraction of 120
+const FRACTIONAL_SCALE_DENOMINATOR: f32 = 120.0;
+
+/// MIME types that are offered / accepted for text in the clipboard, in order of preference
+const TEXT_MIME_TYPES: &[&str] = &[
+    "text/plain;charset=utf-8",
+    "UTF8_STRING",
+    "text/plain",
+    "STRING",
+    "TEXT",
+];
+
+// ---- libwayland-client FFI
+
+pub(crate) enum WlProxy {}
+type WlDisplay = WlProxy;
+
+#[repr(C)]
+pub(crate) struct WlMessage {
+    name: *const c_char,
+    signature: *const c_char,
+    types: *const *const WlInterface,
+}
+
+#[repr(C)]
+pub(crate) struct WlInterface {
+    name: *const c_char,
+    version: c_int,
+    method_count: c_int,
+    methods: *const WlMessage,
+    event_count: c_int,
+    events: *const WlMessage,
+}
+
+unsafe impl Sync for WlMessage {}
+unsafe impl Sync for WlInterface {}
+
+#[repr(C)]
+struct WlArray {
+    size: usize,
+    alloc: usize,
+    data: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+union WlArgument {
+    i: i32,
+    u: u32,
+    f: i32,
+    s: *const c_char,
+    o: *mut WlProxy,
+    n: u32,
+    a: *mut WlArray,
+    h: i32,
+}
+
+type wl_display_connectFuncType = unsafe extern "C" fn(*const c_char) -> *mut WlDisplay;
+type wl_display_disconnectFuncType = unsafe extern "C" fn(*mut WlDisplay);
+type wl_display_get_fdFuncType = unsafe extern "C" fn(*mut WlDisplay) -> c_int;
+type wl_display_dispatch_pendingFuncType = unsafe extern "C" fn(*mut WlDisplay) -> c_int;
+type wl_display_flushFuncType = unsafe extern "C" fn(*mut WlDisplay) -> c_int;
+type wl_display_roundtripFuncType = unsafe extern "C" fn(*mut WlDisplay) -> c_int;
+type wl_display_prepare_readFuncType = unsafe extern "C" fn(*mut WlDisplay) -> c_int;
+type wl_display_read_eventsFuncType = unsafe extern "C" fn(*mut WlDisplay) -> c_int;
+type wl_display_cancel_readFuncType = unsafe extern "C" fn(*mut WlDisplay);
+type wl_display_get_errorFuncType = unsafe extern "C" fn(*mut WlDisplay) -> c_int;
+type wl_proxy_marshal_array_constructor_versionedFuncType = unsafe extern "C" fn(*mut WlProxy, u32, *mut WlArgument, *const WlInterface, u32) -> *mut WlProxy;
+type wl_proxy_marshal_arrayFuncType = unsafe extern "C" fn(*mut WlProxy, u32, *mut WlArgument);
+type wl_proxy_add_listenerFuncType = unsafe extern "C" fn(*mut WlProxy, *const c_void, *mut c_void) -> c_int;
+type wl_proxy_destroyFuncType = unsafe extern "C" fn(*mut WlProxy);
+type wl_proxy_get_versionFuncType = unsafe extern "C" fn(*mut WlProxy) -> u32;
+
+pub(crate) struct WaylandClient {
+    pub library: Library,
+    pub wl_display_connect: wl_display_connectFuncType,
+    pub wl_display_disconnect: wl_display_disconnectFuncType,
+    pub wl_display_get_fd: wl_display_get_fdFuncType,
+    pub wl_display_dispatch_pending: wl_display_dispatch_pendingFuncType,
+    pub wl_display_flush: wl_display_flushFuncType,
+    pub wl_display_roundtrip: wl_display_roundtripFuncType,
+    pub wl_display_prepare_read: wl_display_prepare_readFuncType,
+    pub wl_display_read_events: wl_display_read_eventsFuncType,
+    pub wl_display_cancel_read: wl_display_cancel_readFuncType,
+    pub wl_display_get_error: wl_display_get_errorFuncType,
+    pub wl_proxy_marshal_array_constructor_versioned: wl_proxy_marshal_array_constructor_versionedFuncType,
+    pub wl_proxy_marshal_array: wl_proxy_marshal_arrayFuncType,
+    pub wl_proxy_add_listener: wl_proxy_add_listenerFuncType,
+    pub wl_proxy_destroy: wl_proxy_destroyFuncType,
+    pub wl_proxy_get_version: wl_proxy_get_versionFuncType,
+    // interfaces of the core protocol, exported as data symbols
+    pub wl_registry_interface: *const WlInterface,
+    pub wl_compositor_interface: *const WlInterface,
+    pub wl_surface_interface: *const WlInterface,
+    pub wl_seat_interface: *const WlInterface,
+    pub wl_pointer_interface: *const WlInterface,
+    pub wl_keyboard_interface: *const WlInterface,
+    pub wl_data_device_manager_interface: *const WlInterface,
+    pub wl_data_device_interface: *const WlInterface,
+    pub wl_data_source_interface: *const WlInterface,
+}
+
+impl WaylandClient {
+    pub(crate) fn new() -> Result<Self, String> {
+
+        let wl = Library::load("libwayland-client.so.0")
+            .map_err(|e| format!("Could not load libwayland-client: {}", e))?;
+
+        // the type of the function pointer is inferred from the field of the WaylandClient struct
+        macro_rules! load {($name:ident) => {
+            wl.get(stringify!($name))
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(format!("libwayland-client: no symbol {}", stringify!($name)))?
+        }}
+
+        Ok(WaylandClient {
+            wl_display_connect: load!(wl_display_connect),
+            wl_display_disconnect: load!(wl_display_disconnect),
+            wl_display_get_fd: load!(wl_display_get_fd),
+            wl_display_dispatch_pending: load!(wl_display_dispatch_pending),
+            wl_display_flush: load!(wl_display_flush),
+            wl_display_roundtrip: load!(wl_display_roundtrip),
+            wl_display_prepare_read: load!(wl_display_prepare_read),
+            wl_display_read_events: load!(wl_display_read_events),
+            wl_display_cancel_read: load!(wl_display_cancel_read),
+            wl_display_get_error: load!(wl_display_get_error),
+            wl_proxy_marshal_array_constructor_versioned: load!(wl_proxy_marshal_array_constructor_versioned),
+            wl_proxy_marshal_array: load!(wl_proxy_marshal_array),
+            wl_proxy_add_listener: load!(wl_proxy_add_listener),
+            wl_proxy_destroy: load!(wl_proxy_destroy),
+            wl_proxy_get_version: load!(wl_proxy_get_version),
+            wl_registry_interface: load!(wl_registry_interface),
+            wl_compositor_interface: load!(wl_compositor_interface),
+            wl_surface_interface: load!(wl_surface_interface),
+            wl_seat_interface: load!(wl_seat_interface),
+            wl_pointer_interface: load!(wl_pointer_interface),
+            wl_keyboard_interface: load!(wl_keyboard_interface),
+            wl_data_device_manager_interface: load!(wl_data_device_manager_interface),
+            wl_data_device_interface: load!(wl_data_device_interface),
+            wl_data_source_interface: load!(wl_data_source_interface),
+            library: wl,
+        })
+    }
+
+    /// Sends a request that doesn't create a new object
+    unsafe fn marshal(&self, proxy: *mut WlProxy, opcode: u32, args: &mut [WlArgument]) {
+        (self.wl_proxy_marshal_array)(proxy, opcode, args.as_mut_ptr());
+    }
+
+    /// Sends a request that creates a new object with the same version as `proxy`,
+    /// the `new_id` argument in `args` is filled in by libwayland
+    unsafe fn marshal_constructor(
+        &self,
+        proxy: *mut WlProxy,
+        opcode: u32,
+        args: &mut [WlArgument],
+        interface: *const WlInterface,
+    ) -> *mut WlProxy {
+        let version = (self.wl_proxy_get_version)(proxy);
+        (self.wl_proxy_marshal_array_constructor_versioned)(proxy, opcode, args.as_mut_ptr(), interface, version)
+    }
+
+    /// Sends a destructor request (if the interface has one) and frees the proxy
+    unsafe fn destroy(&self, proxy: *mut WlProxy, destroy_opcode: Option<u32>) {
+        if proxy.is_null() {
+            return;
+        }
+        if let Some(opcode) = destroy_opcode {
+            self.marshal(proxy, opcode, &mut []);
+        }
+        (self.wl_proxy_destroy)(proxy);
+    }
+}
+
+impl fmt::Debug for WaylandClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.library.fmt(f)
+    }
+}
+
+// ---- libwayland-egl FFI
+
+type wl_egl_window_createFuncType = unsafe extern "C" fn(*mut WlProxy, c_int, c_int) -> *mut c_void;
+type wl_egl_window_resizeFuncType = unsafe extern "C" fn(*mut c_void, c_int, c_int, c_int, c_int);
+type wl_egl_window_destroyFuncType = unsafe extern "C" fn(*mut c_void);
+
+pub(crate) struct WaylandEgl {
+    pub library: Library,
+    pub wl_egl_window_create: wl_egl_window_createFuncType,
+    pub wl_egl_window_resize: wl_egl_window_resizeFuncType,
+    pub wl_egl_window_destroy: wl_egl_window_destroyFuncType,
+}
+
+impl WaylandEgl {
+    pub(crate) fn new() -> Result<Self, String> {
+
+        let wl_egl = Library::load("libwayland-egl.so.1")
+            .map_err(|e| format!("Could not load libwayland-egl: {}", e))?;
+
+        macro_rules! load {($name:ident) => {
+            wl_egl.get(stringify!($name))
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(format!("libwayland-egl: no function {}", stringify!($name)))?
+        }}
+
+        Ok(WaylandEgl {
+            wl_egl_window_create: load!(wl_egl_window_create),
+            wl_egl_window_resize: load!(wl_egl_window_resize),
+            wl_egl_window_destroy: load!(wl_egl_window_destroy),
+            library: wl_egl,
+        })
+    }
+}
+
+// ---- libxkbcommon FFI
+
+const XKB_KEYMAP_FORMAT_TEXT_V1: c_int = 1;
+// keycodes in wl_keyboard.key are evdev codes, xkb keycodes are offset by 8
+const XKB_EVDEV_OFFSET: u32 = 8;
+
+type xkb_context_newFuncType = unsafe extern "C" fn(c_int) -> *mut c_void;
+type xkb_context_unrefFuncType = unsafe extern "C" fn(*mut c_void);
+type xkb_keymap_new_from_stringFuncType = unsafe extern "C" fn(*mut c_void, *const c_char, c_int, c_int) -> *mut c_void;
+type xkb_keymap_unrefFuncType = unsafe extern "C" fn(*mut c_void);
+type xkb_state_newFuncType = unsafe extern "C" fn(*mut c_void) -> *mut c_void;
+type xkb_state_unrefFuncType = unsafe extern "C" fn(*mut c_void);
+type xkb_state_update_maskFuncType = unsafe extern "C" fn(*mut c_void, u32, u32, u32, u32, u32, u32) -> c_int;
+type xkb_state_key_get_one_symFuncType = unsafe extern "C" fn(*mut c_void, u32) -> u32;
+type xkb_state_key_get_utf32FuncType = unsafe extern "C" fn(*mut c_void, u32) -> u32;
+
+pub(crate) struct Xkb {
+    pub library: Library,
+    pub xkb_context_new: xkb_context_newFuncType,
+    pub xkb_context_unref: xkb_context_unrefFuncType,
+    pub xkb_keymap_new_from_string: xkb_keymap_new_from_stringFuncType,
+    pub xkb_keymap_unref: xkb_keymap_unrefFuncType,
+    pub xkb_state_new: xkb_state_newFuncType,
+    pub xkb_state_unref: xkb_state_unrefFuncType,
+    pub xkb_state_update_mask: xkb_state_update_maskFuncType,
+    pub xkb_state_key_get_one_sym: xkb_state_key_get_one_symFuncType,
+    pub xkb_state_key_get_utf32: xkb_state_key_get_utf32FuncType,
+}
+
+impl Xkb {
+    pub(crate) fn new() -> Result<Self, String> {
+
+        let xkb = Library::load("libxkbcommon.so.0")
+            .map_err(|e| format!("Could not load libxkbcommon: {}", e))?;
+
+        macro_rules! load {($name:ident) => {
+            xkb.get(stringify!($name))
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(format!("libxkbcommon: no function {}", stringify!($name)))?
+        }}
+
+        Ok(Xkb {
+            xkb_context_new: load!(xkb_context_new),
+            xkb_context_unref: load!(xkb_context_unref),
+            xkb_keymap_new_from_string: load!(xkb_keymap_new_from_string),
+            xkb_keymap_unref: load!(xkb_keymap_unref),
+            xkb_state_new: load!(xkb_state_new),
+            xkb_state_unref: load!(xkb_state_unref),
+            xkb_state_update_mask: load!(xkb_state_update_mask),
+            xkb_state_key_get_one_sym: load!(xkb_state_key_get_one_sym),
+            xkb_state_key_get_utf32: load!(xkb_state_key_get_utf32),
+            library: xkb,
+        })
+    }
+}
+
+// ---- extension protocol tables
+//
+// Only the names and signatures are needed: all objects created by these
+// interfaces are created with an explicit interface, none of their events
+// carry objects, so the `types` of every message can be left empty.
+
+#[repr(C)]
+struct NullTypes([*const WlInterface; 8]);
+unsafe impl Sync for NullTypes {}
+
+static NULL_TYPES: NullTypes = NullTypes([ptr::null(); 8]);
+
+macro_rules! wl_message {($name:expr, $signature:expr) => {
+    WlMessage {
+        name: concat!($name, "\0").as_ptr() as *const c_char,
+        signature: concat!($signature, "\0").as_ptr() as *const c_char,
+        types: &NULL_TYPES.0 as *const [*const WlInterface; 8] as *const *const WlInterface,
+    }
+}}
+
+macro_rules! wl_interface {($name:expr, $version:expr, $requests:ident, $events:ident) => {
+    WlInterface {
+        name: concat!($name, "\0").as_ptr() as *const c_char,
+        version: $version,
+        method_count: $requests.len() as c_int,
+        methods: &$requests as *const [WlMessage; $requests.len()] as *const WlMessage,
+        event_count: $events.len() as c_int,
+        events: &$events as *const [WlMessage; $events.len()] as *const WlMessage,
+    }
+}}
+
+static NO_MESSAGES: [WlMessage; 0] = [];
+
+static XDG_WM_BASE_REQUESTS: [WlMessage; 4] = [
+    wl_message!("destroy", ""),
+    wl_message!("create_positioner", "n"),
+    wl_message!("get_xdg_surface", "no"),
+    wl_message!("pong", "u"),
+];
+static XDG_WM_BASE_EVENTS: [WlMessage; 1] = [
+    wl_message!("ping", "u"),
+];
+static XDG_WM_BASE_INTERFACE: WlInterface = wl_interface!("xdg_wm_base", 1, XDG_WM_BASE_REQUESTS, XDG_WM_BASE_EVENTS);
+
+static XDG_SURFACE_REQUESTS: [WlMessage; 5] = [
+    wl_message!("destroy", ""),
+    wl_message!("get_toplevel", "n"),
+    wl_message!("get_popup", "n?oo"),
+    wl_message!("set_window_geometry", "iiii"),
+    wl_message!("ack_configure", "u"),
+];
+static XDG_SURFACE_EVENTS: [WlMessage; 1] = [
+    wl_message!("configure", "u"),
+];
+static XDG_SURFACE_INTERFACE: WlInterface = wl_interface!("xdg_surface", 1, XDG_SURFACE_REQUESTS, XDG_SURFACE_EVENTS);
+
+static XDG_TOPLEVEL_REQUESTS: [WlMessage; 14] = [
+    wl_message!("destroy", ""),
+    wl_message!("set_parent", "?o"),
+    wl_message!("set_title", "s"),
+    wl_message!("set_app_id", "s"),
+    wl_message!("show_window_menu", "ouii"),
+    wl_message!("move", "ou"),
+    wl_message!("resize", "ouu"),
+    wl_message!("set_max_size", "ii"),
+    wl_message!("set_min_size", "ii"),
+    wl_message!("set_maximized", ""),
+    wl_message!("unset_maximized", ""),
+    wl_message!("set_fullscreen", "?o"),
+    wl_message!("unset_fullscreen", ""),
+    wl_message!("set_minimized", ""),
+];
+static XDG_TOPLEVEL_EVENTS: [WlMessage; 2] = [
+    wl_message!("configure", "iia"),
+    wl_message!("close", ""),
+];
+static XDG_TOPLEVEL_INTERFACE: WlInterface = wl_interface!("xdg_toplevel", 1, XDG_TOPLEVEL_REQUESTS, XDG_TOPLEVEL_EVENTS);
+
+static XDG_POSITIONER_REQUESTS: [WlMessage; 7] = [
+    wl_message!("destroy", ""),
+    wl_message!("set_size", "ii"),
+    wl_message!("set_anchor_rect", "iiii"),
+    wl_message!("set_anchor", "u"),
+    wl_message!("set_gravity", "u"),
+    wl_message!("set_constraint_adjustment", "u"),
+    wl_message!("set_offset", "ii"),
+];
+static XDG_POSITIONER_INTERFACE: WlInterface = wl_interface!("xdg_positioner", 1, XDG_POSITIONER_REQUESTS, NO_MESSAGES);
+
+static XDG_POPUP_REQUESTS: [WlMessage; 2] = [
+    wl_message!("destroy", ""),
+    wl_message!("grab", "ou"),
+];
+static XDG_POPUP_EVENTS: [WlMessage; 2] = [
+    wl_message!("configure", "iiii"),
+    wl_message!("popup_done", ""),
+];
+static XDG_POPUP_INTERFACE: WlInterface = wl_interface!("xdg_popup", 1, XDG_POPUP_REQUESTS, XDG_POPUP_EVENTS);
+
+static WP_VIEWPORTER_REQUESTS: [WlMessage; 2] = [
+    wl_message!("destroy", ""),
+    wl_message!("get_viewport", "no"),
+];
+static WP_VIEWPORTER_INTERFACE: WlInterface = wl_interface!("wp_viewporter", 1, WP_VIEWPORTER_REQUESTS, NO_MESSAGES);
+
+static WP_VIEWPORT_REQUESTS: [WlMessage; 3] = [
+    wl_message!("destroy", ""),
+    wl_message!("set_source", "ffff"),
+    wl_message!("set_destination", "ii"),
+];
+static WP_VIEWPORT_INTERFACE: WlInterface = wl_interface!("wp_viewport", 1, WP_VIEWPORT_REQUESTS, NO_MESSAGES);
+
+static WP_FRACTIONAL_SCALE_MANAGER_REQUESTS: [WlMessage; 2] = [
+    wl_message!("destroy", ""),
+    wl_message!("get_fractional_scale", "no"),
+];
+static WP_FRACTIONAL_SCALE_MANAGER_INTERFACE: WlInterface = wl_interface!(
+    "wp_fractional_scale_manager_v1", 1, WP_FRACTIONAL_SCALE_MANAGER_REQUESTS, NO_MESSAGES
+);
+
+static WP_FRACTIONAL_SCALE_REQUESTS: [WlMessage; 1] = [
+    wl_message!("destroy", ""),
+];
+static WP_FRACTIONAL_SCALE_EVENTS: [WlMessage; 1] = [
+    wl_message!("preferred_scale", "u"),
+];
+static WP_FRACTIONAL_SCALE_INTERFACE: WlInterface = wl_interface!(
+    "wp_fractional_scale_v1", 1, WP_FRACTIONAL_SCALE_REQUESTS, WP_FRACTIONAL_SCALE_EVENTS
+);
+
+static WP_CURSOR_SHAPE_MANAGER_REQUESTS: [WlMessage; 3] = [
+    wl_message!("destroy", ""),
+    wl_message!("get_pointer", "no"),
+    wl_message!("get_tablet_tool_v2", "no"),
+];
+static WP_CURSOR_SHAPE_MANAGER_INTERFACE: WlInterface = wl_interface!(
+    "wp_cursor_shape_manager_v1", 1, WP_CURSOR_SHAPE_MANAGER_REQUESTS, NO_MESSAGES
+);
+
+static WP_CURSOR_SHAPE_DEVICE_REQUESTS: [WlMessage; 2] = [
+    wl_message!("destroy", ""),
+    wl_message!("set_shape", "uu"),
+];
+static WP_CURSOR_SHAPE_DEVICE_INTERFACE: WlInterface = wl_interface!(
+    "wp_cursor_shape_device_v1", 1, WP_CURSOR_SHAPE_DEVICE_REQUESTS, NO_MESSAGES
+);
+
+// request opcodes
+
+const WL_DISPLAY_GET_REGISTRY: u32 = 1;
+const WL_REGISTRY_BIND: u32 = 0;
+const WL_COMPOSITOR_CREATE_SURFACE: u32 = 0;
+const WL_SURFACE_DESTROY: u32 = 0;
+const WL_SURFACE_COMMIT: u32 = 6;
+const WL_SEAT_GET_POINTER: u32 = 0;
+const WL_SEAT_GET_KEYBOARD: u32 = 1;
+const WL_POINTER_SET_CURSOR: u32 = 0;
+const WL_DATA_DEVICE_MANAGER_CREATE_DATA_SOURCE: u32 = 0;
+const WL_DATA_DEVICE_MANAGER_GET_DATA_DEVICE: u32 = 1;
+const WL_DATA_DEVICE_SET_SELECTION: u32 = 1;
+const WL_DATA_OFFER_RECEIVE: u32 = 1;
+const WL_DATA_OFFER_DESTROY: u32 = 2;
+const WL_DATA_SOURCE_OFFER: u32 = 0;
+const WL_DATA_SOURCE_DESTROY: u32 = 1;
+const XDG_WM_BASE_CREATE_POSITIONER: u32 = 1;
+const XDG_WM_BASE_GET_XDG_SURFACE: u32 = 2;
+const XDG_WM_BASE_PONG: u32 = 3;
+const XDG_SURFACE_DESTROY: u32 = 0;
+const XDG_SURFACE_GET_TOPLEVEL: u32 = 1;
+const XDG_SURFACE_GET_POPUP: u32 = 2;
+const XDG_SURFACE_ACK_CONFIGURE: u32 = 4;
+const XDG_TOPLEVEL_DESTROY: u32 = 0;
+const XDG_TOPLEVEL_SET_TITLE: u32 = 2;
+const XDG_TOPLEVEL_SET_APP_ID: u32 = 3;
+const XDG_TOPLEVEL_SET_MAX_SIZE: u32 = 7;
+const XDG_TOPLEVEL_SET_MIN_SIZE: u32 = 8;
+const XDG_POSITIONER_DESTROY: u32 = 0;
+const XDG_POSITIONER_SET_SIZE: u32 = 1;
+const XDG_POSITIONER_SET_ANCHOR_RECT: u32 = 2;
+const XDG_POSITIONER_SET_ANCHOR: u32 = 3;
+const XDG_POSITIONER_SET_GRAVITY: u32 = 4;
+const XDG_POSITIONER_SET_CONSTRAINT_ADJUSTMENT: u32 = 5;
+const XDG_POPUP_DESTROY: u32 = 0;
+const XDG_POPUP_GRAB: u32 = 1;
+const WP_VIEWPORTER_GET_VIEWPORT: u32 = 1;
+const WP_VIEWPORT_DESTROY: u32 = 0;
+const WP_VIEWPORT_SET_DESTINATION: u32 = 2;
+const WP_FRACTIONAL_SCALE_MANAGER_GET_FRACTIONAL_SCALE: u32 = 1;
+const WP_FRACTIONAL_SCALE_DESTROY: u32 = 0;
+const WP_CURSOR_SHAPE_MANAGER_GET_POINTER: u32 = 1;
+const WP_CURSOR_SHAPE_DEVICE_SET_SHAPE: u32 = 1;
+
+// enum values
+
+const WL_SEAT_CAPABILITY_POINTER: u32 = 1;
+const WL_SEAT_CAPABILITY_KEYBOARD: u32 = 2;
+const WL_POINTER_BUTTON_STATE_PRESSED: u32 = 1;
+const WL_POINTER_AXIS_VERTICAL_SCROLL: u32 = 0;
+const WL_POINTER_AXIS_HORIZONTAL_SCROLL: u32 = 1;
+const WL_KEYBOARD_KEY_STATE_PRESSED: u32 = 1;
+const WL_KEYBOARD_KEYMAP_FORMAT_XKB_V1: u32 = 1;
+const XDG_TOPLEVEL_STATE_MAXIMIZED: u32 = 1;
+const XDG_TOPLEVEL_STATE_FULLSCREEN: u32 = 2;
+const XDG_POSITIONER_ANCHOR_TOP_LEFT: u32 = 5;
+const XDG_POSITIONER_GRAVITY_BOTTOM_RIGHT: u32 = 8;
+const XDG_POSITIONER_CONSTRAINT_ADJUSTMENT_SLIDE_X: u32 = 1;
+const XDG_POSITIONER_CONSTRAINT_ADJUSTMENT_SLIDE_Y: u32 = 2;
+const XDG_POSITIONER_CONSTRAINT_ADJUSTMENT_FLIP_Y: u32 = 8;
+
+// linux/input-event-codes.h
+const BTN_LEFT: u32 = 0x110;
+const BTN_RIGHT: u32 = 0x111;
+const BTN_MIDDLE: u32 = 0x112;
+
+// highest versions of the core interfaces whose events the listeners below can handle
+const WL_COMPOSITOR_VERSION: u32 = 4;
+const WL_SEAT_VERSION: u32 = 5;
+const WL_DATA_DEVICE_MANAGER_VERSION: u32 = 3;
+
+fn wl_fixed_to_f64(f: i32) -> f64 {
+    f as f64 / 256.0
+}
+
+unsafe fn cstr_to_string(s: *const c_char) -> Option<String> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s).to_string_lossy().into_owned())
+    }
+}
+
+fn to_cstring(s: &str) -> CString {
+    CString::new(s.replace('\0', "")).unwrap_or_default()
+}
+
+// ---- listeners
+
+/// Events that were received from the compositor, pushed by the listeners
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum WaylandEvent {
+    Global { name: u32, interface: String, version: u32 },
+    Ping { serial: u32 },
+    SeatCapabilities { capabilities: u32 },
+    Configure { xdg_surface: *mut WlProxy, serial: u32 },
+    ToplevelConfigure { toplevel: *mut WlProxy, width: i32, height: i32, states: Vec<u32> },
+    ToplevelClose { toplevel: *mut WlProxy },
+    PopupDone { popup: *mut WlProxy },
+    PreferredScale { fractional_scale: *mut WlProxy, scale: u32 },
+    PointerEnter { serial: u32, surface: *mut WlProxy, x: f64, y: f64 },
+    PointerLeave { surface: *mut WlProxy },
+    PointerMotion { x: f64, y: f64 },
+    PointerButton { serial: u32, button: u32, pressed: bool },
+    PointerAxis { axis: u32, value: f64 },
+    Keymap { keymap: Option<String> },
+    KeyboardEnter { serial: u32, surface: *mut WlProxy },
+    KeyboardLeave { surface: *mut WlProxy },
+    Key { serial: u32, key: u32, pressed: bool },
+    Modifiers { depressed: u32, latched: u32, locked: u32, group: u32 },
+    DataOffer { offer: *mut WlProxy },
+    DataOfferMimeType { offer: *mut WlProxy, mime_type: String },
+    Selection { offer: *mut WlProxy },
+    DataSourceSend { source: *mut WlProxy, mime_type: String, fd: c_int },
+    DataSourceCancelled { source: *mut WlProxy },
+}
+
+/// User data of all listeners, has to stay at the same address (boxed)
+pub(crate) struct EventQueue {
+    events: Vec<WaylandEvent>,
+    // objects created by events (wl_data_offer) need a listener before the next event is dispatched
+    add_listener: wl_proxy_add_listenerFuncType,
+}
+
+unsafe fn push_event(data: *mut c_void, event: WaylandEvent) {
+    let queue = &mut *(data as *mut EventQueue);
+    queue.events.push(event);
+}
+
+unsafe fn wl_array_to_u32_vec(array: *mut WlArray) -> Vec<u32> {
+    if array.is_null() || (*array).data.is_null() {
+        return Vec::new();
+    }
+    let len = (*array).size / mem::size_of::<u32>();
+    core::slice::from_raw_parts((*array).data as *const u32, len).to_vec()
+}
+
+#[repr(C)]
+struct WlRegistryListener {
+    global: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, *const c_char, u32),
+    global_remove: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32),
+}
+
+unsafe extern "C" fn registry_global(data: *mut c_void, _: *mut WlProxy, name: u32, interface: *const c_char, version: u32) {
+    let interface = cstr_to_string(interface).unwrap_or_default();
+    push_event(data, WaylandEvent::Global { name, interface, version });
+}
+unsafe extern "C" fn registry_global_remove(_: *mut c_void, _: *mut WlProxy, _: u32) { }
+
+static REGISTRY_LISTENER: WlRegistryListener = WlRegistryListener {
+    global: registry_global,
+    global_remove: registry_global_remove,
+};
+
+#[repr(C)]
+struct WlSeatListener {
+    capabilities: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32),
+    name: unsafe extern "C" fn(*mut c_void, *mut WlProxy, *const c_char),
+}
+
+unsafe extern "C" fn seat_capabilities(data: *mut c_void, _: *mut WlProxy, capabilities: u32) {
+    push_event(data, WaylandEvent::SeatCapabilities { capabilities });
+}
+unsafe extern "C" fn seat_name(_: *mut c_void, _: *mut WlProxy, _: *const c_char) { }
+
+static SEAT_LISTENER: WlSeatListener = WlSeatListener {
+    capabilities: seat_capabilities,
+    name: seat_name,
+};
+
+#[repr(C)]
+struct WlPointerListener {
+    enter: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, *mut WlProxy, i32, i32),
+    leave: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, *mut WlProxy),
+    motion: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, i32, i32),
+    button: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, u32, u32, u32),
+    axis: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, u32, i32),
+    frame: unsafe extern "C" fn(*mut c_void, *mut WlProxy),
+    axis_source: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32),
+    axis_stop: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, u32),
+    axis_discrete: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, i32),
+}
+
+unsafe extern "C" fn pointer_enter(data: *mut c_void, _: *mut WlProxy, serial: u32, surface: *mut WlProxy, x: i32, y: i32) {
+    push_event(data, WaylandEvent::PointerEnter { serial, surface, x: wl_fixed_to_f64(x), y: wl_fixed_to_f64(y) });
+}
+unsafe extern "C" fn pointer_leave(data: *mut c_void, _: *mut WlProxy, _serial: u32, surface: *mut WlProxy) {
+    push_event(data, WaylandEvent::PointerLeave { surface });
+}
+unsafe extern "C" fn pointer_motion(data: *mut c_void, _: *mut WlProxy, _time: u32, x: i32, y: i32) {
+    push_event(data, WaylandEvent::PointerMotion { x: wl_fixed_to_f64(x), y: wl_fixed_to_f64(y) });
+}
+unsafe extern "C" fn pointer_button(data: *mut c_void, _: *mut WlProxy, serial: u32, _time: u32, button: u32, state: u32) {
+    push_event(data, WaylandEvent::PointerButton { serial, button, pressed: state == WL_POINTER_BUTTON_STATE_PRESSED });
+}
+unsafe extern "C" fn pointer_axis(data: *mut c_void, _: *mut WlProxy, _time: u32, axis: u32, value: i32) {
+    push_event(data, WaylandEvent::PointerAxis { axis, value: wl_fixed_to_f64(value) });
+}
+unsafe extern "C" fn pointer_frame(_: *mut c_void, _: *mut WlProxy) { }
+unsafe extern "C" fn pointer_axis_source(_: *mut c_void, _: *mut WlProxy, _: u32) { }
+unsafe extern "C" fn pointer_axis_stop(_: *mut c_void, _: *mut WlProxy, _: u32, _: u32) { }
+unsafe extern "C" fn pointer_axis_discrete(_: *mut c_void, _: *mut WlProxy, _: u32, _: i32) { }
+
+static POINTER_LISTENER: WlPointerListener = WlPointerListener {
+    enter: pointer_enter,
+    leave: pointer_leave,
+    motion: pointer_motion,
+    button: pointer_button,
+    axis: pointer_axis,
+    frame: pointer_frame,
+    axis_source: pointer_axis_source,
+    axis_stop: pointer_axis_stop,
+    axis_discrete: pointer_axis_discrete,
+};
+
+#[repr(C)]
+struct WlKeyboardListener {
+    keymap: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, i32, u32),
+    enter: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, *mut WlProxy, *mut WlArray),
+    leave: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, *mut WlProxy),
+    key: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, u32, u32, u32),
+    modifiers: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, u32, u32, u32, u32),
+    repeat_info: unsafe extern "C" fn(*mut c_void, *mut WlProxy, i32, i32),
+}
+
+unsafe extern "C" fn keyboard_keymap(data: *mut c_void, _: *mut WlProxy, format: u32, fd: i32, size: u32) {
+
+    use std::fs::File;
+    use std::os::unix::fs::FileExt;
+    use std::os::unix::io::FromRawFd;
+
+    // the file is owned by us and closed when dropped
+    let file = File::from_raw_fd(fd);
+
+    // the file description may be shared with other clients: read at
+    // offset 0 instead of moving the shared file offset
+    let keymap = if format == WL_KEYBOARD_KEYMAP_FORMAT_XKB_V1 {
+        let mut buf = vec![0; size as usize];
+        file.read_exact_at(&mut buf, 0).ok().and_then(|_| {
+            while buf.last() == Some(&0) {
+                buf.pop();
+            }
+            String::from_utf8(buf).ok()
+        })
+    } else {
+        None
+    };
+
+    push_event(data, WaylandEvent::Keymap { keymap });
+}
+unsafe extern "C" fn keyboard_enter(data: *mut c_void, _: *mut WlProxy, serial: u32, surface: *mut WlProxy, _keys: *mut WlArray) {
+    push_event(data, WaylandEvent::KeyboardEnter { serial, surface });
+}
+unsafe extern "C" fn keyboard_leave(data: *mut c_void, _: *mut WlProxy, _serial: u32, surface: *mut WlProxy) {
+    push_event(data, WaylandEvent::KeyboardLeave { surface });
+}
+unsafe extern "C" fn keyboard_key(data: *mut c_void, _: *mut WlProxy, serial: u32, _time: u32, key: u32, state: u32) {
+    push_event(data, WaylandEvent::Key { serial, key, pressed: state == WL_KEYBOARD_KEY_STATE_PRESSED });
+}
+unsafe extern "C" fn keyboard_modifiers(data: *mut c_void, _: *mut WlProxy, _serial: u32, depressed: u32, latched: u32, locked: u32, group: u32) {
+    push_event(data, WaylandEvent::Modifiers { depressed, latched, locked, group });
+}
+unsafe extern "C" fn keyboard_repeat_info(_: *mut c_void, _: *mut WlProxy, _: i32, _: i32) { }
+
+static KEYBOARD_LISTENER: WlKeyboardListener = WlKeyboardListener {
+    keymap: keyboard_keymap,
+    enter: keyboard_enter,
+    leave: keyboard_leave,
+    key: keyboard_key,
+    modifiers: keyboard_modifiers,
+    repeat_info: keyboard_repeat_info,
+};
+
+#[repr(C)]
+struct WlDataDeviceListener {
+    data_offer: unsafe extern "C" fn(*mut c_void, *mut WlProxy, *mut WlProxy),
+    enter: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, *mut WlProxy, i32, i32, *mut WlProxy),
+    leave: unsafe extern "C" fn(*mut c_void, *mut WlProxy),
+    motion: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32, i32, i32),
+    drop: unsafe extern "C" fn(*mut c_void, *mut WlProxy),
+    selection: unsafe extern "C" fn(*mut c_void, *mut WlProxy, *mut WlProxy),
+}
+
+unsafe extern "C" fn data_device_data_offer(data: *mut c_void, _: *mut WlProxy, offer: *mut WlProxy) {
+    let queue = &mut *(data as *mut EventQueue);
+    (queue.add_listener)(offer, &DATA_OFFER_LISTENER as *const WlDataOfferListener as *const c_void, data);
+    queue.events.push(WaylandEvent::DataOffer { offer });
+}
+unsafe extern "C" fn data_device_enter(_: *mut c_void, _: *mut WlProxy, _: u32, _: *mut WlProxy, _: i32, _: i32, _: *mut WlProxy) { }
+unsafe extern "C" fn data_device_leave(_: *mut c_void, _: *mut WlProxy) { }
+unsafe extern "C" fn data_device_motion(_: *mut c_void, _: *mut WlProxy, _: u32, _: i32, _: i32) { }
+unsafe extern "C" fn data_device_drop(_: *mut c_void, _: *mut WlProxy) { }
+unsafe extern "C" fn data_device_selection(data: *mut c_void, _: *mut WlProxy, offer: *mut WlProxy) {
+    push_event(data, WaylandEvent::Selection { offer });
+}
+
+static DATA_DEVICE_LISTENER: WlDataDeviceListener = WlDataDeviceListener {
+    data_offer: data_device_data_offer,
+    enter: data_device_enter,
+    leave: data_device_leave,
+    motion: data_device_motion,
+    drop: data_device_drop,
+    selection: data_device_selection,
+};
+
+#[repr(C)]
+struct WlDataOfferListener {
+    offer: unsafe extern "C" fn(*mut c_void, *mut WlProxy, *const c_char),
+    source_actions: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32),
+    action: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32),
+}
+
+unsafe extern "C" fn data_offer_offer(data: *mut c_void, offer: *mut WlProxy, mime_type: *const c_char) {
+    if let Some(mime_type) = cstr_to_string(mime_type) {
+        push_event(data, WaylandEvent::DataOfferMimeType { offer, mime_type });
+    }
+}
+unsafe extern "C" fn data_offer_source_actions(_: *mut c_void, _: *mut WlProxy, _: u32) { }
+unsafe extern "C" fn data_offer_action(_: *mut c_void, _: *mut WlProxy, _: u32) { }
+
+static DATA_OFFER_LISTENER: WlDataOfferListener = WlDataOfferListener {
+    offer: data_offer_offer,
+    source_actions: data_offer_source_actions,
+    action: data_offer_action,
+};
+
+#[repr(C)]
+struct WlDataSourceListener {
+    target: unsafe extern "C" fn(*mut c_void, *mut WlProxy, *const c_char),
+    send: unsafe extern "C" fn(*mut c_void, *mut WlProxy, *const c_char, i32),
+    cancelled: unsafe extern "C" fn(*mut c_void, *mut WlProxy),
+    dnd_drop_performed: unsafe extern "C" fn(*mut c_void, *mut WlProxy),
+    dnd_finished: unsafe extern "C" fn(*mut c_void, *mut WlProxy),
+    action: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32),
+}
+
+unsafe extern "C" fn data_source_target(_: *mut c_void, _: *mut WlProxy, _: *const c_char) { }
+unsafe extern "C" fn data_source_send(data: *mut c_void, source: *mut WlProxy, mime_type: *const c_char, fd: i32) {
+    let mime_type = cstr_to_string(mime_type).unwrap_or_default();
+    push_event(data, WaylandEvent::DataSourceSend { source, mime_type, fd });
+}
+unsafe extern "C" fn data_source_cancelled(data: *mut c_void, source: *mut WlProxy) {
+    push_event(data, WaylandEvent::DataSourceCancelled { source });
+}
+unsafe extern "C" fn data_source_dnd_drop_performed(_: *mut c_void, _: *mut WlProxy) { }
+unsafe extern "C" fn data_source_dnd_finished(_: *mut c_void, _: *mut WlProxy) { }
+unsafe extern "C" fn data_source_action(_: *mut c_void, _: *mut WlProxy, _: u32) { }
+
+static DATA_SOURCE_LISTENER: WlDataSourceListener = WlDataSourceListener {
+    target: data_source_target,
+    send: data_source_send,
+    cancelled: data_source_cancelled,
+    dnd_drop_performed: data_source_dnd_drop_performed,
+    dnd_finished: data_source_dnd_finished,
+    action: data_source_action,
+};
+
+#[repr(C)]
+struct XdgWmBaseListener {
+    ping: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32),
+}
+
+unsafe extern "C" fn xdg_wm_base_ping(data: *mut c_void, _: *mut WlProxy, serial: u32) {
+    push_event(data, WaylandEvent::Ping { serial });
+}
+
+static XDG_WM_BASE_LISTENER: XdgWmBaseListener = XdgWmBaseListener {
+    ping: xdg_wm_base_ping,
+};
+
+#[repr(C)]
+struct XdgSurfaceListener {
+    configure: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32),
+}
+
+unsafe extern "C" fn xdg_surface_configure(data: *mut c_void, xdg_surface: *mut WlProxy, serial: u32) {
+    push_event(data, WaylandEvent::Configure { xdg_surface, serial });
+}
+
+static XDG_SURFACE_LISTENER: XdgSurfaceListener = XdgSurfaceListener {
+    configure: xdg_surface_configure,
+};
+
+#[repr(C)]
+struct XdgToplevelListener {
+    configure: unsafe extern "C" fn(*mut c_void, *mut WlProxy, i32, i32, *mut WlArray),
+    close: unsafe extern "C" fn(*mut c_void, *mut WlProxy),
+}
+
+unsafe extern "C" fn xdg_toplevel_configure(data: *mut c_void, toplevel: *mut WlProxy, width: i32, height: i32, states: *mut WlArray) {
+    let states = wl_array_to_u32_vec(states);
+    push_event(data, WaylandEvent::ToplevelConfigure { toplevel, width, height, states });
+}
+unsafe extern "C" fn xdg_toplevel_close(data: *mut c_void, toplevel: *mut WlProxy) {
+    push_event(data, WaylandEvent::ToplevelClose { toplevel });
+}
+
+static XDG_TOPLEVEL_LISTENER: XdgToplevelListener = XdgToplevelListener {
+    configure: xdg_toplevel_configure,
+    close: xdg_toplevel_close,
+};
+
+#[repr(C)]
+struct XdgPopupListener {
+    configure: unsafe extern "C" fn(*mut c_void, *mut WlProxy, i32, i32, i32, i32),
+    popup_done: unsafe extern "C" fn(*mut c_void, *mut WlProxy),
+}
+
+// the size of a popup is not changed by the compositor, only its position
+unsafe extern "C" fn xdg_popup_configure(_: *mut c_void, _: *mut WlProxy, _: i32, _: i32, _: i32, _: i32) { }
+unsafe extern "C" fn xdg_popup_popup_done(data: *mut c_void, popup: *mut WlProxy) {
+    push_event(data, WaylandEvent::PopupDone { popup });
+}
+
+static XDG_POPUP_LISTENER: XdgPopupListener = XdgPopupListener {
+    configure: xdg_popup_configure,
+    popup_done: xdg_popup_popup_done,
+};
+
+#[repr(C)]
+struct WpFractionalScaleListener {
+    preferred_scale: unsafe extern "C" fn(*mut c_void, *mut WlProxy, u32),
+}
+
+unsafe extern "C" fn fractional_scale_preferred_scale(data: *mut c_void, fractional_scale: *mut WlProxy, scale: u32) {
+    push_event(data, WaylandEvent::PreferredScale { fractional_scale, scale });
+}
+
+static FRACTIONAL_SCALE_LISTENER: WpFractionalScaleListener = WpFractionalScaleListener {
+    preferred_scale: fractional_scale_preferred_scale,
+};
+
+// ---- connection
+
+/// Connection to the compositor, owns the globals and the input devices of the seat
+pub(crate) struct WaylandDisplay {
+    pub client: Rc<WaylandClient>,
+    pub display: *mut WlDisplay,
+    pub registry: *mut WlProxy,
+    pub queue: Box<EventQueue>,
+    // globals, null if the compositor doesn't support them
+    pub compositor: *mut WlProxy,
+    pub wm_base: *mut WlProxy,
+    pub seat: *mut WlProxy,
+    pub data_device_manager: *mut WlProxy,
+    pub viewporter: *mut WlProxy,
+    pub fractional_scale_manager: *mut WlProxy,
+    pub cursor_shape_manager: *mut WlProxy,
+    // input devices of the seat
+    pub pointer: *mut WlProxy,
+    pub keyboard: *mut WlProxy,
+    pub cursor_shape_device: *mut WlProxy,
+    // keyboard layout, None if libxkbcommon is not available
+    pub xkb: Option<Rc<Xkb>>,
+    xkb_context: *mut c_void,
+    xkb_keymap: *mut c_void,
+    xkb_state: *mut c_void,
+    /// Surface that currently has the pointer focus (null if none)
+    pub pointer_focus: *mut WlProxy,
+    /// Serial of the last wl_pointer.enter, needed to change the cursor
+    pub pointer_serial: u32,
+    /// Surface that currently has the keyboard focus (null if none)
+    pub keyboard_focus: *mut WlProxy,
+    /// Serial of the last input event, needed to grab the input for a popup
+    pub input_serial: u32,
+    pub clipboard: Rc<RefCell<WaylandClipboard>>,
+}
+
+impl WaylandDisplay {
+
+    /// Connects to the compositor (`name` = `None` uses `WAYLAND_DISPLAY`)
+    /// and binds the globals and input devices
+    pub(crate) fn connect(client: Rc<WaylandClient>, xkb: Option<Rc<Xkb>>, name: Option<&str>) -> Result<Self, String> {
+
+        let name = name.map(to_cstring);
+        let display = unsafe { (client.wl_display_connect)(name.as_ref().map(|n| n.as_ptr()).unwrap_or(ptr::null())) };
+        if display.is_null() {
+            return Err(format!("Wayland: wl_display_connect() failed: could not connect to compositor"));
+        }
+
+        let mut queue = Box::new(EventQueue {
+            events: Vec::new(),
+            add_listener: client.wl_proxy_add_listener,
+        });
+
+        let registry = unsafe {
+            let registry = client.marshal_constructor(
+                display,
+                WL_DISPLAY_GET_REGISTRY,
+                &mut [WlArgument { n: 0 }],
+                client.wl_registry_interface,
+            );
+            (client.wl_proxy_add_listener)(
+                registry,
+                &REGISTRY_LISTENER as *const WlRegistryListener as *const c_void,
+                &mut *queue as *mut EventQueue as *mut c_void,
+            );
+            registry
+        };
+
+        let xkb_context = match xkb.as_ref() {
+            Some(xkb) => unsafe { (xkb.xkb_context_new)(0) },
+            None => ptr::null_mut(),
+        };
+
+        let clipboard = Rc::new(RefCell::new(WaylandClipboard {
+            client: client.clone(),
+            display,
+            data_device_manager: ptr::null_mut(),
+            data_device: ptr::null_mut(),
+            queue: &mut *queue as *mut EventQueue,
+            offers: BTreeMap::new(),
+            selection: ptr::null_mut(),
+            source: None,
+            serial: 0,
+        }));
+
+        let mut wayland_display = Self {
+            client,
+            display,
+            registry,
+            queue,
+            compositor: ptr::null_mut(),
+            wm_base: ptr::null_mut(),
+            seat: ptr::null_mut(),
+            data_device_manager: ptr::null_mut(),
+            viewporter: ptr::null_mut(),
+            fractional_scale_manager: ptr::null_mut(),
+            cursor_shape_manager: ptr::null_mut(),
+            pointer: ptr::null_mut(),
+            keyboard: ptr::null_mut(),
+            cursor_shape_device: ptr::null_mut(),
+            xkb,
+            xkb_context,
+            xkb_keymap: ptr::null_mut(),
+            xkb_state: ptr::null_mut(),
+            pointer_focus: ptr::null_mut(),
+            pointer_serial: 0,
+            keyboard_focus: ptr::null_mut(),
+            input_serial: 0,
+            clipboard,
+        };
+
+        // first roundtrip: receive and bind the globals,
+        // second roundtrip: receive the seat capabilities and the keymap
+        for _ in 0..2 {
+            if unsafe { (wayland_display.client.wl_display_roundtrip)(wayland_display.display) } == -1 {
+                return Err(format!("Wayland: wl_display_roundtrip() failed: {}", wayland_display.get_error()));
+            }
+            for event in wayland_display.take_events() {
+                let _ = wayland_display.handle_global_event(event);
+            }
+        }
+
+        Ok(wayland_display)
+    }
+
+    fn get_error(&self) -> c_int {
+        unsafe { (self.client.wl_display_get_error)(self.display) }
+    }
+
+    fn queue_ptr(&mut self) -> *mut c_void {
+        &mut *self.queue as *mut EventQueue as *mut c_void
+    }
+
+    unsafe fn add_listener<T>(&mut self, proxy: *mut WlProxy, listener: &'static T) {
+        let queue = self.queue_ptr();
+        (self.client.wl_proxy_add_listener)(proxy, listener as *const T as *const c_void, queue);
+    }
+
+    unsafe fn bind(&self, name: u32, interface: *const WlInterface, version: u32) -> *mut WlProxy {
+        let mut args = [
+            WlArgument { u: name },
+            WlArgument { s: (*interface).name },
+            WlArgument { u: version },
+            WlArgument { n: 0 },
+        ];
+        (self.client.wl_proxy_marshal_array_constructor_versioned)(
+            self.registry,
+            WL_REGISTRY_BIND,
+            args.as_mut_ptr(),
+            interface,
+            version,
+        )
+    }
+
+    /// Removes all events that were received since the last call
+    pub(crate) fn take_events(&mut self) -> Vec<WaylandEvent> {
+        mem::replace(&mut self.queue.events, Vec::new())
+    }
+
+    /// Reads and dispatches events, waiting at most `timeout_ms`
+    /// milliseconds (-1 = forever) for the compositor to send new events
+    pub(crate) fn dispatch(&mut self, timeout_ms: i32) -> Result<(), String> {
+
+        let client = self.client.clone();
+
+        unsafe {
+            // events that were already read from the socket have to be dispatched first
+            while (client.wl_display_prepare_read)(self.display) != 0 {
+                (client.wl_display_dispatch_pending)(self.display);
+            }
+
+            (client.wl_display_flush)(self.display);
+
+            let timeout_ms = if self.queue.events.is_empty() { timeout_ms } else { 0 };
+            let mut fds = PollFd {
+                fd: (client.wl_display_get_fd)(self.display),
+                events: POLLIN,
+                revents: 0,
+            };
+
+            if poll(&mut fds, 1, timeout_ms) > 0 {
+                (client.wl_display_read_events)(self.display);
+            } else {
+                (client.wl_display_cancel_read)(self.display);
+            }
+
+            if (client.wl_display_dispatch_pending)(self.display) == -1 {
+                return Err(format!("Wayland: connection to compositor lost (error {})", self.get_error()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handles the events that are not specific to one window (globals, seat,
+    /// keymap, clipboard), returns the event if it has to be handled by a window
+    pub(crate) fn handle_global_event(&mut self, event: WaylandEvent) -> Option<WaylandEvent> {
+
+        use self::WaylandEvent::*;
+
+        match event {
+            Global { name, interface, version } => {
+                unsafe { self.bind_global(name, &interface, version) };
+                None
+            },
+            Ping { serial } => {
+                unsafe { self.client.marshal(self.wm_base, XDG_WM_BASE_PONG, &mut [WlArgument { u: serial }]) };
+                None
+            },
+            SeatCapabilities { capabilities } => {
+                unsafe { self.update_seat(capabilities) };
+                None
+            },
+            Keymap { keymap } => {
+                self.set_keymap(keymap);
+                None
+            },
+            Modifiers { depressed, latched, locked, group } => {
+                if let (Some(xkb), false) = (self.xkb.as_ref(), self.xkb_state.is_null()) {
+                    unsafe { (xkb.xkb_state_update_mask)(self.xkb_state, depressed, latched, locked, 0, 0, group) };
+                }
+                None
+            },
+            DataOffer { offer } => {
+                if let Ok(mut clipboard) = self.clipboard.try_borrow_mut() {
+                    clipboard.offers.insert(offer as usize, Vec::new());
+                }
+                None
+            },
+            DataOfferMimeType { offer, mime_type } => {
+                if let Ok(mut clipboard) = self.clipboard.try_borrow_mut() {
+                    clipboard.offers.entry(offer as usize).or_insert_with(Vec::new).push(mime_type);
+                }
+                None
+            },
+            Selection { offer } => {
+                if let Ok(mut clipboard) = self.clipboard.try_borrow_mut() {
+                    clipboard.set_selection(offer);
+                }
+                None
+            },
+            DataSourceSend { source, mime_type, fd } => {
+                if let Ok(clipboard) = self.clipboard.try_borrow() {
+                    clipboard.send(source, &mime_type, fd);
+                }
+                None
+            },
+            DataSourceCancelled { source } => {
+                if let Ok(mut clipboard) = self.clipboard.try_borrow_mut() {
+                    clipboard.cancel_source(source);
+                }
+                None
+            },
+            PointerEnter { serial, surface, x, y } => {
+                self.pointer_focus = surface;
+                self.pointer_serial = serial;
+                Some(PointerEnter { serial, surface, x, y })
+            },
+            PointerLeave { surface } => {
+                self.pointer_focus = ptr::null_mut();
+                Some(PointerLeave { surface })
+            },
+            PointerButton { serial, button, pressed } => {
+                self.set_input_serial(serial);
+                Some(PointerButton { serial, button, pressed })
+            },
+            KeyboardEnter { serial, surface } => {
+                self.keyboard_focus = surface;
+                self.set_input_serial(serial);
+                Some(KeyboardEnter { serial, surface })
+            },
+            KeyboardLeave { surface } => {
+                self.keyboard_focus = ptr::null_mut();
+                Some(KeyboardLeave { surface })
+            },
+            Key { serial, key, pressed } => {
+                self.set_input_serial(serial);
+                Some(Key { serial, key, pressed })
+            },
+            other => Some(other),
+        }
+    }
+
+    unsafe fn bind_global(&mut self, name: u32, interface: &str, version: u32) {
+        match interface {
+            "wl_compositor" if self.compositor.is_null() => {
+                self.compositor = self.bind(name, self.client.wl_compositor_interface, version.min(WL_COMPOSITOR_VERSION));
+            },
+            "xdg_wm_base" if self.wm_base.is_null() => {
+                self.wm_base = self.bind(name, &XDG_WM_BASE_INTERFACE, 1);
+                self.add_listener(self.wm_base, &XDG_WM_BASE_LISTENER);
+            },
+            "wl_seat" if self.seat.is_null() => {
+                self.seat = self.bind(name, self.client.wl_seat_interface, version.min(WL_SEAT_VERSION));
+                self.add_listener(self.seat, &SEAT_LISTENER);
+            },
+            "wl_data_device_manager" if self.data_device_manager.is_null() => {
+                self.data_device_manager = self.bind(
+                    name,
+                    self.client.wl_data_device_manager_interface,
+                    version.min(WL_DATA_DEVICE_MANAGER_VERSION)
+                );
+            },
+            "wp_viewporter" if self.viewporter.is_null() => {
+                self.viewporter = self.bind(name, &WP_VIEWPORTER_INTERFACE, 1);
+            },
+            "wp_fractional_scale_manager_v1" if self.fractional_scale_manager.is_null() => {
+                self.fractional_scale_manager = self.bind(name, &WP_FRACTIONAL_SCALE_MANAGER_INTERFACE, 1);
+            },
+            "wp_cursor_shape_manager_v1" if self.cursor_shape_manager.is_null() => {
+                self.cursor_shape_manager = self.bind(name, &WP_CURSOR_SHAPE_MANAGER_INTERFACE, 1);
+            },
+            _ => { },
+        }
+    }
+
+    /// Creates the pointer, keyboard and clipboard devices of the seat
+    unsafe fn update_seat(&mut self, capabilities: u32) {
+
+        if capabilities & WL_SEAT_CAPABILITY_POINTER != 0 && self.pointer.is_null() {
+            self.pointer = self.client.marshal_constructor(
+                self.seat,
+                WL_SEAT_GET_POINTER,
+                &mut [WlArgument { n: 0 }],
+                self.client.wl_pointer_interface,
+            );
+            self.add_listener(self.pointer, &POINTER_LISTENER);
+
+            if !self.cursor_shape_manager.is_null() {
+                self.cursor_shape_device = self.client.marshal_constructor(
+                    self.cursor_shape_manager,
+                    WP_CURSOR_SHAPE_MANAGER_GET_POINTER,
+                    &mut [WlArgument { n: 0 }, WlArgument { o: self.pointer }],
+                    &WP_CURSOR_SHAPE_DEVICE_INTERFACE,
+                );
+            }
+        }
+
+        if capabilities & WL_SEAT_CAPABILITY_KEYBOARD != 0 && self.keyboard.is_null() {
+            self.keyboard = self.client.marshal_constructor(
+                self.seat,
+                WL_SEAT_GET_KEYBOARD,
+                &mut [WlArgument { n: 0 }],
+                self.client.wl_keyboard_interface,
+            );
+            self.add_listener(self.keyboard, &KEYBOARD_LISTENER);
+        }
+
+        let mut clipboard = match self.clipboard.try_borrow_mut() {
+            Ok(o) => o,
+            Err(_) => return,
+        };
+
+        if clipboard.data_device.is_null() && !self.data_device_manager.is_null() {
+            let data_device = self.client.marshal_constructor(
+                self.data_device_manager,
+                WL_DATA_DEVICE_MANAGER_GET_DATA_DEVICE,
+                &mut [WlArgument { n: 0 }, WlArgument { o: self.seat }],
+                self.client.wl_data_device_interface,
+            );
+            (self.client.wl_proxy_add_listener)(
+                data_device,
+                &DATA_DEVICE_LISTENER as *const WlDataDeviceListener as *const c_void,
+                &mut *self.queue as *mut EventQueue as *mut c_void,
+            );
+            clipboard.data_device_manager = self.data_device_manager;
+            clipboard.data_device = data_device;
+        }
+    }
+
+    fn set_input_serial(&mut self, serial: u32) {
+        self.input_serial = serial;
+        if let Ok(mut clipboard) = self.clipboard.try_borrow_mut() {
+            clipboard.serial = serial;
+        }
+    }
+
+    fn set_keymap(&mut self, keymap: Option<String>) {
+
+        let xkb = match self.xkb.as_ref() {
+            Some(s) => s.clone(),
+            None => return,
+        };
+
+        unsafe {
+            if !self.xkb_state.is_null() {
+                (xkb.xkb_state_unref)(self.xkb_state);
+                self.xkb_state = ptr::null_mut();
+            }
+            if !self.xkb_keymap.is_null() {
+                (xkb.xkb_keymap_unref)(self.xkb_keymap);
+                self.xkb_keymap = ptr::null_mut();
+            }
+
+            let keymap = match keymap {
+                Some(s) if !self.xkb_context.is_null() => to_cstring(&s),
+                _ => return,
+            };
+
+            self.xkb_keymap = (xkb.xkb_keymap_new_from_string)(self.xkb_context, keymap.as_ptr(), XKB_KEYMAP_FORMAT_TEXT_V1, 0);
+            if !self.xkb_keymap.is_null() {
+                self.xkb_state = (xkb.xkb_state_new)(self.xkb_keymap);
+            }
+        }
+    }
+
+    /// Translates an evdev key code from wl_keyboard.key with the current keymap
+    /// and modifiers, returns the virtual key code and the character of the key
+    pub(crate) fn get_key(&self, key: u32) -> (Option<VirtualKeyCode>, Option<char>) {
+
+        let xkb = match self.xkb.as_ref() {
+            Some(s) if !self.xkb_state.is_null() => s,
+            _ => return (None, None),
+        };
+
+        let keycode = key + XKB_EVDEV_OFFSET;
+        let keysym = unsafe { (xkb.xkb_state_key_get_one_sym)(self.xkb_state, keycode) };
+        let utf32 = unsafe { (xkb.xkb_state_key_get_utf32)(self.xkb_state, keycode) };
+        let c = if utf32 == 0 { None } else { core::char::from_u32(utf32) };
+
+        (translate_keysym(keysym), c)
+    }
+
+    /// Sets the cursor of the pointer, `None` hides the cursor
+    ///
+    /// Without wp_cursor_shape_v1 the compositor keeps showing its default cursor
+    pub(crate) fn set_cursor(&mut self, cursor: Option<MouseCursorType>) {
+
+        if self.pointer.is_null() {
+            return;
+        }
+
+        match cursor {
+            Some(c) => {
+                if self.cursor_shape_device.is_null() {
+                    return;
+                }
+                unsafe {
+                    self.client.marshal(self.cursor_shape_device, WP_CURSOR_SHAPE_DEVICE_SET_SHAPE, &mut [
+                        WlArgument { u: self.pointer_serial },
+                        WlArgument { u: translate_cursor_shape(c) },
+                    ]);
+                }
+            },
+            None => unsafe {
+                self.client.marshal(self.pointer, WL_POINTER_SET_CURSOR, &mut [
+                    WlArgument { u: self.pointer_serial },
+                    WlArgument { o: ptr::null_mut() },
+                    WlArgument { i: 0 },
+                    WlArgument { i: 0 },
+                ]);
+            },
+        }
+    }
+
+    /// Creates a wl_surface with the xdg_surface and xdg_toplevel role, the
+    /// surface has to be committed before the compositor sends the first configure event
+    pub(crate) fn create_toplevel(&mut self) -> Result<(*mut WlProxy, *mut WlProxy, *mut WlProxy), String> {
+
+        let (surface, xdg_surface) = self.create_xdg_surface()?;
+
+        unsafe {
+            let toplevel = self.client.marshal_constructor(
+                xdg_surface,
+                XDG_SURFACE_GET_TOPLEVEL,
+                &mut [WlArgument { n: 0 }],
+                &XDG_TOPLEVEL_INTERFACE,
+            );
+            self.add_listener(toplevel, &XDG_TOPLEVEL_LISTENER);
+
+            if surface.is_null() || xdg_surface.is_null() || toplevel.is_null() {
+                return Err(format!("Wayland: could not create xdg_toplevel surface"));
+            }
+
+            Ok((surface, xdg_surface, toplevel))
+        }
+    }
+
+    /// Creates a wl_surface with the xdg_surface and xdg_popup role, `rect` (x, y, width, height)
+    /// is relative to the `parent` xdg_surface. The compositor moves the popup so that it stays
+    /// on the screen. A popup that grabs the input gets the keyboard focus and is dismissed
+    /// (xdg_popup.popup_done) when the user clicks outside of it.
+    pub(crate) fn create_popup(
+        &mut self,
+        parent: *mut WlProxy,
+        rect: (i32, i32, i32, i32),
+        grab: bool,
+    ) -> Result<(*mut WlProxy, *mut WlProxy, *mut WlProxy), String> {
+
+        let (surface, xdg_surface) = self.create_xdg_surface()?;
+        let (x, y, width, height) = rect;
+
+        unsafe {
+            let positioner = self.client.marshal_constructor(
+                self.wm_base,
+                XDG_WM_BASE_CREATE_POSITIONER,
+                &mut [WlArgument { n: 0 }],
+                &XDG_POSITIONER_INTERFACE,
+            );
+
+            // the top left corner of the popup is at (x, y)
+            self.client.marshal(positioner, XDG_POSITIONER_SET_SIZE, &mut [
+                WlArgument { i: width.max(1) },
+                WlArgument { i: height.max(1) },
+            ]);
+            self.client.marshal(positioner, XDG_POSITIONER_SET_ANCHOR_RECT, &mut [
+                WlArgument { i: x },
+                WlArgument { i: y },
+                WlArgument { i: 1 },
+                WlArgument { i: 1 },
+            ]);
+            self.client.marshal(positioner, XDG_POSITIONER_SET_ANCHOR, &mut [WlArgument { u: XDG_POSITIONER_ANCHOR_TOP_LEFT }]);
+            self.client.marshal(positioner, XDG_POSITIONER_SET_GRAVITY, &mut [WlArgument { u: XDG_POSITIONER_GRAVITY_BOTTOM_RIGHT }]);
+            self.client.marshal(positioner, XDG_POSITIONER_SET_CONSTRAINT_ADJUSTMENT, &mut [WlArgument {
+                u: XDG_POSITIONER_CONSTRAINT_ADJUSTMENT_SLIDE_X |
+                   XDG_POSITIONER_CONSTRAINT_ADJUSTMENT_SLIDE_Y |
+                   XDG_POSITIONER_CONSTRAINT_ADJUSTMENT_FLIP_Y
+            }]);
+
+            let popup = self.client.marshal_constructor(
+                xdg_surface,
+                XDG_SURFACE_GET_POPUP,
+                &mut [WlArgument { n: 0 }, WlArgument { o: parent }, WlArgument { o: positioner }],
+                &XDG_POPUP_INTERFACE,
+            );
+            self.add_listener(popup, &XDG_POPUP_LISTENER);
+
+            // the positioner is only needed to create the popup
+            self.client.destroy(positioner, Some(XDG_POSITIONER_DESTROY));
+
+            if surface.is_null() || xdg_surface.is_null() || popup.is_null() {
+                return Err(format!("Wayland: could not create xdg_popup surface"));
+            }
+
+            // the grab has to be requested before the surface is committed
+            if grab && !self.seat.is_null() {
+                self.client.marshal(popup, XDG_POPUP_GRAB, &mut [
+                    WlArgument { o: self.seat },
+                    WlArgument { u: self.input_serial },
+                ]);
+            }
+
+            Ok((surface, xdg_surface, popup))
+        }
+    }
+
+    fn create_xdg_surface(&mut self) -> Result<(*mut WlProxy, *mut WlProxy), String> {
+
+        if self.compositor.is_null() {
+            return Err(format!("Wayland: compositor has no wl_compositor"));
+        }
+
+        if self.wm_base.is_null() {
+            return Err(format!("Wayland: compositor does not support xdg_wm_base (xdg-shell)"));
+        }
+
+        unsafe {
+            let surface = self.client.marshal_constructor(
+                self.compositor,
+                WL_COMPOSITOR_CREATE_SURFACE,
+                &mut [WlArgument { n: 0 }],
+                self.client.wl_surface_interface,
+            );
+
+            let xdg_surface = self.client.marshal_constructor(
+                self.wm_base,
+                XDG_WM_BASE_GET_XDG_SURFACE,
+                &mut [WlArgument { n: 0 }, WlArgument { o: surface }],
+                &XDG_SURFACE_INTERFACE,
+            );
+            self.add_listener(xdg_surface, &XDG_SURFACE_LISTENER);
+
+            Ok((surface, xdg_surface))
+        }
+    }
+}
+
+impl Drop for WaylandDisplay {
+    fn drop(&mut self) {
+        unsafe {
+            if let Some(xkb) = self.xkb.as_ref() {
+                if !self.xkb_state.is_null() { (xkb.xkb_state_unref)(self.xkb_state); }
+                if !self.xkb_keymap.is_null() { (xkb.xkb_keymap_unref)(self.xkb_keymap); }
+                if !self.xkb_context.is_null() { (xkb.xkb_context_unref)(self.xkb_context); }
+            }
+            if let Ok(mut clipboard) = self.clipboard.try_borrow_mut() {
+                clipboard.disconnect();
+            }
+            (self.client.wl_display_disconnect)(self.display);
+        }
+    }
+}
+
+// ---- clipboard
+
+thread_local! {
+    // clipboard of the running Wayland application (set in run()), used by `app::Clipboard`
+    static CLIPBOARD: RefCell<Option<Rc<RefCell<WaylandClipboard>>>> = RefCell::new(None);
+}
+
+/// Clipboard (CLIPBOARD selection) of a wl_seat, using the wl_data_device protocol
+pub(crate) struct WaylandClipboard {
+    client: Rc<WaylandClient>,
+    display: *mut WlDisplay,
+    data_device_manager: *mut WlProxy,
+    data_device: *mut WlProxy,
+    queue: *mut EventQueue,
+    /// wl_data_offer -> offered MIME types
+    offers: BTreeMap<usize, Vec<String>>,
+    /// Current selection offer of the compositor (null if the clipboard is empty)
+    selection: *mut WlProxy,
+    /// wl_data_source + text if this application owns the clipboard
+    source: Option<(*mut WlProxy, String)>,
+    /// Serial of the last input event, required to set the selection
+    serial: u32,
+}
+
+impl WaylandClipboard {
+
+    fn set_selection(&mut self, offer: *mut WlProxy) {
+        // offers that are not the selection are not needed anymore
+        let old_offers = self.offers.keys()
+            .filter(|o| **o != offer as usize)
+            .copied()
+            .collect::<Vec<_>>();
+        for o in old_offers {
+            self.offers.remove(&o);
+            unsafe { self.client.destroy(o as *mut WlProxy, Some(WL_DATA_OFFER_DESTROY)) };
+        }
+        self.selection = offer;
+    }
+
+    fn get_string(&mut self) -> Option<String> {
+
+        use std::io::Read;
+        use std::os::unix::io::AsRawFd;
+        use std::os::unix::net::UnixStream;
+        use std::time::Duration;
+
+        // reading from our own source would deadlock
+        if let Some((_, text)) = self.source.as_ref() {
+            return Some(text.clone());
+        }
+
+        if self.selection.is_null() {
+            return None;
+        }
+
+        let offered = self.offers.get(&(self.selection as usize))?;
+        let mime_type = TEXT_MIME_TYPES.iter().find(|m| offered.iter().any(|o| o == **m))?;
+        let mime_type = to_cstring(mime_type);
+
+        let (mut reader, writer) = UnixStream::pair().ok()?;
+
+        unsafe {
+            // the fd is duplicated by libwayland when the request is marshalled
+            self.client.marshal(self.selection, WL_DATA_OFFER_RECEIVE, &mut [
+                WlArgument { s: mime_type.as_ptr() },
+                WlArgument { h: writer.as_raw_fd() },
+            ]);
+            (self.client.wl_display_flush)(self.display);
+        }
+
+        mem::drop(writer);
+
+        reader.set_read_timeout(Some(Duration::from_secs(1))).ok()?;
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).ok()?;
+        String::from_utf8(buf).ok()
+    }
+
+    fn set_string(&mut self, text: String) -> Option<()> {
+
+        if self.data_device_manager.is_null() || self.data_device.is_null() {
+            return None;
+        }
+
+        if let Some((source, _)) = self.source.take() {
+            unsafe { self.client.destroy(source, Some(WL_DATA_SOURCE_DESTROY)) };
+        }
+
+        unsafe {
+            let source = self.client.marshal_constructor(
+                self.data_device_manager,
+                WL_DATA_DEVICE_MANAGER_CREATE_DATA_SOURCE,
+                &mut [WlArgument { n: 0 }],
+                self.client.wl_data_source_interface,
+            );
+
+            if source.is_null() {
+                return None;
+            }
+
+            (self.client.wl_proxy_add_listener)(
+                source,
+                &DATA_SOURCE_LISTENER as *const WlDataSourceListener as *const c_void,
+                self.queue as *mut c_void,
+            );
+
+            for mime_type in TEXT_MIME_TYPES {
+                let mime_type = to_cstring(mime_type);
+                self.client.marshal(source, WL_DATA_SOURCE_OFFER, &mut [WlArgument { s: mime_type.as_ptr() }]);
+            }
+
+            self.client.marshal(self.data_device, WL_DATA_DEVICE_SET_SELECTION, &mut [
+                WlArgument { o: source },
+                WlArgument { u: self.serial },
+            ]);
+
+            (self.client.wl_display_flush)(self.display);
+
+            self.source = Some((source, text));
+        }
+
+        Some(())
+    }
+
+    /// Another client wants to paste the clipboard of this application
+    fn send(&self, source: *mut WlProxy, mime_type: &str, fd: c_int) {
+
+        use std::fs::File;
+        use std::io::Write;
+        use std::os::unix::io::FromRawFd;
+
+        let mut file = unsafe { File::from_raw_fd(fd) };
+
+        if let Some((own_source, text)) = self.source.as_ref() {
+            if *own_source == source && TEXT_MIME_TYPES.contains(&mime_type) {
+                let _ = file.write_all(text.as_bytes());
+            }
+        }
+    }
+
+    /// Another client has taken over the clipboard
+    fn cancel_source(&mut self, source: *mut WlProxy) {
+        if self.source.as_ref().map(|s| s.0) == Some(source) {
+            self.source = None;
+        }
+        unsafe { self.client.destroy(source, Some(WL_DATA_SOURCE_DESTROY)) };
+    }
+
+    fn disconnect(&mut self) {
+        if let Some((source, _)) = self.source.take() {
+            unsafe { self.client.destroy(source, Some(WL_DATA_SOURCE_DESTROY)) };
+        }
+        self.set_selection(ptr::null_mut());
+        self.data_device = ptr::null_mut();
+        self.data_device_manager = ptr::null_mut();
+    }
+}
+
+/// Returns whether the application is running on Wayland (and the
+/// clipboard has to be accessed via `get_clipboard_string`)
+pub(crate) fn is_connected() -> bool {
+    CLIPBOARD.with(|c| c.borrow().is_some())
+}
+
+/// Returns the text in the clipboard of the Wayland seat
+pub(crate) fn get_clipboard_string() -> Option<String> {
+    CLIPBOARD.with(|c| {
+        let c = c.borrow();
+        let mut clipboard = c.as_ref()?.try_borrow_mut().ok()?;
+        clipboard.get_string()
+    })
+}
+
+/// Sets the text in the clipboard of the Wayland seat
+pub(crate) fn set_clipboard_string(text: String) -> Option<()> {
+    CLIPBOARD.with(|c| {
+        let c = c.borrow();
+        let mut clipboard = c.as_ref()?.try_borrow_mut().ok()?;
+        clipboard.set_string(text)
+    })
+}
+
+// ---- translation
+
+/// Translates a cursor to its `wp_cursor_shape_device_v1.shape` value
+pub(crate) fn translate_cursor_shape(cursor: MouseCursorType) -> u32 {
+    use azul_core::window::MouseCursorType::*;
+    match cursor {
+        Default | Arrow => 1,
+        ContextMenu => 2,
+        Help => 3,
+        Hand => 4,
+        Progress => 5,
+        Wait => 6,
+        Cell => 7,
+        Crosshair => 8,
+        Text => 9,
+        VerticalText => 10,
+        Alias => 11,
+        Copy => 12,
+        Move => 13,
+        NoDrop => 14,
+        NotAllowed => 15,
+        Grab => 16,
+        Grabbing => 17,
+        EResize => 18,
+        NResize => 19,
+        NeResize => 20,
+        NwResize => 21,
+        SResize => 22,
+        SeResize => 23,
+        SwResize => 24,
+        WResize => 25,
+        EwResize => 26,
+        NsResize => 27,
+        NeswResize => 28,
+        NwseResize => 29,
+        ColResize => 30,
+        RowResize => 31,
+        AllScroll => 32,
+        ZoomIn => 33,
+        ZoomOut => 34,
+    }
+}
+
+/// Translates the states of an xdg_toplevel.configure event to a `WindowFrame`
+pub(crate) fn translate_toplevel_states(states: &[u32]) -> WindowFrame {
+    if states.contains(&XDG_TOPLEVEL_STATE_FULLSCREEN) {
+        WindowFrame::Fullscreen
+    } else if states.contains(&XDG_TOPLEVEL_STATE_MAXIMIZED) {
+        WindowFrame::Maximized
+    } else {
+        WindowFrame::Normal
+    }
+}
+
+/// Returns the rectangle (x, y, width, height) of a popup at the physical `position`,
+/// relative to the surface of the `parent` window and in logical pixels
+fn get_popup_rect(position: PhysicalPositionI32, size: LogicalSize, parent: &FullWindowState) -> (i32, i32, i32, i32) {
+
+    let parent_position = match parent.position {
+        WindowPosition::Initialized(p) => p,
+        WindowPosition::Uninitialized => PhysicalPositionI32 { x: 0, y: 0 },
+    };
+
+    let hidpi_factor = parent.size.hidpi_factor;
+
+    (
+        ((position.x - parent_position.x) as f32 / hidpi_factor).round() as i32,
+        ((position.y - parent_position.y) as f32 / hidpi_factor).round() as i32,
+        size.width.round() as i32,
+        size.height.round() as i32,
+    )
+}
+
+// ---- windows
+
+struct WaylandWindow {
+    // Wayland objects of the window, either `toplevel` or `popup` is null
+    pub surface: *mut WlProxy,
+    pub xdg_surface: *mut WlProxy,
+    pub toplevel: *mut WlProxy,
+    pub popup: *mut WlProxy,
+    /// Window that the popup belongs to, has to be destroyed after the popup
+    pub popup_parent: Option<usize>,
+    // null if the compositor doesn't support fractional scaling
+    pub viewport: *mut WlProxy,
+    pub fractional_scale: *mut WlProxy,
+    // size + frame of the last xdg_toplevel.configure, applied on xdg_surface.configure
+    pub pending_size: Option<(i32, i32)>,
+    pub pending_frame: WindowFrame,
+    // whether the first configure event has been acknowledged (nothing can be rendered before)
+    pub configured: bool,
+    // EGL OpenGL 3.2 context, rendering into the wl_egl_window
+    pub egl_window: *mut c_void,
+    pub egl_surface: EGLSurface,
+    pub egl_display: EGLDisplay,
+    pub egl_context: EGLContext,
+    // libwayland-client.so (dynamically loaded)
+    pub client: Rc<WaylandClient>,
+    pub display: *mut WlDisplay,
+    // libwayland-egl.so (dynamically loaded)
+    pub wayland_egl: Rc<WaylandEgl>,
+    // libEGL.so library (dynamically loaded)
+    pub egl: Rc<Egl>,
+    // OpenGL functions, loaded from libEGL.so
+    pub gl_functions: GlFunctions,
+    /// See azul-core, stores the entire UI (DOM, CSS styles, layout results, etc.)
+    pub internal: WindowInternal,
+    /// OpenGL context pointer with compiled SVG and FXAA shaders
+    pub gl_context_ptr: OptionGlContextPtr,
+    /// Main render API that can be used to register and un-register fonts and images
+    pub render_api: WrRenderApi,
+    /// WebRender renderer implementation (software or hardware)
+    pub renderer: Option<WrRenderer>,
+    /// Hit-tester, lazily initialized and updated every time the display list changes layout
+    pub hit_tester: AsyncHitTester,
+    /// Connection to the AT-SPI accessibility bus, `None` if no screen reader support is available
+    pub accessibility: Option<AtSpiBridge>,
+    /// Next ticks of the running timers and threads
+    pub timers: LinuxTimers,
+    /// Windows that the callbacks of this window created, created by the event loop
+    pub windows_created: Vec<WindowCreateOptions>,
+}
+
+impl WaylandWindow {
+
+    /// Creates a toplevel window or, if the window is undecorated and has a position,
+    /// a popup of the `parent` window (the window whose callback created the window)
+    fn new(
+        wayland_display: &mut WaylandDisplay,
+        wayland_egl: Rc<WaylandEgl>,
+        egl: Rc<Egl>,
+        options: &mut WindowCreateOptions,
+        parent: Option<&WaylandWindow>,
+        shared_application_data: SharedApplicationData
+    ) -> Result<Self, LinuxStartupError> {
+
+        use self::LinuxStartupError::Create;
+        use self::LinuxWindowCreateError::{Wayland as WaylandError, Egl as EglError};
+        use azul_core::window::{RendererType, HwAcceleration};
+        use azul_core::gl::GlContextPtr;
+        use webrender::api::ColorF as WrColorF;
+        use webrender::ProgramCache as WrProgramCache;
+        use crate::{
+            compositor::Compositor,
+            wr_translate::{
+                translate_document_id_wr,
+                translate_id_namespace_wr,
+                wr_translate_debug_flags,
+                wr_translate_document_id,
+            },
+        };
+        use azul_core::callbacks::PipelineId;
+
+        let client = wayland_display.client.clone();
+
+        let popup_parent = match (parent, options.state.position) {
+            (Some(parent), WindowPosition::Initialized(position)) if !options.state.flags.has_decorations => {
+                Some((parent, position))
+            },
+            _ => None,
+        };
+
+        let (surface, xdg_surface, toplevel, popup) = match popup_parent {
+            Some((parent, position)) => {
+                let rect = get_popup_rect(position, options.state.size.dimensions, &parent.internal.current_window_state);
+                // popups that take the focus (i.e. dropdown lists) receive the keyboard input
+                let grab = options.state.flags.has_focus;
+                let (surface, xdg_surface, popup) = wayland_display.create_popup(parent.xdg_surface, rect, grab)
+                    .map_err(|e| Create(WaylandError(e)))?;
+                (surface, xdg_surface, ptr::null_mut(), popup)
+            },
+            None => {
+                let (surface, xdg_surface, toplevel) = wayland_display.create_toplevel()
+                    .map_err(|e| Create(WaylandError(e)))?;
+
+                let title = to_cstring(options.state.title.as_str());
+                unsafe { client.marshal(toplevel, XDG_TOPLEVEL_SET_TITLE, &mut [WlArgument { s: title.as_ptr() }]) };
+
+                if let Some(app_id) = options.state.platform_specific_options.linux_options.wayland_app_id.as_ref() {
+                    let app_id = to_cstring(app_id.as_str());
+                    unsafe { client.marshal(toplevel, XDG_TOPLEVEL_SET_APP_ID, &mut [WlArgument { s: app_id.as_ptr() }]) };
+                }
+
+                if let Some(min) = options.state.size.min_dimensions.into_option() {
+                    unsafe { client.marshal(toplevel, XDG_TOPLEVEL_SET_MIN_SIZE, &mut [
+                        WlArgument { i: min.width.round().max(0.0) as i32 },
+                        WlArgument { i: min.height.round().max(0.0) as i32 },
+                    ]) };
+                }
+
+                if let Some(max) = options.state.size.max_dimensions.into_option() {
+                    unsafe { client.marshal(toplevel, XDG_TOPLEVEL_SET_MAX_SIZE, &mut [
+                        WlArgument { i: max.width.round().max(0.0) as i32 },
+                        WlArgument { i: max.height.round().max(0.0) as i32 },
+                    ]) };
+                }
+
+                // the position of the toplevel is unknown, positions are relative to the toplevel
+                options.state.position = WindowPosition::Initialized(PhysicalPositionI32 { x: 0, y: 0 });
+
+                (surface, xdg_surface, toplevel, ptr::null_mut())
+            },
+        };
+
+        // fractional scaling: the buffer is rendered at the physical size,
+        // the viewport scales it down to the logical size of the surface
+        let (viewport, fractional_scale) = if !wayland_display.viewporter.is_null() &&
+                                              !wayland_display.fractional_scale_manager.is_null() {
+            unsafe {
+                let viewport = client.marshal_constructor(
+                    wayland_display.viewporter,
+                    WP_VIEWPORTER_GET_VIEWPORT,
+                    &mut [WlArgument { n: 0 }, WlArgument { o: surface }],
+                    &WP_VIEWPORT_INTERFACE,
+                );
+                let fractional_scale = client.marshal_constructor(
+                    wayland_display.fractional_scale_manager,
+                    WP_FRACTIONAL_SCALE_MANAGER_GET_FRACTIONAL_SCALE,
+                    &mut [WlArgument { n: 0 }, WlArgument { o: surface }],
+                    &WP_FRACTIONAL_SCALE_INTERFACE,
+                );
+                wayland_display.add_listener(fractional_scale, &FRACTIONAL_SCALE_LISTENER);
+                (viewport, fractional_scale)
+            }
+        } else {
+            (ptr::null_mut(), ptr::null_mut())
+        };
+
+        // the scale is only known after the surface has been mapped (preferred_scale event)
+        options.state.size.dpi = 96;
+        options.state.size.hidpi_factor = 1.0;
+        options.state.size.system_hidpi_factor = 1.0;
+
+        // initial commit without a buffer, the compositor answers with the first configure event
+        unsafe { client.marshal(surface, WL_SURFACE_COMMIT, &mut []) };
+
+        let logical_size = options.state.size.dimensions;
+        let physical_size = logical_size.to_physical(options.state.size.hidpi_factor);
+
+        let egl_window = unsafe { (wayland_egl.wl_egl_window_create)(
+            surface,
+            physical_size.width.max(1) as c_int,
+            physical_size.height.max(1) as c_int,
+        ) };
+
+        if egl_window.is_null() {
+            return Err(Create(WaylandError(format!("Wayland: wl_egl_window_create() failed"))));
+        }
+
+        let egl_display = (egl.eglGetDisplay)(wayland_display.display as *mut c_void);
+        if egl_display == EGL_NO_DISPLAY {
+            return Err(Create(EglError(format!("EGL: eglGetDisplay(): no display"))));
+        }
+
+        let mut major = 0;
+        let mut minor = 0;
+
+        let init_result = (egl.eglInitialize)(egl_display, &mut major, &mut minor);
+        if init_result != EGL_TRUE {
+            return Err(Create(EglError(format!("EGL: eglInitialize(): cannot initialize display: {}", init_result))));
+        }
+
+        // choose OpenGL API for EGL, by default it uses OpenGL ES
+        let egl_bound = (egl.eglBindAPI)(EGL_OPENGL_API);
+        if egl_bound != EGL_TRUE {
+            return Err(Create(EglError(format!("EGL: eglBindAPI(): Failed to select OpenGL API for EGL: {}", egl_bound))));
+        }
+
+        let egl_attr = [
+
+            EGL_SURFACE_TYPE,      EGL_WINDOW_BIT,
+            EGL_CONFORMANT,        EGL_OPENGL_BIT,
+            EGL_RENDERABLE_TYPE,   EGL_OPENGL_BIT,
+            EGL_COLOR_BUFFER_TYPE, EGL_RGB_BUFFER,
+
+            EGL_RED_SIZE,      8,
+            EGL_GREEN_SIZE,    8,
+            EGL_BLUE_SIZE,     8,
+            EGL_DEPTH_SIZE,   24,
+            EGL_STENCIL_SIZE,  8,
+
+            EGL_NONE,
+        ];
+
+        let mut config: EGLConfig = unsafe { mem::zeroed() };
+        let mut count = 0;
+        let egl_config_chosen = (egl.eglChooseConfig)(egl_display, egl_attr.as_ptr(), &mut config, 1, &mut count);
+        if egl_config_chosen != EGL_TRUE {
+            return Err(Create(EglError(format!("EGL: eglChooseConfig(): Cannot choose EGL config: {}", egl_config_chosen))));
+        }
+
+        if count != 1 {
+            return Err(Create(EglError(format!("EGL: eglChooseConfig(): Expected 1 EglConfig, got {}", count))));
+        }
+
+        let egl_surface_attr = [
+            EGL_GL_COLORSPACE, EGL_GL_COLORSPACE_LINEAR,
+            EGL_RENDER_BUFFER, EGL_BACK_BUFFER,
+            EGL_NONE,
+        ];
+
+        let egl_surface = (egl.eglCreateWindowSurface)(
+            egl_display,
+            config,
+            egl_window,
+            egl_surface_attr.as_ptr()
+        );
+
+        if egl_surface == EGL_NO_SURFACE {
+            return Err(Create(EglError(format!("EGL: eglCreateWindowSurface(): no surface found"))));
+        }
+
+        let egl_context_attr = [
+            EGL_CONTEXT_MAJOR_VERSION, 3,
+            EGL_CONTEXT_MINOR_VERSION, 2,
+            EGL_CONTEXT_OPENGL_PROFILE_MASK, EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT,
+            EGL_NONE,
+        ];
+
+        let egl_context = (egl.eglCreateContext)(egl_display, config, EGL_NO_CONTEXT, egl_context_attr.as_ptr());
+        if egl_context == EGL_NO_CONTEXT {
+            let err = (egl.eglGetError)();
+            return Err(Create(EglError(format!("EGL: eglCreateContext() failed with status {} = {}", err, display_egl_status(err)))));
+        }
+
+        let egl_is_current = (egl.eglMakeCurrent)(egl_display, egl_surface, egl_surface, egl_context);
+        if egl_is_current != EGL_TRUE {
+            return Err(Create(EglError(format!("EGL: eglMakeCurrent(): failed to make context current: {}", egl_is_current))));
+        }
+
+        // eglSwapBuffers must not block waiting for frame callbacks:
+        // hidden / minimized surfaces don't get any
+        (egl.eglSwapInterval)(egl_display, 0);
+
+        let mut gl_functions = GlFunctions::initialize(egl.clone());
+        gl_functions.load();
+
+        // Initialize WebRender - the EGL context above is always hardware
+        // accelerated, software rendering is only used if explicitly requested
+        let rt = match options.renderer.into_option() {
+            Some(s) => match s.hw_accel {
+                HwAcceleration::Disabled => RendererType::Software,
+                HwAcceleration::DontCare | HwAcceleration::Enabled => RendererType::Hardware,
+            },
+            None => RendererType::Hardware,
+        };
+
+        // compiles SVG and FXAA shader programs...
+        let gl_context_ptr = Some(GlContextPtr::new(
+            rt,
+            gl_functions.functions.clone()
+        )).into();
+
+        // Invoke callback to initialize UI for the first time
+        let (mut renderer, sender) = WrRenderer::new(
+            gl_functions.functions.clone(),
+            Box::new(Notifier {}),
+            WrRendererOptions {
+                resource_override_path: None,
+                use_optimized_shaders: true,
+                enable_aa: true,
+                enable_subpixel_aa: true,
+                force_subpixel_aa: true,
+                clear_color: WrColorF {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.0,
+                }, // transparent
+                panic_on_gl_error: false,
+                precache_flags: WrShaderPrecacheFlags::EMPTY,
+                cached_programs: Some(WrProgramCache::new(None)),
+                enable_multithreading: true,
+                debug_flags: wr_translate_debug_flags(&options.state.debug_state),
+                ..WrRendererOptions::default()
+            },
+            WR_SHADER_CACHE,
+        ).map_err(|e| Create(EglError(format!("Could not init WebRender: {:?}", e))))?;
+
+        renderer.set_external_image_handler(Box::new(Compositor::default()));
+
+        let mut render_api = sender.create_api();
+
+        let framebuffer_size = WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32);
+        let document_id = translate_document_id_wr(render_api.add_document(framebuffer_size));
+        let pipeline_id = PipelineId::new();
+        let id_namespace = translate_id_namespace_wr(render_api.get_namespace_id());
+
+        // hit tester will be empty on startup
+        let hit_tester = render_api
+            .request_hit_tester(wr_translate_document_id(document_id))
+            .resolve();
+
+        let hit_tester_ref = &*hit_tester;
+
+        let mut appdata_lock = match shared_application_data.inner.try_borrow_mut() {
+            Ok(o) => o,
+            Err(e) => return Err(Create(EglError(format!("could not lock application data")))),
+        };
+
+        let appdata_lock = &mut *appdata_lock;
+        let fc_cache = &mut appdata_lock.fc_cache;
+        let image_cache = &appdata_lock.image_cache;
+        let data = &mut appdata_lock.data;
+
+        let mut initial_resource_updates = Vec::new();
+        let mut internal = fc_cache.apply_closure(|fc_cache| {
+            use azul_core::window::WindowInternalInit;
+
+            WindowInternal::new(
+                WindowInternalInit {
+                    window_create_options: options.clone(),
+                    document_id,
+                    id_namespace,
+                },
+                data,
+                image_cache,
+                &gl_context_ptr,
+                &mut initial_resource_updates,
+                &crate::app::CALLBACKS,
+                fc_cache,
+                azul_layout::do_the_relayout,
+                |window_state, scroll_states, layout_results| {
+                    crate::wr_translate::fullhittest_new_webrender(
+                        hit_tester_ref,
+                        document_id,
+                        window_state.focused_node,
+                        layout_results,
+                        &window_state.mouse_state.cursor_position,
+                        window_state.size.hidpi_factor,
+                    )
+                },
+            )
+        });
+
+        let mut txn = WrTransaction::new();
+
+        // re-layout the window content for the first frame
+        // (since the width / height might have changed)
+        let size = internal.current_window_state.size.clone();
+        let theme = internal.current_window_state.theme;
+        let resize_result = fc_cache.apply_closure(|fc_cache| {
+            internal.do_quick_resize(
+                &image_cache,
+                &crate::app::CALLBACKS,
+                azul_layout::do_the_relayout,
+                fc_cache,
+                &gl_context_ptr,
+                &size,
+                theme,
+            )
+        });
+
+        wr_synchronize_updated_images(resize_result.updated_images, &document_id, &mut txn);
+
+        txn.set_document_view(
+            WrDeviceIntRect::from_size(
+                WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32),
+            )
+        );
+
+        render_api.send_transaction(wr_translate_document_id(internal.document_id), txn);
+
+        render_api.flush_scene_builder();
+
+        // Build the display list and send it to webrender for the first time
+        rebuild_display_list(
+            &mut internal,
+            &mut render_api,
+            &appdata_lock.image_cache,
+            initial_resource_updates,
+        );
+
+        render_api.flush_scene_builder();
+
+        generate_frame(
+            &mut internal,
+            &mut render_api,
+            true,
+        );
+
+        render_api.flush_scene_builder();
+
+        // Update the hit-tester to account for the new hit-testing functionality
+        let hit_tester = render_api.request_hit_tester(wr_translate_document_id(document_id));
+
+        // Connect to the screen reader, if there is one
+        let accessibility = match AtSpiBridge::new(options.state.title.as_str()) {
+            Ok(o) => Some(o),
+            Err(e) => {
+                #[cfg(feature = "logging")] {
+                    log::info!("{}", e);
+                }
+                None
+            },
+        };
+
+        let mut window = Self {
+            surface,
+            xdg_surface,
+            toplevel,
+            popup,
+            popup_parent: popup_parent.map(|(parent, _)| parent.surface as usize),
+            viewport,
+            fractional_scale,
+            pending_size: None,
+            pending_frame: WindowFrame::Normal,
+            configured: false,
+            egl_window,
+            egl_surface,
+            egl_display,
+            egl_context,
+            client,
+            display: wayland_display.display,
+            wayland_egl,
+            egl,
+            render_api,
+            hit_tester: AsyncHitTester::Requested(hit_tester),
+            internal,
+            renderer: Some(renderer),
+            gl_functions,
+            gl_context_ptr,
+            accessibility,
+            timers: LinuxTimers::default(),
+            windows_created: Vec::new(),
+        };
+
+        window.publish_accessibility_tree();
+
+        Ok(window)
+    }
+
+    /// Acknowledges a configure event and applies the size / frame of the last xdg_toplevel.configure
+    fn configure(&mut self, serial: u32, appdata: &mut ApplicationData) -> Result<(), LinuxStartupError> {
+
+        use azul_core::window::LogicalSize;
+
+        unsafe { self.client.marshal(self.xdg_surface, XDG_SURFACE_ACK_CONFIGURE, &mut [WlArgument { u: serial }]) };
+
+        let mut new_size = self.internal.current_window_state.size.clone();
+
+        // 0 x 0 = the compositor lets the client decide the size
+        if let Some((width, height)) = self.pending_size.take() {
+            if width > 0 && height > 0 {
+                new_size.dimensions = LogicalSize::new(width as f32, height as f32);
+            }
+        }
+
+        let frame = self.pending_frame;
+        let current_state = &self.internal.current_window_state;
+        if !self.configured || new_size.dimensions != current_state.size.dimensions || frame != current_state.flags.frame {
+            self.resize(new_size, frame, appdata);
+        }
+
+        self.configured = true;
+        self.render()
+    }
+
+    /// Sets the scale factor of the window (from wp_fractional_scale_v1.preferred_scale),
+    /// returns whether the window needs to be re-rendered
+    fn set_scale(&mut self, scale: f32, appdata: &mut ApplicationData) -> bool {
+
+        if self.viewport.is_null() || (scale - self.internal.current_window_state.size.hidpi_factor).abs() < 0.001 {
+            return false;
+        }
+
+        let mut new_size = self.internal.current_window_state.size.clone();
+        new_size.hidpi_factor = scale;
+        new_size.system_hidpi_factor = scale;
+        new_size.dpi = (scale.max(0.0) * 96.0).round() as u32;
+
+        let frame = self.internal.current_window_state.flags.frame;
+        self.resize(new_size, frame, appdata);
+        self.configured
+    }
+
+    /// Resizes the EGL buffer + viewport and re-layouts the window content
+    fn resize(&mut self, new_size: WindowSize, frame: WindowFrame, appdata: &mut ApplicationData) {
+
+        use crate::wr_translate::wr_translate_document_id;
+
+        let mut new_window_state = self.internal.current_window_state.clone();
+        new_window_state.size = new_size;
+        new_window_state.flags.frame = frame;
+
+        let logical_size = new_window_state.size.get_logical_size();
+        let physical_size = new_window_state.size.get_physical_size();
+
+        self.make_current();
+
+        // both are applied on the next commit (eglSwapBuffers)
+        unsafe {
+            (self.wayland_egl.wl_egl_window_resize)(
+                self.egl_window,
+                physical_size.width.max(1) as c_int,
+                physical_size.height.max(1) as c_int,
+                0, 0,
+            );
+            if !self.viewport.is_null() {
+                self.client.marshal(self.viewport, WP_VIEWPORT_SET_DESTINATION, &mut [
+                    WlArgument { i: logical_size.width.round().max(1.0) as i32 },
+                    WlArgument { i: logical_size.height.round().max(1.0) as i32 },
+                ]);
+            }
+        }
+
+        let internal = &mut self.internal;
+        let gl_context_ptr = &self.gl_context_ptr;
+        let image_cache = &appdata.image_cache;
+
+        let resize_result = appdata.fc_cache.apply_closure(|fc_cache| {
+            internal.do_quick_resize(
+                image_cache,
+                &crate::app::CALLBACKS,
+                azul_layout::do_the_relayout,
+                fc_cache,
+                gl_context_ptr,
+                &new_window_state.size,
+                new_window_state.theme,
+            )
+        });
+
+        let mut txn = WrTransaction::new();
+        wr_synchronize_updated_images(resize_result.updated_images, &self.internal.document_id, &mut txn);
+        txn.set_document_view(
+            WrDeviceIntRect::from_size(
+                WrDeviceIntSize::new(physical_size.width as i32, physical_size.height as i32),
+            )
+        );
+        self.render_api.send_transaction(wr_translate_document_id(self.internal.document_id), txn);
+
+        self.internal.previous_window_state = Some(self.internal.current_window_state.clone());
+        self.internal.current_window_state = new_window_state;
+
+        self.rebuild_display_list(&appdata.image_cache, Vec::new());
+    }
+
+    /// Renders the last frame that was sent to WebRender and swaps the buffers
+    fn render(&mut self) -> Result<(), LinuxStartupError> {
+
+        use self::LinuxStartupError::Create;
+        use self::LinuxWindowCreateError::Egl as EglError;
+
+        // no buffer may be attached before the first configure event
+        if !self.configured {
+            return Ok(());
+        }
+
+        let physical_size = self.internal.current_window_state.size.get_physical_size();
+        let width = physical_size.width as i32;
+        let height = physical_size.height as i32;
+
+        self.make_current();
+        self.render_api.flush_scene_builder();
+
+        self.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        self.gl_functions.functions.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
+        self.gl_functions.functions.disable(gl_context_loader::gl::MULTISAMPLE);
+
+        self.gl_functions.functions.viewport(0, 0, width, height);
+        self.gl_functions.functions.clear_color(0.0, 0.0, 0.0, 1.0);
+        self.gl_functions.functions.clear(
+            gl::COLOR_BUFFER_BIT |
+            gl::DEPTH_BUFFER_BIT |
+            gl::STENCIL_BUFFER_BIT
+        );
+
+        let mut current_program = [0_i32];
+        unsafe {
+            self.gl_functions.functions.get_integer_v(
+                gl_context_loader::gl::CURRENT_PROGRAM,
+                (&mut current_program[..]).into()
+            );
+        }
+
+        if let Some(r) = self.renderer.as_mut() {
+            let framebuffer_size = WrDeviceIntSize::new(width, height);
+            r.update();
+            let _ = r.render(framebuffer_size, 0);
+        }
+
+        let swap_result = (self.egl.eglSwapBuffers)(self.egl_display, self.egl_surface);
+        if swap_result != EGL_TRUE {
+            return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
+        }
+
+        self.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        self.gl_functions.functions.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+        self.gl_functions.functions.use_program(current_program[0] as u32);
+
+        Ok(())
+    }
+
+    /// Processes the changes of the `current_window_state` (after an input event):
+    /// hit-tests the cursor position, updates the cursor, invokes the callbacks
+    /// and restyles / relayouts the window. Returns whether the window needs to be re-rendered
+    fn process_input(&mut self, wayland_display: &mut WaylandDisplay, appdata: &mut ApplicationData) -> bool {
+
+        use azul_core::window::{CursorTypeHitTest, OptionMouseCursorType};
+        use azul_core::window_state::{Events, CallbacksOfHitTest};
+
+        self.make_current();
+
+        // cursor moved or the layout changed, so we need a new hit test
+        if self.internal.current_window_state.mouse_state.cursor_position.is_inside_window() {
+            let hit_test = crate::wr_translate::fullhittest_new_webrender(
+                &*self.hit_tester.resolve(),
+                self.internal.document_id,
+                self.internal.current_window_state.focused_node,
+                &self.internal.layout_results,
+                &self.internal.current_window_state.mouse_state.cursor_position,
+                self.internal.current_window_state.size.hidpi_factor,
+            );
+            let cht = CursorTypeHitTest::new(&hit_test, &self.internal.layout_results);
+            self.internal.current_window_state.last_hit_test = hit_test;
+
+            // update the cursor if necessary
+            if self.internal.current_window_state.mouse_state.mouse_cursor_type != OptionMouseCursorType::Some(cht.cursor_icon) {
+                self.internal.current_window_state.mouse_state.mouse_cursor_type = OptionMouseCursorType::Some(cht.cursor_icon);
+                wayland_display.set_cursor(Some(cht.cursor_icon));
+            }
+        }
+
+        // Get events
+        let events = Events::new(
+            &self.internal.current_window_state,
+            &self.internal.previous_window_state,
+        );
+
+        // Get nodes for events
+        let nodes_to_check = NodesToCheck::new(
+            &self.internal.current_window_state.last_hit_test,
+            &events
+        );
+
+        let window_handle = self.get_raw_window_handle();
+        let internal = &mut self.internal;
+        let gl_context_ptr = &self.gl_context_ptr;
+        let image_cache = &mut appdata.image_cache;
+        let system_callbacks = &appdata.config.system_callbacks;
+
+        // Invoke callbacks on nodes
+        let callback_results = appdata.fc_cache.apply_closure(|fc_cache| {
+
+            // Get callbacks for nodes
+            let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &internal.layout_results);
+            let current_scroll_states = internal.get_current_scroll_states();
+
+            // Invoke user-defined callbacks in the UI
+            callbacks.call(
+                &internal.previous_window_state,
+                &internal.current_window_state,
+                &window_handle,
+                &current_scroll_states,
+                gl_context_ptr,
+                &mut internal.layout_results,
+                &mut internal.scroll_states,
+                image_cache,
+                fc_cache,
+                system_callbacks,
+                &internal.renderer_resources,
+            )
+        });
+
+        let should_render = self.process_callback_results(callback_results, &nodes_to_check, appdata);
+        self.publish_accessibility_tree();
+        should_render
+    }
+
+    /// Sends the current accessibility tree to the screen reader (if connected)
+    fn publish_accessibility_tree(&mut self) {
+
+        use azul_core::window::WindowPosition;
+
+        let bridge = match self.accessibility.as_mut() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let state = &self.internal.current_window_state;
+        let window = AccessibleWindow {
+            title: state.title.as_str().to_string(),
+            position: match state.position {
+                WindowPosition::Initialized(p) => (p.x, p.y),
+                WindowPosition::Uninitialized => (0, 0),
+            },
+            size: state.size.get_logical_size(),
+            hidpi_factor: state.size.hidpi_factor,
+            is_focused: state.flags.has_focus,
+        };
+
+        bridge.publish(window, AccessibilityTree::new(&self.internal.layout_results, state.focused_node));
+    }
+
+    /// Answers the requests of the screen reader, waiting at most `timeout_ms` milliseconds
+    /// for new requests. Actions (clicking a button, focusing a node, ...) invoke the callbacks
+    /// of the node, the window is then updated and the new tree is published.
+    fn process_accessibility_requests(
+        &mut self,
+        appdata: &mut ApplicationData,
+        timeout_ms: i32,
+    ) -> Result<(), LinuxStartupError> {
+
+        let requests = match self.accessibility.as_mut() {
+            Some(s) => s.dispatch(timeout_ms),
+            None => return Ok(()),
+        };
+
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        let mut should_render = false;
+
+        for request in requests {
+
+            let window_handle = self.get_raw_window_handle();
+            let internal = &mut self.internal;
+            let gl_context_ptr = &self.gl_context_ptr;
+            let image_cache = &mut appdata.image_cache;
+            let system_callbacks = &appdata.config.system_callbacks;
+
+            let callback_results = appdata.fc_cache.apply_closure(|fc_cache| {
+                internal.invoke_accessibility_action(
+                    request.node,
+                    request.action,
+                    &window_handle,
+                    gl_context_ptr,
+                    image_cache,
+                    fc_cache,
+                    system_callbacks,
+                )
+            });
+
+            let nodes_to_check = NodesToCheck::simulated_mouse_move(
+                &self.internal.current_window_state.last_hit_test,
+                self.internal.current_window_state.focused_node,
+                self.internal.current_window_state.mouse_state.mouse_down()
+            );
+
+            should_render = self.process_callback_results(callback_results, &nodes_to_check, appdata) || should_render;
+        }
+
+        self.publish_accessibility_tree();
+
+        if should_render {
+            self.render()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LinuxWindow for WaylandWindow {
+
+    fn make_current(&self) {
+        (self.egl.eglMakeCurrent)(
+            self.egl_display,
+            self.egl_surface,
+            self.egl_surface,
+            self.egl_context
+        );
+    }
+
+    fn parts(&mut self) -> LinuxWindowParts<'_> {
+        LinuxWindowParts {
+            internal: &mut self.internal,
+            gl_context_ptr: &self.gl_context_ptr,
+            render_api: &mut self.render_api,
+            hit_tester: &mut self.hit_tester,
+            timers: &mut self.timers,
+            windows_created: &mut self.windows_created,
+        }
+    }
+
+    fn get_raw_window_handle(&self) -> RawWindowHandle {
+        RawWindowHandle::Wayland(WaylandHandle {
+            surface: self.surface as *mut c_void,
+            display: self.display as *mut c_void,
+        })
+    }
+}
+
+impl Drop for WaylandWindow {
+    fn drop(&mut self) {
+        unsafe {
+            self.client.destroy(self.fractional_scale, Some(WP_FRACTIONAL_SCALE_DESTROY));
+            self.client.destroy(self.viewport, Some(WP_VIEWPORT_DESTROY));
+            self.client.destroy(self.toplevel, Some(XDG_TOPLEVEL_DESTROY));
+            self.client.destroy(self.popup, Some(XDG_POPUP_DESTROY));
+            self.client.destroy(self.xdg_surface, Some(XDG_SURFACE_DESTROY));
+            (self.wayland_egl.wl_egl_window_destroy)(self.egl_window);
+            self.client.destroy(self.surface, Some(WL_SURFACE_DESTROY));
+        }
+    }
+}
+
+// ---- run
+
+/// Returns whether a Wayland compositor is running and the libraries
+/// required for the Wayland shell can be loaded
+pub fn is_available() -> bool {
+
+    if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        return false;
+    }
+
+    if WaylandEgl::new().is_err() {
+        return false;
+    }
+
+    let client = match WaylandClient::new() {
+        Ok(o) => o,
+        Err(_) => return false,
+    };
+
+    let display = unsafe { (client.wl_display_connect)(ptr::null()) };
+    if display.is_null() {
+        return false;
+    }
+
+    unsafe { (client.wl_display_disconnect)(display) };
+    true
+}
+
+/// Main function that starts when app.run() is invoked
+pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, LinuxStartupError> {
+
+    use self::LinuxStartupError::Create;
+    use self::LinuxWindowCreateError::Wayland as WaylandError;
+
+    let App {
+        data,
+        config,
+        mut windows,
+        image_cache,
+        fc_cache,
+    } = app;
+
+    let client = Rc::new(WaylandClient::new().map_err(|e| Create(WaylandError(e)))?);
+    let wayland_egl = Rc::new(WaylandEgl::new().map_err(|e| Create(WaylandError(e)))?);
+    let egl = Rc::new(Egl::new()?);
+
+    // without libxkbcommon, key events only have scancodes
+    let xkb = match Xkb::new() {
+        Ok(o) => Some(Rc::new(o)),
+        Err(e) => {
+            #[cfg(feature = "logging")] {
+                log::warn!("{}", e);
+            }
+            None
+        },
+    };
+
+    let mut wayland_display = WaylandDisplay::connect(client, xkb, None)
+        .map_err(|e| Create(WaylandError(e)))?;
+
+    let app_data_inner = Rc::new(RefCell::new(ApplicationData {
+        data,
+        config,
+        image_cache,
+        fc_cache,
+    }));
+
+    let mut active_windows = BTreeMap::new();
+    let shared_application_data = SharedApplicationData { inner: app_data_inner.clone() };
+
+    for options in windows.iter_mut().chain(core::iter::once(&mut root_window)) {
+        let window = open_window(&mut wayland_display, &wayland_egl, &egl, options, None, &shared_application_data)?;
+        active_windows.insert(window.surface as usize, window);
+    }
+
+    CLIPBOARD.with(|c| *c.borrow_mut() = Some(wayland_display.clipboard.clone()));
+
+    let result = run_event_loop(&mut wayland_display, &wayland_egl, &egl, &mut active_windows, &shared_application_data);
+
+    CLIPBOARD.with(|c| *c.borrow_mut() = None);
+
+    // windows have to be destroyed before the connection is closed
+    mem::drop(active_windows);
+    mem::drop(wayland_display);
+
+    result.map(|_| 0)
+}
+
+/// Creates a window (see `WaylandWindow::new`), then invokes its `AfterMount` and create callbacks
+fn open_window(
+    wayland_display: &mut WaylandDisplay,
+    wayland_egl: &Rc<WaylandEgl>,
+    egl: &Rc<Egl>,
+    options: &mut WindowCreateOptions,
+    parent: Option<&WaylandWindow>,
+    shared_application_data: &SharedApplicationData,
+) -> Result<WaylandWindow, LinuxStartupError> {
+
+    let mut window = WaylandWindow::new(
+        wayland_display,
+        wayland_egl.clone(),
+        egl.clone(),
+        options,
+        parent,
+        shared_application_data.clone()
+    )?;
+
+    let mut appdata_lock = shared_application_data.inner.try_borrow_mut()
+        .map_err(LinuxStartupError::BorrowMut)?;
+
+    if window.run_window_created(&mut options.create_callback, &mut *appdata_lock) {
+        window.publish_accessibility_tree();
+        window.render()?;
+    }
+
+    Ok(window)
+}
+
+/// Removes the windows and their popups, a popup is destroyed before its parent
+fn close_windows(active_windows: &mut BTreeMap<usize, WaylandWindow>, mut windows_to_close: Vec<usize>) {
+
+    let mut i = 0;
+    while i < windows_to_close.len() {
+        let parent = windows_to_close[i];
+        windows_to_close.extend(
+            active_windows.iter()
+            .filter(|(_, w)| w.popup_parent == Some(parent))
+            .map(|(id, _)| *id)
+        );
+        i += 1;
+    }
+
+    for id in windows_to_close.iter().rev() {
+        active_windows.remove(id);
+    }
+}
+
+fn run_event_loop(
+    wayland_display: &mut WaylandDisplay,
+    wayland_egl: &Rc<WaylandEgl>,
+    egl: &Rc<Egl>,
+    active_windows: &mut BTreeMap<usize, WaylandWindow>,
+    shared_application_data: &SharedApplicationData,
+) -> Result<(), LinuxStartupError> {
+
+    use self::LinuxStartupError::Create;
+    use self::LinuxWindowCreateError::Wayland as WaylandError;
+    use azul_core::window::{
+        CursorPosition, FullHitTest, LogicalPosition, OptionMouseCursorType,
+    };
+
+    loop {
+
+        // while there are no Wayland events, answer the requests of the screen reader,
+        // advance the running CSS animations and run the timers
+        let max_timeout_ms = if active_windows.values().any(|w| w.internal.animations.has_running_animations()) {
+            ANIMATION_FRAME_TIME_MS
+        } else if active_windows.values().any(|w| w.accessibility.is_some()) {
+            ACCESSIBILITY_POLL_TIMEOUT_MS
+        } else {
+            -1
+        };
+
+        let next_tick = active_windows.values().filter_map(|w| w.timers.get_next_tick()).min();
+        let timeout_ms = get_poll_timeout_ms(max_timeout_ms, next_tick);
+
+        wayland_display.dispatch(timeout_ms).map_err(|e| Create(WaylandError(e)))?;
+
+        let mut appdata_lock = shared_application_data.inner.try_borrow_mut()
+            .map_err(LinuxStartupError::BorrowMut)?;
+        let appdata = &mut *appdata_lock;
+
+        let mut windows_to_render = BTreeSet::new();
+        let mut windows_to_close = Vec::new();
+
+        for event in wayland_display.take_events() {
+
+            let event = match wayland_display.handle_global_event(event) {
+                Some(s) => s,
+                None => continue,
+            };
+
+            match event {
+                WaylandEvent::Configure { xdg_surface, serial } => {
+                    if let Some(window) = active_windows.values_mut().find(|w| w.xdg_surface == xdg_surface) {
+                        window.configure(serial, appdata)?;
+                    }
+                },
+                WaylandEvent::ToplevelConfigure { toplevel, width, height, states } => {
+                    if let Some(window) = active_windows.values_mut().find(|w| w.toplevel == toplevel) {
+                        window.pending_size = Some((width, height));
+                        window.pending_frame = translate_toplevel_states(&states);
+                    }
+                },
+                WaylandEvent::ToplevelClose { toplevel } => {
+                    if let Some((id, _)) = active_windows.iter().find(|(_, w)| w.toplevel == toplevel) {
+                        windows_to_close.push(*id);
+                    }
+                },
+                WaylandEvent::PopupDone { popup } => {
+                    // the compositor dismissed the popup (i.e. the user clicked outside of it),
+                    // the popup loses the focus before it is closed
+                    if let Some((id, window)) = active_windows.iter_mut().find(|(_, w)| w.popup == popup) {
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        state.flags.has_focus = false;
+                        window.process_input(wayland_display, appdata);
+                        windows_to_close.push(*id);
+                    }
+                },
+                WaylandEvent::PreferredScale { fractional_scale, scale } => {
+                    if let Some((id, window)) = active_windows.iter_mut().find(|(_, w)| w.fractional_scale == fractional_scale) {
+                        if window.set_scale(scale as f32 / FRACTIONAL_SCALE_DENOMINATOR, appdata) {
+                            windows_to_render.insert(*id);
+                        }
+                    }
+                },
+                WaylandEvent::PointerEnter { surface, x, y, .. } => {
+                    if let Some(window) = active_windows.get_mut(&(surface as usize)) {
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        // the cursor has to be set again after every enter event
+                        state.mouse_state.mouse_cursor_type = OptionMouseCursorType::None;
+                        state.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(x as f32, y as f32));
+                        if window.process_input(wayland_display, appdata) {
+                            windows_to_render.insert(surface as usize);
+                        }
+                    }
+                },
+                WaylandEvent::PointerLeave { surface } => {
+                    if let Some(window) = active_windows.get_mut(&(surface as usize)) {
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        let last_seen = match state.mouse_state.cursor_position {
+                            CursorPosition::InWindow(i) => i,
+                            _ => LogicalPosition::zero(),
+                        };
+                        state.mouse_state.cursor_position = CursorPosition::OutOfWindow(last_seen);
+                        state.last_hit_test = FullHitTest::empty(state.focused_node);
+                        state.mouse_state.mouse_cursor_type = OptionMouseCursorType::None;
+                        if window.process_input(wayland_display, appdata) {
+                            windows_to_render.insert(surface as usize);
+                        }
+                    }
+                },
+                WaylandEvent::PointerMotion { x, y } => {
+                    let id = wayland_display.pointer_focus as usize;
+                    if let Some(window) = active_windows.get_mut(&id) {
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        state.mouse_state.cursor_position = CursorPosition::InWindow(LogicalPosition::new(x as f32, y as f32));
+                        if window.process_input(wayland_display, appdata) {
+                            windows_to_render.insert(id);
+                        }
+                    }
+                },
+                WaylandEvent::PointerButton { button, pressed, .. } => {
+                    let id = wayland_display.pointer_focus as usize;
+                    if let Some(window) = active_windows.get_mut(&id) {
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        match button {
+                            BTN_LEFT => state.mouse_state.left_down = pressed,
+                            BTN_RIGHT => state.mouse_state.right_down = pressed,
+                            BTN_MIDDLE => state.mouse_state.middle_down = pressed,
+                            _ => continue,
+                        }
+                        if window.process_input(wayland_display, appdata) {
+                            windows_to_render.insert(id);
+                        }
+                    }
+                },
+                WaylandEvent::PointerAxis { axis, value } => {
+                    let id = wayland_display.pointer_focus as usize;
+                    if let Some(window) = active_windows.get_mut(&id) {
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        // positive = scroll down / right on Wayland, the opposite of a wheel delta
+                        match axis {
+                            WL_POINTER_AXIS_VERTICAL_SCROLL => state.mouse_state.scroll_y = Some(-value as f32).into(),
+                            WL_POINTER_AXIS_HORIZONTAL_SCROLL => state.mouse_state.scroll_x = Some(-value as f32).into(),
+                            _ => continue,
+                        }
+                        if window.process_input(wayland_display, appdata) {
+                            windows_to_render.insert(id);
+                        }
+                        window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+                    }
+                },
+                WaylandEvent::KeyboardEnter { surface, .. } => {
+                    if let Some(window) = active_windows.get_mut(&(surface as usize)) {
+                        window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+                        window.internal.current_window_state.flags.has_focus = true;
+                        if window.process_input(wayland_display, appdata) {
+                            windows_to_render.insert(surface as usize);
+                        }
+                    }
+                },
+                WaylandEvent::KeyboardLeave { surface } => {
+                    if let Some(window) = active_windows.get_mut(&(surface as usize)) {
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        state.flags.has_focus = false;
+                        // key release events are not sent to unfocused surfaces
+                        state.keyboard_state.current_char = None.into();
+                        state.keyboard_state.current_virtual_keycode = None.into();
+                        state.keyboard_state.pressed_virtual_keycodes = Vec::new().into();
+                        state.keyboard_state.pressed_scancodes = Vec::new().into();
+                        if window.process_input(wayland_display, appdata) {
+                            windows_to_render.insert(surface as usize);
+                        }
+                    }
+                },
+                WaylandEvent::Key { key, pressed, .. } => {
+
+                    let id = wayland_display.keyboard_focus as usize;
+                    let (vk, c) = wayland_display.get_key(key);
+
+                    if let Some(window) = active_windows.get_mut(&id) {
+
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        state.keyboard_state.current_char = None.into();
+
+                        if pressed {
+                            state.keyboard_state.pressed_scancodes.insert_hm_item(key);
+                            if let Some(vk) = vk {
+                                state.keyboard_state.current_virtual_keycode = Some(vk).into();
+                                state.keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
+                            }
+                        } else {
+                            state.keyboard_state.pressed_scancodes.remove_hm_item(&key);
+                            if let Some(vk) = vk {
+                                state.keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
+                                state.keyboard_state.current_virtual_keycode = None.into();
+                            }
+                        }
+
+                        let mut should_render = window.process_input(wayland_display, appdata);
+
+                        // same as WM_KEYDOWN + WM_CHAR: the character is
+                        // processed after the key down event
+                        if let Some(c) = c.filter(|c| pressed && !c.is_control()) {
+                            window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+                            window.internal.current_window_state.keyboard_state.current_char = Some(c as u32).into();
+                            should_render = window.process_input(wayland_display, appdata) || should_render;
+                        }
+
+                        if should_render {
+                            windows_to_render.insert(id);
+                        }
+                    }
+                },
+                _ => { },
+            }
+        }
+
+        for (id, window) in active_windows.iter_mut() {
+            if window.run_timers_and_threads(appdata) {
+                window.publish_accessibility_tree();
+                windows_to_render.insert(*id);
+            }
+            if window.internal.animations.has_running_animations() && window.run_animation_frame(appdata) {
+                windows_to_render.insert(*id);
+            }
+        }
+
+        for id in windows_to_render {
+            if let Some(window) = active_windows.get_mut(&id) {
+                window.render()?;
+            }
+        }
+
+        for window in active_windows.values_mut() {
+            window.process_accessibility_requests(appdata, 0)?;
+        }
+
+        // windows that the callbacks created or closed (i.e. popups)
+        let mut windows_created = Vec::new();
+        for (id, window) in active_windows.iter_mut() {
+            windows_created.extend(window.windows_created.drain(..).map(|options| (*id, options)));
+            if window.internal.current_window_state.flags.is_about_to_close {
+                windows_to_close.push(*id);
+            }
+        }
+
+        close_windows(active_windows, windows_to_close);
+
+        // creating a window needs the application data
+        mem::drop(appdata_lock);
+
+        for (parent, mut options) in windows_created {
+            match open_window(wayland_display, wayland_egl, egl, &mut options, active_windows.get(&parent), shared_application_data) {
+                Ok(window) => { active_windows.insert(window.surface as usize, window); },
+                Err(e) => {
+                    #[cfg(feature = "logging")] {
+                        log::error!("Wayland: could not create window: {:?}", e);
+                    }
+                },
+            }
+        }
+
+        if active_windows.is_empty() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::path::PathBuf;
+    use std::process::{Child, Command, Stdio};
+    use std::time::{Duration, Instant};
+
+    // headless weston instance with a private XDG_RUNTIME_DIR, killed when the test ends
+    struct TestCompositor {
+        weston: Child,
+        runtime_dir: PathBuf,
+        socket: String,
+    }
+
+    impl TestCompositor {
+        fn start(name: &str) -> Option<Self> {
+
+            use std::os::unix::fs::PermissionsExt;
+
+            let runtime_dir = std::env::temp_dir().join(format!("azul-wayland-test-{}-{}", name, std::process::id()));
+            std::fs::create_dir_all(&runtime_dir).ok()?;
+            std::fs::set_permissions(&runtime_dir, std::fs::Permissions::from_mode(0o700)).ok()?;
+
+            let socket_name = format!("wayland-azul-{}", name);
+            let weston = Command::new("weston")
+                .args(&["--backend=headless-backend.so", "--idle-time=0"])
+                .arg(format!("--socket={}", socket_name))
+                .env("XDG_RUNTIME_DIR", &runtime_dir)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .ok()?;
+
+            let socket = runtime_dir.join(&socket_name);
+            let compositor = Self {
+                weston,
+                socket: socket.to_string_lossy().into_owned(),
+                runtime_dir,
+            };
+
+            let start = Instant::now();
+            while !socket.exists() {
+                if start.elapsed() > Duration::from_secs(10) {
+                    return None;
+                }
+                std::thread::sleep(Duration::from_millis(50));
+            }
+
+            Some(compositor)
+        }
+    }
+
+    impl Drop for TestCompositor {
+        fn drop(&mut self) {
+            let _ = self.weston.kill();
+            let _ = self.weston.wait();
+            let _ = std::fs::remove_dir_all(&self.runtime_dir);
+        }
+    }
+
+    #[test]
+    fn translate_cursor_shapes() {
+        assert_eq!(translate_cursor_shape(MouseCursorType::Default), 1);
+        assert_eq!(translate_cursor_shape(MouseCursorType::Arrow), 1);
+        assert_eq!(translate_cursor_shape(MouseCursorType::Hand), 4);
+        assert_eq!(translate_cursor_shape(MouseCursorType::Text), 9);
+        assert_eq!(translate_cursor_shape(MouseCursorType::ZoomOut), 34);
+    }
+
+    #[test]
+    fn translate_window_frame() {
+        assert_eq!(translate_toplevel_states(&[]), WindowFrame::Normal);
+        assert_eq!(translate_toplevel_states(&[4]), WindowFrame::Normal);
+        assert_eq!(translate_toplevel_states(&[1, 4]), WindowFrame::Maximized);
+        assert_eq!(translate_toplevel_states(&[1, 2]), WindowFrame::Fullscreen);
+    }
+
+    #[test]
+    fn protocol_tables() {
+        unsafe {
+            assert_eq!(CStr::from_ptr(XDG_TOPLEVEL_INTERFACE.name).to_str(), Ok("xdg_toplevel"));
+            assert_eq!(XDG_TOPLEVEL_INTERFACE.method_count, 14);
+            assert_eq!(XDG_TOPLEVEL_INTERFACE.event_count, 2);
+            let set_title = &*XDG_TOPLEVEL_INTERFACE.methods.offset(XDG_TOPLEVEL_SET_TITLE as isize);
+            assert_eq!(CStr::from_ptr(set_title.name).to_str(), Ok("set_title"));
+            let ack = &*XDG_SURFACE_INTERFACE.methods.offset(XDG_SURFACE_ACK_CONFIGURE as isize);
+            assert_eq!(CStr::from_ptr(ack.signature).to_str(), Ok("u"));
+            let get_popup = &*XDG_SURFACE_INTERFACE.methods.offset(XDG_SURFACE_GET_POPUP as isize);
+            assert_eq!(CStr::from_ptr(get_popup.name).to_str(), Ok("get_popup"));
+            let grab = &*XDG_POPUP_INTERFACE.methods.offset(XDG_POPUP_GRAB as isize);
+            assert_eq!(CStr::from_ptr(grab.signature).to_str(), Ok("ou"));
+            let set_constraint_adjustment = &*XDG_POSITIONER_INTERFACE.methods.offset(XDG_POSITIONER_SET_CONSTRAINT_ADJUSTMENT as isize);
+            assert_eq!(CStr::from_ptr(set_constraint_adjustment.name).to_str(), Ok("set_constraint_adjustment"));
+        }
+    }
+
+    #[test]
+    fn popup_rect_is_relative_to_the_parent() {
+
+        let mut parent = FullWindowState::default();
+        parent.position = WindowPosition::Initialized(PhysicalPositionI32 { x: 0, y: 0 });
+        parent.size.hidpi_factor = 1.0;
+
+        let size = LogicalSize::new(200.0, 24.5);
+        assert_eq!(get_popup_rect(PhysicalPositionI32 { x: 10, y: 30 }, size, &parent), (10, 30, 200, 25));
+
+        // physical pixels of the parent -> logical pixels
+        parent.size.hidpi_factor = 2.0;
+        assert_eq!(get_popup_rect(PhysicalPositionI32 { x: 10, y: 30 }, size, &parent), (5, 15, 200, 25));
+
+        // popup of a popup
+        parent.position = WindowPosition::Initialized(PhysicalPositionI32 { x: 100, y: 50 });
+        assert_eq!(get_popup_rect(PhysicalPositionI32 { x: 120, y: 90 }, size, &parent), (10, 20, 200, 25));
+    }
+
+    #[test]
+    fn headless_toplevel_is_configured() {
+
+        // skipped if weston or libwayland-client are not installed
+        let compositor = match TestCompositor::start("configure") {
+            Some(s) => s,
+            None => return,
+        };
+
+        let client = match WaylandClient::new() {
+            Ok(o) => Rc::new(o),
+            Err(_) => return,
+        };
+
+        let mut display = WaylandDisplay::connect(client, None, Some(&compositor.socket)).unwrap();
+        assert!(!display.compositor.is_null());
+        assert!(!display.wm_base.is_null());
+
+        let (surface, xdg_surface, toplevel) = display.create_toplevel().unwrap();
+        unsafe { display.client.marshal(surface, WL_SURFACE_COMMIT, &mut []) };
+
+        let mut configured = None;
+        let start = Instant::now();
+        while configured.is_none() && start.elapsed() < Duration::from_secs(10) {
+            display.dispatch(100).unwrap();
+            for event in display.take_events() {
+                if let Some(WaylandEvent::Configure { xdg_surface: s, serial }) = display.handle_global_event(event) {
+                    assert_eq!(s, xdg_surface);
+                    configured = Some(serial);
+                }
+            }
+        }
+
+        let serial = configured.expect("no xdg_surface.configure event received");
+        unsafe {
+            display.client.marshal(xdg_surface, XDG_SURFACE_ACK_CONFIGURE, &mut [WlArgument { u: serial }]);
+            display.client.destroy(toplevel, Some(XDG_TOPLEVEL_DESTROY));
+            display.client.destroy(xdg_surface, Some(XDG_SURFACE_DESTROY));
+            display.client.destroy(surface, Some(WL_SURFACE_DESTROY));
+        }
+        display.dispatch(0).unwrap();
+    }
+}
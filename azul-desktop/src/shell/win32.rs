@@ -89,6 +89,8 @@ type TIMERPTR = winapi::shared::basetsd::UINT_PTR;
 const AZ_TICK_REGENERATE_DOM: usize = 1;
 // ID sent by WM_TIMER to check the thread results
 const AZ_THREAD_TICK: usize = 2;
+// ID sent by WM_TIMER to advance the running CSS animations
+const AZ_ANIMATION_TICK: usize = 3;
 
 const AZ_REGENERATE_DOM: u32 = WM_APP + 1;
 const AZ_REGENERATE_DISPLAY_LIST: u32 = WM_APP + 2;
@@ -1600,6 +1602,8 @@ struct Window {
     timers: BTreeMap<TimerId, TIMERPTR>,
     /// If threads is non-empty, the window will receive a WM_TIMER every 16ms
     thread_timer_running: Option<TIMERPTR>,
+    /// If CSS animations are running, the window will receive a WM_TIMER every 16ms
+    animation_timer_running: Option<TIMERPTR>,
     /// characters are combined via two following wparam messages
     high_surrogate: Option<u16>,
 }
@@ -2099,6 +2103,7 @@ impl Window {
             context_menu: None,
             timers: BTreeMap::new(),
             thread_timer_running: None,
+            animation_timer_running: None,
             high_surrogate: None,
         };
 
@@ -2216,6 +2221,21 @@ impl Window {
         }
     }
 
+    fn start_stop_animation_timer(&mut self) {
+
+        use winapi::um::winuser::{SetTimer, KillTimer};
+
+        if !self.internal.animations.has_running_animations() {
+            if let Some(animation_tick) = self.animation_timer_running {
+                unsafe { KillTimer(self.hwnd, animation_tick) };
+            }
+            self.animation_timer_running = None;
+        } else if self.animation_timer_running.is_none() {
+            let res = unsafe { SetTimer(self.hwnd, AZ_ANIMATION_TICK, 16, None) }; // 16ms timer
+            self.animation_timer_running = Some(res);
+        }
+    }
+
     // Stop all timers that have a NodeId attached to them because in the next
     // frame the NodeId would be invalid, leading to crashes / panics
     fn stop_timers_with_node_ids(&mut self) {
@@ -2701,15 +2721,55 @@ unsafe extern "system" fn WindowProc(
                         &mut current_window.internal.layout_results,
                         &image_cache,
                         &mut current_window.internal.renderer_resources,
-                        current_window.internal.current_window_state.size.get_layout_size(),
+                        &current_window.internal.current_window_state.size,
+                        current_window.internal.current_window_state.theme,
                         &current_window.internal.document_id,
                         None,
                         None,
                         &None,
+                        &mut current_window.internal.animations,
                         azul_layout::do_the_relayout,
                     );
 
+                    current_window.start_stop_animation_timer();
+
+                    // the DOM is now mounted: let the nodes start their timers, etc.
+                    let config = &ab.config;
+                    let hinstance = ab.hinstance;
+                    let mut new_windows = Vec::new();
+                    let mut destroyed_windows = Vec::new();
+
+                    ret = process_after_mount(
+                        hinstance,
+                        current_window,
+                        fc_cache,
+                        image_cache,
+                        config,
+                        &mut new_windows,
+                        &mut destroyed_windows,
+                    );
+
                     PostMessageW(hwnd, AZ_REGENERATE_DISPLAY_LIST, 0, 0);
+
+                    mem::drop(app_borrow);
+                    create_windows(hinstance, shared_application_data, new_windows);
+                    let mut app_borrow = shared_application_data.inner.try_borrow_mut().unwrap();
+                    let mut ab = &mut *app_borrow;
+                    destroy_windows(ab, destroyed_windows);
+
+                    match ret {
+                        ProcessEventResult::ShouldRegenerateDomCurrentWindow => {
+                            PostMessageW(hwnd, AZ_REGENERATE_DOM, 0, 0);
+                        },
+                        ProcessEventResult::ShouldRegenerateDomAllWindows => {
+                            for window in ab.windows.values() {
+                                PostMessageW(window.hwnd, AZ_REGENERATE_DOM, 0, 0);
+                            }
+                        },
+                        _ => { },
+                    }
+
+                    return 0;
                 }
 
                 mem::drop(app_borrow);
@@ -3542,6 +3602,19 @@ unsafe extern "system" fn WindowProc(
                         mem::drop(app_borrow);
                         return DefWindowProcW(hwnd, msg, wparam, lparam)
                     },
+                    AZ_ANIMATION_TICK => {
+
+                        // tick every 16ms while CSS animations are running
+                        match windows.get_mut(&hwnd_key) {
+                            Some(current_window) => {
+                                ret = process_animation_frame(current_window, image_cache);
+                            },
+                            None => {
+                                mem::drop(app_borrow);
+                                return DefWindowProcW(hwnd, msg, wparam, lparam);
+                            },
+                        }
+                    },
                     AZ_THREAD_TICK => {
 
                         // tick every 16ms to process new thread messages
@@ -4080,6 +4153,49 @@ fn process_timer(
     );
 }
 
+#[must_use]
+fn process_after_mount(
+    hinstance: HINSTANCE,
+    window: &mut Window,
+    fc_cache: &mut LazyFcCache,
+    image_cache: &mut ImageCache,
+    config: &AppConfig,
+    new_windows: &mut Vec<WindowCreateOptions>,
+    destroyed_windows: &mut Vec<usize>
+) -> ProcessEventResult {
+
+    use azul_core::window::{RawWindowHandle, WindowsHandle};
+
+    let callback_result = fc_cache.apply_closure(|fc_cache| {
+
+        let window_handle = RawWindowHandle::Windows(WindowsHandle {
+            hwnd: window.hwnd as *mut _,
+            hinstance: hinstance as *mut _,
+        });
+
+        window.internal.invoke_after_mount_callbacks(
+            &window_handle,
+            &window.gl_context_ptr,
+            image_cache,
+            fc_cache,
+            &config.system_callbacks,
+        )
+    });
+
+    return process_callback_results(
+        callback_result,
+        window,
+        &NodesToCheck::empty(
+            window.internal.current_window_state.mouse_state.mouse_down(),
+            window.internal.current_window_state.focused_node,
+        ),
+        image_cache,
+        fc_cache,
+        new_windows,
+        destroyed_windows
+    );
+}
+
 #[must_use]
 fn process_threads(
     hinstance: HINSTANCE,
@@ -4190,6 +4306,8 @@ fn process_callback_results(
             modified,
             window.internal.current_window_state.dropped_file.clone(),
             window.internal.current_window_state.hovered_file.clone(),
+            window.internal.current_window_state.dropped_text.clone(),
+            window.internal.current_window_state.hovered_text.clone(),
             window.internal.current_window_state.focused_node.clone(),
             window.internal.current_window_state.last_hit_test.clone(),
         );
@@ -4230,14 +4348,18 @@ fn process_callback_results(
         &mut window.internal.layout_results,
         &image_cache,
         &mut window.internal.renderer_resources,
-        window.internal.current_window_state.size.get_layout_size(),
+        &window.internal.current_window_state.size,
+        window.internal.current_window_state.theme,
         &window.internal.document_id,
         callback_results.css_properties_changed.as_ref(),
         callback_results.words_changed.as_ref(),
         &callback_results.update_focused_node,
+        &mut window.internal.animations,
         azul_layout::do_the_relayout,
     );
 
+    window.start_stop_animation_timer();
+
 
     if let Some(rsn) = style_layout_changes.nodes_that_changed_size.as_ref() {
 
@@ -4296,6 +4418,45 @@ fn process_callback_results(
     }
 }
 
+// Advances the running CSS transitions and animations by one frame
+#[must_use]
+fn process_animation_frame(window: &mut Window, image_cache: &ImageCache) -> ProcessEventResult {
+
+    use azul_core::window_state::{StyleAndLayoutChanges, NodesToCheck};
+
+    let nodes_to_check = NodesToCheck::empty(
+        window.internal.current_window_state.mouse_state.mouse_down(),
+        window.internal.current_window_state.focused_node,
+    );
+
+    let style_layout_changes = StyleAndLayoutChanges::new(
+        &nodes_to_check,
+        &mut window.internal.layout_results,
+        &image_cache,
+        &mut window.internal.renderer_resources,
+        &window.internal.current_window_state.size,
+        window.internal.current_window_state.theme,
+        &window.internal.document_id,
+        None,
+        None,
+        &None,
+        &mut window.internal.animations,
+        azul_layout::do_the_relayout,
+    );
+
+    window.start_stop_animation_timer();
+
+    if style_layout_changes.did_resize_nodes() {
+        ProcessEventResult::UpdateHitTesterAndProcessAgain
+    } else if style_layout_changes.need_regenerate_display_list() {
+        ProcessEventResult::ShouldUpdateDisplayListCurrentWindow
+    } else if style_layout_changes.need_redraw() {
+        ProcessEventResult::ShouldReRenderCurrentWindow
+    } else {
+        ProcessEventResult::DoNothing
+    }
+}
+
 fn create_windows(hinstance: HINSTANCE, app: &mut SharedApplicationData, new: Vec<WindowCreateOptions>) {
     for opts in new {
         if let Ok(w) = Window::create(hinstance, opts, app.clone()) {
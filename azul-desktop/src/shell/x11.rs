@@ -1,6 +1,10 @@
 use crate::{
     app::{App, LazyFcCache},
-    gl::{c_char, c_ushort, c_uchar, c_int, c_uint, c_long, c_ulong},
+    shell::{
+        atspi::{AtSpiBridge, AccessibleWindow},
+        linux::{LinuxWindow, LinuxWindowParts, LinuxTimers, PollFd, POLLIN, poll, get_poll_timeout_ms},
+    },
+    gl::{c_char, c_short, c_ushort, c_uchar, c_int, c_uint, c_long, c_ulong},
     wr_translate::{
         rebuild_display_list,
         generate_frame,
@@ -19,12 +23,12 @@ use azul_core::{
     FastBTreeSet, FastHashMap,
     app_resources::{
         ImageMask, ImageRef, Epoch,
-        AppConfig, ImageCache, ResourceUpdate,
+        AppConfig, ImageCache,
         RendererResources, GlTextureCache,
     },
     callbacks::{
         RefAny, UpdateImageType,
-        DomNodeId, DocumentId
+        DomNodeId, DocumentId, OptionCallback,
     },
     gl::OptionGlContextPtr,
     task::{Thread, ThreadId, Timer, TimerId},
@@ -36,9 +40,13 @@ use azul_core::{
         LogicalSize, Menu, MenuCallback, MenuItem,
         MonitorVec, WindowCreateOptions, WindowInternal,
         WindowState, FullWindowState, ScrollResult,
-        MouseCursorType, CallCallbacksResult
+        MouseCursorType, MouseState, VirtualKeyCode, WindowPosition,
+        PhysicalPositionI32, XWindowType,
+        RawWindowHandle, XlibHandle, ClipboardSelection, ClipboardContent,
+        ImeComposition, OptionImeComposition, ImePosition, is_text_mime_type,
     },
     window_state::NodesToCheck,
+    accessibility::AccessibilityTree,
 };
 use core::{
     fmt,
@@ -66,14 +74,22 @@ use webrender::{
     RendererOptions as WrRendererOptions, ShaderPrecacheFlags as WrShaderPrecacheFlags,
     Shaders as WrShaders, Transaction as WrTransaction,
 };
+use azul_css::AzString;
 use std::ffi::{CString, OsStr};
 use std::os::raw;
 use gl_context_loader::gl;
 
 // TODO: Cache compiled shaders between renderers
-const WR_SHADER_CACHE: Option<&Rc<RefCell<WrShaders>>> = None;
+pub(crate) const WR_SHADER_CACHE: Option<&Rc<RefCell<WrShaders>>> = None;
+
+// How long to wait for X11 events before answering the requests of the screen reader
+const ACCESSIBILITY_POLL_TIMEOUT_MS: i32 = 16;
+
+// Time between two frames of running CSS animations
+const ANIMATION_FRAME_TIME_MS: i32 = 16;
 
 extern { // syscalls
+    fn setlocale(category: raw::c_int, locale: *const raw::c_char) -> *mut raw::c_char;
     fn dlopen(filename: *const raw::c_char, flags: raw::c_int) -> *mut raw::c_void;
     fn dlsym(handle: *mut raw::c_void, symbol: *const raw::c_char) -> *mut raw::c_void;
     fn dlclose(handle: *mut raw::c_void) -> raw::c_int;
@@ -84,6 +100,7 @@ extern { // syscalls
 pub enum LinuxWindowCreateError {
     X(String),
     Egl(String),
+    Wayland(String),
     NoGlContext,
     Renderer(WrRendererError),
     BorrowMut(BorrowMutError),
@@ -122,27 +139,27 @@ pub fn get_monitors(app: &App) -> MonitorVec {
 
 // Minimal typedefs from <EGL/egl.h>
 
-type EGLDisplay = *mut c_void;
-type EGLNativeDisplayType = *mut c_void;
-type EGLNativeWindowType = *mut c_void;
-type EGLint = i32;
-type EGLBoolean = u32;
-type EGLenum = u32;
-type EGLConfig = *mut c_void;
-type EGLContext = *mut c_void;
-type EGLSurface = *mut c_void;
-
-type eglGetDisplayFuncType = extern "C" fn(EGLNativeDisplayType) -> EGLDisplay;
-type eglInitializeFuncType = extern "C" fn(EGLDisplay, *mut EGLint, *mut EGLint) -> EGLBoolean;
-type eglBindAPIFuncType = extern "C" fn(EGLenum) -> EGLBoolean;
-type eglChooseConfigFuncType = extern "C" fn(EGLDisplay, *const EGLint,*mut EGLConfig, EGLint, *mut EGLint) -> EGLBoolean;
-type eglCreateWindowSurfaceFuncType = extern "C" fn(EGLDisplay, EGLConfig, EGLNativeWindowType, *const EGLint) -> EGLSurface;
-type eglSwapIntervalFuncType = extern "C" fn(EGLDisplay, EGLint) -> EGLBoolean;
-type eglCreateContextFuncType = extern "C" fn(EGLDisplay, EGLConfig, EGLContext, *const EGLint) -> EGLContext;
-type eglMakeCurrentFuncType = extern "C" fn(EGLDisplay, EGLSurface, EGLSurface, EGLContext) -> EGLBoolean;
-type eglSwapBuffersFuncType = extern "C" fn(EGLDisplay, EGLSurface) -> EGLBoolean;
-type eglGetErrorFuncType = extern "C" fn () -> EGLint;
-type eglGetProcAddressFuncType = extern "C" fn(*const c_char) -> *mut raw::c_void;
+pub(crate) type EGLDisplay = *mut c_void;
+pub(crate) type EGLNativeDisplayType = *mut c_void;
+pub(crate) type EGLNativeWindowType = *mut c_void;
+pub(crate) type EGLint = i32;
+pub(crate) type EGLBoolean = u32;
+pub(crate) type EGLenum = u32;
+pub(crate) type EGLConfig = *mut c_void;
+pub(crate) type EGLContext = *mut c_void;
+pub(crate) type EGLSurface = *mut c_void;
+
+pub(crate) type eglGetDisplayFuncType = extern "C" fn(EGLNativeDisplayType) -> EGLDisplay;
+pub(crate) type eglInitializeFuncType = extern "C" fn(EGLDisplay, *mut EGLint, *mut EGLint) -> EGLBoolean;
+pub(crate) type eglBindAPIFuncType = extern "C" fn(EGLenum) -> EGLBoolean;
+pub(crate) type eglChooseConfigFuncType = extern "C" fn(EGLDisplay, *const EGLint,*mut EGLConfig, EGLint, *mut EGLint) -> EGLBoolean;
+pub(crate) type eglCreateWindowSurfaceFuncType = extern "C" fn(EGLDisplay, EGLConfig, EGLNativeWindowType, *const EGLint) -> EGLSurface;
+pub(crate) type eglSwapIntervalFuncType = extern "C" fn(EGLDisplay, EGLint) -> EGLBoolean;
+pub(crate) type eglCreateContextFuncType = extern "C" fn(EGLDisplay, EGLConfig, EGLContext, *const EGLint) -> EGLContext;
+pub(crate) type eglMakeCurrentFuncType = extern "C" fn(EGLDisplay, EGLSurface, EGLSurface, EGLContext) -> EGLBoolean;
+pub(crate) type eglSwapBuffersFuncType = extern "C" fn(EGLDisplay, EGLSurface) -> EGLBoolean;
+pub(crate) type eglGetErrorFuncType = extern "C" fn () -> EGLint;
+pub(crate) type eglGetProcAddressFuncType = extern "C" fn(*const c_char) -> *mut raw::c_void;
 
 type XDefaultScreenFuncType = extern "C" fn(*mut Display) -> c_int;
 type XRootWindowFuncType = extern "C" fn(*mut Display, c_int) -> c_ulong;
@@ -151,40 +168,71 @@ type XStoreNameFuncType = extern "C" fn(*mut Display, c_ulong, *const c_char) ->
 type XInternAtomFuncType = extern "C" fn(*mut Display, *const c_char, c_int) -> c_ulong;
 type XSetWMProtocolsFuncType = extern "C" fn(*mut Display, c_ulong,*mut c_ulong, c_int) -> c_int;
 type XMapWindowFuncType = extern "C" fn(*mut Display, c_ulong) -> c_int;
+type XMoveWindowFuncType = extern "C" fn(*mut Display, Window, c_int, c_int) -> c_int;
 type XOpenDisplayFuncType = extern "C" fn(*const c_char) -> *mut Display;
 type XCloseDisplayFuncType = extern "C" fn(*mut Display) -> c_int;
 type XPendingFuncType = extern "C" fn(*mut Display) -> c_int;
 type XNextEventFuncType = extern "C" fn(*mut Display, *mut XEvent) -> c_int;
+type XPeekEventFuncType = extern "C" fn(*mut Display, *mut XEvent) -> c_int;
+type XConnectionNumberFuncType = extern "C" fn(*mut Display) -> c_int;
 type XSelectInputFuncType = extern "C" fn(_: *mut Display, _: c_ulong, _: c_long) -> c_int;
-
-const EGL_NO_DISPLAY: EGLDisplay = 0 as *mut c_void;
-const EGL_OPENGL_API: EGLenum = 0x30A2;
-const EGL_SURFACE_TYPE: EGLint = 0x3033;
-const EGL_WINDOW_BIT: EGLint = 0x0004;
-const EGL_CONFORMANT: EGLint = 0x3042;
-const EGL_OPENGL_BIT: EGLint = 0x0008;
-const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
-const EGL_COLOR_BUFFER_TYPE: EGLint = 0x303F;
-const EGL_RGB_BUFFER: EGLint = 0x308E;
-const EGL_BLUE_SIZE: EGLint = 0x3022;
-const EGL_GREEN_SIZE: EGLint = 0x3023;
-const EGL_RED_SIZE: EGLint = 0x3024;
-const EGL_DEPTH_SIZE: EGLint = 0x3025;
-const EGL_STENCIL_SIZE: EGLint = 0x3026;
-const EGL_NONE: EGLint = 0x3038;
-const EGL_GL_COLORSPACE: EGLint = 0x3087;
-const EGL_GL_COLORSPACE_LINEAR: EGLint = 0x308A;
-const EGL_RENDER_BUFFER: EGLint = 0x3086;
-const EGL_BACK_BUFFER: EGLint = 0x3084;
-const EGL_NO_SURFACE: EGLSurface = 0 as *mut c_void;
-const EGL_NO_CONTEXT: EGLContext = 0 as *mut c_void;
-const EGL_FALSE: EGLBoolean = 0;
-const EGL_TRUE: EGLBoolean = 1;
-
-const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x00003098;
-const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x000030fb;
-const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x000030fd;
-const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x00000001;
+type XLookupStringFuncType = extern "C" fn(*mut XKeyEvent, *mut c_char, c_int, *mut KeySym, *mut c_void) -> c_int;
+type XkbKeycodeToKeysymFuncType = extern "C" fn(*mut Display, c_uchar, c_int, c_int) -> KeySym;
+type XSetSelectionOwnerFuncType = extern "C" fn(*mut Display, Atom, Window, Time) -> c_int;
+type XGetSelectionOwnerFuncType = extern "C" fn(*mut Display, Atom) -> Window;
+type XConvertSelectionFuncType = extern "C" fn(*mut Display, Atom, Atom, Atom, Window, Time) -> c_int;
+type XCheckIfEventFuncType = extern "C" fn(*mut Display, *mut XEvent, XEventPredicate, *mut c_char) -> X11Bool;
+type XGetWindowPropertyFuncType = extern "C" fn(*mut Display, Window, Atom, c_long, c_long, X11Bool, Atom, *mut Atom, *mut c_int, *mut c_ulong, *mut c_ulong, *mut *mut c_uchar) -> c_int;
+type XChangePropertyFuncType = extern "C" fn(*mut Display, Window, Atom, Atom, c_int, c_int, *const c_uchar, c_int) -> c_int;
+type XSendEventFuncType = extern "C" fn(*mut Display, Window, X11Bool, c_long, *mut XEvent) -> c_int;
+type XFlushFuncType = extern "C" fn(*mut Display) -> c_int;
+type XFreeFuncType = extern "C" fn(*mut c_void) -> c_int;
+type XTranslateCoordinatesFuncType = extern "C" fn(*mut Display, Window, Window, c_int, c_int, *mut c_int, *mut c_int, *mut Window) -> X11Bool;
+type XSetLocaleModifiersFuncType = extern "C" fn(*const c_char) -> *mut c_char;
+type XOpenIMFuncType = extern "C" fn(*mut Display, *mut c_void, *mut c_char, *mut c_char) -> XIM;
+type XCloseIMFuncType = extern "C" fn(XIM) -> c_int;
+type XGetIMValuesFuncType = unsafe extern "C" fn(XIM, ...) -> *mut c_char;
+type XCreateICFuncType = unsafe extern "C" fn(XIM, ...) -> XIC;
+type XDestroyICFuncType = extern "C" fn(XIC);
+type XGetICValuesFuncType = unsafe extern "C" fn(XIC, ...) -> *mut c_char;
+type XSetICValuesFuncType = unsafe extern "C" fn(XIC, ...) -> *mut c_char;
+type XSetICFocusFuncType = extern "C" fn(XIC);
+type XUnsetICFocusFuncType = extern "C" fn(XIC);
+type XFilterEventFuncType = extern "C" fn(*mut XEvent, Window) -> X11Bool;
+type Xutf8LookupStringFuncType = extern "C" fn(XIC, *mut XKeyEvent, *mut c_char, c_int, *mut KeySym, *mut c_int) -> c_int;
+type XVaCreateNestedListFuncType = unsafe extern "C" fn(c_int, ...) -> *mut c_void;
+
+// predicate of XCheckIfEvent: (display, event, user argument) -> whether the event should be returned
+type XEventPredicate = extern "C" fn(*mut Display, *mut XEvent, *mut c_char) -> X11Bool;
+
+pub(crate) const EGL_NO_DISPLAY: EGLDisplay = 0 as *mut c_void;
+pub(crate) const EGL_OPENGL_API: EGLenum = 0x30A2;
+pub(crate) const EGL_SURFACE_TYPE: EGLint = 0x3033;
+pub(crate) const EGL_WINDOW_BIT: EGLint = 0x0004;
+pub(crate) const EGL_CONFORMANT: EGLint = 0x3042;
+pub(crate) const EGL_OPENGL_BIT: EGLint = 0x0008;
+pub(crate) const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+pub(crate) const EGL_COLOR_BUFFER_TYPE: EGLint = 0x303F;
+pub(crate) const EGL_RGB_BUFFER: EGLint = 0x308E;
+pub(crate) const EGL_BLUE_SIZE: EGLint = 0x3022;
+pub(crate) const EGL_GREEN_SIZE: EGLint = 0x3023;
+pub(crate) const EGL_RED_SIZE: EGLint = 0x3024;
+pub(crate) const EGL_DEPTH_SIZE: EGLint = 0x3025;
+pub(crate) const EGL_STENCIL_SIZE: EGLint = 0x3026;
+pub(crate) const EGL_NONE: EGLint = 0x3038;
+pub(crate) const EGL_GL_COLORSPACE: EGLint = 0x3087;
+pub(crate) const EGL_GL_COLORSPACE_LINEAR: EGLint = 0x308A;
+pub(crate) const EGL_RENDER_BUFFER: EGLint = 0x3086;
+pub(crate) const EGL_BACK_BUFFER: EGLint = 0x3084;
+pub(crate) const EGL_NO_SURFACE: EGLSurface = 0 as *mut c_void;
+pub(crate) const EGL_NO_CONTEXT: EGLContext = 0 as *mut c_void;
+pub(crate) const EGL_FALSE: EGLBoolean = 0;
+pub(crate) const EGL_TRUE: EGLBoolean = 1;
+
+pub(crate) const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x00003098;
+pub(crate) const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x000030fb;
+pub(crate) const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x000030fd;
+pub(crate) const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x00000001;
 
 const WM_PROTOCOLS: u64 = 0;
 
@@ -913,6 +961,7 @@ struct XVisibilityEvent {
 
 const X11_INPUT_OUTPUT: c_int = 1;
 const X11_COPY_FROM_PARENT: c_int = 0;
+const X11_CW_OVERRIDE_REDIRECT: c_ulong = 0x0200;
 const X11_CW_EVENT_MASK: c_ulong = 0x0800;
 const X11_STRUCTURE_NOTIFY_MASK: c_long = 0x0002_0000;
 const X11_EXPOSURE_MASK: c_long = 0x0000_8000;
@@ -922,18 +971,107 @@ const X11_KEY_RELEASE_MASK: c_long = 0x0000_0002;
 const X11_POINTER_MOTION_MASK: c_long = 0x0000_0040;
 const X11_BUTTON_PRESS_MASK: c_long = 0x0000_0004;
 const X11_BUTTON_RELEASE_MASK: c_long = 0x0000_0008;
+const X11_ENTER_WINDOW_MASK: c_long = 0x0000_0010;
+const X11_LEAVE_WINDOW_MASK: c_long = 0x0000_0020;
+const X11_FOCUS_CHANGE_MASK: c_long = 0x0020_0000;
+const X11_PROPERTY_CHANGE_MASK: c_long = 0x0040_0000;
 
 const X11_FALSE: X11Bool = 0;
-
+const X11_TRUE: X11Bool = 1;
+
+const X11_KEY_PRESS: c_int = 2;
+const X11_KEY_RELEASE: c_int = 3;
+const X11_BUTTON_PRESS: c_int = 4;
+const X11_BUTTON_RELEASE: c_int = 5;
+const X11_MOTION_NOTIFY: c_int = 6;
+const X11_ENTER_NOTIFY: c_int = 7;
+const X11_LEAVE_NOTIFY: c_int = 8;
+const X11_FOCUS_IN: c_int = 9;
+const X11_FOCUS_OUT: c_int = 10;
 const X11_EXPOSE: c_int = 12;
+const X11_CONFIGURE_NOTIFY: c_int = 22;
 const X11_RESIZE_REQUEST: c_int = 25;
+const X11_PROPERTY_NOTIFY: c_int = 28;
+const X11_SELECTION_CLEAR: c_int = 29;
+const X11_SELECTION_REQUEST: c_int = 30;
+const X11_SELECTION_NOTIFY: c_int = 31;
 const X11_CLIENT_MESSAGE: c_int = 33;
 
+// XPropertyEvent.state
+const X11_PROPERTY_NEW_VALUE: c_int = 0;
+
+// XCrossingEvent.mode
+const X11_NOTIFY_GRAB: c_int = 1;
+const X11_NOTIFY_UNGRAB: c_int = 2;
+const X11_PROPERTY_DELETE: c_int = 1;
+
+const X11_PROP_MODE_REPLACE: c_int = 0;
+const X11_ANY_PROPERTY_TYPE: Atom = 0;
+const X11_CURRENT_TIME: Time = 0;
+const X11_SUCCESS: c_int = 0;
+// predefined atoms "ATOM" and "WM_SIZE_HINTS" (Xatom.h)
+const X11_XA_ATOM: Atom = 4;
+const X11_XA_WM_SIZE_HINTS: Atom = 41;
+
+// XSizeHints.flags: the position of the window was set by the user / the program
+const X11_US_POSITION: c_long = 0x0001;
+const X11_P_POSITION: c_long = 0x0004;
+// number of 32-bit values of the WM_NORMAL_HINTS property
+const X11_SIZE_HINTS_LEN: usize = 18;
+
+// _MOTIF_WM_HINTS: flags = MWM_HINTS_DECORATIONS, decorations = none
+const MWM_HINTS_DECORATIONS: c_long = 0x0002;
+const MWM_HINTS_LEN: usize = 5;
+
+// modifier bits of XKeyEvent.state
+const X11_CONTROL_MASK: c_uint = 0x0004;
+
+// XFocusChangeEvent.detail: focus is on the root window, the event
+// is only sent because the pointer is inside of the window
+const X11_NOTIFY_POINTER: c_int = 5;
+
+// XIMStyle flags: how the preedit text and the status of the input method are displayed
+const XIM_PREEDIT_CALLBACKS: XIMStyle = 0x0002;
+const XIM_PREEDIT_POSITION: XIMStyle = 0x0004;
+const XIM_PREEDIT_NOTHING: XIMStyle = 0x0008;
+const XIM_PREEDIT_NONE: XIMStyle = 0x0010;
+const XIM_STATUS_NOTHING: XIMStyle = 0x0400;
+const XIM_STATUS_NONE: XIMStyle = 0x0800;
+
+// Xutf8LookupString() status
+const X11_BUFFER_OVERFLOW: c_int = -1;
+const X11_LOOKUP_CHARS: c_int = 2;
+const X11_LOOKUP_KEYSYM: c_int = 3;
+const X11_LOOKUP_BOTH: c_int = 4;
+
+// XIMCaretDirection
+const XIM_FORWARD_CHAR: c_int = 0;
+const XIM_BACKWARD_CHAR: c_int = 1;
+const XIM_LINE_START: c_int = 8;
+const XIM_LINE_END: c_int = 9;
+const XIM_ABSOLUTE_POSITION: c_int = 10;
+
+// setlocale() category (locale.h)
+const LC_CTYPE: c_int = 0;
+
+// X11 reports the scroll wheel as buttons 4 - 7
+const X11_BUTTON_LEFT: c_uint = 1;
+const X11_BUTTON_MIDDLE: c_uint = 2;
+const X11_BUTTON_RIGHT: c_uint = 3;
+const X11_BUTTON_SCROLL_UP: c_uint = 4;
+const X11_BUTTON_SCROLL_DOWN: c_uint = 5;
+const X11_BUTTON_SCROLL_LEFT: c_uint = 6;
+const X11_BUTTON_SCROLL_RIGHT: c_uint = 7;
+
 type X11Bool = c_int;
 type XID = c_ulong;
+type KeySym = XID;
 type X11Pixmap = XID;
 type X11Colormap = XID;
 type X11Cursor = XID;
+type XIM = *mut c_void;
+type XIC = *mut c_void;
+type XIMStyle = c_ulong;
 
 #[repr(C)]
 struct XSetWindowAttributes {
@@ -959,6 +1097,7 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
 
     use self::LinuxStartupError::Create;
     use self::LinuxWindowCreateError::{X, Egl as EglError};
+    use azul_core::window::{CursorPosition, FullHitTest, LogicalPosition};
 
     let App {
         data,
@@ -971,6 +1110,12 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
     let xlib = Rc::new(Xlib::new()?);
     let egl = Rc::new(Egl::new()?);
 
+    CLIPBOARD.with(|c| *c.borrow_mut() = Some(X11Clipboard::new(xlib.clone())));
+
+    // the input method is selected by the locale and the XMODIFIERS environment variable
+    unsafe { setlocale(LC_CTYPE, b"\0".as_ptr() as *const raw::c_char) };
+    (xlib.XSetLocaleModifiers)(b"\0".as_ptr() as *const c_char);
+
     let mut active_windows = BTreeMap::new();
 
     let app_data_inner = Rc::new(RefCell::new(ApplicationData {
@@ -980,164 +1125,349 @@ pub fn run(app: App, mut root_window: WindowCreateOptions) -> Result<isize, Linu
         fc_cache,
     }));
 
-    for options in windows.iter_mut() {
-        let mut window = X11Window::new(
-            xlib.clone(),
-            egl.clone(),
-            options,
-            SharedApplicationData { inner: app_data_inner.clone() }
-        )?;
-        window.show();
+    let mut cur_xevent = XEvent { pad: [0;24] };
+    let shared_application_data = SharedApplicationData { inner: app_data_inner.clone() };
+
+    for options in windows.iter_mut().chain(core::iter::once(&mut root_window)) {
+        let window = open_window(xlib.clone(), egl.clone(), options, &shared_application_data)?;
         active_windows.insert(window.id, window);
     }
 
-    let mut window = X11Window::new(
-        xlib.clone(),
-        egl.clone(),
-        &mut root_window,
-        SharedApplicationData { inner: app_data_inner.clone() }
-    )?;
-    window.show();
-    active_windows.insert(window.id, window);
-
-    let mut cur_xevent = XEvent { pad: [0;24] };
-
     loop {
 
         let mut windows_to_close = Vec::new();
 
         for (window_id, window) in active_windows.iter_mut() {
 
-            // blocks until next event
-            unsafe { (xlib.XNextEvent)(window.dpy.get(), &mut cur_xevent) };
-
-            let cur_event_type = cur_xevent.get_type();
-
-            match cur_event_type {
-                // window shown
-                X11_EXPOSE => {
-                    let expose_data = unsafe { cur_xevent.expose };
-                    let width = expose_data.width;
-                    let height = expose_data.height;
-
-                    window.make_current();
-                    window.render_api.flush_scene_builder();
-
-                    window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                    window.gl_functions.functions.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
-                    window.gl_functions.functions.disable(gl_context_loader::gl::MULTISAMPLE);
-
-                    window.gl_functions.functions.viewport(0, 0, width, height);
-                    window.gl_functions.functions.clear_color(0.0, 0.0, 0.0, 1.0);
-                    window.gl_functions.functions.clear(
-                        gl::COLOR_BUFFER_BIT |
-                        gl::DEPTH_BUFFER_BIT |
-                        gl::STENCIL_BUFFER_BIT
-                    );
-
-                    let mut current_program = [0_i32];
-                    unsafe {
-                        window.gl_functions.functions.get_integer_v(
-                            gl_context_loader::gl::CURRENT_PROGRAM,
-                            (&mut current_program[..]).into()
+            // process the events that have already been received
+            while unsafe { (xlib.XPending)(window.dpy.get()) } > 0 {
+
+                unsafe { (xlib.XNextEvent)(window.dpy.get(), &mut cur_xevent) };
+
+                if let Some(time) = get_event_time(&cur_xevent) {
+                    with_clipboard(|c| c.last_event_time = time);
+                }
+
+                // key events of a composition are consumed by the input method
+                if window.filter_ime_event(&mut cur_xevent) {
+                    window.process_ime_composition(&shared_application_data)?;
+                    continue;
+                }
+
+                let cur_event_type = cur_xevent.get_type();
+
+                match cur_event_type {
+                    // window moved or resized
+                    X11_CONFIGURE_NOTIFY => {
+                        window.update_position();
+                    },
+                    // window shown
+                    X11_EXPOSE => {
+                        let expose_data = unsafe { cur_xevent.expose };
+                        window.render(expose_data.width, expose_data.height)?;
+                    },
+                    // window resized
+                    X11_RESIZE_REQUEST => {
+
+                        let resize_request_data = unsafe { cur_xevent.resize_request };
+                        let width = resize_request_data.width;
+                        let height = resize_request_data.height;
+
+                        window.make_current();
+                        window.render_api.flush_scene_builder();
+
+                        window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+                        window.gl_functions.functions.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
+                        window.gl_functions.functions.disable(gl_context_loader::gl::MULTISAMPLE);
+
+                        window.gl_functions.functions.viewport(0, 0, width, height);
+                        window.gl_functions.functions.clear_color(0.0, 0.0, 0.0, 1.0);
+                        window.gl_functions.functions.clear(
+                            gl::COLOR_BUFFER_BIT |
+                            gl::DEPTH_BUFFER_BIT |
+                            gl::STENCIL_BUFFER_BIT
                         );
-                    }
 
-                    if let Some(r) = window.renderer.as_mut() {
-                        let framebuffer_size = WrDeviceIntSize::new(width, height);
-                        r.update();
-                        let _ = r.render(framebuffer_size, 0);
-                    }
+                        let mut current_program = [0_i32];
+                        unsafe {
+                            window.gl_functions.functions.get_integer_v(
+                                gl_context_loader::gl::CURRENT_PROGRAM,
+                                (&mut current_program[..]).into()
+                            );
+                        }
+
+                        if let Some(r) = window.renderer.as_mut() {
+                            let framebuffer_size = WrDeviceIntSize::new(width, height);
+                            r.update();
+                            let _ = r.render(framebuffer_size, 0);
+                        }
+
+                        let swap_result = (window.egl.eglSwapBuffers)(window.egl_display, window.egl_surface);
+                        if swap_result != EGL_TRUE {
+                            return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
+                        }
+                    },
+                    // window closed
+                    X11_CLIENT_MESSAGE => {
+                        let xclient_data = unsafe { cur_xevent.client_message };
+                        if (xclient_data.data.as_longs().get(0).copied() == Some(window.wm_delete_window_atom)) {
+                            windows_to_close.push(*window_id);
+                        } else {
+                            // files or text dragged from another application
+                            window.handle_xdnd_message(&xclient_data, &shared_application_data)?;
+                        }
+                    },
+                    X11_KEY_PRESS | X11_KEY_RELEASE => {
+
+                        let pressed = cur_event_type == X11_KEY_PRESS;
+                        let mut key_event = unsafe { cur_xevent.key };
+
+                        // auto-repeat sends a KeyRelease + KeyPress pair, skip the
+                        // release so that the key stays pressed while it repeats
+                        if !pressed && window.is_auto_repeat_release(&key_event) {
+                            continue;
+                        }
+
+                        let scancode = key_event.keycode as u32;
+                        let (vk, text) = window.translate_key_event(&mut key_event);
+
+                        // the composition ends before its text is committed
+                        window.process_ime_composition(&shared_application_data)?;
+
+                        // keycode 0: text committed by the input method, no physical key was pressed
+                        if scancode != 0 {
+
+                            let state = &mut window.internal.current_window_state;
+                            window.internal.previous_window_state = Some(state.clone());
+                            state.keyboard_state.current_char = None.into();
+
+                            if pressed {
+                                state.keyboard_state.pressed_scancodes.insert_hm_item(scancode);
+                                if let Some(vk) = vk {
+                                    // the key down event fires again on every repeat
+                                    if state.keyboard_state.current_virtual_keycode.into_option() == Some(vk) {
+                                        if let Some(previous) = window.internal.previous_window_state.as_mut() {
+                                            previous.keyboard_state.current_virtual_keycode = None.into();
+                                        }
+                                    }
+                                    state.keyboard_state.current_virtual_keycode = Some(vk).into();
+                                    state.keyboard_state.pressed_virtual_keycodes.insert_hm_item(vk);
+                                }
+                            } else {
+                                state.keyboard_state.pressed_scancodes.remove_hm_item(&scancode);
+                                if let Some(vk) = vk {
+                                    state.keyboard_state.pressed_virtual_keycodes.remove_hm_item(&vk);
+                                    state.keyboard_state.current_virtual_keycode = None.into();
+                                }
+                            }
+
+                            window.process_input(&shared_application_data)?;
+                        }
+
+                        // same as WM_KEYDOWN + WM_CHAR: the characters are processed
+                        // after the key down event, one TextInput event per character
+                        for c in text.chars().filter(|c| pressed && !c.is_control()) {
+                            window.internal.previous_window_state = Some(window.internal.current_window_state.clone());
+                            window.internal.current_window_state.keyboard_state.current_char = Some(c as u32).into();
+                            window.process_input(&shared_application_data)?;
+                            window.internal.current_window_state.keyboard_state.current_char = None.into();
+                        }
+                    },
+                    X11_BUTTON_PRESS | X11_BUTTON_RELEASE => {
+
+                        let button_event = unsafe { cur_xevent.button };
+                        let pressed = cur_event_type == X11_BUTTON_PRESS;
+
+                        let state = &mut window.internal.current_window_state;
+                        let previous_state = state.clone();
+                        if !apply_button_event(&mut state.mouse_state, button_event.button, pressed) {
+                            continue;
+                        }
+                        window.internal.previous_window_state = Some(previous_state);
+
+                        window.process_input(&shared_application_data)?;
+                        window.internal.current_window_state.mouse_state.reset_scroll_to_zero();
+                    },
+                    // pointer grabs (window manager, popup menus) send crossing events
+                    // although the pointer didn't move, these would end the hover state
+                    X11_ENTER_NOTIFY | X11_LEAVE_NOTIFY if {
+                        let mode = unsafe { cur_xevent.crossing.mode };
+                        mode == X11_NOTIFY_GRAB || mode == X11_NOTIFY_UNGRAB
+                    } => { },
+                    X11_MOTION_NOTIFY | X11_ENTER_NOTIFY => {
+
+                        let (x, y) = match cur_event_type {
+                            X11_MOTION_NOTIFY => { let m = unsafe { cur_xevent.motion }; (m.x, m.y) },
+                            _ => { let c = unsafe { cur_xevent.crossing }; (c.x, c.y) },
+                        };
+
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        let hidpi_factor = state.size.hidpi_factor;
+                        state.mouse_state.cursor_position = CursorPosition::InWindow(
+                            LogicalPosition::new(x as f32 / hidpi_factor, y as f32 / hidpi_factor)
+                        );
 
-                    let swap_result = (window.egl.eglSwapBuffers)(window.egl_display, window.egl_surface);
-                    if swap_result != EGL_TRUE {
-                        return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
-                    }
+                        window.process_input(&shared_application_data)?;
+                    },
+                    X11_LEAVE_NOTIFY => {
+
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        let last_seen = match state.mouse_state.cursor_position {
+                            CursorPosition::InWindow(i) => i,
+                            _ => LogicalPosition::zero(),
+                        };
+                        state.mouse_state.cursor_position = CursorPosition::OutOfWindow(last_seen);
+                        state.last_hit_test = FullHitTest::empty(state.focused_node);
+
+                        window.process_input(&shared_application_data)?;
+                    },
+                    X11_FOCUS_IN | X11_FOCUS_OUT => {
+
+                        let focus_event = unsafe { cur_xevent.focus_change };
+                        if focus_event.detail == X11_NOTIFY_POINTER {
+                            continue;
+                        }
+
+                        let state = &mut window.internal.current_window_state;
+                        window.internal.previous_window_state = Some(state.clone());
+                        state.flags.has_focus = cur_event_type == X11_FOCUS_IN;
+
+                        if let Some(ime) = window.ime.as_mut() {
+                            ime.set_focus(cur_event_type == X11_FOCUS_IN);
+                        }
+
+                        // key release events are not sent to unfocused windows
+                        if cur_event_type == X11_FOCUS_OUT {
+                            state.keyboard_state.current_char = None.into();
+                            state.keyboard_state.current_virtual_keycode = None.into();
+                            state.keyboard_state.pressed_virtual_keycodes = Vec::new().into();
+                            state.keyboard_state.pressed_scancodes = Vec::new().into();
+                        }
+
+                        window.process_input(&shared_application_data)?;
+                    },
+                    // another application wants to paste the clipboard / PRIMARY selection of this window
+                    X11_SELECTION_REQUEST => {
+                        let request = unsafe { cur_xevent.selection_request };
+                        with_clipboard(|c| c.handle_selection_request(&request));
+                    },
+                    // another application has taken over the selection
+                    X11_SELECTION_CLEAR => {
+                        let clear = unsafe { cur_xevent.selection_clear };
+                        with_clipboard(|c| c.handle_selection_clear(&clear));
+                    },
+                    // requestor of an INCR transfer has read the last chunk
+                    X11_PROPERTY_NOTIFY => {
+                        let property = unsafe { cur_xevent.property };
+                        with_clipboard(|c| c.handle_property_notify(&property));
+                    },
+                    _ => { },
+                }
+            }
 
-                    window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                    window.gl_functions.functions.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
-                    window.gl_functions.functions.use_program(current_program[0] as u32);
-                },
-                // window resized
-                X11_RESIZE_REQUEST => {
-
-                    let resize_request_data = unsafe { cur_xevent.resize_request };
-                    let width = resize_request_data.width;
-                    let height = resize_request_data.height;
-
-                    window.make_current();
-                    window.render_api.flush_scene_builder();
-
-                    window.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
-                    window.gl_functions.functions.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
-                    window.gl_functions.functions.disable(gl_context_loader::gl::MULTISAMPLE);
-
-                    window.gl_functions.functions.viewport(0, 0, width, height);
-                    window.gl_functions.functions.clear_color(0.0, 0.0, 0.0, 1.0);
-                    window.gl_functions.functions.clear(
-                        gl::COLOR_BUFFER_BIT |
-                        gl::DEPTH_BUFFER_BIT |
-                        gl::STENCIL_BUFFER_BIT
-                    );
+            window.process_timers(&shared_application_data)?;
 
-                    let mut current_program = [0_i32];
-                    unsafe {
-                        window.gl_functions.functions.get_integer_v(
-                            gl_context_loader::gl::CURRENT_PROGRAM,
-                            (&mut current_program[..]).into()
-                        );
-                    }
+            if window.internal.animations.has_running_animations() {
+                window.process_animation_frame(&shared_application_data)?;
+            }
 
-                    if let Some(r) = window.renderer.as_mut() {
-                        let framebuffer_size = WrDeviceIntSize::new(width, height);
-                        r.update();
-                        let _ = r.render(framebuffer_size, 0);
-                    }
+            window.process_accessibility_requests(&shared_application_data, 0)?;
+        }
 
-                    let swap_result = (window.egl.eglSwapBuffers)(window.egl_display, window.egl_surface);
-                    if swap_result != EGL_TRUE {
-                        return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
-                    }
-                },
-                // window closed
-                X11_CLIENT_MESSAGE => {
-                    let xclient_data = unsafe { cur_xevent.client_message };
-                    if (xclient_data.data.as_longs().get(0).copied() == Some(window.wm_delete_window_atom)) {
-                        windows_to_close.push(*window_id);
-                    }
-                },
-                _ => { },
+        // windows that the callbacks created or closed (i.e. popups)
+        let mut windows_created = Vec::new();
+        for (window_id, window) in active_windows.iter_mut() {
+            windows_created.append(&mut window.windows_created);
+            if window.internal.current_window_state.flags.is_about_to_close {
+                windows_to_close.push(*window_id);
             }
-
         }
 
         for w in windows_to_close {
-            active_windows.remove(&w);
+            if let Some(mut window) = active_windows.remove(&w) {
+                let dpy = window.dpy.get() as *mut Display;
+                with_clipboard(|c| c.remove_window(dpy, w));
+            }
+        }
+
+        for mut options in windows_created {
+            match open_window(xlib.clone(), egl.clone(), &mut options, &shared_application_data) {
+                Ok(window) => { active_windows.insert(window.id, window); },
+                Err(e) => {
+                    #[cfg(feature = "logging")] {
+                        log::error!("X11: could not create window: {:?}", e);
+                    }
+                },
+            }
         }
 
         if active_windows.is_empty() {
             break;
         }
+
+        // events may have been queued while processing the other windows
+        if active_windows.values_mut().any(|w| unsafe { (xlib.XPending)(w.dpy.get()) } > 0) {
+            continue;
+        }
+
+        // wait for the next event of any window, but only until the next timer tick,
+        // animation frame or until the requests of the screen reader have to be answered
+        let max_timeout_ms = if active_windows.values().any(|w| w.internal.animations.has_running_animations()) {
+            ANIMATION_FRAME_TIME_MS
+        } else if active_windows.values().any(|w| w.accessibility.is_some()) {
+            ACCESSIBILITY_POLL_TIMEOUT_MS
+        } else {
+            -1
+        };
+
+        let next_tick = active_windows.values().filter_map(|w| w.timers.get_next_tick()).min();
+        let timeout_ms = get_poll_timeout_ms(max_timeout_ms, next_tick);
+
+        let mut fds = active_windows.values_mut().map(|w| PollFd {
+            fd: (xlib.XConnectionNumber)(w.dpy.get()),
+            events: POLLIN,
+            revents: 0,
+        }).collect::<Vec<_>>();
+
+        unsafe { poll(fds.as_mut_ptr(), fds.len() as c_ulong, timeout_ms) };
     }
 
+    CLIPBOARD.with(|c| *c.borrow_mut() = None);
+
     Ok(0)
 }
 
+/// Creates and shows a window, then invokes its `AfterMount` and create callbacks
+fn open_window(
+    xlib: Rc<Xlib>,
+    egl: Rc<Egl>,
+    options: &mut WindowCreateOptions,
+    shared_application_data: &SharedApplicationData,
+) -> Result<X11Window, LinuxStartupError> {
+    let mut window = X11Window::new(xlib, egl, options, shared_application_data.clone())?;
+    with_clipboard(|c| c.add_window(window.dpy.get(), window.id));
+    window.show();
+    window.process_window_created(&mut options.create_callback, shared_application_data)?;
+    Ok(window)
+}
+
 #[derive(Debug, Clone)]
-struct SharedApplicationData {
-    inner: Rc<RefCell<ApplicationData>>,
+pub(crate) struct SharedApplicationData {
+    pub(crate) inner: Rc<RefCell<ApplicationData>>,
 }
 
 // ApplicationData struct that is shared across windows
 #[derive(Debug)]
-struct ApplicationData {
-    data: RefAny,
-    config: AppConfig,
-    image_cache: ImageCache,
-    fc_cache: LazyFcCache,
+pub(crate) struct ApplicationData {
+    pub(crate) data: RefAny,
+    pub(crate) config: AppConfig,
+    pub(crate) image_cache: ImageCache,
+    pub(crate) fc_cache: LazyFcCache,
 }
 
-fn display_egl_status(e: EGLint) -> &'static str {
+pub(crate) fn display_egl_status(e: EGLint) -> &'static str {
 
     const BAD_ACCESS: EGLint = 0x3002;
     const BAD_ALLOC: EGLint = 0x3003;
@@ -1171,7 +1501,7 @@ fn display_egl_status(e: EGLint) -> &'static str {
     }
 }
 
-struct Notifier {}
+pub(crate) struct Notifier {}
 
 impl WrRenderNotifier for Notifier {
     fn clone(&self) -> Box<dyn WrRenderNotifier> {
@@ -1191,6 +1521,9 @@ impl WrRenderNotifier for Notifier {
 struct X11Window {
     // X11 raw window handle
     pub id: u64,
+    /// Input method of the window, declared before `dpy` so that
+    /// it is destroyed before the display connection is closed
+    ime: Option<X11Ime>,
     pub dpy: X11Display,
     // EGL OpenGL 3.2 context
     pub egl_surface: EGLSurface,
@@ -1214,6 +1547,16 @@ struct X11Window {
     pub renderer: Option<WrRenderer>,
     /// Hit-tester, lazily initialized and updated every time the display list changes layout
     pub hit_tester: AsyncHitTester,
+    /// Connection to the AT-SPI accessibility bus, `None` if no screen reader support is available
+    pub accessibility: Option<AtSpiBridge>,
+    /// Next ticks of the running timers and threads
+    pub timers: LinuxTimers,
+    /// Atoms of the XDND drag-and-drop protocol
+    xdnd_atoms: XdndAtoms,
+    /// Drag-and-drop operation of another application that is currently over the window
+    xdnd: Option<XdndDrag>,
+    /// Windows that the callbacks of this window created, created by the event loop
+    pub windows_created: Vec<WindowCreateOptions>,
 }
 
 struct Xlib {
@@ -1225,11 +1568,39 @@ struct Xlib {
     pub XInternAtom: XInternAtomFuncType,
     pub XSetWMProtocols: XSetWMProtocolsFuncType,
     pub XMapWindow: XMapWindowFuncType,
+    pub XMoveWindow: XMoveWindowFuncType,
     pub XOpenDisplay: XOpenDisplayFuncType,
     pub XCloseDisplay: XCloseDisplayFuncType,
     pub XPending: XPendingFuncType,
     pub XNextEvent: XNextEventFuncType,
+    pub XPeekEvent: XPeekEventFuncType,
+    pub XConnectionNumber: XConnectionNumberFuncType,
     pub XSelectInput: XSelectInputFuncType,
+    pub XLookupString: XLookupStringFuncType,
+    pub XkbKeycodeToKeysym: XkbKeycodeToKeysymFuncType,
+    pub XSetSelectionOwner: XSetSelectionOwnerFuncType,
+    pub XGetSelectionOwner: XGetSelectionOwnerFuncType,
+    pub XConvertSelection: XConvertSelectionFuncType,
+    pub XCheckIfEvent: XCheckIfEventFuncType,
+    pub XGetWindowProperty: XGetWindowPropertyFuncType,
+    pub XChangeProperty: XChangePropertyFuncType,
+    pub XSendEvent: XSendEventFuncType,
+    pub XFlush: XFlushFuncType,
+    pub XFree: XFreeFuncType,
+    pub XTranslateCoordinates: XTranslateCoordinatesFuncType,
+    pub XSetLocaleModifiers: XSetLocaleModifiersFuncType,
+    pub XOpenIM: XOpenIMFuncType,
+    pub XCloseIM: XCloseIMFuncType,
+    pub XGetIMValues: XGetIMValuesFuncType,
+    pub XCreateIC: XCreateICFuncType,
+    pub XDestroyIC: XDestroyICFuncType,
+    pub XGetICValues: XGetICValuesFuncType,
+    pub XSetICValues: XSetICValuesFuncType,
+    pub XSetICFocus: XSetICFocusFuncType,
+    pub XUnsetICFocus: XUnsetICFocusFuncType,
+    pub XFilterEvent: XFilterEventFuncType,
+    pub Xutf8LookupString: Xutf8LookupStringFuncType,
+    pub XVaCreateNestedList: XVaCreateNestedListFuncType,
 }
 
 impl Xlib {
@@ -1270,6 +1641,10 @@ impl Xlib {
             .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
             .ok_or(Create(Egl(format!("X11: no function XMapWindow"))))?;
 
+        let XMoveWindow: XMoveWindowFuncType = x11.get("XMoveWindow")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XMoveWindow"))))?;
+
         let XOpenDisplay: XOpenDisplayFuncType = x11.get("XOpenDisplay")
             .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
             .ok_or(Create(Egl(format!("X11: no function XOpenDisplay"))))?;
@@ -1286,10 +1661,118 @@ impl Xlib {
             .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
             .ok_or(Create(Egl(format!("X11: no function XNextEvent"))))?;
 
+        let XPeekEvent: XPeekEventFuncType = x11.get("XPeekEvent")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XPeekEvent"))))?;
+
+        let XConnectionNumber: XConnectionNumberFuncType = x11.get("XConnectionNumber")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XConnectionNumber"))))?;
+
         let XSelectInput: XSelectInputFuncType = x11.get("XSelectInput")
             .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
             .ok_or(Create(Egl(format!("X11: no function XSelectInput"))))?;
 
+        let XLookupString: XLookupStringFuncType = x11.get("XLookupString")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XLookupString"))))?;
+
+        let XkbKeycodeToKeysym: XkbKeycodeToKeysymFuncType = x11.get("XkbKeycodeToKeysym")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XkbKeycodeToKeysym"))))?;
+
+        let XSetSelectionOwner: XSetSelectionOwnerFuncType = x11.get("XSetSelectionOwner")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XSetSelectionOwner"))))?;
+
+        let XGetSelectionOwner: XGetSelectionOwnerFuncType = x11.get("XGetSelectionOwner")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XGetSelectionOwner"))))?;
+
+        let XConvertSelection: XConvertSelectionFuncType = x11.get("XConvertSelection")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XConvertSelection"))))?;
+
+        let XCheckIfEvent: XCheckIfEventFuncType = x11.get("XCheckIfEvent")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XCheckIfEvent"))))?;
+
+        let XGetWindowProperty: XGetWindowPropertyFuncType = x11.get("XGetWindowProperty")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XGetWindowProperty"))))?;
+
+        let XChangeProperty: XChangePropertyFuncType = x11.get("XChangeProperty")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XChangeProperty"))))?;
+
+        let XSendEvent: XSendEventFuncType = x11.get("XSendEvent")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XSendEvent"))))?;
+
+        let XFlush: XFlushFuncType = x11.get("XFlush")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XFlush"))))?;
+
+        let XFree: XFreeFuncType = x11.get("XFree")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XFree"))))?;
+
+        let XTranslateCoordinates: XTranslateCoordinatesFuncType = x11.get("XTranslateCoordinates")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XTranslateCoordinates"))))?;
+
+        let XSetLocaleModifiers: XSetLocaleModifiersFuncType = x11.get("XSetLocaleModifiers")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XSetLocaleModifiers"))))?;
+
+        let XOpenIM: XOpenIMFuncType = x11.get("XOpenIM")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XOpenIM"))))?;
+
+        let XCloseIM: XCloseIMFuncType = x11.get("XCloseIM")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XCloseIM"))))?;
+
+        let XGetIMValues: XGetIMValuesFuncType = x11.get("XGetIMValues")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XGetIMValues"))))?;
+
+        let XCreateIC: XCreateICFuncType = x11.get("XCreateIC")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XCreateIC"))))?;
+
+        let XDestroyIC: XDestroyICFuncType = x11.get("XDestroyIC")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XDestroyIC"))))?;
+
+        let XGetICValues: XGetICValuesFuncType = x11.get("XGetICValues")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XGetICValues"))))?;
+
+        let XSetICValues: XSetICValuesFuncType = x11.get("XSetICValues")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XSetICValues"))))?;
+
+        let XSetICFocus: XSetICFocusFuncType = x11.get("XSetICFocus")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XSetICFocus"))))?;
+
+        let XUnsetICFocus: XUnsetICFocusFuncType = x11.get("XUnsetICFocus")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XUnsetICFocus"))))?;
+
+        let XFilterEvent: XFilterEventFuncType = x11.get("XFilterEvent")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XFilterEvent"))))?;
+
+        let Xutf8LookupString: Xutf8LookupStringFuncType = x11.get("Xutf8LookupString")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function Xutf8LookupString"))))?;
+
+        let XVaCreateNestedList: XVaCreateNestedListFuncType = x11.get("XVaCreateNestedList")
+            .and_then(|ptr| if ptr.is_null() { None } else { Some(unsafe { mem::transmute(ptr) })})
+            .ok_or(Create(Egl(format!("X11: no function XVaCreateNestedList"))))?;
+
         Ok(Xlib {
             library: x11,
             XDefaultScreen,
@@ -1299,16 +1782,44 @@ impl Xlib {
             XInternAtom,
             XSetWMProtocols,
             XMapWindow,
+            XMoveWindow,
             XOpenDisplay,
             XCloseDisplay,
             XPending,
             XNextEvent,
+            XPeekEvent,
+            XConnectionNumber,
             XSelectInput,
+            XLookupString,
+            XkbKeycodeToKeysym,
+            XSetSelectionOwner,
+            XGetSelectionOwner,
+            XConvertSelection,
+            XCheckIfEvent,
+            XGetWindowProperty,
+            XChangeProperty,
+            XSendEvent,
+            XFlush,
+            XFree,
+            XTranslateCoordinates,
+            XSetLocaleModifiers,
+            XOpenIM,
+            XCloseIM,
+            XGetIMValues,
+            XCreateIC,
+            XDestroyIC,
+            XGetICValues,
+            XSetICValues,
+            XSetICFocus,
+            XUnsetICFocus,
+            XFilterEvent,
+            Xutf8LookupString,
+            XVaCreateNestedList,
         })
     }
 }
 
-struct Egl {
+pub(crate) struct Egl {
     pub library: Library,
     pub eglMakeCurrent: eglMakeCurrentFuncType,
     pub eglSwapBuffers: eglSwapBuffersFuncType,
@@ -1324,7 +1835,7 @@ struct Egl {
 }
 
 impl Egl {
-    fn new() -> Result<Self, LinuxStartupError> {
+    pub(crate) fn new() -> Result<Self, LinuxStartupError> {
 
         use self::LinuxStartupError::Create;
         use self::LinuxWindowCreateError::{X, Egl};
@@ -1421,10 +1932,26 @@ impl X11Window {
             X11_POINTER_MOTION_MASK |
             X11_BUTTON_PRESS_MASK |
             X11_BUTTON_RELEASE_MASK |
+            X11_ENTER_WINDOW_MASK |
+            X11_LEAVE_WINDOW_MASK |
+            X11_FOCUS_CHANGE_MASK |
+            X11_PROPERTY_CHANGE_MASK |
             X11_STRUCTURE_NOTIFY_MASK;
 
         let mut xattr: XSetWindowAttributes = unsafe { mem::zeroed() };
         xattr.event_mask = mask;
+        let mut xattr_mask = X11_CW_EVENT_MASK;
+
+        // popups (i.e. tooltips) are neither decorated nor moved by the window manager
+        if options.state.platform_specific_options.linux_options.x11_override_redirect {
+            xattr.override_redirect = X11_TRUE;
+            xattr_mask |= X11_CW_OVERRIDE_REDIRECT;
+        }
+
+        let (x, y) = match options.state.position {
+            WindowPosition::Initialized(p) => (p.x, p.y),
+            WindowPosition::Uninitialized => (0, 0),
+        };
 
         let dpi_scale_factor = dpy.get_dpi_scale_factor();
         options.state.size.dpi = (dpi_scale_factor.max(0.0) * 96.0).round() as u32;
@@ -1436,14 +1963,14 @@ impl X11Window {
 
         let window = unsafe { (xlib.XCreateWindow)(
             dpy.get(), root,
-            0, 0,
+            x, y,
             logical_size.width.round().max(0.0) as u32,
             logical_size.height.round().max(0.0) as u32,
             0,
             X11_COPY_FROM_PARENT,
             X11_INPUT_OUTPUT as u32,
             ptr::null_mut(), // = CopyFromParent
-            X11_CW_EVENT_MASK,
+            xattr_mask,
             &mut xattr,
         ) };
 
@@ -1456,6 +1983,8 @@ impl X11Window {
         let window_title = encode_ascii(&options.state.title);
         unsafe { (xlib.XStoreName)(dpy.get(), window, window_title.as_ptr() as *const i8) };
 
+        set_window_manager_hints(&xlib, dpy.display, window, &options.state);
+
         // subscribe to window close notification
         let wm_protocols_atom = unsafe { (xlib.XInternAtom)(
             dpy.get(),
@@ -1476,6 +2005,25 @@ impl X11Window {
             1
         ) };
 
+        // compose text with the input method, it may need additional events (i.e. KeyRelease)
+        let ime = X11Ime::new(xlib.clone(), dpy.display, window);
+        if let Some(ime) = ime.as_ref() {
+            unsafe { (xlib.XSelectInput)(dpy.get(), window, mask | ime.get_filter_events()); }
+        }
+
+        // accept files and text that are dropped on the window
+        let xdnd_atoms = XdndAtoms::new(&xlib, dpy.display);
+        unsafe { (xlib.XChangeProperty)(
+            dpy.display,
+            window,
+            xdnd_atoms.aware,
+            X11_XA_ATOM,
+            32,
+            X11_PROP_MODE_REPLACE,
+            &XDND_VERSION as *const c_long as *const c_uchar,
+            1
+        ) };
+
         let egl_display = (egl.eglGetDisplay)(dpy.display as *mut c_void);
         if egl_display == EGL_NO_DISPLAY {
             return Err(Create(EglError(format!("EGL: eglGetDisplay(): no display"))));
@@ -1719,12 +2267,24 @@ impl X11Window {
         // Update the hit-tester to account for the new hit-testing functionality
         let hit_tester = render_api.request_hit_tester(wr_translate_document_id(document_id));
 
-        Ok(Self {
+        // Connect to the screen reader, if there is one
+        let accessibility = match AtSpiBridge::new(options.state.title.as_str()) {
+            Ok(o) => Some(o),
+            Err(e) => {
+                #[cfg(feature = "logging")] {
+                    log::info!("{}", e);
+                }
+                None
+            },
+        };
+
+        let mut window = Self {
             egl_surface,
             egl_display,
             egl_context,
             wm_delete_window_atom: wm_delete_window_atom as i64,
             id: window,
+            ime,
             dpy,
             xlib,
             egl,
@@ -1734,147 +2294,1969 @@ impl X11Window {
             renderer: Some(renderer),
             gl_functions,
             gl_context_ptr,
-        })
-    }
+            accessibility,
+            timers: LinuxTimers::default(),
+            xdnd_atoms,
+            xdnd: None,
+            windows_created: Vec::new(),
+        };
 
-    fn make_current(&self) {
-        (self.egl.eglMakeCurrent)(
-            self.egl_display,
-            self.egl_surface,
-            self.egl_surface,
-            self.egl_context
-        );
+        window.publish_accessibility_tree();
+
+        Ok(window)
     }
 
     fn show(&mut self) {
         unsafe { (self.xlib.XMapWindow)(self.dpy.get(), self.id) };
     }
-}
 
-struct X11Display {
-    display: *mut Display,
-    xlib: Rc<Xlib>,
-}
+    /// Renders the last frame that was sent to WebRender and swaps the buffers
+    fn render(&mut self, width: c_int, height: c_int) -> Result<(), LinuxStartupError> {
 
-impl X11Display {
+        use self::LinuxStartupError::Create;
+        use self::LinuxWindowCreateError::Egl as EglError;
 
-    fn get<'a>(&'a mut self) -> &'a mut Display {
-        unsafe { &mut *self.display }
-    }
+        self.make_current();
+        self.render_api.flush_scene_builder();
 
-    fn open(xlib: Rc<Xlib>) -> Option<Self> {
+        self.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        self.gl_functions.functions.disable(gl_context_loader::gl::FRAMEBUFFER_SRGB);
+        self.gl_functions.functions.disable(gl_context_loader::gl::MULTISAMPLE);
 
-        let dpy = unsafe { (xlib.XOpenDisplay)(&0) };
+        self.gl_functions.functions.viewport(0, 0, width, height);
+        self.gl_functions.functions.clear_color(0.0, 0.0, 0.0, 1.0);
+        self.gl_functions.functions.clear(
+            gl::COLOR_BUFFER_BIT |
+            gl::DEPTH_BUFFER_BIT |
+            gl::STENCIL_BUFFER_BIT
+        );
 
-        if dpy.is_null() {
-            return None;
+        let mut current_program = [0_i32];
+        unsafe {
+            self.gl_functions.functions.get_integer_v(
+                gl_context_loader::gl::CURRENT_PROGRAM,
+                (&mut current_program[..]).into()
+            );
         }
 
-        Some(Self {
-            display: dpy,
-            xlib,
-        })
-    }
+        if let Some(r) = self.renderer.as_mut() {
+            let framebuffer_size = WrDeviceIntSize::new(width, height);
+            r.update();
+            let _ = r.render(framebuffer_size, 0);
+        }
 
-    /// Return the DPI on X11 systems
-    ///
-    /// Note: slow - cache output!
-    pub fn get_dpi_scale_factor(&self) -> f32 {
+        let swap_result = (self.egl.eglSwapBuffers)(self.egl_display, self.egl_surface);
+        if swap_result != EGL_TRUE {
+            return Err(Create(EglError(format!("EGL: eglSwapBuffers(): Failed to swap OpenGL buffers: {}", swap_result))));
+        }
 
-        use std::env;
-        use std::process::Command;
+        self.gl_functions.functions.bind_framebuffer(gl_context_loader::gl::FRAMEBUFFER, 0);
+        self.gl_functions.functions.bind_texture(gl_context_loader::gl::TEXTURE_2D, 0);
+        self.gl_functions.functions.use_program(current_program[0] as u32);
 
-        // Execute "gsettings get org.gnome.desktop.interface text-scaling-factor"
-        // and parse the output
-        let gsettings_dpi_factor =
-            Command::new("gsettings")
-                .arg("get")
-                .arg("org.gnome.desktop.interface")
-                .arg("text-scaling-factor")
-                .output().ok()
-                .map(|output| output.stdout)
-                .and_then(|stdout_bytes| String::from_utf8(stdout_bytes).ok())
-                .map(|stdout_string| stdout_string.lines().collect::<String>())
-                .and_then(|gsettings_output| gsettings_output.parse::<f32>().ok());
+        Ok(())
+    }
 
-        if let Some(s) = gsettings_dpi_factor {
-            return s;
+    /// Returns whether the next queued event is the KeyPress that auto-repeat
+    /// sends together with this KeyRelease (same key, same time)
+    fn is_auto_repeat_release(&self, key_event: &XKeyEvent) -> bool {
+        let dpy = self.dpy.get();
+        if unsafe { (self.xlib.XPending)(dpy) } == 0 {
+            return false;
         }
+        let mut next = XEvent { pad: [0;24] };
+        unsafe { (self.xlib.XPeekEvent)(dpy, &mut next) };
+        let (next_type, next_key) = unsafe { (next.type_, next.key) };
+        next_type == X11_KEY_PRESS &&
+        next_key.keycode == key_event.keycode &&
+        next_key.time == key_event.time
+    }
 
-        // Failed, try parsing QT_FONT_DPI
-        let qt_font_dpi = env::var("QT_FONT_DPI")
-            .ok()
-            .and_then(|font_dpi| font_dpi.parse::<f32>().ok());
+    /// Translates a KeyPress / KeyRelease event to the virtual keycode and the typed
+    /// text (only for key presses without Ctrl), which can also be committed by the input method
+    fn translate_key_event(&mut self, key_event: &mut XKeyEvent) -> (Option<VirtualKeyCode>, String) {
 
-        if let Some(s) = qt_font_dpi {
-            return s;
-        }
+        use crate::shell::keysym::translate_keysym;
 
-        // Failed again, try getting monitor info from XRandR
-        // TODO
-        return 1.0;
+        let is_key_press = key_event.type_ == X11_KEY_PRESS;
 
-        /*
-            XRRScreenResources *xrrr = XRRGetScreenResources(d, w);
-            XRRCrtcInfo *xrrci;
-            int i;
-            int ncrtc = xrrr->ncrtc;
-            for (i = 0; i < ncrtc; ++i) {
-                xrrci = XRRGetCrtcInfo(d, xrrr, xrrr->crtcs[i]);
-                printf("%dx%d+%d+%d\n", xrrci->width, xrrci->height, xrrci->x, xrrci->y);
-                XRRFreeCrtcInfo(xrrci);
-            }
-            XRRFreeScreenResources(xrrr);
-        */
+        // the input method returns the committed text of a composition as a KeyPress event
+        let (keysym, ime_text) = match self.ime.as_ref() {
+            Some(ime) if is_key_press => {
+                let (keysym, text) = ime.lookup_string(key_event);
+                (keysym, Some(text))
+            },
+            _ => {
+                // XLookupString applies Shift, Caps Lock, Num Lock and AltGr to the keysym
+                let mut text_buffer = [0 as c_char; 32];
+                let mut keysym: KeySym = 0;
+                unsafe {
+                    (self.xlib.XLookupString)(
+                        key_event,
+                        text_buffer.as_mut_ptr(),
+                        text_buffer.len() as c_int,
+                        &mut keysym,
+                        ptr::null_mut(),
+                    )
+                };
+                (keysym, None)
+            },
+        };
+
+        // Shift + 1 = "exclam" has no virtual keycode: use the unshifted keysym of the active group
+        let vk = translate_keysym(keysym as u32).or_else(|| {
+            let group = ((key_event.state >> 13) & 0x3) as c_int;
+            let unshifted = unsafe { (self.xlib.XkbKeycodeToKeysym)(self.dpy.get(), key_event.keycode as c_uchar, group, 0) };
+            translate_keysym(unshifted as u32)
+        });
 
+        let text = if is_key_press && key_event.state & X11_CONTROL_MASK == 0 {
+            match ime_text {
+                Some(text) => text,
+                None => translate_keysym_to_char(keysym).map(String::from).unwrap_or_default(),
+            }
+        } else {
+            String::new()
+        };
 
+        (vk, text)
     }
-}
 
-impl Drop for X11Display {
-    fn drop(&mut self) {
-        // TODO: error checking?
-        let _ = unsafe { (self.xlib.XCloseDisplay)(self.display) };
+    /// Passes the event to the input method, returns whether the input
+    /// method has consumed it (i.e. a key press that is part of a composition)
+    fn filter_ime_event(&mut self, event: &mut XEvent) -> bool {
+        self.ime.is_some() && (self.xlib.XFilterEvent)(event, 0) != X11_FALSE
     }
-}
 
-/// A platform-specific equivalent of the cross-platform `Library`.
-pub struct Library {
-    name: &'static str,
-    ptr: *mut raw::c_void
-}
+    /// Sends the preedit text of the input method to the focused node
+    /// (`CompositionStart`, `CompositionUpdate` and `CompositionEnd` events)
+    fn process_ime_composition(&mut self, shared_application_data: &SharedApplicationData) -> Result<(), LinuxStartupError> {
 
-unsafe impl Send for Library {}
-unsafe impl Sync for Library {}
+        let composition = match self.ime.as_ref() {
+            Some(ime) => ime.get_composition(),
+            None => return Ok(()),
+        };
 
-impl Library {
+        if self.internal.current_window_state.keyboard_state.ime_composition == composition {
+            return Ok(());
+        }
 
-    /// Dynamically load an arbitrary library by its name (dlopen)
-    pub fn load(name: &'static str) -> Result<Self, String> {
+        let state = &mut self.internal.current_window_state;
+        self.internal.previous_window_state = Some(state.clone());
+        state.keyboard_state.ime_composition = composition;
 
-        use alloc::borrow::Cow;
-        use std::ffi::{CString, CStr};
+        self.process_input(shared_application_data)
+    }
 
-        const RTLD_NOW: raw::c_int = 2;
+    /// Moves the candidate window of the input method to the `ime_position` of the window state
+    fn update_ime_position(&mut self) {
 
-        let cow = CString::new(name.as_bytes()).map_err(|e| String::new())?;
-        let ptr = unsafe { dlopen(cow.as_ptr(), RTLD_NOW) };
+        let state = &self.internal.current_window_state;
+        let position = match state.ime_position {
+            ImePosition::Initialized(p) => p,
+            ImePosition::Uninitialized => return,
+        };
+        let hidpi_factor = state.size.hidpi_factor;
 
-        if ptr.is_null() {
-            let dlerr = unsafe { CStr::from_ptr(dlerror()) };
-            Err(dlerr.to_str().ok().map(|s| s.to_string()).unwrap_or_default())
-        } else {
-            Ok(Self { name, ptr })
+        if let Some(ime) = self.ime.as_mut() {
+            ime.set_spot_location(XPoint {
+                x: (position.x * hidpi_factor).round() as c_short,
+                y: (position.y * hidpi_factor).round() as c_short,
+            });
         }
     }
 
-    pub fn get(&self, symbol: &str) -> Option<*mut raw::c_void> {
+    /// Processes the changes of the `current_window_state` (after an input event):
+    /// hit-tests the cursor position, invokes the callbacks, restyles / relayouts
+    /// the window and renders it if necessary
+    fn process_input(&mut self, shared_application_data: &SharedApplicationData) -> Result<(), LinuxStartupError> {
 
-        use std::ffi::CString;
+        let should_render = match shared_application_data.inner.try_borrow_mut() {
+            Ok(mut appdata_lock) => self.process_window_state_changes(&mut *appdata_lock),
+            Err(_) => false,
+        };
 
-        let symbol_name_new = CString::new(symbol.as_bytes()).ok()?;
-        let symbol_new = unsafe { dlsym(self.ptr, symbol_name_new.as_ptr()) };
-        let error = unsafe { dlerror() };
+        self.publish_accessibility_tree();
+
+        if should_render {
+            let physical_size = self.internal.current_window_state.size.get_physical_size();
+            self.render(physical_size.width as i32, physical_size.height as i32)?;
+        }
+
+        Ok(())
+    }
+
+    /// Diffs the previous and current window state (same as on Win32) and invokes
+    /// the callbacks of the hit nodes, returns whether the window needs to be re-rendered
+    fn process_window_state_changes(&mut self, appdata: &mut ApplicationData) -> bool {
+
+        use azul_core::window_state::{Events, CallbacksOfHitTest};
+
+        self.make_current();
+
+        // cursor moved or the layout changed, so we need a new hit test
+        if self.internal.current_window_state.mouse_state.cursor_position.is_inside_window() {
+            let hit_test = crate::wr_translate::fullhittest_new_webrender(
+                &*self.hit_tester.resolve(),
+                self.internal.document_id,
+                self.internal.current_window_state.focused_node,
+                &self.internal.layout_results,
+                &self.internal.current_window_state.mouse_state.cursor_position,
+                self.internal.current_window_state.size.hidpi_factor,
+            );
+            self.internal.current_window_state.last_hit_test = hit_test;
+        }
+
+        // Get events
+        let events = Events::new(
+            &self.internal.current_window_state,
+            &self.internal.previous_window_state,
+        );
+
+        // Get nodes for events
+        let nodes_to_check = NodesToCheck::new(
+            &self.internal.current_window_state.last_hit_test,
+            &events
+        );
+
+        let window_handle = self.get_raw_window_handle();
+        let internal = &mut self.internal;
+        let gl_context_ptr = &self.gl_context_ptr;
+        let image_cache = &mut appdata.image_cache;
+        let system_callbacks = &appdata.config.system_callbacks;
+
+        // Invoke callbacks on nodes
+        let callback_results = appdata.fc_cache.apply_closure(|fc_cache| {
+
+            // Get callbacks for nodes
+            let mut callbacks = CallbacksOfHitTest::new(&nodes_to_check, &events, &internal.layout_results);
+            let current_scroll_states = internal.get_current_scroll_states();
+
+            // Invoke user-defined callbacks in the UI
+            callbacks.call(
+                &internal.previous_window_state,
+                &internal.current_window_state,
+                &window_handle,
+                &current_scroll_states,
+                gl_context_ptr,
+                &mut internal.layout_results,
+                &mut internal.scroll_states,
+                image_cache,
+                fc_cache,
+                system_callbacks,
+                &internal.renderer_resources,
+            )
+        });
+
+        self.process_callback_results(callback_results, &nodes_to_check, appdata)
+    }
+
+    /// Handles the XdndEnter / XdndPosition / XdndLeave / XdndDrop messages of a drag source,
+    /// ignores all other client messages
+    fn handle_xdnd_message(
+        &mut self,
+        message: &XClientMessageEvent,
+        shared_application_data: &SharedApplicationData,
+    ) -> Result<(), LinuxStartupError> {
+
+        use azul_core::window::{CursorPosition, LogicalPosition};
+
+        let atoms = self.xdnd_atoms;
+        if ![atoms.enter, atoms.position, atoms.leave, atoms.drop].contains(&message.message_type) {
+            return Ok(());
+        }
+
+        let data = message.data.as_longs();
+        let source = data[0] as Window;
+        let is_current_source = self.xdnd.as_ref().map(|d| d.source) == Some(source);
+
+        if message.message_type == atoms.enter {
+
+            // bit 0: the source offers more than three types, all types are stored in XdndTypeList
+            let offered = if data[1] & 1 != 0 {
+                self.get_xdnd_type_list(source)
+            } else {
+                data[2..5].iter().filter(|t| **t != 0).map(|t| *t as Atom).collect()
+            };
+
+            self.xdnd = Some(XdndDrag {
+                source,
+                version: data[1] >> 24,
+                target: select_xdnd_target(&offered, &atoms.get_accepted_types()),
+                payload_requested: false,
+                payload: None,
+            });
+
+        } else if message.message_type == atoms.position && is_current_source {
+
+            // the data is requested before the drop, so that callbacks can already
+            // see which files are hovered over the window (data.l[3]: timestamp)
+            self.request_xdnd_payload_once(data[3] as Time);
+
+            let payload = self.xdnd.as_ref().and_then(|d| d.payload.clone());
+            let accept = payload.is_some();
+            // bit 1: send a new XdndPosition for every mouse movement
+            self.send_xdnd_message(source, atoms.status, [
+                self.id as c_long,
+                if accept { 0b11 } else { 0b10 },
+                0,
+                0,
+                if accept { atoms.action_copy as c_long } else { 0 },
+            ]);
+
+            let (root_x, root_y) = decode_xdnd_position(data[2]);
+            let (x, y) = self.translate_root_coordinates(root_x, root_y);
+
+            let state = &mut self.internal.current_window_state;
+            self.internal.previous_window_state = Some(state.clone());
+            let hidpi_factor = state.size.hidpi_factor;
+            state.mouse_state.cursor_position = CursorPosition::InWindow(
+                LogicalPosition::new(x as f32 / hidpi_factor, y as f32 / hidpi_factor)
+            );
+            // only one file can be hovered, but all files are dropped
+            let (hovered_file, hovered_text) = match payload {
+                Some(XdndPayload::Files(files)) => (files.first().cloned(), None),
+                Some(XdndPayload::Text(text)) => (None, Some(text)),
+                None => (None, None),
+            };
+            state.hovered_file = hovered_file;
+            state.hovered_text = hovered_text;
+
+            self.process_input(shared_application_data)?;
+
+        } else if message.message_type == atoms.leave && is_current_source {
+
+            self.xdnd = None;
+            self.cancel_xdnd_hover(shared_application_data)?;
+
+        } else if message.message_type == atoms.drop && is_current_source {
+
+            // no XdndPosition was received before the drop (data.l[2]: timestamp)
+            self.request_xdnd_payload_once(data[2] as Time);
+
+            let drag = match self.xdnd.take() {
+                Some(s) => s,
+                None => return Ok(()),
+            };
+
+            // the data has already been transferred, so the source can finish the drag before the callbacks run
+            let accept = drag.payload.is_some();
+            self.send_xdnd_message(source, atoms.finished, [
+                self.id as c_long,
+                if accept && drag.version >= 5 { 1 } else { 0 },
+                if accept && drag.version >= 5 { atoms.action_copy as c_long } else { 0 },
+                0,
+                0,
+            ]);
+
+            let dropped: Vec<(Option<AzString>, Option<AzString>)> = match drag.payload {
+                Some(XdndPayload::Files(files)) => files.into_iter().map(|f| (Some(f), None)).collect(),
+                Some(XdndPayload::Text(text)) => vec![(None, Some(text))],
+                None => Vec::new(),
+            };
+
+            if dropped.is_empty() {
+                self.cancel_xdnd_hover(shared_application_data)?;
+            }
+
+            // one DroppedFile event per file: every file is hovered and then dropped
+            for (file, text) in dropped {
+                let state = &mut self.internal.current_window_state;
+                state.hovered_file = file.clone();
+                state.hovered_text = text.clone();
+                self.internal.previous_window_state = Some(state.clone());
+                state.hovered_file = None;
+                state.hovered_text = None;
+                state.dropped_file = file;
+                state.dropped_text = text;
+
+                self.process_input(shared_application_data)?;
+
+                let state = &mut self.internal.current_window_state;
+                state.dropped_file = None;
+                state.dropped_text = None;
+            }
+
+        }
+
+        Ok(())
+    }
+
+    /// The drag left the window or was dropped without any usable data
+    fn cancel_xdnd_hover(&mut self, shared_application_data: &SharedApplicationData) -> Result<(), LinuxStartupError> {
+
+        let state = &mut self.internal.current_window_state;
+        if state.hovered_file.is_none() && state.hovered_text.is_none() {
+            return Ok(());
+        }
+
+        self.internal.previous_window_state = Some(state.clone());
+        state.hovered_file = None;
+        state.hovered_text = None;
+
+        self.process_input(shared_application_data)
+    }
+
+    /// Reads the XdndTypeList property of the source window (without deleting it)
+    fn get_xdnd_type_list(&self, source: Window) -> Vec<Atom> {
+        let dpy = self.dpy.display;
+        let type_list = self.xdnd_atoms.type_list;
+        // items of format 32 are stored as long
+        with_clipboard(|c| c.read_property(dpy, source, type_list, false))
+        .flatten()
+        .map(|(_, data)| {
+            data.chunks_exact(mem::size_of::<Atom>())
+            .filter_map(|c| c.try_into().ok().map(Atom::from_ne_bytes))
+            .collect()
+        })
+        .unwrap_or_default()
+    }
+
+    /// Asks the drag source to convert the XdndSelection to the target type and waits for
+    /// the data, `time` is the timestamp of the XdndPosition or XdndDrop message
+    fn request_xdnd_payload(&self, target: Atom, time: Time) -> Option<XdndPayload> {
+        let dpy = self.dpy.display;
+        let window = self.id as Window;
+        let selection = self.xdnd_atoms.selection;
+        let data = with_clipboard(|c| {
+            let property = c.intern_atom(dpy, CLIPBOARD_PROPERTY);
+            c.convert_selection(dpy, window, selection, target, property, time)
+        }).flatten()?;
+        parse_xdnd_payload(data, target == self.xdnd_atoms.uri_list)
+    }
+
+    /// Requests the payload of the current drag from the source, only once per drag
+    fn request_xdnd_payload_once(&mut self, time: Time) {
+        let target = match self.xdnd.as_mut() {
+            Some(drag) if !drag.payload_requested => {
+                drag.payload_requested = true;
+                drag.target
+            },
+            _ => return,
+        };
+        let payload = target.and_then(|t| self.request_xdnd_payload(t, time));
+        if let Some(drag) = self.xdnd.as_mut() {
+            drag.payload = payload;
+        }
+    }
+
+    /// Sends an XdndStatus / XdndFinished message to the drag source
+    fn send_xdnd_message(&self, source: Window, message_type: Atom, data: [c_long; 5]) {
+
+        let dpy = self.dpy.display;
+        let mut event = XEvent { pad: [0; 24] };
+        event.client_message = XClientMessageEvent {
+            type_: X11_CLIENT_MESSAGE,
+            serial: 0,
+            send_event: X11_TRUE,
+            display: dpy,
+            window: source,
+            message_type,
+            format: 32,
+            data: ClientMessageData { longs: data },
+        };
+
+        unsafe {
+            (self.xlib.XSendEvent)(dpy, source, X11_FALSE, 0, &mut event);
+            (self.xlib.XFlush)(dpy);
+        }
+    }
+
+    /// Converts root window coordinates (as sent in XdndPosition) to window coordinates
+    fn translate_root_coordinates(&self, x: c_int, y: c_int) -> (c_int, c_int) {
+
+        let dpy = self.dpy.display;
+        let mut window_x = 0;
+        let mut window_y = 0;
+        let mut child = 0;
+
+        unsafe {
+            let root = (self.xlib.XRootWindow)(dpy, (self.xlib.XDefaultScreen)(dpy));
+            (self.xlib.XTranslateCoordinates)(dpy, root, self.id as Window, x, y, &mut window_x, &mut window_y, &mut child);
+        }
+
+        (window_x, window_y)
+    }
+
+    /// Updates the position of the window on the screen after the window was moved
+    /// (ConfigureNotify), the position of the event is relative to the frame of the
+    /// window manager, not to the root window
+    fn update_position(&mut self) {
+
+        let dpy = self.dpy.display;
+        let mut root_x = 0;
+        let mut root_y = 0;
+        let mut child = 0;
+
+        unsafe {
+            let root = (self.xlib.XRootWindow)(dpy, (self.xlib.XDefaultScreen)(dpy));
+            (self.xlib.XTranslateCoordinates)(dpy, self.id as Window, root, 0, 0, &mut root_x, &mut root_y, &mut child);
+        }
+
+        self.internal.current_window_state.position = WindowPosition::Initialized(PhysicalPositionI32 { x: root_x, y: root_y });
+    }
+
+    /// Sends the current accessibility tree to the screen reader (if connected)
+    fn publish_accessibility_tree(&mut self) {
+
+        let bridge = match self.accessibility.as_mut() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let state = &self.internal.current_window_state;
+        let window = AccessibleWindow {
+            title: state.title.as_str().to_string(),
+            position: match state.position {
+                WindowPosition::Initialized(p) => (p.x, p.y),
+                WindowPosition::Uninitialized => (0, 0),
+            },
+            size: state.size.get_logical_size(),
+            hidpi_factor: state.size.hidpi_factor,
+            is_focused: state.flags.has_focus,
+        };
+
+        bridge.publish(window, AccessibilityTree::new(&self.internal.layout_results, state.focused_node));
+    }
+
+    /// Renders the next frame of the running CSS animations
+    fn process_animation_frame(
+        &mut self,
+        shared_application_data: &SharedApplicationData,
+    ) -> Result<(), LinuxStartupError> {
+
+        let should_render = match shared_application_data.inner.try_borrow_mut() {
+            Ok(mut appdata_lock) => self.run_animation_frame(&mut *appdata_lock),
+            Err(_) => false,
+        };
+
+        if should_render {
+            let physical_size = self.internal.current_window_state.size.get_physical_size();
+            self.render(physical_size.width as i32, physical_size.height as i32)?;
+        }
+
+        Ok(())
+    }
+
+    /// Invokes the timers and threads whose tick is due, then renders the window if necessary
+    fn process_timers(
+        &mut self,
+        shared_application_data: &SharedApplicationData,
+    ) -> Result<(), LinuxStartupError> {
+
+        let should_render = match shared_application_data.inner.try_borrow_mut() {
+            Ok(mut appdata_lock) => self.run_timers_and_threads(&mut *appdata_lock),
+            Err(_) => false,
+        };
+
+        if should_render {
+            self.publish_accessibility_tree();
+            let physical_size = self.internal.current_window_state.size.get_physical_size();
+            self.render(physical_size.width as i32, physical_size.height as i32)?;
+        }
+
+        Ok(())
+    }
+
+    /// Invokes the `AfterMount` callbacks of the initial DOM and the create callback of the window
+    fn process_window_created(
+        &mut self,
+        create_callback: &mut OptionCallback,
+        shared_application_data: &SharedApplicationData,
+    ) -> Result<(), LinuxStartupError> {
+
+        let should_render = match shared_application_data.inner.try_borrow_mut() {
+            Ok(mut appdata_lock) => self.run_window_created(create_callback, &mut *appdata_lock),
+            Err(_) => false,
+        };
+
+        if should_render {
+            self.publish_accessibility_tree();
+            let physical_size = self.internal.current_window_state.size.get_physical_size();
+            self.render(physical_size.width as i32, physical_size.height as i32)?;
+        }
+
+        Ok(())
+    }
+
+    /// Answers the requests of the screen reader, waiting at most `timeout_ms` milliseconds
+    /// for new requests. Actions (clicking a button, focusing a node, ...) invoke the callbacks
+    /// of the node, the window is then updated and the new tree is published.
+    fn process_accessibility_requests(
+        &mut self,
+        shared_application_data: &SharedApplicationData,
+        timeout_ms: i32,
+    ) -> Result<(), LinuxStartupError> {
+
+        let requests = match self.accessibility.as_mut() {
+            Some(s) => s.dispatch(timeout_ms),
+            None => return Ok(()),
+        };
+
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        let mut appdata_lock = match shared_application_data.inner.try_borrow_mut() {
+            Ok(o) => o,
+            Err(_) => return Ok(()),
+        };
+
+        let appdata_lock = &mut *appdata_lock;
+        let mut should_render = false;
+
+        for request in requests {
+
+            let window_handle = self.get_raw_window_handle();
+            let internal = &mut self.internal;
+            let gl_context_ptr = &self.gl_context_ptr;
+            let image_cache = &mut appdata_lock.image_cache;
+            let system_callbacks = &appdata_lock.config.system_callbacks;
+
+            let callback_results = appdata_lock.fc_cache.apply_closure(|fc_cache| {
+                internal.invoke_accessibility_action(
+                    request.node,
+                    request.action,
+                    &window_handle,
+                    gl_context_ptr,
+                    image_cache,
+                    fc_cache,
+                    system_callbacks,
+                )
+            });
+
+            let nodes_to_check = NodesToCheck::simulated_mouse_move(
+                &self.internal.current_window_state.last_hit_test,
+                self.internal.current_window_state.focused_node,
+                self.internal.current_window_state.mouse_state.mouse_down()
+            );
+
+            should_render = self.process_callback_results(callback_results, &nodes_to_check, appdata_lock) || should_render;
+        }
+
+        self.publish_accessibility_tree();
+
+        if should_render {
+            let physical_size = self.internal.current_window_state.size.get_physical_size();
+            self.render(physical_size.width as i32, physical_size.height as i32)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LinuxWindow for X11Window {
+
+    fn make_current(&self) {
+        (self.egl.eglMakeCurrent)(
+            self.egl_display,
+            self.egl_surface,
+            self.egl_surface,
+            self.egl_context
+        );
+    }
+
+    fn parts(&mut self) -> LinuxWindowParts<'_> {
+        LinuxWindowParts {
+            internal: &mut self.internal,
+            gl_context_ptr: &self.gl_context_ptr,
+            render_api: &mut self.render_api,
+            hit_tester: &mut self.hit_tester,
+            timers: &mut self.timers,
+            windows_created: &mut self.windows_created,
+        }
+    }
+
+    fn get_raw_window_handle(&self) -> RawWindowHandle {
+        RawWindowHandle::Xlib(XlibHandle {
+            window: self.id,
+            display: self.dpy.display as *mut c_void,
+        })
+    }
+
+    // of the window state that the callbacks modified, the X11 shell only
+    // takes over the position of the window and of the IME candidate window
+    fn apply_modified_window_state(&mut self, modified_window_state: &WindowState) {
+
+        if let WindowPosition::Initialized(p) = modified_window_state.position {
+            if modified_window_state.position != self.internal.current_window_state.position {
+                unsafe { (self.xlib.XMoveWindow)(self.dpy.get(), self.id as Window, p.x, p.y) };
+                self.internal.current_window_state.position = modified_window_state.position;
+            }
+        }
+
+        self.internal.current_window_state.ime_position = modified_window_state.ime_position;
+        self.update_ime_position();
+    }
+}
+
+/// Sets the hints of a new window for the window manager: the position (if the application
+/// set it), the `_NET_WM_WINDOW_TYPE` of the `x11_window_types`, whether the window has
+/// decorations (`_MOTIF_WM_HINTS`) and whether it stays above the other windows
+fn set_window_manager_hints(xlib: &Xlib, dpy: *mut Display, window: Window, state: &WindowState) {
+
+    let intern_atom = |name: &str| {
+        let name = encode_ascii(name);
+        unsafe { (xlib.XInternAtom)(dpy, name.as_ptr() as *const c_char, X11_FALSE) }
+    };
+
+    // properties with format 32 are passed as an array of longs
+    let change_property = |property: Atom, property_type: Atom, data: &[c_long]| unsafe {
+        (xlib.XChangeProperty)(
+            dpy,
+            window,
+            property,
+            property_type,
+            32,
+            X11_PROP_MODE_REPLACE,
+            data.as_ptr() as *const c_uchar,
+            data.len() as c_int
+        );
+    };
+
+    // without USPosition, the window manager may place the window anywhere
+    if let WindowPosition::Initialized(p) = state.position {
+        let mut size_hints = [0; X11_SIZE_HINTS_LEN];
+        size_hints[0] = X11_US_POSITION | X11_P_POSITION;
+        size_hints[1] = p.x as c_long;
+        size_hints[2] = p.y as c_long;
+        change_property(intern_atom("WM_NORMAL_HINTS"), X11_XA_WM_SIZE_HINTS, &size_hints);
+    }
+
+    let window_types = state.platform_specific_options.linux_options.x11_window_types
+        .as_ref()
+        .iter()
+        .map(|t| intern_atom(get_window_type_atom_name(*t)) as c_long)
+        .collect::<Vec<_>>();
+
+    if !window_types.is_empty() {
+        change_property(intern_atom("_NET_WM_WINDOW_TYPE"), X11_XA_ATOM, &window_types);
+    }
+
+    if !state.flags.has_decorations {
+        let mut motif_hints = [0; MWM_HINTS_LEN];
+        motif_hints[0] = MWM_HINTS_DECORATIONS;
+        let motif_hints_atom = intern_atom("_MOTIF_WM_HINTS");
+        change_property(motif_hints_atom, motif_hints_atom, &motif_hints);
+    }
+
+    // the initial state of the window, set before the window is mapped
+    if state.flags.is_always_on_top {
+        change_property(intern_atom("_NET_WM_STATE"), X11_XA_ATOM, &[intern_atom("_NET_WM_STATE_ABOVE") as c_long]);
+    }
+}
+
+fn get_window_type_atom_name(window_type: XWindowType) -> &'static str {
+    match window_type {
+        XWindowType::Desktop => "_NET_WM_WINDOW_TYPE_DESKTOP",
+        XWindowType::Dock => "_NET_WM_WINDOW_TYPE_DOCK",
+        XWindowType::Toolbar => "_NET_WM_WINDOW_TYPE_TOOLBAR",
+        XWindowType::Menu => "_NET_WM_WINDOW_TYPE_MENU",
+        XWindowType::Utility => "_NET_WM_WINDOW_TYPE_UTILITY",
+        XWindowType::Splash => "_NET_WM_WINDOW_TYPE_SPLASH",
+        XWindowType::Dialog => "_NET_WM_WINDOW_TYPE_DIALOG",
+        XWindowType::DropdownMenu => "_NET_WM_WINDOW_TYPE_DROPDOWN_MENU",
+        XWindowType::PopupMenu => "_NET_WM_WINDOW_TYPE_POPUP_MENU",
+        XWindowType::Tooltip => "_NET_WM_WINDOW_TYPE_TOOLTIP",
+        XWindowType::Notification => "_NET_WM_WINDOW_TYPE_NOTIFICATION",
+        XWindowType::Combo => "_NET_WM_WINDOW_TYPE_COMBO",
+        XWindowType::Dnd => "_NET_WM_WINDOW_TYPE_DND",
+        XWindowType::Normal => "_NET_WM_WINDOW_TYPE_NORMAL",
+    }
+}
+
+/// Translates a keysym (see `X11/keysymdef.h`) to the character it types
+fn translate_keysym_to_char(keysym: KeySym) -> Option<char> {
+    match keysym {
+        // Latin-1 keysyms are identical to their code point
+        0x0020..=0x007e | 0x00a0..=0x00ff => core::char::from_u32(keysym as u32),
+        // keypad digits and operators: KP_Multiply ("*") .. KP_9, KP_Equal
+        0xffaa..=0xffb9 | 0xffbd => core::char::from_u32((keysym - 0xff80) as u32),
+        // all other characters are sent as "0x01000000 + code point"
+        0x0100_0100..=0x0110_ffff => core::char::from_u32((keysym - 0x0100_0000) as u32),
+        _ => None,
+    }
+}
+
+/// Applies a ButtonPress / ButtonRelease event to the mouse state,
+/// returns `false` if the event does not change the mouse state
+fn apply_button_event(mouse_state: &mut MouseState, button: c_uint, pressed: bool) -> bool {
+    match button {
+        X11_BUTTON_LEFT => mouse_state.left_down = pressed,
+        X11_BUTTON_MIDDLE => mouse_state.middle_down = pressed,
+        X11_BUTTON_RIGHT => mouse_state.right_down = pressed,
+        // one wheel "click" = one press + release, positive = scroll up / left (same as WM_MOUSEWHEEL)
+        X11_BUTTON_SCROLL_UP if pressed => mouse_state.scroll_y = Some(1.0).into(),
+        X11_BUTTON_SCROLL_DOWN if pressed => mouse_state.scroll_y = Some(-1.0).into(),
+        X11_BUTTON_SCROLL_LEFT if pressed => mouse_state.scroll_x = Some(1.0).into(),
+        X11_BUTTON_SCROLL_RIGHT if pressed => mouse_state.scroll_x = Some(-1.0).into(),
+        _ => return false,
+    }
+    true
+}
+
+// ---- clipboard
+
+// Largest property that is sent in one piece, larger contents are sent with the INCR protocol
+const X11_INCR_CHUNK_SIZE: usize = 256 * 1024;
+
+// How long to wait for the owner of a selection to answer
+const CLIPBOARD_TIMEOUT_MS: u64 = 1000;
+
+// Name of the property on the requesting window that the selection owner writes the content to
+const CLIPBOARD_PROPERTY: &str = "AZUL_CLIPBOARD";
+
+thread_local! {
+    // clipboard of the running X11 application (set in run()), used by `app::get_clipboard_content`
+    static CLIPBOARD: RefCell<Option<X11Clipboard>> = RefCell::new(None);
+}
+
+fn with_clipboard<T, F: FnOnce(&mut X11Clipboard) -> T>(f: F) -> Option<T> {
+    CLIPBOARD.with(|c| {
+        let mut c = c.try_borrow_mut().ok()?;
+        Some(f(c.as_mut()?))
+    })
+}
+
+/// Owner and requestor of the CLIPBOARD and PRIMARY selections (ICCCM section 2)
+///
+/// The selections are owned by the window that set the content, other applications
+/// request the content with `SelectionRequest` events which are answered in the event loop.
+pub(crate) struct X11Clipboard {
+    xlib: Rc<Xlib>,
+    /// (owner window, selection atom) -> content offered to other applications
+    owned: BTreeMap<(Window, Atom), ClipboardContent>,
+    /// Contents larger than `X11_INCR_CHUNK_SIZE` that are currently sent to other applications
+    incr_transfers: Vec<IncrTransfer>,
+    /// Open windows of the application, `app::Clipboard` uses the first one
+    windows: Vec<(*mut Display, Window)>,
+    /// Time of the last input event, selections are owned and requested
+    /// as of this time instead of `CurrentTime` (ICCCM section 2.1)
+    last_event_time: Time,
+}
+
+/// Content that is sent chunk by chunk: the requestor deletes the property
+/// after reading it, then the next chunk is written (ICCCM section 2.7.2)
+struct IncrTransfer {
+    display: *mut Display,
+    /// Window of this application that owns the selection
+    owner: Window,
+    requestor: Window,
+    property: Atom,
+    target: Atom,
+    content: ClipboardContent,
+    offset: usize,
+}
+
+impl X11Clipboard {
+
+    fn new(xlib: Rc<Xlib>) -> Self {
+        Self {
+            xlib,
+            owned: BTreeMap::new(),
+            incr_transfers: Vec::new(),
+            windows: Vec::new(),
+            last_event_time: X11_CURRENT_TIME,
+        }
+    }
+
+    fn intern_atom(&self, dpy: *mut Display, name: &str) -> Atom {
+        let name = encode_ascii(name);
+        unsafe { (self.xlib.XInternAtom)(dpy, name.as_ptr() as *const c_char, X11_FALSE) }
+    }
+
+    fn get_selection_atom(&self, dpy: *mut Display, selection: ClipboardSelection) -> Atom {
+        match selection {
+            ClipboardSelection::Clipboard => self.intern_atom(dpy, "CLIPBOARD"),
+            ClipboardSelection::Primary => self.intern_atom(dpy, "PRIMARY"),
+        }
+    }
+
+    /// Makes the window the owner of the selection, returns false if the
+    /// X server didn't accept the window as the owner
+    fn set_content(
+        &mut self,
+        dpy: *mut Display,
+        window: Window,
+        selection: ClipboardSelection,
+        content: ClipboardContent,
+    ) -> bool {
+
+        let selection = self.get_selection_atom(dpy, selection);
+
+        let owner = unsafe {
+            (self.xlib.XSetSelectionOwner)(dpy, selection, window, self.last_event_time);
+            (self.xlib.XGetSelectionOwner)(dpy, selection)
+        };
+
+        if owner != window {
+            return false;
+        }
+
+        // only one window of the application can own the selection
+        self.owned.retain(|(_, s), _| *s != selection);
+        self.owned.insert((window, selection), content);
+        true
+    }
+
+    /// Requests the content of the selection from its owner and waits for the answer
+    fn get_content(
+        &mut self,
+        dpy: *mut Display,
+        window: Window,
+        selection: ClipboardSelection,
+        mime_type: &str,
+    ) -> Option<ClipboardContent> {
+
+        let selection = self.get_selection_atom(dpy, selection);
+
+        // the event loop is blocked while the callback is running,
+        // so a request to this application would never be answered
+        if let Some(content) = self.owned.iter().find(|((_, s), _)| *s == selection).map(|(_, c)| c) {
+            return convert_clipboard_content(content, mime_type);
+        }
+
+        let property = self.intern_atom(dpy, CLIPBOARD_PROPERTY);
+
+        get_clipboard_request_targets(mime_type).iter().find_map(|target| {
+            let target = self.intern_atom(dpy, target);
+            let data = self.convert_selection(dpy, window, selection, target, property, self.last_event_time)?;
+            Some(ClipboardContent {
+                mime_type: if is_text_mime_type(mime_type) {
+                    AzString::from_const_str(ClipboardContent::MIME_TYPE_TEXT)
+                } else {
+                    mime_type.to_string().into()
+                },
+                data: data.into(),
+            })
+        })
+    }
+
+    /// Asks the selection owner to write the selection (converted to `target`) as of the
+    /// `time` into the `property` of the window, returns the content of the property
+    fn convert_selection(
+        &self,
+        dpy: *mut Display,
+        window: Window,
+        selection: Atom,
+        target: Atom,
+        property: Atom,
+        time: Time,
+    ) -> Option<Vec<u8>> {
+
+        unsafe {
+            (self.xlib.XConvertSelection)(dpy, selection, target, property, window, time);
+            (self.xlib.XFlush)(dpy);
+        }
+
+        let notify = self.wait_for_event(dpy, window, X11_SELECTION_NOTIFY, |e| unsafe { e.selection.selection == selection })?;
+
+        // property = None: the owner can't convert the selection to the target
+        if unsafe { notify.selection.property } == 0 {
+            return None;
+        }
+
+        let (property_type, data) = self.read_property(dpy, window, property, true)?;
+        if property_type != self.intern_atom(dpy, "INCR") {
+            return Some(data);
+        }
+
+        // INCR: deleting the property (in read_property) tells the owner to send the next chunk,
+        // a chunk of length zero marks the end of the content
+        let mut content = Vec::new();
+        loop {
+            self.wait_for_event(dpy, window, X11_PROPERTY_NOTIFY, |e| unsafe {
+                e.property.atom == property && e.property.state == X11_PROPERTY_NEW_VALUE
+            })?;
+            let (chunk_type, chunk) = self.read_property(dpy, window, property, true)?;
+            // the notification of the INCR property itself (or of a chunk that was already
+            // read) can still be queued, the property doesn't exist (type None) in that case
+            if chunk_type == 0 {
+                continue;
+            }
+            if chunk.is_empty() {
+                return Some(content);
+            }
+            content.extend_from_slice(&chunk);
+        }
+    }
+
+    /// Reads (and optionally deletes) a property of the window, returns the type and the content of the property
+    fn read_property(&self, dpy: *mut Display, window: Window, property: Atom, delete: bool) -> Option<(Atom, Vec<u8>)> {
+
+        let mut property_type = 0;
+        let mut format = 0;
+        let mut item_count = 0;
+        let mut bytes_after = 0;
+        let mut data = ptr::null_mut();
+
+        let result = unsafe {
+            (self.xlib.XGetWindowProperty)(
+                dpy, window, property,
+                0, 0x1fff_ffff, // offset and length in 32-bit units
+                if delete { X11_TRUE } else { X11_FALSE },
+                X11_ANY_PROPERTY_TYPE,
+                &mut property_type,
+                &mut format,
+                &mut item_count,
+                &mut bytes_after,
+                &mut data,
+            )
+        };
+
+        if result != X11_SUCCESS {
+            return None;
+        }
+
+        // items of format 16 and 32 are stored as short / long
+        let len = match format {
+            8 => item_count as usize,
+            16 => item_count as usize * mem::size_of::<i16>(),
+            32 => item_count as usize * mem::size_of::<c_long>(),
+            _ => 0,
+        };
+
+        let content = if data.is_null() {
+            Vec::new()
+        } else {
+            let content = unsafe { core::slice::from_raw_parts(data, len) }.to_vec();
+            unsafe { (self.xlib.XFree)(data as *mut c_void) };
+            content
+        };
+
+        Some((property_type, content))
+    }
+
+    /// Waits for an event of the window that matches the filter, other events
+    /// (including events of the same type that don't match) stay in the queue
+    fn wait_for_event<F: Fn(&XEvent) -> bool>(
+        &self,
+        dpy: *mut Display,
+        window: Window,
+        event_type: c_int,
+        filter: F,
+    ) -> Option<XEvent> {
+
+        use std::time::{Duration, Instant};
+
+        let filter = |e: &XEvent| unsafe { e.type_ == event_type && e.any.window == window } && filter(e);
+        let timeout = Instant::now() + Duration::from_millis(CLIPBOARD_TIMEOUT_MS);
+
+        loop {
+
+            if let Some(s) = check_if_event(&self.xlib, dpy, &filter) {
+                return Some(s);
+            }
+
+            // XCheckIfEvent has read everything that arrived so far,
+            // sleep until the X server sends the next event
+            let timeout_ms = get_poll_timeout_ms(-1, Some(timeout));
+            if timeout_ms == 0 {
+                return None;
+            }
+
+            let mut fd = PollFd {
+                fd: (self.xlib.XConnectionNumber)(dpy),
+                events: POLLIN,
+                revents: 0,
+            };
+
+            unsafe { poll(&mut fd, 1, timeout_ms) };
+        }
+    }
+
+    /// Another application wants to paste the selection owned by this application
+    fn handle_selection_request(&mut self, request: &XSelectionRequestEvent) {
+
+        let dpy = request.display;
+
+        // obsolete clients don't set a property, the target is used instead
+        let property = if request.property == 0 { request.target } else { request.property };
+        let mut reply_property = 0;
+
+        if let Some(content) = self.owned.get(&(request.owner, request.selection)) {
+
+            let targets_atom = self.intern_atom(dpy, "TARGETS");
+            let targets = get_clipboard_owner_targets(content)
+                .iter()
+                .map(|t| self.intern_atom(dpy, t))
+                .collect::<Vec<_>>();
+
+            if request.target == targets_atom {
+                // items of format 32 are sent as long
+                let atoms = Some(targets_atom).into_iter()
+                    .chain(targets.iter().copied())
+                    .map(|a| a as c_long)
+                    .collect::<Vec<_>>();
+                unsafe {
+                    (self.xlib.XChangeProperty)(
+                        dpy, request.requestor, property, X11_XA_ATOM, 32, X11_PROP_MODE_REPLACE,
+                        atoms.as_ptr() as *const c_uchar, atoms.len() as c_int,
+                    );
+                }
+                reply_property = property;
+            } else if targets.contains(&request.target) {
+                let data = content.data.as_ref();
+                if data.len() > X11_INCR_CHUNK_SIZE {
+                    // the value of the INCR property is a lower bound of the content size
+                    let size = [data.len() as c_long];
+                    let incr_atom = self.intern_atom(dpy, "INCR");
+                    unsafe {
+                        (self.xlib.XSelectInput)(dpy, request.requestor, X11_PROPERTY_CHANGE_MASK);
+                        (self.xlib.XChangeProperty)(
+                            dpy, request.requestor, property, incr_atom, 32, X11_PROP_MODE_REPLACE,
+                            size.as_ptr() as *const c_uchar, 1,
+                        );
+                    }
+                    self.incr_transfers.push(IncrTransfer {
+                        display: dpy,
+                        owner: request.owner,
+                        requestor: request.requestor,
+                        property,
+                        target: request.target,
+                        content: content.clone(),
+                        offset: 0,
+                    });
+                } else {
+                    unsafe {
+                        (self.xlib.XChangeProperty)(
+                            dpy, request.requestor, property, request.target, 8, X11_PROP_MODE_REPLACE,
+                            data.as_ptr(), data.len() as c_int,
+                        );
+                    }
+                }
+                reply_property = property;
+            }
+        }
+
+        // property = None tells the requestor that the conversion was refused
+        let mut reply = XEvent { pad: [0; 24] };
+        reply.selection = XSelectionEvent {
+            type_: X11_SELECTION_NOTIFY,
+            serial: 0,
+            send_event: X11_TRUE,
+            display: dpy,
+            requestor: request.requestor,
+            selection: request.selection,
+            target: request.target,
+            property: reply_property,
+            time: request.time,
+        };
+
+        unsafe {
+            (self.xlib.XSendEvent)(dpy, request.requestor, X11_FALSE, 0, &mut reply);
+            (self.xlib.XFlush)(dpy);
+        }
+    }
+
+    /// Another application has taken over the selection
+    fn handle_selection_clear(&mut self, clear: &XSelectionClearEvent) {
+        self.owned.remove(&(clear.window, clear.selection));
+    }
+
+    /// Sends the next chunk of an INCR transfer after the requestor has deleted the last chunk
+    fn handle_property_notify(&mut self, event: &XPropertyEvent) {
+
+        if event.state != X11_PROPERTY_DELETE {
+            return;
+        }
+
+        let index = match self.incr_transfers.iter().position(|t| {
+            t.display == event.display && t.requestor == event.window && t.property == event.atom
+        }) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let transfer = &mut self.incr_transfers[index];
+        let remaining = &transfer.content.data.as_ref()[transfer.offset..];
+        let chunk = &remaining[..remaining.len().min(X11_INCR_CHUNK_SIZE)];
+
+        unsafe {
+            (self.xlib.XChangeProperty)(
+                transfer.display, transfer.requestor, transfer.property, transfer.target,
+                8, X11_PROP_MODE_REPLACE, chunk.as_ptr(), chunk.len() as c_int,
+            );
+            (self.xlib.XFlush)(transfer.display);
+        }
+
+        transfer.offset += chunk.len();
+
+        // the chunk of length zero was sent, the transfer is finished
+        if chunk.is_empty() {
+            self.incr_transfers.remove(index);
+        }
+    }
+
+    fn add_window(&mut self, dpy: *mut Display, window: Window) {
+        self.windows.push((dpy, window));
+    }
+
+    /// Removes the selections and transfers of a closed window
+    fn remove_window(&mut self, dpy: *mut Display, window: Window) {
+        self.windows.retain(|w| *w != (dpy, window));
+        self.owned.retain(|(w, _), _| *w != window);
+        self.incr_transfers.retain(|t| t.display != dpy || t.owner != window);
+    }
+}
+
+/// Removes the first event in the queue that matches the filter (without blocking)
+fn check_if_event<F: Fn(&XEvent) -> bool>(xlib: &Xlib, dpy: *mut Display, filter: &F) -> Option<XEvent> {
+
+    extern "C" fn predicate<F: Fn(&XEvent) -> bool>(_: *mut Display, event: *mut XEvent, filter: *mut c_char) -> X11Bool {
+        let filter = unsafe { &*(filter as *const F) };
+        if filter(unsafe { &*event }) { X11_TRUE } else { X11_FALSE }
+    }
+
+    let mut event = XEvent { pad: [0; 24] };
+    let found = (xlib.XCheckIfEvent)(dpy, &mut event, predicate::<F>, filter as *const F as *mut c_char);
+    if found != X11_FALSE { Some(event) } else { None }
+}
+
+/// Returns the targets that the content is offered as (in addition to TARGETS)
+fn get_clipboard_owner_targets(content: &ClipboardContent) -> Vec<&str> {
+    if content.is_text() {
+        vec!["UTF8_STRING", ClipboardContent::MIME_TYPE_TEXT, "text/plain"]
+    } else {
+        vec![content.mime_type.as_str()]
+    }
+}
+
+/// Returns the targets to request from the selection owner for a MIME type, in order of preference
+fn get_clipboard_request_targets(mime_type: &str) -> Vec<&str> {
+    if is_text_mime_type(mime_type) {
+        vec!["UTF8_STRING", ClipboardContent::MIME_TYPE_TEXT, "text/plain"]
+    } else {
+        vec![mime_type]
+    }
+}
+
+/// Converts the content owned by this application to the requested MIME type
+fn convert_clipboard_content(content: &ClipboardContent, mime_type: &str) -> Option<ClipboardContent> {
+    if content.mime_type.as_str() == mime_type {
+        Some(content.clone())
+    } else if is_text_mime_type(mime_type) {
+        content.get_text().map(ClipboardContent::text)
+    } else {
+        None
+    }
+}
+
+/// Reads the CLIPBOARD or PRIMARY selection, called from `app::get_clipboard_content`
+pub(crate) fn get_clipboard_content(
+    window: &XlibHandle,
+    selection: ClipboardSelection,
+    mime_type: &str,
+) -> Option<ClipboardContent> {
+    with_clipboard(|c| c.get_content(window.display as *mut Display, window.window, selection, mime_type))?
+}
+
+/// Sets the CLIPBOARD or PRIMARY selection, called from `app::set_clipboard_content`
+pub(crate) fn set_clipboard_content(
+    window: &XlibHandle,
+    selection: ClipboardSelection,
+    content: ClipboardContent,
+) -> bool {
+    with_clipboard(|c| c.set_content(window.display as *mut Display, window.window, selection, content))
+    .unwrap_or(false)
+}
+
+/// Returns whether an X11 application is running on this thread
+pub(crate) fn is_running() -> bool {
+    CLIPBOARD.with(|c| c.try_borrow().map(|c| c.is_some()).unwrap_or(true))
+}
+
+/// Reads the CLIPBOARD selection as text, called from `app::Clipboard`
+pub(crate) fn get_clipboard_string() -> Option<String> {
+    with_clipboard(|c| {
+        let (dpy, window) = c.windows.first().copied()?;
+        let content = c.get_content(dpy, window, ClipboardSelection::Clipboard, ClipboardContent::MIME_TYPE_TEXT)?;
+        Some(content.get_text()?.into_library_owned_string())
+    })?
+}
+
+/// Sets the text of the CLIPBOARD selection, called from `app::Clipboard`
+pub(crate) fn set_clipboard_string(text: String) -> Option<()> {
+    with_clipboard(|c| {
+        let (dpy, window) = c.windows.first().copied()?;
+        if c.set_content(dpy, window, ClipboardSelection::Clipboard, ClipboardContent::text(text.into())) {
+            Some(())
+        } else {
+            None
+        }
+    })?
+}
+
+/// Returns the time of events that are sent in response to user input
+fn get_event_time(event: &XEvent) -> Option<Time> {
+    unsafe {
+        match event.type_ {
+            X11_KEY_PRESS | X11_KEY_RELEASE => Some(event.key.time),
+            X11_BUTTON_PRESS | X11_BUTTON_RELEASE => Some(event.button.time),
+            X11_MOTION_NOTIFY => Some(event.motion.time),
+            X11_ENTER_NOTIFY | X11_LEAVE_NOTIFY => Some(event.crossing.time),
+            X11_PROPERTY_NOTIFY => Some(event.property.time),
+            _ => None,
+        }
+    }
+}
+
+// XDND protocol version announced in the XdndAware property of the window
+const XDND_VERSION: c_long = 5;
+
+/// Atoms of the XDND drag-and-drop protocol, see https://freedesktop.org/wiki/Specifications/XDND/
+#[derive(Debug, Copy, Clone)]
+struct XdndAtoms {
+    aware: Atom,
+    enter: Atom,
+    position: Atom,
+    status: Atom,
+    leave: Atom,
+    drop: Atom,
+    finished: Atom,
+    selection: Atom,
+    type_list: Atom,
+    action_copy: Atom,
+    uri_list: Atom,
+    text_plain_utf8: Atom,
+    utf8_string: Atom,
+    text_plain: Atom,
+}
+
+impl XdndAtoms {
+
+    fn new(xlib: &Xlib, dpy: *mut Display) -> Self {
+        let intern_atom = |name: &str| {
+            let name = encode_ascii(name);
+            unsafe { (xlib.XInternAtom)(dpy, name.as_ptr() as *const c_char, X11_FALSE) }
+        };
+        Self {
+            aware: intern_atom("XdndAware"),
+            enter: intern_atom("XdndEnter"),
+            position: intern_atom("XdndPosition"),
+            status: intern_atom("XdndStatus"),
+            leave: intern_atom("XdndLeave"),
+            drop: intern_atom("XdndDrop"),
+            finished: intern_atom("XdndFinished"),
+            selection: intern_atom("XdndSelection"),
+            type_list: intern_atom("XdndTypeList"),
+            action_copy: intern_atom("XdndActionCopy"),
+            uri_list: intern_atom(ClipboardContent::MIME_TYPE_URI_LIST),
+            text_plain_utf8: intern_atom(ClipboardContent::MIME_TYPE_TEXT),
+            utf8_string: intern_atom("UTF8_STRING"),
+            text_plain: intern_atom("text/plain"),
+        }
+    }
+
+    /// Data types that can be dropped on the window, in order of preference
+    fn get_accepted_types(&self) -> [Atom; 4] {
+        [self.uri_list, self.text_plain_utf8, self.utf8_string, self.text_plain]
+    }
+}
+
+/// Drag-and-drop operation of another application that is currently over the window
+struct XdndDrag {
+    /// Window of the application that started the drag
+    source: Window,
+    /// XDND version of the source
+    version: c_long,
+    /// Data type that is requested from the source, `None` if the window can't accept any of the offered types
+    target: Option<Atom>,
+    /// Set once the payload was requested, so that a failed request is not repeated
+    payload_requested: bool,
+    /// Dragged files or text, requested from the source on the first XdndPosition message
+    payload: Option<XdndPayload>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum XdndPayload {
+    Files(Vec<AzString>),
+    Text(AzString),
+}
+
+/// Returns the first of the `accepted` types that the source offers
+fn select_xdnd_target(offered: &[Atom], accepted: &[Atom]) -> Option<Atom> {
+    accepted.iter().copied().find(|a| offered.contains(a))
+}
+
+/// XdndPosition stores the root window coordinates of the cursor as `(x << 16) | y`
+fn decode_xdnd_position(packed: c_long) -> (c_int, c_int) {
+    (((packed >> 16) & 0xffff) as c_int, (packed & 0xffff) as c_int)
+}
+
+/// Converts the data sent by the drag source into the dragged files / text,
+/// a `text/uri-list` without local files is not accepted
+fn parse_xdnd_payload(data: Vec<u8>, is_uri_list: bool) -> Option<XdndPayload> {
+    if is_uri_list {
+        let content = ClipboardContent {
+            mime_type: AzString::from_const_str(ClipboardContent::MIME_TYPE_URI_LIST),
+            data: data.into(),
+        };
+        let files = content.get_uri_list()
+            .iter()
+            .filter_map(|uri| file_uri_to_path(uri.as_str()))
+            .map(|path| path.into())
+            .collect::<Vec<AzString>>();
+        if files.is_empty() { None } else { Some(XdndPayload::Files(files)) }
+    } else {
+        let text = String::from_utf8_lossy(&data).trim_end_matches('\0').to_string();
+        if text.is_empty() { None } else { Some(XdndPayload::Text(text.into())) }
+    }
+}
+
+/// Converts a `file://[host]/path` URI (RFC 8089) into a path, percent-escapes are decoded
+fn file_uri_to_path(uri: &str) -> Option<String> {
+
+    let rest = uri.strip_prefix("file://")?;
+    // the host is usually empty or "localhost", the path starts after it
+    let path = &rest[rest.find('/')?..];
+
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = match bytes.get(i..i + 3) {
+            Some([b'%', hi, lo]) => core::str::from_utf8(&[*hi, *lo]).ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None,
+        };
+        match escaped {
+            Some(byte) => { decoded.push(byte); i += 3; },
+            None => { decoded.push(bytes[i]); i += 1; },
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+// XIM value names (XNInputStyle, XNClientWindow, ... in Xlib.h)
+const XN_QUERY_INPUT_STYLE: &[u8] = b"queryInputStyle\0";
+const XN_INPUT_STYLE: &[u8] = b"inputStyle\0";
+const XN_CLIENT_WINDOW: &[u8] = b"clientWindow\0";
+const XN_FOCUS_WINDOW: &[u8] = b"focusWindow\0";
+const XN_FILTER_EVENTS: &[u8] = b"filterEvents\0";
+const XN_PREEDIT_ATTRIBUTES: &[u8] = b"preeditAttributes\0";
+const XN_SPOT_LOCATION: &[u8] = b"spotLocation\0";
+const XN_PREEDIT_START_CALLBACK: &[u8] = b"preeditStartCallback\0";
+const XN_PREEDIT_DONE_CALLBACK: &[u8] = b"preeditDoneCallback\0";
+const XN_PREEDIT_DRAW_CALLBACK: &[u8] = b"preeditDrawCallback\0";
+const XN_PREEDIT_CARET_CALLBACK: &[u8] = b"preeditCaretCallback\0";
+
+fn xn(name: &[u8]) -> *const c_char {
+    name.as_ptr() as *const c_char
+}
+
+#[repr(C)]
+struct XIMStyles {
+    count_styles: c_ushort,
+    supported_styles: *mut XIMStyle,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct XPoint {
+    x: c_short,
+    y: c_short,
+}
+
+#[repr(C)]
+struct XIMCallback {
+    client_data: *mut c_char,
+    callback: *mut c_void,
+}
+
+#[repr(C)]
+struct XIMText {
+    length: c_ushort,
+    feedback: *mut c_ulong,
+    encoding_is_wchar: X11Bool,
+    string: XIMTextString,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+union XIMTextString {
+    multi_byte: *mut c_char,
+    wide_char: *mut c_int,
+}
+
+#[repr(C)]
+struct XIMPreeditDrawCallbackStruct {
+    caret: c_int,
+    chg_first: c_int,
+    chg_length: c_int,
+    text: *mut XIMText,
+}
+
+#[repr(C)]
+struct XIMPreeditCaretCallbackStruct {
+    position: c_int,
+    direction: c_int,
+    style: c_int,
+}
+
+/// Connection to the input method (XIM) of a window, used to compose text that
+/// can't be typed directly (CJK, dead keys): the input method filters the key
+/// events, reports the preedit text through the callbacks of the input context
+/// ("on-the-spot") and sends the committed text as a KeyPress event
+struct X11Ime {
+    xlib: Rc<Xlib>,
+    im: XIM,
+    ic: XIC,
+    /// Preedit text, written by the preedit callbacks of the input context
+    preedit: Box<XimPreedit>,
+    /// Preedit callbacks, the input context keeps pointers to them
+    callbacks: Box<[XIMCallback; 4]>,
+    /// Last position of the candidate window, in physical window coordinates
+    spot_location: Option<XPoint>,
+}
+
+impl X11Ime {
+
+    /// Opens the input method of the current locale and creates an input context for the
+    /// window, returns `None` if there is no input method (i.e. no IM server is running)
+    fn new(xlib: Rc<Xlib>, dpy: *mut Display, window: Window) -> Option<Self> {
+
+        let im = (xlib.XOpenIM)(dpy, ptr::null_mut(), ptr::null_mut(), ptr::null_mut());
+        if im.is_null() {
+            return None;
+        }
+
+        let style = match get_xim_styles(&xlib, im).and_then(|styles| select_xim_style(&styles)) {
+            Some(s) => s,
+            None => {
+                (xlib.XCloseIM)(im);
+                return None;
+            },
+        };
+
+        let mut preedit = Box::new(XimPreedit::default());
+        let client_data = &mut *preedit as *mut XimPreedit as *mut c_char;
+        let callbacks = Box::new([
+            XIMCallback { client_data, callback: xim_preedit_start as *mut c_void },
+            XIMCallback { client_data, callback: xim_preedit_done as *mut c_void },
+            XIMCallback { client_data, callback: xim_preedit_draw as *mut c_void },
+            XIMCallback { client_data, callback: xim_preedit_caret as *mut c_void },
+        ]);
+        let mut spot_location = XPoint { x: 0, y: 0 };
+
+        let preedit_attributes = unsafe {
+            if style & XIM_PREEDIT_CALLBACKS != 0 {
+                (xlib.XVaCreateNestedList)(
+                    0,
+                    xn(XN_PREEDIT_START_CALLBACK), &callbacks[0] as *const XIMCallback,
+                    xn(XN_PREEDIT_DONE_CALLBACK), &callbacks[1] as *const XIMCallback,
+                    xn(XN_PREEDIT_DRAW_CALLBACK), &callbacks[2] as *const XIMCallback,
+                    xn(XN_PREEDIT_CARET_CALLBACK), &callbacks[3] as *const XIMCallback,
+                    ptr::null_mut::<c_void>(),
+                )
+            } else if style & XIM_PREEDIT_POSITION != 0 {
+                // "over-the-spot": the input method draws the preedit text at the spot location
+                (xlib.XVaCreateNestedList)(
+                    0,
+                    xn(XN_SPOT_LOCATION), &mut spot_location as *mut XPoint,
+                    ptr::null_mut::<c_void>(),
+                )
+            } else {
+                ptr::null_mut()
+            }
+        };
+
+        let ic = unsafe {
+            if preedit_attributes.is_null() {
+                (xlib.XCreateIC)(
+                    im,
+                    xn(XN_INPUT_STYLE), style,
+                    xn(XN_CLIENT_WINDOW), window,
+                    xn(XN_FOCUS_WINDOW), window,
+                    ptr::null_mut::<c_void>(),
+                )
+            } else {
+                (xlib.XCreateIC)(
+                    im,
+                    xn(XN_INPUT_STYLE), style,
+                    xn(XN_CLIENT_WINDOW), window,
+                    xn(XN_FOCUS_WINDOW), window,
+                    xn(XN_PREEDIT_ATTRIBUTES), preedit_attributes,
+                    ptr::null_mut::<c_void>(),
+                )
+            }
+        };
+
+        if !preedit_attributes.is_null() {
+            (xlib.XFree)(preedit_attributes);
+        }
+
+        if ic.is_null() {
+            (xlib.XCloseIM)(im);
+            return None;
+        }
+
+        Some(Self { xlib, im, ic, preedit, callbacks, spot_location: None })
+    }
+
+    /// Events that the input method needs to receive on the client window
+    fn get_filter_events(&self) -> c_long {
+        let mut filter_events: c_long = 0;
+        unsafe { (self.xlib.XGetICValues)(
+            self.ic,
+            xn(XN_FILTER_EVENTS), &mut filter_events as *mut c_long,
+            ptr::null_mut::<c_void>(),
+        ) };
+        filter_events
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        if focused {
+            (self.xlib.XSetICFocus)(self.ic);
+        } else {
+            (self.xlib.XUnsetICFocus)(self.ic);
+        }
+    }
+
+    /// Moves the candidate window of the input method (or the preedit text
+    /// in "over-the-spot" mode), relative to the top left of the window
+    fn set_spot_location(&mut self, spot_location: XPoint) {
+
+        if self.spot_location == Some(spot_location) {
+            return;
+        }
+
+        self.spot_location = Some(spot_location);
+        let mut spot_location = spot_location;
+
+        unsafe {
+            let preedit_attributes = (self.xlib.XVaCreateNestedList)(
+                0,
+                xn(XN_SPOT_LOCATION), &mut spot_location as *mut XPoint,
+                ptr::null_mut::<c_void>(),
+            );
+            if preedit_attributes.is_null() {
+                return;
+            }
+            (self.xlib.XSetICValues)(
+                self.ic,
+                xn(XN_PREEDIT_ATTRIBUTES), preedit_attributes,
+                ptr::null_mut::<c_void>(),
+            );
+            (self.xlib.XFree)(preedit_attributes);
+        }
+    }
+
+    /// Returns the keysym and the UTF-8 text of a KeyPress event,
+    /// including the text that the input method has committed
+    fn lookup_string(&self, key_event: &mut XKeyEvent) -> (KeySym, String) {
+
+        let mut text_buffer = vec![0_u8; 64];
+        let mut keysym: KeySym = 0;
+        let mut status: c_int = 0;
+
+        let mut len = (self.xlib.Xutf8LookupString)(
+            self.ic,
+            key_event,
+            text_buffer.as_mut_ptr() as *mut c_char,
+            text_buffer.len() as c_int,
+            &mut keysym,
+            &mut status,
+        );
+
+        // long commits (i.e. a whole sentence): retry with the required buffer size
+        if status == X11_BUFFER_OVERFLOW {
+            text_buffer = vec![0_u8; len.max(0) as usize];
+            len = (self.xlib.Xutf8LookupString)(
+                self.ic,
+                key_event,
+                text_buffer.as_mut_ptr() as *mut c_char,
+                text_buffer.len() as c_int,
+                &mut keysym,
+                &mut status,
+            );
+        }
+
+        let text = match status {
+            X11_LOOKUP_CHARS | X11_LOOKUP_BOTH => {
+                let len = (len.max(0) as usize).min(text_buffer.len());
+                String::from_utf8_lossy(&text_buffer[..len]).into_owned()
+            },
+            _ => String::new(),
+        };
+
+        let keysym = match status {
+            X11_LOOKUP_KEYSYM | X11_LOOKUP_BOTH => keysym,
+            _ => 0,
+        };
+
+        (keysym, text)
+    }
+
+    fn get_composition(&self) -> OptionImeComposition {
+        self.preedit.get_composition()
+    }
+}
+
+impl Drop for X11Ime {
+    fn drop(&mut self) {
+        (self.xlib.XDestroyIC)(self.ic);
+        (self.xlib.XCloseIM)(self.im);
+    }
+}
+
+/// Returns the input styles that the input method supports
+fn get_xim_styles(xlib: &Xlib, im: XIM) -> Option<Vec<XIMStyle>> {
+
+    let mut styles: *mut XIMStyles = ptr::null_mut();
+    let error = unsafe { (xlib.XGetIMValues)(
+        im,
+        xn(XN_QUERY_INPUT_STYLE), &mut styles as *mut *mut XIMStyles,
+        ptr::null_mut::<c_void>(),
+    ) };
+
+    if !error.is_null() || styles.is_null() {
+        return None;
+    }
+
+    let supported = unsafe {
+        let s = &*styles;
+        if s.supported_styles.is_null() {
+            Vec::new()
+        } else {
+            core::slice::from_raw_parts(s.supported_styles, s.count_styles as usize).to_vec()
+        }
+    };
+
+    (xlib.XFree)(styles as *mut c_void);
+
+    Some(supported)
+}
+
+/// Selects the input style, in order of preference: preedit text drawn by the
+/// application, drawn by the input method at the text cursor, or in its own window
+fn select_xim_style(supported: &[XIMStyle]) -> Option<XIMStyle> {
+    [
+        XIM_PREEDIT_CALLBACKS | XIM_STATUS_NOTHING,
+        XIM_PREEDIT_CALLBACKS | XIM_STATUS_NONE,
+        XIM_PREEDIT_POSITION | XIM_STATUS_NOTHING,
+        XIM_PREEDIT_POSITION | XIM_STATUS_NONE,
+        XIM_PREEDIT_NOTHING | XIM_STATUS_NOTHING,
+        XIM_PREEDIT_NOTHING | XIM_STATUS_NONE,
+        XIM_PREEDIT_NONE | XIM_STATUS_NONE,
+    ].iter().copied().find(|style| supported.contains(style))
+}
+
+/// Preedit text of the input method, updated by the XIM preedit callbacks
+#[derive(Debug, Default, Clone, PartialEq)]
+struct XimPreedit {
+    text: Vec<char>,
+    /// Position of the cursor in the preedit text, in characters
+    cursor: usize,
+}
+
+impl XimPreedit {
+
+    /// Replaces `chg_length` characters at `chg_first` with `text` and moves the cursor to `caret`
+    fn draw(&mut self, chg_first: c_int, chg_length: c_int, text: &[char], caret: c_int) {
+        let start = (chg_first.max(0) as usize).min(self.text.len());
+        let end = start.saturating_add(chg_length.max(0) as usize).min(self.text.len());
+        self.text.splice(start..end, text.iter().copied());
+        self.cursor = (caret.max(0) as usize).min(self.text.len());
+    }
+
+    /// Moves the cursor in the given `XIMCaretDirection`, returns the new position
+    fn move_caret(&mut self, direction: c_int, position: c_int) -> usize {
+        self.cursor = match direction {
+            XIM_FORWARD_CHAR => self.cursor.saturating_add(1),
+            XIM_BACKWARD_CHAR => self.cursor.saturating_sub(1),
+            XIM_LINE_START => 0,
+            XIM_LINE_END => self.text.len(),
+            XIM_ABSOLUTE_POSITION => position.max(0) as usize,
+            _ => self.cursor,
+        }.min(self.text.len());
+        self.cursor
+    }
+
+    /// Composition that is reported to the focused node, `None` if the preedit text is empty
+    fn get_composition(&self) -> OptionImeComposition {
+        if self.text.is_empty() {
+            None.into()
+        } else {
+            Some(ImeComposition {
+                text: self.text.iter().collect::<String>().into(),
+                cursor: self.cursor,
+            }).into()
+        }
+    }
+}
+
+extern "C" fn xim_preedit_start(_: XIC, client_data: *mut c_char, _: *mut c_char) -> c_int {
+    let preedit = unsafe { &mut *(client_data as *mut XimPreedit) };
+    *preedit = XimPreedit::default();
+    -1 // no length limit for the preedit text
+}
+
+extern "C" fn xim_preedit_done(_: XIC, client_data: *mut c_char, _: *mut c_char) {
+    let preedit = unsafe { &mut *(client_data as *mut XimPreedit) };
+    *preedit = XimPreedit::default();
+}
+
+extern "C" fn xim_preedit_draw(_: XIC, client_data: *mut c_char, call_data: *mut c_char) {
+    let preedit = unsafe { &mut *(client_data as *mut XimPreedit) };
+    let draw = unsafe { &*(call_data as *const XIMPreeditDrawCallbackStruct) };
+    // no text = the changed characters are deleted
+    let text = if draw.text.is_null() { Vec::new() } else { unsafe { decode_xim_text(&*draw.text) } };
+    preedit.draw(draw.chg_first, draw.chg_length, &text, draw.caret);
+}
+
+extern "C" fn xim_preedit_caret(_: XIC, client_data: *mut c_char, call_data: *mut c_char) {
+    let preedit = unsafe { &mut *(client_data as *mut XimPreedit) };
+    let caret = unsafe { &mut *(call_data as *mut XIMPreeditCaretCallbackStruct) };
+    // the input method reads back the new position
+    caret.position = preedit.move_caret(caret.direction, caret.position) as c_int;
+}
+
+/// Decodes the preedit text sent by the input method, multi-byte
+/// strings are in the encoding of the locale (usually UTF-8)
+unsafe fn decode_xim_text(text: &XIMText) -> Vec<char> {
+    let length = text.length as usize;
+    if text.encoding_is_wchar != X11_FALSE {
+        if text.string.wide_char.is_null() {
+            return Vec::new();
+        }
+        core::slice::from_raw_parts(text.string.wide_char, length)
+            .iter()
+            .filter_map(|c| char::from_u32(*c as u32))
+            .collect()
+    } else {
+        if text.string.multi_byte.is_null() {
+            return Vec::new();
+        }
+        std::ffi::CStr::from_ptr(text.string.multi_byte).to_string_lossy().chars().take(length).collect()
+    }
+}
+
+struct X11Display {
+    display: *mut Display,
+    xlib: Rc<Xlib>,
+}
+
+impl X11Display {
+
+    fn get<'a>(&'a mut self) -> &'a mut Display {
+        unsafe { &mut *self.display }
+    }
+
+    fn open(xlib: Rc<Xlib>) -> Option<Self> {
+
+        let dpy = unsafe { (xlib.XOpenDisplay)(&0) };
+
+        if dpy.is_null() {
+            return None;
+        }
+
+        Some(Self {
+            display: dpy,
+            xlib,
+        })
+    }
+
+    /// Return the DPI on X11 systems
+    ///
+    /// Note: slow - cache output!
+    pub fn get_dpi_scale_factor(&self) -> f32 {
+
+        use std::env;
+        use std::process::Command;
+
+        // Execute "gsettings get org.gnome.desktop.interface text-scaling-factor"
+        // and parse the output
+        let gsettings_dpi_factor =
+            Command::new("gsettings")
+                .arg("get")
+                .arg("org.gnome.desktop.interface")
+                .arg("text-scaling-factor")
+                .output().ok()
+                .map(|output| output.stdout)
+                .and_then(|stdout_bytes| String::from_utf8(stdout_bytes).ok())
+                .map(|stdout_string| stdout_string.lines().collect::<String>())
+                .and_then(|gsettings_output| gsettings_output.parse::<f32>().ok());
+
+        if let Some(s) = gsettings_dpi_factor {
+            return s;
+        }
+
+        // Failed, try parsing QT_FONT_DPI
+        let qt_font_dpi = env::var("QT_FONT_DPI")
+            .ok()
+            .and_then(|font_dpi| font_dpi.parse::<f32>().ok());
+
+        if let Some(s) = qt_font_dpi {
+            return s;
+        }
+
+        // Failed again, try getting monitor info from XRandR
+        // TODO
+        return 1.0;
+
+        /*
+            XRRScreenResources *xrrr = XRRGetScreenResources(d, w);
+            XRRCrtcInfo *xrrci;
+            int i;
+            int ncrtc = xrrr->ncrtc;
+            for (i = 0; i < ncrtc; ++i) {
+                xrrci = XRRGetCrtcInfo(d, xrrr, xrrr->crtcs[i]);
+                printf("%dx%d+%d+%d\n", xrrci->width, xrrci->height, xrrci->x, xrrci->y);
+                XRRFreeCrtcInfo(xrrci);
+            }
+            XRRFreeScreenResources(xrrr);
+        */
+
+
+    }
+}
+
+impl Drop for X11Display {
+    fn drop(&mut self) {
+        // TODO: error checking?
+        let _ = unsafe { (self.xlib.XCloseDisplay)(self.display) };
+    }
+}
+
+/// A platform-specific equivalent of the cross-platform `Library`.
+pub struct Library {
+    name: &'static str,
+    ptr: *mut raw::c_void
+}
+
+unsafe impl Send for Library {}
+unsafe impl Sync for Library {}
+
+impl Library {
+
+    /// Dynamically load an arbitrary library by its name (dlopen)
+    pub fn load(name: &'static str) -> Result<Self, String> {
+
+        use alloc::borrow::Cow;
+        use std::ffi::{CString, CStr};
+
+        const RTLD_NOW: raw::c_int = 2;
+
+        let cow = CString::new(name.as_bytes()).map_err(|e| String::new())?;
+        let ptr = unsafe { dlopen(cow.as_ptr(), RTLD_NOW) };
+
+        if ptr.is_null() {
+            let dlerr = unsafe { CStr::from_ptr(dlerror()) };
+            Err(dlerr.to_str().ok().map(|s| s.to_string()).unwrap_or_default())
+        } else {
+            Ok(Self { name, ptr })
+        }
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<*mut raw::c_void> {
+
+        use std::ffi::CString;
+
+        let symbol_name_new = CString::new(symbol.as_bytes()).ok()?;
+        let symbol_new = unsafe { dlsym(self.ptr, symbol_name_new.as_ptr()) };
+        let error = unsafe { dlerror() };
         if error.is_null() {
             Some(symbol_new)
         } else {
@@ -1902,11 +4284,11 @@ impl Drop for Library {
 }
 
 // OpenGL functions from wglGetProcAddress OR loaded from opengl32.dll
-struct GlFunctions {
+pub(crate) struct GlFunctions {
     _opengl32_dll_handle: Option<Library>,
     egl: Rc<Egl>,
     // implements Rc<dyn gleam::Gl>!
-    functions: Rc<GenericGlContext>,
+    pub(crate) functions: Rc<GenericGlContext>,
 }
 
 impl fmt::Debug for GlFunctions {
@@ -1928,7 +4310,7 @@ fn encode_ascii(input: &str) -> Vec<u8> {
 impl GlFunctions {
 
     // Initializes the DLL, but does not load the functions yet
-    fn initialize(egl: Rc<Egl>) -> Self {
+    pub(crate) fn initialize(egl: Rc<Egl>) -> Self {
 
         // zero-initialize all function pointers
         let context: GenericGlContext = unsafe { mem::zeroed() };
@@ -1942,7 +4324,7 @@ impl GlFunctions {
     }
 
     // Assuming the OpenGL context is current, loads the OpenGL function pointers
-    fn load(&mut self) {
+    pub(crate) fn load(&mut self) {
 
         fn get_func(egl: &Egl, s: &'static str, opengl32_dll: &Option<Library>) -> *mut gl_context_loader::c_void {
 
@@ -2851,3 +5233,495 @@ impl GlFunctions {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::path::Path;
+    use std::process::{Child, Command, Stdio};
+    use std::sync::atomic::AtomicBool;
+    use std::time::{Duration, Instant};
+
+    // Xvfb instance on a free display number, killed when the test ends
+    struct TestServer {
+        xvfb: Child,
+        display: CString,
+    }
+
+    impl TestServer {
+        fn start() -> Option<Self> {
+
+            let display_number = (90..190).find(|n| {
+                !Path::new(&format!("/tmp/.X11-unix/X{}", n)).exists() &&
+                !Path::new(&format!("/tmp/.X{}-lock", n)).exists()
+            })?;
+
+            let xvfb = Command::new("Xvfb")
+                .arg(format!(":{}", display_number))
+                .args(&["-screen", "0", "640x480x24", "-nolisten", "tcp"])
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .ok()?;
+
+            let server = Self {
+                xvfb,
+                display: CString::new(format!(":{}", display_number)).ok()?,
+            };
+
+            let socket = format!("/tmp/.X11-unix/X{}", display_number);
+            let start = Instant::now();
+            while !Path::new(&socket).exists() {
+                if start.elapsed() > Duration::from_secs(10) {
+                    return None;
+                }
+                std::thread::sleep(Duration::from_millis(50));
+            }
+
+            Some(server)
+        }
+    }
+
+    impl Drop for TestServer {
+        fn drop(&mut self) {
+            let _ = self.xvfb.kill();
+            let _ = self.xvfb.wait();
+        }
+    }
+
+    #[test]
+    fn translate_keysyms_to_chars() {
+        assert_eq!(translate_keysym_to_char(0x0061), Some('a'));
+        assert_eq!(translate_keysym_to_char(0x0041), Some('A'));
+        assert_eq!(translate_keysym_to_char(0x0020), Some(' '));
+        assert_eq!(translate_keysym_to_char(0x00e4), Some('ä'));
+        assert_eq!(translate_keysym_to_char(0xffaa), Some('*'));
+        assert_eq!(translate_keysym_to_char(0xffb7), Some('7'));
+        assert_eq!(translate_keysym_to_char(0x010020ac), Some('€'));
+        assert_eq!(translate_keysym_to_char(0xff0d), None); // Return
+        assert_eq!(translate_keysym_to_char(0xffe1), None); // Shift_L
+    }
+
+    #[test]
+    fn apply_button_events() {
+
+        let mut mouse_state = MouseState::default();
+
+        assert!(apply_button_event(&mut mouse_state, X11_BUTTON_LEFT, true));
+        assert!(mouse_state.left_down);
+        assert!(apply_button_event(&mut mouse_state, X11_BUTTON_RIGHT, true));
+        assert!(mouse_state.right_down);
+        assert!(apply_button_event(&mut mouse_state, X11_BUTTON_LEFT, false));
+        assert!(!mouse_state.left_down);
+
+        assert!(apply_button_event(&mut mouse_state, X11_BUTTON_SCROLL_DOWN, true));
+        assert_eq!(mouse_state.scroll_y.into_option(), Some(-1.0));
+        assert!(apply_button_event(&mut mouse_state, X11_BUTTON_SCROLL_LEFT, true));
+        assert_eq!(mouse_state.scroll_x.into_option(), Some(1.0));
+
+        // releasing a wheel button and the "back" / "forward" buttons are ignored
+        assert!(!apply_button_event(&mut mouse_state, X11_BUTTON_SCROLL_DOWN, false));
+        assert!(!apply_button_event(&mut mouse_state, 8, true));
+    }
+
+    #[test]
+    fn file_uris_are_converted_to_paths() {
+        assert_eq!(file_uri_to_path("file:///home/user/a.txt"), Some("/home/user/a.txt".to_string()));
+        assert_eq!(file_uri_to_path("file://localhost/tmp/a%20b.png"), Some("/tmp/a b.png".to_string()));
+        assert_eq!(file_uri_to_path("file:///tmp/%C3%A4%2"), Some("/tmp/\u{e4}%2".to_string()));
+        assert_eq!(file_uri_to_path("https://example.com/a.txt"), None);
+    }
+
+    #[test]
+    fn xdnd_payloads_are_parsed() {
+
+        let uri_list = b"# comment\r\nfile:///tmp/a.txt\r\nhttps://example.com/\r\nfile:///tmp/b.txt\r\n".to_vec();
+        assert_eq!(parse_xdnd_payload(uri_list, true), Some(XdndPayload::Files(vec![
+            AzString::from_const_str("/tmp/a.txt"),
+            AzString::from_const_str("/tmp/b.txt"),
+        ])));
+        assert_eq!(parse_xdnd_payload(b"https://example.com/\r\n".to_vec(), true), None);
+
+        assert_eq!(parse_xdnd_payload(b"hello\0".to_vec(), false), Some(XdndPayload::Text("hello".into())));
+        assert_eq!(parse_xdnd_payload(Vec::new(), false), None);
+    }
+
+    #[test]
+    fn xdnd_positions_and_targets_are_decoded() {
+        assert_eq!(decode_xdnd_position((640 << 16) | 480), (640, 480));
+        assert_eq!(select_xdnd_target(&[10, 20, 30], &[40, 30, 20]), Some(30));
+        assert_eq!(select_xdnd_target(&[10], &[40, 30, 20]), None);
+    }
+
+    #[test]
+    fn xim_styles_are_selected_by_preference() {
+        let callbacks = XIM_PREEDIT_CALLBACKS | XIM_STATUS_NOTHING;
+        let position = XIM_PREEDIT_POSITION | XIM_STATUS_NONE;
+        assert_eq!(select_xim_style(&[XIM_PREEDIT_NOTHING | XIM_STATUS_NOTHING, callbacks]), Some(callbacks));
+        assert_eq!(select_xim_style(&[XIM_PREEDIT_NONE | XIM_STATUS_NONE, position]), Some(position));
+        // XIMStatusArea is not supported
+        assert_eq!(select_xim_style(&[XIM_PREEDIT_CALLBACKS | 0x0100]), None);
+        assert_eq!(select_xim_style(&[]), None);
+    }
+
+    #[test]
+    fn xim_preedit_draws_and_caret_moves_are_applied() {
+
+        let mut preedit = XimPreedit::default();
+        assert_eq!(preedit.get_composition(), OptionImeComposition::None);
+
+        preedit.draw(0, 0, &['n', 'i'], 2);
+        assert_eq!(preedit.cursor, 2);
+
+        // the input method replaces the typed latin characters with kana
+        preedit.draw(0, 2, &['に'], 1);
+        preedit.draw(1, 0, &['ほ', 'ん'], 3);
+        assert_eq!(preedit.get_composition(), OptionImeComposition::Some(ImeComposition {
+            text: "にほん".to_string().into(),
+            cursor: 3,
+        }));
+
+        assert_eq!(preedit.move_caret(XIM_BACKWARD_CHAR, 0), 2);
+        assert_eq!(preedit.move_caret(XIM_LINE_START, 0), 0);
+        assert_eq!(preedit.move_caret(XIM_BACKWARD_CHAR, 0), 0);
+        assert_eq!(preedit.move_caret(XIM_ABSOLUTE_POSITION, 10), 3);
+
+        // out-of-range changes are clamped, deleting all characters ends the composition
+        preedit.draw(0, 10, &[], 5);
+        assert_eq!(preedit.cursor, 0);
+        assert_eq!(preedit.get_composition(), OptionImeComposition::None);
+    }
+
+    #[test]
+    fn xvfb_keycodes_are_translated() {
+
+        use crate::shell::keysym::translate_keysym;
+
+        // skipped if Xvfb or libX11 are not installed
+        let server = match TestServer::start() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let xlib = match Xlib::new() {
+            Ok(o) => o,
+            Err(_) => return,
+        };
+
+        let dpy = (xlib.XOpenDisplay)(server.display.as_ptr());
+        assert!(!dpy.is_null());
+
+        // evdev keycodes of the default "us" keymap: 38 = a, 10 = 1, 36 = Return
+        let a = (xlib.XkbKeycodeToKeysym)(dpy, 38, 0, 0);
+        let shift_a = (xlib.XkbKeycodeToKeysym)(dpy, 38, 0, 1);
+        let shift_1 = (xlib.XkbKeycodeToKeysym)(dpy, 10, 0, 1);
+        let enter = (xlib.XkbKeycodeToKeysym)(dpy, 36, 0, 0);
+
+        assert_eq!(translate_keysym(a as u32), Some(VirtualKeyCode::A));
+        assert_eq!(translate_keysym_to_char(a), Some('a'));
+        assert_eq!(translate_keysym_to_char(shift_a), Some('A'));
+        assert_eq!(translate_keysym_to_char(shift_1), Some('!'));
+        assert_eq!(translate_keysym(enter as u32), Some(VirtualKeyCode::Return));
+        assert_eq!(translate_keysym_to_char(enter), None);
+
+        (xlib.XCloseDisplay)(dpy);
+    }
+
+    // unmapped window on the test server, receives the property changes of the clipboard protocol
+    fn create_test_window(xlib: &Xlib, dpy: *mut Display) -> Window {
+        let root = (xlib.XRootWindow)(dpy, (xlib.XDefaultScreen)(dpy));
+        let mut xattr: XSetWindowAttributes = unsafe { mem::zeroed() };
+        xattr.event_mask = X11_PROPERTY_CHANGE_MASK;
+        (xlib.XCreateWindow)(
+            dpy, root, 0, 0, 1, 1, 0,
+            X11_COPY_FROM_PARENT, X11_INPUT_OUTPUT as u32, ptr::null_mut(),
+            X11_CW_EVENT_MASK, &mut xattr,
+        )
+    }
+
+    // second connection to the test server (= another application) that owns a selection,
+    // answers the requests for the selection on its own thread until it is dropped
+    struct SelectionOwner {
+        stop: Arc<AtomicBool>,
+        thread: Option<std::thread::JoinHandle<()>>,
+    }
+
+    impl SelectionOwner {
+        fn start(server: &TestServer, selection: ClipboardSelection, content: ClipboardContent) -> Self {
+
+            let display = server.display.clone();
+            let stop = Arc::new(AtomicBool::new(false));
+            let thread_stop = stop.clone();
+            let (ready_sender, ready_receiver) = std::sync::mpsc::channel();
+
+            let thread = std::thread::spawn(move || {
+
+                let xlib = Rc::new(Xlib::new().unwrap());
+                let dpy = (xlib.XOpenDisplay)(display.as_ptr());
+                let window = create_test_window(&xlib, dpy);
+                let mut clipboard = X11Clipboard::new(xlib.clone());
+                assert!(clipboard.set_content(dpy, window, selection, content));
+                let _ = ready_sender.send(());
+
+                let mut event = XEvent { pad: [0; 24] };
+                while !thread_stop.load(AtomicOrdering::SeqCst) {
+                    if (xlib.XPending)(dpy) == 0 {
+                        std::thread::sleep(Duration::from_millis(1));
+                        continue;
+                    }
+                    (xlib.XNextEvent)(dpy, &mut event);
+                    match event.get_type() {
+                        X11_SELECTION_REQUEST => clipboard.handle_selection_request(unsafe { &event.selection_request }),
+                        X11_PROPERTY_NOTIFY => clipboard.handle_property_notify(unsafe { &event.property }),
+                        _ => { },
+                    }
+                }
+
+                (xlib.XCloseDisplay)(dpy);
+            });
+
+            ready_receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+
+            Self { stop, thread: Some(thread) }
+        }
+    }
+
+    impl Drop for SelectionOwner {
+        fn drop(&mut self) {
+            self.stop.store(true, AtomicOrdering::SeqCst);
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+        }
+    }
+
+    // connection of the application that requests the selections
+    struct SelectionRequestor {
+        xlib: Rc<Xlib>,
+        dpy: *mut Display,
+        window: Window,
+        clipboard: X11Clipboard,
+    }
+
+    impl SelectionRequestor {
+
+        fn open(server: &TestServer) -> Self {
+            let xlib = Rc::new(Xlib::new().unwrap());
+            let dpy = (xlib.XOpenDisplay)(server.display.as_ptr());
+            assert!(!dpy.is_null());
+            let window = create_test_window(&xlib, dpy);
+            let clipboard = X11Clipboard::new(xlib.clone());
+            Self { xlib, dpy, window, clipboard }
+        }
+
+        fn get_content(&mut self, selection: ClipboardSelection, mime_type: &str) -> Option<ClipboardContent> {
+            self.clipboard.get_content(self.dpy, self.window, selection, mime_type)
+        }
+
+        fn get_targets(&self, selection: ClipboardSelection) -> Vec<Atom> {
+            let selection = self.clipboard.get_selection_atom(self.dpy, selection);
+            let targets = self.atom("TARGETS");
+            let property = self.atom(CLIPBOARD_PROPERTY);
+            self.clipboard.convert_selection(self.dpy, self.window, selection, targets, property, X11_CURRENT_TIME)
+                .unwrap_or_default()
+                .chunks(mem::size_of::<c_long>())
+                .map(|c| c_long::from_ne_bytes(c.try_into().unwrap()) as Atom)
+                .collect()
+        }
+
+        fn atom(&self, name: &str) -> Atom {
+            self.clipboard.intern_atom(self.dpy, name)
+        }
+    }
+
+    impl Drop for SelectionRequestor {
+        fn drop(&mut self) {
+            (self.xlib.XCloseDisplay)(self.dpy);
+        }
+    }
+
+    // skipped if Xvfb or libX11 are not installed
+    fn start_clipboard_test_server() -> Option<TestServer> {
+        Xlib::new().ok()?;
+        TestServer::start()
+    }
+
+    #[test]
+    fn clipboard_targets_are_selected_by_mime_type() {
+
+        let text = ClipboardContent::text("hello".into());
+        let png = ClipboardContent::png(vec![0x89, b'P', b'N', b'G'].into());
+        let uri_list = ClipboardContent::uri_list(&[AzString::from_const_str("file:///tmp/a.txt")]);
+
+        assert_eq!(get_clipboard_owner_targets(&text), vec!["UTF8_STRING", "text/plain;charset=utf-8", "text/plain"]);
+        assert_eq!(get_clipboard_owner_targets(&png), vec!["image/png"]);
+        assert_eq!(get_clipboard_owner_targets(&uri_list), vec!["text/uri-list"]);
+
+        assert_eq!(get_clipboard_request_targets("STRING"), vec!["UTF8_STRING", "text/plain;charset=utf-8", "text/plain"]);
+        assert_eq!(get_clipboard_request_targets("image/png"), vec!["image/png"]);
+        assert_eq!(get_clipboard_request_targets("text/uri-list"), vec!["text/uri-list"]);
+    }
+
+    #[test]
+    fn owned_clipboard_content_is_converted() {
+
+        let text = ClipboardContent::text("hello".into());
+        let png = ClipboardContent::png(vec![0x89, b'P', b'N', b'G'].into());
+        let uri_list = ClipboardContent::uri_list(&[AzString::from_const_str("file:///tmp/a.txt")]);
+
+        assert_eq!(convert_clipboard_content(&text, "UTF8_STRING"), Some(text.clone()));
+        assert_eq!(convert_clipboard_content(&text, "image/png"), None);
+        assert_eq!(convert_clipboard_content(&png, "image/png"), Some(png.clone()));
+        assert_eq!(convert_clipboard_content(&png, "text/plain"), None);
+        assert_eq!(convert_clipboard_content(&uri_list, "text/uri-list"), Some(uri_list.clone()));
+        // a uri-list can be pasted as text
+        assert_eq!(convert_clipboard_content(&uri_list, "text/plain"), Some(ClipboardContent::text("file:///tmp/a.txt\r\n".into())));
+    }
+
+    #[test]
+    fn closed_windows_only_remove_their_own_clipboard_transfers() {
+
+        let xlib = match Xlib::new() {
+            Ok(o) => Rc::new(o),
+            Err(_) => return,
+        };
+
+        // the clipboard never dereferences the display when removing a window
+        let dpy = ptr::NonNull::<Display>::dangling().as_ptr();
+        let content = ClipboardContent::text("hello".into());
+        let mut clipboard = X11Clipboard::new(xlib);
+
+        for (owner, selection) in [(1, 10), (2, 11)].iter() {
+            clipboard.owned.insert((*owner, *selection), content.clone());
+            clipboard.incr_transfers.push(IncrTransfer {
+                display: dpy,
+                owner: *owner,
+                requestor: 100,
+                property: 12,
+                target: 13,
+                content: content.clone(),
+                offset: 0,
+            });
+        }
+
+        clipboard.remove_window(dpy, 1);
+
+        assert_eq!(clipboard.owned.keys().copied().collect::<Vec<_>>(), vec![(2, 11)]);
+        assert_eq!(clipboard.incr_transfers.iter().map(|t| t.owner).collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn xvfb_wait_for_event_keeps_rejected_events() {
+
+        let server = match start_clipboard_test_server() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let requestor = SelectionRequestor::open(&server);
+        let clipboard = requestor.atom("CLIPBOARD");
+        let primary = requestor.atom("PRIMARY");
+
+        for selection in [clipboard, primary].iter() {
+            let mut event = XEvent { pad: [0; 24] };
+            event.selection = XSelectionEvent {
+                type_: X11_SELECTION_NOTIFY,
+                serial: 0,
+                send_event: X11_TRUE,
+                display: requestor.dpy,
+                requestor: requestor.window,
+                selection: *selection,
+                target: 0,
+                property: 0,
+                time: X11_CURRENT_TIME,
+            };
+            (requestor.xlib.XSendEvent)(requestor.dpy, requestor.window, X11_FALSE, 0, &mut event);
+        }
+        (requestor.xlib.XFlush)(requestor.dpy);
+
+        let wait_for_selection = |selection: Atom| {
+            requestor.clipboard.wait_for_event(requestor.dpy, requestor.window, X11_SELECTION_NOTIFY, |e| unsafe {
+                e.selection.selection == selection
+            })
+        };
+
+        // waiting for the second event must not drop the first one
+        assert!(wait_for_selection(primary).is_some());
+        assert!(wait_for_selection(clipboard).is_some());
+        assert!(wait_for_selection(clipboard).is_none());
+    }
+
+    #[test]
+    fn xvfb_clipboard_targets_are_offered() {
+
+        let server = match start_clipboard_test_server() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let _owner = SelectionOwner::start(&server, ClipboardSelection::Clipboard, ClipboardContent::text("hello".into()));
+        let requestor = SelectionRequestor::open(&server);
+
+        let targets = requestor.get_targets(ClipboardSelection::Clipboard);
+        for target in ["TARGETS", "UTF8_STRING", "text/plain;charset=utf-8", "text/plain"].iter() {
+            assert!(targets.contains(&requestor.atom(target)), "missing target {}", target);
+        }
+        assert!(!targets.contains(&requestor.atom("image/png")));
+    }
+
+    #[test]
+    fn xvfb_primary_selection_is_separate_from_the_clipboard() {
+
+        let server = match start_clipboard_test_server() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let _owner = SelectionOwner::start(&server, ClipboardSelection::Primary, ClipboardContent::text("selected text".into()));
+        let mut requestor = SelectionRequestor::open(&server);
+
+        assert_eq!(
+            requestor.get_content(ClipboardSelection::Primary, "text/plain"),
+            Some(ClipboardContent::text("selected text".into()))
+        );
+        assert_eq!(requestor.get_content(ClipboardSelection::Clipboard, "text/plain"), None);
+    }
+
+    #[test]
+    fn xvfb_large_png_is_sent_with_incr() {
+
+        let server = match start_clipboard_test_server() {
+            Some(s) => s,
+            None => return,
+        };
+
+        // three chunks, the last one is not full
+        let data = (0..X11_INCR_CHUNK_SIZE * 2 + 17).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let png = ClipboardContent::png(data.into());
+
+        let _owner = SelectionOwner::start(&server, ClipboardSelection::Clipboard, png.clone());
+        let mut requestor = SelectionRequestor::open(&server);
+
+        assert_eq!(requestor.get_content(ClipboardSelection::Clipboard, "image/png"), Some(png));
+        // the image is not offered as text
+        assert_eq!(requestor.get_content(ClipboardSelection::Clipboard, "text/plain"), None);
+    }
+
+    #[test]
+    fn xvfb_uri_list_is_transferred() {
+
+        let server = match start_clipboard_test_server() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let uris = vec![AzString::from_const_str("file:///tmp/a.txt"), AzString::from_const_str("file:///tmp/b%20c.png")];
+        let _owner = SelectionOwner::start(&server, ClipboardSelection::Clipboard, ClipboardContent::uri_list(&uris));
+        let mut requestor = SelectionRequestor::open(&server);
+
+        let content = requestor.get_content(ClipboardSelection::Clipboard, ClipboardContent::MIME_TYPE_URI_LIST).unwrap();
+        assert_eq!(content.mime_type.as_str(), ClipboardContent::MIME_TYPE_URI_LIST);
+        assert_eq!(content.get_uri_list(), uris);
+    }
+}
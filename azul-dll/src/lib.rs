@@ -131,6 +131,35 @@ pub use AzWebHandleTT as AzWebHandle;
 pub type AzAndroidHandleTT = azul_core::window::AndroidHandle;
 pub use AzAndroidHandleTT as AzAndroidHandle;
 
+/// Which system clipboard to read from / write to
+pub type AzClipboardSelectionTT = azul_core::window::ClipboardSelection;
+pub use AzClipboardSelectionTT as AzClipboardSelection;
+
+/// Content of the system clipboard, tagged with its MIME type
+pub type AzClipboardContentTT = azul_core::window::ClipboardContent;
+pub use AzClipboardContentTT as AzClipboardContent;
+/// Creates a new `ClipboardContent` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `ClipboardContent::text()` constructor.
+#[no_mangle] pub extern "C" fn AzClipboardContent_text(text: AzString) -> AzClipboardContent { AzClipboardContent::text(text) }
+/// Creates a new `ClipboardContent` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `ClipboardContent::uri_list()` constructor.
+#[no_mangle] pub extern "C" fn AzClipboardContent_uriList(uris: AzStringVec) -> AzClipboardContent { AzClipboardContent::uri_list(uris.as_ref()) }
+/// Creates a new `ClipboardContent` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `ClipboardContent::png()` constructor.
+#[no_mangle] pub extern "C" fn AzClipboardContent_png(data: AzU8Vec) -> AzClipboardContent { AzClipboardContent::png(data) }
+/// Equivalent to the Rust `ClipboardContent::is_text()` function.
+#[no_mangle] pub extern "C" fn AzClipboardContent_isText(clipboardcontent: &AzClipboardContent) -> bool { clipboardcontent.is_text() }
+/// Equivalent to the Rust `ClipboardContent::get_text()` function.
+#[no_mangle] pub extern "C" fn AzClipboardContent_getText(clipboardcontent: &AzClipboardContent) -> AzOptionString { clipboardcontent.get_text().into() }
+/// Equivalent to the Rust `ClipboardContent::get_uri_list()` function.
+#[no_mangle] pub extern "C" fn AzClipboardContent_getUriList(clipboardcontent: &AzClipboardContent) -> AzStringVec { clipboardcontent.get_uri_list().into() }
+/// Destructor: Takes ownership of the `ClipboardContent` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzClipboardContent_delete(object: &mut AzClipboardContent) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Which windowing system the window should be created with on Linux
+pub type AzLinuxBackendTT = azul_core::window::LinuxBackend;
+pub use AzLinuxBackendTT as AzLinuxBackend;
+
 /// X11 window hint: Type of window
 pub type AzXWindowTypeTT = azul_core::window::XWindowType;
 pub use AzXWindowTypeTT as AzXWindowType;
@@ -231,6 +260,12 @@ pub use AzKeyboardStateTT as AzKeyboardState;
 /// Destructor: Takes ownership of the `KeyboardState` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzKeyboardState_delete(object: &mut AzKeyboardState) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Preedit text of an input method, i.e. the pinyin or kana that was typed before the user selected one of the candidates
+pub type AzImeCompositionTT = azul_core::window::ImeComposition;
+pub use AzImeCompositionTT as AzImeComposition;
+/// Destructor: Takes ownership of the `ImeComposition` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzImeComposition_delete(object: &mut AzImeComposition) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Current icon of the mouse cursor
 pub type AzMouseCursorTypeTT = azul_core::window::MouseCursorType;
 pub use AzMouseCursorTypeTT as AzMouseCursorType;
@@ -377,6 +412,14 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousKeyboardState(callbackinfo: &AzCallbackInfo) -> AzOptionKeyboardState { callbackinfo.get_previous_keyboard_state().into() }
 /// Returns a copy of the internal `MouseState`. Same as `self.get_window_state().mouse_state`
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getPreviousMouseState(callbackinfo: &AzCallbackInfo) -> AzOptionMouseState { callbackinfo.get_previous_mouse_state().into() }
+/// Returns the path of the file that is currently dragged over the window
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getHoveredFile(callbackinfo: &AzCallbackInfo) -> AzOptionString { callbackinfo.get_hovered_file() }
+/// Returns the path of the file that was dropped on the window (during `On::DroppedFile`)
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getDroppedFile(callbackinfo: &AzCallbackInfo) -> AzOptionString { callbackinfo.get_dropped_file() }
+/// Returns the text that is currently dragged over the window
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getHoveredText(callbackinfo: &AzCallbackInfo) -> AzOptionString { callbackinfo.get_hovered_text() }
+/// Returns the text that was dropped on the window (during `On::DroppedFile`)
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getDroppedText(callbackinfo: &AzCallbackInfo) -> AzOptionString { callbackinfo.get_dropped_text() }
 /// Returns a copy of the current windows `RawWindowHandle`.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_getCurrentWindowHandle(callbackinfo: &AzCallbackInfo) -> AzRawWindowHandle { callbackinfo.get_current_window_handle() }
 /// Returns a **reference-counted copy** of the current windows' `Gl` (context). You can use this to render OpenGL textures.
@@ -441,6 +484,12 @@ pub use AzCallbackInfoTT as AzCallbackInfo;
 #[no_mangle] pub extern "C" fn AzCallbackInfo_stopPropagation(callbackinfo: &mut AzCallbackInfo) { callbackinfo.stop_propagation(); }
 /// Spawns a new window with the given `WindowCreateOptions`.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_createWindow(callbackinfo: &mut AzCallbackInfo, new_window: AzWindowCreateOptions) { callbackinfo.create_window(new_window); }
+/// Reads the system clipboard (or the X11 PRIMARY selection) in the requested MIME type
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getClipboardContent(callbackinfo: &AzCallbackInfo, selection: AzClipboardSelection, mime_type: AzString) -> AzOptionClipboardContent { callbackinfo.get_clipboard_content(selection, mime_type) }
+/// Shortcut for reading the clipboard as `text/plain;charset=utf-8`
+#[no_mangle] pub extern "C" fn AzCallbackInfo_getClipboardText(callbackinfo: &AzCallbackInfo, selection: AzClipboardSelection) -> AzOptionString { callbackinfo.get_clipboard_text(selection) }
+/// Sets the system clipboard (or the X11 PRIMARY selection), returns whether the clipboard could be set
+#[no_mangle] pub extern "C" fn AzCallbackInfo_setClipboardContent(callbackinfo: &mut AzCallbackInfo, selection: AzClipboardSelection, content: AzClipboardContent) -> bool { callbackinfo.set_clipboard_content(selection, content) }
 /// Adds a new `Timer` to the runtime. See the documentation for `Timer` for more information.
 #[no_mangle] pub extern "C" fn AzCallbackInfo_startTimer(callbackinfo: &mut AzCallbackInfo, timer: AzTimer) -> AzTimerId { callbackinfo.start_timer(timer) }
 /// Starts an animation timer on a give NodeId - same as a `Timer`, but uses a pre-configured interpolation function to drive the animation timer
@@ -505,6 +554,10 @@ pub type AzInlineTextTT = azul_impl::callbacks::InlineText;
 pub use AzInlineTextTT as AzInlineText;
 /// Hit-tests the inline text, returns detailed information about which glyph / word / line, etc. the position (usually the mouse cursor) is currently over. Result may be empty (no hits) or contain more than one result (cursor is hovering over multiple overlapping glyphs at once).
 #[no_mangle] pub extern "C" fn AzInlineText_hitTest(inlinetext: &AzInlineText, position: AzLogicalPosition) -> AzInlineTextHitVec { inlinetext.hit_test(position).into() }
+/// Returns the rectangle (zero width, height of the line) of a text cursor placed in front of the character at `char_index`, relative to the top left corner of the inline text. In right-to-left words, the front of a character is its right edge. If the index is past the last character, the cursor is placed behind the last character.
+#[no_mangle] pub extern "C" fn AzInlineText_getCursorRect(inlinetext: &AzInlineText, char_index: usize) -> AzOptionLogicalRect { inlinetext.get_cursor_rect(char_index).into() }
+/// Returns the rectangles covering the characters in the logical range `start..end`, relative to the top left corner of the inline text. A logical range of mixed-direction text is not necessarily visually continuous, so the result can contain more than one rectangle per line.
+#[no_mangle] pub extern "C" fn AzInlineText_getSelectionRects(inlinetext: &AzInlineText, start: usize, end: usize) -> AzLogicalRectVec { inlinetext.get_selection_rects(start, end).into() }
 /// Destructor: Takes ownership of the `InlineText` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzInlineText_delete(object: &mut AzInlineText) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -582,6 +635,11 @@ pub type AzIFrameCallbackType = extern "C" fn(&mut AzRefAny, &mut AzIFrameCallba
 pub type AzIFrameCallbackInfoTT = azul_impl::callbacks::IFrameCallbackInfo;
 pub use AzIFrameCallbackInfoTT as AzIFrameCallbackInfo;
 
+/// C-ABI wrapper over a `TooltipCallbackType`
+pub type AzTooltipCallbackTT = azul_impl::callbacks::TooltipCallback;
+pub use AzTooltipCallbackTT as AzTooltipCallback;
+
+pub type AzTooltipCallbackType = extern "C" fn(&mut AzRefAny, &mut AzLayoutCallbackInfo) -> AzStyledDom;
 /// <img src="../images/scrollbounds.png"/>
 pub type AzIFrameCallbackReturnTT = azul_impl::callbacks::IFrameCallbackReturn;
 pub use AzIFrameCallbackReturnTT as AzIFrameCallbackReturn;
@@ -800,6 +858,10 @@ pub use AzDomTT as AzDom;
 #[no_mangle] pub extern "C" fn AzDom_setAccessibilityInfo(dom: &mut AzDom, accessibility_info: AzAccessibilityInfo) { dom.root.set_accessibility_info(accessibility_info) }
 /// Same as set_accessibility_info, but as a builder method
 #[no_mangle] pub extern "C" fn AzDom_withAccessibilityInfo(dom: &mut AzDom, accessibility_info: AzAccessibilityInfo) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_accessibility_info(accessibility_info); dom }
+/// Sets the actions that assistive technologies can invoke on the DOM root node. See `NodeData::set_accessibility_actions` for more information.
+#[no_mangle] pub extern "C" fn AzDom_setAccessibilityActions(dom: &mut AzDom, actions: AzAccessibilityActionVec) { dom.root.set_accessibility_actions(actions) }
+/// Same as set_accessibility_actions, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withAccessibilityActions(dom: &mut AzDom, actions: AzAccessibilityActionVec) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_accessibility_actions(actions); dom }
 /// Sets the menu bar for the DOM root node. See `NodeData::set_menu_bar` for more information.
 #[no_mangle] pub extern "C" fn AzDom_setMenuBar(dom: &mut AzDom, menu_bar: AzMenu) { dom.root.set_menu_bar(menu_bar) }
 /// Same as set_accessibility_info, but as a builder method
@@ -808,6 +870,10 @@ pub use AzDomTT as AzDom;
 #[no_mangle] pub extern "C" fn AzDom_setContextMenu(dom: &mut AzDom, context_menu: AzMenu) { dom.root.set_context_menu(context_menu) }
 /// Same as set_context_menu, but as a builder method
 #[no_mangle] pub extern "C" fn AzDom_withContextMenu(dom: &mut AzDom, context_menu: AzMenu) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_context_menu(context_menu); dom }
+/// Sets the tooltip for the DOM root node. See `NodeData::set_tooltip` for more information.
+#[no_mangle] pub extern "C" fn AzDom_setTooltip(dom: &mut AzDom, tooltip: AzTooltip) { dom.root.set_tooltip(tooltip) }
+/// Same as set_tooltip, but as a builder method
+#[no_mangle] pub extern "C" fn AzDom_withTooltip(dom: &mut AzDom, tooltip: AzTooltip) -> AzDom { let mut dom = dom.swap_with_default(); dom.root.set_tooltip(tooltip); dom }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
 #[no_mangle] pub extern "C" fn AzDom_hash(dom: &AzDom) -> u64 { dom.root.calculate_node_data_hash().0 }
 /// Returns the number of nodes in the DOM, including all child DOM trees. Result is equal to `self.total_children + 1` (count of all child trees + the root node)
@@ -902,10 +968,18 @@ pub use AzNodeDataTT as AzNodeData;
 #[no_mangle] pub extern "C" fn AzNodeData_setTabIndex(nodedata: &mut AzNodeData, tab_index: AzTabIndex) { nodedata.set_tab_index(tab_index) }
 /// Sets accessibility attributes for this node
 #[no_mangle] pub extern "C" fn AzNodeData_setAccessibilityInfo(nodedata: &mut AzNodeData, accessibility_info: AzAccessibilityInfo) { nodedata.set_accessibility_info(accessibility_info) }
+/// Sets the actions that assistive technologies can invoke on this node. Actions are dispatched as callbacks on the node, i.e. `Default` invokes the `MouseUp` callbacks
+#[no_mangle] pub extern "C" fn AzNodeData_setAccessibilityActions(nodedata: &mut AzNodeData, actions: AzAccessibilityActionVec) { nodedata.set_accessibility_actions(actions) }
+/// Adds an action that assistive technologies can invoke on this node
+#[no_mangle] pub extern "C" fn AzNodeData_addAccessibilityAction(nodedata: &mut AzNodeData, action: AzAccessibilityAction) { nodedata.add_accessibility_action(action) }
+/// Same as set_accessibility_actions, but as a builder method
+#[no_mangle] pub extern "C" fn AzNodeData_withAccessibilityActions(nodedata: &mut AzNodeData, actions: AzAccessibilityActionVec) -> AzNodeData { let mut nodedata = nodedata.swap_with_default(); nodedata.set_accessibility_actions(actions); nodedata }
 /// Adds a (native) menu bar: If this node is the root node the menu bar will be added to the window, else it will be displayed using the width and position of the bounding rectangle
 #[no_mangle] pub extern "C" fn AzNodeData_setMenuBar(nodedata: &mut AzNodeData, menu_bar: AzMenu) { nodedata.set_menu_bar(menu_bar) }
 /// Signalizes that this node has a (native) context-aware menu. If set, the user can left-click the node to open the menu
 #[no_mangle] pub extern "C" fn AzNodeData_setContextMenu(nodedata: &mut AzNodeData, context_menu: AzMenu) { nodedata.set_context_menu(context_menu) }
+/// Shows a tooltip once the mouse has rested on this node for `tooltip.delay`. The tooltip is hidden when the mouse leaves the node or a mouse button or key is pressed.
+#[no_mangle] pub extern "C" fn AzNodeData_setTooltip(nodedata: &mut AzNodeData, tooltip: AzTooltip) { nodedata.set_tooltip(tooltip) }
 /// Calculates the hash of this node (note: in order to be truly unique, you also have to hash the DOM and Node ID).
 #[no_mangle] pub extern "C" fn AzNodeData_hash(nodedata: &AzNodeData) -> u64 { nodedata.calculate_node_data_hash().0 }
 /// Destructor: Takes ownership of the `NodeData` pointer and deletes it.
@@ -957,6 +1031,34 @@ pub use AzAccessibilityInfoTT as AzAccessibilityInfo;
 /// Destructor: Takes ownership of the `AccessibilityInfo` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzAccessibilityInfo_delete(object: &mut AzAccessibilityInfo) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Tooltip of a node, shown in an always-on-top popup window once the mouse has rested on the node for `delay`. See `NodeData::set_tooltip`
+pub type AzTooltipTT = azul_impl::dom::Tooltip;
+pub use AzTooltipTT as AzTooltip;
+/// Creates a new `Tooltip` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Tooltip::text()` constructor.
+#[no_mangle] pub extern "C" fn AzTooltip_text(text: AzString) -> AzTooltip { AzTooltip::text(text) }
+/// Creates a new `Tooltip` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Tooltip::dom()` constructor.
+#[no_mangle] pub extern "C" fn AzTooltip_dom(data: AzRefAny, callback: AzTooltipCallbackType) -> AzTooltip { AzTooltip::dom(data, callback) }
+/// Equivalent to the Rust `Tooltip::set_delay()` function.
+#[no_mangle] pub extern "C" fn AzTooltip_setDelay(tooltip: &mut AzTooltip, delay: AzDuration) { tooltip.set_delay(delay) }
+/// Equivalent to the Rust `Tooltip::with_delay()` function.
+#[no_mangle] pub extern "C" fn AzTooltip_withDelay(tooltip: &mut AzTooltip, delay: AzDuration) -> AzTooltip { tooltip.with_delay(delay) }
+/// Destructor: Takes ownership of the `Tooltip` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTooltip_delete(object: &mut AzTooltip) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TooltipContent` struct
+pub type AzTooltipContentTT = azul_impl::dom::TooltipContent;
+pub use AzTooltipContentTT as AzTooltipContent;
+/// Destructor: Takes ownership of the `TooltipContent` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTooltipContent_delete(object: &mut AzTooltipContent) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TooltipDom` struct
+pub type AzTooltipDomTT = azul_impl::dom::TooltipDom;
+pub use AzTooltipDomTT as AzTooltipDom;
+/// Destructor: Takes ownership of the `TooltipDom` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTooltipDom_delete(object: &mut AzTooltipDom) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// MSAA Accessibility role constants. For information on what each role does, see the <a href="https://docs.microsoft.com/en-us/windows/win32/winauto/object-roles">MSDN Role Constants page</a>
 pub type AzAccessibilityRoleTT = azul_impl::dom::AccessibilityRole;
 pub use AzAccessibilityRoleTT as AzAccessibilityRole;
@@ -965,6 +1067,10 @@ pub use AzAccessibilityRoleTT as AzAccessibilityRole;
 pub type AzAccessibilityStateTT = azul_impl::dom::AccessibilityState;
 pub use AzAccessibilityStateTT as AzAccessibilityState;
 
+/// Action that an assistive technology (screen reader, voice control) can invoke on a node. See `NodeData::set_accessibility_actions`
+pub type AzAccessibilityActionTT = azul_impl::dom::AccessibilityAction;
+pub use AzAccessibilityActionTT as AzAccessibilityAction;
+
 /// Re-export of rust-allocated (stack based) `TabIndex` struct
 pub type AzTabIndexTT = azul_impl::dom::TabIndex;
 pub use AzTabIndexTT as AzTabIndex;
@@ -1104,6 +1210,50 @@ pub use AzStylesheetTT as AzStylesheet;
 /// Destructor: Takes ownership of the `Stylesheet` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzStylesheet_delete(object: &mut AzStylesheet) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// One `@keyframes` block, i.e. `@keyframes fade { from { opacity: 0; } to { opacity: 1; } }`
+pub type AzCssKeyframesTT = azul_impl::css::CssKeyframes;
+pub use AzCssKeyframesTT as AzCssKeyframes;
+/// Destructor: Takes ownership of the `CssKeyframes` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssKeyframes_delete(object: &mut AzCssKeyframes) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// One keyframe inside of a `@keyframes` block, i.e. `50% { opacity: 0.5; }`
+pub type AzCssKeyframeTT = azul_impl::css::CssKeyframe;
+pub use AzCssKeyframeTT as AzCssKeyframe;
+/// Destructor: Takes ownership of the `CssKeyframe` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssKeyframe_delete(object: &mut AzCssKeyframe) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// One `@media` block, i.e. `@media (prefers-color-scheme: dark) { body { color: white; } }`
+pub type AzCssMediaRuleTT = azul_impl::css::CssMediaRule;
+pub use AzCssMediaRuleTT as AzCssMediaRule;
+/// Destructor: Takes ownership of the `CssMediaRule` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssMediaRule_delete(object: &mut AzCssMediaRule) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// One query of a media query list, i.e. `screen and (min-width: 600px)`
+pub type AzCssMediaQueryTT = azul_impl::css::CssMediaQuery;
+pub use AzCssMediaQueryTT as AzCssMediaQuery;
+/// Destructor: Takes ownership of the `CssMediaQuery` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssMediaQuery_delete(object: &mut AzCssMediaQuery) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Media type of a `CssMediaQuery`
+pub type AzCssMediaTypeTT = azul_impl::css::CssMediaType;
+pub use AzCssMediaTypeTT as AzCssMediaType;
+
+/// One media feature, i.e. `(min-width: 600px)` or `(prefers-color-scheme: dark)`
+pub type AzCssMediaFeatureTT = azul_impl::css::CssMediaFeature;
+pub use AzCssMediaFeatureTT as AzCssMediaFeature;
+
+/// Range of a numeric media feature, i.e. `(min-width: 600px)` or `(400px < width <= 700px)`
+pub type AzCssMediaRangeTT = azul_impl::css::CssMediaRange;
+pub use AzCssMediaRangeTT as AzCssMediaRange;
+
+/// Re-export of rust-allocated (stack based) `CssMediaOrientation` struct
+pub type AzCssMediaOrientationTT = azul_impl::css::CssMediaOrientation;
+pub use AzCssMediaOrientationTT as AzCssMediaOrientation;
+
+/// Re-export of rust-allocated (stack based) `CssColorScheme` struct
+pub type AzCssColorSchemeTT = azul_impl::css::CssColorScheme;
+pub use AzCssColorSchemeTT as AzCssColorScheme;
+
 /// Re-export of rust-allocated (stack based) `Css` struct
 pub type AzCssTT = azul_impl::css::Css;
 pub use AzCssTT as AzCss;
@@ -1118,6 +1268,10 @@ pub use AzCssTT as AzCss;
 pub type AzCssPropertyTypeTT = azul_impl::css::CssPropertyType;
 pub use AzCssPropertyTypeTT as AzCssPropertyType;
 
+/// Represents a CSS shorthand key that expands to multiple properties, for example `margin`
+pub type AzCombinedCssPropertyTypeTT = azul_impl::css::CombinedCssPropertyType;
+pub use AzCombinedCssPropertyTypeTT as AzCombinedCssPropertyType;
+
 /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
 pub type AzAnimationInterpolationFunctionTT = azul_impl::css::AnimationInterpolationFunction;
 pub use AzAnimationInterpolationFunctionTT as AzAnimationInterpolationFunction;
@@ -1196,6 +1350,34 @@ pub use AzStyleCompositeFilterTT as AzStyleCompositeFilter;
 pub type AzLayoutAlignContentTT = azul_impl::css::LayoutAlignContent;
 pub use AzLayoutAlignContentTT as AzLayoutAlignContent;
 
+/// One end of a grid track size, i.e. the `min` or `max` of a `minmax(min, max)`
+pub type AzGridTrackBreadthTT = azul_impl::css::GridTrackBreadth;
+pub use AzGridTrackBreadthTT as AzGridTrackBreadth;
+
+/// Single track size in a `grid-template-columns`, `grid-template-rows`, `grid-auto-columns` or `grid-auto-rows` attribute
+pub type AzGridTrackSizingTT = azul_impl::css::GridTrackSizing;
+pub use AzGridTrackSizingTT as AzGridTrackSizing;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridAutoFlow` struct
+pub type AzLayoutGridAutoFlowTT = azul_impl::css::LayoutGridAutoFlow;
+pub use AzLayoutGridAutoFlowTT as AzLayoutGridAutoFlow;
+
+/// Start or end line of a `grid-column` or `grid-row` attribute
+pub type AzGridLineTT = azul_impl::css::GridLine;
+pub use AzGridLineTT as AzGridLine;
+
+/// Re-export of rust-allocated (stack based) `GridPlacement` struct
+pub type AzGridPlacementTT = azul_impl::css::GridPlacement;
+pub use AzGridPlacementTT as AzGridPlacement;
+
+/// Re-export of rust-allocated (stack based) `LayoutRowGap` struct
+pub type AzLayoutRowGapTT = azul_impl::css::LayoutRowGap;
+pub use AzLayoutRowGapTT as AzLayoutRowGap;
+
+/// Re-export of rust-allocated (stack based) `LayoutColumnGap` struct
+pub type AzLayoutColumnGapTT = azul_impl::css::LayoutColumnGap;
+pub use AzLayoutColumnGapTT as AzLayoutColumnGap;
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItems` struct
 pub type AzLayoutAlignItemsTT = azul_impl::css::LayoutAlignItems;
 pub use AzLayoutAlignItemsTT as AzLayoutAlignItems;
@@ -1560,10 +1742,72 @@ pub use AzStyleTransformScale3DTT as AzStyleTransformScale3D;
 pub type AzStyleTransformSkew2DTT = azul_impl::css::StyleTransformSkew2D;
 pub use AzStyleTransformSkew2DTT as AzStyleTransformSkew2D;
 
+/// Represents a `<time>` value in a `transition-duration`, `transition-delay`, `animation-duration` or `animation-delay` attribute, stored in milliseconds
+pub type AzStyleTimeTT = azul_impl::css::StyleTime;
+pub use AzStyleTimeTT as AzStyleTime;
+
+/// Control points of a `cubic-bezier(x1, y1, x2, y2)` easing function
+pub type AzStyleCubicBezierTT = azul_impl::css::StyleCubicBezier;
+pub use AzStyleCubicBezierTT as AzStyleCubicBezier;
+
+/// Position of the jumps in a `steps(n, position)` easing function
+pub type AzStyleStepPositionTT = azul_impl::css::StyleStepPosition;
+pub use AzStyleStepPositionTT as AzStyleStepPosition;
+
+/// Represents a `steps(n, position)` easing function
+pub type AzStyleStepsTT = azul_impl::css::StyleSteps;
+pub use AzStyleStepsTT as AzStyleSteps;
+
+/// Represents an `<easing-function>` in a `transition-timing-function` or `animation-timing-function` attribute
+pub type AzStyleTimingFunctionTT = azul_impl::css::StyleTimingFunction;
+pub use AzStyleTimingFunctionTT as AzStyleTimingFunction;
+
+/// Single entry in a `transition-property` attribute
+pub type AzStyleTransitionPropertyTT = azul_impl::css::StyleTransitionProperty;
+pub use AzStyleTransitionPropertyTT as AzStyleTransitionProperty;
+
+/// Single entry in an `animation-name` attribute, refers to a `@keyframes` block
+pub type AzStyleAnimationNameTT = azul_impl::css::StyleAnimationName;
+pub use AzStyleAnimationNameTT as AzStyleAnimationName;
+/// Destructor: Takes ownership of the `StyleAnimationName` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationName_delete(object: &mut AzStyleAnimationName) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Single entry in an `animation-iteration-count` attribute
+pub type AzStyleAnimationIterationCountTT = azul_impl::css::StyleAnimationIterationCount;
+pub use AzStyleAnimationIterationCountTT as AzStyleAnimationIterationCount;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirection` struct
+pub type AzStyleAnimationDirectionTT = azul_impl::css::StyleAnimationDirection;
+pub use AzStyleAnimationDirectionTT as AzStyleAnimationDirection;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillMode` struct
+pub type AzStyleAnimationFillModeTT = azul_impl::css::StyleAnimationFillMode;
+pub use AzStyleAnimationFillModeTT as AzStyleAnimationFillMode;
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationPlayState` struct
+pub type AzStyleAnimationPlayStateTT = azul_impl::css::StyleAnimationPlayState;
+pub use AzStyleAnimationPlayStateTT as AzStyleAnimationPlayState;
+
 /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
 pub type AzStyleTextAlignTT = azul_impl::css::StyleTextAlign;
 pub use AzStyleTextAlignTT as AzStyleTextAlign;
 
+/// Base writing direction of a text (`direction: ltr | rtl`)
+pub type AzStyleDirectionTT = azul_impl::css::StyleDirection;
+pub use AzStyleDirectionTT as AzStyleDirection;
+
+/// Where lines may break inside of words (`word-break: normal | break-all | keep-all`)
+pub type AzStyleWordBreakTT = azul_impl::css::StyleWordBreak;
+pub use AzStyleWordBreakTT as AzStyleWordBreak;
+
+/// Whether words that are wider than the line may be broken at arbitrary points (`overflow-wrap: normal | anywhere | break-word`)
+pub type AzStyleOverflowWrapTT = azul_impl::css::StyleOverflowWrap;
+pub use AzStyleOverflowWrapTT as AzStyleOverflowWrap;
+
+/// Whether words are hyphenated when breaking lines (`hyphens: none | manual | auto`)
+pub type AzStyleHyphensTT = azul_impl::css::StyleHyphens;
+pub use AzStyleHyphensTT as AzStyleHyphens;
+
 /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
 pub type AzStyleTextColorTT = azul_impl::css::StyleTextColor;
 pub use AzStyleTextColorTT as AzStyleTextColor;
@@ -1580,6 +1824,80 @@ pub use AzStyleBoxShadowValueTT as AzStyleBoxShadowValue;
 pub type AzLayoutAlignContentValueTT = azul_impl::css::LayoutAlignContentValue;
 pub use AzLayoutAlignContentValueTT as AzLayoutAlignContentValue;
 
+/// Re-export of rust-allocated (stack based) `GridTrackSizingVecValue` struct
+pub type AzGridTrackSizingVecValueTT = azul_impl::css::GridTrackSizingVecValue;
+pub use AzGridTrackSizingVecValueTT as AzGridTrackSizingVecValue;
+/// Destructor: Takes ownership of the `GridTrackSizingVecValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzGridTrackSizingVecValue_delete(object: &mut AzGridTrackSizingVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `GridTrackSizingValue` struct
+pub type AzGridTrackSizingValueTT = azul_impl::css::GridTrackSizingValue;
+pub use AzGridTrackSizingValueTT as AzGridTrackSizingValue;
+
+/// Re-export of rust-allocated (stack based) `LayoutGridAutoFlowValue` struct
+pub type AzLayoutGridAutoFlowValueTT = azul_impl::css::LayoutGridAutoFlowValue;
+pub use AzLayoutGridAutoFlowValueTT as AzLayoutGridAutoFlowValue;
+
+/// Re-export of rust-allocated (stack based) `GridPlacementValue` struct
+pub type AzGridPlacementValueTT = azul_impl::css::GridPlacementValue;
+pub use AzGridPlacementValueTT as AzGridPlacementValue;
+
+/// Re-export of rust-allocated (stack based) `LayoutRowGapValue` struct
+pub type AzLayoutRowGapValueTT = azul_impl::css::LayoutRowGapValue;
+pub use AzLayoutRowGapValueTT as AzLayoutRowGapValue;
+
+/// Re-export of rust-allocated (stack based) `LayoutColumnGapValue` struct
+pub type AzLayoutColumnGapValueTT = azul_impl::css::LayoutColumnGapValue;
+pub use AzLayoutColumnGapValueTT as AzLayoutColumnGapValue;
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecValue` struct
+pub type AzStyleTransitionPropertyVecValueTT = azul_impl::css::StyleTransitionPropertyVecValue;
+pub use AzStyleTransitionPropertyVecValueTT as AzStyleTransitionPropertyVecValue;
+/// Destructor: Takes ownership of the `StyleTransitionPropertyVecValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransitionPropertyVecValue_delete(object: &mut AzStyleTransitionPropertyVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleTimeVecValue` struct
+pub type AzStyleTimeVecValueTT = azul_impl::css::StyleTimeVecValue;
+pub use AzStyleTimeVecValueTT as AzStyleTimeVecValue;
+/// Destructor: Takes ownership of the `StyleTimeVecValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTimeVecValue_delete(object: &mut AzStyleTimeVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleTimingFunctionVecValue` struct
+pub type AzStyleTimingFunctionVecValueTT = azul_impl::css::StyleTimingFunctionVecValue;
+pub use AzStyleTimingFunctionVecValueTT as AzStyleTimingFunctionVecValue;
+/// Destructor: Takes ownership of the `StyleTimingFunctionVecValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTimingFunctionVecValue_delete(object: &mut AzStyleTimingFunctionVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationNameVecValue` struct
+pub type AzStyleAnimationNameVecValueTT = azul_impl::css::StyleAnimationNameVecValue;
+pub use AzStyleAnimationNameVecValueTT as AzStyleAnimationNameVecValue;
+/// Destructor: Takes ownership of the `StyleAnimationNameVecValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationNameVecValue_delete(object: &mut AzStyleAnimationNameVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecValue` struct
+pub type AzStyleAnimationIterationCountVecValueTT = azul_impl::css::StyleAnimationIterationCountVecValue;
+pub use AzStyleAnimationIterationCountVecValueTT as AzStyleAnimationIterationCountVecValue;
+/// Destructor: Takes ownership of the `StyleAnimationIterationCountVecValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationIterationCountVecValue_delete(object: &mut AzStyleAnimationIterationCountVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecValue` struct
+pub type AzStyleAnimationDirectionVecValueTT = azul_impl::css::StyleAnimationDirectionVecValue;
+pub use AzStyleAnimationDirectionVecValueTT as AzStyleAnimationDirectionVecValue;
+/// Destructor: Takes ownership of the `StyleAnimationDirectionVecValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationDirectionVecValue_delete(object: &mut AzStyleAnimationDirectionVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecValue` struct
+pub type AzStyleAnimationFillModeVecValueTT = azul_impl::css::StyleAnimationFillModeVecValue;
+pub use AzStyleAnimationFillModeVecValueTT as AzStyleAnimationFillModeVecValue;
+/// Destructor: Takes ownership of the `StyleAnimationFillModeVecValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationFillModeVecValue_delete(object: &mut AzStyleAnimationFillModeVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationPlayStateVecValue` struct
+pub type AzStyleAnimationPlayStateVecValueTT = azul_impl::css::StyleAnimationPlayStateVecValue;
+pub use AzStyleAnimationPlayStateVecValueTT as AzStyleAnimationPlayStateVecValue;
+/// Destructor: Takes ownership of the `StyleAnimationPlayStateVecValue` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationPlayStateVecValue_delete(object: &mut AzStyleAnimationPlayStateVecValue) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
 pub type AzLayoutAlignItemsValueTT = azul_impl::css::LayoutAlignItemsValue;
 pub use AzLayoutAlignItemsValueTT as AzLayoutAlignItemsValue;
@@ -1820,6 +2138,22 @@ pub use AzStyleTabWidthValueTT as AzStyleTabWidthValue;
 pub type AzStyleTextAlignValueTT = azul_impl::css::StyleTextAlignValue;
 pub use AzStyleTextAlignValueTT as AzStyleTextAlignValue;
 
+/// Re-export of rust-allocated (stack based) `StyleDirectionValue` struct
+pub type AzStyleDirectionValueTT = azul_impl::css::StyleDirectionValue;
+pub use AzStyleDirectionValueTT as AzStyleDirectionValue;
+
+/// Re-export of rust-allocated (stack based) `StyleWordBreakValue` struct
+pub type AzStyleWordBreakValueTT = azul_impl::css::StyleWordBreakValue;
+pub use AzStyleWordBreakValueTT as AzStyleWordBreakValue;
+
+/// Re-export of rust-allocated (stack based) `StyleOverflowWrapValue` struct
+pub type AzStyleOverflowWrapValueTT = azul_impl::css::StyleOverflowWrapValue;
+pub use AzStyleOverflowWrapValueTT as AzStyleOverflowWrapValue;
+
+/// Re-export of rust-allocated (stack based) `StyleHyphensValue` struct
+pub type AzStyleHyphensValueTT = azul_impl::css::StyleHyphensValue;
+pub use AzStyleHyphensValueTT as AzStyleHyphensValue;
+
 /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
 pub type AzStyleTextColorValueTT = azul_impl::css::StyleTextColorValue;
 pub use AzStyleTextColorValueTT as AzStyleTextColorValue;
@@ -1877,8 +2211,113 @@ pub use AzCssPropertyTT as AzCssProperty;
 /// Re-export of rust-allocated (stack based) `Ribbon` struct
 pub type AzRibbonTT = crate::widgets::ribbon::Ribbon;
 pub use AzRibbonTT as AzRibbon;
+/// Creates a new `Ribbon` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Ribbon::new()` constructor.
+#[no_mangle] pub extern "C" fn AzRibbon_new(tabs: AzRibbonTabVec) -> AzRibbon { AzRibbon::new(tabs) }
+/// Equivalent to the Rust `Ribbon::set_tab_active()` function.
+#[no_mangle] pub extern "C" fn AzRibbon_setTabActive(ribbon: &mut AzRibbon, tab_active: i32) { ribbon.set_tab_active(tab_active) }
+/// Equivalent to the Rust `Ribbon::with_tab_active()` function.
+#[no_mangle] pub extern "C" fn AzRibbon_withTabActive(ribbon: &mut AzRibbon, tab_active: i32) -> AzRibbon { ribbon.with_tab_active(tab_active) }
+/// Equivalent to the Rust `Ribbon::set_available_width()` function.
+#[no_mangle] pub extern "C" fn AzRibbon_setAvailableWidth(ribbon: &mut AzRibbon, available_width: f32) { ribbon.set_available_width(available_width) }
+/// Equivalent to the Rust `Ribbon::with_available_width()` function.
+#[no_mangle] pub extern "C" fn AzRibbon_withAvailableWidth(ribbon: &mut AzRibbon, available_width: f32) -> AzRibbon { ribbon.with_available_width(available_width) }
+/// Equivalent to the Rust `Ribbon::set_on_tab_clicked()` function.
+#[no_mangle] pub extern "C" fn AzRibbon_setOnTabClicked(ribbon: &mut AzRibbon, data: AzRefAny, callback: AzRibbonOnTabClickedCallbackType) { ribbon.set_on_tab_clicked(data, callback) }
+/// Equivalent to the Rust `Ribbon::with_on_tab_clicked()` function.
+#[no_mangle] pub extern "C" fn AzRibbon_withOnTabClicked(ribbon: &mut AzRibbon, data: AzRefAny, callback: AzRibbonOnTabClickedCallbackType) -> AzRibbon { ribbon.with_on_tab_clicked(data, callback) }
 /// Equivalent to the Rust `Ribbon::dom()` function.
-#[no_mangle] pub extern "C" fn AzRibbon_dom(ribbon: &mut AzRibbon, callback: AzRibbonOnTabClickedCallback, data: AzRefAny) -> AzDom { ribbon.dom(callback, data) }
+#[no_mangle] pub extern "C" fn AzRibbon_dom(ribbon: &mut AzRibbon) -> AzDom { ribbon.dom() }
+/// Destructor: Takes ownership of the `Ribbon` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRibbon_delete(object: &mut AzRibbon) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `RibbonTab` struct
+pub type AzRibbonTabTT = crate::widgets::ribbon::RibbonTab;
+pub use AzRibbonTabTT as AzRibbonTab;
+/// Creates a new `RibbonTab` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `RibbonTab::new()` constructor.
+#[no_mangle] pub extern "C" fn AzRibbonTab_new(label: AzString, groups: AzRibbonGroupVec) -> AzRibbonTab { AzRibbonTab::new(label, groups) }
+/// Equivalent to the Rust `RibbonTab::set_key_tip()` function.
+#[no_mangle] pub extern "C" fn AzRibbonTab_setKeyTip(ribbontab: &mut AzRibbonTab, key_tip: AzString) { ribbontab.set_key_tip(key_tip) }
+/// Equivalent to the Rust `RibbonTab::with_key_tip()` function.
+#[no_mangle] pub extern "C" fn AzRibbonTab_withKeyTip(ribbontab: &mut AzRibbonTab, key_tip: AzString) -> AzRibbonTab { ribbontab.with_key_tip(key_tip) }
+/// Destructor: Takes ownership of the `RibbonTab` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRibbonTab_delete(object: &mut AzRibbonTab) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `RibbonGroup` struct
+pub type AzRibbonGroupTT = crate::widgets::ribbon::RibbonGroup;
+pub use AzRibbonGroupTT as AzRibbonGroup;
+/// Creates a new `RibbonGroup` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `RibbonGroup::new()` constructor.
+#[no_mangle] pub extern "C" fn AzRibbonGroup_new(label: AzString, items: AzRibbonItemVec) -> AzRibbonGroup { AzRibbonGroup::new(label, items) }
+/// Equivalent to the Rust `RibbonGroup::set_icon()` function.
+#[no_mangle] pub extern "C" fn AzRibbonGroup_setIcon(ribbongroup: &mut AzRibbonGroup, icon: AzImageRef) { ribbongroup.set_icon(icon) }
+/// Equivalent to the Rust `RibbonGroup::with_icon()` function.
+#[no_mangle] pub extern "C" fn AzRibbonGroup_withIcon(ribbongroup: &mut AzRibbonGroup, icon: AzImageRef) -> AzRibbonGroup { ribbongroup.with_icon(icon) }
+/// Destructor: Takes ownership of the `RibbonGroup` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRibbonGroup_delete(object: &mut AzRibbonGroup) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `RibbonItem` struct
+pub type AzRibbonItemTT = crate::widgets::ribbon::RibbonItem;
+pub use AzRibbonItemTT as AzRibbonItem;
+/// Creates a new `RibbonItem` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `RibbonItem::new()` constructor.
+#[no_mangle] pub extern "C" fn AzRibbonItem_new(kind: AzRibbonItemKind, label: AzString) -> AzRibbonItem { AzRibbonItem::new(kind, label) }
+/// Equivalent to the Rust `RibbonItem::set_icon()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_setIcon(ribbonitem: &mut AzRibbonItem, icon: AzImageRef) { ribbonitem.set_icon(icon) }
+/// Equivalent to the Rust `RibbonItem::with_icon()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_withIcon(ribbonitem: &mut AzRibbonItem, icon: AzImageRef) -> AzRibbonItem { ribbonitem.with_icon(icon) }
+/// Equivalent to the Rust `RibbonItem::set_tooltip()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_setTooltip(ribbonitem: &mut AzRibbonItem, tooltip: AzString) { ribbonitem.set_tooltip(tooltip) }
+/// Equivalent to the Rust `RibbonItem::with_tooltip()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_withTooltip(ribbonitem: &mut AzRibbonItem, tooltip: AzString) -> AzRibbonItem { ribbonitem.with_tooltip(tooltip) }
+/// Equivalent to the Rust `RibbonItem::set_key_tip()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_setKeyTip(ribbonitem: &mut AzRibbonItem, key_tip: AzString) { ribbonitem.set_key_tip(key_tip) }
+/// Equivalent to the Rust `RibbonItem::with_key_tip()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_withKeyTip(ribbonitem: &mut AzRibbonItem, key_tip: AzString) -> AzRibbonItem { ribbonitem.with_key_tip(key_tip) }
+/// Equivalent to the Rust `RibbonItem::set_enabled()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_setEnabled(ribbonitem: &mut AzRibbonItem, enabled: bool) { ribbonitem.set_enabled(enabled) }
+/// Equivalent to the Rust `RibbonItem::with_enabled()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_withEnabled(ribbonitem: &mut AzRibbonItem, enabled: bool) -> AzRibbonItem { ribbonitem.with_enabled(enabled) }
+/// Equivalent to the Rust `RibbonItem::set_toggled()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_setToggled(ribbonitem: &mut AzRibbonItem, toggled: bool) { ribbonitem.set_toggled(toggled) }
+/// Equivalent to the Rust `RibbonItem::with_toggled()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_withToggled(ribbonitem: &mut AzRibbonItem, toggled: bool) -> AzRibbonItem { ribbonitem.with_toggled(toggled) }
+/// Equivalent to the Rust `RibbonItem::set_choices()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_setChoices(ribbonitem: &mut AzRibbonItem, choices: AzStringVec) { ribbonitem.set_choices(choices) }
+/// Equivalent to the Rust `RibbonItem::with_choices()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_withChoices(ribbonitem: &mut AzRibbonItem, choices: AzStringVec) -> AzRibbonItem { ribbonitem.with_choices(choices) }
+/// Equivalent to the Rust `RibbonItem::set_on_click()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_setOnClick(ribbonitem: &mut AzRibbonItem, data: AzRefAny, callback: AzRibbonOnItemClickCallbackType) { ribbonitem.set_on_click(data, callback) }
+/// Equivalent to the Rust `RibbonItem::with_on_click()` function.
+#[no_mangle] pub extern "C" fn AzRibbonItem_withOnClick(ribbonitem: &mut AzRibbonItem, data: AzRefAny, callback: AzRibbonOnItemClickCallbackType) -> AzRibbonItem { ribbonitem.with_on_click(data, callback) }
+/// Destructor: Takes ownership of the `RibbonItem` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRibbonItem_delete(object: &mut AzRibbonItem) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `RibbonItemKind` struct
+pub type AzRibbonItemKindTT = crate::widgets::ribbon::RibbonItemKind;
+pub use AzRibbonItemKindTT as AzRibbonItemKind;
+
+/// Re-export of rust-allocated (stack based) `RibbonItemState` struct
+pub type AzRibbonItemStateTT = crate::widgets::ribbon::RibbonItemState;
+pub use AzRibbonItemStateTT as AzRibbonItemState;
+
+/// Re-export of rust-allocated (stack based) `RibbonOnItemClick` struct
+pub type AzRibbonOnItemClickTT = crate::widgets::ribbon::RibbonOnItemClick;
+pub use AzRibbonOnItemClickTT as AzRibbonOnItemClick;
+/// Destructor: Takes ownership of the `RibbonOnItemClick` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRibbonOnItemClick_delete(object: &mut AzRibbonOnItemClick) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `RibbonOnItemClickCallback` struct
+pub type AzRibbonOnItemClickCallbackTT = crate::widgets::ribbon::RibbonOnItemClickCallback;
+pub use AzRibbonOnItemClickCallbackTT as AzRibbonOnItemClickCallback;
+
+pub type AzRibbonOnItemClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzRibbonItemState) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `RibbonOnTabClicked` struct
+pub type AzRibbonOnTabClickedTT = crate::widgets::ribbon::RibbonOnTabClicked;
+pub use AzRibbonOnTabClickedTT as AzRibbonOnTabClicked;
+/// Destructor: Takes ownership of the `RibbonOnTabClicked` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRibbonOnTabClicked_delete(object: &mut AzRibbonOnTabClicked) {  unsafe { core::ptr::drop_in_place(object); } }
 
 /// Re-export of rust-allocated (stack based) `RibbonOnTabClickedCallback` struct
 pub type AzRibbonOnTabClickedCallbackTT = crate::widgets::ribbon::RibbonOnTabClickedCallback;
@@ -2134,6 +2573,74 @@ pub use AzOnTextInputReturnTT as AzOnTextInputReturn;
 pub type AzTextInputValidTT = crate::widgets::text_input::TextInputValid;
 pub use AzTextInputValidTT as AzTextInputValid;
 
+/// Multi-line text input with soft wrapping, scrolling and an undo history
+pub type AzTextAreaTT = crate::widgets::text_area::TextArea;
+pub use AzTextAreaTT as AzTextArea;
+/// Creates a new `TextArea` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TextArea::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTextArea_new() -> AzTextArea { AzTextArea::new() }
+/// Equivalent to the Rust `TextArea::set_text()` function.
+#[no_mangle] pub extern "C" fn AzTextArea_setText(textarea: &mut AzTextArea, text: AzString) { textarea.set_text(text) }
+/// Equivalent to the Rust `TextArea::with_text()` function.
+#[no_mangle] pub extern "C" fn AzTextArea_withText(textarea: &mut AzTextArea, text: AzString) -> AzTextArea { textarea.with_text(text) }
+/// Equivalent to the Rust `TextArea::set_state()` function.
+#[no_mangle] pub extern "C" fn AzTextArea_setState(textarea: &mut AzTextArea, state: AzTextAreaState) { textarea.set_state(state) }
+/// Equivalent to the Rust `TextArea::with_state()` function.
+#[no_mangle] pub extern "C" fn AzTextArea_withState(textarea: &mut AzTextArea, state: AzTextAreaState) -> AzTextArea { textarea.with_state(state) }
+/// Equivalent to the Rust `TextArea::set_on_text_change()` function.
+#[no_mangle] pub extern "C" fn AzTextArea_setOnTextChange(textarea: &mut AzTextArea, data: AzRefAny, callback: AzTextAreaOnTextChangeCallbackType) { textarea.set_on_text_change(data, callback) }
+/// Equivalent to the Rust `TextArea::with_on_text_change()` function.
+#[no_mangle] pub extern "C" fn AzTextArea_withOnTextChange(textarea: &mut AzTextArea, data: AzRefAny, callback: AzTextAreaOnTextChangeCallbackType) -> AzTextArea { textarea.with_on_text_change(data, callback) }
+/// Equivalent to the Rust `TextArea::swap_with_default()` function.
+#[no_mangle] pub extern "C" fn AzTextArea_swapWithDefault(textarea: &mut AzTextArea) -> AzTextArea { textarea.swap_with_default() }
+/// Equivalent to the Rust `TextArea::dom()` function.
+#[no_mangle] pub extern "C" fn AzTextArea_dom(textarea: &mut AzTextArea) -> AzDom { textarea.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `TextArea` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextArea_delete(object: &mut AzTextArea) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TextAreaState` struct
+pub type AzTextAreaStateTT = crate::widgets::text_area::TextAreaState;
+pub use AzTextAreaStateTT as AzTextAreaState;
+/// Equivalent to the Rust `TextAreaState::get_text()` function.
+#[no_mangle] pub extern "C" fn AzTextAreaState_getText(textareastate: &AzTextAreaState) -> AzString { textareastate.get_text().into() }
+/// Equivalent to the Rust `TextAreaState::set_text()` function.
+#[no_mangle] pub extern "C" fn AzTextAreaState_setText(textareastate: &mut AzTextAreaState, text: AzString) { textareastate.set_text(text) }
+/// Equivalent to the Rust `TextAreaState::get_selected_text()` function.
+#[no_mangle] pub extern "C" fn AzTextAreaState_getSelectedText(textareastate: &AzTextAreaState) -> AzString { textareastate.get_selected_text().into() }
+/// Equivalent to the Rust `TextAreaState::select_all()` function.
+#[no_mangle] pub extern "C" fn AzTextAreaState_selectAll(textareastate: &mut AzTextAreaState) { textareastate.select_all() }
+/// Equivalent to the Rust `TextAreaState::can_undo()` function.
+#[no_mangle] pub extern "C" fn AzTextAreaState_canUndo(textareastate: &AzTextAreaState) -> bool { textareastate.can_undo() }
+/// Equivalent to the Rust `TextAreaState::can_redo()` function.
+#[no_mangle] pub extern "C" fn AzTextAreaState_canRedo(textareastate: &AzTextAreaState) -> bool { textareastate.can_redo() }
+/// Equivalent to the Rust `TextAreaState::undo()` function.
+#[no_mangle] pub extern "C" fn AzTextAreaState_undo(textareastate: &mut AzTextAreaState) -> bool { textareastate.undo() }
+/// Equivalent to the Rust `TextAreaState::redo()` function.
+#[no_mangle] pub extern "C" fn AzTextAreaState_redo(textareastate: &mut AzTextAreaState) -> bool { textareastate.redo() }
+/// Destructor: Takes ownership of the `TextAreaState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextAreaState_delete(object: &mut AzTextAreaState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Single step in the undo / redo history of a `TextArea`: `removed` was replaced with `inserted` at `position`
+pub type AzTextAreaEditTT = crate::widgets::text_area::TextAreaEdit;
+pub use AzTextAreaEditTT as AzTextAreaEdit;
+/// Destructor: Takes ownership of the `TextAreaEdit` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextAreaEdit_delete(object: &mut AzTextAreaEdit) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// What kind of change a `TextAreaEdit` made, consecutive edits of the same kind can be merged into one undo step
+pub type AzTextAreaEditKindTT = crate::widgets::text_area::TextAreaEditKind;
+pub use AzTextAreaEditKindTT as AzTextAreaEditKind;
+
+pub type AzTextAreaOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextAreaState) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TextAreaOnTextChangeCallback` struct
+pub type AzTextAreaOnTextChangeCallbackTT = crate::widgets::text_area::TextAreaOnTextChangeCallback;
+pub use AzTextAreaOnTextChangeCallbackTT as AzTextAreaOnTextChangeCallback;
+
+/// Re-export of rust-allocated (stack based) `TextAreaOnTextChange` struct
+pub type AzTextAreaOnTextChangeTT = crate::widgets::text_area::TextAreaOnTextChange;
+pub use AzTextAreaOnTextChangeTT as AzTextAreaOnTextChange;
+/// Destructor: Takes ownership of the `TextAreaOnTextChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextAreaOnTextChange_delete(object: &mut AzTextAreaOnTextChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `NumberInput` struct
 pub type AzNumberInputTT = crate::widgets::number_input::NumberInput;
 pub use AzNumberInputTT as AzNumberInput;
@@ -2211,6 +2718,9 @@ pub use AzProgressBarTT as AzProgressBar;
 /// Creates a new `ProgressBar` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `ProgressBar::new()` constructor.
 #[no_mangle] pub extern "C" fn AzProgressBar_new(percent_done: f32) -> AzProgressBar { AzProgressBar::new(percent_done) }
+/// Creates a new `ProgressBar` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `ProgressBar::indeterminate()` constructor.
+#[no_mangle] pub extern "C" fn AzProgressBar_indeterminate() -> AzProgressBar { AzProgressBar::indeterminate() }
 /// Equivalent to the Rust `ProgressBar::set_height()` function.
 #[no_mangle] pub extern "C" fn AzProgressBar_setHeight(progressbar: &mut AzProgressBar, height: AzPixelValue) { progressbar.set_height(height); }
 /// Equivalent to the Rust `ProgressBar::with_height()` function.
@@ -2225,6 +2735,22 @@ pub use AzProgressBarTT as AzProgressBar;
 #[no_mangle] pub extern "C" fn AzProgressBar_withBarBackground(progressbar: &mut AzProgressBar, background: AzStyleBackgroundContentVec) -> AzProgressBar { let mut progressbar = progressbar.swap_with_default(); progressbar.set_bar_background(background); progressbar }
 /// Equivalent to the Rust `ProgressBar::dom()` function.
 #[no_mangle] pub extern "C" fn AzProgressBar_dom(progressbar: &mut AzProgressBar) -> AzDom { progressbar.swap_with_default().dom() }
+/// Equivalent to the Rust `ProgressBar::set_mode()` function.
+#[no_mangle] pub extern "C" fn AzProgressBar_setMode(progressbar: &mut AzProgressBar, mode: AzProgressBarMode) { progressbar.set_mode(mode) }
+/// Equivalent to the Rust `ProgressBar::with_mode()` function.
+#[no_mangle] pub extern "C" fn AzProgressBar_withMode(progressbar: &mut AzProgressBar, mode: AzProgressBarMode) -> AzProgressBar { progressbar.with_mode(mode) }
+/// Equivalent to the Rust `ProgressBar::set_style()` function.
+#[no_mangle] pub extern "C" fn AzProgressBar_setStyle(progressbar: &mut AzProgressBar, style: AzProgressBarStyle) { progressbar.set_style(style) }
+/// Equivalent to the Rust `ProgressBar::with_style()` function.
+#[no_mangle] pub extern "C" fn AzProgressBar_withStyle(progressbar: &mut AzProgressBar, style: AzProgressBarStyle) -> AzProgressBar { progressbar.with_style(style) }
+/// Equivalent to the Rust `ProgressBar::set_display_percentage()` function.
+#[no_mangle] pub extern "C" fn AzProgressBar_setDisplayPercentage(progressbar: &mut AzProgressBar, display_percentage: bool) { progressbar.set_display_percentage(display_percentage) }
+/// Equivalent to the Rust `ProgressBar::with_display_percentage()` function.
+#[no_mangle] pub extern "C" fn AzProgressBar_withDisplayPercentage(progressbar: &mut AzProgressBar, display_percentage: bool) -> AzProgressBar { progressbar.with_display_percentage(display_percentage) }
+/// Equivalent to the Rust `ProgressBar::set_text_format()` function.
+#[no_mangle] pub extern "C" fn AzProgressBar_setTextFormat(progressbar: &mut AzProgressBar, text_format: AzString) { progressbar.set_text_format(text_format) }
+/// Equivalent to the Rust `ProgressBar::with_text_format()` function.
+#[no_mangle] pub extern "C" fn AzProgressBar_withTextFormat(progressbar: &mut AzProgressBar, text_format: AzString) -> AzProgressBar { progressbar.with_text_format(text_format) }
 /// Destructor: Takes ownership of the `ProgressBar` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzProgressBar_delete(object: &mut AzProgressBar) {  unsafe { core::ptr::drop_in_place(object); } }
 
@@ -2232,6 +2758,39 @@ pub use AzProgressBarTT as AzProgressBar;
 pub type AzProgressBarStateTT = crate::widgets::progressbar::ProgressBarState;
 pub use AzProgressBarStateTT as AzProgressBarState;
 
+/// Re-export of rust-allocated (stack based) `ProgressBarMode` struct
+pub type AzProgressBarModeTT = crate::widgets::progressbar::ProgressBarMode;
+pub use AzProgressBarModeTT as AzProgressBarMode;
+
+/// Re-export of rust-allocated (stack based) `ProgressBarStyle` struct
+pub type AzProgressBarStyleTT = crate::widgets::progressbar::ProgressBarStyle;
+pub use AzProgressBarStyleTT as AzProgressBarStyle;
+
+/// Circular, endlessly rotating "busy" indicator for operations without a known total
+pub type AzSpinnerTT = crate::widgets::spinner::Spinner;
+pub use AzSpinnerTT as AzSpinner;
+/// Creates a new `Spinner` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Spinner::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSpinner_new() -> AzSpinner { AzSpinner::new() }
+/// Equivalent to the Rust `Spinner::set_size()` function.
+#[no_mangle] pub extern "C" fn AzSpinner_setSize(spinner: &mut AzSpinner, size: AzPixelValue) { spinner.set_size(size) }
+/// Equivalent to the Rust `Spinner::with_size()` function.
+#[no_mangle] pub extern "C" fn AzSpinner_withSize(spinner: &mut AzSpinner, size: AzPixelValue) -> AzSpinner { spinner.with_size(size) }
+/// Equivalent to the Rust `Spinner::set_thickness()` function.
+#[no_mangle] pub extern "C" fn AzSpinner_setThickness(spinner: &mut AzSpinner, thickness: AzPixelValue) { spinner.set_thickness(thickness) }
+/// Equivalent to the Rust `Spinner::with_thickness()` function.
+#[no_mangle] pub extern "C" fn AzSpinner_withThickness(spinner: &mut AzSpinner, thickness: AzPixelValue) -> AzSpinner { spinner.with_thickness(thickness) }
+/// Equivalent to the Rust `Spinner::set_color()` function.
+#[no_mangle] pub extern "C" fn AzSpinner_setColor(spinner: &mut AzSpinner, color: AzColorU) { spinner.set_color(color) }
+/// Equivalent to the Rust `Spinner::with_color()` function.
+#[no_mangle] pub extern "C" fn AzSpinner_withColor(spinner: &mut AzSpinner, color: AzColorU) -> AzSpinner { spinner.with_color(color) }
+/// Equivalent to the Rust `Spinner::set_track_color()` function.
+#[no_mangle] pub extern "C" fn AzSpinner_setTrackColor(spinner: &mut AzSpinner, track_color: AzColorU) { spinner.set_track_color(track_color) }
+/// Equivalent to the Rust `Spinner::with_track_color()` function.
+#[no_mangle] pub extern "C" fn AzSpinner_withTrackColor(spinner: &mut AzSpinner, track_color: AzColorU) -> AzSpinner { spinner.with_track_color(track_color) }
+/// Equivalent to the Rust `Spinner::dom()` function.
+#[no_mangle] pub extern "C" fn AzSpinner_dom(spinner: &mut AzSpinner) -> AzDom { spinner.swap_with_default().dom() }
+
 /// Re-export of rust-allocated (stack based) `TabHeader` struct
 pub type AzTabHeaderTT = crate::widgets::tabs::TabHeader;
 pub use AzTabHeaderTT as AzTabHeader;
@@ -2246,6 +2805,18 @@ pub use AzTabHeaderTT as AzTabHeader;
 #[no_mangle] pub extern "C" fn AzTabHeader_setOnClick(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnClickCallbackType) { tabheader.set_on_click(data, callback) }
 /// Equivalent to the Rust `TabHeader::with_on_click()` function.
 #[no_mangle] pub extern "C" fn AzTabHeader_withOnClick(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnClickCallbackType) -> AzTabHeader { tabheader.with_on_click(data, callback) }
+/// Equivalent to the Rust `TabHeader::set_on_close()` function.
+#[no_mangle] pub extern "C" fn AzTabHeader_setOnClose(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnCloseCallbackType) { tabheader.set_on_close(data, callback) }
+/// Equivalent to the Rust `TabHeader::with_on_close()` function.
+#[no_mangle] pub extern "C" fn AzTabHeader_withOnClose(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnCloseCallbackType) -> AzTabHeader { tabheader.with_on_close(data, callback) }
+/// Equivalent to the Rust `TabHeader::set_on_reorder()` function.
+#[no_mangle] pub extern "C" fn AzTabHeader_setOnReorder(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnReorderCallbackType) { tabheader.set_on_reorder(data, callback) }
+/// Equivalent to the Rust `TabHeader::with_on_reorder()` function.
+#[no_mangle] pub extern "C" fn AzTabHeader_withOnReorder(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnReorderCallbackType) -> AzTabHeader { tabheader.with_on_reorder(data, callback) }
+/// Equivalent to the Rust `TabHeader::set_on_context_menu()` function.
+#[no_mangle] pub extern "C" fn AzTabHeader_setOnContextMenu(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnContextMenuCallbackType) { tabheader.set_on_context_menu(data, callback) }
+/// Equivalent to the Rust `TabHeader::with_on_context_menu()` function.
+#[no_mangle] pub extern "C" fn AzTabHeader_withOnContextMenu(tabheader: &mut AzTabHeader, data: AzRefAny, callback: AzTabOnContextMenuCallbackType) -> AzTabHeader { tabheader.with_on_context_menu(data, callback) }
 /// Equivalent to the Rust `TabHeader::dom()` function.
 #[no_mangle] pub extern "C" fn AzTabHeader_dom(tabheader: &mut AzTabHeader) -> AzDom { tabheader.dom() }
 /// Destructor: Takes ownership of the `TabHeader` pointer and deletes it.
@@ -2281,6 +2852,39 @@ pub type AzTabOnClickCallbackTT = crate::widgets::tabs::TabOnClickCallback;
 pub use AzTabOnClickCallbackTT as AzTabOnClickCallback;
 
 pub type AzTabOnClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTabHeaderState) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TabOnClose` struct
+pub type AzTabOnCloseTT = crate::widgets::tabs::TabOnClose;
+pub use AzTabOnCloseTT as AzTabOnClose;
+/// Destructor: Takes ownership of the `TabOnClose` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabOnClose_delete(object: &mut AzTabOnClose) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TabOnCloseCallback` struct
+pub type AzTabOnCloseCallbackTT = crate::widgets::tabs::TabOnCloseCallback;
+pub use AzTabOnCloseCallbackTT as AzTabOnCloseCallback;
+
+pub type AzTabOnCloseCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TabOnReorder` struct
+pub type AzTabOnReorderTT = crate::widgets::tabs::TabOnReorder;
+pub use AzTabOnReorderTT as AzTabOnReorder;
+/// Destructor: Takes ownership of the `TabOnReorder` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabOnReorder_delete(object: &mut AzTabOnReorder) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TabOnReorderCallback` struct
+pub type AzTabOnReorderCallbackTT = crate::widgets::tabs::TabOnReorderCallback;
+pub use AzTabOnReorderCallbackTT as AzTabOnReorderCallback;
+
+pub type AzTabOnReorderCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TabOnContextMenu` struct
+pub type AzTabOnContextMenuTT = crate::widgets::tabs::TabOnContextMenu;
+pub use AzTabOnContextMenuTT as AzTabOnContextMenu;
+/// Destructor: Takes ownership of the `TabOnContextMenu` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTabOnContextMenu_delete(object: &mut AzTabOnContextMenu) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TabOnContextMenuCallback` struct
+pub type AzTabOnContextMenuCallbackTT = crate::widgets::tabs::TabOnContextMenuCallback;
+pub use AzTabOnContextMenuCallbackTT as AzTabOnContextMenuCallback;
+
+pub type AzTabOnContextMenuCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `Frame` struct
 pub type AzFrameTT = crate::widgets::frame::Frame;
 pub use AzFrameTT as AzFrame;
@@ -2296,6 +2900,155 @@ pub use AzFrameTT as AzFrame;
 /// Destructor: Takes ownership of the `Frame` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzFrame_delete(object: &mut AzFrame) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Container with resizable panes, separated by draggable handles
+pub type AzSplitterTT = crate::widgets::splitter::Splitter;
+pub use AzSplitterTT as AzSplitter;
+/// Creates a new `Splitter` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Splitter::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSplitter_new(orientation: AzSplitterOrientation, panes: AzSplitterPaneVec) -> AzSplitter { AzSplitter::new(orientation, panes) }
+/// Equivalent to the Rust `Splitter::set_state()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_setState(splitter: &mut AzSplitter, state: AzSplitterState) { splitter.set_state(state) }
+/// Equivalent to the Rust `Splitter::with_state()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_withState(splitter: &mut AzSplitter, state: AzSplitterState) -> AzSplitter { splitter.with_state(state) }
+/// Equivalent to the Rust `Splitter::set_on_resize()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_setOnResize(splitter: &mut AzSplitter, data: AzRefAny, callback: AzSplitterOnResizeCallbackType) { splitter.set_on_resize(data, callback) }
+/// Equivalent to the Rust `Splitter::with_on_resize()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_withOnResize(splitter: &mut AzSplitter, data: AzRefAny, callback: AzSplitterOnResizeCallbackType) -> AzSplitter { splitter.with_on_resize(data, callback) }
+/// Equivalent to the Rust `Splitter::dom()` function.
+#[no_mangle] pub extern "C" fn AzSplitter_dom(splitter: &mut AzSplitter) -> AzDom { splitter.dom() }
+/// Destructor: Takes ownership of the `Splitter` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitter_delete(object: &mut AzSplitter) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SplitterOrientation` struct
+pub type AzSplitterOrientationTT = crate::widgets::splitter::SplitterOrientation;
+pub use AzSplitterOrientationTT as AzSplitterOrientation;
+
+/// Re-export of rust-allocated (stack based) `SplitterPane` struct
+pub type AzSplitterPaneTT = crate::widgets::splitter::SplitterPane;
+pub use AzSplitterPaneTT as AzSplitterPane;
+/// Creates a new `SplitterPane` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `SplitterPane::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSplitterPane_new(content: AzDom) -> AzSplitterPane { AzSplitterPane::new(content) }
+/// Equivalent to the Rust `SplitterPane::set_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_setSize(splitterpane: &mut AzSplitterPane, size: f32) { splitterpane.set_size(size) }
+/// Equivalent to the Rust `SplitterPane::with_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_withSize(splitterpane: &mut AzSplitterPane, size: f32) -> AzSplitterPane { splitterpane.with_size(size) }
+/// Equivalent to the Rust `SplitterPane::set_min_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_setMinSize(splitterpane: &mut AzSplitterPane, min_size: f32) { splitterpane.set_min_size(min_size) }
+/// Equivalent to the Rust `SplitterPane::with_min_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_withMinSize(splitterpane: &mut AzSplitterPane, min_size: f32) -> AzSplitterPane { splitterpane.with_min_size(min_size) }
+/// Equivalent to the Rust `SplitterPane::set_max_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_setMaxSize(splitterpane: &mut AzSplitterPane, max_size: f32) { splitterpane.set_max_size(max_size) }
+/// Equivalent to the Rust `SplitterPane::with_max_size()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_withMaxSize(splitterpane: &mut AzSplitterPane, max_size: f32) -> AzSplitterPane { splitterpane.with_max_size(max_size) }
+/// Equivalent to the Rust `SplitterPane::set_collapsible()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_setCollapsible(splitterpane: &mut AzSplitterPane, collapsible: bool) { splitterpane.set_collapsible(collapsible) }
+/// Equivalent to the Rust `SplitterPane::with_collapsible()` function.
+#[no_mangle] pub extern "C" fn AzSplitterPane_withCollapsible(splitterpane: &mut AzSplitterPane, collapsible: bool) -> AzSplitterPane { splitterpane.with_collapsible(collapsible) }
+/// Destructor: Takes ownership of the `SplitterPane` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterPane_delete(object: &mut AzSplitterPane) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SplitterState` struct
+pub type AzSplitterStateTT = crate::widgets::splitter::SplitterState;
+pub use AzSplitterStateTT as AzSplitterState;
+/// Destructor: Takes ownership of the `SplitterState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterState_delete(object: &mut AzSplitterState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SplitterPaneState` struct
+pub type AzSplitterPaneStateTT = crate::widgets::splitter::SplitterPaneState;
+pub use AzSplitterPaneStateTT as AzSplitterPaneState;
+
+/// Re-export of rust-allocated (stack based) `SplitterOnResize` struct
+pub type AzSplitterOnResizeTT = crate::widgets::splitter::SplitterOnResize;
+pub use AzSplitterOnResizeTT as AzSplitterOnResize;
+/// Destructor: Takes ownership of the `SplitterOnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterOnResize_delete(object: &mut AzSplitterOnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SplitterOnResizeCallback` struct
+pub type AzSplitterOnResizeCallbackTT = crate::widgets::splitter::SplitterOnResizeCallback;
+pub use AzSplitterOnResizeCallbackTT as AzSplitterOnResizeCallback;
+
+pub type AzSplitterOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSplitterState) -> AzUpdate;
+/// Thumb that can be dragged along a track to select a value between `min` and `max`
+pub type AzSliderTT = crate::widgets::slider::Slider;
+pub use AzSliderTT as AzSlider;
+/// Creates a new `Slider` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `Slider::new()` constructor.
+#[no_mangle] pub extern "C" fn AzSlider_new(orientation: AzSliderOrientation, value: f32, min: f32, max: f32) -> AzSlider { AzSlider::new(orientation, value, min, max) }
+/// Equivalent to the Rust `Slider::set_step()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setStep(slider: &mut AzSlider, step: f32) { slider.set_step(step) }
+/// Equivalent to the Rust `Slider::with_step()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withStep(slider: &mut AzSlider, step: f32) -> AzSlider { slider.with_step(step) }
+/// Equivalent to the Rust `Slider::set_tick_interval()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setTickInterval(slider: &mut AzSlider, tick_interval: f32) { slider.set_tick_interval(tick_interval) }
+/// Equivalent to the Rust `Slider::with_tick_interval()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withTickInterval(slider: &mut AzSlider, tick_interval: f32) -> AzSlider { slider.with_tick_interval(tick_interval) }
+/// Equivalent to the Rust `Slider::set_on_value_change()` function.
+#[no_mangle] pub extern "C" fn AzSlider_setOnValueChange(slider: &mut AzSlider, data: AzRefAny, callback: AzSliderOnValueChangeCallbackType) { slider.set_on_value_change(data, callback) }
+/// Equivalent to the Rust `Slider::with_on_value_change()` function.
+#[no_mangle] pub extern "C" fn AzSlider_withOnValueChange(slider: &mut AzSlider, data: AzRefAny, callback: AzSliderOnValueChangeCallbackType) -> AzSlider { slider.with_on_value_change(data, callback) }
+/// Equivalent to the Rust `Slider::dom()` function.
+#[no_mangle] pub extern "C" fn AzSlider_dom(slider: &mut AzSlider) -> AzDom { slider.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `Slider` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSlider_delete(object: &mut AzSlider) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Slider with two thumbs to select a range (`low..=high`) between `min` and `max`
+pub type AzRangeSliderTT = crate::widgets::slider::RangeSlider;
+pub use AzRangeSliderTT as AzRangeSlider;
+/// Creates a new `RangeSlider` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `RangeSlider::new()` constructor.
+#[no_mangle] pub extern "C" fn AzRangeSlider_new(orientation: AzSliderOrientation, low: f32, high: f32, min: f32, max: f32) -> AzRangeSlider { AzRangeSlider::new(orientation, low, high, min, max) }
+/// Equivalent to the Rust `RangeSlider::set_step()` function.
+#[no_mangle] pub extern "C" fn AzRangeSlider_setStep(rangeslider: &mut AzRangeSlider, step: f32) { rangeslider.set_step(step) }
+/// Equivalent to the Rust `RangeSlider::with_step()` function.
+#[no_mangle] pub extern "C" fn AzRangeSlider_withStep(rangeslider: &mut AzRangeSlider, step: f32) -> AzRangeSlider { rangeslider.with_step(step) }
+/// Equivalent to the Rust `RangeSlider::set_tick_interval()` function.
+#[no_mangle] pub extern "C" fn AzRangeSlider_setTickInterval(rangeslider: &mut AzRangeSlider, tick_interval: f32) { rangeslider.set_tick_interval(tick_interval) }
+/// Equivalent to the Rust `RangeSlider::with_tick_interval()` function.
+#[no_mangle] pub extern "C" fn AzRangeSlider_withTickInterval(rangeslider: &mut AzRangeSlider, tick_interval: f32) -> AzRangeSlider { rangeslider.with_tick_interval(tick_interval) }
+/// Equivalent to the Rust `RangeSlider::set_on_value_change()` function.
+#[no_mangle] pub extern "C" fn AzRangeSlider_setOnValueChange(rangeslider: &mut AzRangeSlider, data: AzRefAny, callback: AzRangeSliderOnValueChangeCallbackType) { rangeslider.set_on_value_change(data, callback) }
+/// Equivalent to the Rust `RangeSlider::with_on_value_change()` function.
+#[no_mangle] pub extern "C" fn AzRangeSlider_withOnValueChange(rangeslider: &mut AzRangeSlider, data: AzRefAny, callback: AzRangeSliderOnValueChangeCallbackType) -> AzRangeSlider { rangeslider.with_on_value_change(data, callback) }
+/// Equivalent to the Rust `RangeSlider::dom()` function.
+#[no_mangle] pub extern "C" fn AzRangeSlider_dom(rangeslider: &mut AzRangeSlider) -> AzDom { rangeslider.swap_with_default().dom() }
+/// Destructor: Takes ownership of the `RangeSlider` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRangeSlider_delete(object: &mut AzRangeSlider) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+pub type AzSliderOrientationTT = crate::widgets::slider::SliderOrientation;
+pub use AzSliderOrientationTT as AzSliderOrientation;
+
+/// Re-export of rust-allocated (stack based) `SliderState` struct
+pub type AzSliderStateTT = crate::widgets::slider::SliderState;
+pub use AzSliderStateTT as AzSliderState;
+
+/// Re-export of rust-allocated (stack based) `RangeSliderState` struct
+pub type AzRangeSliderStateTT = crate::widgets::slider::RangeSliderState;
+pub use AzRangeSliderStateTT as AzRangeSliderState;
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+pub type AzSliderOnValueChangeTT = crate::widgets::slider::SliderOnValueChange;
+pub use AzSliderOnValueChangeTT as AzSliderOnValueChange;
+/// Destructor: Takes ownership of the `SliderOnValueChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSliderOnValueChange_delete(object: &mut AzSliderOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+pub type AzSliderOnValueChangeCallbackTT = crate::widgets::slider::SliderOnValueChangeCallback;
+pub use AzSliderOnValueChangeCallbackTT as AzSliderOnValueChangeCallback;
+
+pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSliderState) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `RangeSliderOnValueChange` struct
+pub type AzRangeSliderOnValueChangeTT = crate::widgets::slider::RangeSliderOnValueChange;
+pub use AzRangeSliderOnValueChangeTT as AzRangeSliderOnValueChange;
+/// Destructor: Takes ownership of the `RangeSliderOnValueChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRangeSliderOnValueChange_delete(object: &mut AzRangeSliderOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `RangeSliderOnValueChangeCallback` struct
+pub type AzRangeSliderOnValueChangeCallbackTT = crate::widgets::slider::RangeSliderOnValueChangeCallback;
+pub use AzRangeSliderOnValueChangeCallbackTT as AzRangeSliderOnValueChangeCallback;
+
+pub type AzRangeSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzRangeSliderState) -> AzUpdate;
 /// Re-export of rust-allocated (stack based) `NodeGraph` struct
 pub type AzNodeGraphTT = crate::widgets::node_graph::NodeGraph;
 pub use AzNodeGraphTT as AzNodeGraph;
@@ -2502,6 +3255,42 @@ pub use AzListViewTT as AzListView;
 #[no_mangle] pub extern "C" fn AzListView_new(columns: AzStringVec) -> AzListView { AzListView::new(columns) }
 /// Equivalent to the Rust `ListView::with_rows()` function.
 #[no_mangle] pub extern "C" fn AzListView_withRows(listview: &mut AzListView, rows: AzListViewRowVec) -> AzListView { listview.with_rows(rows) }
+/// Equivalent to the Rust `ListView::set_sorted_by()` function.
+#[no_mangle] pub extern "C" fn AzListView_setSortedBy(listview: &mut AzListView, sorted_by: AzOptionUsize) { listview.set_sorted_by(sorted_by) }
+/// Equivalent to the Rust `ListView::with_sorted_by()` function.
+#[no_mangle] pub extern "C" fn AzListView_withSortedBy(listview: &mut AzListView, sorted_by: AzOptionUsize) -> AzListView { listview.with_sorted_by(sorted_by) }
+/// Equivalent to the Rust `ListView::set_scroll_offset()` function.
+#[no_mangle] pub extern "C" fn AzListView_setScrollOffset(listview: &mut AzListView, scroll_offset: AzPixelValueNoPercent) { listview.set_scroll_offset(scroll_offset) }
+/// Equivalent to the Rust `ListView::with_scroll_offset()` function.
+#[no_mangle] pub extern "C" fn AzListView_withScrollOffset(listview: &mut AzListView, scroll_offset: AzPixelValueNoPercent) -> AzListView { listview.with_scroll_offset(scroll_offset) }
+/// Equivalent to the Rust `ListView::set_row_height()` function.
+#[no_mangle] pub extern "C" fn AzListView_setRowHeight(listview: &mut AzListView, row_height: AzPixelValueNoPercent) { listview.set_row_height(row_height) }
+/// Equivalent to the Rust `ListView::with_row_height()` function.
+#[no_mangle] pub extern "C" fn AzListView_withRowHeight(listview: &mut AzListView, row_height: AzPixelValueNoPercent) -> AzListView { listview.with_row_height(row_height) }
+/// Equivalent to the Rust `ListView::set_column_widths()` function.
+#[no_mangle] pub extern "C" fn AzListView_setColumnWidths(listview: &mut AzListView, column_widths: AzF32Vec) { listview.set_column_widths(column_widths) }
+/// Equivalent to the Rust `ListView::with_column_widths()` function.
+#[no_mangle] pub extern "C" fn AzListView_withColumnWidths(listview: &mut AzListView, column_widths: AzF32Vec) -> AzListView { listview.with_column_widths(column_widths) }
+/// Equivalent to the Rust `ListView::set_row_provider()` function.
+#[no_mangle] pub extern "C" fn AzListView_setRowProvider(listview: &mut AzListView, row_count: usize, data: AzRefAny, callback: AzListViewRowProviderCallbackType) { listview.set_row_provider(row_count, data, callback) }
+/// Equivalent to the Rust `ListView::with_row_provider()` function.
+#[no_mangle] pub extern "C" fn AzListView_withRowProvider(listview: &mut AzListView, row_count: usize, data: AzRefAny, callback: AzListViewRowProviderCallbackType) -> AzListView { listview.with_row_provider(row_count, data, callback) }
+/// Equivalent to the Rust `ListView::set_on_lazy_load_scroll()` function.
+#[no_mangle] pub extern "C" fn AzListView_setOnLazyLoadScroll(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnLazyLoadScrollCallbackType) { listview.set_on_lazy_load_scroll(data, callback) }
+/// Equivalent to the Rust `ListView::with_on_lazy_load_scroll()` function.
+#[no_mangle] pub extern "C" fn AzListView_withOnLazyLoadScroll(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnLazyLoadScrollCallbackType) -> AzListView { listview.with_on_lazy_load_scroll(data, callback) }
+/// Equivalent to the Rust `ListView::set_on_column_click()` function.
+#[no_mangle] pub extern "C" fn AzListView_setOnColumnClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnClickCallbackType) { listview.set_on_column_click(data, callback) }
+/// Equivalent to the Rust `ListView::with_on_column_click()` function.
+#[no_mangle] pub extern "C" fn AzListView_withOnColumnClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnClickCallbackType) -> AzListView { listview.with_on_column_click(data, callback) }
+/// Equivalent to the Rust `ListView::set_on_column_resize()` function.
+#[no_mangle] pub extern "C" fn AzListView_setOnColumnResize(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnResizeCallbackType) { listview.set_on_column_resize(data, callback) }
+/// Equivalent to the Rust `ListView::with_on_column_resize()` function.
+#[no_mangle] pub extern "C" fn AzListView_withOnColumnResize(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnColumnResizeCallbackType) -> AzListView { listview.with_on_column_resize(data, callback) }
+/// Equivalent to the Rust `ListView::set_on_row_click()` function.
+#[no_mangle] pub extern "C" fn AzListView_setOnRowClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowClickCallbackType) { listview.set_on_row_click(data, callback) }
+/// Equivalent to the Rust `ListView::with_on_row_click()` function.
+#[no_mangle] pub extern "C" fn AzListView_withOnRowClick(listview: &mut AzListView, data: AzRefAny, callback: AzListViewOnRowClickCallbackType) -> AzListView { listview.with_on_row_click(data, callback) }
 /// Equivalent to the Rust `ListView::dom()` function.
 #[no_mangle] pub extern "C" fn AzListView_dom(listview: &mut AzListView) -> AzDom { listview.swap_with_default().dom() }
 /// Destructor: Takes ownership of the `ListView` pointer and deletes it.
@@ -2552,23 +3341,158 @@ pub use AzListViewOnRowClickTT as AzListViewOnRowClick;
 /// Destructor: Takes ownership of the `ListViewOnRowClick` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzListViewOnRowClick_delete(object: &mut AzListViewOnRowClick) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Range of row indices, `start` is inclusive, `end` is exclusive
+pub type AzListViewRowRangeTT = crate::widgets::list_view::ListViewRowRange;
+pub use AzListViewRowRangeTT as AzListViewRowRange;
+
+pub type AzListViewOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, f32) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `ListViewOnColumnResizeCallback` struct
+pub type AzListViewOnColumnResizeCallbackTT = crate::widgets::list_view::ListViewOnColumnResizeCallback;
+pub use AzListViewOnColumnResizeCallbackTT as AzListViewOnColumnResizeCallback;
+
+/// Re-export of rust-allocated (stack based) `ListViewOnColumnResize` struct
+pub type AzListViewOnColumnResizeTT = crate::widgets::list_view::ListViewOnColumnResize;
+pub use AzListViewOnColumnResizeTT as AzListViewOnColumnResize;
+/// Destructor: Takes ownership of the `ListViewOnColumnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzListViewOnColumnResize_delete(object: &mut AzListViewOnColumnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzListViewRowProviderCallbackType = extern "C" fn(&mut AzRefAny, &AzListViewState, AzListViewRowRange) -> AzListViewRowVec;
+/// Re-export of rust-allocated (stack based) `ListViewRowProviderCallback` struct
+pub type AzListViewRowProviderCallbackTT = crate::widgets::list_view::ListViewRowProviderCallback;
+pub use AzListViewRowProviderCallbackTT as AzListViewRowProviderCallback;
+
+/// Re-export of rust-allocated (stack based) `ListViewRowProvider` struct
+pub type AzListViewRowProviderTT = crate::widgets::list_view::ListViewRowProvider;
+pub use AzListViewRowProviderTT as AzListViewRowProvider;
+/// Destructor: Takes ownership of the `ListViewRowProvider` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzListViewRowProvider_delete(object: &mut AzListViewRowProvider) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `TreeView` struct
 pub type AzTreeViewTT = crate::widgets::tree_view::TreeView;
 pub use AzTreeViewTT as AzTreeView;
 /// Creates a new `TreeView` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `TreeView::new()` constructor.
-#[no_mangle] pub extern "C" fn AzTreeView_new(root: AzString) -> AzTreeView { AzTreeView::new(root) }
+#[no_mangle] pub extern "C" fn AzTreeView_new(nodes: AzTreeViewNodeVec) -> AzTreeView { AzTreeView::new(nodes) }
+/// Equivalent to the Rust `TreeView::set_nodes()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setNodes(treeview: &mut AzTreeView, nodes: AzTreeViewNodeVec) { treeview.set_nodes(nodes) }
+/// Equivalent to the Rust `TreeView::with_nodes()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withNodes(treeview: &mut AzTreeView, nodes: AzTreeViewNodeVec) -> AzTreeView { treeview.with_nodes(nodes) }
+/// Equivalent to the Rust `TreeView::set_state()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setState(treeview: &mut AzTreeView, state: AzTreeViewState) { treeview.set_state(state) }
+/// Equivalent to the Rust `TreeView::with_state()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withState(treeview: &mut AzTreeView, state: AzTreeViewState) -> AzTreeView { treeview.with_state(state) }
+/// Equivalent to the Rust `TreeView::set_selection_mode()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setSelectionMode(treeview: &mut AzTreeView, selection_mode: AzTreeViewSelectionMode) { treeview.set_selection_mode(selection_mode) }
+/// Equivalent to the Rust `TreeView::with_selection_mode()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withSelectionMode(treeview: &mut AzTreeView, selection_mode: AzTreeViewSelectionMode) -> AzTreeView { treeview.with_selection_mode(selection_mode) }
+/// Equivalent to the Rust `TreeView::set_on_expand()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnExpand(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnExpandCallbackType) { treeview.set_on_expand(data, callback) }
+/// Equivalent to the Rust `TreeView::with_on_expand()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnExpand(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnExpandCallbackType) -> AzTreeView { treeview.with_on_expand(data, callback) }
+/// Equivalent to the Rust `TreeView::set_on_select()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnSelect(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnSelectCallbackType) { treeview.set_on_select(data, callback) }
+/// Equivalent to the Rust `TreeView::with_on_select()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnSelect(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnSelectCallbackType) -> AzTreeView { treeview.with_on_select(data, callback) }
+/// Equivalent to the Rust `TreeView::set_on_double_click()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnDoubleClick(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnDoubleClickCallbackType) { treeview.set_on_double_click(data, callback) }
+/// Equivalent to the Rust `TreeView::with_on_double_click()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnDoubleClick(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnDoubleClickCallbackType) -> AzTreeView { treeview.with_on_double_click(data, callback) }
+/// Equivalent to the Rust `TreeView::set_on_context_menu()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_setOnContextMenu(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnContextMenuCallbackType) { treeview.set_on_context_menu(data, callback) }
+/// Equivalent to the Rust `TreeView::with_on_context_menu()` function.
+#[no_mangle] pub extern "C" fn AzTreeView_withOnContextMenu(treeview: &mut AzTreeView, data: AzRefAny, callback: AzTreeViewOnContextMenuCallbackType) -> AzTreeView { treeview.with_on_context_menu(data, callback) }
 /// Equivalent to the Rust `TreeView::dom()` function.
 #[no_mangle] pub extern "C" fn AzTreeView_dom(treeview: &mut AzTreeView) -> AzDom { treeview.swap_with_default().dom() }
 /// Destructor: Takes ownership of the `TreeView` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzTreeView_delete(object: &mut AzTreeView) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `TreeViewNodeId` struct
+pub type AzTreeViewNodeIdTT = crate::widgets::tree_view::TreeViewNodeId;
+pub use AzTreeViewNodeIdTT as AzTreeViewNodeId;
+
+/// Re-export of rust-allocated (stack based) `TreeViewNode` struct
+pub type AzTreeViewNodeTT = crate::widgets::tree_view::TreeViewNode;
+pub use AzTreeViewNodeTT as AzTreeViewNode;
+/// Creates a new `TreeViewNode` instance whose memory is owned by the rust allocator
+/// Equivalent to the Rust `TreeViewNode::new()` constructor.
+#[no_mangle] pub extern "C" fn AzTreeViewNode_new(id: AzTreeViewNodeId, parent: AzOptionTreeViewNodeId, label: AzString) -> AzTreeViewNode { AzTreeViewNode::new(id, parent, label) }
+/// Destructor: Takes ownership of the `TreeViewNode` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewNode_delete(object: &mut AzTreeViewNode) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+pub type AzTreeViewSelectionModeTT = crate::widgets::tree_view::TreeViewSelectionMode;
+pub use AzTreeViewSelectionModeTT as AzTreeViewSelectionMode;
+
+/// Re-export of rust-allocated (stack based) `TreeViewState` struct
+pub type AzTreeViewStateTT = crate::widgets::tree_view::TreeViewState;
+pub use AzTreeViewStateTT as AzTreeViewState;
+/// Destructor: Takes ownership of the `TreeViewState` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewState_delete(object: &mut AzTreeViewState) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzTreeViewOnExpandCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId, bool) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnExpandCallback` struct
+pub type AzTreeViewOnExpandCallbackTT = crate::widgets::tree_view::TreeViewOnExpandCallback;
+pub use AzTreeViewOnExpandCallbackTT as AzTreeViewOnExpandCallback;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnExpand` struct
+pub type AzTreeViewOnExpandTT = crate::widgets::tree_view::TreeViewOnExpand;
+pub use AzTreeViewOnExpandTT as AzTreeViewOnExpand;
+/// Destructor: Takes ownership of the `TreeViewOnExpand` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnExpand_delete(object: &mut AzTreeViewOnExpand) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzTreeViewOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelectCallback` struct
+pub type AzTreeViewOnSelectCallbackTT = crate::widgets::tree_view::TreeViewOnSelectCallback;
+pub use AzTreeViewOnSelectCallbackTT as AzTreeViewOnSelectCallback;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelect` struct
+pub type AzTreeViewOnSelectTT = crate::widgets::tree_view::TreeViewOnSelect;
+pub use AzTreeViewOnSelectTT as AzTreeViewOnSelect;
+/// Destructor: Takes ownership of the `TreeViewOnSelect` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnSelect_delete(object: &mut AzTreeViewOnSelect) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzTreeViewOnDoubleClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnDoubleClickCallback` struct
+pub type AzTreeViewOnDoubleClickCallbackTT = crate::widgets::tree_view::TreeViewOnDoubleClickCallback;
+pub use AzTreeViewOnDoubleClickCallbackTT as AzTreeViewOnDoubleClickCallback;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnDoubleClick` struct
+pub type AzTreeViewOnDoubleClickTT = crate::widgets::tree_view::TreeViewOnDoubleClick;
+pub use AzTreeViewOnDoubleClickTT as AzTreeViewOnDoubleClick;
+/// Destructor: Takes ownership of the `TreeViewOnDoubleClick` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnDoubleClick_delete(object: &mut AzTreeViewOnDoubleClick) {  unsafe { core::ptr::drop_in_place(object); } }
+
+pub type AzTreeViewOnContextMenuCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId) -> AzUpdate;
+/// Re-export of rust-allocated (stack based) `TreeViewOnContextMenuCallback` struct
+pub type AzTreeViewOnContextMenuCallbackTT = crate::widgets::tree_view::TreeViewOnContextMenuCallback;
+pub use AzTreeViewOnContextMenuCallbackTT as AzTreeViewOnContextMenuCallback;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnContextMenu` struct
+pub type AzTreeViewOnContextMenuTT = crate::widgets::tree_view::TreeViewOnContextMenu;
+pub use AzTreeViewOnContextMenuTT as AzTreeViewOnContextMenu;
+/// Destructor: Takes ownership of the `TreeViewOnContextMenu` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewOnContextMenu_delete(object: &mut AzTreeViewOnContextMenu) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `DropDown` struct
 pub type AzDropDownTT = crate::widgets::drop_down::DropDown;
 pub use AzDropDownTT as AzDropDown;
 /// Creates a new `DropDown` instance whose memory is owned by the rust allocator
 /// Equivalent to the Rust `DropDown::new()` constructor.
 #[no_mangle] pub extern "C" fn AzDropDown_new(choices: AzStringVec) -> AzDropDown { AzDropDown::new(choices) }
+/// Equivalent to the Rust `DropDown::set_selected()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setSelected(dropdown: &mut AzDropDown, selected: usize) { dropdown.set_selected(selected) }
+/// Equivalent to the Rust `DropDown::with_selected()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withSelected(dropdown: &mut AzDropDown, selected: usize) -> AzDropDown { dropdown.with_selected(selected) }
+/// Equivalent to the Rust `DropDown::set_disabled_choices()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setDisabledChoices(dropdown: &mut AzDropDown, disabled_choices: AzU32Vec) { dropdown.set_disabled_choices(disabled_choices) }
+/// Equivalent to the Rust `DropDown::with_disabled_choices()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withDisabledChoices(dropdown: &mut AzDropDown, disabled_choices: AzU32Vec) -> AzDropDown { dropdown.with_disabled_choices(disabled_choices) }
+/// Equivalent to the Rust `DropDown::set_on_choice_change()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_setOnChoiceChange(dropdown: &mut AzDropDown, data: AzRefAny, callback: AzDropDownOnChoiceChangeCallbackType) { dropdown.set_on_choice_change(data, callback) }
+/// Equivalent to the Rust `DropDown::with_on_choice_change()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_withOnChoiceChange(dropdown: &mut AzDropDown, data: AzRefAny, callback: AzDropDownOnChoiceChangeCallbackType) -> AzDropDown { dropdown.with_on_choice_change(data, callback) }
+/// Equivalent to the Rust `DropDown::swap_with_default()` function.
+#[no_mangle] pub extern "C" fn AzDropDown_swapWithDefault(dropdown: &mut AzDropDown) -> AzDropDown { dropdown.swap_with_default() }
 /// Equivalent to the Rust `DropDown::dom()` function.
 #[no_mangle] pub extern "C" fn AzDropDown_dom(dropdown: &mut AzDropDown) -> AzDom { dropdown.swap_with_default().dom() }
 /// Destructor: Takes ownership of the `DropDown` pointer and deletes it.
@@ -4008,6 +4932,16 @@ pub type AzGetSystemTimeFnType = extern "C" fn() -> AzInstant;
 pub type AzGetSystemTimeFnTT = azul_impl::task::GetSystemTimeCallback;
 pub use AzGetSystemTimeFnTT as AzGetSystemTimeFn;
 
+pub type AzGetClipboardFnType = extern "C" fn(&AzRawWindowHandle, AzClipboardSelection, AzString) -> AzOptionClipboardContent;
+/// Reads the content of the system clipboard in the given MIME type
+pub type AzGetClipboardFnTT = azul_impl::task::GetClipboardCallback;
+pub use AzGetClipboardFnTT as AzGetClipboardFn;
+
+pub type AzSetClipboardFnType = extern "C" fn(&AzRawWindowHandle, AzClipboardSelection, AzClipboardContent) -> bool;
+/// Sets the content of the system clipboard, returns whether the clipboard could be set
+pub type AzSetClipboardFnTT = azul_impl::task::SetClipboardCallback;
+pub use AzSetClipboardFnTT as AzSetClipboardFn;
+
 pub type AzCheckThreadFinishedFnType = extern "C" fn(&c_void) -> bool;
 /// Function called to check if the thread has finished
 pub type AzCheckThreadFinishedFnTT = azul_impl::task::CheckThreadFinishedCallback;
@@ -4080,6 +5014,54 @@ pub use AzListViewRowVecTT as AzListViewRowVec;
 /// Destructor: Takes ownership of the `ListViewRowVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzListViewRowVec_delete(object: &mut AzListViewRowVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<TreeViewNode>`
+pub type AzTreeViewNodeVecTT = crate::widgets::tree_view::TreeViewNodeVec;
+pub use AzTreeViewNodeVecTT as AzTreeViewNodeVec;
+/// Destructor: Takes ownership of the `TreeViewNodeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewNodeVec_delete(object: &mut AzTreeViewNodeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<TreeViewNodeId>`
+pub type AzTreeViewNodeIdVecTT = crate::widgets::tree_view::TreeViewNodeIdVec;
+pub use AzTreeViewNodeIdVecTT as AzTreeViewNodeIdVec;
+/// Destructor: Takes ownership of the `TreeViewNodeIdVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTreeViewNodeIdVec_delete(object: &mut AzTreeViewNodeIdVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<RibbonTab>`
+pub type AzRibbonTabVecTT = crate::widgets::ribbon::RibbonTabVec;
+pub use AzRibbonTabVecTT as AzRibbonTabVec;
+/// Destructor: Takes ownership of the `RibbonTabVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRibbonTabVec_delete(object: &mut AzRibbonTabVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<RibbonGroup>`
+pub type AzRibbonGroupVecTT = crate::widgets::ribbon::RibbonGroupVec;
+pub use AzRibbonGroupVecTT as AzRibbonGroupVec;
+/// Destructor: Takes ownership of the `RibbonGroupVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRibbonGroupVec_delete(object: &mut AzRibbonGroupVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<RibbonItem>`
+pub type AzRibbonItemVecTT = crate::widgets::ribbon::RibbonItemVec;
+pub use AzRibbonItemVecTT as AzRibbonItemVec;
+/// Destructor: Takes ownership of the `RibbonItemVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzRibbonItemVec_delete(object: &mut AzRibbonItemVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+pub type AzSplitterPaneVecTT = crate::widgets::splitter::SplitterPaneVec;
+pub use AzSplitterPaneVecTT as AzSplitterPaneVec;
+/// Destructor: Takes ownership of the `SplitterPaneVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterPaneVec_delete(object: &mut AzSplitterPaneVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+pub type AzSplitterPaneStateVecTT = crate::widgets::splitter::SplitterPaneStateVec;
+pub use AzSplitterPaneStateVecTT as AzSplitterPaneStateVec;
+/// Destructor: Takes ownership of the `SplitterPaneStateVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzSplitterPaneStateVec_delete(object: &mut AzSplitterPaneStateVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<TextAreaEdit>`
+pub type AzTextAreaEditVecTT = crate::widgets::text_area::TextAreaEditVec;
+pub use AzTextAreaEditVecTT as AzTextAreaEditVec;
+/// Destructor: Takes ownership of the `TextAreaEditVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzTextAreaEditVec_delete(object: &mut AzTextAreaEditVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<StyleFilter>`
 pub type AzStyleFilterVecTT = azul_impl::css::StyleFilterVec;
 pub use AzStyleFilterVecTT as AzStyleFilterVec;
@@ -4152,6 +5134,12 @@ pub use AzAccessibilityStateVecTT as AzAccessibilityStateVec;
 /// Destructor: Takes ownership of the `AccessibilityStateVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzAccessibilityStateVec_delete(object: &mut AzAccessibilityStateVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<AccessibilityAction>`
+pub type AzAccessibilityActionVecTT = azul_impl::dom::AccessibilityActionVec;
+pub use AzAccessibilityActionVecTT as AzAccessibilityActionVec;
+/// Destructor: Takes ownership of the `AccessibilityActionVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzAccessibilityActionVec_delete(object: &mut AzAccessibilityActionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<MenuItem>`
 pub type AzMenuItemVecTT = azul_core::window::MenuItemVec;
 pub use AzMenuItemVecTT as AzMenuItemVec;
@@ -4268,6 +5256,60 @@ pub use AzStyleTransformVecTT as AzStyleTransformVec;
 /// Destructor: Takes ownership of the `StyleTransformVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzStyleTransformVec_delete(object: &mut AzStyleTransformVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+pub type AzStyleTransitionPropertyVecTT = azul_impl::css::StyleTransitionPropertyVec;
+pub use AzStyleTransitionPropertyVecTT as AzStyleTransitionPropertyVec;
+/// Destructor: Takes ownership of the `StyleTransitionPropertyVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTransitionPropertyVec_delete(object: &mut AzStyleTransitionPropertyVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleTime>`
+pub type AzStyleTimeVecTT = azul_impl::css::StyleTimeVec;
+pub use AzStyleTimeVecTT as AzStyleTimeVec;
+/// Destructor: Takes ownership of the `StyleTimeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTimeVec_delete(object: &mut AzStyleTimeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleTimingFunction>`
+pub type AzStyleTimingFunctionVecTT = azul_impl::css::StyleTimingFunctionVec;
+pub use AzStyleTimingFunctionVecTT as AzStyleTimingFunctionVec;
+/// Destructor: Takes ownership of the `StyleTimingFunctionVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleTimingFunctionVec_delete(object: &mut AzStyleTimingFunctionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+pub type AzStyleAnimationNameVecTT = azul_impl::css::StyleAnimationNameVec;
+pub use AzStyleAnimationNameVecTT as AzStyleAnimationNameVec;
+/// Destructor: Takes ownership of the `StyleAnimationNameVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationNameVec_delete(object: &mut AzStyleAnimationNameVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+pub type AzStyleAnimationIterationCountVecTT = azul_impl::css::StyleAnimationIterationCountVec;
+pub use AzStyleAnimationIterationCountVecTT as AzStyleAnimationIterationCountVec;
+/// Destructor: Takes ownership of the `StyleAnimationIterationCountVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationIterationCountVec_delete(object: &mut AzStyleAnimationIterationCountVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+pub type AzStyleAnimationDirectionVecTT = azul_impl::css::StyleAnimationDirectionVec;
+pub use AzStyleAnimationDirectionVecTT as AzStyleAnimationDirectionVec;
+/// Destructor: Takes ownership of the `StyleAnimationDirectionVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationDirectionVec_delete(object: &mut AzStyleAnimationDirectionVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+pub type AzStyleAnimationFillModeVecTT = azul_impl::css::StyleAnimationFillModeVec;
+pub use AzStyleAnimationFillModeVecTT as AzStyleAnimationFillModeVec;
+/// Destructor: Takes ownership of the `StyleAnimationFillModeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationFillModeVec_delete(object: &mut AzStyleAnimationFillModeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationPlayState>`
+pub type AzStyleAnimationPlayStateVecTT = azul_impl::css::StyleAnimationPlayStateVec;
+pub use AzStyleAnimationPlayStateVecTT as AzStyleAnimationPlayStateVec;
+/// Destructor: Takes ownership of the `StyleAnimationPlayStateVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzStyleAnimationPlayStateVec_delete(object: &mut AzStyleAnimationPlayStateVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
+pub type AzGridTrackSizingVecTT = azul_impl::css::GridTrackSizingVec;
+pub use AzGridTrackSizingVecTT as AzGridTrackSizingVec;
+/// Destructor: Takes ownership of the `GridTrackSizingVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzGridTrackSizingVec_delete(object: &mut AzGridTrackSizingVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `Vec<CssProperty>`
 pub type AzCssPropertyVecTT = azul_impl::css::CssPropertyVec;
 pub use AzCssPropertyVecTT as AzCssPropertyVec;
@@ -4352,6 +5394,36 @@ pub use AzStylesheetVecTT as AzStylesheetVec;
 /// Destructor: Takes ownership of the `StylesheetVec` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzStylesheetVec_delete(object: &mut AzStylesheetVec) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+pub type AzCssKeyframesVecTT = azul_impl::css::CssKeyframesVec;
+pub use AzCssKeyframesVecTT as AzCssKeyframesVec;
+/// Destructor: Takes ownership of the `CssKeyframesVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssKeyframesVec_delete(object: &mut AzCssKeyframesVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+pub type AzCssKeyframeVecTT = azul_impl::css::CssKeyframeVec;
+pub use AzCssKeyframeVecTT as AzCssKeyframeVec;
+/// Destructor: Takes ownership of the `CssKeyframeVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssKeyframeVec_delete(object: &mut AzCssKeyframeVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<CssMediaRule>`
+pub type AzCssMediaRuleVecTT = azul_impl::css::CssMediaRuleVec;
+pub use AzCssMediaRuleVecTT as AzCssMediaRuleVec;
+/// Destructor: Takes ownership of the `CssMediaRuleVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssMediaRuleVec_delete(object: &mut AzCssMediaRuleVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<CssMediaQuery>`
+pub type AzCssMediaQueryVecTT = azul_impl::css::CssMediaQueryVec;
+pub use AzCssMediaQueryVecTT as AzCssMediaQueryVec;
+/// Destructor: Takes ownership of the `CssMediaQueryVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssMediaQueryVec_delete(object: &mut AzCssMediaQueryVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Wrapper over a Rust-allocated `Vec<CssMediaFeature>`
+pub type AzCssMediaFeatureVecTT = azul_impl::css::CssMediaFeatureVec;
+pub use AzCssMediaFeatureVecTT as AzCssMediaFeatureVec;
+/// Destructor: Takes ownership of the `CssMediaFeatureVec` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzCssMediaFeatureVec_delete(object: &mut AzCssMediaFeatureVec) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Wrapper over a Rust-allocated `CssRuleBlock`
 pub type AzCssRuleBlockVecTT = azul_impl::css::CssRuleBlockVec;
 pub use AzCssRuleBlockVecTT as AzCssRuleBlockVec;
@@ -4474,6 +5546,46 @@ pub type AzListViewRowVecDestructorTT = crate::widgets::list_view::ListViewRowVe
 pub use AzListViewRowVecDestructorTT as AzListViewRowVecDestructor;
 
 pub type AzListViewRowVecDestructorType = extern "C" fn(&mut AzListViewRowVec);
+/// Re-export of rust-allocated (stack based) `TreeViewNodeVecDestructor` struct
+pub type AzTreeViewNodeVecDestructorTT = crate::widgets::tree_view::TreeViewNodeVecDestructor;
+pub use AzTreeViewNodeVecDestructorTT as AzTreeViewNodeVecDestructor;
+
+pub type AzTreeViewNodeVecDestructorType = extern "C" fn(&mut AzTreeViewNodeVec);
+/// Re-export of rust-allocated (stack based) `TreeViewNodeIdVecDestructor` struct
+pub type AzTreeViewNodeIdVecDestructorTT = crate::widgets::tree_view::TreeViewNodeIdVecDestructor;
+pub use AzTreeViewNodeIdVecDestructorTT as AzTreeViewNodeIdVecDestructor;
+
+pub type AzTreeViewNodeIdVecDestructorType = extern "C" fn(&mut AzTreeViewNodeIdVec);
+/// Re-export of rust-allocated (stack based) `RibbonTabVecDestructor` struct
+pub type AzRibbonTabVecDestructorTT = crate::widgets::ribbon::RibbonTabVecDestructor;
+pub use AzRibbonTabVecDestructorTT as AzRibbonTabVecDestructor;
+
+pub type AzRibbonTabVecDestructorType = extern "C" fn(&mut AzRibbonTabVec);
+/// Re-export of rust-allocated (stack based) `RibbonGroupVecDestructor` struct
+pub type AzRibbonGroupVecDestructorTT = crate::widgets::ribbon::RibbonGroupVecDestructor;
+pub use AzRibbonGroupVecDestructorTT as AzRibbonGroupVecDestructor;
+
+pub type AzRibbonGroupVecDestructorType = extern "C" fn(&mut AzRibbonGroupVec);
+/// Re-export of rust-allocated (stack based) `RibbonItemVecDestructor` struct
+pub type AzRibbonItemVecDestructorTT = crate::widgets::ribbon::RibbonItemVecDestructor;
+pub use AzRibbonItemVecDestructorTT as AzRibbonItemVecDestructor;
+
+pub type AzRibbonItemVecDestructorType = extern "C" fn(&mut AzRibbonItemVec);
+/// Re-export of rust-allocated (stack based) `SplitterPaneVecDestructor` struct
+pub type AzSplitterPaneVecDestructorTT = crate::widgets::splitter::SplitterPaneVecDestructor;
+pub use AzSplitterPaneVecDestructorTT as AzSplitterPaneVecDestructor;
+
+pub type AzSplitterPaneVecDestructorType = extern "C" fn(&mut AzSplitterPaneVec);
+/// Re-export of rust-allocated (stack based) `SplitterPaneStateVecDestructor` struct
+pub type AzSplitterPaneStateVecDestructorTT = crate::widgets::splitter::SplitterPaneStateVecDestructor;
+pub use AzSplitterPaneStateVecDestructorTT as AzSplitterPaneStateVecDestructor;
+
+pub type AzSplitterPaneStateVecDestructorType = extern "C" fn(&mut AzSplitterPaneStateVec);
+/// Re-export of rust-allocated (stack based) `TextAreaEditVecDestructor` struct
+pub type AzTextAreaEditVecDestructorTT = crate::widgets::text_area::TextAreaEditVecDestructor;
+pub use AzTextAreaEditVecDestructorTT as AzTextAreaEditVecDestructor;
+
+pub type AzTextAreaEditVecDestructorType = extern "C" fn(&mut AzTextAreaEditVec);
 /// Re-export of rust-allocated (stack based) `StyleFilterVecDestructor` struct
 pub type AzStyleFilterVecDestructorTT = azul_impl::css::StyleFilterVecDestructor;
 pub use AzStyleFilterVecDestructorTT as AzStyleFilterVecDestructor;
@@ -4534,6 +5646,11 @@ pub type AzAccessibilityStateVecDestructorTT = azul_impl::dom::AccessibilityStat
 pub use AzAccessibilityStateVecDestructorTT as AzAccessibilityStateVecDestructor;
 
 pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
+/// Re-export of rust-allocated (stack based) `AccessibilityActionVecDestructor` struct
+pub type AzAccessibilityActionVecDestructorTT = azul_impl::dom::AccessibilityActionVecDestructor;
+pub use AzAccessibilityActionVecDestructorTT as AzAccessibilityActionVecDestructor;
+
+pub type AzAccessibilityActionVecDestructorType = extern "C" fn(&mut AzAccessibilityActionVec);
 /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
 pub type AzMenuItemVecDestructorTT = azul_core::window::MenuItemVecDestructor;
 pub use AzMenuItemVecDestructorTT as AzMenuItemVecDestructor;
@@ -4624,6 +5741,51 @@ pub type AzStyleTransformVecDestructorTT = azul_impl::css::StyleTransformVecDest
 pub use AzStyleTransformVecDestructorTT as AzStyleTransformVecDestructor;
 
 pub type AzStyleTransformVecDestructorType = extern "C" fn(&mut AzStyleTransformVec);
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecDestructor` struct
+pub type AzStyleTransitionPropertyVecDestructorTT = azul_impl::css::StyleTransitionPropertyVecDestructor;
+pub use AzStyleTransitionPropertyVecDestructorTT as AzStyleTransitionPropertyVecDestructor;
+
+pub type AzStyleTransitionPropertyVecDestructorType = extern "C" fn(&mut AzStyleTransitionPropertyVec);
+/// Re-export of rust-allocated (stack based) `StyleTimeVecDestructor` struct
+pub type AzStyleTimeVecDestructorTT = azul_impl::css::StyleTimeVecDestructor;
+pub use AzStyleTimeVecDestructorTT as AzStyleTimeVecDestructor;
+
+pub type AzStyleTimeVecDestructorType = extern "C" fn(&mut AzStyleTimeVec);
+/// Re-export of rust-allocated (stack based) `StyleTimingFunctionVecDestructor` struct
+pub type AzStyleTimingFunctionVecDestructorTT = azul_impl::css::StyleTimingFunctionVecDestructor;
+pub use AzStyleTimingFunctionVecDestructorTT as AzStyleTimingFunctionVecDestructor;
+
+pub type AzStyleTimingFunctionVecDestructorType = extern "C" fn(&mut AzStyleTimingFunctionVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationNameVecDestructor` struct
+pub type AzStyleAnimationNameVecDestructorTT = azul_impl::css::StyleAnimationNameVecDestructor;
+pub use AzStyleAnimationNameVecDestructorTT as AzStyleAnimationNameVecDestructor;
+
+pub type AzStyleAnimationNameVecDestructorType = extern "C" fn(&mut AzStyleAnimationNameVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecDestructor` struct
+pub type AzStyleAnimationIterationCountVecDestructorTT = azul_impl::css::StyleAnimationIterationCountVecDestructor;
+pub use AzStyleAnimationIterationCountVecDestructorTT as AzStyleAnimationIterationCountVecDestructor;
+
+pub type AzStyleAnimationIterationCountVecDestructorType = extern "C" fn(&mut AzStyleAnimationIterationCountVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecDestructor` struct
+pub type AzStyleAnimationDirectionVecDestructorTT = azul_impl::css::StyleAnimationDirectionVecDestructor;
+pub use AzStyleAnimationDirectionVecDestructorTT as AzStyleAnimationDirectionVecDestructor;
+
+pub type AzStyleAnimationDirectionVecDestructorType = extern "C" fn(&mut AzStyleAnimationDirectionVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecDestructor` struct
+pub type AzStyleAnimationFillModeVecDestructorTT = azul_impl::css::StyleAnimationFillModeVecDestructor;
+pub use AzStyleAnimationFillModeVecDestructorTT as AzStyleAnimationFillModeVecDestructor;
+
+pub type AzStyleAnimationFillModeVecDestructorType = extern "C" fn(&mut AzStyleAnimationFillModeVec);
+/// Re-export of rust-allocated (stack based) `StyleAnimationPlayStateVecDestructor` struct
+pub type AzStyleAnimationPlayStateVecDestructorTT = azul_impl::css::StyleAnimationPlayStateVecDestructor;
+pub use AzStyleAnimationPlayStateVecDestructorTT as AzStyleAnimationPlayStateVecDestructor;
+
+pub type AzStyleAnimationPlayStateVecDestructorType = extern "C" fn(&mut AzStyleAnimationPlayStateVec);
+/// Re-export of rust-allocated (stack based) `GridTrackSizingVecDestructor` struct
+pub type AzGridTrackSizingVecDestructorTT = azul_impl::css::GridTrackSizingVecDestructor;
+pub use AzGridTrackSizingVecDestructorTT as AzGridTrackSizingVecDestructor;
+
+pub type AzGridTrackSizingVecDestructorType = extern "C" fn(&mut AzGridTrackSizingVec);
 /// Re-export of rust-allocated (stack based) `CssPropertyVecDestructor` struct
 pub type AzCssPropertyVecDestructorTT = azul_impl::css::CssPropertyVecDestructor;
 pub use AzCssPropertyVecDestructorTT as AzCssPropertyVecDestructor;
@@ -4694,6 +5856,31 @@ pub type AzStylesheetVecDestructorTT = azul_impl::css::StylesheetVecDestructor;
 pub use AzStylesheetVecDestructorTT as AzStylesheetVecDestructor;
 
 pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
+/// Re-export of rust-allocated (stack based) `CssKeyframesVecDestructor` struct
+pub type AzCssKeyframesVecDestructorTT = azul_impl::css::CssKeyframesVecDestructor;
+pub use AzCssKeyframesVecDestructorTT as AzCssKeyframesVecDestructor;
+
+pub type AzCssKeyframesVecDestructorType = extern "C" fn(&mut AzCssKeyframesVec);
+/// Re-export of rust-allocated (stack based) `CssKeyframeVecDestructor` struct
+pub type AzCssKeyframeVecDestructorTT = azul_impl::css::CssKeyframeVecDestructor;
+pub use AzCssKeyframeVecDestructorTT as AzCssKeyframeVecDestructor;
+
+pub type AzCssKeyframeVecDestructorType = extern "C" fn(&mut AzCssKeyframeVec);
+/// Re-export of rust-allocated (stack based) `CssMediaRuleVecDestructor` struct
+pub type AzCssMediaRuleVecDestructorTT = azul_impl::css::CssMediaRuleVecDestructor;
+pub use AzCssMediaRuleVecDestructorTT as AzCssMediaRuleVecDestructor;
+
+pub type AzCssMediaRuleVecDestructorType = extern "C" fn(&mut AzCssMediaRuleVec);
+/// Re-export of rust-allocated (stack based) `CssMediaQueryVecDestructor` struct
+pub type AzCssMediaQueryVecDestructorTT = azul_impl::css::CssMediaQueryVecDestructor;
+pub use AzCssMediaQueryVecDestructorTT as AzCssMediaQueryVecDestructor;
+
+pub type AzCssMediaQueryVecDestructorType = extern "C" fn(&mut AzCssMediaQueryVec);
+/// Re-export of rust-allocated (stack based) `CssMediaFeatureVecDestructor` struct
+pub type AzCssMediaFeatureVecDestructorTT = azul_impl::css::CssMediaFeatureVecDestructor;
+pub use AzCssMediaFeatureVecDestructorTT as AzCssMediaFeatureVecDestructor;
+
+pub type AzCssMediaFeatureVecDestructorType = extern "C" fn(&mut AzCssMediaFeatureVec);
 /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
 pub type AzCssRuleBlockVecDestructorTT = azul_impl::css::CssRuleBlockVecDestructor;
 pub use AzCssRuleBlockVecDestructorTT as AzCssRuleBlockVecDestructor;
@@ -4790,6 +5977,34 @@ pub use AzOptionListViewOnRowClickTT as AzOptionListViewOnRowClick;
 /// Destructor: Takes ownership of the `OptionListViewOnRowClick` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionListViewOnRowClick_delete(object: &mut AzOptionListViewOnRowClick) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionTreeViewNodeId` struct
+pub type AzOptionTreeViewNodeIdTT = crate::widgets::tree_view::OptionTreeViewNodeId;
+pub use AzOptionTreeViewNodeIdTT as AzOptionTreeViewNodeId;
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnExpand` struct
+pub type AzOptionTreeViewOnExpandTT = crate::widgets::tree_view::OptionTreeViewOnExpand;
+pub use AzOptionTreeViewOnExpandTT as AzOptionTreeViewOnExpand;
+/// Destructor: Takes ownership of the `OptionTreeViewOnExpand` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnExpand_delete(object: &mut AzOptionTreeViewOnExpand) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelect` struct
+pub type AzOptionTreeViewOnSelectTT = crate::widgets::tree_view::OptionTreeViewOnSelect;
+pub use AzOptionTreeViewOnSelectTT as AzOptionTreeViewOnSelect;
+/// Destructor: Takes ownership of the `OptionTreeViewOnSelect` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnSelect_delete(object: &mut AzOptionTreeViewOnSelect) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnDoubleClick` struct
+pub type AzOptionTreeViewOnDoubleClickTT = crate::widgets::tree_view::OptionTreeViewOnDoubleClick;
+pub use AzOptionTreeViewOnDoubleClickTT as AzOptionTreeViewOnDoubleClick;
+/// Destructor: Takes ownership of the `OptionTreeViewOnDoubleClick` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnDoubleClick_delete(object: &mut AzOptionTreeViewOnDoubleClick) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnContextMenu` struct
+pub type AzOptionTreeViewOnContextMenuTT = crate::widgets::tree_view::OptionTreeViewOnContextMenu;
+pub use AzOptionTreeViewOnContextMenuTT as AzOptionTreeViewOnContextMenu;
+/// Destructor: Takes ownership of the `OptionTreeViewOnContextMenu` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTreeViewOnContextMenu_delete(object: &mut AzOptionTreeViewOnContextMenu) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnClick` struct
 pub type AzOptionListViewOnColumnClickTT = crate::widgets::list_view::OptionListViewOnColumnClick;
 pub use AzOptionListViewOnColumnClickTT as AzOptionListViewOnColumnClick;
@@ -4802,6 +6017,18 @@ pub use AzOptionListViewOnLazyLoadScrollTT as AzOptionListViewOnLazyLoadScroll;
 /// Destructor: Takes ownership of the `OptionListViewOnLazyLoadScroll` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionListViewOnLazyLoadScroll_delete(object: &mut AzOptionListViewOnLazyLoadScroll) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionListViewOnColumnResize` struct
+pub type AzOptionListViewOnColumnResizeTT = crate::widgets::list_view::OptionListViewOnColumnResize;
+pub use AzOptionListViewOnColumnResizeTT as AzOptionListViewOnColumnResize;
+/// Destructor: Takes ownership of the `OptionListViewOnColumnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionListViewOnColumnResize_delete(object: &mut AzOptionListViewOnColumnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionListViewRowProvider` struct
+pub type AzOptionListViewRowProviderTT = crate::widgets::list_view::OptionListViewRowProvider;
+pub use AzOptionListViewRowProviderTT as AzOptionListViewRowProvider;
+/// Destructor: Takes ownership of the `OptionListViewRowProvider` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionListViewRowProvider_delete(object: &mut AzOptionListViewRowProvider) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionMenu` struct
 pub type AzOptionMenuTT = azul_core::window::OptionMenu;
 pub use AzOptionMenuTT as AzOptionMenu;
@@ -4890,6 +6117,54 @@ pub use AzOptionTabOnClickTT as AzOptionTabOnClick;
 /// Destructor: Takes ownership of the `OptionTabOnClick` pointer and deletes it.
 #[no_mangle] pub extern "C" fn AzOptionTabOnClick_delete(object: &mut AzOptionTabOnClick) {  unsafe { core::ptr::drop_in_place(object); } }
 
+/// Re-export of rust-allocated (stack based) `OptionTabOnClose` struct
+pub type AzOptionTabOnCloseTT = crate::widgets::tabs::OptionTabOnClose;
+pub use AzOptionTabOnCloseTT as AzOptionTabOnClose;
+/// Destructor: Takes ownership of the `OptionTabOnClose` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTabOnClose_delete(object: &mut AzOptionTabOnClose) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTabOnReorder` struct
+pub type AzOptionTabOnReorderTT = crate::widgets::tabs::OptionTabOnReorder;
+pub use AzOptionTabOnReorderTT as AzOptionTabOnReorder;
+/// Destructor: Takes ownership of the `OptionTabOnReorder` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTabOnReorder_delete(object: &mut AzOptionTabOnReorder) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionTabOnContextMenu` struct
+pub type AzOptionTabOnContextMenuTT = crate::widgets::tabs::OptionTabOnContextMenu;
+pub use AzOptionTabOnContextMenuTT as AzOptionTabOnContextMenu;
+/// Destructor: Takes ownership of the `OptionTabOnContextMenu` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTabOnContextMenu_delete(object: &mut AzOptionTabOnContextMenu) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionRibbonOnTabClicked` struct
+pub type AzOptionRibbonOnTabClickedTT = crate::widgets::ribbon::OptionRibbonOnTabClicked;
+pub use AzOptionRibbonOnTabClickedTT as AzOptionRibbonOnTabClicked;
+/// Destructor: Takes ownership of the `OptionRibbonOnTabClicked` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionRibbonOnTabClicked_delete(object: &mut AzOptionRibbonOnTabClicked) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionRibbonOnItemClick` struct
+pub type AzOptionRibbonOnItemClickTT = crate::widgets::ribbon::OptionRibbonOnItemClick;
+pub use AzOptionRibbonOnItemClickTT as AzOptionRibbonOnItemClick;
+/// Destructor: Takes ownership of the `OptionRibbonOnItemClick` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionRibbonOnItemClick_delete(object: &mut AzOptionRibbonOnItemClick) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionSplitterOnResize` struct
+pub type AzOptionSplitterOnResizeTT = crate::widgets::splitter::OptionSplitterOnResize;
+pub use AzOptionSplitterOnResizeTT as AzOptionSplitterOnResize;
+/// Destructor: Takes ownership of the `OptionSplitterOnResize` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionSplitterOnResize_delete(object: &mut AzOptionSplitterOnResize) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+pub type AzOptionSliderOnValueChangeTT = crate::widgets::slider::OptionSliderOnValueChange;
+pub use AzOptionSliderOnValueChangeTT as AzOptionSliderOnValueChange;
+/// Destructor: Takes ownership of the `OptionSliderOnValueChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionSliderOnValueChange_delete(object: &mut AzOptionSliderOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionRangeSliderOnValueChange` struct
+pub type AzOptionRangeSliderOnValueChangeTT = crate::widgets::slider::OptionRangeSliderOnValueChange;
+pub use AzOptionRangeSliderOnValueChangeTT as AzOptionRangeSliderOnValueChange;
+/// Destructor: Takes ownership of the `OptionRangeSliderOnValueChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionRangeSliderOnValueChange_delete(object: &mut AzOptionRangeSliderOnValueChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionFileInputOnPathChange` struct
 pub type AzOptionFileInputOnPathChangeTT = crate::widgets::file_input::OptionFileInputOnPathChange;
 pub use AzOptionFileInputOnPathChangeTT as AzOptionFileInputOnPathChange;
@@ -4924,6 +6199,12 @@ pub use AzOptionTextInputOnFocusLostTT as AzOptionTextInputOnFocusLost;
 pub type AzOptionTextInputSelectionTT = crate::widgets::text_input::OptionTextInputSelection;
 pub use AzOptionTextInputSelectionTT as AzOptionTextInputSelection;
 
+/// Re-export of rust-allocated (stack based) `OptionTextAreaOnTextChange` struct
+pub type AzOptionTextAreaOnTextChangeTT = crate::widgets::text_area::OptionTextAreaOnTextChange;
+pub use AzOptionTextAreaOnTextChangeTT as AzOptionTextAreaOnTextChange;
+/// Destructor: Takes ownership of the `OptionTextAreaOnTextChange` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionTextAreaOnTextChange_delete(object: &mut AzOptionTextAreaOnTextChange) {  unsafe { core::ptr::drop_in_place(object); } }
+
 /// Re-export of rust-allocated (stack based) `OptionNumberInputOnFocusLost` struct
 pub type AzOptionNumberInputOnFocusLostTT = crate::widgets::number_input::OptionNumberInputOnFocusLost;
 pub use AzOptionNumberInputOnFocusLostTT as AzOptionNumberInputOnFocusLost;
@@ -5140,6 +6421,22 @@ pub use AzOptionHwndHandleTT as AzOptionHwndHandle;
 pub type AzOptionLogicalPositionTT = azul_core::window::OptionLogicalPosition;
 pub use AzOptionLogicalPositionTT as AzOptionLogicalPosition;
 
+/// Re-export of rust-allocated (stack based) `OptionImeComposition` struct
+pub type AzOptionImeCompositionTT = azul_core::window::OptionImeComposition;
+pub use AzOptionImeCompositionTT as AzOptionImeComposition;
+/// Destructor: Takes ownership of the `OptionImeComposition` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionImeComposition_delete(object: &mut AzOptionImeComposition) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionClipboardContent` struct
+pub type AzOptionClipboardContentTT = azul_core::window::OptionClipboardContent;
+pub use AzOptionClipboardContentTT as AzOptionClipboardContent;
+/// Destructor: Takes ownership of the `OptionClipboardContent` pointer and deletes it.
+#[no_mangle] pub extern "C" fn AzOptionClipboardContent_delete(object: &mut AzOptionClipboardContent) {  unsafe { core::ptr::drop_in_place(object); } }
+
+/// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+pub type AzOptionLogicalRectTT = azul_core::window::OptionLogicalRect;
+pub use AzOptionLogicalRectTT as AzOptionLogicalRect;
+
 /// Re-export of rust-allocated (stack based) `OptionPhysicalPositionI32` struct
 pub type AzOptionPhysicalPositionI32TT = azul_core::window::OptionPhysicalPositionI32;
 pub use AzOptionPhysicalPositionI32TT as AzOptionPhysicalPositionI32;
@@ -5569,6 +6866,21 @@ mod test_sizes {
         pub a_native_window: *mut c_void,
     }
 
+    /// Which system clipboard to read from / write to
+    #[repr(C)]
+    pub enum AzClipboardSelection {
+        Clipboard,
+        Primary,
+    }
+
+    /// Which windowing system the window should be created with on Linux
+    #[repr(C)]
+    pub enum AzLinuxBackend {
+        Auto,
+        X11,
+        Wayland,
+    }
+
     /// X11 window hint: Type of window
     #[repr(C)]
     pub enum AzXWindowType {
@@ -6014,6 +7326,15 @@ mod test_sizes {
     /// `AzIFrameCallbackType` struct
     pub type AzIFrameCallbackType = extern "C" fn(&mut AzRefAny, &mut AzIFrameCallbackInfo) -> AzIFrameCallbackReturn;
 
+    /// C-ABI wrapper over a `TooltipCallbackType`
+    #[repr(C)]
+    pub struct AzTooltipCallback {
+        pub cb: AzTooltipCallbackType,
+    }
+
+    /// `AzTooltipCallbackType` struct
+    pub type AzTooltipCallbackType = extern "C" fn(&mut AzRefAny, &mut AzLayoutCallbackInfo) -> AzStyledDom;
+
     /// Re-export of rust-allocated (stack based) `RenderImageCallback` struct
     #[repr(C)]
     pub struct AzRenderImageCallback {
@@ -6078,6 +7399,9 @@ mod test_sizes {
         TextInput,
         VirtualKeyDown,
         VirtualKeyUp,
+        CompositionStart,
+        CompositionUpdate,
+        CompositionEnd,
         HoveredFile,
         DroppedFile,
         HoveredFileCancelled,
@@ -6105,6 +7429,9 @@ mod test_sizes {
         TextInput,
         VirtualKeyDown,
         VirtualKeyUp,
+        CompositionStart,
+        CompositionUpdate,
+        CompositionEnd,
         HoveredFile,
         DroppedFile,
         HoveredFileCancelled,
@@ -6134,6 +7461,9 @@ mod test_sizes {
         TextInput,
         VirtualKeyDown,
         VirtualKeyUp,
+        CompositionStart,
+        CompositionUpdate,
+        CompositionEnd,
         FocusReceived,
         FocusLost,
     }
@@ -6158,6 +7488,9 @@ mod test_sizes {
         TextInput,
         VirtualKeyDown,
         VirtualKeyUp,
+        CompositionStart,
+        CompositionUpdate,
+        CompositionEnd,
         HoveredFile,
         DroppedFile,
         HoveredFileCancelled,
@@ -6280,6 +7613,19 @@ mod test_sizes {
         Protected,
     }
 
+    /// Action that an assistive technology (screen reader, voice control) can invoke on a node. See `NodeData::set_accessibility_actions`
+    #[repr(C)]
+    pub enum AzAccessibilityAction {
+        Default,
+        Focus,
+        Toggle,
+        Expand,
+        Collapse,
+        Select,
+        Increment,
+        Decrement,
+    }
+
     /// Re-export of rust-allocated (stack based) `TabIndex` struct
     #[repr(C, u8)]
     pub enum AzTabIndex {
@@ -6337,6 +7683,28 @@ mod test_sizes {
         pub offset: u32,
     }
 
+    /// Media type of a `CssMediaQuery`
+    #[repr(C)]
+    pub enum AzCssMediaType {
+        All,
+        Screen,
+        Print,
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssMediaOrientation` struct
+    #[repr(C)]
+    pub enum AzCssMediaOrientation {
+        Portrait,
+        Landscape,
+    }
+
+    /// Re-export of rust-allocated (stack based) `CssColorScheme` struct
+    #[repr(C)]
+    pub enum AzCssColorScheme {
+        Light,
+        Dark,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
     #[repr(C)]
     pub enum AzCssPropertyType {
@@ -6344,6 +7712,10 @@ mod test_sizes {
         FontSize,
         FontFamily,
         TextAlign,
+        Direction,
+        WordBreak,
+        OverflowWrap,
+        Hyphens,
         LetterSpacing,
         LineHeight,
         WordSpacing,
@@ -6370,6 +7742,15 @@ mod test_sizes {
         JustifyContent,
         AlignItems,
         AlignContent,
+        GridTemplateColumns,
+        GridTemplateRows,
+        GridAutoColumns,
+        GridAutoRows,
+        GridAutoFlow,
+        GridColumn,
+        GridRow,
+        RowGap,
+        ColumnGap,
         BackgroundContent,
         BackgroundPosition,
         BackgroundSize,
@@ -6414,6 +7795,39 @@ mod test_sizes {
         Filter,
         BackdropFilter,
         TextShadow,
+        TransitionProperty,
+        TransitionDuration,
+        TransitionTimingFunction,
+        TransitionDelay,
+        AnimationName,
+        AnimationDuration,
+        AnimationTimingFunction,
+        AnimationDelay,
+        AnimationIterationCount,
+        AnimationDirection,
+        AnimationFillMode,
+        AnimationPlayState,
+    }
+
+    /// Represents a CSS shorthand key that expands to multiple properties, for example `margin`
+    #[repr(C)]
+    pub enum AzCombinedCssPropertyType {
+        BorderRadius,
+        Overflow,
+        Margin,
+        Border,
+        BorderLeft,
+        BorderRight,
+        BorderTop,
+        BorderBottom,
+        Padding,
+        BoxShadow,
+        BackgroundColor,
+        BackgroundImage,
+        Gap,
+        GridGap,
+        Transition,
+        Animation,
     }
 
     /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -6483,6 +7897,23 @@ mod test_sizes {
         SpaceAround,
     }
 
+    /// Re-export of rust-allocated (stack based) `LayoutGridAutoFlow` struct
+    #[repr(C)]
+    pub enum AzLayoutGridAutoFlow {
+        Row,
+        Column,
+        RowDense,
+        ColumnDense,
+    }
+
+    /// Start or end line of a `grid-column` or `grid-row` attribute
+    #[repr(C, u8)]
+    pub enum AzGridLine {
+        Auto,
+        Line(i32),
+        Span(u32),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutAlignItems` struct
     #[repr(C)]
     pub enum AzLayoutAlignItems {
@@ -6515,6 +7946,7 @@ mod test_sizes {
         Flex,
         Block,
         InlineBlock,
+        Grid,
     }
 
     /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -6678,20 +8110,106 @@ mod test_sizes {
         Visible,
     }
 
+    /// Represents a `<time>` value in a `transition-duration`, `transition-delay`, `animation-duration` or `animation-delay` attribute, stored in milliseconds
+    #[repr(C)]
+    pub struct AzStyleTime {
+        pub ms: i32,
+    }
+
+    /// Position of the jumps in a `steps(n, position)` easing function
+    #[repr(C)]
+    pub enum AzStyleStepPosition {
+        JumpStart,
+        JumpEnd,
+        JumpNone,
+        JumpBoth,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirection` struct
+    #[repr(C)]
+    pub enum AzStyleAnimationDirection {
+        Normal,
+        Reverse,
+        Alternate,
+        AlternateReverse,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillMode` struct
+    #[repr(C)]
+    pub enum AzStyleAnimationFillMode {
+        None,
+        Forwards,
+        Backwards,
+        Both,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationPlayState` struct
+    #[repr(C)]
+    pub enum AzStyleAnimationPlayState {
+        Running,
+        Paused,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
     #[repr(C)]
     pub enum AzStyleTextAlign {
         Left,
         Center,
         Right,
+        Start,
+        End,
     }
 
-    /// Re-export of rust-allocated (stack based) `Ribbon` struct
+    /// Base writing direction of a text (`direction: ltr | rtl`)
     #[repr(C)]
-    pub struct AzRibbon {
-        pub tab_active: i32,
+    pub enum AzStyleDirection {
+        Ltr,
+        Rtl,
+    }
+
+    /// Where lines may break inside of words (`word-break: normal | break-all | keep-all`)
+    #[repr(C)]
+    pub enum AzStyleWordBreak {
+        Normal,
+        BreakAll,
+        KeepAll,
+    }
+
+    /// Whether words that are wider than the line may be broken at arbitrary points (`overflow-wrap: normal | anywhere | break-word`)
+    #[repr(C)]
+    pub enum AzStyleOverflowWrap {
+        Normal,
+        Anywhere,
+        BreakWord,
+    }
+
+    /// Whether words are hyphenated when breaking lines (`hyphens: none | manual | auto`)
+    #[repr(C)]
+    pub enum AzStyleHyphens {
+        None,
+        Manual,
+        Auto,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonItemKind` struct
+    #[repr(C)]
+    pub enum AzRibbonItemKind {
+        LargeButton,
+        SmallButton,
+        Toggle,
+        DropDown,
+        SplitButton,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonOnItemClickCallback` struct
+    #[repr(C)]
+    pub struct AzRibbonOnItemClickCallback {
+        pub cb: AzRibbonOnItemClickCallbackType,
     }
 
+    /// `AzRibbonOnItemClickCallbackType` struct
+    pub type AzRibbonOnItemClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzRibbonItemState) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `RibbonOnTabClickedCallback` struct
     #[repr(C)]
     pub struct AzRibbonOnTabClickedCallback {
@@ -6775,6 +8293,23 @@ mod test_sizes {
         No,
     }
 
+    /// What kind of change a `TextAreaEdit` made, consecutive edits of the same kind can be merged into one undo step
+    #[repr(C)]
+    pub enum AzTextAreaEditKind {
+        Insert,
+        Delete,
+        Replace,
+    }
+
+    /// `AzTextAreaOnTextChangeCallbackType` struct
+    pub type AzTextAreaOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextAreaState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TextAreaOnTextChangeCallback` struct
+    #[repr(C)]
+    pub struct AzTextAreaOnTextChangeCallback {
+        pub cb: AzTextAreaOnTextChangeCallbackType,
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputState` struct
     #[repr(C)]
     pub struct AzNumberInputState {
@@ -6802,11 +8337,18 @@ mod test_sizes {
     /// `AzNumberInputOnFocusLostCallbackType` struct
     pub type AzNumberInputOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzNumberInputState) -> AzUpdate;
 
-    /// Re-export of rust-allocated (stack based) `ProgressBarState` struct
+    /// Re-export of rust-allocated (stack based) `ProgressBarMode` struct
     #[repr(C)]
-    pub struct AzProgressBarState {
-        pub percent_done: f32,
-        pub display_percentage: bool,
+    pub enum AzProgressBarMode {
+        Determinate,
+        Indeterminate,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ProgressBarStyle` struct
+    #[repr(C)]
+    pub enum AzProgressBarStyle {
+        Solid,
+        Stripes,
     }
 
     /// Re-export of rust-allocated (stack based) `TabHeaderState` struct
@@ -6824,6 +8366,101 @@ mod test_sizes {
     /// `AzTabOnClickCallbackType` struct
     pub type AzTabOnClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTabHeaderState) -> AzUpdate;
 
+    /// Re-export of rust-allocated (stack based) `TabOnCloseCallback` struct
+    #[repr(C)]
+    pub struct AzTabOnCloseCallback {
+        pub cb: AzTabOnCloseCallbackType,
+    }
+
+    /// `AzTabOnCloseCallbackType` struct
+    pub type AzTabOnCloseCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TabOnReorderCallback` struct
+    #[repr(C)]
+    pub struct AzTabOnReorderCallback {
+        pub cb: AzTabOnReorderCallbackType,
+    }
+
+    /// `AzTabOnReorderCallbackType` struct
+    pub type AzTabOnReorderCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TabOnContextMenuCallback` struct
+    #[repr(C)]
+    pub struct AzTabOnContextMenuCallback {
+        pub cb: AzTabOnContextMenuCallbackType,
+    }
+
+    /// `AzTabOnContextMenuCallbackType` struct
+    pub type AzTabOnContextMenuCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SplitterOrientation` struct
+    #[repr(C)]
+    pub enum AzSplitterOrientation {
+        Horizontal,
+        Vertical,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitterPaneState` struct
+    #[repr(C)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub struct AzSplitterPaneState {
+        pub size: f32,
+        pub collapsed: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitterOnResizeCallback` struct
+    #[repr(C)]
+    pub struct AzSplitterOnResizeCallback {
+        pub cb: AzSplitterOnResizeCallbackType,
+    }
+
+    /// `AzSplitterOnResizeCallbackType` struct
+    pub type AzSplitterOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSplitterState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+    #[repr(C)]
+    pub enum AzSliderOrientation {
+        Horizontal,
+        Vertical,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderState` struct
+    #[repr(C)]
+    pub struct AzSliderState {
+        pub value: f32,
+        pub min: f32,
+        pub max: f32,
+        pub step: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RangeSliderState` struct
+    #[repr(C)]
+    pub struct AzRangeSliderState {
+        pub low: f32,
+        pub high: f32,
+        pub min: f32,
+        pub max: f32,
+        pub step: f32,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+    #[repr(C)]
+    pub struct AzSliderOnValueChangeCallback {
+        pub cb: AzSliderOnValueChangeCallbackType,
+    }
+
+    /// `AzSliderOnValueChangeCallbackType` struct
+    pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSliderState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `RangeSliderOnValueChangeCallback` struct
+    #[repr(C)]
+    pub struct AzRangeSliderOnValueChangeCallback {
+        pub cb: AzRangeSliderOnValueChangeCallbackType,
+    }
+
+    /// `AzRangeSliderOnValueChangeCallbackType` struct
+    pub type AzRangeSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzRangeSliderState) -> AzUpdate;
+
     /// Re-export of rust-allocated (stack based) `NodeGraphStyle` struct
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -6978,6 +8615,80 @@ mod test_sizes {
         pub cb: AzListViewOnRowClickCallbackType,
     }
 
+    /// Range of row indices, `start` is inclusive, `end` is exclusive
+    #[repr(C)]
+    pub struct AzListViewRowRange {
+        pub start: usize,
+        pub end: usize,
+    }
+
+    /// `AzListViewOnColumnResizeCallbackType` struct
+    pub type AzListViewOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, f32) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `ListViewOnColumnResizeCallback` struct
+    #[repr(C)]
+    pub struct AzListViewOnColumnResizeCallback {
+        pub cb: AzListViewOnColumnResizeCallbackType,
+    }
+
+    /// `AzListViewRowProviderCallbackType` struct
+    pub type AzListViewRowProviderCallbackType = extern "C" fn(&mut AzRefAny, &AzListViewState, AzListViewRowRange) -> AzListViewRowVec;
+
+    /// Re-export of rust-allocated (stack based) `ListViewRowProviderCallback` struct
+    #[repr(C)]
+    pub struct AzListViewRowProviderCallback {
+        pub cb: AzListViewRowProviderCallbackType,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeId` struct
+    #[repr(C)]
+    pub struct AzTreeViewNodeId {
+        pub inner: u64,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+    #[repr(C)]
+    pub enum AzTreeViewSelectionMode {
+        Single,
+        Multiple,
+    }
+
+    /// `AzTreeViewOnExpandCallbackType` struct
+    pub type AzTreeViewOnExpandCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId, bool) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnExpandCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnExpandCallback {
+        pub cb: AzTreeViewOnExpandCallbackType,
+    }
+
+    /// `AzTreeViewOnSelectCallbackType` struct
+    pub type AzTreeViewOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelectCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnSelectCallback {
+        pub cb: AzTreeViewOnSelectCallbackType,
+    }
+
+    /// `AzTreeViewOnDoubleClickCallbackType` struct
+    pub type AzTreeViewOnDoubleClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnDoubleClickCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnDoubleClickCallback {
+        pub cb: AzTreeViewOnDoubleClickCallbackType,
+    }
+
+    /// `AzTreeViewOnContextMenuCallbackType` struct
+    pub type AzTreeViewOnContextMenuCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId) -> AzUpdate;
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnContextMenuCallback` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnContextMenuCallback {
+        pub cb: AzTreeViewOnContextMenuCallbackType,
+    }
+
     /// `AzDropDownOnChoiceChangeCallbackType` struct
     pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
 
@@ -7526,6 +9237,24 @@ mod test_sizes {
         pub cb: AzGetSystemTimeFnType,
     }
 
+    /// `AzGetClipboardFnType` struct
+    pub type AzGetClipboardFnType = extern "C" fn(&AzRawWindowHandle, AzClipboardSelection, AzString) -> AzOptionClipboardContent;
+
+    /// Reads the content of the system clipboard in the given MIME type
+    #[repr(C)]
+    pub struct AzGetClipboardFn {
+        pub cb: AzGetClipboardFnType,
+    }
+
+    /// `AzSetClipboardFnType` struct
+    pub type AzSetClipboardFnType = extern "C" fn(&AzRawWindowHandle, AzClipboardSelection, AzClipboardContent) -> bool;
+
+    /// Sets the content of the system clipboard, returns whether the clipboard could be set
+    #[repr(C)]
+    pub struct AzSetClipboardFn {
+        pub cb: AzSetClipboardFnType,
+    }
+
     /// `AzCheckThreadFinishedFnType` struct
     pub type AzCheckThreadFinishedFnType = extern "C" fn(&c_void) -> bool;
 
@@ -7620,37 +9349,125 @@ mod test_sizes {
     /// `AzListViewRowVecDestructorType` struct
     pub type AzListViewRowVecDestructorType = extern "C" fn(&mut AzListViewRowVec);
 
-    /// Re-export of rust-allocated (stack based) `StyleFilterVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzStyleFilterVecDestructor {
+    pub enum AzTreeViewNodeVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzStyleFilterVecDestructorType),
+        External(AzTreeViewNodeVecDestructorType),
     }
 
-    /// `AzStyleFilterVecDestructorType` struct
-    pub type AzStyleFilterVecDestructorType = extern "C" fn(&mut AzStyleFilterVec);
+    /// `AzTreeViewNodeVecDestructorType` struct
+    pub type AzTreeViewNodeVecDestructorType = extern "C" fn(&mut AzTreeViewNodeVec);
 
-    /// Re-export of rust-allocated (stack based) `LogicalRectVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `TreeViewNodeIdVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzLogicalRectVecDestructor {
+    pub enum AzTreeViewNodeIdVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzLogicalRectVecDestructorType),
+        External(AzTreeViewNodeIdVecDestructorType),
     }
 
-    /// `AzLogicalRectVecDestructorType` struct
-    pub type AzLogicalRectVecDestructorType = extern "C" fn(&mut AzLogicalRectVec);
+    /// `AzTreeViewNodeIdVecDestructorType` struct
+    pub type AzTreeViewNodeIdVecDestructorType = extern "C" fn(&mut AzTreeViewNodeIdVec);
 
-    /// Re-export of rust-allocated (stack based) `NodeTypeIdInfoMapVecDestructor` struct
+    /// Re-export of rust-allocated (stack based) `RibbonTabVecDestructor` struct
     #[repr(C, u8)]
-    pub enum AzNodeTypeIdInfoMapVecDestructor {
+    pub enum AzRibbonTabVecDestructor {
         DefaultRust,
         NoDestructor,
-        External(AzNodeTypeIdInfoMapVecDestructorType),
+        External(AzRibbonTabVecDestructorType),
     }
 
-    /// `AzNodeTypeIdInfoMapVecDestructorType` struct
+    /// `AzRibbonTabVecDestructorType` struct
+    pub type AzRibbonTabVecDestructorType = extern "C" fn(&mut AzRibbonTabVec);
+
+    /// Re-export of rust-allocated (stack based) `RibbonGroupVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzRibbonGroupVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzRibbonGroupVecDestructorType),
+    }
+
+    /// `AzRibbonGroupVecDestructorType` struct
+    pub type AzRibbonGroupVecDestructorType = extern "C" fn(&mut AzRibbonGroupVec);
+
+    /// Re-export of rust-allocated (stack based) `RibbonItemVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzRibbonItemVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzRibbonItemVecDestructorType),
+    }
+
+    /// `AzRibbonItemVecDestructorType` struct
+    pub type AzRibbonItemVecDestructorType = extern "C" fn(&mut AzRibbonItemVec);
+
+    /// Re-export of rust-allocated (stack based) `SplitterPaneVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzSplitterPaneVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSplitterPaneVecDestructorType),
+    }
+
+    /// `AzSplitterPaneVecDestructorType` struct
+    pub type AzSplitterPaneVecDestructorType = extern "C" fn(&mut AzSplitterPaneVec);
+
+    /// Re-export of rust-allocated (stack based) `SplitterPaneStateVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzSplitterPaneStateVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzSplitterPaneStateVecDestructorType),
+    }
+
+    /// `AzSplitterPaneStateVecDestructorType` struct
+    pub type AzSplitterPaneStateVecDestructorType = extern "C" fn(&mut AzSplitterPaneStateVec);
+
+    /// Re-export of rust-allocated (stack based) `TextAreaEditVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzTextAreaEditVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzTextAreaEditVecDestructorType),
+    }
+
+    /// `AzTextAreaEditVecDestructorType` struct
+    pub type AzTextAreaEditVecDestructorType = extern "C" fn(&mut AzTextAreaEditVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleFilterVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleFilterVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleFilterVecDestructorType),
+    }
+
+    /// `AzStyleFilterVecDestructorType` struct
+    pub type AzStyleFilterVecDestructorType = extern "C" fn(&mut AzStyleFilterVec);
+
+    /// Re-export of rust-allocated (stack based) `LogicalRectVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzLogicalRectVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzLogicalRectVecDestructorType),
+    }
+
+    /// `AzLogicalRectVecDestructorType` struct
+    pub type AzLogicalRectVecDestructorType = extern "C" fn(&mut AzLogicalRectVec);
+
+    /// Re-export of rust-allocated (stack based) `NodeTypeIdInfoMapVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzNodeTypeIdInfoMapVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzNodeTypeIdInfoMapVecDestructorType),
+    }
+
+    /// `AzNodeTypeIdInfoMapVecDestructorType` struct
     pub type AzNodeTypeIdInfoMapVecDestructorType = extern "C" fn(&mut AzNodeTypeIdInfoMapVec);
 
     /// Re-export of rust-allocated (stack based) `InputOutputTypeIdInfoMapVecDestructor` struct
@@ -7752,6 +9569,17 @@ mod test_sizes {
     /// `AzAccessibilityStateVecDestructorType` struct
     pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+    /// Re-export of rust-allocated (stack based) `AccessibilityActionVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzAccessibilityActionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzAccessibilityActionVecDestructorType),
+    }
+
+    /// `AzAccessibilityActionVecDestructorType` struct
+    pub type AzAccessibilityActionVecDestructorType = extern "C" fn(&mut AzAccessibilityActionVec);
+
     /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzMenuItemVecDestructor {
@@ -7950,6 +9778,105 @@ mod test_sizes {
     /// `AzStyleTransformVecDestructorType` struct
     pub type AzStyleTransformVecDestructorType = extern "C" fn(&mut AzStyleTransformVec);
 
+    /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionPropertyVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleTransitionPropertyVecDestructorType),
+    }
+
+    /// `AzStyleTransitionPropertyVecDestructorType` struct
+    pub type AzStyleTransitionPropertyVecDestructorType = extern "C" fn(&mut AzStyleTransitionPropertyVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleTimeVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTimeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleTimeVecDestructorType),
+    }
+
+    /// `AzStyleTimeVecDestructorType` struct
+    pub type AzStyleTimeVecDestructorType = extern "C" fn(&mut AzStyleTimeVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleTimingFunctionVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTimingFunctionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleTimingFunctionVecDestructorType),
+    }
+
+    /// `AzStyleTimingFunctionVecDestructorType` struct
+    pub type AzStyleTimingFunctionVecDestructorType = extern "C" fn(&mut AzStyleTimingFunctionVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationNameVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationNameVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationNameVecDestructorType),
+    }
+
+    /// `AzStyleAnimationNameVecDestructorType` struct
+    pub type AzStyleAnimationNameVecDestructorType = extern "C" fn(&mut AzStyleAnimationNameVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationIterationCountVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationIterationCountVecDestructorType),
+    }
+
+    /// `AzStyleAnimationIterationCountVecDestructorType` struct
+    pub type AzStyleAnimationIterationCountVecDestructorType = extern "C" fn(&mut AzStyleAnimationIterationCountVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationDirectionVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationDirectionVecDestructorType),
+    }
+
+    /// `AzStyleAnimationDirectionVecDestructorType` struct
+    pub type AzStyleAnimationDirectionVecDestructorType = extern "C" fn(&mut AzStyleAnimationDirectionVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationFillModeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationFillModeVecDestructorType),
+    }
+
+    /// `AzStyleAnimationFillModeVecDestructorType` struct
+    pub type AzStyleAnimationFillModeVecDestructorType = extern "C" fn(&mut AzStyleAnimationFillModeVec);
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationPlayStateVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationPlayStateVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzStyleAnimationPlayStateVecDestructorType),
+    }
+
+    /// `AzStyleAnimationPlayStateVecDestructorType` struct
+    pub type AzStyleAnimationPlayStateVecDestructorType = extern "C" fn(&mut AzStyleAnimationPlayStateVec);
+
+    /// Re-export of rust-allocated (stack based) `GridTrackSizingVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzGridTrackSizingVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzGridTrackSizingVecDestructorType),
+    }
+
+    /// `AzGridTrackSizingVecDestructorType` struct
+    pub type AzGridTrackSizingVecDestructorType = extern "C" fn(&mut AzGridTrackSizingVec);
+
     /// Re-export of rust-allocated (stack based) `CssPropertyVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzCssPropertyVecDestructor {
@@ -8104,6 +10031,61 @@ mod test_sizes {
     /// `AzStylesheetVecDestructorType` struct
     pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
 
+    /// Re-export of rust-allocated (stack based) `CssKeyframesVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssKeyframesVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssKeyframesVecDestructorType),
+    }
+
+    /// `AzCssKeyframesVecDestructorType` struct
+    pub type AzCssKeyframesVecDestructorType = extern "C" fn(&mut AzCssKeyframesVec);
+
+    /// Re-export of rust-allocated (stack based) `CssKeyframeVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssKeyframeVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssKeyframeVecDestructorType),
+    }
+
+    /// `AzCssKeyframeVecDestructorType` struct
+    pub type AzCssKeyframeVecDestructorType = extern "C" fn(&mut AzCssKeyframeVec);
+
+    /// Re-export of rust-allocated (stack based) `CssMediaRuleVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssMediaRuleVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssMediaRuleVecDestructorType),
+    }
+
+    /// `AzCssMediaRuleVecDestructorType` struct
+    pub type AzCssMediaRuleVecDestructorType = extern "C" fn(&mut AzCssMediaRuleVec);
+
+    /// Re-export of rust-allocated (stack based) `CssMediaQueryVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssMediaQueryVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssMediaQueryVecDestructorType),
+    }
+
+    /// `AzCssMediaQueryVecDestructorType` struct
+    pub type AzCssMediaQueryVecDestructorType = extern "C" fn(&mut AzCssMediaQueryVec);
+
+    /// Re-export of rust-allocated (stack based) `CssMediaFeatureVecDestructor` struct
+    #[repr(C, u8)]
+    pub enum AzCssMediaFeatureVecDestructor {
+        DefaultRust,
+        NoDestructor,
+        External(AzCssMediaFeatureVecDestructorType),
+    }
+
+    /// `AzCssMediaFeatureVecDestructorType` struct
+    pub type AzCssMediaFeatureVecDestructorType = extern "C" fn(&mut AzCssMediaFeatureVec);
+
     /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
     #[repr(C, u8)]
     pub enum AzCssRuleBlockVecDestructor {
@@ -8377,6 +10359,8 @@ mod test_sizes {
     pub struct AzSystemCallbacks {
         pub create_thread_fn: AzCreateThreadFn,
         pub get_system_time_fn: AzGetSystemTimeFn,
+        pub get_clipboard_fn: AzGetClipboardFn,
+        pub set_clipboard_fn: AzSetClipboardFn,
     }
 
     /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
@@ -8509,6 +10493,7 @@ mod test_sizes {
         pub bounds: AzLogicalRect,
         pub unicode_codepoint: AzOptionChar,
         pub glyph_index: u32,
+        pub font_index: usize,
     }
 
     /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
@@ -8530,6 +10515,8 @@ mod test_sizes {
         pub char_index_relative_to_line: usize,
         pub glyph_index_relative_to_word: usize,
         pub char_index_relative_to_word: usize,
+        pub is_rtl: bool,
+        pub cursor_index_relative_to_text: usize,
     }
 
     /// Re-export of rust-allocated (stack based) `IFrameCallbackInfo` struct
@@ -8577,6 +10564,13 @@ mod test_sizes {
         Focus(AzFocusEventFilter),
     }
 
+    /// Re-export of rust-allocated (stack based) `TooltipDom` struct
+    #[repr(C)]
+    pub struct AzTooltipDom {
+        pub data: AzRefAny,
+        pub callback: AzTooltipCallback,
+    }
+
     /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
     #[repr(C)]
     pub struct AzMenuCallback {
@@ -8600,6 +10594,15 @@ mod test_sizes {
         Pattern(AzCssNthChildPattern),
     }
 
+    /// Range of a numeric media feature, i.e. `(min-width: 600px)` or `(400px < width <= 700px)`
+    #[repr(C)]
+    pub struct AzCssMediaRange {
+        pub min: AzOptionF32,
+        pub min_inclusive: bool,
+        pub max: AzOptionF32,
+        pub max_inclusive: bool,
+    }
+
     /// Re-export of rust-allocated (stack based) `PixelValue` struct
     #[repr(C)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
@@ -8657,6 +10660,42 @@ mod test_sizes {
         Arithmetic([AzFloatValue;4]),
     }
 
+    /// One end of a grid track size, i.e. the `min` or `max` of a `minmax(min, max)`
+    #[repr(C, u8)]
+    pub enum AzGridTrackBreadth {
+        Fixed(AzPixelValue),
+        Fraction(AzFloatValue),
+        Auto,
+        MinContent,
+        MaxContent,
+    }
+
+    /// Single track size in a `grid-template-columns`, `grid-template-rows`, `grid-auto-columns` or `grid-auto-rows` attribute
+    #[repr(C)]
+    pub struct AzGridTrackSizing {
+        pub min: AzGridTrackBreadth,
+        pub max: AzGridTrackBreadth,
+    }
+
+    /// Re-export of rust-allocated (stack based) `GridPlacement` struct
+    #[repr(C)]
+    pub struct AzGridPlacement {
+        pub start: AzGridLine,
+        pub end: AzGridLine,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutRowGap` struct
+    #[repr(C)]
+    pub struct AzLayoutRowGap {
+        pub inner: AzPixelValue,
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutColumnGap` struct
+    #[repr(C)]
+    pub struct AzLayoutColumnGap {
+        pub inner: AzPixelValue,
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutBottom` struct
     #[repr(C)]
     pub struct AzLayoutBottom {
@@ -9082,6 +11121,49 @@ mod test_sizes {
         pub y: AzPercentageValue,
     }
 
+    /// Control points of a `cubic-bezier(x1, y1, x2, y2)` easing function
+    #[repr(C)]
+    pub struct AzStyleCubicBezier {
+        pub x1: AzFloatValue,
+        pub y1: AzFloatValue,
+        pub x2: AzFloatValue,
+        pub y2: AzFloatValue,
+    }
+
+    /// Represents a `steps(n, position)` easing function
+    #[repr(C)]
+    pub struct AzStyleSteps {
+        pub count: u32,
+        pub position: AzStyleStepPosition,
+    }
+
+    /// Represents an `<easing-function>` in a `transition-timing-function` or `animation-timing-function` attribute
+    #[repr(C, u8)]
+    pub enum AzStyleTimingFunction {
+        Ease,
+        Linear,
+        EaseIn,
+        EaseOut,
+        EaseInOut,
+        CubicBezier(AzStyleCubicBezier),
+        Steps(AzStyleSteps),
+    }
+
+    /// Single entry in a `transition-property` attribute
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionProperty {
+        All,
+        Property(AzCssPropertyType),
+        Shorthand(AzCombinedCssPropertyType),
+    }
+
+    /// Single entry in an `animation-iteration-count` attribute
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationIterationCount {
+        Infinite,
+        Count(AzFloatValue),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
     #[repr(C)]
     pub struct AzStyleTextColor {
@@ -9114,6 +11196,56 @@ mod test_sizes {
         Exact(AzLayoutAlignContent),
     }
 
+    /// Re-export of rust-allocated (stack based) `GridTrackSizingValue` struct
+    #[repr(C, u8)]
+    pub enum AzGridTrackSizingValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzGridTrackSizing),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutGridAutoFlowValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutGridAutoFlowValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutGridAutoFlow),
+    }
+
+    /// Re-export of rust-allocated (stack based) `GridPlacementValue` struct
+    #[repr(C, u8)]
+    pub enum AzGridPlacementValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzGridPlacement),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutRowGapValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutRowGapValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutRowGap),
+    }
+
+    /// Re-export of rust-allocated (stack based) `LayoutColumnGapValue` struct
+    #[repr(C, u8)]
+    pub enum AzLayoutColumnGapValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzLayoutColumnGap),
+    }
+
     /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
     #[repr(C, u8)]
     pub enum AzLayoutAlignItemsValue {
@@ -9624,59 +11756,99 @@ mod test_sizes {
         Exact(AzStyleTextAlign),
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
+    /// Re-export of rust-allocated (stack based) `StyleDirectionValue` struct
     #[repr(C, u8)]
-    pub enum AzStyleTextColorValue {
+    pub enum AzStyleDirectionValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzStyleTextColor),
+        Exact(AzStyleDirection),
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleWordSpacingValue` struct
+    /// Re-export of rust-allocated (stack based) `StyleWordBreakValue` struct
     #[repr(C, u8)]
-    pub enum AzStyleWordSpacingValue {
+    pub enum AzStyleWordBreakValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzStyleWordSpacing),
+        Exact(AzStyleWordBreak),
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleOpacityValue` struct
+    /// Re-export of rust-allocated (stack based) `StyleOverflowWrapValue` struct
     #[repr(C, u8)]
-    pub enum AzStyleOpacityValue {
+    pub enum AzStyleOverflowWrapValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzStyleOpacity),
+        Exact(AzStyleOverflowWrap),
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleTransformOriginValue` struct
+    /// Re-export of rust-allocated (stack based) `StyleHyphensValue` struct
     #[repr(C, u8)]
-    pub enum AzStyleTransformOriginValue {
+    pub enum AzStyleHyphensValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzStyleTransformOrigin),
+        Exact(AzStyleHyphens),
     }
 
-    /// Re-export of rust-allocated (stack based) `StylePerspectiveOriginValue` struct
+    /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
     #[repr(C, u8)]
-    pub enum AzStylePerspectiveOriginValue {
+    pub enum AzStyleTextColorValue {
         Auto,
         None,
         Inherit,
         Initial,
-        Exact(AzStylePerspectiveOrigin),
+        Exact(AzStyleTextColor),
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibilityValue` struct
+    /// Re-export of rust-allocated (stack based) `StyleWordSpacingValue` struct
     #[repr(C, u8)]
-    pub enum AzStyleBackfaceVisibilityValue {
+    pub enum AzStyleWordSpacingValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleWordSpacing),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleOpacityValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleOpacityValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleOpacity),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransformOriginValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransformOriginValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransformOrigin),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StylePerspectiveOriginValue` struct
+    #[repr(C, u8)]
+    pub enum AzStylePerspectiveOriginValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStylePerspectiveOrigin),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleBackfaceVisibilityValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleBackfaceVisibilityValue {
         Auto,
         None,
         Inherit,
@@ -9694,6 +11866,27 @@ mod test_sizes {
         Exact(AzStyleMixBlendMode),
     }
 
+    /// Re-export of rust-allocated (stack based) `RibbonItemState` struct
+    #[repr(C)]
+    pub struct AzRibbonItemState {
+        pub toggled: bool,
+        pub choice: AzOptionUsize,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonOnItemClick` struct
+    #[repr(C)]
+    pub struct AzRibbonOnItemClick {
+        pub data: AzRefAny,
+        pub callback: AzRibbonOnItemClickCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonOnTabClicked` struct
+    #[repr(C)]
+    pub struct AzRibbonOnTabClicked {
+        pub data: AzRefAny,
+        pub callback: AzRibbonOnTabClickedCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `ButtonOnClick` struct
     #[repr(C)]
     pub struct AzButtonOnClick {
@@ -9763,6 +11956,13 @@ mod test_sizes {
         pub valid: AzTextInputValid,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextAreaOnTextChange` struct
+    #[repr(C)]
+    pub struct AzTextAreaOnTextChange {
+        pub data: AzRefAny,
+        pub callback: AzTextAreaOnTextChangeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputOnValueChange` struct
     #[repr(C)]
     pub struct AzNumberInputOnValueChange {
@@ -9777,6 +11977,23 @@ mod test_sizes {
         pub callback: AzNumberInputOnFocusLostCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `ProgressBarState` struct
+    #[repr(C)]
+    pub struct AzProgressBarState {
+        pub percent_done: f32,
+        pub display_percentage: bool,
+        pub mode: AzProgressBarMode,
+    }
+
+    /// Circular, endlessly rotating "busy" indicator for operations without a known total
+    #[repr(C)]
+    pub struct AzSpinner {
+        pub size: AzPixelValue,
+        pub thickness: AzPixelValue,
+        pub color: AzColorU,
+        pub track_color: AzColorU,
+    }
+
     /// Re-export of rust-allocated (stack based) `TabOnClick` struct
     #[repr(C)]
     pub struct AzTabOnClick {
@@ -9784,6 +12001,48 @@ mod test_sizes {
         pub callback: AzTabOnClickCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `TabOnClose` struct
+    #[repr(C)]
+    pub struct AzTabOnClose {
+        pub data: AzRefAny,
+        pub callback: AzTabOnCloseCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabOnReorder` struct
+    #[repr(C)]
+    pub struct AzTabOnReorder {
+        pub data: AzRefAny,
+        pub callback: AzTabOnReorderCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TabOnContextMenu` struct
+    #[repr(C)]
+    pub struct AzTabOnContextMenu {
+        pub data: AzRefAny,
+        pub callback: AzTabOnContextMenuCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SplitterOnResize` struct
+    #[repr(C)]
+    pub struct AzSplitterOnResize {
+        pub data: AzRefAny,
+        pub callback: AzSplitterOnResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+    #[repr(C)]
+    pub struct AzSliderOnValueChange {
+        pub data: AzRefAny,
+        pub callback: AzSliderOnValueChangeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RangeSliderOnValueChange` struct
+    #[repr(C)]
+    pub struct AzRangeSliderOnValueChange {
+        pub data: AzRefAny,
+        pub callback: AzRangeSliderOnValueChangeCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeAdded` struct
     #[repr(C)]
     pub struct AzNodeGraphOnNodeAdded {
@@ -9875,6 +12134,48 @@ mod test_sizes {
         pub callback: AzListViewOnRowClickCallback,
     }
 
+    /// Re-export of rust-allocated (stack based) `ListViewOnColumnResize` struct
+    #[repr(C)]
+    pub struct AzListViewOnColumnResize {
+        pub data: AzRefAny,
+        pub callback: AzListViewOnColumnResizeCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `ListViewRowProvider` struct
+    #[repr(C)]
+    pub struct AzListViewRowProvider {
+        pub data: AzRefAny,
+        pub callback: AzListViewRowProviderCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnExpand` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnExpand {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnExpandCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnSelect` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnSelect {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnSelectCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnDoubleClick` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnDoubleClick {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnDoubleClickCallback,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewOnContextMenu` struct
+    #[repr(C)]
+    pub struct AzTreeViewOnContextMenu {
+        pub data: AzRefAny,
+        pub callback: AzTreeViewOnContextMenuCallback,
+    }
+
     /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
     #[repr(C)]
     pub struct AzDropDownOnChoiceChange {
@@ -10050,6 +12351,24 @@ mod test_sizes {
         pub callback: AzWriteBackCallback,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TreeViewNodeId>`
+    #[repr(C)]
+    pub struct AzTreeViewNodeIdVec {
+        pub(crate) ptr: *const AzTreeViewNodeId,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewNodeIdVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+    #[repr(C)]
+    pub struct AzSplitterPaneStateVec {
+        pub(crate) ptr: *const AzSplitterPaneState,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSplitterPaneStateVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
     #[repr(C)]
     pub struct AzLogicalRectVec {
@@ -10095,6 +12414,15 @@ mod test_sizes {
         pub destructor: AzAccessibilityStateVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<AccessibilityAction>`
+    #[repr(C)]
+    pub struct AzAccessibilityActionVec {
+        pub(crate) ptr: *const AzAccessibilityAction,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzAccessibilityActionVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<MenuItem>`
     #[repr(C)]
     pub struct AzMenuItemVec {
@@ -10176,6 +12504,78 @@ mod test_sizes {
         pub destructor: AzStyleBackgroundSizeVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+    #[repr(C)]
+    pub struct AzStyleTransitionPropertyVec {
+        pub(crate) ptr: *const AzStyleTransitionProperty,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTransitionPropertyVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTime>`
+    #[repr(C)]
+    pub struct AzStyleTimeVec {
+        pub(crate) ptr: *const AzStyleTime,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTimeVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleTimingFunction>`
+    #[repr(C)]
+    pub struct AzStyleTimingFunctionVec {
+        pub(crate) ptr: *const AzStyleTimingFunction,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleTimingFunctionVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+    #[repr(C)]
+    pub struct AzStyleAnimationIterationCountVec {
+        pub(crate) ptr: *const AzStyleAnimationIterationCount,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationIterationCountVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+    #[repr(C)]
+    pub struct AzStyleAnimationDirectionVec {
+        pub(crate) ptr: *const AzStyleAnimationDirection,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationDirectionVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+    #[repr(C)]
+    pub struct AzStyleAnimationFillModeVec {
+        pub(crate) ptr: *const AzStyleAnimationFillMode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationFillModeVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationPlayState>`
+    #[repr(C)]
+    pub struct AzStyleAnimationPlayStateVec {
+        pub(crate) ptr: *const AzStyleAnimationPlayState,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationPlayStateVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
+    #[repr(C)]
+    pub struct AzGridTrackSizingVec {
+        pub(crate) ptr: *const AzGridTrackSizing,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzGridTrackSizingVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `SvgVertex`
     #[repr(C)]
     pub struct AzSvgVertexVec {
@@ -10327,6 +12727,41 @@ mod test_sizes {
         Some(AzListViewOnRowClick),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewNodeId` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewNodeId {
+        None,
+        Some(AzTreeViewNodeId),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnExpand` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnExpand {
+        None,
+        Some(AzTreeViewOnExpand),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelect` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnSelect {
+        None,
+        Some(AzTreeViewOnSelect),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnDoubleClick` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnDoubleClick {
+        None,
+        Some(AzTreeViewOnDoubleClick),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTreeViewOnContextMenu` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTreeViewOnContextMenu {
+        None,
+        Some(AzTreeViewOnContextMenu),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnClick` struct
     #[repr(C, u8)]
     pub enum AzOptionListViewOnColumnClick {
@@ -10341,6 +12776,20 @@ mod test_sizes {
         Some(AzListViewOnLazyLoadScroll),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnResize` struct
+    #[repr(C, u8)]
+    pub enum AzOptionListViewOnColumnResize {
+        None,
+        Some(AzListViewOnColumnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionListViewRowProvider` struct
+    #[repr(C, u8)]
+    pub enum AzOptionListViewRowProvider {
+        None,
+        Some(AzListViewRowProvider),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionPixelValueNoPercent` struct
     #[repr(C, u8)]
     pub enum AzOptionPixelValueNoPercent {
@@ -10432,6 +12881,62 @@ mod test_sizes {
         Some(AzTabOnClick),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTabOnClose` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTabOnClose {
+        None,
+        Some(AzTabOnClose),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabOnReorder` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTabOnReorder {
+        None,
+        Some(AzTabOnReorder),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionTabOnContextMenu` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTabOnContextMenu {
+        None,
+        Some(AzTabOnContextMenu),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionRibbonOnTabClicked` struct
+    #[repr(C, u8)]
+    pub enum AzOptionRibbonOnTabClicked {
+        None,
+        Some(AzRibbonOnTabClicked),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionRibbonOnItemClick` struct
+    #[repr(C, u8)]
+    pub enum AzOptionRibbonOnItemClick {
+        None,
+        Some(AzRibbonOnItemClick),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSplitterOnResize` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSplitterOnResize {
+        None,
+        Some(AzSplitterOnResize),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionSliderOnValueChange {
+        None,
+        Some(AzSliderOnValueChange),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionRangeSliderOnValueChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionRangeSliderOnValueChange {
+        None,
+        Some(AzRangeSliderOnValueChange),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionFileInputOnPathChange` struct
     #[repr(C, u8)]
     pub enum AzOptionFileInputOnPathChange {
@@ -10474,6 +12979,13 @@ mod test_sizes {
         Some(AzTextInputSelection),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionTextAreaOnTextChange` struct
+    #[repr(C, u8)]
+    pub enum AzOptionTextAreaOnTextChange {
+        None,
+        Some(AzTextAreaOnTextChange),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionNumberInputOnFocusLost` struct
     #[repr(C, u8)]
     pub enum AzOptionNumberInputOnFocusLost {
@@ -10656,6 +13168,13 @@ mod test_sizes {
         Some(AzLogicalPosition),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+    #[repr(C, u8)]
+    pub enum AzOptionLogicalRect {
+        None,
+        Some(AzLogicalRect),
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionPhysicalPositionI32` struct
     #[repr(C, u8)]
     pub enum AzOptionPhysicalPositionI32 {
@@ -10823,15 +13342,6 @@ mod test_sizes {
         pub max_dimensions: AzOptionLogicalSize,
     }
 
-    /// Current keyboard state, stores what keys / characters have been pressed
-    #[repr(C)]
-    pub struct AzKeyboardState {
-        pub current_char: AzOptionChar,
-        pub current_virtual_keycode: AzOptionVirtualKeyCode,
-        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
-        pub pressed_scancodes: AzScanCodeVec,
-    }
-
     /// Current mouse / cursor state
     #[repr(C)]
     pub struct AzMouseState {
@@ -10857,6 +13367,7 @@ mod test_sizes {
     pub struct AzInlineTextContents {
         pub glyphs: AzInlineGlyphVec,
         pub bounds: AzLogicalRect,
+        pub is_rtl: bool,
     }
 
     /// Re-export of rust-allocated (stack based) `ResolvedTextLayoutOptions` struct
@@ -10870,6 +13381,10 @@ mod test_sizes {
         pub max_horizontal_width: AzOptionF32,
         pub leading: AzOptionF32,
         pub holes: AzLogicalRectVec,
+        pub direction: AzStyleDirection,
+        pub word_break: AzStyleWordBreak,
+        pub overflow_wrap: AzStyleOverflowWrap,
+        pub hyphens: AzStyleHyphens,
     }
 
     /// Easing function of the animation (ease-in, ease-out, ease-in-out, custom)
@@ -10949,6 +13464,16 @@ mod test_sizes {
         Focus,
     }
 
+    /// One media feature, i.e. `(min-width: 600px)` or `(prefers-color-scheme: dark)`
+    #[repr(C, u8)]
+    pub enum AzCssMediaFeature {
+        Width(AzCssMediaRange),
+        Height(AzCssMediaRange),
+        Resolution(AzCssMediaRange),
+        Orientation(AzCssMediaOrientation),
+        PrefersColorScheme(AzCssColorScheme),
+    }
+
     /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
     #[repr(C, u8)]
     pub enum AzAnimationInterpolationFunction {
@@ -11002,39 +13527,119 @@ mod test_sizes {
         pub stops: AzNormalizedLinearColorStopVec,
     }
 
-    /// Re-export of rust-allocated (stack based) `ConicGradient` struct
-    #[repr(C)]
-    pub struct AzConicGradient {
-        pub extend_mode: AzExtendMode,
-        pub center: AzStyleBackgroundPosition,
-        pub angle: AzAngleValue,
-        pub stops: AzNormalizedRadialColorStopVec,
+    /// Re-export of rust-allocated (stack based) `ConicGradient` struct
+    #[repr(C)]
+    pub struct AzConicGradient {
+        pub extend_mode: AzExtendMode,
+        pub center: AzStyleBackgroundPosition,
+        pub angle: AzAngleValue,
+        pub stops: AzNormalizedRadialColorStopVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransform` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransform {
+        Matrix(AzStyleTransformMatrix2D),
+        Matrix3D(AzStyleTransformMatrix3D),
+        Translate(AzStyleTransformTranslate2D),
+        Translate3D(AzStyleTransformTranslate3D),
+        TranslateX(AzPixelValue),
+        TranslateY(AzPixelValue),
+        TranslateZ(AzPixelValue),
+        Rotate(AzAngleValue),
+        Rotate3D(AzStyleTransformRotate3D),
+        RotateX(AzAngleValue),
+        RotateY(AzAngleValue),
+        RotateZ(AzAngleValue),
+        Scale(AzStyleTransformScale2D),
+        Scale3D(AzStyleTransformScale3D),
+        ScaleX(AzPercentageValue),
+        ScaleY(AzPercentageValue),
+        ScaleZ(AzPercentageValue),
+        Skew(AzStyleTransformSkew2D),
+        SkewX(AzPercentageValue),
+        SkewY(AzPercentageValue),
+        Perspective(AzPixelValue),
+    }
+
+    /// Re-export of rust-allocated (stack based) `GridTrackSizingVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzGridTrackSizingVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzGridTrackSizingVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTransitionPropertyVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTransitionPropertyVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTimeVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTimeVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTimeVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleTimingFunctionVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleTimingFunctionVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleTimingFunctionVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationIterationCountVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationIterationCountVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationDirectionVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationDirectionVec),
+    }
+
+    /// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationFillModeVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationFillModeVec),
     }
 
-    /// Re-export of rust-allocated (stack based) `StyleTransform` struct
+    /// Re-export of rust-allocated (stack based) `StyleAnimationPlayStateVecValue` struct
     #[repr(C, u8)]
-    pub enum AzStyleTransform {
-        Matrix(AzStyleTransformMatrix2D),
-        Matrix3D(AzStyleTransformMatrix3D),
-        Translate(AzStyleTransformTranslate2D),
-        Translate3D(AzStyleTransformTranslate3D),
-        TranslateX(AzPixelValue),
-        TranslateY(AzPixelValue),
-        TranslateZ(AzPixelValue),
-        Rotate(AzAngleValue),
-        Rotate3D(AzStyleTransformRotate3D),
-        RotateX(AzAngleValue),
-        RotateY(AzAngleValue),
-        RotateZ(AzAngleValue),
-        Scale(AzStyleTransformScale2D),
-        Scale3D(AzStyleTransformScale3D),
-        ScaleX(AzPercentageValue),
-        ScaleY(AzPercentageValue),
-        ScaleZ(AzPercentageValue),
-        Skew(AzStyleTransformSkew2D),
-        SkewX(AzPercentageValue),
-        SkewY(AzPercentageValue),
-        Perspective(AzPixelValue),
+    pub enum AzStyleAnimationPlayStateVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationPlayStateVec),
     }
 
     /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
@@ -11074,6 +13679,17 @@ mod test_sizes {
         pub on_toggle: AzOptionCheckBoxOnToggle,
     }
 
+    /// Single step in the undo / redo history of a `TextArea`: `removed` was replaced with `inserted` at `position`
+    #[repr(C)]
+    pub struct AzTextAreaEdit {
+        pub position: usize,
+        pub removed: AzU32Vec,
+        pub inserted: AzU32Vec,
+        pub kind: AzTextAreaEditKind,
+        pub cursor_before: usize,
+        pub selection_anchor_before: AzOptionUsize,
+    }
+
     /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
     #[repr(C)]
     pub struct AzNumberInputStateWrapper {
@@ -11082,6 +13698,13 @@ mod test_sizes {
         pub on_focus_lost: AzOptionNumberInputOnFocusLost,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitterState` struct
+    #[repr(C)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub struct AzSplitterState {
+        pub panes: AzSplitterPaneStateVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeGraphCallbacks` struct
     #[repr(C)]
     pub struct AzNodeGraphCallbacks {
@@ -11251,6 +13874,15 @@ mod test_sizes {
         pub destructor: AzListViewRowVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TextAreaEdit>`
+    #[repr(C)]
+    pub struct AzTextAreaEditVec {
+        pub(crate) ptr: *const AzTextAreaEdit,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTextAreaEditVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<StyleFilter>`
     #[repr(C)]
     pub struct AzStyleFilterVec {
@@ -11305,6 +13937,15 @@ mod test_sizes {
         pub destructor: AzSvgPathElementVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssMediaFeature>`
+    #[repr(C)]
+    pub struct AzCssMediaFeatureVec {
+        pub(crate) ptr: *const AzCssMediaFeature,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssMediaFeatureVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `StringVec`
     #[repr(C)]
     pub struct AzStringVec {
@@ -11360,13 +14001,6 @@ mod test_sizes {
         Some(AzMouseState),
     }
 
-    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
-    #[repr(C, u8)]
-    pub enum AzOptionKeyboardState {
-        None,
-        Some(AzKeyboardState),
-    }
-
     /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
     #[repr(C, u8)]
     pub enum AzOptionStringVec {
@@ -11460,6 +14094,20 @@ mod test_sizes {
         pub pos: AzSvgParseErrorPosition,
     }
 
+    /// Content of the system clipboard, tagged with its MIME type
+    #[repr(C)]
+    pub struct AzClipboardContent {
+        pub mime_type: AzString,
+        pub data: AzU8Vec,
+    }
+
+    /// Preedit text of an input method, i.e. the pinyin or kana that was typed before the user selected one of the candidates
+    #[repr(C)]
+    pub struct AzImeComposition {
+        pub text: AzString,
+        pub cursor: usize,
+    }
+
     /// Window configuration specific to Win32
     #[repr(C)]
     pub struct AzWindowsWindowOptions {
@@ -11585,6 +14233,13 @@ mod test_sizes {
         pub default_action: AzOptionString,
     }
 
+    /// Re-export of rust-allocated (stack based) `TooltipContent` struct
+    #[repr(C, u8)]
+    pub enum AzTooltipContent {
+        Text(AzString),
+        Dom(AzTooltipDom),
+    }
+
     /// Re-export of rust-allocated (stack based) `IdOrClass` struct
     #[repr(C, u8)]
     pub enum AzIdOrClass {
@@ -11615,6 +14270,14 @@ mod test_sizes {
         Children,
     }
 
+    /// One query of a media query list, i.e. `screen and (min-width: 600px)`
+    #[repr(C)]
+    pub struct AzCssMediaQuery {
+        pub negated: bool,
+        pub media_type: AzCssMediaType,
+        pub features: AzCssMediaFeatureVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundContent {
@@ -11653,6 +14316,13 @@ mod test_sizes {
         Ref(AzFontRef),
     }
 
+    /// Single entry in an `animation-name` attribute, refers to a `@keyframes` block
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationName {
+        None,
+        Name(AzString),
+    }
+
     /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
     #[repr(C, u8)]
     pub enum AzScrollbarStyleValue {
@@ -11683,6 +14353,20 @@ mod test_sizes {
         Exact(AzStyleFilterVec),
     }
 
+    /// Re-export of rust-allocated (stack based) `RibbonItem` struct
+    #[repr(C)]
+    pub struct AzRibbonItem {
+        pub kind: AzRibbonItemKind,
+        pub label: AzString,
+        pub icon: AzOptionImageRef,
+        pub tooltip: AzOptionString,
+        pub key_tip: AzOptionString,
+        pub enabled: bool,
+        pub toggled: bool,
+        pub choices: AzStringVec,
+        pub on_click: AzOptionRibbonOnItemClick,
+    }
+
     /// Re-export of rust-allocated (stack based) `FileInputState` struct
     #[repr(C)]
     pub struct AzFileInputState {
@@ -11707,12 +14391,28 @@ mod test_sizes {
         pub cursor_pos: usize,
     }
 
+    /// Re-export of rust-allocated (stack based) `TextAreaState` struct
+    #[repr(C)]
+    pub struct AzTextAreaState {
+        pub text: AzU32Vec,
+        pub cursor: usize,
+        pub selection_anchor: AzOptionUsize,
+        pub preferred_x: AzOptionF32,
+        pub scroll_offset: f32,
+        pub undo_stack: AzTextAreaEditVec,
+        pub redo_stack: AzTextAreaEditVec,
+        pub last_edit_time: AzOptionInstant,
+    }
+
     /// Re-export of rust-allocated (stack based) `TabHeader` struct
     #[repr(C)]
     pub struct AzTabHeader {
         pub tabs: AzStringVec,
         pub active_tab: usize,
         pub on_click: AzOptionTabOnClick,
+        pub on_close: AzOptionTabOnClose,
+        pub on_reorder: AzOptionTabOnReorder,
+        pub on_context_menu: AzOptionTabOnContextMenu,
     }
 
     /// Re-export of rust-allocated (stack based) `NodeTypeFieldValue` struct
@@ -11756,6 +14456,11 @@ mod test_sizes {
         pub on_lazy_load_scroll: AzOptionListViewOnLazyLoadScroll,
         pub on_column_click: AzOptionListViewOnColumnClick,
         pub on_row_click: AzOptionListViewOnRowClick,
+        pub row_count: usize,
+        pub row_height: AzPixelValueNoPercent,
+        pub column_widths: AzF32Vec,
+        pub row_provider: AzOptionListViewRowProvider,
+        pub on_column_resize: AzOptionListViewOnColumnResize,
     }
 
     /// Re-export of rust-allocated (stack based) `ListViewState` struct
@@ -11767,12 +14472,29 @@ mod test_sizes {
         pub scroll_offset: AzPixelValueNoPercent,
         pub current_scroll_position: AzLogicalPosition,
         pub current_content_height: AzLogicalSize,
+        pub row_count: usize,
+        pub visible_rows: AzListViewRowRange,
+        pub column_widths: AzF32Vec,
     }
 
-    /// Re-export of rust-allocated (stack based) `TreeView` struct
+    /// Re-export of rust-allocated (stack based) `TreeViewNode` struct
     #[repr(C)]
-    pub struct AzTreeView {
-        pub root: AzString,
+    pub struct AzTreeViewNode {
+        pub id: AzTreeViewNodeId,
+        pub parent: AzOptionTreeViewNodeId,
+        pub label: AzString,
+        pub icon: AzOptionImageRef,
+        pub has_children: bool,
+    }
+
+    /// Re-export of rust-allocated (stack based) `TreeViewState` struct
+    #[repr(C)]
+    pub struct AzTreeViewState {
+        pub expanded: AzTreeViewNodeIdVec,
+        pub selected: AzTreeViewNodeIdVec,
+        pub focused: AzOptionTreeViewNodeId,
+        pub selection_anchor: AzOptionTreeViewNodeId,
+        pub last_click_time: AzOptionInstant,
     }
 
     /// Re-export of rust-allocated (stack based) `DropDown` struct
@@ -11780,6 +14502,7 @@ mod test_sizes {
     pub struct AzDropDown {
         pub choices: AzStringVec,
         pub selected: usize,
+        pub disabled_choices: AzU32Vec,
         pub on_choice_change: AzOptionDropDownOnChoiceChange,
     }
 
@@ -11913,6 +14636,24 @@ mod test_sizes {
         pub value: AzFmtValue,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<TreeViewNode>`
+    #[repr(C)]
+    pub struct AzTreeViewNodeVec {
+        pub(crate) ptr: *const AzTreeViewNode,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzTreeViewNodeVecDestructor,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<RibbonItem>`
+    #[repr(C)]
+    pub struct AzRibbonItemVec {
+        pub(crate) ptr: *const AzRibbonItem,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzRibbonItemVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<StyleFontFamily>`
     #[repr(C)]
     pub struct AzStyleFontFamilyVec {
@@ -11967,6 +14708,15 @@ mod test_sizes {
         pub destructor: AzStyleBackgroundContentVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+    #[repr(C)]
+    pub struct AzStyleAnimationNameVec {
+        pub(crate) ptr: *const AzStyleAnimationName,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStyleAnimationNameVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<SvgPath>`
     #[repr(C)]
     pub struct AzSvgPathVec {
@@ -11994,6 +14744,15 @@ mod test_sizes {
         pub destructor: AzCssPathSelectorVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssMediaQuery>`
+    #[repr(C)]
+    pub struct AzCssMediaQueryVec {
+        pub(crate) ptr: *const AzCssMediaQuery,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssMediaQueryVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `CallbackData`
     #[repr(C)]
     pub struct AzCallbackDataVec {
@@ -12049,6 +14808,20 @@ mod test_sizes {
         Some(AzWaylandTheme),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionImeComposition` struct
+    #[repr(C, u8)]
+    pub enum AzOptionImeComposition {
+        None,
+        Some(AzImeComposition),
+    }
+
+    /// Re-export of rust-allocated (stack based) `OptionClipboardContent` struct
+    #[repr(C, u8)]
+    pub enum AzOptionClipboardContent {
+        None,
+        Some(AzClipboardContent),
+    }
+
     /// Re-export of rust-allocated (stack based) `ResultRawImageDecodeImageError` struct
     #[repr(C, u8)]
     pub enum AzResultRawImageDecodeImageError {
@@ -12074,9 +14847,20 @@ mod test_sizes {
         InvalidCharacterData,
     }
 
+    /// Current keyboard state, stores what keys / characters have been pressed
+    #[repr(C)]
+    pub struct AzKeyboardState {
+        pub current_char: AzOptionChar,
+        pub current_virtual_keycode: AzOptionVirtualKeyCode,
+        pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
+        pub pressed_scancodes: AzScanCodeVec,
+        pub ime_composition: AzOptionImeComposition,
+    }
+
     /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
     #[repr(C)]
     pub struct AzLinuxWindowOptions {
+        pub backend: AzLinuxBackend,
         pub x11_visual: AzOptionX11Visual,
         pub x11_screen: AzOptionI32,
         pub x11_wm_classes: AzStringPairVec,
@@ -12098,6 +14882,13 @@ mod test_sizes {
         pub bounds: AzLogicalRect,
     }
 
+    /// Tooltip of a node, shown in an always-on-top popup window once the mouse has rested on the node for `delay`. See `NodeData::set_tooltip`
+    #[repr(C)]
+    pub struct AzTooltip {
+        pub content: AzTooltipContent,
+        pub delay: AzDuration,
+    }
+
     /// Item entry in a menu or menu bar
     #[repr(C, u8)]
     pub enum AzMenuItem {
@@ -12112,6 +14903,16 @@ mod test_sizes {
         pub selectors: AzCssPathSelectorVec,
     }
 
+    /// Re-export of rust-allocated (stack based) `StyleAnimationNameVecValue` struct
+    #[repr(C, u8)]
+    pub enum AzStyleAnimationNameVecValue {
+        Auto,
+        None,
+        Inherit,
+        Initial,
+        Exact(AzStyleAnimationNameVec),
+    }
+
     /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
     #[repr(C, u8)]
     pub enum AzStyleBackgroundContentVecValue {
@@ -12139,6 +14940,10 @@ mod test_sizes {
         FontSize(AzStyleFontSizeValue),
         FontFamily(AzStyleFontFamilyVecValue),
         TextAlign(AzStyleTextAlignValue),
+        Direction(AzStyleDirectionValue),
+        WordBreak(AzStyleWordBreakValue),
+        OverflowWrap(AzStyleOverflowWrapValue),
+        Hyphens(AzStyleHyphensValue),
         LetterSpacing(AzStyleLetterSpacingValue),
         LineHeight(AzStyleLineHeightValue),
         WordSpacing(AzStyleWordSpacingValue),
@@ -12165,6 +14970,15 @@ mod test_sizes {
         JustifyContent(AzLayoutJustifyContentValue),
         AlignItems(AzLayoutAlignItemsValue),
         AlignContent(AzLayoutAlignContentValue),
+        GridTemplateColumns(AzGridTrackSizingVecValue),
+        GridTemplateRows(AzGridTrackSizingVecValue),
+        GridAutoColumns(AzGridTrackSizingValue),
+        GridAutoRows(AzGridTrackSizingValue),
+        GridAutoFlow(AzLayoutGridAutoFlowValue),
+        GridColumn(AzGridPlacementValue),
+        GridRow(AzGridPlacementValue),
+        RowGap(AzLayoutRowGapValue),
+        ColumnGap(AzLayoutColumnGapValue),
         BackgroundContent(AzStyleBackgroundContentVecValue),
         BackgroundPosition(AzStyleBackgroundPositionVecValue),
         BackgroundSize(AzStyleBackgroundSizeVecValue),
@@ -12209,6 +15023,26 @@ mod test_sizes {
         Filter(AzStyleFilterVecValue),
         BackdropFilter(AzStyleFilterVecValue),
         TextShadow(AzStyleBoxShadowValue),
+        TransitionProperty(AzStyleTransitionPropertyVecValue),
+        TransitionDuration(AzStyleTimeVecValue),
+        TransitionTimingFunction(AzStyleTimingFunctionVecValue),
+        TransitionDelay(AzStyleTimeVecValue),
+        AnimationName(AzStyleAnimationNameVecValue),
+        AnimationDuration(AzStyleTimeVecValue),
+        AnimationTimingFunction(AzStyleTimingFunctionVecValue),
+        AnimationDelay(AzStyleTimeVecValue),
+        AnimationIterationCount(AzStyleAnimationIterationCountVecValue),
+        AnimationDirection(AzStyleAnimationDirectionVecValue),
+        AnimationFillMode(AzStyleAnimationFillModeVecValue),
+        AnimationPlayState(AzStyleAnimationPlayStateVecValue),
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonGroup` struct
+    #[repr(C)]
+    pub struct AzRibbonGroup {
+        pub label: AzString,
+        pub icon: AzOptionImageRef,
+        pub items: AzRibbonItemVec,
     }
 
     /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
@@ -12233,6 +15067,13 @@ mod test_sizes {
         pub cursor_animation: AzOptionTimerId,
     }
 
+    /// Multi-line text input with soft wrapping, scrolling and an undo history
+    #[repr(C)]
+    pub struct AzTextArea {
+        pub state: AzTextAreaState,
+        pub on_text_change: AzOptionTextAreaOnTextChange,
+    }
+
     /// Re-export of rust-allocated (stack based) `ProgressBar` struct
     #[repr(C)]
     pub struct AzProgressBar {
@@ -12240,6 +15081,8 @@ mod test_sizes {
         pub height: AzPixelValue,
         pub bar_background: AzStyleBackgroundContentVec,
         pub container_background: AzStyleBackgroundContentVec,
+        pub style: AzProgressBarStyle,
+        pub text_format: AzOptionString,
     }
 
     /// Re-export of rust-allocated (stack based) `NodeTypeIdInfoMap` struct
@@ -12266,6 +15109,18 @@ mod test_sizes {
         pub value: AzNodeTypeFieldValue,
     }
 
+    /// Re-export of rust-allocated (stack based) `TreeView` struct
+    #[repr(C)]
+    pub struct AzTreeView {
+        pub nodes: AzTreeViewNodeVec,
+        pub state: AzTreeViewState,
+        pub selection_mode: AzTreeViewSelectionMode,
+        pub on_expand: AzOptionTreeViewOnExpand,
+        pub on_select: AzOptionTreeViewOnSelect,
+        pub on_double_click: AzOptionTreeViewOnDoubleClick,
+        pub on_context_menu: AzOptionTreeViewOnContextMenu,
+    }
+
     /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
     #[repr(C, u8)]
     pub enum AzCssPropertySource {
@@ -12323,6 +15178,15 @@ mod test_sizes {
         pub text: AzOptionString,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<RibbonGroup>`
+    #[repr(C)]
+    pub struct AzRibbonGroupVec {
+        pub(crate) ptr: *const AzRibbonGroup,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzRibbonGroupVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<NodeTypeIdInfoMap>`
     #[repr(C)]
     pub struct AzNodeTypeIdInfoMapVec {
@@ -12384,6 +15248,13 @@ mod test_sizes {
         Some(AzCssProperty),
     }
 
+    /// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+    #[repr(C, u8)]
+    pub enum AzOptionKeyboardState {
+        None,
+        Some(AzKeyboardState),
+    }
+
     /// Re-export of rust-allocated (stack based) `XmlTextError` struct
     #[repr(C)]
     pub struct AzXmlTextError {
@@ -12512,6 +15383,21 @@ mod test_sizes {
         pub default_value: AzCssProperty,
     }
 
+    /// One keyframe inside of a `@keyframes` block, i.e. `50% { opacity: 0.5; }`
+    #[repr(C)]
+    pub struct AzCssKeyframe {
+        pub offset: AzPercentageValue,
+        pub properties: AzCssPropertyVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `RibbonTab` struct
+    #[repr(C)]
+    pub struct AzRibbonTab {
+        pub label: AzString,
+        pub key_tip: AzOptionString,
+        pub groups: AzRibbonGroupVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `Node` struct
     #[repr(C)]
     pub struct AzNode {
@@ -12539,6 +15425,15 @@ mod test_sizes {
         pub style: AzSvgStyle,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<RibbonTab>`
+    #[repr(C)]
+    pub struct AzRibbonTabVec {
+        pub(crate) ptr: *const AzRibbonTab,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzRibbonTabVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `Vec<NodeDataInlineCssProperty>`
     #[repr(C)]
     pub struct AzNodeDataInlineCssPropertyVec {
@@ -12548,6 +15443,15 @@ mod test_sizes {
         pub destructor: AzNodeDataInlineCssPropertyVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+    #[repr(C)]
+    pub struct AzCssKeyframeVec {
+        pub(crate) ptr: *const AzCssKeyframe,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssKeyframeVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
     #[repr(C, u8)]
     pub enum AzOptionWindowState {
@@ -12619,6 +15523,22 @@ mod test_sizes {
         Dynamic(AzDynamicCssProperty),
     }
 
+    /// One `@keyframes` block, i.e. `@keyframes fade { from { opacity: 0; } to { opacity: 1; } }`
+    #[repr(C)]
+    pub struct AzCssKeyframes {
+        pub name: AzString,
+        pub frames: AzCssKeyframeVec,
+    }
+
+    /// Re-export of rust-allocated (stack based) `Ribbon` struct
+    #[repr(C)]
+    pub struct AzRibbon {
+        pub tabs: AzRibbonTabVec,
+        pub tab_active: i32,
+        pub available_width: AzOptionF32,
+        pub on_tab_clicked: AzOptionRibbonOnTabClicked,
+    }
+
     /// Re-export of rust-allocated (stack based) `Button` struct
     #[repr(C)]
     pub struct AzButton {
@@ -12679,6 +15599,36 @@ mod test_sizes {
         pub state: AzNumberInputStateWrapper,
     }
 
+    /// Thumb that can be dragged along a track to select a value between `min` and `max`
+    #[repr(C)]
+    pub struct AzSlider {
+        pub orientation: AzSliderOrientation,
+        pub state: AzSliderState,
+        pub tick_interval: f32,
+        pub on_value_change: AzOptionSliderOnValueChange,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub track_style: AzNodeDataInlineCssPropertyVec,
+        pub fill_style: AzNodeDataInlineCssPropertyVec,
+        pub rail_style: AzNodeDataInlineCssPropertyVec,
+        pub thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub tick_style: AzNodeDataInlineCssPropertyVec,
+    }
+
+    /// Slider with two thumbs to select a range (`low..=high`) between `min` and `max`
+    #[repr(C)]
+    pub struct AzRangeSlider {
+        pub orientation: AzSliderOrientation,
+        pub state: AzRangeSliderState,
+        pub tick_interval: f32,
+        pub on_value_change: AzOptionRangeSliderOnValueChange,
+        pub container_style: AzNodeDataInlineCssPropertyVec,
+        pub track_style: AzNodeDataInlineCssPropertyVec,
+        pub fill_style: AzNodeDataInlineCssPropertyVec,
+        pub rail_style: AzNodeDataInlineCssPropertyVec,
+        pub thumb_style: AzNodeDataInlineCssPropertyVec,
+        pub tick_style: AzNodeDataInlineCssPropertyVec,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeIdNodeMap` struct
     #[repr(C)]
     pub struct AzNodeIdNodeMap {
@@ -12704,6 +15654,15 @@ mod test_sizes {
         pub destructor: AzCssDeclarationVecDestructor,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+    #[repr(C)]
+    pub struct AzCssKeyframesVec {
+        pub(crate) ptr: *const AzCssKeyframes,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzCssKeyframesVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `NodeDataVec`
     #[repr(C)]
     pub struct AzNodeDataVec {
@@ -12765,6 +15724,16 @@ mod test_sizes {
         pub content: AzDom,
     }
 
+    /// Re-export of rust-allocated (stack based) `SplitterPane` struct
+    #[repr(C)]
+    pub struct AzSplitterPane {
+        pub content: AzDom,
+        pub size: AzOptionF32,
+        pub min_size: f32,
+        pub max_size: AzOptionF32,
+        pub collapsible: bool,
+    }
+
     /// Re-export of rust-allocated (stack based) `NodeGraph` struct
     #[repr(C)]
     pub struct AzNodeGraph {
@@ -12795,6 +15764,15 @@ mod test_sizes {
         pub css_property_cache: AzCssPropertyCache,
     }
 
+    /// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+    #[repr(C)]
+    pub struct AzSplitterPaneVec {
+        pub(crate) ptr: *const AzSplitterPane,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzSplitterPaneVecDestructor,
+    }
+
     /// Wrapper over a Rust-allocated `CssRuleBlock`
     #[repr(C)]
     pub struct AzCssRuleBlockVec {
@@ -12839,19 +15817,30 @@ mod test_sizes {
         pub virtual_scroll_offset: AzLogicalPosition,
     }
 
-    /// Re-export of rust-allocated (stack based) `Stylesheet` struct
+    /// One `@media` block, i.e. `@media (prefers-color-scheme: dark) { body { color: white; } }`
     #[repr(C)]
-    pub struct AzStylesheet {
+    pub struct AzCssMediaRule {
+        pub queries: AzCssMediaQueryVec,
+        pub insert_index: usize,
         pub rules: AzCssRuleBlockVec,
     }
 
-    /// Wrapper over a Rust-allocated `Stylesheet`
+    /// Container with resizable panes, separated by draggable handles
     #[repr(C)]
-    pub struct AzStylesheetVec {
-        pub(crate) ptr: *const AzStylesheet,
+    pub struct AzSplitter {
+        pub orientation: AzSplitterOrientation,
+        pub panes: AzSplitterPaneVec,
+        pub state: AzSplitterState,
+        pub on_resize: AzOptionSplitterOnResize,
+    }
+
+    /// Wrapper over a Rust-allocated `Vec<CssMediaRule>`
+    #[repr(C)]
+    pub struct AzCssMediaRuleVec {
+        pub(crate) ptr: *const AzCssMediaRule,
         pub len: usize,
         pub cap: usize,
-        pub destructor: AzStylesheetVecDestructor,
+        pub destructor: AzCssMediaRuleVecDestructor,
     }
 
     /// Re-export of rust-allocated (stack based) `ResultSvgXmlNodeSvgParseError` struct
@@ -12868,6 +15857,23 @@ mod test_sizes {
         Err(AzSvgParseError),
     }
 
+    /// Re-export of rust-allocated (stack based) `Stylesheet` struct
+    #[repr(C)]
+    pub struct AzStylesheet {
+        pub rules: AzCssRuleBlockVec,
+        pub keyframes: AzCssKeyframesVec,
+        pub media_rules: AzCssMediaRuleVec,
+    }
+
+    /// Wrapper over a Rust-allocated `Stylesheet`
+    #[repr(C)]
+    pub struct AzStylesheetVec {
+        pub(crate) ptr: *const AzStylesheet,
+        pub len: usize,
+        pub cap: usize,
+        pub destructor: AzStylesheetVecDestructor,
+    }
+
     /// Re-export of rust-allocated (stack based) `Css` struct
     #[repr(C)]
     pub struct AzCss {
@@ -12896,6 +15902,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WindowsHandle>(), "AzWindowsHandle"), (Layout::new::<AzWindowsHandle>(), "AzWindowsHandle"));
         assert_eq!((Layout::new::<azul_core::window::WebHandle>(), "AzWebHandle"), (Layout::new::<AzWebHandle>(), "AzWebHandle"));
         assert_eq!((Layout::new::<azul_core::window::AndroidHandle>(), "AzAndroidHandle"), (Layout::new::<AzAndroidHandle>(), "AzAndroidHandle"));
+        assert_eq!((Layout::new::<azul_core::window::ClipboardSelection>(), "AzClipboardSelection"), (Layout::new::<AzClipboardSelection>(), "AzClipboardSelection"));
+        assert_eq!((Layout::new::<azul_core::window::LinuxBackend>(), "AzLinuxBackend"), (Layout::new::<AzLinuxBackend>(), "AzLinuxBackend"));
         assert_eq!((Layout::new::<azul_core::window::XWindowType>(), "AzXWindowType"), (Layout::new::<AzXWindowType>(), "AzXWindowType"));
         assert_eq!((Layout::new::<azul_core::window::PhysicalPositionI32>(), "AzPhysicalPositionI32"), (Layout::new::<AzPhysicalPositionI32>(), "AzPhysicalPositionI32"));
         assert_eq!((Layout::new::<azul_core::window::PhysicalSizeU32>(), "AzPhysicalSizeU32"), (Layout::new::<AzPhysicalSizeU32>(), "AzPhysicalSizeU32"));
@@ -12923,6 +15931,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::AnimationRepeat>(), "AzAnimationRepeat"), (Layout::new::<AzAnimationRepeat>(), "AzAnimationRepeat"));
         assert_eq!((Layout::new::<azul_impl::callbacks::AnimationRepeatCount>(), "AzAnimationRepeatCount"), (Layout::new::<AzAnimationRepeatCount>(), "AzAnimationRepeatCount"));
         assert_eq!((Layout::new::<azul_impl::callbacks::IFrameCallback>(), "AzIFrameCallback"), (Layout::new::<AzIFrameCallback>(), "AzIFrameCallback"));
+        assert_eq!((Layout::new::<azul_impl::callbacks::TooltipCallback>(), "AzTooltipCallback"), (Layout::new::<AzTooltipCallback>(), "AzTooltipCallback"));
         assert_eq!((Layout::new::<azul_impl::callbacks::RenderImageCallback>(), "AzRenderImageCallback"), (Layout::new::<AzRenderImageCallback>(), "AzRenderImageCallback"));
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallback>(), "AzTimerCallback"), (Layout::new::<AzTimerCallback>(), "AzTimerCallback"));
         assert_eq!((Layout::new::<azul_impl::callbacks::WriteBackCallback>(), "AzWriteBackCallback"), (Layout::new::<AzWriteBackCallback>(), "AzWriteBackCallback"));
@@ -12936,19 +15945,26 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::ApplicationEventFilter>(), "AzApplicationEventFilter"), (Layout::new::<AzApplicationEventFilter>(), "AzApplicationEventFilter"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityRole>(), "AzAccessibilityRole"), (Layout::new::<AzAccessibilityRole>(), "AzAccessibilityRole"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityState>(), "AzAccessibilityState"), (Layout::new::<AzAccessibilityState>(), "AzAccessibilityState"));
+        assert_eq!((Layout::new::<azul_impl::dom::AccessibilityAction>(), "AzAccessibilityAction"), (Layout::new::<AzAccessibilityAction>(), "AzAccessibilityAction"));
         assert_eq!((Layout::new::<azul_impl::dom::TabIndex>(), "AzTabIndex"), (Layout::new::<AzTabIndex>(), "AzTabIndex"));
         assert_eq!((Layout::new::<azul_core::window::ContextMenuMouseButton>(), "AzContextMenuMouseButton"), (Layout::new::<AzContextMenuMouseButton>(), "AzContextMenuMouseButton"));
         assert_eq!((Layout::new::<azul_core::window::MenuPopupPosition>(), "AzMenuPopupPosition"), (Layout::new::<AzMenuPopupPosition>(), "AzMenuPopupPosition"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemState>(), "AzMenuItemState"), (Layout::new::<AzMenuItemState>(), "AzMenuItemState"));
         assert_eq!((Layout::new::<azul_impl::css::NodeTypeTag>(), "AzNodeTypeKey"), (Layout::new::<AzNodeTypeKey>(), "AzNodeTypeKey"));
         assert_eq!((Layout::new::<azul_impl::css::CssNthChildPattern>(), "AzCssNthChildPattern"), (Layout::new::<AzCssNthChildPattern>(), "AzCssNthChildPattern"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaType>(), "AzCssMediaType"), (Layout::new::<AzCssMediaType>(), "AzCssMediaType"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaOrientation>(), "AzCssMediaOrientation"), (Layout::new::<AzCssMediaOrientation>(), "AzCssMediaOrientation"));
+        assert_eq!((Layout::new::<azul_impl::css::CssColorScheme>(), "AzCssColorScheme"), (Layout::new::<AzCssColorScheme>(), "AzCssColorScheme"));
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyType>(), "AzCssPropertyType"), (Layout::new::<AzCssPropertyType>(), "AzCssPropertyType"));
+        assert_eq!((Layout::new::<azul_impl::css::CombinedCssPropertyType>(), "AzCombinedCssPropertyType"), (Layout::new::<AzCombinedCssPropertyType>(), "AzCombinedCssPropertyType"));
         assert_eq!((Layout::new::<azul_impl::css::ColorU>(), "AzColorU"), (Layout::new::<AzColorU>(), "AzColorU"));
         assert_eq!((Layout::new::<azul_impl::css::SizeMetric>(), "AzSizeMetric"), (Layout::new::<AzSizeMetric>(), "AzSizeMetric"));
         assert_eq!((Layout::new::<azul_impl::css::FloatValue>(), "AzFloatValue"), (Layout::new::<AzFloatValue>(), "AzFloatValue"));
         assert_eq!((Layout::new::<azul_impl::css::BoxShadowClipMode>(), "AzBoxShadowClipMode"), (Layout::new::<AzBoxShadowClipMode>(), "AzBoxShadowClipMode"));
         assert_eq!((Layout::new::<azul_impl::css::StyleMixBlendMode>(), "AzStyleMixBlendMode"), (Layout::new::<AzStyleMixBlendMode>(), "AzStyleMixBlendMode"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignContent>(), "AzLayoutAlignContent"), (Layout::new::<AzLayoutAlignContent>(), "AzLayoutAlignContent"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridAutoFlow>(), "AzLayoutGridAutoFlow"), (Layout::new::<AzLayoutGridAutoFlow>(), "AzLayoutGridAutoFlow"));
+        assert_eq!((Layout::new::<azul_impl::css::GridLine>(), "AzGridLine"), (Layout::new::<AzGridLine>(), "AzGridLine"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignItems>(), "AzLayoutAlignItems"), (Layout::new::<AzLayoutAlignItems>(), "AzLayoutAlignItems"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBoxSizing>(), "AzLayoutBoxSizing"), (Layout::new::<AzLayoutBoxSizing>(), "AzLayoutBoxSizing"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexDirection>(), "AzLayoutFlexDirection"), (Layout::new::<AzLayoutFlexDirection>(), "AzLayoutFlexDirection"));
@@ -12967,8 +15983,18 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::BorderStyle>(), "AzBorderStyle"), (Layout::new::<AzBorderStyle>(), "AzBorderStyle"));
         assert_eq!((Layout::new::<azul_impl::css::StyleCursor>(), "AzStyleCursor"), (Layout::new::<AzStyleCursor>(), "AzStyleCursor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"), (Layout::new::<AzStyleBackfaceVisibility>(), "AzStyleBackfaceVisibility"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTime>(), "AzStyleTime"), (Layout::new::<AzStyleTime>(), "AzStyleTime"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleStepPosition>(), "AzStyleStepPosition"), (Layout::new::<AzStyleStepPosition>(), "AzStyleStepPosition"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDirection>(), "AzStyleAnimationDirection"), (Layout::new::<AzStyleAnimationDirection>(), "AzStyleAnimationDirection"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationFillMode>(), "AzStyleAnimationFillMode"), (Layout::new::<AzStyleAnimationFillMode>(), "AzStyleAnimationFillMode"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationPlayState>(), "AzStyleAnimationPlayState"), (Layout::new::<AzStyleAnimationPlayState>(), "AzStyleAnimationPlayState"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlign>(), "AzStyleTextAlign"), (Layout::new::<AzStyleTextAlign>(), "AzStyleTextAlign"));
-        assert_eq!((Layout::new::<crate::widgets::ribbon::Ribbon>(), "AzRibbon"), (Layout::new::<AzRibbon>(), "AzRibbon"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleDirection>(), "AzStyleDirection"), (Layout::new::<AzStyleDirection>(), "AzStyleDirection"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleWordBreak>(), "AzStyleWordBreak"), (Layout::new::<AzStyleWordBreak>(), "AzStyleWordBreak"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleOverflowWrap>(), "AzStyleOverflowWrap"), (Layout::new::<AzStyleOverflowWrap>(), "AzStyleOverflowWrap"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleHyphens>(), "AzStyleHyphens"), (Layout::new::<AzStyleHyphens>(), "AzStyleHyphens"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonItemKind>(), "AzRibbonItemKind"), (Layout::new::<AzRibbonItemKind>(), "AzRibbonItemKind"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonOnItemClickCallback>(), "AzRibbonOnItemClickCallback"), (Layout::new::<AzRibbonOnItemClickCallback>(), "AzRibbonOnItemClickCallback"));
         assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonOnTabClickedCallback>(), "AzRibbonOnTabClickedCallback"), (Layout::new::<AzRibbonOnTabClickedCallback>(), "AzRibbonOnTabClickedCallback"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputOnPathChangeCallback>(), "AzFileInputOnPathChangeCallback"), (Layout::new::<AzFileInputOnPathChangeCallback>(), "AzFileInputOnPathChangeCallback"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxOnToggleCallback>(), "AzCheckBoxOnToggleCallback"), (Layout::new::<AzCheckBoxOnToggleCallback>(), "AzCheckBoxOnToggleCallback"));
@@ -12979,12 +16005,26 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnVirtualKeyDownCallback>(), "AzTextInputOnVirtualKeyDownCallback"), (Layout::new::<AzTextInputOnVirtualKeyDownCallback>(), "AzTextInputOnVirtualKeyDownCallback"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnFocusLostCallback>(), "AzTextInputOnFocusLostCallback"), (Layout::new::<AzTextInputOnFocusLostCallback>(), "AzTextInputOnFocusLostCallback"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputValid>(), "AzTextInputValid"), (Layout::new::<AzTextInputValid>(), "AzTextInputValid"));
+        assert_eq!((Layout::new::<crate::widgets::text_area::TextAreaEditKind>(), "AzTextAreaEditKind"), (Layout::new::<AzTextAreaEditKind>(), "AzTextAreaEditKind"));
+        assert_eq!((Layout::new::<crate::widgets::text_area::TextAreaOnTextChangeCallback>(), "AzTextAreaOnTextChangeCallback"), (Layout::new::<AzTextAreaOnTextChangeCallback>(), "AzTextAreaOnTextChangeCallback"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputState>(), "AzNumberInputState"), (Layout::new::<AzNumberInputState>(), "AzNumberInputState"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnValueChangeCallback>(), "AzNumberInputOnValueChangeCallback"), (Layout::new::<AzNumberInputOnValueChangeCallback>(), "AzNumberInputOnValueChangeCallback"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnFocusLostCallback>(), "AzNumberInputOnFocusLostCallback"), (Layout::new::<AzNumberInputOnFocusLostCallback>(), "AzNumberInputOnFocusLostCallback"));
-        assert_eq!((Layout::new::<crate::widgets::progressbar::ProgressBarState>(), "AzProgressBarState"), (Layout::new::<AzProgressBarState>(), "AzProgressBarState"));
+        assert_eq!((Layout::new::<crate::widgets::progressbar::ProgressBarMode>(), "AzProgressBarMode"), (Layout::new::<AzProgressBarMode>(), "AzProgressBarMode"));
+        assert_eq!((Layout::new::<crate::widgets::progressbar::ProgressBarStyle>(), "AzProgressBarStyle"), (Layout::new::<AzProgressBarStyle>(), "AzProgressBarStyle"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabHeaderState>(), "AzTabHeaderState"), (Layout::new::<AzTabHeaderState>(), "AzTabHeaderState"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabOnClickCallback>(), "AzTabOnClickCallback"), (Layout::new::<AzTabOnClickCallback>(), "AzTabOnClickCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tabs::TabOnCloseCallback>(), "AzTabOnCloseCallback"), (Layout::new::<AzTabOnCloseCallback>(), "AzTabOnCloseCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tabs::TabOnReorderCallback>(), "AzTabOnReorderCallback"), (Layout::new::<AzTabOnReorderCallback>(), "AzTabOnReorderCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tabs::TabOnContextMenuCallback>(), "AzTabOnContextMenuCallback"), (Layout::new::<AzTabOnContextMenuCallback>(), "AzTabOnContextMenuCallback"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterOrientation>(), "AzSplitterOrientation"), (Layout::new::<AzSplitterOrientation>(), "AzSplitterOrientation"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneState>(), "AzSplitterPaneState"), (Layout::new::<AzSplitterPaneState>(), "AzSplitterPaneState"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterOnResizeCallback>(), "AzSplitterOnResizeCallback"), (Layout::new::<AzSplitterOnResizeCallback>(), "AzSplitterOnResizeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderOrientation>(), "AzSliderOrientation"), (Layout::new::<AzSliderOrientation>(), "AzSliderOrientation"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderState>(), "AzSliderState"), (Layout::new::<AzSliderState>(), "AzSliderState"));
+        assert_eq!((Layout::new::<crate::widgets::slider::RangeSliderState>(), "AzRangeSliderState"), (Layout::new::<AzRangeSliderState>(), "AzRangeSliderState"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderOnValueChangeCallback>(), "AzSliderOnValueChangeCallback"), (Layout::new::<AzSliderOnValueChangeCallback>(), "AzSliderOnValueChangeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::slider::RangeSliderOnValueChangeCallback>(), "AzRangeSliderOnValueChangeCallback"), (Layout::new::<AzRangeSliderOnValueChangeCallback>(), "AzRangeSliderOnValueChangeCallback"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphStyle>(), "AzNodeGraphStyle"), (Layout::new::<AzNodeGraphStyle>(), "AzNodeGraphStyle"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeAddedCallback>(), "AzNodeGraphOnNodeAddedCallback"), (Layout::new::<AzNodeGraphOnNodeAddedCallback>(), "AzNodeGraphOnNodeAddedCallback"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeRemovedCallback>(), "AzNodeGraphOnNodeRemovedCallback"), (Layout::new::<AzNodeGraphOnNodeRemovedCallback>(), "AzNodeGraphOnNodeRemovedCallback"));
@@ -13003,6 +16043,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnLazyLoadScrollCallback>(), "AzListViewOnLazyLoadScrollCallback"), (Layout::new::<AzListViewOnLazyLoadScrollCallback>(), "AzListViewOnLazyLoadScrollCallback"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnClickCallback>(), "AzListViewOnColumnClickCallback"), (Layout::new::<AzListViewOnColumnClickCallback>(), "AzListViewOnColumnClickCallback"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnRowClickCallback>(), "AzListViewOnRowClickCallback"), (Layout::new::<AzListViewOnRowClickCallback>(), "AzListViewOnRowClickCallback"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowRange>(), "AzListViewRowRange"), (Layout::new::<AzListViewRowRange>(), "AzListViewRowRange"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnResizeCallback>(), "AzListViewOnColumnResizeCallback"), (Layout::new::<AzListViewOnColumnResizeCallback>(), "AzListViewOnColumnResizeCallback"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowProviderCallback>(), "AzListViewRowProviderCallback"), (Layout::new::<AzListViewRowProviderCallback>(), "AzListViewRowProviderCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeId>(), "AzTreeViewNodeId"), (Layout::new::<AzTreeViewNodeId>(), "AzTreeViewNodeId"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewSelectionMode>(), "AzTreeViewSelectionMode"), (Layout::new::<AzTreeViewSelectionMode>(), "AzTreeViewSelectionMode"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnExpandCallback>(), "AzTreeViewOnExpandCallback"), (Layout::new::<AzTreeViewOnExpandCallback>(), "AzTreeViewOnExpandCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnSelectCallback>(), "AzTreeViewOnSelectCallback"), (Layout::new::<AzTreeViewOnSelectCallback>(), "AzTreeViewOnSelectCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnDoubleClickCallback>(), "AzTreeViewOnDoubleClickCallback"), (Layout::new::<AzTreeViewOnDoubleClickCallback>(), "AzTreeViewOnDoubleClickCallback"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnContextMenuCallback>(), "AzTreeViewOnContextMenuCallback"), (Layout::new::<AzTreeViewOnContextMenuCallback>(), "AzTreeViewOnContextMenuCallback"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"), (Layout::new::<AzDropDownOnChoiceChangeCallback>(), "AzDropDownOnChoiceChangeCallback"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::NodeHierarchyItem>(), "AzNodeHierarchyItem"), (Layout::new::<AzNodeHierarchyItem>(), "AzNodeHierarchyItem"));
         assert_eq!((Layout::new::<azul_impl::style::CascadeInfo>(), "AzCascadeInfo"), (Layout::new::<AzCascadeInfo>(), "AzCascadeInfo"));
@@ -13072,6 +16121,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::ThreadReceiver>(), "AzThreadReceiver"), (Layout::new::<AzThreadReceiver>(), "AzThreadReceiver"));
         assert_eq!((Layout::new::<azul_impl::task::CreateThreadCallback>(), "AzCreateThreadFn"), (Layout::new::<AzCreateThreadFn>(), "AzCreateThreadFn"));
         assert_eq!((Layout::new::<azul_impl::task::GetSystemTimeCallback>(), "AzGetSystemTimeFn"), (Layout::new::<AzGetSystemTimeFn>(), "AzGetSystemTimeFn"));
+        assert_eq!((Layout::new::<azul_impl::task::GetClipboardCallback>(), "AzGetClipboardFn"), (Layout::new::<AzGetClipboardFn>(), "AzGetClipboardFn"));
+        assert_eq!((Layout::new::<azul_impl::task::SetClipboardCallback>(), "AzSetClipboardFn"), (Layout::new::<AzSetClipboardFn>(), "AzSetClipboardFn"));
         assert_eq!((Layout::new::<azul_impl::task::CheckThreadFinishedCallback>(), "AzCheckThreadFinishedFn"), (Layout::new::<AzCheckThreadFinishedFn>(), "AzCheckThreadFinishedFn"));
         assert_eq!((Layout::new::<azul_impl::task::LibrarySendThreadMsgCallback>(), "AzLibrarySendThreadMsgFn"), (Layout::new::<AzLibrarySendThreadMsgFn>(), "AzLibrarySendThreadMsgFn"));
         assert_eq!((Layout::new::<azul_impl::task::LibraryReceiveThreadMsgCallback>(), "AzLibraryReceiveThreadMsgFn"), (Layout::new::<AzLibraryReceiveThreadMsgFn>(), "AzLibraryReceiveThreadMsgFn"));
@@ -13082,6 +16133,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::ThreadSenderDestructorCallback>(), "AzThreadSenderDestructorFn"), (Layout::new::<AzThreadSenderDestructorFn>(), "AzThreadSenderDestructorFn"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"), (Layout::new::<AzStyleFontFamilyVecDestructor>(), "AzStyleFontFamilyVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowVecDestructor>(), "AzListViewRowVecDestructor"), (Layout::new::<AzListViewRowVecDestructor>(), "AzListViewRowVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeVecDestructor>(), "AzTreeViewNodeVecDestructor"), (Layout::new::<AzTreeViewNodeVecDestructor>(), "AzTreeViewNodeVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeIdVecDestructor>(), "AzTreeViewNodeIdVecDestructor"), (Layout::new::<AzTreeViewNodeIdVecDestructor>(), "AzTreeViewNodeIdVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonTabVecDestructor>(), "AzRibbonTabVecDestructor"), (Layout::new::<AzRibbonTabVecDestructor>(), "AzRibbonTabVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonGroupVecDestructor>(), "AzRibbonGroupVecDestructor"), (Layout::new::<AzRibbonGroupVecDestructor>(), "AzRibbonGroupVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonItemVecDestructor>(), "AzRibbonItemVecDestructor"), (Layout::new::<AzRibbonItemVecDestructor>(), "AzRibbonItemVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneVecDestructor>(), "AzSplitterPaneVecDestructor"), (Layout::new::<AzSplitterPaneVecDestructor>(), "AzSplitterPaneVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneStateVecDestructor>(), "AzSplitterPaneStateVecDestructor"), (Layout::new::<AzSplitterPaneStateVecDestructor>(), "AzSplitterPaneStateVecDestructor"));
+        assert_eq!((Layout::new::<crate::widgets::text_area::TextAreaEditVecDestructor>(), "AzTextAreaEditVecDestructor"), (Layout::new::<AzTextAreaEditVecDestructor>(), "AzTextAreaEditVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVecDestructor>(), "AzStyleFilterVecDestructor"), (Layout::new::<AzStyleFilterVecDestructor>(), "AzStyleFilterVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::LogicalRectVecDestructor>(), "AzLogicalRectVecDestructor"), (Layout::new::<AzLogicalRectVecDestructor>(), "AzLogicalRectVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeIdInfoMapVecDestructor>(), "AzNodeTypeIdInfoMapVecDestructor"), (Layout::new::<AzNodeTypeIdInfoMapVecDestructor>(), "AzNodeTypeIdInfoMapVecDestructor"));
@@ -13094,6 +16153,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnectionVecDestructor>(), "AzOutputConnectionVecDestructor"), (Layout::new::<AzOutputConnectionVecDestructor>(), "AzOutputConnectionVecDestructor"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputNodeAndIndexVecDestructor>(), "AzInputNodeAndIndexVecDestructor"), (Layout::new::<AzInputNodeAndIndexVecDestructor>(), "AzInputNodeAndIndexVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityStateVecDestructor>(), "AzAccessibilityStateVecDestructor"), (Layout::new::<AzAccessibilityStateVecDestructor>(), "AzAccessibilityStateVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::dom::AccessibilityActionVecDestructor>(), "AzAccessibilityActionVecDestructor"), (Layout::new::<AzAccessibilityActionVecDestructor>(), "AzAccessibilityActionVecDestructor"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemVecDestructor>(), "AzMenuItemVecDestructor"), (Layout::new::<AzMenuItemVecDestructor>(), "AzMenuItemVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"), (Layout::new::<AzTessellatedSvgNodeVecDestructor>(), "AzTessellatedSvgNodeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNodeVecDestructor>(), "AzXmlNodeVecDestructor"), (Layout::new::<AzXmlNodeVecDestructor>(), "AzXmlNodeVecDestructor"));
@@ -13112,6 +16172,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVecDestructor>(), "AzStyleBackgroundRepeatVecDestructor"), (Layout::new::<AzStyleBackgroundRepeatVecDestructor>(), "AzStyleBackgroundRepeatVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVecDestructor>(), "AzStyleBackgroundSizeVecDestructor"), (Layout::new::<AzStyleBackgroundSizeVecDestructor>(), "AzStyleBackgroundSizeVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVecDestructor>(), "AzStyleTransformVecDestructor"), (Layout::new::<AzStyleTransformVecDestructor>(), "AzStyleTransformVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionPropertyVecDestructor>(), "AzStyleTransitionPropertyVecDestructor"), (Layout::new::<AzStyleTransitionPropertyVecDestructor>(), "AzStyleTransitionPropertyVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTimeVecDestructor>(), "AzStyleTimeVecDestructor"), (Layout::new::<AzStyleTimeVecDestructor>(), "AzStyleTimeVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTimingFunctionVecDestructor>(), "AzStyleTimingFunctionVecDestructor"), (Layout::new::<AzStyleTimingFunctionVecDestructor>(), "AzStyleTimingFunctionVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationNameVecDestructor>(), "AzStyleAnimationNameVecDestructor"), (Layout::new::<AzStyleAnimationNameVecDestructor>(), "AzStyleAnimationNameVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationIterationCountVecDestructor>(), "AzStyleAnimationIterationCountVecDestructor"), (Layout::new::<AzStyleAnimationIterationCountVecDestructor>(), "AzStyleAnimationIterationCountVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDirectionVecDestructor>(), "AzStyleAnimationDirectionVecDestructor"), (Layout::new::<AzStyleAnimationDirectionVecDestructor>(), "AzStyleAnimationDirectionVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationFillModeVecDestructor>(), "AzStyleAnimationFillModeVecDestructor"), (Layout::new::<AzStyleAnimationFillModeVecDestructor>(), "AzStyleAnimationFillModeVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationPlayStateVecDestructor>(), "AzStyleAnimationPlayStateVecDestructor"), (Layout::new::<AzStyleAnimationPlayStateVecDestructor>(), "AzStyleAnimationPlayStateVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackSizingVecDestructor>(), "AzGridTrackSizingVecDestructor"), (Layout::new::<AzGridTrackSizingVecDestructor>(), "AzGridTrackSizingVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyVecDestructor>(), "AzCssPropertyVecDestructor"), (Layout::new::<AzCssPropertyVecDestructor>(), "AzCssPropertyVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgMultiPolygonVecDestructor>(), "AzSvgMultiPolygonVecDestructor"), (Layout::new::<AzSvgMultiPolygonVecDestructor>(), "AzSvgMultiPolygonVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathVecDestructor>(), "AzSvgPathVecDestructor"), (Layout::new::<AzSvgPathVecDestructor>(), "AzSvgPathVecDestructor"));
@@ -13126,6 +16195,11 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"), (Layout::new::<AzCssDeclarationVecDestructor>(), "AzCssDeclarationVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelectorVecDestructor>(), "AzCssPathSelectorVecDestructor"), (Layout::new::<AzCssPathSelectorVecDestructor>(), "AzCssPathSelectorVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::StylesheetVecDestructor>(), "AzStylesheetVecDestructor"), (Layout::new::<AzStylesheetVecDestructor>(), "AzStylesheetVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframesVecDestructor>(), "AzCssKeyframesVecDestructor"), (Layout::new::<AzCssKeyframesVecDestructor>(), "AzCssKeyframesVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframeVecDestructor>(), "AzCssKeyframeVecDestructor"), (Layout::new::<AzCssKeyframeVecDestructor>(), "AzCssKeyframeVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaRuleVecDestructor>(), "AzCssMediaRuleVecDestructor"), (Layout::new::<AzCssMediaRuleVecDestructor>(), "AzCssMediaRuleVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaQueryVecDestructor>(), "AzCssMediaQueryVecDestructor"), (Layout::new::<AzCssMediaQueryVecDestructor>(), "AzCssMediaQueryVecDestructor"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaFeatureVecDestructor>(), "AzCssMediaFeatureVecDestructor"), (Layout::new::<AzCssMediaFeatureVecDestructor>(), "AzCssMediaFeatureVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlockVecDestructor>(), "AzCssRuleBlockVecDestructor"), (Layout::new::<AzCssRuleBlockVecDestructor>(), "AzCssRuleBlockVecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::F32VecDestructor>(), "AzF32VecDestructor"), (Layout::new::<AzF32VecDestructor>(), "AzF32VecDestructor"));
         assert_eq!((Layout::new::<azul_impl::css::U16VecDestructor>(), "AzU16VecDestructor"), (Layout::new::<AzU16VecDestructor>(), "AzU16VecDestructor"));
@@ -13175,9 +16249,11 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::RefAny>(), "AzRefAny"), (Layout::new::<AzRefAny>(), "AzRefAny"));
         assert_eq!((Layout::new::<azul_impl::dom::IFrameNode>(), "AzIFrameNode"), (Layout::new::<AzIFrameNode>(), "AzIFrameNode"));
         assert_eq!((Layout::new::<azul_impl::dom::NotEventFilter>(), "AzNotEventFilter"), (Layout::new::<AzNotEventFilter>(), "AzNotEventFilter"));
+        assert_eq!((Layout::new::<azul_impl::dom::TooltipDom>(), "AzTooltipDom"), (Layout::new::<AzTooltipDom>(), "AzTooltipDom"));
         assert_eq!((Layout::new::<azul_core::window::MenuCallback>(), "AzMenuCallback"), (Layout::new::<AzMenuCallback>(), "AzMenuCallback"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemIcon>(), "AzMenuItemIcon"), (Layout::new::<AzMenuItemIcon>(), "AzMenuItemIcon"));
         assert_eq!((Layout::new::<azul_impl::css::CssNthChildSelector>(), "AzCssNthChildSelector"), (Layout::new::<AzCssNthChildSelector>(), "AzCssNthChildSelector"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaRange>(), "AzCssMediaRange"), (Layout::new::<AzCssMediaRange>(), "AzCssMediaRange"));
         assert_eq!((Layout::new::<azul_impl::css::PixelValue>(), "AzPixelValue"), (Layout::new::<AzPixelValue>(), "AzPixelValue"));
         assert_eq!((Layout::new::<azul_impl::css::PixelValueNoPercent>(), "AzPixelValueNoPercent"), (Layout::new::<AzPixelValueNoPercent>(), "AzPixelValueNoPercent"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBoxShadow>(), "AzStyleBoxShadow"), (Layout::new::<AzStyleBoxShadow>(), "AzStyleBoxShadow"));
@@ -13185,6 +16261,11 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleColorMatrix>(), "AzStyleColorMatrix"), (Layout::new::<AzStyleColorMatrix>(), "AzStyleColorMatrix"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterOffset>(), "AzStyleFilterOffset"), (Layout::new::<AzStyleFilterOffset>(), "AzStyleFilterOffset"));
         assert_eq!((Layout::new::<azul_impl::css::StyleCompositeFilter>(), "AzStyleCompositeFilter"), (Layout::new::<AzStyleCompositeFilter>(), "AzStyleCompositeFilter"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackBreadth>(), "AzGridTrackBreadth"), (Layout::new::<AzGridTrackBreadth>(), "AzGridTrackBreadth"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackSizing>(), "AzGridTrackSizing"), (Layout::new::<AzGridTrackSizing>(), "AzGridTrackSizing"));
+        assert_eq!((Layout::new::<azul_impl::css::GridPlacement>(), "AzGridPlacement"), (Layout::new::<AzGridPlacement>(), "AzGridPlacement"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutRowGap>(), "AzLayoutRowGap"), (Layout::new::<AzLayoutRowGap>(), "AzLayoutRowGap"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutColumnGap>(), "AzLayoutColumnGap"), (Layout::new::<AzLayoutColumnGap>(), "AzLayoutColumnGap"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBottom>(), "AzLayoutBottom"), (Layout::new::<AzLayoutBottom>(), "AzLayoutBottom"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexGrow>(), "AzLayoutFlexGrow"), (Layout::new::<AzLayoutFlexGrow>(), "AzLayoutFlexGrow"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutFlexShrink>(), "AzLayoutFlexShrink"), (Layout::new::<AzLayoutFlexShrink>(), "AzLayoutFlexShrink"));
@@ -13246,10 +16327,20 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformScale2D>(), "AzStyleTransformScale2D"), (Layout::new::<AzStyleTransformScale2D>(), "AzStyleTransformScale2D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformScale3D>(), "AzStyleTransformScale3D"), (Layout::new::<AzStyleTransformScale3D>(), "AzStyleTransformScale3D"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformSkew2D>(), "AzStyleTransformSkew2D"), (Layout::new::<AzStyleTransformSkew2D>(), "AzStyleTransformSkew2D"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleCubicBezier>(), "AzStyleCubicBezier"), (Layout::new::<AzStyleCubicBezier>(), "AzStyleCubicBezier"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleSteps>(), "AzStyleSteps"), (Layout::new::<AzStyleSteps>(), "AzStyleSteps"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTimingFunction>(), "AzStyleTimingFunction"), (Layout::new::<AzStyleTimingFunction>(), "AzStyleTimingFunction"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionProperty>(), "AzStyleTransitionProperty"), (Layout::new::<AzStyleTransitionProperty>(), "AzStyleTransitionProperty"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationIterationCount>(), "AzStyleAnimationIterationCount"), (Layout::new::<AzStyleAnimationIterationCount>(), "AzStyleAnimationIterationCount"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextColor>(), "AzStyleTextColor"), (Layout::new::<AzStyleTextColor>(), "AzStyleTextColor"));
         assert_eq!((Layout::new::<azul_impl::css::StyleWordSpacing>(), "AzStyleWordSpacing"), (Layout::new::<AzStyleWordSpacing>(), "AzStyleWordSpacing"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBoxShadowValue>(), "AzStyleBoxShadowValue"), (Layout::new::<AzStyleBoxShadowValue>(), "AzStyleBoxShadowValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignContentValue>(), "AzLayoutAlignContentValue"), (Layout::new::<AzLayoutAlignContentValue>(), "AzLayoutAlignContentValue"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackSizingValue>(), "AzGridTrackSizingValue"), (Layout::new::<AzGridTrackSizingValue>(), "AzGridTrackSizingValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutGridAutoFlowValue>(), "AzLayoutGridAutoFlowValue"), (Layout::new::<AzLayoutGridAutoFlowValue>(), "AzLayoutGridAutoFlowValue"));
+        assert_eq!((Layout::new::<azul_impl::css::GridPlacementValue>(), "AzGridPlacementValue"), (Layout::new::<AzGridPlacementValue>(), "AzGridPlacementValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutRowGapValue>(), "AzLayoutRowGapValue"), (Layout::new::<AzLayoutRowGapValue>(), "AzLayoutRowGapValue"));
+        assert_eq!((Layout::new::<azul_impl::css::LayoutColumnGapValue>(), "AzLayoutColumnGapValue"), (Layout::new::<AzLayoutColumnGapValue>(), "AzLayoutColumnGapValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutAlignItemsValue>(), "AzLayoutAlignItemsValue"), (Layout::new::<AzLayoutAlignItemsValue>(), "AzLayoutAlignItemsValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBottomValue>(), "AzLayoutBottomValue"), (Layout::new::<AzLayoutBottomValue>(), "AzLayoutBottomValue"));
         assert_eq!((Layout::new::<azul_impl::css::LayoutBoxSizingValue>(), "AzLayoutBoxSizingValue"), (Layout::new::<AzLayoutBoxSizingValue>(), "AzLayoutBoxSizingValue"));
@@ -13301,6 +16392,10 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleLineHeightValue>(), "AzStyleLineHeightValue"), (Layout::new::<AzStyleLineHeightValue>(), "AzStyleLineHeightValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTabWidthValue>(), "AzStyleTabWidthValue"), (Layout::new::<AzStyleTabWidthValue>(), "AzStyleTabWidthValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextAlignValue>(), "AzStyleTextAlignValue"), (Layout::new::<AzStyleTextAlignValue>(), "AzStyleTextAlignValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleDirectionValue>(), "AzStyleDirectionValue"), (Layout::new::<AzStyleDirectionValue>(), "AzStyleDirectionValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleWordBreakValue>(), "AzStyleWordBreakValue"), (Layout::new::<AzStyleWordBreakValue>(), "AzStyleWordBreakValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleOverflowWrapValue>(), "AzStyleOverflowWrapValue"), (Layout::new::<AzStyleOverflowWrapValue>(), "AzStyleOverflowWrapValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleHyphensValue>(), "AzStyleHyphensValue"), (Layout::new::<AzStyleHyphensValue>(), "AzStyleHyphensValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTextColorValue>(), "AzStyleTextColorValue"), (Layout::new::<AzStyleTextColorValue>(), "AzStyleTextColorValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleWordSpacingValue>(), "AzStyleWordSpacingValue"), (Layout::new::<AzStyleWordSpacingValue>(), "AzStyleWordSpacingValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleOpacityValue>(), "AzStyleOpacityValue"), (Layout::new::<AzStyleOpacityValue>(), "AzStyleOpacityValue"));
@@ -13308,6 +16403,9 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StylePerspectiveOriginValue>(), "AzStylePerspectiveOriginValue"), (Layout::new::<AzStylePerspectiveOriginValue>(), "AzStylePerspectiveOriginValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackfaceVisibilityValue>(), "AzStyleBackfaceVisibilityValue"), (Layout::new::<AzStyleBackfaceVisibilityValue>(), "AzStyleBackfaceVisibilityValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleMixBlendModeValue>(), "AzStyleMixBlendModeValue"), (Layout::new::<AzStyleMixBlendModeValue>(), "AzStyleMixBlendModeValue"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonItemState>(), "AzRibbonItemState"), (Layout::new::<AzRibbonItemState>(), "AzRibbonItemState"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonOnItemClick>(), "AzRibbonOnItemClick"), (Layout::new::<AzRibbonOnItemClick>(), "AzRibbonOnItemClick"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonOnTabClicked>(), "AzRibbonOnTabClicked"), (Layout::new::<AzRibbonOnTabClicked>(), "AzRibbonOnTabClicked"));
         assert_eq!((Layout::new::<crate::widgets::button::ButtonOnClick>(), "AzButtonOnClick"), (Layout::new::<AzButtonOnClick>(), "AzButtonOnClick"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputOnPathChange>(), "AzFileInputOnPathChange"), (Layout::new::<AzFileInputOnPathChange>(), "AzFileInputOnPathChange"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxOnToggle>(), "AzCheckBoxOnToggle"), (Layout::new::<AzCheckBoxOnToggle>(), "AzCheckBoxOnToggle"));
@@ -13318,9 +16416,18 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnVirtualKeyDown>(), "AzTextInputOnVirtualKeyDown"), (Layout::new::<AzTextInputOnVirtualKeyDown>(), "AzTextInputOnVirtualKeyDown"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputOnFocusLost>(), "AzTextInputOnFocusLost"), (Layout::new::<AzTextInputOnFocusLost>(), "AzTextInputOnFocusLost"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OnTextInputReturn>(), "AzOnTextInputReturn"), (Layout::new::<AzOnTextInputReturn>(), "AzOnTextInputReturn"));
+        assert_eq!((Layout::new::<crate::widgets::text_area::TextAreaOnTextChange>(), "AzTextAreaOnTextChange"), (Layout::new::<AzTextAreaOnTextChange>(), "AzTextAreaOnTextChange"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnValueChange>(), "AzNumberInputOnValueChange"), (Layout::new::<AzNumberInputOnValueChange>(), "AzNumberInputOnValueChange"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputOnFocusLost>(), "AzNumberInputOnFocusLost"), (Layout::new::<AzNumberInputOnFocusLost>(), "AzNumberInputOnFocusLost"));
+        assert_eq!((Layout::new::<crate::widgets::progressbar::ProgressBarState>(), "AzProgressBarState"), (Layout::new::<AzProgressBarState>(), "AzProgressBarState"));
+        assert_eq!((Layout::new::<crate::widgets::spinner::Spinner>(), "AzSpinner"), (Layout::new::<AzSpinner>(), "AzSpinner"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabOnClick>(), "AzTabOnClick"), (Layout::new::<AzTabOnClick>(), "AzTabOnClick"));
+        assert_eq!((Layout::new::<crate::widgets::tabs::TabOnClose>(), "AzTabOnClose"), (Layout::new::<AzTabOnClose>(), "AzTabOnClose"));
+        assert_eq!((Layout::new::<crate::widgets::tabs::TabOnReorder>(), "AzTabOnReorder"), (Layout::new::<AzTabOnReorder>(), "AzTabOnReorder"));
+        assert_eq!((Layout::new::<crate::widgets::tabs::TabOnContextMenu>(), "AzTabOnContextMenu"), (Layout::new::<AzTabOnContextMenu>(), "AzTabOnContextMenu"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterOnResize>(), "AzSplitterOnResize"), (Layout::new::<AzSplitterOnResize>(), "AzSplitterOnResize"));
+        assert_eq!((Layout::new::<crate::widgets::slider::SliderOnValueChange>(), "AzSliderOnValueChange"), (Layout::new::<AzSliderOnValueChange>(), "AzSliderOnValueChange"));
+        assert_eq!((Layout::new::<crate::widgets::slider::RangeSliderOnValueChange>(), "AzRangeSliderOnValueChange"), (Layout::new::<AzRangeSliderOnValueChange>(), "AzRangeSliderOnValueChange"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeAdded>(), "AzNodeGraphOnNodeAdded"), (Layout::new::<AzNodeGraphOnNodeAdded>(), "AzNodeGraphOnNodeAdded"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeRemoved>(), "AzNodeGraphOnNodeRemoved"), (Layout::new::<AzNodeGraphOnNodeRemoved>(), "AzNodeGraphOnNodeRemoved"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OnNodeGraphDragged>(), "AzNodeGraphOnNodeGraphDragged"), (Layout::new::<AzNodeGraphOnNodeGraphDragged>(), "AzNodeGraphOnNodeGraphDragged"));
@@ -13334,6 +16441,12 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnLazyLoadScroll>(), "AzListViewOnLazyLoadScroll"), (Layout::new::<AzListViewOnLazyLoadScroll>(), "AzListViewOnLazyLoadScroll"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnClick>(), "AzListViewOnColumnClick"), (Layout::new::<AzListViewOnColumnClick>(), "AzListViewOnColumnClick"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnRowClick>(), "AzListViewOnRowClick"), (Layout::new::<AzListViewOnRowClick>(), "AzListViewOnRowClick"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewOnColumnResize>(), "AzListViewOnColumnResize"), (Layout::new::<AzListViewOnColumnResize>(), "AzListViewOnColumnResize"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowProvider>(), "AzListViewRowProvider"), (Layout::new::<AzListViewRowProvider>(), "AzListViewRowProvider"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnExpand>(), "AzTreeViewOnExpand"), (Layout::new::<AzTreeViewOnExpand>(), "AzTreeViewOnExpand"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnSelect>(), "AzTreeViewOnSelect"), (Layout::new::<AzTreeViewOnSelect>(), "AzTreeViewOnSelect"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnDoubleClick>(), "AzTreeViewOnDoubleClick"), (Layout::new::<AzTreeViewOnDoubleClick>(), "AzTreeViewOnDoubleClick"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewOnContextMenu>(), "AzTreeViewOnContextMenu"), (Layout::new::<AzTreeViewOnContextMenu>(), "AzTreeViewOnContextMenu"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::DropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"), (Layout::new::<AzDropDownOnChoiceChange>(), "AzDropDownOnChoiceChange"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::ParentWithNodeDepth>(), "AzParentWithNodeDepth"), (Layout::new::<AzParentWithNodeDepth>(), "AzParentWithNodeDepth"));
         assert_eq!((Layout::new::<azul_impl::gl::GlContextPtr>(), "AzGl"), (Layout::new::<AzGl>(), "AzGl"));
@@ -13349,11 +16462,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::Duration>(), "AzDuration"), (Layout::new::<AzDuration>(), "AzDuration"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadSendMsg>(), "AzThreadSendMsg"), (Layout::new::<AzThreadSendMsg>(), "AzThreadSendMsg"));
         assert_eq!((Layout::new::<azul_impl::task::ThreadWriteBackMsg>(), "AzThreadWriteBackMsg"), (Layout::new::<AzThreadWriteBackMsg>(), "AzThreadWriteBackMsg"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeIdVec>(), "AzTreeViewNodeIdVec"), (Layout::new::<AzTreeViewNodeIdVec>(), "AzTreeViewNodeIdVec"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneStateVec>(), "AzSplitterPaneStateVec"), (Layout::new::<AzSplitterPaneStateVec>(), "AzSplitterPaneStateVec"));
         assert_eq!((Layout::new::<azul_core::window::LogicalRectVec>(), "AzLogicalRectVec"), (Layout::new::<AzLogicalRectVec>(), "AzLogicalRectVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputTypeIdVec>(), "AzInputOutputTypeIdVec"), (Layout::new::<AzInputOutputTypeIdVec>(), "AzInputOutputTypeIdVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputNodeAndIndexVec>(), "AzOutputNodeAndIndexVec"), (Layout::new::<AzOutputNodeAndIndexVec>(), "AzOutputNodeAndIndexVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputNodeAndIndexVec>(), "AzInputNodeAndIndexVec"), (Layout::new::<AzInputNodeAndIndexVec>(), "AzInputNodeAndIndexVec"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityStateVec>(), "AzAccessibilityStateVec"), (Layout::new::<AzAccessibilityStateVec>(), "AzAccessibilityStateVec"));
+        assert_eq!((Layout::new::<azul_impl::dom::AccessibilityActionVec>(), "AzAccessibilityActionVec"), (Layout::new::<AzAccessibilityActionVec>(), "AzAccessibilityActionVec"));
         assert_eq!((Layout::new::<azul_core::window::MenuItemVec>(), "AzMenuItemVec"), (Layout::new::<AzMenuItemVec>(), "AzMenuItemVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNodeVec>(), "AzXmlNodeVec"), (Layout::new::<AzXmlNodeVec>(), "AzXmlNodeVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineGlyphVec>(), "AzInlineGlyphVec"), (Layout::new::<AzInlineGlyphVec>(), "AzInlineGlyphVec"));
@@ -13363,6 +16479,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundPositionVec>(), "AzStyleBackgroundPositionVec"), (Layout::new::<AzStyleBackgroundPositionVec>(), "AzStyleBackgroundPositionVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVec>(), "AzStyleBackgroundRepeatVec"), (Layout::new::<AzStyleBackgroundRepeatVec>(), "AzStyleBackgroundRepeatVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVec>(), "AzStyleBackgroundSizeVec"), (Layout::new::<AzStyleBackgroundSizeVec>(), "AzStyleBackgroundSizeVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionPropertyVec>(), "AzStyleTransitionPropertyVec"), (Layout::new::<AzStyleTransitionPropertyVec>(), "AzStyleTransitionPropertyVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTimeVec>(), "AzStyleTimeVec"), (Layout::new::<AzStyleTimeVec>(), "AzStyleTimeVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTimingFunctionVec>(), "AzStyleTimingFunctionVec"), (Layout::new::<AzStyleTimingFunctionVec>(), "AzStyleTimingFunctionVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationIterationCountVec>(), "AzStyleAnimationIterationCountVec"), (Layout::new::<AzStyleAnimationIterationCountVec>(), "AzStyleAnimationIterationCountVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDirectionVec>(), "AzStyleAnimationDirectionVec"), (Layout::new::<AzStyleAnimationDirectionVec>(), "AzStyleAnimationDirectionVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationFillModeVec>(), "AzStyleAnimationFillModeVec"), (Layout::new::<AzStyleAnimationFillModeVec>(), "AzStyleAnimationFillModeVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationPlayStateVec>(), "AzStyleAnimationPlayStateVec"), (Layout::new::<AzStyleAnimationPlayStateVec>(), "AzStyleAnimationPlayStateVec"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackSizingVec>(), "AzGridTrackSizingVec"), (Layout::new::<AzGridTrackSizingVec>(), "AzGridTrackSizingVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgVertexVec>(), "AzSvgVertexVec"), (Layout::new::<AzSvgVertexVec>(), "AzSvgVertexVec"));
         assert_eq!((Layout::new::<azul_impl::css::U32Vec>(), "AzU32Vec"), (Layout::new::<AzU32Vec>(), "AzU32Vec"));
         assert_eq!((Layout::new::<azul_core::window::XWindowTypeVec>(), "AzXWindowTypeVec"), (Layout::new::<AzXWindowTypeVec>(), "AzXWindowTypeVec"));
@@ -13380,8 +16504,15 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::styled_dom::NodeHierarchyItemVec>(), "AzNodeHierarchyItemVec"), (Layout::new::<AzNodeHierarchyItemVec>(), "AzNodeHierarchyItemVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::ParentWithNodeDepthVec>(), "AzParentWithNodeDepthVec"), (Layout::new::<AzParentWithNodeDepthVec>(), "AzParentWithNodeDepthVec"));
         assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnRowClick>(), "AzOptionListViewOnRowClick"), (Layout::new::<AzOptionListViewOnRowClick>(), "AzOptionListViewOnRowClick"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewNodeId>(), "AzOptionTreeViewNodeId"), (Layout::new::<AzOptionTreeViewNodeId>(), "AzOptionTreeViewNodeId"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnExpand>(), "AzOptionTreeViewOnExpand"), (Layout::new::<AzOptionTreeViewOnExpand>(), "AzOptionTreeViewOnExpand"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnSelect>(), "AzOptionTreeViewOnSelect"), (Layout::new::<AzOptionTreeViewOnSelect>(), "AzOptionTreeViewOnSelect"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnDoubleClick>(), "AzOptionTreeViewOnDoubleClick"), (Layout::new::<AzOptionTreeViewOnDoubleClick>(), "AzOptionTreeViewOnDoubleClick"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::OptionTreeViewOnContextMenu>(), "AzOptionTreeViewOnContextMenu"), (Layout::new::<AzOptionTreeViewOnContextMenu>(), "AzOptionTreeViewOnContextMenu"));
         assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnColumnClick>(), "AzOptionListViewOnColumnClick"), (Layout::new::<AzOptionListViewOnColumnClick>(), "AzOptionListViewOnColumnClick"));
         assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnLazyLoadScroll>(), "AzOptionListViewOnLazyLoadScroll"), (Layout::new::<AzOptionListViewOnLazyLoadScroll>(), "AzOptionListViewOnLazyLoadScroll"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewOnColumnResize>(), "AzOptionListViewOnColumnResize"), (Layout::new::<AzOptionListViewOnColumnResize>(), "AzOptionListViewOnColumnResize"));
+        assert_eq!((Layout::new::<crate::widgets::list_view::OptionListViewRowProvider>(), "AzOptionListViewRowProvider"), (Layout::new::<AzOptionListViewRowProvider>(), "AzOptionListViewRowProvider"));
         assert_eq!((Layout::new::<azul_impl::css::OptionPixelValueNoPercent>(), "AzOptionPixelValueNoPercent"), (Layout::new::<AzOptionPixelValueNoPercent>(), "AzOptionPixelValueNoPercent"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::OptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"), (Layout::new::<AzOptionDropDownOnChoiceChange>(), "AzOptionDropDownOnChoiceChange"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OptionOnNodeAdded>(), "AzOptionNodeGraphOnNodeAdded"), (Layout::new::<AzOptionNodeGraphOnNodeAdded>(), "AzOptionNodeGraphOnNodeAdded"));
@@ -13395,12 +16526,21 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::color_input::OptionColorInputOnValueChange>(), "AzOptionColorInputOnValueChange"), (Layout::new::<AzOptionColorInputOnValueChange>(), "AzOptionColorInputOnValueChange"));
         assert_eq!((Layout::new::<crate::widgets::button::OptionButtonOnClick>(), "AzOptionButtonOnClick"), (Layout::new::<AzOptionButtonOnClick>(), "AzOptionButtonOnClick"));
         assert_eq!((Layout::new::<crate::widgets::tabs::OptionTabOnClick>(), "AzOptionTabOnClick"), (Layout::new::<AzOptionTabOnClick>(), "AzOptionTabOnClick"));
+        assert_eq!((Layout::new::<crate::widgets::tabs::OptionTabOnClose>(), "AzOptionTabOnClose"), (Layout::new::<AzOptionTabOnClose>(), "AzOptionTabOnClose"));
+        assert_eq!((Layout::new::<crate::widgets::tabs::OptionTabOnReorder>(), "AzOptionTabOnReorder"), (Layout::new::<AzOptionTabOnReorder>(), "AzOptionTabOnReorder"));
+        assert_eq!((Layout::new::<crate::widgets::tabs::OptionTabOnContextMenu>(), "AzOptionTabOnContextMenu"), (Layout::new::<AzOptionTabOnContextMenu>(), "AzOptionTabOnContextMenu"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::OptionRibbonOnTabClicked>(), "AzOptionRibbonOnTabClicked"), (Layout::new::<AzOptionRibbonOnTabClicked>(), "AzOptionRibbonOnTabClicked"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::OptionRibbonOnItemClick>(), "AzOptionRibbonOnItemClick"), (Layout::new::<AzOptionRibbonOnItemClick>(), "AzOptionRibbonOnItemClick"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::OptionSplitterOnResize>(), "AzOptionSplitterOnResize"), (Layout::new::<AzOptionSplitterOnResize>(), "AzOptionSplitterOnResize"));
+        assert_eq!((Layout::new::<crate::widgets::slider::OptionSliderOnValueChange>(), "AzOptionSliderOnValueChange"), (Layout::new::<AzOptionSliderOnValueChange>(), "AzOptionSliderOnValueChange"));
+        assert_eq!((Layout::new::<crate::widgets::slider::OptionRangeSliderOnValueChange>(), "AzOptionRangeSliderOnValueChange"), (Layout::new::<AzOptionRangeSliderOnValueChange>(), "AzOptionRangeSliderOnValueChange"));
         assert_eq!((Layout::new::<crate::widgets::file_input::OptionFileInputOnPathChange>(), "AzOptionFileInputOnPathChange"), (Layout::new::<AzOptionFileInputOnPathChange>(), "AzOptionFileInputOnPathChange"));
         assert_eq!((Layout::new::<crate::widgets::check_box::OptionCheckBoxOnToggle>(), "AzOptionCheckBoxOnToggle"), (Layout::new::<AzOptionCheckBoxOnToggle>(), "AzOptionCheckBoxOnToggle"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnTextInput>(), "AzOptionTextInputOnTextInput"), (Layout::new::<AzOptionTextInputOnTextInput>(), "AzOptionTextInputOnTextInput"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnVirtualKeyDown>(), "AzOptionTextInputOnVirtualKeyDown"), (Layout::new::<AzOptionTextInputOnVirtualKeyDown>(), "AzOptionTextInputOnVirtualKeyDown"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputOnFocusLost>(), "AzOptionTextInputOnFocusLost"), (Layout::new::<AzOptionTextInputOnFocusLost>(), "AzOptionTextInputOnFocusLost"));
         assert_eq!((Layout::new::<crate::widgets::text_input::OptionTextInputSelection>(), "AzOptionTextInputSelection"), (Layout::new::<AzOptionTextInputSelection>(), "AzOptionTextInputSelection"));
+        assert_eq!((Layout::new::<crate::widgets::text_area::OptionTextAreaOnTextChange>(), "AzOptionTextAreaOnTextChange"), (Layout::new::<AzOptionTextAreaOnTextChange>(), "AzOptionTextAreaOnTextChange"));
         assert_eq!((Layout::new::<crate::widgets::number_input::OptionNumberInputOnFocusLost>(), "AzOptionNumberInputOnFocusLost"), (Layout::new::<AzOptionNumberInputOnFocusLost>(), "AzOptionNumberInputOnFocusLost"));
         assert_eq!((Layout::new::<crate::widgets::number_input::OptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"), (Layout::new::<AzOptionNumberInputOnValueChange>(), "AzOptionNumberInputOnValueChange"));
         assert_eq!((Layout::new::<azul_core::window::OptionMenuItemIcon>(), "AzOptionMenuItemIcon"), (Layout::new::<AzOptionMenuItemIcon>(), "AzOptionMenuItemIcon"));
@@ -13427,6 +16567,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::OptionColorU>(), "AzOptionColorU"), (Layout::new::<AzOptionColorU>(), "AzOptionColorU"));
         assert_eq!((Layout::new::<azul_impl::svg::OptionSvgDashPattern>(), "AzOptionSvgDashPattern"), (Layout::new::<AzOptionSvgDashPattern>(), "AzOptionSvgDashPattern"));
         assert_eq!((Layout::new::<azul_core::window::OptionLogicalPosition>(), "AzOptionLogicalPosition"), (Layout::new::<AzOptionLogicalPosition>(), "AzOptionLogicalPosition"));
+        assert_eq!((Layout::new::<azul_core::window::OptionLogicalRect>(), "AzOptionLogicalRect"), (Layout::new::<AzOptionLogicalRect>(), "AzOptionLogicalRect"));
         assert_eq!((Layout::new::<azul_core::window::OptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"), (Layout::new::<AzOptionPhysicalPositionI32>(), "AzOptionPhysicalPositionI32"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseCursorType>(), "AzOptionMouseCursorType"), (Layout::new::<AzOptionMouseCursorType>(), "AzOptionMouseCursorType"));
         assert_eq!((Layout::new::<azul_core::window::OptionLogicalSize>(), "AzOptionLogicalSize"), (Layout::new::<AzOptionLogicalSize>(), "AzOptionLogicalSize"));
@@ -13449,7 +16590,6 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::WindowIcon>(), "AzWindowIcon"), (Layout::new::<AzWindowIcon>(), "AzWindowIcon"));
         assert_eq!((Layout::new::<azul_core::window::TaskBarIcon>(), "AzTaskBarIcon"), (Layout::new::<AzTaskBarIcon>(), "AzTaskBarIcon"));
         assert_eq!((Layout::new::<azul_core::window::WindowSize>(), "AzWindowSize"), (Layout::new::<AzWindowSize>(), "AzWindowSize"));
-        assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::MouseState>(), "AzMouseState"), (Layout::new::<AzMouseState>(), "AzMouseState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::MarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"), (Layout::new::<AzMarshaledLayoutCallback>(), "AzMarshaledLayoutCallback"));
         assert_eq!((Layout::new::<azul_core::callbacks::InlineTextContents>(), "AzInlineTextContents"), (Layout::new::<AzInlineTextContents>(), "AzInlineTextContents"));
@@ -13461,6 +16601,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_core::window::Menu>(), "AzMenu"), (Layout::new::<AzMenu>(), "AzMenu"));
         assert_eq!((Layout::new::<azul_core::window::VirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"), (Layout::new::<AzVirtualKeyCodeCombo>(), "AzVirtualKeyCodeCombo"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathPseudoSelector>(), "AzCssPathPseudoSelector"), (Layout::new::<AzCssPathPseudoSelector>(), "AzCssPathPseudoSelector"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaFeature>(), "AzCssMediaFeature"), (Layout::new::<AzCssMediaFeature>(), "AzCssMediaFeature"));
         assert_eq!((Layout::new::<azul_impl::css::AnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"), (Layout::new::<AzAnimationInterpolationFunction>(), "AzAnimationInterpolationFunction"));
         assert_eq!((Layout::new::<azul_impl::css::InterpolateResolver>(), "AzInterpolateContext"), (Layout::new::<AzInterpolateContext>(), "AzInterpolateContext"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilter>(), "AzStyleFilter"), (Layout::new::<AzStyleFilter>(), "AzStyleFilter"));
@@ -13468,11 +16609,21 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::RadialGradient>(), "AzRadialGradient"), (Layout::new::<AzRadialGradient>(), "AzRadialGradient"));
         assert_eq!((Layout::new::<azul_impl::css::ConicGradient>(), "AzConicGradient"), (Layout::new::<AzConicGradient>(), "AzConicGradient"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransform>(), "AzStyleTransform"), (Layout::new::<AzStyleTransform>(), "AzStyleTransform"));
+        assert_eq!((Layout::new::<azul_impl::css::GridTrackSizingVecValue>(), "AzGridTrackSizingVecValue"), (Layout::new::<AzGridTrackSizingVecValue>(), "AzGridTrackSizingVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTransitionPropertyVecValue>(), "AzStyleTransitionPropertyVecValue"), (Layout::new::<AzStyleTransitionPropertyVecValue>(), "AzStyleTransitionPropertyVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTimeVecValue>(), "AzStyleTimeVecValue"), (Layout::new::<AzStyleTimeVecValue>(), "AzStyleTimeVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleTimingFunctionVecValue>(), "AzStyleTimingFunctionVecValue"), (Layout::new::<AzStyleTimingFunctionVecValue>(), "AzStyleTimingFunctionVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationIterationCountVecValue>(), "AzStyleAnimationIterationCountVecValue"), (Layout::new::<AzStyleAnimationIterationCountVecValue>(), "AzStyleAnimationIterationCountVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationDirectionVecValue>(), "AzStyleAnimationDirectionVecValue"), (Layout::new::<AzStyleAnimationDirectionVecValue>(), "AzStyleAnimationDirectionVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationFillModeVecValue>(), "AzStyleAnimationFillModeVecValue"), (Layout::new::<AzStyleAnimationFillModeVecValue>(), "AzStyleAnimationFillModeVecValue"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationPlayStateVecValue>(), "AzStyleAnimationPlayStateVecValue"), (Layout::new::<AzStyleAnimationPlayStateVecValue>(), "AzStyleAnimationPlayStateVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundPositionVecValue>(), "AzStyleBackgroundPositionVecValue"), (Layout::new::<AzStyleBackgroundPositionVecValue>(), "AzStyleBackgroundPositionVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"), (Layout::new::<AzStyleBackgroundRepeatVecValue>(), "AzStyleBackgroundRepeatVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"), (Layout::new::<AzStyleBackgroundSizeVecValue>(), "AzStyleBackgroundSizeVecValue"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"), (Layout::new::<AzCheckBoxStateWrapper>(), "AzCheckBoxStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::text_area::TextAreaEdit>(), "AzTextAreaEdit"), (Layout::new::<AzTextAreaEdit>(), "AzTextAreaEdit"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInputStateWrapper>(), "AzNumberInputStateWrapper"), (Layout::new::<AzNumberInputStateWrapper>(), "AzNumberInputStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterState>(), "AzSplitterState"), (Layout::new::<AzSplitterState>(), "AzSplitterState"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraphCallbacks>(), "AzNodeGraphCallbacks"), (Layout::new::<AzNodeGraphCallbacks>(), "AzNodeGraphCallbacks"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputConnection>(), "AzInputConnection"), (Layout::new::<AzInputConnection>(), "AzInputConnection"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnection>(), "AzOutputConnection"), (Layout::new::<AzOutputConnection>(), "AzOutputConnection"));
@@ -13493,12 +16644,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::ThreadReceiveMsg>(), "AzThreadReceiveMsg"), (Layout::new::<AzThreadReceiveMsg>(), "AzThreadReceiveMsg"));
         assert_eq!((Layout::new::<azul_impl::css::AzString>(), "AzString"), (Layout::new::<AzString>(), "AzString"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewRowVec>(), "AzListViewRowVec"), (Layout::new::<AzListViewRowVec>(), "AzListViewRowVec"));
+        assert_eq!((Layout::new::<crate::widgets::text_area::TextAreaEditVec>(), "AzTextAreaEditVec"), (Layout::new::<AzTextAreaEditVec>(), "AzTextAreaEditVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVec>(), "AzStyleFilterVec"), (Layout::new::<AzStyleFilterVec>(), "AzStyleFilterVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputConnectionVec>(), "AzInputConnectionVec"), (Layout::new::<AzInputConnectionVec>(), "AzInputConnectionVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::OutputConnectionVec>(), "AzOutputConnectionVec"), (Layout::new::<AzOutputConnectionVec>(), "AzOutputConnectionVec"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"), (Layout::new::<AzTessellatedSvgNodeVec>(), "AzTessellatedSvgNodeVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVec>(), "AzStyleTransformVec"), (Layout::new::<AzStyleTransformVec>(), "AzStyleTransformVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathElementVec>(), "AzSvgPathElementVec"), (Layout::new::<AzSvgPathElementVec>(), "AzSvgPathElementVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaFeatureVec>(), "AzCssMediaFeatureVec"), (Layout::new::<AzCssMediaFeatureVec>(), "AzCssMediaFeatureVec"));
         assert_eq!((Layout::new::<azul_impl::css::StringVec>(), "AzStringVec"), (Layout::new::<AzStringVec>(), "AzStringVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledNodeVec>(), "AzStyledNodeVec"), (Layout::new::<AzStyledNodeVec>(), "AzStyledNodeVec"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::TagIdToNodeIdMappingVec>(), "AzTagIdToNodeIdMappingVec"), (Layout::new::<AzTagIdToNodeIdMappingVec>(), "AzTagIdToNodeIdMappingVec"));
@@ -13506,7 +16659,6 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::ui_solver::OptionResolvedTextLayoutOptions>(), "AzOptionResolvedTextLayoutOptions"), (Layout::new::<AzOptionResolvedTextLayoutOptions>(), "AzOptionResolvedTextLayoutOptions"));
         assert_eq!((Layout::new::<azul_core::window::OptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"), (Layout::new::<AzOptionVirtualKeyCodeCombo>(), "AzOptionVirtualKeyCodeCombo"));
         assert_eq!((Layout::new::<azul_core::window::OptionMouseState>(), "AzOptionMouseState"), (Layout::new::<AzOptionMouseState>(), "AzOptionMouseState"));
-        assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
         assert_eq!((Layout::new::<azul_impl::css::OptionStringVec>(), "AzOptionStringVec"), (Layout::new::<AzOptionStringVec>(), "AzOptionStringVec"));
         assert_eq!((Layout::new::<azul_impl::task::OptionThreadReceiveMsg>(), "AzOptionThreadReceiveMsg"), (Layout::new::<AzOptionThreadReceiveMsg>(), "AzOptionThreadReceiveMsg"));
         assert_eq!((Layout::new::<azul_core::window::OptionTaskBarIcon>(), "AzOptionTaskBarIcon"), (Layout::new::<AzOptionTaskBarIcon>(), "AzOptionTaskBarIcon"));
@@ -13520,6 +16672,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::xml::UnknownEntityReferenceError>(), "AzUnknownEntityReferenceError"), (Layout::new::<AzUnknownEntityReferenceError>(), "AzUnknownEntityReferenceError"));
         assert_eq!((Layout::new::<azul_impl::xml::DuplicatedAttributeError>(), "AzDuplicatedAttributeError"), (Layout::new::<AzDuplicatedAttributeError>(), "AzDuplicatedAttributeError"));
         assert_eq!((Layout::new::<azul_impl::xml::InvalidStringError>(), "AzInvalidStringError"), (Layout::new::<AzInvalidStringError>(), "AzInvalidStringError"));
+        assert_eq!((Layout::new::<azul_core::window::ClipboardContent>(), "AzClipboardContent"), (Layout::new::<AzClipboardContent>(), "AzClipboardContent"));
+        assert_eq!((Layout::new::<azul_core::window::ImeComposition>(), "AzImeComposition"), (Layout::new::<AzImeComposition>(), "AzImeComposition"));
         assert_eq!((Layout::new::<azul_core::window::WindowsWindowOptions>(), "AzWindowsWindowOptions"), (Layout::new::<AzWindowsWindowOptions>(), "AzWindowsWindowOptions"));
         assert_eq!((Layout::new::<azul_core::window::WaylandTheme>(), "AzWaylandTheme"), (Layout::new::<AzWaylandTheme>(), "AzWaylandTheme"));
         assert_eq!((Layout::new::<azul_core::window::AzStringPair>(), "AzStringPair"), (Layout::new::<AzStringPair>(), "AzStringPair"));
@@ -13529,26 +16683,32 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::dom::CallbackData>(), "AzCallbackData"), (Layout::new::<AzCallbackData>(), "AzCallbackData"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeType>(), "AzNodeType"), (Layout::new::<AzNodeType>(), "AzNodeType"));
         assert_eq!((Layout::new::<azul_impl::dom::AccessibilityInfo>(), "AzAccessibilityInfo"), (Layout::new::<AzAccessibilityInfo>(), "AzAccessibilityInfo"));
+        assert_eq!((Layout::new::<azul_impl::dom::TooltipContent>(), "AzTooltipContent"), (Layout::new::<AzTooltipContent>(), "AzTooltipContent"));
         assert_eq!((Layout::new::<azul_impl::dom::IdOrClass>(), "AzIdOrClass"), (Layout::new::<AzIdOrClass>(), "AzIdOrClass"));
         assert_eq!((Layout::new::<azul_core::window::StringMenuItem>(), "AzStringMenuItem"), (Layout::new::<AzStringMenuItem>(), "AzStringMenuItem"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelector>(), "AzCssPathSelector"), (Layout::new::<AzCssPathSelector>(), "AzCssPathSelector"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaQuery>(), "AzCssMediaQuery"), (Layout::new::<AzCssMediaQuery>(), "AzCssMediaQuery"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContent>(), "AzStyleBackgroundContent"), (Layout::new::<AzStyleBackgroundContent>(), "AzStyleBackgroundContent"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarInfo>(), "AzScrollbarInfo"), (Layout::new::<AzScrollbarInfo>(), "AzScrollbarInfo"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyle>(), "AzScrollbarStyle"), (Layout::new::<AzScrollbarStyle>(), "AzScrollbarStyle"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamily>(), "AzStyleFontFamily"), (Layout::new::<AzStyleFontFamily>(), "AzStyleFontFamily"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationName>(), "AzStyleAnimationName"), (Layout::new::<AzStyleAnimationName>(), "AzStyleAnimationName"));
         assert_eq!((Layout::new::<azul_impl::css::ScrollbarStyleValue>(), "AzScrollbarStyleValue"), (Layout::new::<AzScrollbarStyleValue>(), "AzScrollbarStyleValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleTransformVecValue>(), "AzStyleTransformVecValue"), (Layout::new::<AzStyleTransformVecValue>(), "AzStyleTransformVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFilterVecValue>(), "AzStyleFilterVecValue"), (Layout::new::<AzStyleFilterVecValue>(), "AzStyleFilterVecValue"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonItem>(), "AzRibbonItem"), (Layout::new::<AzRibbonItem>(), "AzRibbonItem"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputState>(), "AzFileInputState"), (Layout::new::<AzFileInputState>(), "AzFileInputState"));
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInputStateWrapper>(), "AzColorInputStateWrapper"), (Layout::new::<AzColorInputStateWrapper>(), "AzColorInputStateWrapper"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputState>(), "AzTextInputState"), (Layout::new::<AzTextInputState>(), "AzTextInputState"));
+        assert_eq!((Layout::new::<crate::widgets::text_area::TextAreaState>(), "AzTextAreaState"), (Layout::new::<AzTextAreaState>(), "AzTextAreaState"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabHeader>(), "AzTabHeader"), (Layout::new::<AzTabHeader>(), "AzTabHeader"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeFieldValue>(), "AzNodeTypeFieldValue"), (Layout::new::<AzNodeTypeFieldValue>(), "AzNodeTypeFieldValue"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeInfo>(), "AzNodeTypeInfo"), (Layout::new::<AzNodeTypeInfo>(), "AzNodeTypeInfo"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputInfo>(), "AzInputOutputInfo"), (Layout::new::<AzInputOutputInfo>(), "AzInputOutputInfo"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListView>(), "AzListView"), (Layout::new::<AzListView>(), "AzListView"));
         assert_eq!((Layout::new::<crate::widgets::list_view::ListViewState>(), "AzListViewState"), (Layout::new::<AzListViewState>(), "AzListViewState"));
-        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeView>(), "AzTreeView"), (Layout::new::<AzTreeView>(), "AzTreeView"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNode>(), "AzTreeViewNode"), (Layout::new::<AzTreeViewNode>(), "AzTreeViewNode"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewState>(), "AzTreeViewState"), (Layout::new::<AzTreeViewState>(), "AzTreeViewState"));
         assert_eq!((Layout::new::<crate::widgets::drop_down::DropDown>(), "AzDropDown"), (Layout::new::<AzDropDown>(), "AzDropDown"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttribute>(), "AzVertexAttribute"), (Layout::new::<AzVertexAttribute>(), "AzVertexAttribute"));
         assert_eq!((Layout::new::<azul_impl::gl::AzDebugMessage>(), "AzDebugMessage"), (Layout::new::<AzDebugMessage>(), "AzDebugMessage"));
@@ -13563,15 +16723,19 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::task::Timer>(), "AzTimer"), (Layout::new::<AzTimer>(), "AzTimer"));
         assert_eq!((Layout::new::<azul_impl::str::FmtValue>(), "AzFmtValue"), (Layout::new::<AzFmtValue>(), "AzFmtValue"));
         assert_eq!((Layout::new::<azul_impl::str::FmtArg>(), "AzFmtArg"), (Layout::new::<AzFmtArg>(), "AzFmtArg"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeViewNodeVec>(), "AzTreeViewNodeVec"), (Layout::new::<AzTreeViewNodeVec>(), "AzTreeViewNodeVec"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonItemVec>(), "AzRibbonItemVec"), (Layout::new::<AzRibbonItemVec>(), "AzRibbonItemVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVec>(), "AzStyleFontFamilyVec"), (Layout::new::<AzStyleFontFamilyVec>(), "AzStyleFontFamilyVec"));
         assert_eq!((Layout::new::<azul_impl::str::FmtArgVec>(), "AzFmtArgVec"), (Layout::new::<AzFmtArgVec>(), "AzFmtArgVec"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineWordVec>(), "AzInlineWordVec"), (Layout::new::<AzInlineWordVec>(), "AzInlineWordVec"));
         assert_eq!((Layout::new::<azul_core::window::MonitorVec>(), "AzMonitorVec"), (Layout::new::<AzMonitorVec>(), "AzMonitorVec"));
         assert_eq!((Layout::new::<azul_impl::dom::IdOrClassVec>(), "AzIdOrClassVec"), (Layout::new::<AzIdOrClassVec>(), "AzIdOrClassVec"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVec>(), "AzStyleBackgroundContentVec"), (Layout::new::<AzStyleBackgroundContentVec>(), "AzStyleBackgroundContentVec"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationNameVec>(), "AzStyleAnimationNameVec"), (Layout::new::<AzStyleAnimationNameVec>(), "AzStyleAnimationNameVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgPathVec>(), "AzSvgPathVec"), (Layout::new::<AzSvgPathVec>(), "AzSvgPathVec"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexAttributeVec>(), "AzVertexAttributeVec"), (Layout::new::<AzVertexAttributeVec>(), "AzVertexAttributeVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssPathSelectorVec>(), "AzCssPathSelectorVec"), (Layout::new::<AzCssPathSelectorVec>(), "AzCssPathSelectorVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaQueryVec>(), "AzCssMediaQueryVec"), (Layout::new::<AzCssMediaQueryVec>(), "AzCssMediaQueryVec"));
         assert_eq!((Layout::new::<azul_impl::dom::CallbackDataVec>(), "AzCallbackDataVec"), (Layout::new::<AzCallbackDataVec>(), "AzCallbackDataVec"));
         assert_eq!((Layout::new::<azul_impl::gl::AzDebugMessageVec>(), "AzDebugMessageVec"), (Layout::new::<AzDebugMessageVec>(), "AzDebugMessageVec"));
         assert_eq!((Layout::new::<azul_core::window::StringPairVec>(), "AzStringPairVec"), (Layout::new::<AzStringPairVec>(), "AzStringPairVec"));
@@ -13579,21 +16743,29 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::file::OptionFile>(), "AzOptionFile"), (Layout::new::<AzOptionFile>(), "AzOptionFile"));
         assert_eq!((Layout::new::<azul_impl::resources::OptionRawImage>(), "AzOptionRawImage"), (Layout::new::<AzOptionRawImage>(), "AzOptionRawImage"));
         assert_eq!((Layout::new::<azul_core::window::OptionWaylandTheme>(), "AzOptionWaylandTheme"), (Layout::new::<AzOptionWaylandTheme>(), "AzOptionWaylandTheme"));
+        assert_eq!((Layout::new::<azul_core::window::OptionImeComposition>(), "AzOptionImeComposition"), (Layout::new::<AzOptionImeComposition>(), "AzOptionImeComposition"));
+        assert_eq!((Layout::new::<azul_core::window::OptionClipboardContent>(), "AzOptionClipboardContent"), (Layout::new::<AzOptionClipboardContent>(), "AzOptionClipboardContent"));
         assert_eq!((Layout::new::<azul_impl::resources::decode::ResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"), (Layout::new::<AzResultRawImageDecodeImageError>(), "AzResultRawImageDecodeImageError"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlStreamError>(), "AzXmlStreamError"), (Layout::new::<AzXmlStreamError>(), "AzXmlStreamError"));
+        assert_eq!((Layout::new::<azul_core::window::KeyboardState>(), "AzKeyboardState"), (Layout::new::<AzKeyboardState>(), "AzKeyboardState"));
         assert_eq!((Layout::new::<azul_core::window::LinuxWindowOptions>(), "AzLinuxWindowOptions"), (Layout::new::<AzLinuxWindowOptions>(), "AzLinuxWindowOptions"));
         assert_eq!((Layout::new::<azul_impl::callbacks::InlineLine>(), "AzInlineLine"), (Layout::new::<AzInlineLine>(), "AzInlineLine"));
+        assert_eq!((Layout::new::<azul_impl::dom::Tooltip>(), "AzTooltip"), (Layout::new::<AzTooltip>(), "AzTooltip"));
         assert_eq!((Layout::new::<azul_core::window::MenuItem>(), "AzMenuItem"), (Layout::new::<AzMenuItem>(), "AzMenuItem"));
         assert_eq!((Layout::new::<azul_impl::css::CssPath>(), "AzCssPath"), (Layout::new::<AzCssPath>(), "AzCssPath"));
+        assert_eq!((Layout::new::<azul_impl::css::StyleAnimationNameVecValue>(), "AzStyleAnimationNameVecValue"), (Layout::new::<AzStyleAnimationNameVecValue>(), "AzStyleAnimationNameVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"), (Layout::new::<AzStyleBackgroundContentVecValue>(), "AzStyleBackgroundContentVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::StyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"), (Layout::new::<AzStyleFontFamilyVecValue>(), "AzStyleFontFamilyVecValue"));
         assert_eq!((Layout::new::<azul_impl::css::CssProperty>(), "AzCssProperty"), (Layout::new::<AzCssProperty>(), "AzCssProperty"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonGroup>(), "AzRibbonGroup"), (Layout::new::<AzRibbonGroup>(), "AzRibbonGroup"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInputStateWrapper>(), "AzFileInputStateWrapper"), (Layout::new::<AzFileInputStateWrapper>(), "AzFileInputStateWrapper"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInputStateWrapper>(), "AzTextInputStateWrapper"), (Layout::new::<AzTextInputStateWrapper>(), "AzTextInputStateWrapper"));
+        assert_eq!((Layout::new::<crate::widgets::text_area::TextArea>(), "AzTextArea"), (Layout::new::<AzTextArea>(), "AzTextArea"));
         assert_eq!((Layout::new::<crate::widgets::progressbar::ProgressBar>(), "AzProgressBar"), (Layout::new::<AzProgressBar>(), "AzProgressBar"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeIdInfoMap>(), "AzNodeTypeIdInfoMap"), (Layout::new::<AzNodeTypeIdInfoMap>(), "AzNodeTypeIdInfoMap"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputTypeIdInfoMap>(), "AzInputOutputTypeIdInfoMap"), (Layout::new::<AzInputOutputTypeIdInfoMap>(), "AzInputOutputTypeIdInfoMap"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeField>(), "AzNodeTypeField"), (Layout::new::<AzNodeTypeField>(), "AzNodeTypeField"));
+        assert_eq!((Layout::new::<crate::widgets::tree_view::TreeView>(), "AzTreeView"), (Layout::new::<AzTreeView>(), "AzTreeView"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::CssPropertySource>(), "AzCssPropertySource"), (Layout::new::<AzCssPropertySource>(), "AzCssPropertySource"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexLayout>(), "AzVertexLayout"), (Layout::new::<AzVertexLayout>(), "AzVertexLayout"));
         assert_eq!((Layout::new::<azul_impl::gl::VertexArrayObject>(), "AzVertexArrayObject"), (Layout::new::<AzVertexArrayObject>(), "AzVertexArrayObject"));
@@ -13601,6 +16773,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::svg::SvgMultiPolygon>(), "AzSvgMultiPolygon"), (Layout::new::<AzSvgMultiPolygon>(), "AzSvgMultiPolygon"));
         assert_eq!((Layout::new::<azul_impl::svg::TessellatedGPUSvgNode>(), "AzTessellatedGPUSvgNode"), (Layout::new::<AzTessellatedGPUSvgNode>(), "AzTessellatedGPUSvgNode"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlNode>(), "AzXmlNode"), (Layout::new::<AzXmlNode>(), "AzXmlNode"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonGroupVec>(), "AzRibbonGroupVec"), (Layout::new::<AzRibbonGroupVec>(), "AzRibbonGroupVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeIdInfoMapVec>(), "AzNodeTypeIdInfoMapVec"), (Layout::new::<AzNodeTypeIdInfoMapVec>(), "AzNodeTypeIdInfoMapVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::InputOutputTypeIdInfoMapVec>(), "AzInputOutputTypeIdInfoMapVec"), (Layout::new::<AzInputOutputTypeIdInfoMapVec>(), "AzInputOutputTypeIdInfoMapVec"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeTypeFieldVec>(), "AzNodeTypeFieldVec"), (Layout::new::<AzNodeTypeFieldVec>(), "AzNodeTypeFieldVec"));
@@ -13608,6 +16781,7 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::css::CssPropertyVec>(), "AzCssPropertyVec"), (Layout::new::<AzCssPropertyVec>(), "AzCssPropertyVec"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"), (Layout::new::<AzSvgMultiPolygonVec>(), "AzSvgMultiPolygonVec"));
         assert_eq!((Layout::new::<azul_impl::css::OptionCssProperty>(), "AzOptionCssProperty"), (Layout::new::<AzOptionCssProperty>(), "AzOptionCssProperty"));
+        assert_eq!((Layout::new::<azul_core::window::OptionKeyboardState>(), "AzOptionKeyboardState"), (Layout::new::<AzOptionKeyboardState>(), "AzOptionKeyboardState"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlTextError>(), "AzXmlTextError"), (Layout::new::<AzXmlTextError>(), "AzXmlTextError"));
         assert_eq!((Layout::new::<azul_core::window::PlatformSpecificOptions>(), "AzPlatformSpecificOptions"), (Layout::new::<AzPlatformSpecificOptions>(), "AzPlatformSpecificOptions"));
         assert_eq!((Layout::new::<azul_core::window::WindowState>(), "AzWindowState"), (Layout::new::<AzWindowState>(), "AzWindowState"));
@@ -13618,10 +16792,14 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::TimerCallbackInfo>(), "AzTimerCallbackInfo"), (Layout::new::<AzTimerCallbackInfo>(), "AzTimerCallbackInfo"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"), (Layout::new::<AzNodeDataInlineCssProperty>(), "AzNodeDataInlineCssProperty"));
         assert_eq!((Layout::new::<azul_impl::css::DynamicCssProperty>(), "AzDynamicCssProperty"), (Layout::new::<AzDynamicCssProperty>(), "AzDynamicCssProperty"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframe>(), "AzCssKeyframe"), (Layout::new::<AzCssKeyframe>(), "AzCssKeyframe"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonTab>(), "AzRibbonTab"), (Layout::new::<AzRibbonTab>(), "AzRibbonTab"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::Node>(), "AzNode"), (Layout::new::<AzNode>(), "AzNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgNode>(), "AzSvgNode"), (Layout::new::<AzSvgNode>(), "AzSvgNode"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgStyledNode>(), "AzSvgStyledNode"), (Layout::new::<AzSvgStyledNode>(), "AzSvgStyledNode"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::RibbonTabVec>(), "AzRibbonTabVec"), (Layout::new::<AzRibbonTabVec>(), "AzRibbonTabVec"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"), (Layout::new::<AzNodeDataInlineCssPropertyVec>(), "AzNodeDataInlineCssPropertyVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframeVec>(), "AzCssKeyframeVec"), (Layout::new::<AzCssKeyframeVec>(), "AzCssKeyframeVec"));
         assert_eq!((Layout::new::<azul_core::window::OptionWindowState>(), "AzOptionWindowState"), (Layout::new::<AzOptionWindowState>(), "AzOptionWindowState"));
         assert_eq!((Layout::new::<azul_impl::callbacks::OptionInlineText>(), "AzOptionInlineText"), (Layout::new::<AzOptionInlineText>(), "AzOptionInlineText"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlParseError>(), "AzXmlParseError"), (Layout::new::<AzXmlParseError>(), "AzXmlParseError"));
@@ -13629,6 +16807,8 @@ mod test_sizes {
         assert_eq!((Layout::new::<azul_impl::callbacks::FocusTarget>(), "AzFocusTarget"), (Layout::new::<AzFocusTarget>(), "AzFocusTarget"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeData>(), "AzNodeData"), (Layout::new::<AzNodeData>(), "AzNodeData"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclaration>(), "AzCssDeclaration"), (Layout::new::<AzCssDeclaration>(), "AzCssDeclaration"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframes>(), "AzCssKeyframes"), (Layout::new::<AzCssKeyframes>(), "AzCssKeyframes"));
+        assert_eq!((Layout::new::<crate::widgets::ribbon::Ribbon>(), "AzRibbon"), (Layout::new::<AzRibbon>(), "AzRibbon"));
         assert_eq!((Layout::new::<crate::widgets::button::Button>(), "AzButton"), (Layout::new::<AzButton>(), "AzButton"));
         assert_eq!((Layout::new::<crate::widgets::file_input::FileInput>(), "AzFileInput"), (Layout::new::<AzFileInput>(), "AzFileInput"));
         assert_eq!((Layout::new::<crate::widgets::check_box::CheckBox>(), "AzCheckBox"), (Layout::new::<AzCheckBox>(), "AzCheckBox"));
@@ -13636,26 +16816,34 @@ mod test_sizes {
         assert_eq!((Layout::new::<crate::widgets::color_input::ColorInput>(), "AzColorInput"), (Layout::new::<AzColorInput>(), "AzColorInput"));
         assert_eq!((Layout::new::<crate::widgets::text_input::TextInput>(), "AzTextInput"), (Layout::new::<AzTextInput>(), "AzTextInput"));
         assert_eq!((Layout::new::<crate::widgets::number_input::NumberInput>(), "AzNumberInput"), (Layout::new::<AzNumberInput>(), "AzNumberInput"));
+        assert_eq!((Layout::new::<crate::widgets::slider::Slider>(), "AzSlider"), (Layout::new::<AzSlider>(), "AzSlider"));
+        assert_eq!((Layout::new::<crate::widgets::slider::RangeSlider>(), "AzRangeSlider"), (Layout::new::<AzRangeSlider>(), "AzRangeSlider"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeIdNodeMap>(), "AzNodeIdNodeMap"), (Layout::new::<AzNodeIdNodeMap>(), "AzNodeIdNodeMap"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeIdNodeMapVec>(), "AzNodeIdNodeMapVec"), (Layout::new::<AzNodeIdNodeMapVec>(), "AzNodeIdNodeMapVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssDeclarationVec>(), "AzCssDeclarationVec"), (Layout::new::<AzCssDeclarationVec>(), "AzCssDeclarationVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssKeyframesVec>(), "AzCssKeyframesVec"), (Layout::new::<AzCssKeyframesVec>(), "AzCssKeyframesVec"));
         assert_eq!((Layout::new::<azul_impl::dom::NodeDataVec>(), "AzNodeDataVec"), (Layout::new::<AzNodeDataVec>(), "AzNodeDataVec"));
         assert_eq!((Layout::new::<azul_impl::xml::XmlError>(), "AzXmlError"), (Layout::new::<AzXmlError>(), "AzXmlError"));
         assert_eq!((Layout::new::<azul_impl::dom::Dom>(), "AzDom"), (Layout::new::<AzDom>(), "AzDom"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlock>(), "AzCssRuleBlock"), (Layout::new::<AzCssRuleBlock>(), "AzCssRuleBlock"));
         assert_eq!((Layout::new::<crate::widgets::tabs::TabContent>(), "AzTabContent"), (Layout::new::<AzTabContent>(), "AzTabContent"));
         assert_eq!((Layout::new::<crate::widgets::frame::Frame>(), "AzFrame"), (Layout::new::<AzFrame>(), "AzFrame"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPane>(), "AzSplitterPane"), (Layout::new::<AzSplitterPane>(), "AzSplitterPane"));
         assert_eq!((Layout::new::<crate::widgets::node_graph::NodeGraph>(), "AzNodeGraph"), (Layout::new::<AzNodeGraph>(), "AzNodeGraph"));
         assert_eq!((Layout::new::<azul_impl::styled_dom::StyledDom>(), "AzStyledDom"), (Layout::new::<AzStyledDom>(), "AzStyledDom"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::SplitterPaneVec>(), "AzSplitterPaneVec"), (Layout::new::<AzSplitterPaneVec>(), "AzSplitterPaneVec"));
         assert_eq!((Layout::new::<azul_impl::css::CssRuleBlockVec>(), "AzCssRuleBlockVec"), (Layout::new::<AzCssRuleBlockVec>(), "AzCssRuleBlockVec"));
         assert_eq!((Layout::new::<azul_impl::dom::OptionDom>(), "AzOptionDom"), (Layout::new::<AzOptionDom>(), "AzOptionDom"));
         assert_eq!((Layout::new::<azul_impl::xml::ResultXmlXmlError>(), "AzResultXmlXmlError"), (Layout::new::<AzResultXmlXmlError>(), "AzResultXmlXmlError"));
         assert_eq!((Layout::new::<azul_impl::svg::SvgParseError>(), "AzSvgParseError"), (Layout::new::<AzSvgParseError>(), "AzSvgParseError"));
         assert_eq!((Layout::new::<azul_impl::callbacks::IFrameCallbackReturn>(), "AzIFrameCallbackReturn"), (Layout::new::<AzIFrameCallbackReturn>(), "AzIFrameCallbackReturn"));
-        assert_eq!((Layout::new::<azul_impl::css::Stylesheet>(), "AzStylesheet"), (Layout::new::<AzStylesheet>(), "AzStylesheet"));
-        assert_eq!((Layout::new::<azul_impl::css::StylesheetVec>(), "AzStylesheetVec"), (Layout::new::<AzStylesheetVec>(), "AzStylesheetVec"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaRule>(), "AzCssMediaRule"), (Layout::new::<AzCssMediaRule>(), "AzCssMediaRule"));
+        assert_eq!((Layout::new::<crate::widgets::splitter::Splitter>(), "AzSplitter"), (Layout::new::<AzSplitter>(), "AzSplitter"));
+        assert_eq!((Layout::new::<azul_impl::css::CssMediaRuleVec>(), "AzCssMediaRuleVec"), (Layout::new::<AzCssMediaRuleVec>(), "AzCssMediaRuleVec"));
         assert_eq!((Layout::new::<azul_impl::svg::ResultSvgXmlNodeSvgParseError>(), "AzResultSvgXmlNodeSvgParseError"), (Layout::new::<AzResultSvgXmlNodeSvgParseError>(), "AzResultSvgXmlNodeSvgParseError"));
         assert_eq!((Layout::new::<azul_impl::svg::ResultSvgSvgParseError>(), "AzResultSvgSvgParseError"), (Layout::new::<AzResultSvgSvgParseError>(), "AzResultSvgSvgParseError"));
+        assert_eq!((Layout::new::<azul_impl::css::Stylesheet>(), "AzStylesheet"), (Layout::new::<AzStylesheet>(), "AzStylesheet"));
+        assert_eq!((Layout::new::<azul_impl::css::StylesheetVec>(), "AzStylesheetVec"), (Layout::new::<AzStylesheetVec>(), "AzStylesheetVec"));
         assert_eq!((Layout::new::<azul_impl::css::Css>(), "AzCss"), (Layout::new::<AzCss>(), "AzCss"));
     }
 }
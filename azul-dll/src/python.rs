@@ -833,6 +833,21 @@ pub struct AzAndroidHandle {
     pub a_native_window: *mut c_void,
 }
 
+/// Which system clipboard to read from / write to
+#[repr(C)]
+pub enum AzClipboardSelection {
+    Clipboard,
+    Primary,
+}
+
+/// Which windowing system the window should be created with on Linux
+#[repr(C)]
+pub enum AzLinuxBackend {
+    Auto,
+    X11,
+    Wayland,
+}
+
 /// X11 window hint: Type of window
 #[repr(C)]
 pub enum AzXWindowType {
@@ -1263,6 +1278,15 @@ pub struct AzIFrameCallback {
 /// `AzIFrameCallbackType` struct
 pub type AzIFrameCallbackType = extern "C" fn(&mut AzRefAny, &mut AzIFrameCallbackInfo) -> AzIFrameCallbackReturn;
 
+/// C-ABI wrapper over a `TooltipCallbackType`
+#[repr(C)]
+pub struct AzTooltipCallback {
+    pub cb: AzTooltipCallbackType,
+}
+
+/// `AzTooltipCallbackType` struct
+pub type AzTooltipCallbackType = extern "C" fn(&mut AzRefAny, &mut AzLayoutCallbackInfo) -> AzStyledDom;
+
 /// Re-export of rust-allocated (stack based) `RenderImageCallback` struct
 #[repr(C)]
 pub struct AzRenderImageCallback {
@@ -1327,6 +1351,9 @@ pub enum AzOn {
     TextInput,
     VirtualKeyDown,
     VirtualKeyUp,
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
     HoveredFile,
     DroppedFile,
     HoveredFileCancelled,
@@ -1354,6 +1381,9 @@ pub enum AzHoverEventFilter {
     TextInput,
     VirtualKeyDown,
     VirtualKeyUp,
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
     HoveredFile,
     DroppedFile,
     HoveredFileCancelled,
@@ -1383,6 +1413,9 @@ pub enum AzFocusEventFilter {
     TextInput,
     VirtualKeyDown,
     VirtualKeyUp,
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
     FocusReceived,
     FocusLost,
 }
@@ -1407,6 +1440,9 @@ pub enum AzWindowEventFilter {
     TextInput,
     VirtualKeyDown,
     VirtualKeyUp,
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
     HoveredFile,
     DroppedFile,
     HoveredFileCancelled,
@@ -1529,6 +1565,19 @@ pub enum AzAccessibilityState {
     Protected,
 }
 
+/// Action that an assistive technology (screen reader, voice control) can invoke on a node. See `NodeData::set_accessibility_actions`
+#[repr(C)]
+pub enum AzAccessibilityAction {
+    Default,
+    Focus,
+    Toggle,
+    Expand,
+    Collapse,
+    Select,
+    Increment,
+    Decrement,
+}
+
 /// Re-export of rust-allocated (stack based) `TabIndex` struct
 #[repr(C, u8)]
 pub enum AzTabIndex {
@@ -1586,6 +1635,28 @@ pub struct AzCssNthChildPattern {
     pub offset: u32,
 }
 
+/// Media type of a `CssMediaQuery`
+#[repr(C)]
+pub enum AzCssMediaType {
+    All,
+    Screen,
+    Print,
+}
+
+/// Re-export of rust-allocated (stack based) `CssMediaOrientation` struct
+#[repr(C)]
+pub enum AzCssMediaOrientation {
+    Portrait,
+    Landscape,
+}
+
+/// Re-export of rust-allocated (stack based) `CssColorScheme` struct
+#[repr(C)]
+pub enum AzCssColorScheme {
+    Light,
+    Dark,
+}
+
 /// Re-export of rust-allocated (stack based) `CssPropertyType` struct
 #[repr(C)]
 pub enum AzCssPropertyType {
@@ -1593,6 +1664,10 @@ pub enum AzCssPropertyType {
     FontSize,
     FontFamily,
     TextAlign,
+    Direction,
+    WordBreak,
+    OverflowWrap,
+    Hyphens,
     LetterSpacing,
     LineHeight,
     WordSpacing,
@@ -1619,6 +1694,15 @@ pub enum AzCssPropertyType {
     JustifyContent,
     AlignItems,
     AlignContent,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridAutoColumns,
+    GridAutoRows,
+    GridAutoFlow,
+    GridColumn,
+    GridRow,
+    RowGap,
+    ColumnGap,
     BackgroundContent,
     BackgroundPosition,
     BackgroundSize,
@@ -1663,6 +1747,39 @@ pub enum AzCssPropertyType {
     Filter,
     BackdropFilter,
     TextShadow,
+    TransitionProperty,
+    TransitionDuration,
+    TransitionTimingFunction,
+    TransitionDelay,
+    AnimationName,
+    AnimationDuration,
+    AnimationTimingFunction,
+    AnimationDelay,
+    AnimationIterationCount,
+    AnimationDirection,
+    AnimationFillMode,
+    AnimationPlayState,
+}
+
+/// Represents a CSS shorthand key that expands to multiple properties, for example `margin`
+#[repr(C)]
+pub enum AzCombinedCssPropertyType {
+    BorderRadius,
+    Overflow,
+    Margin,
+    Border,
+    BorderLeft,
+    BorderRight,
+    BorderTop,
+    BorderBottom,
+    Padding,
+    BoxShadow,
+    BackgroundColor,
+    BackgroundImage,
+    Gap,
+    GridGap,
+    Transition,
+    Animation,
 }
 
 /// Re-export of rust-allocated (stack based) `ColorU` struct
@@ -1728,6 +1845,23 @@ pub enum AzLayoutAlignContent {
     SpaceAround,
 }
 
+/// Re-export of rust-allocated (stack based) `LayoutGridAutoFlow` struct
+#[repr(C)]
+pub enum AzLayoutGridAutoFlow {
+    Row,
+    Column,
+    RowDense,
+    ColumnDense,
+}
+
+/// Start or end line of a `grid-column` or `grid-row` attribute
+#[repr(C, u8)]
+pub enum AzGridLine {
+    Auto,
+    Line(i32),
+    Span(u32),
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItems` struct
 #[repr(C)]
 pub enum AzLayoutAlignItems {
@@ -1760,6 +1894,7 @@ pub enum AzLayoutDisplay {
     Flex,
     Block,
     InlineBlock,
+    Grid,
 }
 
 /// Re-export of rust-allocated (stack based) `LayoutFloat` struct
@@ -1917,20 +2052,106 @@ pub enum AzStyleBackfaceVisibility {
     Visible,
 }
 
+/// Represents a `<time>` value in a `transition-duration`, `transition-delay`, `animation-duration` or `animation-delay` attribute, stored in milliseconds
+#[repr(C)]
+pub struct AzStyleTime {
+    pub ms: i32,
+}
+
+/// Position of the jumps in a `steps(n, position)` easing function
+#[repr(C)]
+pub enum AzStyleStepPosition {
+    JumpStart,
+    JumpEnd,
+    JumpNone,
+    JumpBoth,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirection` struct
+#[repr(C)]
+pub enum AzStyleAnimationDirection {
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillMode` struct
+#[repr(C)]
+pub enum AzStyleAnimationFillMode {
+    None,
+    Forwards,
+    Backwards,
+    Both,
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationPlayState` struct
+#[repr(C)]
+pub enum AzStyleAnimationPlayState {
+    Running,
+    Paused,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTextAlign` struct
 #[repr(C)]
 pub enum AzStyleTextAlign {
     Left,
     Center,
     Right,
+    Start,
+    End,
 }
 
-/// Re-export of rust-allocated (stack based) `Ribbon` struct
+/// Base writing direction of a text (`direction: ltr | rtl`)
 #[repr(C)]
-pub struct AzRibbon {
-    pub tab_active: i32,
+pub enum AzStyleDirection {
+    Ltr,
+    Rtl,
+}
+
+/// Where lines may break inside of words (`word-break: normal | break-all | keep-all`)
+#[repr(C)]
+pub enum AzStyleWordBreak {
+    Normal,
+    BreakAll,
+    KeepAll,
+}
+
+/// Whether words that are wider than the line may be broken at arbitrary points (`overflow-wrap: normal | anywhere | break-word`)
+#[repr(C)]
+pub enum AzStyleOverflowWrap {
+    Normal,
+    Anywhere,
+    BreakWord,
+}
+
+/// Whether words are hyphenated when breaking lines (`hyphens: none | manual | auto`)
+#[repr(C)]
+pub enum AzStyleHyphens {
+    None,
+    Manual,
+    Auto,
 }
 
+/// Re-export of rust-allocated (stack based) `RibbonItemKind` struct
+#[repr(C)]
+pub enum AzRibbonItemKind {
+    LargeButton,
+    SmallButton,
+    Toggle,
+    DropDown,
+    SplitButton,
+}
+
+/// Re-export of rust-allocated (stack based) `RibbonOnItemClickCallback` struct
+#[repr(C)]
+pub struct AzRibbonOnItemClickCallback {
+    pub cb: AzRibbonOnItemClickCallbackType,
+}
+
+/// `AzRibbonOnItemClickCallbackType` struct
+pub type AzRibbonOnItemClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzRibbonItemState) -> AzUpdate;
+
 /// Re-export of rust-allocated (stack based) `RibbonOnTabClickedCallback` struct
 #[repr(C)]
 pub struct AzRibbonOnTabClickedCallback {
@@ -2014,6 +2235,23 @@ pub enum AzTextInputValid {
     No,
 }
 
+/// What kind of change a `TextAreaEdit` made, consecutive edits of the same kind can be merged into one undo step
+#[repr(C)]
+pub enum AzTextAreaEditKind {
+    Insert,
+    Delete,
+    Replace,
+}
+
+/// `AzTextAreaOnTextChangeCallbackType` struct
+pub type AzTextAreaOnTextChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTextAreaState) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TextAreaOnTextChangeCallback` struct
+#[repr(C)]
+pub struct AzTextAreaOnTextChangeCallback {
+    pub cb: AzTextAreaOnTextChangeCallbackType,
+}
+
 /// Re-export of rust-allocated (stack based) `NumberInputState` struct
 #[repr(C)]
 pub struct AzNumberInputState {
@@ -2041,11 +2279,18 @@ pub struct AzNumberInputOnFocusLostCallback {
 /// `AzNumberInputOnFocusLostCallbackType` struct
 pub type AzNumberInputOnFocusLostCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzNumberInputState) -> AzUpdate;
 
-/// Re-export of rust-allocated (stack based) `ProgressBarState` struct
+/// Re-export of rust-allocated (stack based) `ProgressBarMode` struct
 #[repr(C)]
-pub struct AzProgressBarState {
-    pub percent_done: f32,
-    pub display_percentage: bool,
+pub enum AzProgressBarMode {
+    Determinate,
+    Indeterminate,
+}
+
+/// Re-export of rust-allocated (stack based) `ProgressBarStyle` struct
+#[repr(C)]
+pub enum AzProgressBarStyle {
+    Solid,
+    Stripes,
 }
 
 /// Re-export of rust-allocated (stack based) `TabHeaderState` struct
@@ -2063,6 +2308,100 @@ pub struct AzTabOnClickCallback {
 /// `AzTabOnClickCallbackType` struct
 pub type AzTabOnClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTabHeaderState) -> AzUpdate;
 
+/// Re-export of rust-allocated (stack based) `TabOnCloseCallback` struct
+#[repr(C)]
+pub struct AzTabOnCloseCallback {
+    pub cb: AzTabOnCloseCallbackType,
+}
+
+/// `AzTabOnCloseCallbackType` struct
+pub type AzTabOnCloseCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TabOnReorderCallback` struct
+#[repr(C)]
+pub struct AzTabOnReorderCallback {
+    pub cb: AzTabOnReorderCallbackType,
+}
+
+/// `AzTabOnReorderCallbackType` struct
+pub type AzTabOnReorderCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize, usize) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TabOnContextMenuCallback` struct
+#[repr(C)]
+pub struct AzTabOnContextMenuCallback {
+    pub cb: AzTabOnContextMenuCallbackType,
+}
+
+/// `AzTabOnContextMenuCallbackType` struct
+pub type AzTabOnContextMenuCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `SplitterOrientation` struct
+#[repr(C)]
+pub enum AzSplitterOrientation {
+    Horizontal,
+    Vertical,
+}
+
+/// Re-export of rust-allocated (stack based) `SplitterPaneState` struct
+#[repr(C)]
+pub struct AzSplitterPaneState {
+    pub size: f32,
+    pub collapsed: bool,
+}
+
+/// Re-export of rust-allocated (stack based) `SplitterOnResizeCallback` struct
+#[repr(C)]
+pub struct AzSplitterOnResizeCallback {
+    pub cb: AzSplitterOnResizeCallbackType,
+}
+
+/// `AzSplitterOnResizeCallbackType` struct
+pub type AzSplitterOnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSplitterState) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `SliderOrientation` struct
+#[repr(C)]
+pub enum AzSliderOrientation {
+    Horizontal,
+    Vertical,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderState` struct
+#[repr(C)]
+pub struct AzSliderState {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+
+/// Re-export of rust-allocated (stack based) `RangeSliderState` struct
+#[repr(C)]
+pub struct AzRangeSliderState {
+    pub low: f32,
+    pub high: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChangeCallback` struct
+#[repr(C)]
+pub struct AzSliderOnValueChangeCallback {
+    pub cb: AzSliderOnValueChangeCallbackType,
+}
+
+/// `AzSliderOnValueChangeCallbackType` struct
+pub type AzSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzSliderState) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `RangeSliderOnValueChangeCallback` struct
+#[repr(C)]
+pub struct AzRangeSliderOnValueChangeCallback {
+    pub cb: AzRangeSliderOnValueChangeCallbackType,
+}
+
+/// `AzRangeSliderOnValueChangeCallbackType` struct
+pub type AzRangeSliderOnValueChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzRangeSliderState) -> AzUpdate;
+
 /// Re-export of rust-allocated (stack based) `NodeGraphStyle` struct
 #[repr(C)]
 pub enum AzNodeGraphStyle {
@@ -2207,6 +2546,80 @@ pub struct AzListViewOnRowClickCallback {
     pub cb: AzListViewOnRowClickCallbackType,
 }
 
+/// Range of row indices, `start` is inclusive, `end` is exclusive
+#[repr(C)]
+pub struct AzListViewRowRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// `AzListViewOnColumnResizeCallbackType` struct
+pub type AzListViewOnColumnResizeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzListViewState, usize, f32) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `ListViewOnColumnResizeCallback` struct
+#[repr(C)]
+pub struct AzListViewOnColumnResizeCallback {
+    pub cb: AzListViewOnColumnResizeCallbackType,
+}
+
+/// `AzListViewRowProviderCallbackType` struct
+pub type AzListViewRowProviderCallbackType = extern "C" fn(&mut AzRefAny, &AzListViewState, AzListViewRowRange) -> AzListViewRowVec;
+
+/// Re-export of rust-allocated (stack based) `ListViewRowProviderCallback` struct
+#[repr(C)]
+pub struct AzListViewRowProviderCallback {
+    pub cb: AzListViewRowProviderCallbackType,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewNodeId` struct
+#[repr(C)]
+pub struct AzTreeViewNodeId {
+    pub inner: u64,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewSelectionMode` struct
+#[repr(C)]
+pub enum AzTreeViewSelectionMode {
+    Single,
+    Multiple,
+}
+
+/// `AzTreeViewOnExpandCallbackType` struct
+pub type AzTreeViewOnExpandCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId, bool) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnExpandCallback` struct
+#[repr(C)]
+pub struct AzTreeViewOnExpandCallback {
+    pub cb: AzTreeViewOnExpandCallbackType,
+}
+
+/// `AzTreeViewOnSelectCallbackType` struct
+pub type AzTreeViewOnSelectCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelectCallback` struct
+#[repr(C)]
+pub struct AzTreeViewOnSelectCallback {
+    pub cb: AzTreeViewOnSelectCallbackType,
+}
+
+/// `AzTreeViewOnDoubleClickCallbackType` struct
+pub type AzTreeViewOnDoubleClickCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnDoubleClickCallback` struct
+#[repr(C)]
+pub struct AzTreeViewOnDoubleClickCallback {
+    pub cb: AzTreeViewOnDoubleClickCallbackType,
+}
+
+/// `AzTreeViewOnContextMenuCallbackType` struct
+pub type AzTreeViewOnContextMenuCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, &AzTreeViewState, AzTreeViewNodeId) -> AzUpdate;
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnContextMenuCallback` struct
+#[repr(C)]
+pub struct AzTreeViewOnContextMenuCallback {
+    pub cb: AzTreeViewOnContextMenuCallbackType,
+}
+
 /// `AzDropDownOnChoiceChangeCallbackType` struct
 pub type AzDropDownOnChoiceChangeCallbackType = extern "C" fn(&mut AzRefAny, &mut AzCallbackInfo, usize) -> AzUpdate;
 
@@ -2751,6 +3164,24 @@ pub struct AzGetSystemTimeFn {
     pub cb: AzGetSystemTimeFnType,
 }
 
+/// `AzGetClipboardFnType` struct
+pub type AzGetClipboardFnType = extern "C" fn(&AzRawWindowHandle, AzClipboardSelection, AzString) -> AzOptionClipboardContent;
+
+/// Reads the content of the system clipboard in the given MIME type
+#[repr(C)]
+pub struct AzGetClipboardFn {
+    pub cb: AzGetClipboardFnType,
+}
+
+/// `AzSetClipboardFnType` struct
+pub type AzSetClipboardFnType = extern "C" fn(&AzRawWindowHandle, AzClipboardSelection, AzClipboardContent) -> bool;
+
+/// Sets the content of the system clipboard, returns whether the clipboard could be set
+#[repr(C)]
+pub struct AzSetClipboardFn {
+    pub cb: AzSetClipboardFnType,
+}
+
 /// `AzCheckThreadFinishedFnType` struct
 pub type AzCheckThreadFinishedFnType = extern "C" fn(&c_void) -> bool;
 
@@ -2845,6 +3276,94 @@ pub enum AzListViewRowVecDestructor {
 /// `AzListViewRowVecDestructorType` struct
 pub type AzListViewRowVecDestructorType = extern "C" fn(&mut AzListViewRowVec);
 
+/// Re-export of rust-allocated (stack based) `TreeViewNodeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTreeViewNodeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTreeViewNodeVecDestructorType),
+}
+
+/// `AzTreeViewNodeVecDestructorType` struct
+pub type AzTreeViewNodeVecDestructorType = extern "C" fn(&mut AzTreeViewNodeVec);
+
+/// Re-export of rust-allocated (stack based) `TreeViewNodeIdVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTreeViewNodeIdVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTreeViewNodeIdVecDestructorType),
+}
+
+/// `AzTreeViewNodeIdVecDestructorType` struct
+pub type AzTreeViewNodeIdVecDestructorType = extern "C" fn(&mut AzTreeViewNodeIdVec);
+
+/// Re-export of rust-allocated (stack based) `RibbonTabVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzRibbonTabVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzRibbonTabVecDestructorType),
+}
+
+/// `AzRibbonTabVecDestructorType` struct
+pub type AzRibbonTabVecDestructorType = extern "C" fn(&mut AzRibbonTabVec);
+
+/// Re-export of rust-allocated (stack based) `RibbonGroupVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzRibbonGroupVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzRibbonGroupVecDestructorType),
+}
+
+/// `AzRibbonGroupVecDestructorType` struct
+pub type AzRibbonGroupVecDestructorType = extern "C" fn(&mut AzRibbonGroupVec);
+
+/// Re-export of rust-allocated (stack based) `RibbonItemVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzRibbonItemVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzRibbonItemVecDestructorType),
+}
+
+/// `AzRibbonItemVecDestructorType` struct
+pub type AzRibbonItemVecDestructorType = extern "C" fn(&mut AzRibbonItemVec);
+
+/// Re-export of rust-allocated (stack based) `SplitterPaneVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzSplitterPaneVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzSplitterPaneVecDestructorType),
+}
+
+/// `AzSplitterPaneVecDestructorType` struct
+pub type AzSplitterPaneVecDestructorType = extern "C" fn(&mut AzSplitterPaneVec);
+
+/// Re-export of rust-allocated (stack based) `SplitterPaneStateVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzSplitterPaneStateVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzSplitterPaneStateVecDestructorType),
+}
+
+/// `AzSplitterPaneStateVecDestructorType` struct
+pub type AzSplitterPaneStateVecDestructorType = extern "C" fn(&mut AzSplitterPaneStateVec);
+
+/// Re-export of rust-allocated (stack based) `TextAreaEditVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzTextAreaEditVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzTextAreaEditVecDestructorType),
+}
+
+/// `AzTextAreaEditVecDestructorType` struct
+pub type AzTextAreaEditVecDestructorType = extern "C" fn(&mut AzTextAreaEditVec);
+
 /// Re-export of rust-allocated (stack based) `StyleFilterVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzStyleFilterVecDestructor {
@@ -2977,6 +3496,17 @@ pub enum AzAccessibilityStateVecDestructor {
 /// `AzAccessibilityStateVecDestructorType` struct
 pub type AzAccessibilityStateVecDestructorType = extern "C" fn(&mut AzAccessibilityStateVec);
 
+/// Re-export of rust-allocated (stack based) `AccessibilityActionVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzAccessibilityActionVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzAccessibilityActionVecDestructorType),
+}
+
+/// `AzAccessibilityActionVecDestructorType` struct
+pub type AzAccessibilityActionVecDestructorType = extern "C" fn(&mut AzAccessibilityActionVec);
+
 /// Re-export of rust-allocated (stack based) `MenuItemVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzMenuItemVecDestructor {
@@ -3175,6 +3705,105 @@ pub enum AzStyleTransformVecDestructor {
 /// `AzStyleTransformVecDestructorType` struct
 pub type AzStyleTransformVecDestructorType = extern "C" fn(&mut AzStyleTransformVec);
 
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionPropertyVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleTransitionPropertyVecDestructorType),
+}
+
+/// `AzStyleTransitionPropertyVecDestructorType` struct
+pub type AzStyleTransitionPropertyVecDestructorType = extern "C" fn(&mut AzStyleTransitionPropertyVec);
+
+/// Re-export of rust-allocated (stack based) `StyleTimeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleTimeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleTimeVecDestructorType),
+}
+
+/// `AzStyleTimeVecDestructorType` struct
+pub type AzStyleTimeVecDestructorType = extern "C" fn(&mut AzStyleTimeVec);
+
+/// Re-export of rust-allocated (stack based) `StyleTimingFunctionVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleTimingFunctionVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleTimingFunctionVecDestructorType),
+}
+
+/// `AzStyleTimingFunctionVecDestructorType` struct
+pub type AzStyleTimingFunctionVecDestructorType = extern "C" fn(&mut AzStyleTimingFunctionVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationNameVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationNameVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationNameVecDestructorType),
+}
+
+/// `AzStyleAnimationNameVecDestructorType` struct
+pub type AzStyleAnimationNameVecDestructorType = extern "C" fn(&mut AzStyleAnimationNameVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationIterationCountVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationIterationCountVecDestructorType),
+}
+
+/// `AzStyleAnimationIterationCountVecDestructorType` struct
+pub type AzStyleAnimationIterationCountVecDestructorType = extern "C" fn(&mut AzStyleAnimationIterationCountVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationDirectionVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationDirectionVecDestructorType),
+}
+
+/// `AzStyleAnimationDirectionVecDestructorType` struct
+pub type AzStyleAnimationDirectionVecDestructorType = extern "C" fn(&mut AzStyleAnimationDirectionVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationFillModeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationFillModeVecDestructorType),
+}
+
+/// `AzStyleAnimationFillModeVecDestructorType` struct
+pub type AzStyleAnimationFillModeVecDestructorType = extern "C" fn(&mut AzStyleAnimationFillModeVec);
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationPlayStateVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationPlayStateVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzStyleAnimationPlayStateVecDestructorType),
+}
+
+/// `AzStyleAnimationPlayStateVecDestructorType` struct
+pub type AzStyleAnimationPlayStateVecDestructorType = extern "C" fn(&mut AzStyleAnimationPlayStateVec);
+
+/// Re-export of rust-allocated (stack based) `GridTrackSizingVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzGridTrackSizingVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzGridTrackSizingVecDestructorType),
+}
+
+/// `AzGridTrackSizingVecDestructorType` struct
+pub type AzGridTrackSizingVecDestructorType = extern "C" fn(&mut AzGridTrackSizingVec);
+
 /// Re-export of rust-allocated (stack based) `CssPropertyVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzCssPropertyVecDestructor {
@@ -3329,6 +3958,61 @@ pub enum AzStylesheetVecDestructor {
 /// `AzStylesheetVecDestructorType` struct
 pub type AzStylesheetVecDestructorType = extern "C" fn(&mut AzStylesheetVec);
 
+/// Re-export of rust-allocated (stack based) `CssKeyframesVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssKeyframesVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssKeyframesVecDestructorType),
+}
+
+/// `AzCssKeyframesVecDestructorType` struct
+pub type AzCssKeyframesVecDestructorType = extern "C" fn(&mut AzCssKeyframesVec);
+
+/// Re-export of rust-allocated (stack based) `CssKeyframeVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssKeyframeVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssKeyframeVecDestructorType),
+}
+
+/// `AzCssKeyframeVecDestructorType` struct
+pub type AzCssKeyframeVecDestructorType = extern "C" fn(&mut AzCssKeyframeVec);
+
+/// Re-export of rust-allocated (stack based) `CssMediaRuleVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssMediaRuleVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssMediaRuleVecDestructorType),
+}
+
+/// `AzCssMediaRuleVecDestructorType` struct
+pub type AzCssMediaRuleVecDestructorType = extern "C" fn(&mut AzCssMediaRuleVec);
+
+/// Re-export of rust-allocated (stack based) `CssMediaQueryVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssMediaQueryVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssMediaQueryVecDestructorType),
+}
+
+/// `AzCssMediaQueryVecDestructorType` struct
+pub type AzCssMediaQueryVecDestructorType = extern "C" fn(&mut AzCssMediaQueryVec);
+
+/// Re-export of rust-allocated (stack based) `CssMediaFeatureVecDestructor` struct
+#[repr(C, u8)]
+pub enum AzCssMediaFeatureVecDestructor {
+    DefaultRust,
+    NoDestructor,
+    External(AzCssMediaFeatureVecDestructorType),
+}
+
+/// `AzCssMediaFeatureVecDestructorType` struct
+pub type AzCssMediaFeatureVecDestructorType = extern "C" fn(&mut AzCssMediaFeatureVec);
+
 /// Re-export of rust-allocated (stack based) `CssRuleBlockVecDestructor` struct
 #[repr(C, u8)]
 pub enum AzCssRuleBlockVecDestructor {
@@ -3602,6 +4286,8 @@ pub struct AzSvgParseErrorPosition {
 pub struct AzSystemCallbacks {
     pub create_thread_fn: AzCreateThreadFn,
     pub get_system_time_fn: AzGetSystemTimeFn,
+    pub get_clipboard_fn: AzGetClipboardFn,
+    pub set_clipboard_fn: AzSetClipboardFn,
 }
 
 /// Force a specific renderer: note that azul will **crash** on startup if the `RendererOptions` are not satisfied.
@@ -3724,6 +4410,7 @@ pub struct AzInlineGlyph {
     pub bounds: AzLogicalRect,
     pub unicode_codepoint: AzOptionCharEnumWrapper,
     pub glyph_index: u32,
+    pub font_index: usize,
 }
 
 /// Re-export of rust-allocated (stack based) `InlineTextHit` struct
@@ -3745,6 +4432,8 @@ pub struct AzInlineTextHit {
     pub char_index_relative_to_line: usize,
     pub glyph_index_relative_to_word: usize,
     pub char_index_relative_to_word: usize,
+    pub is_rtl: bool,
+    pub cursor_index_relative_to_text: usize,
 }
 
 /// Re-export of rust-allocated (stack based) `IFrameCallbackInfo` struct
@@ -3792,6 +4481,13 @@ pub enum AzNotEventFilter {
     Focus(AzFocusEventFilter),
 }
 
+/// Re-export of rust-allocated (stack based) `TooltipDom` struct
+#[repr(C)]
+pub struct AzTooltipDom {
+    pub data: AzRefAny,
+    pub callback: AzTooltipCallback,
+}
+
 /// Similar to `dom.CallbackData`, stores some data + a callback to call when the menu is activated
 #[repr(C)]
 pub struct AzMenuCallback {
@@ -3815,6 +4511,15 @@ pub enum AzCssNthChildSelector {
     Pattern(AzCssNthChildPattern),
 }
 
+/// Range of a numeric media feature, i.e. `(min-width: 600px)` or `(400px < width <= 700px)`
+#[repr(C)]
+pub struct AzCssMediaRange {
+    pub min: AzOptionF32EnumWrapper,
+    pub min_inclusive: bool,
+    pub max: AzOptionF32EnumWrapper,
+    pub max_inclusive: bool,
+}
+
 /// Re-export of rust-allocated (stack based) `PixelValue` struct
 #[repr(C)]
 pub struct AzPixelValue {
@@ -3870,6 +4575,42 @@ pub enum AzStyleCompositeFilter {
     Arithmetic([AzFloatValue;4]),
 }
 
+/// One end of a grid track size, i.e. the `min` or `max` of a `minmax(min, max)`
+#[repr(C, u8)]
+pub enum AzGridTrackBreadth {
+    Fixed(AzPixelValue),
+    Fraction(AzFloatValue),
+    Auto,
+    MinContent,
+    MaxContent,
+}
+
+/// Single track size in a `grid-template-columns`, `grid-template-rows`, `grid-auto-columns` or `grid-auto-rows` attribute
+#[repr(C)]
+pub struct AzGridTrackSizing {
+    pub min: AzGridTrackBreadthEnumWrapper,
+    pub max: AzGridTrackBreadthEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `GridPlacement` struct
+#[repr(C)]
+pub struct AzGridPlacement {
+    pub start: AzGridLineEnumWrapper,
+    pub end: AzGridLineEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutRowGap` struct
+#[repr(C)]
+pub struct AzLayoutRowGap {
+    pub inner: AzPixelValue,
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutColumnGap` struct
+#[repr(C)]
+pub struct AzLayoutColumnGap {
+    pub inner: AzPixelValue,
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutBottom` struct
 #[repr(C)]
 pub struct AzLayoutBottom {
@@ -4282,6 +5023,49 @@ pub struct AzStyleTransformSkew2D {
     pub y: AzPercentageValue,
 }
 
+/// Control points of a `cubic-bezier(x1, y1, x2, y2)` easing function
+#[repr(C)]
+pub struct AzStyleCubicBezier {
+    pub x1: AzFloatValue,
+    pub y1: AzFloatValue,
+    pub x2: AzFloatValue,
+    pub y2: AzFloatValue,
+}
+
+/// Represents a `steps(n, position)` easing function
+#[repr(C)]
+pub struct AzStyleSteps {
+    pub count: u32,
+    pub position: AzStyleStepPositionEnumWrapper,
+}
+
+/// Represents an `<easing-function>` in a `transition-timing-function` or `animation-timing-function` attribute
+#[repr(C, u8)]
+pub enum AzStyleTimingFunction {
+    Ease,
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(AzStyleCubicBezier),
+    Steps(AzStyleSteps),
+}
+
+/// Single entry in a `transition-property` attribute
+#[repr(C, u8)]
+pub enum AzStyleTransitionProperty {
+    All,
+    Property(AzCssPropertyType),
+    Shorthand(AzCombinedCssPropertyType),
+}
+
+/// Single entry in an `animation-iteration-count` attribute
+#[repr(C, u8)]
+pub enum AzStyleAnimationIterationCount {
+    Infinite,
+    Count(AzFloatValue),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTextColor` struct
 #[repr(C)]
 pub struct AzStyleTextColor {
@@ -4314,6 +5098,56 @@ pub enum AzLayoutAlignContentValue {
     Exact(AzLayoutAlignContent),
 }
 
+/// Re-export of rust-allocated (stack based) `GridTrackSizingValue` struct
+#[repr(C, u8)]
+pub enum AzGridTrackSizingValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzGridTrackSizing),
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutGridAutoFlowValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutGridAutoFlowValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutGridAutoFlow),
+}
+
+/// Re-export of rust-allocated (stack based) `GridPlacementValue` struct
+#[repr(C, u8)]
+pub enum AzGridPlacementValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzGridPlacement),
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutRowGapValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutRowGapValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutRowGap),
+}
+
+/// Re-export of rust-allocated (stack based) `LayoutColumnGapValue` struct
+#[repr(C, u8)]
+pub enum AzLayoutColumnGapValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzLayoutColumnGap),
+}
+
 /// Re-export of rust-allocated (stack based) `LayoutAlignItemsValue` struct
 #[repr(C, u8)]
 pub enum AzLayoutAlignItemsValue {
@@ -4824,6 +5658,46 @@ pub enum AzStyleTextAlignValue {
     Exact(AzStyleTextAlign),
 }
 
+/// Re-export of rust-allocated (stack based) `StyleDirectionValue` struct
+#[repr(C, u8)]
+pub enum AzStyleDirectionValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleDirection),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleWordBreakValue` struct
+#[repr(C, u8)]
+pub enum AzStyleWordBreakValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleWordBreak),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleOverflowWrapValue` struct
+#[repr(C, u8)]
+pub enum AzStyleOverflowWrapValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleOverflowWrap),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleHyphensValue` struct
+#[repr(C, u8)]
+pub enum AzStyleHyphensValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleHyphens),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleTextColorValue` struct
 #[repr(C, u8)]
 pub enum AzStyleTextColorValue {
@@ -4894,6 +5768,27 @@ pub enum AzStyleMixBlendModeValue {
     Exact(AzStyleMixBlendMode),
 }
 
+/// Re-export of rust-allocated (stack based) `RibbonItemState` struct
+#[repr(C)]
+pub struct AzRibbonItemState {
+    pub toggled: bool,
+    pub choice: AzOptionUsizeEnumWrapper,
+}
+
+/// Re-export of rust-allocated (stack based) `RibbonOnItemClick` struct
+#[repr(C)]
+pub struct AzRibbonOnItemClick {
+    pub data: AzRefAny,
+    pub callback: AzRibbonOnItemClickCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `RibbonOnTabClicked` struct
+#[repr(C)]
+pub struct AzRibbonOnTabClicked {
+    pub data: AzRefAny,
+    pub callback: AzRibbonOnTabClickedCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `ButtonOnClick` struct
 #[repr(C)]
 pub struct AzButtonOnClick {
@@ -4963,6 +5858,13 @@ pub struct AzOnTextInputReturn {
     pub valid: AzTextInputValidEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `TextAreaOnTextChange` struct
+#[repr(C)]
+pub struct AzTextAreaOnTextChange {
+    pub data: AzRefAny,
+    pub callback: AzTextAreaOnTextChangeCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `NumberInputOnValueChange` struct
 #[repr(C)]
 pub struct AzNumberInputOnValueChange {
@@ -4977,6 +5879,23 @@ pub struct AzNumberInputOnFocusLost {
     pub callback: AzNumberInputOnFocusLostCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `ProgressBarState` struct
+#[repr(C)]
+pub struct AzProgressBarState {
+    pub percent_done: f32,
+    pub display_percentage: bool,
+    pub mode: AzProgressBarModeEnumWrapper,
+}
+
+/// Circular, endlessly rotating "busy" indicator for operations without a known total
+#[repr(C)]
+pub struct AzSpinner {
+    pub size: AzPixelValue,
+    pub thickness: AzPixelValue,
+    pub color: AzColorU,
+    pub track_color: AzColorU,
+}
+
 /// Re-export of rust-allocated (stack based) `TabOnClick` struct
 #[repr(C)]
 pub struct AzTabOnClick {
@@ -4984,6 +5903,48 @@ pub struct AzTabOnClick {
     pub callback: AzTabOnClickCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `TabOnClose` struct
+#[repr(C)]
+pub struct AzTabOnClose {
+    pub data: AzRefAny,
+    pub callback: AzTabOnCloseCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TabOnReorder` struct
+#[repr(C)]
+pub struct AzTabOnReorder {
+    pub data: AzRefAny,
+    pub callback: AzTabOnReorderCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TabOnContextMenu` struct
+#[repr(C)]
+pub struct AzTabOnContextMenu {
+    pub data: AzRefAny,
+    pub callback: AzTabOnContextMenuCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `SplitterOnResize` struct
+#[repr(C)]
+pub struct AzSplitterOnResize {
+    pub data: AzRefAny,
+    pub callback: AzSplitterOnResizeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `SliderOnValueChange` struct
+#[repr(C)]
+pub struct AzSliderOnValueChange {
+    pub data: AzRefAny,
+    pub callback: AzSliderOnValueChangeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `RangeSliderOnValueChange` struct
+#[repr(C)]
+pub struct AzRangeSliderOnValueChange {
+    pub data: AzRefAny,
+    pub callback: AzRangeSliderOnValueChangeCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `NodeGraphOnNodeAdded` struct
 #[repr(C)]
 pub struct AzNodeGraphOnNodeAdded {
@@ -5075,6 +6036,48 @@ pub struct AzListViewOnRowClick {
     pub callback: AzListViewOnRowClickCallback,
 }
 
+/// Re-export of rust-allocated (stack based) `ListViewOnColumnResize` struct
+#[repr(C)]
+pub struct AzListViewOnColumnResize {
+    pub data: AzRefAny,
+    pub callback: AzListViewOnColumnResizeCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `ListViewRowProvider` struct
+#[repr(C)]
+pub struct AzListViewRowProvider {
+    pub data: AzRefAny,
+    pub callback: AzListViewRowProviderCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnExpand` struct
+#[repr(C)]
+pub struct AzTreeViewOnExpand {
+    pub data: AzRefAny,
+    pub callback: AzTreeViewOnExpandCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnSelect` struct
+#[repr(C)]
+pub struct AzTreeViewOnSelect {
+    pub data: AzRefAny,
+    pub callback: AzTreeViewOnSelectCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnDoubleClick` struct
+#[repr(C)]
+pub struct AzTreeViewOnDoubleClick {
+    pub data: AzRefAny,
+    pub callback: AzTreeViewOnDoubleClickCallback,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewOnContextMenu` struct
+#[repr(C)]
+pub struct AzTreeViewOnContextMenu {
+    pub data: AzRefAny,
+    pub callback: AzTreeViewOnContextMenuCallback,
+}
+
 /// Re-export of rust-allocated (stack based) `DropDownOnChoiceChange` struct
 #[repr(C)]
 pub struct AzDropDownOnChoiceChange {
@@ -5247,6 +6250,24 @@ pub struct AzThreadWriteBackMsg {
     pub callback: AzWriteBackCallback,
 }
 
+/// Wrapper over a Rust-allocated `Vec<TreeViewNodeId>`
+#[repr(C)]
+pub struct AzTreeViewNodeIdVec {
+    pub(crate) ptr: *const AzTreeViewNodeId,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzTreeViewNodeIdVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<SplitterPaneState>`
+#[repr(C)]
+pub struct AzSplitterPaneStateVec {
+    pub(crate) ptr: *const AzSplitterPaneState,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzSplitterPaneStateVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<LogicalRect>`
 #[repr(C)]
 pub struct AzLogicalRectVec {
@@ -5292,6 +6313,15 @@ pub struct AzAccessibilityStateVec {
     pub destructor: AzAccessibilityStateVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<AccessibilityAction>`
+#[repr(C)]
+pub struct AzAccessibilityActionVec {
+    pub(crate) ptr: *const AzAccessibilityActionEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzAccessibilityActionVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<MenuItem>`
 #[repr(C)]
 pub struct AzMenuItemVec {
@@ -5373,6 +6403,78 @@ pub struct AzStyleBackgroundSizeVec {
     pub destructor: AzStyleBackgroundSizeVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<StyleTransitionProperty>`
+#[repr(C)]
+pub struct AzStyleTransitionPropertyVec {
+    pub(crate) ptr: *const AzStyleTransitionPropertyEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStyleTransitionPropertyVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleTime>`
+#[repr(C)]
+pub struct AzStyleTimeVec {
+    pub(crate) ptr: *const AzStyleTime,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStyleTimeVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleTimingFunction>`
+#[repr(C)]
+pub struct AzStyleTimingFunctionVec {
+    pub(crate) ptr: *const AzStyleTimingFunctionEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStyleTimingFunctionVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationIterationCount>`
+#[repr(C)]
+pub struct AzStyleAnimationIterationCountVec {
+    pub(crate) ptr: *const AzStyleAnimationIterationCountEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStyleAnimationIterationCountVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationDirection>`
+#[repr(C)]
+pub struct AzStyleAnimationDirectionVec {
+    pub(crate) ptr: *const AzStyleAnimationDirectionEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStyleAnimationDirectionVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationFillMode>`
+#[repr(C)]
+pub struct AzStyleAnimationFillModeVec {
+    pub(crate) ptr: *const AzStyleAnimationFillModeEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStyleAnimationFillModeVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationPlayState>`
+#[repr(C)]
+pub struct AzStyleAnimationPlayStateVec {
+    pub(crate) ptr: *const AzStyleAnimationPlayStateEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStyleAnimationPlayStateVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<GridTrackSizing>`
+#[repr(C)]
+pub struct AzGridTrackSizingVec {
+    pub(crate) ptr: *const AzGridTrackSizing,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzGridTrackSizingVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `SvgVertex`
 #[repr(C)]
 pub struct AzSvgVertexVec {
@@ -5524,6 +6626,41 @@ pub enum AzOptionListViewOnRowClick {
     Some(AzListViewOnRowClick),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTreeViewNodeId` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewNodeId {
+    None,
+    Some(AzTreeViewNodeId),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnExpand` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnExpand {
+    None,
+    Some(AzTreeViewOnExpand),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnSelect` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnSelect {
+    None,
+    Some(AzTreeViewOnSelect),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnDoubleClick` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnDoubleClick {
+    None,
+    Some(AzTreeViewOnDoubleClick),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTreeViewOnContextMenu` struct
+#[repr(C, u8)]
+pub enum AzOptionTreeViewOnContextMenu {
+    None,
+    Some(AzTreeViewOnContextMenu),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionListViewOnColumnClick` struct
 #[repr(C, u8)]
 pub enum AzOptionListViewOnColumnClick {
@@ -5538,6 +6675,20 @@ pub enum AzOptionListViewOnLazyLoadScroll {
     Some(AzListViewOnLazyLoadScroll),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionListViewOnColumnResize` struct
+#[repr(C, u8)]
+pub enum AzOptionListViewOnColumnResize {
+    None,
+    Some(AzListViewOnColumnResize),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionListViewRowProvider` struct
+#[repr(C, u8)]
+pub enum AzOptionListViewRowProvider {
+    None,
+    Some(AzListViewRowProvider),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionPixelValueNoPercent` struct
 #[repr(C, u8)]
 pub enum AzOptionPixelValueNoPercent {
@@ -5629,6 +6780,62 @@ pub enum AzOptionTabOnClick {
     Some(AzTabOnClick),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTabOnClose` struct
+#[repr(C, u8)]
+pub enum AzOptionTabOnClose {
+    None,
+    Some(AzTabOnClose),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTabOnReorder` struct
+#[repr(C, u8)]
+pub enum AzOptionTabOnReorder {
+    None,
+    Some(AzTabOnReorder),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionTabOnContextMenu` struct
+#[repr(C, u8)]
+pub enum AzOptionTabOnContextMenu {
+    None,
+    Some(AzTabOnContextMenu),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionRibbonOnTabClicked` struct
+#[repr(C, u8)]
+pub enum AzOptionRibbonOnTabClicked {
+    None,
+    Some(AzRibbonOnTabClicked),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionRibbonOnItemClick` struct
+#[repr(C, u8)]
+pub enum AzOptionRibbonOnItemClick {
+    None,
+    Some(AzRibbonOnItemClick),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSplitterOnResize` struct
+#[repr(C, u8)]
+pub enum AzOptionSplitterOnResize {
+    None,
+    Some(AzSplitterOnResize),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionSliderOnValueChange` struct
+#[repr(C, u8)]
+pub enum AzOptionSliderOnValueChange {
+    None,
+    Some(AzSliderOnValueChange),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionRangeSliderOnValueChange` struct
+#[repr(C, u8)]
+pub enum AzOptionRangeSliderOnValueChange {
+    None,
+    Some(AzRangeSliderOnValueChange),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionFileInputOnPathChange` struct
 #[repr(C, u8)]
 pub enum AzOptionFileInputOnPathChange {
@@ -5671,6 +6878,13 @@ pub enum AzOptionTextInputSelection {
     Some(AzTextInputSelection),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionTextAreaOnTextChange` struct
+#[repr(C, u8)]
+pub enum AzOptionTextAreaOnTextChange {
+    None,
+    Some(AzTextAreaOnTextChange),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionNumberInputOnFocusLost` struct
 #[repr(C, u8)]
 pub enum AzOptionNumberInputOnFocusLost {
@@ -5853,6 +7067,13 @@ pub enum AzOptionLogicalPosition {
     Some(AzLogicalPosition),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionLogicalRect` struct
+#[repr(C, u8)]
+pub enum AzOptionLogicalRect {
+    None,
+    Some(AzLogicalRect),
+}
+
 /// Re-export of rust-allocated (stack based) `OptionPhysicalPositionI32` struct
 #[repr(C, u8)]
 pub enum AzOptionPhysicalPositionI32 {
@@ -6017,15 +7238,6 @@ pub struct AzWindowSize {
     pub max_dimensions: AzOptionLogicalSizeEnumWrapper,
 }
 
-/// Current keyboard state, stores what keys / characters have been pressed
-#[repr(C)]
-pub struct AzKeyboardState {
-    pub current_char: AzOptionCharEnumWrapper,
-    pub current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper,
-    pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
-    pub pressed_scancodes: AzScanCodeVec,
-}
-
 /// Current mouse / cursor state
 #[repr(C)]
 pub struct AzMouseState {
@@ -6051,6 +7263,7 @@ pub struct AzMarshaledLayoutCallback {
 pub struct AzInlineTextContents {
     pub glyphs: AzInlineGlyphVec,
     pub bounds: AzLogicalRect,
+    pub is_rtl: bool,
 }
 
 /// Re-export of rust-allocated (stack based) `ResolvedTextLayoutOptions` struct
@@ -6064,6 +7277,10 @@ pub struct AzResolvedTextLayoutOptions {
     pub max_horizontal_width: AzOptionF32EnumWrapper,
     pub leading: AzOptionF32EnumWrapper,
     pub holes: AzLogicalRectVec,
+    pub direction: AzStyleDirectionEnumWrapper,
+    pub word_break: AzStyleWordBreakEnumWrapper,
+    pub overflow_wrap: AzStyleOverflowWrapEnumWrapper,
+    pub hyphens: AzStyleHyphensEnumWrapper,
 }
 
 /// Easing function of the animation (ease-in, ease-out, ease-in-out, custom)
@@ -6142,6 +7359,16 @@ pub enum AzCssPathPseudoSelector {
     Focus,
 }
 
+/// One media feature, i.e. `(min-width: 600px)` or `(prefers-color-scheme: dark)`
+#[repr(C, u8)]
+pub enum AzCssMediaFeature {
+    Width(AzCssMediaRange),
+    Height(AzCssMediaRange),
+    Resolution(AzCssMediaRange),
+    Orientation(AzCssMediaOrientation),
+    PrefersColorScheme(AzCssColorScheme),
+}
+
 /// Re-export of rust-allocated (stack based) `AnimationInterpolationFunction` struct
 #[repr(C, u8)]
 pub enum AzAnimationInterpolationFunction {
@@ -6230,6 +7457,86 @@ pub enum AzStyleTransform {
     Perspective(AzPixelValue),
 }
 
+/// Re-export of rust-allocated (stack based) `GridTrackSizingVecValue` struct
+#[repr(C, u8)]
+pub enum AzGridTrackSizingVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzGridTrackSizingVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTransitionPropertyVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTransitionPropertyVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTransitionPropertyVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTimeVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTimeVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTimeVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleTimingFunctionVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleTimingFunctionVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleTimingFunctionVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationIterationCountVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationIterationCountVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationIterationCountVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationDirectionVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationDirectionVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationDirectionVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationFillModeVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationFillModeVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationFillModeVec),
+}
+
+/// Re-export of rust-allocated (stack based) `StyleAnimationPlayStateVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationPlayStateVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationPlayStateVec),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundPositionVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundPositionVecValue {
@@ -6267,6 +7574,17 @@ pub struct AzCheckBoxStateWrapper {
     pub on_toggle: AzOptionCheckBoxOnToggleEnumWrapper,
 }
 
+/// Single step in the undo / redo history of a `TextArea`: `removed` was replaced with `inserted` at `position`
+#[repr(C)]
+pub struct AzTextAreaEdit {
+    pub position: usize,
+    pub removed: AzU32Vec,
+    pub inserted: AzU32Vec,
+    pub kind: AzTextAreaEditKindEnumWrapper,
+    pub cursor_before: usize,
+    pub selection_anchor_before: AzOptionUsizeEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `NumberInputStateWrapper` struct
 #[repr(C)]
 pub struct AzNumberInputStateWrapper {
@@ -6275,6 +7593,12 @@ pub struct AzNumberInputStateWrapper {
     pub on_focus_lost: AzOptionNumberInputOnFocusLostEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `SplitterState` struct
+#[repr(C)]
+pub struct AzSplitterState {
+    pub panes: AzSplitterPaneStateVec,
+}
+
 /// Re-export of rust-allocated (stack based) `NodeGraphCallbacks` struct
 #[repr(C)]
 pub struct AzNodeGraphCallbacks {
@@ -6443,6 +7767,15 @@ pub struct AzListViewRowVec {
     pub destructor: AzListViewRowVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<TextAreaEdit>`
+#[repr(C)]
+pub struct AzTextAreaEditVec {
+    pub(crate) ptr: *const AzTextAreaEdit,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzTextAreaEditVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<StyleFilter>`
 #[repr(C)]
 pub struct AzStyleFilterVec {
@@ -6497,6 +7830,15 @@ pub struct AzSvgPathElementVec {
     pub destructor: AzSvgPathElementVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssMediaFeature>`
+#[repr(C)]
+pub struct AzCssMediaFeatureVec {
+    pub(crate) ptr: *const AzCssMediaFeatureEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzCssMediaFeatureVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `StringVec`
 #[repr(C)]
 pub struct AzStringVec {
@@ -6552,13 +7894,6 @@ pub enum AzOptionMouseState {
     Some(AzMouseState),
 }
 
-/// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
-#[repr(C, u8)]
-pub enum AzOptionKeyboardState {
-    None,
-    Some(AzKeyboardState),
-}
-
 /// Re-export of rust-allocated (stack based) `OptionStringVec` struct
 #[repr(C, u8)]
 pub enum AzOptionStringVec {
@@ -6651,6 +7986,20 @@ pub struct AzInvalidStringError {
     pub pos: AzSvgParseErrorPosition,
 }
 
+/// Content of the system clipboard, tagged with its MIME type
+#[repr(C)]
+pub struct AzClipboardContent {
+    pub mime_type: AzString,
+    pub data: AzU8Vec,
+}
+
+/// Preedit text of an input method, i.e. the pinyin or kana that was typed before the user selected one of the candidates
+#[repr(C)]
+pub struct AzImeComposition {
+    pub text: AzString,
+    pub cursor: usize,
+}
+
 /// Window configuration specific to Win32
 #[repr(C)]
 pub struct AzWindowsWindowOptions {
@@ -6775,6 +8124,13 @@ pub struct AzAccessibilityInfo {
     pub default_action: AzOptionStringEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `TooltipContent` struct
+#[repr(C, u8)]
+pub enum AzTooltipContent {
+    Text(AzString),
+    Dom(AzTooltipDom),
+}
+
 /// Re-export of rust-allocated (stack based) `IdOrClass` struct
 #[repr(C, u8)]
 pub enum AzIdOrClass {
@@ -6805,6 +8161,14 @@ pub enum AzCssPathSelector {
     Children,
 }
 
+/// One query of a media query list, i.e. `screen and (min-width: 600px)`
+#[repr(C)]
+pub struct AzCssMediaQuery {
+    pub negated: bool,
+    pub media_type: AzCssMediaTypeEnumWrapper,
+    pub features: AzCssMediaFeatureVec,
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContent` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundContent {
@@ -6843,6 +8207,13 @@ pub enum AzStyleFontFamily {
     Ref(AzFontRef),
 }
 
+/// Single entry in an `animation-name` attribute, refers to a `@keyframes` block
+#[repr(C, u8)]
+pub enum AzStyleAnimationName {
+    None,
+    Name(AzString),
+}
+
 /// Re-export of rust-allocated (stack based) `ScrollbarStyleValue` struct
 #[repr(C, u8)]
 pub enum AzScrollbarStyleValue {
@@ -6873,6 +8244,20 @@ pub enum AzStyleFilterVecValue {
     Exact(AzStyleFilterVec),
 }
 
+/// Re-export of rust-allocated (stack based) `RibbonItem` struct
+#[repr(C)]
+pub struct AzRibbonItem {
+    pub kind: AzRibbonItemKindEnumWrapper,
+    pub label: AzString,
+    pub icon: AzOptionImageRefEnumWrapper,
+    pub tooltip: AzOptionStringEnumWrapper,
+    pub key_tip: AzOptionStringEnumWrapper,
+    pub enabled: bool,
+    pub toggled: bool,
+    pub choices: AzStringVec,
+    pub on_click: AzOptionRibbonOnItemClickEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `FileInputState` struct
 #[repr(C)]
 pub struct AzFileInputState {
@@ -6897,12 +8282,28 @@ pub struct AzTextInputState {
     pub cursor_pos: usize,
 }
 
+/// Re-export of rust-allocated (stack based) `TextAreaState` struct
+#[repr(C)]
+pub struct AzTextAreaState {
+    pub text: AzU32Vec,
+    pub cursor: usize,
+    pub selection_anchor: AzOptionUsizeEnumWrapper,
+    pub preferred_x: AzOptionF32EnumWrapper,
+    pub scroll_offset: f32,
+    pub undo_stack: AzTextAreaEditVec,
+    pub redo_stack: AzTextAreaEditVec,
+    pub last_edit_time: AzOptionInstantEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `TabHeader` struct
 #[repr(C)]
 pub struct AzTabHeader {
     pub tabs: AzStringVec,
     pub active_tab: usize,
     pub on_click: AzOptionTabOnClickEnumWrapper,
+    pub on_close: AzOptionTabOnCloseEnumWrapper,
+    pub on_reorder: AzOptionTabOnReorderEnumWrapper,
+    pub on_context_menu: AzOptionTabOnContextMenuEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `NodeTypeFieldValue` struct
@@ -6943,6 +8344,11 @@ pub struct AzListView {
     pub on_lazy_load_scroll: AzOptionListViewOnLazyLoadScrollEnumWrapper,
     pub on_column_click: AzOptionListViewOnColumnClickEnumWrapper,
     pub on_row_click: AzOptionListViewOnRowClickEnumWrapper,
+    pub row_count: usize,
+    pub row_height: AzPixelValueNoPercent,
+    pub column_widths: AzF32Vec,
+    pub row_provider: AzOptionListViewRowProviderEnumWrapper,
+    pub on_column_resize: AzOptionListViewOnColumnResizeEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `ListViewState` struct
@@ -6954,12 +8360,29 @@ pub struct AzListViewState {
     pub scroll_offset: AzPixelValueNoPercent,
     pub current_scroll_position: AzLogicalPosition,
     pub current_content_height: AzLogicalSize,
+    pub row_count: usize,
+    pub visible_rows: AzListViewRowRange,
+    pub column_widths: AzF32Vec,
 }
 
-/// Re-export of rust-allocated (stack based) `TreeView` struct
+/// Re-export of rust-allocated (stack based) `TreeViewNode` struct
 #[repr(C)]
-pub struct AzTreeView {
-    pub root: AzString,
+pub struct AzTreeViewNode {
+    pub id: AzTreeViewNodeId,
+    pub parent: AzOptionTreeViewNodeIdEnumWrapper,
+    pub label: AzString,
+    pub icon: AzOptionImageRefEnumWrapper,
+    pub has_children: bool,
+}
+
+/// Re-export of rust-allocated (stack based) `TreeViewState` struct
+#[repr(C)]
+pub struct AzTreeViewState {
+    pub expanded: AzTreeViewNodeIdVec,
+    pub selected: AzTreeViewNodeIdVec,
+    pub focused: AzOptionTreeViewNodeIdEnumWrapper,
+    pub selection_anchor: AzOptionTreeViewNodeIdEnumWrapper,
+    pub last_click_time: AzOptionInstantEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `DropDown` struct
@@ -6967,6 +8390,7 @@ pub struct AzTreeView {
 pub struct AzDropDown {
     pub choices: AzStringVec,
     pub selected: usize,
+    pub disabled_choices: AzU32Vec,
     pub on_choice_change: AzOptionDropDownOnChoiceChangeEnumWrapper,
 }
 
@@ -7099,6 +8523,24 @@ pub struct AzFmtArg {
     pub value: AzFmtValueEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<TreeViewNode>`
+#[repr(C)]
+pub struct AzTreeViewNodeVec {
+    pub(crate) ptr: *const AzTreeViewNode,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzTreeViewNodeVecDestructorEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<RibbonItem>`
+#[repr(C)]
+pub struct AzRibbonItemVec {
+    pub(crate) ptr: *const AzRibbonItem,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzRibbonItemVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<StyleFontFamily>`
 #[repr(C)]
 pub struct AzStyleFontFamilyVec {
@@ -7153,6 +8595,15 @@ pub struct AzStyleBackgroundContentVec {
     pub destructor: AzStyleBackgroundContentVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<StyleAnimationName>`
+#[repr(C)]
+pub struct AzStyleAnimationNameVec {
+    pub(crate) ptr: *const AzStyleAnimationNameEnumWrapper,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStyleAnimationNameVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<SvgPath>`
 #[repr(C)]
 pub struct AzSvgPathVec {
@@ -7180,6 +8631,15 @@ pub struct AzCssPathSelectorVec {
     pub destructor: AzCssPathSelectorVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssMediaQuery>`
+#[repr(C)]
+pub struct AzCssMediaQueryVec {
+    pub(crate) ptr: *const AzCssMediaQuery,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzCssMediaQueryVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `CallbackData`
 #[repr(C)]
 pub struct AzCallbackDataVec {
@@ -7235,6 +8695,20 @@ pub enum AzOptionWaylandTheme {
     Some(AzWaylandTheme),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionImeComposition` struct
+#[repr(C, u8)]
+pub enum AzOptionImeComposition {
+    None,
+    Some(AzImeComposition),
+}
+
+/// Re-export of rust-allocated (stack based) `OptionClipboardContent` struct
+#[repr(C, u8)]
+pub enum AzOptionClipboardContent {
+    None,
+    Some(AzClipboardContent),
+}
+
 /// Re-export of rust-allocated (stack based) `ResultRawImageDecodeImageError` struct
 #[repr(C, u8)]
 pub enum AzResultRawImageDecodeImageError {
@@ -7260,9 +8734,20 @@ pub enum AzXmlStreamError {
     InvalidCharacterData,
 }
 
+/// Current keyboard state, stores what keys / characters have been pressed
+#[repr(C)]
+pub struct AzKeyboardState {
+    pub current_char: AzOptionCharEnumWrapper,
+    pub current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper,
+    pub pressed_virtual_keycodes: AzVirtualKeyCodeVec,
+    pub pressed_scancodes: AzScanCodeVec,
+    pub ime_composition: AzOptionImeCompositionEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `LinuxWindowOptions` struct
 #[repr(C)]
 pub struct AzLinuxWindowOptions {
+    pub backend: AzLinuxBackendEnumWrapper,
     pub x11_visual: AzOptionX11VisualEnumWrapper,
     pub x11_screen: AzOptionI32EnumWrapper,
     pub x11_wm_classes: AzStringPairVec,
@@ -7284,6 +8769,13 @@ pub struct AzInlineLine {
     pub bounds: AzLogicalRect,
 }
 
+/// Tooltip of a node, shown in an always-on-top popup window once the mouse has rested on the node for `delay`. See `NodeData::set_tooltip`
+#[repr(C)]
+pub struct AzTooltip {
+    pub content: AzTooltipContentEnumWrapper,
+    pub delay: AzDurationEnumWrapper,
+}
+
 /// Item entry in a menu or menu bar
 #[repr(C, u8)]
 pub enum AzMenuItem {
@@ -7298,6 +8790,16 @@ pub struct AzCssPath {
     pub selectors: AzCssPathSelectorVec,
 }
 
+/// Re-export of rust-allocated (stack based) `StyleAnimationNameVecValue` struct
+#[repr(C, u8)]
+pub enum AzStyleAnimationNameVecValue {
+    Auto,
+    None,
+    Inherit,
+    Initial,
+    Exact(AzStyleAnimationNameVec),
+}
+
 /// Re-export of rust-allocated (stack based) `StyleBackgroundContentVecValue` struct
 #[repr(C, u8)]
 pub enum AzStyleBackgroundContentVecValue {
@@ -7325,6 +8827,10 @@ pub enum AzCssProperty {
     FontSize(AzStyleFontSizeValue),
     FontFamily(AzStyleFontFamilyVecValue),
     TextAlign(AzStyleTextAlignValue),
+    Direction(AzStyleDirectionValue),
+    WordBreak(AzStyleWordBreakValue),
+    OverflowWrap(AzStyleOverflowWrapValue),
+    Hyphens(AzStyleHyphensValue),
     LetterSpacing(AzStyleLetterSpacingValue),
     LineHeight(AzStyleLineHeightValue),
     WordSpacing(AzStyleWordSpacingValue),
@@ -7351,6 +8857,15 @@ pub enum AzCssProperty {
     JustifyContent(AzLayoutJustifyContentValue),
     AlignItems(AzLayoutAlignItemsValue),
     AlignContent(AzLayoutAlignContentValue),
+    GridTemplateColumns(AzGridTrackSizingVecValue),
+    GridTemplateRows(AzGridTrackSizingVecValue),
+    GridAutoColumns(AzGridTrackSizingValue),
+    GridAutoRows(AzGridTrackSizingValue),
+    GridAutoFlow(AzLayoutGridAutoFlowValue),
+    GridColumn(AzGridPlacementValue),
+    GridRow(AzGridPlacementValue),
+    RowGap(AzLayoutRowGapValue),
+    ColumnGap(AzLayoutColumnGapValue),
     BackgroundContent(AzStyleBackgroundContentVecValue),
     BackgroundPosition(AzStyleBackgroundPositionVecValue),
     BackgroundSize(AzStyleBackgroundSizeVecValue),
@@ -7395,6 +8910,26 @@ pub enum AzCssProperty {
     Filter(AzStyleFilterVecValue),
     BackdropFilter(AzStyleFilterVecValue),
     TextShadow(AzStyleBoxShadowValue),
+    TransitionProperty(AzStyleTransitionPropertyVecValue),
+    TransitionDuration(AzStyleTimeVecValue),
+    TransitionTimingFunction(AzStyleTimingFunctionVecValue),
+    TransitionDelay(AzStyleTimeVecValue),
+    AnimationName(AzStyleAnimationNameVecValue),
+    AnimationDuration(AzStyleTimeVecValue),
+    AnimationTimingFunction(AzStyleTimingFunctionVecValue),
+    AnimationDelay(AzStyleTimeVecValue),
+    AnimationIterationCount(AzStyleAnimationIterationCountVecValue),
+    AnimationDirection(AzStyleAnimationDirectionVecValue),
+    AnimationFillMode(AzStyleAnimationFillModeVecValue),
+    AnimationPlayState(AzStyleAnimationPlayStateVecValue),
+}
+
+/// Re-export of rust-allocated (stack based) `RibbonGroup` struct
+#[repr(C)]
+pub struct AzRibbonGroup {
+    pub label: AzString,
+    pub icon: AzOptionImageRefEnumWrapper,
+    pub items: AzRibbonItemVec,
 }
 
 /// Re-export of rust-allocated (stack based) `FileInputStateWrapper` struct
@@ -7419,6 +8954,13 @@ pub struct AzTextInputStateWrapper {
     pub cursor_animation: AzOptionTimerIdEnumWrapper,
 }
 
+/// Multi-line text input with soft wrapping, scrolling and an undo history
+#[repr(C)]
+pub struct AzTextArea {
+    pub state: AzTextAreaState,
+    pub on_text_change: AzOptionTextAreaOnTextChangeEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `ProgressBar` struct
 #[repr(C)]
 pub struct AzProgressBar {
@@ -7426,6 +8968,8 @@ pub struct AzProgressBar {
     pub height: AzPixelValue,
     pub bar_background: AzStyleBackgroundContentVec,
     pub container_background: AzStyleBackgroundContentVec,
+    pub style: AzProgressBarStyleEnumWrapper,
+    pub text_format: AzOptionStringEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `NodeTypeIdInfoMap` struct
@@ -7449,6 +8993,18 @@ pub struct AzNodeTypeField {
     pub value: AzNodeTypeFieldValueEnumWrapper,
 }
 
+/// Re-export of rust-allocated (stack based) `TreeView` struct
+#[repr(C)]
+pub struct AzTreeView {
+    pub nodes: AzTreeViewNodeVec,
+    pub state: AzTreeViewState,
+    pub selection_mode: AzTreeViewSelectionModeEnumWrapper,
+    pub on_expand: AzOptionTreeViewOnExpandEnumWrapper,
+    pub on_select: AzOptionTreeViewOnSelectEnumWrapper,
+    pub on_double_click: AzOptionTreeViewOnDoubleClickEnumWrapper,
+    pub on_context_menu: AzOptionTreeViewOnContextMenuEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `CssPropertySource` struct
 #[repr(C, u8)]
 pub enum AzCssPropertySource {
@@ -7506,6 +9062,15 @@ pub struct AzXmlNode {
     pub text: AzOptionStringEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<RibbonGroup>`
+#[repr(C)]
+pub struct AzRibbonGroupVec {
+    pub(crate) ptr: *const AzRibbonGroup,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzRibbonGroupVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<NodeTypeIdInfoMap>`
 #[repr(C)]
 pub struct AzNodeTypeIdInfoMapVec {
@@ -7567,6 +9132,13 @@ pub enum AzOptionCssProperty {
     Some(AzCssProperty),
 }
 
+/// Re-export of rust-allocated (stack based) `OptionKeyboardState` struct
+#[repr(C, u8)]
+pub enum AzOptionKeyboardState {
+    None,
+    Some(AzKeyboardState),
+}
+
 /// Re-export of rust-allocated (stack based) `XmlTextError` struct
 #[repr(C)]
 pub struct AzXmlTextError {
@@ -7695,6 +9267,21 @@ pub struct AzDynamicCssProperty {
     pub default_value: AzCssPropertyEnumWrapper,
 }
 
+/// One keyframe inside of a `@keyframes` block, i.e. `50% { opacity: 0.5; }`
+#[repr(C)]
+pub struct AzCssKeyframe {
+    pub offset: AzPercentageValue,
+    pub properties: AzCssPropertyVec,
+}
+
+/// Re-export of rust-allocated (stack based) `RibbonTab` struct
+#[repr(C)]
+pub struct AzRibbonTab {
+    pub label: AzString,
+    pub key_tip: AzOptionStringEnumWrapper,
+    pub groups: AzRibbonGroupVec,
+}
+
 /// Re-export of rust-allocated (stack based) `Node` struct
 #[repr(C)]
 pub struct AzNode {
@@ -7722,6 +9309,15 @@ pub struct AzSvgStyledNode {
     pub style: AzSvgStyleEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<RibbonTab>`
+#[repr(C)]
+pub struct AzRibbonTabVec {
+    pub(crate) ptr: *const AzRibbonTab,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzRibbonTabVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `Vec<NodeDataInlineCssProperty>`
 #[repr(C)]
 pub struct AzNodeDataInlineCssPropertyVec {
@@ -7731,6 +9327,15 @@ pub struct AzNodeDataInlineCssPropertyVec {
     pub destructor: AzNodeDataInlineCssPropertyVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssKeyframe>`
+#[repr(C)]
+pub struct AzCssKeyframeVec {
+    pub(crate) ptr: *const AzCssKeyframe,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzCssKeyframeVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `OptionWindowState` struct
 #[repr(C, u8)]
 pub enum AzOptionWindowState {
@@ -7802,6 +9407,22 @@ pub enum AzCssDeclaration {
     Dynamic(AzDynamicCssProperty),
 }
 
+/// One `@keyframes` block, i.e. `@keyframes fade { from { opacity: 0; } to { opacity: 1; } }`
+#[repr(C)]
+pub struct AzCssKeyframes {
+    pub name: AzString,
+    pub frames: AzCssKeyframeVec,
+}
+
+/// Re-export of rust-allocated (stack based) `Ribbon` struct
+#[repr(C)]
+pub struct AzRibbon {
+    pub tabs: AzRibbonTabVec,
+    pub tab_active: i32,
+    pub available_width: AzOptionF32EnumWrapper,
+    pub on_tab_clicked: AzOptionRibbonOnTabClickedEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `Button` struct
 #[repr(C)]
 pub struct AzButton {
@@ -7862,6 +9483,36 @@ pub struct AzNumberInput {
     pub state: AzNumberInputStateWrapper,
 }
 
+/// Thumb that can be dragged along a track to select a value between `min` and `max`
+#[repr(C)]
+pub struct AzSlider {
+    pub orientation: AzSliderOrientationEnumWrapper,
+    pub state: AzSliderState,
+    pub tick_interval: f32,
+    pub on_value_change: AzOptionSliderOnValueChangeEnumWrapper,
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    pub track_style: AzNodeDataInlineCssPropertyVec,
+    pub fill_style: AzNodeDataInlineCssPropertyVec,
+    pub rail_style: AzNodeDataInlineCssPropertyVec,
+    pub thumb_style: AzNodeDataInlineCssPropertyVec,
+    pub tick_style: AzNodeDataInlineCssPropertyVec,
+}
+
+/// Slider with two thumbs to select a range (`low..=high`) between `min` and `max`
+#[repr(C)]
+pub struct AzRangeSlider {
+    pub orientation: AzSliderOrientationEnumWrapper,
+    pub state: AzRangeSliderState,
+    pub tick_interval: f32,
+    pub on_value_change: AzOptionRangeSliderOnValueChangeEnumWrapper,
+    pub container_style: AzNodeDataInlineCssPropertyVec,
+    pub track_style: AzNodeDataInlineCssPropertyVec,
+    pub fill_style: AzNodeDataInlineCssPropertyVec,
+    pub rail_style: AzNodeDataInlineCssPropertyVec,
+    pub thumb_style: AzNodeDataInlineCssPropertyVec,
+    pub tick_style: AzNodeDataInlineCssPropertyVec,
+}
+
 /// Re-export of rust-allocated (stack based) `NodeIdNodeMap` struct
 #[repr(C)]
 pub struct AzNodeIdNodeMap {
@@ -7887,6 +9538,15 @@ pub struct AzCssDeclarationVec {
     pub destructor: AzCssDeclarationVecDestructorEnumWrapper,
 }
 
+/// Wrapper over a Rust-allocated `Vec<CssKeyframes>`
+#[repr(C)]
+pub struct AzCssKeyframesVec {
+    pub(crate) ptr: *const AzCssKeyframes,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzCssKeyframesVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `NodeDataVec`
 #[repr(C)]
 pub struct AzNodeDataVec {
@@ -7948,6 +9608,16 @@ pub struct AzFrame {
     pub content: AzDom,
 }
 
+/// Re-export of rust-allocated (stack based) `SplitterPane` struct
+#[repr(C)]
+pub struct AzSplitterPane {
+    pub content: AzDom,
+    pub size: AzOptionF32EnumWrapper,
+    pub min_size: f32,
+    pub max_size: AzOptionF32EnumWrapper,
+    pub collapsible: bool,
+}
+
 /// Re-export of rust-allocated (stack based) `NodeGraph` struct
 #[repr(C)]
 pub struct AzNodeGraph {
@@ -7978,6 +9648,15 @@ pub struct AzStyledDom {
     pub css_property_cache: AzCssPropertyCache,
 }
 
+/// Wrapper over a Rust-allocated `Vec<SplitterPane>`
+#[repr(C)]
+pub struct AzSplitterPaneVec {
+    pub(crate) ptr: *const AzSplitterPane,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzSplitterPaneVecDestructorEnumWrapper,
+}
+
 /// Wrapper over a Rust-allocated `CssRuleBlock`
 #[repr(C)]
 pub struct AzCssRuleBlockVec {
@@ -8022,19 +9701,30 @@ pub struct AzIFrameCallbackReturn {
     pub virtual_scroll_offset: AzLogicalPosition,
 }
 
-/// Re-export of rust-allocated (stack based) `Stylesheet` struct
+/// One `@media` block, i.e. `@media (prefers-color-scheme: dark) { body { color: white; } }`
 #[repr(C)]
-pub struct AzStylesheet {
+pub struct AzCssMediaRule {
+    pub queries: AzCssMediaQueryVec,
+    pub insert_index: usize,
     pub rules: AzCssRuleBlockVec,
 }
 
-/// Wrapper over a Rust-allocated `Stylesheet`
+/// Container with resizable panes, separated by draggable handles
 #[repr(C)]
-pub struct AzStylesheetVec {
-    pub(crate) ptr: *const AzStylesheet,
+pub struct AzSplitter {
+    pub orientation: AzSplitterOrientationEnumWrapper,
+    pub panes: AzSplitterPaneVec,
+    pub state: AzSplitterState,
+    pub on_resize: AzOptionSplitterOnResizeEnumWrapper,
+}
+
+/// Wrapper over a Rust-allocated `Vec<CssMediaRule>`
+#[repr(C)]
+pub struct AzCssMediaRuleVec {
+    pub(crate) ptr: *const AzCssMediaRule,
     pub len: usize,
     pub cap: usize,
-    pub destructor: AzStylesheetVecDestructorEnumWrapper,
+    pub destructor: AzCssMediaRuleVecDestructorEnumWrapper,
 }
 
 /// Re-export of rust-allocated (stack based) `ResultSvgXmlNodeSvgParseError` struct
@@ -8051,6 +9741,23 @@ pub enum AzResultSvgSvgParseError {
     Err(AzSvgParseError),
 }
 
+/// Re-export of rust-allocated (stack based) `Stylesheet` struct
+#[repr(C)]
+pub struct AzStylesheet {
+    pub rules: AzCssRuleBlockVec,
+    pub keyframes: AzCssKeyframesVec,
+    pub media_rules: AzCssMediaRuleVec,
+}
+
+/// Wrapper over a Rust-allocated `Stylesheet`
+#[repr(C)]
+pub struct AzStylesheetVec {
+    pub(crate) ptr: *const AzStylesheet,
+    pub len: usize,
+    pub cap: usize,
+    pub destructor: AzStylesheetVecDestructorEnumWrapper,
+}
+
 /// Re-export of rust-allocated (stack based) `Css` struct
 #[repr(C)]
 pub struct AzCss {
@@ -8087,6 +9794,18 @@ pub struct AzHwAccelerationEnumWrapper {
     pub inner: AzHwAcceleration,
 }
 
+/// `AzClipboardSelectionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzClipboardSelectionEnumWrapper {
+    pub inner: AzClipboardSelection,
+}
+
+/// `AzLinuxBackendEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLinuxBackendEnumWrapper {
+    pub inner: AzLinuxBackend,
+}
+
 /// `AzXWindowTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzXWindowTypeEnumWrapper {
@@ -8201,6 +9920,12 @@ pub struct AzAccessibilityStateEnumWrapper {
     pub inner: AzAccessibilityState,
 }
 
+/// `AzAccessibilityActionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzAccessibilityActionEnumWrapper {
+    pub inner: AzAccessibilityAction,
+}
+
 /// `AzTabIndexEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzTabIndexEnumWrapper {
@@ -8231,12 +9956,36 @@ pub struct AzNodeTypeKeyEnumWrapper {
     pub inner: AzNodeTypeKey,
 }
 
+/// `AzCssMediaTypeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssMediaTypeEnumWrapper {
+    pub inner: AzCssMediaType,
+}
+
+/// `AzCssMediaOrientationEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssMediaOrientationEnumWrapper {
+    pub inner: AzCssMediaOrientation,
+}
+
+/// `AzCssColorSchemeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssColorSchemeEnumWrapper {
+    pub inner: AzCssColorScheme,
+}
+
 /// `AzCssPropertyTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzCssPropertyTypeEnumWrapper {
     pub inner: AzCssPropertyType,
 }
 
+/// `AzCombinedCssPropertyTypeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCombinedCssPropertyTypeEnumWrapper {
+    pub inner: AzCombinedCssPropertyType,
+}
+
 /// `AzSizeMetricEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzSizeMetricEnumWrapper {
@@ -8261,6 +10010,18 @@ pub struct AzLayoutAlignContentEnumWrapper {
     pub inner: AzLayoutAlignContent,
 }
 
+/// `AzLayoutGridAutoFlowEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutGridAutoFlowEnumWrapper {
+    pub inner: AzLayoutGridAutoFlow,
+}
+
+/// `AzGridLineEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGridLineEnumWrapper {
+    pub inner: AzGridLine,
+}
+
 /// `AzLayoutAlignItemsEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzLayoutAlignItemsEnumWrapper {
@@ -8369,24 +10130,114 @@ pub struct AzStyleBackfaceVisibilityEnumWrapper {
     pub inner: AzStyleBackfaceVisibility,
 }
 
+/// `AzStyleStepPositionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleStepPositionEnumWrapper {
+    pub inner: AzStyleStepPosition,
+}
+
+/// `AzStyleAnimationDirectionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationDirectionEnumWrapper {
+    pub inner: AzStyleAnimationDirection,
+}
+
+/// `AzStyleAnimationFillModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationFillModeEnumWrapper {
+    pub inner: AzStyleAnimationFillMode,
+}
+
+/// `AzStyleAnimationPlayStateEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationPlayStateEnumWrapper {
+    pub inner: AzStyleAnimationPlayState,
+}
+
 /// `AzStyleTextAlignEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleTextAlignEnumWrapper {
     pub inner: AzStyleTextAlign,
 }
 
+/// `AzStyleDirectionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleDirectionEnumWrapper {
+    pub inner: AzStyleDirection,
+}
+
+/// `AzStyleWordBreakEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleWordBreakEnumWrapper {
+    pub inner: AzStyleWordBreak,
+}
+
+/// `AzStyleOverflowWrapEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleOverflowWrapEnumWrapper {
+    pub inner: AzStyleOverflowWrap,
+}
+
+/// `AzStyleHyphensEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleHyphensEnumWrapper {
+    pub inner: AzStyleHyphens,
+}
+
+/// `AzRibbonItemKindEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzRibbonItemKindEnumWrapper {
+    pub inner: AzRibbonItemKind,
+}
+
 /// `AzTextInputValidEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzTextInputValidEnumWrapper {
     pub inner: AzTextInputValid,
 }
 
+/// `AzTextAreaEditKindEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTextAreaEditKindEnumWrapper {
+    pub inner: AzTextAreaEditKind,
+}
+
+/// `AzProgressBarModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzProgressBarModeEnumWrapper {
+    pub inner: AzProgressBarMode,
+}
+
+/// `AzProgressBarStyleEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzProgressBarStyleEnumWrapper {
+    pub inner: AzProgressBarStyle,
+}
+
+/// `AzSplitterOrientationEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzSplitterOrientationEnumWrapper {
+    pub inner: AzSplitterOrientation,
+}
+
+/// `AzSliderOrientationEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzSliderOrientationEnumWrapper {
+    pub inner: AzSliderOrientation,
+}
+
 /// `AzNodeGraphStyleEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzNodeGraphStyleEnumWrapper {
     pub inner: AzNodeGraphStyle,
 }
 
+/// `AzTreeViewSelectionModeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTreeViewSelectionModeEnumWrapper {
+    pub inner: AzTreeViewSelectionMode,
+}
+
 /// `AzVertexAttributeTypeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzVertexAttributeTypeEnumWrapper {
@@ -8513,6 +10364,54 @@ pub struct AzListViewRowVecDestructorEnumWrapper {
     pub inner: AzListViewRowVecDestructor,
 }
 
+/// `AzTreeViewNodeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTreeViewNodeVecDestructorEnumWrapper {
+    pub inner: AzTreeViewNodeVecDestructor,
+}
+
+/// `AzTreeViewNodeIdVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTreeViewNodeIdVecDestructorEnumWrapper {
+    pub inner: AzTreeViewNodeIdVecDestructor,
+}
+
+/// `AzRibbonTabVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzRibbonTabVecDestructorEnumWrapper {
+    pub inner: AzRibbonTabVecDestructor,
+}
+
+/// `AzRibbonGroupVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzRibbonGroupVecDestructorEnumWrapper {
+    pub inner: AzRibbonGroupVecDestructor,
+}
+
+/// `AzRibbonItemVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzRibbonItemVecDestructorEnumWrapper {
+    pub inner: AzRibbonItemVecDestructor,
+}
+
+/// `AzSplitterPaneVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzSplitterPaneVecDestructorEnumWrapper {
+    pub inner: AzSplitterPaneVecDestructor,
+}
+
+/// `AzSplitterPaneStateVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzSplitterPaneStateVecDestructorEnumWrapper {
+    pub inner: AzSplitterPaneStateVecDestructor,
+}
+
+/// `AzTextAreaEditVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTextAreaEditVecDestructorEnumWrapper {
+    pub inner: AzTextAreaEditVecDestructor,
+}
+
 /// `AzStyleFilterVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleFilterVecDestructorEnumWrapper {
@@ -8585,6 +10484,12 @@ pub struct AzAccessibilityStateVecDestructorEnumWrapper {
     pub inner: AzAccessibilityStateVecDestructor,
 }
 
+/// `AzAccessibilityActionVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzAccessibilityActionVecDestructorEnumWrapper {
+    pub inner: AzAccessibilityActionVecDestructor,
+}
+
 /// `AzMenuItemVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzMenuItemVecDestructorEnumWrapper {
@@ -8693,6 +10598,60 @@ pub struct AzStyleTransformVecDestructorEnumWrapper {
     pub inner: AzStyleTransformVecDestructor,
 }
 
+/// `AzStyleTransitionPropertyVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransitionPropertyVecDestructorEnumWrapper {
+    pub inner: AzStyleTransitionPropertyVecDestructor,
+}
+
+/// `AzStyleTimeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTimeVecDestructorEnumWrapper {
+    pub inner: AzStyleTimeVecDestructor,
+}
+
+/// `AzStyleTimingFunctionVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTimingFunctionVecDestructorEnumWrapper {
+    pub inner: AzStyleTimingFunctionVecDestructor,
+}
+
+/// `AzStyleAnimationNameVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationNameVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationNameVecDestructor,
+}
+
+/// `AzStyleAnimationIterationCountVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationIterationCountVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationIterationCountVecDestructor,
+}
+
+/// `AzStyleAnimationDirectionVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationDirectionVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationDirectionVecDestructor,
+}
+
+/// `AzStyleAnimationFillModeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationFillModeVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationFillModeVecDestructor,
+}
+
+/// `AzStyleAnimationPlayStateVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationPlayStateVecDestructorEnumWrapper {
+    pub inner: AzStyleAnimationPlayStateVecDestructor,
+}
+
+/// `AzGridTrackSizingVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGridTrackSizingVecDestructorEnumWrapper {
+    pub inner: AzGridTrackSizingVecDestructor,
+}
+
 /// `AzCssPropertyVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzCssPropertyVecDestructorEnumWrapper {
@@ -8777,6 +10736,36 @@ pub struct AzStylesheetVecDestructorEnumWrapper {
     pub inner: AzStylesheetVecDestructor,
 }
 
+/// `AzCssKeyframesVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssKeyframesVecDestructorEnumWrapper {
+    pub inner: AzCssKeyframesVecDestructor,
+}
+
+/// `AzCssKeyframeVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssKeyframeVecDestructorEnumWrapper {
+    pub inner: AzCssKeyframeVecDestructor,
+}
+
+/// `AzCssMediaRuleVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssMediaRuleVecDestructorEnumWrapper {
+    pub inner: AzCssMediaRuleVecDestructor,
+}
+
+/// `AzCssMediaQueryVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssMediaQueryVecDestructorEnumWrapper {
+    pub inner: AzCssMediaQueryVecDestructor,
+}
+
+/// `AzCssMediaFeatureVecDestructorEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssMediaFeatureVecDestructorEnumWrapper {
+    pub inner: AzCssMediaFeatureVecDestructor,
+}
+
 /// `AzCssRuleBlockVecDestructorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzCssRuleBlockVecDestructorEnumWrapper {
@@ -8999,6 +10988,12 @@ pub struct AzStyleCompositeFilterEnumWrapper {
     pub inner: AzStyleCompositeFilter,
 }
 
+/// `AzGridTrackBreadthEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGridTrackBreadthEnumWrapper {
+    pub inner: AzGridTrackBreadth,
+}
+
 /// `AzDirectionEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzDirectionEnumWrapper {
@@ -9023,6 +11018,24 @@ pub struct AzStyleBackgroundSizeEnumWrapper {
     pub inner: AzStyleBackgroundSize,
 }
 
+/// `AzStyleTimingFunctionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTimingFunctionEnumWrapper {
+    pub inner: AzStyleTimingFunction,
+}
+
+/// `AzStyleTransitionPropertyEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransitionPropertyEnumWrapper {
+    pub inner: AzStyleTransitionProperty,
+}
+
+/// `AzStyleAnimationIterationCountEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationIterationCountEnumWrapper {
+    pub inner: AzStyleAnimationIterationCount,
+}
+
 /// `AzStyleBoxShadowValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBoxShadowValueEnumWrapper {
@@ -9035,6 +11048,36 @@ pub struct AzLayoutAlignContentValueEnumWrapper {
     pub inner: AzLayoutAlignContentValue,
 }
 
+/// `AzGridTrackSizingValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGridTrackSizingValueEnumWrapper {
+    pub inner: AzGridTrackSizingValue,
+}
+
+/// `AzLayoutGridAutoFlowValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutGridAutoFlowValueEnumWrapper {
+    pub inner: AzLayoutGridAutoFlowValue,
+}
+
+/// `AzGridPlacementValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGridPlacementValueEnumWrapper {
+    pub inner: AzGridPlacementValue,
+}
+
+/// `AzLayoutRowGapValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutRowGapValueEnumWrapper {
+    pub inner: AzLayoutRowGapValue,
+}
+
+/// `AzLayoutColumnGapValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzLayoutColumnGapValueEnumWrapper {
+    pub inner: AzLayoutColumnGapValue,
+}
+
 /// `AzLayoutAlignItemsValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzLayoutAlignItemsValueEnumWrapper {
@@ -9341,6 +11384,30 @@ pub struct AzStyleTextAlignValueEnumWrapper {
     pub inner: AzStyleTextAlignValue,
 }
 
+/// `AzStyleDirectionValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleDirectionValueEnumWrapper {
+    pub inner: AzStyleDirectionValue,
+}
+
+/// `AzStyleWordBreakValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleWordBreakValueEnumWrapper {
+    pub inner: AzStyleWordBreakValue,
+}
+
+/// `AzStyleOverflowWrapValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleOverflowWrapValueEnumWrapper {
+    pub inner: AzStyleOverflowWrapValue,
+}
+
+/// `AzStyleHyphensValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleHyphensValueEnumWrapper {
+    pub inner: AzStyleHyphensValue,
+}
+
 /// `AzStyleTextColorValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleTextColorValueEnumWrapper {
@@ -9407,6 +11474,36 @@ pub struct AzOptionListViewOnRowClickEnumWrapper {
     pub inner: AzOptionListViewOnRowClick,
 }
 
+/// `AzOptionTreeViewNodeIdEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTreeViewNodeIdEnumWrapper {
+    pub inner: AzOptionTreeViewNodeId,
+}
+
+/// `AzOptionTreeViewOnExpandEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTreeViewOnExpandEnumWrapper {
+    pub inner: AzOptionTreeViewOnExpand,
+}
+
+/// `AzOptionTreeViewOnSelectEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTreeViewOnSelectEnumWrapper {
+    pub inner: AzOptionTreeViewOnSelect,
+}
+
+/// `AzOptionTreeViewOnDoubleClickEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTreeViewOnDoubleClickEnumWrapper {
+    pub inner: AzOptionTreeViewOnDoubleClick,
+}
+
+/// `AzOptionTreeViewOnContextMenuEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTreeViewOnContextMenuEnumWrapper {
+    pub inner: AzOptionTreeViewOnContextMenu,
+}
+
 /// `AzOptionListViewOnColumnClickEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionListViewOnColumnClickEnumWrapper {
@@ -9419,6 +11516,18 @@ pub struct AzOptionListViewOnLazyLoadScrollEnumWrapper {
     pub inner: AzOptionListViewOnLazyLoadScroll,
 }
 
+/// `AzOptionListViewOnColumnResizeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionListViewOnColumnResizeEnumWrapper {
+    pub inner: AzOptionListViewOnColumnResize,
+}
+
+/// `AzOptionListViewRowProviderEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionListViewRowProviderEnumWrapper {
+    pub inner: AzOptionListViewRowProvider,
+}
+
 /// `AzOptionPixelValueNoPercentEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionPixelValueNoPercentEnumWrapper {
@@ -9497,6 +11606,54 @@ pub struct AzOptionTabOnClickEnumWrapper {
     pub inner: AzOptionTabOnClick,
 }
 
+/// `AzOptionTabOnCloseEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTabOnCloseEnumWrapper {
+    pub inner: AzOptionTabOnClose,
+}
+
+/// `AzOptionTabOnReorderEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTabOnReorderEnumWrapper {
+    pub inner: AzOptionTabOnReorder,
+}
+
+/// `AzOptionTabOnContextMenuEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTabOnContextMenuEnumWrapper {
+    pub inner: AzOptionTabOnContextMenu,
+}
+
+/// `AzOptionRibbonOnTabClickedEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionRibbonOnTabClickedEnumWrapper {
+    pub inner: AzOptionRibbonOnTabClicked,
+}
+
+/// `AzOptionRibbonOnItemClickEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionRibbonOnItemClickEnumWrapper {
+    pub inner: AzOptionRibbonOnItemClick,
+}
+
+/// `AzOptionSplitterOnResizeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionSplitterOnResizeEnumWrapper {
+    pub inner: AzOptionSplitterOnResize,
+}
+
+/// `AzOptionSliderOnValueChangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionSliderOnValueChangeEnumWrapper {
+    pub inner: AzOptionSliderOnValueChange,
+}
+
+/// `AzOptionRangeSliderOnValueChangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionRangeSliderOnValueChangeEnumWrapper {
+    pub inner: AzOptionRangeSliderOnValueChange,
+}
+
 /// `AzOptionFileInputOnPathChangeEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionFileInputOnPathChangeEnumWrapper {
@@ -9533,6 +11690,12 @@ pub struct AzOptionTextInputSelectionEnumWrapper {
     pub inner: AzOptionTextInputSelection,
 }
 
+/// `AzOptionTextAreaOnTextChangeEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionTextAreaOnTextChangeEnumWrapper {
+    pub inner: AzOptionTextAreaOnTextChange,
+}
+
 /// `AzOptionNumberInputOnFocusLostEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionNumberInputOnFocusLostEnumWrapper {
@@ -9689,6 +11852,12 @@ pub struct AzOptionLogicalPositionEnumWrapper {
     pub inner: AzOptionLogicalPosition,
 }
 
+/// `AzOptionLogicalRectEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionLogicalRectEnumWrapper {
+    pub inner: AzOptionLogicalRect,
+}
+
 /// `AzOptionPhysicalPositionI32EnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionPhysicalPositionI32EnumWrapper {
@@ -9779,6 +11948,12 @@ pub struct AzCssPathPseudoSelectorEnumWrapper {
     pub inner: AzCssPathPseudoSelector,
 }
 
+/// `AzCssMediaFeatureEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzCssMediaFeatureEnumWrapper {
+    pub inner: AzCssMediaFeature,
+}
+
 /// `AzAnimationInterpolationFunctionEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzAnimationInterpolationFunctionEnumWrapper {
@@ -9797,6 +11972,54 @@ pub struct AzStyleTransformEnumWrapper {
     pub inner: AzStyleTransform,
 }
 
+/// `AzGridTrackSizingVecValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzGridTrackSizingVecValueEnumWrapper {
+    pub inner: AzGridTrackSizingVecValue,
+}
+
+/// `AzStyleTransitionPropertyVecValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTransitionPropertyVecValueEnumWrapper {
+    pub inner: AzStyleTransitionPropertyVecValue,
+}
+
+/// `AzStyleTimeVecValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTimeVecValueEnumWrapper {
+    pub inner: AzStyleTimeVecValue,
+}
+
+/// `AzStyleTimingFunctionVecValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleTimingFunctionVecValueEnumWrapper {
+    pub inner: AzStyleTimingFunctionVecValue,
+}
+
+/// `AzStyleAnimationIterationCountVecValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationIterationCountVecValueEnumWrapper {
+    pub inner: AzStyleAnimationIterationCountVecValue,
+}
+
+/// `AzStyleAnimationDirectionVecValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationDirectionVecValueEnumWrapper {
+    pub inner: AzStyleAnimationDirectionVecValue,
+}
+
+/// `AzStyleAnimationFillModeVecValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationFillModeVecValueEnumWrapper {
+    pub inner: AzStyleAnimationFillModeVecValue,
+}
+
+/// `AzStyleAnimationPlayStateVecValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationPlayStateVecValueEnumWrapper {
+    pub inner: AzStyleAnimationPlayStateVecValue,
+}
+
 /// `AzStyleBackgroundPositionVecValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBackgroundPositionVecValueEnumWrapper {
@@ -9863,12 +12086,6 @@ pub struct AzOptionMouseStateEnumWrapper {
     pub inner: AzOptionMouseState,
 }
 
-/// `AzOptionKeyboardStateEnumWrapper` struct
-#[repr(transparent)]
-pub struct AzOptionKeyboardStateEnumWrapper {
-    pub inner: AzOptionKeyboardState,
-}
-
 /// `AzOptionStringVecEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzOptionStringVecEnumWrapper {
@@ -9929,6 +12146,12 @@ pub struct AzNodeTypeEnumWrapper {
     pub inner: AzNodeType,
 }
 
+/// `AzTooltipContentEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzTooltipContentEnumWrapper {
+    pub inner: AzTooltipContent,
+}
+
 /// `AzIdOrClassEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzIdOrClassEnumWrapper {
@@ -9953,6 +12176,12 @@ pub struct AzStyleFontFamilyEnumWrapper {
     pub inner: AzStyleFontFamily,
 }
 
+/// `AzStyleAnimationNameEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationNameEnumWrapper {
+    pub inner: AzStyleAnimationName,
+}
+
 /// `AzScrollbarStyleValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzScrollbarStyleValueEnumWrapper {
@@ -10013,6 +12242,18 @@ pub struct AzOptionWaylandThemeEnumWrapper {
     pub inner: AzOptionWaylandTheme,
 }
 
+/// `AzOptionImeCompositionEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionImeCompositionEnumWrapper {
+    pub inner: AzOptionImeComposition,
+}
+
+/// `AzOptionClipboardContentEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionClipboardContentEnumWrapper {
+    pub inner: AzOptionClipboardContent,
+}
+
 /// `AzResultRawImageDecodeImageErrorEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzResultRawImageDecodeImageErrorEnumWrapper {
@@ -10031,6 +12272,12 @@ pub struct AzMenuItemEnumWrapper {
     pub inner: AzMenuItem,
 }
 
+/// `AzStyleAnimationNameVecValueEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzStyleAnimationNameVecValueEnumWrapper {
+    pub inner: AzStyleAnimationNameVecValue,
+}
+
 /// `AzStyleBackgroundContentVecValueEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzStyleBackgroundContentVecValueEnumWrapper {
@@ -10061,6 +12308,12 @@ pub struct AzOptionCssPropertyEnumWrapper {
     pub inner: AzOptionCssProperty,
 }
 
+/// `AzOptionKeyboardStateEnumWrapper` struct
+#[repr(transparent)]
+pub struct AzOptionKeyboardStateEnumWrapper {
+    pub inner: AzOptionKeyboardState,
+}
+
 /// `AzNodeDataInlineCssPropertyEnumWrapper` struct
 #[repr(transparent)]
 pub struct AzNodeDataInlineCssPropertyEnumWrapper {
@@ -10185,11 +12438,14 @@ unsafe impl Send for AzGl { }
 unsafe impl Send for AzRefstrVecRef { }
 unsafe impl Send for AzFontMetrics { }
 unsafe impl Send for AzInstantPtr { }
+unsafe impl Send for AzTreeViewNodeIdVec { }
+unsafe impl Send for AzSplitterPaneStateVec { }
 unsafe impl Send for AzLogicalRectVec { }
 unsafe impl Send for AzInputOutputTypeIdVec { }
 unsafe impl Send for AzOutputNodeAndIndexVec { }
 unsafe impl Send for AzInputNodeAndIndexVec { }
 unsafe impl Send for AzAccessibilityStateVec { }
+unsafe impl Send for AzAccessibilityActionVec { }
 unsafe impl Send for AzMenuItemVec { }
 unsafe impl Send for AzXmlNodeVec { }
 unsafe impl Send for AzInlineGlyphVec { }
@@ -10199,6 +12455,14 @@ unsafe impl Send for AzDomVec { }
 unsafe impl Send for AzStyleBackgroundPositionVec { }
 unsafe impl Send for AzStyleBackgroundRepeatVec { }
 unsafe impl Send for AzStyleBackgroundSizeVec { }
+unsafe impl Send for AzStyleTransitionPropertyVec { }
+unsafe impl Send for AzStyleTimeVec { }
+unsafe impl Send for AzStyleTimingFunctionVec { }
+unsafe impl Send for AzStyleAnimationIterationCountVec { }
+unsafe impl Send for AzStyleAnimationDirectionVec { }
+unsafe impl Send for AzStyleAnimationFillModeVec { }
+unsafe impl Send for AzStyleAnimationPlayStateVec { }
+unsafe impl Send for AzGridTrackSizingVec { }
 unsafe impl Send for AzSvgVertexVec { }
 unsafe impl Send for AzU32Vec { }
 unsafe impl Send for AzXWindowTypeVec { }
@@ -10220,31 +12484,38 @@ unsafe impl Send for AzLayoutCallbackInfo { }
 unsafe impl Send for AzTexture { }
 unsafe impl Send for AzTessellatedSvgNodeVecRef { }
 unsafe impl Send for AzListViewRowVec { }
+unsafe impl Send for AzTextAreaEditVec { }
 unsafe impl Send for AzStyleFilterVec { }
 unsafe impl Send for AzInputConnectionVec { }
 unsafe impl Send for AzOutputConnectionVec { }
 unsafe impl Send for AzTessellatedSvgNodeVec { }
 unsafe impl Send for AzStyleTransformVec { }
 unsafe impl Send for AzSvgPathElementVec { }
+unsafe impl Send for AzCssMediaFeatureVec { }
 unsafe impl Send for AzStringVec { }
 unsafe impl Send for AzStyledNodeVec { }
 unsafe impl Send for AzTagIdToNodeIdMappingVec { }
 unsafe impl Send for AzWaylandTheme { }
 unsafe impl Send for AzFile { }
+unsafe impl Send for AzTreeViewNodeVec { }
+unsafe impl Send for AzRibbonItemVec { }
 unsafe impl Send for AzStyleFontFamilyVec { }
 unsafe impl Send for AzFmtArgVec { }
 unsafe impl Send for AzInlineWordVec { }
 unsafe impl Send for AzMonitorVec { }
 unsafe impl Send for AzIdOrClassVec { }
 unsafe impl Send for AzStyleBackgroundContentVec { }
+unsafe impl Send for AzStyleAnimationNameVec { }
 unsafe impl Send for AzSvgPathVec { }
 unsafe impl Send for AzVertexAttributeVec { }
 unsafe impl Send for AzCssPathSelectorVec { }
+unsafe impl Send for AzCssMediaQueryVec { }
 unsafe impl Send for AzCallbackDataVec { }
 unsafe impl Send for AzDebugMessageVec { }
 unsafe impl Send for AzStringPairVec { }
 unsafe impl Send for AzVertexArrayObject { }
 unsafe impl Send for AzVertexBuffer { }
+unsafe impl Send for AzRibbonGroupVec { }
 unsafe impl Send for AzNodeTypeIdInfoMapVec { }
 unsafe impl Send for AzInputOutputTypeIdInfoMapVec { }
 unsafe impl Send for AzNodeTypeFieldVec { }
@@ -10253,12 +12524,17 @@ unsafe impl Send for AzCssPropertyVec { }
 unsafe impl Send for AzSvgMultiPolygonVec { }
 unsafe impl Send for AzCallbackInfo { }
 unsafe impl Send for AzTimerCallbackInfo { }
+unsafe impl Send for AzRibbonTabVec { }
 unsafe impl Send for AzNodeDataInlineCssPropertyVec { }
+unsafe impl Send for AzCssKeyframeVec { }
 unsafe impl Send for AzNodeData { }
 unsafe impl Send for AzNodeIdNodeMapVec { }
 unsafe impl Send for AzCssDeclarationVec { }
+unsafe impl Send for AzCssKeyframesVec { }
 unsafe impl Send for AzNodeDataVec { }
+unsafe impl Send for AzSplitterPaneVec { }
 unsafe impl Send for AzCssRuleBlockVec { }
+unsafe impl Send for AzCssMediaRuleVec { }
 unsafe impl Send for AzStylesheetVec { }
 
 
@@ -10279,6 +12555,8 @@ impl Clone for AzWaylandHandle { fn clone(&self) -> Self { let r: &azul_core::wi
 impl Clone for AzWindowsHandle { fn clone(&self) -> Self { let r: &azul_core::window::WindowsHandle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWebHandle { fn clone(&self) -> Self { let r: &azul_core::window::WebHandle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAndroidHandle { fn clone(&self) -> Self { let r: &azul_core::window::AndroidHandle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzClipboardSelectionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ClipboardSelection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLinuxBackendEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::LinuxBackend = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXWindowTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::XWindowType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPhysicalPositionI32 { fn clone(&self) -> Self { let r: &azul_core::window::PhysicalPositionI32 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPhysicalSizeU32 { fn clone(&self) -> Self { let r: &azul_core::window::PhysicalSizeU32 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10306,6 +12584,7 @@ impl Clone for AzPositionInfoInner { fn clone(&self) -> Self { let r: &azul_impl
 impl Clone for AzAnimationRepeatEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::AnimationRepeat = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationRepeatCountEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::AnimationRepeatCount = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::IFrameCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTooltipCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TooltipCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRenderImageCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::RenderImageCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTimerCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWriteBackCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::WriteBackCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10319,19 +12598,26 @@ impl Clone for AzComponentEventFilterEnumWrapper { fn clone(&self) -> Self { let
 impl Clone for AzApplicationEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::ApplicationEventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityRoleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityRole = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAccessibilityActionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityAction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabIndexEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::TabIndex = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzContextMenuMouseButtonEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::ContextMenuMouseButton = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuPopupPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuPopupPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeKeyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::NodeTypeTag = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssNthChildPattern { fn clone(&self) -> Self { let r: &azul_impl::css::CssNthChildPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaOrientationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaOrientation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssColorSchemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssColorScheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCombinedCssPropertyTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CombinedCssPropertyType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorU { fn clone(&self) -> Self { let r: &azul_impl::css::ColorU = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSizeMetricEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::SizeMetric = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFloatValue { fn clone(&self) -> Self { let r: &azul_impl::css::FloatValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzBoxShadowClipModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::BoxShadowClipMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleMixBlendModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleMixBlendMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridAutoFlowEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridAutoFlow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridLineEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignItemsEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignItems = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBoxSizingEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBoxSizing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexDirectionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexDirection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10350,8 +12636,18 @@ impl Clone for AzStyleBackgroundRepeatEnumWrapper { fn clone(&self) -> Self { le
 impl Clone for AzBorderStyleEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::BorderStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleCursorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCursor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackfaceVisibilityEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibility = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTime { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTime = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleStepPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleStepPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDirectionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDirection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationFillModeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationFillMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationPlayStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationPlayState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlign = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzRibbon { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::Ribbon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleDirectionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleDirection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleWordBreakEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWordBreak = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleOverflowWrapEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOverflowWrap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleHyphensEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleHyphens = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonItemKindEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonItemKind = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonOnItemClickCallback { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonOnItemClickCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzRibbonOnTabClickedCallback { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonOnTabClickedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputOnPathChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputOnPathChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxOnToggleCallback { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxOnToggleCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10362,12 +12658,26 @@ impl Clone for AzTextInputOnTextInputCallback { fn clone(&self) -> Self { let r:
 impl Clone for AzTextInputOnVirtualKeyDownCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnVirtualKeyDownCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnFocusLostCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnFocusLostCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputValidEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputValid = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextAreaEditKindEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_area::TextAreaEditKind = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextAreaOnTextChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::text_area::TextAreaOnTextChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputState { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnValueChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnValueChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnFocusLostCallback { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnFocusLostCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzProgressBarState { fn clone(&self) -> Self { let r: &crate::widgets::progressbar::ProgressBarState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzProgressBarModeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::progressbar::ProgressBarMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzProgressBarStyleEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::progressbar::ProgressBarStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabHeaderState { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabHeaderState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabOnClickCallback { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabOnClickCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabOnCloseCallback { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabOnCloseCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabOnReorderCallback { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabOnReorderCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabOnContextMenuCallback { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabOnContextMenuCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterOrientationEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterOrientation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneState { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterOnResizeCallback { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterOnResizeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderOrientationEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderOrientation = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderState { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRangeSliderState { fn clone(&self) -> Self { let r: &crate::widgets::slider::RangeSliderState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderOnValueChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderOnValueChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRangeSliderOnValueChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::slider::RangeSliderOnValueChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphStyleEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphOnNodeAddedCallback { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeAddedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphOnNodeRemovedCallback { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeRemovedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10386,6 +12696,15 @@ impl Clone for AzNodeDragAmount { fn clone(&self) -> Self { let r: &crate::widge
 impl Clone for AzListViewOnLazyLoadScrollCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnLazyLoadScrollCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnColumnClickCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnClickCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnRowClickCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnRowClickCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewRowRange { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewOnColumnResizeCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnResizeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewRowProviderCallback { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowProviderCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeId { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewSelectionModeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewSelectionMode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnExpandCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnExpandCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnSelectCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnSelectCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnDoubleClickCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnDoubleClickCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnContextMenuCallback { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnContextMenuCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDropDownOnChoiceChangeCallback { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChangeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeHierarchyItem { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::NodeHierarchyItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCascadeInfo { fn clone(&self) -> Self { let r: &azul_impl::style::CascadeInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10455,6 +12774,8 @@ impl Clone for AzThreadSender { fn clone(&self) -> Self { let r: &azul_impl::tas
 impl Clone for AzThreadReceiver { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadReceiver = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCreateThreadFn { fn clone(&self) -> Self { let r: &azul_impl::task::CreateThreadCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGetSystemTimeFn { fn clone(&self) -> Self { let r: &azul_impl::task::GetSystemTimeCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGetClipboardFn { fn clone(&self) -> Self { let r: &azul_impl::task::GetClipboardCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSetClipboardFn { fn clone(&self) -> Self { let r: &azul_impl::task::SetClipboardCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckThreadFinishedFn { fn clone(&self) -> Self { let r: &azul_impl::task::CheckThreadFinishedCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLibrarySendThreadMsgFn { fn clone(&self) -> Self { let r: &azul_impl::task::LibrarySendThreadMsgCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLibraryReceiveThreadMsgFn { fn clone(&self) -> Self { let r: &azul_impl::task::LibraryReceiveThreadMsgCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10465,6 +12786,14 @@ impl Clone for AzThreadReceiverDestructorFn { fn clone(&self) -> Self { let r: &
 impl Clone for AzThreadSenderDestructorFn { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSenderDestructorCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewRowVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeIdVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeIdVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonTabVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonTabVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonGroupVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonGroupVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonItemVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonItemVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneStateVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneStateVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextAreaEditVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_area::TextAreaEditVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLogicalRectVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::LogicalRectVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeIdInfoMapVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeIdInfoMapVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10477,6 +12806,7 @@ impl Clone for AzOutputNodeAndIndexVecDestructorEnumWrapper { fn clone(&self) ->
 impl Clone for AzOutputConnectionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnectionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputNodeAndIndexVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputNodeAndIndexVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityStateVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAccessibilityActionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityActionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNodeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNodeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10495,6 +12825,15 @@ impl Clone for AzStyleBackgroundPositionVecDestructorEnumWrapper { fn clone(&sel
 impl Clone for AzStyleBackgroundRepeatVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundSizeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionPropertyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTimeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTimeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTimingFunctionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTimingFunctionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationNameVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationNameVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationIterationCountVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationIterationCountVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDirectionVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDirectionVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationFillModeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationFillModeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationPlayStateVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationPlayStateVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackSizingVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackSizingVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgMultiPolygonVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgMultiPolygonVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10509,6 +12848,11 @@ impl Clone for AzScanCodeVecDestructorEnumWrapper { fn clone(&self) -> Self { le
 impl Clone for AzCssDeclarationVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelectorVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStylesheetVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StylesheetVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframesVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframesVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframeVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframeVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaRuleVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaRuleVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaQueryVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaQueryVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaFeatureVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaFeatureVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlockVecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlockVecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzF32VecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::F32VecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU16VecDestructorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::U16VecDestructor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10558,9 +12902,11 @@ impl Clone for AzTimerCallbackReturn { fn clone(&self) -> Self { let r: &azul_im
 impl Clone for AzRefAny { fn clone(&self) -> Self { let r: &azul_impl::callbacks::RefAny = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameNode { fn clone(&self) -> Self { let r: &azul_impl::dom::IFrameNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNotEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NotEventFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTooltipDom { fn clone(&self) -> Self { let r: &azul_impl::dom::TooltipDom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuCallback { fn clone(&self) -> Self { let r: &azul_core::window::MenuCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssNthChildSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssNthChildSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaRange { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaRange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPixelValue { fn clone(&self) -> Self { let r: &azul_impl::css::PixelValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPixelValueNoPercent { fn clone(&self) -> Self { let r: &azul_impl::css::PixelValueNoPercent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBoxShadow { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBoxShadow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10568,6 +12914,11 @@ impl Clone for AzStyleBlur { fn clone(&self) -> Self { let r: &azul_impl::css::S
 impl Clone for AzStyleColorMatrix { fn clone(&self) -> Self { let r: &azul_impl::css::StyleColorMatrix = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterOffset { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterOffset = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleCompositeFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCompositeFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackBreadthEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackBreadth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackSizing { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackSizing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridPlacement { fn clone(&self) -> Self { let r: &azul_impl::css::GridPlacement = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutRowGap { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutRowGap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutColumnGap { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutColumnGap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBottom { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBottom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexGrow { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexGrow = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutFlexShrink { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutFlexShrink = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10629,10 +12980,20 @@ impl Clone for AzStyleTransformRotate3D { fn clone(&self) -> Self { let r: &azul
 impl Clone for AzStyleTransformScale2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformScale2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformScale3D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformScale3D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformSkew2D { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformSkew2D = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleCubicBezier { fn clone(&self) -> Self { let r: &azul_impl::css::StyleCubicBezier = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleSteps { fn clone(&self) -> Self { let r: &azul_impl::css::StyleSteps = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTimingFunctionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTimingFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationIterationCountEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationIterationCount = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextColor { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextColor = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleWordSpacing { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWordSpacing = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBoxShadowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBoxShadowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignContentValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignContentValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackSizingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackSizingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutGridAutoFlowValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutGridAutoFlowValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridPlacementValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridPlacementValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutRowGapValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutRowGapValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzLayoutColumnGapValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutColumnGapValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutAlignItemsValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutAlignItemsValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBottomValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBottomValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLayoutBoxSizingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::LayoutBoxSizingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10684,6 +13045,10 @@ impl Clone for AzStyleLetterSpacingValueEnumWrapper { fn clone(&self) -> Self {
 impl Clone for AzStyleLineHeightValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleLineHeightValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTabWidthValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTabWidthValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextAlignValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextAlignValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleDirectionValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleDirectionValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleWordBreakValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWordBreakValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleOverflowWrapValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOverflowWrapValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleHyphensValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleHyphensValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTextColorValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTextColorValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleWordSpacingValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleWordSpacingValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleOpacityValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleOpacityValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10691,6 +13056,9 @@ impl Clone for AzStyleTransformOriginValueEnumWrapper { fn clone(&self) -> Self
 impl Clone for AzStylePerspectiveOriginValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StylePerspectiveOriginValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackfaceVisibilityValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackfaceVisibilityValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleMixBlendModeValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleMixBlendModeValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonItemState { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonItemState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonOnItemClick { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonOnItemClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonOnTabClicked { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonOnTabClicked = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzButtonOnClick { fn clone(&self) -> Self { let r: &crate::widgets::button::ButtonOnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputOnPathChange { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputOnPathChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxOnToggle { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxOnToggle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10701,9 +13069,18 @@ impl Clone for AzTextInputOnTextInput { fn clone(&self) -> Self { let r: &crate:
 impl Clone for AzTextInputOnVirtualKeyDown { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnVirtualKeyDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputOnFocusLost { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOnTextInputReturn { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OnTextInputReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextAreaOnTextChange { fn clone(&self) -> Self { let r: &crate::widgets::text_area::TextAreaOnTextChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnValueChange { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputOnFocusLost { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzProgressBarState { fn clone(&self) -> Self { let r: &crate::widgets::progressbar::ProgressBarState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSpinner { fn clone(&self) -> Self { let r: &crate::widgets::spinner::Spinner = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabOnClick { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabOnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabOnClose { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabOnClose = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabOnReorder { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabOnReorder = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTabOnContextMenu { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabOnContextMenu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterOnResize { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterOnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSliderOnValueChange { fn clone(&self) -> Self { let r: &crate::widgets::slider::SliderOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRangeSliderOnValueChange { fn clone(&self) -> Self { let r: &crate::widgets::slider::RangeSliderOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphOnNodeAdded { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeAdded = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphOnNodeRemoved { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeRemoved = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphOnNodeGraphDragged { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OnNodeGraphDragged = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10717,6 +13094,12 @@ impl Clone for AzInputNodeAndIndex { fn clone(&self) -> Self { let r: &crate::wi
 impl Clone for AzListViewOnLazyLoadScroll { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnLazyLoadScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnColumnClick { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewOnRowClick { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnRowClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewOnColumnResize { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewOnColumnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzListViewRowProvider { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowProvider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnExpand { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnExpand = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnSelect { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnSelect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnDoubleClick { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnDoubleClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewOnContextMenu { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewOnContextMenu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDropDownOnChoiceChange { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzParentWithNodeDepth { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::ParentWithNodeDepth = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzGl { fn clone(&self) -> Self { let r: &azul_impl::gl::GlContextPtr = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10732,11 +13115,14 @@ impl Clone for AzInstantPtr { fn clone(&self) -> Self { let r: &azul_impl::task:
 impl Clone for AzDurationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::Duration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadSendMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadSendMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzThreadWriteBackMsg { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadWriteBackMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeIdVec { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeIdVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneStateVec { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLogicalRectVec { fn clone(&self) -> Self { let r: &azul_core::window::LogicalRectVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputTypeIdVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputTypeIdVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputNodeAndIndexVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputNodeAndIndexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputNodeAndIndexVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputNodeAndIndexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityStateVec { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzAccessibilityActionVec { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityActionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemVec { fn clone(&self) -> Self { let r: &azul_core::window::MenuItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNodeVec { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineGlyphVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineGlyphVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10746,6 +13132,14 @@ impl Clone for AzDomVec { fn clone(&self) -> Self { let r: &azul_impl::dom::DomV
 impl Clone for AzStyleBackgroundPositionVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundPositionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundRepeatVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundSizeVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTimeVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTimeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTimingFunctionVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTimingFunctionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationIterationCountVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationIterationCountVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDirectionVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDirectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationFillModeVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationFillModeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationPlayStateVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationPlayStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackSizingVec { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackSizingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgVertexVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgVertexVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzU32Vec { fn clone(&self) -> Self { let r: &azul_impl::css::U32Vec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXWindowTypeVec { fn clone(&self) -> Self { let r: &azul_core::window::XWindowTypeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10763,8 +13157,15 @@ impl Clone for AzNodeIdVec { fn clone(&self) -> Self { let r: &azul_impl::styled
 impl Clone for AzNodeHierarchyItemVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::NodeHierarchyItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzParentWithNodeDepthVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::ParentWithNodeDepthVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionListViewOnRowClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnRowClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewNodeIdEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewNodeId = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnExpandEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnExpand = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnSelectEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnSelect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnDoubleClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnDoubleClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTreeViewOnContextMenuEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::OptionTreeViewOnContextMenu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionListViewOnColumnClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnColumnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionListViewOnLazyLoadScrollEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnLazyLoadScroll = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionListViewOnColumnResizeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewOnColumnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionListViewRowProviderEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::list_view::OptionListViewRowProvider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPixelValueNoPercentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionPixelValueNoPercent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionDropDownOnChoiceChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::OptionDropDownOnChoiceChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNodeGraphOnNodeAddedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OptionOnNodeAdded = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10778,12 +13179,21 @@ impl Clone for AzOptionNodeGraphOnNodeFieldEditedEnumWrapper { fn clone(&self) -
 impl Clone for AzOptionColorInputOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::color_input::OptionColorInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionButtonOnClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::button::OptionButtonOnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTabOnClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tabs::OptionTabOnClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTabOnCloseEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tabs::OptionTabOnClose = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTabOnReorderEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tabs::OptionTabOnReorder = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTabOnContextMenuEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::tabs::OptionTabOnContextMenu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionRibbonOnTabClickedEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::OptionRibbonOnTabClicked = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionRibbonOnItemClickEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::OptionRibbonOnItemClick = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSplitterOnResizeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::splitter::OptionSplitterOnResize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionSliderOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::OptionSliderOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionRangeSliderOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::slider::OptionRangeSliderOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionFileInputOnPathChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::file_input::OptionFileInputOnPathChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionCheckBoxOnToggleEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::check_box::OptionCheckBoxOnToggle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputOnTextInputEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnTextInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputOnVirtualKeyDownEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnVirtualKeyDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputOnFocusLostEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTextInputSelectionEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::OptionTextInputSelection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionTextAreaOnTextChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_area::OptionTextAreaOnTextChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNumberInputOnFocusLostEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::OptionNumberInputOnFocusLost = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionNumberInputOnValueChangeEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::OptionNumberInputOnValueChange = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMenuItemIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMenuItemIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10810,6 +13220,7 @@ impl Clone for AzOptionDomNodeIdEnumWrapper { fn clone(&self) -> Self { let r: &
 impl Clone for AzOptionColorUEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionColorU = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionSvgDashPatternEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::OptionSvgDashPattern = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLogicalPositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalPosition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionLogicalRectEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalRect = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionPhysicalPositionI32EnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionPhysicalPositionI32 = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseCursorTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseCursorType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionLogicalSizeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionLogicalSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10832,7 +13243,6 @@ impl Clone for AzLargeWindowIconBytes { fn clone(&self) -> Self { let r: &azul_c
 impl Clone for AzWindowIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::WindowIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTaskBarIcon { fn clone(&self) -> Self { let r: &azul_core::window::TaskBarIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowSize { fn clone(&self) -> Self { let r: &azul_core::window::WindowSize = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMouseState { fn clone(&self) -> Self { let r: &azul_core::window::MouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMarshaledLayoutCallback { fn clone(&self) -> Self { let r: &azul_impl::callbacks::MarshaledLayoutCallback = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineTextContents { fn clone(&self) -> Self { let r: &azul_core::callbacks::InlineTextContents = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10844,6 +13254,7 @@ impl Clone for AzEventFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul
 impl Clone for AzMenu { fn clone(&self) -> Self { let r: &azul_core::window::Menu = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVirtualKeyCodeCombo { fn clone(&self) -> Self { let r: &azul_core::window::VirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathPseudoSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathPseudoSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaFeatureEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaFeature = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAnimationInterpolationFunctionEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::AnimationInterpolationFunction = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInterpolateContext { fn clone(&self) -> Self { let r: &azul_impl::css::InterpolateResolver = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10851,11 +13262,21 @@ impl Clone for AzLinearGradient { fn clone(&self) -> Self { let r: &azul_impl::c
 impl Clone for AzRadialGradient { fn clone(&self) -> Self { let r: &azul_impl::css::RadialGradient = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzConicGradient { fn clone(&self) -> Self { let r: &azul_impl::css::ConicGradient = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransform = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzGridTrackSizingVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::GridTrackSizingVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTransitionPropertyVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransitionPropertyVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTimeVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTimeVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleTimingFunctionVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTimingFunctionVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationIterationCountVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationIterationCountVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationDirectionVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationDirectionVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationFillModeVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationFillModeVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationPlayStateVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationPlayStateVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundPositionVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundPositionVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundRepeatVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundRepeatVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundSizeVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundSizeVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBoxStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBoxStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextAreaEdit { fn clone(&self) -> Self { let r: &crate::widgets::text_area::TextAreaEdit = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterState { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraphCallbacks { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraphCallbacks = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputConnection { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputConnection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputConnection { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnection = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10876,12 +13297,14 @@ impl Clone for AzInstantEnumWrapper { fn clone(&self) -> Self { let r: &azul_imp
 impl Clone for AzThreadReceiveMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::ThreadReceiveMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzString { fn clone(&self) -> Self { let r: &azul_impl::css::AzString = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewRowVec { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewRowVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextAreaEditVec { fn clone(&self) -> Self { let r: &crate::widgets::text_area::TextAreaEditVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputConnectionVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputConnectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOutputConnectionVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::OutputConnectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedSvgNodeVec { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedSvgNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathElementVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathElementVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaFeatureVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaFeatureVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringVec { fn clone(&self) -> Self { let r: &azul_impl::css::StringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledNodeVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTagIdToNodeIdMappingVec { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::TagIdToNodeIdMappingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10889,7 +13312,6 @@ impl Clone for AzOptionMenuEnumWrapper { fn clone(&self) -> Self { let r: &azul_
 impl Clone for AzOptionResolvedTextLayoutOptionsEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::ui_solver::OptionResolvedTextLayoutOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionVirtualKeyCodeComboEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionVirtualKeyCodeCombo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionMouseStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionMouseState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionStringVecEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionStringVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionThreadReceiveMsgEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::task::OptionThreadReceiveMsg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionTaskBarIconEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionTaskBarIcon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10903,6 +13325,8 @@ impl Clone for AzUnexpectedCloseTagError { fn clone(&self) -> Self { let r: &azu
 impl Clone for AzUnknownEntityReferenceError { fn clone(&self) -> Self { let r: &azul_impl::xml::UnknownEntityReferenceError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDuplicatedAttributeError { fn clone(&self) -> Self { let r: &azul_impl::xml::DuplicatedAttributeError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInvalidStringError { fn clone(&self) -> Self { let r: &azul_impl::xml::InvalidStringError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzClipboardContent { fn clone(&self) -> Self { let r: &azul_core::window::ClipboardContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzImeComposition { fn clone(&self) -> Self { let r: &azul_core::window::ImeComposition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowsWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::WindowsWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWaylandTheme { fn clone(&self) -> Self { let r: &azul_core::window::WaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringPair { fn clone(&self) -> Self { let r: &azul_core::window::AzStringPair = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10912,26 +13336,32 @@ impl Clone for AzInlineWordEnumWrapper { fn clone(&self) -> Self { let r: &azul_
 impl Clone for AzCallbackData { fn clone(&self) -> Self { let r: &azul_impl::dom::CallbackData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeType = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzAccessibilityInfo { fn clone(&self) -> Self { let r: &azul_impl::dom::AccessibilityInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTooltipContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::TooltipContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIdOrClassEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::IdOrClass = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringMenuItem { fn clone(&self) -> Self { let r: &azul_core::window::StringMenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelector = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaQuery { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaQuery = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarInfo { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyle { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyle = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamily = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationNameEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationName = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzScrollbarStyleValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::ScrollbarStyleValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleTransformVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleTransformVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFilterVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFilterVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonItem { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputState { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzColorInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputState { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextAreaState { fn clone(&self) -> Self { let r: &crate::widgets::text_area::TextAreaState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabHeader { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabHeader = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeFieldValueEnumWrapper { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeFieldValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeInfo { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputInfo { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListView { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzListViewState { fn clone(&self) -> Self { let r: &crate::widgets::list_view::ListViewState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzTreeView { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNode { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewState { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDropDown { fn clone(&self) -> Self { let r: &crate::widgets::drop_down::DropDown = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttribute { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttribute = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugMessage { fn clone(&self) -> Self { let r: &azul_impl::gl::AzDebugMessage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10946,15 +13376,19 @@ impl Clone for AzFileTypeList { fn clone(&self) -> Self { let r: &azul_impl::dia
 impl Clone for AzTimer { fn clone(&self) -> Self { let r: &azul_impl::task::Timer = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFmtValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::str::FmtValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFmtArg { fn clone(&self) -> Self { let r: &azul_impl::str::FmtArg = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeViewNodeVec { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeViewNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonItemVec { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFmtArgVec { fn clone(&self) -> Self { let r: &azul_impl::str::FmtArgVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineWordVec { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineWordVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMonitorVec { fn clone(&self) -> Self { let r: &azul_core::window::MonitorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIdOrClassVec { fn clone(&self) -> Self { let r: &azul_impl::dom::IdOrClassVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationNameVec { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationNameVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgPathVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgPathVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexAttributeVec { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexAttributeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPathSelectorVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPathSelectorVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaQueryVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaQueryVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCallbackDataVec { fn clone(&self) -> Self { let r: &azul_impl::dom::CallbackDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDebugMessageVec { fn clone(&self) -> Self { let r: &azul_impl::gl::AzDebugMessageVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStringPairVec { fn clone(&self) -> Self { let r: &azul_core::window::StringPairVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10962,21 +13396,29 @@ impl Clone for AzOptionFileTypeListEnumWrapper { fn clone(&self) -> Self { let r
 impl Clone for AzOptionFileEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::file::OptionFile = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionRawImageEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::OptionRawImage = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWaylandThemeEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWaylandTheme = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionImeCompositionEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionImeComposition = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionClipboardContentEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionClipboardContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultRawImageDecodeImageErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::resources::decode::ResultRawImageDecodeImageError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlStreamErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlStreamError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzKeyboardState { fn clone(&self) -> Self { let r: &azul_core::window::KeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzLinuxWindowOptions { fn clone(&self) -> Self { let r: &azul_core::window::LinuxWindowOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInlineLine { fn clone(&self) -> Self { let r: &azul_impl::callbacks::InlineLine = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTooltip { fn clone(&self) -> Self { let r: &azul_impl::dom::Tooltip = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzMenuItemEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::MenuItem = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPath { fn clone(&self) -> Self { let r: &azul_impl::css::CssPath = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStyleAnimationNameVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleAnimationNameVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleBackgroundContentVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleBackgroundContentVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyleFontFamilyVecValueEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::StyleFontFamilyVecValue = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonGroup { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonGroup = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInputStateWrapper { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInputStateWrapper = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTextArea { fn clone(&self) -> Self { let r: &crate::widgets::text_area::TextArea = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzProgressBar { fn clone(&self) -> Self { let r: &crate::widgets::progressbar::ProgressBar = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeIdInfoMap { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeIdInfoMap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputTypeIdInfoMap { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputTypeIdInfoMap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeField { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeField = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzTreeView { fn clone(&self) -> Self { let r: &crate::widgets::tree_view::TreeView = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssPropertySourceEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::CssPropertySource = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexLayout { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexLayout = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzVertexArrayObject { fn clone(&self) -> Self { let r: &azul_impl::gl::VertexArrayObject = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10984,6 +13426,7 @@ impl Clone for AzVertexBuffer { fn clone(&self) -> Self { let r: &azul_impl::gl:
 impl Clone for AzSvgMultiPolygon { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgMultiPolygon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTessellatedGPUSvgNode { fn clone(&self) -> Self { let r: &azul_impl::svg::TessellatedGPUSvgNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlNode { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonGroupVec { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonGroupVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeIdInfoMapVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeIdInfoMapVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzInputOutputTypeIdInfoMapVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::InputOutputTypeIdInfoMapVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeTypeFieldVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeTypeFieldVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -10991,6 +13434,7 @@ impl Clone for AzInlineLineVec { fn clone(&self) -> Self { let r: &azul_impl::ca
 impl Clone for AzCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgMultiPolygonVec { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgMultiPolygonVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::OptionCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzOptionKeyboardStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionKeyboardState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlTextError { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlTextError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzPlatformSpecificOptions { fn clone(&self) -> Self { let r: &azul_core::window::PlatformSpecificOptions = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzWindowState { fn clone(&self) -> Self { let r: &azul_core::window::WindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11001,10 +13445,14 @@ impl Clone for AzAnimation { fn clone(&self) -> Self { let r: &azul_impl::callba
 impl Clone for AzTimerCallbackInfo { fn clone(&self) -> Self { let r: &azul_impl::callbacks::TimerCallbackInfo = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDynamicCssProperty { fn clone(&self) -> Self { let r: &azul_impl::css::DynamicCssProperty = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframe { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframe = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonTab { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonTab = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNode { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::Node = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgNodeEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgStyledNode { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgStyledNode = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbonTabVec { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::RibbonTabVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataInlineCssPropertyVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataInlineCssPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframeVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionWindowStateEnumWrapper { fn clone(&self) -> Self { let r: &azul_core::window::OptionWindowState = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionInlineTextEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::OptionInlineText = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11012,6 +13460,8 @@ impl Clone for AzWindowCreateOptions { fn clone(&self) -> Self { let r: &azul_co
 impl Clone for AzFocusTargetEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::callbacks::FocusTarget = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeData { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeData = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclaration = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframes { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframes = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRibbon { fn clone(&self) -> Self { let r: &crate::widgets::ribbon::Ribbon = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzButton { fn clone(&self) -> Self { let r: &crate::widgets::button::Button = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFileInput { fn clone(&self) -> Self { let r: &crate::widgets::file_input::FileInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCheckBox { fn clone(&self) -> Self { let r: &crate::widgets::check_box::CheckBox = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
@@ -11019,26 +13469,34 @@ impl Clone for AzLabel { fn clone(&self) -> Self { let r: &crate::widgets::label
 impl Clone for AzColorInput { fn clone(&self) -> Self { let r: &crate::widgets::color_input::ColorInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTextInput { fn clone(&self) -> Self { let r: &crate::widgets::text_input::TextInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNumberInput { fn clone(&self) -> Self { let r: &crate::widgets::number_input::NumberInput = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSlider { fn clone(&self) -> Self { let r: &crate::widgets::slider::Slider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzRangeSlider { fn clone(&self) -> Self { let r: &crate::widgets::slider::RangeSlider = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeIdNodeMap { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeIdNodeMap = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeIdNodeMapVec { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeIdNodeMapVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssDeclarationVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssDeclarationVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssKeyframesVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssKeyframesVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeDataVec { fn clone(&self) -> Self { let r: &azul_impl::dom::NodeDataVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzXmlErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::XmlError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzDom { fn clone(&self) -> Self { let r: &azul_impl::dom::Dom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlock { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlock = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzTabContent { fn clone(&self) -> Self { let r: &crate::widgets::tabs::TabContent = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzFrame { fn clone(&self) -> Self { let r: &crate::widgets::frame::Frame = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPane { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPane = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzNodeGraph { fn clone(&self) -> Self { let r: &crate::widgets::node_graph::NodeGraph = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzStyledDom { fn clone(&self) -> Self { let r: &azul_impl::styled_dom::StyledDom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitterPaneVec { fn clone(&self) -> Self { let r: &crate::widgets::splitter::SplitterPaneVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCssRuleBlockVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssRuleBlockVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzOptionDomEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::dom::OptionDom = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultXmlXmlErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::xml::ResultXmlXmlError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzSvgParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::SvgParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzIFrameCallbackReturn { fn clone(&self) -> Self { let r: &azul_impl::callbacks::IFrameCallbackReturn = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzStylesheet { fn clone(&self) -> Self { let r: &azul_impl::css::Stylesheet = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
-impl Clone for AzStylesheetVec { fn clone(&self) -> Self { let r: &azul_impl::css::StylesheetVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaRule { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaRule = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzSplitter { fn clone(&self) -> Self { let r: &crate::widgets::splitter::Splitter = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzCssMediaRuleVec { fn clone(&self) -> Self { let r: &azul_impl::css::CssMediaRuleVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultSvgXmlNodeSvgParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::ResultSvgXmlNodeSvgParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzResultSvgSvgParseErrorEnumWrapper { fn clone(&self) -> Self { let r: &azul_impl::svg::ResultSvgSvgParseError = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStylesheet { fn clone(&self) -> Self { let r: &azul_impl::css::Stylesheet = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
+impl Clone for AzStylesheetVec { fn clone(&self) -> Self { let r: &azul_impl::css::StylesheetVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 impl Clone for AzCss { fn clone(&self) -> Self { let r: &azul_impl::css::Css = unsafe { mem::transmute(self) }; unsafe { mem::transmute(r.clone()) } } }
 
 // Implement Drop for all objects with drop constructors
@@ -11058,11 +13516,14 @@ impl Drop for AzThreadReceiver { fn drop(&mut self) { crate::AzThreadReceiver_de
 impl Drop for AzRefAny { fn drop(&mut self) { crate::AzRefAny_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzGl { fn drop(&mut self) { crate::AzGl_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInstantPtr { fn drop(&mut self) { crate::AzInstantPtr_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTreeViewNodeIdVec { fn drop(&mut self) { crate::AzTreeViewNodeIdVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzSplitterPaneStateVec { fn drop(&mut self) { crate::AzSplitterPaneStateVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzLogicalRectVec { fn drop(&mut self) { crate::AzLogicalRectVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInputOutputTypeIdVec { fn drop(&mut self) { crate::AzInputOutputTypeIdVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzOutputNodeAndIndexVec { fn drop(&mut self) { crate::AzOutputNodeAndIndexVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInputNodeAndIndexVec { fn drop(&mut self) { crate::AzInputNodeAndIndexVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzAccessibilityStateVec { fn drop(&mut self) { crate::AzAccessibilityStateVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzAccessibilityActionVec { fn drop(&mut self) { crate::AzAccessibilityActionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzMenuItemVec { fn drop(&mut self) { crate::AzMenuItemVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzXmlNodeVec { fn drop(&mut self) { crate::AzXmlNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineGlyphVec { fn drop(&mut self) { crate::AzInlineGlyphVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11072,6 +13533,14 @@ impl Drop for AzDomVec { fn drop(&mut self) { crate::AzDomVec_delete(unsafe { me
 impl Drop for AzStyleBackgroundPositionVec { fn drop(&mut self) { crate::AzStyleBackgroundPositionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleBackgroundRepeatVec { fn drop(&mut self) { crate::AzStyleBackgroundRepeatVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleBackgroundSizeVec { fn drop(&mut self) { crate::AzStyleBackgroundSizeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleTransitionPropertyVec { fn drop(&mut self) { crate::AzStyleTransitionPropertyVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleTimeVec { fn drop(&mut self) { crate::AzStyleTimeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleTimingFunctionVec { fn drop(&mut self) { crate::AzStyleTimingFunctionVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationIterationCountVec { fn drop(&mut self) { crate::AzStyleAnimationIterationCountVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationDirectionVec { fn drop(&mut self) { crate::AzStyleAnimationDirectionVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationFillModeVec { fn drop(&mut self) { crate::AzStyleAnimationFillModeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationPlayStateVec { fn drop(&mut self) { crate::AzStyleAnimationPlayStateVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzGridTrackSizingVec { fn drop(&mut self) { crate::AzGridTrackSizingVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgVertexVec { fn drop(&mut self) { crate::AzSvgVertexVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzU32Vec { fn drop(&mut self) { crate::AzU32Vec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzXWindowTypeVec { fn drop(&mut self) { crate::AzXWindowTypeVec_delete(unsafe { mem::transmute(self) }); } }
@@ -11090,41 +13559,53 @@ impl Drop for AzNodeHierarchyItemVec { fn drop(&mut self) { crate::AzNodeHierarc
 impl Drop for AzParentWithNodeDepthVec { fn drop(&mut self) { crate::AzParentWithNodeDepthVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTexture { fn drop(&mut self) { crate::AzTexture_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzListViewRowVec { fn drop(&mut self) { crate::AzListViewRowVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTextAreaEditVec { fn drop(&mut self) { crate::AzTextAreaEditVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleFilterVec { fn drop(&mut self) { crate::AzStyleFilterVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInputConnectionVec { fn drop(&mut self) { crate::AzInputConnectionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzOutputConnectionVec { fn drop(&mut self) { crate::AzOutputConnectionVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTessellatedSvgNodeVec { fn drop(&mut self) { crate::AzTessellatedSvgNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleTransformVec { fn drop(&mut self) { crate::AzStyleTransformVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgPathElementVec { fn drop(&mut self) { crate::AzSvgPathElementVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssMediaFeatureVec { fn drop(&mut self) { crate::AzCssMediaFeatureVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStringVec { fn drop(&mut self) { crate::AzStringVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyledNodeVec { fn drop(&mut self) { crate::AzStyledNodeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzTagIdToNodeIdMappingVec { fn drop(&mut self) { crate::AzTagIdToNodeIdMappingVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzFile { fn drop(&mut self) { crate::AzFile_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzTreeViewNodeVec { fn drop(&mut self) { crate::AzTreeViewNodeVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzRibbonItemVec { fn drop(&mut self) { crate::AzRibbonItemVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleFontFamilyVec { fn drop(&mut self) { crate::AzStyleFontFamilyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzFmtArgVec { fn drop(&mut self) { crate::AzFmtArgVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineWordVec { fn drop(&mut self) { crate::AzInlineWordVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzMonitorVec { fn drop(&mut self) { crate::AzMonitorVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzIdOrClassVec { fn drop(&mut self) { crate::AzIdOrClassVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStyleBackgroundContentVec { fn drop(&mut self) { crate::AzStyleBackgroundContentVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzStyleAnimationNameVec { fn drop(&mut self) { crate::AzStyleAnimationNameVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgPathVec { fn drop(&mut self) { crate::AzSvgPathVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzVertexAttributeVec { fn drop(&mut self) { crate::AzVertexAttributeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssPathSelectorVec { fn drop(&mut self) { crate::AzCssPathSelectorVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssMediaQueryVec { fn drop(&mut self) { crate::AzCssMediaQueryVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCallbackDataVec { fn drop(&mut self) { crate::AzCallbackDataVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzDebugMessageVec { fn drop(&mut self) { crate::AzDebugMessageVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStringPairVec { fn drop(&mut self) { crate::AzStringPairVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzVertexArrayObject { fn drop(&mut self) { crate::AzVertexArrayObject_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzVertexBuffer { fn drop(&mut self) { crate::AzVertexBuffer_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzRibbonGroupVec { fn drop(&mut self) { crate::AzRibbonGroupVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeTypeIdInfoMapVec { fn drop(&mut self) { crate::AzNodeTypeIdInfoMapVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInputOutputTypeIdInfoMapVec { fn drop(&mut self) { crate::AzInputOutputTypeIdInfoMapVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeTypeFieldVec { fn drop(&mut self) { crate::AzNodeTypeFieldVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzInlineLineVec { fn drop(&mut self) { crate::AzInlineLineVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssPropertyVec { fn drop(&mut self) { crate::AzCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzSvgMultiPolygonVec { fn drop(&mut self) { crate::AzSvgMultiPolygonVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzRibbonTabVec { fn drop(&mut self) { crate::AzRibbonTabVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataInlineCssPropertyVec { fn drop(&mut self) { crate::AzNodeDataInlineCssPropertyVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssKeyframeVec { fn drop(&mut self) { crate::AzCssKeyframeVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeIdNodeMapVec { fn drop(&mut self) { crate::AzNodeIdNodeMapVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssDeclarationVec { fn drop(&mut self) { crate::AzCssDeclarationVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssKeyframesVec { fn drop(&mut self) { crate::AzCssKeyframesVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzNodeDataVec { fn drop(&mut self) { crate::AzNodeDataVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzSplitterPaneVec { fn drop(&mut self) { crate::AzSplitterPaneVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzCssRuleBlockVec { fn drop(&mut self) { crate::AzCssRuleBlockVec_delete(unsafe { mem::transmute(self) }); } }
+impl Drop for AzCssMediaRuleVec { fn drop(&mut self) { crate::AzCssMediaRuleVec_delete(unsafe { mem::transmute(self) }); } }
 impl Drop for AzStylesheetVec { fn drop(&mut self) { crate::AzStylesheetVec_delete(unsafe { mem::transmute(self) }); } }
 
 
@@ -11650,6 +14131,118 @@ impl PyObjectProtocol for AzAndroidHandle {
     }
 }
 
+#[pymethods]
+impl AzClipboardSelectionEnumWrapper {
+    #[classattr]
+    fn Clipboard() -> AzClipboardSelectionEnumWrapper { AzClipboardSelectionEnumWrapper { inner: AzClipboardSelection::Clipboard } }
+    #[classattr]
+    fn Primary() -> AzClipboardSelectionEnumWrapper { AzClipboardSelectionEnumWrapper { inner: AzClipboardSelection::Primary } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzClipboardSelectionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ClipboardSelection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ClipboardSelection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzClipboardSelectionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzClipboardContent {
+    #[staticmethod]
+    fn text(text: String) -> AzClipboardContent {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzClipboardContent_text(
+            mem::transmute(text),
+        )) }
+    }
+    #[staticmethod]
+    fn uri_list(uris: AzStringVec) -> AzClipboardContent {
+        unsafe { mem::transmute(crate::AzClipboardContent_uriList(
+            mem::transmute(uris),
+        )) }
+    }
+    #[staticmethod]
+    fn png(data: Vec<u8>) -> AzClipboardContent {
+        let data = pyvecu8_to_vecu8(&data);
+        unsafe { mem::transmute(crate::AzClipboardContent_png(
+            mem::transmute(data),
+        )) }
+    }
+    fn is_text(&self) -> bool {
+        unsafe { mem::transmute(crate::AzClipboardContent_isText(
+            mem::transmute(self),
+        )) }
+    }
+    fn get_text(&self) -> Option<String> {
+        let m: AzOptionString = unsafe { mem::transmute(crate::AzClipboardContent_getText(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionString::Some(s) => Some({ let s: AzString = unsafe { mem::transmute(s) }; s.into() }),
+            AzOptionString::None => None,
+        }
+
+    }
+    fn get_uri_list(&self) -> AzStringVec {
+        unsafe { mem::transmute(crate::AzClipboardContent_getUriList(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzClipboardContent {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ClipboardContent = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ClipboardContent = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLinuxBackendEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLinuxBackendEnumWrapper { AzLinuxBackendEnumWrapper { inner: AzLinuxBackend::Auto } }
+    #[classattr]
+    fn X11() -> AzLinuxBackendEnumWrapper { AzLinuxBackendEnumWrapper { inner: AzLinuxBackend::X11 } }
+    #[classattr]
+    fn Wayland() -> AzLinuxBackendEnumWrapper { AzLinuxBackendEnumWrapper { inner: AzLinuxBackend::Wayland } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLinuxBackendEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::LinuxBackend = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::LinuxBackend = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzLinuxBackendEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzXWindowTypeEnumWrapper {
     #[classattr]
@@ -12461,12 +15054,13 @@ impl PyObjectProtocol for AzDebugState {
 #[pymethods]
 impl AzKeyboardState {
     #[new]
-    fn __new__(current_char: AzOptionCharEnumWrapper, current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper, pressed_virtual_keycodes: AzVirtualKeyCodeVec, pressed_scancodes: AzScanCodeVec) -> Self {
+    fn __new__(current_char: AzOptionCharEnumWrapper, current_virtual_keycode: AzOptionVirtualKeyCodeEnumWrapper, pressed_virtual_keycodes: AzVirtualKeyCodeVec, pressed_scancodes: AzScanCodeVec, ime_composition: AzOptionImeCompositionEnumWrapper) -> Self {
         Self {
             current_char,
             current_virtual_keycode,
             pressed_virtual_keycodes,
             pressed_scancodes,
+            ime_composition,
         }
     }
 
@@ -12508,6 +15102,28 @@ impl PyObjectProtocol for AzKeyboardState {
     }
 }
 
+#[pymethods]
+impl AzImeComposition {
+    #[new]
+    fn __new__(text: AzString, cursor: usize) -> Self {
+        Self {
+            text,
+            cursor,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzImeComposition {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImeComposition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::ImeComposition = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMouseCursorTypeEnumWrapper {
     #[classattr]
@@ -12778,8 +15394,9 @@ impl PyObjectProtocol for AzStringPair {
 #[pymethods]
 impl AzLinuxWindowOptions {
     #[new]
-    fn __new__(x11_visual: AzOptionX11VisualEnumWrapper, x11_screen: AzOptionI32EnumWrapper, x11_wm_classes: AzStringPairVec, x11_override_redirect: bool, x11_window_types: AzXWindowTypeVec, x11_gtk_theme_variant: AzOptionStringEnumWrapper, x11_resize_increments: AzOptionLogicalSizeEnumWrapper, x11_base_size: AzOptionLogicalSizeEnumWrapper, wayland_app_id: AzOptionStringEnumWrapper, wayland_theme: AzOptionWaylandThemeEnumWrapper, request_user_attention: bool, window_icon: AzOptionWindowIconEnumWrapper) -> Self {
+    fn __new__(backend: AzLinuxBackendEnumWrapper, x11_visual: AzOptionX11VisualEnumWrapper, x11_screen: AzOptionI32EnumWrapper, x11_wm_classes: AzStringPairVec, x11_override_redirect: bool, x11_window_types: AzXWindowTypeVec, x11_gtk_theme_variant: AzOptionStringEnumWrapper, x11_resize_increments: AzOptionLogicalSizeEnumWrapper, x11_base_size: AzOptionLogicalSizeEnumWrapper, wayland_app_id: AzOptionStringEnumWrapper, wayland_theme: AzOptionWaylandThemeEnumWrapper, request_user_attention: bool, window_icon: AzOptionWindowIconEnumWrapper) -> Self {
         Self {
+            backend,
             x11_visual,
             x11_screen,
             x11_wm_classes,
@@ -13257,6 +15874,46 @@ impl AzCallbackInfo {
             AzOptionMouseState::None => None,
         }
 
+    }
+    fn get_hovered_file(&self) -> Option<String> {
+        let m: AzOptionString = unsafe { mem::transmute(crate::AzCallbackInfo_getHoveredFile(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionString::Some(s) => Some({ let s: AzString = unsafe { mem::transmute(s) }; s.into() }),
+            AzOptionString::None => None,
+        }
+
+    }
+    fn get_dropped_file(&self) -> Option<String> {
+        let m: AzOptionString = unsafe { mem::transmute(crate::AzCallbackInfo_getDroppedFile(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionString::Some(s) => Some({ let s: AzString = unsafe { mem::transmute(s) }; s.into() }),
+            AzOptionString::None => None,
+        }
+
+    }
+    fn get_hovered_text(&self) -> Option<String> {
+        let m: AzOptionString = unsafe { mem::transmute(crate::AzCallbackInfo_getHoveredText(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionString::Some(s) => Some({ let s: AzString = unsafe { mem::transmute(s) }; s.into() }),
+            AzOptionString::None => None,
+        }
+
+    }
+    fn get_dropped_text(&self) -> Option<String> {
+        let m: AzOptionString = unsafe { mem::transmute(crate::AzCallbackInfo_getDroppedText(
+            mem::transmute(self),
+        )) };
+        match m {
+            AzOptionString::Some(s) => Some({ let s: AzString = unsafe { mem::transmute(s) }; s.into() }),
+            AzOptionString::None => None,
+        }
+
     }
     fn get_current_window_handle(&self) -> AzRawWindowHandleEnumWrapper {
         unsafe { mem::transmute(crate::AzCallbackInfo_getCurrentWindowHandle(
@@ -13541,6 +16198,37 @@ impl AzCallbackInfo {
             mem::transmute(new_window),
         )) }
     }
+    fn get_clipboard_content(&self, selection: AzClipboardSelectionEnumWrapper, mime_type: String) -> Option<AzClipboardContent> {
+        let mime_type = pystring_to_azstring(&mime_type);
+        let m: AzOptionClipboardContent = unsafe { mem::transmute(crate::AzCallbackInfo_getClipboardContent(
+            mem::transmute(self),
+            mem::transmute(selection),
+            mem::transmute(mime_type),
+        )) };
+        match m {
+            AzOptionClipboardContent::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionClipboardContent::None => None,
+        }
+
+    }
+    fn get_clipboard_text(&self, selection: AzClipboardSelectionEnumWrapper) -> Option<String> {
+        let m: AzOptionString = unsafe { mem::transmute(crate::AzCallbackInfo_getClipboardText(
+            mem::transmute(self),
+            mem::transmute(selection),
+        )) };
+        match m {
+            AzOptionString::Some(s) => Some({ let s: AzString = unsafe { mem::transmute(s) }; s.into() }),
+            AzOptionString::None => None,
+        }
+
+    }
+    fn set_clipboard_content(&mut self, selection: AzClipboardSelectionEnumWrapper, content: AzClipboardContent) -> bool {
+        unsafe { mem::transmute(crate::AzCallbackInfo_setClipboardContent(
+            mem::transmute(self),
+            mem::transmute(selection),
+            mem::transmute(content),
+        )) }
+    }
     fn start_timer(&mut self, timer: AzTimer) -> AzTimerId {
         unsafe { mem::transmute(crate::AzCallbackInfo_startTimer(
             mem::transmute(self),
@@ -13827,6 +16515,24 @@ impl AzInlineText {
             mem::transmute(position),
         )) }
     }
+    fn get_cursor_rect(&self, char_index: usize) -> Option<AzLogicalRect> {
+        let m: AzOptionLogicalRect = unsafe { mem::transmute(crate::AzInlineText_getCursorRect(
+            mem::transmute(self),
+            mem::transmute(char_index),
+        )) };
+        match m {
+            AzOptionLogicalRect::Some(s) => Some(unsafe { mem::transmute(s) }),
+            AzOptionLogicalRect::None => None,
+        }
+
+    }
+    fn get_selection_rects(&self, start: usize, end: usize) -> AzLogicalRectVec {
+        unsafe { mem::transmute(crate::AzInlineText_getSelectionRects(
+            mem::transmute(self),
+            mem::transmute(start),
+            mem::transmute(end),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -13899,10 +16605,11 @@ impl PyObjectProtocol for AzInlineWordEnumWrapper {
 #[pymethods]
 impl AzInlineTextContents {
     #[new]
-    fn __new__(glyphs: AzInlineGlyphVec, bounds: AzLogicalRect) -> Self {
+    fn __new__(glyphs: AzInlineGlyphVec, bounds: AzLogicalRect, is_rtl: bool) -> Self {
         Self {
             glyphs,
             bounds,
+            is_rtl,
         }
     }
 
@@ -13921,11 +16628,12 @@ impl PyObjectProtocol for AzInlineTextContents {
 #[pymethods]
 impl AzInlineGlyph {
     #[new]
-    fn __new__(bounds: AzLogicalRect, unicode_codepoint: AzOptionCharEnumWrapper, glyph_index: u32) -> Self {
+    fn __new__(bounds: AzLogicalRect, unicode_codepoint: AzOptionCharEnumWrapper, glyph_index: u32, font_index: usize) -> Self {
         Self {
             bounds,
             unicode_codepoint,
             glyph_index,
+            font_index,
         }
     }
 
@@ -13944,7 +16652,7 @@ impl PyObjectProtocol for AzInlineGlyph {
 #[pymethods]
 impl AzInlineTextHit {
     #[new]
-    fn __new__(unicode_codepoint: AzOptionCharEnumWrapper, hit_relative_to_inline_text: AzLogicalPosition, hit_relative_to_line: AzLogicalPosition, hit_relative_to_text_content: AzLogicalPosition, hit_relative_to_glyph: AzLogicalPosition, line_index_relative_to_text: usize, word_index_relative_to_text: usize, text_content_index_relative_to_text: usize, glyph_index_relative_to_text: usize, char_index_relative_to_text: usize, word_index_relative_to_line: usize, text_content_index_relative_to_line: usize, glyph_index_relative_to_line: usize, char_index_relative_to_line: usize, glyph_index_relative_to_word: usize, char_index_relative_to_word: usize) -> Self {
+    fn __new__(unicode_codepoint: AzOptionCharEnumWrapper, hit_relative_to_inline_text: AzLogicalPosition, hit_relative_to_line: AzLogicalPosition, hit_relative_to_text_content: AzLogicalPosition, hit_relative_to_glyph: AzLogicalPosition, line_index_relative_to_text: usize, word_index_relative_to_text: usize, text_content_index_relative_to_text: usize, glyph_index_relative_to_text: usize, char_index_relative_to_text: usize, word_index_relative_to_line: usize, text_content_index_relative_to_line: usize, glyph_index_relative_to_line: usize, char_index_relative_to_line: usize, glyph_index_relative_to_word: usize, char_index_relative_to_word: usize, is_rtl: bool, cursor_index_relative_to_text: usize) -> Self {
         Self {
             unicode_codepoint,
             hit_relative_to_inline_text,
@@ -13962,6 +16670,8 @@ impl AzInlineTextHit {
             char_index_relative_to_line,
             glyph_index_relative_to_word,
             char_index_relative_to_word,
+            is_rtl,
+            cursor_index_relative_to_text,
         }
     }
 
@@ -14216,6 +16926,26 @@ impl PyObjectProtocol for AzIFrameCallbackInfo {
     }
 }
 
+#[pymethods]
+impl AzTooltipCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTooltipCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TooltipCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::callbacks::TooltipCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzIFrameCallbackReturn {
     #[new]
@@ -14821,6 +17551,18 @@ impl AzDom {
             mem::transmute(accessibility_info),
         )) }
     }
+    fn set_accessibility_actions(&mut self, actions: AzAccessibilityActionVec) -> () {
+        unsafe { mem::transmute(crate::AzDom_setAccessibilityActions(
+            mem::transmute(self),
+            mem::transmute(actions),
+        )) }
+    }
+    fn with_accessibility_actions(&mut self, actions: AzAccessibilityActionVec) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withAccessibilityActions(
+            mem::transmute(self),
+            mem::transmute(actions),
+        )) }
+    }
     fn set_menu_bar(&mut self, menu_bar: AzMenu) -> () {
         unsafe { mem::transmute(crate::AzDom_setMenuBar(
             mem::transmute(self),
@@ -14845,6 +17587,18 @@ impl AzDom {
             mem::transmute(context_menu),
         )) }
     }
+    fn set_tooltip(&mut self, tooltip: AzTooltip) -> () {
+        unsafe { mem::transmute(crate::AzDom_setTooltip(
+            mem::transmute(self),
+            mem::transmute(tooltip),
+        )) }
+    }
+    fn with_tooltip(&mut self, tooltip: AzTooltip) -> AzDom {
+        unsafe { mem::transmute(crate::AzDom_withTooltip(
+            mem::transmute(self),
+            mem::transmute(tooltip),
+        )) }
+    }
     fn hash(&self) -> u64 {
         unsafe { mem::transmute(crate::AzDom_hash(
             mem::transmute(self),
@@ -15165,6 +17919,24 @@ impl AzNodeData {
             mem::transmute(accessibility_info),
         )) }
     }
+    fn set_accessibility_actions(&mut self, actions: AzAccessibilityActionVec) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setAccessibilityActions(
+            mem::transmute(self),
+            mem::transmute(actions),
+        )) }
+    }
+    fn add_accessibility_action(&mut self, action: AzAccessibilityActionEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_addAccessibilityAction(
+            mem::transmute(self),
+            mem::transmute(action),
+        )) }
+    }
+    fn with_accessibility_actions(&mut self, actions: AzAccessibilityActionVec) -> AzNodeData {
+        unsafe { mem::transmute(crate::AzNodeData_withAccessibilityActions(
+            mem::transmute(self),
+            mem::transmute(actions),
+        )) }
+    }
     fn set_menu_bar(&mut self, menu_bar: AzMenu) -> () {
         unsafe { mem::transmute(crate::AzNodeData_setMenuBar(
             mem::transmute(self),
@@ -15177,6 +17949,12 @@ impl AzNodeData {
             mem::transmute(context_menu),
         )) }
     }
+    fn set_tooltip(&mut self, tooltip: AzTooltip) -> () {
+        unsafe { mem::transmute(crate::AzNodeData_setTooltip(
+            mem::transmute(self),
+            mem::transmute(tooltip),
+        )) }
+    }
     fn hash(&self) -> u64 {
         unsafe { mem::transmute(crate::AzNodeData_hash(
             mem::transmute(self),
@@ -15349,6 +18127,12 @@ impl AzOnEnumWrapper {
     #[classattr]
     fn VirtualKeyUp() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::VirtualKeyUp } }
     #[classattr]
+    fn CompositionStart() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::CompositionStart } }
+    #[classattr]
+    fn CompositionUpdate() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::CompositionUpdate } }
+    #[classattr]
+    fn CompositionEnd() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::CompositionEnd } }
+    #[classattr]
     fn HoveredFile() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::HoveredFile } }
     #[classattr]
     fn DroppedFile() -> AzOnEnumWrapper { AzOnEnumWrapper { inner: AzOn::DroppedFile } }
@@ -15458,6 +18242,12 @@ impl AzHoverEventFilterEnumWrapper {
     #[classattr]
     fn VirtualKeyUp() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::VirtualKeyUp } }
     #[classattr]
+    fn CompositionStart() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::CompositionStart } }
+    #[classattr]
+    fn CompositionUpdate() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::CompositionUpdate } }
+    #[classattr]
+    fn CompositionEnd() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::CompositionEnd } }
+    #[classattr]
     fn HoveredFile() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::HoveredFile } }
     #[classattr]
     fn DroppedFile() -> AzHoverEventFilterEnumWrapper { AzHoverEventFilterEnumWrapper { inner: AzHoverEventFilter::DroppedFile } }
@@ -15530,6 +18320,12 @@ impl AzFocusEventFilterEnumWrapper {
     #[classattr]
     fn VirtualKeyUp() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::VirtualKeyUp } }
     #[classattr]
+    fn CompositionStart() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::CompositionStart } }
+    #[classattr]
+    fn CompositionUpdate() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::CompositionUpdate } }
+    #[classattr]
+    fn CompositionEnd() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::CompositionEnd } }
+    #[classattr]
     fn FocusReceived() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::FocusReceived } }
     #[classattr]
     fn FocusLost() -> AzFocusEventFilterEnumWrapper { AzFocusEventFilterEnumWrapper { inner: AzFocusEventFilter::FocusLost } }
@@ -15621,6 +18417,12 @@ impl AzWindowEventFilterEnumWrapper {
     #[classattr]
     fn VirtualKeyUp() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::VirtualKeyUp } }
     #[classattr]
+    fn CompositionStart() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::CompositionStart } }
+    #[classattr]
+    fn CompositionUpdate() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::CompositionUpdate } }
+    #[classattr]
+    fn CompositionEnd() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::CompositionEnd } }
+    #[classattr]
     fn HoveredFile() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::HoveredFile } }
     #[classattr]
     fn DroppedFile() -> AzWindowEventFilterEnumWrapper { AzWindowEventFilterEnumWrapper { inner: AzWindowEventFilter::DroppedFile } }
@@ -15756,6 +18558,90 @@ impl PyObjectProtocol for AzAccessibilityInfo {
     }
 }
 
+#[pymethods]
+impl AzTooltip {
+    #[staticmethod]
+    fn text(text: String) -> AzTooltip {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTooltip_text(
+            mem::transmute(text),
+        )) }
+    }
+    fn set_delay(&mut self, delay: AzDurationEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzTooltip_setDelay(
+            mem::transmute(self),
+            mem::transmute(delay),
+        )) }
+    }
+    fn with_delay(&mut self, delay: AzDurationEnumWrapper) -> AzTooltip {
+        unsafe { mem::transmute(crate::AzTooltip_withDelay(
+            mem::transmute(self),
+            mem::transmute(delay),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTooltip {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::Tooltip = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::Tooltip = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTooltipContentEnumWrapper {
+    #[staticmethod]
+    fn Text(v: AzString) -> AzTooltipContentEnumWrapper { AzTooltipContentEnumWrapper { inner: AzTooltipContent::Text(v) } }
+    #[staticmethod]
+    fn Dom(v: AzTooltipDom) -> AzTooltipContentEnumWrapper { AzTooltipContentEnumWrapper { inner: AzTooltipContent::Dom(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTooltipContent;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTooltipContent::Text(v) => Ok(vec!["Text".into_py(py), v.clone().into_py(py)]),
+            AzTooltipContent::Dom(v) => Ok(vec!["Dom".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTooltipContentEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::TooltipContent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::TooltipContent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTooltipDom {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTooltipCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTooltipDom {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::TooltipDom = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::TooltipDom = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzAccessibilityRoleEnumWrapper {
     #[classattr]
@@ -15964,6 +18850,46 @@ impl PyObjectProtocol for AzAccessibilityStateEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzAccessibilityActionEnumWrapper {
+    #[classattr]
+    fn Default() -> AzAccessibilityActionEnumWrapper { AzAccessibilityActionEnumWrapper { inner: AzAccessibilityAction::Default } }
+    #[classattr]
+    fn Focus() -> AzAccessibilityActionEnumWrapper { AzAccessibilityActionEnumWrapper { inner: AzAccessibilityAction::Focus } }
+    #[classattr]
+    fn Toggle() -> AzAccessibilityActionEnumWrapper { AzAccessibilityActionEnumWrapper { inner: AzAccessibilityAction::Toggle } }
+    #[classattr]
+    fn Expand() -> AzAccessibilityActionEnumWrapper { AzAccessibilityActionEnumWrapper { inner: AzAccessibilityAction::Expand } }
+    #[classattr]
+    fn Collapse() -> AzAccessibilityActionEnumWrapper { AzAccessibilityActionEnumWrapper { inner: AzAccessibilityAction::Collapse } }
+    #[classattr]
+    fn Select() -> AzAccessibilityActionEnumWrapper { AzAccessibilityActionEnumWrapper { inner: AzAccessibilityAction::Select } }
+    #[classattr]
+    fn Increment() -> AzAccessibilityActionEnumWrapper { AzAccessibilityActionEnumWrapper { inner: AzAccessibilityAction::Increment } }
+    #[classattr]
+    fn Decrement() -> AzAccessibilityActionEnumWrapper { AzAccessibilityActionEnumWrapper { inner: AzAccessibilityAction::Decrement } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAccessibilityActionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::AccessibilityAction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::AccessibilityAction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzAccessibilityActionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzTabIndexEnumWrapper {
     #[classattr]
@@ -16612,9 +19538,11 @@ impl PyObjectProtocol for AzCssNthChildPattern {
 #[pymethods]
 impl AzStylesheet {
     #[new]
-    fn __new__(rules: AzCssRuleBlockVec) -> Self {
+    fn __new__(rules: AzCssRuleBlockVec, keyframes: AzCssKeyframesVec, media_rules: AzCssMediaRuleVec) -> Self {
         Self {
             rules,
+            keyframes,
+            media_rules,
         }
     }
 
@@ -16630,6 +19558,244 @@ impl PyObjectProtocol for AzStylesheet {
     }
 }
 
+#[pymethods]
+impl AzCssKeyframes {
+    #[new]
+    fn __new__(name: AzString, frames: AzCssKeyframeVec) -> Self {
+        Self {
+            name,
+            frames,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframes {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframes = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframes = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssKeyframe {
+    #[new]
+    fn __new__(offset: AzPercentageValue, properties: AzCssPropertyVec) -> Self {
+        Self {
+            offset,
+            properties,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframe {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframe = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframe = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaRule {
+    #[new]
+    fn __new__(queries: AzCssMediaQueryVec, insert_index: usize, rules: AzCssRuleBlockVec) -> Self {
+        Self {
+            queries,
+            insert_index,
+            rules,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaRule {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaRule = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaRule = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaQuery {
+    #[new]
+    fn __new__(negated: bool, media_type: AzCssMediaTypeEnumWrapper, features: AzCssMediaFeatureVec) -> Self {
+        Self {
+            negated,
+            media_type,
+            features,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaQuery {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQuery = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQuery = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaTypeEnumWrapper {
+    #[classattr]
+    fn All() -> AzCssMediaTypeEnumWrapper { AzCssMediaTypeEnumWrapper { inner: AzCssMediaType::All } }
+    #[classattr]
+    fn Screen() -> AzCssMediaTypeEnumWrapper { AzCssMediaTypeEnumWrapper { inner: AzCssMediaType::Screen } }
+    #[classattr]
+    fn Print() -> AzCssMediaTypeEnumWrapper { AzCssMediaTypeEnumWrapper { inner: AzCssMediaType::Print } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaTypeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaType = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaType = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCssMediaTypeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzCssMediaFeatureEnumWrapper {
+    #[staticmethod]
+    fn Width(v: AzCssMediaRange) -> AzCssMediaFeatureEnumWrapper { AzCssMediaFeatureEnumWrapper { inner: AzCssMediaFeature::Width(v) } }
+    #[staticmethod]
+    fn Height(v: AzCssMediaRange) -> AzCssMediaFeatureEnumWrapper { AzCssMediaFeatureEnumWrapper { inner: AzCssMediaFeature::Height(v) } }
+    #[staticmethod]
+    fn Resolution(v: AzCssMediaRange) -> AzCssMediaFeatureEnumWrapper { AzCssMediaFeatureEnumWrapper { inner: AzCssMediaFeature::Resolution(v) } }
+    #[staticmethod]
+    fn Orientation(v: AzCssMediaOrientationEnumWrapper) -> AzCssMediaFeatureEnumWrapper { AzCssMediaFeatureEnumWrapper { inner: AzCssMediaFeature::Orientation(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn PrefersColorScheme(v: AzCssColorSchemeEnumWrapper) -> AzCssMediaFeatureEnumWrapper { AzCssMediaFeatureEnumWrapper { inner: AzCssMediaFeature::PrefersColorScheme(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssMediaFeature;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssMediaFeature::Width(v) => Ok(vec!["Width".into_py(py), v.clone().into_py(py)]),
+            AzCssMediaFeature::Height(v) => Ok(vec!["Height".into_py(py), v.clone().into_py(py)]),
+            AzCssMediaFeature::Resolution(v) => Ok(vec!["Resolution".into_py(py), v.clone().into_py(py)]),
+            AzCssMediaFeature::Orientation(v) => Ok(vec!["Orientation".into_py(py), { let m: &AzCssMediaOrientationEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssMediaFeature::PrefersColorScheme(v) => Ok(vec!["PrefersColorScheme".into_py(py), { let m: &AzCssColorSchemeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaFeatureEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaFeature = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaFeature = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaRange {
+    #[new]
+    fn __new__(min: AzOptionF32EnumWrapper, min_inclusive: bool, max: AzOptionF32EnumWrapper, max_inclusive: bool) -> Self {
+        Self {
+            min,
+            min_inclusive,
+            max,
+            max_inclusive,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaRange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaOrientationEnumWrapper {
+    #[classattr]
+    fn Portrait() -> AzCssMediaOrientationEnumWrapper { AzCssMediaOrientationEnumWrapper { inner: AzCssMediaOrientation::Portrait } }
+    #[classattr]
+    fn Landscape() -> AzCssMediaOrientationEnumWrapper { AzCssMediaOrientationEnumWrapper { inner: AzCssMediaOrientation::Landscape } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaOrientationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCssMediaOrientationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzCssColorSchemeEnumWrapper {
+    #[classattr]
+    fn Light() -> AzCssColorSchemeEnumWrapper { AzCssColorSchemeEnumWrapper { inner: AzCssColorScheme::Light } }
+    #[classattr]
+    fn Dark() -> AzCssColorSchemeEnumWrapper { AzCssColorSchemeEnumWrapper { inner: AzCssColorScheme::Dark } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssColorSchemeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssColorScheme = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssColorScheme = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCssColorSchemeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzCss {
     #[staticmethod]
@@ -16666,6 +19832,14 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn TextAlign() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TextAlign } }
     #[classattr]
+    fn Direction() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Direction } }
+    #[classattr]
+    fn WordBreak() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::WordBreak } }
+    #[classattr]
+    fn OverflowWrap() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::OverflowWrap } }
+    #[classattr]
+    fn Hyphens() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::Hyphens } }
+    #[classattr]
     fn LetterSpacing() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::LetterSpacing } }
     #[classattr]
     fn LineHeight() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::LineHeight } }
@@ -16718,6 +19892,24 @@ impl AzCssPropertyTypeEnumWrapper {
     #[classattr]
     fn AlignContent() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AlignContent } }
     #[classattr]
+    fn GridTemplateColumns() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridTemplateColumns } }
+    #[classattr]
+    fn GridTemplateRows() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridTemplateRows } }
+    #[classattr]
+    fn GridAutoColumns() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridAutoColumns } }
+    #[classattr]
+    fn GridAutoRows() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridAutoRows } }
+    #[classattr]
+    fn GridAutoFlow() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridAutoFlow } }
+    #[classattr]
+    fn GridColumn() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridColumn } }
+    #[classattr]
+    fn GridRow() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::GridRow } }
+    #[classattr]
+    fn RowGap() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::RowGap } }
+    #[classattr]
+    fn ColumnGap() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::ColumnGap } }
+    #[classattr]
     fn BackgroundContent() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackgroundContent } }
     #[classattr]
     fn BackgroundPosition() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackgroundPosition } }
@@ -16805,6 +19997,30 @@ impl AzCssPropertyTypeEnumWrapper {
     fn BackdropFilter() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::BackdropFilter } }
     #[classattr]
     fn TextShadow() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TextShadow } }
+    #[classattr]
+    fn TransitionProperty() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionProperty } }
+    #[classattr]
+    fn TransitionDuration() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionDuration } }
+    #[classattr]
+    fn TransitionTimingFunction() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionTimingFunction } }
+    #[classattr]
+    fn TransitionDelay() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::TransitionDelay } }
+    #[classattr]
+    fn AnimationName() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationName } }
+    #[classattr]
+    fn AnimationDuration() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationDuration } }
+    #[classattr]
+    fn AnimationTimingFunction() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationTimingFunction } }
+    #[classattr]
+    fn AnimationDelay() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationDelay } }
+    #[classattr]
+    fn AnimationIterationCount() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationIterationCount } }
+    #[classattr]
+    fn AnimationDirection() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationDirection } }
+    #[classattr]
+    fn AnimationFillMode() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationFillMode } }
+    #[classattr]
+    fn AnimationPlayState() -> AzCssPropertyTypeEnumWrapper { AzCssPropertyTypeEnumWrapper { inner: AzCssPropertyType::AnimationPlayState } }
 }
 
 #[pyproto]
@@ -16827,6 +20043,62 @@ impl PyObjectProtocol for AzCssPropertyTypeEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzCombinedCssPropertyTypeEnumWrapper {
+    #[classattr]
+    fn BorderRadius() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::BorderRadius } }
+    #[classattr]
+    fn Overflow() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::Overflow } }
+    #[classattr]
+    fn Margin() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::Margin } }
+    #[classattr]
+    fn Border() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::Border } }
+    #[classattr]
+    fn BorderLeft() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::BorderLeft } }
+    #[classattr]
+    fn BorderRight() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::BorderRight } }
+    #[classattr]
+    fn BorderTop() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::BorderTop } }
+    #[classattr]
+    fn BorderBottom() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::BorderBottom } }
+    #[classattr]
+    fn Padding() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::Padding } }
+    #[classattr]
+    fn BoxShadow() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::BoxShadow } }
+    #[classattr]
+    fn BackgroundColor() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::BackgroundColor } }
+    #[classattr]
+    fn BackgroundImage() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::BackgroundImage } }
+    #[classattr]
+    fn Gap() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::Gap } }
+    #[classattr]
+    fn GridGap() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::GridGap } }
+    #[classattr]
+    fn Transition() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::Transition } }
+    #[classattr]
+    fn Animation() -> AzCombinedCssPropertyTypeEnumWrapper { AzCombinedCssPropertyTypeEnumWrapper { inner: AzCombinedCssPropertyType::Animation } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCombinedCssPropertyTypeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CombinedCssPropertyType = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CombinedCssPropertyType = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzCombinedCssPropertyTypeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzAnimationInterpolationFunctionEnumWrapper {
     #[classattr]
@@ -17317,6 +20589,194 @@ impl PyObjectProtocol for AzLayoutAlignContentEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzGridTrackBreadthEnumWrapper {
+    #[staticmethod]
+    fn Fixed(v: AzPixelValue) -> AzGridTrackBreadthEnumWrapper { AzGridTrackBreadthEnumWrapper { inner: AzGridTrackBreadth::Fixed(v) } }
+    #[staticmethod]
+    fn Fraction(v: AzFloatValue) -> AzGridTrackBreadthEnumWrapper { AzGridTrackBreadthEnumWrapper { inner: AzGridTrackBreadth::Fraction(v) } }
+    #[classattr]
+    fn Auto() -> AzGridTrackBreadthEnumWrapper { AzGridTrackBreadthEnumWrapper { inner: AzGridTrackBreadth::Auto } }
+    #[classattr]
+    fn MinContent() -> AzGridTrackBreadthEnumWrapper { AzGridTrackBreadthEnumWrapper { inner: AzGridTrackBreadth::MinContent } }
+    #[classattr]
+    fn MaxContent() -> AzGridTrackBreadthEnumWrapper { AzGridTrackBreadthEnumWrapper { inner: AzGridTrackBreadth::MaxContent } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridTrackBreadth;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridTrackBreadth::Fixed(v) => Ok(vec!["Fixed".into_py(py), v.clone().into_py(py)]),
+            AzGridTrackBreadth::Fraction(v) => Ok(vec!["Fraction".into_py(py), v.clone().into_py(py)]),
+            AzGridTrackBreadth::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzGridTrackBreadth::MinContent => Ok(vec!["MinContent".into_py(py), ().into_py(py)]),
+            AzGridTrackBreadth::MaxContent => Ok(vec!["MaxContent".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackBreadthEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackBreadth = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackBreadth = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridTrackSizing {
+    #[new]
+    fn __new__(min: AzGridTrackBreadthEnumWrapper, max: AzGridTrackBreadthEnumWrapper) -> Self {
+        Self {
+            min,
+            max,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackSizing {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizing = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizing = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridAutoFlowEnumWrapper {
+    #[classattr]
+    fn Row() -> AzLayoutGridAutoFlowEnumWrapper { AzLayoutGridAutoFlowEnumWrapper { inner: AzLayoutGridAutoFlow::Row } }
+    #[classattr]
+    fn Column() -> AzLayoutGridAutoFlowEnumWrapper { AzLayoutGridAutoFlowEnumWrapper { inner: AzLayoutGridAutoFlow::Column } }
+    #[classattr]
+    fn RowDense() -> AzLayoutGridAutoFlowEnumWrapper { AzLayoutGridAutoFlowEnumWrapper { inner: AzLayoutGridAutoFlow::RowDense } }
+    #[classattr]
+    fn ColumnDense() -> AzLayoutGridAutoFlowEnumWrapper { AzLayoutGridAutoFlowEnumWrapper { inner: AzLayoutGridAutoFlow::ColumnDense } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridAutoFlowEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridAutoFlow = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridAutoFlow = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzLayoutGridAutoFlowEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzGridLineEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzGridLineEnumWrapper { AzGridLineEnumWrapper { inner: AzGridLine::Auto } }
+    #[staticmethod]
+    fn Line(v: i32) -> AzGridLineEnumWrapper { AzGridLineEnumWrapper { inner: AzGridLine::Line(v) } }
+    #[staticmethod]
+    fn Span(v: u32) -> AzGridLineEnumWrapper { AzGridLineEnumWrapper { inner: AzGridLine::Span(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridLine;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridLine::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzGridLine::Line(v) => Ok(vec!["Line".into_py(py), v.into_py(py)]),
+            AzGridLine::Span(v) => Ok(vec!["Span".into_py(py), v.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridLineEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridLine = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridLine = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridPlacement {
+    #[new]
+    fn __new__(start: AzGridLineEnumWrapper, end: AzGridLineEnumWrapper) -> Self {
+        Self {
+            start,
+            end,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridPlacement {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridPlacement = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridPlacement = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutRowGap {
+    #[new]
+    fn __new__(inner: AzPixelValue) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutRowGap {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutRowGap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutRowGap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutColumnGap {
+    #[new]
+    fn __new__(inner: AzPixelValue) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutColumnGap {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutColumnGap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutColumnGap = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutAlignItemsEnumWrapper {
     #[classattr]
@@ -17440,6 +20900,8 @@ impl AzLayoutDisplayEnumWrapper {
     fn Block() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::Block } }
     #[classattr]
     fn InlineBlock() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::InlineBlock } }
+    #[classattr]
+    fn Grid() -> AzLayoutDisplayEnumWrapper { AzLayoutDisplayEnumWrapper { inner: AzLayoutDisplay::Grid } }
 }
 
 #[pyproto]
@@ -19593,6 +23055,331 @@ impl PyObjectProtocol for AzStyleTransformSkew2D {
     }
 }
 
+#[pymethods]
+impl AzStyleTime {
+    #[new]
+    fn __new__(ms: i32) -> Self {
+        Self {
+            ms,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTime {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTime = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTime = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleCubicBezier {
+    #[new]
+    fn __new__(x1: AzFloatValue, y1: AzFloatValue, x2: AzFloatValue, y2: AzFloatValue) -> Self {
+        Self {
+            x1,
+            y1,
+            x2,
+            y2,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleCubicBezier {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCubicBezier = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleCubicBezier = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleStepPositionEnumWrapper {
+    #[classattr]
+    fn JumpStart() -> AzStyleStepPositionEnumWrapper { AzStyleStepPositionEnumWrapper { inner: AzStyleStepPosition::JumpStart } }
+    #[classattr]
+    fn JumpEnd() -> AzStyleStepPositionEnumWrapper { AzStyleStepPositionEnumWrapper { inner: AzStyleStepPosition::JumpEnd } }
+    #[classattr]
+    fn JumpNone() -> AzStyleStepPositionEnumWrapper { AzStyleStepPositionEnumWrapper { inner: AzStyleStepPosition::JumpNone } }
+    #[classattr]
+    fn JumpBoth() -> AzStyleStepPositionEnumWrapper { AzStyleStepPositionEnumWrapper { inner: AzStyleStepPosition::JumpBoth } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleStepPositionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleStepPosition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleStepPosition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleStepPositionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleSteps {
+    #[new]
+    fn __new__(count: u32, position: AzStyleStepPositionEnumWrapper) -> Self {
+        Self {
+            count,
+            position,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleSteps {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSteps = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleSteps = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTimingFunctionEnumWrapper {
+    #[classattr]
+    fn Ease() -> AzStyleTimingFunctionEnumWrapper { AzStyleTimingFunctionEnumWrapper { inner: AzStyleTimingFunction::Ease } }
+    #[classattr]
+    fn Linear() -> AzStyleTimingFunctionEnumWrapper { AzStyleTimingFunctionEnumWrapper { inner: AzStyleTimingFunction::Linear } }
+    #[classattr]
+    fn EaseIn() -> AzStyleTimingFunctionEnumWrapper { AzStyleTimingFunctionEnumWrapper { inner: AzStyleTimingFunction::EaseIn } }
+    #[classattr]
+    fn EaseOut() -> AzStyleTimingFunctionEnumWrapper { AzStyleTimingFunctionEnumWrapper { inner: AzStyleTimingFunction::EaseOut } }
+    #[classattr]
+    fn EaseInOut() -> AzStyleTimingFunctionEnumWrapper { AzStyleTimingFunctionEnumWrapper { inner: AzStyleTimingFunction::EaseInOut } }
+    #[staticmethod]
+    fn CubicBezier(v: AzStyleCubicBezier) -> AzStyleTimingFunctionEnumWrapper { AzStyleTimingFunctionEnumWrapper { inner: AzStyleTimingFunction::CubicBezier(v) } }
+    #[staticmethod]
+    fn Steps(v: AzStyleSteps) -> AzStyleTimingFunctionEnumWrapper { AzStyleTimingFunctionEnumWrapper { inner: AzStyleTimingFunction::Steps(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTimingFunction;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTimingFunction::Ease => Ok(vec!["Ease".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunction::Linear => Ok(vec!["Linear".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunction::EaseIn => Ok(vec!["EaseIn".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunction::EaseOut => Ok(vec!["EaseOut".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunction::EaseInOut => Ok(vec!["EaseInOut".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunction::CubicBezier(v) => Ok(vec!["CubicBezier".into_py(py), v.clone().into_py(py)]),
+            AzStyleTimingFunction::Steps(v) => Ok(vec!["Steps".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTimingFunctionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimingFunction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimingFunction = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionPropertyEnumWrapper {
+    #[classattr]
+    fn All() -> AzStyleTransitionPropertyEnumWrapper { AzStyleTransitionPropertyEnumWrapper { inner: AzStyleTransitionProperty::All } }
+    #[staticmethod]
+    fn Property(v: AzCssPropertyTypeEnumWrapper) -> AzStyleTransitionPropertyEnumWrapper { AzStyleTransitionPropertyEnumWrapper { inner: AzStyleTransitionProperty::Property(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn Shorthand(v: AzCombinedCssPropertyTypeEnumWrapper) -> AzStyleTransitionPropertyEnumWrapper { AzStyleTransitionPropertyEnumWrapper { inner: AzStyleTransitionProperty::Shorthand(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionProperty;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionProperty::All => Ok(vec!["All".into_py(py), ().into_py(py)]),
+            AzStyleTransitionProperty::Property(v) => Ok(vec!["Property".into_py(py), { let m: &AzCssPropertyTypeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzStyleTransitionProperty::Shorthand(v) => Ok(vec!["Shorthand".into_py(py), { let m: &AzCombinedCssPropertyTypeEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionProperty = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionProperty = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationNameEnumWrapper {
+    #[classattr]
+    fn None() -> AzStyleAnimationNameEnumWrapper { AzStyleAnimationNameEnumWrapper { inner: AzStyleAnimationName::None } }
+    #[staticmethod]
+    fn Name(v: AzString) -> AzStyleAnimationNameEnumWrapper { AzStyleAnimationNameEnumWrapper { inner: AzStyleAnimationName::Name(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationName;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationName::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationName::Name(v) => Ok(vec!["Name".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationNameEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationName = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationName = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationIterationCountEnumWrapper {
+    #[classattr]
+    fn Infinite() -> AzStyleAnimationIterationCountEnumWrapper { AzStyleAnimationIterationCountEnumWrapper { inner: AzStyleAnimationIterationCount::Infinite } }
+    #[staticmethod]
+    fn Count(v: AzFloatValue) -> AzStyleAnimationIterationCountEnumWrapper { AzStyleAnimationIterationCountEnumWrapper { inner: AzStyleAnimationIterationCount::Count(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationIterationCount;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationIterationCount::Infinite => Ok(vec!["Infinite".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCount::Count(v) => Ok(vec!["Count".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationIterationCountEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCount = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCount = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDirectionEnumWrapper {
+    #[classattr]
+    fn Normal() -> AzStyleAnimationDirectionEnumWrapper { AzStyleAnimationDirectionEnumWrapper { inner: AzStyleAnimationDirection::Normal } }
+    #[classattr]
+    fn Reverse() -> AzStyleAnimationDirectionEnumWrapper { AzStyleAnimationDirectionEnumWrapper { inner: AzStyleAnimationDirection::Reverse } }
+    #[classattr]
+    fn Alternate() -> AzStyleAnimationDirectionEnumWrapper { AzStyleAnimationDirectionEnumWrapper { inner: AzStyleAnimationDirection::Alternate } }
+    #[classattr]
+    fn AlternateReverse() -> AzStyleAnimationDirectionEnumWrapper { AzStyleAnimationDirectionEnumWrapper { inner: AzStyleAnimationDirection::AlternateReverse } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDirectionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleAnimationDirectionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationFillModeEnumWrapper {
+    #[classattr]
+    fn None() -> AzStyleAnimationFillModeEnumWrapper { AzStyleAnimationFillModeEnumWrapper { inner: AzStyleAnimationFillMode::None } }
+    #[classattr]
+    fn Forwards() -> AzStyleAnimationFillModeEnumWrapper { AzStyleAnimationFillModeEnumWrapper { inner: AzStyleAnimationFillMode::Forwards } }
+    #[classattr]
+    fn Backwards() -> AzStyleAnimationFillModeEnumWrapper { AzStyleAnimationFillModeEnumWrapper { inner: AzStyleAnimationFillMode::Backwards } }
+    #[classattr]
+    fn Both() -> AzStyleAnimationFillModeEnumWrapper { AzStyleAnimationFillModeEnumWrapper { inner: AzStyleAnimationFillMode::Both } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationFillModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleAnimationFillModeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationPlayStateEnumWrapper {
+    #[classattr]
+    fn Running() -> AzStyleAnimationPlayStateEnumWrapper { AzStyleAnimationPlayStateEnumWrapper { inner: AzStyleAnimationPlayState::Running } }
+    #[classattr]
+    fn Paused() -> AzStyleAnimationPlayStateEnumWrapper { AzStyleAnimationPlayStateEnumWrapper { inner: AzStyleAnimationPlayState::Paused } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationPlayStateEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationPlayState = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationPlayState = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleAnimationPlayStateEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzStyleTextAlignEnumWrapper {
     #[classattr]
@@ -19601,6 +23388,10 @@ impl AzStyleTextAlignEnumWrapper {
     fn Center() -> AzStyleTextAlignEnumWrapper { AzStyleTextAlignEnumWrapper { inner: AzStyleTextAlign::Center } }
     #[classattr]
     fn Right() -> AzStyleTextAlignEnumWrapper { AzStyleTextAlignEnumWrapper { inner: AzStyleTextAlign::Right } }
+    #[classattr]
+    fn Start() -> AzStyleTextAlignEnumWrapper { AzStyleTextAlignEnumWrapper { inner: AzStyleTextAlign::Start } }
+    #[classattr]
+    fn End() -> AzStyleTextAlignEnumWrapper { AzStyleTextAlignEnumWrapper { inner: AzStyleTextAlign::End } }
 }
 
 #[pyproto]
@@ -19623,6 +23414,124 @@ impl PyObjectProtocol for AzStyleTextAlignEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleDirectionEnumWrapper {
+    #[classattr]
+    fn Ltr() -> AzStyleDirectionEnumWrapper { AzStyleDirectionEnumWrapper { inner: AzStyleDirection::Ltr } }
+    #[classattr]
+    fn Rtl() -> AzStyleDirectionEnumWrapper { AzStyleDirectionEnumWrapper { inner: AzStyleDirection::Rtl } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleDirectionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleDirection = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleDirectionEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleWordBreakEnumWrapper {
+    #[classattr]
+    fn Normal() -> AzStyleWordBreakEnumWrapper { AzStyleWordBreakEnumWrapper { inner: AzStyleWordBreak::Normal } }
+    #[classattr]
+    fn BreakAll() -> AzStyleWordBreakEnumWrapper { AzStyleWordBreakEnumWrapper { inner: AzStyleWordBreak::BreakAll } }
+    #[classattr]
+    fn KeepAll() -> AzStyleWordBreakEnumWrapper { AzStyleWordBreakEnumWrapper { inner: AzStyleWordBreak::KeepAll } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleWordBreakEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleWordBreak = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleWordBreak = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleWordBreakEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleOverflowWrapEnumWrapper {
+    #[classattr]
+    fn Normal() -> AzStyleOverflowWrapEnumWrapper { AzStyleOverflowWrapEnumWrapper { inner: AzStyleOverflowWrap::Normal } }
+    #[classattr]
+    fn Anywhere() -> AzStyleOverflowWrapEnumWrapper { AzStyleOverflowWrapEnumWrapper { inner: AzStyleOverflowWrap::Anywhere } }
+    #[classattr]
+    fn BreakWord() -> AzStyleOverflowWrapEnumWrapper { AzStyleOverflowWrapEnumWrapper { inner: AzStyleOverflowWrap::BreakWord } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleOverflowWrapEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleOverflowWrap = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleOverflowWrap = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleOverflowWrapEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzStyleHyphensEnumWrapper {
+    #[classattr]
+    fn None() -> AzStyleHyphensEnumWrapper { AzStyleHyphensEnumWrapper { inner: AzStyleHyphens::None } }
+    #[classattr]
+    fn Manual() -> AzStyleHyphensEnumWrapper { AzStyleHyphensEnumWrapper { inner: AzStyleHyphens::Manual } }
+    #[classattr]
+    fn Auto() -> AzStyleHyphensEnumWrapper { AzStyleHyphensEnumWrapper { inner: AzStyleHyphens::Auto } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleHyphensEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleHyphens = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleHyphens = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzStyleHyphensEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
 #[pymethods]
 impl AzStyleTextColor {
     #[new]
@@ -19741,6 +23650,538 @@ impl PyObjectProtocol for AzLayoutAlignContentValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzGridTrackSizingVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzGridTrackSizingVecValueEnumWrapper { AzGridTrackSizingVecValueEnumWrapper { inner: AzGridTrackSizingVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzGridTrackSizingVecValueEnumWrapper { AzGridTrackSizingVecValueEnumWrapper { inner: AzGridTrackSizingVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzGridTrackSizingVecValueEnumWrapper { AzGridTrackSizingVecValueEnumWrapper { inner: AzGridTrackSizingVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzGridTrackSizingVecValueEnumWrapper { AzGridTrackSizingVecValueEnumWrapper { inner: AzGridTrackSizingVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzGridTrackSizingVec) -> AzGridTrackSizingVecValueEnumWrapper { AzGridTrackSizingVecValueEnumWrapper { inner: AzGridTrackSizingVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridTrackSizingVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridTrackSizingVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackSizingVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridTrackSizingValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzGridTrackSizingValueEnumWrapper { AzGridTrackSizingValueEnumWrapper { inner: AzGridTrackSizingValue::Auto } }
+    #[classattr]
+    fn None() -> AzGridTrackSizingValueEnumWrapper { AzGridTrackSizingValueEnumWrapper { inner: AzGridTrackSizingValue::None } }
+    #[classattr]
+    fn Inherit() -> AzGridTrackSizingValueEnumWrapper { AzGridTrackSizingValueEnumWrapper { inner: AzGridTrackSizingValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzGridTrackSizingValueEnumWrapper { AzGridTrackSizingValueEnumWrapper { inner: AzGridTrackSizingValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzGridTrackSizing) -> AzGridTrackSizingValueEnumWrapper { AzGridTrackSizingValueEnumWrapper { inner: AzGridTrackSizingValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridTrackSizingValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridTrackSizingValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackSizingValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutGridAutoFlowValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutGridAutoFlowValueEnumWrapper { AzLayoutGridAutoFlowValueEnumWrapper { inner: AzLayoutGridAutoFlowValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutGridAutoFlowValueEnumWrapper { AzLayoutGridAutoFlowValueEnumWrapper { inner: AzLayoutGridAutoFlowValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutGridAutoFlowValueEnumWrapper { AzLayoutGridAutoFlowValueEnumWrapper { inner: AzLayoutGridAutoFlowValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutGridAutoFlowValueEnumWrapper { AzLayoutGridAutoFlowValueEnumWrapper { inner: AzLayoutGridAutoFlowValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutGridAutoFlowEnumWrapper) -> AzLayoutGridAutoFlowValueEnumWrapper { AzLayoutGridAutoFlowValueEnumWrapper { inner: AzLayoutGridAutoFlowValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutGridAutoFlowValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutGridAutoFlowValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutGridAutoFlowValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutGridAutoFlowValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutGridAutoFlowValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutGridAutoFlowValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzLayoutGridAutoFlowEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutGridAutoFlowValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridAutoFlowValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutGridAutoFlowValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridPlacementValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzGridPlacementValueEnumWrapper { AzGridPlacementValueEnumWrapper { inner: AzGridPlacementValue::Auto } }
+    #[classattr]
+    fn None() -> AzGridPlacementValueEnumWrapper { AzGridPlacementValueEnumWrapper { inner: AzGridPlacementValue::None } }
+    #[classattr]
+    fn Inherit() -> AzGridPlacementValueEnumWrapper { AzGridPlacementValueEnumWrapper { inner: AzGridPlacementValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzGridPlacementValueEnumWrapper { AzGridPlacementValueEnumWrapper { inner: AzGridPlacementValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzGridPlacement) -> AzGridPlacementValueEnumWrapper { AzGridPlacementValueEnumWrapper { inner: AzGridPlacementValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridPlacementValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridPlacementValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzGridPlacementValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzGridPlacementValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzGridPlacementValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzGridPlacementValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridPlacementValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridPlacementValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridPlacementValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutRowGapValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutRowGap) -> AzLayoutRowGapValueEnumWrapper { AzLayoutRowGapValueEnumWrapper { inner: AzLayoutRowGapValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutRowGapValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutRowGapValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutRowGapValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutRowGapValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutRowGapValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutRowGapValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutRowGapValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutRowGapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutRowGapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzLayoutColumnGapValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::Auto } }
+    #[classattr]
+    fn None() -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::None } }
+    #[classattr]
+    fn Inherit() -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzLayoutColumnGap) -> AzLayoutColumnGapValueEnumWrapper { AzLayoutColumnGapValueEnumWrapper { inner: AzLayoutColumnGapValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzLayoutColumnGapValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzLayoutColumnGapValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzLayoutColumnGapValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzLayoutColumnGapValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzLayoutColumnGapValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzLayoutColumnGapValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzLayoutColumnGapValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutColumnGapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::LayoutColumnGapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTransitionPropertyVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTransitionPropertyVec) -> AzStyleTransitionPropertyVecValueEnumWrapper { AzStyleTransitionPropertyVecValueEnumWrapper { inner: AzStyleTransitionPropertyVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionPropertyVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionPropertyVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTimeVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTimeVecValueEnumWrapper { AzStyleTimeVecValueEnumWrapper { inner: AzStyleTimeVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTimeVecValueEnumWrapper { AzStyleTimeVecValueEnumWrapper { inner: AzStyleTimeVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTimeVecValueEnumWrapper { AzStyleTimeVecValueEnumWrapper { inner: AzStyleTimeVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTimeVecValueEnumWrapper { AzStyleTimeVecValueEnumWrapper { inner: AzStyleTimeVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTimeVec) -> AzStyleTimeVecValueEnumWrapper { AzStyleTimeVecValueEnumWrapper { inner: AzStyleTimeVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTimeVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTimeVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTimeVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTimeVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTimeVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTimeVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTimeVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimeVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimeVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTimingFunctionVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleTimingFunctionVecValueEnumWrapper { AzStyleTimingFunctionVecValueEnumWrapper { inner: AzStyleTimingFunctionVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleTimingFunctionVecValueEnumWrapper { AzStyleTimingFunctionVecValueEnumWrapper { inner: AzStyleTimingFunctionVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleTimingFunctionVecValueEnumWrapper { AzStyleTimingFunctionVecValueEnumWrapper { inner: AzStyleTimingFunctionVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleTimingFunctionVecValueEnumWrapper { AzStyleTimingFunctionVecValueEnumWrapper { inner: AzStyleTimingFunctionVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleTimingFunctionVec) -> AzStyleTimingFunctionVecValueEnumWrapper { AzStyleTimingFunctionVecValueEnumWrapper { inner: AzStyleTimingFunctionVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTimingFunctionVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTimingFunctionVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunctionVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunctionVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunctionVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunctionVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTimingFunctionVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimingFunctionVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimingFunctionVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationNameVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationNameVec) -> AzStyleAnimationNameVecValueEnumWrapper { AzStyleAnimationNameVecValueEnumWrapper { inner: AzStyleAnimationNameVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationNameVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationNameVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationNameVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationIterationCountVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationIterationCountVec) -> AzStyleAnimationIterationCountVecValueEnumWrapper { AzStyleAnimationIterationCountVecValueEnumWrapper { inner: AzStyleAnimationIterationCountVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationIterationCountVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationIterationCountVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationIterationCountVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDirectionVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationDirectionVec) -> AzStyleAnimationDirectionVecValueEnumWrapper { AzStyleAnimationDirectionVecValueEnumWrapper { inner: AzStyleAnimationDirectionVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationDirectionVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationDirectionVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDirectionVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationFillModeVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationFillModeVec) -> AzStyleAnimationFillModeVecValueEnumWrapper { AzStyleAnimationFillModeVecValueEnumWrapper { inner: AzStyleAnimationFillModeVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationFillModeVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationFillModeVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationFillModeVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationPlayStateVecValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleAnimationPlayStateVecValueEnumWrapper { AzStyleAnimationPlayStateVecValueEnumWrapper { inner: AzStyleAnimationPlayStateVecValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleAnimationPlayStateVecValueEnumWrapper { AzStyleAnimationPlayStateVecValueEnumWrapper { inner: AzStyleAnimationPlayStateVecValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleAnimationPlayStateVecValueEnumWrapper { AzStyleAnimationPlayStateVecValueEnumWrapper { inner: AzStyleAnimationPlayStateVecValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleAnimationPlayStateVecValueEnumWrapper { AzStyleAnimationPlayStateVecValueEnumWrapper { inner: AzStyleAnimationPlayStateVecValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleAnimationPlayStateVec) -> AzStyleAnimationPlayStateVecValueEnumWrapper { AzStyleAnimationPlayStateVecValueEnumWrapper { inner: AzStyleAnimationPlayStateVecValue::Exact(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationPlayStateVecValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationPlayStateVecValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleAnimationPlayStateVecValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleAnimationPlayStateVecValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleAnimationPlayStateVecValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleAnimationPlayStateVecValue::Exact(v) => Ok(vec!["Exact".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationPlayStateVecValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationPlayStateVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationPlayStateVecValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzLayoutAlignItemsValueEnumWrapper {
     #[classattr]
@@ -21907,6 +26348,158 @@ impl PyObjectProtocol for AzStyleTextAlignValueEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleDirectionValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleDirectionEnumWrapper) -> AzStyleDirectionValueEnumWrapper { AzStyleDirectionValueEnumWrapper { inner: AzStyleDirectionValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleDirectionValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleDirectionValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleDirectionValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleDirectionValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleDirectionValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleDirectionValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleDirectionEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleDirectionValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleDirectionValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleDirectionValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleWordBreakValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleWordBreakValueEnumWrapper { AzStyleWordBreakValueEnumWrapper { inner: AzStyleWordBreakValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleWordBreakValueEnumWrapper { AzStyleWordBreakValueEnumWrapper { inner: AzStyleWordBreakValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleWordBreakValueEnumWrapper { AzStyleWordBreakValueEnumWrapper { inner: AzStyleWordBreakValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleWordBreakValueEnumWrapper { AzStyleWordBreakValueEnumWrapper { inner: AzStyleWordBreakValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleWordBreakEnumWrapper) -> AzStyleWordBreakValueEnumWrapper { AzStyleWordBreakValueEnumWrapper { inner: AzStyleWordBreakValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleWordBreakValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleWordBreakValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleWordBreakValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleWordBreakValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleWordBreakValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleWordBreakValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleWordBreakEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleWordBreakValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleWordBreakValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleWordBreakValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleOverflowWrapValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleOverflowWrapValueEnumWrapper { AzStyleOverflowWrapValueEnumWrapper { inner: AzStyleOverflowWrapValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleOverflowWrapValueEnumWrapper { AzStyleOverflowWrapValueEnumWrapper { inner: AzStyleOverflowWrapValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleOverflowWrapValueEnumWrapper { AzStyleOverflowWrapValueEnumWrapper { inner: AzStyleOverflowWrapValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleOverflowWrapValueEnumWrapper { AzStyleOverflowWrapValueEnumWrapper { inner: AzStyleOverflowWrapValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleOverflowWrapEnumWrapper) -> AzStyleOverflowWrapValueEnumWrapper { AzStyleOverflowWrapValueEnumWrapper { inner: AzStyleOverflowWrapValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleOverflowWrapValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleOverflowWrapValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleOverflowWrapValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleOverflowWrapValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleOverflowWrapValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleOverflowWrapValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleOverflowWrapEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleOverflowWrapValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleOverflowWrapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleOverflowWrapValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleHyphensValueEnumWrapper {
+    #[classattr]
+    fn Auto() -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::Auto } }
+    #[classattr]
+    fn None() -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::None } }
+    #[classattr]
+    fn Inherit() -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::Inherit } }
+    #[classattr]
+    fn Initial() -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::Initial } }
+    #[staticmethod]
+    fn Exact(v: AzStyleHyphensEnumWrapper) -> AzStyleHyphensValueEnumWrapper { AzStyleHyphensValueEnumWrapper { inner: AzStyleHyphensValue::Exact(unsafe { mem::transmute(v) }) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleHyphensValue;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleHyphensValue::Auto => Ok(vec!["Auto".into_py(py), ().into_py(py)]),
+            AzStyleHyphensValue::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzStyleHyphensValue::Inherit => Ok(vec!["Inherit".into_py(py), ().into_py(py)]),
+            AzStyleHyphensValue::Initial => Ok(vec!["Initial".into_py(py), ().into_py(py)]),
+            AzStyleHyphensValue::Exact(v) => Ok(vec!["Exact".into_py(py), { let m: &AzStyleHyphensEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleHyphensValueEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleHyphensValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleHyphensValue = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleTextColorValueEnumWrapper {
     #[classattr]
@@ -22260,6 +26853,14 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn TextAlign(v: AzStyleTextAlignValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TextAlign(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn Direction(v: AzStyleDirectionValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Direction(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn WordBreak(v: AzStyleWordBreakValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::WordBreak(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn OverflowWrap(v: AzStyleOverflowWrapValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::OverflowWrap(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn Hyphens(v: AzStyleHyphensValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::Hyphens(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn LetterSpacing(v: AzStyleLetterSpacingValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::LetterSpacing(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn LineHeight(v: AzStyleLineHeightValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::LineHeight(unsafe { mem::transmute(v) }) } }
@@ -22312,6 +26913,24 @@ impl AzCssPropertyEnumWrapper {
     #[staticmethod]
     fn AlignContent(v: AzLayoutAlignContentValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AlignContent(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
+    fn GridTemplateColumns(v: AzGridTrackSizingVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridTemplateColumns(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridTemplateRows(v: AzGridTrackSizingVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridTemplateRows(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridAutoColumns(v: AzGridTrackSizingValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridAutoColumns(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridAutoRows(v: AzGridTrackSizingValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridAutoRows(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridAutoFlow(v: AzLayoutGridAutoFlowValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridAutoFlow(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridColumn(v: AzGridPlacementValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridColumn(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn GridRow(v: AzGridPlacementValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::GridRow(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn RowGap(v: AzLayoutRowGapValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::RowGap(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn ColumnGap(v: AzLayoutColumnGapValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::ColumnGap(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
     fn BackgroundContent(v: AzStyleBackgroundContentVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackgroundContent(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn BackgroundPosition(v: AzStyleBackgroundPositionVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackgroundPosition(unsafe { mem::transmute(v) }) } }
@@ -22399,6 +27018,30 @@ impl AzCssPropertyEnumWrapper {
     fn BackdropFilter(v: AzStyleFilterVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::BackdropFilter(unsafe { mem::transmute(v) }) } }
     #[staticmethod]
     fn TextShadow(v: AzStyleBoxShadowValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TextShadow(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionProperty(v: AzStyleTransitionPropertyVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionProperty(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionDuration(v: AzStyleTimeVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionDuration(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionTimingFunction(v: AzStyleTimingFunctionVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionTimingFunction(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn TransitionDelay(v: AzStyleTimeVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::TransitionDelay(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationName(v: AzStyleAnimationNameVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationName(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationDuration(v: AzStyleTimeVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationDuration(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationTimingFunction(v: AzStyleTimingFunctionVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationTimingFunction(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationDelay(v: AzStyleTimeVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationDelay(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationIterationCount(v: AzStyleAnimationIterationCountVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationIterationCount(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationDirection(v: AzStyleAnimationDirectionVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationDirection(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationFillMode(v: AzStyleAnimationFillModeVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationFillMode(unsafe { mem::transmute(v) }) } }
+    #[staticmethod]
+    fn AnimationPlayState(v: AzStyleAnimationPlayStateVecValueEnumWrapper) -> AzCssPropertyEnumWrapper { AzCssPropertyEnumWrapper { inner: AzCssProperty::AnimationPlayState(unsafe { mem::transmute(v) }) } }
 
     fn r#match(&self) -> PyResult<Vec<PyObject>> {
         use crate::python::AzCssProperty;
@@ -22410,6 +27053,10 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::FontSize(v) => Ok(vec!["FontSize".into_py(py), { let m: &AzStyleFontSizeValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::FontFamily(v) => Ok(vec!["FontFamily".into_py(py), { let m: &AzStyleFontFamilyVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::TextAlign(v) => Ok(vec!["TextAlign".into_py(py), { let m: &AzStyleTextAlignValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::Direction(v) => Ok(vec!["Direction".into_py(py), { let m: &AzStyleDirectionValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::WordBreak(v) => Ok(vec!["WordBreak".into_py(py), { let m: &AzStyleWordBreakValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::OverflowWrap(v) => Ok(vec!["OverflowWrap".into_py(py), { let m: &AzStyleOverflowWrapValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::Hyphens(v) => Ok(vec!["Hyphens".into_py(py), { let m: &AzStyleHyphensValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::LetterSpacing(v) => Ok(vec!["LetterSpacing".into_py(py), { let m: &AzStyleLetterSpacingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::LineHeight(v) => Ok(vec!["LineHeight".into_py(py), { let m: &AzStyleLineHeightValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::WordSpacing(v) => Ok(vec!["WordSpacing".into_py(py), { let m: &AzStyleWordSpacingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
@@ -22436,6 +27083,15 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::JustifyContent(v) => Ok(vec!["JustifyContent".into_py(py), { let m: &AzLayoutJustifyContentValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::AlignItems(v) => Ok(vec!["AlignItems".into_py(py), { let m: &AzLayoutAlignItemsValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::AlignContent(v) => Ok(vec!["AlignContent".into_py(py), { let m: &AzLayoutAlignContentValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridTemplateColumns(v) => Ok(vec!["GridTemplateColumns".into_py(py), { let m: &AzGridTrackSizingVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridTemplateRows(v) => Ok(vec!["GridTemplateRows".into_py(py), { let m: &AzGridTrackSizingVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridAutoColumns(v) => Ok(vec!["GridAutoColumns".into_py(py), { let m: &AzGridTrackSizingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridAutoRows(v) => Ok(vec!["GridAutoRows".into_py(py), { let m: &AzGridTrackSizingValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridAutoFlow(v) => Ok(vec!["GridAutoFlow".into_py(py), { let m: &AzLayoutGridAutoFlowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridColumn(v) => Ok(vec!["GridColumn".into_py(py), { let m: &AzGridPlacementValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::GridRow(v) => Ok(vec!["GridRow".into_py(py), { let m: &AzGridPlacementValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::RowGap(v) => Ok(vec!["RowGap".into_py(py), { let m: &AzLayoutRowGapValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::ColumnGap(v) => Ok(vec!["ColumnGap".into_py(py), { let m: &AzLayoutColumnGapValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackgroundContent(v) => Ok(vec!["BackgroundContent".into_py(py), { let m: &AzStyleBackgroundContentVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackgroundPosition(v) => Ok(vec!["BackgroundPosition".into_py(py), { let m: &AzStyleBackgroundPositionVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackgroundSize(v) => Ok(vec!["BackgroundSize".into_py(py), { let m: &AzStyleBackgroundSizeVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
@@ -22480,6 +27136,18 @@ impl AzCssPropertyEnumWrapper {
             AzCssProperty::Filter(v) => Ok(vec!["Filter".into_py(py), { let m: &AzStyleFilterVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::BackdropFilter(v) => Ok(vec!["BackdropFilter".into_py(py), { let m: &AzStyleFilterVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
             AzCssProperty::TextShadow(v) => Ok(vec!["TextShadow".into_py(py), { let m: &AzStyleBoxShadowValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionProperty(v) => Ok(vec!["TransitionProperty".into_py(py), { let m: &AzStyleTransitionPropertyVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionDuration(v) => Ok(vec!["TransitionDuration".into_py(py), { let m: &AzStyleTimeVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionTimingFunction(v) => Ok(vec!["TransitionTimingFunction".into_py(py), { let m: &AzStyleTimingFunctionVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::TransitionDelay(v) => Ok(vec!["TransitionDelay".into_py(py), { let m: &AzStyleTimeVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationName(v) => Ok(vec!["AnimationName".into_py(py), { let m: &AzStyleAnimationNameVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationDuration(v) => Ok(vec!["AnimationDuration".into_py(py), { let m: &AzStyleTimeVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationTimingFunction(v) => Ok(vec!["AnimationTimingFunction".into_py(py), { let m: &AzStyleTimingFunctionVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationDelay(v) => Ok(vec!["AnimationDelay".into_py(py), { let m: &AzStyleTimeVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationIterationCount(v) => Ok(vec!["AnimationIterationCount".into_py(py), { let m: &AzStyleAnimationIterationCountVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationDirection(v) => Ok(vec!["AnimationDirection".into_py(py), { let m: &AzStyleAnimationDirectionVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationFillMode(v) => Ok(vec!["AnimationFillMode".into_py(py), { let m: &AzStyleAnimationFillModeVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
+            AzCssProperty::AnimationPlayState(v) => Ok(vec!["AnimationPlayState".into_py(py), { let m: &AzStyleAnimationPlayStateVecValueEnumWrapper = unsafe { mem::transmute(v) }; m.clone() }.into_py(py)]),
         }
     }
 }
@@ -22497,12 +27165,40 @@ impl PyObjectProtocol for AzCssPropertyEnumWrapper {
 #[pymethods]
 impl AzRibbon {
     #[new]
-    fn __new__(tab_active: i32) -> Self {
-        Self {
-            tab_active,
-        }
+    fn new(tabs: AzRibbonTabVec) -> AzRibbon {
+        unsafe { mem::transmute(crate::AzRibbon_new(
+            mem::transmute(tabs),
+        )) }
+    }
+    fn set_tab_active(&mut self, tab_active: i32) -> () {
+        unsafe { mem::transmute(crate::AzRibbon_setTabActive(
+            mem::transmute(self),
+            mem::transmute(tab_active),
+        )) }
+    }
+    fn with_tab_active(&mut self, tab_active: i32) -> AzRibbon {
+        unsafe { mem::transmute(crate::AzRibbon_withTabActive(
+            mem::transmute(self),
+            mem::transmute(tab_active),
+        )) }
+    }
+    fn set_available_width(&mut self, available_width: f32) -> () {
+        unsafe { mem::transmute(crate::AzRibbon_setAvailableWidth(
+            mem::transmute(self),
+            mem::transmute(available_width),
+        )) }
+    }
+    fn with_available_width(&mut self, available_width: f32) -> AzRibbon {
+        unsafe { mem::transmute(crate::AzRibbon_withAvailableWidth(
+            mem::transmute(self),
+            mem::transmute(available_width),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzRibbon_dom(
+            mem::transmute(self),
+        )) }
     }
-
 }
 
 #[pyproto]
@@ -22515,6 +27211,294 @@ impl PyObjectProtocol for AzRibbon {
     }
 }
 
+#[pymethods]
+impl AzRibbonTab {
+    #[new]
+    fn new(label: String, groups: AzRibbonGroupVec) -> AzRibbonTab {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzRibbonTab_new(
+            mem::transmute(label),
+            mem::transmute(groups),
+        )) }
+    }
+    fn set_key_tip(&mut self, key_tip: String) -> () {
+        let key_tip = pystring_to_azstring(&key_tip);
+        unsafe { mem::transmute(crate::AzRibbonTab_setKeyTip(
+            mem::transmute(self),
+            mem::transmute(key_tip),
+        )) }
+    }
+    fn with_key_tip(&mut self, key_tip: String) -> AzRibbonTab {
+        let key_tip = pystring_to_azstring(&key_tip);
+        unsafe { mem::transmute(crate::AzRibbonTab_withKeyTip(
+            mem::transmute(self),
+            mem::transmute(key_tip),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonTab {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonTab = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonTab = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonGroup {
+    #[new]
+    fn new(label: String, items: AzRibbonItemVec) -> AzRibbonGroup {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzRibbonGroup_new(
+            mem::transmute(label),
+            mem::transmute(items),
+        )) }
+    }
+    fn set_icon(&mut self, icon: AzImageRef) -> () {
+        unsafe { mem::transmute(crate::AzRibbonGroup_setIcon(
+            mem::transmute(self),
+            mem::transmute(icon),
+        )) }
+    }
+    fn with_icon(&mut self, icon: AzImageRef) -> AzRibbonGroup {
+        unsafe { mem::transmute(crate::AzRibbonGroup_withIcon(
+            mem::transmute(self),
+            mem::transmute(icon),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonGroup {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonGroup = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonGroup = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonItem {
+    #[new]
+    fn new(kind: AzRibbonItemKindEnumWrapper, label: String) -> AzRibbonItem {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzRibbonItem_new(
+            mem::transmute(kind),
+            mem::transmute(label),
+        )) }
+    }
+    fn set_icon(&mut self, icon: AzImageRef) -> () {
+        unsafe { mem::transmute(crate::AzRibbonItem_setIcon(
+            mem::transmute(self),
+            mem::transmute(icon),
+        )) }
+    }
+    fn with_icon(&mut self, icon: AzImageRef) -> AzRibbonItem {
+        unsafe { mem::transmute(crate::AzRibbonItem_withIcon(
+            mem::transmute(self),
+            mem::transmute(icon),
+        )) }
+    }
+    fn set_tooltip(&mut self, tooltip: String) -> () {
+        let tooltip = pystring_to_azstring(&tooltip);
+        unsafe { mem::transmute(crate::AzRibbonItem_setTooltip(
+            mem::transmute(self),
+            mem::transmute(tooltip),
+        )) }
+    }
+    fn with_tooltip(&mut self, tooltip: String) -> AzRibbonItem {
+        let tooltip = pystring_to_azstring(&tooltip);
+        unsafe { mem::transmute(crate::AzRibbonItem_withTooltip(
+            mem::transmute(self),
+            mem::transmute(tooltip),
+        )) }
+    }
+    fn set_key_tip(&mut self, key_tip: String) -> () {
+        let key_tip = pystring_to_azstring(&key_tip);
+        unsafe { mem::transmute(crate::AzRibbonItem_setKeyTip(
+            mem::transmute(self),
+            mem::transmute(key_tip),
+        )) }
+    }
+    fn with_key_tip(&mut self, key_tip: String) -> AzRibbonItem {
+        let key_tip = pystring_to_azstring(&key_tip);
+        unsafe { mem::transmute(crate::AzRibbonItem_withKeyTip(
+            mem::transmute(self),
+            mem::transmute(key_tip),
+        )) }
+    }
+    fn set_enabled(&mut self, enabled: bool) -> () {
+        unsafe { mem::transmute(crate::AzRibbonItem_setEnabled(
+            mem::transmute(self),
+            mem::transmute(enabled),
+        )) }
+    }
+    fn with_enabled(&mut self, enabled: bool) -> AzRibbonItem {
+        unsafe { mem::transmute(crate::AzRibbonItem_withEnabled(
+            mem::transmute(self),
+            mem::transmute(enabled),
+        )) }
+    }
+    fn set_toggled(&mut self, toggled: bool) -> () {
+        unsafe { mem::transmute(crate::AzRibbonItem_setToggled(
+            mem::transmute(self),
+            mem::transmute(toggled),
+        )) }
+    }
+    fn with_toggled(&mut self, toggled: bool) -> AzRibbonItem {
+        unsafe { mem::transmute(crate::AzRibbonItem_withToggled(
+            mem::transmute(self),
+            mem::transmute(toggled),
+        )) }
+    }
+    fn set_choices(&mut self, choices: AzStringVec) -> () {
+        unsafe { mem::transmute(crate::AzRibbonItem_setChoices(
+            mem::transmute(self),
+            mem::transmute(choices),
+        )) }
+    }
+    fn with_choices(&mut self, choices: AzStringVec) -> AzRibbonItem {
+        unsafe { mem::transmute(crate::AzRibbonItem_withChoices(
+            mem::transmute(self),
+            mem::transmute(choices),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonItem {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItem = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItem = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonItemKindEnumWrapper {
+    #[classattr]
+    fn LargeButton() -> AzRibbonItemKindEnumWrapper { AzRibbonItemKindEnumWrapper { inner: AzRibbonItemKind::LargeButton } }
+    #[classattr]
+    fn SmallButton() -> AzRibbonItemKindEnumWrapper { AzRibbonItemKindEnumWrapper { inner: AzRibbonItemKind::SmallButton } }
+    #[classattr]
+    fn Toggle() -> AzRibbonItemKindEnumWrapper { AzRibbonItemKindEnumWrapper { inner: AzRibbonItemKind::Toggle } }
+    #[classattr]
+    fn DropDown() -> AzRibbonItemKindEnumWrapper { AzRibbonItemKindEnumWrapper { inner: AzRibbonItemKind::DropDown } }
+    #[classattr]
+    fn SplitButton() -> AzRibbonItemKindEnumWrapper { AzRibbonItemKindEnumWrapper { inner: AzRibbonItemKind::SplitButton } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonItemKindEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItemKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItemKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzRibbonItemKindEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzRibbonItemState {
+    #[new]
+    fn __new__(toggled: bool, choice: AzOptionUsizeEnumWrapper) -> Self {
+        Self {
+            toggled,
+            choice,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonItemState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItemState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItemState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonOnItemClick {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzRibbonOnItemClickCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonOnItemClick {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonOnItemClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonOnItemClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonOnItemClickCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonOnItemClickCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonOnItemClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonOnItemClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonOnTabClicked {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzRibbonOnTabClickedCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonOnTabClicked {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonOnTabClicked = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonOnTabClicked = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzRibbonOnTabClickedCallback {
     #[new]
@@ -23334,6 +28318,228 @@ impl PyObjectProtocol for AzTextInputValidEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTextArea {
+    #[new]
+    fn new() -> AzTextArea {
+        unsafe { mem::transmute(crate::AzTextArea_new()) }
+    }
+    fn set_text(&mut self, text: String) -> () {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextArea_setText(
+            mem::transmute(self),
+            mem::transmute(text),
+        )) }
+    }
+    fn with_text(&mut self, text: String) -> AzTextArea {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextArea_withText(
+            mem::transmute(self),
+            mem::transmute(text),
+        )) }
+    }
+    fn set_state(&mut self, state: AzTextAreaState) -> () {
+        unsafe { mem::transmute(crate::AzTextArea_setState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn with_state(&mut self, state: AzTextAreaState) -> AzTextArea {
+        unsafe { mem::transmute(crate::AzTextArea_withState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn swap_with_default(&mut self) -> AzTextArea {
+        unsafe { mem::transmute(crate::AzTextArea_swapWithDefault(
+            mem::transmute(self),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzTextArea_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextArea {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextArea = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextArea = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextAreaState {
+    #[new]
+    fn __new__(text: AzU32Vec, cursor: usize, selection_anchor: AzOptionUsizeEnumWrapper, preferred_x: AzOptionF32EnumWrapper, scroll_offset: f32, undo_stack: AzTextAreaEditVec, redo_stack: AzTextAreaEditVec, last_edit_time: AzOptionInstantEnumWrapper) -> Self {
+        Self {
+            text,
+            cursor,
+            selection_anchor,
+            preferred_x,
+            scroll_offset,
+            undo_stack,
+            redo_stack,
+            last_edit_time,
+        }
+    }
+
+    fn get_text(&self) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzTextAreaState_getText(
+            mem::transmute(self),
+        )) })
+    }
+    fn set_text(&mut self, text: String) -> () {
+        let text = pystring_to_azstring(&text);
+        unsafe { mem::transmute(crate::AzTextAreaState_setText(
+            mem::transmute(self),
+            mem::transmute(text),
+        )) }
+    }
+    fn get_selected_text(&self) -> String {
+        az_string_to_py_string(unsafe { mem::transmute(crate::AzTextAreaState_getSelectedText(
+            mem::transmute(self),
+        )) })
+    }
+    fn select_all(&mut self) -> () {
+        unsafe { mem::transmute(crate::AzTextAreaState_selectAll(
+            mem::transmute(self),
+        )) }
+    }
+    fn can_undo(&self) -> bool {
+        unsafe { mem::transmute(crate::AzTextAreaState_canUndo(
+            mem::transmute(self),
+        )) }
+    }
+    fn can_redo(&self) -> bool {
+        unsafe { mem::transmute(crate::AzTextAreaState_canRedo(
+            mem::transmute(self),
+        )) }
+    }
+    fn undo(&mut self) -> bool {
+        unsafe { mem::transmute(crate::AzTextAreaState_undo(
+            mem::transmute(self),
+        )) }
+    }
+    fn redo(&mut self) -> bool {
+        unsafe { mem::transmute(crate::AzTextAreaState_redo(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextAreaState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextAreaEdit {
+    #[new]
+    fn __new__(position: usize, removed: AzU32Vec, inserted: AzU32Vec, kind: AzTextAreaEditKindEnumWrapper, cursor_before: usize, selection_anchor_before: AzOptionUsizeEnumWrapper) -> Self {
+        Self {
+            position,
+            removed,
+            inserted,
+            kind,
+            cursor_before,
+            selection_anchor_before,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextAreaEdit {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaEdit = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextAreaEditKindEnumWrapper {
+    #[classattr]
+    fn Insert() -> AzTextAreaEditKindEnumWrapper { AzTextAreaEditKindEnumWrapper { inner: AzTextAreaEditKind::Insert } }
+    #[classattr]
+    fn Delete() -> AzTextAreaEditKindEnumWrapper { AzTextAreaEditKindEnumWrapper { inner: AzTextAreaEditKind::Delete } }
+    #[classattr]
+    fn Replace() -> AzTextAreaEditKindEnumWrapper { AzTextAreaEditKindEnumWrapper { inner: AzTextAreaEditKind::Replace } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextAreaEditKindEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaEditKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaEditKind = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTextAreaEditKindEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTextAreaOnTextChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextAreaOnTextChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaOnTextChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaOnTextChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextAreaOnTextChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTextAreaOnTextChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextAreaOnTextChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaOnTextChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaOnTextChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNumberInput {
     #[new]
@@ -23528,6 +28734,10 @@ impl AzProgressBar {
             mem::transmute(percent_done),
         )) }
     }
+    #[staticmethod]
+    fn indeterminate() -> AzProgressBar {
+        unsafe { mem::transmute(crate::AzProgressBar_indeterminate()) }
+    }
     fn set_height(&mut self, height: AzPixelValue) -> () {
         unsafe { mem::transmute(crate::AzProgressBar_setHeight(
             mem::transmute(self),
@@ -23569,6 +28779,56 @@ impl AzProgressBar {
             mem::transmute(self),
         )) }
     }
+    fn set_mode(&mut self, mode: AzProgressBarModeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzProgressBar_setMode(
+            mem::transmute(self),
+            mem::transmute(mode),
+        )) }
+    }
+    fn with_mode(&mut self, mode: AzProgressBarModeEnumWrapper) -> AzProgressBar {
+        unsafe { mem::transmute(crate::AzProgressBar_withMode(
+            mem::transmute(self),
+            mem::transmute(mode),
+        )) }
+    }
+    fn set_style(&mut self, style: AzProgressBarStyleEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzProgressBar_setStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn with_style(&mut self, style: AzProgressBarStyleEnumWrapper) -> AzProgressBar {
+        unsafe { mem::transmute(crate::AzProgressBar_withStyle(
+            mem::transmute(self),
+            mem::transmute(style),
+        )) }
+    }
+    fn set_display_percentage(&mut self, display_percentage: bool) -> () {
+        unsafe { mem::transmute(crate::AzProgressBar_setDisplayPercentage(
+            mem::transmute(self),
+            mem::transmute(display_percentage),
+        )) }
+    }
+    fn with_display_percentage(&mut self, display_percentage: bool) -> AzProgressBar {
+        unsafe { mem::transmute(crate::AzProgressBar_withDisplayPercentage(
+            mem::transmute(self),
+            mem::transmute(display_percentage),
+        )) }
+    }
+    fn set_text_format(&mut self, text_format: String) -> () {
+        let text_format = pystring_to_azstring(&text_format);
+        unsafe { mem::transmute(crate::AzProgressBar_setTextFormat(
+            mem::transmute(self),
+            mem::transmute(text_format),
+        )) }
+    }
+    fn with_text_format(&mut self, text_format: String) -> AzProgressBar {
+        let text_format = pystring_to_azstring(&text_format);
+        unsafe { mem::transmute(crate::AzProgressBar_withTextFormat(
+            mem::transmute(self),
+            mem::transmute(text_format),
+        )) }
+    }
 }
 
 #[pyproto]
@@ -23584,10 +28844,11 @@ impl PyObjectProtocol for AzProgressBar {
 #[pymethods]
 impl AzProgressBarState {
     #[new]
-    fn __new__(percent_done: f32, display_percentage: bool) -> Self {
+    fn __new__(percent_done: f32, display_percentage: bool, mode: AzProgressBarModeEnumWrapper) -> Self {
         Self {
             percent_done,
             display_percentage,
+            mode,
         }
     }
 
@@ -23603,6 +28864,133 @@ impl PyObjectProtocol for AzProgressBarState {
     }
 }
 
+#[pymethods]
+impl AzProgressBarModeEnumWrapper {
+    #[classattr]
+    fn Determinate() -> AzProgressBarModeEnumWrapper { AzProgressBarModeEnumWrapper { inner: AzProgressBarMode::Determinate } }
+    #[classattr]
+    fn Indeterminate() -> AzProgressBarModeEnumWrapper { AzProgressBarModeEnumWrapper { inner: AzProgressBarMode::Indeterminate } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzProgressBarModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::progressbar::ProgressBarMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::progressbar::ProgressBarMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzProgressBarModeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzProgressBarStyleEnumWrapper {
+    #[classattr]
+    fn Solid() -> AzProgressBarStyleEnumWrapper { AzProgressBarStyleEnumWrapper { inner: AzProgressBarStyle::Solid } }
+    #[classattr]
+    fn Stripes() -> AzProgressBarStyleEnumWrapper { AzProgressBarStyleEnumWrapper { inner: AzProgressBarStyle::Stripes } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzProgressBarStyleEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::progressbar::ProgressBarStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::progressbar::ProgressBarStyle = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzProgressBarStyleEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzSpinner {
+    #[new]
+    fn new() -> AzSpinner {
+        unsafe { mem::transmute(crate::AzSpinner_new()) }
+    }
+    fn set_size(&mut self, size: AzPixelValue) -> () {
+        unsafe { mem::transmute(crate::AzSpinner_setSize(
+            mem::transmute(self),
+            mem::transmute(size),
+        )) }
+    }
+    fn with_size(&mut self, size: AzPixelValue) -> AzSpinner {
+        unsafe { mem::transmute(crate::AzSpinner_withSize(
+            mem::transmute(self),
+            mem::transmute(size),
+        )) }
+    }
+    fn set_thickness(&mut self, thickness: AzPixelValue) -> () {
+        unsafe { mem::transmute(crate::AzSpinner_setThickness(
+            mem::transmute(self),
+            mem::transmute(thickness),
+        )) }
+    }
+    fn with_thickness(&mut self, thickness: AzPixelValue) -> AzSpinner {
+        unsafe { mem::transmute(crate::AzSpinner_withThickness(
+            mem::transmute(self),
+            mem::transmute(thickness),
+        )) }
+    }
+    fn set_color(&mut self, color: AzColorU) -> () {
+        unsafe { mem::transmute(crate::AzSpinner_setColor(
+            mem::transmute(self),
+            mem::transmute(color),
+        )) }
+    }
+    fn with_color(&mut self, color: AzColorU) -> AzSpinner {
+        unsafe { mem::transmute(crate::AzSpinner_withColor(
+            mem::transmute(self),
+            mem::transmute(color),
+        )) }
+    }
+    fn set_track_color(&mut self, track_color: AzColorU) -> () {
+        unsafe { mem::transmute(crate::AzSpinner_setTrackColor(
+            mem::transmute(self),
+            mem::transmute(track_color),
+        )) }
+    }
+    fn with_track_color(&mut self, track_color: AzColorU) -> AzSpinner {
+        unsafe { mem::transmute(crate::AzSpinner_withTrackColor(
+            mem::transmute(self),
+            mem::transmute(track_color),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzSpinner_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSpinner {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spinner::Spinner = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::spinner::Spinner = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzTabHeader {
     #[new]
@@ -23740,6 +29128,132 @@ impl PyObjectProtocol for AzTabOnClickCallback {
     }
 }
 
+#[pymethods]
+impl AzTabOnClose {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTabOnCloseCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabOnClose {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnClose = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnClose = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabOnCloseCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabOnCloseCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnCloseCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnCloseCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabOnReorder {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTabOnReorderCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabOnReorder {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnReorder = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnReorder = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabOnReorderCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabOnReorderCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnReorderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnReorderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabOnContextMenu {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTabOnContextMenuCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabOnContextMenu {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnContextMenu = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnContextMenu = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTabOnContextMenuCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTabOnContextMenuCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnContextMenuCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::TabOnContextMenuCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzFrame {
     #[new]
@@ -23779,6 +29293,491 @@ impl PyObjectProtocol for AzFrame {
     }
 }
 
+#[pymethods]
+impl AzSplitter {
+    #[new]
+    fn new(orientation: AzSplitterOrientationEnumWrapper, panes: AzSplitterPaneVec) -> AzSplitter {
+        unsafe { mem::transmute(crate::AzSplitter_new(
+            mem::transmute(orientation),
+            mem::transmute(panes),
+        )) }
+    }
+    fn set_state(&mut self, state: AzSplitterState) -> () {
+        unsafe { mem::transmute(crate::AzSplitter_setState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn with_state(&mut self, state: AzSplitterState) -> AzSplitter {
+        unsafe { mem::transmute(crate::AzSplitter_withState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzSplitter_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitter {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::Splitter = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::Splitter = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterOrientationEnumWrapper {
+    #[classattr]
+    fn Horizontal() -> AzSplitterOrientationEnumWrapper { AzSplitterOrientationEnumWrapper { inner: AzSplitterOrientation::Horizontal } }
+    #[classattr]
+    fn Vertical() -> AzSplitterOrientationEnumWrapper { AzSplitterOrientationEnumWrapper { inner: AzSplitterOrientation::Vertical } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterOrientationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzSplitterOrientationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzSplitterPane {
+    #[new]
+    fn new(content: AzDom) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_new(
+            mem::transmute(content),
+        )) }
+    }
+    fn set_size(&mut self, size: f32) -> () {
+        unsafe { mem::transmute(crate::AzSplitterPane_setSize(
+            mem::transmute(self),
+            mem::transmute(size),
+        )) }
+    }
+    fn with_size(&mut self, size: f32) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_withSize(
+            mem::transmute(self),
+            mem::transmute(size),
+        )) }
+    }
+    fn set_min_size(&mut self, min_size: f32) -> () {
+        unsafe { mem::transmute(crate::AzSplitterPane_setMinSize(
+            mem::transmute(self),
+            mem::transmute(min_size),
+        )) }
+    }
+    fn with_min_size(&mut self, min_size: f32) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_withMinSize(
+            mem::transmute(self),
+            mem::transmute(min_size),
+        )) }
+    }
+    fn set_max_size(&mut self, max_size: f32) -> () {
+        unsafe { mem::transmute(crate::AzSplitterPane_setMaxSize(
+            mem::transmute(self),
+            mem::transmute(max_size),
+        )) }
+    }
+    fn with_max_size(&mut self, max_size: f32) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_withMaxSize(
+            mem::transmute(self),
+            mem::transmute(max_size),
+        )) }
+    }
+    fn set_collapsible(&mut self, collapsible: bool) -> () {
+        unsafe { mem::transmute(crate::AzSplitterPane_setCollapsible(
+            mem::transmute(self),
+            mem::transmute(collapsible),
+        )) }
+    }
+    fn with_collapsible(&mut self, collapsible: bool) -> AzSplitterPane {
+        unsafe { mem::transmute(crate::AzSplitterPane_withCollapsible(
+            mem::transmute(self),
+            mem::transmute(collapsible),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPane {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPane = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPane = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterState {
+    #[new]
+    fn __new__(panes: AzSplitterPaneStateVec) -> Self {
+        Self {
+            panes,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneState {
+    #[new]
+    fn __new__(size: f32, collapsed: bool) -> Self {
+        Self {
+            size,
+            collapsed,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterOnResize {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzSplitterOnResizeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterOnResize {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterOnResizeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterOnResizeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterOnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSlider {
+    #[new]
+    fn new(orientation: AzSliderOrientationEnumWrapper, value: f32, min: f32, max: f32) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_new(
+            mem::transmute(orientation),
+            mem::transmute(value),
+            mem::transmute(min),
+            mem::transmute(max),
+        )) }
+    }
+    fn set_step(&mut self, step: f32) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn with_step(&mut self, step: f32) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn set_tick_interval(&mut self, tick_interval: f32) -> () {
+        unsafe { mem::transmute(crate::AzSlider_setTickInterval(
+            mem::transmute(self),
+            mem::transmute(tick_interval),
+        )) }
+    }
+    fn with_tick_interval(&mut self, tick_interval: f32) -> AzSlider {
+        unsafe { mem::transmute(crate::AzSlider_withTickInterval(
+            mem::transmute(self),
+            mem::transmute(tick_interval),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzSlider_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSlider {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::Slider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::Slider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRangeSlider {
+    #[new]
+    fn new(orientation: AzSliderOrientationEnumWrapper, low: f32, high: f32, min: f32, max: f32) -> AzRangeSlider {
+        unsafe { mem::transmute(crate::AzRangeSlider_new(
+            mem::transmute(orientation),
+            mem::transmute(low),
+            mem::transmute(high),
+            mem::transmute(min),
+            mem::transmute(max),
+        )) }
+    }
+    fn set_step(&mut self, step: f32) -> () {
+        unsafe { mem::transmute(crate::AzRangeSlider_setStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn with_step(&mut self, step: f32) -> AzRangeSlider {
+        unsafe { mem::transmute(crate::AzRangeSlider_withStep(
+            mem::transmute(self),
+            mem::transmute(step),
+        )) }
+    }
+    fn set_tick_interval(&mut self, tick_interval: f32) -> () {
+        unsafe { mem::transmute(crate::AzRangeSlider_setTickInterval(
+            mem::transmute(self),
+            mem::transmute(tick_interval),
+        )) }
+    }
+    fn with_tick_interval(&mut self, tick_interval: f32) -> AzRangeSlider {
+        unsafe { mem::transmute(crate::AzRangeSlider_withTickInterval(
+            mem::transmute(self),
+            mem::transmute(tick_interval),
+        )) }
+    }
+    fn dom(&mut self) -> AzDom {
+        unsafe { mem::transmute(crate::AzRangeSlider_dom(
+            mem::transmute(self),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRangeSlider {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::RangeSlider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::RangeSlider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderOrientationEnumWrapper {
+    #[classattr]
+    fn Horizontal() -> AzSliderOrientationEnumWrapper { AzSliderOrientationEnumWrapper { inner: AzSliderOrientation::Horizontal } }
+    #[classattr]
+    fn Vertical() -> AzSliderOrientationEnumWrapper { AzSliderOrientationEnumWrapper { inner: AzSliderOrientation::Vertical } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderOrientationEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOrientation = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzSliderOrientationEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzSliderState {
+    #[new]
+    fn __new__(value: f32, min: f32, max: f32, step: f32) -> Self {
+        Self {
+            value,
+            min,
+            max,
+            step,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRangeSliderState {
+    #[new]
+    fn __new__(low: f32, high: f32, min: f32, max: f32, step: f32) -> Self {
+        Self {
+            low,
+            high,
+            min,
+            max,
+            step,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRangeSliderState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::RangeSliderState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::RangeSliderState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderOnValueChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzSliderOnValueChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderOnValueChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSliderOnValueChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSliderOnValueChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::SliderOnValueChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRangeSliderOnValueChange {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzRangeSliderOnValueChangeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRangeSliderOnValueChange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::RangeSliderOnValueChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::RangeSliderOnValueChange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRangeSliderOnValueChangeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRangeSliderOnValueChangeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::RangeSliderOnValueChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::RangeSliderOnValueChangeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzNodeGraph {
     #[new]
@@ -24631,6 +30630,56 @@ impl AzListView {
             mem::transmute(rows),
         )) }
     }
+    fn set_sorted_by(&mut self, sorted_by: AzOptionUsizeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzListView_setSortedBy(
+            mem::transmute(self),
+            mem::transmute(sorted_by),
+        )) }
+    }
+    fn with_sorted_by(&mut self, sorted_by: AzOptionUsizeEnumWrapper) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withSortedBy(
+            mem::transmute(self),
+            mem::transmute(sorted_by),
+        )) }
+    }
+    fn set_scroll_offset(&mut self, scroll_offset: AzPixelValueNoPercent) -> () {
+        unsafe { mem::transmute(crate::AzListView_setScrollOffset(
+            mem::transmute(self),
+            mem::transmute(scroll_offset),
+        )) }
+    }
+    fn with_scroll_offset(&mut self, scroll_offset: AzPixelValueNoPercent) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withScrollOffset(
+            mem::transmute(self),
+            mem::transmute(scroll_offset),
+        )) }
+    }
+    fn set_row_height(&mut self, row_height: AzPixelValueNoPercent) -> () {
+        unsafe { mem::transmute(crate::AzListView_setRowHeight(
+            mem::transmute(self),
+            mem::transmute(row_height),
+        )) }
+    }
+    fn with_row_height(&mut self, row_height: AzPixelValueNoPercent) -> AzListView {
+        unsafe { mem::transmute(crate::AzListView_withRowHeight(
+            mem::transmute(self),
+            mem::transmute(row_height),
+        )) }
+    }
+    fn set_column_widths(&mut self, column_widths: Vec<f32>) -> () {
+        let column_widths = pyvecf32_to_vecf32(&column_widths);
+        unsafe { mem::transmute(crate::AzListView_setColumnWidths(
+            mem::transmute(self),
+            mem::transmute(column_widths),
+        )) }
+    }
+    fn with_column_widths(&mut self, column_widths: Vec<f32>) -> AzListView {
+        let column_widths = pyvecf32_to_vecf32(&column_widths);
+        unsafe { mem::transmute(crate::AzListView_withColumnWidths(
+            mem::transmute(self),
+            mem::transmute(column_widths),
+        )) }
+    }
     fn dom(&mut self) -> AzDom {
         unsafe { mem::transmute(crate::AzListView_dom(
             mem::transmute(self),
@@ -24673,7 +30722,7 @@ impl PyObjectProtocol for AzListViewRow {
 #[pymethods]
 impl AzListViewState {
     #[new]
-    fn __new__(columns: AzStringVec, sorted_by: AzOptionUsizeEnumWrapper, current_row_count: usize, scroll_offset: AzPixelValueNoPercent, current_scroll_position: AzLogicalPosition, current_content_height: AzLogicalSize) -> Self {
+    fn __new__(columns: AzStringVec, sorted_by: AzOptionUsizeEnumWrapper, current_row_count: usize, scroll_offset: AzPixelValueNoPercent, current_scroll_position: AzLogicalPosition, current_content_height: AzLogicalSize, row_count: usize, visible_rows: AzListViewRowRange, column_widths: AzF32Vec) -> Self {
         Self {
             columns,
             sorted_by,
@@ -24681,6 +30730,9 @@ impl AzListViewState {
             scroll_offset,
             current_scroll_position,
             current_content_height,
+            row_count,
+            visible_rows,
+            column_widths,
         }
     }
 
@@ -24822,13 +30874,154 @@ impl PyObjectProtocol for AzListViewOnRowClick {
     }
 }
 
+#[pymethods]
+impl AzListViewRowRange {
+    #[new]
+    fn __new__(start: usize, end: usize) -> Self {
+        Self {
+            start,
+            end,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewRowRange {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowRange = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnColumnResizeCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnColumnResizeCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnResizeCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewOnColumnResize {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzListViewOnColumnResizeCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewOnColumnResize {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewOnColumnResize = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewRowProviderCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewRowProviderCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowProviderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowProviderCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzListViewRowProvider {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzListViewRowProviderCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzListViewRowProvider {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowProvider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::ListViewRowProvider = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzTreeView {
     #[new]
-    fn new(root: String) -> AzTreeView {
-        let root = pystring_to_azstring(&root);
+    fn new(nodes: AzTreeViewNodeVec) -> AzTreeView {
         unsafe { mem::transmute(crate::AzTreeView_new(
-            mem::transmute(root),
+            mem::transmute(nodes),
+        )) }
+    }
+    fn set_nodes(&mut self, nodes: AzTreeViewNodeVec) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setNodes(
+            mem::transmute(self),
+            mem::transmute(nodes),
+        )) }
+    }
+    fn with_nodes(&mut self, nodes: AzTreeViewNodeVec) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_withNodes(
+            mem::transmute(self),
+            mem::transmute(nodes),
+        )) }
+    }
+    fn set_state(&mut self, state: AzTreeViewState) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn with_state(&mut self, state: AzTreeViewState) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_withState(
+            mem::transmute(self),
+            mem::transmute(state),
+        )) }
+    }
+    fn set_selection_mode(&mut self, selection_mode: AzTreeViewSelectionModeEnumWrapper) -> () {
+        unsafe { mem::transmute(crate::AzTreeView_setSelectionMode(
+            mem::transmute(self),
+            mem::transmute(selection_mode),
+        )) }
+    }
+    fn with_selection_mode(&mut self, selection_mode: AzTreeViewSelectionModeEnumWrapper) -> AzTreeView {
+        unsafe { mem::transmute(crate::AzTreeView_withSelectionMode(
+            mem::transmute(self),
+            mem::transmute(selection_mode),
         )) }
     }
     fn dom(&mut self) -> AzDom {
@@ -24848,6 +31041,271 @@ impl PyObjectProtocol for AzTreeView {
     }
 }
 
+#[pymethods]
+impl AzTreeViewNodeId {
+    #[new]
+    fn __new__(inner: u64) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeId {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeId = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewNode {
+    #[new]
+    fn new(id: AzTreeViewNodeId, parent: AzOptionTreeViewNodeIdEnumWrapper, label: String) -> AzTreeViewNode {
+        let label = pystring_to_azstring(&label);
+        unsafe { mem::transmute(crate::AzTreeViewNode_new(
+            mem::transmute(id),
+            mem::transmute(parent),
+            mem::transmute(label),
+        )) }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNode {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNode = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNode = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewSelectionModeEnumWrapper {
+    #[classattr]
+    fn Single() -> AzTreeViewSelectionModeEnumWrapper { AzTreeViewSelectionModeEnumWrapper { inner: AzTreeViewSelectionMode::Single } }
+    #[classattr]
+    fn Multiple() -> AzTreeViewSelectionModeEnumWrapper { AzTreeViewSelectionModeEnumWrapper { inner: AzTreeViewSelectionMode::Multiple } }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewSelectionModeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewSelectionMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewSelectionMode = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __richcmp__(&self, other: AzTreeViewSelectionModeEnumWrapper, op: pyo3::class::basic::CompareOp) -> PyResult<bool> {
+        match op {
+            pyo3::class::basic::CompareOp::Lt => { Ok((self.clone().inner as usize) <  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Le => { Ok((self.clone().inner as usize) <= (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Eq => { Ok((self.clone().inner as usize) == (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ne => { Ok((self.clone().inner as usize) != (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Gt => { Ok((self.clone().inner as usize) >  (other.clone().inner as usize)) }
+            pyo3::class::basic::CompareOp::Ge => { Ok((self.clone().inner as usize) >= (other.clone().inner as usize)) }
+        }
+    }
+}
+
+#[pymethods]
+impl AzTreeViewState {
+    #[new]
+    fn __new__(expanded: AzTreeViewNodeIdVec, selected: AzTreeViewNodeIdVec, focused: AzOptionTreeViewNodeIdEnumWrapper, selection_anchor: AzOptionTreeViewNodeIdEnumWrapper, last_click_time: AzOptionInstantEnumWrapper) -> Self {
+        Self {
+            expanded,
+            selected,
+            focused,
+            selection_anchor,
+            last_click_time,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewState {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewState = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnExpandCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnExpandCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnExpandCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnExpandCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnExpand {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnExpandCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnExpand {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnExpand = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnExpand = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnSelectCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnSelectCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelectCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelectCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnSelect {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnSelectCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnSelect {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnSelect = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnDoubleClickCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnDoubleClickCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnDoubleClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnDoubleClickCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnDoubleClick {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnDoubleClickCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnDoubleClick {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnDoubleClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnDoubleClick = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnContextMenuCallback {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnContextMenuCallback {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnContextMenuCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnContextMenuCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewOnContextMenu {
+    #[new]
+    fn __new__(data: AzRefAny, callback: AzTreeViewOnContextMenuCallback) -> Self {
+        Self {
+            data,
+            callback,
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewOnContextMenu {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnContextMenu = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewOnContextMenu = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzDropDown {
     #[new]
@@ -24856,6 +31314,35 @@ impl AzDropDown {
             mem::transmute(choices),
         )) }
     }
+    fn set_selected(&mut self, selected: usize) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setSelected(
+            mem::transmute(self),
+            mem::transmute(selected),
+        )) }
+    }
+    fn with_selected(&mut self, selected: usize) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withSelected(
+            mem::transmute(self),
+            mem::transmute(selected),
+        )) }
+    }
+    fn set_disabled_choices(&mut self, disabled_choices: AzU32Vec) -> () {
+        unsafe { mem::transmute(crate::AzDropDown_setDisabledChoices(
+            mem::transmute(self),
+            mem::transmute(disabled_choices),
+        )) }
+    }
+    fn with_disabled_choices(&mut self, disabled_choices: AzU32Vec) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_withDisabledChoices(
+            mem::transmute(self),
+            mem::transmute(disabled_choices),
+        )) }
+    }
+    fn swap_with_default(&mut self) -> AzDropDown {
+        unsafe { mem::transmute(crate::AzDropDown_swapWithDefault(
+            mem::transmute(self),
+        )) }
+    }
     fn dom(&mut self) -> AzDom {
         unsafe { mem::transmute(crate::AzDropDown_dom(
             mem::transmute(self),
@@ -33165,6 +39652,46 @@ impl PyObjectProtocol for AzGetSystemTimeFn {
     }
 }
 
+#[pymethods]
+impl AzGetClipboardFn {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGetClipboardFn {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::GetClipboardCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::GetClipboardCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSetClipboardFn {
+    #[new]
+    fn __new__() -> Self {
+        Self {
+        }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSetClipboardFn {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::SetClipboardCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::task::SetClipboardCallback = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCheckThreadFinishedFn {
 }
@@ -33419,6 +39946,206 @@ impl PyObjectProtocol for AzListViewRowVec {
     }
 }
 
+#[pymethods]
+impl AzTreeViewNodeVec {
+    /// Creates a new `TreeViewNodeVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTreeViewNode>) -> Self {
+        let m: crate::widgets::tree_view::TreeViewNodeVec = crate::widgets::tree_view::TreeViewNodeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TreeViewNode as a Python array
+    fn array(&self) -> Vec<AzTreeViewNode> {
+        let m: &crate::widgets::tree_view::TreeViewNodeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewNodeIdVec {
+    /// Creates a new `TreeViewNodeIdVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTreeViewNodeId>) -> Self {
+        let m: crate::widgets::tree_view::TreeViewNodeIdVec = crate::widgets::tree_view::TreeViewNodeIdVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TreeViewNodeId as a Python array
+    fn array(&self) -> Vec<AzTreeViewNodeId> {
+        let m: &crate::widgets::tree_view::TreeViewNodeIdVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeIdVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeIdVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeIdVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonTabVec {
+    /// Creates a new `RibbonTabVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzRibbonTab>) -> Self {
+        let m: crate::widgets::ribbon::RibbonTabVec = crate::widgets::ribbon::RibbonTabVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the RibbonTab as a Python array
+    fn array(&self) -> Vec<AzRibbonTab> {
+        let m: &crate::widgets::ribbon::RibbonTabVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonTabVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonTabVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonTabVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonGroupVec {
+    /// Creates a new `RibbonGroupVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzRibbonGroup>) -> Self {
+        let m: crate::widgets::ribbon::RibbonGroupVec = crate::widgets::ribbon::RibbonGroupVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the RibbonGroup as a Python array
+    fn array(&self) -> Vec<AzRibbonGroup> {
+        let m: &crate::widgets::ribbon::RibbonGroupVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonGroupVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonGroupVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonGroupVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonItemVec {
+    /// Creates a new `RibbonItemVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzRibbonItem>) -> Self {
+        let m: crate::widgets::ribbon::RibbonItemVec = crate::widgets::ribbon::RibbonItemVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the RibbonItem as a Python array
+    fn array(&self) -> Vec<AzRibbonItem> {
+        let m: &crate::widgets::ribbon::RibbonItemVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonItemVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItemVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItemVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneVec {
+    /// Creates a new `SplitterPaneVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzSplitterPane>) -> Self {
+        let m: crate::widgets::splitter::SplitterPaneVec = crate::widgets::splitter::SplitterPaneVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the SplitterPane as a Python array
+    fn array(&self) -> Vec<AzSplitterPane> {
+        let m: &crate::widgets::splitter::SplitterPaneVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneStateVec {
+    /// Creates a new `SplitterPaneStateVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzSplitterPaneState>) -> Self {
+        let m: crate::widgets::splitter::SplitterPaneStateVec = crate::widgets::splitter::SplitterPaneStateVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the SplitterPaneState as a Python array
+    fn array(&self) -> Vec<AzSplitterPaneState> {
+        let m: &crate::widgets::splitter::SplitterPaneStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneStateVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneStateVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneStateVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextAreaEditVec {
+    /// Creates a new `TextAreaEditVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzTextAreaEdit>) -> Self {
+        let m: crate::widgets::text_area::TextAreaEditVec = crate::widgets::text_area::TextAreaEditVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the TextAreaEdit as a Python array
+    fn array(&self) -> Vec<AzTextAreaEdit> {
+        let m: &crate::widgets::text_area::TextAreaEditVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextAreaEditVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaEditVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaEditVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleFilterVec {
     /// Creates a new `StyleFilterEnumWrapperVec` from a Python array
@@ -33719,6 +40446,31 @@ impl PyObjectProtocol for AzAccessibilityStateVec {
     }
 }
 
+#[pymethods]
+impl AzAccessibilityActionVec {
+    /// Creates a new `AccessibilityActionEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzAccessibilityActionEnumWrapper>) -> Self {
+        let m: azul_impl::dom::AccessibilityActionVec = azul_impl::dom::AccessibilityActionVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the AccessibilityActionEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzAccessibilityActionEnumWrapper> {
+        let m: &azul_impl::dom::AccessibilityActionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAccessibilityActionVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::AccessibilityActionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::AccessibilityActionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemVec {
     /// Creates a new `MenuItemEnumWrapperVec` from a Python array
@@ -34199,6 +40951,231 @@ impl PyObjectProtocol for AzStyleTransformVec {
     }
 }
 
+#[pymethods]
+impl AzStyleTransitionPropertyVec {
+    /// Creates a new `StyleTransitionPropertyEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleTransitionPropertyEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleTransitionPropertyVec = azul_impl::css::StyleTransitionPropertyVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleTransitionPropertyEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleTransitionPropertyEnumWrapper> {
+        let m: &azul_impl::css::StyleTransitionPropertyVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTimeVec {
+    /// Creates a new `StyleTimeVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleTime>) -> Self {
+        let m: azul_impl::css::StyleTimeVec = azul_impl::css::StyleTimeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleTime as a Python array
+    fn array(&self) -> Vec<AzStyleTime> {
+        let m: &azul_impl::css::StyleTimeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTimeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTimingFunctionVec {
+    /// Creates a new `StyleTimingFunctionEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleTimingFunctionEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleTimingFunctionVec = azul_impl::css::StyleTimingFunctionVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleTimingFunctionEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleTimingFunctionEnumWrapper> {
+        let m: &azul_impl::css::StyleTimingFunctionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTimingFunctionVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimingFunctionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimingFunctionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationNameVec {
+    /// Creates a new `StyleAnimationNameEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationNameEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleAnimationNameVec = azul_impl::css::StyleAnimationNameVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationNameEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationNameEnumWrapper> {
+        let m: &azul_impl::css::StyleAnimationNameVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationNameVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationIterationCountVec {
+    /// Creates a new `StyleAnimationIterationCountEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationIterationCountEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleAnimationIterationCountVec = azul_impl::css::StyleAnimationIterationCountVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationIterationCountEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationIterationCountEnumWrapper> {
+        let m: &azul_impl::css::StyleAnimationIterationCountVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationIterationCountVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDirectionVec {
+    /// Creates a new `StyleAnimationDirectionEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationDirectionEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleAnimationDirectionVec = azul_impl::css::StyleAnimationDirectionVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationDirectionEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationDirectionEnumWrapper> {
+        let m: &azul_impl::css::StyleAnimationDirectionVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDirectionVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationFillModeVec {
+    /// Creates a new `StyleAnimationFillModeEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationFillModeEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleAnimationFillModeVec = azul_impl::css::StyleAnimationFillModeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationFillModeEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationFillModeEnumWrapper> {
+        let m: &azul_impl::css::StyleAnimationFillModeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationFillModeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationPlayStateVec {
+    /// Creates a new `StyleAnimationPlayStateEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzStyleAnimationPlayStateEnumWrapper>) -> Self {
+        let m: azul_impl::css::StyleAnimationPlayStateVec = azul_impl::css::StyleAnimationPlayStateVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the StyleAnimationPlayStateEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzStyleAnimationPlayStateEnumWrapper> {
+        let m: &azul_impl::css::StyleAnimationPlayStateVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationPlayStateVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationPlayStateVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationPlayStateVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridTrackSizingVec {
+    /// Creates a new `GridTrackSizingVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzGridTrackSizing>) -> Self {
+        let m: azul_impl::css::GridTrackSizingVec = azul_impl::css::GridTrackSizingVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the GridTrackSizing as a Python array
+    fn array(&self) -> Vec<AzGridTrackSizing> {
+        let m: &azul_impl::css::GridTrackSizingVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackSizingVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssPropertyVec {
     /// Creates a new `CssPropertyEnumWrapperVec` from a Python array
@@ -34549,6 +41526,131 @@ impl PyObjectProtocol for AzStylesheetVec {
     }
 }
 
+#[pymethods]
+impl AzCssKeyframesVec {
+    /// Creates a new `CssKeyframesVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssKeyframes>) -> Self {
+        let m: azul_impl::css::CssKeyframesVec = azul_impl::css::CssKeyframesVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssKeyframes as a Python array
+    fn array(&self) -> Vec<AzCssKeyframes> {
+        let m: &azul_impl::css::CssKeyframesVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframesVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframesVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframesVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssKeyframeVec {
+    /// Creates a new `CssKeyframeVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssKeyframe>) -> Self {
+        let m: azul_impl::css::CssKeyframeVec = azul_impl::css::CssKeyframeVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssKeyframe as a Python array
+    fn array(&self) -> Vec<AzCssKeyframe> {
+        let m: &azul_impl::css::CssKeyframeVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframeVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframeVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaRuleVec {
+    /// Creates a new `CssMediaRuleVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssMediaRule>) -> Self {
+        let m: azul_impl::css::CssMediaRuleVec = azul_impl::css::CssMediaRuleVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssMediaRule as a Python array
+    fn array(&self) -> Vec<AzCssMediaRule> {
+        let m: &azul_impl::css::CssMediaRuleVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaRuleVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaRuleVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaRuleVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaQueryVec {
+    /// Creates a new `CssMediaQueryVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssMediaQuery>) -> Self {
+        let m: azul_impl::css::CssMediaQueryVec = azul_impl::css::CssMediaQueryVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssMediaQuery as a Python array
+    fn array(&self) -> Vec<AzCssMediaQuery> {
+        let m: &azul_impl::css::CssMediaQueryVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaQueryVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQueryVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQueryVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaFeatureVec {
+    /// Creates a new `CssMediaFeatureEnumWrapperVec` from a Python array
+    #[new]
+    fn __new__(input: Vec<AzCssMediaFeatureEnumWrapper>) -> Self {
+        let m: azul_impl::css::CssMediaFeatureVec = azul_impl::css::CssMediaFeatureVec::from_vec(unsafe { mem::transmute(input) }); unsafe { mem::transmute(m) }
+    }
+    
+    /// Returns the CssMediaFeatureEnumWrapper as a Python array
+    fn array(&self) -> Vec<AzCssMediaFeatureEnumWrapper> {
+        let m: &azul_impl::css::CssMediaFeatureVec = unsafe { mem::transmute(self) }; unsafe { mem::transmute(m.clone().into_library_owned_vec()) }
+    }
+
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaFeatureVec {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaFeatureVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaFeatureVec = unsafe { mem::transmute(self) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssRuleBlockVec {
     /// Creates a new `CssRuleBlockVec` from a Python array
@@ -35048,6 +42150,246 @@ impl PyObjectProtocol for AzListViewRowVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzTreeViewNodeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTreeViewNodeVecDestructorEnumWrapper { AzTreeViewNodeVecDestructorEnumWrapper { inner: AzTreeViewNodeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTreeViewNodeVecDestructorEnumWrapper { AzTreeViewNodeVecDestructorEnumWrapper { inner: AzTreeViewNodeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTreeViewNodeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTreeViewNodeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTreeViewNodeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTreeViewNodeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTreeViewNodeIdVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTreeViewNodeIdVecDestructorEnumWrapper { AzTreeViewNodeIdVecDestructorEnumWrapper { inner: AzTreeViewNodeIdVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTreeViewNodeIdVecDestructorEnumWrapper { AzTreeViewNodeIdVecDestructorEnumWrapper { inner: AzTreeViewNodeIdVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTreeViewNodeIdVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTreeViewNodeIdVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTreeViewNodeIdVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTreeViewNodeIdVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTreeViewNodeIdVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeIdVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::TreeViewNodeIdVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonTabVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzRibbonTabVecDestructorEnumWrapper { AzRibbonTabVecDestructorEnumWrapper { inner: AzRibbonTabVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzRibbonTabVecDestructorEnumWrapper { AzRibbonTabVecDestructorEnumWrapper { inner: AzRibbonTabVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzRibbonTabVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzRibbonTabVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzRibbonTabVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzRibbonTabVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonTabVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonTabVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonTabVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonGroupVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzRibbonGroupVecDestructorEnumWrapper { AzRibbonGroupVecDestructorEnumWrapper { inner: AzRibbonGroupVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzRibbonGroupVecDestructorEnumWrapper { AzRibbonGroupVecDestructorEnumWrapper { inner: AzRibbonGroupVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzRibbonGroupVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzRibbonGroupVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzRibbonGroupVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzRibbonGroupVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonGroupVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonGroupVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonGroupVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzRibbonItemVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzRibbonItemVecDestructorEnumWrapper { AzRibbonItemVecDestructorEnumWrapper { inner: AzRibbonItemVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzRibbonItemVecDestructorEnumWrapper { AzRibbonItemVecDestructorEnumWrapper { inner: AzRibbonItemVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzRibbonItemVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzRibbonItemVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzRibbonItemVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzRibbonItemVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzRibbonItemVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItemVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::RibbonItemVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzSplitterPaneVecDestructorEnumWrapper { AzSplitterPaneVecDestructorEnumWrapper { inner: AzSplitterPaneVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzSplitterPaneVecDestructorEnumWrapper { AzSplitterPaneVecDestructorEnumWrapper { inner: AzSplitterPaneVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzSplitterPaneVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzSplitterPaneVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzSplitterPaneVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzSplitterPaneVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzSplitterPaneStateVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzSplitterPaneStateVecDestructorEnumWrapper { AzSplitterPaneStateVecDestructorEnumWrapper { inner: AzSplitterPaneStateVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzSplitterPaneStateVecDestructorEnumWrapper { AzSplitterPaneStateVecDestructorEnumWrapper { inner: AzSplitterPaneStateVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzSplitterPaneStateVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzSplitterPaneStateVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzSplitterPaneStateVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzSplitterPaneStateVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzSplitterPaneStateVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneStateVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::SplitterPaneStateVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzTextAreaEditVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzTextAreaEditVecDestructorEnumWrapper { AzTextAreaEditVecDestructorEnumWrapper { inner: AzTextAreaEditVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzTextAreaEditVecDestructorEnumWrapper { AzTextAreaEditVecDestructorEnumWrapper { inner: AzTextAreaEditVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzTextAreaEditVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzTextAreaEditVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzTextAreaEditVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzTextAreaEditVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzTextAreaEditVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaEditVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::TextAreaEditVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzStyleFilterVecDestructorEnumWrapper {
     #[classattr]
@@ -35408,6 +42750,36 @@ impl PyObjectProtocol for AzAccessibilityStateVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzAccessibilityActionVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzAccessibilityActionVecDestructorEnumWrapper { AzAccessibilityActionVecDestructorEnumWrapper { inner: AzAccessibilityActionVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzAccessibilityActionVecDestructorEnumWrapper { AzAccessibilityActionVecDestructorEnumWrapper { inner: AzAccessibilityActionVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzAccessibilityActionVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzAccessibilityActionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzAccessibilityActionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzAccessibilityActionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzAccessibilityActionVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::AccessibilityActionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::dom::AccessibilityActionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzMenuItemVecDestructorEnumWrapper {
     #[classattr]
@@ -35948,6 +43320,276 @@ impl PyObjectProtocol for AzStyleTransformVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzStyleTransitionPropertyVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleTransitionPropertyVecDestructorEnumWrapper { AzStyleTransitionPropertyVecDestructorEnumWrapper { inner: AzStyleTransitionPropertyVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleTransitionPropertyVecDestructorEnumWrapper { AzStyleTransitionPropertyVecDestructorEnumWrapper { inner: AzStyleTransitionPropertyVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTransitionPropertyVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTransitionPropertyVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleTransitionPropertyVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTransitionPropertyVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTransitionPropertyVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTimeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleTimeVecDestructorEnumWrapper { AzStyleTimeVecDestructorEnumWrapper { inner: AzStyleTimeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleTimeVecDestructorEnumWrapper { AzStyleTimeVecDestructorEnumWrapper { inner: AzStyleTimeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTimeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTimeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleTimeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleTimeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTimeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleTimingFunctionVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleTimingFunctionVecDestructorEnumWrapper { AzStyleTimingFunctionVecDestructorEnumWrapper { inner: AzStyleTimingFunctionVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleTimingFunctionVecDestructorEnumWrapper { AzStyleTimingFunctionVecDestructorEnumWrapper { inner: AzStyleTimingFunctionVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleTimingFunctionVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleTimingFunctionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunctionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleTimingFunctionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleTimingFunctionVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimingFunctionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleTimingFunctionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationNameVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationNameVecDestructorEnumWrapper { AzStyleAnimationNameVecDestructorEnumWrapper { inner: AzStyleAnimationNameVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationNameVecDestructorEnumWrapper { AzStyleAnimationNameVecDestructorEnumWrapper { inner: AzStyleAnimationNameVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationNameVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationNameVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationNameVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationNameVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationNameVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationIterationCountVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationIterationCountVecDestructorEnumWrapper { AzStyleAnimationIterationCountVecDestructorEnumWrapper { inner: AzStyleAnimationIterationCountVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationIterationCountVecDestructorEnumWrapper { AzStyleAnimationIterationCountVecDestructorEnumWrapper { inner: AzStyleAnimationIterationCountVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationIterationCountVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationIterationCountVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationIterationCountVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationIterationCountVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationIterationCountVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationDirectionVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationDirectionVecDestructorEnumWrapper { AzStyleAnimationDirectionVecDestructorEnumWrapper { inner: AzStyleAnimationDirectionVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationDirectionVecDestructorEnumWrapper { AzStyleAnimationDirectionVecDestructorEnumWrapper { inner: AzStyleAnimationDirectionVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationDirectionVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationDirectionVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationDirectionVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationDirectionVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationDirectionVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationFillModeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationFillModeVecDestructorEnumWrapper { AzStyleAnimationFillModeVecDestructorEnumWrapper { inner: AzStyleAnimationFillModeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationFillModeVecDestructorEnumWrapper { AzStyleAnimationFillModeVecDestructorEnumWrapper { inner: AzStyleAnimationFillModeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationFillModeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationFillModeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationFillModeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationFillModeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationFillModeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzStyleAnimationPlayStateVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzStyleAnimationPlayStateVecDestructorEnumWrapper { AzStyleAnimationPlayStateVecDestructorEnumWrapper { inner: AzStyleAnimationPlayStateVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzStyleAnimationPlayStateVecDestructorEnumWrapper { AzStyleAnimationPlayStateVecDestructorEnumWrapper { inner: AzStyleAnimationPlayStateVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzStyleAnimationPlayStateVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzStyleAnimationPlayStateVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzStyleAnimationPlayStateVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzStyleAnimationPlayStateVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzStyleAnimationPlayStateVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationPlayStateVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::StyleAnimationPlayStateVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzGridTrackSizingVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzGridTrackSizingVecDestructorEnumWrapper { AzGridTrackSizingVecDestructorEnumWrapper { inner: AzGridTrackSizingVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzGridTrackSizingVecDestructorEnumWrapper { AzGridTrackSizingVecDestructorEnumWrapper { inner: AzGridTrackSizingVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzGridTrackSizingVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzGridTrackSizingVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzGridTrackSizingVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzGridTrackSizingVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::GridTrackSizingVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssPropertyVecDestructorEnumWrapper {
     #[classattr]
@@ -36368,6 +44010,156 @@ impl PyObjectProtocol for AzStylesheetVecDestructorEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzCssKeyframesVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssKeyframesVecDestructorEnumWrapper { AzCssKeyframesVecDestructorEnumWrapper { inner: AzCssKeyframesVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssKeyframesVecDestructorEnumWrapper { AzCssKeyframesVecDestructorEnumWrapper { inner: AzCssKeyframesVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssKeyframesVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssKeyframesVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssKeyframesVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssKeyframesVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframesVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframesVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframesVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssKeyframeVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssKeyframeVecDestructorEnumWrapper { AzCssKeyframeVecDestructorEnumWrapper { inner: AzCssKeyframeVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssKeyframeVecDestructorEnumWrapper { AzCssKeyframeVecDestructorEnumWrapper { inner: AzCssKeyframeVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssKeyframeVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssKeyframeVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssKeyframeVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssKeyframeVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssKeyframeVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssKeyframeVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaRuleVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssMediaRuleVecDestructorEnumWrapper { AzCssMediaRuleVecDestructorEnumWrapper { inner: AzCssMediaRuleVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssMediaRuleVecDestructorEnumWrapper { AzCssMediaRuleVecDestructorEnumWrapper { inner: AzCssMediaRuleVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssMediaRuleVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssMediaRuleVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssMediaRuleVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssMediaRuleVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaRuleVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaRuleVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaRuleVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaQueryVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssMediaQueryVecDestructorEnumWrapper { AzCssMediaQueryVecDestructorEnumWrapper { inner: AzCssMediaQueryVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssMediaQueryVecDestructorEnumWrapper { AzCssMediaQueryVecDestructorEnumWrapper { inner: AzCssMediaQueryVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssMediaQueryVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssMediaQueryVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssMediaQueryVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssMediaQueryVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaQueryVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQueryVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaQueryVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzCssMediaFeatureVecDestructorEnumWrapper {
+    #[classattr]
+    fn DefaultRust() -> AzCssMediaFeatureVecDestructorEnumWrapper { AzCssMediaFeatureVecDestructorEnumWrapper { inner: AzCssMediaFeatureVecDestructor::DefaultRust } }
+    #[classattr]
+    fn NoDestructor() -> AzCssMediaFeatureVecDestructorEnumWrapper { AzCssMediaFeatureVecDestructorEnumWrapper { inner: AzCssMediaFeatureVecDestructor::NoDestructor } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzCssMediaFeatureVecDestructor;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzCssMediaFeatureVecDestructor::DefaultRust => Ok(vec!["DefaultRust".into_py(py), ().into_py(py)]),
+            AzCssMediaFeatureVecDestructor::NoDestructor => Ok(vec!["NoDestructor".into_py(py), ().into_py(py)]),
+            AzCssMediaFeatureVecDestructor::External(v) => Ok(vec!["External".into_py(py), ().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzCssMediaFeatureVecDestructorEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaFeatureVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_impl::css::CssMediaFeatureVecDestructor = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzCssRuleBlockVecDestructorEnumWrapper {
     #[classattr]
@@ -36937,6 +44729,151 @@ impl PyObjectProtocol for AzOptionListViewOnRowClickEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTreeViewNodeIdEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewNodeIdEnumWrapper { AzOptionTreeViewNodeIdEnumWrapper { inner: AzOptionTreeViewNodeId::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewNodeId) -> AzOptionTreeViewNodeIdEnumWrapper { AzOptionTreeViewNodeIdEnumWrapper { inner: AzOptionTreeViewNodeId::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewNodeId;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewNodeId::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewNodeId::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewNodeIdEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewNodeId = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewNodeId = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnExpandEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnExpandEnumWrapper { AzOptionTreeViewOnExpandEnumWrapper { inner: AzOptionTreeViewOnExpand::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnExpand) -> AzOptionTreeViewOnExpandEnumWrapper { AzOptionTreeViewOnExpandEnumWrapper { inner: AzOptionTreeViewOnExpand::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnExpand;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnExpand::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnExpand::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnExpandEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnExpand = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnExpand = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnSelectEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnSelectEnumWrapper { AzOptionTreeViewOnSelectEnumWrapper { inner: AzOptionTreeViewOnSelect::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnSelect) -> AzOptionTreeViewOnSelectEnumWrapper { AzOptionTreeViewOnSelectEnumWrapper { inner: AzOptionTreeViewOnSelect::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnSelect;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnSelect::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnSelect::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnSelectEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnSelect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnSelect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnDoubleClickEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnDoubleClickEnumWrapper { AzOptionTreeViewOnDoubleClickEnumWrapper { inner: AzOptionTreeViewOnDoubleClick::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnDoubleClick) -> AzOptionTreeViewOnDoubleClickEnumWrapper { AzOptionTreeViewOnDoubleClickEnumWrapper { inner: AzOptionTreeViewOnDoubleClick::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnDoubleClick;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnDoubleClick::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnDoubleClick::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnDoubleClickEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnDoubleClick = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnDoubleClick = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTreeViewOnContextMenuEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTreeViewOnContextMenuEnumWrapper { AzOptionTreeViewOnContextMenuEnumWrapper { inner: AzOptionTreeViewOnContextMenu::None } }
+    #[staticmethod]
+    fn Some(v: AzTreeViewOnContextMenu) -> AzOptionTreeViewOnContextMenuEnumWrapper { AzOptionTreeViewOnContextMenuEnumWrapper { inner: AzOptionTreeViewOnContextMenu::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTreeViewOnContextMenu;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTreeViewOnContextMenu::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTreeViewOnContextMenu::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTreeViewOnContextMenuEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnContextMenu = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tree_view::OptionTreeViewOnContextMenu = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionListViewOnColumnClickEnumWrapper {
     #[classattr]
@@ -36995,6 +44932,64 @@ impl PyObjectProtocol for AzOptionListViewOnLazyLoadScrollEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionListViewOnColumnResizeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionListViewOnColumnResizeEnumWrapper { AzOptionListViewOnColumnResizeEnumWrapper { inner: AzOptionListViewOnColumnResize::None } }
+    #[staticmethod]
+    fn Some(v: AzListViewOnColumnResize) -> AzOptionListViewOnColumnResizeEnumWrapper { AzOptionListViewOnColumnResizeEnumWrapper { inner: AzOptionListViewOnColumnResize::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionListViewOnColumnResize;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionListViewOnColumnResize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionListViewOnColumnResize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionListViewOnColumnResizeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewOnColumnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewOnColumnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionListViewRowProviderEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionListViewRowProviderEnumWrapper { AzOptionListViewRowProviderEnumWrapper { inner: AzOptionListViewRowProvider::None } }
+    #[staticmethod]
+    fn Some(v: AzListViewRowProvider) -> AzOptionListViewRowProviderEnumWrapper { AzOptionListViewRowProviderEnumWrapper { inner: AzOptionListViewRowProvider::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionListViewRowProvider;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionListViewRowProvider::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionListViewRowProvider::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionListViewRowProviderEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewRowProvider = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::list_view::OptionListViewRowProvider = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionMenuEnumWrapper {
     #[classattr]
@@ -37430,6 +45425,238 @@ impl PyObjectProtocol for AzOptionTabOnClickEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTabOnCloseEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTabOnCloseEnumWrapper { AzOptionTabOnCloseEnumWrapper { inner: AzOptionTabOnClose::None } }
+    #[staticmethod]
+    fn Some(v: AzTabOnClose) -> AzOptionTabOnCloseEnumWrapper { AzOptionTabOnCloseEnumWrapper { inner: AzOptionTabOnClose::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTabOnClose;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTabOnClose::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTabOnClose::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTabOnCloseEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::OptionTabOnClose = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::OptionTabOnClose = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTabOnReorderEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTabOnReorderEnumWrapper { AzOptionTabOnReorderEnumWrapper { inner: AzOptionTabOnReorder::None } }
+    #[staticmethod]
+    fn Some(v: AzTabOnReorder) -> AzOptionTabOnReorderEnumWrapper { AzOptionTabOnReorderEnumWrapper { inner: AzOptionTabOnReorder::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTabOnReorder;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTabOnReorder::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTabOnReorder::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTabOnReorderEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::OptionTabOnReorder = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::OptionTabOnReorder = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionTabOnContextMenuEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTabOnContextMenuEnumWrapper { AzOptionTabOnContextMenuEnumWrapper { inner: AzOptionTabOnContextMenu::None } }
+    #[staticmethod]
+    fn Some(v: AzTabOnContextMenu) -> AzOptionTabOnContextMenuEnumWrapper { AzOptionTabOnContextMenuEnumWrapper { inner: AzOptionTabOnContextMenu::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTabOnContextMenu;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTabOnContextMenu::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTabOnContextMenu::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTabOnContextMenuEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::OptionTabOnContextMenu = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::tabs::OptionTabOnContextMenu = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionRibbonOnTabClickedEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionRibbonOnTabClickedEnumWrapper { AzOptionRibbonOnTabClickedEnumWrapper { inner: AzOptionRibbonOnTabClicked::None } }
+    #[staticmethod]
+    fn Some(v: AzRibbonOnTabClicked) -> AzOptionRibbonOnTabClickedEnumWrapper { AzOptionRibbonOnTabClickedEnumWrapper { inner: AzOptionRibbonOnTabClicked::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionRibbonOnTabClicked;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionRibbonOnTabClicked::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionRibbonOnTabClicked::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionRibbonOnTabClickedEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::OptionRibbonOnTabClicked = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::OptionRibbonOnTabClicked = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionRibbonOnItemClickEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionRibbonOnItemClickEnumWrapper { AzOptionRibbonOnItemClickEnumWrapper { inner: AzOptionRibbonOnItemClick::None } }
+    #[staticmethod]
+    fn Some(v: AzRibbonOnItemClick) -> AzOptionRibbonOnItemClickEnumWrapper { AzOptionRibbonOnItemClickEnumWrapper { inner: AzOptionRibbonOnItemClick::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionRibbonOnItemClick;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionRibbonOnItemClick::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionRibbonOnItemClick::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionRibbonOnItemClickEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::OptionRibbonOnItemClick = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::ribbon::OptionRibbonOnItemClick = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSplitterOnResizeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSplitterOnResizeEnumWrapper { AzOptionSplitterOnResizeEnumWrapper { inner: AzOptionSplitterOnResize::None } }
+    #[staticmethod]
+    fn Some(v: AzSplitterOnResize) -> AzOptionSplitterOnResizeEnumWrapper { AzOptionSplitterOnResizeEnumWrapper { inner: AzOptionSplitterOnResize::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSplitterOnResize;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSplitterOnResize::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSplitterOnResize::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSplitterOnResizeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::OptionSplitterOnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::splitter::OptionSplitterOnResize = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionSliderOnValueChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionSliderOnValueChangeEnumWrapper { AzOptionSliderOnValueChangeEnumWrapper { inner: AzOptionSliderOnValueChange::None } }
+    #[staticmethod]
+    fn Some(v: AzSliderOnValueChange) -> AzOptionSliderOnValueChangeEnumWrapper { AzOptionSliderOnValueChangeEnumWrapper { inner: AzOptionSliderOnValueChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionSliderOnValueChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionSliderOnValueChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionSliderOnValueChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionSliderOnValueChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::OptionSliderOnValueChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::OptionSliderOnValueChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionRangeSliderOnValueChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionRangeSliderOnValueChangeEnumWrapper { AzOptionRangeSliderOnValueChangeEnumWrapper { inner: AzOptionRangeSliderOnValueChange::None } }
+    #[staticmethod]
+    fn Some(v: AzRangeSliderOnValueChange) -> AzOptionRangeSliderOnValueChangeEnumWrapper { AzOptionRangeSliderOnValueChangeEnumWrapper { inner: AzOptionRangeSliderOnValueChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionRangeSliderOnValueChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionRangeSliderOnValueChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionRangeSliderOnValueChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionRangeSliderOnValueChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::OptionRangeSliderOnValueChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::slider::OptionRangeSliderOnValueChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionFileInputOnPathChangeEnumWrapper {
     #[classattr]
@@ -37604,6 +45831,35 @@ impl PyObjectProtocol for AzOptionTextInputSelectionEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionTextAreaOnTextChangeEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionTextAreaOnTextChangeEnumWrapper { AzOptionTextAreaOnTextChangeEnumWrapper { inner: AzOptionTextAreaOnTextChange::None } }
+    #[staticmethod]
+    fn Some(v: AzTextAreaOnTextChange) -> AzOptionTextAreaOnTextChangeEnumWrapper { AzOptionTextAreaOnTextChangeEnumWrapper { inner: AzOptionTextAreaOnTextChange::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionTextAreaOnTextChange;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionTextAreaOnTextChange::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionTextAreaOnTextChange::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionTextAreaOnTextChangeEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::OptionTextAreaOnTextChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &crate::widgets::text_area::OptionTextAreaOnTextChange = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionNumberInputOnFocusLostEnumWrapper {
     #[classattr]
@@ -38849,6 +47105,93 @@ impl PyObjectProtocol for AzOptionLogicalPositionEnumWrapper {
     }
 }
 
+#[pymethods]
+impl AzOptionImeCompositionEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionImeCompositionEnumWrapper { AzOptionImeCompositionEnumWrapper { inner: AzOptionImeComposition::None } }
+    #[staticmethod]
+    fn Some(v: AzImeComposition) -> AzOptionImeCompositionEnumWrapper { AzOptionImeCompositionEnumWrapper { inner: AzOptionImeComposition::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionImeComposition;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionImeComposition::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionImeComposition::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionImeCompositionEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionImeComposition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionImeComposition = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionClipboardContentEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionClipboardContentEnumWrapper { AzOptionClipboardContentEnumWrapper { inner: AzOptionClipboardContent::None } }
+    #[staticmethod]
+    fn Some(v: AzClipboardContent) -> AzOptionClipboardContentEnumWrapper { AzOptionClipboardContentEnumWrapper { inner: AzOptionClipboardContent::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionClipboardContent;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionClipboardContent::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionClipboardContent::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionClipboardContentEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionClipboardContent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionClipboardContent = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
+#[pymethods]
+impl AzOptionLogicalRectEnumWrapper {
+    #[classattr]
+    fn None() -> AzOptionLogicalRectEnumWrapper { AzOptionLogicalRectEnumWrapper { inner: AzOptionLogicalRect::None } }
+    #[staticmethod]
+    fn Some(v: AzLogicalRect) -> AzOptionLogicalRectEnumWrapper { AzOptionLogicalRectEnumWrapper { inner: AzOptionLogicalRect::Some(v) } }
+
+    fn r#match(&self) -> PyResult<Vec<PyObject>> {
+        use crate::python::AzOptionLogicalRect;
+        use pyo3::conversion::IntoPy;
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match &self.inner {
+            AzOptionLogicalRect::None => Ok(vec!["None".into_py(py), ().into_py(py)]),
+            AzOptionLogicalRect::Some(v) => Ok(vec!["Some".into_py(py), v.clone().into_py(py)]),
+        }
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for AzOptionLogicalRectEnumWrapper {
+    fn __str__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionLogicalRect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+    fn __repr__(&self) -> Result<String, PyErr> { 
+        let m: &azul_core::window::OptionLogicalRect = unsafe { mem::transmute(&self.inner) }; Ok(format!("{:#?}", m))
+    }
+}
+
 #[pymethods]
 impl AzOptionPhysicalPositionI32EnumWrapper {
     #[classattr]
@@ -40158,6 +48501,9 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzWindowsHandle>()?;
     m.add_class::<AzWebHandle>()?;
     m.add_class::<AzAndroidHandle>()?;
+    m.add_class::<AzClipboardSelectionEnumWrapper>()?;
+    m.add_class::<AzClipboardContent>()?;
+    m.add_class::<AzLinuxBackendEnumWrapper>()?;
     m.add_class::<AzXWindowTypeEnumWrapper>()?;
     m.add_class::<AzPhysicalPositionI32>()?;
     m.add_class::<AzPhysicalSizeU32>()?;
@@ -40176,6 +48522,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzWindowFrameEnumWrapper>()?;
     m.add_class::<AzDebugState>()?;
     m.add_class::<AzKeyboardState>()?;
+    m.add_class::<AzImeComposition>()?;
     m.add_class::<AzMouseCursorTypeEnumWrapper>()?;
     m.add_class::<AzCursorPositionEnumWrapper>()?;
     m.add_class::<AzMouseState>()?;
@@ -40225,6 +48572,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzAnimationEasingEnumWrapper>()?;
     m.add_class::<AzIFrameCallback>()?;
     m.add_class::<AzIFrameCallbackInfo>()?;
+    m.add_class::<AzTooltipCallback>()?;
     m.add_class::<AzIFrameCallbackReturn>()?;
     m.add_class::<AzRenderImageCallback>()?;
     m.add_class::<AzRenderImageCallbackInfo>()?;
@@ -40251,8 +48599,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzComponentEventFilterEnumWrapper>()?;
     m.add_class::<AzApplicationEventFilterEnumWrapper>()?;
     m.add_class::<AzAccessibilityInfo>()?;
+    m.add_class::<AzTooltip>()?;
+    m.add_class::<AzTooltipContentEnumWrapper>()?;
+    m.add_class::<AzTooltipDom>()?;
     m.add_class::<AzAccessibilityRoleEnumWrapper>()?;
     m.add_class::<AzAccessibilityStateEnumWrapper>()?;
+    m.add_class::<AzAccessibilityActionEnumWrapper>()?;
     m.add_class::<AzTabIndexEnumWrapper>()?;
     m.add_class::<AzIdOrClassEnumWrapper>()?;
     m.add_class::<AzNodeDataInlineCssPropertyEnumWrapper>()?;
@@ -40277,8 +48629,18 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzCssNthChildSelectorEnumWrapper>()?;
     m.add_class::<AzCssNthChildPattern>()?;
     m.add_class::<AzStylesheet>()?;
+    m.add_class::<AzCssKeyframes>()?;
+    m.add_class::<AzCssKeyframe>()?;
+    m.add_class::<AzCssMediaRule>()?;
+    m.add_class::<AzCssMediaQuery>()?;
+    m.add_class::<AzCssMediaTypeEnumWrapper>()?;
+    m.add_class::<AzCssMediaFeatureEnumWrapper>()?;
+    m.add_class::<AzCssMediaRange>()?;
+    m.add_class::<AzCssMediaOrientationEnumWrapper>()?;
+    m.add_class::<AzCssColorSchemeEnumWrapper>()?;
     m.add_class::<AzCss>()?;
     m.add_class::<AzCssPropertyTypeEnumWrapper>()?;
+    m.add_class::<AzCombinedCssPropertyTypeEnumWrapper>()?;
     m.add_class::<AzAnimationInterpolationFunctionEnumWrapper>()?;
     m.add_class::<AzInterpolateContext>()?;
     m.add_class::<AzColorU>()?;
@@ -40295,6 +48657,13 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleFilterOffset>()?;
     m.add_class::<AzStyleCompositeFilterEnumWrapper>()?;
     m.add_class::<AzLayoutAlignContentEnumWrapper>()?;
+    m.add_class::<AzGridTrackBreadthEnumWrapper>()?;
+    m.add_class::<AzGridTrackSizing>()?;
+    m.add_class::<AzLayoutGridAutoFlowEnumWrapper>()?;
+    m.add_class::<AzGridLineEnumWrapper>()?;
+    m.add_class::<AzGridPlacement>()?;
+    m.add_class::<AzLayoutRowGap>()?;
+    m.add_class::<AzLayoutColumnGap>()?;
     m.add_class::<AzLayoutAlignItemsEnumWrapper>()?;
     m.add_class::<AzLayoutBottom>()?;
     m.add_class::<AzLayoutBoxSizingEnumWrapper>()?;
@@ -40382,11 +48751,40 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleTransformScale2D>()?;
     m.add_class::<AzStyleTransformScale3D>()?;
     m.add_class::<AzStyleTransformSkew2D>()?;
+    m.add_class::<AzStyleTime>()?;
+    m.add_class::<AzStyleCubicBezier>()?;
+    m.add_class::<AzStyleStepPositionEnumWrapper>()?;
+    m.add_class::<AzStyleSteps>()?;
+    m.add_class::<AzStyleTimingFunctionEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionPropertyEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationNameEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationIterationCountEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDirectionEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationFillModeEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationPlayStateEnumWrapper>()?;
     m.add_class::<AzStyleTextAlignEnumWrapper>()?;
+    m.add_class::<AzStyleDirectionEnumWrapper>()?;
+    m.add_class::<AzStyleWordBreakEnumWrapper>()?;
+    m.add_class::<AzStyleOverflowWrapEnumWrapper>()?;
+    m.add_class::<AzStyleHyphensEnumWrapper>()?;
     m.add_class::<AzStyleTextColor>()?;
     m.add_class::<AzStyleWordSpacing>()?;
     m.add_class::<AzStyleBoxShadowValueEnumWrapper>()?;
     m.add_class::<AzLayoutAlignContentValueEnumWrapper>()?;
+    m.add_class::<AzGridTrackSizingVecValueEnumWrapper>()?;
+    m.add_class::<AzGridTrackSizingValueEnumWrapper>()?;
+    m.add_class::<AzLayoutGridAutoFlowValueEnumWrapper>()?;
+    m.add_class::<AzGridPlacementValueEnumWrapper>()?;
+    m.add_class::<AzLayoutRowGapValueEnumWrapper>()?;
+    m.add_class::<AzLayoutColumnGapValueEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionPropertyVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleTimeVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleTimingFunctionVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationNameVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationIterationCountVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDirectionVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationFillModeVecValueEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationPlayStateVecValueEnumWrapper>()?;
     m.add_class::<AzLayoutAlignItemsValueEnumWrapper>()?;
     m.add_class::<AzLayoutBottomValueEnumWrapper>()?;
     m.add_class::<AzLayoutBoxSizingValueEnumWrapper>()?;
@@ -40444,6 +48842,10 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleLineHeightValueEnumWrapper>()?;
     m.add_class::<AzStyleTabWidthValueEnumWrapper>()?;
     m.add_class::<AzStyleTextAlignValueEnumWrapper>()?;
+    m.add_class::<AzStyleDirectionValueEnumWrapper>()?;
+    m.add_class::<AzStyleWordBreakValueEnumWrapper>()?;
+    m.add_class::<AzStyleOverflowWrapValueEnumWrapper>()?;
+    m.add_class::<AzStyleHyphensValueEnumWrapper>()?;
     m.add_class::<AzStyleTextColorValueEnumWrapper>()?;
     m.add_class::<AzStyleWordSpacingValueEnumWrapper>()?;
     m.add_class::<AzStyleOpacityValueEnumWrapper>()?;
@@ -40456,6 +48858,14 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzCssPropertyEnumWrapper>()?;
 
     m.add_class::<AzRibbon>()?;
+    m.add_class::<AzRibbonTab>()?;
+    m.add_class::<AzRibbonGroup>()?;
+    m.add_class::<AzRibbonItem>()?;
+    m.add_class::<AzRibbonItemKindEnumWrapper>()?;
+    m.add_class::<AzRibbonItemState>()?;
+    m.add_class::<AzRibbonOnItemClick>()?;
+    m.add_class::<AzRibbonOnItemClickCallback>()?;
+    m.add_class::<AzRibbonOnTabClicked>()?;
     m.add_class::<AzRibbonOnTabClickedCallback>()?;
     m.add_class::<AzButton>()?;
     m.add_class::<AzButtonOnClick>()?;
@@ -40488,6 +48898,12 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzTextInputOnFocusLostCallback>()?;
     m.add_class::<AzOnTextInputReturn>()?;
     m.add_class::<AzTextInputValidEnumWrapper>()?;
+    m.add_class::<AzTextArea>()?;
+    m.add_class::<AzTextAreaState>()?;
+    m.add_class::<AzTextAreaEdit>()?;
+    m.add_class::<AzTextAreaEditKindEnumWrapper>()?;
+    m.add_class::<AzTextAreaOnTextChangeCallback>()?;
+    m.add_class::<AzTextAreaOnTextChange>()?;
     m.add_class::<AzNumberInput>()?;
     m.add_class::<AzNumberInputStateWrapper>()?;
     m.add_class::<AzNumberInputState>()?;
@@ -40497,12 +48913,37 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNumberInputOnFocusLostCallback>()?;
     m.add_class::<AzProgressBar>()?;
     m.add_class::<AzProgressBarState>()?;
+    m.add_class::<AzProgressBarModeEnumWrapper>()?;
+    m.add_class::<AzProgressBarStyleEnumWrapper>()?;
+    m.add_class::<AzSpinner>()?;
     m.add_class::<AzTabHeader>()?;
     m.add_class::<AzTabHeaderState>()?;
     m.add_class::<AzTabContent>()?;
     m.add_class::<AzTabOnClick>()?;
     m.add_class::<AzTabOnClickCallback>()?;
+    m.add_class::<AzTabOnClose>()?;
+    m.add_class::<AzTabOnCloseCallback>()?;
+    m.add_class::<AzTabOnReorder>()?;
+    m.add_class::<AzTabOnReorderCallback>()?;
+    m.add_class::<AzTabOnContextMenu>()?;
+    m.add_class::<AzTabOnContextMenuCallback>()?;
     m.add_class::<AzFrame>()?;
+    m.add_class::<AzSplitter>()?;
+    m.add_class::<AzSplitterOrientationEnumWrapper>()?;
+    m.add_class::<AzSplitterPane>()?;
+    m.add_class::<AzSplitterState>()?;
+    m.add_class::<AzSplitterPaneState>()?;
+    m.add_class::<AzSplitterOnResize>()?;
+    m.add_class::<AzSplitterOnResizeCallback>()?;
+    m.add_class::<AzSlider>()?;
+    m.add_class::<AzRangeSlider>()?;
+    m.add_class::<AzSliderOrientationEnumWrapper>()?;
+    m.add_class::<AzSliderState>()?;
+    m.add_class::<AzRangeSliderState>()?;
+    m.add_class::<AzSliderOnValueChange>()?;
+    m.add_class::<AzSliderOnValueChangeCallback>()?;
+    m.add_class::<AzRangeSliderOnValueChange>()?;
+    m.add_class::<AzRangeSliderOnValueChangeCallback>()?;
     m.add_class::<AzNodeGraph>()?;
     m.add_class::<AzNodeTypeIdInfoMap>()?;
     m.add_class::<AzInputOutputTypeIdInfoMap>()?;
@@ -40549,7 +48990,24 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzListViewOnColumnClick>()?;
     m.add_class::<AzListViewOnRowClickCallback>()?;
     m.add_class::<AzListViewOnRowClick>()?;
+    m.add_class::<AzListViewRowRange>()?;
+    m.add_class::<AzListViewOnColumnResizeCallback>()?;
+    m.add_class::<AzListViewOnColumnResize>()?;
+    m.add_class::<AzListViewRowProviderCallback>()?;
+    m.add_class::<AzListViewRowProvider>()?;
     m.add_class::<AzTreeView>()?;
+    m.add_class::<AzTreeViewNodeId>()?;
+    m.add_class::<AzTreeViewNode>()?;
+    m.add_class::<AzTreeViewSelectionModeEnumWrapper>()?;
+    m.add_class::<AzTreeViewState>()?;
+    m.add_class::<AzTreeViewOnExpandCallback>()?;
+    m.add_class::<AzTreeViewOnExpand>()?;
+    m.add_class::<AzTreeViewOnSelectCallback>()?;
+    m.add_class::<AzTreeViewOnSelect>()?;
+    m.add_class::<AzTreeViewOnDoubleClickCallback>()?;
+    m.add_class::<AzTreeViewOnDoubleClick>()?;
+    m.add_class::<AzTreeViewOnContextMenuCallback>()?;
+    m.add_class::<AzTreeViewOnContextMenu>()?;
     m.add_class::<AzDropDown>()?;
     m.add_class::<AzDropDownOnChoiceChangeCallback>()?;
     m.add_class::<AzDropDownOnChoiceChange>()?;
@@ -40681,6 +49139,8 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzThreadWriteBackMsg>()?;
     m.add_class::<AzCreateThreadFn>()?;
     m.add_class::<AzGetSystemTimeFn>()?;
+    m.add_class::<AzGetClipboardFn>()?;
+    m.add_class::<AzSetClipboardFn>()?;
     m.add_class::<AzCheckThreadFinishedFn>()?;
     m.add_class::<AzLibrarySendThreadMsgFn>()?;
     m.add_class::<AzLibraryReceiveThreadMsgFn>()?;
@@ -40695,6 +49155,14 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzString>()?;
 
     m.add_class::<AzListViewRowVec>()?;
+    m.add_class::<AzTreeViewNodeVec>()?;
+    m.add_class::<AzTreeViewNodeIdVec>()?;
+    m.add_class::<AzRibbonTabVec>()?;
+    m.add_class::<AzRibbonGroupVec>()?;
+    m.add_class::<AzRibbonItemVec>()?;
+    m.add_class::<AzSplitterPaneVec>()?;
+    m.add_class::<AzSplitterPaneStateVec>()?;
+    m.add_class::<AzTextAreaEditVec>()?;
     m.add_class::<AzStyleFilterVec>()?;
     m.add_class::<AzLogicalRectVec>()?;
     m.add_class::<AzNodeTypeIdInfoMapVec>()?;
@@ -40707,6 +49175,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOutputConnectionVec>()?;
     m.add_class::<AzInputNodeAndIndexVec>()?;
     m.add_class::<AzAccessibilityStateVec>()?;
+    m.add_class::<AzAccessibilityActionVec>()?;
     m.add_class::<AzMenuItemVec>()?;
     m.add_class::<AzTessellatedSvgNodeVec>()?;
     m.add_class::<AzStyleFontFamilyVec>()?;
@@ -40726,6 +49195,15 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleBackgroundRepeatVec>()?;
     m.add_class::<AzStyleBackgroundSizeVec>()?;
     m.add_class::<AzStyleTransformVec>()?;
+    m.add_class::<AzStyleTransitionPropertyVec>()?;
+    m.add_class::<AzStyleTimeVec>()?;
+    m.add_class::<AzStyleTimingFunctionVec>()?;
+    m.add_class::<AzStyleAnimationNameVec>()?;
+    m.add_class::<AzStyleAnimationIterationCountVec>()?;
+    m.add_class::<AzStyleAnimationDirectionVec>()?;
+    m.add_class::<AzStyleAnimationFillModeVec>()?;
+    m.add_class::<AzStyleAnimationPlayStateVec>()?;
+    m.add_class::<AzGridTrackSizingVec>()?;
     m.add_class::<AzCssPropertyVec>()?;
     m.add_class::<AzSvgMultiPolygonVec>()?;
     m.add_class::<AzSvgPathVec>()?;
@@ -40740,6 +49218,11 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzCssDeclarationVec>()?;
     m.add_class::<AzCssPathSelectorVec>()?;
     m.add_class::<AzStylesheetVec>()?;
+    m.add_class::<AzCssKeyframesVec>()?;
+    m.add_class::<AzCssKeyframeVec>()?;
+    m.add_class::<AzCssMediaRuleVec>()?;
+    m.add_class::<AzCssMediaQueryVec>()?;
+    m.add_class::<AzCssMediaFeatureVec>()?;
     m.add_class::<AzCssRuleBlockVec>()?;
     m.add_class::<AzU16Vec>()?;
     m.add_class::<AzF32Vec>()?;
@@ -40760,6 +49243,14 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNodeDataVec>()?;
     m.add_class::<AzStyleFontFamilyVecDestructorEnumWrapper>()?;
     m.add_class::<AzListViewRowVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTreeViewNodeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTreeViewNodeIdVecDestructorEnumWrapper>()?;
+    m.add_class::<AzRibbonTabVecDestructorEnumWrapper>()?;
+    m.add_class::<AzRibbonGroupVecDestructorEnumWrapper>()?;
+    m.add_class::<AzRibbonItemVecDestructorEnumWrapper>()?;
+    m.add_class::<AzSplitterPaneVecDestructorEnumWrapper>()?;
+    m.add_class::<AzSplitterPaneStateVecDestructorEnumWrapper>()?;
+    m.add_class::<AzTextAreaEditVecDestructorEnumWrapper>()?;
     m.add_class::<AzStyleFilterVecDestructorEnumWrapper>()?;
     m.add_class::<AzLogicalRectVecDestructorEnumWrapper>()?;
     m.add_class::<AzNodeTypeIdInfoMapVecDestructorEnumWrapper>()?;
@@ -40772,6 +49263,7 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOutputConnectionVecDestructorEnumWrapper>()?;
     m.add_class::<AzInputNodeAndIndexVecDestructorEnumWrapper>()?;
     m.add_class::<AzAccessibilityStateVecDestructorEnumWrapper>()?;
+    m.add_class::<AzAccessibilityActionVecDestructorEnumWrapper>()?;
     m.add_class::<AzMenuItemVecDestructorEnumWrapper>()?;
     m.add_class::<AzTessellatedSvgNodeVecDestructorEnumWrapper>()?;
     m.add_class::<AzXmlNodeVecDestructorEnumWrapper>()?;
@@ -40790,6 +49282,15 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzStyleBackgroundRepeatVecDestructorEnumWrapper>()?;
     m.add_class::<AzStyleBackgroundSizeVecDestructorEnumWrapper>()?;
     m.add_class::<AzStyleTransformVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleTransitionPropertyVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleTimeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleTimingFunctionVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationNameVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationIterationCountVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationDirectionVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationFillModeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzStyleAnimationPlayStateVecDestructorEnumWrapper>()?;
+    m.add_class::<AzGridTrackSizingVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssPropertyVecDestructorEnumWrapper>()?;
     m.add_class::<AzSvgMultiPolygonVecDestructorEnumWrapper>()?;
     m.add_class::<AzSvgPathVecDestructorEnumWrapper>()?;
@@ -40804,6 +49305,11 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzCssDeclarationVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssPathSelectorVecDestructorEnumWrapper>()?;
     m.add_class::<AzStylesheetVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssKeyframesVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssKeyframeVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssMediaRuleVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssMediaQueryVecDestructorEnumWrapper>()?;
+    m.add_class::<AzCssMediaFeatureVecDestructorEnumWrapper>()?;
     m.add_class::<AzCssRuleBlockVecDestructorEnumWrapper>()?;
     m.add_class::<AzF32VecDestructorEnumWrapper>()?;
     m.add_class::<AzU16VecDestructorEnumWrapper>()?;
@@ -40824,8 +49330,15 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzNodeDataVecDestructorEnumWrapper>()?;
 
     m.add_class::<AzOptionListViewOnRowClickEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewNodeIdEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnExpandEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnSelectEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnDoubleClickEnumWrapper>()?;
+    m.add_class::<AzOptionTreeViewOnContextMenuEnumWrapper>()?;
     m.add_class::<AzOptionListViewOnColumnClickEnumWrapper>()?;
     m.add_class::<AzOptionListViewOnLazyLoadScrollEnumWrapper>()?;
+    m.add_class::<AzOptionListViewOnColumnResizeEnumWrapper>()?;
+    m.add_class::<AzOptionListViewRowProviderEnumWrapper>()?;
     m.add_class::<AzOptionMenuEnumWrapper>()?;
     m.add_class::<AzOptionPixelValueNoPercentEnumWrapper>()?;
     m.add_class::<AzOptionDropDownOnChoiceChangeEnumWrapper>()?;
@@ -40841,12 +49354,21 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionColorInputOnValueChangeEnumWrapper>()?;
     m.add_class::<AzOptionButtonOnClickEnumWrapper>()?;
     m.add_class::<AzOptionTabOnClickEnumWrapper>()?;
+    m.add_class::<AzOptionTabOnCloseEnumWrapper>()?;
+    m.add_class::<AzOptionTabOnReorderEnumWrapper>()?;
+    m.add_class::<AzOptionTabOnContextMenuEnumWrapper>()?;
+    m.add_class::<AzOptionRibbonOnTabClickedEnumWrapper>()?;
+    m.add_class::<AzOptionRibbonOnItemClickEnumWrapper>()?;
+    m.add_class::<AzOptionSplitterOnResizeEnumWrapper>()?;
+    m.add_class::<AzOptionSliderOnValueChangeEnumWrapper>()?;
+    m.add_class::<AzOptionRangeSliderOnValueChangeEnumWrapper>()?;
     m.add_class::<AzOptionFileInputOnPathChangeEnumWrapper>()?;
     m.add_class::<AzOptionCheckBoxOnToggleEnumWrapper>()?;
     m.add_class::<AzOptionTextInputOnTextInputEnumWrapper>()?;
     m.add_class::<AzOptionTextInputOnVirtualKeyDownEnumWrapper>()?;
     m.add_class::<AzOptionTextInputOnFocusLostEnumWrapper>()?;
     m.add_class::<AzOptionTextInputSelectionEnumWrapper>()?;
+    m.add_class::<AzOptionTextAreaOnTextChangeEnumWrapper>()?;
     m.add_class::<AzOptionNumberInputOnFocusLostEnumWrapper>()?;
     m.add_class::<AzOptionNumberInputOnValueChangeEnumWrapper>()?;
     m.add_class::<AzOptionMenuItemIconEnumWrapper>()?;
@@ -40890,6 +49412,9 @@ fn azul(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<AzOptionTaskBarIconEnumWrapper>()?;
     m.add_class::<AzOptionHwndHandleEnumWrapper>()?;
     m.add_class::<AzOptionLogicalPositionEnumWrapper>()?;
+    m.add_class::<AzOptionImeCompositionEnumWrapper>()?;
+    m.add_class::<AzOptionClipboardContentEnumWrapper>()?;
+    m.add_class::<AzOptionLogicalRectEnumWrapper>()?;
     m.add_class::<AzOptionPhysicalPositionI32EnumWrapper>()?;
     m.add_class::<AzOptionWindowIconEnumWrapper>()?;
     m.add_class::<AzOptionStringEnumWrapper>()?;
@@ -299,7 +299,7 @@ impl Button {
 
         use azul_desktop::dom::{
             EventFilter, HoverEventFilter,
-            CallbackData,
+            CallbackData, AccessibilityInfo, AccessibilityRole,
         };
         use azul_desktop::callbacks::Callback;
 
@@ -317,11 +317,15 @@ impl Button {
         static CONTAINER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-button-container"))];
         static LABEL_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-button-content"))];
 
+        let accessibility_info = AccessibilityInfo::new(AccessibilityRole::PushButton)
+            .with_name(self.label.clone());
+
         Dom::div()
         .with_ids_and_classes(IdOrClassVec::from_const_slice(CONTAINER_CLASS))
         .with_inline_css_props(self.container_style)
         .with_callbacks(callbacks.into())
         .with_tab_index(TabIndex::Auto)
+        .with_accessibility_info(accessibility_info)
         .with_children(vec![
             Dom::text(self.label)
             .with_ids_and_classes(IdOrClassVec::from_const_slice(LABEL_CLASS))
@@ -144,10 +144,20 @@ impl CheckBox {
 
         use azul_desktop::dom::{
             Dom, EventFilter, HoverEventFilter,
-            CallbackData, DomVec,
+            CallbackData, DomVec, AccessibilityInfo, AccessibilityRole,
+            AccessibilityState, AccessibilityStateVec, AccessibilityAction,
         };
         use azul_desktop::callbacks::Callback;
 
+        let states: &'static [AccessibilityState] = if self.state.inner.checked {
+            &[AccessibilityState::Checked]
+        } else {
+            &[]
+        };
+
+        let accessibility_info = AccessibilityInfo::new(AccessibilityRole::CheckButton)
+            .with_states(AccessibilityStateVec::from_const_slice(states));
+
         Dom::div()
         .with_ids_and_classes(IdOrClassVec::from(CHECKBOX_CONTAINER_CLASS))
         .with_inline_css_props(self.container_style)
@@ -159,6 +169,8 @@ impl CheckBox {
             }
         ].into())
         .with_tab_index(TabIndex::Auto)
+        .with_accessibility_info(accessibility_info)
+        .with_accessibility_actions(vec![AccessibilityAction::Toggle].into())
         .with_children(vec![
             Dom::div()
             .with_ids_and_classes(IdOrClassVec::from(CHECKBOX_CONTENT_CLASS))
@@ -1,14 +1,14 @@
 use azul_core::{callbacks::{
-        Callback, FocusTarget,
+        Callback, DomNodeId,
         MarshaledLayoutCallback,
-        MarshaledLayoutCallbackInner
+        MarshaledLayoutCallbackInner,
     }, dom::{
         CallbackData,
         EventFilter,
         FocusEventFilter,
         HoverEventFilter,
         WindowEventFilter
-    }, window::{PhysicalPosition, PhysicalPositionI32, WindowState}};
+    }, window::{LogicalPosition, LogicalSize, VirtualKeyCode}};
 use azul_desktop::{
     css::*,
     css::AzString,
@@ -19,13 +19,13 @@ use azul_desktop::{
         NodeDataInlineCssPropertyVec,
         IdOrClassVec, DomVec,
         NodeDataInlineCssProperty,
-        NodeDataInlineCssProperty::{Normal, Active, Hover, Focus},
+        NodeDataInlineCssProperty::{Normal, Hover},
     },
     styled_dom::StyledDom,
-    resources::{ImageRef, OptionImageRef},
     callbacks::{RefAny, Update, CallbackInfo},
 };
 use std::vec::Vec;
+use std::string::String;
 
 const STRING_16146701490593874959: AzString = AzString::from_const_str("sans-serif");
 const STYLE_BACKGROUND_CONTENT_4857374953508308215_ITEMS: &[StyleBackgroundContent] = &[
@@ -234,6 +234,132 @@ const CSS_MATCH_7938442083662451131_PROPERTIES: &[NodeDataInlineCssProperty] = &
 const CSS_MATCH_7938442083662451131: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_7938442083662451131_PROPERTIES);
 
 
+
+const COLOR_FFFFFF: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 }; // white
+const COLOR_ACACAC: ColorU = ColorU { r: 172, g: 172, b: 172, a: 255 }; // #acacac
+const COLOR_DCDCDC: ColorU = ColorU { r: 220, g: 220, b: 220, a: 255 }; // #dcdcdc
+const COLOR_DAECFC: ColorU = ColorU { r: 218, g: 236, b: 252, a: 255 }; // #daecfc
+const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4c4c4c
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+
+const POPUP_BACKGROUND_WHITE: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_FFFFFF)];
+const POPUP_BACKGROUND: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(POPUP_BACKGROUND_WHITE);
+
+const HIGHLIGHT_BACKGROUND_BLUE: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_DAECFC)];
+const HIGHLIGHT_BACKGROUND: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(HIGHLIGHT_BACKGROUND_BLUE);
+const NO_BACKGROUND: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(&[]);
+
+/// Height of a single choice in the popup list
+const DROPDOWN_CHOICE_HEIGHT: f32 = 20.0;
+/// Height of the search field on top of the popup list
+const DROPDOWN_SEARCH_HEIGHT: f32 = 22.0;
+/// How many choices are visible before the popup list starts to scroll
+const DROPDOWN_MAX_VISIBLE_CHOICES: usize = 10;
+
+// -- popup window style
+
+static DROPDOWN_POPUP_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_background_content(POPUP_BACKGROUND)),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    Normal(CssProperty::const_font_family(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_18001933966972968559_ITEMS))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+
+    // border: 1px solid #acacac;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_ACACAC })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_ACACAC })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_ACACAC })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_ACACAC })),
+];
+
+static DROPDOWN_SEARCH_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_height(LayoutHeight::const_px(22))),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(0))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_DCDCDC })),
+];
+
+static DROPDOWN_SEARCH_PLACEHOLDER_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_9B9B9B })),
+];
+
+static DROPDOWN_LIST_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Auto)),
+];
+
+static DROPDOWN_CHOICE_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_height(LayoutHeight::const_px(20))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    Normal(CssProperty::const_cursor(StyleCursor::Default)),
+    Hover(CssProperty::const_background_content(HIGHLIGHT_BACKGROUND)),
+];
+
+static DROPDOWN_CHOICE_HIGHLIGHTED_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_height(LayoutHeight::const_px(20))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    Normal(CssProperty::const_cursor(StyleCursor::Default)),
+    Normal(CssProperty::const_background_content(HIGHLIGHT_BACKGROUND)),
+];
+
+static DROPDOWN_CHOICE_DISABLED_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_height(LayoutHeight::const_px(20))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    Normal(CssProperty::const_cursor(StyleCursor::Default)),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_9B9B9B })),
+];
+
+const IDS_AND_CLASSES_DROPDOWN_POPUP: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-dropdown-popup")),
+];
+const DROPDOWN_POPUP_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_DROPDOWN_POPUP);
+
+const IDS_AND_CLASSES_DROPDOWN_SEARCH: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-dropdown-search")),
+];
+const DROPDOWN_SEARCH_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_DROPDOWN_SEARCH);
+
+const IDS_AND_CLASSES_DROPDOWN_LIST: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-dropdown-list")),
+];
+const DROPDOWN_LIST_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_DROPDOWN_LIST);
+
+const IDS_AND_CLASSES_DROPDOWN_CHOICE: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-dropdown-choice")),
+];
+const DROPDOWN_CHOICE_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_DROPDOWN_CHOICE);
+
 pub type DropDownOnChoiceChangeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, usize) -> Update;
 impl_callback!(DropDownOnChoiceChange, OptionDropDownOnChoiceChange, DropDownOnChoiceChangeCallback, DropDownOnChoiceChangeCallbackType);
 
@@ -241,6 +367,8 @@ impl_callback!(DropDownOnChoiceChange, OptionDropDownOnChoiceChange, DropDownOnC
 pub struct DropDown {
     pub choices: StringVec,
     pub selected: usize,
+    /// Indices of choices that are shown greyed out and can't be selected
+    pub disabled_choices: U32Vec,
     pub on_choice_change: OptionDropDownOnChoiceChange,
 }
 
@@ -249,6 +377,7 @@ impl Default for DropDown {
         Self {
             choices: StringVec::from_const_slice(&[]),
             selected: 0,
+            disabled_choices: U32Vec::from_const_slice(&[]),
             on_choice_change: None.into()
         }
     }
@@ -259,8 +388,7 @@ impl DropDown {
     pub fn new(choices: StringVec) -> Self {
         Self {
             choices,
-            selected: 0,
-            on_choice_change: None.into(),
+            .. Default::default()
         }
     }
 
@@ -270,8 +398,60 @@ impl DropDown {
         m
     }
 
+    pub fn with_selected(&mut self, selected: usize) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_selected(selected);
+        s
+    }
+
+    pub fn set_selected(&mut self, selected: usize) {
+        self.selected = selected;
+    }
+
+    pub fn with_disabled_choices(&mut self, disabled_choices: U32Vec) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_disabled_choices(disabled_choices);
+        s
+    }
+
+    pub fn set_disabled_choices(&mut self, disabled_choices: U32Vec) {
+        self.disabled_choices = disabled_choices;
+    }
+
+    pub fn with_on_choice_change(&mut self, data: RefAny, on_choice_change: DropDownOnChoiceChangeCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_choice_change(data, on_choice_change);
+        s
+    }
+
+    pub fn set_on_choice_change(&mut self, data: RefAny, on_choice_change: DropDownOnChoiceChangeCallbackType) {
+        self.on_choice_change = Some(DropDownOnChoiceChange {
+            data,
+            callback: DropDownOnChoiceChangeCallback { cb: on_choice_change },
+        }).into();
+    }
+
+    fn is_choice_disabled(&self, choice: usize) -> bool {
+        self.disabled_choices.as_ref().iter().any(|d| *d as usize == choice)
+    }
+
+    fn get_choice_text(&self, choice: usize) -> AzString {
+        self.choices.as_ref().get(choice).cloned().unwrap_or_else(|| AzString::from_const_str(""))
+    }
+
+    /// Returns the next selectable choice in the direction of `delta` (used when
+    /// the user presses up / down while the closed dropdown is focused)
+    fn get_next_choice(&self, delta: isize) -> Option<usize> {
+        let all_choices = (0..self.choices.len()).collect::<Vec<_>>();
+        let current = all_choices.iter().position(|c| *c == self.selected);
+        let position = step_enabled(&all_choices, |c| self.is_choice_disabled(c), current, delta)?;
+        all_choices.get(position).copied()
+    }
+
     pub fn dom(self) -> Dom {
-        let data = RefAny::new(self);
+
+        let selected_text = self.get_choice_text(self.selected);
+        let data = RefAny::new(DropDownLocalDataset::new(self));
 
         Dom::div()
         .with_inline_css_props(CSS_MATCH_4428877324022630014)
@@ -293,10 +473,15 @@ impl DropDown {
             .with_tab_index(TabIndex::Auto)
             .with_callbacks(vec![
                 CallbackData {
-                    event: EventFilter::Focus(FocusEventFilter::FocusReceived),
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
                     data: data.clone(),
                     callback: Callback { cb: on_dropdown_click },
-                }
+                },
+                CallbackData {
+                    event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                    data: data.clone(),
+                    callback: Callback { cb: on_dropdown_key_down },
+                },
             ].into())
             .with_children(DomVec::from_vec(vec![
                 Dom::div()
@@ -308,7 +493,7 @@ impl DropDown {
                     IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11862789041977911489)
                 })
                 .with_children(DomVec::from_vec(vec![
-                    Dom::text(AzString::from_const_str("Checkbox"))
+                    Dom::text(selected_text)
                     .with_inline_css_props(CSS_MATCH_16432538576103237591)
                 ])),
                 Dom::div()
@@ -344,173 +529,586 @@ impl DropDown {
     }
 }
 
-// dataset holding the choices
+/// Starting from the position `current` in `choices`, walks `delta` selectable
+/// choices forward or backward, skipping disabled ones and stopping at either end.
+/// Returns the new position in `choices` (not the choice itself).
+fn step_enabled<F: Fn(usize) -> bool>(choices: &[usize], is_disabled: F, current: Option<usize>, delta: isize) -> Option<usize> {
+
+    let mut enabled = choices.iter().enumerate().filter(|(_, c)| !is_disabled(**c)).map(|(i, _)| i);
+
+    let current = match current {
+        Some(s) => s,
+        None => {
+            // nothing highlighted yet: moving down starts at the top, moving up at the bottom
+            return if delta < 0 { enabled.last() } else { enabled.next() };
+        },
+    };
+
+    let mut result = None;
+    let mut steps = delta.unsigned_abs();
+
+    if delta < 0 {
+        for i in (0..current.min(choices.len())).rev() {
+            if steps == 0 { break; }
+            if !is_disabled(choices[i]) { result = Some(i); steps -= 1; }
+        }
+    } else {
+        for (i, c) in choices.iter().enumerate().skip(current.saturating_add(1)) {
+            if steps == 0 { break; }
+            if !is_disabled(*c) { result = Some(i); steps -= 1; }
+        }
+    }
+
+    // at either end of the list: keep the current choice
+    result.or_else(|| if choices.get(current).map(|c| !is_disabled(*c)).unwrap_or(false) {
+        Some(current)
+    } else {
+        enabled.next()
+    })
+}
+
+/// Returns the scroll offset so that the choice at `row` is fully visible
+fn scroll_to_row(row: usize, scroll_offset: f32, viewport_height: f32) -> f32 {
+    let row_top = row as f32 * DROPDOWN_CHOICE_HEIGHT;
+    let row_bottom = row_top + DROPDOWN_CHOICE_HEIGHT;
+    if row_top < scroll_offset {
+        row_top
+    } else if row_bottom > scroll_offset + viewport_height {
+        (row_bottom - viewport_height).max(0.0)
+    } else {
+        scroll_offset
+    }
+}
+
+/// State of the open popup, shared between the parent window and the popup window
+#[derive(Debug, Clone, PartialEq)]
+struct DropDownPopup {
+    /// Text typed into the search field
+    query: String,
+    /// Choices matching the query, in display order
+    filtered: Vec<usize>,
+    /// Position in `filtered` that is highlighted by the keyboard
+    highlighted: Option<usize>,
+}
+
+impl DropDownPopup {
+
+    fn new(dropdown: &DropDown) -> Self {
+        let mut popup = Self {
+            query: String::new(),
+            filtered: Vec::new(),
+            highlighted: None,
+        };
+        popup.set_query(dropdown, String::new());
+        if !dropdown.is_choice_disabled(dropdown.selected) {
+            if let Some(p) = popup.filtered.iter().position(|c| *c == dropdown.selected) {
+                popup.highlighted = Some(p);
+            }
+        }
+        popup
+    }
+
+    /// Filters the choices (case-insensitive substring match) and
+    /// highlights the first selectable match
+    fn set_query(&mut self, dropdown: &DropDown, query: String) {
+        let query_lowercase = query.to_lowercase();
+        self.filtered = dropdown.choices.as_ref().iter().enumerate()
+            .filter(|(_, c)| c.as_str().to_lowercase().contains(&query_lowercase))
+            .map(|(i, _)| i)
+            .collect();
+        self.query = query;
+        self.highlighted = step_enabled(&self.filtered, |c| dropdown.is_choice_disabled(c), None, 1);
+    }
+
+    fn move_highlight(&mut self, dropdown: &DropDown, delta: isize) {
+        self.highlighted = step_enabled(&self.filtered, |c| dropdown.is_choice_disabled(c), self.highlighted, delta);
+    }
+
+    fn get_highlighted_choice(&self) -> Option<usize> {
+        self.filtered.get(self.highlighted?).copied()
+    }
+}
+
+// dataset holding the dropdown, shared with the popup window while it is open
 struct DropDownLocalDataset {
-    choices: StringVec,
-    on_choice_change: OptionDropDownOnChoiceChange,
-    width_px: f32,
+    dropdown: DropDown,
+    popup: Option<DropDownPopup>,
+    /// Text node displaying the selected choice in the parent window
+    label_node: Option<DomNodeId>,
+}
+
+impl DropDownLocalDataset {
+
+    fn new(dropdown: DropDown) -> Self {
+        Self {
+            dropdown,
+            popup: None,
+            label_node: None,
+        }
+    }
+
+    /// Selects a new choice, updates the label and invokes the user callback.
+    /// Must be called with the `CallbackInfo` of the parent window.
+    fn select_choice(&mut self, info: &mut CallbackInfo, choice: usize) -> Update {
+
+        if choice == self.dropdown.selected || self.dropdown.is_choice_disabled(choice) {
+            return Update::DoNothing;
+        }
+
+        self.dropdown.selected = choice;
+
+        if let Some(label_node) = self.label_node {
+            info.set_string_contents(label_node, self.dropdown.get_choice_text(choice));
+        }
+
+        match self.dropdown.on_choice_change.as_mut() {
+            Some(DropDownOnChoiceChange { data, callback }) => (callback.cb)(data, info, choice),
+            None => Update::DoNothing,
+        }
+    }
+
+    /// Selects a new choice from the popup window and invokes the user callback.
+    /// The label belongs to the parent window, so all windows are re-rendered.
+    fn select_choice_from_popup(&mut self, info: &mut CallbackInfo, choice: usize) -> Update {
+
+        if choice == self.dropdown.selected || self.dropdown.is_choice_disabled(choice) {
+            return Update::DoNothing;
+        }
+
+        self.dropdown.selected = choice;
+
+        if let Some(DropDownOnChoiceChange { data, callback }) = self.dropdown.on_choice_change.as_mut() {
+            (callback.cb)(data, info, choice);
+        }
+
+        Update::RefreshDomAllWindows
+    }
+}
+
+// dataset of a single row in the popup list
+struct DropDownChoiceLocalDataset {
+    dropdown: RefAny,
+    choice: usize,
+}
+
+/// Returns the node of the label text, given the `.__azul-native-dropdown-wrapper` node
+fn get_label_node(info: &CallbackInfo, wrapper: DomNodeId) -> Option<DomNodeId> {
+    info.get_first_child(info.get_first_child(wrapper)?)
 }
 
 extern "C"
 fn on_dropdown_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    open_popup(data, info).unwrap_or(Update::DoNothing)
+}
 
-    use azul_core::window::{
-        WindowCreateOptions,
-        OptionHwndHandle,
-        WindowPosition,
-        RawWindowHandle,
-        WindowsHandle,
-    };
+extern "C"
+fn on_dropdown_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
 
-    println!("dropdown clicked!");
+    fn on_dropdown_key_down_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
 
-    let mut data = match data.downcast_ref::<DropDown>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+        let keyboard_state = info.get_current_keyboard_state();
+        let key = keyboard_state.current_virtual_keycode.into_option()?;
 
-    println!("1!");
+        let delta = match key {
+            VirtualKeyCode::Down if keyboard_state.alt_down() => return open_popup(data, info),
+            VirtualKeyCode::Return | VirtualKeyCode::Space | VirtualKeyCode::F4 => return open_popup(data, info),
+            VirtualKeyCode::Up => -1,
+            VirtualKeyCode::Down => 1,
+            VirtualKeyCode::Home => isize::MIN,
+            VirtualKeyCode::End => isize::MAX,
+            _ => return None,
+        };
 
-    let size = match info.get_node_size(info.get_hit_node()) {
-        Some(s) => s,
-        None => return Update::DoNothing,
-    };
+        let label_node = get_label_node(info, info.get_hit_node());
+        let mut dataset = data.downcast_mut::<DropDownLocalDataset>()?;
+        dataset.label_node = label_node;
+        let next = dataset.dropdown.get_next_choice(delta)?;
+        Some(dataset.select_choice(info, next))
+    }
+
+    on_dropdown_key_down_inner(data, info).unwrap_or(Update::DoNothing)
+}
 
-    println!("2!");
+/// Opens the choices in a borderless child window below the dropdown, so that
+/// the list can overflow the clip rect of the parent DOM and the parent window
+fn open_popup(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
 
-    let position = match info.get_node_position(info.get_hit_node()) {
-        Some(s) => s,
-        None => return Update::DoNothing,
+    use azul_core::window::{
+        WindowCreateOptions,
+        WindowPosition,
     };
 
-    println!("3!");
+    let data_clone = data.clone();
+    let mut dataset = data.downcast_mut::<DropDownLocalDataset>()?;
+
+    if dataset.popup.is_some() {
+        return None;
+    }
+
+    let hit_node = info.get_hit_node();
+    let size = info.get_node_size(hit_node)?;
+    let position = info.get_node_position(hit_node)?;
 
     let mut child_window_state = info.get_current_window_state();
+    let hidpi_factor = child_window_state.size.hidpi_factor;
 
-    // align the child window to the bottom of the checkbox
+    // align the child window to the bottom of the dropdown
     let mut pos = position.get_static_offset();
     pos.y += size.height;
 
-    let window_pos = match child_window_state.position {
-        WindowPosition::Uninitialized => return Update::DoNothing,
+    child_window_state.position = match child_window_state.position {
+        WindowPosition::Uninitialized => return None,
         WindowPosition::Initialized(window_top_left_corner) => {
-            let mut child_window_pos = window_top_left_corner.clone();
-            child_window_pos.x += pos.x as i32;
-            child_window_pos.y += pos.y as i32;
+            let mut child_window_pos = window_top_left_corner;
+            child_window_pos.x += (pos.x * hidpi_factor) as i32;
+            child_window_pos.y += (pos.y * hidpi_factor) as i32;
             WindowPosition::Initialized(child_window_pos)
         },
     };
 
-    println!("4!");
-
-    child_window_state.position = window_pos;
-
     #[cfg(target_os = "windows")] {
-        let raw_window_handle = match info.get_current_window_handle() {
-            RawWindowHandle::Windows(WindowsHandle { hwnd, hinstance }) => {
-                OptionHwndHandle::Some(hwnd)
-            },
-            _ => return Update::DoNothing,
-        };
+        use azul_core::window::{OptionHwndHandle, RawWindowHandle, WindowsHandle};
+        if let RawWindowHandle::Windows(WindowsHandle { hwnd, .. }) = info.get_current_window_handle() {
+            // parent window = this window
+            child_window_state.platform_specific_options.windows_options.parent_window = OptionHwndHandle::Some(hwnd);
+        }
+    }
 
-        // parent window = this window
-        child_window_state.platform_specific_options.windows_options.parent_window = raw_window_handle;
+    #[cfg(target_os = "linux")] {
+        use azul_core::window::XWindowType;
+        // managed by the window manager (not override-redirect), so that
+        // the popup gets the keyboard focus and closes on focus loss
+        child_window_state.platform_specific_options.linux_options.x11_window_types = vec![XWindowType::Combo].into();
     }
 
-    // special callback to pass in the dropdown choices to the child window
+    let visible_choices = dataset.dropdown.choices.len().clamp(1, DROPDOWN_MAX_VISIBLE_CHOICES);
+    child_window_state.title = AzString::from_const_str("");
+    child_window_state.size.dimensions = LogicalSize::new(
+        size.width,
+        DROPDOWN_SEARCH_HEIGHT + visible_choices as f32 * DROPDOWN_CHOICE_HEIGHT + 2.0,
+    );
+    child_window_state.flags.has_decorations = false;
+    child_window_state.flags.is_always_on_top = true;
+    child_window_state.flags.is_resizable = false;
+    child_window_state.flags.is_about_to_close = false;
+    child_window_state.close_callback = None.into();
+
+    // the popup window renders the shared dataset, so it sees the current filter state
     child_window_state.layout_callback = LayoutCallback::Marshaled(MarshaledLayoutCallback {
-        marshal_data: RefAny::new(DropDownLocalDataset {
-            choices: data.choices.clone(),
-            on_choice_change: data.on_choice_change.clone(),
-            width_px: size.width,
-        }),
+        marshal_data: data_clone,
         cb: MarshaledLayoutCallbackInner { cb: dropdownWindowLayoutFn }
     });
 
-    println!("4!");
+    dataset.popup = Some(DropDownPopup::new(&dataset.dropdown));
 
     info.create_window(WindowCreateOptions {
         state: child_window_state,
-        size_to_content: true,
+        size_to_content: false,
         renderer: None.into(),
         theme: None.into(),
         create_callback: None.into(),
         hot_reload: false,
     });
 
-    println!("5!");
-
-    Update::DoNothing
-}
-
-struct ChoiceChangeLocalDataset {
-    choice_id: usize,
-    on_choice_change: OptionDropDownOnChoiceChange,
+    Some(Update::DoNothing)
 }
 
 #[allow(non_snake_case)]
 extern "C"
-fn dropdownWindowLayoutFn(data: &mut RefAny, _: &mut RefAny, info: &mut LayoutCallbackInfo) -> StyledDom {
-
-    println!("rendering window!");
+fn dropdownWindowLayoutFn(data: &mut RefAny, _: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
 
     let data_clone = data.clone();
-    let dropdown_local_dataset = match data.downcast_ref::<DropDownLocalDataset>() {
+    let dataset = match data.downcast_ref::<DropDownLocalDataset>() {
         Some(s) => s,
         None => return StyledDom::default(),
     };
 
-    dropdown_local_dataset.choices.iter().enumerate().map(|(choice_id, choice)| {
-        Dom::text(choice.clone())
-        .with_tab_index(TabIndex::Auto)
-        .with_callbacks(vec![
-            CallbackData {
-                event: EventFilter::Focus(FocusEventFilter::FocusReceived),
-                data: RefAny::new(ChoiceChangeLocalDataset {
-                    choice_id,
-                    on_choice_change: dropdown_local_dataset.on_choice_change.clone(),
-                }),
-                callback: Callback { cb: on_choice_change }
-            }
-        ].into())
-    })
-    .collect::<Dom>()
+    let popup = match dataset.popup.as_ref() {
+        Some(s) => s,
+        None => return StyledDom::default(),
+    };
+
+    let search = if popup.query.is_empty() {
+        Dom::text(AzString::from_const_str("Search..."))
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DROPDOWN_SEARCH_PLACEHOLDER_PROPS))
+    } else {
+        Dom::text(AzString::from(popup.query.clone()))
+    };
+
+    let choices = popup.filtered.iter().enumerate().map(|(row, choice)| {
+        let text = Dom::text(dataset.dropdown.get_choice_text(*choice));
+        if dataset.dropdown.is_choice_disabled(*choice) {
+            Dom::div()
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DROPDOWN_CHOICE_DISABLED_PROPS))
+            .with_ids_and_classes(DROPDOWN_CHOICE_CLASS)
+            .with_children(vec![text].into())
+        } else {
+            let props = if popup.highlighted == Some(row) { DROPDOWN_CHOICE_HIGHLIGHTED_PROPS } else { DROPDOWN_CHOICE_PROPS };
+            Dom::div()
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(props))
+            .with_ids_and_classes(DROPDOWN_CHOICE_CLASS)
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                    data: RefAny::new(DropDownChoiceLocalDataset {
+                        dropdown: data_clone.clone(),
+                        choice: *choice,
+                    }),
+                    callback: Callback { cb: on_choice_click }
+                }
+            ].into())
+            .with_children(vec![text].into())
+        }
+    }).collect::<Vec<_>>();
+
+    let choices = if choices.is_empty() {
+        vec![
+            Dom::div()
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DROPDOWN_CHOICE_DISABLED_PROPS))
+            .with_children(vec![Dom::text(AzString::from_const_str("No matches"))].into())
+        ]
+    } else {
+        choices
+    };
+
+    Dom::div()
+    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DROPDOWN_POPUP_PROPS))
+    .with_ids_and_classes(DROPDOWN_POPUP_CLASS)
     .with_callbacks(vec![
+        CallbackData {
+            event: EventFilter::Window(WindowEventFilter::TextInput),
+            data: data_clone.clone(),
+            callback: Callback { cb: on_popup_text_input }
+        },
+        CallbackData {
+            event: EventFilter::Window(WindowEventFilter::VirtualKeyDown),
+            data: data_clone.clone(),
+            callback: Callback { cb: on_popup_key_down }
+        },
         CallbackData {
             event: EventFilter::Window(WindowEventFilter::WindowFocusLost),
             data: data_clone,
             callback: Callback { cb: close_choice_window }
-        }
+        },
+    ].into())
+    .with_children(vec![
+        Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DROPDOWN_SEARCH_PROPS))
+        .with_ids_and_classes(DROPDOWN_SEARCH_CLASS)
+        .with_children(vec![search].into()),
+        Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(DROPDOWN_LIST_PROPS))
+        .with_ids_and_classes(DROPDOWN_LIST_CLASS)
+        .with_children(choices.into()),
     ].into())
     .style(Css::empty())
 }
 
+/// Returns the `.__azul-native-dropdown-list` node, given the popup root node
+fn get_popup_list_node(info: &CallbackInfo, popup_root: DomNodeId) -> Option<DomNodeId> {
+    info.get_next_sibling(info.get_first_child(popup_root)?)
+}
+
+fn get_popup_row_node(info: &CallbackInfo, list: DomNodeId, row: usize) -> Option<DomNodeId> {
+    let mut node = info.get_first_child(list)?;
+    for _ in 0..row {
+        node = info.get_next_sibling(node)?;
+    }
+    Some(node)
+}
+
+extern "C"
+fn on_popup_text_input(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_popup_text_input_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let c = core::char::from_u32(keyboard_state.current_char.into_option()?)?;
+        if c.is_control() {
+            return None;
+        }
+
+        let mut dataset = data.downcast_mut::<DropDownLocalDataset>()?;
+        let dataset = &mut *dataset;
+        let popup = dataset.popup.as_mut()?;
+
+        let mut query = popup.query.clone();
+        query.push(c);
+        popup.set_query(&dataset.dropdown, query);
+
+        if let Some(list) = get_popup_list_node(info, info.get_hit_node()) {
+            info.set_scroll_position(list, LogicalPosition::new(0.0, 0.0));
+        }
+
+        Some(Update::RefreshDom)
+    }
+
+    on_popup_text_input_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
 extern "C"
-fn on_choice_change(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+fn on_popup_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
 
-    let result = {
-        let mut data = match data.downcast_mut::<ChoiceChangeLocalDataset>() {
-            Some(s) => s,
-            None => return Update::DoNothing,
+    fn on_popup_key_down_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let key = info.get_current_keyboard_state().current_virtual_keycode.into_option()?;
+
+        let delta = match key {
+            VirtualKeyCode::Escape => return Some(close_choice_window(data, info)),
+            VirtualKeyCode::Return => {
+                let choice = data.downcast_ref::<DropDownLocalDataset>()?.popup.as_ref()?.get_highlighted_choice();
+                return Some(choose_and_close(data, info, choice));
+            },
+            VirtualKeyCode::Back => {
+                let mut dataset = data.downcast_mut::<DropDownLocalDataset>()?;
+                let dataset = &mut *dataset;
+                let popup = dataset.popup.as_mut()?;
+                let mut query = popup.query.clone();
+                query.pop()?;
+                popup.set_query(&dataset.dropdown, query);
+                return Some(Update::RefreshDom);
+            },
+            VirtualKeyCode::Up => -1,
+            VirtualKeyCode::Down => 1,
+            VirtualKeyCode::PageUp => -(DROPDOWN_MAX_VISIBLE_CHOICES as isize),
+            VirtualKeyCode::PageDown => DROPDOWN_MAX_VISIBLE_CHOICES as isize,
+            VirtualKeyCode::Home => isize::MIN,
+            VirtualKeyCode::End => isize::MAX,
+            _ => return None,
         };
 
-        let choice_id = data.choice_id;
+        let mut dataset = data.downcast_mut::<DropDownLocalDataset>()?;
+        let dataset = &mut *dataset;
+        let popup = dataset.popup.as_mut()?;
 
-        match data.on_choice_change.as_mut() {
-            Some(DropDownOnChoiceChange { data, callback }) => (callback.cb)(data, info, choice_id),
-            None => Update::DoNothing,
+        let previous = popup.highlighted;
+        popup.move_highlight(&dataset.dropdown, delta);
+        let current = popup.highlighted?;
+        if previous == Some(current) {
+            return None;
+        }
+
+        // move the highlight without rebuilding the list, so the scroll position is kept
+        let list = get_popup_list_node(info, info.get_hit_node())?;
+        if let Some(previous_row) = previous.and_then(|p| get_popup_row_node(info, list, p)) {
+            info.set_css_property(previous_row, CssProperty::background_content(NO_BACKGROUND));
+        }
+        if let Some(current_row) = get_popup_row_node(info, list, current) {
+            info.set_css_property(current_row, CssProperty::background_content(HIGHLIGHT_BACKGROUND));
         }
+
+        let scroll_offset = info.get_scroll_position(list).map(|p| p.y).unwrap_or(0.0);
+        let viewport_height = info.get_node_size(list).map(|s| s.height).unwrap_or(0.0);
+        let new_scroll_offset = scroll_to_row(current, scroll_offset, viewport_height);
+        if new_scroll_offset != scroll_offset {
+            info.set_scroll_position(list, LogicalPosition::new(0.0, new_scroll_offset));
+        }
+
+        Some(Update::DoNothing)
+    }
+
+    on_popup_key_down_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C"
+fn on_choice_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    let (mut dropdown, choice) = match data.downcast_ref::<DropDownChoiceLocalDataset>() {
+        Some(s) => (s.dropdown.clone(), s.choice),
+        None => return Update::DoNothing,
     };
 
-    close_choice_window(data, info);
+    choose_and_close(&mut dropdown, info, Some(choice))
+}
+
+/// Closes the popup window, selecting the `choice` (if any) first
+fn choose_and_close(data: &mut RefAny, info: &mut CallbackInfo, choice: Option<usize>) -> Update {
+
+    let update = match (data.downcast_mut::<DropDownLocalDataset>(), choice) {
+        (Some(mut dataset), Some(choice)) => dataset.select_choice_from_popup(info, choice),
+        _ => Update::DoNothing,
+    };
 
-    result
+    close_choice_window(data, info);
+    update
 }
 
 extern "C"
-fn close_choice_window(_: &mut RefAny, info: &mut CallbackInfo) -> Update {
+fn close_choice_window(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    if let Some(mut dataset) = data.downcast_mut::<DropDownLocalDataset>() {
+        dataset.popup = None;
+    }
     let mut flags = info.get_current_window_flags();
     flags.is_about_to_close = true;
     info.set_window_flags(flags);
-    Update::RefreshDom
-}
\ No newline at end of file
+    Update::DoNothing
+}
+
+#[cfg(test)]
+mod drop_down_test {
+
+    use super::*;
+
+    fn dropdown(choices: &[&str], disabled: &[u32]) -> DropDown {
+        DropDown::new(choices.iter().map(|c| AzString::from(String::from(*c))).collect::<Vec<_>>().into())
+        .with_disabled_choices(disabled.to_vec().into())
+    }
+
+    #[test]
+    fn filtering_is_case_insensitive() {
+        let d = dropdown(&["Apple", "banana", "Pineapple", "Cherry"], &[]);
+        let mut popup = DropDownPopup::new(&d);
+        assert_eq!(popup.filtered, vec![0, 1, 2, 3]);
+
+        popup.set_query(&d, "APP".into());
+        assert_eq!(popup.filtered, vec![0, 2]);
+        assert_eq!(popup.get_highlighted_choice(), Some(0));
+
+        popup.set_query(&d, "xyz".into());
+        assert!(popup.filtered.is_empty());
+        assert_eq!(popup.get_highlighted_choice(), None);
+    }
+
+    #[test]
+    fn highlight_skips_disabled_choices() {
+        let d = dropdown(&["a", "b", "c", "d", "e"], &[0, 2, 4]);
+        let mut popup = DropDownPopup::new(&d);
+
+        // selected choice 0 is disabled, so the first selectable one is highlighted
+        assert_eq!(popup.get_highlighted_choice(), Some(1));
+        popup.move_highlight(&d, 1);
+        assert_eq!(popup.get_highlighted_choice(), Some(3));
+        popup.move_highlight(&d, 1);
+        assert_eq!(popup.get_highlighted_choice(), Some(3));
+        popup.move_highlight(&d, isize::MIN);
+        assert_eq!(popup.get_highlighted_choice(), Some(1));
+    }
+
+    #[test]
+    fn popup_starts_at_the_selected_choice() {
+        let d = dropdown(&["a", "b", "c"], &[]).with_selected(2);
+        let mut popup = DropDownPopup::new(&d);
+        assert_eq!(popup.get_highlighted_choice(), Some(2));
+        popup.move_highlight(&d, -(DROPDOWN_MAX_VISIBLE_CHOICES as isize));
+        assert_eq!(popup.get_highlighted_choice(), Some(0));
+    }
+
+    #[test]
+    fn closed_dropdown_steps_through_enabled_choices() {
+        let mut d = dropdown(&["a", "b", "c", "d"], &[1]);
+        assert_eq!(d.get_next_choice(1), Some(2));
+        assert_eq!(d.get_next_choice(-1), Some(0));
+        d.set_selected(3);
+        assert_eq!(d.get_next_choice(1), Some(3));
+        assert_eq!(d.get_next_choice(-1), Some(2));
+        assert_eq!(d.get_next_choice(isize::MIN), Some(0));
+    }
+
+    #[test]
+    fn scrolling_follows_the_highlight() {
+        let viewport = DROPDOWN_CHOICE_HEIGHT * 3.0;
+        assert_eq!(scroll_to_row(1, 0.0, viewport), 0.0);
+        assert_eq!(scroll_to_row(4, 0.0, viewport), 2.0 * DROPDOWN_CHOICE_HEIGHT);
+        assert_eq!(scroll_to_row(0, 2.0 * DROPDOWN_CHOICE_HEIGHT, viewport), 0.0);
+    }
+}
@@ -1,5 +1,5 @@
 //! File input button, same as `Button`, but selects and
-//! opens a file dialog instead
+//! opens a file dialog instead. Files can also be dropped on the button.
 
 use azul_desktop::{
     css::*,
@@ -7,8 +7,9 @@ use azul_desktop::{
     dom::{
         TabIndex, Dom, IdOrClass, IdOrClass::Class, NodeDataInlineCssPropertyVec, IdOrClassVec,
         NodeDataInlineCssProperty, NodeDataInlineCssProperty::{Normal, Active, Hover, Focus},
+        EventFilter, HoverEventFilter,
     },
-    dialogs::OptionFileTypeList,
+    dialogs::{FileTypeList, OptionFileTypeList},
     resources::{ImageRef, OptionImageRef},
     callbacks::{RefAny, Update, CallbackInfo},
 };
@@ -155,20 +156,64 @@ impl FileInput {
             None => self.default_text.clone(),
         };
 
-        Button {
+        let data = RefAny::new(self.state);
+
+        let mut dom = Button {
             label: button_label,
             image: self.image,
             container_style: self.container_style,
             label_style: self.label_style,
             image_style: self.image_style,
             on_click: Some(ButtonOnClick {
-                data: RefAny::new(self.state),
+                data: data.clone(),
                 callback: ButtonOnClickCallback { cb: fileinput_on_click },
             }).into()
-        }.dom()
+        }.dom();
+
+        dom.root.add_callback(EventFilter::Hover(HoverEventFilter::DroppedFile), data, fileinput_on_file_dropped);
+
+        dom
     }
 }
 
+/// Returns whether the file has one of the extensions of the file type list
+/// (used to filter dropped files, the file dialog filters the files itself)
+fn is_accepted_file_type(path: &str, file_types: Option<&FileTypeList>) -> bool {
+
+    let file_types = match file_types {
+        Some(s) => s,
+        None => return true,
+    };
+
+    let extension = match std::path::Path::new(path).extension() {
+        Some(s) => s.to_string_lossy().to_lowercase(),
+        None => return false,
+    };
+
+    file_types.document_types.as_ref().iter().any(|t| {
+        t.as_str().trim_start_matches("*.").trim_start_matches('.').to_lowercase() == extension
+    })
+}
+
+/// Sets the new path and invokes the `on_path_change` callback
+fn fileinput_set_path(
+    fileinputstatewrapper: &mut FileInputStateWrapper,
+    info: &mut CallbackInfo,
+    path: AzString,
+) -> Update {
+
+    fileinputstatewrapper.inner.path = OptionAzString::Some(path);
+
+    let mut result = match fileinputstatewrapper.on_path_change.as_mut() {
+        Some(FileInputOnPathChange { data, callback }) => (callback.cb)(data, info, &fileinputstatewrapper.inner),
+        None => return Update::DoNothing,
+    };
+
+    result.max_self(Update::RefreshDom);
+
+    result
+}
+
 extern "C" fn fileinput_on_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
 
     use azul_desktop::dialogs::open_file_dialog;
@@ -185,18 +230,50 @@ extern "C" fn fileinput_on_click(data: &mut RefAny, info: &mut CallbackInfo) ->
         fileinputstatewrapper.default_dir.as_ref().map(|s| s.as_str()),
         fileinputstatewrapper.file_types.clone().into_option()
     ) {
-        Some(s) => OptionAzString::Some(s),
+        Some(s) => s,
         None => return Update::DoNothing,
     };
 
-    fileinputstatewrapper.inner.path = user_new_file_selected;
+    fileinput_set_path(fileinputstatewrapper, info, user_new_file_selected)
+}
 
-    let mut result = match fileinputstatewrapper.on_path_change.as_mut() {
-        Some(FileInputOnPathChange { data, callback }) => (callback.cb)(data, info, &fileinputstatewrapper.inner),
+extern "C" fn fileinput_on_file_dropped(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    let dropped_file = match info.get_dropped_file().into_option() {
+        Some(s) => s,
         None => return Update::DoNothing,
     };
 
-    result.max_self(Update::RefreshDom);
+    let mut fileinputstatewrapper = match data.downcast_mut::<FileInputStateWrapper>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+    let mut fileinputstatewrapper = &mut *fileinputstatewrapper;
 
-    result
+    if !is_accepted_file_type(dropped_file.as_str(), fileinputstatewrapper.file_types.as_ref()) {
+        return Update::DoNothing;
+    }
+
+    fileinput_set_path(fileinputstatewrapper, info, dropped_file)
+}
+
+#[cfg(test)]
+mod file_input_test {
+
+    use super::*;
+
+    #[test]
+    fn dropped_files_are_filtered_by_extension() {
+
+        let file_types = FileTypeList {
+            document_types: vec![AzString::from("png"), AzString::from("*.JPG")].into(),
+            document_descriptor: "Images".into(),
+        };
+
+        assert!(is_accepted_file_type("/tmp/a.txt", None));
+        assert!(is_accepted_file_type("/tmp/a.png", Some(&file_types)));
+        assert!(is_accepted_file_type("/tmp/b.jpg", Some(&file_types)));
+        assert!(!is_accepted_file_type("/tmp/a.txt", Some(&file_types)));
+        assert!(!is_accepted_file_type("/tmp/png", Some(&file_types)));
+    }
 }
@@ -7,8 +7,10 @@ use azul_core::window::{
 };
 use azul_desktop::gl::OptionUsize;
 use azul_desktop::callbacks::{
-    CallbackInfo, RefAny, Update
+    Callback, CallbackInfo, RefAny, Update,
+    IFrameCallbackInfo, IFrameCallbackReturn,
 };
+use azul_core::callbacks::DomNodeId;
 use azul_desktop::dom::{
     Dom, IdOrClass, TabIndex,
     IdOrClass::{Id, Class},
@@ -168,7 +170,11 @@ const CSS_MATCH_13758717721055992976: NodeDataInlineCssPropertyVec = NodeDataInl
 
 const CSS_MATCH_15295293133676720691_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-list-header-dragwidth-drag
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(2) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(4) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_percent(100) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(0) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Right(LayoutRightValue::Exact(LayoutRight { inner: PixelValue::const_px(0) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Cursor(StyleCursorValue::Exact(StyleCursor::ColResize))),
     NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute)))
 ];
 const CSS_MATCH_15295293133676720691: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_15295293133676720691_PROPERTIES);
@@ -415,6 +421,51 @@ const IDS_AND_CLASSES_18330792117162403422: &[IdOrClass] = &[
 const COLUMN_NAME_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_18330792117162403422);
 
 
+const IDS_AND_CLASSES_LIST_VIEW_ROWS_SPACER: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-rows-spacer")),
+];
+const ROW_SPACER_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_LIST_VIEW_ROWS_SPACER);
+
+
+const IDS_AND_CLASSES_LIST_VIEW_HEADER_DRAGWIDTH_DRAG: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-dragwidth-drag")),
+];
+const COLUMN_DRAG_HANDLE_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_LIST_VIEW_HEADER_DRAGWIDTH_DRAG);
+
+
+const IDS_AND_CLASSES_LIST_VIEW_HEADER_ARROW_DOWN: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-arrow-down")),
+];
+const ARROW_DOWN_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_LIST_VIEW_HEADER_ARROW_DOWN);
+
+
+const IDS_AND_CLASSES_LIST_VIEW_HEADER_ARROW_DOWN_INNER: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-arrow-down-inner")),
+];
+const ARROW_DOWN_INNER_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_LIST_VIEW_HEADER_ARROW_DOWN_INNER);
+
+
+const IDS_AND_CLASSES_LIST_VIEW_HEADER_ARROW_DOWN_INNER_DECO: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul_native-list-header-arrow-down-inner-deco")),
+];
+const ARROW_DOWN_INNER_DECO_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_LIST_VIEW_HEADER_ARROW_DOWN_INNER_DECO);
+
+const CSS_MATCH_LIST_VIEW_VIRTUAL_ROWS_PROPERTIES: &[NodeDataInlineCssProperty] = &[
+    // .__azul_native-list-rows (virtualized)
+    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Auto))),
+    NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Column)))
+];
+const CSS_MATCH_LIST_VIEW_VIRTUAL_ROWS: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_LIST_VIEW_VIRTUAL_ROWS_PROPERTIES);
+
+/// Default height of a row if the rows are provided by a row provider
+const DEFAULT_ROW_HEIGHT: isize = 20;
+/// Rows that are rendered above and below the visible rows, so that
+/// scrolling by a few rows doesn't have to call the row provider again
+const OVERSCAN_ROWS: usize = 10;
+/// Minimum width that a column can be resized to
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+
 pub type ListViewOnLazyLoadScrollCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &ListViewState) -> Update;
 impl_callback!(ListViewOnLazyLoadScroll, OptionListViewOnLazyLoadScroll, ListViewOnLazyLoadScrollCallback, ListViewOnLazyLoadScrollCallbackType);
 
@@ -424,6 +475,41 @@ impl_callback!(ListViewOnColumnClick, OptionListViewOnColumnClick, ListViewOnCol
 pub type ListViewOnRowClickCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &ListViewState, row_clicked: usize) -> Update;
 impl_callback!(ListViewOnRowClick, OptionListViewOnRowClick, ListViewOnRowClickCallback, ListViewOnRowClickCallbackType);
 
+pub type ListViewOnColumnResizeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &ListViewState, column_resized: usize, new_width: f32) -> Update;
+impl_callback!(ListViewOnColumnResize, OptionListViewOnColumnResize, ListViewOnColumnResizeCallback, ListViewOnColumnResizeCallbackType);
+
+/// Returns the rows in the given range, in the order given by `ListViewState::sorted_by`
+pub type ListViewRowProviderCallbackType = extern "C" fn(&mut RefAny, &ListViewState, ListViewRowRange) -> ListViewRowVec;
+impl_callback!(ListViewRowProvider, OptionListViewRowProvider, ListViewRowProviderCallback, ListViewRowProviderCallbackType);
+
+/// Range of row indices, `start` is inclusive, `end` is exclusive
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct ListViewRowRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl ListViewRowRange {
+
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether all rows of `other` are inside of this range
+    pub fn contains_range(&self, other: &ListViewRowRange) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+}
+
 /// State of the ListView, but without row data
 #[derive(Debug, Clone)]
 #[repr(C)]
@@ -440,6 +526,12 @@ pub struct ListViewState {
     pub current_scroll_position: LogicalPosition,
     /// Current height of the row container
     pub current_content_height: LogicalSize,
+    /// Total number of rows, including the rows that are not in the DOM
+    pub row_count: usize,
+    /// Rows that are currently visible in the row container
+    pub visible_rows: ListViewRowRange,
+    /// Width of each column in pixels, `0.0` for the default width
+    pub column_widths: F32Vec,
 }
 
 /// List view, optionally able to lazy-load data
@@ -471,6 +563,19 @@ pub struct ListView {
     /// What to do when the user left-clicks a row
     /// (usually used for selecting the row depending on the state)
     pub on_row_click: OptionListViewOnRowClick,
+    /// Total number of rows (only used if the rows come from the `row_provider`)
+    pub row_count: usize,
+    /// Height of a single row (only used if the rows come from the `row_provider`)
+    pub row_height: PixelValueNoPercent,
+    /// Width of each column in pixels, `0.0` or a missing entry
+    /// means that the column uses the default width
+    pub column_widths: F32Vec,
+    /// If set, the ListView ignores the `rows` and only requests
+    /// the rows that are currently visible from the row provider
+    pub row_provider: OptionListViewRowProvider,
+    /// What to do when the user has finished resizing a column
+    /// (usually used for storing the new column widths)
+    pub on_column_resize: OptionListViewOnColumnResize,
 }
 
 impl Default for ListView {
@@ -487,6 +592,13 @@ impl Default for ListView {
             on_lazy_load_scroll: None.into(),
             on_column_click: None.into(),
             on_row_click: None.into(),
+            row_count: 0,
+            row_height: PixelValueNoPercent {
+                inner: PixelValue::const_px(DEFAULT_ROW_HEIGHT)
+            },
+            column_widths: F32Vec::from_const_slice(&[]),
+            row_provider: None.into(),
+            on_column_resize: None.into(),
         }
     }
 }
@@ -506,6 +618,60 @@ impl_vec_clone!(ListViewRow, ListViewRowVec, ListViewRowVecDestructor);
 impl_vec_mut!(ListViewRow, ListViewRowVec);
 impl_vec_debug!(ListViewRow, ListViewRowVec);
 
+/// Returns the rows that intersect the row container, given
+/// the current scroll offset of the row container
+fn get_visible_row_range(scroll_offset: f32, viewport_height: f32, row_height: f32, row_count: usize) -> ListViewRowRange {
+
+    if row_count == 0 || row_height <= 0.0 {
+        return ListViewRowRange::new(0, 0);
+    }
+
+    let top = scroll_offset.max(0.0);
+    let bottom = top + viewport_height.max(0.0);
+    let start = ((top / row_height).floor() as usize).min(row_count);
+    let end = ((bottom / row_height).ceil() as usize).min(row_count).max(start);
+
+    ListViewRowRange::new(start, end)
+}
+
+/// Adds `OVERSCAN_ROWS` rows above and below the visible rows
+fn get_rendered_row_range(visible: ListViewRowRange, row_count: usize) -> ListViewRowRange {
+    ListViewRowRange::new(
+        visible.start.saturating_sub(OVERSCAN_ROWS),
+        visible.end.saturating_add(OVERSCAN_ROWS).min(row_count),
+    )
+}
+
+/// Clamps the scroll offset so that the last row can't be scrolled
+/// above the bottom of the row container
+fn clamp_scroll_offset(scroll_offset: f32, viewport_height: f32, content_height: f32) -> f32 {
+    scroll_offset.min(content_height - viewport_height).max(0.0)
+}
+
+/// Appends a fixed width to the default CSS of a column header or cell
+fn with_column_width(css: NodeDataInlineCssPropertyVec, width: Option<f32>) -> NodeDataInlineCssPropertyVec {
+    let width = match width {
+        Some(s) => PixelValue::px(s),
+        None => return css,
+    };
+    let mut props = css.into_library_owned_vec();
+    props.push(NodeDataInlineCssProperty::Normal(CssProperty::MinWidth(LayoutMinWidthValue::Exact(LayoutMinWidth { inner: width }))));
+    props.push(NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: width }))));
+    props.push(NodeDataInlineCssProperty::Normal(CssProperty::MaxWidth(LayoutMaxWidthValue::Exact(LayoutMaxWidth { inner: width }))));
+    props.into()
+}
+
+fn with_height(css: NodeDataInlineCssPropertyVec, height: Option<f32>) -> NodeDataInlineCssPropertyVec {
+    let height = match height {
+        Some(s) => PixelValue::px(s),
+        None => return css,
+    };
+    let mut props = css.into_library_owned_vec();
+    props.push(NodeDataInlineCssProperty::Normal(CssProperty::BoxSizing(LayoutBoxSizingValue::Exact(LayoutBoxSizing::BorderBox))));
+    props.push(NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(0) }))));
+    props.push(NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: height }))));
+    props.into()
+}
 
 impl ListView {
 
@@ -539,6 +705,7 @@ impl ListView {
     }
 
     pub fn set_rows(&mut self, rows: ListViewRowVec) {
+        self.row_count = rows.len();
         self.rows = rows;
     }
 
@@ -582,6 +749,58 @@ impl ListView {
         self.column_context_menu = Some(column_context_menu).into();
     }
 
+    pub fn with_row_height(&mut self, row_height: PixelValueNoPercent) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_row_height(row_height);
+        m
+    }
+
+    pub fn set_row_height(&mut self, row_height: PixelValueNoPercent) {
+        self.row_height = row_height;
+    }
+
+    pub fn with_column_widths(&mut self, column_widths: F32Vec) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_column_widths(column_widths);
+        m
+    }
+
+    pub fn set_column_widths(&mut self, column_widths: F32Vec) {
+        self.column_widths = column_widths;
+    }
+
+    /// Virtualizes the ListView: instead of rendering the `rows`, the ListView only
+    /// asks the row provider for the rows that are currently visible
+    pub fn with_row_provider(&mut self, row_count: usize, data: RefAny, row_provider: ListViewRowProviderCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_row_provider(row_count, data, row_provider);
+        m
+    }
+
+    pub fn set_row_provider(&mut self, row_count: usize, data: RefAny, row_provider: ListViewRowProviderCallbackType) {
+        self.row_count = row_count;
+        self.row_provider = Some(ListViewRowProvider {
+            data,
+            callback: ListViewRowProviderCallback { cb: row_provider }
+        }).into();
+    }
+
+    pub fn with_on_lazy_load_scroll(&mut self, data: RefAny, on_lazy_load_scroll: ListViewOnLazyLoadScrollCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_lazy_load_scroll(data, on_lazy_load_scroll);
+        m
+    }
+
+    /// Called when the user scrolls outside of the rows that are currently
+    /// rendered: store `state.current_scroll_position` and pass it back in
+    /// via `set_scroll_offset` so that the scroll position survives the relayout
+    pub fn set_on_lazy_load_scroll(&mut self, data: RefAny, on_lazy_load_scroll: ListViewOnLazyLoadScrollCallbackType) {
+        self.on_lazy_load_scroll = Some(ListViewOnLazyLoadScroll {
+            data,
+            callback: ListViewOnLazyLoadScrollCallback { cb: on_lazy_load_scroll }
+        }).into();
+    }
+
     pub fn with_on_column_click(&mut self, data: RefAny, on_column_click: ListViewOnColumnClickCallbackType) -> Self {
         let mut m = self.swap_with_default();
         m.set_on_column_click(data, on_column_click);
@@ -608,43 +827,596 @@ impl ListView {
         }).into();
     }
 
-    pub fn dom(self) -> Dom {
-        Dom::div()
-        .with_inline_css_props(CSS_MATCH_17553577885456905601)
-        .with_ids_and_classes(LIST_VIEW_CONTAINER_CLASS)
-        .with_children(DomVec::from_vec(vec![
+    pub fn with_on_column_resize(&mut self, data: RefAny, on_column_resize: ListViewOnColumnResizeCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_column_resize(data, on_column_resize);
+        m
+    }
+
+    pub fn set_on_column_resize(&mut self, data: RefAny, on_column_resize: ListViewOnColumnResizeCallbackType) {
+        self.on_column_resize = Some(ListViewOnColumnResize {
+            data,
+            callback: ListViewOnColumnResizeCallback { cb: on_column_resize }
+        }).into();
+    }
+
+    fn is_virtualized(&self) -> bool {
+        self.row_provider.is_some()
+    }
+
+    fn get_row_height(&self) -> f32 {
+        self.row_height.inner.to_pixels(0.0)
+    }
+
+    fn get_column_width(&self, column: usize) -> Option<f32> {
+        self.column_widths.as_ref().get(column).copied().filter(|w| *w > 0.0)
+    }
+
+    fn set_column_width(&mut self, column: usize, width: f32) {
+        let mut column_widths = self.column_widths.clone().into_library_owned_vec();
+        if column_widths.len() <= column {
+            column_widths.resize(column + 1, 0.0);
+        }
+        column_widths[column] = width;
+        self.column_widths = column_widths.into();
+    }
+
+    /// Height of all rows, including the rows that are not in the DOM
+    fn get_content_height(&self) -> f32 {
+        if self.is_virtualized() {
+            self.row_count as f32 * self.get_row_height()
+        } else {
+            self.content_height.as_ref().map(|h| h.inner.to_pixels(0.0)).unwrap_or(0.0)
+        }
+    }
+
+    fn render_row(&self, row: &ListViewRow, height: Option<f32>) -> Dom {
+
+        use azul_desktop::dom::{
+            AccessibilityInfo, AccessibilityRole,
+            AccessibilityState, AccessibilityStateVec,
+        };
+
+        // the rows can be selected by the screen reader
+        const ROW_STATES: &[AccessibilityState] = &[AccessibilityState::Selectable];
+
+        let height = height.or_else(|| row.height.as_ref().map(|h| h.inner.to_pixels(0.0)));
 
-            // header
+        Dom::div()
+        .with_inline_css_props(with_height(CSS_MATCH_7894335449545988724, height))
+        .with_ids_and_classes(ROW_CLASS.clone())
+        .with_tab_index(TabIndex::Auto)
+        .with_accessibility_info(
+            AccessibilityInfo::new(AccessibilityRole::Row)
+            .with_states(AccessibilityStateVec::from_const_slice(ROW_STATES))
+        )
+        .with_children(row.cells.as_ref().iter().enumerate().map(|(column, cell)| {
             Dom::div()
-            .with_inline_css_props(CSS_MATCH_15315949193378715186)
-            .with_ids_and_classes(HEADER_CONTAINER_CLASS)
-            .with_children(self.columns.iter().map(|col| {
+            .with_inline_css_props(with_column_width(CSS_MATCH_12980082330151137475, self.get_column_width(column)))
+            .with_ids_and_classes(CELL_CLASS)
+            .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::Cell))
+            .with_child(cell.clone())
+        }).collect::<Vec<_>>().into())
+    }
+
+    fn render_header(&self, list: &RefAny) -> Dom {
+
+        use azul_desktop::dom::{
+            AccessibilityInfo, AccessibilityRole,
+            CallbackData, EventFilter, HoverEventFilter,
+        };
+
+        Dom::div()
+        .with_inline_css_props(CSS_MATCH_15315949193378715186)
+        .with_ids_and_classes(HEADER_CONTAINER_CLASS)
+        .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::Row))
+        .with_children(self.columns.iter().enumerate().map(|(column, col)| {
+
+            let column_dataset = RefAny::new(ListViewColumnLocalDataset {
+                column,
+                list: list.clone(),
+            });
+
+            let mut children = vec![
+                Dom::text(col.clone())
+                .with_inline_css_props(CSS_MATCH_15673486787900743642)
+            ];
+
+            if self.sorted_by.into_option() == Some(column) {
+                children.push(
+                    Dom::div()
+                    .with_inline_css_props(CSS_MATCH_1085706216385961159)
+                    .with_ids_and_classes(ARROW_DOWN_CLASS)
+                    .with_child(
+                        Dom::div()
+                        .with_inline_css_props(CSS_MATCH_13758717721055992976)
+                        .with_ids_and_classes(ARROW_DOWN_INNER_CLASS)
+                        .with_child(
+                            Dom::div()
+                            .with_inline_css_props(CSS_MATCH_1574792189506859253)
+                            .with_ids_and_classes(ARROW_DOWN_INNER_DECO_CLASS)
+                        )
+                    )
+                );
+            }
+
+            children.push(
                 Dom::div()
-                .with_inline_css_props(CSS_MATCH_12498280255863106397)
-                .with_ids_and_classes(COLUMN_NAME_CLASS)
-                .with_child({
-                    Dom::text(col.clone())
-                    .with_inline_css_props(CSS_MATCH_15673486787900743642)
-                })
-            }).collect::<Vec<_>>().into()),
-
-            // rows
+                .with_inline_css_props(CSS_MATCH_15295293133676720691)
+                .with_ids_and_classes(COLUMN_DRAG_HANDLE_CLASS)
+                .with_callbacks(vec![
+                    CallbackData {
+                        event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                        callback: Callback { cb: on_list_view_column_drag_start },
+                        data: column_dataset.clone(),
+                    }
+                ].into())
+            );
+
+            Dom::div()
+            .with_inline_css_props(with_column_width(CSS_MATCH_12498280255863106397, self.get_column_width(column)))
+            .with_ids_and_classes(COLUMN_NAME_CLASS)
+            .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::ColumnHeader))
+            .with_callbacks(vec![
+                CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                    callback: Callback { cb: on_list_view_column_click },
+                    data: column_dataset,
+                }
+            ].into())
+            .with_children(children.into())
+        }).collect::<Vec<_>>().into())
+    }
+
+    pub fn dom(mut self) -> Dom {
+
+        use azul_desktop::dom::{
+            AccessibilityInfo, AccessibilityRole,
+            CallbackData, EventFilter, HoverEventFilter, WindowEventFilter,
+        };
+
+        let rows = core::mem::replace(&mut self.rows, ListViewRowVec::from_const_slice(&[]));
+        let virtualized = self.is_virtualized();
+        let scroll_offset = self.scroll_offset.inner.to_pixels(0.0);
+        let rendered_rows = if virtualized {
+            ListViewRowRange::new(0, 0) // set by the row container on the first render
+        } else {
+            ListViewRowRange::new(0, rows.len())
+        };
+
+        // shared between the header, the row container and the columns
+        let list = RefAny::new(ListViewLocalDataset {
+            list: self,
+            scroll_offset,
+            viewport: LogicalSize::zero(),
+            rendered_rows,
+            has_scroll_frame: false,
+            column_drag: None,
+            suppress_column_click: false,
+        });
+
+        let header = match list.clone().downcast_ref::<ListViewLocalDataset>() {
+            Some(s) => s.list.render_header(&list),
+            None => Dom::div(),
+        };
+
+        // exposed as a table: the header and each row are table rows
+        let row_container = if virtualized {
+            // only the visible rows are in the DOM, the IFrame
+            // calls the row provider when the visible rows change
+            Dom::iframe(list.clone(), on_list_view_render_rows)
+            .with_inline_css_props(CSS_MATCH_LIST_VIEW_VIRTUAL_ROWS)
+            .with_ids_and_classes(ROW_CONTAINER_CLASS)
+        } else {
+            let row_doms = match list.clone().downcast_ref::<ListViewLocalDataset>() {
+                Some(s) => rows.as_ref().iter().map(|row| s.list.render_row(row, None)).collect::<Vec<_>>(),
+                None => Vec::new(),
+            };
             Dom::div()
             .with_inline_css_props(CSS_MATCH_4852927511892172364)
             .with_ids_and_classes(ROW_CONTAINER_CLASS)
-            .with_children(self.rows.into_iter().map(|row| {
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_7894335449545988724)
-                .with_tab_index(TabIndex::Auto)
-                .with_ids_and_classes(ROW_CLASS.clone())
-                .with_tab_index(TabIndex::Auto)
-                .with_children(row.cells.as_ref().iter().map(|cell| {
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_12980082330151137475)
-                    .with_ids_and_classes(CELL_CLASS)
-                    .with_child(cell.clone())
-                }).collect::<Vec<_>>().into())
-            }).collect::<Vec<_>>().into())
-        ]))
+            .with_children(row_doms.into())
+        };
+
+        Dom::div()
+        .with_inline_css_props(CSS_MATCH_17553577885456905601)
+        .with_ids_and_classes(LIST_VIEW_CONTAINER_CLASS)
+        .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::Table))
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                callback: Callback { cb: on_list_view_scroll },
+                data: list.clone(),
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseDown),
+                callback: Callback { cb: on_list_view_mouse_down },
+                data: list.clone(),
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                callback: Callback { cb: on_list_view_column_drag },
+                data: list.clone(),
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                callback: Callback { cb: on_list_view_column_drag_end },
+                data: list,
+            },
+        ].into())
+        .with_children(DomVec::from_vec(vec![header, row_container]))
+    }
+}
+
+/// Column that is currently being resized by dragging the edge of the column header
+#[derive(Debug, Copy, Clone)]
+struct ListViewColumnDrag {
+    column: usize,
+    header_node: DomNodeId,
+    start_cursor_x: f32,
+    start_width: f32,
+    current_width: f32,
+}
+
+struct ListViewLocalDataset {
+    /// ListView without the row data
+    list: ListView,
+    /// Current vertical scroll position of the rows
+    scroll_offset: f32,
+    /// Size of the row container during the last render
+    viewport: LogicalSize,
+    /// Rows that are currently in the DOM
+    rendered_rows: ListViewRowRange,
+    /// Set after the first render of the rows, from then on the
+    /// scroll offset of the row container is the scroll position
+    has_scroll_frame: bool,
+    column_drag: Option<ListViewColumnDrag>,
+    /// Set when a column resize ends, so that releasing the mouse
+    /// on the column header doesn't sort by that column
+    suppress_column_click: bool,
+}
+
+struct ListViewColumnLocalDataset {
+    column: usize,
+    /// `RefAny<ListViewLocalDataset>`, shared with the other columns
+    list: RefAny,
+}
+
+impl ListViewLocalDataset {
+
+    fn get_state(&self) -> ListViewState {
+        let row_height = self.list.get_row_height();
+        ListViewState {
+            columns: self.list.columns.clone(),
+            sorted_by: self.list.sorted_by,
+            current_row_count: self.rendered_rows.len(),
+            scroll_offset: PixelValueNoPercent {
+                inner: PixelValue::px(self.rendered_rows.start as f32 * row_height)
+            },
+            current_scroll_position: LogicalPosition::new(0.0, self.scroll_offset),
+            current_content_height: LogicalSize::new(self.viewport.width, self.list.get_content_height()),
+            row_count: self.list.row_count,
+            visible_rows: get_visible_row_range(self.scroll_offset, self.viewport.height, row_height, self.list.row_count),
+            column_widths: self.list.column_widths.clone(),
+        }
+    }
+
+    /// Calls the row provider for the rows around the visible rows and builds
+    /// the row container: a spacer for the rows above, the rows and a spacer
+    /// for the rows below, so that the scroll height matches the full list
+    fn render_rows(&mut self) -> Dom {
+
+        let row_height = self.list.get_row_height();
+        let row_count = self.list.row_count;
+        let visible = get_visible_row_range(self.scroll_offset, self.viewport.height, row_height, row_count);
+        let range = get_rendered_row_range(visible, row_count);
+        self.rendered_rows = range;
+
+        let state = self.get_state();
+        let rows = match self.list.row_provider.as_mut() {
+            Some(ListViewRowProvider { data, callback }) => (callback.cb)(data, &state, range),
+            None => ListViewRowVec::from_const_slice(&[]),
+        };
+
+        let mut children = Vec::with_capacity(range.len() + 2);
+        children.push(
+            Dom::div()
+            .with_inline_css_props(with_height(NodeDataInlineCssPropertyVec::from_const_slice(&[]), Some(range.start as f32 * row_height)))
+            .with_ids_and_classes(ROW_SPACER_CLASS)
+        );
+        // never render more rows than requested, otherwise the
+        // spacer below would be at the wrong position
+        children.extend(rows.as_ref().iter().take(range.len()).map(|row| self.list.render_row(row, Some(row_height))));
+        children.push(
+            Dom::div()
+            .with_inline_css_props(with_height(NodeDataInlineCssPropertyVec::from_const_slice(&[]), Some(row_count.saturating_sub(range.end) as f32 * row_height)))
+            .with_ids_and_classes(ROW_SPACER_CLASS)
+        );
+
+        Dom::div()
+        .with_inline_css_props(CSS_MATCH_4852927511892172364)
+        .with_ids_and_classes(ROW_CONTAINER_CLASS)
+        .with_children(children.into())
+    }
+
+    fn dispatch_lazy_load_scroll(&mut self, info: &mut CallbackInfo) -> Update {
+        let state = self.get_state();
+        match self.list.on_lazy_load_scroll.as_mut() {
+            Some(ListViewOnLazyLoadScroll { data, callback }) => (callback.cb)(data, info, &state),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn dispatch_column_click(&mut self, info: &mut CallbackInfo, column: usize) -> Update {
+        let state = self.get_state();
+        match self.list.on_column_click.as_mut() {
+            Some(ListViewOnColumnClick { data, callback }) => (callback.cb)(data, info, &state, column),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn dispatch_column_resize(&mut self, info: &mut CallbackInfo, column: usize, width: f32) -> Update {
+        let state = self.get_state();
+        match self.list.on_column_resize.as_mut() {
+            Some(ListViewOnColumnResize { data, callback }) => (callback.cb)(data, info, &state, column, width),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+extern "C" fn on_list_view_render_rows(data: &mut RefAny, info: &mut IFrameCallbackInfo) -> IFrameCallbackReturn {
+
+    fn on_list_view_render_rows_inner(data: &mut RefAny, info: &mut IFrameCallbackInfo) -> Option<IFrameCallbackReturn> {
+
+        let mut list = data.downcast_mut::<ListViewLocalDataset>()?;
+
+        list.viewport = info.get_bounds().get_logical_size();
+
+        // the scroll frame of the row container is only known after
+        // the first layout, before that the ListView starts at the
+        // scroll offset that it was created with
+        let content_height = list.list.get_content_height();
+        if list.has_scroll_frame {
+            list.scroll_offset = info.scroll_offset.y;
+        }
+        list.has_scroll_frame = true;
+        list.scroll_offset = clamp_scroll_offset(list.scroll_offset, list.viewport.height, content_height);
+
+        let dom = list.render_rows().style(Css::empty());
+        let row_height = list.list.get_row_height();
+
+        Some(IFrameCallbackReturn {
+            dom,
+            scroll_size: LogicalSize::new(list.viewport.width, content_height),
+            scroll_offset: LogicalPosition::new(0.0, list.scroll_offset),
+            virtual_scroll_size: LogicalSize::new(list.viewport.width, list.rendered_rows.len() as f32 * row_height),
+            virtual_scroll_offset: LogicalPosition::new(0.0, list.rendered_rows.start as f32 * row_height),
+        })
+    }
+
+    on_list_view_render_rows_inner(data, info).unwrap_or_default()
+}
+
+extern "C" fn on_list_view_scroll(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_list_view_scroll_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut list = data.downcast_mut::<ListViewLocalDataset>()?;
+
+        if !list.list.is_virtualized() {
+            return None;
+        }
+
+        let scroll_y = info.get_current_mouse_state().get_scroll_y();
+        if scroll_y == 0.0 {
+            return None;
+        }
+
+        // container > [header, row container]
+        let container = info.get_hit_node();
+        let header = info.get_first_child(container)?;
+        let row_container = info.get_next_sibling(header)?;
+
+        let viewport = info.get_node_size(row_container).unwrap_or(list.viewport);
+        let content_height = list.list.get_content_height();
+        let new_scroll_offset = clamp_scroll_offset(list.scroll_offset + scroll_y, viewport.height, content_height);
+
+        list.viewport = viewport;
+        list.scroll_offset = new_scroll_offset;
+        info.set_scroll_position(row_container, LogicalPosition::new(0.0, new_scroll_offset));
+
+        let visible = get_visible_row_range(new_scroll_offset, viewport.height, list.list.get_row_height(), list.list.row_count);
+
+        if list.rendered_rows.contains_range(&visible) {
+            Some(Update::DoNothing)
+        } else {
+            Some(list.dispatch_lazy_load_scroll(info))
+        }
+    }
+
+    on_list_view_scroll_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_list_view_column_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_list_view_column_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut column_dataset = data.downcast_mut::<ListViewColumnLocalDataset>()?;
+        let column_dataset = &mut *column_dataset;
+        let column = column_dataset.column;
+        let mut list = column_dataset.list.downcast_mut::<ListViewLocalDataset>()?;
+
+        // releasing the mouse after resizing a column is not a click
+        let was_resizing = list.column_drag.is_some() || list.suppress_column_click;
+        list.suppress_column_click = false;
+        if was_resizing {
+            return None;
+        }
+
+        Some(list.dispatch_column_click(info, column))
+    }
+
+    on_list_view_column_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_list_view_mouse_down(data: &mut RefAny, _info: &mut CallbackInfo) -> Update {
+    if let Some(mut list) = data.downcast_mut::<ListViewLocalDataset>() {
+        list.suppress_column_click = false;
+    }
+    Update::DoNothing
+}
+
+extern "C" fn on_list_view_column_drag_start(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_list_view_column_drag_start_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut column_dataset = data.downcast_mut::<ListViewColumnLocalDataset>()?;
+        let column_dataset = &mut *column_dataset;
+        let column = column_dataset.column;
+        let mut list = column_dataset.list.downcast_mut::<ListViewLocalDataset>()?;
+
+        // header item > drag handle
+        let header_node = info.get_parent(info.get_hit_node())?;
+        let start_cursor_x = info.get_cursor_relative_to_viewport().into_option()?.x;
+        let start_width = info.get_node_size(header_node)?.width;
+
+        list.column_drag = Some(ListViewColumnDrag {
+            column,
+            header_node,
+            start_cursor_x,
+            start_width,
+            current_width: start_width,
+        });
+
+        Some(Update::DoNothing)
+    }
+
+    on_list_view_column_drag_start_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_list_view_column_drag(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_list_view_column_drag_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut list = data.downcast_mut::<ListViewLocalDataset>()?;
+        let mut drag = list.column_drag?;
+
+        let cursor_x = info.get_cursor_relative_to_viewport().into_option()?.x;
+        drag.current_width = (drag.start_width + cursor_x - drag.start_cursor_x).max(MIN_COLUMN_WIDTH);
+        list.column_drag = Some(drag);
+
+        // only the header is resized while dragging, the
+        // cells follow once the resize is finished
+        let width = PixelValue::px(drag.current_width);
+        info.set_css_property(drag.header_node, CssProperty::MinWidth(LayoutMinWidthValue::Exact(LayoutMinWidth { inner: width })));
+        info.set_css_property(drag.header_node, CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: width })));
+        info.set_css_property(drag.header_node, CssProperty::MaxWidth(LayoutMaxWidthValue::Exact(LayoutMaxWidth { inner: width })));
+
+        Some(Update::DoNothing)
+    }
+
+    on_list_view_column_drag_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_list_view_column_drag_end(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_list_view_column_drag_end_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut list = data.downcast_mut::<ListViewLocalDataset>()?;
+        let drag = list.column_drag.take()?;
+
+        list.suppress_column_click = true;
+        list.list.set_column_width(drag.column, drag.current_width);
+
+        Some(list.dispatch_column_resize(info, drag.column, drag.current_width))
+    }
+
+    on_list_view_column_drag_end_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+#[cfg(test)]
+mod list_view_test {
+
+    use super::*;
+
+    #[test]
+    fn visible_row_range() {
+        assert_eq!(get_visible_row_range(0.0, 100.0, 20.0, 500_000), ListViewRowRange::new(0, 5));
+        assert_eq!(get_visible_row_range(30.0, 100.0, 20.0, 500_000), ListViewRowRange::new(1, 7));
+        assert_eq!(get_visible_row_range(9_999_900.0, 100.0, 20.0, 500_000), ListViewRowRange::new(499_995, 500_000));
+        assert_eq!(get_visible_row_range(20_000_000.0, 100.0, 20.0, 500_000), ListViewRowRange::new(500_000, 500_000));
+        assert_eq!(get_visible_row_range(0.0, 100.0, 20.0, 0), ListViewRowRange::new(0, 0));
+        assert_eq!(get_visible_row_range(0.0, 100.0, 0.0, 10), ListViewRowRange::new(0, 0));
+    }
+
+    #[test]
+    fn rendered_row_range_adds_overscan() {
+        let rendered = get_rendered_row_range(ListViewRowRange::new(2, 7), 100);
+        assert_eq!(rendered, ListViewRowRange::new(0, 7 + OVERSCAN_ROWS));
+        let rendered = get_rendered_row_range(ListViewRowRange::new(95, 100), 100);
+        assert_eq!(rendered, ListViewRowRange::new(95 - OVERSCAN_ROWS, 100));
+        assert!(rendered.contains_range(&ListViewRowRange::new(95, 100)));
+        assert!(!rendered.contains_range(&ListViewRowRange::new(80, 90)));
+        assert!(rendered.contains_range(&ListViewRowRange::new(0, 0)));
+    }
+
+    #[test]
+    fn scroll_offset_is_clamped() {
+        assert_eq!(clamp_scroll_offset(-10.0, 100.0, 1000.0), 0.0);
+        assert_eq!(clamp_scroll_offset(500.0, 100.0, 1000.0), 500.0);
+        assert_eq!(clamp_scroll_offset(2000.0, 100.0, 1000.0), 900.0);
+        // content smaller than the viewport can't be scrolled
+        assert_eq!(clamp_scroll_offset(50.0, 100.0, 40.0), 0.0);
+    }
+
+    #[test]
+    fn column_widths() {
+        let mut list = ListView::new(StringVec::from_const_slice(&[]));
+        assert_eq!(list.get_column_width(0), None);
+        list.set_column_width(2, 150.0);
+        assert_eq!(list.column_widths.as_ref(), &[0.0, 0.0, 150.0]);
+        assert_eq!(list.get_column_width(0), None);
+        assert_eq!(list.get_column_width(2), Some(150.0));
+    }
+
+    extern "C" fn provide_rows(_: &mut RefAny, state: &ListViewState, range: ListViewRowRange) -> ListViewRowVec {
+        // the rows have to be requested in the sort order of the ListView
+        if state.sorted_by.into_option() != Some(1) {
+            return ListViewRowVec::from_const_slice(&[]);
+        }
+        (range.start..range.end).map(|_| ListViewRow {
+            cells: DomVec::from_vec(vec![Dom::div()]),
+            height: None.into(),
+        }).collect::<Vec<_>>().into()
+    }
+
+    #[test]
+    fn row_provider_renders_only_visible_rows() {
+
+        let mut dataset = ListViewLocalDataset {
+            list: ListView::new(StringVec::from_const_slice(&[]))
+                .with_sorted_by(Some(1).into())
+                .with_row_provider(500_000, RefAny::new(()), provide_rows),
+            scroll_offset: 200_000.0,
+            viewport: LogicalSize::new(300.0, 100.0),
+            rendered_rows: ListViewRowRange::new(0, 0),
+            has_scroll_frame: true,
+            column_drag: None,
+            suppress_column_click: false,
+        };
+
+        let dom = dataset.render_rows();
+
+        // rows 10_000..10_005 are visible
+        let expected = ListViewRowRange::new(10_000 - OVERSCAN_ROWS, 10_005 + OVERSCAN_ROWS);
+        assert_eq!(dataset.rendered_rows, expected);
+        // spacer + rows + spacer
+        assert_eq!(dom.children.as_ref().len(), expected.len() + 2);
+
+        let state = dataset.get_state();
+        assert_eq!(state.row_count, 500_000);
+        assert_eq!(state.visible_rows, ListViewRowRange::new(10_000, 10_005));
+        assert_eq!(state.current_row_count, expected.len());
+        assert_eq!(state.current_content_height.height, 500_000.0 * DEFAULT_ROW_HEIGHT as f32);
     }
 }
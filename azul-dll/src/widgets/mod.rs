@@ -96,10 +96,14 @@ pub mod text_input;
 pub mod number_input;
 /// Progress bar widget
 pub mod progressbar;
+/// Circular "busy" spinner widget
+pub mod spinner;
 /// Tab container widgets
 pub mod tabs;
 /// Frame container widget
 pub mod frame;
+/// Resizable panes separated by draggable handles
+pub mod splitter;
 /// Node graph widget
 pub mod node_graph;
 /// List view widget
@@ -112,7 +116,7 @@ pub mod drop_down;
 pub mod ribbon;
 // /// Spreadsheet (iframe) widget
 // pub mod spreadsheet;
-// /// Slider widget
-// pub mod slider;
-// /// Multi-line text input
-// pub mod text_edit;
\ No newline at end of file
+/// Horizontal / vertical slider and two-thumb range slider
+pub mod slider;
+/// Multi-line text input
+pub mod text_area;
\ No newline at end of file
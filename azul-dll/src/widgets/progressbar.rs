@@ -1,11 +1,19 @@
+use alloc::vec::Vec;
 use azul_desktop::{
     dom::{
         Dom, NodeDataInlineCssProperty, NodeDataInlineCssPropertyVec,
         NodeDataInlineCssProperty::{Normal, Focus}, IdOrClassVec,
-        IdOrClass::Class, IdOrClass
+        IdOrClass::Class, IdOrClass, DomVec, CallbackData,
+        EventFilter, ComponentEventFilter,
     },
+    callbacks::{
+        Update, RefAny, CallbackInfo, Callback,
+        TimerCallbackInfo, TimerCallbackReturn,
+    },
+    task::{Timer, TerminateTimer, Duration, SystemTimeDiff},
+    str::{fmt_string, FmtArg, FmtArgVec, FmtValue},
     css::*,
-    css::AzString,
+    css::{AzString, OptionAzString},
 };
 
 const STYLE_BACKGROUND_CONTENT_2688422633177340412_ITEMS: &[StyleBackgroundContent] = &[
@@ -44,6 +52,41 @@ const LINEAR_COLOR_STOP_3104396762583413726_ITEMS: &[NormalizedLinearColorStop]
     NormalizedLinearColorStop { offset: PercentageValue::const_new(100), color: ColorU { r: 203, g: 203, b: 203, a: 255 } }
 ];
 
+const SANS_SERIF_STR: &str = "sans-serif";
+const SANS_SERIF: AzString = AzString::from_const_str(SANS_SERIF_STR);
+const SANS_SERIF_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(SANS_SERIF)];
+const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(SANS_SERIF_FAMILIES);
+
+const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4C4C4C
+const COLOR_STRIPE: ColorU = ColorU { r: 255, g: 255, b: 255, a: 70 };
+const COLOR_TRANSPARENT: ColorU = ColorU { r: 0, g: 0, b: 0, a: 0 };
+
+static PROGRESS_BAR_TEXT_PROPS: &[NodeDataInlineCssProperty] = &[
+    // .__azul-native-progress-bar-text
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_right(LayoutRight::const_px(0))),
+    Normal(CssProperty::const_bottom(LayoutBottom::const_px(0))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    Normal(CssProperty::const_text_align(StyleTextAlign::Center)),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+/// Number of stripes drawn over the bar in `ProgressBarStyle::Stripes`
+const PROGRESS_BAR_STRIPE_COUNT: usize = 24;
+
+/// Width of the moving bar in indeterminate mode, in percent of the container
+const MARQUEE_WIDTH_PERCENT: f32 = 25.0;
+/// How far the moving bar travels per timer tick, in percent of the container
+const MARQUEE_STEP_PERCENT: f32 = 2.5;
+const MARQUEE_INTERVAL_MS: u64 = 30;
+
+/// Since the layout solver doesn't support percentages in relation to the
+/// parent, the bar widths are expressed as flex-grow factors, see `dom()`
+const FLEX_GROW_PER_PERCENT: f32 = 10000000.0 / 100.0;
+
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct ProgressBar {
@@ -51,6 +94,11 @@ pub struct ProgressBar {
     pub height: PixelValue,
     pub bar_background: StyleBackgroundContentVec,
     pub container_background: StyleBackgroundContentVec,
+    pub style: ProgressBarStyle,
+    /// Text drawn over the bar, formatted with `fmt_string`: the
+    /// arguments `{percent}` (rounded, `u32`) and `{percent_done}` (`f32`)
+    /// are available. If unset and `display_percentage` is true, shows "{percent}%".
+    pub text_format: OptionAzString,
 }
 
 #[derive(Debug, Clone)]
@@ -58,6 +106,42 @@ pub struct ProgressBar {
 pub struct ProgressBarState {
     pub percent_done: f32,
     pub display_percentage: bool,
+    pub mode: ProgressBarMode,
+}
+
+/// Whether the progress bar shows a known amount of progress
+/// or an animated "marquee" for operations without a known total
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum ProgressBarMode {
+    Determinate,
+    Indeterminate,
+}
+
+impl Default for ProgressBarMode {
+    fn default() -> Self {
+        ProgressBarMode::Determinate
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum ProgressBarStyle {
+    /// Draws the bar with the `bar_background`
+    Solid,
+    /// Draws diagonal stripes on top of the `bar_background`
+    Stripes,
+}
+
+impl Default for ProgressBarStyle {
+    fn default() -> Self {
+        ProgressBarStyle::Solid
+    }
+}
+
+/// Timer data of an indeterminate progress bar
+struct ProgressBarMarquee {
+    step: usize,
 }
 
 impl ProgressBar {
@@ -65,13 +149,23 @@ impl ProgressBar {
     #[inline]
     pub fn new(percent_done: f32) -> Self {
         Self {
-            state: ProgressBarState { percent_done, display_percentage: false },
+            state: ProgressBarState { percent_done, display_percentage: false, mode: ProgressBarMode::Determinate },
             height: PixelValue::const_px(15),
             bar_background: StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_2688422633177340412_ITEMS),
             container_background: StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_14586281004485141058_ITEMS),
+            style: ProgressBarStyle::Solid,
+            text_format: None.into(),
         }
     }
 
+    /// Creates a progress bar for an operation without a known total
+    #[inline]
+    pub fn indeterminate() -> Self {
+        let mut s = Self::new(0.0);
+        s.state.mode = ProgressBarMode::Indeterminate;
+        s
+    }
+
     #[inline]
     pub fn swap_with_default(&mut self) -> Self {
         let mut s = Self::new(0.0);
@@ -91,9 +185,129 @@ impl ProgressBar {
         self.height = height;
     }
 
-    pub fn dom(&self) -> Dom {
+    pub fn set_mode(&mut self, mode: ProgressBarMode) {
+        self.state.mode = mode;
+    }
+
+    pub fn with_mode(&mut self, mode: ProgressBarMode) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_mode(mode);
+        s
+    }
+
+    pub fn set_style(&mut self, style: ProgressBarStyle) {
+        self.style = style;
+    }
+
+    pub fn with_style(&mut self, style: ProgressBarStyle) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_style(style);
+        s
+    }
+
+    pub fn set_display_percentage(&mut self, display_percentage: bool) {
+        self.state.display_percentage = display_percentage;
+    }
+
+    pub fn with_display_percentage(&mut self, display_percentage: bool) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_display_percentage(display_percentage);
+        s
+    }
+
+    pub fn set_text_format(&mut self, text_format: AzString) {
+        self.text_format = Some(text_format).into();
+    }
+
+    pub fn with_text_format(&mut self, text_format: AzString) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_text_format(text_format);
+        s
+    }
+
+    /// Returns the text drawn over the bar, if any
+    pub fn get_text(&self) -> Option<String> {
+        let format = match self.text_format.as_ref() {
+            Some(s) => s.clone(),
+            None if self.state.display_percentage &&
+                    self.state.mode == ProgressBarMode::Determinate => AzString::from_const_str("{percent}%"),
+            None => return None,
+        };
+
+        let percent_done = self.state.percent_done.max(0.0).min(100.0);
+        Some(fmt_string(format, FmtArgVec::from_vec(vec![
+            FmtArg { key: AzString::from_const_str("percent"), value: FmtValue::Uint(percent_done.round() as u32) },
+            FmtArg { key: AzString::from_const_str("percent_done"), value: FmtValue::Float(percent_done) },
+        ])))
+    }
+
+    fn get_bar_background(&self) -> StyleBackgroundContentVec {
+        match self.style {
+            ProgressBarStyle::Solid => self.bar_background.clone(),
+            ProgressBarStyle::Stripes => {
+                let mut v = self.bar_background.clone().into_library_owned_vec();
+                v.push(get_stripes_background(PROGRESS_BAR_STRIPE_COUNT));
+                v.into()
+            }
+        }
+    }
+
+    fn bar_dom(&self, flex_grow: f32) -> Dom {
+        Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(vec![
+            // .__azul-native-progress-bar-bar
+            NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::new(flex_grow) }))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(StyleBoxShadow {
+                    offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
+                    color: ColorU { r: 0, g: 51, b: 0, a: 51 },
+                    blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
+                    spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
+                    clip_mode: BoxShadowClipMode::Inset,
+                }))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(StyleBoxShadow {
+                    offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
+                    color: ColorU { r: 0, g: 51, b: 0, a: 51 },
+                    blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
+                    spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
+                    clip_mode: BoxShadowClipMode::Inset,
+                }))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(StyleBoxShadow {
+                    offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
+                    color: ColorU { r: 0, g: 51, b: 0, a: 51 },
+                    blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
+                    spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
+                    clip_mode: BoxShadowClipMode::Inset,
+                }))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(StyleBoxShadow {
+                    offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
+                    color: ColorU { r: 0, g: 51, b: 0, a: 51 },
+                    blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
+                    spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
+                    clip_mode: BoxShadowClipMode::Inset,
+                }))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomRightRadius(StyleBorderBottomRightRadiusValue::Exact(StyleBorderBottomRightRadius { inner: PixelValue::const_px(1) }))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomLeftRadius(StyleBorderBottomLeftRadiusValue::Exact(StyleBorderBottomLeftRadius { inner: PixelValue::const_px(1) }))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BorderTopRightRadius(StyleBorderTopRightRadiusValue::Exact(StyleBorderTopRightRadius { inner: PixelValue::const_px(1) }))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BorderTopLeftRadius(StyleBorderTopLeftRadiusValue::Exact(StyleBorderTopLeftRadius { inner: PixelValue::const_px(1) }))),
+            NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(self.get_bar_background())))
+        ]))
+        .with_ids_and_classes({
+            const IDS_AND_CLASSES_16512648314570682783: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-progress-bar-bar")),
+            ];
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_16512648314570682783)
+        })
+    }
 
-        use azul_core::dom::DomVec;
+    fn spacer_dom(flex_grow: f32, classes: IdOrClassVec) -> Dom {
+        Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(vec![
+            NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::new(flex_grow) })))
+        ]))
+        .with_ids_and_classes(classes)
+    }
+
+    pub fn dom(&self) -> Dom {
 
         // NOTE: This is a hack, but a quite effective one:
         // since the layout solver doesn't support percentages in relation to the parent,
@@ -103,16 +317,61 @@ impl ProgressBar {
         // 0%:   [ [ flex-grow: 0 ],        [ flex-grow: 10000000 ] ]
         // 60%:  [ [ flex-grow: 4000000 ],  [ flex-grow: 6000000  ] ]
         // 100%: [ [ flex-grow: 10000000 ], [ flex-grow: 0        ] ]
+        //
+        // In indeterminate mode, a third element in front of the bar moves it
+        // to the right, see `get_marquee_flex_grow()`
 
-        let percent_done = self.state.percent_done.max(0.0).min(100.0);
-        let flex_grow_bar = 10000000.0 / 100.0 * percent_done;
-        let flex_grow_remaining = 10000000.0 / 100.0 * (100.0 - percent_done);
+        const IDS_AND_CLASSES_2492405364126620395: &[IdOrClass] = &[
+            Class(AzString::from_const_str("__azul-native-progress-bar-remaining")),
+        ];
+        const IDS_AND_CLASSES_PROGRESS_BAR_LEADING: &[IdOrClass] = &[
+            Class(AzString::from_const_str("__azul-native-progress-bar-leading")),
+        ];
+        const IDS_AND_CLASSES_PROGRESS_BAR_TEXT: &[IdOrClass] = &[
+            Class(AzString::from_const_str("__azul-native-progress-bar-text")),
+        ];
+
+        let mut children = Vec::new();
+        let mut callbacks = Vec::new();
+
+        match self.state.mode {
+            ProgressBarMode::Determinate => {
+                let percent_done = self.state.percent_done.max(0.0).min(100.0);
+                children.push(self.bar_dom(FLEX_GROW_PER_PERCENT * percent_done));
+                children.push(Self::spacer_dom(
+                    FLEX_GROW_PER_PERCENT * (100.0 - percent_done),
+                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2492405364126620395)
+                ));
+            },
+            ProgressBarMode::Indeterminate => {
+                let (leading, bar, remaining) = get_marquee_flex_grow(0);
+                children.push(Self::spacer_dom(leading, IdOrClassVec::from_const_slice(IDS_AND_CLASSES_PROGRESS_BAR_LEADING)));
+                children.push(self.bar_dom(bar));
+                children.push(Self::spacer_dom(remaining, IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2492405364126620395)));
+                // the timer is attached to the container node, so it
+                // stops as soon as the node is removed from the DOM
+                callbacks.push(CallbackData {
+                    event: EventFilter::Component(ComponentEventFilter::AfterMount),
+                    callback: Callback { cb: on_progressbar_mount },
+                    data: RefAny::new(ProgressBarMarquee { step: 0 }),
+                });
+            },
+        }
+
+        if let Some(text) = self.get_text() {
+            children.push(
+                Dom::text(AzString::from(text))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(PROGRESS_BAR_TEXT_PROPS))
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_PROGRESS_BAR_TEXT))
+            );
+        }
 
         Dom::div()
         .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(vec![
            // .__azul-native-progress-bar-container
            NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: self.height.clone() }))),
            NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
+           NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
            NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(StyleBoxShadow {
                offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
                color: ColorU { r: 0, g: 0, b: 0, a: 9 },
@@ -165,62 +424,162 @@ impl ProgressBar {
             ];
             IdOrClassVec::from_const_slice(IDS_AND_CLASSES_10874511710181900075)
         })
-        .with_children(DomVec::from_vec(vec![
-            Dom::div()
-            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(vec![
-                // .__azul-native-progress-bar-bar
-                NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::new(flex_grow_bar) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowBottom(StyleBoxShadowValue::Exact(StyleBoxShadow {
-                        offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
-                        color: ColorU { r: 0, g: 51, b: 0, a: 51 },
-                        blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
-                        spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
-                        clip_mode: BoxShadowClipMode::Inset,
-                    }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowTop(StyleBoxShadowValue::Exact(StyleBoxShadow {
-                        offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
-                        color: ColorU { r: 0, g: 51, b: 0, a: 51 },
-                        blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
-                        spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
-                        clip_mode: BoxShadowClipMode::Inset,
-                    }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowRight(StyleBoxShadowValue::Exact(StyleBoxShadow {
-                        offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
-                        color: ColorU { r: 0, g: 51, b: 0, a: 51 },
-                        blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
-                        spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
-                        clip_mode: BoxShadowClipMode::Inset,
-                    }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BoxShadowLeft(StyleBoxShadowValue::Exact(StyleBoxShadow {
-                        offset: [PixelValueNoPercent { inner: PixelValue::const_px(0) }, PixelValueNoPercent { inner: PixelValue::const_px(0) }],
-                        color: ColorU { r: 0, g: 51, b: 0, a: 51 },
-                        blur_radius: PixelValueNoPercent { inner: PixelValue::const_px(15) },
-                        spread_radius: PixelValueNoPercent { inner: PixelValue::const_px(12) },
-                        clip_mode: BoxShadowClipMode::Inset,
-                    }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomRightRadius(StyleBorderBottomRightRadiusValue::Exact(StyleBorderBottomRightRadius { inner: PixelValue::const_px(1) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomLeftRadius(StyleBorderBottomLeftRadiusValue::Exact(StyleBorderBottomLeftRadius { inner: PixelValue::const_px(1) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderTopRightRadius(StyleBorderTopRightRadiusValue::Exact(StyleBorderTopRightRadius { inner: PixelValue::const_px(1) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BorderTopLeftRadius(StyleBorderTopLeftRadiusValue::Exact(StyleBorderTopLeftRadius { inner: PixelValue::const_px(1) }))),
-                NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(self.bar_background.clone())))
-            ]))
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_16512648314570682783: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-progress-bar-bar")),
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_16512648314570682783)
-            }),
-            Dom::div()
-            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(vec![
-                // .__azul-native-progress-bar-remaining
-                NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::new(flex_grow_remaining) })))
-            ]))
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_2492405364126620395: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-progress-bar-remaining")),
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2492405364126620395)
-            })
-        ]))
+        .with_callbacks(callbacks.into())
+        .with_children(DomVec::from_vec(children))
+    }
+}
+impl From<ProgressBar> for Dom {
+    fn from(p: ProgressBar) -> Dom {
+        p.dom()
+    }
+}
+
+/// Returns a linear gradient with `count` diagonal half-transparent stripes,
+/// drawn on top of the bar background in `ProgressBarStyle::Stripes`
+fn get_stripes_background(count: usize) -> StyleBackgroundContent {
+
+    let stripe_width = 100.0 / count.max(1) as f32;
+    let mut stops = Vec::with_capacity(count * 4);
+
+    for i in 0..count {
+        let start = stripe_width * i as f32;
+        let mid = start + stripe_width / 2.0;
+        let end = start + stripe_width;
+        stops.push(NormalizedLinearColorStop { offset: PercentageValue::new(start), color: COLOR_STRIPE });
+        stops.push(NormalizedLinearColorStop { offset: PercentageValue::new(mid), color: COLOR_STRIPE });
+        stops.push(NormalizedLinearColorStop { offset: PercentageValue::new(mid), color: COLOR_TRANSPARENT });
+        stops.push(NormalizedLinearColorStop { offset: PercentageValue::new(end), color: COLOR_TRANSPARENT });
+    }
+
+    StyleBackgroundContent::LinearGradient(LinearGradient {
+        direction: Direction::FromTo(DirectionCorners { from: DirectionCorner::BottomLeft, to: DirectionCorner::TopRight }),
+        extend_mode: ExtendMode::Clamp,
+        stops: stops.into(),
+    })
+}
+
+/// Returns the flex-grow factors of the (leading spacer, bar, remaining spacer)
+/// of an indeterminate progress bar after `step` timer ticks: the bar slides in
+/// from the left, moves across the container and slides out on the right
+fn get_marquee_flex_grow(step: usize) -> (f32, f32, f32) {
+    let cycle = 100.0 + MARQUEE_WIDTH_PERCENT;
+    let position = (step as f32 * MARQUEE_STEP_PERCENT) % cycle;
+    let bar_start = (position - MARQUEE_WIDTH_PERCENT).max(0.0);
+    let bar_end = position.min(100.0);
+    (
+        FLEX_GROW_PER_PERCENT * bar_start,
+        FLEX_GROW_PER_PERCENT * (bar_end - bar_start),
+        FLEX_GROW_PER_PERCENT * (100.0 - bar_end),
+    )
+}
+
+extern "C"
+fn on_progressbar_mount(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    let mut timer = Timer::new(data.clone(), on_progressbar_marquee_tick, info.get_system_time_fn())
+        .with_interval(Duration::System(SystemTimeDiff::from_millis(MARQUEE_INTERVAL_MS)));
+    timer.node_id = Some(info.get_hit_node()).into();
+    info.start_timer(timer);
+    Update::DoNothing
+}
+
+extern "C"
+fn on_progressbar_marquee_tick(data: &mut RefAny, info: &mut TimerCallbackInfo) -> TimerCallbackReturn {
+
+    fn on_progressbar_marquee_tick_inner(data: &mut RefAny, info: &mut TimerCallbackInfo) -> Option<()> {
+        let mut marquee = data.downcast_mut::<ProgressBarMarquee>()?;
+        let container = info.node_id.into_option()?;
+        let leading = info.callback_info.get_first_child(container)?;
+        let bar = info.callback_info.get_next_sibling(leading)?;
+        let remaining = info.callback_info.get_next_sibling(bar)?;
+
+        marquee.step = marquee.step.wrapping_add(1);
+        let (flex_grow_leading, flex_grow_bar, flex_grow_remaining) = get_marquee_flex_grow(marquee.step);
+
+        for (node, flex_grow) in [(leading, flex_grow_leading), (bar, flex_grow_bar), (remaining, flex_grow_remaining)].iter().copied() {
+            info.callback_info.set_css_property(node, CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow {
+                inner: FloatValue::new(flex_grow)
+            })));
+        }
+
+        Some(())
     }
-}
\ No newline at end of file
+
+    TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: match on_progressbar_marquee_tick_inner(data, info) {
+            Some(()) => TerminateTimer::Continue,
+            None => TerminateTimer::Terminate,
+        },
+    }
+}
+
+#[cfg(test)]
+mod progressbar_test {
+
+    use super::*;
+
+    #[test]
+    fn marquee_flex_grow_covers_container() {
+        for step in 0..200 {
+            let (leading, bar, remaining) = get_marquee_flex_grow(step);
+            assert!(leading >= 0.0 && bar >= 0.0 && remaining >= 0.0);
+            assert!(bar <= FLEX_GROW_PER_PERCENT * MARQUEE_WIDTH_PERCENT + 1.0);
+            let total = leading + bar + remaining;
+            assert!((total - FLEX_GROW_PER_PERCENT * 100.0).abs() < 1.0, "step {}: {}", step, total);
+        }
+    }
+
+    #[test]
+    fn marquee_slides_in_moves_and_wraps() {
+        let p = FLEX_GROW_PER_PERCENT;
+        assert_eq!(get_marquee_flex_grow(0), (0.0, 0.0, 100.0 * p));
+        // sliding in from the left
+        assert_eq!(get_marquee_flex_grow(4), (0.0, 10.0 * p, 90.0 * p));
+        // fully visible, moving right
+        assert_eq!(get_marquee_flex_grow(20), (25.0 * p, 25.0 * p, 50.0 * p));
+        // sliding out on the right
+        assert_eq!(get_marquee_flex_grow(46), (90.0 * p, 10.0 * p, 0.0));
+        // wraps around after a full cycle
+        assert_eq!(get_marquee_flex_grow(50), get_marquee_flex_grow(0));
+    }
+
+    #[test]
+    fn stripes_alternate_with_hard_edges() {
+        let stops = match get_stripes_background(4) {
+            StyleBackgroundContent::LinearGradient(lg) => lg.stops,
+            other => panic!("expected a linear gradient, got {:?}", other),
+        };
+        let stops = stops.as_ref();
+        assert_eq!(stops.len(), 16);
+        assert_eq!(stops[0].offset.get(), 0.0);
+        assert_eq!(stops[1].offset, stops[2].offset);
+        assert_eq!(stops[1].color, COLOR_STRIPE);
+        assert_eq!(stops[2].color, COLOR_TRANSPARENT);
+        assert_eq!(stops[15].offset.get(), 100.0);
+    }
+
+    #[test]
+    fn stripes_are_drawn_on_top_of_the_bar() {
+        let solid = ProgressBar::new(50.0);
+        assert_eq!(solid.get_bar_background().len(), solid.bar_background.len());
+        let striped = ProgressBar::new(50.0).with_style(ProgressBarStyle::Stripes);
+        let bg = striped.get_bar_background();
+        assert_eq!(bg.len(), striped.bar_background.len() + 1);
+        assert_eq!(bg.as_ref()[0], striped.bar_background.as_ref()[0]);
+    }
+
+    #[test]
+    fn text_is_only_shown_if_requested() {
+        assert_eq!(ProgressBar::new(42.0).get_text(), None);
+        assert_eq!(ProgressBar::new(42.4).with_display_percentage(true).get_text(), Some("42%".to_string()));
+        assert_eq!(ProgressBar::indeterminate().with_display_percentage(true).get_text(), None);
+        assert_eq!(
+            ProgressBar::new(150.0).with_text_format("{percent} of 100".into()).get_text(),
+            Some("100 of 100".to_string())
+        );
+        assert_eq!(
+            ProgressBar::indeterminate().with_text_format("Downloading...".into()).get_text(),
+            Some("Downloading...".to_string())
+        );
+    }
+}
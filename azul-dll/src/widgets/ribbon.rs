@@ -1,31 +1,29 @@
+use alloc::vec::Vec;
+use alloc::string::String;
 use azul_desktop::css::*;
 use azul_desktop::css::AzString;
 use azul_desktop::dom::{
-    Dom, DomVec, IdOrClass, TabIndex, HoverEventFilter,
-    IdOrClass::{Id, Class}, IdOrClassVec, CallbackData,
+    Dom, DomVec, IdOrClass, HoverEventFilter,
+    WindowEventFilter, IdOrClass::Class,
+    IdOrClassVec, CallbackData,
     EventFilter, NodeDataInlineCssProperty,
-    NodeDataInlineCssPropertyVec,
+    NodeDataInlineCssPropertyVec, Tooltip,
 };
 use azul_desktop::callbacks::{
-    Callback, CallbackInfo, Update, RefAny,
+    Callback, CallbackInfo, Update, RefAny, DomNodeId,
+};
+use azul_desktop::resources::{ImageRef, OptionImageRef};
+use azul_desktop::gl::OptionUsize;
+use azul_core::window::{
+    Menu, MenuItem, MenuItemVec, StringMenuItem,
+    MenuItemIcon, MenuItemState, MenuPopupPosition,
+    ContextMenuMouseButton, VirtualKeyCode,
 };
 
 const STRING_16146701490593874959: AzString = AzString::from_const_str("sans-serif");
 const STYLE_BACKGROUND_CONTENT_4878363956973295354_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 173, g: 216, b: 230, a: 255 })
 ];
-const STYLE_BACKGROUND_CONTENT_4967804087795204988_ITEMS: &[StyleBackgroundContent] = &[
-    StyleBackgroundContent::Color(ColorU { r: 250, g: 128, b: 114, a: 255 })
-];
-const STYLE_BACKGROUND_CONTENT_8568982142085024634_ITEMS: &[StyleBackgroundContent] = &[
-    StyleBackgroundContent::Color(ColorU { r: 250, g: 235, b: 215, a: 255 })
-];
-const STYLE_BACKGROUND_CONTENT_12869309920691526943_ITEMS: &[StyleBackgroundContent] = &[
-    StyleBackgroundContent::Color(ColorU { r: 240, g: 248, b: 255, a: 255 })
-];
-const STYLE_BACKGROUND_CONTENT_14573424550548235545_ITEMS: &[StyleBackgroundContent] = &[
-    StyleBackgroundContent::Color(ColorU { r: 33, g: 114, b: 69, a: 255 })
-];
 const STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 255, g: 255, b: 255, a: 255 })
 ];
@@ -33,24 +31,6 @@ const STYLE_FONT_FAMILY_8122988506401935406_ITEMS: &[StyleFontFamily] = &[
     StyleFontFamily::System(STRING_16146701490593874959)
 ];
 
-const CSS_MATCH_10111026547520801912_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-table-container .column-wrapper .line-numbers
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(25) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(14) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } })))
-];
-const CSS_MATCH_10111026547520801912: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_10111026547520801912_PROPERTIES);
-
-const CSS_MATCH_10537637882082253178_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-formula-container .formula-commit .btn-2
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_12869309920691526943_ITEMS))))
-];
-const CSS_MATCH_10537637882082253178: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_10537637882082253178_PROPERTIES);
-
 const CSS_MATCH_11184921220530473733_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-ribbon-tabs div.after-tabs
     NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
@@ -60,148 +40,6 @@ const CSS_MATCH_11184921220530473733_PROPERTIES: &[NodeDataInlineCssProperty] =
 ];
 const CSS_MATCH_11184921220530473733: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11184921220530473733_PROPERTIES);
 
-const CSS_MATCH_11324334306954975636_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section.2
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(210) }))),
-    // .__azul_native-ribbon-section
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 225, g: 225, b: 225, a: 255 } })))
-];
-const CSS_MATCH_11324334306954975636: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11324334306954975636_PROPERTIES);
-
-const CSS_MATCH_11749096093730352054_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-formula-container
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(3) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(3) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(10) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(10) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row)))
-];
-const CSS_MATCH_11749096093730352054: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11749096093730352054_PROPERTIES);
-
-const CSS_MATCH_11805228191975472988_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-formula-container .formula-entry
-    NodeDataInlineCssProperty::Normal(CssProperty::MarginRight(LayoutMarginRightValue::Exact(LayoutMarginRight { inner: PixelValue::const_px(3) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } })))
-];
-const CSS_MATCH_11805228191975472988: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11805228191975472988_PROPERTIES);
-
-const CSS_MATCH_11894410514907408907_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-content
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) })))
-];
-const CSS_MATCH_11894410514907408907: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11894410514907408907_PROPERTIES);
-
-const CSS_MATCH_12543025518776072814_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-name
-    NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 68, g: 68, b: 68, a: 255 } })))
-];
-const CSS_MATCH_12543025518776072814: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_12543025518776072814_PROPERTIES);
-
-const CSS_MATCH_12657755885219626491_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-table-container .column-wrapper .line-numbers p
-    NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(13) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 229, g: 229, b: 229, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(LayoutAlignItems::Center)))
-];
-const CSS_MATCH_12657755885219626491: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_12657755885219626491_PROPERTIES);
-
-const CSS_MATCH_12860013474863056225_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section.1
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(135) }))),
-    // .__azul_native-ribbon-section
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 225, g: 225, b: 225, a: 255 } })))
-];
-const CSS_MATCH_12860013474863056225: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_12860013474863056225_PROPERTIES);
-
-const CSS_MATCH_14371786645818370801_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-tabs p.home
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(19) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(19) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 33, g: 114, b: 69, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 33, g: 114, b: 69, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 33, g: 114, b: 69, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 33, g: 114, b: 69, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_14573424550548235545_ITEMS)))),
-    // .__azul_native-ribbon-tabs p
-    NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(14) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(14) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(12) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 101, g: 101, b: 101, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 213, g: 213, b: 213, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS)))),
-    NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(LayoutAlignItems::Center)))
-];
-const CSS_MATCH_14371786645818370801: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14371786645818370801_PROPERTIES);
-
-const CSS_MATCH_14675068197785310311_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-table-container
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS))))
-];
-const CSS_MATCH_14675068197785310311: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14675068197785310311_PROPERTIES);
-
 const CSS_MATCH_14701061083766788292_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-ribbon-action-vertical-large .icon-wrapper
     NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::Center))),
@@ -211,18 +49,6 @@ const CSS_MATCH_14701061083766788292_PROPERTIES: &[NodeDataInlineCssProperty] =
 ];
 const CSS_MATCH_14701061083766788292: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14701061083766788292_PROPERTIES);
 
-const CSS_MATCH_14707506486468900090_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-content
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) })))
-];
-const CSS_MATCH_14707506486468900090: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14707506486468900090_PROPERTIES);
-
-const CSS_MATCH_14738982339524920711_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-content
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) })))
-];
-const CSS_MATCH_14738982339524920711: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14738982339524920711_PROPERTIES);
-
 const CSS_MATCH_15716718910432952660_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-ribbon-action-vertical-large .icon-wrapper .icon
     NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(32) }))),
@@ -231,43 +57,6 @@ const CSS_MATCH_15716718910432952660_PROPERTIES: &[NodeDataInlineCssProperty] =
 ];
 const CSS_MATCH_15716718910432952660: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_15716718910432952660_PROPERTIES);
 
-const CSS_MATCH_15943161397910029460_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-formula-container .formula-commit .btn-1
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4967804087795204988_ITEMS))))
-];
-const CSS_MATCH_15943161397910029460: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_15943161397910029460_PROPERTIES);
-
-const CSS_MATCH_16851364358900804450_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-name
-    NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 68, g: 68, b: 68, a: 255 } })))
-];
-const CSS_MATCH_16851364358900804450: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_16851364358900804450_PROPERTIES);
-
-const CSS_MATCH_17089226259487272686_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section.7
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(185) }))),
-    // .__azul_native-ribbon-section
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 225, g: 225, b: 225, a: 255 } })))
-];
-const CSS_MATCH_17089226259487272686: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17089226259487272686_PROPERTIES);
-
-const CSS_MATCH_17283019665138187991_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-formula-container .formula-commit .btn-3
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(30) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_8568982142085024634_ITEMS))))
-];
-const CSS_MATCH_17283019665138187991: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17283019665138187991_PROPERTIES);
-
 const CSS_MATCH_17524132644355033702_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-ribbon-tabs p.active
     NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 33, g: 114, b: 69, a: 255 } }))),
@@ -315,22 +104,6 @@ const CSS_MATCH_17524132644355033702_PROPERTIES: &[NodeDataInlineCssProperty] =
 ];
 const CSS_MATCH_17524132644355033702: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17524132644355033702_PROPERTIES);
 
-const CSS_MATCH_1934381104964361563_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-action-vertical-large .dropdown
-    NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
-    NodeDataInlineCssProperty::Normal(CssProperty::AlignItems(LayoutAlignItemsValue::Exact(LayoutAlignItems::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::AlignContent(LayoutAlignContentValue::Exact(LayoutAlignContent::Center)))
-];
-const CSS_MATCH_1934381104964361563: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_1934381104964361563_PROPERTIES);
-
-const CSS_MATCH_2161661208916302443_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-formula-container .formula-entry .dropdown-sm
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(10) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_12869309920691526943_ITEMS))))
-];
-const CSS_MATCH_2161661208916302443: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_2161661208916302443_PROPERTIES);
-
 const CSS_MATCH_2233073185823558635_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-ribbon-section-name
     NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
@@ -393,127 +166,6 @@ const CSS_MATCH_3221151331850347044_PROPERTIES: &[NodeDataInlineCssProperty] = &
 ];
 const CSS_MATCH_3221151331850347044: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_3221151331850347044_PROPERTIES);
 
-const CSS_MATCH_3888401522023951407_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section.5
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(180) }))),
-    // .__azul_native-ribbon-section
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 225, g: 225, b: 225, a: 255 } })))
-];
-const CSS_MATCH_3888401522023951407: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_3888401522023951407_PROPERTIES);
-
-const CSS_MATCH_4060245836920688376_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section.6
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(135) }))),
-    // .__azul_native-ribbon-section
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 225, g: 225, b: 225, a: 255 } })))
-];
-const CSS_MATCH_4060245836920688376: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_4060245836920688376_PROPERTIES);
-
-const CSS_MATCH_4538658364223133674_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-content
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) })))
-];
-const CSS_MATCH_4538658364223133674: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_4538658364223133674_PROPERTIES);
-
-const CSS_MATCH_4856252049803891913_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-name
-    NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 68, g: 68, b: 68, a: 255 } })))
-];
-const CSS_MATCH_4856252049803891913: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_4856252049803891913_PROPERTIES);
-
-const CSS_MATCH_489944609689083320_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-table-container .header-row .select-all
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(25) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } })))
-];
-const CSS_MATCH_489944609689083320: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_489944609689083320_PROPERTIES);
-
-const CSS_MATCH_491594124841839797_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-action-vertical-large .dropdown .icon
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_4967804087795204988_ITEMS))))
-];
-const CSS_MATCH_491594124841839797: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_491594124841839797_PROPERTIES);
-
-const CSS_MATCH_5884971763667172938_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-table-container .header-row p
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(65) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(14) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 229, g: 229, b: 229, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } })))
-];
-const CSS_MATCH_5884971763667172938: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_5884971763667172938_PROPERTIES);
-
-const CSS_MATCH_6328747057139953245_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-name
-    NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 68, g: 68, b: 68, a: 255 } })))
-];
-const CSS_MATCH_6328747057139953245: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6328747057139953245_PROPERTIES);
-
-const CSS_MATCH_6727848633830580264_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-table-container .header-row
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(20) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row)))
-];
-const CSS_MATCH_6727848633830580264: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6727848633830580264_PROPERTIES);
-
-const CSS_MATCH_6736299128913213977_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section.4
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(140) }))),
-    // .__azul_native-ribbon-section
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 225, g: 225, b: 225, a: 255 } })))
-];
-const CSS_MATCH_6736299128913213977: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6736299128913213977_PROPERTIES);
-
-const CSS_MATCH_6737656294326280219_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-formula-container .formula-entry .formula-text
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(10) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(10) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(13) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS))))
-];
-const CSS_MATCH_6737656294326280219: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6737656294326280219_PROPERTIES);
-
 const CSS_MATCH_6756514148882865175_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-ribbon-action-vertical-large p
     NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
@@ -525,34 +177,6 @@ const CSS_MATCH_6756514148882865175_PROPERTIES: &[NodeDataInlineCssProperty] = &
 ];
 const CSS_MATCH_6756514148882865175: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6756514148882865175_PROPERTIES);
 
-const CSS_MATCH_681808671153488983_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-formula-container .formula-dropdown
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(100) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(6) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(6) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(3) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(3) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::MarginRight(LayoutMarginRightValue::Exact(LayoutMarginRight { inner: PixelValue::const_px(30) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::JustifyContent(LayoutJustifyContentValue::Exact(LayoutJustifyContent::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(13) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontFamily(StyleFontFamilyVecValue::Exact(StyleFontFamilyVec::from_const_slice(STYLE_FONT_FAMILY_8122988506401935406_ITEMS)))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 34, g: 34, b: 34, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16746671892555275291_ITEMS))))
-];
-const CSS_MATCH_681808671153488983: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_681808671153488983_PROPERTIES);
-
 const CSS_MATCH_7952568575592251546_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul_native-ribbon-action-vertical-large
     NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(4) }))),
@@ -564,487 +188,1480 @@ const CSS_MATCH_7952568575592251546_PROPERTIES: &[NodeDataInlineCssProperty] = &
 ];
 const CSS_MATCH_7952568575592251546: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_7952568575592251546_PROPERTIES);
 
-const CSS_MATCH_8539348830707080062_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .minixel-formula-container .formula-commit
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(110) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::MarginRight(LayoutMarginRightValue::Exact(LayoutMarginRight { inner: PixelValue::const_px(3) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row))),
+const CSS_MATCH_9926913261609802002_PROPERTIES: &[NodeDataInlineCssProperty] = &[
+    // .__azul_native-ribbon-tabs div.between-tabs
+    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(3) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
     NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 171, g: 171, b: 171, a: 255 } })))
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 213, g: 213, b: 213, a: 255 } })))
 ];
-const CSS_MATCH_8539348830707080062: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_8539348830707080062_PROPERTIES);
+const CSS_MATCH_9926913261609802002: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_9926913261609802002_PROPERTIES);
 
-const CSS_MATCH_8561962837455305444_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-content
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) })))
+// -- GROUPS, ITEMS AND KEY TIPS
+
+const COLOR_E1E1E1: ColorU = ColorU { r: 225, g: 225, b: 225, a: 255 }; // #e1e1e1
+const COLOR_444444: ColorU = ColorU { r: 68, g: 68, b: 68, a: 255 }; // #444444
+
+const ITEM_HOVER_BACKGROUND_ITEMS: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 225, g: 239, b: 230, a: 255 })];
+const ITEM_TOGGLED_BACKGROUND_ITEMS: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 197, g: 224, b: 207, a: 255 })];
+const ITEM_NO_BACKGROUND_ITEMS: &[StyleBackgroundContent] = &[];
+const KEY_TIP_BACKGROUND_ITEMS: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(ColorU { r: 255, g: 255, b: 255, a: 255 })];
+
+const ITEM_HOVER_BACKGROUND: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(ITEM_HOVER_BACKGROUND_ITEMS);
+const ITEM_TOGGLED_BACKGROUND: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(ITEM_TOGGLED_BACKGROUND_ITEMS);
+const ITEM_NO_BACKGROUND: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(ITEM_NO_BACKGROUND_ITEMS);
+
+/// Estimated width of one character of a label, used to decide when groups have to shrink
+const RIBBON_CHAR_WIDTH: f32 = 6.0;
+const RIBBON_LARGE_ICON_SIZE: f32 = 32.0;
+const RIBBON_SMALL_ICON_SIZE: f32 = 16.0;
+const RIBBON_ICON_GAP: f32 = 4.0;
+const RIBBON_ARROW_WIDTH: f32 = 10.0;
+const RIBBON_ITEM_PADDING: f32 = 4.0;
+/// Left + right padding and the right border of a group
+const RIBBON_GROUP_PADDING: f32 = 5.0;
+/// Left + right padding of the ribbon body
+const RIBBON_BODY_PADDING: f32 = 4.0;
+const RIBBON_SMALL_ITEMS_PER_COLUMN: usize = 3;
+
+static RIBBON_TAB_EXTRA_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Relative)),
 ];
-const CSS_MATCH_8561962837455305444: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_8561962837455305444_PROPERTIES);
 
-const CSS_MATCH_8787113990689659847_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-name
-    NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 68, g: 68, b: 68, a: 255 } })))
+static RIBBON_GROUP_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
 ];
-const CSS_MATCH_8787113990689659847: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_8787113990689659847_PROPERTIES);
 
-const CSS_MATCH_8808521992961481081_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-name
-    NodeDataInlineCssProperty::Normal(CssProperty::TextAlign(StyleTextAlignValue::Exact(StyleTextAlign::Center))),
-    NodeDataInlineCssProperty::Normal(CssProperty::FontSize(StyleFontSizeValue::Exact(StyleFontSize { inner: PixelValue::const_px(11) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 68, g: 68, b: 68, a: 255 } })))
+static RIBBON_GROUP_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_E1E1E1 })),
 ];
-const CSS_MATCH_8808521992961481081: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_8808521992961481081_PROPERTIES);
 
-const CSS_MATCH_9123706516995286623_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-content
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) })))
+static RIBBON_GROUP_HIDDEN_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_display(LayoutDisplay::None)),
 ];
-const CSS_MATCH_9123706516995286623: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_9123706516995286623_PROPERTIES);
 
-const CSS_MATCH_9206206203058145671_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section-content
-    NodeDataInlineCssProperty::Normal(CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::const_new(1) })))
+static RIBBON_GROUP_CONTENT_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
 ];
-const CSS_MATCH_9206206203058145671: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_9206206203058145671_PROPERTIES);
 
-const CSS_MATCH_970131228357345953_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-section.3
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(265) }))),
-    // .__azul_native-ribbon-section
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 225, g: 225, b: 225, a: 255 } })))
+static RIBBON_COLUMN_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
 ];
-const CSS_MATCH_970131228357345953: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_970131228357345953_PROPERTIES);
 
-const CSS_MATCH_9926913261609802002_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul_native-ribbon-tabs div.between-tabs
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(3) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 213, g: 213, b: 213, a: 255 } })))
+static RIBBON_ITEM_EXTRA_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(ITEM_HOVER_BACKGROUND)),
 ];
-const CSS_MATCH_9926913261609802002: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_9926913261609802002_PROPERTIES);
 
-#[repr(C)]
-pub struct Ribbon {
-    pub tab_active: i32,
-}
+static RIBBON_SMALL_ITEM_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(22))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(4))),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(ITEM_HOVER_BACKGROUND)),
+];
 
-pub type RibbonOnTabClickedCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, i32) -> Update;
-impl_callback!(RibbonOnTabClicked, OptionRibbonOnTabClicked, RibbonOnTabClickedCallback, RibbonOnTabClickedCallbackType);
+static RIBBON_ITEM_TOGGLED_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(ITEM_TOGGLED_BACKGROUND)),
+];
 
-impl Ribbon {
-    pub fn dom(&self, callback: RibbonOnTabClickedCallback, data: RefAny) -> Dom {
-        Dom::div()
-        .with_ids_and_classes({
-            const IDS_AND_CLASSES_9612282517634156717: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul_native-ribbon-container")),
+static RIBBON_ITEM_DISABLED_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_opacity(StyleOpacity::const_new(40))),
+];
 
-            ];
-            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9612282517634156717)
-        })
-        .with_children(DomVec::from_vec(vec![
+static RIBBON_PART_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+];
 
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_2258738109329535793)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_9041457122899952067: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul_native-ribbon-tabs")),
+static RIBBON_SMALL_PART_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+];
 
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9041457122899952067)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::text(AzString::from_const_str("FILE"))
-                .with_inline_css_props(CSS_MATCH_14371786645818370801)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_4826288409200248071: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("home")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_4826288409200248071)
-                }),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_9926913261609802002)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_9410866575549354381: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("between-tabs")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9410866575549354381)
-                }),
-                render_tab_element("HOME", self.tab_active == 0, 0, callback, data.clone()),
-                render_tab_element("INSERT", self.tab_active == 1, 1, callback, data.clone()),
-                render_tab_element("PAGE LAYOUT", self.tab_active == 2, 2, callback, data.clone()),
-                render_tab_element("FORMULAS", self.tab_active == 3, 3, callback, data.clone()),
-                render_tab_element("DATA", self.tab_active == 4, 4, callback, data.clone()),
-                render_tab_element("REVIEW", self.tab_active == 5, 5, callback, data.clone()),
-                render_tab_element("VIEW", self.tab_active == 6, 6, callback, data.clone()),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_11184921220530473733)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_16912306910777040419: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("after-tabs")),
+static RIBBON_SMALL_ICON_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(16))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(16))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_margin_right(LayoutMarginRight::const_px(4))),
+];
 
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_16912306910777040419)
-                })
-            ])),
+static RIBBON_SMALL_LABEL_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+];
 
-            // tab content
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_3221151331850347044)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_2825694991725398553: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul_native-ribbon-body")),
+static RIBBON_ARROW_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(9))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_444444 })),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(ITEM_HOVER_BACKGROUND)),
+];
+
+static RIBBON_KEY_TIP_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_display(LayoutDisplay::None)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_left(LayoutLeft::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_bottom(LayoutBottom::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_font_size(StyleFontSize::const_px(10))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_444444 })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(KEY_TIP_BACKGROUND_ITEMS))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_444444 })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_444444 })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_444444 })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_444444 })),
+];
+
+/// How a `RibbonItem` is drawn and what happens when it is clicked
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum RibbonItemKind {
+    /// Large button, the icon is drawn above the label
+    LargeButton,
+    /// Small button, the icon is drawn next to the label.
+    /// Up to three small items are stacked on top of each other
+    SmallButton,
+    /// Small button that stays pressed until it is clicked again
+    Toggle,
+    /// Small button that opens a menu with the `choices` of the item
+    DropDown,
+    /// Large button with an arrow below it: the button invokes the
+    /// callback directly, the arrow opens a menu with the `choices`
+    SplitButton,
+}
+
+impl RibbonItemKind {
+    fn is_large(&self) -> bool {
+        matches!(self, RibbonItemKind::LargeButton | RibbonItemKind::SplitButton)
+    }
+
+    fn has_menu(&self) -> bool {
+        matches!(self, RibbonItemKind::DropDown | RibbonItemKind::SplitButton)
+    }
+}
+
+/// Command inside of a `RibbonGroup`
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct RibbonItem {
+    pub kind: RibbonItemKind,
+    pub label: AzString,
+    /// Optional icon, 32x32 for large buttons, 16x16 for small buttons
+    pub icon: OptionImageRef,
+    /// Text shown when hovering over the item
+    pub tooltip: OptionAzString,
+    /// Letters that invoke the item after pressing Alt
+    pub key_tip: OptionAzString,
+    /// Disabled items are greyed out and don't invoke the callback
+    pub enabled: bool,
+    /// Whether a `Toggle` is currently pressed
+    pub toggled: bool,
+    /// Menu entries of `DropDown` and `SplitButton` items
+    pub choices: StringVec,
+    pub on_click: OptionRibbonOnItemClick,
+}
+
+impl_vec!(RibbonItem, RibbonItemVec, RibbonItemVecDestructor);
+impl_vec_clone!(RibbonItem, RibbonItemVec, RibbonItemVecDestructor);
+impl_vec_mut!(RibbonItem, RibbonItemVec);
+impl_vec_debug!(RibbonItem, RibbonItemVec);
+
+impl Default for RibbonItem {
+    fn default() -> Self {
+        Self {
+            kind: RibbonItemKind::SmallButton,
+            label: AzString::from_const_str(""),
+            icon: None.into(),
+            tooltip: None.into(),
+            key_tip: None.into(),
+            enabled: true,
+            toggled: false,
+            choices: StringVec::from_const_slice(&[]),
+            on_click: None.into(),
+        }
+    }
+}
+
+/// State passed to the `on_click` callback of a `RibbonItem`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct RibbonItemState {
+    /// New state of a `Toggle` item
+    pub toggled: bool,
+    /// Index of the chosen menu entry, `None` if the button itself was clicked
+    pub choice: OptionUsize,
+}
+
+pub type RibbonOnItemClickCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &RibbonItemState) -> Update;
+impl_callback!(RibbonOnItemClick, OptionRibbonOnItemClick, RibbonOnItemClickCallback, RibbonOnItemClickCallbackType);
+
+impl RibbonItem {
+
+    pub fn new(kind: RibbonItemKind, label: AzString) -> Self {
+        Self {
+            kind,
+            label,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut default = Self::default();
+        core::mem::swap(&mut default, self);
+        default
+    }
+
+    pub fn set_icon(&mut self, icon: ImageRef) {
+        self.icon = Some(icon).into();
+    }
+
+    pub fn with_icon(&mut self, icon: ImageRef) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_icon(icon);
+        s
+    }
+
+    pub fn set_tooltip(&mut self, tooltip: AzString) {
+        self.tooltip = Some(tooltip).into();
+    }
+
+    pub fn with_tooltip(&mut self, tooltip: AzString) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_tooltip(tooltip);
+        s
+    }
+
+    pub fn set_key_tip(&mut self, key_tip: AzString) {
+        self.key_tip = Some(key_tip).into();
+    }
+
+    pub fn with_key_tip(&mut self, key_tip: AzString) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_key_tip(key_tip);
+        s
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn with_enabled(&mut self, enabled: bool) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_enabled(enabled);
+        s
+    }
+
+    pub fn set_toggled(&mut self, toggled: bool) {
+        self.toggled = toggled;
+    }
+
+    pub fn with_toggled(&mut self, toggled: bool) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_toggled(toggled);
+        s
+    }
+
+    pub fn set_choices(&mut self, choices: StringVec) {
+        self.choices = choices;
+    }
+
+    pub fn with_choices(&mut self, choices: StringVec) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_choices(choices);
+        s
+    }
+
+    pub fn set_on_click(&mut self, data: RefAny, on_click: RibbonOnItemClickCallbackType) {
+        self.on_click = Some(RibbonOnItemClick { data, callback: RibbonOnItemClickCallback { cb: on_click }}).into();
+    }
+
+    pub fn with_on_click(&mut self, data: RefAny, on_click: RibbonOnItemClickCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_click(data, on_click);
+        s
+    }
+}
+
+/// Labeled group of items. If the window is too narrow, the group first
+/// draws all items as small buttons, then collapses into a single button
+/// that shows the items in a menu
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct RibbonGroup {
+    pub label: AzString,
+    /// Icon of the button that is shown when the group is collapsed
+    pub icon: OptionImageRef,
+    pub items: RibbonItemVec,
+}
+
+impl_vec!(RibbonGroup, RibbonGroupVec, RibbonGroupVecDestructor);
+impl_vec_clone!(RibbonGroup, RibbonGroupVec, RibbonGroupVecDestructor);
+impl_vec_mut!(RibbonGroup, RibbonGroupVec);
+impl_vec_debug!(RibbonGroup, RibbonGroupVec);
+
+impl Default for RibbonGroup {
+    fn default() -> Self {
+        Self {
+            label: AzString::from_const_str(""),
+            icon: None.into(),
+            items: RibbonItemVec::from_const_slice(&[]),
+        }
+    }
+}
+
+impl RibbonGroup {
+
+    pub fn new(label: AzString, items: RibbonItemVec) -> Self {
+        Self {
+            label,
+            items,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut default = Self::default();
+        core::mem::swap(&mut default, self);
+        default
+    }
+
+    pub fn set_icon(&mut self, icon: ImageRef) {
+        self.icon = Some(icon).into();
+    }
+
+    pub fn with_icon(&mut self, icon: ImageRef) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_icon(icon);
+        s
+    }
+}
+
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct RibbonTab {
+    pub label: AzString,
+    /// Letters that activate the tab after pressing Alt
+    pub key_tip: OptionAzString,
+    pub groups: RibbonGroupVec,
+}
+
+impl_vec!(RibbonTab, RibbonTabVec, RibbonTabVecDestructor);
+impl_vec_clone!(RibbonTab, RibbonTabVec, RibbonTabVecDestructor);
+impl_vec_mut!(RibbonTab, RibbonTabVec);
+impl_vec_debug!(RibbonTab, RibbonTabVec);
+
+impl Default for RibbonTab {
+    fn default() -> Self {
+        Self {
+            label: AzString::from_const_str(""),
+            key_tip: None.into(),
+            groups: RibbonGroupVec::from_const_slice(&[]),
+        }
+    }
+}
+
+impl RibbonTab {
+
+    pub fn new(label: AzString, groups: RibbonGroupVec) -> Self {
+        Self {
+            label,
+            groups,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut default = Self::default();
+        core::mem::swap(&mut default, self);
+        default
+    }
+
+    pub fn set_key_tip(&mut self, key_tip: AzString) {
+        self.key_tip = Some(key_tip).into();
+    }
+
+    pub fn with_key_tip(&mut self, key_tip: AzString) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_key_tip(key_tip);
+        s
+    }
+}
+
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct Ribbon {
+    pub tabs: RibbonTabVec,
+    pub tab_active: i32,
+    /// Width of the ribbon when the DOM is built, usually the width of
+    /// the window. Used to shrink the groups before the window is resized
+    pub available_width: OptionF32,
+    pub on_tab_clicked: OptionRibbonOnTabClicked,
+}
+
+impl Default for Ribbon {
+    fn default() -> Self {
+        Self {
+            tabs: RibbonTabVec::from_const_slice(&[]),
+            tab_active: 0,
+            available_width: None.into(),
+            on_tab_clicked: None.into(),
+        }
+    }
+}
+
+pub type RibbonOnTabClickedCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, i32) -> Update;
+impl_callback!(RibbonOnTabClicked, OptionRibbonOnTabClicked, RibbonOnTabClickedCallback, RibbonOnTabClickedCallbackType);
+
+impl Ribbon {
+
+    pub fn new(tabs: RibbonTabVec) -> Self {
+        Self {
+            tabs,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut default = Self::default();
+        core::mem::swap(&mut default, self);
+        default
+    }
+
+    pub fn set_tab_active(&mut self, tab_active: i32) {
+        self.tab_active = tab_active;
+    }
+
+    pub fn with_tab_active(&mut self, tab_active: i32) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_tab_active(tab_active);
+        s
+    }
+
+    pub fn set_available_width(&mut self, available_width: f32) {
+        self.available_width = Some(available_width).into();
+    }
+
+    pub fn with_available_width(&mut self, available_width: f32) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_available_width(available_width);
+        s
+    }
+
+    pub fn set_on_tab_clicked(&mut self, data: RefAny, on_tab_clicked: RibbonOnTabClickedCallbackType) {
+        self.on_tab_clicked = Some(RibbonOnTabClicked { data, callback: RibbonOnTabClickedCallback { cb: on_tab_clicked }}).into();
+    }
+
+    pub fn with_on_tab_clicked(&mut self, data: RefAny, on_tab_clicked: RibbonOnTabClickedCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_tab_clicked(data, on_tab_clicked);
+        s
+    }
+
+    pub fn dom(&mut self) -> Dom {
+
+        use azul_desktop::dom::{AccessibilityInfo, AccessibilityRole};
+
+        let s = self.swap_with_default();
+        let tabs: Vec<RibbonTab> = s.tabs.into_library_owned_vec();
+        let tab_active = s.tab_active;
 
+        let groups = tabs.get(tab_active.max(0) as usize)
+            .map(|tab| tab.groups.clone().into_library_owned_vec())
+            .unwrap_or_default();
+
+        let group_widths = groups.iter().map(|group| [
+            get_group_width(group, RibbonGroupSize::Large),
+            get_group_width(group, RibbonGroupSize::Small),
+            get_group_width(group, RibbonGroupSize::Collapsed),
+        ]).collect::<Vec<_>>();
+
+        let group_sizes = match s.available_width.into_option() {
+            Some(w) => get_group_sizes(&group_widths, w - RIBBON_BODY_PADDING),
+            None => vec![RibbonGroupSize::Large; groups.len()],
+        };
+
+        let group_markers = groups.iter().map(|_| RefAny::new(RibbonGroupMarker { })).collect::<Vec<_>>();
+
+        let mut key_tips = Vec::new();
+        for (tab_idx, tab) in tabs.iter().enumerate() {
+            if let Some(keys) = tab.key_tip.as_ref() {
+                key_tips.push(RibbonKeyTip::new(keys.as_str(), RibbonKeyTipTarget::Tab(tab_idx)));
+            }
+        }
+        for (group_idx, group) in groups.iter().enumerate() {
+            for (item_idx, item) in group.items.as_ref().iter().enumerate() {
+                if let Some(keys) = item.key_tip.as_ref() {
+                    key_tips.push(RibbonKeyTip::new(keys.as_str(), RibbonKeyTipTarget::Item { group: group_idx, item: item_idx }));
+                }
+            }
+        }
+
+        let ribbon = RefAny::new(RibbonLocalDataset {
+            groups: groups.clone(),
+            group_widths,
+            group_sizes: group_sizes.clone(),
+            group_markers: group_markers.clone(),
+            key_tips: key_tips.clone(),
+            key_tips_visible: false,
+            alt_pressed: false,
+            typed_keys: String::new(),
+            on_tab_clicked: s.on_tab_clicked,
+        });
+
+        let key_tip_badge = |target: RibbonKeyTipTarget, size: RibbonGroupSize| -> Option<Dom> {
+            let key_tip = key_tips.iter().find(|k| k.target == target)?;
+            Some(Dom::text(AzString::from(key_tip.keys.clone()))
+            .with_dataset(Some(key_tip.get_marker(size).clone()).into())
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_KEY_TIP_PROPS))
+            .with_ids_and_classes({
+                const IDS_AND_CLASSES_RIBBON_KEY_TIP: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("__azul_native-ribbon-key-tip")),
+                ];
+                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_RIBBON_KEY_TIP)
+            }))
+        };
+
+        // tab strip
+        let mut tab_items = vec![
+            Dom::div()
+            .with_inline_css_props(CSS_MATCH_9926913261609802002)
+            .with_ids_and_classes({
+                const IDS_AND_CLASSES_9410866575549354381: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("between-tabs")),
+                ];
+                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9410866575549354381)
+            }),
+        ];
+
+        for (tab_idx, tab) in tabs.iter().enumerate() {
+            let css = if tab_idx as i32 == tab_active {
+                CSS_MATCH_17524132644355033702
+            } else {
+                CSS_MATCH_2310038472753606232
+            };
+            let mut children = vec![Dom::text(tab.label.clone())];
+            children.extend(key_tip_badge(RibbonKeyTipTarget::Tab(tab_idx), RibbonGroupSize::Large));
+            tab_items.push(
+                Dom::div()
+                .with_inline_css_props(with_extra_props(css, RIBBON_TAB_EXTRA_PROPS))
+                .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::Pagetab).with_name(tab.label.clone()))
+                .with_callbacks(vec![CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::MouseUp),
+                    callback: Callback { cb: on_ribbon_tab_click },
+                    data: RefAny::new(RibbonTabLocalDataset { ribbon: ribbon.clone(), tab: tab_idx }),
+                }].into())
+                .with_children(children.into())
+            );
+        }
+
+        tab_items.push(
+            Dom::div()
+            .with_inline_css_props(CSS_MATCH_11184921220530473733)
+            .with_ids_and_classes({
+                const IDS_AND_CLASSES_16912306910777040419: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("after-tabs")),
+                ];
+                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_16912306910777040419)
+            })
+        );
+
+        // groups of the active tab, each group is rendered in all
+        // sizes, only one of them is visible at a time
+        let group_doms = groups.iter().enumerate().map(|(group_idx, group)| {
+            let variants = [RibbonGroupSize::Large, RibbonGroupSize::Small, RibbonGroupSize::Collapsed]
+            .iter()
+            .map(|size| render_group(group, group_idx, *size, *size == group_sizes[group_idx], &ribbon, &key_tip_badge))
+            .collect::<Vec<_>>();
+
+            Dom::div()
+            .with_dataset(Some(group_markers[group_idx].clone()).into())
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_GROUP_CONTAINER_PROPS))
+            .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::Grouping).with_name(group.label.clone()))
+            .with_children(variants.into())
+        }).collect::<Vec<_>>();
+
+        Dom::div()
+        .with_ids_and_classes({
+            const IDS_AND_CLASSES_9612282517634156717: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul_native-ribbon-container")),
+            ];
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9612282517634156717)
+        })
+        .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::Toolbar))
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::VirtualKeyDown),
+                callback: Callback { cb: on_ribbon_key_down },
+                data: ribbon.clone(),
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::VirtualKeyUp),
+                callback: Callback { cb: on_ribbon_key_up },
+                data: ribbon.clone(),
+            },
+        ].into())
+        .with_children(DomVec::from_vec(vec![
+            Dom::div()
+            .with_inline_css_props(CSS_MATCH_2258738109329535793)
+            .with_ids_and_classes({
+                const IDS_AND_CLASSES_9041457122899952067: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("__azul_native-ribbon-tabs")),
+                ];
+                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9041457122899952067)
+            })
+            .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::PageTabList))
+            .with_children(tab_items.into()),
+
+            Dom::div()
+            .with_inline_css_props(CSS_MATCH_3221151331850347044)
+            .with_ids_and_classes({
+                const IDS_AND_CLASSES_2825694991725398553: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("__azul_native-ribbon-body")),
                 ];
                 IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2825694991725398553)
             })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_12860013474863056225)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_10025392060247617630: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul_native-ribbon-section")),
-                    Class(AzString::from_const_str("1")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_10025392060247617630)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_9123706516995286623)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_2004408468416758999: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2004408468416758999)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::div()
-                        .with_inline_css_props(CSS_MATCH_7952568575592251546)
-                        .with_ids_and_classes({
-                            const IDS_AND_CLASSES_6126546624613363847: &[IdOrClass] = &[
-                                                    Class(AzString::from_const_str("__azul_native-ribbon-action-vertical-large")),
-
-                            ];
-                            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6126546624613363847)
-                        })
-                        .with_children(DomVec::from_vec(vec![
-                            Dom::div()
-                            .with_inline_css_props(CSS_MATCH_14701061083766788292)
-                            .with_ids_and_classes({
-                                const IDS_AND_CLASSES_4343297541786025485: &[IdOrClass] = &[
-                                                            Class(AzString::from_const_str("icon-wrapper")),
-
-                                ];
-                                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_4343297541786025485)
-                            })
-                            .with_children(DomVec::from_vec(vec![
-                                Dom::div()
-                                .with_inline_css_props(CSS_MATCH_15716718910432952660)
-                                .with_ids_and_classes({
-                                    const IDS_AND_CLASSES_638783468819161744: &[IdOrClass] = &[
-                                                                    Class(AzString::from_const_str("icon")),
-
-                                    ];
-                                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_638783468819161744)
-                                })
-                            ])),
-                            Dom::text(AzString::from_const_str("Paste"))
-                            .with_inline_css_props(CSS_MATCH_6756514148882865175),
-                            Dom::div()
-                            .with_inline_css_props(CSS_MATCH_1934381104964361563)
-                            .with_ids_and_classes({
-                                const IDS_AND_CLASSES_17000242124219500924: &[IdOrClass] = &[
-                                                            Class(AzString::from_const_str("dropdown")),
-
-                                ];
-                                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17000242124219500924)
-                            })
-                            .with_children(DomVec::from_vec(vec![
-                                Dom::div()
-                                .with_inline_css_props(CSS_MATCH_491594124841839797)
-                                .with_ids_and_classes({
-                                    const IDS_AND_CLASSES_638783468819161744: &[IdOrClass] = &[
-                                                                    Class(AzString::from_const_str("icon")),
-
-                                    ];
-                                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_638783468819161744)
-                                })
-                            ]))
-                        ]))
-                    ])),
-                    Dom::text(AzString::from_const_str("Clipboard"))
-                    .with_inline_css_props(CSS_MATCH_2233073185823558635)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6233255149722984275: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-name")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6233255149722984275)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_11324334306954975636)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_16234433965518568113: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul_native-ribbon-section")),
-                    Class(AzString::from_const_str("2")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_16234433965518568113)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_4538658364223133674)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_2004408468416758999: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2004408468416758999)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str(""))
-                    ])),
-                    Dom::text(AzString::from_const_str("Font"))
-                    .with_inline_css_props(CSS_MATCH_12543025518776072814)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6233255149722984275: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-name")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6233255149722984275)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_970131228357345953)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_8769206706192203364: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul_native-ribbon-section")),
-                    Class(AzString::from_const_str("3")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_8769206706192203364)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_8561962837455305444)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_2004408468416758999: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2004408468416758999)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str(""))
-                    ])),
-                    Dom::text(AzString::from_const_str("Alignment"))
-                    .with_inline_css_props(CSS_MATCH_8808521992961481081)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6233255149722984275: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-name")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6233255149722984275)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_6736299128913213977)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_8980483043948686304: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul_native-ribbon-section")),
-                    Class(AzString::from_const_str("4")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_8980483043948686304)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_9206206203058145671)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_2004408468416758999: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2004408468416758999)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str(""))
-                    ])),
-                    Dom::text(AzString::from_const_str("Number"))
-                    .with_inline_css_props(CSS_MATCH_16851364358900804450)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6233255149722984275: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-name")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6233255149722984275)
-                    })
-                ])),
+            .with_callbacks(vec![CallbackData {
+                event: EventFilter::Window(WindowEventFilter::Resized),
+                callback: Callback { cb: on_ribbon_resize },
+                data: ribbon,
+            }].into())
+            .with_children(group_doms.into())
+        ]))
+    }
+}
+
+fn with_extra_props(css: NodeDataInlineCssPropertyVec, extra: &[NodeDataInlineCssProperty]) -> NodeDataInlineCssPropertyVec {
+    let mut css = css.into_library_owned_vec();
+    css.extend(extra.iter().cloned());
+    css.into()
+}
+
+fn render_icon(icon: &OptionImageRef, large: bool) -> Dom {
+    let icon_props = if large {
+        CSS_MATCH_15716718910432952660
+    } else {
+        NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_SMALL_ICON_PROPS)
+    };
+    match icon.as_ref() {
+        Some(image) => Dom::image(image.clone()),
+        None => Dom::div(),
+    }
+    .with_inline_css_props(icon_props)
+}
+
+fn render_arrow() -> Dom {
+    Dom::text(AzString::from_const_str("\u{25be}"))
+    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_ARROW_PROPS))
+}
+
+fn render_group<F: Fn(RibbonKeyTipTarget, RibbonGroupSize) -> Option<Dom>>(
+    group: &RibbonGroup,
+    group_idx: usize,
+    size: RibbonGroupSize,
+    visible: bool,
+    ribbon: &RefAny,
+    key_tip_badge: &F,
+) -> Dom {
+
+    let content = match size {
+        RibbonGroupSize::Collapsed => {
+            // single large button that shows the items in a menu
+            let mut children = vec![
                 Dom::div()
-                .with_inline_css_props(CSS_MATCH_3888401522023951407)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_6781594546968350058: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul_native-ribbon-section")),
-                    Class(AzString::from_const_str("5")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6781594546968350058)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14738982339524920711)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_2004408468416758999: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2004408468416758999)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str(""))
-                    ])),
-                    Dom::text(AzString::from_const_str("Styles"))
-                    .with_inline_css_props(CSS_MATCH_8787113990689659847)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6233255149722984275: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-name")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6233255149722984275)
-                    })
-                ])),
+                .with_inline_css_props(CSS_MATCH_14701061083766788292)
+                .with_children(vec![render_icon(&group.icon, true)].into()),
+                Dom::text(group.label.clone())
+                .with_inline_css_props(CSS_MATCH_6756514148882865175),
+            ];
+            children.push(render_arrow());
+            vec![
                 Dom::div()
-                .with_inline_css_props(CSS_MATCH_4060245836920688376)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_11618651107626783359: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul_native-ribbon-section")),
-                    Class(AzString::from_const_str("6")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11618651107626783359)
-                })
-                .with_children(DomVec::from_vec(vec![
+                .with_inline_css_props(with_extra_props(CSS_MATCH_7952568575592251546, RIBBON_ITEM_EXTRA_PROPS))
+                .with_context_menu(get_group_menu(group, group_idx, ribbon))
+                .with_children(children.into())
+            ]
+        },
+        _ => {
+            let items = group.items.as_ref();
+            let key_tip_badge = |target| key_tip_badge(target, size);
+            let key_tip_badge = &key_tip_badge;
+            get_group_columns(items, size).into_iter().map(|column| {
+                if column.large {
+                    let item_idx = column.items[0];
+                    render_item(&items[item_idx], group_idx, item_idx, true, ribbon, key_tip_badge)
+                } else {
                     Dom::div()
-                    .with_inline_css_props(CSS_MATCH_11894410514907408907)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_2004408468416758999: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2004408468416758999)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str(""))
-                    ])),
-                    Dom::text(AzString::from_const_str("Cells"))
-                    .with_inline_css_props(CSS_MATCH_6328747057139953245)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6233255149722984275: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-name")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6233255149722984275)
-                    })
-                ])),
+                    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_COLUMN_PROPS))
+                    .with_children(column.items.iter().map(|item_idx| {
+                        render_item(&items[*item_idx], group_idx, *item_idx, false, ribbon, key_tip_badge)
+                    }).collect::<Vec<_>>().into())
+                }
+            }).collect()
+        }
+    };
+
+    let group_props = if visible {
+        NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_GROUP_PROPS)
+    } else {
+        with_extra_props(NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_GROUP_PROPS), RIBBON_GROUP_HIDDEN_PROPS)
+    };
+
+    Dom::div()
+    .with_inline_css_props(group_props)
+    .with_ids_and_classes({
+        const IDS_AND_CLASSES_RIBBON_SECTION: &[IdOrClass] = &[
+            Class(AzString::from_const_str("__azul_native-ribbon-section")),
+        ];
+        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_RIBBON_SECTION)
+    })
+    .with_children(DomVec::from_vec(vec![
+        Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_GROUP_CONTENT_PROPS))
+        .with_ids_and_classes({
+            const IDS_AND_CLASSES_2004408468416758999: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul_native-ribbon-section-content")),
+            ];
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2004408468416758999)
+        })
+        .with_children(content.into()),
+        Dom::text(group.label.clone())
+        .with_inline_css_props(CSS_MATCH_2233073185823558635)
+        .with_ids_and_classes({
+            const IDS_AND_CLASSES_6233255149722984275: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul_native-ribbon-section-name")),
+            ];
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6233255149722984275)
+        })
+    ]))
+}
+
+fn render_item<F: Fn(RibbonKeyTipTarget) -> Option<Dom>>(
+    item: &RibbonItem,
+    group_idx: usize,
+    item_idx: usize,
+    large: bool,
+    ribbon: &RefAny,
+    key_tip_badge: &F,
+) -> Dom {
+
+    use azul_desktop::dom::{
+        AccessibilityInfo, AccessibilityRole,
+        AccessibilityState, AccessibilityStateVec,
+    };
+
+    let dataset = RibbonItemLocalDataset {
+        ribbon: ribbon.clone(),
+        group: group_idx,
+        item: item_idx,
+    };
+
+    let click_callback = || -> Vec<CallbackData> {
+        if !item.enabled {
+            return Vec::new();
+        }
+        vec![CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::MouseUp),
+            callback: Callback { cb: on_ribbon_item_click },
+            data: RefAny::new(dataset.clone()),
+        }]
+    };
+
+    let (part_props, label_props) = if large {
+        (RIBBON_PART_PROPS, CSS_MATCH_6756514148882865175)
+    } else {
+        (RIBBON_SMALL_PART_PROPS, NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_SMALL_LABEL_PROPS))
+    };
+
+    let icon = if large {
+        Dom::div()
+        .with_inline_css_props(CSS_MATCH_14701061083766788292)
+        .with_children(vec![render_icon(&item.icon, true)].into())
+    } else {
+        render_icon(&item.icon, false)
+    };
+
+    let button = vec![icon, Dom::text(item.label.clone()).with_inline_css_props(label_props)];
+
+    let mut children = match item.kind {
+        RibbonItemKind::SplitButton => {
+            // button and arrow are separate click targets
+            let mut arrow = render_arrow();
+            if item.enabled {
+                arrow = arrow.with_context_menu(get_choices_menu(item, &dataset));
+            }
+            vec![
                 Dom::div()
-                .with_inline_css_props(CSS_MATCH_17089226259487272686)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_4188199152450384868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul_native-ribbon-section")),
-                    Class(AzString::from_const_str("7")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_4188199152450384868)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14707506486468900090)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_2004408468416758999: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2004408468416758999)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str(""))
-                    ])),
-                    Dom::text(AzString::from_const_str("Editing"))
-                    .with_inline_css_props(CSS_MATCH_4856252049803891913)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6233255149722984275: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul_native-ribbon-section-name")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6233255149722984275)
-                    })
-                ]))
-            ]))
-        ]))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(part_props))
+                .with_callbacks(click_callback().into())
+                .with_children(button.into()),
+                arrow,
+            ]
+        },
+        RibbonItemKind::DropDown => {
+            let mut children = button;
+            children.push(render_arrow());
+            children
+        },
+        _ => button,
+    };
+
+    children.extend(key_tip_badge(RibbonKeyTipTarget::Item { group: group_idx, item: item_idx }));
+
+    let mut item_props = if large {
+        with_extra_props(CSS_MATCH_7952568575592251546, RIBBON_ITEM_EXTRA_PROPS)
+    } else {
+        NodeDataInlineCssPropertyVec::from_const_slice(RIBBON_SMALL_ITEM_PROPS)
+    };
+    if item.kind == RibbonItemKind::Toggle && item.toggled {
+        item_props = with_extra_props(item_props, RIBBON_ITEM_TOGGLED_PROPS);
+    }
+    if !item.enabled {
+        item_props = with_extra_props(item_props, RIBBON_ITEM_DISABLED_PROPS);
+    }
+
+    let role = match item.kind {
+        RibbonItemKind::LargeButton | RibbonItemKind::SmallButton => AccessibilityRole::PushButton,
+        RibbonItemKind::Toggle => AccessibilityRole::CheckButton,
+        RibbonItemKind::DropDown => AccessibilityRole::ButtonDropdown,
+        RibbonItemKind::SplitButton => AccessibilityRole::SplitButton,
+    };
+
+    let mut states = Vec::new();
+    if !item.enabled {
+        states.push(AccessibilityState::Unavailable);
+    }
+    if item.kind == RibbonItemKind::Toggle && item.toggled {
+        states.push(AccessibilityState::Checked);
+    }
+
+    let mut dom = Dom::div()
+    .with_inline_css_props(item_props)
+    .with_accessibility_info(
+        AccessibilityInfo::new(role)
+        .with_name(item.label.clone())
+        .with_states(AccessibilityStateVec::from_vec(states))
+    );
+
+    match item.kind {
+        RibbonItemKind::SplitButton => { },
+        RibbonItemKind::DropDown => if item.enabled {
+            dom = dom.with_context_menu(get_choices_menu(item, &dataset));
+        },
+        _ => {
+            dom = dom.with_callbacks(click_callback().into());
+        }
+    }
+
+    if let Some(tooltip) = item.tooltip.as_ref() {
+        dom = dom.with_tooltip(Tooltip::text(tooltip.clone()));
     }
+
+    dom.with_children(children.into())
 }
 
-struct MyCustomStruct {
-    which_tab_to_activate_on_click: i32,
-    on_tab_change_callback: RibbonOnTabClickedCallback,
-    on_tab_change_data: RefAny,
+/// Menu with the `choices` of a `DropDown` or `SplitButton`, opened with the left mouse button
+fn get_choices_menu(item: &RibbonItem, dataset: &RibbonItemLocalDataset) -> Menu {
+    let mut menu = Menu::new(get_choices_menu_items(item, dataset));
+    menu.position = MenuPopupPosition::BottomOfHitRect;
+    menu.context_mouse_btn = ContextMenuMouseButton::Left;
+    menu
 }
 
-fn render_tab_element(
-    text: &'static str,
-    active: bool,
-    which_tab_to_activate_on_click: i32,
-    callback: RibbonOnTabClickedCallback,
-    data: RefAny,
-) -> Dom {
-    Dom::text(AzString::from_const_str(text))
-    .with_inline_css_props(if active { CSS_MATCH_17524132644355033702 } else { CSS_MATCH_2310038472753606232 })
-    .with_callbacks(vec![
-        CallbackData {
-            event: EventFilter::Hover(HoverEventFilter::MouseUp), // onmouseup
-            callback: Callback { cb: my_callback },
-            data: RefAny::new(MyCustomStruct {
-                which_tab_to_activate_on_click,
-                on_tab_change_callback: callback,
-                on_tab_change_data: data,
-            }),
+fn get_choices_menu_items(item: &RibbonItem, dataset: &RibbonItemLocalDataset) -> MenuItemVec {
+    item.choices.as_ref().iter().enumerate().map(|(choice, label)| {
+        MenuItem::String(StringMenuItem::new(label.clone()).with_callback(
+            RefAny::new(RibbonChoiceLocalDataset { item: dataset.clone(), choice }),
+            on_ribbon_choice_click,
+        ))
+    }).collect::<Vec<_>>().into()
+}
+
+/// Menu of a collapsed group, containing all items of the group
+fn get_group_menu(group: &RibbonGroup, group_idx: usize, ribbon: &RefAny) -> Menu {
+
+    let items = group.items.as_ref().iter().enumerate().map(|(item_idx, item)| {
+
+        let dataset = RibbonItemLocalDataset {
+            ribbon: ribbon.clone(),
+            group: group_idx,
+            item: item_idx,
+        };
+
+        let mut menu_item = StringMenuItem::new(item.label.clone());
+
+        if !item.enabled {
+            menu_item.state = MenuItemState::Greyed;
+        } else {
+            if item.kind.has_menu() {
+                menu_item = menu_item.with_children(get_choices_menu_items(item, &dataset));
+            }
+            if item.kind != RibbonItemKind::DropDown {
+                menu_item = menu_item.with_callback(RefAny::new(dataset), on_ribbon_item_click);
+            }
         }
-    ].into())
+
+        if item.kind == RibbonItemKind::Toggle {
+            menu_item.icon = Some(MenuItemIcon::Checkbox(item.toggled)).into();
+        }
+
+        MenuItem::String(menu_item)
+    }).collect::<Vec<_>>();
+
+    let mut menu = Menu::new(items.into());
+    menu.position = MenuPopupPosition::BottomOfHitRect;
+    menu.context_mouse_btn = ContextMenuMouseButton::Left;
+    menu
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum RibbonGroupSize {
+    /// Large items are drawn as large buttons
+    Large,
+    /// All items are drawn as small buttons
+    Small,
+    /// The group is drawn as a single button that opens a menu
+    Collapsed,
 }
 
-extern "C" fn my_callback(
-    data: &mut RefAny,
-    info: &mut CallbackInfo
-) -> Update {
+/// Items that are drawn on top of each other
+#[derive(Debug, Clone, PartialEq)]
+struct RibbonColumn {
+    large: bool,
+    items: Vec<usize>,
+}
+
+fn get_group_columns(items: &[RibbonItem], size: RibbonGroupSize) -> Vec<RibbonColumn> {
+
+    let mut columns = Vec::new();
+    let mut current = Vec::new();
+
+    for (item_idx, item) in items.iter().enumerate() {
+        if size == RibbonGroupSize::Large && item.kind.is_large() {
+            if !current.is_empty() {
+                columns.push(RibbonColumn { large: false, items: core::mem::take(&mut current) });
+            }
+            columns.push(RibbonColumn { large: true, items: vec![item_idx] });
+        } else {
+            current.push(item_idx);
+            if current.len() == RIBBON_SMALL_ITEMS_PER_COLUMN {
+                columns.push(RibbonColumn { large: false, items: core::mem::take(&mut current) });
+            }
+        }
+    }
+
+    if !current.is_empty() {
+        columns.push(RibbonColumn { large: false, items: current });
+    }
+
+    columns
+}
+
+fn get_text_width(text: &str) -> f32 {
+    text.chars().count() as f32 * RIBBON_CHAR_WIDTH
+}
 
-    let mut data = match data.downcast_mut::<MyCustomStruct>() {
-        Some(s) => s,
-        None => return Update::DoNothing,
+fn get_item_width(item: &RibbonItem, large: bool) -> f32 {
+    let label_width = get_text_width(item.label.as_str());
+    let arrow_width = if item.kind.has_menu() { RIBBON_ARROW_WIDTH } else { 0.0 };
+    if large {
+        label_width.max(RIBBON_LARGE_ICON_SIZE) + 2.0 * RIBBON_ITEM_PADDING
+    } else {
+        RIBBON_SMALL_ICON_SIZE + RIBBON_ICON_GAP + label_width + arrow_width + 2.0 * RIBBON_ITEM_PADDING
+    }
+}
+
+/// Estimates the width of a group when drawn in the given size
+fn get_group_width(group: &RibbonGroup, size: RibbonGroupSize) -> f32 {
+
+    let label_width = get_text_width(group.label.as_str()) + 2.0 * RIBBON_ITEM_PADDING;
+    let items = group.items.as_ref();
+
+    let content_width = match size {
+        RibbonGroupSize::Collapsed => RIBBON_LARGE_ICON_SIZE + 2.0 * RIBBON_ITEM_PADDING,
+        _ => get_group_columns(items, size).iter().map(|column| {
+            column.items.iter()
+            .map(|item_idx| get_item_width(&items[*item_idx], column.large))
+            .fold(0.0_f32, f32::max)
+        }).sum(),
+    };
+
+    content_width.max(label_width) + RIBBON_GROUP_PADDING
+}
+
+/// Shrinks the groups from right to left until all groups fit into `available_width`:
+/// first all groups are drawn with small buttons, then they are collapsed
+fn get_group_sizes(group_widths: &[[f32; 3]], available_width: f32) -> Vec<RibbonGroupSize> {
+
+    let mut sizes = vec![RibbonGroupSize::Large; group_widths.len()];
+
+    let total_width = |sizes: &[RibbonGroupSize]| -> f32 {
+        sizes.iter().zip(group_widths.iter()).map(|(size, widths)| widths[*size as usize]).sum()
     };
 
-    let which_tab_to_activate_on_click = data.which_tab_to_activate_on_click;
+    for next_size in [RibbonGroupSize::Small, RibbonGroupSize::Collapsed].iter() {
+        for group_idx in (0..group_widths.len()).rev() {
+            if total_width(&sizes) <= available_width {
+                return sizes;
+            }
+            let widths = &group_widths[group_idx];
+            if widths[*next_size as usize] < widths[sizes[group_idx] as usize] {
+                sizes[group_idx] = *next_size;
+            }
+        }
+    }
+
+    sizes
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum RibbonKeyTipTarget {
+    Tab(usize),
+    Item { group: usize, item: usize },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum RibbonKeyTipMatch {
+    /// The typed keys are the key tip at this index
+    Found(usize),
+    /// The typed keys are the start of at least one key tip
+    Partial,
+    NoMatch,
+}
+
+#[derive(Clone)]
+struct RibbonKeyTip {
+    /// Upper-case letters of the key tip
+    keys: String,
+    target: RibbonKeyTipTarget,
+    /// Datasets of the key tip badges in the large and the small layout
+    /// of the groups, used to find the badge nodes (tabs only use the first)
+    markers: [RefAny; 2],
+}
+
+impl RibbonKeyTip {
+
+    fn new(keys: &str, target: RibbonKeyTipTarget) -> Self {
+        Self {
+            keys: keys.to_uppercase(),
+            target,
+            markers: [RefAny::new(RibbonKeyTipMarker { }), RefAny::new(RibbonKeyTipMarker { })],
+        }
+    }
 
-    (&mut data.on_tab_change_callback.cb)(&mut data.on_tab_change_data, info, which_tab_to_activate_on_click)
-}
\ No newline at end of file
+    fn get_marker(&self, size: RibbonGroupSize) -> &RefAny {
+        match size {
+            RibbonGroupSize::Small => &self.markers[1],
+            _ => &self.markers[0],
+        }
+    }
+}
+
+fn match_key_tip<'a, I: Iterator<Item = &'a str>>(typed_keys: &str, key_tips: I) -> RibbonKeyTipMatch {
+    let mut result = RibbonKeyTipMatch::NoMatch;
+    for (idx, keys) in key_tips.enumerate() {
+        if keys == typed_keys {
+            return RibbonKeyTipMatch::Found(idx);
+        } else if keys.starts_with(typed_keys) {
+            result = RibbonKeyTipMatch::Partial;
+        }
+    }
+    result
+}
+
+fn virtual_key_to_char(vk: VirtualKeyCode) -> Option<char> {
+    use self::VirtualKeyCode::*;
+    const LETTERS: &[VirtualKeyCode] = &[
+        A, B, C, D, E, F, G, H, I, J, K, L, M,
+        N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    ];
+    const DIGITS: &[VirtualKeyCode] = &[Key0, Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9];
+    if let Some(idx) = LETTERS.iter().position(|k| *k == vk) {
+        return Some((b'A' + idx as u8) as char);
+    }
+    DIGITS.iter().position(|k| *k == vk).map(|idx| (b'0' + idx as u8) as char)
+}
+
+struct RibbonGroupMarker { }
+struct RibbonKeyTipMarker { }
+
+struct RibbonLocalDataset {
+    /// Groups of the active tab
+    groups: Vec<RibbonGroup>,
+    group_widths: Vec<[f32; 3]>,
+    group_sizes: Vec<RibbonGroupSize>,
+    /// Datasets of the group containers, used to find the group nodes
+    group_markers: Vec<RefAny>,
+    key_tips: Vec<RibbonKeyTip>,
+    key_tips_visible: bool,
+    /// Set when Alt is pressed, cleared if another key is pressed
+    /// before Alt is released (Alt+F4, Alt+<accelerator>)
+    alt_pressed: bool,
+    typed_keys: String,
+    on_tab_clicked: OptionRibbonOnTabClicked,
+}
+
+impl RibbonLocalDataset {
+
+    fn dispatch_tab_click(&mut self, info: &mut CallbackInfo, tab: usize) -> Update {
+        match self.on_tab_clicked.as_mut() {
+            Some(RibbonOnTabClicked { callback, data }) => (callback.cb)(data, info, tab as i32),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn dispatch_item_click(&mut self, info: &mut CallbackInfo, group: usize, item: usize, choice: Option<usize>) -> Option<Update> {
+
+        let item = self.groups.get_mut(group)?.items.as_mut().get_mut(item)?;
+        if !item.enabled {
+            return None;
+        }
+
+        if item.kind == RibbonItemKind::Toggle {
+            item.toggled = !item.toggled;
+        }
+
+        let state = RibbonItemState {
+            toggled: item.toggled,
+            choice: choice.into(),
+        };
+
+        Some(match item.on_click.as_mut() {
+            Some(RibbonOnItemClick { callback, data }) => (callback.cb)(data, info, &state),
+            None => Update::DoNothing,
+        })
+    }
+
+    fn set_key_tips_visible(&mut self, info: &mut CallbackInfo, visible: bool) {
+        self.key_tips_visible = visible;
+        self.typed_keys.clear();
+        let display = if visible { LayoutDisplay::Flex } else { LayoutDisplay::None };
+        for key_tip in self.key_tips.iter() {
+            for node in get_key_tip_nodes(info, key_tip) {
+                info.set_css_property(node, CssProperty::const_display(display));
+            }
+        }
+    }
+
+    fn set_group_sizes(&mut self, info: &mut CallbackInfo, sizes: Vec<RibbonGroupSize>) {
+        for (group_idx, size) in sizes.iter().enumerate() {
+            if self.group_sizes.get(group_idx) == Some(size) {
+                continue;
+            }
+            let group_node = match self.group_markers.get(group_idx).and_then(|m| info.get_node_id_of_root_dataset(m.clone())) {
+                Some(s) => s,
+                None => continue,
+            };
+            // group container > [large, small, collapsed]
+            let mut variant = info.get_first_child(group_node);
+            for variant_size in [RibbonGroupSize::Large, RibbonGroupSize::Small, RibbonGroupSize::Collapsed].iter() {
+                let node = match variant {
+                    Some(s) => s,
+                    None => break,
+                };
+                let display = if variant_size == size { LayoutDisplay::Flex } else { LayoutDisplay::None };
+                info.set_css_property(node, CssProperty::const_display(display));
+                variant = info.get_next_sibling(node);
+            }
+        }
+        self.group_sizes = sizes;
+    }
+}
+
+/// Returns the key tip badges of the key tip, in all group layouts
+fn get_key_tip_nodes(info: &mut CallbackInfo, key_tip: &RibbonKeyTip) -> Vec<DomNodeId> {
+    key_tip.markers.iter().filter_map(|m| info.get_node_id_of_root_dataset(m.clone())).collect()
+}
+
+/// Toggles are redrawn directly instead of re-rendering the whole ribbon
+fn restyle_toggle(info: &mut CallbackInfo, item: &RibbonItem, item_node: DomNodeId) {
+    if item.kind == RibbonItemKind::Toggle {
+        let background = if item.toggled { ITEM_TOGGLED_BACKGROUND } else { ITEM_NO_BACKGROUND };
+        info.set_css_property(item_node, CssProperty::const_background_content(background));
+    }
+}
+
+struct RibbonTabLocalDataset {
+    ribbon: RefAny,
+    tab: usize,
+}
+
+#[derive(Clone)]
+struct RibbonItemLocalDataset {
+    ribbon: RefAny,
+    group: usize,
+    item: usize,
+}
+
+struct RibbonChoiceLocalDataset {
+    item: RibbonItemLocalDataset,
+    choice: usize,
+}
+
+extern "C" fn on_ribbon_tab_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_ribbon_tab_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut tab_dataset = data.downcast_mut::<RibbonTabLocalDataset>()?;
+        let tab_dataset = &mut *tab_dataset;
+        let tab = tab_dataset.tab;
+        let mut ribbon = tab_dataset.ribbon.downcast_mut::<RibbonLocalDataset>()?;
+        Some(ribbon.dispatch_tab_click(info, tab))
+    }
+
+    on_ribbon_tab_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_ribbon_item_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_ribbon_item_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut item_dataset = data.downcast_mut::<RibbonItemLocalDataset>()?;
+        let item_dataset = &mut *item_dataset;
+        let (group, item) = (item_dataset.group, item_dataset.item);
+        let mut ribbon = item_dataset.ribbon.downcast_mut::<RibbonLocalDataset>()?;
+        let update = ribbon.dispatch_item_click(info, group, item, None)?;
+
+        // the hit node is the item itself
+        let item = ribbon.groups.get(group)?.items.as_ref().get(item)?;
+        restyle_toggle(info, item, info.get_hit_node());
+
+        Some(update)
+    }
+
+    on_ribbon_item_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_ribbon_choice_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_ribbon_choice_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut choice_dataset = data.downcast_mut::<RibbonChoiceLocalDataset>()?;
+        let choice_dataset = &mut *choice_dataset;
+        let choice = choice_dataset.choice;
+        let (group, item) = (choice_dataset.item.group, choice_dataset.item.item);
+        let mut ribbon = choice_dataset.item.ribbon.downcast_mut::<RibbonLocalDataset>()?;
+        ribbon.dispatch_item_click(info, group, item, Some(choice))
+    }
+
+    on_ribbon_choice_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_ribbon_resize(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_ribbon_resize_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut ribbon = data.downcast_mut::<RibbonLocalDataset>()?;
+        let body_width = info.get_node_size(info.get_hit_node())?.width;
+        let sizes = get_group_sizes(&ribbon.group_widths, body_width - RIBBON_BODY_PADDING);
+        if sizes != ribbon.group_sizes {
+            ribbon.set_group_sizes(info, sizes);
+        }
+        Some(Update::DoNothing)
+    }
+
+    on_ribbon_resize_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+/// Alt shows the key tips of the tabs and the items of the active tab,
+/// typing the letters of a key tip then activates the tab or item
+extern "C" fn on_ribbon_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_ribbon_key_down_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut ribbon = data.downcast_mut::<RibbonLocalDataset>()?;
+        let keyboard_state = info.get_current_keyboard_state();
+        let vk = keyboard_state.current_virtual_keycode.into_option()?;
+
+        if ribbon.key_tips.is_empty() {
+            return None;
+        }
+
+        match vk {
+            // the key tips are toggled when Alt is released
+            VirtualKeyCode::LAlt | VirtualKeyCode::RAlt => {
+                ribbon.alt_pressed = true;
+                return Some(Update::DoNothing);
+            },
+            _ => ribbon.alt_pressed = false,
+        }
+
+        match vk {
+            VirtualKeyCode::Escape if ribbon.key_tips_visible => {
+                ribbon.set_key_tips_visible(info, false);
+                return Some(Update::DoNothing);
+            },
+            _ => { },
+        }
+
+        if !ribbon.key_tips_visible {
+            return None;
+        }
+
+        let c = virtual_key_to_char(vk)?;
+        let mut typed_keys = ribbon.typed_keys.clone();
+        typed_keys.push(c);
+
+        match match_key_tip(&typed_keys, ribbon.key_tips.iter().map(|k| k.keys.as_str())) {
+            RibbonKeyTipMatch::Found(idx) => {
+                let target = ribbon.key_tips[idx].target;
+                // the badges are children of the tab / item nodes
+                let item_nodes = get_key_tip_nodes(info, &ribbon.key_tips[idx]).into_iter()
+                    .filter_map(|badge| info.get_parent(badge))
+                    .collect::<Vec<_>>();
+                ribbon.set_key_tips_visible(info, false);
+                match target {
+                    RibbonKeyTipTarget::Tab(tab) => Some(ribbon.dispatch_tab_click(info, tab)),
+                    RibbonKeyTipTarget::Item { group, item } => {
+                        let update = ribbon.dispatch_item_click(info, group, item, None)?;
+                        let item = ribbon.groups.get(group)?.items.as_ref().get(item)?;
+                        for item_node in item_nodes {
+                            restyle_toggle(info, item, item_node);
+                        }
+                        Some(update)
+                    },
+                }
+            },
+            RibbonKeyTipMatch::Partial => {
+                ribbon.typed_keys = typed_keys;
+                Some(Update::DoNothing)
+            },
+            RibbonKeyTipMatch::NoMatch => {
+                ribbon.typed_keys.clear();
+                Some(Update::DoNothing)
+            },
+        }
+    }
+
+    on_ribbon_key_down_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_ribbon_key_up(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_ribbon_key_up_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut ribbon = data.downcast_mut::<RibbonLocalDataset>()?;
+
+        // the released key is the last key that was pressed
+        let vk = info.get_previous_keyboard_state()?.current_virtual_keycode.into_option()?;
+        let alt_pressed = ribbon.alt_pressed;
+        ribbon.alt_pressed = false;
+
+        match vk {
+            VirtualKeyCode::LAlt | VirtualKeyCode::RAlt if alt_pressed && !ribbon.key_tips.is_empty() => {
+                let visible = !ribbon.key_tips_visible;
+                ribbon.set_key_tips_visible(info, visible);
+                Some(Update::DoNothing)
+            },
+            _ => None,
+        }
+    }
+
+    on_ribbon_key_up_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+#[cfg(test)]
+mod ribbon_test {
+
+    use super::*;
+
+    fn item(kind: RibbonItemKind, label: &'static str) -> RibbonItem {
+        RibbonItem::new(kind, AzString::from_const_str(label))
+    }
+
+    fn column(large: bool, items: &[usize]) -> RibbonColumn {
+        RibbonColumn { large, items: items.to_vec() }
+    }
+
+    #[test]
+    fn small_items_are_stacked_in_columns_of_three() {
+        let items = vec![
+            item(RibbonItemKind::LargeButton, "Paste"),
+            item(RibbonItemKind::SmallButton, "Cut"),
+            item(RibbonItemKind::SmallButton, "Copy"),
+            item(RibbonItemKind::Toggle, "Bold"),
+            item(RibbonItemKind::DropDown, "Font"),
+            item(RibbonItemKind::SplitButton, "Format"),
+        ];
+
+        assert_eq!(get_group_columns(&items, RibbonGroupSize::Large), vec![
+            column(true, &[0]),
+            column(false, &[1, 2, 3]),
+            column(false, &[4]),
+            column(true, &[5]),
+        ]);
+
+        assert_eq!(get_group_columns(&items, RibbonGroupSize::Small), vec![
+            column(false, &[0, 1, 2]),
+            column(false, &[3, 4, 5]),
+        ]);
+    }
+
+    #[test]
+    fn groups_shrink_from_right_to_left() {
+        use self::RibbonGroupSize::*;
+
+        let widths = [[100.0, 80.0, 40.0], [100.0, 80.0, 40.0], [100.0, 80.0, 40.0]];
+
+        assert_eq!(get_group_sizes(&widths, 1000.0), vec![Large, Large, Large]);
+        assert_eq!(get_group_sizes(&widths, 280.0), vec![Large, Large, Small]);
+        assert_eq!(get_group_sizes(&widths, 240.0), vec![Small, Small, Small]);
+        assert_eq!(get_group_sizes(&widths, 200.0), vec![Small, Small, Collapsed]);
+        assert_eq!(get_group_sizes(&widths, 10.0), vec![Collapsed, Collapsed, Collapsed]);
+    }
+
+    #[test]
+    fn groups_only_shrink_if_they_get_narrower() {
+        use self::RibbonGroupSize::*;
+        // small buttons with long labels can be wider than the large buttons
+        let widths = [[50.0, 90.0, 40.0]];
+        assert_eq!(get_group_sizes(&widths, 45.0), vec![Collapsed]);
+    }
+
+    #[test]
+    fn collapsed_group_is_narrower_than_expanded_group() {
+        let group = RibbonGroup::new(AzString::from_const_str("Clipboard"), vec![
+            item(RibbonItemKind::LargeButton, "Paste"),
+            item(RibbonItemKind::SmallButton, "Cut"),
+            item(RibbonItemKind::SmallButton, "Copy"),
+            item(RibbonItemKind::SmallButton, "Format Painter"),
+        ].into());
+        let large = get_group_width(&group, RibbonGroupSize::Large);
+        let small = get_group_width(&group, RibbonGroupSize::Small);
+        let collapsed = get_group_width(&group, RibbonGroupSize::Collapsed);
+        assert!(collapsed < small);
+        assert!(collapsed < large);
+    }
+
+    #[test]
+    fn key_tips_match_typed_letters() {
+        let key_tips = ["H", "N", "FP", "FR"];
+        let m = |typed: &str| match_key_tip(typed, key_tips.iter().copied());
+        assert_eq!(m("H"), RibbonKeyTipMatch::Found(0));
+        assert_eq!(m("F"), RibbonKeyTipMatch::Partial);
+        assert_eq!(m("FR"), RibbonKeyTipMatch::Found(3));
+        assert_eq!(m("X"), RibbonKeyTipMatch::NoMatch);
+    }
+
+    #[test]
+    fn virtual_keys_map_to_key_tip_letters() {
+        assert_eq!(virtual_key_to_char(VirtualKeyCode::A), Some('A'));
+        assert_eq!(virtual_key_to_char(VirtualKeyCode::Z), Some('Z'));
+        assert_eq!(virtual_key_to_char(VirtualKeyCode::Key0), Some('0'));
+        assert_eq!(virtual_key_to_char(VirtualKeyCode::Key9), Some('9'));
+        assert_eq!(virtual_key_to_char(VirtualKeyCode::Escape), None);
+    }
+}
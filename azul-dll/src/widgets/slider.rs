@@ -0,0 +1,1032 @@
+use alloc::vec::Vec;
+use alloc::string::ToString;
+use azul_desktop::css::*;
+use azul_desktop::css::AzString;
+use azul_desktop::dom::{
+    Dom, IdOrClass, IdOrClass::Class, TabIndex,
+    HoverEventFilter, WindowEventFilter, FocusEventFilter, EventFilter,
+    CallbackData, AccessibilityInfo, AccessibilityRole,
+    NodeDataInlineCssProperty, NodeDataInlineCssProperty::{Normal, Hover},
+    DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec,
+};
+use azul_desktop::callbacks::{
+    Update, RefAny, CallbackInfo, Callback,
+};
+use azul_core::callbacks::DomNodeId;
+use azul_core::window::VirtualKeyCode;
+
+const COLOR_569DE5: ColorU = ColorU { r: 86, g: 157, b: 229, a: 255 }; // #569de5
+const COLOR_E1E1E1: ColorU = ColorU { r: 225, g: 225, b: 225, a: 255 }; // #e1e1e1
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_FFFFFF: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 }; // #ffffff
+
+const SLIDER_FILL_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_569DE5)];
+const SLIDER_RAIL_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_E1E1E1)];
+const SLIDER_THUMB_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_FFFFFF)];
+const SLIDER_TICK_BACKGROUND: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_9B9B9B)];
+
+/// Width and height of the default thumb, in pixels
+const SLIDER_THUMB_SIZE: isize = 16;
+/// Number of steps that PageUp / PageDown move the thumb
+const SLIDER_PAGE_STEPS: f32 = 10.0;
+/// Step size of sliders without a `step`, as a fraction of the range
+const SLIDER_CONTINUOUS_STEP: f32 = 0.01;
+/// Upper limit for the number of tick marks, to keep the DOM small
+const SLIDER_MAX_TICK_COUNT: usize = 500;
+
+// NOTE: The layout solver doesn't support percentages in relation to the parent,
+// so the track is split into segments that are sized with flex-grow (same
+// as in the progress bar), the thumbs are placed in between the segments.
+const FLEX_GROW_SCALE: f32 = 10000000.0;
+
+static SLIDER_HORIZONTAL_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_min_width(LayoutMinWidth::const_px(100))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(2))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(2))),
+];
+
+static SLIDER_VERTICAL_CONTAINER_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_min_height(LayoutMinHeight::const_px(100))),
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(2))),
+];
+
+static SLIDER_HORIZONTAL_TRACK_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_height(LayoutHeight::const_px(SLIDER_THUMB_SIZE))),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+];
+
+static SLIDER_VERTICAL_TRACK_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(SLIDER_THUMB_SIZE))),
+    Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    Normal(CssProperty::const_cursor(StyleCursor::Pointer)),
+];
+
+static SLIDER_HORIZONTAL_FILL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_height(LayoutHeight::const_px(4))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SLIDER_FILL_BACKGROUND))),
+];
+
+static SLIDER_VERTICAL_FILL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(4))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SLIDER_FILL_BACKGROUND))),
+];
+
+static SLIDER_HORIZONTAL_RAIL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_height(LayoutHeight::const_px(4))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SLIDER_RAIL_BACKGROUND))),
+];
+
+static SLIDER_VERTICAL_RAIL_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(4))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SLIDER_RAIL_BACKGROUND))),
+];
+
+static SLIDER_THUMB_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(SLIDER_THUMB_SIZE))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(SLIDER_THUMB_SIZE))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SLIDER_THUMB_BACKGROUND))),
+
+    // border: 1px solid #9b9b9b;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Solid })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+
+    // border-radius: 50%;
+
+    Normal(CssProperty::const_border_top_left_radius(StyleBorderTopLeftRadius::const_px(SLIDER_THUMB_SIZE / 2))),
+    Normal(CssProperty::const_border_top_right_radius(StyleBorderTopRightRadius::const_px(SLIDER_THUMB_SIZE / 2))),
+    Normal(CssProperty::const_border_bottom_left_radius(StyleBorderBottomLeftRadius::const_px(SLIDER_THUMB_SIZE / 2))),
+    Normal(CssProperty::const_border_bottom_right_radius(StyleBorderBottomRightRadius::const_px(SLIDER_THUMB_SIZE / 2))),
+
+    Hover(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_569DE5 })),
+    Hover(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_569DE5 })),
+    Hover(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_569DE5 })),
+    Hover(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_569DE5 })),
+];
+
+static SLIDER_HORIZONTAL_TICK_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(1))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(5))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SLIDER_TICK_BACKGROUND))),
+];
+
+static SLIDER_VERTICAL_TICK_STYLE: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(5))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(1))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SLIDER_TICK_BACKGROUND))),
+];
+
+/// Direction in which the thumb of a `Slider` or `RangeSlider` moves
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum SliderOrientation {
+    /// Minimum on the left, maximum on the right
+    Horizontal,
+    /// Minimum at the bottom, maximum at the top
+    Vertical,
+}
+
+/// Value of a `Slider`, passed to the `on_value_change` callback
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SliderState {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    /// Values are snapped to `min + n * step`, 0 for a continuous slider
+    pub step: f32,
+}
+
+/// Selected range of a `RangeSlider`, passed to the `on_value_change` callback
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct RangeSliderState {
+    pub low: f32,
+    pub high: f32,
+    pub min: f32,
+    pub max: f32,
+    /// Values are snapped to `min + n * step`, 0 for a continuous slider
+    pub step: f32,
+}
+
+pub type SliderOnValueChangeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &SliderState) -> Update;
+impl_callback!(SliderOnValueChange, OptionSliderOnValueChange, SliderOnValueChangeCallback, SliderOnValueChangeCallbackType);
+
+pub type RangeSliderOnValueChangeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &RangeSliderState) -> Update;
+impl_callback!(RangeSliderOnValueChange, OptionRangeSliderOnValueChange, RangeSliderOnValueChangeCallback, RangeSliderOnValueChangeCallbackType);
+
+/// Thumb that can be dragged along a track to select a value between `min` and `max`
+///
+/// The slider can be moved with the mouse (clicking on the track moves the
+/// thumb to the cursor) and, once focused, with the arrow keys, PageUp / PageDown
+/// (10 steps) and Home / End. The widget updates itself while the value changes,
+/// `on_value_change` is called on every change: returning `Update::RefreshDom`
+/// from the callback regenerates the slider, which ends an active drag.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct Slider {
+    pub orientation: SliderOrientation,
+    pub state: SliderState,
+    /// Distance between two tick marks (in the unit of the value), 0 for no tick marks
+    pub tick_interval: f32,
+    pub on_value_change: OptionSliderOnValueChange,
+    /// Style of the outer container (track + tick marks)
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style of the track that contains the thumb
+    pub track_style: NodeDataInlineCssPropertyVec,
+    /// Style of the track segment between `min` and the thumb
+    pub fill_style: NodeDataInlineCssPropertyVec,
+    /// Style of the track segment between the thumb and `max`
+    pub rail_style: NodeDataInlineCssPropertyVec,
+    /// Style of the thumb, tick marks are placed for the default 16px thumb
+    pub thumb_style: NodeDataInlineCssPropertyVec,
+    /// Style of a single tick mark
+    pub tick_style: NodeDataInlineCssPropertyVec,
+}
+
+/// Slider with two thumbs to select a range (`low..=high`) between `min` and `max`
+///
+/// Behaves like a `Slider`: the thumb closest to the cursor is moved by the
+/// mouse, the keyboard moves the thumb that was used last. The thumbs can't
+/// pass each other, the value of each thumb is at its inner edge, so that the
+/// fill segment between the thumbs is exactly the selected range.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct RangeSlider {
+    pub orientation: SliderOrientation,
+    pub state: RangeSliderState,
+    /// Distance between two tick marks (in the unit of the value), 0 for no tick marks
+    pub tick_interval: f32,
+    pub on_value_change: OptionRangeSliderOnValueChange,
+    /// Style of the outer container (track + tick marks)
+    pub container_style: NodeDataInlineCssPropertyVec,
+    /// Style of the track that contains the thumbs
+    pub track_style: NodeDataInlineCssPropertyVec,
+    /// Style of the track segment between the two thumbs
+    pub fill_style: NodeDataInlineCssPropertyVec,
+    /// Style of the track segments outside of the selected range
+    pub rail_style: NodeDataInlineCssPropertyVec,
+    /// Style of the thumbs, tick marks are placed for the default 16px thumb
+    pub thumb_style: NodeDataInlineCssPropertyVec,
+    /// Style of a single tick mark
+    pub tick_style: NodeDataInlineCssPropertyVec,
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self::new(SliderOrientation::Horizontal, 0.0, 0.0, 100.0)
+    }
+}
+
+impl Default for RangeSlider {
+    fn default() -> Self {
+        Self::new(SliderOrientation::Horizontal, 0.0, 100.0, 0.0, 100.0)
+    }
+}
+
+impl Slider {
+
+    pub fn new(orientation: SliderOrientation, value: f32, min: f32, max: f32) -> Self {
+        let styles = SliderStyles::new(orientation);
+        Self {
+            orientation,
+            state: SliderState { value, min, max, step: 0.0 },
+            tick_interval: 0.0,
+            on_value_change: None.into(),
+            container_style: styles.container_style,
+            track_style: styles.track_style,
+            fill_style: styles.fill_style,
+            rail_style: styles.rail_style,
+            thumb_style: styles.thumb_style,
+            tick_style: styles.tick_style,
+        }
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::default();
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    pub fn set_step(&mut self, step: f32) {
+        self.state.step = step;
+    }
+
+    pub fn with_step(&mut self, step: f32) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_step(step);
+        s
+    }
+
+    pub fn set_tick_interval(&mut self, tick_interval: f32) {
+        self.tick_interval = tick_interval;
+    }
+
+    pub fn with_tick_interval(&mut self, tick_interval: f32) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_tick_interval(tick_interval);
+        s
+    }
+
+    pub fn set_on_value_change(&mut self, data: RefAny, on_value_change: SliderOnValueChangeCallbackType) {
+        self.on_value_change = Some(SliderOnValueChange {
+            data,
+            callback: SliderOnValueChangeCallback { cb: on_value_change },
+        }).into();
+    }
+
+    pub fn with_on_value_change(&mut self, data: RefAny, on_value_change: SliderOnValueChangeCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_value_change(data, on_value_change);
+        s
+    }
+
+    pub fn dom(self) -> Dom {
+
+        let Slider {
+            orientation, state, tick_interval, on_value_change,
+            container_style, track_style, fill_style, rail_style, thumb_style, tick_style,
+        } = self;
+
+        let range = SliderRange::new(state.min, state.max, state.step);
+        let value = range.snap(state.value);
+
+        let styles = SliderStyles { container_style, track_style, fill_style, rail_style, thumb_style, tick_style };
+        let segment_styles = [styles.fill_style.clone(), styles.rail_style.clone()];
+
+        let dataset = RefAny::new(SliderLocalDataset {
+            orientation,
+            range,
+            values: vec![value],
+            active_thumb: 0,
+            drag: None,
+            on_value_change: SliderValueChangeHandler::Single(on_value_change),
+        });
+
+        styles.dom(
+            orientation,
+            &range,
+            &[value],
+            &segment_styles,
+            tick_interval,
+            dataset,
+        )
+    }
+}
+
+impl RangeSlider {
+
+    pub fn new(orientation: SliderOrientation, low: f32, high: f32, min: f32, max: f32) -> Self {
+        let styles = SliderStyles::new(orientation);
+        Self {
+            orientation,
+            state: RangeSliderState { low, high, min, max, step: 0.0 },
+            tick_interval: 0.0,
+            on_value_change: None.into(),
+            container_style: styles.container_style,
+            track_style: styles.track_style,
+            fill_style: styles.fill_style,
+            rail_style: styles.rail_style,
+            thumb_style: styles.thumb_style,
+            tick_style: styles.tick_style,
+        }
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::default();
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    pub fn set_step(&mut self, step: f32) {
+        self.state.step = step;
+    }
+
+    pub fn with_step(&mut self, step: f32) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_step(step);
+        s
+    }
+
+    pub fn set_tick_interval(&mut self, tick_interval: f32) {
+        self.tick_interval = tick_interval;
+    }
+
+    pub fn with_tick_interval(&mut self, tick_interval: f32) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_tick_interval(tick_interval);
+        s
+    }
+
+    pub fn set_on_value_change(&mut self, data: RefAny, on_value_change: RangeSliderOnValueChangeCallbackType) {
+        self.on_value_change = Some(RangeSliderOnValueChange {
+            data,
+            callback: RangeSliderOnValueChangeCallback { cb: on_value_change },
+        }).into();
+    }
+
+    pub fn with_on_value_change(&mut self, data: RefAny, on_value_change: RangeSliderOnValueChangeCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_value_change(data, on_value_change);
+        s
+    }
+
+    pub fn dom(self) -> Dom {
+
+        let RangeSlider {
+            orientation, state, tick_interval, on_value_change,
+            container_style, track_style, fill_style, rail_style, thumb_style, tick_style,
+        } = self;
+
+        let range = SliderRange::new(state.min, state.max, state.step);
+        let low = range.snap(state.low.min(state.high));
+        let high = range.snap(state.high).max(low);
+
+        let styles = SliderStyles { container_style, track_style, fill_style, rail_style, thumb_style, tick_style };
+        let segment_styles = [styles.rail_style.clone(), styles.fill_style.clone(), styles.rail_style.clone()];
+
+        let dataset = RefAny::new(SliderLocalDataset {
+            orientation,
+            range,
+            values: vec![low, high],
+            active_thumb: 0,
+            drag: None,
+            on_value_change: SliderValueChangeHandler::Range(on_value_change),
+        });
+
+        styles.dom(
+            orientation,
+            &range,
+            &[low, high],
+            &segment_styles,
+            tick_interval,
+            dataset,
+        )
+    }
+}
+
+impl From<Slider> for Dom {
+    fn from(s: Slider) -> Dom {
+        s.dom()
+    }
+}
+
+impl From<RangeSlider> for Dom {
+    fn from(s: RangeSlider) -> Dom {
+        s.dom()
+    }
+}
+
+/// Styles shared by the `Slider` and the `RangeSlider`
+struct SliderStyles {
+    container_style: NodeDataInlineCssPropertyVec,
+    track_style: NodeDataInlineCssPropertyVec,
+    fill_style: NodeDataInlineCssPropertyVec,
+    rail_style: NodeDataInlineCssPropertyVec,
+    thumb_style: NodeDataInlineCssPropertyVec,
+    tick_style: NodeDataInlineCssPropertyVec,
+}
+
+impl SliderStyles {
+
+    fn new(orientation: SliderOrientation) -> Self {
+        use self::SliderOrientation::*;
+        let (container_style, track_style, fill_style, rail_style, tick_style) = match orientation {
+            Horizontal => (
+                SLIDER_HORIZONTAL_CONTAINER_STYLE,
+                SLIDER_HORIZONTAL_TRACK_STYLE,
+                SLIDER_HORIZONTAL_FILL_STYLE,
+                SLIDER_HORIZONTAL_RAIL_STYLE,
+                SLIDER_HORIZONTAL_TICK_STYLE,
+            ),
+            Vertical => (
+                SLIDER_VERTICAL_CONTAINER_STYLE,
+                SLIDER_VERTICAL_TRACK_STYLE,
+                SLIDER_VERTICAL_FILL_STYLE,
+                SLIDER_VERTICAL_RAIL_STYLE,
+                SLIDER_VERTICAL_TICK_STYLE,
+            ),
+        };
+        Self {
+            container_style: NodeDataInlineCssPropertyVec::from_const_slice(container_style),
+            track_style: NodeDataInlineCssPropertyVec::from_const_slice(track_style),
+            fill_style: NodeDataInlineCssPropertyVec::from_const_slice(fill_style),
+            rail_style: NodeDataInlineCssPropertyVec::from_const_slice(rail_style),
+            thumb_style: NodeDataInlineCssPropertyVec::from_const_slice(SLIDER_THUMB_STYLE),
+            tick_style: NodeDataInlineCssPropertyVec::from_const_slice(tick_style),
+        }
+    }
+
+    /// Builds the slider DOM: `segment_styles` has one more entry than `values`,
+    /// the track alternates between segments and thumbs:
+    ///
+    /// ```text
+    /// container
+    ///     track: segment, thumb, segment [, thumb, segment]
+    ///     ticks: spacer, tick, spacer, tick, ..., spacer
+    /// ```
+    fn dom(
+        self,
+        orientation: SliderOrientation,
+        range: &SliderRange,
+        values: &[f32],
+        segment_styles: &[NodeDataInlineCssPropertyVec],
+        tick_interval: f32,
+        dataset: RefAny,
+    ) -> Dom {
+
+        static SLIDER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider"))];
+        static SLIDER_TRACK_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-track"))];
+        static SLIDER_SEGMENT_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-segment"))];
+        static SLIDER_THUMB_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-thumb"))];
+
+        let SliderStyles { container_style, track_style, thumb_style, tick_style, .. } = self;
+
+        let flex_grow = get_segment_flex_grow(range, values);
+        let mut track_children = Vec::with_capacity(values.len() * 2 + 1);
+        for (segment_idx, segment_style) in segment_styles.iter().enumerate() {
+            if segment_idx != 0 {
+                track_children.push(
+                    Dom::div()
+                    .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_THUMB_CLASS))
+                    .with_inline_css_props(thumb_style.clone())
+                );
+            }
+            let flex_grow = flex_grow.get(segment_idx).copied().unwrap_or(0.0);
+            track_children.push(
+                Dom::div()
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_SEGMENT_CLASS))
+                .with_inline_css_props(with_props(segment_style.clone(), &[get_flex_grow_prop(flex_grow)]))
+            );
+        }
+
+        let track = Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_TRACK_CLASS))
+        .with_inline_css_props(with_props(track_style, &[CssProperty::const_flex_direction(match orientation {
+            SliderOrientation::Horizontal => LayoutFlexDirection::Row,
+            SliderOrientation::Vertical => LayoutFlexDirection::ColumnReverse,
+        })]))
+        .with_callbacks(vec![CallbackData {
+            event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+            callback: Callback { cb: on_slider_mouse_down },
+            data: dataset.clone(),
+        }].into())
+        .with_children(DomVec::from_vec(track_children));
+
+        let accessibility_value = values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" - ");
+
+        let mut children = vec![track];
+        let ticks = get_tick_fractions(range, tick_interval);
+        if !ticks.is_empty() {
+            children.push(get_ticks_dom(orientation, &ticks, values.len(), tick_style));
+        }
+
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_CLASS))
+        .with_inline_css_props(with_props(container_style, &[CssProperty::const_flex_direction(match orientation {
+            SliderOrientation::Horizontal => LayoutFlexDirection::Column,
+            SliderOrientation::Vertical => LayoutFlexDirection::Row,
+        })]))
+        .with_tab_index(TabIndex::Auto)
+        .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::Slider).with_value(accessibility_value.into()))
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                callback: Callback { cb: on_slider_key_down },
+                data: dataset.clone(),
+            },
+            // dragging continues when the cursor leaves the slider
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                callback: Callback { cb: on_slider_drag },
+                data: dataset.clone(),
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                callback: Callback { cb: on_slider_drag_end },
+                data: dataset,
+            },
+        ].into())
+        .with_children(DomVec::from_vec(children))
+    }
+}
+
+/// Row of tick marks below (or next to) the track: the tick marks are spaced
+/// with flex-grow and inset by half of the thumbs, so that they line up with
+/// the positions of the values on the track
+fn get_ticks_dom(orientation: SliderOrientation, ticks: &[f32], thumb_count: usize, tick_style: NodeDataInlineCssPropertyVec) -> Dom {
+
+    static SLIDER_TICKS_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-ticks"))];
+    static SLIDER_TICK_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-slider-tick"))];
+
+    let inset = PixelValue::px(SLIDER_THUMB_SIZE as f32 * thumb_count as f32 / 2.0);
+    let props = match orientation {
+        SliderOrientation::Horizontal => vec![
+            Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+            Normal(CssProperty::const_padding_left(LayoutPaddingLeft { inner: inset })),
+            Normal(CssProperty::const_padding_right(LayoutPaddingRight { inner: inset })),
+            Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(2))),
+        ],
+        SliderOrientation::Vertical => vec![
+            Normal(CssProperty::const_flex_direction(LayoutFlexDirection::ColumnReverse)),
+            Normal(CssProperty::const_padding_top(LayoutPaddingTop { inner: inset })),
+            Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom { inner: inset })),
+            Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+        ],
+    };
+
+    let mut children = Vec::with_capacity(ticks.len() * 2 + 1);
+    let mut last = 0.0;
+    for tick in ticks.iter() {
+        children.push(Dom::div().with_inline_css_props(vec![Normal(get_flex_grow_prop((tick - last) * FLEX_GROW_SCALE))].into()));
+        children.push(
+            Dom::div()
+            .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_TICK_CLASS))
+            .with_inline_css_props(tick_style.clone())
+        );
+        last = *tick;
+    }
+    children.push(Dom::div().with_inline_css_props(vec![Normal(get_flex_grow_prop((1.0 - last) * FLEX_GROW_SCALE))].into()));
+
+    Dom::div()
+    .with_ids_and_classes(IdOrClassVec::from_const_slice(SLIDER_TICKS_CLASS))
+    .with_inline_css_props(props.into())
+    .with_children(DomVec::from_vec(children))
+}
+
+fn with_props(style: NodeDataInlineCssPropertyVec, props: &[CssProperty]) -> NodeDataInlineCssPropertyVec {
+    let mut style = style.into_library_owned_vec();
+    style.extend(props.iter().cloned().map(Normal));
+    style.into()
+}
+
+fn get_flex_grow_prop(flex_grow: f32) -> CssProperty {
+    CssProperty::FlexGrow(LayoutFlexGrowValue::Exact(LayoutFlexGrow { inner: FloatValue::new(flex_grow) }))
+}
+
+/// Flex-grow of the track segments around the thumbs at `values`
+fn get_segment_flex_grow(range: &SliderRange, values: &[f32]) -> Vec<f32> {
+    let mut last = 0.0;
+    let mut flex_grow = values.iter().map(|v| {
+        let fraction = range.get_fraction(*v);
+        let segment = fraction - last;
+        last = fraction;
+        segment * FLEX_GROW_SCALE
+    }).collect::<Vec<_>>();
+    flex_grow.push((1.0 - last) * FLEX_GROW_SCALE);
+    flex_grow
+}
+
+/// Positions of the tick marks, as fractions of the range
+fn get_tick_fractions(range: &SliderRange, tick_interval: f32) -> Vec<f32> {
+    let length = range.max - range.min;
+    if tick_interval <= 0.0 || length <= 0.0 {
+        return Vec::new();
+    }
+    let count = (length / tick_interval).floor() as usize + 1;
+    if count > SLIDER_MAX_TICK_COUNT {
+        return Vec::new();
+    }
+    (0..count).map(|i| (i as f32 * tick_interval / length).min(1.0)).collect()
+}
+
+/// Minimum, maximum and step of a slider
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct SliderRange {
+    min: f32,
+    max: f32,
+    step: f32,
+}
+
+impl SliderRange {
+
+    fn new(min: f32, max: f32, step: f32) -> Self {
+        Self { min, max: max.max(min), step: step.max(0.0) }
+    }
+
+    /// Clamps the value to the range and snaps it to the closest step
+    fn snap(&self, value: f32) -> f32 {
+        if self.max <= self.min {
+            return self.min;
+        }
+        let value = value.clamp(self.min, self.max);
+        if self.step <= 0.0 {
+            return value;
+        }
+        let snapped = self.min + ((value - self.min) / self.step).round() * self.step;
+        // the last step may not reach the maximum
+        if snapped > self.max { snapped - self.step } else { snapped }
+    }
+
+    fn get_fraction(&self, value: f32) -> f32 {
+        if self.max <= self.min {
+            return 0.0;
+        }
+        ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// Distance that the arrow keys move the thumb
+    fn get_key_step(&self) -> f32 {
+        if self.step > 0.0 {
+            self.step
+        } else {
+            (self.max - self.min) * SLIDER_CONTINUOUS_STEP
+        }
+    }
+
+    /// Returns the (unsnapped) value after pressing `key`
+    fn get_key_value(&self, key: VirtualKeyCode, value: f32) -> Option<f32> {
+        let step = self.get_key_step();
+        match key {
+            VirtualKeyCode::Right | VirtualKeyCode::Up => Some(value + step),
+            VirtualKeyCode::Left | VirtualKeyCode::Down => Some(value - step),
+            VirtualKeyCode::PageUp => Some(value + step * SLIDER_PAGE_STEPS),
+            VirtualKeyCode::PageDown => Some(value - step * SLIDER_PAGE_STEPS),
+            VirtualKeyCode::Home => Some(self.min),
+            VirtualKeyCode::End => Some(self.max),
+            _ => None,
+        }
+    }
+
+    /// Snaps the value of the thumb at `thumb` and keeps it between its neighbours
+    fn clamp_thumb_value(&self, values: &[f32], thumb: usize, value: f32) -> f32 {
+        let lower = if thumb == 0 { self.min } else { values.get(thumb - 1).copied().unwrap_or(self.min) };
+        let upper = values.get(thumb + 1).copied().unwrap_or(self.max);
+        self.snap(value).max(lower).min(upper)
+    }
+}
+
+/// Returns the index of the thumb closest to `value`. If two thumbs are
+/// on top of each other, the thumb in the direction of `value` is picked
+fn get_nearest_thumb(values: &[f32], value: f32) -> usize {
+    let mut nearest = 0;
+    for (i, v) in values.iter().enumerate() {
+        let distance = (v - value).abs();
+        let nearest_distance = (values[nearest] - value).abs();
+        if distance < nearest_distance || (distance == nearest_distance && value > *v) {
+            nearest = i;
+        }
+    }
+    nearest
+}
+
+/// Measured size of the track, converts between pixels and values
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct SliderGeometry {
+    track_length: f32,
+    thumb_length: f32,
+    thumb_count: usize,
+}
+
+impl SliderGeometry {
+
+    /// Length of the track that the thumbs can move along
+    fn get_usable_length(&self) -> f32 {
+        self.track_length - self.thumb_length * self.thumb_count as f32
+    }
+
+    /// Distance from the start of the track to the position of the minimum
+    fn get_inset(&self) -> f32 {
+        self.thumb_length * self.thumb_count as f32 / 2.0
+    }
+
+    fn get_value_at(&self, range: &SliderRange, position: f32) -> f32 {
+        let usable = self.get_usable_length();
+        if usable <= 0.0 {
+            return range.min;
+        }
+        range.min + (position - self.get_inset()) / usable * (range.max - range.min)
+    }
+
+    /// Position of the center of the thumb at index `thumb`
+    fn get_thumb_center(&self, range: &SliderRange, thumb: usize, value: f32) -> f32 {
+        range.get_fraction(value) * self.get_usable_length() + self.thumb_length * (thumb as f32 + 0.5)
+    }
+}
+
+/// Thumb that is currently being dragged
+#[derive(Debug, Copy, Clone)]
+struct SliderDrag {
+    thumb: usize,
+    track_node: DomNodeId,
+    start_cursor: f32,
+    start_value: f32,
+    usable_length: f32,
+}
+
+enum SliderValueChangeHandler {
+    Single(OptionSliderOnValueChange),
+    Range(OptionRangeSliderOnValueChange),
+}
+
+struct SliderLocalDataset {
+    orientation: SliderOrientation,
+    range: SliderRange,
+    values: Vec<f32>,
+    /// Thumb that the keyboard moves, the last thumb that was clicked
+    active_thumb: usize,
+    drag: Option<SliderDrag>,
+    on_value_change: SliderValueChangeHandler,
+}
+
+impl SliderLocalDataset {
+
+    fn get_main_axis(&self, x: f32, y: f32) -> f32 {
+        match self.orientation {
+            SliderOrientation::Horizontal => x,
+            SliderOrientation::Vertical => y,
+        }
+    }
+
+    /// Moves the thumb and resizes the track segments, returns `None` if the value didn't change
+    fn set_value(&mut self, info: &mut CallbackInfo, track_node: DomNodeId, thumb: usize, value: f32) -> Option<Update> {
+
+        let value = self.range.clamp_thumb_value(&self.values, thumb, value);
+        let current = self.values.get_mut(thumb)?;
+        if *current == value {
+            return None;
+        }
+        *current = value;
+
+        // track: segment, thumb, segment [, thumb, segment]
+        let mut segment_node = info.get_first_child(track_node);
+        for flex_grow in get_segment_flex_grow(&self.range, &self.values) {
+            let node = segment_node?;
+            info.set_css_property(node, get_flex_grow_prop(flex_grow));
+            segment_node = info.get_next_sibling(node).and_then(|thumb_node| info.get_next_sibling(thumb_node));
+        }
+
+        Some(self.dispatch_value_change(info))
+    }
+
+    fn dispatch_value_change(&mut self, info: &mut CallbackInfo) -> Update {
+        let SliderRange { min, max, step } = self.range;
+        match &mut self.on_value_change {
+            SliderValueChangeHandler::Single(on_value_change) => {
+                let state = SliderState { value: self.values[0], min, max, step };
+                match on_value_change.as_mut() {
+                    Some(SliderOnValueChange { data, callback }) => (callback.cb)(data, info, &state),
+                    None => Update::DoNothing,
+                }
+            },
+            SliderValueChangeHandler::Range(on_value_change) => {
+                let state = RangeSliderState { low: self.values[0], high: self.values[1], min, max, step };
+                match on_value_change.as_mut() {
+                    Some(RangeSliderOnValueChange { data, callback }) => (callback.cb)(data, info, &state),
+                    None => Update::DoNothing,
+                }
+            },
+        }
+    }
+}
+
+extern "C" fn on_slider_mouse_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_slider_mouse_down_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut slider = data.downcast_mut::<SliderLocalDataset>()?;
+
+        let track_node = info.get_hit_node();
+        let thumb_node = info.get_next_sibling(info.get_first_child(track_node)?)?;
+        let track_size = info.get_node_size(track_node)?;
+        let thumb_size = info.get_node_size(thumb_node)?;
+        let cursor = info.get_cursor_relative_to_node().into_option()?;
+        let cursor_in_viewport = info.get_cursor_relative_to_viewport().into_option()?;
+
+        let geometry = SliderGeometry {
+            track_length: slider.get_main_axis(track_size.width, track_size.height),
+            thumb_length: slider.get_main_axis(thumb_size.width, thumb_size.height),
+            thumb_count: slider.values.len(),
+        };
+
+        // vertical sliders start at the bottom
+        let position = match slider.orientation {
+            SliderOrientation::Horizontal => cursor.x,
+            SliderOrientation::Vertical => geometry.track_length - cursor.y,
+        };
+
+        let range = slider.range;
+        let target = geometry.get_value_at(&range, position);
+        let thumb = get_nearest_thumb(&slider.values, target);
+        slider.active_thumb = thumb;
+
+        // clicking on the thumb only starts the drag,
+        // clicking on the track jumps to the cursor first
+        let thumb_center = geometry.get_thumb_center(&range, thumb, slider.values[thumb]);
+        let update = if (thumb_center - position).abs() > geometry.thumb_length / 2.0 {
+            slider.set_value(info, track_node, thumb, target)
+        } else {
+            None
+        };
+
+        slider.drag = Some(SliderDrag {
+            thumb,
+            track_node,
+            start_cursor: slider.get_main_axis(cursor_in_viewport.x, cursor_in_viewport.y),
+            start_value: slider.values[thumb],
+            usable_length: geometry.get_usable_length(),
+        });
+
+        update
+    }
+
+    on_slider_mouse_down_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_slider_drag(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_slider_drag_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut slider = data.downcast_mut::<SliderLocalDataset>()?;
+        let drag = slider.drag?;
+        if drag.usable_length <= 0.0 {
+            return None;
+        }
+
+        let cursor = info.get_cursor_relative_to_viewport().into_option()?;
+        let delta = slider.get_main_axis(cursor.x, cursor.y) - drag.start_cursor;
+        let delta = match slider.orientation {
+            SliderOrientation::Horizontal => delta,
+            SliderOrientation::Vertical => -delta,
+        };
+
+        let value = drag.start_value + delta / drag.usable_length * (slider.range.max - slider.range.min);
+        slider.set_value(info, drag.track_node, drag.thumb, value)
+    }
+
+    on_slider_drag_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_slider_drag_end(data: &mut RefAny, _: &mut CallbackInfo) -> Update {
+    if let Some(mut slider) = data.downcast_mut::<SliderLocalDataset>() {
+        slider.drag = None;
+    }
+    Update::DoNothing
+}
+
+extern "C" fn on_slider_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_slider_key_down_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let key = info.get_current_keyboard_state().current_virtual_keycode.into_option()?;
+        let track_node = info.get_first_child(info.get_hit_node())?;
+
+        let mut slider = data.downcast_mut::<SliderLocalDataset>()?;
+        let thumb = slider.active_thumb;
+        let value = slider.range.get_key_value(key, *slider.values.get(thumb)?)?;
+        slider.set_value(info, track_node, thumb, value)
+    }
+
+    on_slider_key_down_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+#[cfg(test)]
+mod slider_test {
+
+    use super::*;
+
+    #[test]
+    fn values_are_clamped_and_snapped_to_steps() {
+        let range = SliderRange::new(0.0, 10.0, 2.0);
+        assert_eq!(range.snap(-5.0), 0.0);
+        assert_eq!(range.snap(2.9), 2.0);
+        assert_eq!(range.snap(3.1), 4.0);
+        assert_eq!(range.snap(15.0), 10.0);
+
+        // 9 is not a multiple of the step, the last reachable value is 8
+        let range = SliderRange::new(0.0, 9.0, 2.0);
+        assert_eq!(range.snap(9.0), 8.0);
+
+        let continuous = SliderRange::new(-1.0, 1.0, 0.0);
+        assert_eq!(continuous.snap(0.123), 0.123);
+        assert_eq!(SliderRange::new(5.0, 5.0, 1.0).snap(7.0), 5.0);
+    }
+
+    #[test]
+    fn keys_move_by_steps_and_pages() {
+        let range = SliderRange::new(0.0, 100.0, 5.0);
+        assert_eq!(range.get_key_value(VirtualKeyCode::Right, 50.0), Some(55.0));
+        assert_eq!(range.get_key_value(VirtualKeyCode::Down, 50.0), Some(45.0));
+        assert_eq!(range.get_key_value(VirtualKeyCode::PageUp, 50.0), Some(100.0));
+        assert_eq!(range.get_key_value(VirtualKeyCode::PageDown, 50.0), Some(0.0));
+        assert_eq!(range.get_key_value(VirtualKeyCode::Home, 50.0), Some(0.0));
+        assert_eq!(range.get_key_value(VirtualKeyCode::End, 50.0), Some(100.0));
+        assert_eq!(range.get_key_value(VirtualKeyCode::A, 50.0), None);
+
+        // continuous sliders move by 1% of the range
+        let continuous = SliderRange::new(0.0, 200.0, 0.0);
+        assert_eq!(continuous.get_key_value(VirtualKeyCode::Up, 10.0), Some(12.0));
+    }
+
+    #[test]
+    fn range_thumbs_cannot_pass_each_other() {
+        let range = SliderRange::new(0.0, 100.0, 1.0);
+        let values = [20.0, 60.0];
+        assert_eq!(range.clamp_thumb_value(&values, 0, 80.0), 60.0);
+        assert_eq!(range.clamp_thumb_value(&values, 0, -10.0), 0.0);
+        assert_eq!(range.clamp_thumb_value(&values, 1, 10.0), 20.0);
+        assert_eq!(range.clamp_thumb_value(&values, 1, 70.4), 70.0);
+    }
+
+    #[test]
+    fn nearest_thumb_prefers_the_direction_of_the_cursor() {
+        assert_eq!(get_nearest_thumb(&[50.0], 0.0), 0);
+        assert_eq!(get_nearest_thumb(&[20.0, 60.0], 30.0), 0);
+        assert_eq!(get_nearest_thumb(&[20.0, 60.0], 50.0), 1);
+        assert_eq!(get_nearest_thumb(&[40.0, 40.0], 30.0), 0);
+        assert_eq!(get_nearest_thumb(&[40.0, 40.0], 50.0), 1);
+    }
+
+    #[test]
+    fn cursor_position_maps_to_thumb_position() {
+        let range = SliderRange::new(0.0, 100.0, 0.0);
+
+        // one 16px thumb on a 216px track: the thumb center moves from 8px to 208px
+        let geometry = SliderGeometry { track_length: 216.0, thumb_length: 16.0, thumb_count: 1 };
+        assert_eq!(geometry.get_value_at(&range, 8.0), 0.0);
+        assert_eq!(geometry.get_value_at(&range, 108.0), 50.0);
+        assert_eq!(geometry.get_value_at(&range, 208.0), 100.0);
+        assert_eq!(geometry.get_thumb_center(&range, 0, 50.0), 108.0);
+
+        // two thumbs: the values are at the inner edges of the thumbs
+        let geometry = SliderGeometry { track_length: 232.0, thumb_length: 16.0, thumb_count: 2 };
+        assert_eq!(geometry.get_value_at(&range, 116.0), 50.0);
+        assert_eq!(geometry.get_thumb_center(&range, 0, 50.0), 108.0);
+        assert_eq!(geometry.get_thumb_center(&range, 1, 50.0), 124.0);
+    }
+
+    #[test]
+    fn segments_and_ticks_are_fractions_of_the_range() {
+        let range = SliderRange::new(0.0, 10.0, 0.0);
+        assert_eq!(get_segment_flex_grow(&range, &[2.5]), vec![0.25 * FLEX_GROW_SCALE, 0.75 * FLEX_GROW_SCALE]);
+        assert_eq!(get_segment_flex_grow(&range, &[2.5, 5.0]), vec![
+            0.25 * FLEX_GROW_SCALE,
+            0.25 * FLEX_GROW_SCALE,
+            0.5 * FLEX_GROW_SCALE,
+        ]);
+        assert_eq!(get_tick_fractions(&range, 2.5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(get_tick_fractions(&range, 4.0), vec![0.0, 0.4, 0.8]);
+        assert!(get_tick_fractions(&range, 0.0).is_empty());
+        assert!(get_tick_fractions(&range, 0.001).is_empty());
+    }
+}
@@ -0,0 +1,226 @@
+use azul_desktop::css::*;
+use azul_desktop::css::AzString;
+use azul_desktop::dom::{
+    Dom, IdOrClass, IdOrClass::Class, IdOrClassVec,
+    EventFilter, ComponentEventFilter, CallbackData,
+    NodeDataInlineCssProperty::Normal,
+    NodeDataInlineCssPropertyVec,
+};
+use azul_desktop::callbacks::{
+    Update, RefAny, CallbackInfo, Callback,
+    TimerCallbackInfo, TimerCallbackReturn,
+};
+use azul_desktop::task::{Timer, TerminateTimer, Duration, SystemTimeDiff};
+
+const COLOR_569DE5: ColorU = ColorU { r: 86, g: 157, b: 229, a: 255 }; // #569de5
+const COLOR_E1E1E1: ColorU = ColorU { r: 225, g: 225, b: 225, a: 255 }; // #e1e1e1
+
+/// How far the spinner rotates per timer tick, in degrees
+const SPINNER_STEP_DEGREES: f32 = 12.0;
+const SPINNER_INTERVAL_MS: u64 = 30;
+
+/// Circular, endlessly rotating "busy" indicator for operations without a known total
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct Spinner {
+    /// Outer diameter of the spinner
+    pub size: PixelValue,
+    /// Width of the ring
+    pub thickness: PixelValue,
+    /// Color of the rotating arc
+    pub color: ColorU,
+    /// Color of the rest of the ring
+    pub track_color: ColorU,
+}
+
+/// Timer data of a mounted spinner
+struct SpinnerAnimation {
+    step: usize,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Spinner {
+
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            size: PixelValue::const_px(24),
+            thickness: PixelValue::const_px(3),
+            color: COLOR_569DE5,
+            track_color: COLOR_E1E1E1,
+        }
+    }
+
+    #[inline]
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::new();
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    pub fn set_size(&mut self, size: PixelValue) {
+        self.size = size;
+    }
+
+    pub fn with_size(&mut self, size: PixelValue) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_size(size);
+        s
+    }
+
+    pub fn set_thickness(&mut self, thickness: PixelValue) {
+        self.thickness = thickness;
+    }
+
+    pub fn with_thickness(&mut self, thickness: PixelValue) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_thickness(thickness);
+        s
+    }
+
+    pub fn set_color(&mut self, color: ColorU) {
+        self.color = color;
+    }
+
+    pub fn with_color(&mut self, color: ColorU) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_color(color);
+        s
+    }
+
+    pub fn set_track_color(&mut self, track_color: ColorU) {
+        self.track_color = track_color;
+    }
+
+    pub fn with_track_color(&mut self, track_color: ColorU) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_track_color(track_color);
+        s
+    }
+
+    fn get_style(&self) -> NodeDataInlineCssPropertyVec {
+
+        // the outer size includes the border, radius = half of the diameter
+        let radius = PixelValue::from_metric(self.size.metric, self.size.number.get() / 2.0);
+        let border_style = BorderStyle::Solid;
+
+        NodeDataInlineCssPropertyVec::from_vec(vec![
+            // .__azul-native-spinner
+            Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+            Normal(CssProperty::const_width(LayoutWidth { inner: self.size })),
+            Normal(CssProperty::const_height(LayoutHeight { inner: self.size })),
+            Normal(CssProperty::const_min_width(LayoutMinWidth { inner: self.size })),
+            Normal(CssProperty::const_min_height(LayoutMinHeight { inner: self.size })),
+            Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(0))),
+            Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+            Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth { inner: self.thickness })),
+            Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth { inner: self.thickness })),
+            Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth { inner: self.thickness })),
+            Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth { inner: self.thickness })),
+            Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: border_style })),
+            Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: border_style })),
+            Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: border_style })),
+            Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: border_style })),
+            Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: self.color })),
+            Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: self.track_color })),
+            Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: self.track_color })),
+            Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: self.track_color })),
+            Normal(CssProperty::const_border_top_left_radius(StyleBorderTopLeftRadius { inner: radius })),
+            Normal(CssProperty::const_border_top_right_radius(StyleBorderTopRightRadius { inner: radius })),
+            Normal(CssProperty::const_border_bottom_left_radius(StyleBorderBottomLeftRadius { inner: radius })),
+            Normal(CssProperty::const_border_bottom_right_radius(StyleBorderBottomRightRadius { inner: radius })),
+            Normal(get_spinner_transform(0)),
+        ])
+    }
+
+    pub fn dom(self) -> Dom {
+        static SPINNER_CLASS: &[IdOrClass] = &[Class(AzString::from_const_str("__azul-native-spinner"))];
+        Dom::div()
+        .with_ids_and_classes(IdOrClassVec::from_const_slice(SPINNER_CLASS))
+        .with_inline_css_props(self.get_style())
+        // the timer is attached to the spinner node, so it
+        // stops as soon as the node is removed from the DOM
+        .with_callbacks(vec![CallbackData {
+            event: EventFilter::Component(ComponentEventFilter::AfterMount),
+            callback: Callback { cb: on_spinner_mount },
+            data: RefAny::new(SpinnerAnimation { step: 0 }),
+        }].into())
+    }
+}
+
+impl From<Spinner> for Dom {
+    fn from(s: Spinner) -> Dom {
+        s.dom()
+    }
+}
+
+/// Returns the rotation of the spinner after `step` timer ticks, in degrees
+fn get_spinner_rotation(step: usize) -> f32 {
+    (step as f32 * SPINNER_STEP_DEGREES) % 360.0
+}
+
+fn get_spinner_transform(step: usize) -> CssProperty {
+    CssProperty::Transform(StyleTransformVecValue::Exact(vec![
+        StyleTransform::Rotate(AngleValue::deg(get_spinner_rotation(step)))
+    ].into()))
+}
+
+extern "C"
+fn on_spinner_mount(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    let mut timer = Timer::new(data.clone(), on_spinner_tick, info.get_system_time_fn())
+        .with_interval(Duration::System(SystemTimeDiff::from_millis(SPINNER_INTERVAL_MS)));
+    timer.node_id = Some(info.get_hit_node()).into();
+    info.start_timer(timer);
+    Update::DoNothing
+}
+
+extern "C"
+fn on_spinner_tick(data: &mut RefAny, info: &mut TimerCallbackInfo) -> TimerCallbackReturn {
+
+    fn on_spinner_tick_inner(data: &mut RefAny, info: &mut TimerCallbackInfo) -> Option<()> {
+        let mut animation = data.downcast_mut::<SpinnerAnimation>()?;
+        let node = info.node_id.into_option()?;
+        animation.step = animation.step.wrapping_add(1);
+        info.callback_info.set_css_property(node, get_spinner_transform(animation.step));
+        Some(())
+    }
+
+    TimerCallbackReturn {
+        should_update: Update::DoNothing,
+        should_terminate: match on_spinner_tick_inner(data, info) {
+            Some(()) => TerminateTimer::Continue,
+            None => TerminateTimer::Terminate,
+        },
+    }
+}
+
+#[cfg(test)]
+mod spinner_test {
+
+    use super::*;
+
+    #[test]
+    fn rotation_wraps_after_a_full_turn() {
+        assert_eq!(get_spinner_rotation(0), 0.0);
+        assert_eq!(get_spinner_rotation(1), SPINNER_STEP_DEGREES);
+        assert_eq!(get_spinner_rotation(15), 180.0);
+        assert_eq!(get_spinner_rotation(30), 0.0);
+        assert_eq!(get_spinner_rotation(31), SPINNER_STEP_DEGREES);
+    }
+
+    #[test]
+    fn ring_is_round_and_only_colored_on_top() {
+        let style = Spinner::new().with_size(PixelValue::const_px(40)).get_style();
+        let style = style.as_ref();
+        assert!(style.contains(&Normal(CssProperty::const_border_top_left_radius(StyleBorderTopLeftRadius {
+            inner: PixelValue::px(20.0)
+        }))));
+        assert!(style.contains(&Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_569DE5 }))));
+        assert!(style.contains(&Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_E1E1E1 }))));
+    }
+}
@@ -0,0 +1,714 @@
+use alloc::vec::Vec;
+use azul_desktop::css::*;
+use azul_desktop::css::AzString;
+use azul_desktop::dom::{
+    Dom, IdOrClass, IdOrClass::Class,
+    HoverEventFilter, WindowEventFilter, EventFilter,
+    CallbackData, NodeDataInlineCssProperty,
+    DomVec, IdOrClassVec, NodeDataInlineCssPropertyVec,
+};
+use azul_desktop::callbacks::{
+    Update, RefAny, CallbackInfo, Callback,
+};
+use azul_core::callbacks::DomNodeId;
+use azul_core::task::{Instant, Duration, SystemTimeDiff};
+
+const COLOR_E1E1E1: ColorU = ColorU { r: 225, g: 225, b: 225, a: 255 }; // #e1e1e1
+const COLOR_569DE5: ColorU = ColorU { r: 86, g: 157, b: 229, a: 255 }; // #569de5
+
+const SPLITTER_HANDLE_BACKGROUND_ITEMS: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_E1E1E1)];
+const SPLITTER_HANDLE_HOVER_BACKGROUND_ITEMS: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_569DE5)];
+
+/// Maximum time between two clicks on the same handle to count as a double click
+const DOUBLE_CLICK_TIME_MS: u64 = 500;
+
+static SPLITTER_HORIZONTAL_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+];
+
+static SPLITTER_VERTICAL_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+];
+
+static SPLITTER_HORIZONTAL_HANDLE_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(5))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::ColResize)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SPLITTER_HANDLE_BACKGROUND_ITEMS))),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SPLITTER_HANDLE_HOVER_BACKGROUND_ITEMS))),
+];
+
+static SPLITTER_VERTICAL_HANDLE_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(5))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_cursor(StyleCursor::RowResize)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SPLITTER_HANDLE_BACKGROUND_ITEMS))),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(SPLITTER_HANDLE_HOVER_BACKGROUND_ITEMS))),
+];
+
+/// Direction in which the panes of a `Splitter` are laid out
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum SplitterOrientation {
+    /// Panes are placed next to each other, the handles are dragged left and right
+    Horizontal,
+    /// Panes are placed on top of each other, the handles are dragged up and down
+    Vertical,
+}
+
+/// Pane of a `Splitter`
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct SplitterPane {
+    pub content: Dom,
+    /// Initial size of the pane in pixels (width or height, depending on the
+    /// orientation). Panes without a size share the remaining space, so
+    /// at least one of the two panes next to a handle should have a size.
+    pub size: OptionF32,
+    pub min_size: f32,
+    pub max_size: OptionF32,
+    /// Whether double-clicking a handle next to the pane collapses it
+    pub collapsible: bool,
+}
+
+impl_vec!(SplitterPane, SplitterPaneVec, SplitterPaneVecDestructor);
+impl_vec_clone!(SplitterPane, SplitterPaneVec, SplitterPaneVecDestructor);
+impl_vec_mut!(SplitterPane, SplitterPaneVec);
+impl_vec_debug!(SplitterPane, SplitterPaneVec);
+
+impl Default for SplitterPane {
+    fn default() -> Self {
+        Self {
+            content: Dom::div(),
+            size: None.into(),
+            min_size: 0.0,
+            max_size: None.into(),
+            collapsible: false,
+        }
+    }
+}
+
+impl SplitterPane {
+
+    pub fn new(content: Dom) -> Self {
+        Self {
+            content,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut default = Self::default();
+        core::mem::swap(&mut default, self);
+        default
+    }
+
+    pub fn set_size(&mut self, size: f32) {
+        self.size = Some(size).into();
+    }
+
+    pub fn with_size(&mut self, size: f32) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_size(size);
+        s
+    }
+
+    pub fn set_min_size(&mut self, min_size: f32) {
+        self.min_size = min_size;
+    }
+
+    pub fn with_min_size(&mut self, min_size: f32) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_min_size(min_size);
+        s
+    }
+
+    pub fn set_max_size(&mut self, max_size: f32) {
+        self.max_size = Some(max_size).into();
+    }
+
+    pub fn with_max_size(&mut self, max_size: f32) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_max_size(max_size);
+        s
+    }
+
+    pub fn set_collapsible(&mut self, collapsible: bool) {
+        self.collapsible = collapsible;
+    }
+
+    pub fn with_collapsible(&mut self, collapsible: bool) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_collapsible(collapsible);
+        s
+    }
+}
+
+/// Size and collapsed state of a single pane
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct SplitterPaneState {
+    /// Size of the pane in pixels. Ignored for panes without an
+    /// initial size, these always fill the remaining space
+    pub size: f32,
+    pub collapsed: bool,
+}
+
+impl_vec!(SplitterPaneState, SplitterPaneStateVec, SplitterPaneStateVecDestructor);
+impl_vec_clone!(SplitterPaneState, SplitterPaneStateVec, SplitterPaneStateVecDestructor);
+impl_vec_mut!(SplitterPaneState, SplitterPaneStateVec);
+impl_vec_debug!(SplitterPaneState, SplitterPaneStateVec);
+impl_vec_partialeq!(SplitterPaneState, SplitterPaneStateVec);
+
+/// Pane sizes of the `Splitter`.
+///
+/// The state is owned by the application: the `on_resize` callback gets
+/// the new state as an argument, the application should store it (and
+/// save it to restore the layout in the next session) and pass it back
+/// in via `Splitter::with_state` on the next `layout()` call.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct SplitterState {
+    /// One entry per pane, panes without an entry use their initial size
+    pub panes: SplitterPaneStateVec,
+}
+
+impl Default for SplitterState {
+    fn default() -> Self {
+        Self {
+            panes: SplitterPaneStateVec::from_const_slice(&[]),
+        }
+    }
+}
+
+pub type SplitterOnResizeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &SplitterState) -> Update;
+impl_callback!(SplitterOnResize, OptionSplitterOnResize, SplitterOnResizeCallback, SplitterOnResizeCallbackType);
+
+/// Container with resizable panes, separated by draggable handles
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct Splitter {
+    pub orientation: SplitterOrientation,
+    pub panes: SplitterPaneVec,
+    pub state: SplitterState,
+    /// Called when a handle is released after dragging or a pane is collapsed / expanded
+    pub on_resize: OptionSplitterOnResize,
+}
+
+impl Default for Splitter {
+    fn default() -> Self {
+        Self {
+            orientation: SplitterOrientation::Horizontal,
+            panes: SplitterPaneVec::from_const_slice(&[]),
+            state: SplitterState::default(),
+            on_resize: None.into(),
+        }
+    }
+}
+
+impl Splitter {
+
+    pub fn new(orientation: SplitterOrientation, panes: SplitterPaneVec) -> Self {
+        Self {
+            orientation,
+            panes,
+            .. Default::default()
+        }
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut default = Self::default();
+        core::mem::swap(&mut default, self);
+        default
+    }
+
+    pub fn set_state(&mut self, state: SplitterState) {
+        self.state = state;
+    }
+
+    pub fn with_state(&mut self, state: SplitterState) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_state(state);
+        s
+    }
+
+    pub fn set_on_resize(&mut self, data: RefAny, on_resize: SplitterOnResizeCallbackType) {
+        self.on_resize = Some(SplitterOnResize { data, callback: SplitterOnResizeCallback { cb: on_resize }}).into();
+    }
+
+    pub fn with_on_resize(&mut self, data: RefAny, on_resize: SplitterOnResizeCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_resize(data, on_resize);
+        s
+    }
+
+    pub fn dom(&mut self) -> Dom {
+
+        let Splitter { orientation, panes, state, on_resize } = self.swap_with_default();
+        let panes = panes.into_library_owned_vec();
+
+        let limits = panes.iter().map(|pane| SplitterPaneLimits {
+            min_size: pane.min_size,
+            max_size: pane.max_size.into_option(),
+            flexible: pane.size.is_none(),
+            collapsible: pane.collapsible,
+        }).collect::<Vec<_>>();
+
+        // fill up the state with the initial sizes of the panes
+        let state_panes = panes.iter().enumerate().map(|(i, pane)| {
+            state.panes.as_ref().get(i).copied().unwrap_or(SplitterPaneState {
+                size: pane.size.into_option().unwrap_or(0.0),
+                collapsed: false,
+            })
+        }).collect::<Vec<_>>();
+
+        let splitter = RefAny::new(SplitterLocalDataset {
+            orientation,
+            limits: limits.clone(),
+            state: SplitterState { panes: state_panes.clone().into() },
+            drag: None,
+            last_click: None,
+            on_resize,
+        });
+
+        let handle_props = match orientation {
+            SplitterOrientation::Horizontal => SPLITTER_HORIZONTAL_HANDLE_PROPS,
+            SplitterOrientation::Vertical => SPLITTER_VERTICAL_HANDLE_PROPS,
+        };
+
+        let pane_count = panes.len();
+        let mut children = Vec::with_capacity(pane_count * 2);
+
+        for (pane_idx, pane) in panes.into_iter().enumerate() {
+
+            children.push(
+                Dom::div()
+                .with_inline_css_props(get_pane_props(orientation, &limits[pane_idx], &state_panes[pane_idx]))
+                .with_ids_and_classes({
+                    const IDS_AND_CLASSES_SPLITTER_PANE: &[IdOrClass] = &[
+                        Class(AzString::from_const_str("__azul-native-splitter-pane")),
+                    ];
+                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_SPLITTER_PANE)
+                })
+                .with_children(vec![pane.content].into())
+            );
+
+            if pane_idx + 1 == pane_count {
+                break;
+            }
+
+            children.push(
+                Dom::div()
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(handle_props))
+                .with_ids_and_classes({
+                    const IDS_AND_CLASSES_SPLITTER_HANDLE: &[IdOrClass] = &[
+                        Class(AzString::from_const_str("__azul-native-splitter-handle")),
+                    ];
+                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_SPLITTER_HANDLE)
+                })
+                .with_callbacks(vec![CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    callback: Callback { cb: on_splitter_handle_mouse_down },
+                    data: RefAny::new(SplitterHandleLocalDataset { handle: pane_idx, splitter: splitter.clone() }),
+                }].into())
+            );
+        }
+
+        let container_props = match orientation {
+            SplitterOrientation::Horizontal => SPLITTER_HORIZONTAL_PROPS,
+            SplitterOrientation::Vertical => SPLITTER_VERTICAL_PROPS,
+        };
+
+        Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(container_props))
+        .with_ids_and_classes({
+            const IDS_AND_CLASSES_SPLITTER: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-splitter")),
+            ];
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_SPLITTER)
+        })
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                callback: Callback { cb: on_splitter_drag },
+                data: splitter.clone(),
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                callback: Callback { cb: on_splitter_drag_end },
+                data: splitter,
+            },
+        ].into())
+        .with_children(DomVec::from_vec(children))
+    }
+}
+
+fn get_pane_props(orientation: SplitterOrientation, limits: &SplitterPaneLimits, state: &SplitterPaneState) -> NodeDataInlineCssPropertyVec {
+
+    let mut props = vec![
+        NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Column)),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+        NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+    ];
+
+    if state.collapsed {
+        props.push(NodeDataInlineCssProperty::Normal(CssProperty::const_display(LayoutDisplay::None)));
+    }
+
+    if limits.flexible {
+        props.push(NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))));
+        props.extend(get_size_limit_props(orientation, limits.min_size, limits.max_size).into_iter().map(NodeDataInlineCssProperty::Normal));
+    } else {
+        let size = limits.clamp(state.size);
+        props.push(NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(0))));
+        props.push(NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))));
+        props.extend(get_size_props(orientation, size).into_iter().map(NodeDataInlineCssProperty::Normal));
+    }
+
+    props.into()
+}
+
+/// Properties that fix the size of a pane along the main axis
+fn get_size_props(orientation: SplitterOrientation, size: f32) -> Vec<CssProperty> {
+    match orientation {
+        SplitterOrientation::Horizontal => vec![
+            CssProperty::min_width(LayoutMinWidth::px(size)),
+            CssProperty::width(LayoutWidth::px(size)),
+            CssProperty::max_width(LayoutMaxWidth::px(size)),
+        ],
+        SplitterOrientation::Vertical => vec![
+            CssProperty::min_height(LayoutMinHeight::px(size)),
+            CssProperty::height(LayoutHeight::px(size)),
+            CssProperty::max_height(LayoutMaxHeight::px(size)),
+        ],
+    }
+}
+
+fn get_size_limit_props(orientation: SplitterOrientation, min_size: f32, max_size: Option<f32>) -> Vec<CssProperty> {
+    let mut props = Vec::new();
+    match orientation {
+        SplitterOrientation::Horizontal => {
+            props.push(CssProperty::min_width(LayoutMinWidth::px(min_size)));
+            props.extend(max_size.map(|m| CssProperty::max_width(LayoutMaxWidth::px(m))));
+        },
+        SplitterOrientation::Vertical => {
+            props.push(CssProperty::min_height(LayoutMinHeight::px(min_size)));
+            props.extend(max_size.map(|m| CssProperty::max_height(LayoutMaxHeight::px(m))));
+        },
+    }
+    props
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct SplitterPaneLimits {
+    min_size: f32,
+    max_size: Option<f32>,
+    /// Pane fills the remaining space instead of having a fixed size
+    flexible: bool,
+    collapsible: bool,
+}
+
+impl SplitterPaneLimits {
+    fn clamp(&self, size: f32) -> f32 {
+        let size = size.max(self.min_size);
+        match self.max_size {
+            Some(max) => size.min(max.max(self.min_size)),
+            None => size,
+        }
+    }
+}
+
+/// Moves the handle between two panes by `delta` pixels: the first pane grows
+/// by the same amount the second pane shrinks. The delta is limited so that
+/// both panes stay within their min / max size.
+fn get_drag_sizes(
+    sizes: (f32, f32),
+    limits: (&SplitterPaneLimits, &SplitterPaneLimits),
+    delta: f32,
+) -> (f32, f32) {
+
+    let (a, b) = sizes;
+    let (limits_a, limits_b) = limits;
+
+    let mut min_delta = limits_a.min_size - a;
+    let mut max_delta = b - limits_b.min_size;
+    if let Some(max_a) = limits_a.max_size {
+        max_delta = max_delta.min(max_a - a);
+    }
+    if let Some(max_b) = limits_b.max_size {
+        min_delta = min_delta.max(b - max_b);
+    }
+
+    let delta = if min_delta > max_delta {
+        0.0
+    } else {
+        delta.max(min_delta).min(max_delta)
+    };
+
+    (a + delta, b - delta)
+}
+
+/// Returns the pane that is collapsed or expanded when double-clicking the
+/// handle between pane `handle` and `handle + 1`: a collapsed pane is expanded
+/// first, otherwise the first collapsible pane is collapsed
+fn get_collapse_target(limits: &[SplitterPaneLimits], state: &[SplitterPaneState], handle: usize) -> Option<usize> {
+    let candidates = [handle, handle + 1];
+    candidates.iter().copied()
+    .find(|i| state.get(*i).map(|s| s.collapsed).unwrap_or(false))
+    .or_else(|| candidates.iter().copied().find(|i| limits.get(*i).map(|l| l.collapsible).unwrap_or(false)))
+}
+
+fn is_double_click(last_click: &Instant, now: &Instant) -> bool {
+    match (last_click, now) {
+        (Instant::System(_), Instant::System(_)) => {
+            let max = Duration::System(SystemTimeDiff::from_millis(DOUBLE_CLICK_TIME_MS));
+            now.duration_since(last_click).smaller_than(&max)
+        },
+        _ => false,
+    }
+}
+
+/// Handle that is currently being dragged
+#[derive(Debug, Copy, Clone)]
+struct SplitterDrag {
+    handle: usize,
+    pane_nodes: (DomNodeId, DomNodeId),
+    start_cursor: f32,
+    start_sizes: (f32, f32),
+    current_sizes: (f32, f32),
+}
+
+struct SplitterLocalDataset {
+    orientation: SplitterOrientation,
+    limits: Vec<SplitterPaneLimits>,
+    state: SplitterState,
+    drag: Option<SplitterDrag>,
+    /// Handle and time of the last click, used to detect double clicks
+    last_click: Option<(usize, Instant)>,
+    on_resize: OptionSplitterOnResize,
+}
+
+impl SplitterLocalDataset {
+
+    fn get_main_axis(&self, x: f32, y: f32) -> f32 {
+        match self.orientation {
+            SplitterOrientation::Horizontal => x,
+            SplitterOrientation::Vertical => y,
+        }
+    }
+
+    fn dispatch_resize(&mut self, info: &mut CallbackInfo) -> Update {
+        let state = self.state.clone();
+        match self.on_resize.as_mut() {
+            Some(SplitterOnResize { data, callback }) => (callback.cb)(data, info, &state),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn set_pane_size(&mut self, info: &mut CallbackInfo, pane: usize, node: DomNodeId, size: f32) {
+        if let Some(p) = self.state.panes.as_mut().get_mut(pane) {
+            p.size = size;
+        }
+        // flexible panes follow the size of the other pane
+        if self.limits.get(pane).map(|l| l.flexible).unwrap_or(true) {
+            return;
+        }
+        for prop in get_size_props(self.orientation, size) {
+            info.set_css_property(node, prop);
+        }
+    }
+
+    fn toggle_collapsed(&mut self, info: &mut CallbackInfo, handle_node: DomNodeId, handle: usize) -> Option<Update> {
+
+        let pane = get_collapse_target(&self.limits, self.state.panes.as_ref(), handle)?;
+        let pane_node = if pane == handle {
+            info.get_previous_sibling(handle_node)?
+        } else {
+            info.get_next_sibling(handle_node)?
+        };
+
+        let pane_state = self.state.panes.as_mut().get_mut(pane)?;
+        pane_state.collapsed = !pane_state.collapsed;
+        let display = if pane_state.collapsed { LayoutDisplay::None } else { LayoutDisplay::Flex };
+        info.set_css_property(pane_node, CssProperty::const_display(display));
+
+        Some(self.dispatch_resize(info))
+    }
+}
+
+struct SplitterHandleLocalDataset {
+    /// Handle between pane `handle` and `handle + 1`
+    handle: usize,
+    /// `RefAny<SplitterLocalDataset>`, shared with the other handles
+    splitter: RefAny,
+}
+
+extern "C" fn on_splitter_handle_mouse_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_splitter_handle_mouse_down_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut handle_dataset = data.downcast_mut::<SplitterHandleLocalDataset>()?;
+        let handle_dataset = &mut *handle_dataset;
+        let handle = handle_dataset.handle;
+        let mut splitter = handle_dataset.splitter.downcast_mut::<SplitterLocalDataset>()?;
+
+        let handle_node = info.get_hit_node();
+        let now = info.get_current_time();
+
+        let is_double_click = match splitter.last_click.as_ref() {
+            Some((last_handle, last_time)) => *last_handle == handle && is_double_click(last_time, &now),
+            None => false,
+        };
+
+        if is_double_click {
+            splitter.last_click = None;
+            splitter.drag = None;
+            return splitter.toggle_collapsed(info, handle_node, handle);
+        }
+
+        splitter.last_click = Some((handle, now));
+
+        // pane > handle > pane
+        let pane_nodes = (info.get_previous_sibling(handle_node)?, info.get_next_sibling(handle_node)?);
+
+        let collapsed = |i: usize| splitter.state.panes.as_ref().get(i).map(|p| p.collapsed).unwrap_or(false);
+        if collapsed(handle) || collapsed(handle + 1) {
+            return None;
+        }
+
+        let size_a = info.get_node_size(pane_nodes.0)?;
+        let size_b = info.get_node_size(pane_nodes.1)?;
+        let cursor = info.get_cursor_relative_to_viewport().into_option()?;
+        let start_sizes = (
+            splitter.get_main_axis(size_a.width, size_a.height),
+            splitter.get_main_axis(size_b.width, size_b.height),
+        );
+
+        splitter.drag = Some(SplitterDrag {
+            handle,
+            pane_nodes,
+            start_cursor: splitter.get_main_axis(cursor.x, cursor.y),
+            start_sizes,
+            current_sizes: start_sizes,
+        });
+
+        Some(Update::DoNothing)
+    }
+
+    on_splitter_handle_mouse_down_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_splitter_drag(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_splitter_drag_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut splitter = data.downcast_mut::<SplitterLocalDataset>()?;
+        let mut drag = splitter.drag?;
+
+        let cursor = info.get_cursor_relative_to_viewport().into_option()?;
+        let delta = splitter.get_main_axis(cursor.x, cursor.y) - drag.start_cursor;
+
+        let limits_a = splitter.limits.get(drag.handle)?;
+        let limits_b = splitter.limits.get(drag.handle + 1)?;
+        let sizes = get_drag_sizes(drag.start_sizes, (limits_a, limits_b), delta);
+
+        if sizes == drag.current_sizes {
+            return None;
+        }
+
+        drag.current_sizes = sizes;
+        splitter.drag = Some(drag);
+        splitter.set_pane_size(info, drag.handle, drag.pane_nodes.0, sizes.0);
+        splitter.set_pane_size(info, drag.handle + 1, drag.pane_nodes.1, sizes.1);
+
+        Some(Update::DoNothing)
+    }
+
+    on_splitter_drag_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_splitter_drag_end(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_splitter_drag_end_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut splitter = data.downcast_mut::<SplitterLocalDataset>()?;
+        let drag = splitter.drag.take()?;
+        if drag.current_sizes == drag.start_sizes {
+            return None;
+        }
+        Some(splitter.dispatch_resize(info))
+    }
+
+    on_splitter_drag_end_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+#[cfg(test)]
+mod splitter_test {
+
+    use super::*;
+
+    fn limits(min_size: f32, max_size: Option<f32>, collapsible: bool) -> SplitterPaneLimits {
+        SplitterPaneLimits { min_size, max_size, flexible: false, collapsible }
+    }
+
+    fn pane_state(collapsed: bool) -> SplitterPaneState {
+        SplitterPaneState { size: 100.0, collapsed }
+    }
+
+    #[test]
+    fn drag_moves_space_between_panes() {
+        let a = limits(0.0, None, false);
+        let b = limits(0.0, None, false);
+        assert_eq!(get_drag_sizes((100.0, 200.0), (&a, &b), 50.0), (150.0, 150.0));
+        assert_eq!(get_drag_sizes((100.0, 200.0), (&a, &b), -30.0), (70.0, 230.0));
+    }
+
+    #[test]
+    fn drag_respects_min_and_max_sizes() {
+        let a = limits(50.0, Some(180.0), false);
+        let b = limits(40.0, Some(220.0), false);
+        // a can't shrink below 50
+        assert_eq!(get_drag_sizes((100.0, 200.0), (&a, &b), -100.0), (80.0, 220.0));
+        // b can't grow above 220
+        assert_eq!(get_drag_sizes((100.0, 200.0), (&a, &b), -40.0), (80.0, 220.0));
+        // a can't grow above 180
+        assert_eq!(get_drag_sizes((100.0, 200.0), (&a, &b), 100.0), (180.0, 120.0));
+        // b can't shrink below 40
+        let a = limits(0.0, None, false);
+        assert_eq!(get_drag_sizes((100.0, 200.0), (&a, &b), 300.0), (260.0, 40.0));
+    }
+
+    #[test]
+    fn drag_is_ignored_if_limits_conflict() {
+        let a = limits(150.0, None, false);
+        let b = limits(250.0, None, false);
+        assert_eq!(get_drag_sizes((100.0, 200.0), (&a, &b), 20.0), (100.0, 200.0));
+    }
+
+    #[test]
+    fn double_click_collapses_first_collapsible_pane() {
+        let l = [limits(0.0, None, false), limits(0.0, None, true), limits(0.0, None, true)];
+        let s = [pane_state(false), pane_state(false), pane_state(false)];
+        assert_eq!(get_collapse_target(&l, &s, 0), Some(1));
+        assert_eq!(get_collapse_target(&l, &s, 1), Some(1));
+
+        let l = [limits(0.0, None, false), limits(0.0, None, false)];
+        assert_eq!(get_collapse_target(&l, &s, 0), None);
+    }
+
+    #[test]
+    fn double_click_expands_collapsed_pane() {
+        let l = [limits(0.0, None, true), limits(0.0, None, true)];
+        let s = [pane_state(false), pane_state(true)];
+        assert_eq!(get_collapse_target(&l, &s, 0), Some(1));
+    }
+
+    #[test]
+    fn pane_size_is_clamped() {
+        let l = limits(50.0, Some(200.0), false);
+        assert_eq!(l.clamp(10.0), 50.0);
+        assert_eq!(l.clamp(100.0), 100.0);
+        assert_eq!(l.clamp(300.0), 200.0);
+    }
+}
@@ -4,7 +4,7 @@ use azul_desktop::css::*;
 use azul_desktop::css::AzString;
 use azul_desktop::dom::{
     Dom, IdOrClass, TabIndex,
-    HoverEventFilter, EventFilter,
+    HoverEventFilter, WindowEventFilter, EventFilter,
     CallbackData,
     IdOrClass::{Id, Class},
     NodeDataInlineCssProperty,
@@ -13,6 +13,8 @@ use azul_desktop::dom::{
 use azul_desktop::callbacks::{
     Update, RefAny, CallbackInfo, Callback,
 };
+use azul_core::callbacks::DomNodeId;
+use azul_core::window::LogicalPosition;
 
 const STRING_16146701490593874959: AzString = AzString::from_const_str("sans-serif");
 const STYLE_BACKGROUND_CONTENT_8560341490937422656_ITEMS: &[StyleBackgroundContent] = &[
@@ -288,12 +290,85 @@ const CSS_MATCH_11510695043643111367_PROPERTIES: &[NodeDataInlineCssProperty] =
 const CSS_MATCH_11510695043643111367: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11510695043643111367_PROPERTIES);
 
 
+// -- TAB STRIP, CLOSE BUTTON, DROP INDICATOR, OVERFLOW CONTROLS
+
+const COLOR_ACACAC: ColorU = ColorU { r: 172, g: 172, b: 172, a: 255 }; // #acacac
+const COLOR_606060: ColorU = ColorU { r: 96, g: 96, b: 96, a: 255 }; // #606060
+const COLOR_569DE5: ColorU = ColorU { r: 86, g: 157, b: 229, a: 255 }; // #569de5
+
+const DROP_INDICATOR_BACKGROUND_ITEMS: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_569DE5)];
+const DROP_INDICATOR_BACKGROUND: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(DROP_INDICATOR_BACKGROUND_ITEMS);
+
+/// How far the cursor has to move before pressing on a tab starts dragging it
+const TAB_DRAG_THRESHOLD: f32 = 4.0;
+/// How far one click on the scroll arrows scrolls the tab strip
+const TAB_SCROLL_STEP: f32 = 60.0;
+
+static TAB_STRIP_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+];
+
+// appended to the regular tab style: tabs contain the label and the close button
+static TAB_EXTRA_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+];
+
+static TAB_CLOSE_BUTTON_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(14))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(14))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_margin_left(LayoutMarginLeft::const_px(4))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_606060 })),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_15534185073326444643_ITEMS))),
+];
+
+static TAB_DROP_INDICATOR_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(2))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(24))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_background_content(DROP_INDICATOR_BACKGROUND)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_opacity(StyleOpacity::const_new(0))),
+];
+
+static TAB_OVERFLOW_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_ACACAC })),
+];
+
+static TAB_OVERFLOW_BUTTON_PROPS: &[NodeDataInlineCssProperty] = &[
+    NodeDataInlineCssProperty::Normal(CssProperty::const_width(LayoutWidth::const_px(16))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_height(LayoutHeight::const_px(21))),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_justify_content(LayoutJustifyContent::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_align_items(LayoutAlignItems::Center)),
+    NodeDataInlineCssProperty::Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_606060 })),
+    NodeDataInlineCssProperty::Hover(CssProperty::const_background_content(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_15534185073326444643_ITEMS))),
+];
+
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct TabHeader {
     pub tabs: StringVec,
     pub active_tab: usize,
     pub on_click: OptionTabOnClick,
+    /// If set, every tab gets a close button and closes on a middle-click
+    pub on_close: OptionTabOnClose,
+    /// If set, tabs can be dragged to a new position
+    pub on_reorder: OptionTabOnReorder,
+    /// Invoked when a tab is right-clicked
+    pub on_context_menu: OptionTabOnContextMenu,
 }
 
 impl Default for TabHeader {
@@ -302,6 +377,9 @@ impl Default for TabHeader {
             tabs: StringVec::from_const_slice(&[]),
             active_tab: 0,
             on_click: None.into(),
+            on_close: None.into(),
+            on_reorder: None.into(),
+            on_context_menu: None.into(),
         }
     }
 }
@@ -314,13 +392,24 @@ pub struct TabHeaderState {
 pub type TabOnClickCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &TabHeaderState) -> Update;
 impl_callback!(TabOnClick, OptionTabOnClick, TabOnClickCallback, TabOnClickCallbackType);
 
+/// Invoked with the index of the tab that should be closed
+pub type TabOnCloseCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, usize) -> Update;
+impl_callback!(TabOnClose, OptionTabOnClose, TabOnCloseCallback, TabOnCloseCallbackType);
+
+/// Invoked with the old and the new index of a tab that was dragged to a new position
+pub type TabOnReorderCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, usize, usize) -> Update;
+impl_callback!(TabOnReorder, OptionTabOnReorder, TabOnReorderCallback, TabOnReorderCallbackType);
+
+/// Invoked with the index of the tab that was right-clicked
+pub type TabOnContextMenuCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, usize) -> Update;
+impl_callback!(TabOnContextMenu, OptionTabOnContextMenu, TabOnContextMenuCallback, TabOnContextMenuCallbackType);
+
 impl TabHeader {
 
     pub fn new(tabs: StringVec) -> Self {
         Self {
             tabs,
-            active_tab: 0,
-            on_click: None.into(),
+            .. Default::default()
         }
     }
 
@@ -350,120 +439,313 @@ impl TabHeader {
         s
     }
 
-    pub fn dom(&mut self) -> Dom {
+    pub fn set_on_close(&mut self, data: RefAny, on_close: TabOnCloseCallbackType) {
+        self.on_close = Some(TabOnClose { data, callback: TabOnCloseCallback { cb: on_close }}).into();
+    }
+
+    pub fn with_on_close(&mut self, data: RefAny, on_close: TabOnCloseCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_close(data, on_close);
+        s
+    }
 
-        use azul_core::dom::CallbackDataVec;
+    pub fn set_on_reorder(&mut self, data: RefAny, on_reorder: TabOnReorderCallbackType) {
+        self.on_reorder = Some(TabOnReorder { data, callback: TabOnReorderCallback { cb: on_reorder }}).into();
+    }
 
-        let on_click_is_some = self.on_click.is_some();
+    pub fn with_on_reorder(&mut self, data: RefAny, on_reorder: TabOnReorderCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_reorder(data, on_reorder);
+        s
+    }
 
-        Dom::div()
-        .with_inline_css_props(CSS_MATCH_9988039989460234263)
-        .with_ids_and_classes({
-            const IDS_AND_CLASSES_6172459441955124689: &[IdOrClass] = &[
-                Class(AzString::from_const_str("__azul-native-tabs-header")),
-            ];
-            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6172459441955124689)
-        })
-        .with_children({
+    pub fn set_on_context_menu(&mut self, data: RefAny, on_context_menu: TabOnContextMenuCallbackType) {
+        self.on_context_menu = Some(TabOnContextMenu { data, callback: TabOnContextMenuCallback { cb: on_context_menu }}).into();
+    }
 
-            let mut tab_items = vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_17290739305197504468)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_8360971686689797550: &[IdOrClass] = &[
-                        Class(AzString::from_const_str("__azul-native-tabs-before-tabs")),
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_8360971686689797550)
-                }),
-            ];
+    pub fn with_on_context_menu(&mut self, data: RefAny, on_context_menu: TabOnContextMenuCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_context_menu(data, on_context_menu);
+        s
+    }
+
+    pub fn dom(&mut self) -> Dom {
+
+        use azul_core::dom::{
+            CallbackDataVec, AccessibilityInfo, AccessibilityRole,
+            AccessibilityState, AccessibilityStateVec,
+            AccessibilityAction, AccessibilityActionVec,
+        };
+        use azul_core::window::{
+            Menu, MenuItem, MenuItemVec, StringMenuItem,
+            MenuPopupPosition, ContextMenuMouseButton,
+        };
+
+        let s = self.swap_with_default();
+
+        let on_click_is_some = s.on_click.is_some();
+        let on_close_is_some = s.on_close.is_some();
+        let on_reorder_is_some = s.on_reorder.is_some();
+        let on_context_menu_is_some = s.on_context_menu.is_some();
+
+        let header_dataset = RefAny::new(TabHeaderLocalDataset {
+            tab_count: s.tabs.len(),
+            on_click: s.on_click,
+            on_close: s.on_close,
+            on_reorder: s.on_reorder,
+            on_context_menu: s.on_context_menu,
+            drag: None,
+            suppress_click: false,
+            scroll_offset: 0.0,
+        });
+
+        let mut tab_items = vec![
+            Dom::div()
+            .with_inline_css_props(CSS_MATCH_17290739305197504468)
+            .with_ids_and_classes({
+                const IDS_AND_CLASSES_8360971686689797550: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("__azul-native-tabs-before-tabs")),
+                ];
+                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_8360971686689797550)
+            }),
+        ];
+
+        let mut all_tabs_menu = Vec::new();
 
-            let s = self.swap_with_default();
+        for (tab_idx, tab) in s.tabs.as_ref().iter().enumerate() {
 
-            let dataset = TabLocalDataset {
-                tab_idx: 0,
-                on_click: s.on_click,
+            let next_tab_is_active = s.active_tab == tab_idx.saturating_add(1);
+            let previous_tab_was_active = if s.active_tab == 0 {
+                false
+            } else {
+                s.active_tab == tab_idx.saturating_sub(1)
             };
 
-            for (tab_idx, tab) in s.tabs.as_ref().iter().enumerate() {
+            let tab_is_active = s.active_tab == tab_idx;
 
-                let next_tab_is_active = s.active_tab == tab_idx.saturating_add(1);
-                let previous_tab_was_active = if s.active_tab == 0 {
-                    false
-                } else {
-                    s.active_tab == tab_idx.saturating_sub(1)
-                };
+            // classes for previous tab
+            const IDS_AND_CLASSES_5117007530891373979: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-tabs-tab-norightborder")),
+                Class(AzString::from_const_str("__azul-native-tabs-tab-not-active")),
+            ]; // CSS_MATCH_4415083954137121609
 
-                let tab_is_active = s.active_tab == tab_idx;
+            // classes for current tab
+            const IDS_AND_CLASSES_15002865554973741556: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-tabs-tab-active")),
+            ];
 
-                // classes for previous tab
-                const IDS_AND_CLASSES_5117007530891373979: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-tabs-tab-norightborder")),
-                    Class(AzString::from_const_str("__azul-native-tabs-tab-not-active")),
-                ]; // CSS_MATCH_4415083954137121609
+            // classes for next tab
+            const IDS_AND_CLASSES_16877793354714897051: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-tabs-tab-noleftborder")),
+                Class(AzString::from_const_str("__azul-native-tabs-tab-not-active")),
+            ];
 
-                // classes for current tab
-                const IDS_AND_CLASSES_15002865554973741556: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-tabs-tab-active")),
-                ];
+            // classes for default inactive tab
+            const IDS_AND_CLASSES_INACTIVE: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-tabs-tab-not-active")),
+            ];
 
-                // classes for next tab
-                const IDS_AND_CLASSES_16877793354714897051: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-tabs-tab-noleftborder")),
-                    Class(AzString::from_const_str("__azul-native-tabs-tab-not-active")),
-                ];
+            let (ids_and_classes, css_props) = if tab_is_active {
+                (IDS_AND_CLASSES_15002865554973741556, CSS_MATCH_14575853790110873394)
+            } else if next_tab_is_active {
+                // tab before the active tab
+                (IDS_AND_CLASSES_5117007530891373979, CSS_MATCH_4415083954137121609)
+            } else if previous_tab_was_active {
+                // tab after the active tab
+                (IDS_AND_CLASSES_16877793354714897051, CSS_MATCH_13824480602841492081)
+            } else {
+                // default inactive tab
+                (IDS_AND_CLASSES_INACTIVE, CSS_MATCH_11510695043643111367)
+            };
 
-                // classes for default inactive tab
-                const IDS_AND_CLASSES_INACTIVE: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-tabs-tab-not-active")),
-                ];
+            let dataset = RefAny::new(TabLocalDataset {
+                tab_idx,
+                header: header_dataset.clone(),
+            });
 
-                let (ids_and_classes, css_props) = if tab_is_active {
-                    (IDS_AND_CLASSES_15002865554973741556, CSS_MATCH_14575853790110873394)
-                } else if next_tab_is_active {
-                    // tab before the active tab
-                    (IDS_AND_CLASSES_5117007530891373979, CSS_MATCH_4415083954137121609)
-                } else if previous_tab_was_active {
-                    // tab after the active tab
-                    (IDS_AND_CLASSES_16877793354714897051, CSS_MATCH_13824480602841492081)
-                } else {
-                    // default inactive tab
-                    (IDS_AND_CLASSES_INACTIVE, CSS_MATCH_11510695043643111367)
-                };
-
-                let mut dataset = dataset.clone();
-                dataset.tab_idx = tab_idx;
-                let dataset = RefAny::new(dataset);
-
-                tab_items.push(
-                    Dom::text(tab.clone())
-                    .with_callbacks(if on_click_is_some {
-                        vec![
-                            CallbackData {
-                                event: EventFilter::Hover(HoverEventFilter::MouseUp),
-                                callback: Callback { cb: on_tab_click },
-                                data: dataset.clone(),
-                            }
-                        ].into()
-                    } else {
-                        CallbackDataVec::from_const_slice(&[])
+            let tab_states: &'static [AccessibilityState] = if tab_is_active {
+                &[AccessibilityState::Selectable, AccessibilityState::Selected]
+            } else {
+                &[AccessibilityState::Selectable]
+            };
+
+            let tab_actions: &'static [AccessibilityAction] = if on_click_is_some {
+                &[AccessibilityAction::Select]
+            } else {
+                &[]
+            };
+
+            let mut callbacks = Vec::new();
+            if on_click_is_some || on_close_is_some {
+                callbacks.push(CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                    callback: Callback { cb: on_tab_click },
+                    data: dataset.clone(),
+                });
+            }
+            if on_close_is_some {
+                callbacks.push(CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::MiddleMouseUp),
+                    callback: Callback { cb: on_tab_middle_click },
+                    data: dataset.clone(),
+                });
+            }
+            if on_reorder_is_some {
+                callbacks.push(CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                    callback: Callback { cb: on_tab_drag_start },
+                    data: dataset.clone(),
+                });
+            }
+            if on_context_menu_is_some {
+                callbacks.push(CallbackData {
+                    event: EventFilter::Hover(HoverEventFilter::RightMouseUp),
+                    callback: Callback { cb: on_tab_right_click },
+                    data: dataset.clone(),
+                });
+            }
+
+            let mut tab_children = vec![Dom::text(tab.clone())];
+            if on_close_is_some {
+                // clicks are handled by on_tab_click, which checks if the cursor is over the close button
+                tab_children.push(
+                    Dom::text(AzString::from_const_str("\u{00d7}"))
+                    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TAB_CLOSE_BUTTON_PROPS))
+                    .with_ids_and_classes({
+                        const IDS_AND_CLASSES_TAB_CLOSE: &[IdOrClass] = &[
+                            Class(AzString::from_const_str("__azul-native-tabs-close-button")),
+                        ];
+                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TAB_CLOSE)
                     })
-                    .with_dataset(Some(dataset).into())
-                    .with_inline_css_props(css_props)
-                    .with_ids_and_classes(IdOrClassVec::from_const_slice(ids_and_classes))
                 );
             }
 
-            tab_items.push(Dom::div()
-            .with_inline_css_props(CSS_MATCH_3088386549906605418)
+            all_tabs_menu.push(MenuItem::String(
+                StringMenuItem::new(tab.clone()).with_callback(dataset.clone(), on_all_tabs_menu_click)
+            ));
+
+            tab_items.push(
+                Dom::div()
+                .with_callbacks(if callbacks.is_empty() {
+                    CallbackDataVec::from_const_slice(&[])
+                } else {
+                    callbacks.into()
+                })
+                .with_dataset(Some(dataset).into())
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_vec(
+                    css_props.as_ref().iter().chain(TAB_EXTRA_PROPS.iter()).cloned().collect()
+                ))
+                .with_ids_and_classes(IdOrClassVec::from_const_slice(ids_and_classes))
+                .with_accessibility_info(
+                    AccessibilityInfo::new(AccessibilityRole::Pagetab)
+                    .with_states(AccessibilityStateVec::from_const_slice(tab_states))
+                )
+                .with_accessibility_actions(AccessibilityActionVec::from_const_slice(tab_actions))
+                .with_children(tab_children.into())
+            );
+        }
+
+        tab_items.push(Dom::div()
+        .with_inline_css_props(CSS_MATCH_3088386549906605418)
+        .with_ids_and_classes({
+            const IDS_AND_CLASSES_11001585590816277275: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-tabs-after-tabs")),
+            ];
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11001585590816277275)
+        }));
+
+        tab_items.push(Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TAB_DROP_INDICATOR_PROPS))
+        .with_ids_and_classes({
+            const IDS_AND_CLASSES_TAB_DROP_INDICATOR: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-tabs-drop-indicator")),
+            ];
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TAB_DROP_INDICATOR)
+        }));
+
+        // scroll arrows + "all tabs" menu, in case the tabs exceed the width of the header
+        let mut all_tabs_menu = Menu::new(MenuItemVec::from_vec(all_tabs_menu));
+        all_tabs_menu.position = MenuPopupPosition::BottomOfHitRect;
+        all_tabs_menu.context_mouse_btn = ContextMenuMouseButton::Left;
+
+        let overflow_button = |label: &'static str| {
+            Dom::text(AzString::from_const_str(label))
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TAB_OVERFLOW_BUTTON_PROPS))
             .with_ids_and_classes({
-                const IDS_AND_CLASSES_11001585590816277275: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-tabs-after-tabs")),
+                const IDS_AND_CLASSES_TAB_OVERFLOW_BUTTON: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("__azul-native-tabs-overflow-button")),
                 ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11001585590816277275)
-            }));
+                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TAB_OVERFLOW_BUTTON)
+            })
+        };
 
-            tab_items.into()
+        let overflow_controls = Dom::div()
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TAB_OVERFLOW_PROPS))
+        .with_ids_and_classes({
+            const IDS_AND_CLASSES_TAB_OVERFLOW: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-tabs-overflow")),
+            ];
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TAB_OVERFLOW)
         })
+        .with_children(vec![
+            overflow_button("\u{25c2}")
+            .with_callbacks(vec![CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                callback: Callback { cb: on_tab_scroll_left },
+                data: header_dataset.clone(),
+            }].into()),
+            overflow_button("\u{25b8}")
+            .with_callbacks(vec![CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                callback: Callback { cb: on_tab_scroll_right },
+                data: header_dataset.clone(),
+            }].into()),
+            overflow_button("\u{25be}")
+            .with_context_menu(all_tabs_menu),
+        ].into());
+
+        Dom::div()
+        .with_inline_css_props(CSS_MATCH_9988039989460234263)
+        .with_ids_and_classes({
+            const IDS_AND_CLASSES_6172459441955124689: &[IdOrClass] = &[
+                Class(AzString::from_const_str("__azul-native-tabs-header")),
+            ];
+            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6172459441955124689)
+        })
+        .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::PageTabList))
+        .with_callbacks(if on_reorder_is_some {
+            vec![
+                CallbackData {
+                    event: EventFilter::Window(WindowEventFilter::MouseOver),
+                    callback: Callback { cb: on_tab_drag },
+                    data: header_dataset.clone(),
+                },
+                CallbackData {
+                    event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                    callback: Callback { cb: on_tab_drag_end },
+                    data: header_dataset.clone(),
+                },
+            ].into()
+        } else {
+            CallbackDataVec::from_const_slice(&[])
+        })
+        .with_children(vec![
+            Dom::div()
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TAB_STRIP_PROPS))
+            .with_ids_and_classes({
+                const IDS_AND_CLASSES_TAB_STRIP: &[IdOrClass] = &[
+                    Class(AzString::from_const_str("__azul-native-tabs-strip")),
+                ];
+                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TAB_STRIP)
+            })
+            .with_callbacks(vec![CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                callback: Callback { cb: on_tab_strip_scroll },
+                data: header_dataset,
+            }].into())
+            .with_children(tab_items.into()),
+            overflow_controls,
+        ].into())
     }
 }
 
@@ -507,6 +789,8 @@ impl TabContent {
 
     pub fn dom(&mut self) -> Dom {
 
+        use azul_core::dom::{AccessibilityInfo, AccessibilityRole};
+
         const IDS_AND_CLASSES_2989815829020816222: &[IdOrClass] = &[
             Class(AzString::from_const_str("__azul-native-tabs-content")),
         ];
@@ -519,6 +803,7 @@ impl TabContent {
 
         Dom::div()
         .with_inline_css_props(tab_content_css_style)
+        .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::PropertyPage))
         .with_children(DomVec::from_vec(vec![
             Dom::div()
             .with_ids_and_classes(IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2989815829020816222))
@@ -532,32 +817,364 @@ impl TabContent {
 #[derive(Clone)]
 struct TabLocalDataset {
     tab_idx: usize,
+    header: RefAny,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct TabDrag {
+    /// Index of the tab being dragged
+    from: usize,
+    /// Cursor position (relative to the viewport) when the mouse was pressed
+    start_x: f32,
+    /// Slot between two tabs where the tab would be dropped
+    slot: Option<usize>,
+}
+
+struct TabHeaderLocalDataset {
+    tab_count: usize,
     on_click: OptionTabOnClick,
+    on_close: OptionTabOnClose,
+    on_reorder: OptionTabOnReorder,
+    on_context_menu: OptionTabOnContextMenu,
+    drag: Option<TabDrag>,
+    /// Set after a drag, so that releasing the mouse doesn't activate the tab
+    suppress_click: bool,
+    scroll_offset: f32,
+}
+
+impl TabHeaderLocalDataset {
+
+    fn dispatch_click(&mut self, info: &mut CallbackInfo, tab_idx: usize) -> Update {
+        let tab_header_state = TabHeaderState { active_tab: tab_idx };
+        match self.on_click.as_mut() {
+            Some(TabOnClick { callback, data }) => (callback.cb)(data, info, &tab_header_state),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn dispatch_close(&mut self, info: &mut CallbackInfo, tab_idx: usize) -> Update {
+        match self.on_close.as_mut() {
+            Some(TabOnClose { callback, data }) => (callback.cb)(data, info, tab_idx),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn dispatch_reorder(&mut self, info: &mut CallbackInfo, from: usize, to: usize) -> Update {
+        match self.on_reorder.as_mut() {
+            Some(TabOnReorder { callback, data }) => (callback.cb)(data, info, from, to),
+            None => Update::DoNothing,
+        }
+    }
+
+    fn dispatch_context_menu(&mut self, info: &mut CallbackInfo, tab_idx: usize) -> Update {
+        match self.on_context_menu.as_mut() {
+            Some(TabOnContextMenu { callback, data }) => (callback.cb)(data, info, tab_idx),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+/// Returns the slot (0 = before the first tab, `centers.len()` = after
+/// the last tab) that a tab dragged to `cursor_x` would be dropped into
+fn get_drop_slot(cursor_x: f32, tab_centers: &[f32]) -> usize {
+    tab_centers.iter().filter(|center| **center < cursor_x).count()
+}
+
+/// Converts a drop slot into the index the dragged tab ends up at,
+/// returns `None` if the tab would be dropped at its old position
+fn get_reorder_target(from: usize, slot: usize) -> Option<usize> {
+    let to = if slot > from { slot - 1 } else { slot };
+    if to == from { None } else { Some(to) }
+}
+
+fn clamp_scroll_offset(scroll_offset: f32, content_width: f32, viewport_width: f32) -> f32 {
+    scroll_offset.min(content_width - viewport_width).max(0.0)
+}
+
+/// header > [strip > [before-tabs, tabs..., after-tabs, drop indicator], overflow controls]
+fn get_tab_nodes(info: &CallbackInfo, header: DomNodeId, tab_count: usize) -> Option<(DomNodeId, Vec<DomNodeId>)> {
+    let strip = info.get_first_child(header)?;
+    let mut tabs = Vec::with_capacity(tab_count);
+    let mut node = info.get_first_child(strip)?;
+    for _ in 0..tab_count {
+        node = info.get_next_sibling(node)?;
+        tabs.push(node);
+    }
+    Some((strip, tabs))
+}
+
+/// Scrolls the tab strip horizontally, returns the new scroll offset
+fn scroll_tab_strip(info: &mut CallbackInfo, header: DomNodeId, tab_count: usize, scroll_offset: f32) -> Option<f32> {
+    let (strip, tabs) = get_tab_nodes(info, header, tab_count)?;
+    let strip_x = info.get_node_position(strip)?.get_static_offset().x;
+    let viewport_width = info.get_node_size(strip)?.width;
+    let content_width = match tabs.last() {
+        Some(last) => info.get_node_position(*last)?.get_static_offset().x + info.get_node_size(*last)?.width - strip_x,
+        None => 0.0,
+    };
+    let scroll_offset = clamp_scroll_offset(scroll_offset, content_width, viewport_width);
+    info.set_scroll_position(strip, LogicalPosition::new(scroll_offset, 0.0));
+    Some(scroll_offset)
 }
 
 extern "C" fn on_tab_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
 
-    fn select_new_tab_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<()> {
+    fn on_tab_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
 
-        let mut tab_local_dataset = data.downcast_mut::<TabLocalDataset>()?;
-        let tab_idx = tab_local_dataset.tab_idx;
-        let tab_header_state = TabHeaderState { active_tab: tab_idx };
+        let mut tab_dataset = data.downcast_mut::<TabLocalDataset>()?;
+        let tab_dataset = &mut *tab_dataset;
+        let tab_idx = tab_dataset.tab_idx;
+        let mut header = tab_dataset.header.downcast_mut::<TabHeaderLocalDataset>()?;
 
-        let result = {
-            // rustc doesn't understand the borrowing lifetime here
-            let tab_local_dataset = &mut *tab_local_dataset;
-            let onclick = &mut tab_local_dataset.on_click;
+        // releasing the mouse after dragging a tab is not a click
+        let was_dragging = header.drag.map(|d| d.slot.is_some()).unwrap_or(false) || header.suppress_click;
+        header.suppress_click = false;
+        if was_dragging {
+            return None;
+        }
 
-            match onclick.as_mut() {
-                Some(TabOnClick { callback, data }) => (callback.cb)(data, info, &tab_header_state),
-                None => Update::DoNothing,
+        // tab > [label, close button]
+        if header.on_close.is_some() {
+            let tab_node = info.get_hit_node();
+            let close_button = info.get_last_child(tab_node)?;
+            let tab_origin = info.get_node_position(tab_node)?.get_static_offset();
+            let close_origin = info.get_node_position(close_button)?.get_static_offset();
+            let close_size = info.get_node_size(close_button)?;
+            let cursor = info.get_cursor_relative_to_node().into_option()?;
+            let x = close_origin.x - tab_origin.x;
+            let y = close_origin.y - tab_origin.y;
+            if cursor.x >= x && cursor.x <= x + close_size.width &&
+               cursor.y >= y && cursor.y <= y + close_size.height {
+                return Some(header.dispatch_close(info, tab_idx));
             }
+        }
+
+        header.dispatch_click(info, tab_idx);
+
+        Some(Update::RefreshDom)
+    }
+
+    on_tab_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tab_middle_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tab_middle_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut tab_dataset = data.downcast_mut::<TabLocalDataset>()?;
+        let tab_dataset = &mut *tab_dataset;
+        let tab_idx = tab_dataset.tab_idx;
+        let mut header = tab_dataset.header.downcast_mut::<TabHeaderLocalDataset>()?;
+        Some(header.dispatch_close(info, tab_idx))
+    }
+
+    on_tab_middle_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tab_right_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tab_right_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut tab_dataset = data.downcast_mut::<TabLocalDataset>()?;
+        let tab_dataset = &mut *tab_dataset;
+        let tab_idx = tab_dataset.tab_idx;
+        let mut header = tab_dataset.header.downcast_mut::<TabHeaderLocalDataset>()?;
+        Some(header.dispatch_context_menu(info, tab_idx))
+    }
+
+    on_tab_right_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_all_tabs_menu_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_all_tabs_menu_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut tab_dataset = data.downcast_mut::<TabLocalDataset>()?;
+        let tab_dataset = &mut *tab_dataset;
+        let tab_idx = tab_dataset.tab_idx;
+        let mut header = tab_dataset.header.downcast_mut::<TabHeaderLocalDataset>()?;
+        header.dispatch_click(info, tab_idx);
+        Some(Update::RefreshDom)
+    }
+
+    on_all_tabs_menu_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tab_drag_start(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tab_drag_start_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut tab_dataset = data.downcast_mut::<TabLocalDataset>()?;
+        let tab_dataset = &mut *tab_dataset;
+        let tab_idx = tab_dataset.tab_idx;
+        let mut header = tab_dataset.header.downcast_mut::<TabHeaderLocalDataset>()?;
+
+        header.suppress_click = false;
+        header.drag = Some(TabDrag {
+            from: tab_idx,
+            start_x: info.get_cursor_relative_to_viewport().into_option()?.x,
+            slot: None,
+        });
+
+        Some(Update::DoNothing)
+    }
+
+    on_tab_drag_start_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tab_drag(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tab_drag_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut header = data.downcast_mut::<TabHeaderLocalDataset>()?;
+        let mut drag = header.drag?;
+
+        let cursor_x = info.get_cursor_relative_to_viewport().into_option()?.x;
+        if drag.slot.is_none() && (cursor_x - drag.start_x).abs() < TAB_DRAG_THRESHOLD {
+            return None;
+        }
+
+        let (strip, tabs) = get_tab_nodes(info, info.get_hit_node(), header.tab_count)?;
+        let strip_x = info.get_node_position(strip)?.get_static_offset().x;
+
+        let mut tab_edges = Vec::with_capacity(tabs.len() + 1);
+        let mut tab_centers = Vec::with_capacity(tabs.len());
+        for tab in tabs.iter() {
+            let x = info.get_node_position(*tab)?.get_static_offset().x - header.scroll_offset;
+            let width = info.get_node_size(*tab)?.width;
+            tab_edges.push(x);
+            tab_centers.push(x + width / 2.0);
+            if tab_edges.len() == tabs.len() {
+                tab_edges.push(x + width);
+            }
+        }
+
+        let slot = get_drop_slot(cursor_x, &tab_centers);
+        drag.slot = Some(slot);
+        header.drag = Some(drag);
+
+        // the indicator is inside the scrolled strip, so it is positioned
+        // relative to the unscrolled content
+        let indicator = info.get_last_child(strip)?;
+        let indicator_x = tab_edges.get(slot).copied().unwrap_or(0.0) + header.scroll_offset - strip_x - 1.0;
+        info.set_css_property(indicator, CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::px(indicator_x) })));
+        info.set_css_property(indicator, CssProperty::const_opacity(StyleOpacity::const_new(100)));
+
+        Some(Update::DoNothing)
+    }
+
+    on_tab_drag_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tab_drag_end(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tab_drag_end_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut header = data.downcast_mut::<TabHeaderLocalDataset>()?;
+        let drag = header.drag.take()?;
+        let slot = drag.slot?;
+
+        header.suppress_click = true;
+
+        let strip = info.get_first_child(info.get_hit_node())?;
+        let indicator = info.get_last_child(strip)?;
+        info.set_css_property(indicator, CssProperty::const_opacity(StyleOpacity::const_new(0)));
+
+        let to = get_reorder_target(drag.from, slot)?;
+        Some(header.dispatch_reorder(info, drag.from, to))
+    }
+
+    on_tab_drag_end_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tab_strip_scroll(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tab_strip_scroll_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut header = data.downcast_mut::<TabHeaderLocalDataset>()?;
+
+        // vertical mouse wheels scroll the tabs horizontally
+        let mouse_state = info.get_current_mouse_state();
+        let delta = if mouse_state.get_scroll_x() != 0.0 {
+            mouse_state.get_scroll_x()
+        } else {
+            mouse_state.get_scroll_y()
         };
+        if delta == 0.0 {
+            return None;
+        }
+
+        let header_node = info.get_parent(info.get_hit_node())?;
+        header.scroll_offset = scroll_tab_strip(info, header_node, header.tab_count, header.scroll_offset + delta)?;
+
+        Some(Update::DoNothing)
+    }
+
+    on_tab_strip_scroll_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+fn on_tab_scroll_button_inner(data: &mut RefAny, info: &mut CallbackInfo, delta: f32) -> Option<Update> {
+
+    let mut header = data.downcast_mut::<TabHeaderLocalDataset>()?;
+
+    // header > overflow controls > button
+    let header_node = info.get_parent(info.get_parent(info.get_hit_node())?)?;
+    header.scroll_offset = scroll_tab_strip(info, header_node, header.tab_count, header.scroll_offset + delta)?;
 
-        Some(())
+    Some(Update::DoNothing)
+}
+
+extern "C" fn on_tab_scroll_left(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    on_tab_scroll_button_inner(data, info, -TAB_SCROLL_STEP).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tab_scroll_right(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+    on_tab_scroll_button_inner(data, info, TAB_SCROLL_STEP).unwrap_or(Update::DoNothing)
+}
+
+#[cfg(test)]
+mod tabs_test {
+
+    use super::*;
+
+    #[test]
+    fn drop_slot_counts_tab_centers_left_of_cursor() {
+        let centers = [50.0, 150.0, 250.0];
+        assert_eq!(get_drop_slot(0.0, &centers), 0);
+        assert_eq!(get_drop_slot(100.0, &centers), 1);
+        assert_eq!(get_drop_slot(200.0, &centers), 2);
+        assert_eq!(get_drop_slot(300.0, &centers), 3);
+        assert_eq!(get_drop_slot(100.0, &[]), 0);
+    }
+
+    #[test]
+    fn reorder_target_accounts_for_removed_tab() {
+        // dropping a tab next to itself does nothing
+        assert_eq!(get_reorder_target(1, 1), None);
+        assert_eq!(get_reorder_target(1, 2), None);
+        // moving left
+        assert_eq!(get_reorder_target(2, 0), Some(0));
+        // moving right: the slot index includes the dragged tab
+        assert_eq!(get_reorder_target(0, 3), Some(2));
+        assert_eq!(get_reorder_target(0, 2), Some(1));
     }
 
-    let _ = select_new_tab_inner(data, info);
+    #[test]
+    fn scroll_offset_is_clamped_to_content() {
+        assert_eq!(clamp_scroll_offset(-10.0, 500.0, 200.0), 0.0);
+        assert_eq!(clamp_scroll_offset(100.0, 500.0, 200.0), 100.0);
+        assert_eq!(clamp_scroll_offset(400.0, 500.0, 200.0), 300.0);
+        // content fits into the header: no scrolling
+        assert_eq!(clamp_scroll_offset(50.0, 100.0, 200.0), 0.0);
+    }
 
-    Update::RefreshDom
-}
\ No newline at end of file
+    extern "C" fn noop_close(_: &mut RefAny, _: &mut CallbackInfo, _: usize) -> Update { Update::DoNothing }
+    extern "C" fn noop_reorder(_: &mut RefAny, _: &mut CallbackInfo, _: usize, _: usize) -> Update { Update::DoNothing }
+
+    #[test]
+    fn tab_header_builders_set_callbacks() {
+        let header = TabHeader::new(StringVec::from_const_slice(&[]))
+            .with_on_close(RefAny::new(()), noop_close)
+            .with_on_reorder(RefAny::new(()), noop_reorder);
+        assert!(header.on_close.is_some());
+        assert!(header.on_reorder.is_some());
+        assert!(header.on_context_menu.is_none());
+    }
+}
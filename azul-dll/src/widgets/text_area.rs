@@ -0,0 +1,1449 @@
+//! Multi-line text input with soft wrapping, scrolling and an undo history
+
+use azul_desktop::{
+    css::*,
+    css::AzString,
+    gl::OptionUsize,
+    dom::{
+        Dom, TabIndex,
+        IdOrClass, IdOrClass::Class,
+        IdOrClassVec, NodeDataInlineCssPropertyVec,
+        NodeDataInlineCssProperty,
+        NodeDataInlineCssProperty::{Normal, Hover, Focus},
+    },
+    callbacks::{RefAny, Update, CallbackInfo, Callback},
+};
+use azul_core::{
+    callbacks::{InlineText, DomNodeId, FocusTarget},
+    task::{Instant, OptionInstant, Duration, SystemTimeDiff},
+    window::{LogicalPosition, LogicalSize, LogicalRect, VirtualKeyCode, ClipboardContent, ClipboardSelection},
+};
+use azul_impl::text_layout::callback_info_shape_text;
+use alloc::vec::Vec;
+use alloc::string::String;
+
+const BACKGROUND_COLOR: ColorU = ColorU { r: 255, g: 255, b: 255, a: 255 }; // white
+const BLACK: ColorU = ColorU { r: 0, g: 0, b: 0, a: 255 };
+const COLOR_9B9B9B: ColorU = ColorU { r: 155, g: 155, b: 155, a: 255 }; // #9b9b9b
+const COLOR_4286F4: ColorU = ColorU { r: 66, g: 134, b: 244, a: 255 }; // #4286f4
+const COLOR_4C4C4C: ColorU = ColorU { r: 76, g: 76, b: 76, a: 255 }; // #4C4C4C
+const COLOR_B3D7FF: ColorU = ColorU { r: 179, g: 215, b: 255, a: 255 }; // #b3d7ff
+
+const BACKGROUND_THEME_LIGHT: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BACKGROUND_COLOR)];
+const BACKGROUND_COLOR_LIGHT: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(BACKGROUND_THEME_LIGHT);
+
+const CURSOR_COLOR_BLACK: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(BLACK)];
+const CURSOR_COLOR: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(CURSOR_COLOR_BLACK);
+
+const SELECTION_COLOR_BLUE: &[StyleBackgroundContent] = &[StyleBackgroundContent::Color(COLOR_B3D7FF)];
+const SELECTION_COLOR: StyleBackgroundContentVec = StyleBackgroundContentVec::from_const_slice(SELECTION_COLOR_BLUE);
+
+const SANS_SERIF_STR: &str = "sans-serif";
+const SANS_SERIF: AzString = AzString::from_const_str(SANS_SERIF_STR);
+const SANS_SERIF_FAMILIES: &[StyleFontFamily] = &[StyleFontFamily::System(SANS_SERIF)];
+const SANS_SERIF_FAMILY: StyleFontFamilyVec = StyleFontFamilyVec::from_const_slice(SANS_SERIF_FAMILIES);
+
+// -- container style
+
+static TEXT_AREA_CONTAINER_PROPS: &[NodeDataInlineCssProperty] = &[
+
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_cursor(StyleCursor::Text)),
+    Normal(CssProperty::const_box_sizing(LayoutBoxSizing::BorderBox)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(1))),
+    Normal(CssProperty::const_min_height(LayoutMinHeight::const_px(60))),
+    Normal(CssProperty::const_background_content(BACKGROUND_COLOR_LIGHT)),
+
+    Normal(CssProperty::const_padding_left(LayoutPaddingLeft::const_px(2))),
+    Normal(CssProperty::const_padding_right(LayoutPaddingRight::const_px(2))),
+    Normal(CssProperty::const_padding_top(LayoutPaddingTop::const_px(2))),
+    Normal(CssProperty::const_padding_bottom(LayoutPaddingBottom::const_px(2))),
+
+    // border: 1px inset #9b9b9b;
+
+    Normal(CssProperty::const_border_top_width(LayoutBorderTopWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_left_width(LayoutBorderLeftWidth::const_px(1))),
+    Normal(CssProperty::const_border_right_width(LayoutBorderRightWidth::const_px(1))),
+
+    Normal(CssProperty::const_border_top_style(StyleBorderTopStyle { inner: BorderStyle::Inset })),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Inset })),
+    Normal(CssProperty::const_border_left_style(StyleBorderLeftStyle { inner: BorderStyle::Inset })),
+    Normal(CssProperty::const_border_right_style(StyleBorderRightStyle { inner: BorderStyle::Inset })),
+
+    Normal(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_9B9B9B })),
+    Normal(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_9B9B9B })),
+
+    Normal(CssProperty::const_overflow_x(LayoutOverflow::Hidden)),
+    Normal(CssProperty::const_overflow_y(LayoutOverflow::Hidden)),
+
+    // Hover(border-color: #4c4c4c;)
+
+    Hover(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4C4C4C })),
+    Hover(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4C4C4C })),
+    Hover(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4C4C4C })),
+    Hover(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4C4C4C })),
+
+    // Focus(border-color: #4286f4;)
+
+    Focus(CssProperty::const_border_top_color(StyleBorderTopColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_left_color(StyleBorderLeftColor { inner: COLOR_4286F4 })),
+    Focus(CssProperty::const_border_right_color(StyleBorderRightColor { inner: COLOR_4286F4 })),
+];
+
+// -- content (text + selection + cursor), scrolled inside of the container
+
+static TEXT_AREA_CONTENT_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Relative)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(0))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+];
+
+static TEXT_AREA_TEXT_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+static TEXT_AREA_SELECTION_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(0))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(0))),
+    Normal(CssProperty::const_background_content(SELECTION_COLOR)),
+];
+
+static TEXT_AREA_CURSOR_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_width(LayoutWidth::const_px(1))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(13))),
+    Normal(CssProperty::const_background_content(CURSOR_COLOR)),
+    Normal(CssProperty::const_opacity(StyleOpacity::const_new(0))),
+];
+
+const IDS_AND_CLASSES_TEXT_AREA_CONTAINER: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-text-area-container")),
+];
+const TEXT_AREA_CONTAINER_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TEXT_AREA_CONTAINER);
+
+const IDS_AND_CLASSES_TEXT_AREA_CONTENT: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-text-area-content")),
+];
+const TEXT_AREA_CONTENT_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TEXT_AREA_CONTENT);
+
+const IDS_AND_CLASSES_TEXT_AREA_SELECTION: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-text-area-selection")),
+];
+const TEXT_AREA_SELECTION_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TEXT_AREA_SELECTION);
+
+const IDS_AND_CLASSES_TEXT_AREA_TEXT: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-text-area-text")),
+];
+const TEXT_AREA_TEXT_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TEXT_AREA_TEXT);
+
+const IDS_AND_CLASSES_TEXT_AREA_CURSOR: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-text-area-cursor")),
+];
+const TEXT_AREA_CURSOR_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TEXT_AREA_CURSOR);
+
+/// Border + padding of the container, i.e. the offset of the text from the container edge
+const TEXT_AREA_INSET: f32 = 3.0;
+/// Font size of the text, used to estimate the layout as long as the text isn't laid out yet
+const TEXT_AREA_FONT_SIZE: f32 = 11.0;
+/// Estimated width of a space, relative to the font size
+const SPACE_WIDTH_EM: f32 = 0.3;
+/// Keystrokes that are less than this far apart are undone together
+const TYPING_BURST_TIME_MS: u64 = 1000;
+/// Maximum number of edits that can be undone
+const MAX_UNDO_STEPS: usize = 100;
+
+/// What kind of change a `TextAreaEdit` made, consecutive edits
+/// of the same kind can be merged into one undo step
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub enum TextAreaEditKind {
+    /// Characters typed without a selection
+    Insert,
+    /// Characters deleted with Backspace / Delete without a selection
+    Delete,
+    /// Paste, cut or replacing the selection, never merged with other edits
+    Replace,
+}
+
+/// Single step in the undo / redo history of a `TextArea`:
+/// `removed` was replaced with `inserted` at `position`
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextAreaEdit {
+    /// Index of the first changed character
+    pub position: usize,
+    /// Characters that were removed by the edit
+    pub removed: U32Vec,
+    /// Characters that were inserted by the edit
+    pub inserted: U32Vec,
+    pub kind: TextAreaEditKind,
+    /// Cursor before the edit, restored when the edit is undone
+    pub cursor_before: usize,
+    /// Selection anchor before the edit, restored when the edit is undone
+    pub selection_anchor_before: OptionUsize,
+}
+
+impl_vec!(TextAreaEdit, TextAreaEditVec, TextAreaEditVecDestructor);
+impl_vec_clone!(TextAreaEdit, TextAreaEditVec, TextAreaEditVecDestructor);
+impl_vec_mut!(TextAreaEdit, TextAreaEditVec);
+impl_vec_debug!(TextAreaEdit, TextAreaEditVec);
+impl_vec_partialeq!(TextAreaEdit, TextAreaEditVec);
+
+impl TextAreaEdit {
+
+    /// Merges `next` into `self` if `next` continues the same typing
+    /// or deleting, returns `None` if the edits have to stay separate
+    fn merge(&self, next: &TextAreaEdit) -> Option<TextAreaEdit> {
+        match (self.kind, next.kind) {
+            (TextAreaEditKind::Insert, TextAreaEditKind::Insert)
+            if next.position == self.position + self.inserted.len() => {
+                let mut inserted = self.inserted.as_ref().to_vec();
+                inserted.extend_from_slice(next.inserted.as_ref());
+                Some(TextAreaEdit { inserted: inserted.into(), .. self.clone() })
+            },
+            // Backspace
+            (TextAreaEditKind::Delete, TextAreaEditKind::Delete)
+            if next.position + next.removed.len() == self.position => {
+                let mut removed = next.removed.as_ref().to_vec();
+                removed.extend_from_slice(self.removed.as_ref());
+                Some(TextAreaEdit { position: next.position, removed: removed.into(), .. self.clone() })
+            },
+            // Delete
+            (TextAreaEditKind::Delete, TextAreaEditKind::Delete)
+            if next.position == self.position => {
+                let mut removed = self.removed.as_ref().to_vec();
+                removed.extend_from_slice(next.removed.as_ref());
+                Some(TextAreaEdit { removed: removed.into(), .. self.clone() })
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Text, cursor, selection, scroll position and undo history of a `TextArea`.
+///
+/// The `TextArea` edits its copy of the state by itself, the `on_text_change`
+/// callback gets the new state as an argument: if the application re-renders
+/// the DOM, it should store the state and pass it back in via `TextArea::with_state`.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextAreaState {
+    /// Text as unicode codepoints (`Vec<char>`), lines are separated by `\n`
+    pub text: U32Vec,
+    /// Index of the character in front of which the cursor is (`0..=text.len()`)
+    pub cursor: usize,
+    /// Other end of the selection, the selected text is between the anchor and the cursor
+    pub selection_anchor: OptionUsize,
+    /// Horizontal position that the cursor keeps when moving up / down
+    pub preferred_x: OptionF32,
+    /// Vertical scroll position of the text in pixels
+    pub scroll_offset: f32,
+    /// Edits that can be undone, the last edit is undone first
+    pub undo_stack: TextAreaEditVec,
+    /// Undone edits that can be redone
+    pub redo_stack: TextAreaEditVec,
+    /// Time of the last keystroke that changed the text, used to group typing bursts
+    pub last_edit_time: OptionInstant,
+}
+
+impl Default for TextAreaState {
+    fn default() -> Self {
+        Self {
+            text: U32Vec::from_const_slice(&[]),
+            cursor: 0,
+            selection_anchor: None.into(),
+            preferred_x: None.into(),
+            scroll_offset: 0.0,
+            undo_stack: TextAreaEditVec::from_const_slice(&[]),
+            redo_stack: TextAreaEditVec::from_const_slice(&[]),
+            last_edit_time: None.into(),
+        }
+    }
+}
+
+impl TextAreaState {
+
+    pub fn get_text(&self) -> String {
+        self.text
+        .iter()
+        .filter_map(|c| core::char::from_u32(*c))
+        .collect()
+    }
+
+    /// Replaces the text, moves the cursor to the end and clears the undo history
+    pub fn set_text(&mut self, text: AzString) {
+        *self = Self {
+            text: string_to_codepoints(text.as_str()).into(),
+            scroll_offset: self.scroll_offset,
+            .. Self::default()
+        };
+        self.cursor = self.text.len();
+    }
+
+    /// Returns the selected range (`start < end`) or `None` if nothing is selected
+    pub fn get_selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor.into_option()?;
+        if anchor == self.cursor {
+            None
+        } else {
+            Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+        }
+    }
+
+    pub fn get_selected_text(&self) -> String {
+        match self.get_selection() {
+            Some((start, end)) => self.text.as_ref()[start..end]
+                .iter()
+                .filter_map(|c| core::char::from_u32(*c))
+                .collect(),
+            None => String::new(),
+        }
+    }
+
+    pub fn select_all(&mut self) {
+        self.selection_anchor = Some(0).into();
+        self.cursor = self.text.len();
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Reverts the last edit (or typing burst), returns false if there is nothing to undo
+    pub fn undo(&mut self) -> bool {
+
+        let mut undo_stack = self.undo_stack.as_ref().to_vec();
+        let edit = match undo_stack.pop() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let mut text = self.text.as_ref().to_vec();
+        text.splice(edit.position..(edit.position + edit.inserted.len()), edit.removed.iter().copied());
+
+        self.text = text.into();
+        self.cursor = edit.cursor_before;
+        self.selection_anchor = edit.selection_anchor_before;
+        self.preferred_x = None.into();
+        self.last_edit_time = None.into();
+
+        let mut redo_stack = self.redo_stack.as_ref().to_vec();
+        redo_stack.push(edit);
+        self.undo_stack = undo_stack.into();
+        self.redo_stack = redo_stack.into();
+
+        true
+    }
+
+    /// Re-applies the last undone edit, returns false if there is nothing to redo
+    pub fn redo(&mut self) -> bool {
+
+        let mut redo_stack = self.redo_stack.as_ref().to_vec();
+        let edit = match redo_stack.pop() {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let mut text = self.text.as_ref().to_vec();
+        text.splice(edit.position..(edit.position + edit.removed.len()), edit.inserted.iter().copied());
+
+        self.text = text.into();
+        self.cursor = edit.position + edit.inserted.len();
+        self.selection_anchor = None.into();
+        self.preferred_x = None.into();
+        self.last_edit_time = None.into();
+
+        let mut undo_stack = self.undo_stack.as_ref().to_vec();
+        undo_stack.push(edit);
+        self.undo_stack = undo_stack.into();
+        self.redo_stack = redo_stack.into();
+
+        true
+    }
+
+    /// Moves the cursor, `extend_selection` keeps (or starts) the selection
+    /// at the current position, otherwise the selection is removed
+    fn set_cursor(&mut self, index: usize, extend_selection: bool) {
+        let index = index.min(self.text.len());
+        if extend_selection {
+            if self.selection_anchor.is_none() {
+                self.selection_anchor = Some(self.cursor).into();
+            }
+        } else {
+            self.selection_anchor = None.into();
+        }
+        self.cursor = index;
+        // moving the cursor ends the current typing burst
+        self.last_edit_time = None.into();
+    }
+
+    /// Replaces `position..(position + remove_len)` with `inserted` and records the
+    /// change in the undo history, `merge` tries to merge it with the previous edit
+    fn apply_edit(&mut self, position: usize, remove_len: usize, inserted: &[u32], kind: TextAreaEditKind, merge: bool) {
+
+        let mut text = self.text.as_ref().to_vec();
+        let removed = text.splice(position..(position + remove_len), inserted.iter().copied()).collect::<Vec<u32>>();
+
+        let edit = TextAreaEdit {
+            position,
+            removed: removed.into(),
+            inserted: inserted.to_vec().into(),
+            kind,
+            cursor_before: self.cursor,
+            selection_anchor_before: self.selection_anchor,
+        };
+
+        self.text = text.into();
+        self.cursor = position + inserted.len();
+        self.selection_anchor = None.into();
+        self.preferred_x = None.into();
+        self.redo_stack = TextAreaEditVec::from_const_slice(&[]);
+
+        let mut undo_stack = self.undo_stack.as_ref().to_vec();
+        let merged = if merge { undo_stack.last().and_then(|last| last.merge(&edit)) } else { None };
+        match merged {
+            Some(m) => { undo_stack.pop(); undo_stack.push(m); },
+            None => undo_stack.push(edit),
+        }
+        if undo_stack.len() > MAX_UNDO_STEPS {
+            undo_stack.remove(0);
+        }
+        self.undo_stack = undo_stack.into();
+    }
+
+    /// Inserts `text` at the cursor, replacing the selection
+    fn insert_text(&mut self, text: &[u32], merge: bool) {
+        match self.get_selection() {
+            Some((start, end)) => self.apply_edit(start, end - start, text, TextAreaEditKind::Replace, false),
+            None => {
+                let kind = if text.len() == 1 { TextAreaEditKind::Insert } else { TextAreaEditKind::Replace };
+                self.apply_edit(self.cursor, 0, text, kind, merge)
+            },
+        }
+    }
+
+    /// Deletes the selection or one character in front of / behind the cursor,
+    /// returns false if nothing was deleted
+    fn delete(&mut self, forward: bool, merge: bool) -> bool {
+        if let Some((start, end)) = self.get_selection() {
+            self.apply_edit(start, end - start, &[], TextAreaEditKind::Replace, false);
+            return true;
+        }
+        if forward && self.cursor < self.text.len() {
+            self.apply_edit(self.cursor, 1, &[], TextAreaEditKind::Delete, merge);
+            true
+        } else if !forward && self.cursor > 0 {
+            self.apply_edit(self.cursor - 1, 1, &[], TextAreaEditKind::Delete, merge);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Handles a key press, `merge` is true if the key press is part of a typing burst
+    fn handle_key(&mut self, key: VirtualKeyCode, shift: bool, ctrl: bool, layout: &TextAreaLayout, merge: bool) -> Option<TextAreaEvent> {
+
+        use self::VirtualKeyCode::*;
+
+        let len = self.text.len();
+        let selection = self.get_selection();
+        let current_x = layout.get_caret_rect(self.cursor).origin.x;
+
+        // cursor movement without Shift collapses the selection
+        let target = match key {
+            Left if !shift && !ctrl && selection.is_some() => selection?.0,
+            Right if !shift && !ctrl && selection.is_some() => selection?.1,
+            Left if ctrl => get_word_boundary(self.text.as_ref(), self.cursor, false),
+            Right if ctrl => get_word_boundary(self.text.as_ref(), self.cursor, true),
+            Left => self.cursor.saturating_sub(1),
+            Right => (self.cursor + 1).min(len),
+            Up | Down => {
+                let x = self.preferred_x.into_option().unwrap_or(current_x);
+                let lines = if key == Up { -1 } else { 1 };
+                let target = layout.move_vertically(self.cursor, x, lines);
+                self.set_cursor(target, shift);
+                self.preferred_x = Some(x).into();
+                return Some(TextAreaEvent::CursorMoved);
+            },
+            Home if ctrl => 0,
+            End if ctrl => len,
+            Home => layout.get_line_start(self.cursor),
+            End => layout.get_line_end(self.cursor),
+            Back | Delete => {
+                return if self.delete(key == Delete, merge) { Some(TextAreaEvent::TextChanged) } else { None };
+            },
+            Return | NumpadEnter => {
+                self.insert_text(&['\n' as u32], merge);
+                return Some(TextAreaEvent::TextChanged);
+            },
+            A if ctrl => {
+                self.select_all();
+                return Some(TextAreaEvent::CursorMoved);
+            },
+            C if ctrl => return selection.map(|_| TextAreaEvent::Copy),
+            X if ctrl => return selection.map(|_| TextAreaEvent::Cut),
+            V if ctrl => return Some(TextAreaEvent::Paste),
+            Copy => return selection.map(|_| TextAreaEvent::Copy),
+            Cut => return selection.map(|_| TextAreaEvent::Cut),
+            Paste => return Some(TextAreaEvent::Paste),
+            Z if ctrl && shift => return if self.redo() { Some(TextAreaEvent::TextChanged) } else { None },
+            Z if ctrl => return if self.undo() { Some(TextAreaEvent::TextChanged) } else { None },
+            Y if ctrl => return if self.redo() { Some(TextAreaEvent::TextChanged) } else { None },
+            _ => return None,
+        };
+
+        self.set_cursor(target, shift);
+        self.preferred_x = None.into();
+        Some(TextAreaEvent::CursorMoved)
+    }
+}
+
+fn string_to_codepoints(s: &str) -> Vec<u32> {
+    // normalize Windows line endings, the text only uses \n
+    s.chars().filter(|c| *c != '\r').map(|c| c as u32).collect()
+}
+
+fn is_word_char(c: u32) -> bool {
+    core::char::from_u32(c).map(|c| c.is_alphanumeric() || c == '_').unwrap_or(false)
+}
+
+/// Returns the start of the next / previous word, for Ctrl + Left / Right
+fn get_word_boundary(text: &[u32], index: usize, forward: bool) -> usize {
+    let mut i = index.min(text.len());
+    if forward {
+        while i < text.len() && !is_word_char(text[i]) { i += 1; }
+        while i < text.len() && is_word_char(text[i]) { i += 1; }
+    } else {
+        while i > 0 && !is_word_char(text[i - 1]) { i -= 1; }
+        while i > 0 && is_word_char(text[i - 1]) { i -= 1; }
+    }
+    i
+}
+
+fn is_typing_burst(last_edit: &Instant, now: &Instant) -> bool {
+    match (last_edit, now) {
+        (Instant::System(_), Instant::System(_)) => {
+            let max = Duration::System(SystemTimeDiff::from_millis(TYPING_BURST_TIME_MS));
+            now.duration_since(last_edit).smaller_than(&max)
+        },
+        _ => false,
+    }
+}
+
+/// Returns the scroll offset at which the range `top..bottom` is visible in the viewport
+fn scroll_to_range(scroll_offset: f32, viewport_height: f32, content_height: f32, top: f32, bottom: f32) -> f32 {
+    let mut new_offset = scroll_offset;
+    if bottom > new_offset + viewport_height {
+        new_offset = bottom - viewport_height;
+    }
+    if top < new_offset {
+        new_offset = top;
+    }
+    new_offset.min(content_height - viewport_height).max(0.0)
+}
+
+/// What happened in response to a key press
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TextAreaEvent {
+    /// Only the cursor or the selection changed
+    CursorMoved,
+    TextChanged,
+    Copy,
+    Cut,
+    Paste,
+}
+
+pub type TextAreaOnTextChangeCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &TextAreaState) -> Update;
+impl_callback!(TextAreaOnTextChange, OptionTextAreaOnTextChange, TextAreaOnTextChangeCallback, TextAreaOnTextChangeCallbackType);
+
+/// Multi-line text input, for example for comments or config snippets
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TextArea {
+    /// Text, cursor, selection and undo history
+    pub state: TextAreaState,
+    /// Called after every change of the text (typing, cut / paste, undo / redo)
+    pub on_text_change: OptionTextAreaOnTextChange,
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        Self {
+            state: TextAreaState::default(),
+            on_text_change: None.into(),
+        }
+    }
+}
+
+impl TextArea {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn swap_with_default(&mut self) -> Self {
+        let mut s = Self::default();
+        core::mem::swap(&mut s, self);
+        s
+    }
+
+    pub fn with_text(&mut self, text: AzString) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_text(text);
+        s
+    }
+
+    pub fn set_text(&mut self, text: AzString) {
+        self.state.set_text(text);
+    }
+
+    pub fn with_state(&mut self, state: TextAreaState) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_state(state);
+        s
+    }
+
+    pub fn set_state(&mut self, state: TextAreaState) {
+        self.state = state;
+    }
+
+    pub fn with_on_text_change(&mut self, data: RefAny, on_text_change: TextAreaOnTextChangeCallbackType) -> Self {
+        let mut s = self.swap_with_default();
+        s.set_on_text_change(data, on_text_change);
+        s
+    }
+
+    pub fn set_on_text_change(&mut self, data: RefAny, on_text_change: TextAreaOnTextChangeCallbackType) {
+        self.on_text_change = Some(TextAreaOnTextChange {
+            callback: TextAreaOnTextChangeCallback { cb: on_text_change },
+            data,
+        }).into();
+    }
+
+    pub fn dom(self) -> Dom {
+
+        use azul_desktop::dom::{
+            AccessibilityInfo, AccessibilityRole,
+            CallbackData, EventFilter,
+            HoverEventFilter, FocusEventFilter, WindowEventFilter,
+        };
+
+        let text = self.state.get_text();
+
+        let dataset = RefAny::new(TextAreaLocalDataset {
+            text_area: self,
+            drag_origin: None,
+        });
+
+        let selection = || {
+            Dom::div()
+            .with_ids_and_classes(TEXT_AREA_SELECTION_CLASS)
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_AREA_SELECTION_PROPS))
+        };
+
+        // container > content > [selection * 3, text, cursor]
+        Dom::div()
+        .with_ids_and_classes(TEXT_AREA_CONTAINER_CLASS)
+        .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_AREA_CONTAINER_PROPS))
+        .with_tab_index(TabIndex::Auto)
+        .with_accessibility_info(AccessibilityInfo::new(AccessibilityRole::Text).with_value(text.clone().into()))
+        .with_dataset(Some(dataset.clone()).into())
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::FocusReceived),
+                data: dataset.clone(),
+                callback: Callback { cb: on_text_area_focus_received },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::FocusLost),
+                data: dataset.clone(),
+                callback: Callback { cb: on_text_area_focus_lost },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::TextInput),
+                data: dataset.clone(),
+                callback: Callback { cb: on_text_area_text_input },
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                data: dataset.clone(),
+                callback: Callback { cb: on_text_area_virtual_key_down },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::LeftMouseDown),
+                data: dataset.clone(),
+                callback: Callback { cb: on_text_area_mouse_down },
+            },
+            CallbackData {
+                event: EventFilter::Hover(HoverEventFilter::Scroll),
+                data: dataset.clone(),
+                callback: Callback { cb: on_text_area_scroll },
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::MouseOver),
+                data: dataset.clone(),
+                callback: Callback { cb: on_text_area_mouse_drag },
+            },
+            CallbackData {
+                event: EventFilter::Window(WindowEventFilter::LeftMouseUp),
+                data: dataset,
+                callback: Callback { cb: on_text_area_mouse_up },
+            },
+        ].into())
+        .with_children(vec![
+            Dom::div()
+            .with_ids_and_classes(TEXT_AREA_CONTENT_CLASS)
+            .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_AREA_CONTENT_PROPS))
+            .with_children(vec![
+                selection(),
+                selection(),
+                selection(),
+                Dom::text(text)
+                .with_ids_and_classes(TEXT_AREA_TEXT_CLASS)
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_AREA_TEXT_PROPS)),
+                Dom::div()
+                .with_ids_and_classes(TEXT_AREA_CURSOR_CLASS)
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_AREA_CURSOR_PROPS)),
+            ].into())
+        ].into())
+    }
+}
+
+/// Position of the text cursor when it is in front of a character
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct TextAreaCaret {
+    /// Visual line (after soft wrapping)
+    line: usize,
+    x: f32,
+}
+
+/// Vertical extent of a visual line
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct TextAreaLine {
+    top: f32,
+    height: f32,
+}
+
+/// Maps character indices of the text to cursor positions and back
+#[derive(Debug, Clone, PartialEq)]
+struct TextAreaLayout {
+    lines: Vec<TextAreaLine>,
+    /// One caret in front of each character, plus one at the end of the text
+    carets: Vec<TextAreaCaret>,
+}
+
+impl TextAreaLayout {
+
+    /// Layout of the text according to the (soft-wrapped) lines of the `InlineText`
+    ///
+    /// The glyphs of the `InlineText` don't contain whitespace, so spaces are placed
+    /// in between the glyphs around them and line breaks are taken from the text.
+    fn from_inline_text(text: &[u32], inline_text: &InlineText) -> Self {
+
+        let space_width = inline_text.font_size_px * SPACE_WIDTH_EM;
+
+        // line index, leading and trailing edge of each glyph that has a character, in logical order
+        let glyphs = inline_text.lines.iter().enumerate().flat_map(|(line_idx, line)| {
+            line.words.iter().filter_map(|w| w.get_text_content()).flat_map(move |text_content| {
+                text_content.glyphs.iter().filter(|g| g.has_codepoint()).map(move |glyph| {
+                    let left = line.bounds.origin.x + text_content.bounds.origin.x + glyph.bounds.origin.x;
+                    let right = left + glyph.bounds.size.width;
+                    if text_content.is_rtl { (line_idx, right, left) } else { (line_idx, left, right) }
+                })
+            })
+        }).collect::<Vec<_>>();
+
+        let mut glyphs = glyphs.into_iter();
+        let mut carets = Vec::with_capacity(text.len() + 1);
+        let mut line = 0;
+        let mut x = 0.0;
+
+        for c in text.iter() {
+            carets.push(TextAreaCaret { line, x });
+            match core::char::from_u32(*c) {
+                Some('\n') => {
+                    line += 1;
+                    x = 0.0;
+                },
+                Some(' ') => x += space_width,
+                Some('\t') => x += space_width * 4.0,
+                _ => match glyphs.next() {
+                    Some((glyph_line, leading, trailing)) => {
+                        if let Some(caret) = carets.last_mut() {
+                            *caret = TextAreaCaret { line: glyph_line, x: leading };
+                        }
+                        line = glyph_line;
+                        x = trailing;
+                    },
+                    // more characters than glyphs (ligatures)
+                    None => x += space_width,
+                },
+            }
+        }
+        carets.push(TextAreaCaret { line, x });
+
+        // place runs of whitespace in between the glyphs around them
+        let is_blank = |c: u32| c == ' ' as u32 || c == '\t' as u32;
+        let mut i = 0;
+        while i < text.len() {
+            if !is_blank(text[i]) {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < text.len() && is_blank(text[i]) { i += 1; }
+            let (from, to) = (carets[start], carets[i]);
+            if i < text.len() && text[i] != '\n' as u32 && from.line == to.line {
+                let run_len = (i - start) as f32;
+                for (k, caret) in carets[start..i].iter_mut().enumerate() {
+                    caret.x = from.x + (to.x - from.x) * k as f32 / run_len;
+                }
+            }
+        }
+
+        let lines = inline_text.lines.iter().map(|l| TextAreaLine {
+            top: l.bounds.origin.y - l.bounds.size.height,
+            height: l.bounds.size.height,
+        }).collect::<Vec<_>>();
+
+        let line_height = lines.last().map(|l| l.height).unwrap_or(inline_text.font_size_px);
+
+        Self::with_lines(lines, carets, line_height)
+    }
+
+    /// Layout without soft wrapping, assuming that every character has the same width
+    fn monospace(text: &[u32], char_width: f32, line_height: f32) -> Self {
+        let mut carets = Vec::with_capacity(text.len() + 1);
+        let mut line = 0;
+        let mut column = 0;
+        for c in text.iter() {
+            carets.push(TextAreaCaret { line, x: column as f32 * char_width });
+            if *c == '\n' as u32 {
+                line += 1;
+                column = 0;
+            } else {
+                column += 1;
+            }
+        }
+        carets.push(TextAreaCaret { line, x: column as f32 * char_width });
+        Self::with_lines(Vec::new(), carets, line_height)
+    }
+
+    /// Adds lines of `line_height` until there is a line for every caret,
+    /// for example the empty line after a trailing `\n`
+    fn with_lines(mut lines: Vec<TextAreaLine>, carets: Vec<TextAreaCaret>, line_height: f32) -> Self {
+        let last_line = carets.iter().map(|c| c.line).max().unwrap_or(0);
+        while lines.len() <= last_line {
+            let top = lines.last().map(|l| l.top + l.height).unwrap_or(0.0);
+            lines.push(TextAreaLine { top, height: line_height });
+        }
+        Self { lines, carets }
+    }
+
+    fn get_content_height(&self) -> f32 {
+        self.lines.last().map(|l| l.top + l.height).unwrap_or(0.0)
+    }
+
+    fn get_caret(&self, index: usize) -> TextAreaCaret {
+        self.carets[index.min(self.carets.len() - 1)]
+    }
+
+    /// Rectangle of the text cursor in front of the character at `index`
+    fn get_caret_rect(&self, index: usize) -> LogicalRect {
+        let caret = self.get_caret(index);
+        let line = self.lines[caret.line];
+        LogicalRect::new(LogicalPosition::new(caret.x, line.top), LogicalSize::new(0.0, line.height))
+    }
+
+    /// Index of the caret on `line` that is closest to `x`
+    fn get_index_on_line(&self, line: usize, x: f32) -> usize {
+        self.carets.iter().enumerate()
+        .filter(|(_, c)| c.line == line)
+        .fold(None, |closest: Option<(usize, f32)>, (i, c)| {
+            let distance = (c.x - x).abs();
+            match closest {
+                Some((_, d)) if d <= distance => closest,
+                _ => Some((i, distance)),
+            }
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(self.carets.len() - 1)
+    }
+
+    /// Index of the caret that is closest to a position relative to the top left of the text
+    fn get_index_at(&self, position: LogicalPosition) -> usize {
+        let line = self.lines.iter()
+            .position(|l| position.y < l.top + l.height)
+            .unwrap_or(self.lines.len() - 1);
+        self.get_index_on_line(line, position.x)
+    }
+
+    /// Moves the caret `lines` visual lines up (negative) or down, keeping
+    /// the horizontal position `x` - goes to the start / end of the text
+    /// when moving past the first / last line
+    fn move_vertically(&self, index: usize, x: f32, lines: isize) -> usize {
+        let line = self.get_caret(index).line as isize + lines;
+        if line < 0 {
+            0
+        } else if line as usize >= self.lines.len() {
+            self.carets.len() - 1
+        } else {
+            self.get_index_on_line(line as usize, x)
+        }
+    }
+
+    /// First caret index on the visual line of `index`
+    fn get_line_start(&self, index: usize) -> usize {
+        let line = self.get_caret(index).line;
+        self.carets.iter().position(|c| c.line == line).unwrap_or(0)
+    }
+
+    /// Last caret index on the visual line of `index`: in front of the line
+    /// break or, for soft-wrapped lines, in front of the trailing space
+    fn get_line_end(&self, index: usize) -> usize {
+        let line = self.get_caret(index).line;
+        self.carets.iter().rposition(|c| c.line == line).unwrap_or(self.carets.len() - 1)
+    }
+
+    /// Rectangles that highlight the range `start..end`: the rest of the first
+    /// line, the lines in between and the start of the last line
+    fn get_selection_rects(&self, start: usize, end: usize, width: f32) -> [LogicalRect;3] {
+
+        let empty = LogicalRect::new(LogicalPosition::zero(), LogicalSize::zero());
+        if start >= end {
+            return [empty; 3];
+        }
+
+        let (from, to) = (self.get_caret(start), self.get_caret(end));
+        let (from_line, to_line) = (self.lines[from.line], self.lines[to.line]);
+        let rect = |x0: f32, x1: f32, top: f32, bottom: f32| LogicalRect::new(
+            LogicalPosition::new(x0, top),
+            LogicalSize::new((x1 - x0).max(0.0), (bottom - top).max(0.0))
+        );
+
+        if from.line == to.line {
+            return [rect(from.x, to.x, from_line.top, from_line.top + from_line.height), empty, empty];
+        }
+
+        [
+            rect(from.x, width, from_line.top, from_line.top + from_line.height),
+            rect(0.0, width, from_line.top + from_line.height, to_line.top),
+            rect(0.0, to.x, to_line.top, to_line.top + to_line.height),
+        ]
+    }
+}
+
+/// Returns the layout of the `text` in the text node, either from shaping the
+/// text with the font of the text node or - if the text node hasn't been laid
+/// out yet - estimated from the font size
+fn get_text_area_layout(info: &CallbackInfo, text_node: DomNodeId, text: &[u32]) -> TextAreaLayout {
+    let string = text.iter().filter_map(|c| core::char::from_u32(*c)).collect::<String>();
+    match callback_info_shape_text(info, text_node, string.into()) {
+        Some(inline_text) => TextAreaLayout::from_inline_text(text, &inline_text),
+        None => TextAreaLayout::monospace(text, TEXT_AREA_FONT_SIZE * 0.6, TEXT_AREA_FONT_SIZE * 1.2),
+    }
+}
+
+/// Nodes of a rendered `TextArea`
+#[derive(Debug, Copy, Clone)]
+struct TextAreaNodes {
+    container: DomNodeId,
+    selection: [DomNodeId;3],
+    text: DomNodeId,
+    cursor: DomNodeId,
+}
+
+impl TextAreaNodes {
+    /// container > content > [selection * 3, text, cursor]
+    fn new(info: &CallbackInfo, container: DomNodeId) -> Option<Self> {
+        let content = info.get_first_child(container)?;
+        let selection_0 = info.get_first_child(content)?;
+        let selection_1 = info.get_next_sibling(selection_0)?;
+        let selection_2 = info.get_next_sibling(selection_1)?;
+        let text = info.get_next_sibling(selection_2)?;
+        let cursor = info.get_next_sibling(text)?;
+        Some(Self {
+            container,
+            selection: [selection_0, selection_1, selection_2],
+            text,
+            cursor,
+        })
+    }
+}
+
+struct TextAreaLocalDataset {
+    text_area: TextArea,
+    /// Position of the container in the viewport while the mouse is dragged to select text
+    drag_origin: Option<LogicalPosition>,
+}
+
+impl TextAreaLocalDataset {
+
+    /// Updates the text, cursor, selection and scroll position on the screen,
+    /// `follow_cursor` scrolls the cursor into view
+    fn update_display(&mut self, info: &mut CallbackInfo, nodes: TextAreaNodes, text_changed: bool, follow_cursor: bool) {
+
+        let state = &mut self.text_area.state;
+
+        if text_changed {
+            info.set_string_contents(nodes.text, state.get_text().into());
+        }
+
+        let layout = get_text_area_layout(info, nodes.text, state.text.as_ref());
+
+        let cursor_rect = layout.get_caret_rect(state.cursor);
+        info.set_css_property(nodes.cursor, CssProperty::left(LayoutLeft::px(cursor_rect.origin.x)));
+        info.set_css_property(nodes.cursor, CssProperty::top(LayoutTop::px(cursor_rect.origin.y)));
+        info.set_css_property(nodes.cursor, CssProperty::height(LayoutHeight::px(cursor_rect.size.height)));
+
+        let container_size = info.get_node_size(nodes.container).unwrap_or(LogicalSize::zero());
+        let width = info.get_node_size(nodes.text)
+            .map(|s| s.width)
+            .unwrap_or(container_size.width - 2.0 * TEXT_AREA_INSET);
+
+        let (start, end) = state.get_selection().unwrap_or((0, 0));
+        for (node, rect) in nodes.selection.iter().zip(layout.get_selection_rects(start, end, width).iter()) {
+            info.set_css_property(*node, CssProperty::left(LayoutLeft::px(rect.origin.x)));
+            info.set_css_property(*node, CssProperty::top(LayoutTop::px(rect.origin.y)));
+            info.set_css_property(*node, CssProperty::width(LayoutWidth::px(rect.size.width)));
+            info.set_css_property(*node, CssProperty::height(LayoutHeight::px(rect.size.height)));
+        }
+
+        if follow_cursor {
+            let viewport_height = container_size.height - 2.0 * TEXT_AREA_INSET;
+            state.scroll_offset = scroll_to_range(
+                state.scroll_offset,
+                viewport_height,
+                layout.get_content_height(),
+                cursor_rect.origin.y,
+                cursor_rect.origin.y + cursor_rect.size.height
+            );
+            info.set_scroll_position(nodes.container, LogicalPosition::new(0.0, state.scroll_offset));
+        }
+    }
+
+    /// Position of the mouse relative to the top left corner of the text
+    fn get_text_position(&self, cursor_relative_to_container: LogicalPosition) -> LogicalPosition {
+        LogicalPosition::new(
+            cursor_relative_to_container.x - TEXT_AREA_INSET,
+            cursor_relative_to_container.y - TEXT_AREA_INSET + self.text_area.state.scroll_offset,
+        )
+    }
+
+    fn dispatch_text_change(&mut self, info: &mut CallbackInfo) -> Update {
+        let text_area = &mut self.text_area;
+        match text_area.on_text_change.as_mut() {
+            Some(TextAreaOnTextChange { callback, data }) => (callback.cb)(data, info, &text_area.state),
+            None => Update::DoNothing,
+        }
+    }
+}
+
+extern "C" fn on_text_area_focus_received(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_text_area_focus_received_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut text_area = data.downcast_mut::<TextAreaLocalDataset>()?;
+        let nodes = TextAreaNodes::new(info, info.get_hit_node())?;
+        info.set_css_property(nodes.cursor, CssProperty::opacity(StyleOpacity::const_new(100)));
+        text_area.update_display(info, nodes, false, true);
+        Some(Update::DoNothing)
+    }
+
+    on_text_area_focus_received_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_text_area_focus_lost(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_text_area_focus_lost_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+        let mut text_area = data.downcast_mut::<TextAreaLocalDataset>()?;
+        let nodes = TextAreaNodes::new(info, info.get_hit_node())?;
+        text_area.drag_origin = None;
+        text_area.text_area.state.last_edit_time = None.into();
+        info.set_css_property(nodes.cursor, CssProperty::opacity(StyleOpacity::const_new(0)));
+        Some(Update::DoNothing)
+    }
+
+    on_text_area_focus_lost_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_text_area_text_input(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_text_area_text_input_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut text_area = data.downcast_mut::<TextAreaLocalDataset>()?;
+        let keyboard_state = info.get_current_keyboard_state();
+        let c = keyboard_state.current_char.into_option()?;
+
+        // shortcuts are handled in on_text_area_virtual_key_down, Return inserts a \n there
+        if keyboard_state.ctrl_down() && !keyboard_state.alt_down() {
+            return None;
+        }
+        if core::char::from_u32(c).map(|c| c.is_control() && c != '\t').unwrap_or(true) {
+            return None;
+        }
+
+        let nodes = TextAreaNodes::new(info, info.get_hit_node())?;
+        let now = info.get_current_time();
+        let state = &mut text_area.text_area.state;
+        let merge = state.last_edit_time.as_ref().map(|t| is_typing_burst(t, &now)).unwrap_or(false);
+
+        state.insert_text(&[c], merge);
+        state.last_edit_time = Some(now).into();
+
+        text_area.update_display(info, nodes, true, true);
+        Some(text_area.dispatch_text_change(info))
+    }
+
+    on_text_area_text_input_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_text_area_virtual_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_text_area_virtual_key_down_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut text_area = data.downcast_mut::<TextAreaLocalDataset>()?;
+        let keyboard_state = info.get_current_keyboard_state();
+        let key = keyboard_state.current_virtual_keycode.into_option()?;
+        let nodes = TextAreaNodes::new(info, info.get_hit_node())?;
+
+        let now = info.get_current_time();
+        let layout = get_text_area_layout(info, nodes.text, text_area.text_area.state.text.as_ref());
+        let state = &mut text_area.text_area.state;
+        let merge = state.last_edit_time.as_ref().map(|t| is_typing_burst(t, &now)).unwrap_or(false);
+
+        let event = state.handle_key(key, keyboard_state.shift_down(), keyboard_state.ctrl_down(), &layout, merge)?;
+
+        let text_changed = match event {
+            TextAreaEvent::CursorMoved => false,
+            TextAreaEvent::TextChanged => {
+                // only typing continues a burst, undo / redo reset the time
+                if key == VirtualKeyCode::Back || key == VirtualKeyCode::Delete ||
+                   key == VirtualKeyCode::Return || key == VirtualKeyCode::NumpadEnter {
+                    state.last_edit_time = Some(now).into();
+                }
+                true
+            },
+            TextAreaEvent::Copy => {
+                info.set_clipboard_content(ClipboardSelection::Clipboard, ClipboardContent::text(state.get_selected_text().into()));
+                return Some(Update::DoNothing);
+            },
+            TextAreaEvent::Cut => {
+                if !info.set_clipboard_content(ClipboardSelection::Clipboard, ClipboardContent::text(state.get_selected_text().into())) {
+                    return None;
+                }
+                state.delete(false, false)
+            },
+            TextAreaEvent::Paste => {
+                let contents = info.get_clipboard_content(ClipboardSelection::Clipboard, AzString::from_const_str(ClipboardContent::MIME_TYPE_TEXT)).into_option()?.get_text()?;
+                let contents = string_to_codepoints(contents.as_str());
+                if contents.is_empty() {
+                    return None;
+                }
+                state.insert_text(&contents, false);
+                true
+            },
+        };
+
+        text_area.update_display(info, nodes, text_changed, true);
+
+        if text_changed {
+            Some(text_area.dispatch_text_change(info))
+        } else {
+            Some(Update::DoNothing)
+        }
+    }
+
+    on_text_area_virtual_key_down_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_text_area_mouse_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_text_area_mouse_down_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut text_area = data.downcast_mut::<TextAreaLocalDataset>()?;
+        let container = info.get_hit_node();
+        let nodes = TextAreaNodes::new(info, container)?;
+
+        info.set_focus(FocusTarget::Id(container));
+
+        let cursor_relative_to_container = info.get_cursor_relative_to_node().into_option()?;
+        let cursor_in_viewport = info.get_cursor_relative_to_viewport().into_option()?;
+        text_area.drag_origin = Some(LogicalPosition::new(
+            cursor_in_viewport.x - cursor_relative_to_container.x,
+            cursor_in_viewport.y - cursor_relative_to_container.y,
+        ));
+
+        let position = text_area.get_text_position(cursor_relative_to_container);
+        let layout = get_text_area_layout(info, nodes.text, text_area.text_area.state.text.as_ref());
+        let index = layout.get_index_at(position);
+
+        // Shift + click extends the selection, otherwise a new selection starts here
+        let state = &mut text_area.text_area.state;
+        let shift = info.get_current_keyboard_state().shift_down();
+        state.set_cursor(index, shift);
+        if !shift {
+            state.selection_anchor = Some(index).into();
+        }
+        state.preferred_x = None.into();
+
+        text_area.update_display(info, nodes, false, true);
+        Some(Update::DoNothing)
+    }
+
+    on_text_area_mouse_down_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_text_area_mouse_drag(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_text_area_mouse_drag_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut text_area = data.downcast_mut::<TextAreaLocalDataset>()?;
+        let drag_origin = text_area.drag_origin?;
+        let nodes = TextAreaNodes::new(info, info.get_hit_node())?;
+
+        let cursor_in_viewport = info.get_cursor_relative_to_viewport().into_option()?;
+        let position = text_area.get_text_position(LogicalPosition::new(
+            cursor_in_viewport.x - drag_origin.x,
+            cursor_in_viewport.y - drag_origin.y,
+        ));
+
+        let layout = get_text_area_layout(info, nodes.text, text_area.text_area.state.text.as_ref());
+        let index = layout.get_index_at(position);
+        if index == text_area.text_area.state.cursor {
+            return None;
+        }
+
+        text_area.text_area.state.set_cursor(index, true);
+        text_area.update_display(info, nodes, false, true);
+        Some(Update::DoNothing)
+    }
+
+    on_text_area_mouse_drag_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_text_area_mouse_up(data: &mut RefAny, _info: &mut CallbackInfo) -> Update {
+    if let Some(mut text_area) = data.downcast_mut::<TextAreaLocalDataset>() {
+        text_area.drag_origin = None;
+    }
+    Update::DoNothing
+}
+
+extern "C" fn on_text_area_scroll(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_text_area_scroll_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut text_area = data.downcast_mut::<TextAreaLocalDataset>()?;
+        let scroll_y = info.get_current_mouse_state().get_scroll_y();
+        if scroll_y == 0.0 {
+            return None;
+        }
+
+        let nodes = TextAreaNodes::new(info, info.get_hit_node())?;
+        let layout = get_text_area_layout(info, nodes.text, text_area.text_area.state.text.as_ref());
+        let viewport_height = info.get_node_size(nodes.container)?.height - 2.0 * TEXT_AREA_INSET;
+
+        let state = &mut text_area.text_area.state;
+        state.scroll_offset = (state.scroll_offset + scroll_y)
+            .min(layout.get_content_height() - viewport_height)
+            .max(0.0);
+        info.set_scroll_position(nodes.container, LogicalPosition::new(0.0, state.scroll_offset));
+
+        Some(Update::DoNothing)
+    }
+
+    on_text_area_scroll_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+#[cfg(test)]
+mod text_area_test {
+
+    use super::*;
+    use azul_core::callbacks::{InlineLine, InlineWord, InlineTextContents, InlineGlyph};
+
+    fn state(text: &str) -> TextAreaState {
+        let mut s = TextAreaState::default();
+        s.set_text(AzString::from(String::from(text)));
+        s
+    }
+
+    fn type_str(s: &mut TextAreaState, text: &str, merge: bool) {
+        for c in text.chars() {
+            s.insert_text(&[c as u32], merge);
+        }
+    }
+
+    fn layout(s: &TextAreaState) -> TextAreaLayout {
+        TextAreaLayout::monospace(s.text.as_ref(), 10.0, 20.0)
+    }
+
+    fn key(s: &mut TextAreaState, key: VirtualKeyCode, shift: bool) -> Option<TextAreaEvent> {
+        let layout = layout(s);
+        s.handle_key(key, shift, false, &layout, false)
+    }
+
+    #[test]
+    fn typing_burst_is_undone_at_once() {
+        let mut s = state("");
+        type_str(&mut s, "abc", true);
+        s.set_cursor(3, false);
+        type_str(&mut s, "de", true);
+        assert_eq!(s.get_text(), "abcde");
+        assert_eq!(s.undo_stack.len(), 1);
+
+        assert!(s.undo());
+        assert_eq!(s.get_text(), "");
+        assert_eq!(s.cursor, 0);
+        assert!(s.redo());
+        assert_eq!(s.get_text(), "abcde");
+        assert_eq!(s.cursor, 5);
+        assert!(!s.redo());
+    }
+
+    #[test]
+    fn separate_bursts_are_undone_separately() {
+        let mut s = state("");
+        type_str(&mut s, "ab", true);
+        type_str(&mut s, "cd", false);
+        assert_eq!(s.undo_stack.len(), 3);
+        s.undo();
+        assert_eq!(s.get_text(), "abc");
+
+        // a new edit discards the redo history
+        type_str(&mut s, "x", false);
+        assert!(!s.can_redo());
+        assert_eq!(s.get_text(), "abcx");
+    }
+
+    #[test]
+    fn deleting_is_grouped_and_restores_the_selection() {
+        let mut s = state("hello world");
+        s.delete(false, false);
+        s.delete(false, true);
+        s.delete(false, true);
+        assert_eq!(s.get_text(), "hello wo");
+        assert_eq!(s.undo_stack.len(), 1);
+        s.undo();
+        assert_eq!(s.get_text(), "hello world");
+
+        s.selection_anchor = Some(0).into();
+        s.cursor = 5;
+        s.insert_text(&['J' as u32], true);
+        assert_eq!(s.get_text(), "J world");
+        s.undo();
+        assert_eq!(s.get_text(), "hello world");
+        assert_eq!(s.get_selection(), Some((0, 5)));
+        assert_eq!(s.get_selected_text(), "hello");
+    }
+
+    #[test]
+    fn up_and_down_keep_the_column() {
+        let mut s = state("abcdef\nab\nabcdef");
+        s.set_cursor(5, false);
+        key(&mut s, VirtualKeyCode::Down, false);
+        assert_eq!(s.cursor, 9); // end of "ab"
+        key(&mut s, VirtualKeyCode::Down, false);
+        assert_eq!(s.cursor, 15);
+        key(&mut s, VirtualKeyCode::Up, true);
+        key(&mut s, VirtualKeyCode::Up, true);
+        assert_eq!(s.cursor, 5);
+        assert_eq!(s.get_selection(), Some((5, 15)));
+
+        // horizontal movement resets the column, Left collapses the selection
+        key(&mut s, VirtualKeyCode::Left, false);
+        assert_eq!(s.cursor, 5);
+        assert!(s.get_selection().is_none());
+        key(&mut s, VirtualKeyCode::End, false);
+        assert_eq!(s.cursor, 6);
+        key(&mut s, VirtualKeyCode::Up, false);
+        assert_eq!(s.cursor, 0);
+    }
+
+    #[test]
+    fn word_boundaries() {
+        let text = string_to_codepoints("foo bar_baz, qux");
+        assert_eq!(get_word_boundary(&text, 0, true), 3);
+        assert_eq!(get_word_boundary(&text, 3, true), 11);
+        assert_eq!(get_word_boundary(&text, 16, false), 13);
+        assert_eq!(get_word_boundary(&text, 13, false), 4);
+    }
+
+    fn word(x: f32, glyphs: &str) -> InlineWord {
+        InlineWord::Word(InlineTextContents {
+            glyphs: glyphs.chars().enumerate().map(|(i, c)| InlineGlyph {
+                bounds: LogicalRect::new(LogicalPosition::new(i as f32 * 10.0, 0.0), LogicalSize::new(10.0, 20.0)),
+                unicode_codepoint: Some(c as u32).into(),
+                glyph_index: 0,
+                font_index: 0,
+            }).collect::<Vec<_>>().into(),
+            bounds: LogicalRect::new(LogicalPosition::new(x, 0.0), LogicalSize::new(glyphs.len() as f32 * 10.0, 20.0)),
+            is_rtl: false,
+        })
+    }
+
+    #[test]
+    fn soft_wrapped_lines_from_inline_text() {
+
+        // "ab  cd ef" wrapped after "cd", with two spaces of 5px
+        let inline_text = InlineText {
+            lines: vec![
+                InlineLine {
+                    words: vec![word(0.0, "ab"), InlineWord::Space, InlineWord::Space, word(30.0, "cd")].into(),
+                    bounds: LogicalRect::new(LogicalPosition::new(0.0, 20.0), LogicalSize::new(50.0, 20.0)),
+                },
+                InlineLine {
+                    words: vec![word(0.0, "ef")].into(),
+                    bounds: LogicalRect::new(LogicalPosition::new(0.0, 40.0), LogicalSize::new(20.0, 20.0)),
+                },
+            ].into(),
+            content_size: LogicalSize::new(50.0, 40.0),
+            font_size_px: 20.0,
+            last_word_index: 5,
+            baseline_descender_px: 0.0,
+        };
+
+        let text = string_to_codepoints("ab  cd ef\n");
+        let layout = TextAreaLayout::from_inline_text(&text, &inline_text);
+
+        assert_eq!(layout.lines.len(), 3);
+        assert_eq!(layout.get_caret_rect(3), LogicalRect::new(LogicalPosition::new(25.0, 0.0), LogicalSize::new(0.0, 20.0)));
+        assert_eq!(layout.get_caret_rect(7), LogicalRect::new(LogicalPosition::new(0.0, 20.0), LogicalSize::new(0.0, 20.0)));
+        assert_eq!(layout.get_caret_rect(10), LogicalRect::new(LogicalPosition::new(0.0, 40.0), LogicalSize::new(0.0, 20.0)));
+
+        assert_eq!(layout.get_index_at(LogicalPosition::new(12.0, 25.0)), 8);
+        assert_eq!(layout.get_line_start(8), 7);
+        assert_eq!(layout.get_line_end(1), 6);
+        assert_eq!(layout.move_vertically(4, 30.0, 1), 9);
+
+        let rects = layout.get_selection_rects(1, 8, 50.0);
+        assert_eq!(rects[0], LogicalRect::new(LogicalPosition::new(10.0, 0.0), LogicalSize::new(40.0, 20.0)));
+        assert_eq!(rects[1].size.height, 0.0);
+        assert_eq!(rects[2], LogicalRect::new(LogicalPosition::new(0.0, 20.0), LogicalSize::new(10.0, 20.0)));
+    }
+
+    #[test]
+    fn scrolling_follows_the_cursor() {
+        // cursor below the viewport
+        assert_eq!(scroll_to_range(0.0, 100.0, 500.0, 140.0, 160.0), 60.0);
+        // cursor above the viewport
+        assert_eq!(scroll_to_range(60.0, 100.0, 500.0, 20.0, 40.0), 20.0);
+        // cursor visible
+        assert_eq!(scroll_to_range(60.0, 100.0, 500.0, 80.0, 100.0), 60.0);
+        // content smaller than the viewport
+        assert_eq!(scroll_to_range(30.0, 100.0, 50.0, 20.0, 40.0), 0.0);
+    }
+}
@@ -15,7 +15,7 @@ use azul_desktop::{
 use azul_core::{
     callbacks::{Animation, AnimationRepeatCount, InlineText, DomNodeId},
     task::SystemTimeDiff,
-    window::{KeyboardState, LogicalPosition, VirtualKeyCode},
+    window::{KeyboardState, LogicalPosition, VirtualKeyCode, ImePosition},
 };
 use alloc::vec::Vec;
 use alloc::string::String;
@@ -57,6 +57,36 @@ static TEXT_CURSOR_PROPS: &[NodeDataInlineCssProperty] = &[
     Normal(CssProperty::const_transform(StyleTransformVec::from_const_slice(TEXT_CURSOR_TRANSFORM))),
 ];
 
+// -- preedit style (text that is being composed with the input method)
+
+static TEXT_INPUT_PREEDIT_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_position(LayoutPosition::Absolute)),
+    Normal(CssProperty::const_top(LayoutTop::const_px(0))),
+    Normal(CssProperty::const_left(LayoutLeft::const_px(0))),
+    Normal(CssProperty::const_flex_direction(LayoutFlexDirection::Row)),
+    // underline of the preedit text
+    Normal(CssProperty::const_border_bottom_width(LayoutBorderBottomWidth::const_px(1))),
+    Normal(CssProperty::const_border_bottom_style(StyleBorderBottomStyle { inner: BorderStyle::Solid })),
+    Normal(CssProperty::const_border_bottom_color(StyleBorderBottomColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_opacity(StyleOpacity::const_new(0))),
+];
+
+static TEXT_INPUT_PREEDIT_TEXT_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_display(LayoutDisplay::InlineBlock)),
+    Normal(CssProperty::const_flex_grow(LayoutFlexGrow::const_new(0))),
+    Normal(CssProperty::const_font_size(StyleFontSize::const_px(11))),
+    Normal(CssProperty::const_text_color(StyleTextColor { inner: COLOR_4C4C4C })),
+    Normal(CssProperty::const_font_family(SANS_SERIF_FAMILY)),
+];
+
+static TEXT_INPUT_PREEDIT_CURSOR_PROPS: &[NodeDataInlineCssProperty] = &[
+    Normal(CssProperty::const_width(LayoutWidth::const_px(1))),
+    Normal(CssProperty::const_height(LayoutHeight::const_px(11))),
+    Normal(CssProperty::const_flex_shrink(LayoutFlexShrink::const_new(0))),
+    Normal(CssProperty::const_background_content(CURSOR_COLOR)),
+    Normal(CssProperty::const_transform(StyleTransformVec::from_const_slice(TEXT_CURSOR_TRANSFORM))),
+];
+
 // -- container style
 
 #[cfg(target_os = "windows")]
@@ -493,6 +523,7 @@ impl TextInput {
             CallbackData, EventFilter,
             HoverEventFilter, FocusEventFilter,
             IdOrClass::Class, TabIndex,
+            AccessibilityInfo, AccessibilityRole,
         };
 
         self.state.inner.cursor_pos = self.state.inner.text.len();
@@ -506,12 +537,20 @@ impl TextInput {
             .map(|s| s.as_str().to_string())
             .unwrap_or_default();
 
+        // the placeholder is the only hint about the purpose of the field
+        let mut accessibility_info = AccessibilityInfo::new(AccessibilityRole::Text)
+            .with_value(label_text.clone().into());
+        if !placeholder.is_empty() {
+            accessibility_info = accessibility_info.with_name(placeholder.clone().into());
+        }
+
         let state_ref = RefAny::new(self.state);
 
         Dom::div()
         .with_ids_and_classes(vec![Class("__azul-native-text-input-container".into())].into())
         .with_inline_css_props(self.container_style)
         .with_tab_index(TabIndex::Auto)
+        .with_accessibility_info(accessibility_info)
         .with_dataset(Some(state_ref.clone()).into())
         .with_callbacks(vec![
             CallbackData {
@@ -534,6 +573,21 @@ impl TextInput {
                 data: state_ref.clone(),
                 callback: Callback { cb: default_on_virtual_key_down }
             },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::CompositionStart),
+                data: state_ref.clone(),
+                callback: Callback { cb: default_on_composition_update }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::CompositionUpdate),
+                data: state_ref.clone(),
+                callback: Callback { cb: default_on_composition_update }
+            },
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::CompositionEnd),
+                data: state_ref.clone(),
+                callback: Callback { cb: default_on_composition_end }
+            },
             CallbackData {
                 event: EventFilter::Hover(HoverEventFilter::MouseOver),
                 data: state_ref.clone(),
@@ -550,7 +604,20 @@ impl TextInput {
             .with_children(vec![
                 Dom::div()
                 .with_ids_and_classes(vec![Class("__azul-native-text-input-cursor".into())].into())
-                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_CURSOR_PROPS))
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_CURSOR_PROPS)),
+                // preedit text: text before the preedit cursor, cursor, text after the cursor
+                Dom::div()
+                .with_ids_and_classes(vec![Class("__azul-native-text-input-preedit".into())].into())
+                .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_INPUT_PREEDIT_PROPS))
+                .with_children(vec![
+                    Dom::text(String::new())
+                    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_INPUT_PREEDIT_TEXT_PROPS)),
+                    Dom::div()
+                    .with_ids_and_classes(vec![Class("__azul-native-text-input-preedit-cursor".into())].into())
+                    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_INPUT_PREEDIT_CURSOR_PROPS)),
+                    Dom::text(String::new())
+                    .with_inline_css_props(NodeDataInlineCssPropertyVec::from_const_slice(TEXT_INPUT_PREEDIT_TEXT_PROPS)),
+                ].into()),
             ].into())
         ].into())
     }
@@ -584,6 +651,12 @@ fn default_on_focus_received(
 
     text_input.inner.cursor_pos = text_input.inner.text.len();
 
+    // the candidate window of the input method opens at the end of the text
+    if let Some(label_node_id) = info.get_next_sibling(placeholder_text_node_id) {
+        let text_width = get_text_width(info, label_node_id);
+        set_ime_position(info, label_node_id, text_width);
+    }
+
     Update::DoNothing
 }
 
@@ -733,6 +806,99 @@ fn default_on_virtual_key_down_inner(
     None
 }
 
+extern "C"
+fn default_on_composition_update(
+    _: &mut RefAny,
+    info: &mut CallbackInfo
+) -> Update {
+    default_on_composition_update_inner(info)
+    .unwrap_or(Update::DoNothing)
+}
+
+fn default_on_composition_update_inner(info: &mut CallbackInfo) -> Option<Update> {
+    let keyboard_state = info.get_current_keyboard_state();
+
+    let composition = keyboard_state.ime_composition.into_option()?;
+    let placeholder_node_id = info.get_first_child(info.get_hit_node())?;
+    let label_node_id = info.get_next_sibling(placeholder_node_id)?;
+    let cursor_node_id = info.get_first_child(label_node_id)?;
+    let preedit_node_id = info.get_next_sibling(cursor_node_id)?;
+    let preedit_before_node_id = info.get_first_child(preedit_node_id)?;
+    let preedit_cursor_node_id = info.get_next_sibling(preedit_before_node_id)?;
+    let preedit_after_node_id = info.get_next_sibling(preedit_cursor_node_id)?;
+
+    let (before, after) = split_preedit(composition.text.as_str(), composition.cursor);
+    let text_width = get_text_width(info, label_node_id);
+
+    // the preedit text is not part of the text yet, it is shown after the end of the text
+    info.set_string_contents(preedit_before_node_id, before.into());
+    info.set_string_contents(preedit_after_node_id, after.into());
+    info.set_css_property(preedit_node_id, CssProperty::const_left(LayoutLeft::px(text_width)));
+    info.set_css_property(
+        preedit_node_id,
+        CssProperty::const_opacity(StyleOpacity::const_new(100))
+    );
+
+    set_ime_position(info, label_node_id, text_width);
+
+    Some(Update::DoNothing)
+}
+
+extern "C"
+fn default_on_composition_end(
+    _: &mut RefAny,
+    info: &mut CallbackInfo
+) -> Update {
+    default_on_composition_end_inner(info)
+    .unwrap_or(Update::DoNothing)
+}
+
+fn default_on_composition_end_inner(info: &mut CallbackInfo) -> Option<Update> {
+    let placeholder_node_id = info.get_first_child(info.get_hit_node())?;
+    let label_node_id = info.get_next_sibling(placeholder_node_id)?;
+    let cursor_node_id = info.get_first_child(label_node_id)?;
+    let preedit_node_id = info.get_next_sibling(cursor_node_id)?;
+    let preedit_before_node_id = info.get_first_child(preedit_node_id)?;
+    let preedit_cursor_node_id = info.get_next_sibling(preedit_before_node_id)?;
+    let preedit_after_node_id = info.get_next_sibling(preedit_cursor_node_id)?;
+
+    // hide the preedit text, the committed text arrives as TextInput events
+    info.set_css_property(
+        preedit_node_id,
+        CssProperty::const_opacity(StyleOpacity::const_new(0))
+    );
+    info.set_string_contents(preedit_before_node_id, String::new().into());
+    info.set_string_contents(preedit_after_node_id, String::new().into());
+
+    Some(Update::DoNothing)
+}
+
+/// Splits the preedit text at the position of the preedit cursor (in characters)
+fn split_preedit(text: &str, cursor: usize) -> (String, String) {
+    let split = text.char_indices().nth(cursor).map(|(i, _)| i).unwrap_or(text.len());
+    (text[..split].to_string(), text[split..].to_string())
+}
+
+/// Width of the laid out text of the label, `0.0` if the label is empty
+fn get_text_width(info: &CallbackInfo, label_node_id: DomNodeId) -> f32 {
+    info.get_inline_text(label_node_id)
+    .and_then(|t| t.lines.as_ref().last().map(|l| l.bounds.origin.x + l.bounds.size.width))
+    .unwrap_or(0.0)
+}
+
+/// Moves the candidate window of the input method below the end of the text
+fn set_ime_position(info: &mut CallbackInfo, label_node_id: DomNodeId, text_width: f32) -> Option<()> {
+    let label_position = info.get_node_position(label_node_id)?.get_static_offset();
+    let label_size = info.get_node_size(label_node_id)?;
+    let mut window_state = info.get_current_window_state();
+    window_state.ime_position = ImePosition::Initialized(LogicalPosition::new(
+        label_position.x + text_width,
+        label_position.y + label_size.height,
+    ));
+    info.set_window_state(window_state);
+    Some(())
+}
+
 extern "C"
 fn default_on_mouse_hover(
   text_input: &mut RefAny,
@@ -747,4 +913,17 @@ fn default_on_mouse_hover(
     // println!("default_on_mouse_hover");
 
     Update::DoNothing
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod text_input_test {
+
+    use super::*;
+
+    #[test]
+    fn preedit_is_split_at_the_cursor() {
+        assert_eq!(split_preedit("にほんご", 2), ("にほ".to_string(), "んご".to_string()));
+        assert_eq!(split_preedit("にほんご", 0), (String::new(), "にほんご".to_string()));
+        assert_eq!(split_preedit("にほんご", 10), ("にほんご".to_string(), String::new()));
+    }
+}
@@ -7,10 +7,14 @@ use azul_desktop::{
         NodeDataInlineCssPropertyVec,
         IdOrClassVec, DomVec,
         NodeDataInlineCssProperty,
-        NodeDataInlineCssProperty::{Normal, Active, Hover, Focus},
     },
-    resources::{ImageRef, OptionImageRef},
-    callbacks::{RefAny, Update, CallbackInfo},
+    resources::OptionImageRef,
+    callbacks::{RefAny, Update, CallbackInfo, Callback},
+};
+use azul_core::{
+    callbacks::FocusTarget,
+    task::{Instant, OptionInstant, Duration, SystemTimeDiff},
+    window::VirtualKeyCode,
 };
 use std::vec::Vec;
 
@@ -25,9 +29,6 @@ const STYLE_BACKGROUND_CONTENT_3386545019168565479_ITEMS: &[StyleBackgroundConte
         stops: NormalizedLinearColorStopVec::from_const_slice(LINEAR_COLOR_STOP_8524009933333352376_ITEMS),
     })
 ];
-const STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS: &[StyleBackgroundContent] = &[
-    StyleBackgroundContent::Color(ColorU { r: 240, g: 240, b: 240, a: 255 })
-];
 const STYLE_BACKGROUND_CONTENT_15987977139837592998_ITEMS: &[StyleBackgroundContent] = &[
     StyleBackgroundContent::Color(ColorU { r: 0, g: 0, b: 0, a: 255 })
 ];
@@ -68,24 +69,6 @@ const CSS_MATCH_11045010670475678001_PROPERTIES: &[NodeDataInlineCssProperty] =
 ];
 const CSS_MATCH_11045010670475678001: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_11045010670475678001_PROPERTIES);    
 
-const CSS_MATCH_1250869685159433269_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-cross-content-2
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(8) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(8) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
-];
-const CSS_MATCH_1250869685159433269: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_1250869685159433269_PROPERTIES);    
-
 const CSS_MATCH_13401060217940352039_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-view
     NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
@@ -158,17 +141,6 @@ const CSS_MATCH_14249021884908901216_PROPERTIES: &[NodeDataInlineCssProperty] =
 ];
 const CSS_MATCH_14249021884908901216: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14249021884908901216_PROPERTIES);    
 
-const CSS_MATCH_14455923367901630186_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-space-1-filled
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(8) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block)))
-];
-const CSS_MATCH_14455923367901630186: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_14455923367901630186_PROPERTIES);    
-
 const CSS_MATCH_15054086665198995512_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-view-row-label-focusable-wrapper.focused
     NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
@@ -232,38 +204,6 @@ const CSS_MATCH_17035174955428217627_PROPERTIES: &[NodeDataInlineCssProperty] =
 ];
 const CSS_MATCH_17035174955428217627: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17035174955428217627_PROPERTIES);    
 
-const CSS_MATCH_17631951240816806439_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-space-1
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) })))
-];
-const CSS_MATCH_17631951240816806439: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17631951240816806439_PROPERTIES);    
-
-const CSS_MATCH_17932671798964167701_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-space-1-filled-content
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
-];
-const CSS_MATCH_17932671798964167701: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_17932671798964167701_PROPERTIES);    
-
-const CSS_MATCH_2883986488332352590_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // body
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(5) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_11062356617965867290_ITEMS))))
-];
-const CSS_MATCH_2883986488332352590: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_2883986488332352590_PROPERTIES);    
-
 const CSS_MATCH_2919526787497691572_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-view-row
     NodeDataInlineCssProperty::Normal(CssProperty::FlexDirection(LayoutFlexDirectionValue::Exact(LayoutFlexDirection::Row)))
@@ -337,17 +277,6 @@ const CSS_MATCH_6438488809014395635_PROPERTIES: &[NodeDataInlineCssProperty] = &
 ];
 const CSS_MATCH_6438488809014395635: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6438488809014395635_PROPERTIES);    
 
-const CSS_MATCH_6621536559891676126_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-cross
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Relative))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block)))
-];
-const CSS_MATCH_6621536559891676126: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_6621536559891676126_PROPERTIES);    
-
 const CSS_MATCH_8394859448076413888_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-minus
     NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
@@ -356,24 +285,6 @@ const CSS_MATCH_8394859448076413888_PROPERTIES: &[NodeDataInlineCssProperty] = &
 ];
 const CSS_MATCH_8394859448076413888: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_8394859448076413888_PROPERTIES);    
 
-const CSS_MATCH_9438342815980407130_PROPERTIES: &[NodeDataInlineCssProperty] = &[
-    // .__azul-native-tree-cross-content-1
-    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowY(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::OverflowX(LayoutOverflowValue::Exact(LayoutOverflow::Visible))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(0) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(9) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
-    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } })))
-];
-const CSS_MATCH_9438342815980407130: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_9438342815980407130_PROPERTIES);    
-
 const CSS_MATCH_9496626968151854549_PROPERTIES: &[NodeDataInlineCssProperty] = &[
     // .__azul-native-tree-l
     NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
@@ -394,15 +305,530 @@ const CSS_MATCH_9703015952013196920_PROPERTIES: &[NodeDataInlineCssProperty] = &
 ];
 const CSS_MATCH_9703015952013196920: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_9703015952013196920_PROPERTIES);
 
-#[derive(Default)]
+const CSS_MATCH_TREE_VIEW_SPACE_PROPERTIES: &[NodeDataInlineCssProperty] = &[
+    // .__azul-native-tree-space
+    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(18) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(18) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block)))
+];
+const CSS_MATCH_TREE_VIEW_SPACE: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_TREE_VIEW_SPACE_PROPERTIES);
+
+const CSS_MATCH_TREE_VIEW_PLUS_ICON_PROPERTIES: &[NodeDataInlineCssProperty] = &[
+    // .__azul-native-tree-plus-icon
+    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Top(LayoutTopValue::Exact(LayoutTop { inner: PixelValue::const_px(2) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Position(LayoutPositionValue::Exact(LayoutPosition::Absolute))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Left(LayoutLeftValue::Exact(LayoutLeft { inner: PixelValue::const_px(3) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(5) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_15987977139837592998_ITEMS))))
+];
+const CSS_MATCH_TREE_VIEW_PLUS_ICON: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_TREE_VIEW_PLUS_ICON_PROPERTIES);
+
+const CSS_MATCH_TREE_VIEW_ROW_ICON_PROPERTIES: &[NodeDataInlineCssProperty] = &[
+    // .__azul-native-tree-view-row-icon
+    NodeDataInlineCssProperty::Normal(CssProperty::Width(LayoutWidthValue::Exact(LayoutWidth { inner: PixelValue::const_px(16) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Height(LayoutHeightValue::Exact(LayoutHeight { inner: PixelValue::const_px(16) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::MarginTop(LayoutMarginTopValue::Exact(LayoutMarginTop { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::MarginLeft(LayoutMarginLeftValue::Exact(LayoutMarginLeft { inner: PixelValue::const_px(2) })))
+];
+const CSS_MATCH_TREE_VIEW_ROW_ICON: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_TREE_VIEW_ROW_ICON_PROPERTIES);
+
+const CSS_MATCH_TREE_VIEW_ROW_LABEL_SELECTED_PROPERTIES: &[NodeDataInlineCssProperty] = &[
+    // .__azul-native-tree-view-row-label-focusable-wrapper.selected
+    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16215943235627030128_ITEMS)))),
+    // .__azul-native-tree-view-row-label-focusable-wrapper:hover
+    NodeDataInlineCssProperty::Hover(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16215943235627030128_ITEMS)))),
+    // .__azul-native-tree-view-row-label-focusable-wrapper
+    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } })))
+];
+const CSS_MATCH_TREE_VIEW_ROW_LABEL_SELECTED: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_TREE_VIEW_ROW_LABEL_SELECTED_PROPERTIES);
+
+const CSS_MATCH_TREE_VIEW_ROW_LABEL_SELECTED_FOCUSED_PROPERTIES: &[NodeDataInlineCssProperty] = &[
+    // .__azul-native-tree-view-row-label-focusable-wrapper.selected
+    NodeDataInlineCssProperty::Normal(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16215943235627030128_ITEMS)))),
+    // .__azul-native-tree-view-row-label-focusable-wrapper.focused
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 0, g: 0, b: 0, a: 255 } }))),
+    // .__azul-native-tree-view-row-label-focusable-wrapper:hover
+    NodeDataInlineCssProperty::Hover(CssProperty::TextColor(StyleTextColorValue::Exact(StyleTextColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Dotted }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 255 } }))),
+    NodeDataInlineCssProperty::Hover(CssProperty::BackgroundContent(StyleBackgroundContentVecValue::Exact(StyleBackgroundContentVec::from_const_slice(STYLE_BACKGROUND_CONTENT_16215943235627030128_ITEMS)))),
+    // .__azul-native-tree-view-row-label-focusable-wrapper
+    NodeDataInlineCssProperty::Normal(CssProperty::PaddingRight(LayoutPaddingRightValue::Exact(LayoutPaddingRight { inner: PixelValue::const_px(2) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::PaddingLeft(LayoutPaddingLeftValue::Exact(LayoutPaddingLeft { inner: PixelValue::const_px(2) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::PaddingBottom(LayoutPaddingBottomValue::Exact(LayoutPaddingBottom { inner: PixelValue::const_px(0) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::PaddingTop(LayoutPaddingTopValue::Exact(LayoutPaddingTop { inner: PixelValue::const_px(0) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::Display(LayoutDisplayValue::Exact(LayoutDisplay::Block))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomWidth(LayoutBorderBottomWidthValue::Exact(LayoutBorderBottomWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftWidth(LayoutBorderLeftWidthValue::Exact(LayoutBorderLeftWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightWidth(LayoutBorderRightWidthValue::Exact(LayoutBorderRightWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopWidth(LayoutBorderTopWidthValue::Exact(LayoutBorderTopWidth { inner: PixelValue::const_px(1) }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomStyle(StyleBorderBottomStyleValue::Exact(StyleBorderBottomStyle { inner: BorderStyle::Solid }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftStyle(StyleBorderLeftStyleValue::Exact(StyleBorderLeftStyle { inner: BorderStyle::Solid }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightStyle(StyleBorderRightStyleValue::Exact(StyleBorderRightStyle { inner: BorderStyle::Solid }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopStyle(StyleBorderTopStyleValue::Exact(StyleBorderTopStyle { inner: BorderStyle::Solid }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderBottomColor(StyleBorderBottomColorValue::Exact(StyleBorderBottomColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderLeftColor(StyleBorderLeftColorValue::Exact(StyleBorderLeftColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderRightColor(StyleBorderRightColorValue::Exact(StyleBorderRightColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } }))),
+    NodeDataInlineCssProperty::Normal(CssProperty::BorderTopColor(StyleBorderTopColorValue::Exact(StyleBorderTopColor { inner: ColorU { r: 255, g: 255, b: 255, a: 0 } })))
+];
+const CSS_MATCH_TREE_VIEW_ROW_LABEL_SELECTED_FOCUSED: NodeDataInlineCssPropertyVec = NodeDataInlineCssPropertyVec::from_const_slice(CSS_MATCH_TREE_VIEW_ROW_LABEL_SELECTED_FOCUSED_PROPERTIES);
+
+
+const IDS_AND_CLASSES_TREE_VIEW_CONTAINER: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view")),
+];
+const TREE_VIEW_CONTAINER_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_TREE_VIEW_CONTAINER);
+
+const IDS_AND_CLASSES_ROW: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row")),
+];
+const ROW_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ROW);
+
+const IDS_AND_CLASSES_SPACE: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-space")),
+];
+const SPACE_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_SPACE);
+
+const IDS_AND_CLASSES_PIPE_DOWN: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-pipe-down")),
+];
+const PIPE_DOWN_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_PIPE_DOWN);
+
+const IDS_AND_CLASSES_PIPE_DOWN_CONTENT: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-pipe-down-content")),
+];
+const PIPE_DOWN_CONTENT_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_PIPE_DOWN_CONTENT);
+
+const IDS_AND_CLASSES_T: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-t")),
+];
+const T_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_T);
+
+const IDS_AND_CLASSES_T_CONTENT: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-t-content")),
+];
+const T_CONTENT_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_T_CONTENT);
+
+const IDS_AND_CLASSES_T_CONTENT_MINUS: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-t-content-minus")),
+];
+const T_CONTENT_MINUS_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_T_CONTENT_MINUS);
+
+const IDS_AND_CLASSES_L: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-l")),
+];
+const L_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_L);
+
+const IDS_AND_CLASSES_L_CONTENT: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-l-content")),
+];
+const L_CONTENT_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_L_CONTENT);
+
+const IDS_AND_CLASSES_MINUS: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-minus")),
+];
+const MINUS_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_MINUS);
+
+const IDS_AND_CLASSES_MINUS_CONTENT: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-minus-content")),
+];
+const MINUS_CONTENT_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_MINUS_CONTENT);
+
+const IDS_AND_CLASSES_MINUS_ICON: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-minus-icon")),
+];
+const MINUS_ICON_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_MINUS_ICON);
+
+const IDS_AND_CLASSES_PLUS_ICON: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-plus-icon")),
+];
+const PLUS_ICON_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_PLUS_ICON);
+
+const IDS_AND_CLASSES_ROW_ICON: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row-icon")),
+];
+const ROW_ICON_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ROW_ICON);
+
+const IDS_AND_CLASSES_ROW_LABEL: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
+];
+const ROW_LABEL_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ROW_LABEL);
+
+const IDS_AND_CLASSES_ROW_LABEL_WRAPPER: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
+];
+const ROW_LABEL_WRAPPER_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ROW_LABEL_WRAPPER);
+
+const IDS_AND_CLASSES_ROW_LABEL_WRAPPER_FOCUSED: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
+    Class(AzString::from_const_str("focused")),
+];
+const ROW_LABEL_WRAPPER_FOCUSED_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ROW_LABEL_WRAPPER_FOCUSED);
+
+const IDS_AND_CLASSES_ROW_LABEL_WRAPPER_SELECTED: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
+    Class(AzString::from_const_str("selected")),
+];
+const ROW_LABEL_WRAPPER_SELECTED_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ROW_LABEL_WRAPPER_SELECTED);
+
+const IDS_AND_CLASSES_ROW_LABEL_WRAPPER_SELECTED_FOCUSED: &[IdOrClass] = &[
+    Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
+    Class(AzString::from_const_str("selected")),
+    Class(AzString::from_const_str("focused")),
+];
+const ROW_LABEL_WRAPPER_SELECTED_FOCUSED_CLASS: IdOrClassVec = IdOrClassVec::from_const_slice(IDS_AND_CLASSES_ROW_LABEL_WRAPPER_SELECTED_FOCUSED);
+
+/// Identifier of a node in the `TreeView`, chosen by the application
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct TreeViewNodeId {
+    pub inner: u64
+}
+
+impl_option!(TreeViewNodeId, OptionTreeViewNodeId, [Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash]);
+
+impl_vec!(TreeViewNodeId, TreeViewNodeIdVec, TreeViewNodeIdVecDestructor);
+impl_vec_clone!(TreeViewNodeId, TreeViewNodeIdVec, TreeViewNodeIdVecDestructor);
+impl_vec_mut!(TreeViewNodeId, TreeViewNodeIdVec);
+impl_vec_debug!(TreeViewNodeId, TreeViewNodeIdVec);
+impl_vec_partialeq!(TreeViewNodeId, TreeViewNodeIdVec);
+
+/// Single node of the `TreeView`. The tree is stored as a flat list
+/// of nodes, each node pointing to its parent: the order of the nodes
+/// in the list is the order of the siblings on screen.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct TreeViewNode {
+    /// Unique ID of this node
+    pub id: TreeViewNodeId,
+    /// Parent node, `None` for top-level nodes. Nodes whose parent
+    /// does not exist in the node list are not shown
+    pub parent: OptionTreeViewNodeId,
+    /// Text of the node
+    pub label: AzString,
+    /// Optional 16x16 icon, drawn in front of the label
+    pub icon: OptionImageRef,
+    /// Whether the node can be expanded. Set this to `true` if the
+    /// children of this node have not been loaded yet: the application
+    /// can then load the children in the `on_expand` callback
+    pub has_children: bool,
+}
+
+impl_vec!(TreeViewNode, TreeViewNodeVec, TreeViewNodeVecDestructor);
+impl_vec_clone!(TreeViewNode, TreeViewNodeVec, TreeViewNodeVecDestructor);
+impl_vec_mut!(TreeViewNode, TreeViewNodeVec);
+impl_vec_debug!(TreeViewNode, TreeViewNodeVec);
+
+impl TreeViewNode {
+    pub fn new(id: TreeViewNodeId, parent: OptionTreeViewNodeId, label: AzString) -> Self {
+        Self {
+            id,
+            parent,
+            label,
+            icon: None.into(),
+            has_children: false,
+        }
+    }
+}
+
+/// Whether the user can select one or multiple nodes at once
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub enum TreeViewSelectionMode {
+    Single,
+    /// Allows selecting multiple nodes with Ctrl+Click and Shift+Click
+    Multiple,
+}
+
+impl Default for TreeViewSelectionMode {
+    fn default() -> Self { TreeViewSelectionMode::Single }
+}
+
+/// Expand / collapse, selection and focus state of the `TreeView`.
+///
+/// The state is owned by the application: the callbacks of the `TreeView`
+/// get the new state as an argument, the application should store it and
+/// pass it back in via `TreeView::with_state` on the next `layout()` call.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct TreeViewState {
+    /// Nodes that are currently expanded
+    pub expanded: TreeViewNodeIdVec,
+    /// Nodes that are currently selected
+    pub selected: TreeViewNodeIdVec,
+    /// Node that has the keyboard focus (drawn with a dotted border)
+    pub focused: OptionTreeViewNodeId,
+    /// Node where the last range selection (Shift+Click) started
+    pub selection_anchor: OptionTreeViewNodeId,
+    /// Time of the last click on a node label, used to detect double clicks
+    pub last_click_time: OptionInstant,
+}
+
+impl Default for TreeViewState {
+    fn default() -> Self {
+        Self {
+            expanded: TreeViewNodeIdVec::from_const_slice(&[]),
+            selected: TreeViewNodeIdVec::from_const_slice(&[]),
+            focused: None.into(),
+            selection_anchor: None.into(),
+            last_click_time: None.into(),
+        }
+    }
+}
+
+impl TreeViewState {
+
+    pub fn is_expanded(&self, node: TreeViewNodeId) -> bool {
+        self.expanded.as_ref().contains(&node)
+    }
+
+    pub fn is_selected(&self, node: TreeViewNodeId) -> bool {
+        self.selected.as_ref().contains(&node)
+    }
+
+    pub fn set_expanded(&mut self, node: TreeViewNodeId, expanded: bool) {
+        let mut e = self.expanded.as_ref().to_vec();
+        e.retain(|n| *n != node);
+        if expanded {
+            e.push(node);
+        }
+        self.expanded = e.into();
+    }
+
+    fn select_single(&mut self, node: TreeViewNodeId) {
+        self.selected = vec![node].into();
+        self.selection_anchor = Some(node).into();
+        self.focused = Some(node).into();
+    }
+
+    fn toggle_selected(&mut self, node: TreeViewNodeId) {
+        let mut s = self.selected.as_ref().to_vec();
+        if s.contains(&node) {
+            s.retain(|n| *n != node);
+        } else {
+            s.push(node);
+        }
+        self.selected = s.into();
+        self.selection_anchor = Some(node).into();
+        self.focused = Some(node).into();
+    }
+
+    /// Selects all visible nodes between the selection anchor and `node`
+    fn select_range(&mut self, visible: &[TreeViewNodeId], node: TreeViewNodeId, keep_existing: bool) {
+        let anchor = self.selection_anchor.into_option().unwrap_or(node);
+        let (a, b) = match (
+            visible.iter().position(|n| *n == anchor),
+            visible.iter().position(|n| *n == node),
+        ) {
+            (Some(a), Some(b)) => (a.min(b), a.max(b)),
+            _ => { self.select_single(node); return; },
+        };
+
+        let mut s = if keep_existing { self.selected.as_ref().to_vec() } else { Vec::new() };
+        for n in visible[a..=b].iter() {
+            if !s.contains(n) {
+                s.push(*n);
+            }
+        }
+        self.selected = s.into();
+        self.selection_anchor = Some(anchor).into();
+        self.focused = Some(node).into();
+    }
+}
+
+/// What happened in response to a mouse click or a key press
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TreeViewEvent {
+    Select,
+    Expand(TreeViewNodeId, bool),
+    DoubleClick(TreeViewNodeId),
+    ContextMenu(TreeViewNodeId),
+}
+
+/// Visible row of the `TreeView`, in screen order
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TreeViewRow {
+    /// Index into `TreeView::nodes`
+    node_idx: usize,
+    /// Whether the parent of the node at each depth of the path
+    /// to this node has a next sibling, i.e. whether a vertical
+    /// line has to be drawn in that column. The last item refers
+    /// to the node itself.
+    has_next_sibling: Vec<bool>,
+}
+
+impl TreeViewRow {
+    fn depth(&self) -> usize { self.has_next_sibling.len().saturating_sub(1) }
+}
+
+/// Flattens the tree into the list of rows that are currently visible,
+/// i.e. all top-level nodes and the children of expanded nodes
+fn get_visible_rows(nodes: &[TreeViewNode], state: &TreeViewState) -> Vec<TreeViewRow> {
+
+    use alloc::collections::{BTreeMap, BTreeSet};
+
+    let mut children = BTreeMap::<Option<TreeViewNodeId>, Vec<usize>>::new();
+    for (idx, node) in nodes.iter().enumerate() {
+        children.entry(node.parent.into_option()).or_default().push(idx);
+    }
+
+    let mut rows = Vec::new();
+    // guard against cycles and duplicate ids in the node list
+    let mut visited = BTreeSet::new();
+    // (index of node, has_next_sibling of the parent chain)
+    let mut stack = Vec::new();
+
+    let push_children = |stack: &mut Vec<(usize, Vec<bool>)>, parent: Option<TreeViewNodeId>, path: &[bool]| {
+        if let Some(c) = children.get(&parent) {
+            for (i, idx) in c.iter().enumerate().rev() {
+                let mut p = path.to_vec();
+                p.push(i + 1 != c.len());
+                stack.push((*idx, p));
+            }
+        }
+    };
+
+    push_children(&mut stack, None, &[]);
+
+    while let Some((node_idx, has_next_sibling)) = stack.pop() {
+        let node = &nodes[node_idx];
+        if !visited.insert(node.id) {
+            continue;
+        }
+        if node.has_children && state.is_expanded(node.id) {
+            push_children(&mut stack, Some(node.id), &has_next_sibling);
+        }
+        rows.push(TreeViewRow { node_idx, has_next_sibling });
+    }
+
+    rows
+}
+
+/// Returns whether `node` is a (transitive) child of `ancestor`
+fn is_descendant_of(nodes: &[TreeViewNode], node: TreeViewNodeId, ancestor: TreeViewNodeId) -> bool {
+    let mut current = node;
+    // bounded by the node count in case the parent chain contains a cycle
+    for _ in 0..nodes.len() {
+        let parent = match nodes.iter().find(|n| n.id == current).and_then(|n| n.parent.into_option()) {
+            Some(s) => s,
+            None => return false,
+        };
+        if parent == ancestor {
+            return true;
+        }
+        current = parent;
+    }
+    false
+}
+
+pub type TreeViewOnExpandCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &TreeViewState, node: TreeViewNodeId, expanded: bool) -> Update;
+impl_callback!(TreeViewOnExpand, OptionTreeViewOnExpand, TreeViewOnExpandCallback, TreeViewOnExpandCallbackType);
+
+pub type TreeViewOnSelectCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &TreeViewState) -> Update;
+impl_callback!(TreeViewOnSelect, OptionTreeViewOnSelect, TreeViewOnSelectCallback, TreeViewOnSelectCallbackType);
+
+pub type TreeViewOnDoubleClickCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &TreeViewState, node: TreeViewNodeId) -> Update;
+impl_callback!(TreeViewOnDoubleClick, OptionTreeViewOnDoubleClick, TreeViewOnDoubleClickCallback, TreeViewOnDoubleClickCallbackType);
+
+pub type TreeViewOnContextMenuCallbackType = extern "C" fn(&mut RefAny, &mut CallbackInfo, &TreeViewState, node: TreeViewNodeId) -> Update;
+impl_callback!(TreeViewOnContextMenu, OptionTreeViewOnContextMenu, TreeViewOnContextMenuCallback, TreeViewOnContextMenuCallbackType);
+
+/// Tree view, for example for a file explorer
+#[derive(Debug, Clone)]
 #[repr(C)]
 pub struct TreeView {
-    pub root: AzString,
+    /// All nodes of the tree (see `TreeViewNode`)
+    pub nodes: TreeViewNodeVec,
+    /// Expanded / selected / focused nodes
+    pub state: TreeViewState,
+    /// Single or multi-selection (default = single)
+    pub selection_mode: TreeViewSelectionMode,
+    /// Called when a node gets expanded or collapsed (via the +/- box
+    /// or the Left / Right keys). Use this to lazily load the children
+    /// of the expanded node
+    pub on_expand: OptionTreeViewOnExpand,
+    /// Called when the selection or the focused node changes
+    pub on_select: OptionTreeViewOnSelect,
+    /// Called when the user double-clicks a node or presses Enter
+    pub on_double_click: OptionTreeViewOnDoubleClick,
+    /// Called when the user right-clicks a node or presses the menu key
+    pub on_context_menu: OptionTreeViewOnContextMenu,
+}
+
+impl Default for TreeView {
+    fn default() -> Self {
+        Self {
+            nodes: TreeViewNodeVec::from_const_slice(&[]),
+            state: TreeViewState::default(),
+            selection_mode: TreeViewSelectionMode::default(),
+            on_expand: None.into(),
+            on_select: None.into(),
+            on_double_click: None.into(),
+            on_context_menu: None.into(),
+        }
+    }
 }
 
 impl TreeView {
-    pub fn new(root: AzString) -> Self {
-        Self { root }
+
+    pub fn new(nodes: TreeViewNodeVec) -> Self {
+        Self {
+            nodes,
+            .. Default::default()
+        }
     }
 
     pub fn swap_with_default(&mut self) -> Self {
@@ -411,511 +837,720 @@ impl TreeView {
         m
     }
 
+    pub fn with_nodes(&mut self, nodes: TreeViewNodeVec) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_nodes(nodes);
+        m
+    }
+
+    pub fn set_nodes(&mut self, nodes: TreeViewNodeVec) {
+        self.nodes = nodes;
+    }
+
+    pub fn with_state(&mut self, state: TreeViewState) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_state(state);
+        m
+    }
+
+    pub fn set_state(&mut self, state: TreeViewState) {
+        self.state = state;
+    }
+
+    pub fn with_selection_mode(&mut self, selection_mode: TreeViewSelectionMode) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_selection_mode(selection_mode);
+        m
+    }
+
+    pub fn set_selection_mode(&mut self, selection_mode: TreeViewSelectionMode) {
+        self.selection_mode = selection_mode;
+    }
+
+    pub fn with_on_expand(&mut self, data: RefAny, on_expand: TreeViewOnExpandCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_expand(data, on_expand);
+        m
+    }
+
+    pub fn set_on_expand(&mut self, data: RefAny, on_expand: TreeViewOnExpandCallbackType) {
+        self.on_expand = Some(TreeViewOnExpand {
+            data,
+            callback: TreeViewOnExpandCallback { cb: on_expand }
+        }).into();
+    }
+
+    pub fn with_on_select(&mut self, data: RefAny, on_select: TreeViewOnSelectCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_select(data, on_select);
+        m
+    }
+
+    pub fn set_on_select(&mut self, data: RefAny, on_select: TreeViewOnSelectCallbackType) {
+        self.on_select = Some(TreeViewOnSelect {
+            data,
+            callback: TreeViewOnSelectCallback { cb: on_select }
+        }).into();
+    }
+
+    pub fn with_on_double_click(&mut self, data: RefAny, on_double_click: TreeViewOnDoubleClickCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_double_click(data, on_double_click);
+        m
+    }
+
+    pub fn set_on_double_click(&mut self, data: RefAny, on_double_click: TreeViewOnDoubleClickCallbackType) {
+        self.on_double_click = Some(TreeViewOnDoubleClick {
+            data,
+            callback: TreeViewOnDoubleClickCallback { cb: on_double_click }
+        }).into();
+    }
+
+    pub fn with_on_context_menu(&mut self, data: RefAny, on_context_menu: TreeViewOnContextMenuCallbackType) -> Self {
+        let mut m = self.swap_with_default();
+        m.set_on_context_menu(data, on_context_menu);
+        m
+    }
+
+    pub fn set_on_context_menu(&mut self, data: RefAny, on_context_menu: TreeViewOnContextMenuCallbackType) {
+        self.on_context_menu = Some(TreeViewOnContextMenu {
+            data,
+            callback: TreeViewOnContextMenuCallback { cb: on_context_menu }
+        }).into();
+    }
+
+    /// Returns the id, `has_children` and parent of a node
+    fn get_node_info(&self, id: TreeViewNodeId) -> Option<(TreeViewNodeId, bool, Option<TreeViewNodeId>)> {
+        self.nodes.as_ref().iter()
+        .find(|n| n.id == id)
+        .map(|n| (n.id, n.has_children, n.parent.into_option()))
+    }
+
+    fn get_visible_ids(&self) -> Vec<TreeViewNodeId> {
+        let nodes = self.nodes.as_ref();
+        get_visible_rows(nodes, &self.state)
+        .into_iter()
+        .map(|r| nodes[r.node_idx].id)
+        .collect()
+    }
+
+    /// Left click on the label of a node
+    fn handle_click(&mut self, node: TreeViewNodeId, shift: bool, ctrl: bool) -> TreeViewEvent {
+        match self.selection_mode {
+            TreeViewSelectionMode::Multiple if shift => {
+                let visible = self.get_visible_ids();
+                self.state.select_range(&visible, node, ctrl);
+            },
+            TreeViewSelectionMode::Multiple if ctrl => self.state.toggle_selected(node),
+            _ => self.state.select_single(node),
+        }
+        TreeViewEvent::Select
+    }
+
+    /// Right click on the label of a node: the context menu applies to the
+    /// selection if the node is selected, otherwise the node gets selected
+    fn handle_right_click(&mut self, node: TreeViewNodeId) -> TreeViewEvent {
+        if self.state.is_selected(node) {
+            self.state.focused = Some(node).into();
+        } else {
+            self.state.select_single(node);
+        }
+        TreeViewEvent::ContextMenu(node)
+    }
+
+    /// Click on the +/- box of a node
+    fn handle_toggle_expand(&mut self, node: TreeViewNodeId) -> TreeViewEvent {
+        let expand = !self.state.is_expanded(node);
+        self.state.set_expanded(node, expand);
+        // focus can't stay on a node that is now hidden
+        if !expand {
+            if let Some(f) = self.state.focused.into_option() {
+                if is_descendant_of(self.nodes.as_ref(), f, node) {
+                    self.state.focused = Some(node).into();
+                }
+            }
+        }
+        TreeViewEvent::Expand(node, expand)
+    }
+
+    /// Moves the focus to another node via the keyboard
+    fn move_focus(&mut self, visible: &[TreeViewNodeId], node: TreeViewNodeId, shift: bool, ctrl: bool) -> TreeViewEvent {
+        match self.selection_mode {
+            TreeViewSelectionMode::Multiple if shift => self.state.select_range(visible, node, ctrl),
+            // Ctrl + arrow moves the focus without changing the selection
+            TreeViewSelectionMode::Multiple if ctrl => self.state.focused = Some(node).into(),
+            _ => self.state.select_single(node),
+        }
+        TreeViewEvent::Select
+    }
+
+    fn handle_key(&mut self, key: VirtualKeyCode, shift: bool, ctrl: bool) -> Option<TreeViewEvent> {
+
+        let rows = get_visible_rows(self.nodes.as_ref(), &self.state);
+        let visible = rows.iter().map(|r| self.nodes.as_ref()[r.node_idx].id).collect::<Vec<_>>();
+        let last = visible.len().checked_sub(1)?;
+        let focused_idx = self.state.focused.into_option().and_then(|f| visible.iter().position(|n| *n == f));
+
+        let target = match (key, focused_idx) {
+            (VirtualKeyCode::Home, _) | (VirtualKeyCode::Down, None) => 0,
+            (VirtualKeyCode::End, _) | (VirtualKeyCode::Up, None) => last,
+            (VirtualKeyCode::Up, Some(i)) => i.saturating_sub(1),
+            (VirtualKeyCode::Down, Some(i)) => (i + 1).min(last),
+            (_, None) => return None,
+            (VirtualKeyCode::Left, Some(i)) => {
+                let (node_id, has_children, parent) = self.get_node_info(visible[i])?;
+                if has_children && self.state.is_expanded(node_id) {
+                    return Some(self.handle_toggle_expand(node_id));
+                }
+                let parent = parent?;
+                visible.iter().position(|n| *n == parent)?
+            },
+            (VirtualKeyCode::Right, Some(i)) => {
+                let (node_id, has_children, _) = self.get_node_info(visible[i])?;
+                if !has_children {
+                    return None;
+                }
+                if !self.state.is_expanded(node_id) {
+                    return Some(self.handle_toggle_expand(node_id));
+                }
+                // first child (if the children are already loaded)
+                match rows.get(i + 1) {
+                    Some(r) if r.depth() > rows[i].depth() => i + 1,
+                    _ => return None,
+                }
+            },
+            (VirtualKeyCode::Return, Some(i)) | (VirtualKeyCode::NumpadEnter, Some(i)) => {
+                return Some(TreeViewEvent::DoubleClick(visible[i]));
+            },
+            (VirtualKeyCode::Apps, Some(i)) => {
+                return Some(self.handle_right_click(visible[i]));
+            },
+            (VirtualKeyCode::Space, Some(i)) => {
+                match self.selection_mode {
+                    TreeViewSelectionMode::Multiple if ctrl => self.state.toggle_selected(visible[i]),
+                    _ => self.state.select_single(visible[i]),
+                }
+                return Some(TreeViewEvent::Select);
+            },
+            _ => return None,
+        };
+
+        if focused_idx == Some(target) && !shift {
+            return None;
+        }
+
+        Some(self.move_focus(&visible, visible[target], shift, ctrl))
+    }
+
+    /// Invokes the user callback belonging to the `event`
+    fn dispatch(&mut self, info: &mut CallbackInfo, event: TreeViewEvent) -> Update {
+        let state = &self.state;
+        match event {
+            TreeViewEvent::Select => match self.on_select.as_mut() {
+                Some(TreeViewOnSelect { data, callback }) => (callback.cb)(data, info, state),
+                None => Update::DoNothing,
+            },
+            TreeViewEvent::Expand(node, expanded) => match self.on_expand.as_mut() {
+                Some(TreeViewOnExpand { data, callback }) => (callback.cb)(data, info, state, node, expanded),
+                None => Update::DoNothing,
+            },
+            TreeViewEvent::DoubleClick(node) => match self.on_double_click.as_mut() {
+                Some(TreeViewOnDoubleClick { data, callback }) => (callback.cb)(data, info, state, node),
+                None => Update::DoNothing,
+            },
+            TreeViewEvent::ContextMenu(node) => match self.on_context_menu.as_mut() {
+                Some(TreeViewOnContextMenu { data, callback }) => (callback.cb)(data, info, state, node),
+                None => Update::DoNothing,
+            },
+        }
+    }
+
     pub fn dom(self) -> Dom {
-        Dom::div()
-        .with_inline_css_props(CSS_MATCH_13401060217940352039)
-        .with_ids_and_classes({
-            const IDS_AND_CLASSES_9837365222714915139: &[IdOrClass] = &[
-                    Class(AzString::from_const_str("__azul-native-tree-view")),
-
-            ];
-            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_9837365222714915139)
-        })
-        .with_children(DomVec::from_vec(vec![
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
-
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_8394859448076413888)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_5562274544924627603: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-minus")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5562274544924627603)
-                })
-                .with_children(DomVec::from_vec(vec![
+
+        use azul_desktop::dom::{
+            AccessibilityInfo, AccessibilityRole,
+            AccessibilityState, AccessibilityStateVec,
+            AccessibilityAction, AccessibilityActionVec,
+            CallbackData, EventFilter, HoverEventFilter, FocusEventFilter,
+        };
+
+        let rows = get_visible_rows(self.nodes.as_ref(), &self.state);
+        let multiple = self.selection_mode == TreeViewSelectionMode::Multiple;
+
+        // shared between all rows, so that the rows can update the
+        // state before the application re-renders the TreeView
+        let tree = RefAny::new(self.clone());
+
+        let row_doms = rows.iter().map(|row| {
+
+            let node = &self.nodes.as_ref()[row.node_idx];
+            let depth = row.depth();
+            let is_expanded = node.has_children && self.state.is_expanded(node.id);
+            let is_selected = self.state.is_selected(node.id);
+            let is_focused = self.state.focused.into_option() == Some(node.id);
+
+            let row_dataset = RefAny::new(TreeViewRowLocalDataset {
+                node: node.id,
+                tree: tree.clone(),
+            });
+
+            let mut cells = Vec::with_capacity(depth + 3);
+
+            // vertical lines of the parent levels
+            for has_next_sibling in row.has_next_sibling[..depth].iter() {
+                cells.push(if *has_next_sibling {
                     Dom::div()
-                    .with_inline_css_props(CSS_MATCH_6438488809014395635)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_15170138310983987150: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-minus-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15170138310983987150)
-                    })
-                    .with_children(DomVec::from_vec(vec![
+                    .with_inline_css_props(CSS_MATCH_13463400830017583629)
+                    .with_ids_and_classes(PIPE_DOWN_CLASS)
+                    .with_child(
+                        Dom::div()
+                        .with_inline_css_props(CSS_MATCH_5748554468056235124)
+                        .with_ids_and_classes(PIPE_DOWN_CONTENT_CLASS)
+                    )
+                } else {
+                    Dom::div()
+                    .with_inline_css_props(CSS_MATCH_TREE_VIEW_SPACE)
+                    .with_ids_and_classes(SPACE_CLASS)
+                });
+            }
+
+            if node.has_children {
+                // +/- box
+                let (icon_children, expand_action) = if is_expanded {
+                    (vec![
                         Dom::div()
                         .with_inline_css_props(CSS_MATCH_11045010670475678001)
-                        .with_ids_and_classes({
-                            const IDS_AND_CLASSES_276637619792188049: &[IdOrClass] = &[
-                                                    Class(AzString::from_const_str("__azul-native-tree-minus-icon")),
+                        .with_ids_and_classes(MINUS_ICON_CLASS)
+                    ], AccessibilityAction::Collapse)
+                } else {
+                    (vec![
+                        Dom::div()
+                        .with_inline_css_props(CSS_MATCH_11045010670475678001)
+                        .with_ids_and_classes(MINUS_ICON_CLASS),
+                        Dom::div()
+                        .with_inline_css_props(CSS_MATCH_TREE_VIEW_PLUS_ICON)
+                        .with_ids_and_classes(PLUS_ICON_CLASS),
+                    ], AccessibilityAction::Expand)
+                };
 
-                            ];
-                            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_276637619792188049)
-                        })
-                    ]))
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14249021884908901216)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12039918700145849527: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12039918700145849527)
-                    })
-                        .with_tab_index(TabIndex::Auto)
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
-                    ]))
-                ]))
-            ])),
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
-
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_13463400830017583629)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_8562870525116426737: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-pipe-down")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_8562870525116426737)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_5748554468056235124)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12623539011723615844: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-pipe-down-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12623539011723615844)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_17631951240816806439)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_13969147764958421470: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-space-1")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_13969147764958421470)
-                }),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_9703015952013196920)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_12683940372377849649: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-t")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12683940372377849649)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17035174955428217627)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6948782902341484076: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6948782902341484076)
-                    }),
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_10250347571702901767)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_7986348685827112423: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content-minus")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7986348685827112423)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_15054086665198995512)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_966274871881623987: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-                        Class(AzString::from_const_str("focused")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_966274871881623987)
-                    })
-                        .with_tab_index(TabIndex::Auto)
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
-                    ]))
-                ]))
-            ])),
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
-
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_9703015952013196920)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_12683940372377849649: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-t")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12683940372377849649)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17035174955428217627)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6948782902341484076: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6948782902341484076)
-                    }),
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_10250347571702901767)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_7986348685827112423: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content-minus")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7986348685827112423)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_14455923367901630186)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_2250273140132504407: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-space-1-filled")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2250273140132504407)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17932671798964167701)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_11324832106902074912: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-space-1-filled-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11324832106902074912)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_6621536559891676126)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_3445414501074686586: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-cross")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_3445414501074686586)
-                })
-                .with_children(DomVec::from_vec(vec![
+                cells.push(
                     Dom::div()
-                    .with_inline_css_props(CSS_MATCH_9438342815980407130)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_1166576799478159097: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-cross-content-1")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_1166576799478159097)
-                    }),
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_1250869685159433269)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_5610655148321459708: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-cross-content-2")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5610655148321459708)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
-                .with_children(DomVec::from_vec(vec![
+                    .with_inline_css_props(CSS_MATCH_8394859448076413888)
+                    .with_ids_and_classes(MINUS_CLASS)
+                    .with_callbacks(vec![
+                        CallbackData {
+                            event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                            callback: Callback { cb: on_tree_view_expander_click },
+                            data: row_dataset.clone(),
+                        }
+                    ].into())
+                    .with_accessibility_actions(AccessibilityActionVec::from_vec(vec![expand_action]))
+                    .with_child(
+                        Dom::div()
+                        .with_inline_css_props(CSS_MATCH_6438488809014395635)
+                        .with_ids_and_classes(MINUS_CONTENT_CLASS)
+                        .with_children(icon_children.into())
+                    )
+                );
+            } else if row.has_next_sibling[depth] {
+                // ├
+                cells.push(
                     Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14249021884908901216)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12039918700145849527: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12039918700145849527)
-                    })
-                        .with_tab_index(TabIndex::Auto)
+                    .with_inline_css_props(CSS_MATCH_9703015952013196920)
+                    .with_ids_and_classes(T_CLASS)
                     .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
+                        Dom::div()
+                        .with_inline_css_props(CSS_MATCH_17035174955428217627)
+                        .with_ids_and_classes(T_CONTENT_CLASS),
+                        Dom::div()
+                        .with_inline_css_props(CSS_MATCH_10250347571702901767)
+                        .with_ids_and_classes(T_CONTENT_MINUS_CLASS),
                     ]))
-                ]))
-            ])),
-            Dom::div()
-            .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
-
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_9703015952013196920)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_12683940372377849649: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-t")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12683940372377849649)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17035174955428217627)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_6948782902341484076: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_6948782902341484076)
-                    }),
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_10250347571702901767)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_7986348685827112423: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-t-content-minus")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7986348685827112423)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_14455923367901630186)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_2250273140132504407: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-space-1-filled")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2250273140132504407)
-                })
-                .with_children(DomVec::from_vec(vec![
+                );
+            } else {
+                // └
+                cells.push(
                     Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17932671798964167701)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_11324832106902074912: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-space-1-filled-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11324832106902074912)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_8394859448076413888)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_5562274544924627603: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-minus")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5562274544924627603)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_6438488809014395635)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_15170138310983987150: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-minus-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15170138310983987150)
-                    })
-                    .with_children(DomVec::from_vec(vec![
+                    .with_inline_css_props(CSS_MATCH_9496626968151854549)
+                    .with_ids_and_classes(L_CLASS)
+                    .with_child(
                         Dom::div()
-                        .with_inline_css_props(CSS_MATCH_11045010670475678001)
-                        .with_ids_and_classes({
-                            const IDS_AND_CLASSES_276637619792188049: &[IdOrClass] = &[
-                                                    Class(AzString::from_const_str("__azul-native-tree-minus-icon")),
-
-                            ];
-                            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_276637619792188049)
-                        })
-                    ]))
-                ])),
+                        .with_inline_css_props(CSS_MATCH_5479296065075700509)
+                        .with_ids_and_classes(L_CONTENT_CLASS)
+                    )
+                );
+            }
+
+            if let Some(icon) = node.icon.as_ref() {
+                cells.push(
+                    Dom::image(icon.clone())
+                    .with_inline_css_props(CSS_MATCH_TREE_VIEW_ROW_ICON)
+                    .with_ids_and_classes(ROW_ICON_CLASS)
+                );
+            }
+
+            let (wrapper_css, wrapper_classes) = match (is_selected, is_focused) {
+                (true, true) => (CSS_MATCH_TREE_VIEW_ROW_LABEL_SELECTED_FOCUSED, ROW_LABEL_WRAPPER_SELECTED_FOCUSED_CLASS),
+                (true, false) => (CSS_MATCH_TREE_VIEW_ROW_LABEL_SELECTED, ROW_LABEL_WRAPPER_SELECTED_CLASS),
+                (false, true) => (CSS_MATCH_15054086665198995512, ROW_LABEL_WRAPPER_FOCUSED_CLASS),
+                (false, false) => (CSS_MATCH_14249021884908901216, ROW_LABEL_WRAPPER_CLASS),
+            };
+
+            cells.push(
                 Dom::div()
                 .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
-                .with_children(DomVec::from_vec(vec![
+                .with_ids_and_classes(ROW_LABEL_CLASS)
+                .with_child(
                     Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14249021884908901216)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12039918700145849527: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12039918700145849527)
-                    })
-                        .with_tab_index(TabIndex::Auto)
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
-                    ]))
-                ]))
-            ])),
+                    .with_inline_css_props(wrapper_css)
+                    .with_ids_and_classes(wrapper_classes)
+                    .with_callbacks(vec![
+                        CallbackData {
+                            event: EventFilter::Hover(HoverEventFilter::LeftMouseUp),
+                            callback: Callback { cb: on_tree_view_row_click },
+                            data: row_dataset.clone(),
+                        },
+                        CallbackData {
+                            event: EventFilter::Hover(HoverEventFilter::RightMouseUp),
+                            callback: Callback { cb: on_tree_view_row_right_click },
+                            data: row_dataset,
+                        },
+                    ].into())
+                    .with_accessibility_actions(AccessibilityActionVec::from_const_slice(&[AccessibilityAction::Select]))
+                    .with_child(Dom::text(node.label.clone()))
+                )
+            );
+
+            let mut states = vec![AccessibilityState::Selectable];
+            if is_selected {
+                states.push(AccessibilityState::Selected);
+            }
+            if is_focused {
+                states.push(AccessibilityState::Focused);
+            }
+            if node.has_children {
+                states.push(if is_expanded { AccessibilityState::Expanded } else { AccessibilityState::Collapsed });
+            }
+
             Dom::div()
             .with_inline_css_props(CSS_MATCH_2919526787497691572)
-            .with_ids_and_classes({
-                const IDS_AND_CLASSES_15453577716812400238: &[IdOrClass] = &[
-                            Class(AzString::from_const_str("__azul-native-tree-view-row")),
-
-                ];
-                IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15453577716812400238)
-            })
-            .with_children(DomVec::from_vec(vec![
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_9496626968151854549)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_11091968853782313624: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-l")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11091968853782313624)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_5479296065075700509)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_7201172733362059285: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-l-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_7201172733362059285)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_14455923367901630186)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_2250273140132504407: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-space-1-filled")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_2250273140132504407)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_17932671798964167701)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_11324832106902074912: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-space-1-filled-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_11324832106902074912)
-                    })
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_8394859448076413888)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_5562274544924627603: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-minus")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_5562274544924627603)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_6438488809014395635)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_15170138310983987150: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-minus-content")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_15170138310983987150)
-                    })
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::div()
-                        .with_inline_css_props(CSS_MATCH_11045010670475678001)
-                        .with_ids_and_classes({
-                            const IDS_AND_CLASSES_276637619792188049: &[IdOrClass] = &[
-                                                    Class(AzString::from_const_str("__azul-native-tree-minus-icon")),
+            .with_ids_and_classes(ROW_CLASS)
+            .with_accessibility_info(
+                AccessibilityInfo::new(AccessibilityRole::OutlineItem)
+                .with_name(node.label.clone())
+                .with_states(AccessibilityStateVec::from_vec(states))
+            )
+            .with_children(cells.into())
+        }).collect::<Vec<_>>();
+
+        let container_states: &'static [AccessibilityState] = if multiple {
+            &[AccessibilityState::Multiselectable]
+        } else {
+            &[]
+        };
 
-                            ];
-                            IdOrClassVec::from_const_slice(IDS_AND_CLASSES_276637619792188049)
-                        })
-                    ]))
-                ])),
-                Dom::div()
-                .with_inline_css_props(CSS_MATCH_3920366294746786702)
-                .with_ids_and_classes({
-                    const IDS_AND_CLASSES_17022478219263932868: &[IdOrClass] = &[
-                                    Class(AzString::from_const_str("__azul-native-tree-view-row-label")),
-
-                    ];
-                    IdOrClassVec::from_const_slice(IDS_AND_CLASSES_17022478219263932868)
-                })
-                .with_children(DomVec::from_vec(vec![
-                    Dom::div()
-                    .with_inline_css_props(CSS_MATCH_14249021884908901216)
-                    .with_ids_and_classes({
-                        const IDS_AND_CLASSES_12039918700145849527: &[IdOrClass] = &[
-                                            Class(AzString::from_const_str("__azul-native-tree-view-row-label-focusable-wrapper")),
-
-                        ];
-                        IdOrClassVec::from_const_slice(IDS_AND_CLASSES_12039918700145849527)
-                    })
-                        .with_tab_index(TabIndex::Auto)
-                    .with_children(DomVec::from_vec(vec![
-                        Dom::text(AzString::from_const_str("Hello"))
-                    ]))
-                ]))
-            ]))
-        ]))
+        Dom::div()
+        .with_inline_css_props(CSS_MATCH_13401060217940352039)
+        .with_ids_and_classes(TREE_VIEW_CONTAINER_CLASS)
+        .with_tab_index(TabIndex::Auto)
+        .with_callbacks(vec![
+            CallbackData {
+                event: EventFilter::Focus(FocusEventFilter::VirtualKeyDown),
+                callback: Callback { cb: on_tree_view_key_down },
+                data: tree,
+            }
+        ].into())
+        .with_accessibility_info(
+            AccessibilityInfo::new(AccessibilityRole::Outline)
+            .with_states(AccessibilityStateVec::from_const_slice(container_states))
+        )
+        .with_children(row_doms.into())
+    }
+}
+
+struct TreeViewRowLocalDataset {
+    node: TreeViewNodeId,
+    /// `RefAny<TreeView>`, shared with the other rows
+    tree: RefAny,
+}
+
+/// Maximum time between two clicks on the same node to count as a double click
+const DOUBLE_CLICK_TIME_MS: u64 = 500;
+
+fn is_double_click(last_click: &Instant, now: &Instant) -> bool {
+    match (last_click, now) {
+        (Instant::System(_), Instant::System(_)) => {
+            let max = Duration::System(SystemTimeDiff::from_millis(DOUBLE_CLICK_TIME_MS));
+            now.duration_since(last_click).smaller_than(&max)
+        },
+        _ => false,
+    }
+}
+
+/// Gives the keyboard focus to the TreeView container, which
+/// is `levels` levels above the node that was clicked
+fn focus_tree_view(info: &mut CallbackInfo, levels: usize) {
+    let mut node_id = info.get_hit_node();
+    for _ in 0..levels {
+        node_id = match info.get_parent(node_id) {
+            Some(s) => s,
+            None => return,
+        };
+    }
+    info.set_focus(FocusTarget::Id(node_id));
+}
+
+extern "C" fn on_tree_view_row_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tree_view_row_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut row_dataset = data.downcast_mut::<TreeViewRowLocalDataset>()?;
+        let row_dataset = &mut *row_dataset;
+        let node = row_dataset.node;
+        let mut tree = row_dataset.tree.downcast_mut::<TreeView>()?;
+
+        // container > row > label > label wrapper
+        focus_tree_view(info, 3);
+
+        let keyboard_state = info.get_current_keyboard_state();
+        let now = info.get_current_time();
+
+        let is_double_click = tree.state.focused.into_option() == Some(node) &&
+            tree.state.last_click_time.as_ref().map(|t| is_double_click(t, &now)).unwrap_or(false);
+
+        let event = if is_double_click {
+            tree.state.last_click_time = None.into();
+            TreeViewEvent::DoubleClick(node)
+        } else {
+            tree.state.last_click_time = Some(now).into();
+            tree.handle_click(node, keyboard_state.shift_down(), keyboard_state.ctrl_down())
+        };
+
+        Some(tree.dispatch(info, event))
+    }
+
+    on_tree_view_row_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tree_view_row_right_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tree_view_row_right_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut row_dataset = data.downcast_mut::<TreeViewRowLocalDataset>()?;
+        let row_dataset = &mut *row_dataset;
+        let node = row_dataset.node;
+        let mut tree = row_dataset.tree.downcast_mut::<TreeView>()?;
+
+        focus_tree_view(info, 3);
+
+        let event = tree.handle_right_click(node);
+        Some(tree.dispatch(info, event))
+    }
+
+    on_tree_view_row_right_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tree_view_expander_click(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tree_view_expander_click_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut row_dataset = data.downcast_mut::<TreeViewRowLocalDataset>()?;
+        let row_dataset = &mut *row_dataset;
+        let node = row_dataset.node;
+        let mut tree = row_dataset.tree.downcast_mut::<TreeView>()?;
+
+        // container > row > +/- box
+        focus_tree_view(info, 2);
+
+        let event = tree.handle_toggle_expand(node);
+        Some(tree.dispatch(info, event))
+    }
+
+    on_tree_view_expander_click_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+extern "C" fn on_tree_view_key_down(data: &mut RefAny, info: &mut CallbackInfo) -> Update {
+
+    fn on_tree_view_key_down_inner(data: &mut RefAny, info: &mut CallbackInfo) -> Option<Update> {
+
+        let mut tree = data.downcast_mut::<TreeView>()?;
+        let keyboard_state = info.get_current_keyboard_state();
+        let key = keyboard_state.current_virtual_keycode.into_option()?;
+
+        let event = tree.handle_key(key, keyboard_state.shift_down(), keyboard_state.ctrl_down())?;
+        Some(tree.dispatch(info, event))
+    }
+
+    on_tree_view_key_down_inner(data, info).unwrap_or(Update::DoNothing)
+}
+
+#[cfg(test)]
+mod tree_view_test {
+
+    use super::*;
+
+    fn id(inner: u64) -> TreeViewNodeId {
+        TreeViewNodeId { inner }
+    }
+
+    fn node(inner: u64, parent: Option<u64>, has_children: bool) -> TreeViewNode {
+        let mut n = TreeViewNode::new(id(inner), parent.map(id).into(), AzString::from_const_str(""));
+        n.has_children = has_children;
+        n
+    }
+
+    // 1
+    // ├ 2
+    // │ └ 4
+    // └ 3
+    // 5
+    fn test_tree() -> TreeView {
+        TreeView::new(vec![
+            node(1, None, true),
+            node(2, Some(1), true),
+            node(3, Some(1), false),
+            node(4, Some(2), false),
+            node(5, None, false),
+        ].into())
+    }
+
+    fn visible(tree: &TreeView) -> Vec<u64> {
+        tree.get_visible_ids().into_iter().map(|n| n.inner).collect()
+    }
+
+    #[test]
+    fn test_visible_rows() {
+        let mut tree = test_tree();
+        assert_eq!(visible(&tree), vec![1, 5]);
+
+        tree.state.set_expanded(id(1), true);
+        assert_eq!(visible(&tree), vec![1, 2, 3, 5]);
+
+        tree.state.set_expanded(id(2), true);
+        assert_eq!(visible(&tree), vec![1, 2, 4, 3, 5]);
+
+        let rows = get_visible_rows(tree.nodes.as_ref(), &tree.state);
+        let lines = rows.iter().map(|r| r.has_next_sibling.clone()).collect::<Vec<_>>();
+        assert_eq!(lines, vec![
+            vec![true],
+            vec![true, true],
+            vec![true, true, false],
+            vec![true, false],
+            vec![false],
+        ]);
+
+        // collapsing the parent hides the grandchildren, too
+        tree.state.set_expanded(id(1), false);
+        assert_eq!(visible(&tree), vec![1, 5]);
+    }
+
+    #[test]
+    fn test_visible_rows_cycle() {
+        let mut tree = TreeView::new(vec![
+            node(1, None, true),
+            node(2, Some(1), true),
+            node(1, Some(2), true),
+            node(3, Some(4), false), // parent doesn't exist
+        ].into());
+        tree.state.set_expanded(id(1), true);
+        tree.state.set_expanded(id(2), true);
+        assert_eq!(visible(&tree), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_click_selection() {
+        let mut tree = test_tree();
+        tree.state.set_expanded(id(1), true);
+
+        // single selection ignores the modifiers
+        tree.handle_click(id(1), false, false);
+        tree.handle_click(id(3), true, true);
+        assert_eq!(tree.state.selected.as_ref(), &[id(3)]);
+
+        tree.set_selection_mode(TreeViewSelectionMode::Multiple);
+        tree.handle_click(id(1), false, false);
+        tree.handle_click(id(3), true, false);
+        assert_eq!(tree.state.selected.as_ref(), &[id(1), id(2), id(3)]);
+        assert_eq!(tree.state.focused.into_option(), Some(id(3)));
+
+        tree.handle_click(id(2), false, true);
+        assert_eq!(tree.state.selected.as_ref(), &[id(1), id(3)]);
+
+        tree.handle_click(id(5), false, true);
+        assert_eq!(tree.state.selected.as_ref(), &[id(1), id(3), id(5)]);
+    }
+
+    #[test]
+    fn test_right_click() {
+        let mut tree = test_tree();
+        tree.set_selection_mode(TreeViewSelectionMode::Multiple);
+        tree.handle_click(id(1), false, false);
+        tree.handle_click(id(5), false, true);
+
+        // right click on a selected node keeps the selection
+        assert_eq!(tree.handle_right_click(id(1)), TreeViewEvent::ContextMenu(id(1)));
+        assert_eq!(tree.state.selected.as_ref(), &[id(1), id(5)]);
+
+        tree.state.set_expanded(id(1), true);
+        tree.handle_right_click(id(3));
+        assert_eq!(tree.state.selected.as_ref(), &[id(3)]);
+    }
+
+    #[test]
+    fn test_collapse_moves_focus() {
+        let mut tree = test_tree();
+        tree.state.set_expanded(id(1), true);
+        tree.state.set_expanded(id(2), true);
+        tree.handle_click(id(4), false, false);
+
+        assert_eq!(tree.handle_toggle_expand(id(1)), TreeViewEvent::Expand(id(1), false));
+        assert_eq!(tree.state.focused.into_option(), Some(id(1)));
+        assert!(tree.state.is_expanded(id(2)));
+    }
+
+    #[test]
+    fn test_keyboard_navigation() {
+        let mut tree = test_tree();
+
+        assert_eq!(tree.handle_key(VirtualKeyCode::Down, false, false), Some(TreeViewEvent::Select));
+        assert_eq!(tree.state.selected.as_ref(), &[id(1)]);
+
+        assert_eq!(tree.handle_key(VirtualKeyCode::Right, false, false), Some(TreeViewEvent::Expand(id(1), true)));
+        assert_eq!(tree.handle_key(VirtualKeyCode::Right, false, false), Some(TreeViewEvent::Select));
+        assert_eq!(tree.state.focused.into_option(), Some(id(2)));
+
+        assert_eq!(tree.handle_key(VirtualKeyCode::End, false, false), Some(TreeViewEvent::Select));
+        assert_eq!(tree.state.focused.into_option(), Some(id(5)));
+        assert_eq!(tree.handle_key(VirtualKeyCode::Down, false, false), None);
+
+        assert_eq!(tree.handle_key(VirtualKeyCode::Up, false, false), Some(TreeViewEvent::Select));
+        assert_eq!(tree.state.focused.into_option(), Some(id(3)));
+
+        // Left on a leaf goes to the parent, then collapses the parent
+        assert_eq!(tree.handle_key(VirtualKeyCode::Left, false, false), Some(TreeViewEvent::Select));
+        assert_eq!(tree.state.focused.into_option(), Some(id(1)));
+        assert_eq!(tree.handle_key(VirtualKeyCode::Left, false, false), Some(TreeViewEvent::Expand(id(1), false)));
+
+        assert_eq!(tree.handle_key(VirtualKeyCode::Return, false, false), Some(TreeViewEvent::DoubleClick(id(1))));
+        assert_eq!(tree.handle_key(VirtualKeyCode::Apps, false, false), Some(TreeViewEvent::ContextMenu(id(1))));
+    }
+
+    #[test]
+    fn test_keyboard_multi_selection() {
+        let mut tree = test_tree();
+        tree.set_selection_mode(TreeViewSelectionMode::Multiple);
+        tree.state.set_expanded(id(1), true);
+        tree.handle_click(id(1), false, false);
+
+        tree.handle_key(VirtualKeyCode::Down, true, false);
+        tree.handle_key(VirtualKeyCode::Down, true, false);
+        assert_eq!(tree.state.selected.as_ref(), &[id(1), id(2), id(3)]);
+
+        // Ctrl + arrow only moves the focus, Ctrl + Space toggles
+        tree.handle_key(VirtualKeyCode::Down, false, true);
+        assert_eq!(tree.state.focused.into_option(), Some(id(5)));
+        assert_eq!(tree.state.selected.as_ref(), &[id(1), id(2), id(3)]);
+        tree.handle_key(VirtualKeyCode::Space, false, true);
+        assert_eq!(tree.state.selected.as_ref(), &[id(1), id(2), id(3), id(5)]);
     }
 }
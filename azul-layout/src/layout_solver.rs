@@ -243,6 +243,8 @@ macro_rules! typed_arena {(
     $border_right:ident,
     $left:ident,
     $right:ident,
+    $grid_axis:ident,
+    $grid_span:ident,
 ) => (
 
 
@@ -367,6 +369,7 @@ macro_rules! typed_arena {(
         node_hierarchy: &NodeDataContainerRef<'a, NodeHierarchyItem>,
         layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
         layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+        layout_grids: &BTreeMap<NodeId, GridContainer>,
         wh_configs: &NodeDataContainerRef<'a, WhConfig>,
         node_depths: &[ParentWithNodeDepth],
         root_size_width: f32,
@@ -394,18 +397,27 @@ macro_rules! typed_arena {(
 
             let mut children_flex_basis = 0.0_f32;
 
-            parent_id
-            .az_children(node_hierarchy)
-            .filter(|child_id| layout_positions[*child_id] != LayoutPosition::Absolute)
-            .map(|child_id| (child_id, node_data[child_id].min_inner_size_px + node_data[child_id].$get_margin_fn(parent_width)))
-            .for_each(|(_, flex_basis)| {
-                if flex_axis == LayoutAxis::$main_axis {
-                    children_flex_basis += flex_basis;
-                } else {
-                    // cross direction: take max flex basis of children
-                    children_flex_basis = children_flex_basis.max(flex_basis);
-                }
-            });
+            if let Some(grid) = layout_grids.get(&parent_id) {
+                // grid: the minimum size is the sum of the minimum track sizes
+                let items = grid.items.iter()
+                .map(|(child_id, placement)| (placement.$grid_span, node_data[*child_id].min_inner_size_px + node_data[*child_id].$get_margin_fn(parent_width)))
+                .collect::<Vec<_>>();
+                let track_sizes = grid.$grid_axis.solve_track_sizes(None, &items);
+                children_flex_basis = grid.$grid_axis.get_area(&track_sizes, 0.0, GridSpan { start: 0, count: track_sizes.len() }).1;
+            } else {
+                parent_id
+                .az_children(node_hierarchy)
+                .filter(|child_id| layout_positions[*child_id] != LayoutPosition::Absolute)
+                .map(|child_id| (child_id, node_data[child_id].min_inner_size_px + node_data[child_id].$get_margin_fn(parent_width)))
+                .for_each(|(_, flex_basis)| {
+                    if flex_axis == LayoutAxis::$main_axis {
+                        children_flex_basis += flex_basis;
+                    } else {
+                        // cross direction: take max flex basis of children
+                        children_flex_basis = children_flex_basis.max(flex_basis);
+                    }
+                });
+            }
 
             // if the children overflow, then the maximum width / height that can be
             // bubbled is the max_height / max_width of the parent
@@ -436,6 +448,7 @@ macro_rules! typed_arena {(
         layout_flex_grows: &NodeDataContainerRef<'a, f32>,
         layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
         layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+        layout_grids: &BTreeMap<NodeId, GridContainer>,
         node_depths: &[ParentWithNodeDepth],
         root_width: f32,
         parents_to_recalc: &BTreeSet<NodeId>,
@@ -682,6 +695,74 @@ macro_rules! typed_arena {(
             .collect()
         }
 
+        /// Stretches the children of a `display: grid` node to fill their
+        /// grid area, unless they have a fixed width / height
+        fn distribute_space_in_grid<'a>(
+            parent_id: &NodeId,
+            children: &[NodeId],
+            grid: &GridContainer,
+            node_hierarchy: &NodeDataContainerRef<'a, NodeHierarchyItem>,
+            layout_displays: &NodeDataContainerRef<'a, CssPropertyValue<LayoutDisplay>>,
+            layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
+            width_calculated_arena: &'a NodeDataContainerRef<$struct_name>,
+            root_width: f32
+        ) -> Vec<f32> {
+
+            // children that are not part of the grid (position: absolute) are sized as usual
+            let mut children_flex_grow = distribute_space_along_cross_axis(
+                parent_id,
+                children,
+                node_hierarchy,
+                layout_displays,
+                layout_positions,
+                width_calculated_arena,
+                root_width
+            );
+
+            let parent_node_inner_width = {
+                let parent_node = &width_calculated_arena[*parent_id];
+                let parent_parent_width = node_hierarchy[*parent_id].parent_id()
+                .and_then(|p| width_calculated_arena[p].$preferred_field.max_available_space())
+                .unwrap_or(root_width);
+
+                parent_node.total() - parent_node.$get_padding_fn(parent_parent_width)
+            };
+
+            let items = grid.items.iter().map(|(child_id, placement)| {
+                let child = &width_calculated_arena[*child_id];
+                (placement.$grid_span, child.min_inner_size_px + child.$get_margin_fn(parent_node_inner_width))
+            }).collect::<Vec<_>>();
+
+            let track_sizes = grid.$grid_axis.solve_track_sizes(Some(parent_node_inner_width), &items);
+
+            for (child_index_in_parent, child_id) in children.iter().enumerate() {
+
+                let placement = match grid.items.get(child_id) {
+                    Some(s) => s,
+                    None => continue,
+                };
+
+                let child = &width_calculated_arena[*child_id];
+                let (_, area_size) = grid.$grid_axis.get_area(&track_sizes, parent_node_inner_width, placement.$grid_span);
+
+                let child_size = match child.$preferred_field {
+                    WhConstraint::EqualTo(exact) => exact,
+                    other => {
+                        let stretched_size = area_size - child.$get_margin_fn(parent_node_inner_width);
+                        match other.max_available_space() {
+                            Some(max) => stretched_size.min(max),
+                            None => stretched_size,
+                        }
+                    },
+                };
+
+                // so that node.min_inner_size_px + node.flex_grow_px = size of the grid area
+                children_flex_grow[child_index_in_parent] = (child_size - child.min_inner_size_px).max(0.0);
+            }
+
+            children_flex_grow
+        }
+
         use azul_css::{LayoutAxis, LayoutPosition};
 
         // Set the window width on the root node (since there is only one root node, we can
@@ -731,7 +812,18 @@ macro_rules! typed_arena {(
                 let children = parent_id.az_children_collect(&node_hierarchy);
                 let flex_axis = layout_directions[*parent_id].get_axis();
 
-                let result = if flex_axis == LayoutAxis::$main_axis {
+                let result = if let Some(grid) = layout_grids.get(parent_id) {
+                    distribute_space_in_grid(
+                        &parent_id,
+                        &children,
+                        grid,
+                        node_hierarchy,
+                        layout_displays,
+                        layout_positions,
+                        &node_data.as_ref(),
+                        root_width
+                    )
+                } else if flex_axis == LayoutAxis::$main_axis {
                     distribute_space_along_main_axis(
                         &parent_id,
                         &children,
@@ -791,6 +883,8 @@ typed_arena!(
     border_right,
     left,
     right,
+    columns,
+    column,
 );
 
 typed_arena!(
@@ -814,6 +908,8 @@ typed_arena!(
     border_bottom,
     top,
     bottom,
+    rows,
+    row,
 );
 
 /// Returns the solved widths of the items in a BTree form
@@ -823,6 +919,7 @@ pub(crate) fn solve_flex_layout_width<'a, 'b>(
     layout_displays: &NodeDataContainerRef<'a, CssPropertyValue<LayoutDisplay>>,
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
     layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+    layout_grids: &BTreeMap<NodeId, GridContainer>,
     node_hierarchy: &'b NodeDataContainerRef<'a, NodeHierarchyItem>,
     wh_configs: &NodeDataContainerRef<'a, WhConfig>,
     node_depths: &[ParentWithNodeDepth],
@@ -834,6 +931,7 @@ pub(crate) fn solve_flex_layout_width<'a, 'b>(
         node_hierarchy,
         layout_positions,
         layout_directions,
+        layout_grids,
         wh_configs,
         node_depths,
         window_width,
@@ -845,6 +943,7 @@ pub(crate) fn solve_flex_layout_width<'a, 'b>(
         layout_flex_grow,
         layout_positions,
         layout_directions,
+        layout_grids,
         node_depths,
         window_width,
         parents_to_recalc
@@ -858,6 +957,7 @@ pub(crate) fn solve_flex_layout_height<'a, 'b>(
     layout_displays: &NodeDataContainerRef<'a, CssPropertyValue<LayoutDisplay>>,
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
     layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
+    layout_grids: &BTreeMap<NodeId, GridContainer>,
     node_hierarchy: &'b NodeDataContainerRef<'a, NodeHierarchyItem>,
     wh_configs: &NodeDataContainerRef<'a, WhConfig>,
     node_depths: &[ParentWithNodeDepth],
@@ -869,6 +969,7 @@ pub(crate) fn solve_flex_layout_height<'a, 'b>(
         node_hierarchy,
         layout_positions,
         layout_directions,
+        layout_grids,
         wh_configs,
         node_depths,
         window_height
@@ -880,6 +981,7 @@ pub(crate) fn solve_flex_layout_height<'a, 'b>(
         layout_flex_grow,
         layout_positions,
         layout_directions,
+        layout_grids,
         node_depths,
         window_height,
         parents_to_recalc
@@ -897,7 +999,10 @@ macro_rules! get_position {(
     $margin_right:ident,
     $get_padding_left:ident,
     $get_padding_right:ident,
-    $axis:ident
+    $get_margin:ident,
+    $axis:ident,
+    $grid_axis:ident,
+    $grid_span:ident
 ) => (
     /// Traverses along the DOM and solve for the X or Y position
     fn $fn_name<'a>(
@@ -906,6 +1011,7 @@ macro_rules! get_position {(
         layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
         layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
         layout_justify_contents: &NodeDataContainerRef<'a, LayoutJustifyContent>,
+        layout_grids: &BTreeMap<NodeId, GridContainer>,
         node_depths: &[ParentWithNodeDepth],
         solved_widths: &NodeDataContainerRef<'a, $width_layout>,
         parents_to_solve: &BTreeSet<NodeId>
@@ -1043,14 +1149,28 @@ macro_rules! get_position {(
             }).unwrap_or(0.0);
 
             if layout_positions[child_id] == LayoutPosition::Absolute {
-                determine_child_x_absolute(
+                return determine_child_x_absolute(
                     child_id,
                     solved_widths,
                     layout_positions,
                     node_hierarchy,
-                )
-            } else {
-                parent_x_position + child_margin_left
+                );
+            }
+
+            // `auto` margins take up the remaining space of the parent
+            let margin_left_is_auto = match child_node.$margin_left { Some(CssPropertyValue::Auto) => true, _ => false };
+            let margin_right_is_auto = match child_node.$margin_right { Some(CssPropertyValue::Auto) => true, _ => false };
+            let free_space = parent_inner_width - child_node.total();
+
+            match (margin_left_is_auto, margin_right_is_auto) {
+                (true, true) => parent_x_position + (free_space / 2.0).max(0.0),
+                (true, false) => {
+                    let child_margin_right = child_node.$margin_right.and_then(|x| {
+                        Some(x.get_property()?.inner.to_pixels(parent_inner_width))
+                    }).unwrap_or(0.0);
+                    parent_x_position + (free_space - child_margin_right).max(0.0)
+                },
+                _ => parent_x_position + child_margin_left,
             }
         }
 
@@ -1081,7 +1201,37 @@ macro_rules! get_position {(
                 parent_node.total() - (parent_padding_left + parent_padding_right)
             };
 
-            if parent_direction.get_axis() == LayoutAxis::$axis {
+            if let Some(grid) = layout_grids.get(&parent_id) {
+
+                // Grid: solve the tracks again and put each child at the start of its grid area
+                let items = grid.items.iter().map(|(child_id, placement)| {
+                    let child_node = &solved_widths[*child_id];
+                    (placement.$grid_span, child_node.min_inner_size_px + child_node.$get_margin(parent_inner_width))
+                }).collect::<Vec<_>>();
+
+                let track_sizes = grid.$grid_axis.solve_track_sizes(Some(parent_inner_width), &items);
+
+                for child_id in parent_id.az_children(node_hierarchy) {
+                    arena.as_ref_mut()[child_id].0 = match grid.items.get(&child_id) {
+                        Some(placement) => {
+                            let (area_offset, _) = grid.$grid_axis.get_area(&track_sizes, parent_inner_width, placement.$grid_span);
+                            let child_margin_left = solved_widths[child_id].$margin_left.and_then(|x| {
+                                Some(x.get_property()?.inner.to_pixels(parent_inner_width))
+                            }).unwrap_or(0.0);
+                            parent_x_position + area_offset + child_margin_left
+                        },
+                        None => determine_child_x_along_cross_axis(
+                            layout_positions,
+                            solved_widths,
+                            child_id,
+                            parent_x_position,
+                            parent_inner_width,
+                            node_hierarchy,
+                        ),
+                    };
+                }
+
+            } else if parent_direction.get_axis() == LayoutAxis::$axis {
 
                 // Along main axis: Increase X with width of current element
                 let main_axis_alignment = layout_justify_contents[parent_id];
@@ -1173,6 +1323,7 @@ fn get_x_positions<'a>(
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
     layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
     layout_justify_contents: &NodeDataContainerRef<'a, LayoutJustifyContent>,
+    layout_grids: &BTreeMap<NodeId, GridContainer>,
     node_depths: &[ParentWithNodeDepth],
     origin: LogicalPosition,
     parents_to_solve: &BTreeSet<NodeId>,
@@ -1188,7 +1339,10 @@ fn get_x_positions<'a>(
         margin_right,
         get_padding_left,
         get_padding_right,
-        Horizontal
+        get_horizontal_margin,
+        Horizontal,
+        columns,
+        column
     );
 
     get_pos_x(
@@ -1197,6 +1351,7 @@ fn get_x_positions<'a>(
         layout_positions,
         layout_directions,
         layout_justify_contents,
+        layout_grids,
         node_depths,
         solved_widths,
         &parents_to_solve
@@ -1213,6 +1368,7 @@ fn get_y_positions<'a>(
     layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
     layout_directions: &NodeDataContainerRef<'a, LayoutFlexDirection>,
     layout_justify_contents: &NodeDataContainerRef<'a, LayoutJustifyContent>,
+    layout_grids: &BTreeMap<NodeId, GridContainer>,
     node_depths: &[ParentWithNodeDepth],
     origin: LogicalPosition,
     parents_to_solve: &BTreeSet<NodeId>,
@@ -1228,7 +1384,10 @@ fn get_y_positions<'a>(
         margin_bottom,
         get_padding_top,
         get_padding_bottom,
-        Vertical
+        get_vertical_margin,
+        Vertical,
+        rows,
+        row
     );
 
     get_pos_y(
@@ -1237,6 +1396,7 @@ fn get_y_positions<'a>(
         layout_positions,
         layout_directions,
         layout_justify_contents,
+        layout_grids,
         node_depths,
         solved_heights,
         &parents_to_solve
@@ -1365,6 +1525,469 @@ pub fn get_layout_displays<'a>(styled_dom: &StyledDom) -> NodeDataContainer<CssP
     }
 }
 
+/// Range of tracks that a grid item spans along one axis
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GridSpan {
+    /// 0-based index of the first track
+    pub start: usize,
+    /// Number of tracks, always >= 1
+    pub count: usize,
+}
+
+impl GridSpan {
+    #[inline]
+    pub fn end(&self) -> usize { self.start + self.count }
+}
+
+/// Cells of a grid item, resolved from `grid-column`, `grid-row` and `grid-auto-flow`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GridItemPlacement {
+    pub column: GridSpan,
+    pub row: GridSpan,
+}
+
+/// Tracks (explicit + implicit) of one axis of a grid container
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridAxis {
+    pub tracks: Vec<GridTrackSizing>,
+    pub gap: PixelValue,
+}
+
+/// Precalculated `display: grid` container: the tracks of both axes plus
+/// the cells of all in-flow children
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridContainer {
+    pub columns: GridAxis,
+    pub rows: GridAxis,
+    pub items: BTreeMap<NodeId, GridItemPlacement>,
+}
+
+impl GridAxis {
+
+    #[inline]
+    pub fn get_gap(&self, percent_resolve: f32) -> f32 {
+        self.gap.to_pixels(percent_resolve)
+    }
+
+    /// Returns the (offset, size) of the area spanned by `span`,
+    /// relative to the content box of the grid container
+    pub fn get_area(&self, track_sizes: &[f32], percent_resolve: f32, span: GridSpan) -> (f32, f32) {
+        let gap = self.get_gap(percent_resolve);
+        let end = span.end().min(track_sizes.len());
+        let start = span.start.min(end);
+        let offset = track_sizes[..start].iter().sum::<f32>() + gap * start as f32;
+        let size = track_sizes[start..end].iter().sum::<f32>() + gap * (end - start).saturating_sub(1) as f32;
+        (offset, size)
+    }
+
+    /// Sizes the tracks of this axis, loosely following the CSS grid track sizing
+    /// algorithm. `items` are the spans + outer minimum sizes of the items on this axis.
+    ///
+    /// If `available_space` is `None`, all tracks are set to their minimum size
+    /// (used to bubble the minimum size of the grid container to its parents).
+    pub fn solve_track_sizes(&self, available_space: Option<f32>, items: &[(GridSpan, f32)]) -> Vec<f32> {
+
+        let percent_resolve = available_space.unwrap_or(0.0);
+        let gap = self.get_gap(percent_resolve);
+        let gaps_total = gap * self.tracks.len().saturating_sub(1) as f32;
+
+        let get_fraction = |i: usize| match self.tracks[i].max {
+            GridTrackBreadth::Fraction(f) => f.get(),
+            _ => 0.0,
+        };
+
+        // 1. Initialize the base size (minimum) and growth limit (maximum) of each track
+        let mut base_sizes = self.tracks.iter().map(|t| match t.min {
+            GridTrackBreadth::Fixed(p) => p.to_pixels(percent_resolve),
+            _ => 0.0,
+        }).collect::<Vec<f32>>();
+
+        let mut growth_limits = self.tracks.iter().map(|t| match t.max {
+            GridTrackBreadth::Fixed(p) => p.to_pixels(percent_resolve),
+            _ => 0.0,
+        }).collect::<Vec<f32>>();
+
+        // 2. Size the intrinsic tracks to fit the items that only span one track
+        for (span, size) in items.iter().filter(|(span, _)| span.count == 1) {
+            let track = match self.tracks.get(span.start) {
+                Some(s) => s,
+                None => continue,
+            };
+            if track.min.is_intrinsic() {
+                base_sizes[span.start] = base_sizes[span.start].max(*size);
+            }
+            if track.max.is_intrinsic() {
+                growth_limits[span.start] = growth_limits[span.start].max(*size);
+            }
+        }
+
+        // 3. Items spanning multiple tracks distribute the space they are
+        //    missing equally over the intrinsic tracks they span
+        for (span, size) in items.iter().filter(|(span, _)| span.count > 1) {
+            let end = span.end().min(self.tracks.len());
+            if span.start >= end {
+                continue;
+            }
+
+            let current_size = base_sizes[span.start..end].iter().sum::<f32>() + gap * (end - span.start - 1) as f32;
+            let missing_space = *size - current_size;
+            if missing_space <= 0.0 {
+                continue;
+            }
+
+            let intrinsic_tracks = (span.start..end)
+                .filter(|i| self.tracks[*i].min.is_intrinsic())
+                .collect::<Vec<_>>();
+
+            if intrinsic_tracks.is_empty() {
+                continue;
+            }
+
+            let space_per_track = missing_space / intrinsic_tracks.len() as f32;
+            for i in intrinsic_tracks {
+                base_sizes[i] += space_per_track;
+            }
+        }
+
+        // the growth limit can never be smaller than the base size
+        for (limit, base) in growth_limits.iter_mut().zip(base_sizes.iter()) {
+            *limit = limit.max(*base);
+        }
+
+        let available_space = match available_space {
+            Some(s) => s,
+            None => return base_sizes,
+        };
+
+        // 4. Grow the non-flexible tracks equally until they hit their growth limit
+        let mut free_space = available_space - gaps_total - base_sizes.iter().sum::<f32>();
+
+        loop {
+            let growable_tracks = (0..base_sizes.len())
+                .filter(|i| !self.tracks[*i].max.is_fraction() && base_sizes[*i] < growth_limits[*i])
+                .collect::<Vec<_>>();
+
+            if !(free_space > 0.0) || growable_tracks.is_empty() {
+                break;
+            }
+
+            let space_per_track = free_space / growable_tracks.len() as f32;
+            let mut track_hit_limit = false;
+
+            for i in growable_tracks {
+                let space_to_add = space_per_track.min(growth_limits[i] - base_sizes[i]);
+                track_hit_limit = track_hit_limit || space_to_add < space_per_track;
+                base_sizes[i] += space_to_add;
+                free_space -= space_to_add;
+            }
+
+            // all tracks took their full share, the free space is used up
+            if !track_hit_limit {
+                break;
+            }
+        }
+
+        // 5. Expand the flexible tracks: find the size of `1fr`. Tracks whose base size
+        //    is larger than their share are treated as inflexible and the size is recalculated
+        let mut flexible_tracks = (0..base_sizes.len())
+            .filter(|i| self.tracks[*i].max.is_fraction())
+            .collect::<BTreeSet<_>>();
+
+        if flexible_tracks.is_empty() {
+
+            // 6. No flexible tracks: stretch the `auto` tracks over the remaining space
+            let free_space = available_space - gaps_total - base_sizes.iter().sum::<f32>();
+            let auto_tracks = (0..base_sizes.len())
+                .filter(|i| self.tracks[*i].max == GridTrackBreadth::Auto)
+                .collect::<Vec<_>>();
+
+            if free_space > 0.0 && !auto_tracks.is_empty() {
+                let space_per_track = free_space / auto_tracks.len() as f32;
+                for i in auto_tracks {
+                    base_sizes[i] += space_per_track;
+                }
+            }
+
+            return base_sizes;
+        }
+
+        while !flexible_tracks.is_empty() {
+
+            let inflexible_space = (0..base_sizes.len())
+                .filter(|i| !flexible_tracks.contains(i))
+                .map(|i| base_sizes[i])
+                .sum::<f32>();
+
+            // if the sum of the flex factors is less than 1, the tracks don't fill the entire space
+            let flex_factor_sum = flexible_tracks.iter().map(|i| get_fraction(*i)).sum::<f32>().max(1.0);
+            let fr_size = (available_space - gaps_total - inflexible_space).max(0.0) / flex_factor_sum;
+
+            let too_large_tracks = flexible_tracks.iter()
+                .filter(|i| base_sizes[**i] > fr_size * get_fraction(**i))
+                .copied()
+                .collect::<Vec<_>>();
+
+            if too_large_tracks.is_empty() {
+                for i in flexible_tracks.iter() {
+                    base_sizes[*i] = fr_size * get_fraction(*i);
+                }
+                break;
+            }
+
+            for i in too_large_tracks {
+                flexible_tracks.remove(&i);
+            }
+        }
+
+        base_sizes
+    }
+}
+
+/// Resolves a `grid-column` / `grid-row` against the explicit grid. Returns the start
+/// track (`None` if the item has to be auto-placed on this axis) and the number of tracks spanned.
+///
+/// Lines before the start of the explicit grid are clamped to the first line.
+/// Line `0` is invalid in CSS and treated as `auto`.
+fn resolve_grid_placement(placement: &GridPlacement, explicit_tracks: usize) -> (Option<usize>, usize) {
+
+    // 1-based line number to 0-based line index, negative numbers count from the last line
+    let line_to_index = |line: i32| -> usize {
+        if line > 0 {
+            (line - 1) as usize
+        } else {
+            (explicit_tracks as i32 + 1 + line).max(0) as usize
+        }
+    };
+
+    let line_or_auto = |line: GridLine| match line {
+        GridLine::Line(0) => GridLine::Auto,
+        other => other,
+    };
+
+    match (line_or_auto(placement.start), line_or_auto(placement.end)) {
+        (GridLine::Line(start), GridLine::Line(end)) => {
+            let (start, end) = (line_to_index(start), line_to_index(end));
+            if start == end {
+                (Some(start), 1)
+            } else {
+                (Some(start.min(end)), start.max(end) - start.min(end))
+            }
+        },
+        (GridLine::Line(start), GridLine::Span(span)) => (Some(line_to_index(start)), span.max(1) as usize),
+        (GridLine::Line(start), GridLine::Auto) => (Some(line_to_index(start)), 1),
+        (GridLine::Span(span), GridLine::Line(end)) => {
+            let end = line_to_index(end);
+            let start = end.saturating_sub(span.max(1) as usize);
+            (Some(start), (end - start).max(1))
+        },
+        (GridLine::Auto, GridLine::Line(end)) => (Some(line_to_index(end).saturating_sub(1)), 1),
+        (GridLine::Span(span), _) | (GridLine::Auto, GridLine::Span(span)) => (None, span.max(1) as usize),
+        (GridLine::Auto, GridLine::Auto) => (None, 1),
+    }
+}
+
+/// Places the children of a grid container (in DOM order) into the cells of the grid.
+///
+/// `items` are the (child, `grid-column`, `grid-row`) of all in-flow children.
+/// The "major" axis is the axis along which new tracks are created during
+/// auto-placement (rows for `grid-auto-flow: row`), the "minor" axis is
+/// the axis that gets filled first (columns for `grid-auto-flow: row`).
+fn place_grid_items(
+    items: &[(NodeId, GridPlacement, GridPlacement)],
+    explicit_columns: usize,
+    explicit_rows: usize,
+    auto_flow: LayoutGridAutoFlow,
+) -> BTreeMap<NodeId, GridItemPlacement> {
+
+    fn is_free(occupied: &BTreeSet<(usize, usize)>, major: GridSpan, minor: GridSpan) -> bool {
+        (major.start..major.end()).all(|ma| (minor.start..minor.end()).all(|mi| !occupied.contains(&(ma, mi))))
+    }
+
+    fn occupy(occupied: &mut BTreeSet<(usize, usize)>, major: GridSpan, minor: GridSpan) {
+        for ma in major.start..major.end() {
+            for mi in minor.start..minor.end() {
+                occupied.insert((ma, mi));
+            }
+        }
+    }
+
+    let row_flow = auto_flow.get_axis() == LayoutAxis::Horizontal;
+    let dense = auto_flow.is_dense();
+    let explicit_minor_tracks = if row_flow { explicit_columns } else { explicit_rows };
+
+    // (node_id, major axis, minor axis)
+    let items = items.iter().map(|(node_id, column, row)| {
+        let column = resolve_grid_placement(column, explicit_columns);
+        let row = resolve_grid_placement(row, explicit_rows);
+        if row_flow { (*node_id, row, column) } else { (*node_id, column, row) }
+    }).collect::<Vec<_>>();
+
+    // cells that are already taken, stored as (major, minor)
+    let mut occupied = BTreeSet::new();
+    let mut placed = BTreeMap::new();
+
+    // 1. Place the items with a definite position on both axes
+    for (node_id, (major_start, major_count), (minor_start, minor_count)) in items.iter() {
+        if let (Some(major_start), Some(minor_start)) = (major_start, minor_start) {
+            let major = GridSpan { start: *major_start, count: *major_count };
+            let minor = GridSpan { start: *minor_start, count: *minor_count };
+            occupy(&mut occupied, major, minor);
+            placed.insert(*node_id, (major, minor));
+        }
+    }
+
+    // 2. Place the items that are locked to a major track
+    let mut major_track_cursors = BTreeMap::new();
+    for (node_id, (major_start, major_count), (minor_start, minor_count)) in items.iter() {
+        if let (Some(major_start), None) = (major_start, minor_start) {
+            let major = GridSpan { start: *major_start, count: *major_count };
+            let mut minor = GridSpan {
+                start: if dense { 0 } else { major_track_cursors.get(major_start).copied().unwrap_or(0) },
+                count: *minor_count,
+            };
+            while !is_free(&occupied, major, minor) {
+                minor.start += 1;
+            }
+            occupy(&mut occupied, major, minor);
+            major_track_cursors.insert(*major_start, minor.end());
+            placed.insert(*node_id, (major, minor));
+        }
+    }
+
+    // The minor axis doesn't grow during auto-placement,
+    // so the number of minor tracks has to be known upfront
+    let minor_tracks = items.iter()
+        .map(|(_, _, (minor_start, minor_count))| minor_start.unwrap_or(0) + minor_count)
+        .chain(placed.values().map(|(_, minor): &(GridSpan, GridSpan)| minor.end()))
+        .fold(explicit_minor_tracks.max(1), usize::max);
+
+    // 3. Auto-place the remaining items, adding major tracks as necessary
+    let mut cursor = (0, 0); // (major, minor)
+    for (node_id, (major_start, major_count), (minor_start, minor_count)) in items.iter() {
+
+        if major_start.is_some() {
+            continue;
+        }
+
+        if dense {
+            cursor = (0, 0);
+        }
+
+        let (major, minor) = match minor_start {
+            Some(minor_start) => {
+                if !dense && *minor_start < cursor.1 {
+                    cursor.0 += 1;
+                }
+                let minor = GridSpan { start: *minor_start, count: *minor_count };
+                let mut major = GridSpan { start: cursor.0, count: *major_count };
+                while !is_free(&occupied, major, minor) {
+                    major.start += 1;
+                }
+                (major, minor)
+            },
+            None => {
+                let mut major = GridSpan { start: cursor.0, count: *major_count };
+                let mut minor = GridSpan { start: cursor.1, count: *minor_count };
+                loop {
+                    if minor.end() > minor_tracks {
+                        major.start += 1;
+                        minor.start = 0;
+                    } else if is_free(&occupied, major, minor) {
+                        break;
+                    } else {
+                        minor.start += 1;
+                    }
+                }
+                (major, minor)
+            },
+        };
+
+        occupy(&mut occupied, major, minor);
+        cursor = (major.start, minor.end());
+        placed.insert(*node_id, (major, minor));
+    }
+
+    placed.into_iter().map(|(node_id, (major, minor))| {
+        let (column, row) = if row_flow { (minor, major) } else { (major, minor) };
+        (node_id, GridItemPlacement { column, row })
+    }).collect()
+}
+
+/// Precalculates the tracks and item placements of all `display: grid` nodes
+pub fn get_layout_grids<'a>(
+    styled_dom: &StyledDom,
+    layout_displays: &NodeDataContainerRef<'a, CssPropertyValue<LayoutDisplay>>,
+    layout_positions: &NodeDataContainerRef<'a, LayoutPosition>,
+) -> BTreeMap<NodeId, GridContainer> {
+
+    let cache = styled_dom.get_css_property_cache();
+    let node_data_container = styled_dom.node_data.as_container();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+    let node_hierarchy = styled_dom.node_hierarchy.as_container();
+
+    styled_dom.non_leaf_nodes.iter()
+    .filter_map(|p| p.node_id.into_crate_internal())
+    .filter(|parent_id| layout_displays[*parent_id] == CssPropertyValue::Exact(LayoutDisplay::Grid))
+    .map(|parent_id| {
+
+        let node_data = &node_data_container[parent_id];
+        let node_state = &styled_nodes[parent_id].state;
+
+        let mut template_columns = cache.get_grid_template_columns(node_data, &parent_id, node_state)
+            .and_then(|p| p.get_property().map(|v| v.as_ref().to_vec()))
+            .unwrap_or_default();
+        let mut template_rows = cache.get_grid_template_rows(node_data, &parent_id, node_state)
+            .and_then(|p| p.get_property().map(|v| v.as_ref().to_vec()))
+            .unwrap_or_default();
+        let auto_column = cache.get_grid_auto_columns(node_data, &parent_id, node_state)
+            .and_then(|p| p.get_property().copied())
+            .unwrap_or_default();
+        let auto_row = cache.get_grid_auto_rows(node_data, &parent_id, node_state)
+            .and_then(|p| p.get_property().copied())
+            .unwrap_or_default();
+        let auto_flow = cache.get_grid_auto_flow(node_data, &parent_id, node_state)
+            .and_then(|p| p.get_property().copied())
+            .unwrap_or_default();
+        let column_gap = cache.get_column_gap(node_data, &parent_id, node_state)
+            .and_then(|p| p.get_property().copied())
+            .unwrap_or_default();
+        let row_gap = cache.get_row_gap(node_data, &parent_id, node_state)
+            .and_then(|p| p.get_property().copied())
+            .unwrap_or_default();
+
+        // absolute positioned children are not part of the grid
+        let items = parent_id.az_children(&node_hierarchy)
+            .filter(|child_id| layout_positions[*child_id] != LayoutPosition::Absolute)
+            .filter(|child_id| layout_displays[*child_id] != CssPropertyValue::Exact(LayoutDisplay::None))
+            .map(|child_id| {
+                let child_data = &node_data_container[child_id];
+                let child_state = &styled_nodes[child_id].state;
+                let column = cache.get_grid_column(child_data, &child_id, child_state)
+                    .and_then(|p| p.get_property().copied())
+                    .unwrap_or_default();
+                let row = cache.get_grid_row(child_data, &child_id, child_state)
+                    .and_then(|p| p.get_property().copied())
+                    .unwrap_or_default();
+                (child_id, column, row)
+            })
+            .collect::<Vec<_>>();
+
+        let items = place_grid_items(&items, template_columns.len(), template_rows.len(), auto_flow);
+
+        // add the implicit tracks created by the item placement
+        let column_count = items.values().map(|i| i.column.end()).fold(template_columns.len(), usize::max);
+        let row_count = items.values().map(|i| i.row.end()).fold(template_rows.len(), usize::max);
+        template_columns.resize(column_count, auto_column);
+        template_rows.resize(row_count, auto_row);
+
+        (parent_id, GridContainer {
+            columns: GridAxis { tracks: template_columns, gap: column_gap.inner },
+            rows: GridAxis { tracks: template_rows, gap: row_gap.inner },
+            items,
+        })
+    }).collect()
+}
+
 fn precalculate_all_offsets(styled_dom: &StyledDom) -> NodeDataContainer<AllOffsets> {
 
     use rayon::prelude::*;
@@ -1554,10 +2177,17 @@ pub fn do_the_layout(
 
         let mut new_doms = Vec::new();
 
-        for (parent_dom_id, dom_id, styled_dom, rect) in doms.drain(..) {
+        for (parent_dom_id, dom_id, mut styled_dom, rect) in doms.drain(..) {
 
             use azul_core::app_resources::add_fonts_and_images;
 
+            // evaluate the @media blocks before the first layout, iframes
+            // evaluate them against their own size instead of the window size
+            let mut media_environment = full_window_state.size.get_media_environment(window_theme);
+            media_environment.width = rect.size.width;
+            media_environment.height = rect.size.height;
+            let _ = styled_dom.restyle_media(&media_environment);
+
             add_fonts_and_images(
                 image_cache,
                 renderer_resources,
@@ -1569,6 +2199,7 @@ pub fn do_the_layout(
                 &styled_dom,
                 callbacks.load_font_fn,
                 callbacks.parse_font_fn,
+                callbacks.font_has_glyph_fn,
                 callbacks.insert_into_active_gl_textures_fn,
             );
 
@@ -1745,11 +2376,12 @@ pub fn do_the_layout_internal(
     let layout_display_info = get_layout_displays(&styled_dom);
     let layout_directions_info = get_layout_flex_directions(&styled_dom);
     let layout_justify_contents = get_layout_justify_contents(&styled_dom);
+    let layout_grids = get_layout_grids(&styled_dom, &layout_display_info.as_ref(), &layout_position_info.as_ref());
     let layout_offsets = precalculate_all_offsets(&styled_dom);
     let layout_width_heights = precalculate_wh_config(&styled_dom);
 
     // Break all strings into words and / or resolve the TextIds
-    let word_cache = create_word_cache(&styled_dom.node_data.as_container());
+    let word_cache = create_word_cache(&styled_dom);
     // Scale the words to the correct size - TODO: Cache this in the app_resources!
     let shaped_words = create_shaped_words(renderer_resources, &word_cache, &styled_dom);
 
@@ -1819,6 +2451,7 @@ pub fn do_the_layout_internal(
         &layout_display_info.as_ref(),
         &layout_position_info.as_ref(),
         &layout_directions_info.as_ref(),
+        &layout_grids,
         &styled_dom.node_hierarchy.as_container(),
         &layout_width_heights.as_ref(),
         styled_dom.non_leaf_nodes.as_ref(),
@@ -1900,6 +2533,7 @@ pub fn do_the_layout_internal(
         &layout_display_info.as_ref(),
         &layout_position_info.as_ref(),
         &layout_directions_info.as_ref(),
+        &layout_grids,
         &styled_dom.node_hierarchy.as_container(),
         &layout_width_heights.as_ref(),
         styled_dom.non_leaf_nodes.as_ref(),
@@ -1918,6 +2552,7 @@ pub fn do_the_layout_internal(
         &layout_position_info.as_ref(),
         &layout_directions_info.as_ref(),
         &layout_justify_contents.as_ref(),
+        &layout_grids,
         &styled_dom.non_leaf_nodes.as_ref(),
         rect_offset.clone(),
         &all_parents_btreeset,
@@ -1934,6 +2569,7 @@ pub fn do_the_layout_internal(
         &layout_position_info.as_ref(),
         &layout_directions_info.as_ref(),
         &layout_justify_contents.as_ref(),
+        &layout_grids,
         &styled_dom.non_leaf_nodes.as_ref(),
         rect_offset,
         &all_parents_btreeset,
@@ -2234,6 +2870,7 @@ fn position_nodes<'a>(
                             css_property_cache.get_justify_content(child_node_data, &child_node_id, child_styled_node_state)
                             .cloned().and_then(|p| p.get_property_or_default()).unwrap_or_default(),
                             css_property_cache.get_text_align(child_node_data, &child_node_id, child_styled_node_state).cloned(),
+                            word_positions.text_layout_options.direction,
                         );
 
                         inline_text_layout.align_children_horizontal(&child_size_logical, horz_alignment);
@@ -2291,11 +2928,15 @@ fn position_nodes<'a>(
 
 #[cfg(feature = "text_layout")]
 fn create_word_cache<'a>(
-    node_data: &NodeDataContainerRef<'a, NodeData>,
+    styled_dom: &'a StyledDom,
 ) -> BTreeMap<NodeId, Words>
 {
     use azul_text_layout::text_layout::split_text_into_words;
 
+    let css_property_cache = styled_dom.get_css_property_cache();
+    let styled_nodes = styled_dom.styled_nodes.as_container();
+    let node_data = styled_dom.node_data.as_container();
+
     let word_map = node_data.internal
     .par_iter()
     .enumerate()
@@ -2305,13 +2946,57 @@ fn create_word_cache<'a>(
             NodeType::Text(string) => Some(string.as_str()),
             _ => None,
         }?;
-        Some((node_id, split_text_into_words(string)))
+        let styled_node_state = &styled_nodes[node_id].state;
+        let direction = get_text_direction(css_property_cache, node, &node_id, styled_node_state);
+        let (word_break, overflow_wrap, hyphens) = get_line_break_properties(css_property_cache, node, &node_id, styled_node_state);
+        Some((node_id, split_text_into_words(string, direction, word_break, overflow_wrap, hyphens)))
     })
     .collect::<Vec<_>>();
 
     word_map.into_iter().filter_map(|a| a).collect()
 }
 
+/// Returns the (inherited) value of the `direction` property of a node
+#[cfg(feature = "text_layout")]
+fn get_text_direction(
+    css_property_cache: &CssPropertyCache,
+    node_data: &NodeData,
+    node_id: &NodeId,
+    styled_node_state: &StyledNodeState,
+) -> StyleDirection {
+    css_property_cache
+    .get_direction(node_data, node_id, styled_node_state)
+    .and_then(|d| d.get_property().copied())
+    .unwrap_or_default()
+}
+
+/// Returns the (inherited) values of the `word-break`, `overflow-wrap` and `hyphens` properties of a node
+#[cfg(feature = "text_layout")]
+fn get_line_break_properties(
+    css_property_cache: &CssPropertyCache,
+    node_data: &NodeData,
+    node_id: &NodeId,
+    styled_node_state: &StyledNodeState,
+) -> (StyleWordBreak, StyleOverflowWrap, StyleHyphens) {
+
+    let word_break = css_property_cache
+    .get_word_break(node_data, node_id, styled_node_state)
+    .and_then(|wb| wb.get_property().copied())
+    .unwrap_or_default();
+
+    let overflow_wrap = css_property_cache
+    .get_overflow_wrap(node_data, node_id, styled_node_state)
+    .and_then(|ow| ow.get_property().copied())
+    .unwrap_or_default();
+
+    let hyphens = css_property_cache
+    .get_hyphens(node_data, node_id, styled_node_state)
+    .and_then(|h| h.get_property().copied())
+    .unwrap_or_default();
+
+    (word_break, overflow_wrap, hyphens)
+}
+
 // same as get_inline_text(), but shapes a new word instead of using the internal one
 // - necessary to implement text cursor, so that we can calculate the x-offset of
 // the text cursor for the next frame (after the character has been pressed)
@@ -2326,6 +3011,24 @@ pub fn callback_info_shape_text(
     Some(azul_text_layout::text_layout::shape_text(&font_ref, text.as_str(), &text_layout_options))
 }
 
+/// Returns the fallback fonts of the font families (without the primary font),
+/// in the order of the `GlyphInfo::font_index`
+#[cfg(feature = "text_layout")]
+fn get_fallback_fonts<'a>(
+    renderer_resources: &'a RendererResources,
+    css_font_families_hash: &azul_core::styled_dom::StyleFontFamiliesHash,
+) -> Vec<&'a azul_text_layout::text_shaping::ParsedFont> {
+
+    use azul_text_layout::text_shaping::ParsedFont;
+
+    renderer_resources.get_font_fallback_keys(css_font_families_hash)
+    .iter()
+    .skip(1)
+    .filter_map(|font_key| renderer_resources.get_registered_font(font_key))
+    .map(|(font_ref, _)| unsafe { &*(font_ref.get_data().parsed as *const ParsedFont) })
+    .collect()
+}
+
 #[cfg(feature = "text_layout")]
 pub fn create_shaped_words<'a>(
     renderer_resources: &RendererResources,
@@ -2358,7 +3061,10 @@ pub fn create_shaped_words<'a>(
         // downcast the loaded_font.font from *const c_void to *const ParsedFont
         let parsed_font_downcasted = unsafe { &*(font_data.parsed as *const ParsedFont) };
 
-        let shaped_words = shape_words(words, parsed_font_downcasted);
+        let fallback_fonts = get_fallback_fonts(renderer_resources, &css_font_families_hash);
+
+        let direction = get_text_direction(css_property_cache, node_data, node_id, styled_node_state);
+        let shaped_words = shape_words(words, parsed_font_downcasted, &fallback_fonts, direction);
 
         Some((*node_id, shaped_words))
     }).collect()
@@ -2460,6 +3166,8 @@ fn create_word_positions<'a>(
         .get_tab_width(node_data, node_id, &styled_node_state)
         .and_then(|tw| Some(tw.get_property()?.inner.get()));
 
+        let (word_break, overflow_wrap, hyphens) = get_line_break_properties(css_property_cache, node_data, node_id, &styled_node_state);
+
         let text_layout_options = ResolvedTextLayoutOptions {
             max_horizontal_width: max_text_width.into(),
             leading: None.into(), // TODO
@@ -2469,6 +3177,10 @@ fn create_word_positions<'a>(
             letter_spacing: letter_spacing.into(),
             line_height: line_height.into(),
             tab_width: tab_width.into(),
+            direction: get_text_direction(css_property_cache, node_data, node_id, &styled_node_state),
+            word_break,
+            overflow_wrap,
+            hyphens,
         };
 
         let w = position_words(words, shaped_words, &text_layout_options);
@@ -2488,6 +3200,7 @@ fn determine_text_alignment(
     align_items: LayoutAlignItems,
     justify_content: LayoutJustifyContent,
     text_align: Option<CssPropertyValue<StyleTextAlign>>,
+    direction: StyleDirection,
 )
     -> (StyleTextAlign, StyleVerticalAlign)
 {
@@ -2501,8 +3214,8 @@ fn determine_text_alignment(
 
     // Horizontal text alignment
     let mut horz_alignment = match justify_content {
-        LayoutJustifyContent::Start => StyleTextAlign::Left,
-        LayoutJustifyContent::End => StyleTextAlign::Right,
+        LayoutJustifyContent::Start => StyleTextAlign::Start,
+        LayoutJustifyContent::End => StyleTextAlign::End,
         _ => StyleTextAlign::Center,
     };
 
@@ -2511,6 +3224,9 @@ fn determine_text_alignment(
         horz_alignment = text_align;
     }
 
+    // start / end depend on the writing direction
+    let horz_alignment = horz_alignment.resolve(direction);
+
     (horz_alignment, vert_alignment)
 }
 
@@ -2569,7 +3285,22 @@ fn get_nodes_that_need_scroll_clip(
                     _ => true,
                 }
             })
-            .map(|child_id| layouted_rects[child_id].get_approximate_static_bounds())
+            .map(|child_id| {
+                // the margins of the children are part of the scrollable area
+                let child_rect = &layouted_rects[child_id];
+                let bounds = child_rect.get_approximate_static_bounds();
+                let margin = &child_rect.margin;
+                LayoutRect::new(
+                    LayoutPoint::new(
+                        bounds.origin.x - margin.left.round() as isize,
+                        bounds.origin.y - margin.top.round() as isize,
+                    ),
+                    LayoutSize::new(
+                        bounds.size.width + (margin.left + margin.right).round() as isize,
+                        bounds.size.height + (margin.top + margin.bottom).round() as isize,
+                    ),
+                )
+            })
         )?;
 
         // only register the directly overflowing children
@@ -2780,6 +3511,13 @@ pub fn do_the_relayout(
     let mut parents_that_need_to_reposition_children_x = BTreeSet::new();
     let mut parents_that_need_to_reposition_children_y = BTreeSet::new();
 
+    // grid tracks + item placements depend on the display / position of the children
+    let layout_grids = get_layout_grids(
+        &layout_result.styled_dom,
+        &layout_result.layout_displays.as_ref(),
+        &layout_result.layout_positions.as_ref(),
+    );
+
     /*
     if display_changed {
         // recalculate changed display:none nodes
@@ -2834,7 +3572,13 @@ pub fn do_the_relayout(
                 Some(s) => s.0.clone(),
             };
 
-            let new_words = split_text_into_words(new_string.as_str());
+            let new_words = split_text_into_words(
+                new_string.as_str(),
+                text_layout_options.direction,
+                text_layout_options.word_break,
+                text_layout_options.overflow_wrap,
+                text_layout_options.hyphens,
+            );
 
             let css_property_cache = layout_result.styled_dom.get_css_property_cache();
             let styled_nodes = layout_result.styled_dom.styled_nodes.as_container();
@@ -2858,7 +3602,8 @@ pub fn do_the_relayout(
             };
             let font_data = font_ref.get_data();
             let parsed_font_downcasted = unsafe { &*(font_data.parsed as *const ParsedFont) };
-            let new_shaped_words = shape_words(&new_words, parsed_font_downcasted);
+            let fallback_fonts = get_fallback_fonts(renderer_resources, &css_font_families_hash);
+            let new_shaped_words = shape_words(&new_words, parsed_font_downcasted, &fallback_fonts, text_layout_options.direction);
 
             let font_size = css_property_cache.get_font_size_or_default(node_data, node_id, &styled_node_state);
             let font_size_px = font_size.inner.to_pixels(DEFAULT_FONT_SIZE_PX as f32);
@@ -3068,6 +3813,35 @@ pub fn do_the_relayout(
                         margin_y_changed = true;
                     }
 
+                    let grid_tracks_changed =
+                        changes_for_this_node.contains_key(&CssPropertyType::GridTemplateColumns) ||
+                        changes_for_this_node.contains_key(&CssPropertyType::GridTemplateRows) ||
+                        changes_for_this_node.contains_key(&CssPropertyType::GridAutoColumns) ||
+                        changes_for_this_node.contains_key(&CssPropertyType::GridAutoRows) ||
+                        changes_for_this_node.contains_key(&CssPropertyType::GridAutoFlow) ||
+                        changes_for_this_node.contains_key(&CssPropertyType::ColumnGap) ||
+                        changes_for_this_node.contains_key(&CssPropertyType::RowGap);
+
+                    let grid_placement_changed =
+                        changes_for_this_node.contains_key(&CssPropertyType::GridColumn) ||
+                        changes_for_this_node.contains_key(&CssPropertyType::GridRow);
+
+                    if grid_tracks_changed {
+                        // need to recalc the sizes + positions of all grid items
+                        parents_that_need_to_recalc_width_of_children.insert($node_id);
+                        parents_that_need_to_recalc_height_of_children.insert($node_id);
+                        parents_that_need_to_reposition_children_x.insert($node_id);
+                        parents_that_need_to_reposition_children_y.insert($node_id);
+                    }
+
+                    if grid_placement_changed {
+                        // need to recalc the sizes + positions of all siblings
+                        parents_that_need_to_recalc_width_of_children.insert($parent_id);
+                        parents_that_need_to_recalc_height_of_children.insert($parent_id);
+                        parents_that_need_to_reposition_children_x.insert($parent_id);
+                        parents_that_need_to_reposition_children_y.insert($parent_id);
+                    }
+
                     if let Some((previous_preferred_width, current_preferred_width)) = preferred_width_changed {
                         // need to recalc the width of the node
                         // need to bubble the width to the parent width
@@ -3202,6 +3976,7 @@ pub fn do_the_relayout(
             &layout_result.layout_flex_grows.as_ref(),
             &layout_result.layout_positions.as_ref(),
             &layout_result.layout_flex_directions.as_ref(),
+            &layout_grids,
             &layout_result.styled_dom.non_leaf_nodes.as_ref(),
             root_size.width as f32,
             // important - only recalc the widths necessary!
@@ -3248,6 +4023,7 @@ pub fn do_the_relayout(
             &layout_result.layout_flex_grows.as_ref(),
             &layout_result.layout_positions.as_ref(),
             &layout_result.layout_flex_directions.as_ref(),
+            &layout_grids,
             &layout_result.styled_dom.non_leaf_nodes.as_ref(),
             root_size.height as f32,
             // important - only recalc the heights necessary!
@@ -3310,6 +4086,7 @@ pub fn do_the_relayout(
         &layout_result.layout_flex_grows.as_ref(),
         &layout_result.layout_positions.as_ref(),
         &layout_result.layout_flex_directions.as_ref(),
+        &layout_grids,
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
         root_size.width as f32,
         // important - only recalc the widths necessary!
@@ -3323,6 +4100,7 @@ pub fn do_the_relayout(
         &layout_result.layout_flex_grows.as_ref(),
         &layout_result.layout_positions.as_ref(),
         &layout_result.layout_flex_directions.as_ref(),
+        &layout_grids,
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
         root_size.height as f32,
         // important - only recalc the heights necessary!
@@ -3338,6 +4116,7 @@ pub fn do_the_relayout(
         &layout_result.layout_positions.as_ref(),
         &layout_result.layout_flex_directions.as_ref(),
         &layout_result.layout_justify_contents.as_ref(),
+        &layout_grids,
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
         LogicalPosition::new(root_bounds.origin.x as f32, root_bounds.origin.y as f32),
         &parents_that_need_to_reposition_children_x, // <- important
@@ -3350,6 +4129,7 @@ pub fn do_the_relayout(
         &layout_result.layout_positions.as_ref(),
         &layout_result.layout_flex_directions.as_ref(),
         &layout_result.layout_justify_contents.as_ref(),
+        &layout_grids,
         &layout_result.styled_dom.non_leaf_nodes.as_ref(),
         LogicalPosition::new(root_bounds.origin.x as f32, root_bounds.origin.y as f32),
         &parents_that_need_to_reposition_children_y, // <- important
@@ -3472,4 +4252,169 @@ fn test_hash() {
     let a = NodeData::new(NodeType::Div);
     let b = NodeData::new(NodeType::Div);
     assert_eq!(a.calculate_node_data_hash(), b.calculate_node_data_hash())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod grid_tests {
+
+    use super::*;
+
+    const EPSILON: f32 = 0.001;
+
+    fn axis(tracks: &[GridTrackSizing], gap: isize) -> GridAxis {
+        GridAxis { tracks: tracks.to_vec(), gap: PixelValue::const_px(gap) }
+    }
+
+    fn span(start: usize, count: usize) -> GridSpan {
+        GridSpan { start, count }
+    }
+
+    fn placement(start: GridLine, end: GridLine) -> GridPlacement {
+        GridPlacement { start, end }
+    }
+
+    fn assert_tracks(actual: Vec<f32>, expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len(), "{:?} != {:?}", actual, expected);
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < EPSILON, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_grid_tracks_fixed_auto_fr() {
+        // 100px auto 1fr 2fr, the auto track fits its 50px item
+        let columns = axis(&[
+            GridTrackSizing::fixed(PixelValue::const_px(100)),
+            GridTrackSizing::auto(),
+            GridTrackSizing::fraction(FloatValue::const_new(1)),
+            GridTrackSizing::fraction(FloatValue::const_new(2)),
+        ], 0);
+        let items = [(span(1, 1), 50.0)];
+        assert_tracks(columns.solve_track_sizes(Some(1000.0), &items), &[100.0, 50.0, 850.0 / 3.0, 1700.0 / 3.0]);
+        // minimum size: the fr tracks collapse
+        assert_tracks(columns.solve_track_sizes(None, &items), &[100.0, 50.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_grid_tracks_auto_stretch() {
+        // without fr tracks, the auto tracks share the remaining space
+        let columns = axis(&[
+            GridTrackSizing::fixed(PixelValue::const_px(100)),
+            GridTrackSizing::auto(),
+            GridTrackSizing::auto(),
+        ], 0);
+        let items = [(span(1, 1), 50.0)];
+        assert_tracks(columns.solve_track_sizes(Some(1000.0), &items), &[100.0, 475.0, 425.0]);
+    }
+
+    #[test]
+    fn test_grid_tracks_fr_larger_than_share() {
+        // the first track is larger than 1fr and is treated as inflexible
+        let columns = axis(&[
+            GridTrackSizing::fraction(FloatValue::const_new(1)),
+            GridTrackSizing::fraction(FloatValue::const_new(1)),
+        ], 0);
+        let items = [(span(0, 1), 300.0)];
+        assert_tracks(columns.solve_track_sizes(Some(400.0), &items), &[300.0, 100.0]);
+    }
+
+    #[test]
+    fn test_grid_tracks_gap_and_area() {
+        let columns = axis(&[
+            GridTrackSizing::fraction(FloatValue::const_new(1)),
+            GridTrackSizing::fraction(FloatValue::const_new(1)),
+        ], 10);
+        let tracks = columns.solve_track_sizes(Some(210.0), &[]);
+        assert_tracks(tracks.clone(), &[100.0, 100.0]);
+        assert_eq!(columns.get_area(&tracks, 210.0, span(0, 1)), (0.0, 100.0));
+        assert_eq!(columns.get_area(&tracks, 210.0, span(1, 1)), (110.0, 100.0));
+        // the gap between spanned tracks is part of the area
+        assert_eq!(columns.get_area(&tracks, 210.0, span(0, 2)), (0.0, 210.0));
+    }
+
+    #[test]
+    fn test_grid_tracks_spanning_item() {
+        // an item spanning two auto tracks distributes its size equally
+        let columns = axis(&[GridTrackSizing::auto(), GridTrackSizing::auto()], 0);
+        let items = [(span(0, 2), 100.0)];
+        assert_tracks(columns.solve_track_sizes(None, &items), &[50.0, 50.0]);
+        // ... but not onto fixed tracks
+        let columns = axis(&[GridTrackSizing::fixed(PixelValue::const_px(20)), GridTrackSizing::auto()], 0);
+        assert_tracks(columns.solve_track_sizes(None, &items), &[20.0, 80.0]);
+    }
+
+    #[test]
+    fn test_grid_placement_explicit_lines() {
+        use self::GridLine::*;
+        assert_eq!(resolve_grid_placement(&placement(Line(2), Line(4)), 3), (Some(1), 2));
+        // reversed lines are swapped
+        assert_eq!(resolve_grid_placement(&placement(Line(3), Line(1)), 3), (Some(0), 2));
+        // same start and end line: one track
+        assert_eq!(resolve_grid_placement(&placement(Line(2), Line(2)), 3), (Some(1), 1));
+        assert_eq!(resolve_grid_placement(&placement(Line(2), Auto), 3), (Some(1), 1));
+        assert_eq!(resolve_grid_placement(&placement(Auto, Line(3)), 3), (Some(1), 1));
+        assert_eq!(resolve_grid_placement(&placement(Auto, Auto), 3), (None, 1));
+    }
+
+    #[test]
+    fn test_grid_placement_spans() {
+        use self::GridLine::*;
+        assert_eq!(resolve_grid_placement(&placement(Line(1), Span(2)), 3), (Some(0), 2));
+        assert_eq!(resolve_grid_placement(&placement(Span(2), Line(4)), 3), (Some(1), 2));
+        assert_eq!(resolve_grid_placement(&placement(Span(3), Auto), 3), (None, 3));
+        assert_eq!(resolve_grid_placement(&placement(Auto, Span(2)), 3), (None, 2));
+        // span 0 is invalid, spans at least one track
+        assert_eq!(resolve_grid_placement(&placement(Line(1), Span(0)), 3), (Some(0), 1));
+        // spans reaching before the first line are clamped
+        assert_eq!(resolve_grid_placement(&placement(Span(5), Line(2)), 3), (Some(0), 1));
+    }
+
+    #[test]
+    fn test_grid_placement_negative_lines() {
+        use self::GridLine::*;
+        // -1 is the last line of the explicit grid
+        assert_eq!(resolve_grid_placement(&placement(Line(1), Line(-1)), 3), (Some(0), 3));
+        assert_eq!(resolve_grid_placement(&placement(Line(-2), Auto), 3), (Some(2), 1));
+        assert_eq!(resolve_grid_placement(&placement(Span(2), Line(-1)), 3), (Some(1), 2));
+        // lines before the start of the explicit grid are clamped to the first line
+        assert_eq!(resolve_grid_placement(&placement(Line(-10), Line(2)), 3), (Some(0), 1));
+    }
+
+    #[test]
+    fn test_grid_placement_line_zero_is_auto() {
+        use self::GridLine::*;
+        assert_eq!(resolve_grid_placement(&placement(Line(0), Line(0)), 3), (None, 1));
+        assert_eq!(resolve_grid_placement(&placement(Line(0), Span(2)), 3), (None, 2));
+        assert_eq!(resolve_grid_placement(&placement(Line(2), Line(0)), 3), (Some(1), 1));
+        assert_eq!(resolve_grid_placement(&placement(Line(0), Line(3)), 3), (Some(1), 1));
+    }
+
+    #[test]
+    fn test_grid_auto_placement() {
+        use self::GridLine::*;
+
+        let auto = placement(Auto, Auto);
+        let items = [
+            (NodeId::new(1), auto, auto),
+            (NodeId::new(2), placement(Span(2), Auto), auto),
+            (NodeId::new(3), placement(Line(1), Line(-1)), auto),
+            (NodeId::new(4), placement(Line(-2), Auto), placement(Line(1), Auto)),
+            (NodeId::new(5), auto, auto),
+        ];
+
+        let placed = place_grid_items(&items, 3, 0, LayoutGridAutoFlow::Row);
+        let cell = |id: usize| {
+            let p = placed[&NodeId::new(id)];
+            ((p.column.start, p.column.count), (p.row.start, p.row.count))
+        };
+
+        // the explicitly placed item is placed first, the auto-placed items flow around it
+        assert_eq!(cell(4), ((2, 1), (0, 1)));
+        assert_eq!(cell(1), ((0, 1), (0, 1)));
+        // the span 2 item doesn't fit next to the explicit item, so it starts a new row
+        assert_eq!(cell(2), ((0, 2), (1, 1)));
+        // full width item
+        assert_eq!(cell(3), ((0, 3), (2, 1)));
+        assert_eq!(cell(5), ((0, 1), (3, 1)));
+    }
+}
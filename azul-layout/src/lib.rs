@@ -28,3 +28,5 @@ pub use layout_solver::{
 pub use layout_solver::callback_info_shape_text;
 #[cfg(feature = "text_layout")]
 pub use azul_text_layout::parse_font_fn;
+#[cfg(feature = "text_layout")]
+pub use azul_text_layout::font_has_glyph_fn;
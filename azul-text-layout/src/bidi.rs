@@ -0,0 +1,1642 @@
+//! Implementation of the Unicode Bidirectional Algorithm ([UAX #9](https://www.unicode.org/reports/tr9/))
+//!
+//! Resolves the embedding level of every character in a paragraph (explicit
+//! embeddings / overrides / isolates, weak and neutral types, bracket pairs)
+//! and reorders runs of text from the logical into the visual order.
+//!
+//! The paragraph level is not auto-detected (P2 / P3), instead it is set by
+//! the `direction` CSS property (higher-level protocol HL1).
+
+use alloc::vec::Vec;
+use azul_css::StyleDirection;
+
+/// Embedding level of a character - even levels are left-to-right, odd levels are right-to-left
+pub type BidiLevel = u8;
+
+/// Maximum explicit embedding depth (BD2)
+pub const MAX_DEPTH: BidiLevel = 125;
+
+/// Maximum nesting depth of bracket pairs (BD16)
+const MAX_BRACKET_PAIRS_STACK: usize = 63;
+
+/// `Bidi_Class` property of a Unicode character
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BidiClass {
+    /// Left-to-right
+    L,
+    /// Right-to-left
+    R,
+    /// Right-to-left Arabic
+    AL,
+    /// European number
+    EN,
+    /// European number separator
+    ES,
+    /// European number terminator
+    ET,
+    /// Arabic number
+    AN,
+    /// Common number separator
+    CS,
+    /// Nonspacing mark
+    NSM,
+    /// Boundary neutral
+    BN,
+    /// Paragraph separator
+    B,
+    /// Segment separator
+    S,
+    /// Whitespace
+    WS,
+    /// Other neutrals
+    ON,
+    /// Left-to-right embedding
+    LRE,
+    /// Left-to-right override
+    LRO,
+    /// Right-to-left embedding
+    RLE,
+    /// Right-to-left override
+    RLO,
+    /// Pop directional format
+    PDF,
+    /// Left-to-right isolate
+    LRI,
+    /// Right-to-left isolate
+    RLI,
+    /// First strong isolate
+    FSI,
+    /// Pop directional isolate
+    PDI,
+}
+
+use self::BidiClass::*;
+
+impl BidiClass {
+
+    /// Returns whether the class is `R` or `AL`
+    pub fn is_rtl(&self) -> bool {
+        matches!(self, R | AL)
+    }
+
+    /// Returns whether the class is a strong type (`L`, `R` or `AL`)
+    pub fn is_strong(&self) -> bool {
+        matches!(self, L | R | AL)
+    }
+
+    fn is_isolate_initiator(&self) -> bool {
+        matches!(self, LRI | RLI | FSI)
+    }
+
+    /// Characters that are ignored by the rules after X9
+    fn is_removed_by_x9(&self) -> bool {
+        matches!(self, RLE | LRE | RLO | LRO | PDF | BN)
+    }
+
+    /// Neutral or isolate formatting character (NI)
+    fn is_neutral_or_isolate(&self) -> bool {
+        matches!(self, B | S | WS | ON | LRI | RLI | FSI | PDI)
+    }
+
+    /// Direction of the class for resolving neutrals (N0 / N1),
+    /// numbers are treated as right-to-left
+    fn get_strong_direction(&self) -> Option<BidiClass> {
+        match self {
+            L => Some(L),
+            R | AL | EN | AN => Some(R),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the `Bidi_Class` of a character
+pub fn get_bidi_class(c: char) -> BidiClass {
+    use core::cmp::Ordering;
+    match BIDI_CLASS_TABLE.binary_search_by(|&(start, end, _)| {
+        if c < start {
+            Ordering::Greater
+        } else if c > end {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(idx) => BIDI_CLASS_TABLE[idx].2,
+        Err(_) => L,
+    }
+}
+
+/// Returns the `Bidi_Mirroring_Glyph` of a character, i.e. the character
+/// that has to be displayed instead if the character is on a right-to-left level
+pub fn get_mirrored_char(c: char) -> Option<char> {
+    BIDI_MIRRORING_TABLE
+    .binary_search_by(|&(k, _)| k.cmp(&c))
+    .ok()
+    .map(|idx| BIDI_MIRRORING_TABLE[idx].1)
+}
+
+/// Returns whether the embedding level is a right-to-left level
+#[inline]
+pub const fn is_rtl_level(level: BidiLevel) -> bool {
+    level % 2 == 1
+}
+
+/// Returns the paragraph embedding level for the given `direction`
+#[inline]
+pub fn get_base_level(direction: StyleDirection) -> BidiLevel {
+    match direction {
+        StyleDirection::Ltr => 0,
+        StyleDirection::Rtl => 1,
+    }
+}
+
+/// Returns the `Bidi_Class` of each codepoint (invalid codepoints are treated as `L`)
+pub fn get_bidi_classes(text: &[u32]) -> Vec<BidiClass> {
+    text.iter()
+    .map(|c| core::char::from_u32(*c).map(get_bidi_class).unwrap_or(L))
+    .collect()
+}
+
+/// Resolves the embedding levels of a text, given as a list of unicode
+/// codepoints (see `Words::internal_chars`). The text may contain multiple
+/// paragraphs (separated by newlines), every paragraph is resolved independently
+/// with the same `base_level`.
+///
+/// Trailing whitespace is only reset to the paragraph level at the end of
+/// paragraphs (rule L1), the caller has to do the same at the end of each line.
+pub fn resolve_levels(text: &[u32], base_level: BidiLevel) -> Vec<BidiLevel> {
+
+    let classes = get_bidi_classes(text);
+    let mut levels = vec![base_level; text.len()];
+
+    let mut paragraph_start = 0;
+    for i in 0..classes.len() {
+        if classes[i] == B || i == classes.len() - 1 {
+            resolve_paragraph(
+                &text[paragraph_start..=i],
+                &classes[paragraph_start..=i],
+                base_level,
+                &mut levels[paragraph_start..=i],
+            );
+            paragraph_start = i + 1;
+        }
+    }
+
+    levels
+}
+
+/// Returns the indices of the items in visual order (rule L2) - reverses any contiguous
+/// sequence of items that are at a certain level or higher, from the highest level
+/// down to the lowest odd level
+pub fn reorder_visual(levels: &[BidiLevel]) -> Vec<usize> {
+
+    let mut order = (0..levels.len()).collect::<Vec<usize>>();
+
+    let max_level = match levels.iter().copied().max() {
+        Some(s) => s,
+        None => return order,
+    };
+
+    let lowest_odd_level = match levels.iter().copied().filter(|l| is_rtl_level(*l)).min() {
+        Some(s) => s,
+        None => return order, // all levels are LTR
+    };
+
+    for level in (lowest_odd_level..=max_level).rev() {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] >= level {
+                let run_start = i;
+                while i < order.len() && levels[order[i]] >= level {
+                    i += 1;
+                }
+                order[run_start..i].reverse();
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    order
+}
+
+#[derive(Debug, Copy, Clone)]
+struct DirectionalStatus {
+    level: BidiLevel,
+    override_class: Option<BidiClass>,
+    isolate: bool,
+}
+
+/// Least odd / even level greater than `level` (X2 - X5)
+#[inline]
+fn next_level(level: BidiLevel, rtl: bool) -> BidiLevel {
+    if rtl {
+        (level + 1) | 1
+    } else {
+        (level + 2) & !1
+    }
+}
+
+/// Returns the index of the matching PDI for every isolate initiator (BD9)
+fn get_matching_pdis(classes: &[BidiClass]) -> (Vec<Option<usize>>, Vec<bool>) {
+
+    let mut matching_pdi = vec![None; classes.len()];
+    let mut has_matching_initiator = vec![false; classes.len()];
+    let mut initiator_stack = Vec::new();
+
+    for (i, class) in classes.iter().enumerate() {
+        if class.is_isolate_initiator() {
+            initiator_stack.push(i);
+        } else if *class == PDI {
+            if let Some(initiator) = initiator_stack.pop() {
+                matching_pdi[initiator] = Some(i);
+                has_matching_initiator[i] = true;
+            }
+        }
+    }
+
+    (matching_pdi, has_matching_initiator)
+}
+
+/// Returns the direction of the first strong character in `classes[start..end]`,
+/// skipping characters between isolate initiators and their matching PDI (P2)
+fn get_first_strong_class(
+    classes: &[BidiClass],
+    matching_pdi: &[Option<usize>],
+    start: usize,
+    end: usize,
+) -> Option<BidiClass> {
+    let mut i = start;
+    while i < end {
+        match classes[i] {
+            L => return Some(L),
+            R | AL => return Some(R),
+            c if c.is_isolate_initiator() => {
+                i = matching_pdi[i]?;
+            },
+            _ => { },
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Resolves the levels of a single paragraph (rules X1 - I2 and
+/// the paragraph-related part of L1)
+fn resolve_paragraph(
+    text: &[u32],
+    classes: &[BidiClass],
+    paragraph_level: BidiLevel,
+    levels: &mut [BidiLevel],
+) {
+
+    let (matching_pdi, has_matching_initiator) = get_matching_pdis(classes);
+
+    // classes that are modified by the rules X1 - N2
+    let mut processing_classes = classes.to_vec();
+
+    // X1 - X8: explicit levels and directions
+    let mut stack = vec![DirectionalStatus {
+        level: paragraph_level,
+        override_class: None,
+        isolate: false,
+    }];
+    let mut overflow_isolates = 0_usize;
+    let mut overflow_embeddings = 0_usize;
+    let mut valid_isolates = 0_usize;
+
+    for i in 0..classes.len() {
+        let last = *stack.last().unwrap();
+        match classes[i] {
+            RLE | LRE | RLO | LRO => {
+                levels[i] = last.level;
+                let rtl = matches!(classes[i], RLE | RLO);
+                let new_level = next_level(last.level, rtl);
+                if new_level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    stack.push(DirectionalStatus {
+                        level: new_level,
+                        override_class: match classes[i] {
+                            RLO => Some(R),
+                            LRO => Some(L),
+                            _ => None,
+                        },
+                        isolate: false,
+                    });
+                } else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            },
+            RLI | LRI | FSI => {
+                levels[i] = last.level;
+                if let Some(o) = last.override_class {
+                    processing_classes[i] = o;
+                }
+                let rtl = match classes[i] {
+                    RLI => true,
+                    LRI => false,
+                    _ => {
+                        let end = matching_pdi[i].unwrap_or(classes.len());
+                        get_first_strong_class(classes, &matching_pdi, i + 1, end) == Some(R)
+                    },
+                };
+                let new_level = next_level(last.level, rtl);
+                if new_level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    valid_isolates += 1;
+                    stack.push(DirectionalStatus {
+                        level: new_level,
+                        override_class: None,
+                        isolate: true,
+                    });
+                } else {
+                    overflow_isolates += 1;
+                }
+            },
+            PDI => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while !stack.last().unwrap().isolate {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+                let last = *stack.last().unwrap();
+                levels[i] = last.level;
+                if let Some(o) = last.override_class {
+                    processing_classes[i] = o;
+                }
+            },
+            PDF => {
+                if overflow_isolates > 0 {
+                    // do nothing
+                } else if overflow_embeddings > 0 {
+                    overflow_embeddings -= 1;
+                } else if !last.isolate && stack.len() >= 2 {
+                    stack.pop();
+                }
+                levels[i] = stack.last().unwrap().level;
+            },
+            B => {
+                levels[i] = paragraph_level;
+            },
+            BN => {
+                levels[i] = last.level;
+            },
+            _ => {
+                levels[i] = last.level;
+                if let Some(o) = last.override_class {
+                    processing_classes[i] = o;
+                }
+            },
+        }
+    }
+
+    // X9: remove embedding / override characters and boundary neutrals
+    let removed = classes.iter().map(|c| c.is_removed_by_x9()).collect::<Vec<bool>>();
+
+    // X10: compute the level runs and the isolating run sequences
+    let mut level_runs: Vec<Vec<usize>> = Vec::new();
+    let mut last_level = None;
+    for i in (0..classes.len()).filter(|i| !removed[*i]) {
+        if last_level == Some(levels[i]) {
+            level_runs.last_mut().unwrap().push(i);
+        } else {
+            level_runs.push(vec![i]);
+            last_level = Some(levels[i]);
+        }
+    }
+
+    let mut run_starting_with = vec![None; classes.len()];
+    for (run_idx, run) in level_runs.iter().enumerate() {
+        run_starting_with[run[0]] = Some(run_idx);
+    }
+
+    for run in level_runs.iter() {
+
+        // runs starting with a matching PDI are appended to the sequence of their initiator
+        if classes[run[0]] == PDI && has_matching_initiator[run[0]] {
+            continue;
+        }
+
+        let mut sequence = run.clone();
+        loop {
+            let last = *sequence.last().unwrap();
+            let next_run = classes[last]
+                .is_isolate_initiator()
+                .then(|| matching_pdi[last])
+                .flatten()
+                .and_then(|pdi| run_starting_with[pdi]);
+            match next_run {
+                Some(next_run) => sequence.extend_from_slice(&level_runs[next_run]),
+                None => break,
+            }
+        }
+
+        resolve_isolating_run_sequence(
+            text,
+            classes,
+            &removed,
+            &sequence,
+            paragraph_level,
+            levels,
+            &mut processing_classes,
+        );
+    }
+
+    // removed characters get the level of the preceding character
+    let mut previous_level = paragraph_level;
+    for i in 0..classes.len() {
+        if removed[i] {
+            levels[i] = previous_level;
+        } else {
+            previous_level = levels[i];
+        }
+    }
+
+    // L1: reset separators and any whitespace preceding them
+    // (or the end of the paragraph) to the paragraph level
+    let mut reset_whitespace = true;
+    for i in (0..classes.len()).rev() {
+        match classes[i] {
+            B | S => {
+                levels[i] = paragraph_level;
+                reset_whitespace = true;
+            },
+            WS | LRI | RLI | FSI | PDI | RLE | LRE | RLO | LRO | PDF | BN => {
+                if reset_whitespace {
+                    levels[i] = paragraph_level;
+                }
+            },
+            _ => {
+                reset_whitespace = false;
+            },
+        }
+    }
+}
+
+/// Resolves weak types, neutral types and implicit levels
+/// of one isolating run sequence (rules W1 - I2)
+fn resolve_isolating_run_sequence(
+    text: &[u32],
+    original_classes: &[BidiClass],
+    removed: &[bool],
+    sequence: &[usize],
+    paragraph_level: BidiLevel,
+    levels: &mut [BidiLevel],
+    classes: &mut [BidiClass],
+) {
+
+    let first = sequence[0];
+    let last = *sequence.last().unwrap();
+    let level = levels[first];
+
+    let level_before = (0..first).rev()
+        .find(|i| !removed[*i])
+        .map(|i| levels[i])
+        .unwrap_or(paragraph_level);
+
+    let level_after = if original_classes[last].is_isolate_initiator() {
+        paragraph_level
+    } else {
+        (last + 1..original_classes.len())
+            .find(|i| !removed[*i])
+            .map(|i| levels[i])
+            .unwrap_or(paragraph_level)
+    };
+
+    let class_for_level = |l: BidiLevel| if is_rtl_level(l) { R } else { L };
+    let sos = class_for_level(level.max(level_before));
+    let eos = class_for_level(level.max(level_after));
+    let embedding_direction = class_for_level(level);
+
+    // W1: non-spacing marks get the type of the previous character
+    let mut previous = sos;
+    for &i in sequence {
+        if classes[i] == NSM {
+            classes[i] = if previous.is_isolate_initiator() || previous == PDI { ON } else { previous };
+        }
+        previous = classes[i];
+    }
+
+    // W2: European numbers after Arabic letters become Arabic numbers
+    // W3: Arabic letters become R
+    let mut last_strong = sos;
+    for &i in sequence {
+        match classes[i] {
+            L | R => last_strong = classes[i],
+            AL => {
+                last_strong = AL;
+                classes[i] = R;
+            },
+            EN if last_strong == AL => classes[i] = AN,
+            _ => { },
+        }
+    }
+
+    // W4: single separators between two numbers of the same type
+    for k in 1..sequence.len().saturating_sub(1) {
+        let before = classes[sequence[k - 1]];
+        let after = classes[sequence[k + 1]];
+        match classes[sequence[k]] {
+            ES if before == EN && after == EN => classes[sequence[k]] = EN,
+            CS if before == after && (before == EN || before == AN) => classes[sequence[k]] = before,
+            _ => { },
+        }
+    }
+
+    // W5: sequences of European terminators adjacent to European numbers
+    let mut k = 0;
+    while k < sequence.len() {
+        if classes[sequence[k]] != ET {
+            k += 1;
+            continue;
+        }
+        let run_start = k;
+        while k < sequence.len() && classes[sequence[k]] == ET {
+            k += 1;
+        }
+        let en_before = run_start > 0 && classes[sequence[run_start - 1]] == EN;
+        let en_after = k < sequence.len() && classes[sequence[k]] == EN;
+        if en_before || en_after {
+            for j in run_start..k {
+                classes[sequence[j]] = EN;
+            }
+        }
+    }
+
+    // W6: remaining separators and terminators become neutral
+    // W7: European numbers after L become L
+    let mut last_strong = sos;
+    for &i in sequence {
+        match classes[i] {
+            ES | ET | CS => classes[i] = ON,
+            L | R => last_strong = classes[i],
+            EN if last_strong == L => classes[i] = L,
+            _ => { },
+        }
+    }
+
+    // N0: paired brackets
+    resolve_paired_brackets(text, original_classes, sequence, sos, embedding_direction, classes);
+
+    // N1, N2: sequences of neutrals take the direction of the surrounding
+    // strong text if both sides agree, otherwise the embedding direction
+    let mut k = 0;
+    while k < sequence.len() {
+        if !classes[sequence[k]].is_neutral_or_isolate() {
+            k += 1;
+            continue;
+        }
+        let run_start = k;
+        while k < sequence.len() && classes[sequence[k]].is_neutral_or_isolate() {
+            k += 1;
+        }
+        let before = if run_start == 0 {
+            sos
+        } else {
+            classes[sequence[run_start - 1]].get_strong_direction().unwrap_or(embedding_direction)
+        };
+        let after = if k == sequence.len() {
+            eos
+        } else {
+            classes[sequence[k]].get_strong_direction().unwrap_or(embedding_direction)
+        };
+        let resolved = if before == after { before } else { embedding_direction };
+        for j in run_start..k {
+            classes[sequence[j]] = resolved;
+        }
+    }
+
+    // I1, I2: implicit levels
+    for &i in sequence {
+        let level = levels[i];
+        levels[i] = match (is_rtl_level(level), classes[i]) {
+            (false, R) => level + 1,
+            (false, AN) | (false, EN) => level + 2,
+            (true, L) | (true, EN) | (true, AN) => level + 1,
+            _ => level,
+        };
+    }
+}
+
+/// Rule N0: resolves bracket pairs to the embedding direction or
+/// the direction of the strong text inside of / preceding the brackets
+fn resolve_paired_brackets(
+    text: &[u32],
+    original_classes: &[BidiClass],
+    sequence: &[usize],
+    sos: BidiClass,
+    embedding_direction: BidiClass,
+    classes: &mut [BidiClass],
+) {
+
+    // BD16: identify the bracket pairs (positions relative to the sequence)
+    let mut pairs = Vec::new();
+    let mut stack: Vec<(char, usize)> = Vec::new();
+
+    for (k, &i) in sequence.iter().enumerate() {
+        if classes[i] != ON {
+            continue;
+        }
+        let c = match core::char::from_u32(text[i]) {
+            Some(s) => s,
+            None => continue,
+        };
+        if let Some(closing) = get_closing_bracket(c) {
+            if stack.len() >= MAX_BRACKET_PAIRS_STACK {
+                break;
+            }
+            stack.push((closing, k));
+        } else if is_closing_bracket(c) {
+            if let Some(depth) = stack.iter().rposition(|(closing, _)| *closing == c) {
+                pairs.push((stack[depth].1, k));
+                stack.truncate(depth);
+            }
+        }
+    }
+
+    pairs.sort();
+
+    let opposite_direction = if embedding_direction == L { R } else { L };
+
+    for (open, close) in pairs {
+
+        let mut found_embedding_direction = false;
+        let mut found_opposite_direction = false;
+
+        for k in (open + 1)..close {
+            match classes[sequence[k]].get_strong_direction() {
+                Some(d) if d == embedding_direction => {
+                    found_embedding_direction = true;
+                    break;
+                },
+                Some(_) => found_opposite_direction = true,
+                None => { },
+            }
+        }
+
+        let resolved = if found_embedding_direction {
+            embedding_direction
+        } else if found_opposite_direction {
+            let preceding_direction = (0..open).rev()
+                .find_map(|k| classes[sequence[k]].get_strong_direction())
+                .unwrap_or(sos);
+            if preceding_direction == opposite_direction {
+                opposite_direction
+            } else {
+                embedding_direction
+            }
+        } else {
+            continue;
+        };
+
+        for bracket in [open, close].iter().copied() {
+            classes[sequence[bracket]] = resolved;
+            // non-spacing marks following the brackets take the type of the bracket
+            for k in (bracket + 1)..sequence.len() {
+                if original_classes[sequence[k]] != NSM {
+                    break;
+                }
+                classes[sequence[k]] = resolved;
+            }
+        }
+    }
+}
+
+/// Returns the closing bracket if `c` is an opening paired bracket (`Bidi_Paired_Bracket_Type=Open`)
+fn get_closing_bracket(c: char) -> Option<char> {
+    BIDI_BRACKET_PAIRS.iter().find(|(open, _)| *open == c).map(|(_, close)| *close)
+}
+
+fn is_closing_bracket(c: char) -> bool {
+    BIDI_BRACKET_PAIRS.iter().any(|(_, close)| *close == c)
+}
+
+/// Opening and closing paired brackets (`Bidi_Paired_Bracket`), after NFC normalization
+const BIDI_BRACKET_PAIRS: &[(char, char)] = &[
+    ('\u{28}', '\u{29}'), ('\u{5b}', '\u{5d}'), ('\u{7b}', '\u{7d}'), ('\u{f3a}', '\u{f3b}'),
+    ('\u{f3c}', '\u{f3d}'), ('\u{169b}', '\u{169c}'), ('\u{2045}', '\u{2046}'),
+    ('\u{207d}', '\u{207e}'), ('\u{208d}', '\u{208e}'), ('\u{2308}', '\u{2309}'),
+    ('\u{230a}', '\u{230b}'), ('\u{2329}', '\u{232a}'), ('\u{2768}', '\u{2769}'),
+    ('\u{276a}', '\u{276b}'), ('\u{276c}', '\u{276d}'), ('\u{276e}', '\u{276f}'),
+    ('\u{2770}', '\u{2771}'), ('\u{2772}', '\u{2773}'), ('\u{2774}', '\u{2775}'),
+    ('\u{27c5}', '\u{27c6}'), ('\u{27e6}', '\u{27e7}'), ('\u{27e8}', '\u{27e9}'),
+    ('\u{27ea}', '\u{27eb}'), ('\u{27ec}', '\u{27ed}'), ('\u{27ee}', '\u{27ef}'),
+    ('\u{2983}', '\u{2984}'), ('\u{2985}', '\u{2986}'), ('\u{2987}', '\u{2988}'),
+    ('\u{2989}', '\u{298a}'), ('\u{298b}', '\u{298c}'), ('\u{298d}', '\u{2990}'), ('\u{298f}', '\u{298e}'),
+    ('\u{2991}', '\u{2992}'), ('\u{2993}', '\u{2994}'), ('\u{2995}', '\u{2996}'),
+    ('\u{2997}', '\u{2998}'), ('\u{29d8}', '\u{29d9}'), ('\u{29da}', '\u{29db}'),
+    ('\u{29fc}', '\u{29fd}'), ('\u{2e22}', '\u{2e23}'), ('\u{2e24}', '\u{2e25}'),
+    ('\u{2e26}', '\u{2e27}'), ('\u{2e28}', '\u{2e29}'), ('\u{3008}', '\u{3009}'),
+    ('\u{300a}', '\u{300b}'), ('\u{300c}', '\u{300d}'), ('\u{300e}', '\u{300f}'),
+    ('\u{3010}', '\u{3011}'), ('\u{3014}', '\u{3015}'), ('\u{3016}', '\u{3017}'),
+    ('\u{3018}', '\u{3019}'), ('\u{301a}', '\u{301b}'), ('\u{fe59}', '\u{fe5a}'),
+    ('\u{fe5b}', '\u{fe5c}'), ('\u{fe5d}', '\u{fe5e}'), ('\u{ff08}', '\u{ff09}'),
+    ('\u{ff3b}', '\u{ff3d}'), ('\u{ff5b}', '\u{ff5d}'), ('\u{ff5f}', '\u{ff60}'),
+    ('\u{ff62}', '\u{ff63}'),
+];
+
+/// `Bidi_Mirroring_Glyph` mappings, sorted by the first character
+const BIDI_MIRRORING_TABLE: &[(char, char)] = &[
+    ('\u{28}', '\u{29}'), ('\u{29}', '\u{28}'), ('\u{3c}', '\u{3e}'), ('\u{3e}', '\u{3c}'),
+    ('\u{5b}', '\u{5d}'), ('\u{5d}', '\u{5b}'), ('\u{7b}', '\u{7d}'), ('\u{7d}', '\u{7b}'),
+    ('\u{ab}', '\u{bb}'), ('\u{bb}', '\u{ab}'), ('\u{f3a}', '\u{f3b}'), ('\u{f3b}', '\u{f3a}'),
+    ('\u{f3c}', '\u{f3d}'), ('\u{f3d}', '\u{f3c}'), ('\u{169b}', '\u{169c}'),
+    ('\u{169c}', '\u{169b}'), ('\u{2039}', '\u{203a}'), ('\u{203a}', '\u{2039}'),
+    ('\u{2045}', '\u{2046}'), ('\u{2046}', '\u{2045}'), ('\u{207d}', '\u{207e}'),
+    ('\u{207e}', '\u{207d}'), ('\u{208d}', '\u{208e}'), ('\u{208e}', '\u{208d}'),
+    ('\u{2208}', '\u{220b}'), ('\u{2209}', '\u{220c}'), ('\u{220a}', '\u{220d}'),
+    ('\u{220b}', '\u{2208}'), ('\u{220c}', '\u{2209}'), ('\u{220d}', '\u{220a}'),
+    ('\u{2215}', '\u{29f5}'), ('\u{223c}', '\u{223d}'), ('\u{223d}', '\u{223c}'),
+    ('\u{2243}', '\u{22cd}'), ('\u{2252}', '\u{2253}'), ('\u{2253}', '\u{2252}'),
+    ('\u{2254}', '\u{2255}'), ('\u{2255}', '\u{2254}'), ('\u{2264}', '\u{2265}'),
+    ('\u{2265}', '\u{2264}'), ('\u{2266}', '\u{2267}'), ('\u{2267}', '\u{2266}'),
+    ('\u{2268}', '\u{2269}'), ('\u{2269}', '\u{2268}'), ('\u{226a}', '\u{226b}'),
+    ('\u{226b}', '\u{226a}'), ('\u{226e}', '\u{226f}'), ('\u{226f}', '\u{226e}'),
+    ('\u{2270}', '\u{2271}'), ('\u{2271}', '\u{2270}'), ('\u{2272}', '\u{2273}'),
+    ('\u{2273}', '\u{2272}'), ('\u{2274}', '\u{2275}'), ('\u{2275}', '\u{2274}'),
+    ('\u{2276}', '\u{2277}'), ('\u{2277}', '\u{2276}'), ('\u{2278}', '\u{2279}'),
+    ('\u{2279}', '\u{2278}'), ('\u{227a}', '\u{227b}'), ('\u{227b}', '\u{227a}'),
+    ('\u{227c}', '\u{227d}'), ('\u{227d}', '\u{227c}'), ('\u{227e}', '\u{227f}'),
+    ('\u{227f}', '\u{227e}'), ('\u{2280}', '\u{2281}'), ('\u{2281}', '\u{2280}'),
+    ('\u{2282}', '\u{2283}'), ('\u{2283}', '\u{2282}'), ('\u{2284}', '\u{2285}'),
+    ('\u{2285}', '\u{2284}'), ('\u{2286}', '\u{2287}'), ('\u{2287}', '\u{2286}'),
+    ('\u{2288}', '\u{2289}'), ('\u{2289}', '\u{2288}'), ('\u{228a}', '\u{228b}'),
+    ('\u{228b}', '\u{228a}'), ('\u{228f}', '\u{2290}'), ('\u{2290}', '\u{228f}'),
+    ('\u{2291}', '\u{2292}'), ('\u{2292}', '\u{2291}'), ('\u{2298}', '\u{29b8}'),
+    ('\u{22a2}', '\u{22a3}'), ('\u{22a3}', '\u{22a2}'), ('\u{22a6}', '\u{2ade}'),
+    ('\u{22a8}', '\u{2ae4}'), ('\u{22a9}', '\u{2ae3}'), ('\u{22ab}', '\u{2ae5}'),
+    ('\u{22b0}', '\u{22b1}'), ('\u{22b1}', '\u{22b0}'), ('\u{22b2}', '\u{22b3}'),
+    ('\u{22b3}', '\u{22b2}'), ('\u{22b4}', '\u{22b5}'), ('\u{22b5}', '\u{22b4}'),
+    ('\u{22b6}', '\u{22b7}'), ('\u{22b7}', '\u{22b6}'), ('\u{22c9}', '\u{22ca}'),
+    ('\u{22ca}', '\u{22c9}'), ('\u{22cb}', '\u{22cc}'), ('\u{22cc}', '\u{22cb}'),
+    ('\u{22cd}', '\u{2243}'), ('\u{22d0}', '\u{22d1}'), ('\u{22d1}', '\u{22d0}'),
+    ('\u{22d6}', '\u{22d7}'), ('\u{22d7}', '\u{22d6}'), ('\u{22d8}', '\u{22d9}'),
+    ('\u{22d9}', '\u{22d8}'), ('\u{22da}', '\u{22db}'), ('\u{22db}', '\u{22da}'),
+    ('\u{22dc}', '\u{22dd}'), ('\u{22dd}', '\u{22dc}'), ('\u{22de}', '\u{22df}'),
+    ('\u{22df}', '\u{22de}'), ('\u{22e0}', '\u{22e1}'), ('\u{22e1}', '\u{22e0}'),
+    ('\u{22e2}', '\u{22e3}'), ('\u{22e3}', '\u{22e2}'), ('\u{22e4}', '\u{22e5}'),
+    ('\u{22e5}', '\u{22e4}'), ('\u{22e6}', '\u{22e7}'), ('\u{22e7}', '\u{22e6}'),
+    ('\u{22e8}', '\u{22e9}'), ('\u{22e9}', '\u{22e8}'), ('\u{22ea}', '\u{22eb}'),
+    ('\u{22eb}', '\u{22ea}'), ('\u{22ec}', '\u{22ed}'), ('\u{22ed}', '\u{22ec}'),
+    ('\u{22f0}', '\u{22f1}'), ('\u{22f1}', '\u{22f0}'), ('\u{2308}', '\u{2309}'),
+    ('\u{2309}', '\u{2308}'), ('\u{230a}', '\u{230b}'), ('\u{230b}', '\u{230a}'),
+    ('\u{2329}', '\u{232a}'), ('\u{232a}', '\u{2329}'), ('\u{2768}', '\u{2769}'),
+    ('\u{2769}', '\u{2768}'), ('\u{276a}', '\u{276b}'), ('\u{276b}', '\u{276a}'),
+    ('\u{276c}', '\u{276d}'), ('\u{276d}', '\u{276c}'), ('\u{276e}', '\u{276f}'),
+    ('\u{276f}', '\u{276e}'), ('\u{2770}', '\u{2771}'), ('\u{2771}', '\u{2770}'),
+    ('\u{2772}', '\u{2773}'), ('\u{2773}', '\u{2772}'), ('\u{2774}', '\u{2775}'),
+    ('\u{2775}', '\u{2774}'), ('\u{27c3}', '\u{27c4}'), ('\u{27c4}', '\u{27c3}'),
+    ('\u{27c5}', '\u{27c6}'), ('\u{27c6}', '\u{27c5}'), ('\u{27c8}', '\u{27c9}'),
+    ('\u{27c9}', '\u{27c8}'), ('\u{27d5}', '\u{27d6}'), ('\u{27d6}', '\u{27d5}'),
+    ('\u{27dd}', '\u{27de}'), ('\u{27de}', '\u{27dd}'), ('\u{27e2}', '\u{27e3}'),
+    ('\u{27e3}', '\u{27e2}'), ('\u{27e4}', '\u{27e5}'), ('\u{27e5}', '\u{27e4}'),
+    ('\u{27e6}', '\u{27e7}'), ('\u{27e7}', '\u{27e6}'), ('\u{27e8}', '\u{27e9}'),
+    ('\u{27e9}', '\u{27e8}'), ('\u{27ea}', '\u{27eb}'), ('\u{27eb}', '\u{27ea}'),
+    ('\u{27ec}', '\u{27ed}'), ('\u{27ed}', '\u{27ec}'), ('\u{27ee}', '\u{27ef}'),
+    ('\u{27ef}', '\u{27ee}'), ('\u{2983}', '\u{2984}'), ('\u{2984}', '\u{2983}'),
+    ('\u{2985}', '\u{2986}'), ('\u{2986}', '\u{2985}'), ('\u{2987}', '\u{2988}'),
+    ('\u{2988}', '\u{2987}'), ('\u{2989}', '\u{298a}'), ('\u{298a}', '\u{2989}'),
+    ('\u{298b}', '\u{298c}'), ('\u{298c}', '\u{298b}'), ('\u{298d}', '\u{2990}'),
+    ('\u{298e}', '\u{298f}'), ('\u{298f}', '\u{298e}'), ('\u{2990}', '\u{298d}'),
+    ('\u{2991}', '\u{2992}'), ('\u{2992}', '\u{2991}'), ('\u{2993}', '\u{2994}'),
+    ('\u{2994}', '\u{2993}'), ('\u{2995}', '\u{2996}'), ('\u{2996}', '\u{2995}'),
+    ('\u{2997}', '\u{2998}'), ('\u{2998}', '\u{2997}'), ('\u{29b8}', '\u{2298}'),
+    ('\u{29c0}', '\u{29c1}'), ('\u{29c1}', '\u{29c0}'), ('\u{29c4}', '\u{29c5}'),
+    ('\u{29c5}', '\u{29c4}'), ('\u{29cf}', '\u{29d0}'), ('\u{29d0}', '\u{29cf}'),
+    ('\u{29d1}', '\u{29d2}'), ('\u{29d2}', '\u{29d1}'), ('\u{29d4}', '\u{29d5}'),
+    ('\u{29d5}', '\u{29d4}'), ('\u{29d8}', '\u{29d9}'), ('\u{29d9}', '\u{29d8}'),
+    ('\u{29da}', '\u{29db}'), ('\u{29db}', '\u{29da}'), ('\u{29f5}', '\u{2215}'),
+    ('\u{29fc}', '\u{29fd}'), ('\u{29fd}', '\u{29fc}'), ('\u{2ade}', '\u{22a6}'),
+    ('\u{2ae3}', '\u{22a9}'), ('\u{2ae4}', '\u{22a8}'), ('\u{2ae5}', '\u{22ab}'),
+    ('\u{2e02}', '\u{2e03}'), ('\u{2e03}', '\u{2e02}'), ('\u{2e04}', '\u{2e05}'),
+    ('\u{2e05}', '\u{2e04}'), ('\u{2e09}', '\u{2e0a}'), ('\u{2e0a}', '\u{2e09}'),
+    ('\u{2e0c}', '\u{2e0d}'), ('\u{2e0d}', '\u{2e0c}'), ('\u{2e1c}', '\u{2e1d}'),
+    ('\u{2e1d}', '\u{2e1c}'), ('\u{2e20}', '\u{2e21}'), ('\u{2e21}', '\u{2e20}'),
+    ('\u{2e22}', '\u{2e23}'), ('\u{2e23}', '\u{2e22}'), ('\u{2e24}', '\u{2e25}'),
+    ('\u{2e25}', '\u{2e24}'), ('\u{2e26}', '\u{2e27}'), ('\u{2e27}', '\u{2e26}'),
+    ('\u{2e28}', '\u{2e29}'), ('\u{2e29}', '\u{2e28}'), ('\u{3008}', '\u{3009}'),
+    ('\u{3009}', '\u{3008}'), ('\u{300a}', '\u{300b}'), ('\u{300b}', '\u{300a}'),
+    ('\u{300c}', '\u{300d}'), ('\u{300d}', '\u{300c}'), ('\u{300e}', '\u{300f}'),
+    ('\u{300f}', '\u{300e}'), ('\u{3010}', '\u{3011}'), ('\u{3011}', '\u{3010}'),
+    ('\u{3014}', '\u{3015}'), ('\u{3015}', '\u{3014}'), ('\u{3016}', '\u{3017}'),
+    ('\u{3017}', '\u{3016}'), ('\u{3018}', '\u{3019}'), ('\u{3019}', '\u{3018}'),
+    ('\u{301a}', '\u{301b}'), ('\u{301b}', '\u{301a}'), ('\u{fe59}', '\u{fe5a}'),
+    ('\u{fe5a}', '\u{fe59}'), ('\u{fe5b}', '\u{fe5c}'), ('\u{fe5c}', '\u{fe5b}'),
+    ('\u{fe5d}', '\u{fe5e}'), ('\u{fe5e}', '\u{fe5d}'), ('\u{fe64}', '\u{fe65}'),
+    ('\u{fe65}', '\u{fe64}'), ('\u{ff08}', '\u{ff09}'), ('\u{ff09}', '\u{ff08}'),
+    ('\u{ff1c}', '\u{ff1e}'), ('\u{ff1e}', '\u{ff1c}'), ('\u{ff3b}', '\u{ff3d}'),
+    ('\u{ff3d}', '\u{ff3b}'), ('\u{ff5b}', '\u{ff5d}'), ('\u{ff5d}', '\u{ff5b}'),
+    ('\u{ff5f}', '\u{ff60}'), ('\u{ff60}', '\u{ff5f}'), ('\u{ff62}', '\u{ff63}'),
+    ('\u{ff63}', '\u{ff62}'),
+];
+
+/// Ranges of characters that don't have the default `Bidi_Class` of `L`,
+/// generated from `DerivedBidiClass.txt` (Unicode 14.0), including the default
+/// values of unassigned codepoints in right-to-left blocks
+const BIDI_CLASS_TABLE: &[(char, char, BidiClass)] = &[
+    ('\u{0}', '\u{8}', BN),
+    ('\u{9}', '\u{9}', S),
+    ('\u{a}', '\u{a}', B),
+    ('\u{b}', '\u{b}', S),
+    ('\u{c}', '\u{c}', WS),
+    ('\u{d}', '\u{d}', B),
+    ('\u{e}', '\u{1b}', BN),
+    ('\u{1c}', '\u{1e}', B),
+    ('\u{1f}', '\u{1f}', S),
+    ('\u{20}', '\u{20}', WS),
+    ('\u{21}', '\u{22}', ON),
+    ('\u{23}', '\u{25}', ET),
+    ('\u{26}', '\u{2a}', ON),
+    ('\u{2b}', '\u{2b}', ES),
+    ('\u{2c}', '\u{2c}', CS),
+    ('\u{2d}', '\u{2d}', ES),
+    ('\u{2e}', '\u{2f}', CS),
+    ('\u{30}', '\u{39}', EN),
+    ('\u{3a}', '\u{3a}', CS),
+    ('\u{3b}', '\u{40}', ON),
+    ('\u{5b}', '\u{60}', ON),
+    ('\u{7b}', '\u{7e}', ON),
+    ('\u{7f}', '\u{84}', BN),
+    ('\u{85}', '\u{85}', B),
+    ('\u{86}', '\u{9f}', BN),
+    ('\u{a0}', '\u{a0}', CS),
+    ('\u{a1}', '\u{a1}', ON),
+    ('\u{a2}', '\u{a5}', ET),
+    ('\u{a6}', '\u{a9}', ON),
+    ('\u{ab}', '\u{ac}', ON),
+    ('\u{ad}', '\u{ad}', BN),
+    ('\u{ae}', '\u{af}', ON),
+    ('\u{b0}', '\u{b1}', ET),
+    ('\u{b2}', '\u{b3}', EN),
+    ('\u{b4}', '\u{b4}', ON),
+    ('\u{b6}', '\u{b8}', ON),
+    ('\u{b9}', '\u{b9}', EN),
+    ('\u{bb}', '\u{bf}', ON),
+    ('\u{d7}', '\u{d7}', ON),
+    ('\u{f7}', '\u{f7}', ON),
+    ('\u{2b9}', '\u{2ba}', ON),
+    ('\u{2c2}', '\u{2cf}', ON),
+    ('\u{2d2}', '\u{2df}', ON),
+    ('\u{2e5}', '\u{2ed}', ON),
+    ('\u{2ef}', '\u{2ff}', ON),
+    ('\u{300}', '\u{36f}', NSM),
+    ('\u{374}', '\u{375}', ON),
+    ('\u{37e}', '\u{37e}', ON),
+    ('\u{384}', '\u{385}', ON),
+    ('\u{387}', '\u{387}', ON),
+    ('\u{3f6}', '\u{3f6}', ON),
+    ('\u{483}', '\u{489}', NSM),
+    ('\u{58a}', '\u{58a}', ON),
+    ('\u{58d}', '\u{58e}', ON),
+    ('\u{58f}', '\u{58f}', ET),
+    ('\u{590}', '\u{590}', R),
+    ('\u{591}', '\u{5bd}', NSM),
+    ('\u{5be}', '\u{5be}', R),
+    ('\u{5bf}', '\u{5bf}', NSM),
+    ('\u{5c0}', '\u{5c0}', R),
+    ('\u{5c1}', '\u{5c2}', NSM),
+    ('\u{5c3}', '\u{5c3}', R),
+    ('\u{5c4}', '\u{5c5}', NSM),
+    ('\u{5c6}', '\u{5c6}', R),
+    ('\u{5c7}', '\u{5c7}', NSM),
+    ('\u{5c8}', '\u{5ff}', R),
+    ('\u{600}', '\u{605}', AN),
+    ('\u{606}', '\u{607}', ON),
+    ('\u{608}', '\u{608}', AL),
+    ('\u{609}', '\u{60a}', ET),
+    ('\u{60b}', '\u{60b}', AL),
+    ('\u{60c}', '\u{60c}', CS),
+    ('\u{60d}', '\u{60d}', AL),
+    ('\u{60e}', '\u{60f}', ON),
+    ('\u{610}', '\u{61a}', NSM),
+    ('\u{61b}', '\u{64a}', AL),
+    ('\u{64b}', '\u{65f}', NSM),
+    ('\u{660}', '\u{669}', AN),
+    ('\u{66a}', '\u{66a}', ET),
+    ('\u{66b}', '\u{66c}', AN),
+    ('\u{66d}', '\u{66f}', AL),
+    ('\u{670}', '\u{670}', NSM),
+    ('\u{671}', '\u{6d5}', AL),
+    ('\u{6d6}', '\u{6dc}', NSM),
+    ('\u{6dd}', '\u{6dd}', AN),
+    ('\u{6de}', '\u{6de}', ON),
+    ('\u{6df}', '\u{6e4}', NSM),
+    ('\u{6e5}', '\u{6e6}', AL),
+    ('\u{6e7}', '\u{6e8}', NSM),
+    ('\u{6e9}', '\u{6e9}', ON),
+    ('\u{6ea}', '\u{6ed}', NSM),
+    ('\u{6ee}', '\u{6ef}', AL),
+    ('\u{6f0}', '\u{6f9}', EN),
+    ('\u{6fa}', '\u{710}', AL),
+    ('\u{711}', '\u{711}', NSM),
+    ('\u{712}', '\u{72f}', AL),
+    ('\u{730}', '\u{74a}', NSM),
+    ('\u{74b}', '\u{7a5}', AL),
+    ('\u{7a6}', '\u{7b0}', NSM),
+    ('\u{7b1}', '\u{7bf}', AL),
+    ('\u{7c0}', '\u{7ea}', R),
+    ('\u{7eb}', '\u{7f3}', NSM),
+    ('\u{7f4}', '\u{7f5}', R),
+    ('\u{7f6}', '\u{7f9}', ON),
+    ('\u{7fa}', '\u{7fc}', R),
+    ('\u{7fd}', '\u{7fd}', NSM),
+    ('\u{7fe}', '\u{815}', R),
+    ('\u{816}', '\u{819}', NSM),
+    ('\u{81a}', '\u{81a}', R),
+    ('\u{81b}', '\u{823}', NSM),
+    ('\u{824}', '\u{824}', R),
+    ('\u{825}', '\u{827}', NSM),
+    ('\u{828}', '\u{828}', R),
+    ('\u{829}', '\u{82d}', NSM),
+    ('\u{82e}', '\u{858}', R),
+    ('\u{859}', '\u{85b}', NSM),
+    ('\u{85c}', '\u{85f}', R),
+    ('\u{860}', '\u{88f}', AL),
+    ('\u{890}', '\u{891}', AN),
+    ('\u{892}', '\u{897}', AL),
+    ('\u{898}', '\u{89f}', NSM),
+    ('\u{8a0}', '\u{8c9}', AL),
+    ('\u{8ca}', '\u{8e1}', NSM),
+    ('\u{8e2}', '\u{8e2}', AN),
+    ('\u{8e3}', '\u{902}', NSM),
+    ('\u{93a}', '\u{93a}', NSM),
+    ('\u{93c}', '\u{93c}', NSM),
+    ('\u{941}', '\u{948}', NSM),
+    ('\u{94d}', '\u{94d}', NSM),
+    ('\u{951}', '\u{957}', NSM),
+    ('\u{962}', '\u{963}', NSM),
+    ('\u{981}', '\u{981}', NSM),
+    ('\u{9bc}', '\u{9bc}', NSM),
+    ('\u{9c1}', '\u{9c4}', NSM),
+    ('\u{9cd}', '\u{9cd}', NSM),
+    ('\u{9e2}', '\u{9e3}', NSM),
+    ('\u{9f2}', '\u{9f3}', ET),
+    ('\u{9fb}', '\u{9fb}', ET),
+    ('\u{9fe}', '\u{9fe}', NSM),
+    ('\u{a01}', '\u{a02}', NSM),
+    ('\u{a3c}', '\u{a3c}', NSM),
+    ('\u{a41}', '\u{a42}', NSM),
+    ('\u{a47}', '\u{a48}', NSM),
+    ('\u{a4b}', '\u{a4d}', NSM),
+    ('\u{a51}', '\u{a51}', NSM),
+    ('\u{a70}', '\u{a71}', NSM),
+    ('\u{a75}', '\u{a75}', NSM),
+    ('\u{a81}', '\u{a82}', NSM),
+    ('\u{abc}', '\u{abc}', NSM),
+    ('\u{ac1}', '\u{ac5}', NSM),
+    ('\u{ac7}', '\u{ac8}', NSM),
+    ('\u{acd}', '\u{acd}', NSM),
+    ('\u{ae2}', '\u{ae3}', NSM),
+    ('\u{af1}', '\u{af1}', ET),
+    ('\u{afa}', '\u{aff}', NSM),
+    ('\u{b01}', '\u{b01}', NSM),
+    ('\u{b3c}', '\u{b3c}', NSM),
+    ('\u{b3f}', '\u{b3f}', NSM),
+    ('\u{b41}', '\u{b44}', NSM),
+    ('\u{b4d}', '\u{b4d}', NSM),
+    ('\u{b55}', '\u{b56}', NSM),
+    ('\u{b62}', '\u{b63}', NSM),
+    ('\u{b82}', '\u{b82}', NSM),
+    ('\u{bc0}', '\u{bc0}', NSM),
+    ('\u{bcd}', '\u{bcd}', NSM),
+    ('\u{bf3}', '\u{bf8}', ON),
+    ('\u{bf9}', '\u{bf9}', ET),
+    ('\u{bfa}', '\u{bfa}', ON),
+    ('\u{c00}', '\u{c00}', NSM),
+    ('\u{c04}', '\u{c04}', NSM),
+    ('\u{c3c}', '\u{c3c}', NSM),
+    ('\u{c3e}', '\u{c40}', NSM),
+    ('\u{c46}', '\u{c48}', NSM),
+    ('\u{c4a}', '\u{c4d}', NSM),
+    ('\u{c55}', '\u{c56}', NSM),
+    ('\u{c62}', '\u{c63}', NSM),
+    ('\u{c78}', '\u{c7e}', ON),
+    ('\u{c81}', '\u{c81}', NSM),
+    ('\u{cbc}', '\u{cbc}', NSM),
+    ('\u{ccc}', '\u{ccd}', NSM),
+    ('\u{ce2}', '\u{ce3}', NSM),
+    ('\u{d00}', '\u{d01}', NSM),
+    ('\u{d3b}', '\u{d3c}', NSM),
+    ('\u{d41}', '\u{d44}', NSM),
+    ('\u{d4d}', '\u{d4d}', NSM),
+    ('\u{d62}', '\u{d63}', NSM),
+    ('\u{d81}', '\u{d81}', NSM),
+    ('\u{dca}', '\u{dca}', NSM),
+    ('\u{dd2}', '\u{dd4}', NSM),
+    ('\u{dd6}', '\u{dd6}', NSM),
+    ('\u{e31}', '\u{e31}', NSM),
+    ('\u{e34}', '\u{e3a}', NSM),
+    ('\u{e3f}', '\u{e3f}', ET),
+    ('\u{e47}', '\u{e4e}', NSM),
+    ('\u{eb1}', '\u{eb1}', NSM),
+    ('\u{eb4}', '\u{ebc}', NSM),
+    ('\u{ec8}', '\u{ecd}', NSM),
+    ('\u{f18}', '\u{f19}', NSM),
+    ('\u{f35}', '\u{f35}', NSM),
+    ('\u{f37}', '\u{f37}', NSM),
+    ('\u{f39}', '\u{f39}', NSM),
+    ('\u{f3a}', '\u{f3d}', ON),
+    ('\u{f71}', '\u{f7e}', NSM),
+    ('\u{f80}', '\u{f84}', NSM),
+    ('\u{f86}', '\u{f87}', NSM),
+    ('\u{f8d}', '\u{f97}', NSM),
+    ('\u{f99}', '\u{fbc}', NSM),
+    ('\u{fc6}', '\u{fc6}', NSM),
+    ('\u{102d}', '\u{1030}', NSM),
+    ('\u{1032}', '\u{1037}', NSM),
+    ('\u{1039}', '\u{103a}', NSM),
+    ('\u{103d}', '\u{103e}', NSM),
+    ('\u{1058}', '\u{1059}', NSM),
+    ('\u{105e}', '\u{1060}', NSM),
+    ('\u{1071}', '\u{1074}', NSM),
+    ('\u{1082}', '\u{1082}', NSM),
+    ('\u{1085}', '\u{1086}', NSM),
+    ('\u{108d}', '\u{108d}', NSM),
+    ('\u{109d}', '\u{109d}', NSM),
+    ('\u{135d}', '\u{135f}', NSM),
+    ('\u{1390}', '\u{1399}', ON),
+    ('\u{1400}', '\u{1400}', ON),
+    ('\u{1680}', '\u{1680}', WS),
+    ('\u{169b}', '\u{169c}', ON),
+    ('\u{1712}', '\u{1714}', NSM),
+    ('\u{1732}', '\u{1733}', NSM),
+    ('\u{1752}', '\u{1753}', NSM),
+    ('\u{1772}', '\u{1773}', NSM),
+    ('\u{17b4}', '\u{17b5}', NSM),
+    ('\u{17b7}', '\u{17bd}', NSM),
+    ('\u{17c6}', '\u{17c6}', NSM),
+    ('\u{17c9}', '\u{17d3}', NSM),
+    ('\u{17db}', '\u{17db}', ET),
+    ('\u{17dd}', '\u{17dd}', NSM),
+    ('\u{17f0}', '\u{17f9}', ON),
+    ('\u{1800}', '\u{180a}', ON),
+    ('\u{180b}', '\u{180d}', NSM),
+    ('\u{180e}', '\u{180e}', BN),
+    ('\u{180f}', '\u{180f}', NSM),
+    ('\u{1885}', '\u{1886}', NSM),
+    ('\u{18a9}', '\u{18a9}', NSM),
+    ('\u{1920}', '\u{1922}', NSM),
+    ('\u{1927}', '\u{1928}', NSM),
+    ('\u{1932}', '\u{1932}', NSM),
+    ('\u{1939}', '\u{193b}', NSM),
+    ('\u{1940}', '\u{1940}', ON),
+    ('\u{1944}', '\u{1945}', ON),
+    ('\u{19de}', '\u{19ff}', ON),
+    ('\u{1a17}', '\u{1a18}', NSM),
+    ('\u{1a1b}', '\u{1a1b}', NSM),
+    ('\u{1a56}', '\u{1a56}', NSM),
+    ('\u{1a58}', '\u{1a5e}', NSM),
+    ('\u{1a60}', '\u{1a60}', NSM),
+    ('\u{1a62}', '\u{1a62}', NSM),
+    ('\u{1a65}', '\u{1a6c}', NSM),
+    ('\u{1a73}', '\u{1a7c}', NSM),
+    ('\u{1a7f}', '\u{1a7f}', NSM),
+    ('\u{1ab0}', '\u{1ace}', NSM),
+    ('\u{1b00}', '\u{1b03}', NSM),
+    ('\u{1b34}', '\u{1b34}', NSM),
+    ('\u{1b36}', '\u{1b3a}', NSM),
+    ('\u{1b3c}', '\u{1b3c}', NSM),
+    ('\u{1b42}', '\u{1b42}', NSM),
+    ('\u{1b6b}', '\u{1b73}', NSM),
+    ('\u{1b80}', '\u{1b81}', NSM),
+    ('\u{1ba2}', '\u{1ba5}', NSM),
+    ('\u{1ba8}', '\u{1ba9}', NSM),
+    ('\u{1bab}', '\u{1bad}', NSM),
+    ('\u{1be6}', '\u{1be6}', NSM),
+    ('\u{1be8}', '\u{1be9}', NSM),
+    ('\u{1bed}', '\u{1bed}', NSM),
+    ('\u{1bef}', '\u{1bf1}', NSM),
+    ('\u{1c2c}', '\u{1c33}', NSM),
+    ('\u{1c36}', '\u{1c37}', NSM),
+    ('\u{1cd0}', '\u{1cd2}', NSM),
+    ('\u{1cd4}', '\u{1ce0}', NSM),
+    ('\u{1ce2}', '\u{1ce8}', NSM),
+    ('\u{1ced}', '\u{1ced}', NSM),
+    ('\u{1cf4}', '\u{1cf4}', NSM),
+    ('\u{1cf8}', '\u{1cf9}', NSM),
+    ('\u{1dc0}', '\u{1dff}', NSM),
+    ('\u{1fbd}', '\u{1fbd}', ON),
+    ('\u{1fbf}', '\u{1fc1}', ON),
+    ('\u{1fcd}', '\u{1fcf}', ON),
+    ('\u{1fdd}', '\u{1fdf}', ON),
+    ('\u{1fed}', '\u{1fef}', ON),
+    ('\u{1ffd}', '\u{1ffe}', ON),
+    ('\u{2000}', '\u{200a}', WS),
+    ('\u{200b}', '\u{200d}', BN),
+    ('\u{200f}', '\u{200f}', R),
+    ('\u{2010}', '\u{2027}', ON),
+    ('\u{2028}', '\u{2028}', WS),
+    ('\u{2029}', '\u{2029}', B),
+    ('\u{202a}', '\u{202a}', LRE),
+    ('\u{202b}', '\u{202b}', RLE),
+    ('\u{202c}', '\u{202c}', PDF),
+    ('\u{202d}', '\u{202d}', LRO),
+    ('\u{202e}', '\u{202e}', RLO),
+    ('\u{202f}', '\u{202f}', CS),
+    ('\u{2030}', '\u{2034}', ET),
+    ('\u{2035}', '\u{2043}', ON),
+    ('\u{2044}', '\u{2044}', CS),
+    ('\u{2045}', '\u{205e}', ON),
+    ('\u{205f}', '\u{205f}', WS),
+    ('\u{2060}', '\u{2065}', BN),
+    ('\u{2066}', '\u{2066}', LRI),
+    ('\u{2067}', '\u{2067}', RLI),
+    ('\u{2068}', '\u{2068}', FSI),
+    ('\u{2069}', '\u{2069}', PDI),
+    ('\u{206a}', '\u{206f}', BN),
+    ('\u{2070}', '\u{2070}', EN),
+    ('\u{2074}', '\u{2079}', EN),
+    ('\u{207a}', '\u{207b}', ES),
+    ('\u{207c}', '\u{207e}', ON),
+    ('\u{2080}', '\u{2089}', EN),
+    ('\u{208a}', '\u{208b}', ES),
+    ('\u{208c}', '\u{208e}', ON),
+    ('\u{20a0}', '\u{20cf}', ET),
+    ('\u{20d0}', '\u{20f0}', NSM),
+    ('\u{2100}', '\u{2101}', ON),
+    ('\u{2103}', '\u{2106}', ON),
+    ('\u{2108}', '\u{2109}', ON),
+    ('\u{2114}', '\u{2114}', ON),
+    ('\u{2116}', '\u{2118}', ON),
+    ('\u{211e}', '\u{2123}', ON),
+    ('\u{2125}', '\u{2125}', ON),
+    ('\u{2127}', '\u{2127}', ON),
+    ('\u{2129}', '\u{2129}', ON),
+    ('\u{212e}', '\u{212e}', ET),
+    ('\u{213a}', '\u{213b}', ON),
+    ('\u{2140}', '\u{2144}', ON),
+    ('\u{214a}', '\u{214d}', ON),
+    ('\u{2150}', '\u{215f}', ON),
+    ('\u{2189}', '\u{218b}', ON),
+    ('\u{2190}', '\u{2211}', ON),
+    ('\u{2212}', '\u{2212}', ES),
+    ('\u{2213}', '\u{2213}', ET),
+    ('\u{2214}', '\u{2335}', ON),
+    ('\u{237b}', '\u{2394}', ON),
+    ('\u{2396}', '\u{2426}', ON),
+    ('\u{2440}', '\u{244a}', ON),
+    ('\u{2460}', '\u{2487}', ON),
+    ('\u{2488}', '\u{249b}', EN),
+    ('\u{24ea}', '\u{26ab}', ON),
+    ('\u{26ad}', '\u{27ff}', ON),
+    ('\u{2900}', '\u{2b73}', ON),
+    ('\u{2b76}', '\u{2b95}', ON),
+    ('\u{2b97}', '\u{2bff}', ON),
+    ('\u{2ce5}', '\u{2cea}', ON),
+    ('\u{2cef}', '\u{2cf1}', NSM),
+    ('\u{2cf9}', '\u{2cff}', ON),
+    ('\u{2d7f}', '\u{2d7f}', NSM),
+    ('\u{2de0}', '\u{2dff}', NSM),
+    ('\u{2e00}', '\u{2e5d}', ON),
+    ('\u{2e80}', '\u{2e99}', ON),
+    ('\u{2e9b}', '\u{2ef3}', ON),
+    ('\u{2f00}', '\u{2fd5}', ON),
+    ('\u{2ff0}', '\u{2ffb}', ON),
+    ('\u{3000}', '\u{3000}', WS),
+    ('\u{3001}', '\u{3004}', ON),
+    ('\u{3008}', '\u{3020}', ON),
+    ('\u{302a}', '\u{302d}', NSM),
+    ('\u{3030}', '\u{3030}', ON),
+    ('\u{3036}', '\u{3037}', ON),
+    ('\u{303d}', '\u{303f}', ON),
+    ('\u{3099}', '\u{309a}', NSM),
+    ('\u{309b}', '\u{309c}', ON),
+    ('\u{30a0}', '\u{30a0}', ON),
+    ('\u{30fb}', '\u{30fb}', ON),
+    ('\u{31c0}', '\u{31e3}', ON),
+    ('\u{321d}', '\u{321e}', ON),
+    ('\u{3250}', '\u{325f}', ON),
+    ('\u{327c}', '\u{327e}', ON),
+    ('\u{32b1}', '\u{32bf}', ON),
+    ('\u{32cc}', '\u{32cf}', ON),
+    ('\u{3377}', '\u{337a}', ON),
+    ('\u{33de}', '\u{33df}', ON),
+    ('\u{33ff}', '\u{33ff}', ON),
+    ('\u{4dc0}', '\u{4dff}', ON),
+    ('\u{a490}', '\u{a4c6}', ON),
+    ('\u{a60d}', '\u{a60f}', ON),
+    ('\u{a66f}', '\u{a672}', NSM),
+    ('\u{a673}', '\u{a673}', ON),
+    ('\u{a674}', '\u{a67d}', NSM),
+    ('\u{a67e}', '\u{a67f}', ON),
+    ('\u{a69e}', '\u{a69f}', NSM),
+    ('\u{a6f0}', '\u{a6f1}', NSM),
+    ('\u{a700}', '\u{a721}', ON),
+    ('\u{a788}', '\u{a788}', ON),
+    ('\u{a802}', '\u{a802}', NSM),
+    ('\u{a806}', '\u{a806}', NSM),
+    ('\u{a80b}', '\u{a80b}', NSM),
+    ('\u{a825}', '\u{a826}', NSM),
+    ('\u{a828}', '\u{a82b}', ON),
+    ('\u{a82c}', '\u{a82c}', NSM),
+    ('\u{a838}', '\u{a839}', ET),
+    ('\u{a874}', '\u{a877}', ON),
+    ('\u{a8c4}', '\u{a8c5}', NSM),
+    ('\u{a8e0}', '\u{a8f1}', NSM),
+    ('\u{a8ff}', '\u{a8ff}', NSM),
+    ('\u{a926}', '\u{a92d}', NSM),
+    ('\u{a947}', '\u{a951}', NSM),
+    ('\u{a980}', '\u{a982}', NSM),
+    ('\u{a9b3}', '\u{a9b3}', NSM),
+    ('\u{a9b6}', '\u{a9b9}', NSM),
+    ('\u{a9bc}', '\u{a9bd}', NSM),
+    ('\u{a9e5}', '\u{a9e5}', NSM),
+    ('\u{aa29}', '\u{aa2e}', NSM),
+    ('\u{aa31}', '\u{aa32}', NSM),
+    ('\u{aa35}', '\u{aa36}', NSM),
+    ('\u{aa43}', '\u{aa43}', NSM),
+    ('\u{aa4c}', '\u{aa4c}', NSM),
+    ('\u{aa7c}', '\u{aa7c}', NSM),
+    ('\u{aab0}', '\u{aab0}', NSM),
+    ('\u{aab2}', '\u{aab4}', NSM),
+    ('\u{aab7}', '\u{aab8}', NSM),
+    ('\u{aabe}', '\u{aabf}', NSM),
+    ('\u{aac1}', '\u{aac1}', NSM),
+    ('\u{aaec}', '\u{aaed}', NSM),
+    ('\u{aaf6}', '\u{aaf6}', NSM),
+    ('\u{ab6a}', '\u{ab6b}', ON),
+    ('\u{abe5}', '\u{abe5}', NSM),
+    ('\u{abe8}', '\u{abe8}', NSM),
+    ('\u{abed}', '\u{abed}', NSM),
+    ('\u{fb1d}', '\u{fb1d}', R),
+    ('\u{fb1e}', '\u{fb1e}', NSM),
+    ('\u{fb1f}', '\u{fb28}', R),
+    ('\u{fb29}', '\u{fb29}', ES),
+    ('\u{fb2a}', '\u{fb4f}', R),
+    ('\u{fb50}', '\u{fd3d}', AL),
+    ('\u{fd3e}', '\u{fd4f}', ON),
+    ('\u{fd50}', '\u{fdce}', AL),
+    ('\u{fdcf}', '\u{fdcf}', ON),
+    ('\u{fdd0}', '\u{fdef}', BN),
+    ('\u{fdf0}', '\u{fdfc}', AL),
+    ('\u{fdfd}', '\u{fdff}', ON),
+    ('\u{fe00}', '\u{fe0f}', NSM),
+    ('\u{fe10}', '\u{fe19}', ON),
+    ('\u{fe20}', '\u{fe2f}', NSM),
+    ('\u{fe30}', '\u{fe4f}', ON),
+    ('\u{fe50}', '\u{fe50}', CS),
+    ('\u{fe51}', '\u{fe51}', ON),
+    ('\u{fe52}', '\u{fe52}', CS),
+    ('\u{fe54}', '\u{fe54}', ON),
+    ('\u{fe55}', '\u{fe55}', CS),
+    ('\u{fe56}', '\u{fe5e}', ON),
+    ('\u{fe5f}', '\u{fe5f}', ET),
+    ('\u{fe60}', '\u{fe61}', ON),
+    ('\u{fe62}', '\u{fe63}', ES),
+    ('\u{fe64}', '\u{fe66}', ON),
+    ('\u{fe68}', '\u{fe68}', ON),
+    ('\u{fe69}', '\u{fe6a}', ET),
+    ('\u{fe6b}', '\u{fe6b}', ON),
+    ('\u{fe70}', '\u{fefe}', AL),
+    ('\u{feff}', '\u{feff}', BN),
+    ('\u{ff01}', '\u{ff02}', ON),
+    ('\u{ff03}', '\u{ff05}', ET),
+    ('\u{ff06}', '\u{ff0a}', ON),
+    ('\u{ff0b}', '\u{ff0b}', ES),
+    ('\u{ff0c}', '\u{ff0c}', CS),
+    ('\u{ff0d}', '\u{ff0d}', ES),
+    ('\u{ff0e}', '\u{ff0f}', CS),
+    ('\u{ff10}', '\u{ff19}', EN),
+    ('\u{ff1a}', '\u{ff1a}', CS),
+    ('\u{ff1b}', '\u{ff20}', ON),
+    ('\u{ff3b}', '\u{ff40}', ON),
+    ('\u{ff5b}', '\u{ff65}', ON),
+    ('\u{ffe0}', '\u{ffe1}', ET),
+    ('\u{ffe2}', '\u{ffe4}', ON),
+    ('\u{ffe5}', '\u{ffe6}', ET),
+    ('\u{ffe8}', '\u{ffee}', ON),
+    ('\u{fff0}', '\u{fff8}', BN),
+    ('\u{fff9}', '\u{fffd}', ON),
+    ('\u{fffe}', '\u{ffff}', BN),
+    ('\u{10101}', '\u{10101}', ON),
+    ('\u{10140}', '\u{1018c}', ON),
+    ('\u{10190}', '\u{1019c}', ON),
+    ('\u{101a0}', '\u{101a0}', ON),
+    ('\u{101fd}', '\u{101fd}', NSM),
+    ('\u{102e0}', '\u{102e0}', NSM),
+    ('\u{102e1}', '\u{102fb}', EN),
+    ('\u{10376}', '\u{1037a}', NSM),
+    ('\u{10800}', '\u{1091e}', R),
+    ('\u{1091f}', '\u{1091f}', ON),
+    ('\u{10920}', '\u{10a00}', R),
+    ('\u{10a01}', '\u{10a03}', NSM),
+    ('\u{10a04}', '\u{10a04}', R),
+    ('\u{10a05}', '\u{10a06}', NSM),
+    ('\u{10a07}', '\u{10a0b}', R),
+    ('\u{10a0c}', '\u{10a0f}', NSM),
+    ('\u{10a10}', '\u{10a37}', R),
+    ('\u{10a38}', '\u{10a3a}', NSM),
+    ('\u{10a3b}', '\u{10a3e}', R),
+    ('\u{10a3f}', '\u{10a3f}', NSM),
+    ('\u{10a40}', '\u{10ae4}', R),
+    ('\u{10ae5}', '\u{10ae6}', NSM),
+    ('\u{10ae7}', '\u{10b38}', R),
+    ('\u{10b39}', '\u{10b3f}', ON),
+    ('\u{10b40}', '\u{10cff}', R),
+    ('\u{10d00}', '\u{10d23}', AL),
+    ('\u{10d24}', '\u{10d27}', NSM),
+    ('\u{10d28}', '\u{10d2f}', AL),
+    ('\u{10d30}', '\u{10d39}', AN),
+    ('\u{10d3a}', '\u{10d3f}', AL),
+    ('\u{10d40}', '\u{10e5f}', R),
+    ('\u{10e60}', '\u{10e7e}', AN),
+    ('\u{10e7f}', '\u{10eaa}', R),
+    ('\u{10eab}', '\u{10eac}', NSM),
+    ('\u{10ead}', '\u{10ebf}', R),
+    ('\u{10ec0}', '\u{10eff}', AL),
+    ('\u{10f00}', '\u{10f2f}', R),
+    ('\u{10f30}', '\u{10f45}', AL),
+    ('\u{10f46}', '\u{10f50}', NSM),
+    ('\u{10f51}', '\u{10f6f}', AL),
+    ('\u{10f70}', '\u{10f81}', R),
+    ('\u{10f82}', '\u{10f85}', NSM),
+    ('\u{10f86}', '\u{10fff}', R),
+    ('\u{11001}', '\u{11001}', NSM),
+    ('\u{11038}', '\u{11046}', NSM),
+    ('\u{11052}', '\u{11065}', ON),
+    ('\u{11070}', '\u{11070}', NSM),
+    ('\u{11073}', '\u{11074}', NSM),
+    ('\u{1107f}', '\u{11081}', NSM),
+    ('\u{110b3}', '\u{110b6}', NSM),
+    ('\u{110b9}', '\u{110ba}', NSM),
+    ('\u{110c2}', '\u{110c2}', NSM),
+    ('\u{11100}', '\u{11102}', NSM),
+    ('\u{11127}', '\u{1112b}', NSM),
+    ('\u{1112d}', '\u{11134}', NSM),
+    ('\u{11173}', '\u{11173}', NSM),
+    ('\u{11180}', '\u{11181}', NSM),
+    ('\u{111b6}', '\u{111be}', NSM),
+    ('\u{111c9}', '\u{111cc}', NSM),
+    ('\u{111cf}', '\u{111cf}', NSM),
+    ('\u{1122f}', '\u{11231}', NSM),
+    ('\u{11234}', '\u{11234}', NSM),
+    ('\u{11236}', '\u{11237}', NSM),
+    ('\u{1123e}', '\u{1123e}', NSM),
+    ('\u{112df}', '\u{112df}', NSM),
+    ('\u{112e3}', '\u{112ea}', NSM),
+    ('\u{11300}', '\u{11301}', NSM),
+    ('\u{1133b}', '\u{1133c}', NSM),
+    ('\u{11340}', '\u{11340}', NSM),
+    ('\u{11366}', '\u{1136c}', NSM),
+    ('\u{11370}', '\u{11374}', NSM),
+    ('\u{11438}', '\u{1143f}', NSM),
+    ('\u{11442}', '\u{11444}', NSM),
+    ('\u{11446}', '\u{11446}', NSM),
+    ('\u{1145e}', '\u{1145e}', NSM),
+    ('\u{114b3}', '\u{114b8}', NSM),
+    ('\u{114ba}', '\u{114ba}', NSM),
+    ('\u{114bf}', '\u{114c0}', NSM),
+    ('\u{114c2}', '\u{114c3}', NSM),
+    ('\u{115b2}', '\u{115b5}', NSM),
+    ('\u{115bc}', '\u{115bd}', NSM),
+    ('\u{115bf}', '\u{115c0}', NSM),
+    ('\u{115dc}', '\u{115dd}', NSM),
+    ('\u{11633}', '\u{1163a}', NSM),
+    ('\u{1163d}', '\u{1163d}', NSM),
+    ('\u{1163f}', '\u{11640}', NSM),
+    ('\u{11660}', '\u{1166c}', ON),
+    ('\u{116ab}', '\u{116ab}', NSM),
+    ('\u{116ad}', '\u{116ad}', NSM),
+    ('\u{116b0}', '\u{116b5}', NSM),
+    ('\u{116b7}', '\u{116b7}', NSM),
+    ('\u{1171d}', '\u{1171f}', NSM),
+    ('\u{11722}', '\u{11725}', NSM),
+    ('\u{11727}', '\u{1172b}', NSM),
+    ('\u{1182f}', '\u{11837}', NSM),
+    ('\u{11839}', '\u{1183a}', NSM),
+    ('\u{1193b}', '\u{1193c}', NSM),
+    ('\u{1193e}', '\u{1193e}', NSM),
+    ('\u{11943}', '\u{11943}', NSM),
+    ('\u{119d4}', '\u{119d7}', NSM),
+    ('\u{119da}', '\u{119db}', NSM),
+    ('\u{119e0}', '\u{119e0}', NSM),
+    ('\u{11a01}', '\u{11a06}', NSM),
+    ('\u{11a09}', '\u{11a0a}', NSM),
+    ('\u{11a33}', '\u{11a38}', NSM),
+    ('\u{11a3b}', '\u{11a3e}', NSM),
+    ('\u{11a47}', '\u{11a47}', NSM),
+    ('\u{11a51}', '\u{11a56}', NSM),
+    ('\u{11a59}', '\u{11a5b}', NSM),
+    ('\u{11a8a}', '\u{11a96}', NSM),
+    ('\u{11a98}', '\u{11a99}', NSM),
+    ('\u{11c30}', '\u{11c36}', NSM),
+    ('\u{11c38}', '\u{11c3d}', NSM),
+    ('\u{11c92}', '\u{11ca7}', NSM),
+    ('\u{11caa}', '\u{11cb0}', NSM),
+    ('\u{11cb2}', '\u{11cb3}', NSM),
+    ('\u{11cb5}', '\u{11cb6}', NSM),
+    ('\u{11d31}', '\u{11d36}', NSM),
+    ('\u{11d3a}', '\u{11d3a}', NSM),
+    ('\u{11d3c}', '\u{11d3d}', NSM),
+    ('\u{11d3f}', '\u{11d45}', NSM),
+    ('\u{11d47}', '\u{11d47}', NSM),
+    ('\u{11d90}', '\u{11d91}', NSM),
+    ('\u{11d95}', '\u{11d95}', NSM),
+    ('\u{11d97}', '\u{11d97}', NSM),
+    ('\u{11ef3}', '\u{11ef4}', NSM),
+    ('\u{11fd5}', '\u{11fdc}', ON),
+    ('\u{11fdd}', '\u{11fe0}', ET),
+    ('\u{11fe1}', '\u{11ff1}', ON),
+    ('\u{16af0}', '\u{16af4}', NSM),
+    ('\u{16b30}', '\u{16b36}', NSM),
+    ('\u{16f4f}', '\u{16f4f}', NSM),
+    ('\u{16f8f}', '\u{16f92}', NSM),
+    ('\u{16fe2}', '\u{16fe2}', ON),
+    ('\u{16fe4}', '\u{16fe4}', NSM),
+    ('\u{1bc9d}', '\u{1bc9e}', NSM),
+    ('\u{1bca0}', '\u{1bca3}', BN),
+    ('\u{1cf00}', '\u{1cf2d}', NSM),
+    ('\u{1cf30}', '\u{1cf46}', NSM),
+    ('\u{1d167}', '\u{1d169}', NSM),
+    ('\u{1d173}', '\u{1d17a}', BN),
+    ('\u{1d17b}', '\u{1d182}', NSM),
+    ('\u{1d185}', '\u{1d18b}', NSM),
+    ('\u{1d1aa}', '\u{1d1ad}', NSM),
+    ('\u{1d1e9}', '\u{1d1ea}', ON),
+    ('\u{1d200}', '\u{1d241}', ON),
+    ('\u{1d242}', '\u{1d244}', NSM),
+    ('\u{1d245}', '\u{1d245}', ON),
+    ('\u{1d300}', '\u{1d356}', ON),
+    ('\u{1d6db}', '\u{1d6db}', ON),
+    ('\u{1d715}', '\u{1d715}', ON),
+    ('\u{1d74f}', '\u{1d74f}', ON),
+    ('\u{1d789}', '\u{1d789}', ON),
+    ('\u{1d7c3}', '\u{1d7c3}', ON),
+    ('\u{1d7ce}', '\u{1d7ff}', EN),
+    ('\u{1da00}', '\u{1da36}', NSM),
+    ('\u{1da3b}', '\u{1da6c}', NSM),
+    ('\u{1da75}', '\u{1da75}', NSM),
+    ('\u{1da84}', '\u{1da84}', NSM),
+    ('\u{1da9b}', '\u{1da9f}', NSM),
+    ('\u{1daa1}', '\u{1daaf}', NSM),
+    ('\u{1e000}', '\u{1e006}', NSM),
+    ('\u{1e008}', '\u{1e018}', NSM),
+    ('\u{1e01b}', '\u{1e021}', NSM),
+    ('\u{1e023}', '\u{1e024}', NSM),
+    ('\u{1e026}', '\u{1e02a}', NSM),
+    ('\u{1e130}', '\u{1e136}', NSM),
+    ('\u{1e2ae}', '\u{1e2ae}', NSM),
+    ('\u{1e2ec}', '\u{1e2ef}', NSM),
+    ('\u{1e2ff}', '\u{1e2ff}', ET),
+    ('\u{1e800}', '\u{1e8cf}', R),
+    ('\u{1e8d0}', '\u{1e8d6}', NSM),
+    ('\u{1e8d7}', '\u{1e943}', R),
+    ('\u{1e944}', '\u{1e94a}', NSM),
+    ('\u{1e94b}', '\u{1ec6f}', R),
+    ('\u{1ec70}', '\u{1ecbf}', AL),
+    ('\u{1ecc0}', '\u{1ecff}', R),
+    ('\u{1ed00}', '\u{1ed4f}', AL),
+    ('\u{1ed50}', '\u{1edff}', R),
+    ('\u{1ee00}', '\u{1eeef}', AL),
+    ('\u{1eef0}', '\u{1eef1}', ON),
+    ('\u{1eef2}', '\u{1eeff}', AL),
+    ('\u{1ef00}', '\u{1efff}', R),
+    ('\u{1f000}', '\u{1f02b}', ON),
+    ('\u{1f030}', '\u{1f093}', ON),
+    ('\u{1f0a0}', '\u{1f0ae}', ON),
+    ('\u{1f0b1}', '\u{1f0bf}', ON),
+    ('\u{1f0c1}', '\u{1f0cf}', ON),
+    ('\u{1f0d1}', '\u{1f0f5}', ON),
+    ('\u{1f100}', '\u{1f10a}', EN),
+    ('\u{1f10b}', '\u{1f10f}', ON),
+    ('\u{1f12f}', '\u{1f12f}', ON),
+    ('\u{1f16a}', '\u{1f16f}', ON),
+    ('\u{1f1ad}', '\u{1f1ad}', ON),
+    ('\u{1f260}', '\u{1f265}', ON),
+    ('\u{1f300}', '\u{1f6d7}', ON),
+    ('\u{1f6dd}', '\u{1f6ec}', ON),
+    ('\u{1f6f0}', '\u{1f6fc}', ON),
+    ('\u{1f700}', '\u{1f773}', ON),
+    ('\u{1f780}', '\u{1f7d8}', ON),
+    ('\u{1f7e0}', '\u{1f7eb}', ON),
+    ('\u{1f7f0}', '\u{1f7f0}', ON),
+    ('\u{1f800}', '\u{1f80b}', ON),
+    ('\u{1f810}', '\u{1f847}', ON),
+    ('\u{1f850}', '\u{1f859}', ON),
+    ('\u{1f860}', '\u{1f887}', ON),
+    ('\u{1f890}', '\u{1f8ad}', ON),
+    ('\u{1f8b0}', '\u{1f8b1}', ON),
+    ('\u{1f900}', '\u{1fa53}', ON),
+    ('\u{1fa60}', '\u{1fa6d}', ON),
+    ('\u{1fa70}', '\u{1fa74}', ON),
+    ('\u{1fa78}', '\u{1fa7c}', ON),
+    ('\u{1fa80}', '\u{1fa86}', ON),
+    ('\u{1fa90}', '\u{1faac}', ON),
+    ('\u{1fab0}', '\u{1faba}', ON),
+    ('\u{1fac0}', '\u{1fac5}', ON),
+    ('\u{1fad0}', '\u{1fad9}', ON),
+    ('\u{1fae0}', '\u{1fae7}', ON),
+    ('\u{1faf0}', '\u{1faf6}', ON),
+    ('\u{1fb00}', '\u{1fb92}', ON),
+    ('\u{1fb94}', '\u{1fbca}', ON),
+    ('\u{1fbf0}', '\u{1fbf9}', EN),
+    ('\u{1fffe}', '\u{1ffff}', BN),
+    ('\u{2fffe}', '\u{2ffff}', BN),
+    ('\u{3fffe}', '\u{3ffff}', BN),
+    ('\u{4fffe}', '\u{4ffff}', BN),
+    ('\u{5fffe}', '\u{5ffff}', BN),
+    ('\u{6fffe}', '\u{6ffff}', BN),
+    ('\u{7fffe}', '\u{7ffff}', BN),
+    ('\u{8fffe}', '\u{8ffff}', BN),
+    ('\u{9fffe}', '\u{9ffff}', BN),
+    ('\u{afffe}', '\u{affff}', BN),
+    ('\u{bfffe}', '\u{bffff}', BN),
+    ('\u{cfffe}', '\u{cffff}', BN),
+    ('\u{dfffe}', '\u{e00ff}', BN),
+    ('\u{e0100}', '\u{e01ef}', NSM),
+    ('\u{e01f0}', '\u{e0fff}', BN),
+    ('\u{efffe}', '\u{effff}', BN),
+    ('\u{ffffe}', '\u{fffff}', BN),
+    ('\u{10fffe}', '\u{10ffff}', BN),
+];
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn levels(s: &str, base_level: BidiLevel) -> Vec<BidiLevel> {
+        let chars = s.chars().map(|c| c as u32).collect::<Vec<_>>();
+        resolve_levels(&chars, base_level)
+    }
+
+    #[test]
+    fn test_bidi_class() {
+        assert_eq!(get_bidi_class('a'), L);
+        assert_eq!(get_bidi_class('\u{5d0}'), R);
+        assert_eq!(get_bidi_class('\u{639}'), AL);
+        assert_eq!(get_bidi_class('1'), EN);
+        assert_eq!(get_bidi_class('\u{661}'), AN);
+        assert_eq!(get_bidi_class(' '), WS);
+        assert_eq!(get_bidi_class('\t'), S);
+        assert_eq!(get_bidi_class('\n'), B);
+        assert_eq!(get_bidi_class('('), ON);
+        assert_eq!(get_bidi_class('\u{301}'), NSM);
+        assert_eq!(get_bidi_class('\u{4e00}'), L);
+    }
+
+    #[test]
+    fn test_mirrored_char() {
+        assert_eq!(get_mirrored_char('('), Some(')'));
+        assert_eq!(get_mirrored_char(')'), Some('('));
+        assert_eq!(get_mirrored_char('\u{ab}'), Some('\u{bb}'));
+        assert_eq!(get_mirrored_char('a'), None);
+    }
+
+    #[test]
+    fn test_resolve_levels_ltr() {
+        assert_eq!(levels("abc def", 0), vec![0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(levels("abc \u{5d0}\u{5d1}\u{5d2}", 0), vec![0, 0, 0, 0, 1, 1, 1]);
+        // space between RTL text and a number resolves to RTL
+        assert_eq!(levels("\u{5d0}\u{5d1}\u{5d2} 123", 0), vec![1, 1, 1, 1, 2, 2, 2]);
+        // European digits after Arabic letters are Arabic numbers (W2)
+        assert_eq!(levels("\u{639} 12", 0), vec![1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn test_resolve_levels_rtl() {
+        assert_eq!(levels("\u{5d0}\u{5d1} abc", 1), vec![1, 1, 1, 2, 2, 2]);
+        // trailing punctuation takes the paragraph direction
+        assert_eq!(levels("abc!", 1), vec![2, 2, 2, 1]);
+        // "5%" stays one number
+        assert_eq!(levels("\u{5d0} 5%", 1), vec![1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn test_resolve_levels_brackets() {
+        // brackets around RTL text in a LTR paragraph preceded by RTL text are RTL (N0)
+        assert_eq!(levels("\u{5d0}\u{5d1}(\u{5d2}\u{5d3})", 0), vec![1, 1, 1, 1, 1, 1]);
+        // brackets around LTR text in a RTL paragraph preceded by LTR text are LTR
+        assert_eq!(levels("ab(cd)", 1), vec![2, 2, 2, 2, 2, 2]);
+        // brackets around LTR text in a RTL paragraph take the embedding direction
+        assert_eq!(levels("\u{5d0}(cd)", 1), vec![1, 1, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_resolve_levels_explicit() {
+        // a RLE b PDF c
+        assert_eq!(levels("a\u{202b}b\u{202c}c", 0), vec![0, 0, 2, 2, 0]);
+        // RLI abc PDI - trailing isolate formatting characters are reset (L1)
+        assert_eq!(levels("\u{2067}abc\u{2069}", 0), vec![0, 2, 2, 2, 0]);
+        // RLO overrides the strong direction of the following characters
+        assert_eq!(levels("\u{202e}ab\u{202c}", 0), vec![0, 1, 1, 0]);
+        // FSI resolves to the direction of the first strong character
+        assert_eq!(levels("a\u{2068}\u{5d0}b\u{2069}c", 0), vec![0, 0, 1, 2, 0, 0]);
+    }
+
+    #[test]
+    fn test_resolve_levels_multiple_paragraphs() {
+        assert_eq!(levels("\u{5d0} a\nb \u{5d0}", 1), vec![1, 1, 2, 1, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_reorder_visual() {
+        assert_eq!(reorder_visual(&[0, 0, 0]), vec![0, 1, 2]);
+        assert_eq!(reorder_visual(&[0, 0, 1, 1, 1, 0]), vec![0, 1, 4, 3, 2, 5]);
+        assert_eq!(reorder_visual(&[1, 1, 2, 2, 1]), vec![4, 2, 3, 1, 0]);
+        assert_eq!(reorder_visual(&[]), Vec::<usize>::new());
+    }
+}
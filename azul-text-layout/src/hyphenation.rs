@@ -0,0 +1,318 @@
+//! Pattern-based hyphenation (Frank Liang's algorithm, as used by TeX and LibreOffice)
+//!
+//! Hyphenation dictionaries are loaded as data at runtime, either in the TeX
+//! format (`\patterns{ ... }` and `\hyphenation{ ... }`) or in the format of the
+//! LibreOffice / hyphen `hyph_*.dic` files (one pattern per line). No dictionary
+//! is compiled into the binary, so `hyphens: auto` only hyphenates words after
+//! a dictionary has been registered with `register_hyphenation_dictionary`.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use std::sync::RwLock;
+
+/// Default minimum number of characters before the first hyphen
+pub const DEFAULT_LEFT_HYPHEN_MIN: usize = 2;
+/// Default minimum number of characters after the last hyphen
+pub const DEFAULT_RIGHT_HYPHEN_MIN: usize = 3;
+
+/// Dictionaries that are used for `hyphens: auto`, in the order they were registered
+static HYPHENATION_DICTIONARIES: RwLock<Vec<Hyphenator>> = RwLock::new(Vec::new());
+
+/// Set of hyphenation patterns and exceptions for one language
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hyphenator {
+    /// Pattern letters (i.e. `.hyph`) => priorities between the letters (`[0, 0, 3, 0, 0]`)
+    patterns: BTreeMap<Vec<char>, Vec<u8>>,
+    /// Words that are not hyphenated using the patterns => break positions
+    exceptions: BTreeMap<Vec<char>, Vec<usize>>,
+    /// Minimum number of characters before the first hyphen
+    pub left_hyphen_min: usize,
+    /// Minimum number of characters after the last hyphen
+    pub right_hyphen_min: usize,
+    /// Length of the longest pattern
+    max_pattern_len: usize,
+    /// All letters that occur in the patterns
+    alphabet: BTreeSet<char>,
+}
+
+impl Hyphenator {
+
+    /// Parses a hyphenation dictionary, returns `None` if the data doesn't contain any patterns
+    pub fn parse(data: &str) -> Option<Self> {
+
+        let mut hyphenator = Hyphenator {
+            patterns: BTreeMap::new(),
+            exceptions: BTreeMap::new(),
+            left_hyphen_min: DEFAULT_LEFT_HYPHEN_MIN,
+            right_hyphen_min: DEFAULT_RIGHT_HYPHEN_MIN,
+            max_pattern_len: 0,
+            alphabet: BTreeSet::new(),
+        };
+
+        if data.contains("\\patterns") {
+            hyphenator.parse_tex(data);
+        } else {
+            hyphenator.parse_dic(data);
+        }
+
+        if hyphenator.patterns.is_empty() {
+            None
+        } else {
+            Some(hyphenator)
+        }
+    }
+
+    /// Parses the TeX format: `\patterns{.ach4 ...}` and `\hyphenation{ta-ble ...}`
+    fn parse_tex(&mut self, data: &str) {
+
+        #[derive(Copy, Clone, PartialEq)]
+        enum Section { None, Patterns, Exceptions }
+
+        let mut section = Section::None;
+
+        for line in data.lines() {
+
+            // strip comments
+            let line = match line.find('%') {
+                Some(idx) => &line[..idx],
+                None => line,
+            };
+
+            for token in line.split_whitespace() {
+                let token = if token.starts_with("\\patterns{") {
+                    section = Section::Patterns;
+                    &token["\\patterns{".len()..]
+                } else if token.starts_with("\\hyphenation{") {
+                    section = Section::Exceptions;
+                    &token["\\hyphenation{".len()..]
+                } else {
+                    token
+                };
+
+                let (token, section_ended) = match token.find('}') {
+                    Some(idx) => (&token[..idx], true),
+                    None => (token, false),
+                };
+
+                if !token.is_empty() {
+                    match section {
+                        Section::Patterns => self.add_pattern(token),
+                        Section::Exceptions => self.add_exception(token),
+                        Section::None => { },
+                    }
+                }
+
+                if section_ended {
+                    section = Section::None;
+                }
+            }
+        }
+    }
+
+    /// Parses the `hyph_*.dic` format: encoding on the first line, then one pattern per line
+    fn parse_dic(&mut self, data: &str) {
+
+        for (line_idx, line) in data.lines().enumerate() {
+
+            let line = line.trim();
+
+            if line_idx == 0 {
+                continue; // encoding, i.e. "UTF-8" or "ISO8859-1"
+            }
+
+            if line.is_empty() || line.starts_with('%') || line.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            match (tokens.next(), tokens.next()) {
+                (Some("LEFTHYPHENMIN"), Some(n)) => {
+                    if let Ok(n) = n.parse() { self.left_hyphen_min = n; }
+                },
+                (Some("RIGHTHYPHENMIN"), Some(n)) => {
+                    if let Ok(n) = n.parse() { self.right_hyphen_min = n; }
+                },
+                (Some(token), None) => {
+                    // non-standard hyphenation patterns ("c1k/k=k,1,2") are not supported
+                    if !token.contains('/') && token != "NEXTLEVEL" {
+                        self.add_pattern(token);
+                    }
+                },
+                _ => { },
+            }
+        }
+    }
+
+    /// Adds a pattern like `hy3ph` or `.ach4`
+    fn add_pattern(&mut self, pattern: &str) {
+
+        let mut letters = Vec::new();
+        let mut priorities = vec![0];
+
+        for c in pattern.chars() {
+            match c.to_digit(10) {
+                Some(d) => { *priorities.last_mut().unwrap() = d as u8; },
+                None => {
+                    letters.extend(c.to_lowercase());
+                    priorities.push(0);
+                },
+            }
+        }
+
+        if letters.is_empty() {
+            return;
+        }
+
+        self.max_pattern_len = self.max_pattern_len.max(letters.len());
+        self.alphabet.extend(letters.iter().copied().filter(|c| *c != '.'));
+        self.patterns.insert(letters, priorities);
+    }
+
+    /// Adds an exception like `ta-ble`
+    fn add_exception(&mut self, exception: &str) {
+
+        let mut letters = Vec::new();
+        let mut breaks = Vec::new();
+
+        for c in exception.chars() {
+            if c == '-' {
+                breaks.push(letters.len());
+            } else {
+                letters.extend(c.to_lowercase());
+            }
+        }
+
+        if !letters.is_empty() {
+            self.exceptions.insert(letters, breaks);
+        }
+    }
+
+    /// Returns whether every letter of the word is used in at least one pattern
+    /// (used to select a dictionary for a word, since the language isn't known)
+    pub fn can_hyphenate(&self, word: &[char]) -> bool {
+        word.iter().all(|c| {
+            c.to_lowercase().all(|c| self.alphabet.contains(&c))
+        })
+    }
+
+    /// Returns the positions in the word (character indices) before which a hyphen can be inserted
+    pub fn hyphenate(&self, word: &[char]) -> Vec<usize> {
+
+        let len = word.len();
+        if len < self.left_hyphen_min + self.right_hyphen_min || len == 0 {
+            return Vec::new();
+        }
+
+        let lowercase = word.iter().map(|c| c.to_lowercase().next().unwrap_or(*c)).collect::<Vec<char>>();
+
+        if let Some(breaks) = self.exceptions.get(&lowercase) {
+            return breaks.clone();
+        }
+
+        // ".word." - the dots mark the start and end of the word
+        let mut extended = Vec::with_capacity(len + 2);
+        extended.push('.');
+        extended.extend(lowercase.iter().copied());
+        extended.push('.');
+
+        // priorities[i] = priority of a break before extended[i]
+        let mut priorities = vec![0_u8; extended.len() + 1];
+
+        for start in 0..extended.len() {
+            let max_end = (start + self.max_pattern_len).min(extended.len());
+            for end in (start + 1)..=max_end {
+                if let Some(pattern) = self.patterns.get(&extended[start..end]) {
+                    for (offset, priority) in pattern.iter().enumerate() {
+                        let p = &mut priorities[start + offset];
+                        *p = (*p).max(*priority);
+                    }
+                }
+            }
+        }
+
+        // a break before word[i] is a break before extended[i + 1]
+        (self.left_hyphen_min.max(1)..=(len - self.right_hyphen_min.max(1)))
+        .filter(|i| priorities[i + 1] % 2 == 1)
+        .collect()
+    }
+}
+
+/// Parses and registers a hyphenation dictionary for `hyphens: auto`,
+/// returns `false` if the data couldn't be parsed
+pub fn register_hyphenation_dictionary(data: &str) -> bool {
+    match Hyphenator::parse(data) {
+        Some(hyphenator) => {
+            if let Ok(mut dictionaries) = HYPHENATION_DICTIONARIES.write() {
+                dictionaries.push(hyphenator);
+            }
+            true
+        },
+        None => false,
+    }
+}
+
+/// Removes all registered hyphenation dictionaries
+pub fn clear_hyphenation_dictionaries() {
+    if let Ok(mut dictionaries) = HYPHENATION_DICTIONARIES.write() {
+        dictionaries.clear();
+    }
+}
+
+/// Hyphenates a word using the first registered dictionary that contains patterns
+/// for all letters of the word. Returns an empty list if no dictionary matches.
+pub fn hyphenate_word(word: &[char]) -> Vec<usize> {
+    let dictionaries = match HYPHENATION_DICTIONARIES.read() {
+        Ok(d) => d,
+        Err(_) => return Vec::new(),
+    };
+
+    dictionaries.iter()
+    .find(|d| d.can_hyphenate(word))
+    .map(|d| d.hyphenate(word))
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const TEX_PATTERNS: &str = "
+        % test patterns
+        \\patterns{
+        hy3ph he2n hena4 hen5at 1na n2at 1tio 2io o2n
+        }
+        \\hyphenation{ ta-ble }
+    ";
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn test_hyphenate_tex_patterns() {
+        let hyphenator = Hyphenator::parse(TEX_PATTERNS).unwrap();
+        // "hy-phen-ation"
+        assert_eq!(hyphenator.hyphenate(&chars("hyphenation")), vec![2, 6]);
+        assert_eq!(hyphenator.hyphenate(&chars("Hyphenation")), vec![2, 6]);
+        // exceptions override the patterns
+        assert_eq!(hyphenator.hyphenate(&chars("table")), vec![2]);
+        // too short to hyphenate
+        assert_eq!(hyphenator.hyphenate(&chars("hyph")), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_hyphenate_dic_patterns() {
+        let data = "UTF-8\nLEFTHYPHENMIN 1\nRIGHTHYPHENMIN 1\n1b\nc1k/k=k,1,2\n";
+        let hyphenator = Hyphenator::parse(data).unwrap();
+        assert_eq!(hyphenator.left_hyphen_min, 1);
+        assert_eq!(hyphenator.hyphenate(&chars("abab")), vec![1, 3]);
+        assert!(hyphenator.can_hyphenate(&chars("b")));
+        assert!(!hyphenator.can_hyphenate(&chars("abc")));
+    }
+
+    #[test]
+    fn test_parse_empty_dictionary() {
+        assert_eq!(Hyphenator::parse("UTF-8\n"), None);
+    }
+}
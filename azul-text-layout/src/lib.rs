@@ -25,7 +25,7 @@
 //!
 //! ```rust,ignore,no_run
 //! use azul_text_layout::{text_layout, text_shaping::get_font_metrics_freetype};
-//! use azul_css::{LayoutSize, StyleTextAlignmentHorz};
+//! use azul_css::{LayoutSize, StyleTextAlignmentHorz, StyleDirection, StyleWordBreak, StyleOverflowWrap, StyleHyphens};
 //! use azul_core::ui_solver::ResolvedTextLayoutOptions;
 //!
 //! // set all options of the text
@@ -43,12 +43,18 @@
 //!     max_horizontal_width: Some(400.0), // px
 //!     leading: None,
 //!     holes: Vec::new(),
+//!     // base direction for the bidirectional algorithm
+//!     direction: StyleDirection::Ltr,
+//!     // line breaking between letters and hyphenation
+//!     word_break: StyleWordBreak::Normal,
+//!     overflow_wrap: StyleOverflowWrap::Normal,
+//!     hyphens: StyleHyphens::Manual,
 //! };
 //!
 //! // Cache the font metrics of the given font (baseline, height, etc.)
 //! let font_metrics = get_font_metrics_freetype(font_bytes, font_index as i32);
 //! // "Hello World" => ["Hello", "World"]
-//! let words = text_layout::split_text_into_words(text);
+//! let words = text_layout::split_text_into_words(text, StyleDirection::Ltr, StyleWordBreak::Normal, StyleOverflowWrap::Normal, StyleHyphens::Manual);
 //! // "Hello" @ 14px => Size { width: 50px, height: 14px }
 //! let scaled_words = text_layout::words_to_scaled_words(&words, font_bytes, font_index, font_metrics, text_layout_options.font_size_px);
 //! // Calculate the origin of the word relative to the line
@@ -89,6 +95,9 @@ use core::ffi::c_void;
 use crate::text_shaping::ParsedFont;
 use azul_css::{FontData, FontRef};
 
+pub mod bidi;
+pub mod hyphenation;
+pub mod linebreak;
 pub mod script;
 pub mod text_layout;
 pub mod text_shaping;
@@ -140,6 +149,11 @@ pub fn parse_font_fn(source: LoadedFontSource) -> Option<FontRef> {
     })
 }
 
+pub fn font_has_glyph_fn(font_ref: &FontRef, c: u32) -> bool {
+    let parsed_font = unsafe { &*(font_ref.get_data().parsed as *const ParsedFont) };
+    parsed_font.lookup_glyph_index(c).map(|g| g != 0).unwrap_or(false)
+}
+
 pub fn get_font_metrics_fontref(font_ref: &FontRef) -> FontMetrics {
     let parsed_font = unsafe { &*(font_ref.get_data().parsed as *const ParsedFont) };
     parsed_font.font_metrics.clone()
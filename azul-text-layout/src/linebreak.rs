@@ -0,0 +1,2170 @@
+//! Implementation of the Unicode Line Breaking Algorithm ([UAX #14](https://www.unicode.org/reports/tr14/))
+//!
+//! Determines the positions in a text where a line may be broken (break opportunities),
+//! i.e. after spaces and hyphens, between CJK ideographs or after a slash in a URL.
+//!
+//! The classes `AI`, `SG`, `XX` and `SA` are resolved to `AL` (or `CM` for combining marks)
+//! and `CJ` is resolved to `NS` (rule LB1, equivalent to `line-break: strict`). There is no
+//! dictionary-based segmentation for Thai, Lao, Khmer or Myanmar text.
+
+use alloc::vec::Vec;
+use azul_css::StyleWordBreak;
+
+/// `Line_Break` property of a Unicode character, after resolving the classes of LB1
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LineBreakClass {
+    /// Mandatory break
+    BK,
+    /// Carriage return
+    CR,
+    /// Line feed
+    LF,
+    /// Next line
+    NL,
+    /// Space
+    SP,
+    /// Zero width space
+    ZW,
+    /// Zero width joiner
+    ZWJ,
+    /// Word joiner
+    WJ,
+    /// Non-breaking ("glue")
+    GL,
+    /// Combining mark
+    CM,
+    /// Break after
+    BA,
+    /// Break before
+    BB,
+    /// Break opportunity before and after
+    B2,
+    /// Hyphen
+    HY,
+    /// Contingent break opportunity
+    CB,
+    /// Close punctuation
+    CL,
+    /// Close parenthesis
+    CP,
+    /// Exclamation / interrogation
+    EX,
+    /// Inseparable
+    IN,
+    /// Nonstarter
+    NS,
+    /// Open punctuation
+    OP,
+    /// Quotation
+    QU,
+    /// Infix numeric separator
+    IS,
+    /// Numeric
+    NU,
+    /// Postfix numeric
+    PO,
+    /// Prefix numeric
+    PR,
+    /// Symbols allowing break after
+    SY,
+    /// Alphabetic
+    AL,
+    /// Hebrew letter
+    HL,
+    /// Ideographic
+    ID,
+    /// Emoji base
+    EB,
+    /// Emoji modifier
+    EM,
+    /// Hangul LV syllable
+    H2,
+    /// Hangul LVT syllable
+    H3,
+    /// Hangul L jamo
+    JL,
+    /// Hangul V jamo
+    JV,
+    /// Hangul T jamo
+    JT,
+    /// Regional indicator
+    RI,
+}
+
+use self::LineBreakClass::*;
+
+impl LineBreakClass {
+
+    fn is_alphabetic(&self) -> bool {
+        matches!(self, AL | HL)
+    }
+
+    fn is_hangul(&self) -> bool {
+        matches!(self, JL | JV | JT | H2 | H3)
+    }
+
+    fn is_hard_break(&self) -> bool {
+        matches!(self, BK | CR | LF | NL)
+    }
+}
+
+/// Returns the `Line_Break` class of a character
+pub fn get_line_break_class(c: char) -> LineBreakClass {
+    use core::cmp::Ordering;
+    match LINE_BREAK_CLASS_TABLE.binary_search_by(|&(start, end, _)| {
+        if c < start {
+            Ordering::Greater
+        } else if c > end {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(idx) => LINE_BREAK_CLASS_TABLE[idx].2,
+        Err(_) => AL,
+    }
+}
+
+/// Returns the line breaking classes of a text, given as a list of unicode codepoints
+pub fn get_line_break_classes(text: &[u32]) -> Vec<LineBreakClass> {
+    text.iter()
+    .map(|c| core::char::from_u32(*c).map(get_line_break_class).unwrap_or(AL))
+    .collect()
+}
+
+/// Returns for every character of the text whether a line may be broken directly
+/// before it. The first character never has a break opportunity, mandatory breaks
+/// (i.e. after a `\n`) are also reported as break opportunities.
+///
+/// `word-break: break-all` treats letters and numbers like ideographs (breaks are
+/// allowed between any two letters), `word-break: keep-all` suppresses the break
+/// opportunities between letters, numbers and ideographs.
+pub fn get_line_break_opportunities(text: &[u32], word_break: StyleWordBreak) -> Vec<bool> {
+
+    let mut classes = get_line_break_classes(text);
+
+    if word_break == StyleWordBreak::BreakAll {
+        for class in classes.iter_mut() {
+            if matches!(class, AL | HL | NU) {
+                *class = ID;
+            }
+        }
+    }
+
+    let mut breaks = vec![false; text.len()];
+
+    // class of the last character before the current position, after applying LB9 / LB10
+    let mut before = match classes.first() {
+        Some(CM) | Some(ZWJ) => AL, // LB10
+        Some(c) => *c,
+        None => return breaks,
+    };
+    // class of the character before `before` (for LB21a)
+    let mut before_before = None;
+    // last class that wasn't a space (for the rules with "SP*")
+    let mut before_spaces = before;
+    // number of consecutive regional indicators before the current position (LB30a)
+    let mut regional_indicators = if before == RI { 1 } else { 0 };
+
+    for i in 1..classes.len() {
+
+        let raw_before = classes[i - 1];
+        let current = classes[i];
+
+        let (can_break, is_attached) = get_break_at(
+            raw_before,
+            before,
+            before_before,
+            before_spaces,
+            current,
+            regional_indicators,
+        );
+
+        breaks[i] = can_break;
+
+        if is_attached {
+            // LB9: combining marks take the class of their base character
+            continue;
+        }
+
+        // LB10: remaining combining marks are treated as alphabetic
+        let current = if matches!(current, CM | ZWJ) { AL } else { current };
+
+        before_before = Some(before);
+        before = current;
+        if current != SP {
+            before_spaces = current;
+        }
+        regional_indicators = if current == RI { regional_indicators + 1 } else { 0 };
+    }
+
+    if word_break == StyleWordBreak::KeepAll {
+        for i in 1..classes.len() {
+            if is_letter_unit(classes[i - 1]) && is_letter_unit(classes[i]) {
+                breaks[i] = false;
+            }
+        }
+    }
+
+    breaks
+}
+
+/// Typographic letter units for `word-break: keep-all`
+fn is_letter_unit(class: LineBreakClass) -> bool {
+    matches!(class, AL | HL | NU | ID | EB | EM | H2 | H3 | JL | JV | JT | CM)
+}
+
+/// Returns for every character of the text whether the text may be broken before it
+/// when no other break opportunity exists (`overflow-wrap: anywhere`), i.e. between
+/// any two characters that are not part of the same grapheme cluster (approximated by
+/// combining marks, joiners, emoji modifiers, Hangul jamo and regional indicator pairs).
+pub fn get_emergency_break_opportunities(text: &[u32]) -> Vec<bool> {
+
+    let classes = get_line_break_classes(text);
+    let mut breaks = vec![false; text.len()];
+    let mut regional_indicators = 0;
+
+    for i in 1..classes.len() {
+        let (before, current) = (classes[i - 1], classes[i]);
+        regional_indicators = if before == RI { regional_indicators + 1 } else { 0 };
+        breaks[i] = match (before, current) {
+            (_, CM) | (_, ZWJ) | (ZWJ, _) => false,
+            (CR, LF) => false,
+            (EB, EM) | (ID, EM) => false,
+            (RI, RI) => regional_indicators % 2 == 0,
+            (JL, JL) | (JL, JV) | (JL, H2) | (JL, H3) |
+            (JV, JV) | (JV, JT) | (H2, JV) | (H2, JT) |
+            (JT, JT) | (H3, JT) => false,
+            _ => true,
+        };
+    }
+
+    breaks
+}
+
+/// Applies the pair rules LB4 - LB31, returns whether a line may break between
+/// two characters and whether the current character is a combining mark that is
+/// attached to the previous character (LB9)
+fn get_break_at(
+    raw_before: LineBreakClass,
+    before: LineBreakClass,
+    before_before: Option<LineBreakClass>,
+    before_spaces: LineBreakClass,
+    current: LineBreakClass,
+    regional_indicators: usize,
+) -> (bool, bool) {
+
+    // LB4, LB5: always break after hard line breaks, but not within CR LF
+    if raw_before == CR && current == LF {
+        return (false, false);
+    }
+    if raw_before.is_hard_break() {
+        return (true, false);
+    }
+
+    // LB6: don't break before hard line breaks
+    if current.is_hard_break() {
+        return (false, false);
+    }
+
+    // LB7: don't break before spaces or zero width space
+    if matches!(current, SP | ZW) {
+        return (false, false);
+    }
+
+    // LB8: break before any character following a zero width space, even if spaces intervene
+    if before_spaces == ZW {
+        return (true, false);
+    }
+
+    // LB8a: don't break after a zero width joiner
+    if raw_before == ZWJ {
+        return (false, matches!(current, CM | ZWJ));
+    }
+
+    // LB9: don't break a combining character sequence
+    if matches!(current, CM | ZWJ) && !matches!(before, BK | CR | LF | NL | SP | ZW) {
+        return (false, true);
+    }
+
+    // LB10: treat any remaining combining mark as AL
+    let current = if matches!(current, CM | ZWJ) { AL } else { current };
+
+    // LB11: don't break before or after word joiner
+    if current == WJ || before == WJ {
+        return (false, false);
+    }
+
+    // LB12: don't break after glue
+    if before == GL {
+        return (false, false);
+    }
+
+    // LB12a: don't break before glue, except after spaces and hyphens
+    if current == GL && !matches!(before, SP | BA | HY) {
+        return (false, false);
+    }
+
+    // LB13: don't break before closing punctuation, exclamation marks and symbols
+    if matches!(current, CL | CP | EX | IS | SY) {
+        return (false, false);
+    }
+
+    // LB14: don't break after opening punctuation, even after spaces
+    if before_spaces == OP {
+        return (false, false);
+    }
+
+    // LB15: don't break within quotation marks followed by opening punctuation
+    if before_spaces == QU && current == OP {
+        return (false, false);
+    }
+
+    // LB16: don't break between closing punctuation and a nonstarter
+    if matches!(before_spaces, CL | CP) && current == NS {
+        return (false, false);
+    }
+
+    // LB17: don't break within "B2 SP* B2" (i.e. em dashes)
+    if before_spaces == B2 && current == B2 {
+        return (false, false);
+    }
+
+    // LB18: break after spaces
+    if before == SP {
+        return (true, false);
+    }
+
+    // LB19: don't break before or after quotation marks
+    if current == QU || before == QU {
+        return (false, false);
+    }
+
+    // LB20: break before and after contingent break opportunities
+    if current == CB || before == CB {
+        return (true, false);
+    }
+
+    // LB21: don't break before hyphens and nonstarters or after "break before" characters
+    if matches!(current, BA | HY | NS) || before == BB {
+        return (false, false);
+    }
+
+    // LB21a: don't break after the hyphen in Hebrew + hyphen
+    if matches!(before, HY | BA) && before_before == Some(HL) {
+        return (false, false);
+    }
+
+    // LB21b: don't break between a solidus and a Hebrew letter
+    if before == SY && current == HL {
+        return (false, false);
+    }
+
+    // LB22: don't break before ellipses
+    if current == IN {
+        return (false, false);
+    }
+
+    let no_break = match (before, current) {
+        // LB23: don't break between letters and digits
+        (AL, NU) | (HL, NU) | (NU, AL) | (NU, HL) => true,
+        // LB23a: don't break between numeric prefixes and ideographs or ideographs and postfixes
+        (PR, ID) | (PR, EB) | (PR, EM) | (ID, PO) | (EB, PO) | (EM, PO) => true,
+        // LB24: don't break between numeric prefixes / postfixes and letters
+        (PR, AL) | (PR, HL) | (PO, AL) | (PO, HL) | (AL, PR) | (AL, PO) | (HL, PR) | (HL, PO) => true,
+        // LB25: don't break numbers ("$(12.35)", "2,1%")
+        (CL, PO) | (CP, PO) | (CL, PR) | (CP, PR) | (NU, PO) | (NU, PR) |
+        (PO, OP) | (PO, NU) | (PR, OP) | (PR, NU) | (HY, NU) | (IS, NU) |
+        (NU, NU) | (SY, NU) => true,
+        // LB26, LB27: don't break Korean syllables
+        (JL, JL) | (JL, JV) | (JL, H2) | (JL, H3) |
+        (JV, JV) | (JV, JT) | (H2, JV) | (H2, JT) |
+        (JT, JT) | (H3, JT) => true,
+        (a, PO) if a.is_hangul() => true,
+        (PR, b) if b.is_hangul() => true,
+        // LB28: don't break between letters
+        (a, b) if a.is_alphabetic() && b.is_alphabetic() => true,
+        // LB29: don't break between numeric punctuation and letters ("e.g.")
+        (IS, b) if b.is_alphabetic() => true,
+        // LB30: don't break between letters / numbers and parentheses ("(s)he")
+        (a, OP) if a.is_alphabetic() || a == NU => true,
+        (CP, b) if b.is_alphabetic() || b == NU => true,
+        // LB30a: don't break between pairs of regional indicators (flags)
+        (RI, RI) => regional_indicators % 2 == 1,
+        // LB30b: don't break between emoji bases and emoji modifiers
+        (EB, EM) => true,
+        _ => false,
+    };
+
+    // LB31: break everywhere else
+    (!no_break, false)
+}
+
+/// Ranges of characters that don't have the default `Line_Break` class of `AL`, generated
+/// from the general category and east asian width (`UnicodeData.txt`, Unicode 14.0)
+/// and the explicit class assignments of `LineBreak.txt`
+const LINE_BREAK_CLASS_TABLE: &[(char, char, LineBreakClass)] = &[
+    ('\u{0}', '\u{8}', CM),
+    ('\u{9}', '\u{9}', BA),
+    ('\u{a}', '\u{a}', LF),
+    ('\u{b}', '\u{c}', BK),
+    ('\u{d}', '\u{d}', CR),
+    ('\u{e}', '\u{1f}', CM),
+    ('\u{20}', '\u{20}', SP),
+    ('\u{21}', '\u{21}', EX),
+    ('\u{22}', '\u{22}', QU),
+    ('\u{24}', '\u{24}', PR),
+    ('\u{25}', '\u{25}', PO),
+    ('\u{27}', '\u{27}', QU),
+    ('\u{28}', '\u{28}', OP),
+    ('\u{29}', '\u{29}', CP),
+    ('\u{2b}', '\u{2b}', PR),
+    ('\u{2c}', '\u{2c}', IS),
+    ('\u{2d}', '\u{2d}', HY),
+    ('\u{2e}', '\u{2e}', IS),
+    ('\u{2f}', '\u{2f}', SY),
+    ('\u{30}', '\u{39}', NU),
+    ('\u{3a}', '\u{3b}', IS),
+    ('\u{3f}', '\u{3f}', EX),
+    ('\u{5b}', '\u{5b}', OP),
+    ('\u{5c}', '\u{5c}', PR),
+    ('\u{5d}', '\u{5d}', CP),
+    ('\u{7b}', '\u{7b}', OP),
+    ('\u{7c}', '\u{7c}', BA),
+    ('\u{7d}', '\u{7d}', CL),
+    ('\u{7f}', '\u{84}', CM),
+    ('\u{85}', '\u{85}', NL),
+    ('\u{86}', '\u{9f}', CM),
+    ('\u{a0}', '\u{a0}', GL),
+    ('\u{a1}', '\u{a1}', OP),
+    ('\u{a2}', '\u{a2}', PO),
+    ('\u{a3}', '\u{a5}', PR),
+    ('\u{ab}', '\u{ab}', QU),
+    ('\u{ad}', '\u{ad}', BA),
+    ('\u{b0}', '\u{b0}', PO),
+    ('\u{b1}', '\u{b1}', PR),
+    ('\u{b4}', '\u{b4}', BB),
+    ('\u{bb}', '\u{bb}', QU),
+    ('\u{bf}', '\u{bf}', OP),
+    ('\u{2c8}', '\u{2c8}', BB),
+    ('\u{2cc}', '\u{2cc}', BB),
+    ('\u{2df}', '\u{2df}', BB),
+    ('\u{300}', '\u{34e}', CM),
+    ('\u{34f}', '\u{34f}', GL),
+    ('\u{350}', '\u{35b}', CM),
+    ('\u{35c}', '\u{362}', GL),
+    ('\u{363}', '\u{36f}', CM),
+    ('\u{37e}', '\u{37e}', IS),
+    ('\u{483}', '\u{489}', CM),
+    ('\u{589}', '\u{589}', IS),
+    ('\u{58a}', '\u{58a}', BA),
+    ('\u{58f}', '\u{58f}', PR),
+    ('\u{591}', '\u{5bd}', CM),
+    ('\u{5be}', '\u{5be}', BA),
+    ('\u{5bf}', '\u{5bf}', CM),
+    ('\u{5c1}', '\u{5c2}', CM),
+    ('\u{5c4}', '\u{5c5}', CM),
+    ('\u{5c6}', '\u{5c6}', EX),
+    ('\u{5c7}', '\u{5c7}', CM),
+    ('\u{5d0}', '\u{5f2}', HL),
+    ('\u{609}', '\u{60b}', PO),
+    ('\u{60c}', '\u{60d}', IS),
+    ('\u{610}', '\u{61a}', CM),
+    ('\u{61b}', '\u{61b}', EX),
+    ('\u{61d}', '\u{61f}', EX),
+    ('\u{64b}', '\u{65f}', CM),
+    ('\u{660}', '\u{669}', NU),
+    ('\u{66a}', '\u{66a}', PO),
+    ('\u{66b}', '\u{66c}', NU),
+    ('\u{670}', '\u{670}', CM),
+    ('\u{6d4}', '\u{6d4}', EX),
+    ('\u{6d6}', '\u{6dc}', CM),
+    ('\u{6df}', '\u{6e4}', CM),
+    ('\u{6e7}', '\u{6e8}', CM),
+    ('\u{6ea}', '\u{6ed}', CM),
+    ('\u{6f0}', '\u{6f9}', NU),
+    ('\u{711}', '\u{711}', CM),
+    ('\u{730}', '\u{74a}', CM),
+    ('\u{7a6}', '\u{7b0}', CM),
+    ('\u{7c0}', '\u{7c9}', NU),
+    ('\u{7eb}', '\u{7f3}', CM),
+    ('\u{7f8}', '\u{7f8}', IS),
+    ('\u{7f9}', '\u{7f9}', EX),
+    ('\u{7fd}', '\u{7fd}', CM),
+    ('\u{7fe}', '\u{7ff}', PR),
+    ('\u{816}', '\u{819}', CM),
+    ('\u{81b}', '\u{823}', CM),
+    ('\u{825}', '\u{827}', CM),
+    ('\u{829}', '\u{82d}', CM),
+    ('\u{859}', '\u{85b}', CM),
+    ('\u{898}', '\u{89f}', CM),
+    ('\u{8ca}', '\u{8e1}', CM),
+    ('\u{8e3}', '\u{903}', CM),
+    ('\u{93a}', '\u{93c}', CM),
+    ('\u{93e}', '\u{94f}', CM),
+    ('\u{951}', '\u{957}', CM),
+    ('\u{962}', '\u{963}', CM),
+    ('\u{964}', '\u{965}', BA),
+    ('\u{966}', '\u{96f}', NU),
+    ('\u{981}', '\u{983}', CM),
+    ('\u{9bc}', '\u{9bc}', CM),
+    ('\u{9be}', '\u{9cd}', CM),
+    ('\u{9d7}', '\u{9d7}', CM),
+    ('\u{9e2}', '\u{9e3}', CM),
+    ('\u{9e6}', '\u{9ef}', NU),
+    ('\u{9f2}', '\u{9f3}', PO),
+    ('\u{9f9}', '\u{9f9}', PO),
+    ('\u{9fb}', '\u{9fb}', PR),
+    ('\u{9fe}', '\u{a03}', CM),
+    ('\u{a3c}', '\u{a51}', CM),
+    ('\u{a66}', '\u{a6f}', NU),
+    ('\u{a70}', '\u{a71}', CM),
+    ('\u{a75}', '\u{a75}', CM),
+    ('\u{a81}', '\u{a83}', CM),
+    ('\u{abc}', '\u{abc}', CM),
+    ('\u{abe}', '\u{acd}', CM),
+    ('\u{ae2}', '\u{ae3}', CM),
+    ('\u{ae6}', '\u{aef}', NU),
+    ('\u{af1}', '\u{af1}', PR),
+    ('\u{afa}', '\u{b03}', CM),
+    ('\u{b3c}', '\u{b3c}', CM),
+    ('\u{b3e}', '\u{b57}', CM),
+    ('\u{b62}', '\u{b63}', CM),
+    ('\u{b66}', '\u{b6f}', NU),
+    ('\u{b82}', '\u{b82}', CM),
+    ('\u{bbe}', '\u{bcd}', CM),
+    ('\u{bd7}', '\u{bd7}', CM),
+    ('\u{be6}', '\u{bef}', NU),
+    ('\u{bf9}', '\u{bf9}', PR),
+    ('\u{c00}', '\u{c04}', CM),
+    ('\u{c3c}', '\u{c3c}', CM),
+    ('\u{c3e}', '\u{c56}', CM),
+    ('\u{c62}', '\u{c63}', CM),
+    ('\u{c66}', '\u{c6f}', NU),
+    ('\u{c81}', '\u{c83}', CM),
+    ('\u{cbc}', '\u{cbc}', CM),
+    ('\u{cbe}', '\u{cd6}', CM),
+    ('\u{ce2}', '\u{ce3}', CM),
+    ('\u{ce6}', '\u{cef}', NU),
+    ('\u{d00}', '\u{d03}', CM),
+    ('\u{d3b}', '\u{d3c}', CM),
+    ('\u{d3e}', '\u{d4d}', CM),
+    ('\u{d57}', '\u{d57}', CM),
+    ('\u{d62}', '\u{d63}', CM),
+    ('\u{d66}', '\u{d6f}', NU),
+    ('\u{d79}', '\u{d79}', PO),
+    ('\u{d81}', '\u{d83}', CM),
+    ('\u{dca}', '\u{ddf}', CM),
+    ('\u{de6}', '\u{def}', NU),
+    ('\u{df2}', '\u{df3}', CM),
+    ('\u{e31}', '\u{e31}', CM),
+    ('\u{e34}', '\u{e3a}', CM),
+    ('\u{e3f}', '\u{e3f}', PR),
+    ('\u{e47}', '\u{e4e}', CM),
+    ('\u{e50}', '\u{e59}', NU),
+    ('\u{e5a}', '\u{e5b}', BA),
+    ('\u{eb1}', '\u{eb1}', CM),
+    ('\u{eb4}', '\u{ebc}', CM),
+    ('\u{ec8}', '\u{ecd}', CM),
+    ('\u{ed0}', '\u{ed9}', NU),
+    ('\u{f01}', '\u{f04}', BB),
+    ('\u{f06}', '\u{f07}', BB),
+    ('\u{f08}', '\u{f08}', GL),
+    ('\u{f09}', '\u{f0a}', BB),
+    ('\u{f0b}', '\u{f0b}', BA),
+    ('\u{f0c}', '\u{f0c}', GL),
+    ('\u{f0d}', '\u{f11}', EX),
+    ('\u{f12}', '\u{f12}', GL),
+    ('\u{f14}', '\u{f14}', EX),
+    ('\u{f18}', '\u{f19}', CM),
+    ('\u{f20}', '\u{f29}', NU),
+    ('\u{f34}', '\u{f34}', BA),
+    ('\u{f35}', '\u{f35}', CM),
+    ('\u{f37}', '\u{f37}', CM),
+    ('\u{f39}', '\u{f39}', CM),
+    ('\u{f3a}', '\u{f3a}', OP),
+    ('\u{f3b}', '\u{f3b}', CL),
+    ('\u{f3c}', '\u{f3c}', OP),
+    ('\u{f3d}', '\u{f3d}', CL),
+    ('\u{f3e}', '\u{f3f}', CM),
+    ('\u{f71}', '\u{f7e}', CM),
+    ('\u{f7f}', '\u{f7f}', BA),
+    ('\u{f80}', '\u{f84}', CM),
+    ('\u{f85}', '\u{f85}', BA),
+    ('\u{f86}', '\u{f87}', CM),
+    ('\u{f8d}', '\u{fbc}', CM),
+    ('\u{fbe}', '\u{fbf}', BA),
+    ('\u{fc6}', '\u{fc6}', CM),
+    ('\u{fd0}', '\u{fd1}', BB),
+    ('\u{fd2}', '\u{fd2}', BA),
+    ('\u{fd3}', '\u{fd3}', BB),
+    ('\u{fd5}', '\u{fd8}', GL),
+    ('\u{102b}', '\u{103e}', CM),
+    ('\u{1040}', '\u{1049}', NU),
+    ('\u{104a}', '\u{104b}', BA),
+    ('\u{1056}', '\u{1059}', CM),
+    ('\u{105e}', '\u{1060}', CM),
+    ('\u{1062}', '\u{1064}', CM),
+    ('\u{1067}', '\u{106d}', CM),
+    ('\u{1071}', '\u{1074}', CM),
+    ('\u{1082}', '\u{108d}', CM),
+    ('\u{108f}', '\u{108f}', CM),
+    ('\u{1090}', '\u{1099}', NU),
+    ('\u{109a}', '\u{109d}', CM),
+    ('\u{1100}', '\u{115f}', JL),
+    ('\u{1160}', '\u{11a7}', JV),
+    ('\u{11a8}', '\u{11ff}', JT),
+    ('\u{135d}', '\u{135f}', CM),
+    ('\u{1361}', '\u{1361}', BA),
+    ('\u{1680}', '\u{1680}', BA),
+    ('\u{169b}', '\u{169b}', OP),
+    ('\u{169c}', '\u{169c}', CL),
+    ('\u{1712}', '\u{1715}', CM),
+    ('\u{1732}', '\u{1734}', CM),
+    ('\u{1735}', '\u{1736}', BA),
+    ('\u{1752}', '\u{1753}', CM),
+    ('\u{1772}', '\u{1773}', CM),
+    ('\u{17b4}', '\u{17d3}', CM),
+    ('\u{17d4}', '\u{17d5}', BA),
+    ('\u{17d6}', '\u{17d6}', NS),
+    ('\u{17d8}', '\u{17d8}', BA),
+    ('\u{17da}', '\u{17da}', BA),
+    ('\u{17db}', '\u{17db}', PR),
+    ('\u{17dd}', '\u{17dd}', CM),
+    ('\u{17e0}', '\u{17e9}', NU),
+    ('\u{1802}', '\u{1803}', EX),
+    ('\u{1804}', '\u{1805}', BA),
+    ('\u{1806}', '\u{1806}', BB),
+    ('\u{1808}', '\u{1809}', EX),
+    ('\u{180b}', '\u{180d}', CM),
+    ('\u{180e}', '\u{180e}', GL),
+    ('\u{180f}', '\u{180f}', CM),
+    ('\u{1810}', '\u{1819}', NU),
+    ('\u{1885}', '\u{1886}', CM),
+    ('\u{18a9}', '\u{18a9}', CM),
+    ('\u{1920}', '\u{193b}', CM),
+    ('\u{1944}', '\u{1945}', EX),
+    ('\u{1946}', '\u{194f}', NU),
+    ('\u{19d0}', '\u{19d9}', NU),
+    ('\u{1a17}', '\u{1a1b}', CM),
+    ('\u{1a55}', '\u{1a7f}', CM),
+    ('\u{1a80}', '\u{1a99}', NU),
+    ('\u{1ab0}', '\u{1b04}', CM),
+    ('\u{1b34}', '\u{1b44}', CM),
+    ('\u{1b50}', '\u{1b59}', NU),
+    ('\u{1b5a}', '\u{1b5b}', BA),
+    ('\u{1b5d}', '\u{1b60}', BA),
+    ('\u{1b6b}', '\u{1b73}', CM),
+    ('\u{1b80}', '\u{1b82}', CM),
+    ('\u{1ba1}', '\u{1bad}', CM),
+    ('\u{1bb0}', '\u{1bb9}', NU),
+    ('\u{1be6}', '\u{1bf3}', CM),
+    ('\u{1c24}', '\u{1c37}', CM),
+    ('\u{1c40}', '\u{1c49}', NU),
+    ('\u{1c50}', '\u{1c59}', NU),
+    ('\u{1cd0}', '\u{1cd2}', CM),
+    ('\u{1cd4}', '\u{1ce8}', CM),
+    ('\u{1ced}', '\u{1ced}', CM),
+    ('\u{1cf4}', '\u{1cf4}', CM),
+    ('\u{1cf7}', '\u{1cf9}', CM),
+    ('\u{1dc0}', '\u{1dff}', CM),
+    ('\u{1ffd}', '\u{1ffd}', BB),
+    ('\u{2000}', '\u{2006}', BA),
+    ('\u{2007}', '\u{2007}', GL),
+    ('\u{2008}', '\u{200a}', BA),
+    ('\u{200b}', '\u{200b}', ZW),
+    ('\u{200c}', '\u{200c}', CM),
+    ('\u{200d}', '\u{200d}', ZWJ),
+    ('\u{200e}', '\u{200f}', CM),
+    ('\u{2010}', '\u{2010}', BA),
+    ('\u{2011}', '\u{2011}', GL),
+    ('\u{2012}', '\u{2013}', BA),
+    ('\u{2014}', '\u{2014}', B2),
+    ('\u{2018}', '\u{2019}', QU),
+    ('\u{201a}', '\u{201a}', OP),
+    ('\u{201b}', '\u{201d}', QU),
+    ('\u{201e}', '\u{201e}', OP),
+    ('\u{201f}', '\u{201f}', QU),
+    ('\u{2024}', '\u{2026}', IN),
+    ('\u{2027}', '\u{2027}', BA),
+    ('\u{2028}', '\u{2029}', BK),
+    ('\u{202a}', '\u{202e}', CM),
+    ('\u{202f}', '\u{202f}', GL),
+    ('\u{2030}', '\u{2037}', PO),
+    ('\u{2039}', '\u{203a}', QU),
+    ('\u{203c}', '\u{203d}', NS),
+    ('\u{2044}', '\u{2044}', IS),
+    ('\u{2045}', '\u{2045}', OP),
+    ('\u{2046}', '\u{2046}', CL),
+    ('\u{2047}', '\u{2049}', NS),
+    ('\u{2056}', '\u{2056}', BA),
+    ('\u{2058}', '\u{205b}', BA),
+    ('\u{205d}', '\u{205f}', BA),
+    ('\u{2060}', '\u{2060}', WJ),
+    ('\u{2066}', '\u{206f}', CM),
+    ('\u{207d}', '\u{207d}', OP),
+    ('\u{207e}', '\u{207e}', CL),
+    ('\u{208d}', '\u{208d}', OP),
+    ('\u{208e}', '\u{208e}', CL),
+    ('\u{20a0}', '\u{20a6}', PR),
+    ('\u{20a7}', '\u{20a7}', PO),
+    ('\u{20a8}', '\u{20b5}', PR),
+    ('\u{20b6}', '\u{20b6}', PO),
+    ('\u{20b7}', '\u{20ba}', PR),
+    ('\u{20bb}', '\u{20bb}', PO),
+    ('\u{20bc}', '\u{20bd}', PR),
+    ('\u{20be}', '\u{20be}', PO),
+    ('\u{20bf}', '\u{20bf}', PR),
+    ('\u{20c0}', '\u{20c0}', PO),
+    ('\u{20d0}', '\u{20f0}', CM),
+    ('\u{2103}', '\u{2103}', PO),
+    ('\u{2109}', '\u{2109}', PO),
+    ('\u{2116}', '\u{2116}', PR),
+    ('\u{2212}', '\u{2213}', PR),
+    ('\u{22ef}', '\u{22ef}', IN),
+    ('\u{2308}', '\u{2308}', OP),
+    ('\u{2309}', '\u{2309}', CL),
+    ('\u{230a}', '\u{230a}', OP),
+    ('\u{230b}', '\u{230b}', CL),
+    ('\u{231a}', '\u{231b}', ID),
+    ('\u{2329}', '\u{2329}', OP),
+    ('\u{232a}', '\u{232a}', CL),
+    ('\u{23e9}', '\u{23ec}', ID),
+    ('\u{23f0}', '\u{23f0}', ID),
+    ('\u{23f3}', '\u{23f3}', ID),
+    ('\u{25fd}', '\u{25fe}', ID),
+    ('\u{2614}', '\u{2615}', ID),
+    ('\u{261d}', '\u{261d}', EB),
+    ('\u{2648}', '\u{2653}', ID),
+    ('\u{267f}', '\u{267f}', ID),
+    ('\u{2693}', '\u{2693}', ID),
+    ('\u{26a1}', '\u{26a1}', ID),
+    ('\u{26aa}', '\u{26ab}', ID),
+    ('\u{26bd}', '\u{26be}', ID),
+    ('\u{26c4}', '\u{26c5}', ID),
+    ('\u{26ce}', '\u{26ce}', ID),
+    ('\u{26d4}', '\u{26d4}', ID),
+    ('\u{26ea}', '\u{26ea}', ID),
+    ('\u{26f2}', '\u{26f3}', ID),
+    ('\u{26f5}', '\u{26f5}', ID),
+    ('\u{26f9}', '\u{26f9}', EB),
+    ('\u{26fa}', '\u{26fa}', ID),
+    ('\u{26fd}', '\u{26fd}', ID),
+    ('\u{2705}', '\u{2705}', ID),
+    ('\u{270a}', '\u{270d}', EB),
+    ('\u{2728}', '\u{2728}', ID),
+    ('\u{274c}', '\u{274c}', ID),
+    ('\u{274e}', '\u{274e}', ID),
+    ('\u{2753}', '\u{2755}', ID),
+    ('\u{2757}', '\u{2757}', ID),
+    ('\u{275b}', '\u{2760}', QU),
+    ('\u{2762}', '\u{2763}', EX),
+    ('\u{2768}', '\u{2768}', OP),
+    ('\u{2769}', '\u{2769}', CL),
+    ('\u{276a}', '\u{276a}', OP),
+    ('\u{276b}', '\u{276b}', CL),
+    ('\u{276c}', '\u{276c}', OP),
+    ('\u{276d}', '\u{276d}', CL),
+    ('\u{276e}', '\u{276e}', OP),
+    ('\u{276f}', '\u{276f}', CL),
+    ('\u{2770}', '\u{2770}', OP),
+    ('\u{2771}', '\u{2771}', CL),
+    ('\u{2772}', '\u{2772}', OP),
+    ('\u{2773}', '\u{2773}', CL),
+    ('\u{2774}', '\u{2774}', OP),
+    ('\u{2775}', '\u{2775}', CL),
+    ('\u{2795}', '\u{2797}', ID),
+    ('\u{27b0}', '\u{27b0}', ID),
+    ('\u{27bf}', '\u{27bf}', ID),
+    ('\u{27c5}', '\u{27c5}', OP),
+    ('\u{27c6}', '\u{27c6}', CL),
+    ('\u{27e6}', '\u{27e6}', OP),
+    ('\u{27e7}', '\u{27e7}', CL),
+    ('\u{27e8}', '\u{27e8}', OP),
+    ('\u{27e9}', '\u{27e9}', CL),
+    ('\u{27ea}', '\u{27ea}', OP),
+    ('\u{27eb}', '\u{27eb}', CL),
+    ('\u{27ec}', '\u{27ec}', OP),
+    ('\u{27ed}', '\u{27ed}', CL),
+    ('\u{27ee}', '\u{27ee}', OP),
+    ('\u{27ef}', '\u{27ef}', CL),
+    ('\u{2983}', '\u{2983}', OP),
+    ('\u{2984}', '\u{2984}', CL),
+    ('\u{2985}', '\u{2985}', OP),
+    ('\u{2986}', '\u{2986}', CL),
+    ('\u{2987}', '\u{2987}', OP),
+    ('\u{2988}', '\u{2988}', CL),
+    ('\u{2989}', '\u{2989}', OP),
+    ('\u{298a}', '\u{298a}', CL),
+    ('\u{298b}', '\u{298b}', OP),
+    ('\u{298c}', '\u{298c}', CL),
+    ('\u{298d}', '\u{298d}', OP),
+    ('\u{298e}', '\u{298e}', CL),
+    ('\u{298f}', '\u{298f}', OP),
+    ('\u{2990}', '\u{2990}', CL),
+    ('\u{2991}', '\u{2991}', OP),
+    ('\u{2992}', '\u{2992}', CL),
+    ('\u{2993}', '\u{2993}', OP),
+    ('\u{2994}', '\u{2994}', CL),
+    ('\u{2995}', '\u{2995}', OP),
+    ('\u{2996}', '\u{2996}', CL),
+    ('\u{2997}', '\u{2997}', OP),
+    ('\u{2998}', '\u{2998}', CL),
+    ('\u{29d8}', '\u{29d8}', OP),
+    ('\u{29d9}', '\u{29d9}', CL),
+    ('\u{29da}', '\u{29da}', OP),
+    ('\u{29db}', '\u{29db}', CL),
+    ('\u{29fc}', '\u{29fc}', OP),
+    ('\u{29fd}', '\u{29fd}', CL),
+    ('\u{2b1b}', '\u{2b1c}', ID),
+    ('\u{2b50}', '\u{2b50}', ID),
+    ('\u{2b55}', '\u{2b55}', ID),
+    ('\u{2cef}', '\u{2cf1}', CM),
+    ('\u{2cf9}', '\u{2cf9}', EX),
+    ('\u{2cfa}', '\u{2cfc}', BA),
+    ('\u{2cfe}', '\u{2cfe}', EX),
+    ('\u{2cff}', '\u{2cff}', BA),
+    ('\u{2d7f}', '\u{2d7f}', CM),
+    ('\u{2de0}', '\u{2dff}', CM),
+    ('\u{2e02}', '\u{2e05}', QU),
+    ('\u{2e09}', '\u{2e0a}', QU),
+    ('\u{2e0c}', '\u{2e0d}', QU),
+    ('\u{2e0e}', '\u{2e15}', BA),
+    ('\u{2e17}', '\u{2e17}', BA),
+    ('\u{2e19}', '\u{2e19}', BA),
+    ('\u{2e1c}', '\u{2e1d}', QU),
+    ('\u{2e20}', '\u{2e21}', QU),
+    ('\u{2e22}', '\u{2e22}', OP),
+    ('\u{2e23}', '\u{2e23}', CL),
+    ('\u{2e24}', '\u{2e24}', OP),
+    ('\u{2e25}', '\u{2e25}', CL),
+    ('\u{2e26}', '\u{2e26}', OP),
+    ('\u{2e27}', '\u{2e27}', CL),
+    ('\u{2e28}', '\u{2e28}', OP),
+    ('\u{2e29}', '\u{2e29}', CL),
+    ('\u{2e2a}', '\u{2e2d}', BA),
+    ('\u{2e2e}', '\u{2e2e}', EX),
+    ('\u{2e30}', '\u{2e31}', BA),
+    ('\u{2e33}', '\u{2e34}', BA),
+    ('\u{2e3a}', '\u{2e3b}', B2),
+    ('\u{2e3c}', '\u{2e3e}', BA),
+    ('\u{2e40}', '\u{2e41}', BA),
+    ('\u{2e42}', '\u{2e42}', OP),
+    ('\u{2e43}', '\u{2e4a}', BA),
+    ('\u{2e55}', '\u{2e55}', OP),
+    ('\u{2e56}', '\u{2e56}', CL),
+    ('\u{2e57}', '\u{2e57}', OP),
+    ('\u{2e58}', '\u{2e58}', CL),
+    ('\u{2e59}', '\u{2e59}', OP),
+    ('\u{2e5a}', '\u{2e5a}', CL),
+    ('\u{2e5b}', '\u{2e5b}', OP),
+    ('\u{2e5c}', '\u{2e5c}', CL),
+    ('\u{2e80}', '\u{2ffb}', ID),
+    ('\u{3000}', '\u{3000}', BA),
+    ('\u{3001}', '\u{3002}', CL),
+    ('\u{3003}', '\u{3004}', ID),
+    ('\u{3005}', '\u{3005}', NS),
+    ('\u{3006}', '\u{3007}', ID),
+    ('\u{3008}', '\u{3008}', OP),
+    ('\u{3009}', '\u{3009}', CL),
+    ('\u{300a}', '\u{300a}', OP),
+    ('\u{300b}', '\u{300b}', CL),
+    ('\u{300c}', '\u{300c}', OP),
+    ('\u{300d}', '\u{300d}', CL),
+    ('\u{300e}', '\u{300e}', OP),
+    ('\u{300f}', '\u{300f}', CL),
+    ('\u{3010}', '\u{3010}', OP),
+    ('\u{3011}', '\u{3011}', CL),
+    ('\u{3012}', '\u{3013}', ID),
+    ('\u{3014}', '\u{3014}', OP),
+    ('\u{3015}', '\u{3015}', CL),
+    ('\u{3016}', '\u{3016}', OP),
+    ('\u{3017}', '\u{3017}', CL),
+    ('\u{3018}', '\u{3018}', OP),
+    ('\u{3019}', '\u{3019}', CL),
+    ('\u{301a}', '\u{301a}', OP),
+    ('\u{301b}', '\u{301b}', CL),
+    ('\u{301c}', '\u{301c}', NS),
+    ('\u{301d}', '\u{301d}', OP),
+    ('\u{301e}', '\u{301f}', CL),
+    ('\u{3020}', '\u{3029}', ID),
+    ('\u{302a}', '\u{302f}', CM),
+    ('\u{3030}', '\u{303a}', ID),
+    ('\u{303b}', '\u{303c}', NS),
+    ('\u{303d}', '\u{303e}', ID),
+    ('\u{3041}', '\u{3041}', NS),
+    ('\u{3042}', '\u{3042}', ID),
+    ('\u{3043}', '\u{3043}', NS),
+    ('\u{3044}', '\u{3044}', ID),
+    ('\u{3045}', '\u{3045}', NS),
+    ('\u{3046}', '\u{3046}', ID),
+    ('\u{3047}', '\u{3047}', NS),
+    ('\u{3048}', '\u{3048}', ID),
+    ('\u{3049}', '\u{3049}', NS),
+    ('\u{304a}', '\u{3062}', ID),
+    ('\u{3063}', '\u{3063}', NS),
+    ('\u{3064}', '\u{3082}', ID),
+    ('\u{3083}', '\u{3083}', NS),
+    ('\u{3084}', '\u{3084}', ID),
+    ('\u{3085}', '\u{3085}', NS),
+    ('\u{3086}', '\u{3086}', ID),
+    ('\u{3087}', '\u{3087}', NS),
+    ('\u{3088}', '\u{308d}', ID),
+    ('\u{308e}', '\u{308e}', NS),
+    ('\u{308f}', '\u{3094}', ID),
+    ('\u{3095}', '\u{3096}', NS),
+    ('\u{3099}', '\u{309a}', CM),
+    ('\u{309b}', '\u{309e}', NS),
+    ('\u{309f}', '\u{309f}', ID),
+    ('\u{30a0}', '\u{30a1}', NS),
+    ('\u{30a2}', '\u{30a2}', ID),
+    ('\u{30a3}', '\u{30a3}', NS),
+    ('\u{30a4}', '\u{30a4}', ID),
+    ('\u{30a5}', '\u{30a5}', NS),
+    ('\u{30a6}', '\u{30a6}', ID),
+    ('\u{30a7}', '\u{30a7}', NS),
+    ('\u{30a8}', '\u{30a8}', ID),
+    ('\u{30a9}', '\u{30a9}', NS),
+    ('\u{30aa}', '\u{30c2}', ID),
+    ('\u{30c3}', '\u{30c3}', NS),
+    ('\u{30c4}', '\u{30e2}', ID),
+    ('\u{30e3}', '\u{30e3}', NS),
+    ('\u{30e4}', '\u{30e4}', ID),
+    ('\u{30e5}', '\u{30e5}', NS),
+    ('\u{30e6}', '\u{30e6}', ID),
+    ('\u{30e7}', '\u{30e7}', NS),
+    ('\u{30e8}', '\u{30ed}', ID),
+    ('\u{30ee}', '\u{30ee}', NS),
+    ('\u{30ef}', '\u{30f4}', ID),
+    ('\u{30f5}', '\u{30f6}', NS),
+    ('\u{30f7}', '\u{30fa}', ID),
+    ('\u{30fb}', '\u{30fe}', NS),
+    ('\u{30ff}', '\u{31e3}', ID),
+    ('\u{31f0}', '\u{31ff}', NS),
+    ('\u{3200}', '\u{3247}', ID),
+    ('\u{3250}', '\u{4dbf}', ID),
+    ('\u{4e00}', '\u{a014}', ID),
+    ('\u{a015}', '\u{a015}', NS),
+    ('\u{a016}', '\u{a4c6}', ID),
+    ('\u{a4fe}', '\u{a4ff}', BA),
+    ('\u{a60d}', '\u{a60d}', BA),
+    ('\u{a60e}', '\u{a60e}', EX),
+    ('\u{a60f}', '\u{a60f}', BA),
+    ('\u{a620}', '\u{a629}', NU),
+    ('\u{a66f}', '\u{a672}', CM),
+    ('\u{a674}', '\u{a67d}', CM),
+    ('\u{a69e}', '\u{a69f}', CM),
+    ('\u{a6f0}', '\u{a6f1}', CM),
+    ('\u{a6f3}', '\u{a6f7}', BA),
+    ('\u{a802}', '\u{a802}', CM),
+    ('\u{a806}', '\u{a806}', CM),
+    ('\u{a80b}', '\u{a80b}', CM),
+    ('\u{a823}', '\u{a827}', CM),
+    ('\u{a82c}', '\u{a82c}', CM),
+    ('\u{a838}', '\u{a838}', PO),
+    ('\u{a874}', '\u{a875}', BB),
+    ('\u{a876}', '\u{a877}', EX),
+    ('\u{a880}', '\u{a881}', CM),
+    ('\u{a8b4}', '\u{a8c5}', CM),
+    ('\u{a8ce}', '\u{a8cf}', BA),
+    ('\u{a8d0}', '\u{a8d9}', NU),
+    ('\u{a8e0}', '\u{a8f1}', CM),
+    ('\u{a8ff}', '\u{a8ff}', CM),
+    ('\u{a900}', '\u{a909}', NU),
+    ('\u{a926}', '\u{a92d}', CM),
+    ('\u{a92e}', '\u{a92f}', BA),
+    ('\u{a947}', '\u{a953}', CM),
+    ('\u{a960}', '\u{a97c}', JL),
+    ('\u{a980}', '\u{a983}', CM),
+    ('\u{a9b3}', '\u{a9c0}', CM),
+    ('\u{a9c7}', '\u{a9c9}', BA),
+    ('\u{a9d0}', '\u{a9d9}', NU),
+    ('\u{a9e5}', '\u{a9e5}', CM),
+    ('\u{a9f0}', '\u{a9f9}', NU),
+    ('\u{aa29}', '\u{aa36}', CM),
+    ('\u{aa43}', '\u{aa43}', CM),
+    ('\u{aa4c}', '\u{aa4d}', CM),
+    ('\u{aa50}', '\u{aa59}', NU),
+    ('\u{aa5d}', '\u{aa5f}', BA),
+    ('\u{aa7b}', '\u{aa7d}', CM),
+    ('\u{aab0}', '\u{aab0}', CM),
+    ('\u{aab2}', '\u{aab4}', CM),
+    ('\u{aab7}', '\u{aab8}', CM),
+    ('\u{aabe}', '\u{aabf}', CM),
+    ('\u{aac1}', '\u{aac1}', CM),
+    ('\u{aaeb}', '\u{aaef}', CM),
+    ('\u{aaf0}', '\u{aaf1}', BA),
+    ('\u{aaf5}', '\u{aaf6}', CM),
+    ('\u{abe3}', '\u{abea}', CM),
+    ('\u{abeb}', '\u{abeb}', BA),
+    ('\u{abec}', '\u{abed}', CM),
+    ('\u{abf0}', '\u{abf9}', NU),
+    ('\u{ac00}', '\u{ac00}', H2),
+    ('\u{ac01}', '\u{ac1b}', H3),
+    ('\u{ac1c}', '\u{ac1c}', H2),
+    ('\u{ac1d}', '\u{ac37}', H3),
+    ('\u{ac38}', '\u{ac38}', H2),
+    ('\u{ac39}', '\u{ac53}', H3),
+    ('\u{ac54}', '\u{ac54}', H2),
+    ('\u{ac55}', '\u{ac6f}', H3),
+    ('\u{ac70}', '\u{ac70}', H2),
+    ('\u{ac71}', '\u{ac8b}', H3),
+    ('\u{ac8c}', '\u{ac8c}', H2),
+    ('\u{ac8d}', '\u{aca7}', H3),
+    ('\u{aca8}', '\u{aca8}', H2),
+    ('\u{aca9}', '\u{acc3}', H3),
+    ('\u{acc4}', '\u{acc4}', H2),
+    ('\u{acc5}', '\u{acdf}', H3),
+    ('\u{ace0}', '\u{ace0}', H2),
+    ('\u{ace1}', '\u{acfb}', H3),
+    ('\u{acfc}', '\u{acfc}', H2),
+    ('\u{acfd}', '\u{ad17}', H3),
+    ('\u{ad18}', '\u{ad18}', H2),
+    ('\u{ad19}', '\u{ad33}', H3),
+    ('\u{ad34}', '\u{ad34}', H2),
+    ('\u{ad35}', '\u{ad4f}', H3),
+    ('\u{ad50}', '\u{ad50}', H2),
+    ('\u{ad51}', '\u{ad6b}', H3),
+    ('\u{ad6c}', '\u{ad6c}', H2),
+    ('\u{ad6d}', '\u{ad87}', H3),
+    ('\u{ad88}', '\u{ad88}', H2),
+    ('\u{ad89}', '\u{ada3}', H3),
+    ('\u{ada4}', '\u{ada4}', H2),
+    ('\u{ada5}', '\u{adbf}', H3),
+    ('\u{adc0}', '\u{adc0}', H2),
+    ('\u{adc1}', '\u{addb}', H3),
+    ('\u{addc}', '\u{addc}', H2),
+    ('\u{addd}', '\u{adf7}', H3),
+    ('\u{adf8}', '\u{adf8}', H2),
+    ('\u{adf9}', '\u{ae13}', H3),
+    ('\u{ae14}', '\u{ae14}', H2),
+    ('\u{ae15}', '\u{ae2f}', H3),
+    ('\u{ae30}', '\u{ae30}', H2),
+    ('\u{ae31}', '\u{ae4b}', H3),
+    ('\u{ae4c}', '\u{ae4c}', H2),
+    ('\u{ae4d}', '\u{ae67}', H3),
+    ('\u{ae68}', '\u{ae68}', H2),
+    ('\u{ae69}', '\u{ae83}', H3),
+    ('\u{ae84}', '\u{ae84}', H2),
+    ('\u{ae85}', '\u{ae9f}', H3),
+    ('\u{aea0}', '\u{aea0}', H2),
+    ('\u{aea1}', '\u{aebb}', H3),
+    ('\u{aebc}', '\u{aebc}', H2),
+    ('\u{aebd}', '\u{aed7}', H3),
+    ('\u{aed8}', '\u{aed8}', H2),
+    ('\u{aed9}', '\u{aef3}', H3),
+    ('\u{aef4}', '\u{aef4}', H2),
+    ('\u{aef5}', '\u{af0f}', H3),
+    ('\u{af10}', '\u{af10}', H2),
+    ('\u{af11}', '\u{af2b}', H3),
+    ('\u{af2c}', '\u{af2c}', H2),
+    ('\u{af2d}', '\u{af47}', H3),
+    ('\u{af48}', '\u{af48}', H2),
+    ('\u{af49}', '\u{af63}', H3),
+    ('\u{af64}', '\u{af64}', H2),
+    ('\u{af65}', '\u{af7f}', H3),
+    ('\u{af80}', '\u{af80}', H2),
+    ('\u{af81}', '\u{af9b}', H3),
+    ('\u{af9c}', '\u{af9c}', H2),
+    ('\u{af9d}', '\u{afb7}', H3),
+    ('\u{afb8}', '\u{afb8}', H2),
+    ('\u{afb9}', '\u{afd3}', H3),
+    ('\u{afd4}', '\u{afd4}', H2),
+    ('\u{afd5}', '\u{afef}', H3),
+    ('\u{aff0}', '\u{aff0}', H2),
+    ('\u{aff1}', '\u{b00b}', H3),
+    ('\u{b00c}', '\u{b00c}', H2),
+    ('\u{b00d}', '\u{b027}', H3),
+    ('\u{b028}', '\u{b028}', H2),
+    ('\u{b029}', '\u{b043}', H3),
+    ('\u{b044}', '\u{b044}', H2),
+    ('\u{b045}', '\u{b05f}', H3),
+    ('\u{b060}', '\u{b060}', H2),
+    ('\u{b061}', '\u{b07b}', H3),
+    ('\u{b07c}', '\u{b07c}', H2),
+    ('\u{b07d}', '\u{b097}', H3),
+    ('\u{b098}', '\u{b098}', H2),
+    ('\u{b099}', '\u{b0b3}', H3),
+    ('\u{b0b4}', '\u{b0b4}', H2),
+    ('\u{b0b5}', '\u{b0cf}', H3),
+    ('\u{b0d0}', '\u{b0d0}', H2),
+    ('\u{b0d1}', '\u{b0eb}', H3),
+    ('\u{b0ec}', '\u{b0ec}', H2),
+    ('\u{b0ed}', '\u{b107}', H3),
+    ('\u{b108}', '\u{b108}', H2),
+    ('\u{b109}', '\u{b123}', H3),
+    ('\u{b124}', '\u{b124}', H2),
+    ('\u{b125}', '\u{b13f}', H3),
+    ('\u{b140}', '\u{b140}', H2),
+    ('\u{b141}', '\u{b15b}', H3),
+    ('\u{b15c}', '\u{b15c}', H2),
+    ('\u{b15d}', '\u{b177}', H3),
+    ('\u{b178}', '\u{b178}', H2),
+    ('\u{b179}', '\u{b193}', H3),
+    ('\u{b194}', '\u{b194}', H2),
+    ('\u{b195}', '\u{b1af}', H3),
+    ('\u{b1b0}', '\u{b1b0}', H2),
+    ('\u{b1b1}', '\u{b1cb}', H3),
+    ('\u{b1cc}', '\u{b1cc}', H2),
+    ('\u{b1cd}', '\u{b1e7}', H3),
+    ('\u{b1e8}', '\u{b1e8}', H2),
+    ('\u{b1e9}', '\u{b203}', H3),
+    ('\u{b204}', '\u{b204}', H2),
+    ('\u{b205}', '\u{b21f}', H3),
+    ('\u{b220}', '\u{b220}', H2),
+    ('\u{b221}', '\u{b23b}', H3),
+    ('\u{b23c}', '\u{b23c}', H2),
+    ('\u{b23d}', '\u{b257}', H3),
+    ('\u{b258}', '\u{b258}', H2),
+    ('\u{b259}', '\u{b273}', H3),
+    ('\u{b274}', '\u{b274}', H2),
+    ('\u{b275}', '\u{b28f}', H3),
+    ('\u{b290}', '\u{b290}', H2),
+    ('\u{b291}', '\u{b2ab}', H3),
+    ('\u{b2ac}', '\u{b2ac}', H2),
+    ('\u{b2ad}', '\u{b2c7}', H3),
+    ('\u{b2c8}', '\u{b2c8}', H2),
+    ('\u{b2c9}', '\u{b2e3}', H3),
+    ('\u{b2e4}', '\u{b2e4}', H2),
+    ('\u{b2e5}', '\u{b2ff}', H3),
+    ('\u{b300}', '\u{b300}', H2),
+    ('\u{b301}', '\u{b31b}', H3),
+    ('\u{b31c}', '\u{b31c}', H2),
+    ('\u{b31d}', '\u{b337}', H3),
+    ('\u{b338}', '\u{b338}', H2),
+    ('\u{b339}', '\u{b353}', H3),
+    ('\u{b354}', '\u{b354}', H2),
+    ('\u{b355}', '\u{b36f}', H3),
+    ('\u{b370}', '\u{b370}', H2),
+    ('\u{b371}', '\u{b38b}', H3),
+    ('\u{b38c}', '\u{b38c}', H2),
+    ('\u{b38d}', '\u{b3a7}', H3),
+    ('\u{b3a8}', '\u{b3a8}', H2),
+    ('\u{b3a9}', '\u{b3c3}', H3),
+    ('\u{b3c4}', '\u{b3c4}', H2),
+    ('\u{b3c5}', '\u{b3df}', H3),
+    ('\u{b3e0}', '\u{b3e0}', H2),
+    ('\u{b3e1}', '\u{b3fb}', H3),
+    ('\u{b3fc}', '\u{b3fc}', H2),
+    ('\u{b3fd}', '\u{b417}', H3),
+    ('\u{b418}', '\u{b418}', H2),
+    ('\u{b419}', '\u{b433}', H3),
+    ('\u{b434}', '\u{b434}', H2),
+    ('\u{b435}', '\u{b44f}', H3),
+    ('\u{b450}', '\u{b450}', H2),
+    ('\u{b451}', '\u{b46b}', H3),
+    ('\u{b46c}', '\u{b46c}', H2),
+    ('\u{b46d}', '\u{b487}', H3),
+    ('\u{b488}', '\u{b488}', H2),
+    ('\u{b489}', '\u{b4a3}', H3),
+    ('\u{b4a4}', '\u{b4a4}', H2),
+    ('\u{b4a5}', '\u{b4bf}', H3),
+    ('\u{b4c0}', '\u{b4c0}', H2),
+    ('\u{b4c1}', '\u{b4db}', H3),
+    ('\u{b4dc}', '\u{b4dc}', H2),
+    ('\u{b4dd}', '\u{b4f7}', H3),
+    ('\u{b4f8}', '\u{b4f8}', H2),
+    ('\u{b4f9}', '\u{b513}', H3),
+    ('\u{b514}', '\u{b514}', H2),
+    ('\u{b515}', '\u{b52f}', H3),
+    ('\u{b530}', '\u{b530}', H2),
+    ('\u{b531}', '\u{b54b}', H3),
+    ('\u{b54c}', '\u{b54c}', H2),
+    ('\u{b54d}', '\u{b567}', H3),
+    ('\u{b568}', '\u{b568}', H2),
+    ('\u{b569}', '\u{b583}', H3),
+    ('\u{b584}', '\u{b584}', H2),
+    ('\u{b585}', '\u{b59f}', H3),
+    ('\u{b5a0}', '\u{b5a0}', H2),
+    ('\u{b5a1}', '\u{b5bb}', H3),
+    ('\u{b5bc}', '\u{b5bc}', H2),
+    ('\u{b5bd}', '\u{b5d7}', H3),
+    ('\u{b5d8}', '\u{b5d8}', H2),
+    ('\u{b5d9}', '\u{b5f3}', H3),
+    ('\u{b5f4}', '\u{b5f4}', H2),
+    ('\u{b5f5}', '\u{b60f}', H3),
+    ('\u{b610}', '\u{b610}', H2),
+    ('\u{b611}', '\u{b62b}', H3),
+    ('\u{b62c}', '\u{b62c}', H2),
+    ('\u{b62d}', '\u{b647}', H3),
+    ('\u{b648}', '\u{b648}', H2),
+    ('\u{b649}', '\u{b663}', H3),
+    ('\u{b664}', '\u{b664}', H2),
+    ('\u{b665}', '\u{b67f}', H3),
+    ('\u{b680}', '\u{b680}', H2),
+    ('\u{b681}', '\u{b69b}', H3),
+    ('\u{b69c}', '\u{b69c}', H2),
+    ('\u{b69d}', '\u{b6b7}', H3),
+    ('\u{b6b8}', '\u{b6b8}', H2),
+    ('\u{b6b9}', '\u{b6d3}', H3),
+    ('\u{b6d4}', '\u{b6d4}', H2),
+    ('\u{b6d5}', '\u{b6ef}', H3),
+    ('\u{b6f0}', '\u{b6f0}', H2),
+    ('\u{b6f1}', '\u{b70b}', H3),
+    ('\u{b70c}', '\u{b70c}', H2),
+    ('\u{b70d}', '\u{b727}', H3),
+    ('\u{b728}', '\u{b728}', H2),
+    ('\u{b729}', '\u{b743}', H3),
+    ('\u{b744}', '\u{b744}', H2),
+    ('\u{b745}', '\u{b75f}', H3),
+    ('\u{b760}', '\u{b760}', H2),
+    ('\u{b761}', '\u{b77b}', H3),
+    ('\u{b77c}', '\u{b77c}', H2),
+    ('\u{b77d}', '\u{b797}', H3),
+    ('\u{b798}', '\u{b798}', H2),
+    ('\u{b799}', '\u{b7b3}', H3),
+    ('\u{b7b4}', '\u{b7b4}', H2),
+    ('\u{b7b5}', '\u{b7cf}', H3),
+    ('\u{b7d0}', '\u{b7d0}', H2),
+    ('\u{b7d1}', '\u{b7eb}', H3),
+    ('\u{b7ec}', '\u{b7ec}', H2),
+    ('\u{b7ed}', '\u{b807}', H3),
+    ('\u{b808}', '\u{b808}', H2),
+    ('\u{b809}', '\u{b823}', H3),
+    ('\u{b824}', '\u{b824}', H2),
+    ('\u{b825}', '\u{b83f}', H3),
+    ('\u{b840}', '\u{b840}', H2),
+    ('\u{b841}', '\u{b85b}', H3),
+    ('\u{b85c}', '\u{b85c}', H2),
+    ('\u{b85d}', '\u{b877}', H3),
+    ('\u{b878}', '\u{b878}', H2),
+    ('\u{b879}', '\u{b893}', H3),
+    ('\u{b894}', '\u{b894}', H2),
+    ('\u{b895}', '\u{b8af}', H3),
+    ('\u{b8b0}', '\u{b8b0}', H2),
+    ('\u{b8b1}', '\u{b8cb}', H3),
+    ('\u{b8cc}', '\u{b8cc}', H2),
+    ('\u{b8cd}', '\u{b8e7}', H3),
+    ('\u{b8e8}', '\u{b8e8}', H2),
+    ('\u{b8e9}', '\u{b903}', H3),
+    ('\u{b904}', '\u{b904}', H2),
+    ('\u{b905}', '\u{b91f}', H3),
+    ('\u{b920}', '\u{b920}', H2),
+    ('\u{b921}', '\u{b93b}', H3),
+    ('\u{b93c}', '\u{b93c}', H2),
+    ('\u{b93d}', '\u{b957}', H3),
+    ('\u{b958}', '\u{b958}', H2),
+    ('\u{b959}', '\u{b973}', H3),
+    ('\u{b974}', '\u{b974}', H2),
+    ('\u{b975}', '\u{b98f}', H3),
+    ('\u{b990}', '\u{b990}', H2),
+    ('\u{b991}', '\u{b9ab}', H3),
+    ('\u{b9ac}', '\u{b9ac}', H2),
+    ('\u{b9ad}', '\u{b9c7}', H3),
+    ('\u{b9c8}', '\u{b9c8}', H2),
+    ('\u{b9c9}', '\u{b9e3}', H3),
+    ('\u{b9e4}', '\u{b9e4}', H2),
+    ('\u{b9e5}', '\u{b9ff}', H3),
+    ('\u{ba00}', '\u{ba00}', H2),
+    ('\u{ba01}', '\u{ba1b}', H3),
+    ('\u{ba1c}', '\u{ba1c}', H2),
+    ('\u{ba1d}', '\u{ba37}', H3),
+    ('\u{ba38}', '\u{ba38}', H2),
+    ('\u{ba39}', '\u{ba53}', H3),
+    ('\u{ba54}', '\u{ba54}', H2),
+    ('\u{ba55}', '\u{ba6f}', H3),
+    ('\u{ba70}', '\u{ba70}', H2),
+    ('\u{ba71}', '\u{ba8b}', H3),
+    ('\u{ba8c}', '\u{ba8c}', H2),
+    ('\u{ba8d}', '\u{baa7}', H3),
+    ('\u{baa8}', '\u{baa8}', H2),
+    ('\u{baa9}', '\u{bac3}', H3),
+    ('\u{bac4}', '\u{bac4}', H2),
+    ('\u{bac5}', '\u{badf}', H3),
+    ('\u{bae0}', '\u{bae0}', H2),
+    ('\u{bae1}', '\u{bafb}', H3),
+    ('\u{bafc}', '\u{bafc}', H2),
+    ('\u{bafd}', '\u{bb17}', H3),
+    ('\u{bb18}', '\u{bb18}', H2),
+    ('\u{bb19}', '\u{bb33}', H3),
+    ('\u{bb34}', '\u{bb34}', H2),
+    ('\u{bb35}', '\u{bb4f}', H3),
+    ('\u{bb50}', '\u{bb50}', H2),
+    ('\u{bb51}', '\u{bb6b}', H3),
+    ('\u{bb6c}', '\u{bb6c}', H2),
+    ('\u{bb6d}', '\u{bb87}', H3),
+    ('\u{bb88}', '\u{bb88}', H2),
+    ('\u{bb89}', '\u{bba3}', H3),
+    ('\u{bba4}', '\u{bba4}', H2),
+    ('\u{bba5}', '\u{bbbf}', H3),
+    ('\u{bbc0}', '\u{bbc0}', H2),
+    ('\u{bbc1}', '\u{bbdb}', H3),
+    ('\u{bbdc}', '\u{bbdc}', H2),
+    ('\u{bbdd}', '\u{bbf7}', H3),
+    ('\u{bbf8}', '\u{bbf8}', H2),
+    ('\u{bbf9}', '\u{bc13}', H3),
+    ('\u{bc14}', '\u{bc14}', H2),
+    ('\u{bc15}', '\u{bc2f}', H3),
+    ('\u{bc30}', '\u{bc30}', H2),
+    ('\u{bc31}', '\u{bc4b}', H3),
+    ('\u{bc4c}', '\u{bc4c}', H2),
+    ('\u{bc4d}', '\u{bc67}', H3),
+    ('\u{bc68}', '\u{bc68}', H2),
+    ('\u{bc69}', '\u{bc83}', H3),
+    ('\u{bc84}', '\u{bc84}', H2),
+    ('\u{bc85}', '\u{bc9f}', H3),
+    ('\u{bca0}', '\u{bca0}', H2),
+    ('\u{bca1}', '\u{bcbb}', H3),
+    ('\u{bcbc}', '\u{bcbc}', H2),
+    ('\u{bcbd}', '\u{bcd7}', H3),
+    ('\u{bcd8}', '\u{bcd8}', H2),
+    ('\u{bcd9}', '\u{bcf3}', H3),
+    ('\u{bcf4}', '\u{bcf4}', H2),
+    ('\u{bcf5}', '\u{bd0f}', H3),
+    ('\u{bd10}', '\u{bd10}', H2),
+    ('\u{bd11}', '\u{bd2b}', H3),
+    ('\u{bd2c}', '\u{bd2c}', H2),
+    ('\u{bd2d}', '\u{bd47}', H3),
+    ('\u{bd48}', '\u{bd48}', H2),
+    ('\u{bd49}', '\u{bd63}', H3),
+    ('\u{bd64}', '\u{bd64}', H2),
+    ('\u{bd65}', '\u{bd7f}', H3),
+    ('\u{bd80}', '\u{bd80}', H2),
+    ('\u{bd81}', '\u{bd9b}', H3),
+    ('\u{bd9c}', '\u{bd9c}', H2),
+    ('\u{bd9d}', '\u{bdb7}', H3),
+    ('\u{bdb8}', '\u{bdb8}', H2),
+    ('\u{bdb9}', '\u{bdd3}', H3),
+    ('\u{bdd4}', '\u{bdd4}', H2),
+    ('\u{bdd5}', '\u{bdef}', H3),
+    ('\u{bdf0}', '\u{bdf0}', H2),
+    ('\u{bdf1}', '\u{be0b}', H3),
+    ('\u{be0c}', '\u{be0c}', H2),
+    ('\u{be0d}', '\u{be27}', H3),
+    ('\u{be28}', '\u{be28}', H2),
+    ('\u{be29}', '\u{be43}', H3),
+    ('\u{be44}', '\u{be44}', H2),
+    ('\u{be45}', '\u{be5f}', H3),
+    ('\u{be60}', '\u{be60}', H2),
+    ('\u{be61}', '\u{be7b}', H3),
+    ('\u{be7c}', '\u{be7c}', H2),
+    ('\u{be7d}', '\u{be97}', H3),
+    ('\u{be98}', '\u{be98}', H2),
+    ('\u{be99}', '\u{beb3}', H3),
+    ('\u{beb4}', '\u{beb4}', H2),
+    ('\u{beb5}', '\u{becf}', H3),
+    ('\u{bed0}', '\u{bed0}', H2),
+    ('\u{bed1}', '\u{beeb}', H3),
+    ('\u{beec}', '\u{beec}', H2),
+    ('\u{beed}', '\u{bf07}', H3),
+    ('\u{bf08}', '\u{bf08}', H2),
+    ('\u{bf09}', '\u{bf23}', H3),
+    ('\u{bf24}', '\u{bf24}', H2),
+    ('\u{bf25}', '\u{bf3f}', H3),
+    ('\u{bf40}', '\u{bf40}', H2),
+    ('\u{bf41}', '\u{bf5b}', H3),
+    ('\u{bf5c}', '\u{bf5c}', H2),
+    ('\u{bf5d}', '\u{bf77}', H3),
+    ('\u{bf78}', '\u{bf78}', H2),
+    ('\u{bf79}', '\u{bf93}', H3),
+    ('\u{bf94}', '\u{bf94}', H2),
+    ('\u{bf95}', '\u{bfaf}', H3),
+    ('\u{bfb0}', '\u{bfb0}', H2),
+    ('\u{bfb1}', '\u{bfcb}', H3),
+    ('\u{bfcc}', '\u{bfcc}', H2),
+    ('\u{bfcd}', '\u{bfe7}', H3),
+    ('\u{bfe8}', '\u{bfe8}', H2),
+    ('\u{bfe9}', '\u{c003}', H3),
+    ('\u{c004}', '\u{c004}', H2),
+    ('\u{c005}', '\u{c01f}', H3),
+    ('\u{c020}', '\u{c020}', H2),
+    ('\u{c021}', '\u{c03b}', H3),
+    ('\u{c03c}', '\u{c03c}', H2),
+    ('\u{c03d}', '\u{c057}', H3),
+    ('\u{c058}', '\u{c058}', H2),
+    ('\u{c059}', '\u{c073}', H3),
+    ('\u{c074}', '\u{c074}', H2),
+    ('\u{c075}', '\u{c08f}', H3),
+    ('\u{c090}', '\u{c090}', H2),
+    ('\u{c091}', '\u{c0ab}', H3),
+    ('\u{c0ac}', '\u{c0ac}', H2),
+    ('\u{c0ad}', '\u{c0c7}', H3),
+    ('\u{c0c8}', '\u{c0c8}', H2),
+    ('\u{c0c9}', '\u{c0e3}', H3),
+    ('\u{c0e4}', '\u{c0e4}', H2),
+    ('\u{c0e5}', '\u{c0ff}', H3),
+    ('\u{c100}', '\u{c100}', H2),
+    ('\u{c101}', '\u{c11b}', H3),
+    ('\u{c11c}', '\u{c11c}', H2),
+    ('\u{c11d}', '\u{c137}', H3),
+    ('\u{c138}', '\u{c138}', H2),
+    ('\u{c139}', '\u{c153}', H3),
+    ('\u{c154}', '\u{c154}', H2),
+    ('\u{c155}', '\u{c16f}', H3),
+    ('\u{c170}', '\u{c170}', H2),
+    ('\u{c171}', '\u{c18b}', H3),
+    ('\u{c18c}', '\u{c18c}', H2),
+    ('\u{c18d}', '\u{c1a7}', H3),
+    ('\u{c1a8}', '\u{c1a8}', H2),
+    ('\u{c1a9}', '\u{c1c3}', H3),
+    ('\u{c1c4}', '\u{c1c4}', H2),
+    ('\u{c1c5}', '\u{c1df}', H3),
+    ('\u{c1e0}', '\u{c1e0}', H2),
+    ('\u{c1e1}', '\u{c1fb}', H3),
+    ('\u{c1fc}', '\u{c1fc}', H2),
+    ('\u{c1fd}', '\u{c217}', H3),
+    ('\u{c218}', '\u{c218}', H2),
+    ('\u{c219}', '\u{c233}', H3),
+    ('\u{c234}', '\u{c234}', H2),
+    ('\u{c235}', '\u{c24f}', H3),
+    ('\u{c250}', '\u{c250}', H2),
+    ('\u{c251}', '\u{c26b}', H3),
+    ('\u{c26c}', '\u{c26c}', H2),
+    ('\u{c26d}', '\u{c287}', H3),
+    ('\u{c288}', '\u{c288}', H2),
+    ('\u{c289}', '\u{c2a3}', H3),
+    ('\u{c2a4}', '\u{c2a4}', H2),
+    ('\u{c2a5}', '\u{c2bf}', H3),
+    ('\u{c2c0}', '\u{c2c0}', H2),
+    ('\u{c2c1}', '\u{c2db}', H3),
+    ('\u{c2dc}', '\u{c2dc}', H2),
+    ('\u{c2dd}', '\u{c2f7}', H3),
+    ('\u{c2f8}', '\u{c2f8}', H2),
+    ('\u{c2f9}', '\u{c313}', H3),
+    ('\u{c314}', '\u{c314}', H2),
+    ('\u{c315}', '\u{c32f}', H3),
+    ('\u{c330}', '\u{c330}', H2),
+    ('\u{c331}', '\u{c34b}', H3),
+    ('\u{c34c}', '\u{c34c}', H2),
+    ('\u{c34d}', '\u{c367}', H3),
+    ('\u{c368}', '\u{c368}', H2),
+    ('\u{c369}', '\u{c383}', H3),
+    ('\u{c384}', '\u{c384}', H2),
+    ('\u{c385}', '\u{c39f}', H3),
+    ('\u{c3a0}', '\u{c3a0}', H2),
+    ('\u{c3a1}', '\u{c3bb}', H3),
+    ('\u{c3bc}', '\u{c3bc}', H2),
+    ('\u{c3bd}', '\u{c3d7}', H3),
+    ('\u{c3d8}', '\u{c3d8}', H2),
+    ('\u{c3d9}', '\u{c3f3}', H3),
+    ('\u{c3f4}', '\u{c3f4}', H2),
+    ('\u{c3f5}', '\u{c40f}', H3),
+    ('\u{c410}', '\u{c410}', H2),
+    ('\u{c411}', '\u{c42b}', H3),
+    ('\u{c42c}', '\u{c42c}', H2),
+    ('\u{c42d}', '\u{c447}', H3),
+    ('\u{c448}', '\u{c448}', H2),
+    ('\u{c449}', '\u{c463}', H3),
+    ('\u{c464}', '\u{c464}', H2),
+    ('\u{c465}', '\u{c47f}', H3),
+    ('\u{c480}', '\u{c480}', H2),
+    ('\u{c481}', '\u{c49b}', H3),
+    ('\u{c49c}', '\u{c49c}', H2),
+    ('\u{c49d}', '\u{c4b7}', H3),
+    ('\u{c4b8}', '\u{c4b8}', H2),
+    ('\u{c4b9}', '\u{c4d3}', H3),
+    ('\u{c4d4}', '\u{c4d4}', H2),
+    ('\u{c4d5}', '\u{c4ef}', H3),
+    ('\u{c4f0}', '\u{c4f0}', H2),
+    ('\u{c4f1}', '\u{c50b}', H3),
+    ('\u{c50c}', '\u{c50c}', H2),
+    ('\u{c50d}', '\u{c527}', H3),
+    ('\u{c528}', '\u{c528}', H2),
+    ('\u{c529}', '\u{c543}', H3),
+    ('\u{c544}', '\u{c544}', H2),
+    ('\u{c545}', '\u{c55f}', H3),
+    ('\u{c560}', '\u{c560}', H2),
+    ('\u{c561}', '\u{c57b}', H3),
+    ('\u{c57c}', '\u{c57c}', H2),
+    ('\u{c57d}', '\u{c597}', H3),
+    ('\u{c598}', '\u{c598}', H2),
+    ('\u{c599}', '\u{c5b3}', H3),
+    ('\u{c5b4}', '\u{c5b4}', H2),
+    ('\u{c5b5}', '\u{c5cf}', H3),
+    ('\u{c5d0}', '\u{c5d0}', H2),
+    ('\u{c5d1}', '\u{c5eb}', H3),
+    ('\u{c5ec}', '\u{c5ec}', H2),
+    ('\u{c5ed}', '\u{c607}', H3),
+    ('\u{c608}', '\u{c608}', H2),
+    ('\u{c609}', '\u{c623}', H3),
+    ('\u{c624}', '\u{c624}', H2),
+    ('\u{c625}', '\u{c63f}', H3),
+    ('\u{c640}', '\u{c640}', H2),
+    ('\u{c641}', '\u{c65b}', H3),
+    ('\u{c65c}', '\u{c65c}', H2),
+    ('\u{c65d}', '\u{c677}', H3),
+    ('\u{c678}', '\u{c678}', H2),
+    ('\u{c679}', '\u{c693}', H3),
+    ('\u{c694}', '\u{c694}', H2),
+    ('\u{c695}', '\u{c6af}', H3),
+    ('\u{c6b0}', '\u{c6b0}', H2),
+    ('\u{c6b1}', '\u{c6cb}', H3),
+    ('\u{c6cc}', '\u{c6cc}', H2),
+    ('\u{c6cd}', '\u{c6e7}', H3),
+    ('\u{c6e8}', '\u{c6e8}', H2),
+    ('\u{c6e9}', '\u{c703}', H3),
+    ('\u{c704}', '\u{c704}', H2),
+    ('\u{c705}', '\u{c71f}', H3),
+    ('\u{c720}', '\u{c720}', H2),
+    ('\u{c721}', '\u{c73b}', H3),
+    ('\u{c73c}', '\u{c73c}', H2),
+    ('\u{c73d}', '\u{c757}', H3),
+    ('\u{c758}', '\u{c758}', H2),
+    ('\u{c759}', '\u{c773}', H3),
+    ('\u{c774}', '\u{c774}', H2),
+    ('\u{c775}', '\u{c78f}', H3),
+    ('\u{c790}', '\u{c790}', H2),
+    ('\u{c791}', '\u{c7ab}', H3),
+    ('\u{c7ac}', '\u{c7ac}', H2),
+    ('\u{c7ad}', '\u{c7c7}', H3),
+    ('\u{c7c8}', '\u{c7c8}', H2),
+    ('\u{c7c9}', '\u{c7e3}', H3),
+    ('\u{c7e4}', '\u{c7e4}', H2),
+    ('\u{c7e5}', '\u{c7ff}', H3),
+    ('\u{c800}', '\u{c800}', H2),
+    ('\u{c801}', '\u{c81b}', H3),
+    ('\u{c81c}', '\u{c81c}', H2),
+    ('\u{c81d}', '\u{c837}', H3),
+    ('\u{c838}', '\u{c838}', H2),
+    ('\u{c839}', '\u{c853}', H3),
+    ('\u{c854}', '\u{c854}', H2),
+    ('\u{c855}', '\u{c86f}', H3),
+    ('\u{c870}', '\u{c870}', H2),
+    ('\u{c871}', '\u{c88b}', H3),
+    ('\u{c88c}', '\u{c88c}', H2),
+    ('\u{c88d}', '\u{c8a7}', H3),
+    ('\u{c8a8}', '\u{c8a8}', H2),
+    ('\u{c8a9}', '\u{c8c3}', H3),
+    ('\u{c8c4}', '\u{c8c4}', H2),
+    ('\u{c8c5}', '\u{c8df}', H3),
+    ('\u{c8e0}', '\u{c8e0}', H2),
+    ('\u{c8e1}', '\u{c8fb}', H3),
+    ('\u{c8fc}', '\u{c8fc}', H2),
+    ('\u{c8fd}', '\u{c917}', H3),
+    ('\u{c918}', '\u{c918}', H2),
+    ('\u{c919}', '\u{c933}', H3),
+    ('\u{c934}', '\u{c934}', H2),
+    ('\u{c935}', '\u{c94f}', H3),
+    ('\u{c950}', '\u{c950}', H2),
+    ('\u{c951}', '\u{c96b}', H3),
+    ('\u{c96c}', '\u{c96c}', H2),
+    ('\u{c96d}', '\u{c987}', H3),
+    ('\u{c988}', '\u{c988}', H2),
+    ('\u{c989}', '\u{c9a3}', H3),
+    ('\u{c9a4}', '\u{c9a4}', H2),
+    ('\u{c9a5}', '\u{c9bf}', H3),
+    ('\u{c9c0}', '\u{c9c0}', H2),
+    ('\u{c9c1}', '\u{c9db}', H3),
+    ('\u{c9dc}', '\u{c9dc}', H2),
+    ('\u{c9dd}', '\u{c9f7}', H3),
+    ('\u{c9f8}', '\u{c9f8}', H2),
+    ('\u{c9f9}', '\u{ca13}', H3),
+    ('\u{ca14}', '\u{ca14}', H2),
+    ('\u{ca15}', '\u{ca2f}', H3),
+    ('\u{ca30}', '\u{ca30}', H2),
+    ('\u{ca31}', '\u{ca4b}', H3),
+    ('\u{ca4c}', '\u{ca4c}', H2),
+    ('\u{ca4d}', '\u{ca67}', H3),
+    ('\u{ca68}', '\u{ca68}', H2),
+    ('\u{ca69}', '\u{ca83}', H3),
+    ('\u{ca84}', '\u{ca84}', H2),
+    ('\u{ca85}', '\u{ca9f}', H3),
+    ('\u{caa0}', '\u{caa0}', H2),
+    ('\u{caa1}', '\u{cabb}', H3),
+    ('\u{cabc}', '\u{cabc}', H2),
+    ('\u{cabd}', '\u{cad7}', H3),
+    ('\u{cad8}', '\u{cad8}', H2),
+    ('\u{cad9}', '\u{caf3}', H3),
+    ('\u{caf4}', '\u{caf4}', H2),
+    ('\u{caf5}', '\u{cb0f}', H3),
+    ('\u{cb10}', '\u{cb10}', H2),
+    ('\u{cb11}', '\u{cb2b}', H3),
+    ('\u{cb2c}', '\u{cb2c}', H2),
+    ('\u{cb2d}', '\u{cb47}', H3),
+    ('\u{cb48}', '\u{cb48}', H2),
+    ('\u{cb49}', '\u{cb63}', H3),
+    ('\u{cb64}', '\u{cb64}', H2),
+    ('\u{cb65}', '\u{cb7f}', H3),
+    ('\u{cb80}', '\u{cb80}', H2),
+    ('\u{cb81}', '\u{cb9b}', H3),
+    ('\u{cb9c}', '\u{cb9c}', H2),
+    ('\u{cb9d}', '\u{cbb7}', H3),
+    ('\u{cbb8}', '\u{cbb8}', H2),
+    ('\u{cbb9}', '\u{cbd3}', H3),
+    ('\u{cbd4}', '\u{cbd4}', H2),
+    ('\u{cbd5}', '\u{cbef}', H3),
+    ('\u{cbf0}', '\u{cbf0}', H2),
+    ('\u{cbf1}', '\u{cc0b}', H3),
+    ('\u{cc0c}', '\u{cc0c}', H2),
+    ('\u{cc0d}', '\u{cc27}', H3),
+    ('\u{cc28}', '\u{cc28}', H2),
+    ('\u{cc29}', '\u{cc43}', H3),
+    ('\u{cc44}', '\u{cc44}', H2),
+    ('\u{cc45}', '\u{cc5f}', H3),
+    ('\u{cc60}', '\u{cc60}', H2),
+    ('\u{cc61}', '\u{cc7b}', H3),
+    ('\u{cc7c}', '\u{cc7c}', H2),
+    ('\u{cc7d}', '\u{cc97}', H3),
+    ('\u{cc98}', '\u{cc98}', H2),
+    ('\u{cc99}', '\u{ccb3}', H3),
+    ('\u{ccb4}', '\u{ccb4}', H2),
+    ('\u{ccb5}', '\u{cccf}', H3),
+    ('\u{ccd0}', '\u{ccd0}', H2),
+    ('\u{ccd1}', '\u{cceb}', H3),
+    ('\u{ccec}', '\u{ccec}', H2),
+    ('\u{cced}', '\u{cd07}', H3),
+    ('\u{cd08}', '\u{cd08}', H2),
+    ('\u{cd09}', '\u{cd23}', H3),
+    ('\u{cd24}', '\u{cd24}', H2),
+    ('\u{cd25}', '\u{cd3f}', H3),
+    ('\u{cd40}', '\u{cd40}', H2),
+    ('\u{cd41}', '\u{cd5b}', H3),
+    ('\u{cd5c}', '\u{cd5c}', H2),
+    ('\u{cd5d}', '\u{cd77}', H3),
+    ('\u{cd78}', '\u{cd78}', H2),
+    ('\u{cd79}', '\u{cd93}', H3),
+    ('\u{cd94}', '\u{cd94}', H2),
+    ('\u{cd95}', '\u{cdaf}', H3),
+    ('\u{cdb0}', '\u{cdb0}', H2),
+    ('\u{cdb1}', '\u{cdcb}', H3),
+    ('\u{cdcc}', '\u{cdcc}', H2),
+    ('\u{cdcd}', '\u{cde7}', H3),
+    ('\u{cde8}', '\u{cde8}', H2),
+    ('\u{cde9}', '\u{ce03}', H3),
+    ('\u{ce04}', '\u{ce04}', H2),
+    ('\u{ce05}', '\u{ce1f}', H3),
+    ('\u{ce20}', '\u{ce20}', H2),
+    ('\u{ce21}', '\u{ce3b}', H3),
+    ('\u{ce3c}', '\u{ce3c}', H2),
+    ('\u{ce3d}', '\u{ce57}', H3),
+    ('\u{ce58}', '\u{ce58}', H2),
+    ('\u{ce59}', '\u{ce73}', H3),
+    ('\u{ce74}', '\u{ce74}', H2),
+    ('\u{ce75}', '\u{ce8f}', H3),
+    ('\u{ce90}', '\u{ce90}', H2),
+    ('\u{ce91}', '\u{ceab}', H3),
+    ('\u{ceac}', '\u{ceac}', H2),
+    ('\u{cead}', '\u{cec7}', H3),
+    ('\u{cec8}', '\u{cec8}', H2),
+    ('\u{cec9}', '\u{cee3}', H3),
+    ('\u{cee4}', '\u{cee4}', H2),
+    ('\u{cee5}', '\u{ceff}', H3),
+    ('\u{cf00}', '\u{cf00}', H2),
+    ('\u{cf01}', '\u{cf1b}', H3),
+    ('\u{cf1c}', '\u{cf1c}', H2),
+    ('\u{cf1d}', '\u{cf37}', H3),
+    ('\u{cf38}', '\u{cf38}', H2),
+    ('\u{cf39}', '\u{cf53}', H3),
+    ('\u{cf54}', '\u{cf54}', H2),
+    ('\u{cf55}', '\u{cf6f}', H3),
+    ('\u{cf70}', '\u{cf70}', H2),
+    ('\u{cf71}', '\u{cf8b}', H3),
+    ('\u{cf8c}', '\u{cf8c}', H2),
+    ('\u{cf8d}', '\u{cfa7}', H3),
+    ('\u{cfa8}', '\u{cfa8}', H2),
+    ('\u{cfa9}', '\u{cfc3}', H3),
+    ('\u{cfc4}', '\u{cfc4}', H2),
+    ('\u{cfc5}', '\u{cfdf}', H3),
+    ('\u{cfe0}', '\u{cfe0}', H2),
+    ('\u{cfe1}', '\u{cffb}', H3),
+    ('\u{cffc}', '\u{cffc}', H2),
+    ('\u{cffd}', '\u{d017}', H3),
+    ('\u{d018}', '\u{d018}', H2),
+    ('\u{d019}', '\u{d033}', H3),
+    ('\u{d034}', '\u{d034}', H2),
+    ('\u{d035}', '\u{d04f}', H3),
+    ('\u{d050}', '\u{d050}', H2),
+    ('\u{d051}', '\u{d06b}', H3),
+    ('\u{d06c}', '\u{d06c}', H2),
+    ('\u{d06d}', '\u{d087}', H3),
+    ('\u{d088}', '\u{d088}', H2),
+    ('\u{d089}', '\u{d0a3}', H3),
+    ('\u{d0a4}', '\u{d0a4}', H2),
+    ('\u{d0a5}', '\u{d0bf}', H3),
+    ('\u{d0c0}', '\u{d0c0}', H2),
+    ('\u{d0c1}', '\u{d0db}', H3),
+    ('\u{d0dc}', '\u{d0dc}', H2),
+    ('\u{d0dd}', '\u{d0f7}', H3),
+    ('\u{d0f8}', '\u{d0f8}', H2),
+    ('\u{d0f9}', '\u{d113}', H3),
+    ('\u{d114}', '\u{d114}', H2),
+    ('\u{d115}', '\u{d12f}', H3),
+    ('\u{d130}', '\u{d130}', H2),
+    ('\u{d131}', '\u{d14b}', H3),
+    ('\u{d14c}', '\u{d14c}', H2),
+    ('\u{d14d}', '\u{d167}', H3),
+    ('\u{d168}', '\u{d168}', H2),
+    ('\u{d169}', '\u{d183}', H3),
+    ('\u{d184}', '\u{d184}', H2),
+    ('\u{d185}', '\u{d19f}', H3),
+    ('\u{d1a0}', '\u{d1a0}', H2),
+    ('\u{d1a1}', '\u{d1bb}', H3),
+    ('\u{d1bc}', '\u{d1bc}', H2),
+    ('\u{d1bd}', '\u{d1d7}', H3),
+    ('\u{d1d8}', '\u{d1d8}', H2),
+    ('\u{d1d9}', '\u{d1f3}', H3),
+    ('\u{d1f4}', '\u{d1f4}', H2),
+    ('\u{d1f5}', '\u{d20f}', H3),
+    ('\u{d210}', '\u{d210}', H2),
+    ('\u{d211}', '\u{d22b}', H3),
+    ('\u{d22c}', '\u{d22c}', H2),
+    ('\u{d22d}', '\u{d247}', H3),
+    ('\u{d248}', '\u{d248}', H2),
+    ('\u{d249}', '\u{d263}', H3),
+    ('\u{d264}', '\u{d264}', H2),
+    ('\u{d265}', '\u{d27f}', H3),
+    ('\u{d280}', '\u{d280}', H2),
+    ('\u{d281}', '\u{d29b}', H3),
+    ('\u{d29c}', '\u{d29c}', H2),
+    ('\u{d29d}', '\u{d2b7}', H3),
+    ('\u{d2b8}', '\u{d2b8}', H2),
+    ('\u{d2b9}', '\u{d2d3}', H3),
+    ('\u{d2d4}', '\u{d2d4}', H2),
+    ('\u{d2d5}', '\u{d2ef}', H3),
+    ('\u{d2f0}', '\u{d2f0}', H2),
+    ('\u{d2f1}', '\u{d30b}', H3),
+    ('\u{d30c}', '\u{d30c}', H2),
+    ('\u{d30d}', '\u{d327}', H3),
+    ('\u{d328}', '\u{d328}', H2),
+    ('\u{d329}', '\u{d343}', H3),
+    ('\u{d344}', '\u{d344}', H2),
+    ('\u{d345}', '\u{d35f}', H3),
+    ('\u{d360}', '\u{d360}', H2),
+    ('\u{d361}', '\u{d37b}', H3),
+    ('\u{d37c}', '\u{d37c}', H2),
+    ('\u{d37d}', '\u{d397}', H3),
+    ('\u{d398}', '\u{d398}', H2),
+    ('\u{d399}', '\u{d3b3}', H3),
+    ('\u{d3b4}', '\u{d3b4}', H2),
+    ('\u{d3b5}', '\u{d3cf}', H3),
+    ('\u{d3d0}', '\u{d3d0}', H2),
+    ('\u{d3d1}', '\u{d3eb}', H3),
+    ('\u{d3ec}', '\u{d3ec}', H2),
+    ('\u{d3ed}', '\u{d407}', H3),
+    ('\u{d408}', '\u{d408}', H2),
+    ('\u{d409}', '\u{d423}', H3),
+    ('\u{d424}', '\u{d424}', H2),
+    ('\u{d425}', '\u{d43f}', H3),
+    ('\u{d440}', '\u{d440}', H2),
+    ('\u{d441}', '\u{d45b}', H3),
+    ('\u{d45c}', '\u{d45c}', H2),
+    ('\u{d45d}', '\u{d477}', H3),
+    ('\u{d478}', '\u{d478}', H2),
+    ('\u{d479}', '\u{d493}', H3),
+    ('\u{d494}', '\u{d494}', H2),
+    ('\u{d495}', '\u{d4af}', H3),
+    ('\u{d4b0}', '\u{d4b0}', H2),
+    ('\u{d4b1}', '\u{d4cb}', H3),
+    ('\u{d4cc}', '\u{d4cc}', H2),
+    ('\u{d4cd}', '\u{d4e7}', H3),
+    ('\u{d4e8}', '\u{d4e8}', H2),
+    ('\u{d4e9}', '\u{d503}', H3),
+    ('\u{d504}', '\u{d504}', H2),
+    ('\u{d505}', '\u{d51f}', H3),
+    ('\u{d520}', '\u{d520}', H2),
+    ('\u{d521}', '\u{d53b}', H3),
+    ('\u{d53c}', '\u{d53c}', H2),
+    ('\u{d53d}', '\u{d557}', H3),
+    ('\u{d558}', '\u{d558}', H2),
+    ('\u{d559}', '\u{d573}', H3),
+    ('\u{d574}', '\u{d574}', H2),
+    ('\u{d575}', '\u{d58f}', H3),
+    ('\u{d590}', '\u{d590}', H2),
+    ('\u{d591}', '\u{d5ab}', H3),
+    ('\u{d5ac}', '\u{d5ac}', H2),
+    ('\u{d5ad}', '\u{d5c7}', H3),
+    ('\u{d5c8}', '\u{d5c8}', H2),
+    ('\u{d5c9}', '\u{d5e3}', H3),
+    ('\u{d5e4}', '\u{d5e4}', H2),
+    ('\u{d5e5}', '\u{d5ff}', H3),
+    ('\u{d600}', '\u{d600}', H2),
+    ('\u{d601}', '\u{d61b}', H3),
+    ('\u{d61c}', '\u{d61c}', H2),
+    ('\u{d61d}', '\u{d637}', H3),
+    ('\u{d638}', '\u{d638}', H2),
+    ('\u{d639}', '\u{d653}', H3),
+    ('\u{d654}', '\u{d654}', H2),
+    ('\u{d655}', '\u{d66f}', H3),
+    ('\u{d670}', '\u{d670}', H2),
+    ('\u{d671}', '\u{d68b}', H3),
+    ('\u{d68c}', '\u{d68c}', H2),
+    ('\u{d68d}', '\u{d6a7}', H3),
+    ('\u{d6a8}', '\u{d6a8}', H2),
+    ('\u{d6a9}', '\u{d6c3}', H3),
+    ('\u{d6c4}', '\u{d6c4}', H2),
+    ('\u{d6c5}', '\u{d6df}', H3),
+    ('\u{d6e0}', '\u{d6e0}', H2),
+    ('\u{d6e1}', '\u{d6fb}', H3),
+    ('\u{d6fc}', '\u{d6fc}', H2),
+    ('\u{d6fd}', '\u{d717}', H3),
+    ('\u{d718}', '\u{d718}', H2),
+    ('\u{d719}', '\u{d733}', H3),
+    ('\u{d734}', '\u{d734}', H2),
+    ('\u{d735}', '\u{d74f}', H3),
+    ('\u{d750}', '\u{d750}', H2),
+    ('\u{d751}', '\u{d76b}', H3),
+    ('\u{d76c}', '\u{d76c}', H2),
+    ('\u{d76d}', '\u{d787}', H3),
+    ('\u{d788}', '\u{d788}', H2),
+    ('\u{d789}', '\u{d7a3}', H3),
+    ('\u{d7b0}', '\u{d7c6}', JV),
+    ('\u{d7cb}', '\u{d7fb}', JT),
+    ('\u{f900}', '\u{faff}', ID),
+    ('\u{fb1d}', '\u{fb1d}', HL),
+    ('\u{fb1e}', '\u{fb1e}', CM),
+    ('\u{fb1f}', '\u{fb28}', HL),
+    ('\u{fb2a}', '\u{fb4f}', HL),
+    ('\u{fd3e}', '\u{fd3e}', CL),
+    ('\u{fd3f}', '\u{fd3f}', OP),
+    ('\u{fdfc}', '\u{fdfc}', PO),
+    ('\u{fe00}', '\u{fe0f}', CM),
+    ('\u{fe10}', '\u{fe10}', IS),
+    ('\u{fe11}', '\u{fe12}', CL),
+    ('\u{fe13}', '\u{fe14}', IS),
+    ('\u{fe15}', '\u{fe16}', EX),
+    ('\u{fe17}', '\u{fe17}', OP),
+    ('\u{fe18}', '\u{fe18}', CL),
+    ('\u{fe19}', '\u{fe19}', IN),
+    ('\u{fe20}', '\u{fe2f}', CM),
+    ('\u{fe30}', '\u{fe34}', ID),
+    ('\u{fe35}', '\u{fe35}', OP),
+    ('\u{fe36}', '\u{fe36}', CL),
+    ('\u{fe37}', '\u{fe37}', OP),
+    ('\u{fe38}', '\u{fe38}', CL),
+    ('\u{fe39}', '\u{fe39}', OP),
+    ('\u{fe3a}', '\u{fe3a}', CL),
+    ('\u{fe3b}', '\u{fe3b}', OP),
+    ('\u{fe3c}', '\u{fe3c}', CL),
+    ('\u{fe3d}', '\u{fe3d}', OP),
+    ('\u{fe3e}', '\u{fe3e}', CL),
+    ('\u{fe3f}', '\u{fe3f}', OP),
+    ('\u{fe40}', '\u{fe40}', CL),
+    ('\u{fe41}', '\u{fe41}', OP),
+    ('\u{fe42}', '\u{fe42}', CL),
+    ('\u{fe43}', '\u{fe43}', OP),
+    ('\u{fe44}', '\u{fe44}', CL),
+    ('\u{fe45}', '\u{fe46}', ID),
+    ('\u{fe47}', '\u{fe47}', OP),
+    ('\u{fe48}', '\u{fe48}', CL),
+    ('\u{fe49}', '\u{fe4f}', ID),
+    ('\u{fe50}', '\u{fe50}', CL),
+    ('\u{fe51}', '\u{fe51}', ID),
+    ('\u{fe52}', '\u{fe52}', CL),
+    ('\u{fe54}', '\u{fe55}', NS),
+    ('\u{fe56}', '\u{fe57}', EX),
+    ('\u{fe58}', '\u{fe58}', ID),
+    ('\u{fe59}', '\u{fe59}', OP),
+    ('\u{fe5a}', '\u{fe5a}', CL),
+    ('\u{fe5b}', '\u{fe5b}', OP),
+    ('\u{fe5c}', '\u{fe5c}', CL),
+    ('\u{fe5d}', '\u{fe5d}', OP),
+    ('\u{fe5e}', '\u{fe5e}', CL),
+    ('\u{fe5f}', '\u{fe68}', ID),
+    ('\u{fe69}', '\u{fe69}', PR),
+    ('\u{fe6a}', '\u{fe6a}', PO),
+    ('\u{fe6b}', '\u{fe6b}', ID),
+    ('\u{feff}', '\u{feff}', WJ),
+    ('\u{ff01}', '\u{ff01}', EX),
+    ('\u{ff02}', '\u{ff03}', ID),
+    ('\u{ff04}', '\u{ff04}', PR),
+    ('\u{ff05}', '\u{ff05}', PO),
+    ('\u{ff06}', '\u{ff07}', ID),
+    ('\u{ff08}', '\u{ff08}', OP),
+    ('\u{ff09}', '\u{ff09}', CL),
+    ('\u{ff0a}', '\u{ff0b}', ID),
+    ('\u{ff0c}', '\u{ff0c}', CL),
+    ('\u{ff0d}', '\u{ff0d}', ID),
+    ('\u{ff0e}', '\u{ff0e}', CL),
+    ('\u{ff0f}', '\u{ff0f}', ID),
+    ('\u{ff10}', '\u{ff19}', NU),
+    ('\u{ff1a}', '\u{ff1b}', NS),
+    ('\u{ff1c}', '\u{ff1e}', ID),
+    ('\u{ff1f}', '\u{ff1f}', EX),
+    ('\u{ff20}', '\u{ff3a}', ID),
+    ('\u{ff3b}', '\u{ff3b}', OP),
+    ('\u{ff3c}', '\u{ff3c}', ID),
+    ('\u{ff3d}', '\u{ff3d}', CL),
+    ('\u{ff3e}', '\u{ff5a}', ID),
+    ('\u{ff5b}', '\u{ff5b}', OP),
+    ('\u{ff5c}', '\u{ff5c}', ID),
+    ('\u{ff5d}', '\u{ff5d}', CL),
+    ('\u{ff5e}', '\u{ff5e}', ID),
+    ('\u{ff5f}', '\u{ff5f}', OP),
+    ('\u{ff60}', '\u{ff61}', CL),
+    ('\u{ff62}', '\u{ff62}', OP),
+    ('\u{ff63}', '\u{ff64}', CL),
+    ('\u{ff65}', '\u{ff65}', NS),
+    ('\u{ff67}', '\u{ff70}', NS),
+    ('\u{ff9e}', '\u{ff9f}', NS),
+    ('\u{ffe0}', '\u{ffe0}', PO),
+    ('\u{ffe1}', '\u{ffe1}', PR),
+    ('\u{ffe2}', '\u{ffe4}', ID),
+    ('\u{ffe5}', '\u{ffe6}', PR),
+    ('\u{fff9}', '\u{fffb}', CM),
+    ('\u{fffc}', '\u{fffc}', CB),
+    ('\u{101fd}', '\u{101fd}', CM),
+    ('\u{102e0}', '\u{102e0}', CM),
+    ('\u{10376}', '\u{1037a}', CM),
+    ('\u{104a0}', '\u{104a9}', NU),
+    ('\u{10a01}', '\u{10a0f}', CM),
+    ('\u{10a38}', '\u{10a3f}', CM),
+    ('\u{10ae5}', '\u{10ae6}', CM),
+    ('\u{10af6}', '\u{10af6}', IN),
+    ('\u{10d24}', '\u{10d27}', CM),
+    ('\u{10d30}', '\u{10d39}', NU),
+    ('\u{10eab}', '\u{10eac}', CM),
+    ('\u{10f46}', '\u{10f50}', CM),
+    ('\u{10f82}', '\u{10f85}', CM),
+    ('\u{11000}', '\u{11002}', CM),
+    ('\u{11038}', '\u{11046}', CM),
+    ('\u{11066}', '\u{1106f}', NU),
+    ('\u{11070}', '\u{11070}', CM),
+    ('\u{11073}', '\u{11074}', CM),
+    ('\u{1107f}', '\u{11082}', CM),
+    ('\u{110b0}', '\u{110ba}', CM),
+    ('\u{110c2}', '\u{110c2}', CM),
+    ('\u{110f0}', '\u{110f9}', NU),
+    ('\u{11100}', '\u{11102}', CM),
+    ('\u{11127}', '\u{11134}', CM),
+    ('\u{11136}', '\u{1113f}', NU),
+    ('\u{11145}', '\u{11146}', CM),
+    ('\u{11173}', '\u{11173}', CM),
+    ('\u{11180}', '\u{11182}', CM),
+    ('\u{111b3}', '\u{111c0}', CM),
+    ('\u{111c9}', '\u{111cc}', CM),
+    ('\u{111ce}', '\u{111cf}', CM),
+    ('\u{111d0}', '\u{111d9}', NU),
+    ('\u{1122c}', '\u{11237}', CM),
+    ('\u{1123e}', '\u{1123e}', CM),
+    ('\u{112df}', '\u{112ea}', CM),
+    ('\u{112f0}', '\u{112f9}', NU),
+    ('\u{11300}', '\u{11303}', CM),
+    ('\u{1133b}', '\u{1133c}', CM),
+    ('\u{1133e}', '\u{1134d}', CM),
+    ('\u{11357}', '\u{11357}', CM),
+    ('\u{11362}', '\u{11374}', CM),
+    ('\u{11435}', '\u{11446}', CM),
+    ('\u{11450}', '\u{11459}', NU),
+    ('\u{1145e}', '\u{1145e}', CM),
+    ('\u{114b0}', '\u{114c3}', CM),
+    ('\u{114d0}', '\u{114d9}', NU),
+    ('\u{115af}', '\u{115c0}', CM),
+    ('\u{115dc}', '\u{115dd}', CM),
+    ('\u{11630}', '\u{11640}', CM),
+    ('\u{11650}', '\u{11659}', NU),
+    ('\u{116ab}', '\u{116b7}', CM),
+    ('\u{116c0}', '\u{116c9}', NU),
+    ('\u{1171d}', '\u{1172b}', CM),
+    ('\u{11730}', '\u{11739}', NU),
+    ('\u{1182c}', '\u{1183a}', CM),
+    ('\u{118e0}', '\u{118e9}', NU),
+    ('\u{11930}', '\u{1193e}', CM),
+    ('\u{11940}', '\u{11940}', CM),
+    ('\u{11942}', '\u{11943}', CM),
+    ('\u{11950}', '\u{11959}', NU),
+    ('\u{119d1}', '\u{119e0}', CM),
+    ('\u{119e4}', '\u{119e4}', CM),
+    ('\u{11a01}', '\u{11a0a}', CM),
+    ('\u{11a33}', '\u{11a39}', CM),
+    ('\u{11a3b}', '\u{11a3e}', CM),
+    ('\u{11a47}', '\u{11a47}', CM),
+    ('\u{11a51}', '\u{11a5b}', CM),
+    ('\u{11a8a}', '\u{11a99}', CM),
+    ('\u{11c2f}', '\u{11c3f}', CM),
+    ('\u{11c50}', '\u{11c59}', NU),
+    ('\u{11c92}', '\u{11cb6}', CM),
+    ('\u{11d31}', '\u{11d45}', CM),
+    ('\u{11d47}', '\u{11d47}', CM),
+    ('\u{11d50}', '\u{11d59}', NU),
+    ('\u{11d8a}', '\u{11d97}', CM),
+    ('\u{11da0}', '\u{11da9}', NU),
+    ('\u{11ef3}', '\u{11ef6}', CM),
+    ('\u{11fdd}', '\u{11fe0}', PR),
+    ('\u{16a60}', '\u{16a69}', NU),
+    ('\u{16ac0}', '\u{16ac9}', NU),
+    ('\u{16af0}', '\u{16af4}', CM),
+    ('\u{16b30}', '\u{16b36}', CM),
+    ('\u{16b50}', '\u{16b59}', NU),
+    ('\u{16f4f}', '\u{16f4f}', CM),
+    ('\u{16f51}', '\u{16f92}', CM),
+    ('\u{16fe0}', '\u{16fe3}', ID),
+    ('\u{16fe4}', '\u{16ff1}', CM),
+    ('\u{17000}', '\u{1b2fb}', ID),
+    ('\u{1bc9d}', '\u{1bc9e}', CM),
+    ('\u{1cf00}', '\u{1cf46}', CM),
+    ('\u{1d165}', '\u{1d169}', CM),
+    ('\u{1d16d}', '\u{1d172}', CM),
+    ('\u{1d17b}', '\u{1d182}', CM),
+    ('\u{1d185}', '\u{1d18b}', CM),
+    ('\u{1d1aa}', '\u{1d1ad}', CM),
+    ('\u{1d242}', '\u{1d244}', CM),
+    ('\u{1d7ce}', '\u{1d7ff}', NU),
+    ('\u{1da00}', '\u{1da36}', CM),
+    ('\u{1da3b}', '\u{1da6c}', CM),
+    ('\u{1da75}', '\u{1da75}', CM),
+    ('\u{1da84}', '\u{1da84}', CM),
+    ('\u{1da9b}', '\u{1daaf}', CM),
+    ('\u{1e000}', '\u{1e02a}', CM),
+    ('\u{1e130}', '\u{1e136}', CM),
+    ('\u{1e140}', '\u{1e149}', NU),
+    ('\u{1e2ae}', '\u{1e2ae}', CM),
+    ('\u{1e2ec}', '\u{1e2ef}', CM),
+    ('\u{1e2f0}', '\u{1e2f9}', NU),
+    ('\u{1e2ff}', '\u{1e2ff}', PR),
+    ('\u{1e8d0}', '\u{1e8d6}', CM),
+    ('\u{1e944}', '\u{1e94a}', CM),
+    ('\u{1e950}', '\u{1e959}', NU),
+    ('\u{1ecb0}', '\u{1ecb0}', PR),
+    ('\u{1f000}', '\u{1f1e5}', ID),
+    ('\u{1f1e6}', '\u{1f1ff}', RI),
+    ('\u{1f200}', '\u{1f384}', ID),
+    ('\u{1f385}', '\u{1f385}', EB),
+    ('\u{1f386}', '\u{1f3c1}', ID),
+    ('\u{1f3c2}', '\u{1f3c4}', EB),
+    ('\u{1f3c5}', '\u{1f3c6}', ID),
+    ('\u{1f3c7}', '\u{1f3c7}', EB),
+    ('\u{1f3c8}', '\u{1f3c9}', ID),
+    ('\u{1f3ca}', '\u{1f3cc}', EB),
+    ('\u{1f3cd}', '\u{1f3fa}', ID),
+    ('\u{1f3fb}', '\u{1f3ff}', EM),
+    ('\u{1f400}', '\u{1f441}', ID),
+    ('\u{1f442}', '\u{1f443}', EB),
+    ('\u{1f444}', '\u{1f445}', ID),
+    ('\u{1f446}', '\u{1f450}', EB),
+    ('\u{1f451}', '\u{1f465}', ID),
+    ('\u{1f466}', '\u{1f478}', EB),
+    ('\u{1f479}', '\u{1f47b}', ID),
+    ('\u{1f47c}', '\u{1f47c}', EB),
+    ('\u{1f47d}', '\u{1f480}', ID),
+    ('\u{1f481}', '\u{1f483}', EB),
+    ('\u{1f484}', '\u{1f484}', ID),
+    ('\u{1f485}', '\u{1f487}', EB),
+    ('\u{1f488}', '\u{1f4a9}', ID),
+    ('\u{1f4aa}', '\u{1f4aa}', EB),
+    ('\u{1f4ab}', '\u{1f573}', ID),
+    ('\u{1f574}', '\u{1f575}', EB),
+    ('\u{1f576}', '\u{1f579}', ID),
+    ('\u{1f57a}', '\u{1f57a}', EB),
+    ('\u{1f57b}', '\u{1f58f}', ID),
+    ('\u{1f590}', '\u{1f590}', EB),
+    ('\u{1f591}', '\u{1f594}', ID),
+    ('\u{1f595}', '\u{1f596}', EB),
+    ('\u{1f597}', '\u{1f644}', ID),
+    ('\u{1f645}', '\u{1f647}', EB),
+    ('\u{1f648}', '\u{1f64a}', ID),
+    ('\u{1f64b}', '\u{1f64f}', EB),
+    ('\u{1f650}', '\u{1f675}', ID),
+    ('\u{1f676}', '\u{1f678}', QU),
+    ('\u{1f679}', '\u{1f6a2}', ID),
+    ('\u{1f6a3}', '\u{1f6a3}', EB),
+    ('\u{1f6a4}', '\u{1f6b3}', ID),
+    ('\u{1f6b4}', '\u{1f6b6}', EB),
+    ('\u{1f6b7}', '\u{1f6bf}', ID),
+    ('\u{1f6c0}', '\u{1f6c0}', EB),
+    ('\u{1f6c1}', '\u{1f6cb}', ID),
+    ('\u{1f6cc}', '\u{1f6cc}', EB),
+    ('\u{1f6cd}', '\u{1f90b}', ID),
+    ('\u{1f90c}', '\u{1f90c}', EB),
+    ('\u{1f90d}', '\u{1f90e}', ID),
+    ('\u{1f90f}', '\u{1f90f}', EB),
+    ('\u{1f910}', '\u{1f917}', ID),
+    ('\u{1f918}', '\u{1f91f}', EB),
+    ('\u{1f920}', '\u{1f925}', ID),
+    ('\u{1f926}', '\u{1f926}', EB),
+    ('\u{1f927}', '\u{1f92f}', ID),
+    ('\u{1f930}', '\u{1f939}', EB),
+    ('\u{1f93a}', '\u{1f93b}', ID),
+    ('\u{1f93c}', '\u{1f93e}', EB),
+    ('\u{1f93f}', '\u{1f976}', ID),
+    ('\u{1f977}', '\u{1f977}', EB),
+    ('\u{1f978}', '\u{1f9b4}', ID),
+    ('\u{1f9b5}', '\u{1f9b6}', EB),
+    ('\u{1f9b7}', '\u{1f9b7}', ID),
+    ('\u{1f9b8}', '\u{1f9b9}', EB),
+    ('\u{1f9ba}', '\u{1f9ba}', ID),
+    ('\u{1f9bb}', '\u{1f9bb}', EB),
+    ('\u{1f9bc}', '\u{1f9cc}', ID),
+    ('\u{1f9cd}', '\u{1f9cf}', EB),
+    ('\u{1f9d0}', '\u{1f9d0}', ID),
+    ('\u{1f9d1}', '\u{1f9dd}', EB),
+    ('\u{1f9de}', '\u{1fac2}', ID),
+    ('\u{1fac3}', '\u{1fac5}', EB),
+    ('\u{1fac6}', '\u{1faef}', ID),
+    ('\u{1faf0}', '\u{1faf6}', EB),
+    ('\u{1faf7}', '\u{1faff}', ID),
+    ('\u{1fbf0}', '\u{1fbf9}', NU),
+    ('\u{1fc00}', '\u{3fffd}', ID),
+    ('\u{e0001}', '\u{e01ef}', CM),
+];
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn breaks(s: &str, word_break: StyleWordBreak) -> Vec<usize> {
+        let chars = s.chars().map(|c| c as u32).collect::<Vec<_>>();
+        get_line_break_opportunities(&chars, word_break)
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| if *b { Some(i) } else { None })
+        .collect()
+    }
+
+    #[test]
+    fn test_line_break_class() {
+        assert_eq!(get_line_break_class('a'), AL);
+        assert_eq!(get_line_break_class(' '), SP);
+        assert_eq!(get_line_break_class('-'), HY);
+        assert_eq!(get_line_break_class('/'), SY);
+        assert_eq!(get_line_break_class('('), OP);
+        assert_eq!(get_line_break_class(')'), CP);
+        assert_eq!(get_line_break_class('1'), NU);
+        assert_eq!(get_line_break_class('\u{4e00}'), ID);
+        assert_eq!(get_line_break_class('\u{3002}'), CL);
+        assert_eq!(get_line_break_class('\u{3063}'), NS);
+        assert_eq!(get_line_break_class('\u{ac00}'), H2);
+        assert_eq!(get_line_break_class('\u{ac01}'), H3);
+        assert_eq!(get_line_break_class('\u{5d0}'), HL);
+        assert_eq!(get_line_break_class('\u{301}'), CM);
+        assert_eq!(get_line_break_class('\u{a0}'), GL);
+        assert_eq!(get_line_break_class('\u{ad}'), BA);
+    }
+
+    #[test]
+    fn test_latin_text() {
+        // break after spaces, not before
+        assert_eq!(breaks("hello world", StyleWordBreak::Normal), vec![6]);
+        // break after hyphens, not within numbers
+        assert_eq!(breaks("well-known 1-2", StyleWordBreak::Normal), vec![5, 11]);
+        // no breaks before punctuation
+        assert_eq!(breaks("end. (yes)", StyleWordBreak::Normal), vec![5]);
+        // no breaks around non-breaking spaces
+        assert_eq!(breaks("10\u{a0}kg", StyleWordBreak::Normal), Vec::<usize>::new());
+        // combining marks stay with their base character
+        assert_eq!(breaks("e\u{301}-e\u{301}", StyleWordBreak::Normal), vec![3]);
+    }
+
+    #[test]
+    fn test_url() {
+        // break after slashes, but not before
+        assert_eq!(breaks("http://a.com/b/c", StyleWordBreak::Normal), vec![7, 13, 15]);
+    }
+
+    #[test]
+    fn test_cjk_text() {
+        // break between ideographs, not before closing punctuation or small kana
+        assert_eq!(breaks("\u{4e00}\u{4e8c}\u{4e09}\u{3002}", StyleWordBreak::Normal), vec![1, 2]);
+        assert_eq!(breaks("\u{304b}\u{3063}\u{305f}", StyleWordBreak::Normal), vec![2]);
+        // keep-all suppresses breaks between ideographs
+        assert_eq!(breaks("\u{4e00}\u{4e8c}\u{4e09}", StyleWordBreak::KeepAll), Vec::<usize>::new());
+        // Korean syllables are not broken into jamo
+        assert_eq!(breaks("\u{1100}\u{1161}\u{11a8}", StyleWordBreak::Normal), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_break_all() {
+        assert_eq!(breaks("abc", StyleWordBreak::Normal), Vec::<usize>::new());
+        assert_eq!(breaks("abc", StyleWordBreak::BreakAll), vec![1, 2]);
+        assert_eq!(breaks("ab.", StyleWordBreak::BreakAll), vec![1]);
+    }
+
+    #[test]
+    fn test_emergency_breaks() {
+        let chars = "ae\u{301}\u{1f1e9}\u{1f1ea}b".chars().map(|c| c as u32).collect::<Vec<_>>();
+        assert_eq!(
+            get_emergency_break_opportunities(&chars),
+            vec![false, true, false, true, false, true]
+        );
+    }
+}
@@ -4,7 +4,7 @@
 pub use crate::text_shaping::ParsedFont;
 pub use azul_core::{
     app_resources::{
-        Words, Word, WordType,
+        Words, Word, WordType, LineBreakOpportunity, SOFT_HYPHEN,
         ShapedWords, ShapedWord, WordIndex, GlyphIndex, LineLength, IndexOfLineBreak,
         RemainingSpaceToRight, LineBreaks, WordPositions, LayoutedGlyphs, FontMetrics,
     },
@@ -16,9 +16,11 @@ pub use azul_core::{
     },
     window::{LogicalRect, LogicalSize, LogicalPosition},
 };
-pub use azul_css::FontRef;
+pub use azul_css::{FontRef, StyleDirection, StyleWordBreak, StyleOverflowWrap, StyleHyphens};
 use alloc::vec::Vec;
 use alloc::string::String;
+use crate::bidi::{self, BidiLevel};
+use crate::{hyphenation, linebreak};
 
 /// Creates a font from a font file (TTF, OTF, WOFF, etc.)
 ///
@@ -28,7 +30,22 @@ pub fn parse_font(font_bytes: &[u8], font_index: usize, parse_outlines: bool) ->
 }
 
 /// Splits the text by whitespace into logical units (word, tab, return, whitespace).
-pub fn split_text_into_words(text: &str) -> Words {
+///
+/// Words are additionally split at the boundaries of bidi embedding levels
+/// (for example "abc\u{5d0}\u{5d1}" is split into two words), so that every word
+/// can be shaped and reordered as a single left-to-right or right-to-left run.
+///
+/// Words are also split at every line break opportunity inside of the word (see
+/// `get_break_opportunities`), the type of the break is stored in `Word::break_before`.
+/// Since the parts of a word are shaped individually, there is no kerning across
+/// these break opportunities.
+pub fn split_text_into_words(
+    text: &str,
+    direction: StyleDirection,
+    word_break: StyleWordBreak,
+    overflow_wrap: StyleOverflowWrap,
+    hyphens: StyleHyphens,
+) -> Words {
 
     use unicode_normalization::UnicodeNormalization;
 
@@ -56,14 +73,16 @@ pub fn split_text_into_words(text: &str) -> Words {
                 Some(Word {
                     start: last_char_idx + 1,
                     end: ch_idx + 1,
-                    word_type: WordType::Space
+                    word_type: WordType::Space,
+                    break_before: LineBreakOpportunity::Allowed,
                 })
             },
             '\t' => {
                 Some(Word {
                     start: last_char_idx + 1,
                     end: ch_idx + 1,
-                    word_type: WordType::Tab
+                    word_type: WordType::Tab,
+                    break_before: LineBreakOpportunity::Allowed,
                 })
             },
             '\n' => {
@@ -73,6 +92,7 @@ pub fn split_text_into_words(text: &str) -> Words {
                         start: last_char_idx,
                         end: ch_idx + 1,
                         word_type: WordType::Return,
+                        break_before: LineBreakOpportunity::Allowed,
                     }
                 } else {
                     // "\n" return
@@ -80,6 +100,7 @@ pub fn split_text_into_words(text: &str) -> Words {
                         start: last_char_idx + 1,
                         end: ch_idx + 1,
                         word_type: WordType::Return,
+                        break_before: LineBreakOpportunity::Allowed,
                     }
                 })
             },
@@ -91,7 +112,8 @@ pub fn split_text_into_words(text: &str) -> Words {
             Some(Word {
                 start: current_word_start,
                 end: ch_idx,
-                word_type: WordType::Word
+                word_type: WordType::Word,
+                break_before: LineBreakOpportunity::Allowed,
             })
         } else {
             None
@@ -116,7 +138,8 @@ pub fn split_text_into_words(text: &str) -> Words {
         words.push(Word {
             start: current_word_start,
             end: normalized_chars.len(),
-            word_type: WordType::Word
+            word_type: WordType::Word,
+            break_before: LineBreakOpportunity::Allowed,
         });
     }
 
@@ -125,20 +148,163 @@ pub fn split_text_into_words(text: &str) -> Words {
         words.pop();
     }
 
+    let internal_chars = normalized_chars.iter().map(|c| *c as u32).collect::<Vec<u32>>();
+
+    let bidi_levels = get_bidi_levels(&internal_chars, direction);
+    let break_opportunities = get_break_opportunities(&internal_chars, word_break, overflow_wrap, hyphens);
+    words = split_words_at_breaks(words, &break_opportunities, bidi_levels.as_ref().map(|l| l.as_slice()));
+
     Words {
         items: words.into(),
         internal_str: normalized_string.into(),
-        internal_chars: normalized_chars.iter().map(|c| *c as u32).collect(),
+        internal_chars: internal_chars.into(),
+    }
+}
+
+/// Resolves the bidi embedding level of every character of the text. Returns `None`
+/// if all characters are on level 0 (only left-to-right characters in a left-to-right
+/// paragraph), so that the common case doesn't need any reordering.
+pub fn get_bidi_levels(chars: &[u32], direction: StyleDirection) -> Option<Vec<BidiLevel>> {
+
+    use crate::bidi::BidiClass::*;
+
+    let needs_bidi = direction == StyleDirection::Rtl || chars.iter().any(|c| {
+        core::char::from_u32(*c).map(|c| match bidi::get_bidi_class(c) {
+            R | AL | AN | LRE | LRO | RLE | RLO | LRI | RLI | FSI => true,
+            _ => false,
+        }).unwrap_or(false)
+    });
+
+    if needs_bidi {
+        Some(bidi::resolve_levels(chars, bidi::get_base_level(direction)))
+    } else {
+        None
+    }
+}
+
+/// Determines the type of the line break opportunity before every character of the text:
+///
+/// - `Allowed`: break opportunities of the Unicode line breaking algorithm (UAX #14)
+/// - `Hyphenated`: after soft hyphens (unless `hyphens: none`) and, for `hyphens: auto`,
+///   at the hyphenation points of the first registered dictionary that matches the word
+/// - `Emergency`: between any two grapheme clusters if `overflow-wrap` is not `normal`
+pub fn get_break_opportunities(
+    chars: &[u32],
+    word_break: StyleWordBreak,
+    overflow_wrap: StyleOverflowWrap,
+    hyphens: StyleHyphens,
+) -> Vec<LineBreakOpportunity> {
+
+    let mut result = linebreak::get_line_break_opportunities(chars, word_break)
+        .into_iter()
+        .map(|b| if b { LineBreakOpportunity::Allowed } else { LineBreakOpportunity::None })
+        .collect::<Vec<_>>();
+
+    // soft hyphens have the class BA, so the break after them is always allowed by UAX #14
+    for i in 1..chars.len() {
+        if chars[i - 1] == SOFT_HYPHEN as u32 && result[i] == LineBreakOpportunity::Allowed {
+            result[i] = match hyphens {
+                StyleHyphens::None => LineBreakOpportunity::None,
+                StyleHyphens::Manual | StyleHyphens::Auto => LineBreakOpportunity::Hyphenated,
+            };
+        }
+    }
+
+    if hyphens == StyleHyphens::Auto {
+
+        let is_letter = |c: u32| core::char::from_u32(c).map(|c| c.is_alphabetic()).unwrap_or(false);
+        let mut i = 0;
+
+        while i < chars.len() {
+
+            if !is_letter(chars[i]) {
+                i += 1;
+                continue;
+            }
+
+            let word_start = i;
+            while i < chars.len() && is_letter(chars[i]) {
+                i += 1;
+            }
+
+            // words that contain soft hyphens are only hyphenated at the soft hyphens
+            let has_soft_hyphen =
+                (word_start > 0 && chars[word_start - 1] == SOFT_HYPHEN as u32) ||
+                chars.get(i) == Some(&(SOFT_HYPHEN as u32));
+
+            if has_soft_hyphen {
+                continue;
+            }
+
+            let word = chars[word_start..i].iter().filter_map(|c| core::char::from_u32(*c)).collect::<Vec<char>>();
+            for pos in hyphenation::hyphenate_word(&word) {
+                if let Some(b @ LineBreakOpportunity::None) = result.get_mut(word_start + pos) {
+                    *b = LineBreakOpportunity::Hyphenated;
+                }
+            }
+        }
+    }
+
+    if overflow_wrap != StyleOverflowWrap::Normal {
+        for (b, emergency) in result.iter_mut().zip(linebreak::get_emergency_break_opportunities(chars)) {
+            if emergency && *b == LineBreakOpportunity::None {
+                *b = LineBreakOpportunity::Emergency;
+            }
+        }
+    }
+
+    result
+}
+
+/// Splits `WordType::Word` items at line break opportunities and where the embedding level changes
+fn split_words_at_breaks(
+    words: Vec<Word>,
+    break_opportunities: &[LineBreakOpportunity],
+    bidi_levels: Option<&[BidiLevel]>,
+) -> Vec<Word> {
+
+    let mut result = Vec::with_capacity(words.len());
+
+    for word in words {
+
+        if word.word_type != WordType::Word {
+            result.push(word);
+            continue;
+        }
+
+        let mut word_start = word.start;
+        let mut break_before = word.break_before;
+
+        for i in (word.start + 1)..word.end {
+            let level_changes = bidi_levels.map(|levels| levels[i] != levels[i - 1]).unwrap_or(false);
+            if break_opportunities[i] != LineBreakOpportunity::None || level_changes {
+                result.push(Word { start: word_start, end: i, word_type: WordType::Word, break_before });
+                word_start = i;
+                break_before = break_opportunities[i];
+            }
+        }
+
+        result.push(Word { start: word_start, end: word.end, word_type: WordType::Word, break_before });
     }
+
+    result
 }
 
 /// Takes a text broken into semantic items and shape all the words
 /// (does NOT scale the words, only shapes them)
-pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
+///
+/// Words on a right-to-left embedding level are shaped with their mirrored
+/// characters (i.e. `(` is shaped as `)`) and with their own script.
+///
+/// Characters that are not contained in the `font` are shaped with the first of
+/// the `fallback_fonts` that contains them, the `GlyphInfo::font_index` of these
+/// glyphs is the index in the `fallback_fonts` + 1.
+pub fn shape_words(words: &Words, font: &ParsedFont, fallback_fonts: &[&ParsedFont], direction: StyleDirection) -> ShapedWords {
 
     use crate::text_shaping;
 
     let (script, lang) = text_shaping::estimate_script_and_language(&words.internal_str);
+    let bidi_levels = get_bidi_levels(words.internal_chars.as_ref(), direction);
 
     // Get the dimensions of the space glyph
     let space_advance = font.get_space_width().unwrap_or(font.font_metrics.units_per_em as usize);
@@ -153,7 +319,33 @@ pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
         use crate::text_shaping::ShapedTextBufferUnsized;
 
         let chars = &words.internal_chars.as_ref()[word.start..word.end];
-        let shaped_word = font.shape(chars, script, lang);
+        let is_rtl = bidi_levels.as_ref()
+            .and_then(|levels| levels.get(word.start))
+            .map(|level| bidi::is_rtl_level(*level))
+            .unwrap_or(false);
+
+        let mut shaped_word = if is_rtl {
+            let mirrored_chars = chars.iter().map(|c| {
+                core::char::from_u32(*c)
+                .and_then(bidi::get_mirrored_char)
+                .map(|m| m as u32)
+                .unwrap_or(*c)
+            }).collect::<Vec<u32>>();
+            let word_str = mirrored_chars.iter().filter_map(|c| core::char::from_u32(*c)).collect::<String>();
+            let (word_script, word_lang) = text_shaping::estimate_script_and_language(&word_str);
+            shape_font_runs(&mirrored_chars, font, fallback_fonts, word_script, word_lang)
+        } else {
+            shape_font_runs(chars, font, fallback_fonts, script, lang)
+        };
+
+        // soft hyphens are invisible, the hyphen is only inserted if the line is broken at the soft hyphen
+        for info in shaped_word.infos.iter_mut() {
+            if info.glyph.unicode_codepoint.into_option() == Some(SOFT_HYPHEN as u32) {
+                info.size.advance_x = 0;
+                info.size.kerning = 0;
+            }
+        }
+
         let word_width = shaped_word.get_word_visual_width_unscaled();
 
         longest_word_width = longest_word_width.max(word_width);
@@ -166,6 +358,18 @@ pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
         }
     }).collect();
 
+    // U+2010 HYPHEN, or the ASCII hyphen-minus if the font doesn't contain a hyphen glyph
+    let hyphen = [0x2010_u32, '-' as u32]
+    .iter()
+    .find(|c| font.lookup_glyph_index(**c).map(|g| g != 0).unwrap_or(false))
+    .map(|c| {
+        let shaped_hyphen = font.shape(&[*c], script, lang);
+        ShapedWord {
+            word_width: shaped_hyphen.get_word_visual_width_unscaled(),
+            glyph_infos: shaped_hyphen.infos.into(),
+        }
+    });
+
     ShapedWords {
         items: shaped_words,
         longest_word_width: longest_word_width,
@@ -174,11 +378,143 @@ pub fn shape_words(words: &Words, font: &ParsedFont) -> ShapedWords {
         font_metrics_ascender: font.font_metrics.get_ascender_unscaled(),
         font_metrics_descender: font.font_metrics.get_descender_unscaled(),
         font_metrics_line_gap: font.font_metrics.get_line_gap_unscaled(),
+        hyphen,
+    }
+}
+
+/// Returns the index of the font (0 = primary font, 1.. = fallback fonts) for each character:
+/// combining marks use the font of their base character, so that a cluster is never split
+/// across fonts, characters that no font contains are shaped with the primary font (tofu)
+fn get_font_indices(chars: &[u32], font: &ParsedFont, fallback_fonts: &[&ParsedFont]) -> Vec<usize> {
+
+    use crate::linebreak::{get_line_break_class, LineBreakClass};
+
+    let has_glyph = |font: &ParsedFont, c: u32| font.lookup_glyph_index(c).map(|g| g != 0).unwrap_or(false);
+
+    let mut font_indices = Vec::with_capacity(chars.len());
+    let mut current_font_index = 0;
+
+    for (char_idx, c) in chars.iter().enumerate() {
+
+        let is_combining_mark = char_idx != 0 && core::char::from_u32(*c)
+            .map(|c| matches!(get_line_break_class(c), LineBreakClass::CM | LineBreakClass::ZWJ))
+            .unwrap_or(false);
+
+        if !is_combining_mark {
+            current_font_index = if has_glyph(font, *c) {
+                0
+            } else {
+                fallback_fonts.iter()
+                .position(|f| has_glyph(f, *c))
+                .map(|i| i + 1)
+                .unwrap_or(0)
+            };
+        }
+
+        font_indices.push(current_font_index);
+    }
+
+    font_indices
+}
+
+/// Splits the characters into runs of the same font and shapes each run with its font.
+///
+/// Glyphs of the fallback fonts are scaled to the units per em of the primary
+/// font, so that the `ShapedWord` can be scaled with the primary font metrics.
+fn shape_font_runs(
+    chars: &[u32],
+    font: &ParsedFont,
+    fallback_fonts: &[&ParsedFont],
+    script: u32,
+    lang: Option<u32>,
+) -> crate::text_shaping::ShapedTextBufferUnsized {
+
+    use crate::text_shaping::ShapedTextBufferUnsized;
+    use azul_core::app_resources::Placement;
+
+    if fallback_fonts.is_empty() {
+        return font.shape(chars, script, lang);
+    }
+
+    let font_indices = get_font_indices(chars, font, fallback_fonts);
+
+    if font_indices.iter().all(|i| *i == 0) {
+        return font.shape(chars, script, lang);
+    }
+
+    let primary_units_per_em = font.font_metrics.units_per_em as f32;
+    let mut infos = Vec::with_capacity(chars.len());
+    let mut run_start = 0;
+
+    while run_start < chars.len() {
+
+        let font_index = font_indices[run_start];
+        let run_end = font_indices[run_start..]
+            .iter()
+            .position(|i| *i != font_index)
+            .map(|len| run_start + len)
+            .unwrap_or(chars.len());
+
+        let run_font = if font_index == 0 { font } else { fallback_fonts[font_index - 1] };
+        let scale = primary_units_per_em / (run_font.font_metrics.units_per_em.max(1) as f32);
+        let glyph_offset = infos.len();
+        let ShapedTextBufferUnsized { infos: run_infos } = run_font.shape(&chars[run_start..run_end], script, lang);
+
+        let scale_i16 = |v: i16| (v as f32 * scale).round() as i16;
+        let scale_i32 = |v: i32| (v as f32 * scale).round() as i32;
+
+        infos.extend(run_infos.into_iter().map(|mut info| {
+            info.font_index = font_index;
+            if font_index != 0 {
+                info.size.advance_x = (info.size.advance_x as f32 * scale).round() as u16;
+                info.size.size_x = scale_i32(info.size.size_x);
+                info.size.size_y = scale_i32(info.size.size_y);
+                info.size.kerning = scale_i16(info.size.kerning);
+                info.kerning = scale_i16(info.kerning);
+            }
+            // glyph indices of the placement are relative to the start of the run
+            match &mut info.placement {
+                Placement::None => { },
+                Placement::Distance(d) => {
+                    d.x = scale_i32(d.x);
+                    d.y = scale_i32(d.y);
+                },
+                Placement::MarkAnchor(m) => {
+                    m.base_glyph_index += glyph_offset;
+                    m.base_glyph_anchor.x = scale_i16(m.base_glyph_anchor.x);
+                    m.base_glyph_anchor.y = scale_i16(m.base_glyph_anchor.y);
+                    m.mark_anchor.x = scale_i16(m.mark_anchor.x);
+                    m.mark_anchor.y = scale_i16(m.mark_anchor.y);
+                },
+                Placement::MarkOverprint(base_glyph_index) => {
+                    *base_glyph_index += glyph_offset;
+                },
+                Placement::CursiveAnchor(c) => {
+                    c.exit_glyph_index += glyph_offset;
+                    c.exit_glyph_anchor.x = scale_i16(c.exit_glyph_anchor.x);
+                    c.exit_glyph_anchor.y = scale_i16(c.exit_glyph_anchor.y);
+                    c.entry_glyph_anchor.x = scale_i16(c.entry_glyph_anchor.x);
+                    c.entry_glyph_anchor.y = scale_i16(c.entry_glyph_anchor.y);
+                },
+            }
+            info
+        }));
+
+        run_start = run_end;
     }
+
+    ShapedTextBufferUnsized { infos }
 }
 
 /// Positions the words on the screen (does not layout any glyph positions!), necessary for estimating
 /// the intrinsic width + height of the text content.
+///
+/// Lines are broken in the logical order of the words, afterwards the words of each line
+/// are reordered into the visual order according to their bidi embedding level.
+///
+/// Lines are broken after whitespace and at the break opportunities between words
+/// (`Word::break_before`). Emergency break opportunities (`overflow-wrap`) are only
+/// used if the text between two regular break opportunities is wider than the line.
 pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_options: &ResolvedTextLayoutOptions) -> WordPositions {
 
     use self::WordType::*;
@@ -193,6 +529,8 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
     let line_height_px = space_advance_px * text_layout_options.line_height.as_ref().copied().unwrap_or(DEFAULT_LINE_HEIGHT);
     let tab_width_px = space_advance_px * text_layout_options.tab_width.as_ref().copied().unwrap_or(DEFAULT_TAB_WIDTH);
     let spacing_multiplier = text_layout_options.letter_spacing.as_ref().copied().unwrap_or(0.0);
+    let hyphen_width_px = shaped_words.get_hyphen_width_px(text_layout_options.font_size_px);
+    let max_horizontal_width = text_layout_options.max_horizontal_width.as_ref().copied();
 
     let mut line_breaks = Vec::new();
     let mut word_positions = Vec::new();
@@ -201,9 +539,61 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
     let mut shaped_word_idx = 0;
     let mut last_shaped_word_word_idx = 0;
     let mut last_line_start_idx = 0;
+    let mut emergency_breaks_active = false;
 
     let last_word_idx = words.items.len().saturating_sub(1);
 
+    let base_level = bidi::get_base_level(text_layout_options.direction);
+    let bidi_levels = get_bidi_levels(words.internal_chars.as_ref(), text_layout_options.direction);
+    let get_bidi_level = |word: &self::Word| -> BidiLevel {
+        match word.word_type {
+            // tabs and returns are always on the paragraph level (L1)
+            Tab | Return => base_level,
+            Word | Space => bidi_levels.as_ref()
+                .and_then(|levels| levels.get(word.start).copied())
+                .unwrap_or(base_level),
+        }
+    };
+
+    let get_shaped_word_width = |shaped_word: &ShapedWord| -> f32 {
+        let letter_spacing_px = spacing_multiplier * shaped_word
+        .number_of_glyphs().saturating_sub(1) as f32;
+        shaped_word.get_word_width(
+            shaped_words.font_metrics_units_per_em,
+            text_layout_options.font_size_px
+        ) + letter_spacing_px
+    };
+
+    // Width of the words from `word_idx` until the next break opportunity and whether
+    // a hyphen has to be inserted if the line is broken at that break opportunity
+    let get_segment_width = |word_idx: usize, shaped_word_idx: usize, include_emergency_breaks: bool| -> (f32, bool) {
+
+        let mut width = match shaped_words.items.get(shaped_word_idx) {
+            Some(s) => get_shaped_word_width(s),
+            None => return (0.0, false),
+        };
+
+        let mut next_shaped_word_idx = shaped_word_idx + 1;
+        for next_word in words.items.as_ref()[(word_idx + 1)..].iter() {
+            if next_word.word_type != WordType::Word {
+                break;
+            }
+            match next_word.break_before {
+                LineBreakOpportunity::None => { },
+                LineBreakOpportunity::Emergency if !include_emergency_breaks => { },
+                LineBreakOpportunity::Hyphenated => return (width, true),
+                _ => return (width, false),
+            }
+            match shaped_words.items.get(next_shaped_word_idx) {
+                Some(s) => width += spacing_multiplier + get_shaped_word_width(s),
+                None => break,
+            }
+            next_shaped_word_idx += 1;
+        }
+
+        (width, false)
+    };
+
     // The last word is a bit special: Any text must have at least one line break!
     for (word_idx, word) in words.items.iter().enumerate() {
         match word.word_type {
@@ -215,19 +605,56 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
                     None => continue,
                 };
 
-                let letter_spacing_px = spacing_multiplier * shaped_word
-                .number_of_glyphs().saturating_sub(1) as f32;
-
                 // Calculate where the caret would be for the next word
-                let shaped_word_width = shaped_word.get_word_width(
-                    shaped_words.font_metrics_units_per_em,
-                    text_layout_options.font_size_px
-                ) + letter_spacing_px;
+                let shaped_word_width = get_shaped_word_width(shaped_word);
+
+                let follows_word = word_idx > 0 && words.items.as_ref()[word_idx - 1].word_type == WordType::Word;
+
+                // words that follow whitespace can always be broken onto a new line
+                let break_before = if follows_word { word.break_before } else { LineBreakOpportunity::Allowed };
+
+                // overflow-wrap: only break at emergency break opportunities
+                // if the text can't be broken at a regular break opportunity
+                if matches!(break_before, LineBreakOpportunity::Allowed | LineBreakOpportunity::Hyphenated) {
+                    let (regular_segment_width, _) = get_segment_width(word_idx, shaped_word_idx, false);
+                    emergency_breaks_active = max_horizontal_width.map(|max| regular_segment_width > max).unwrap_or(false);
+                }
+
+                let can_break = match break_before {
+                    LineBreakOpportunity::None => false,
+                    LineBreakOpportunity::Emergency => emergency_breaks_active,
+                    LineBreakOpportunity::Allowed | LineBreakOpportunity::Hyphenated => true,
+                };
+
+                if follows_word {
+                    line_caret_x += spacing_multiplier;
+                }
+
+                if !can_break {
+                    // words that are not separated by a break opportunity (i.e. split
+                    // because of a change in the text direction) can't be broken onto a new line
+                    word_positions.push(WordPosition {
+                        shaped_word_index: Some(shaped_word_idx),
+                        position: LogicalPosition::new(line_caret_x, line_caret_y),
+                        size: LogicalSize::new(shaped_word_width, font_size_px + line_height_px),
+                        bidi_level: get_bidi_level(word),
+                        hyphenated: false,
+                    });
+                    line_caret_x += shaped_word_width;
+                    shaped_word_idx += 1;
+                    last_shaped_word_word_idx = word_idx;
+                    continue;
+                }
+
+                // Width of all words until the next break opportunity (+ the hyphen
+                // that has to be inserted if the line is broken there)
+                let (unbreakable_width, needs_hyphen) = get_segment_width(word_idx, shaped_word_idx, emergency_breaks_active);
+                let unbreakable_width = if needs_hyphen { unbreakable_width + hyphen_width_px } else { unbreakable_width };
 
                 // Determine if a line break is necessary
                 let caret_intersection = LineCaretIntersection::new(
                     line_caret_x,
-                    shaped_word_width,
+                    unbreakable_width,
                     line_caret_y,
                     font_size_px + line_height_px,
                     text_layout_options.max_horizontal_width.as_ref().copied(),
@@ -235,16 +662,32 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
 
                 // Correct and advance the line caret position
                 match caret_intersection {
-                    NoLineBreak { new_x, new_y } => {
+                    NoLineBreak { new_x: _, new_y } => {
                         word_positions.push(WordPosition {
                             shaped_word_index: Some(shaped_word_idx),
                             position: LogicalPosition::new(line_caret_x, line_caret_y),
                             size: LogicalSize::new(shaped_word_width, font_size_px + line_height_px),
+                            bidi_level: get_bidi_level(word),
+                            hyphenated: false,
                         });
-                        line_caret_x = new_x;
+                        line_caret_x += shaped_word_width;
                         line_caret_y = new_y;
                     },
                     LineBreak { new_x, new_y } => {
+
+                        if follows_word {
+                            // the letter spacing is not added at the end of the line
+                            line_caret_x -= spacing_multiplier;
+                        }
+
+                        // the line is broken inside of a word: insert a hyphen at the end of the line
+                        if follows_word && break_before == LineBreakOpportunity::Hyphenated {
+                            if let Some(last_word_position) = word_positions.last_mut() {
+                                last_word_position.hyphenated = true;
+                            }
+                            line_caret_x += hyphen_width_px;
+                        }
+
                         // push the line break first
                         line_breaks.push(InlineTextLine {
                             word_start: last_line_start_idx,
@@ -260,6 +703,8 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
                             shaped_word_index: Some(shaped_word_idx),
                             position: LogicalPosition::new(new_x, new_y),
                             size: LogicalSize::new(shaped_word_width, font_size_px + line_height_px),
+                            bidi_level: get_bidi_level(word),
+                            hyphenated: false,
                         });
                         line_caret_x = new_x + shaped_word_width; // add word width for the next word
                         line_caret_y = new_y;
@@ -286,6 +731,8 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
                     shaped_word_index: None,
                     position: LogicalPosition::new(line_caret_x, line_caret_y),
                     size: LogicalSize::new(0.0, font_size_px + line_height_px),
+                    bidi_level: get_bidi_level(word),
+                    hyphenated: false,
                 });
                 if word_idx != last_word_idx {
                     line_caret_x = 0.0;
@@ -313,6 +760,8 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
                             shaped_word_index: None,
                             position: LogicalPosition::new(line_caret_x, line_caret_y),
                             size: LogicalSize::new(x_advance, font_size_px + line_height_px),
+                            bidi_level: get_bidi_level(word),
+                            hyphenated: false,
                         });
                         line_caret_x = new_x;
                         line_caret_y = new_y;
@@ -334,6 +783,8 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
                             shaped_word_index: None,
                             position: LogicalPosition::new(line_caret_x, line_caret_y),
                             size: LogicalSize::new(x_advance, font_size_px + line_height_px),
+                            bidi_level: get_bidi_level(word),
+                            hyphenated: false,
                         });
                         if word_idx != last_word_idx {
                             line_caret_x = new_x; // don't add the space width here when pushing onto new line
@@ -354,6 +805,12 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
         ),
     });
 
+    if bidi_levels.is_some() {
+        for line in line_breaks.iter() {
+            reorder_line(words.items.as_ref(), &mut word_positions, line, base_level);
+        }
+    }
+
     let longest_line_width = line_breaks.iter()
     .map(|line| line.bounds.size.width)
     .fold(0.0_f32, f32::max);
@@ -373,6 +830,68 @@ pub fn position_words(words: &Words, shaped_words: &ShapedWords, text_layout_opt
     }
 }
 
+/// Reorders the words of a single line from the logical into the visual order (rules L1 and L2)
+/// by reassigning the horizontal positions of the words - the line width does not change.
+fn reorder_line(
+    words: &[Word],
+    word_positions: &mut [azul_core::app_resources::WordPosition],
+    line: &azul_core::ui_solver::InlineTextLine,
+    base_level: BidiLevel,
+) {
+
+    let line_end = (line.word_end + 1).min(words.len()).min(word_positions.len());
+    if line.word_start >= line_end {
+        return;
+    }
+
+    // whitespace that overflowed the previous line is still positioned on the previous line
+    let items = (line.word_start..line_end)
+        .filter(|i| word_positions[*i].position.y == line.bounds.origin.y)
+        .collect::<Vec<usize>>();
+
+    if items.is_empty() {
+        return;
+    }
+
+    // L1: whitespace at the end of the line and before tabs is on the paragraph level
+    let mut reset_whitespace = true;
+    for i in items.iter().rev() {
+        match words[*i].word_type {
+            WordType::Tab | WordType::Return => {
+                word_positions[*i].bidi_level = base_level;
+                reset_whitespace = true;
+            },
+            WordType::Space if reset_whitespace => {
+                word_positions[*i].bidi_level = base_level;
+            },
+            _ => {
+                reset_whitespace = false;
+            },
+        }
+    }
+
+    let levels = items.iter().map(|i| word_positions[*i].bidi_level).collect::<Vec<BidiLevel>>();
+    let visual_order = bidi::reorder_visual(&levels);
+
+    if visual_order.iter().enumerate().all(|(visual, logical)| visual == *logical) {
+        return;
+    }
+
+    // horizontal advance of every word, including the spacing to the next word
+    let advances = items.iter().enumerate().map(|(k, i)| {
+        match items.get(k + 1) {
+            Some(next) => word_positions[*next].position.x - word_positions[*i].position.x,
+            None => word_positions[*i].size.width,
+        }
+    }).collect::<Vec<f32>>();
+
+    let mut x = word_positions[items[0]].position.x;
+    for logical in visual_order {
+        word_positions[items[logical]].position.x = x;
+        x += advances[logical];
+    }
+}
+
 /// Returns the (left-aligned!) bounding boxes of the indidividual text lines
 pub fn word_positions_to_inline_text_layout(word_positions: &WordPositions) -> InlineTextLayout {
     InlineTextLayout {
@@ -434,8 +953,8 @@ pub fn shape_text(font: &FontRef, text: &str, options: &ResolvedTextLayoutOption
     let font_data = font.get_data();
     let parsed_font_downcasted = unsafe { &*(font_data.parsed as *const ParsedFont) };
 
-    let words = split_text_into_words(text);
-    let shaped_words = shape_words(&words, parsed_font_downcasted);
+    let words = split_text_into_words(text, options.direction, options.word_break, options.overflow_wrap, options.hyphens);
+    let shaped_words = shape_words(&words, parsed_font_downcasted, &[], options.direction);
     let word_positions = position_words(&words, &shaped_words, options);
     let inline_text_layout = word_positions_to_inline_text_layout(&word_positions);
 
@@ -475,52 +994,112 @@ fn test_split_words() {
     }
 
     let ascii_str = String::from("abc\tdef  \nghi\r\njkl");
-    let words_ascii = split_text_into_words(&ascii_str);
+    let words_ascii = split_text_into_words(&ascii_str, StyleDirection::Ltr, StyleWordBreak::Normal, StyleOverflowWrap::Normal, StyleHyphens::Manual);
     let words_ascii_expected = Words {
         internal_str: ascii_str.clone(),
         internal_chars: string_to_vec(ascii_str),
         items: vec![
-            Word { start: 0,    end: 3,     word_type: WordType::Word     }, // "abc" - (0..3) = Word
-            Word { start: 3,    end: 4,     word_type: WordType::Tab      }, // "\t" - (3..4) = Tab
-            Word { start: 4,    end: 7,     word_type: WordType::Word     }, // "def" - (4..7) = Word
-            Word { start: 7,    end: 8,     word_type: WordType::Space    }, // " " - (7..8) = Space
-            Word { start: 8,    end: 9,     word_type: WordType::Space    }, // " " - (8..9) = Space
-            Word { start: 9,    end: 10,    word_type: WordType::Return   }, // "\n" - (9..10) = Return
-            Word { start: 10,   end: 13,    word_type: WordType::Word     }, // "ghi" - (10..13) = Word
-            Word { start: 13,   end: 15,    word_type: WordType::Return   }, // "\r\n" - (13..15) = Return
-            Word { start: 15,   end: 18,    word_type: WordType::Word     }, // "jkl" - (15..18) = Word
+            Word { start: 0,    end: 3,     word_type: WordType::Word,     break_before: LineBreakOpportunity::Allowed }, // "abc" - (0..3) = Word
+            Word { start: 3,    end: 4,     word_type: WordType::Tab,      break_before: LineBreakOpportunity::Allowed }, // "\t" - (3..4) = Tab
+            Word { start: 4,    end: 7,     word_type: WordType::Word,     break_before: LineBreakOpportunity::Allowed }, // "def" - (4..7) = Word
+            Word { start: 7,    end: 8,     word_type: WordType::Space,    break_before: LineBreakOpportunity::Allowed }, // " " - (7..8) = Space
+            Word { start: 8,    end: 9,     word_type: WordType::Space,    break_before: LineBreakOpportunity::Allowed }, // " " - (8..9) = Space
+            Word { start: 9,    end: 10,    word_type: WordType::Return,   break_before: LineBreakOpportunity::Allowed }, // "\n" - (9..10) = Return
+            Word { start: 10,   end: 13,    word_type: WordType::Word,     break_before: LineBreakOpportunity::Allowed }, // "ghi" - (10..13) = Word
+            Word { start: 13,   end: 15,    word_type: WordType::Return,   break_before: LineBreakOpportunity::Allowed }, // "\r\n" - (13..15) = Return
+            Word { start: 15,   end: 18,    word_type: WordType::Word,     break_before: LineBreakOpportunity::Allowed }, // "jkl" - (15..18) = Word
         ],
     };
 
     assert_words(&words_ascii_expected, &words_ascii);
 
     let unicode_str = String::from("㌊㌋㌌㌍㌎㌏㌐㌑ ㌒㌓㌔㌕㌖㌗");
-    let words_unicode = split_text_into_words(&unicode_str);
+    // word-break: keep-all, otherwise the line can be broken between the ideographs
+    let words_unicode = split_text_into_words(&unicode_str, StyleDirection::Ltr, StyleWordBreak::KeepAll, StyleOverflowWrap::Normal, StyleHyphens::Manual);
     let words_unicode_expected = Words {
         internal_str: unicode_str.clone(),
         internal_chars: string_to_vec(unicode_str),
         items: vec![
-            Word { start: 0,        end: 8,         word_type: WordType::Word   }, // "㌊㌋㌌㌍㌎㌏㌐㌑"
-            Word { start: 8,        end: 9,         word_type: WordType::Space  }, // " "
-            Word { start: 9,        end: 15,        word_type: WordType::Word   }, // "㌒㌓㌔㌕㌖㌗"
+            Word { start: 0,        end: 8,         word_type: WordType::Word,   break_before: LineBreakOpportunity::Allowed }, // "㌊㌋㌌㌍㌎㌏㌐㌑"
+            Word { start: 8,        end: 9,         word_type: WordType::Space,  break_before: LineBreakOpportunity::Allowed }, // " "
+            Word { start: 9,        end: 15,        word_type: WordType::Word,   break_before: LineBreakOpportunity::Allowed }, // "㌒㌓㌔㌕㌖㌗"
         ],
     };
 
     assert_words(&words_unicode_expected, &words_unicode);
 
     let single_str = String::from("A");
-    let words_single_str = split_text_into_words(&single_str);
+    let words_single_str = split_text_into_words(&single_str, StyleDirection::Ltr, StyleWordBreak::Normal, StyleOverflowWrap::Normal, StyleHyphens::Manual);
     let words_single_str_expected = Words {
         internal_str: single_str.clone(),
         internal_chars: string_to_vec(single_str),
         items: vec![
-            Word { start: 0,        end: 1,         word_type: WordType::Word   }, // "A"
+            Word { start: 0,        end: 1,         word_type: WordType::Word,   break_before: LineBreakOpportunity::Allowed }, // "A"
         ],
     };
 
     assert_words(&words_single_str_expected, &words_single_str);
 }
 
+#[test]
+fn test_split_words_at_break_opportunities() {
+
+    fn get_words(s: &str, word_break: StyleWordBreak, overflow_wrap: StyleOverflowWrap, hyphens: StyleHyphens) -> Vec<(String, LineBreakOpportunity)> {
+        let words = split_text_into_words(s, StyleDirection::Ltr, word_break, overflow_wrap, hyphens);
+        words.items.iter().map(|w| (words.get_substr(w), w.break_before)).collect()
+    }
+
+    use self::LineBreakOpportunity::*;
+
+    // ideographs and URLs are split at the UAX #14 break opportunities
+    assert_eq!(get_words("\u{4e00}\u{4e8c}", StyleWordBreak::Normal, StyleOverflowWrap::Normal, StyleHyphens::Manual), vec![
+        ("\u{4e00}".to_string(), Allowed),
+        ("\u{4e8c}".to_string(), Allowed),
+    ]);
+    assert_eq!(get_words("a.com/b", StyleWordBreak::Normal, StyleOverflowWrap::Normal, StyleHyphens::Manual), vec![
+        ("a.com/".to_string(), Allowed),
+        ("b".to_string(), Allowed),
+    ]);
+
+    // soft hyphens are hyphenated break opportunities, unless hyphens: none
+    assert_eq!(get_words("ab\u{ad}cd", StyleWordBreak::Normal, StyleOverflowWrap::Normal, StyleHyphens::Manual), vec![
+        ("ab\u{ad}".to_string(), Allowed),
+        ("cd".to_string(), Hyphenated),
+    ]);
+    assert_eq!(get_words("ab\u{ad}cd", StyleWordBreak::Normal, StyleOverflowWrap::Normal, StyleHyphens::None), vec![
+        ("ab\u{ad}cd".to_string(), Allowed),
+    ]);
+
+    // overflow-wrap: anywhere adds emergency break opportunities between all characters
+    assert_eq!(get_words("abc", StyleWordBreak::Normal, StyleOverflowWrap::Anywhere, StyleHyphens::Manual), vec![
+        ("a".to_string(), Allowed),
+        ("b".to_string(), Emergency),
+        ("c".to_string(), Emergency),
+    ]);
+}
+
+#[test]
+fn test_shape_font_runs_with_fallback_font() {
+
+    let latin_font = parse_font(include_bytes!("../../examples/assets/fonts/SourceSerifPro-Regular.ttf"), 0, false).unwrap();
+    let thai_font = parse_font(include_bytes!("../../examples/assets/fonts/KoHo-Light.ttf"), 0, false).unwrap();
+
+    // U+0E01 THAI CHARACTER KO KAI, U+0E48 THAI CHARACTER MAI EK (combining mark)
+    let chars = ['a' as u32, 0x0E01, 0x0E48, 'b' as u32];
+
+    // Latin letters are taken from the primary font, even if the fallback font contains them
+    assert_eq!(get_font_indices(&chars, &latin_font, &[&thai_font]), vec![0, 1, 1, 0]);
+    // characters that no font contains are shaped with the primary font
+    assert_eq!(get_font_indices(&chars, &latin_font, &[]), vec![0, 0, 0, 0]);
+
+    let shaped = shape_font_runs(&chars, &latin_font, &[&thai_font], 0, None);
+    let font_indices = shaped.infos.iter().map(|i| i.font_index).collect::<Vec<_>>();
+    assert_eq!(font_indices.first(), Some(&0));
+    assert_eq!(font_indices.last(), Some(&0));
+    assert!(font_indices.contains(&1));
+    assert!(shaped.infos.iter().all(|i| i.glyph.glyph_index != 0));
+}
+
 // Scenario 1:
 //
 // +---------+
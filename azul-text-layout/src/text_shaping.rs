@@ -788,6 +788,7 @@ fn translate_info(i: &allsorts::gpos::Info, size: Advance) -> GlyphInfo {
         size,
         kerning: i.kerning,
         placement: translate_placement(&i.placement),
+        font_index: 0,
     }
 }
 
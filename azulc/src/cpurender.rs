@@ -0,0 +1,1908 @@
+//! Headless CPU renderer: rasterizes a `CachedDisplayList` into a `RawImage`
+//! without a GPU or a window, using `tiny-skia` for the rasterization and
+//! `ttf-parser` for the glyph outlines.
+//!
+//! The renderer follows the same rules as the WebRender translation in
+//! `azul-desktop/src/wr_translate.rs`: every frame is positioned relative to
+//! its parent frame, frames with `overflow: hidden` clip their children and
+//! the content of a frame is pushed in the order of the display list.
+
+use alloc::collections::btree_map::BTreeMap;
+use alloc::vec::Vec;
+use azul_core::{
+    app_resources::{
+        DecodedImage, FontInstanceKey, ImageCache, ImageData, ImageKey,
+        ImageRef, ImageRefHash, RawImage, RawImageData, RawImageFormat,
+        RendererResources, ResolvedImage,
+    },
+    display_list::{
+        BoxShadow, CachedDisplayList, DisplayListFrame, DisplayListImageMask,
+        DisplayListMsg, GlyphInstance, LayoutRectContent, RectBackground,
+        StyleBorderColors, StyleBorderRadius, StyleBorderStyles, StyleBorderWidths,
+    },
+    ui_solver::{ComputedTransform3D, LayoutResult, PositionInfo},
+    window::{LogicalPosition, LogicalRect, LogicalSize},
+};
+use azul_css::{
+    BorderStyle, BoxShadowClipMode, ColorU, ConicGradient, CssPropertyValue, ExtendMode,
+    LinearGradient, RadialGradient, StyleBackgroundPosition, StyleBackgroundRepeat,
+    StyleBackgroundSize, StyleBoxShadow, StyleMixBlendMode,
+};
+use tiny_skia::{
+    BlendMode, ClipMask, Color, FillRule, FilterQuality, GradientStop, Paint, Path,
+    LinearGradient as SkLinearGradient, RadialGradient as SkRadialGradient,
+    PathBuilder, Pattern, Pixmap, PixmapPaint, Point, Rect, Shader, SpreadMode,
+    Stroke, StrokeDash, Transform,
+};
+use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+/// Magic constant to approximate a quarter circle with a cubic bezier curve
+const KAPPA: f32 = 0.552_284_8;
+
+/// Renders the display list into an RGBA8 image (non-premultiplied alpha)
+///
+/// The size of the output image is the `root_size` of the display list.
+/// Fonts are resolved via the `FontInstanceKey`s registered in the `renderer_resources`.
+/// Images are resolved via the `ImageKey`s registered in the `renderer_resources`, the
+/// image data is taken from the `image_cache` (CSS backgrounds) and the DOM nodes of
+/// the `layout_results` (image nodes and clip masks).
+///
+/// Returns `None` if the display list has a zero-sized root.
+pub fn render_display_list(
+    display_list: &CachedDisplayList,
+    renderer_resources: &RendererResources,
+    image_cache: &ImageCache,
+    layout_results: &[LayoutResult],
+    background_color: ColorU,
+) -> Option<RawImage> {
+
+    let width = display_list.root_size.width.round().max(0.0) as u32;
+    let height = display_list.root_size.height.round().max(0.0) as u32;
+
+    let mut pixmap = Pixmap::new(width, height)?;
+    pixmap.fill(translate_color(background_color));
+
+    let mut renderer = CpuRenderer {
+        renderer_resources,
+        images: collect_images(renderer_resources, image_cache, layout_results),
+        fonts: BTreeMap::new(),
+        width,
+        height,
+    };
+
+    let root = DrawState { transform: Transform::identity(), clip: Clip::Unclipped };
+    let mut positioned_items = Vec::new();
+    renderer.render_msg(&mut pixmap, &display_list.root, &root, &root, &mut positioned_items);
+
+    Some(pixmap_to_raw_image(pixmap))
+}
+
+/// Converts the premultiplied pixmap into a non-premultiplied RGBA8 image
+fn pixmap_to_raw_image(pixmap: Pixmap) -> RawImage {
+
+    let width = pixmap.width() as usize;
+    let height = pixmap.height() as usize;
+    let mut pixels = pixmap.take();
+
+    for px in pixels.chunks_exact_mut(4) {
+        let a = px[3] as u32;
+        if a != 0 && a != 255 {
+            px[0] = ((px[0] as u32 * 255 + a / 2) / a).min(255) as u8;
+            px[1] = ((px[1] as u32 * 255 + a / 2) / a).min(255) as u8;
+            px[2] = ((px[2] as u32 * 255 + a / 2) / a).min(255) as u8;
+        }
+    }
+
+    RawImage {
+        pixels: RawImageData::U8(pixels.into()),
+        width,
+        height,
+        premultiplied_alpha: false,
+        data_format: RawImageFormat::RGBA8,
+    }
+}
+
+/// Collects all images that the display list could reference and
+/// decodes them into pixmaps, keyed by their `ImageKey`
+fn collect_images(
+    renderer_resources: &RendererResources,
+    image_cache: &ImageCache,
+    layout_results: &[LayoutResult],
+) -> BTreeMap<ImageKey, Pixmap> {
+
+    use azul_core::dom::NodeType;
+
+    let mut image_refs = BTreeMap::<ImageRefHash, &ImageRef>::new();
+
+    for image_ref in image_cache.image_id_map.values() {
+        image_refs.insert(image_ref.get_hash(), image_ref);
+    }
+
+    for layout_result in layout_results.iter() {
+        for node_data in layout_result.styled_dom.node_data.as_ref().iter() {
+            if let NodeType::Image(image_ref) = node_data.get_node_type() {
+                image_refs.insert(image_ref.get_hash(), image_ref);
+            }
+            if let Some(clip_mask) = node_data.get_clip_mask() {
+                image_refs.insert(clip_mask.image.get_hash(), &clip_mask.image);
+            }
+        }
+    }
+
+    image_refs.into_iter().filter_map(|(hash, image_ref)| {
+        let ResolvedImage { key, .. } = renderer_resources.get_image(&hash)?;
+        Some((*key, decoded_image_to_pixmap(image_ref.get_data())?))
+    }).collect()
+}
+
+/// Converts the decoded (BGRA8 or R8, premultiplied) image bytes into a pixmap
+fn decoded_image_to_pixmap(image: &DecodedImage) -> Option<Pixmap> {
+
+    let (descriptor, bytes) = match image {
+        DecodedImage::Raw((descriptor, ImageData::Raw(bytes))) => (descriptor, bytes),
+        _ => return None, // GL textures and callbacks can't be read back on the CPU
+    };
+
+    let mut pixmap = Pixmap::new(descriptor.width as u32, descriptor.height as u32)?;
+    let src = bytes.as_ref();
+
+    match descriptor.format {
+        RawImageFormat::BGRA8 => {
+            for (dst, src) in pixmap.data_mut().chunks_exact_mut(4).zip(src.chunks_exact(4)) {
+                dst[0] = src[2];
+                dst[1] = src[1];
+                dst[2] = src[0];
+                dst[3] = src[3];
+            }
+        },
+        RawImageFormat::RGBA8 => {
+            for (dst, src) in pixmap.data_mut().chunks_exact_mut(4).zip(src.chunks_exact(4)) {
+                dst.copy_from_slice(src);
+            }
+        },
+        RawImageFormat::R8 => {
+            for (dst, src) in pixmap.data_mut().chunks_exact_mut(4).zip(src.iter()) {
+                dst[0] = *src;
+                dst[1] = *src;
+                dst[2] = *src;
+                dst[3] = 0xff;
+            }
+        },
+        _ => return None,
+    }
+
+    Some(pixmap)
+}
+
+/// Current clip of a frame, in device pixels
+#[derive(Clone)]
+enum Clip {
+    /// Nothing is clipped
+    Unclipped,
+    /// Only pixels inside of the mask are drawn
+    Mask(ClipMask),
+    /// Everything is clipped (e.g. overflow:hidden on a zero-sized rect)
+    Empty,
+}
+
+impl Clip {
+
+    fn get_mask(&self) -> Option<&ClipMask> {
+        match self {
+            Clip::Mask(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Clip::Empty => true,
+            _ => false,
+        }
+    }
+}
+
+/// Transform + clip of the origin of the current frame
+#[derive(Clone)]
+struct DrawState {
+    transform: Transform,
+    clip: Clip,
+}
+
+/// Resolved border radii of a rectangle in pixels, (horizontal, vertical)
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+struct BorderRadii {
+    top_left: (f32, f32),
+    top_right: (f32, f32),
+    bottom_right: (f32, f32),
+    bottom_left: (f32, f32),
+}
+
+impl BorderRadii {
+
+    fn new(border_radius: &StyleBorderRadius, rect_size: LogicalSize) -> Self {
+
+        let StyleBorderRadius { top_left, top_right, bottom_left, bottom_right } = border_radius;
+
+        let w = rect_size.width;
+        let h = rect_size.height;
+
+        // percentages are resolved against the width / height of the rect, i.e. "border-radius: 50%;"
+        let top_left = top_left.and_then(|r| r.get_property_or_default()).unwrap_or_default().inner;
+        let top_right = top_right.and_then(|r| r.get_property_or_default()).unwrap_or_default().inner;
+        let bottom_left = bottom_left.and_then(|r| r.get_property_or_default()).unwrap_or_default().inner;
+        let bottom_right = bottom_right.and_then(|r| r.get_property_or_default()).unwrap_or_default().inner;
+
+        Self {
+            top_left: (top_left.to_pixels(w), top_left.to_pixels(h)),
+            top_right: (top_right.to_pixels(w), top_right.to_pixels(h)),
+            bottom_right: (bottom_right.to_pixels(w), bottom_right.to_pixels(h)),
+            bottom_left: (bottom_left.to_pixels(w), bottom_left.to_pixels(h)),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == BorderRadii::default()
+    }
+
+    /// Grows (or shrinks, if `amount` is negative) the radii, used for
+    /// the spread radius of box shadows and the inner edge of borders
+    fn adjust(&self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        fn adjust_corner(r: (f32, f32), h: f32, v: f32) -> (f32, f32) {
+            if r.0 <= 0.0 || r.1 <= 0.0 {
+                (0.0, 0.0)
+            } else {
+                ((r.0 + h).max(0.0), (r.1 + v).max(0.0))
+            }
+        }
+        Self {
+            top_left: adjust_corner(self.top_left, left, top),
+            top_right: adjust_corner(self.top_right, right, top),
+            bottom_right: adjust_corner(self.bottom_right, right, bottom),
+            bottom_left: adjust_corner(self.bottom_left, left, bottom),
+        }
+    }
+}
+
+struct CpuRenderer<'a> {
+    renderer_resources: &'a RendererResources,
+    /// Decoded images, premultiplied RGBA
+    images: BTreeMap<ImageKey, Pixmap>,
+    /// Parsed fonts + font size in pixels, cached per font instance
+    fonts: BTreeMap<FontInstanceKey, Option<(Face<'a>, f32)>>,
+    width: u32,
+    height: u32,
+}
+
+impl<'a> CpuRenderer<'a> {
+
+    fn render_msg(
+        &mut self,
+        target: &mut Pixmap,
+        msg: &DisplayListMsg,
+        parent: &DrawState,
+        root: &DrawState,
+        positioned_items: &mut Vec<DrawState>,
+    ) {
+
+        let msg_position = msg.get_position();
+
+        let (base, x, y) = match &msg_position {
+            PositionInfo::Static(p) | PositionInfo::Relative(p) => (parent.clone(), p.x_offset, p.y_offset),
+            PositionInfo::Absolute(p) => (positioned_items.last().cloned().unwrap_or(root.clone()), p.x_offset, p.y_offset),
+            PositionInfo::Fixed(p) => (root.clone(), p.x_offset, p.y_offset),
+        };
+
+        let mut transform = base.transform.pre_translate(x, y);
+        if let Some((_, t)) = msg.get_transform_key() {
+            transform = transform.pre_concat(translate_transform(t));
+        }
+
+        let state = DrawState { transform, clip: base.clip };
+
+        // opacity, mix-blend-mode and image masks are applied
+        // to the whole frame, so the frame is drawn into a layer first
+        let opacity = msg.get_opacity_key().map(|(_, o)| *o);
+        let mix_blend_mode = msg.get_mix_blend_mode().copied().unwrap_or_default();
+        let image_mask = msg.get_image_mask().copied();
+        let needs_layer = opacity.is_some() || mix_blend_mode != StyleMixBlendMode::Normal || image_mask.is_some();
+
+        if msg_position.is_positioned() {
+            positioned_items.push(state.clone());
+        }
+
+        if needs_layer {
+            if let Some(mut layer) = Pixmap::new(self.width, self.height) {
+                self.render_msg_content(&mut layer, msg, &state, root, positioned_items);
+                if let Some(image_mask) = image_mask.as_ref() {
+                    self.apply_image_mask(&mut layer, image_mask, state.transform);
+                }
+                let paint = PixmapPaint {
+                    opacity: opacity.unwrap_or(1.0).max(0.0).min(1.0),
+                    blend_mode: translate_mix_blend_mode(mix_blend_mode),
+                    quality: FilterQuality::Nearest,
+                };
+                target.draw_pixmap(0, 0, layer.as_ref(), &paint, Transform::identity(), None);
+            }
+        } else {
+            self.render_msg_content(target, msg, &state, root, positioned_items);
+        }
+
+        if msg_position.is_positioned() {
+            positioned_items.pop();
+        }
+    }
+
+    fn render_msg_content(
+        &mut self,
+        target: &mut Pixmap,
+        msg: &DisplayListMsg,
+        state: &DrawState,
+        root: &DrawState,
+        positioned_items: &mut Vec<DrawState>,
+    ) {
+        match msg {
+            DisplayListMsg::IFrame(_, iframe_clip_size, _, cached_display_list) => {
+                let clip_rect = LogicalRect::new(LogicalPosition::zero(), *iframe_clip_size);
+                let iframe_state = DrawState {
+                    transform: state.transform,
+                    clip: self.intersect_clip(&state.clip, build_rect_path(clip_rect), state.transform),
+                };
+                // positioned items inside of the iframe don't escape the iframe
+                let mut iframe_positioned_items = Vec::new();
+                self.render_msg(target, &cached_display_list.root, &iframe_state, &iframe_state, &mut iframe_positioned_items);
+            },
+            DisplayListMsg::Frame(f) => {
+                let content_clip = self.render_frame(target, f, state);
+                let children_state = DrawState {
+                    transform: state.transform,
+                    clip: if f.clip_children.is_some() { content_clip } else { state.clip.clone() },
+                };
+                for child in f.children.iter() {
+                    self.render_msg(target, child, &children_state, root, positioned_items);
+                }
+            },
+            DisplayListMsg::ScrollFrame(sf) => {
+                // only children are scrolled and clipped, not the frame itself
+                let content_clip = self.render_frame(target, &sf.frame, state);
+                let scroll_clip_rect = LogicalRect::new(LogicalPosition::zero(), sf.parent_rect.size);
+                let children_state = DrawState {
+                    transform: state.transform,
+                    clip: self.intersect_clip(&content_clip, build_rect_path(scroll_clip_rect), state.transform),
+                };
+                for child in sf.frame.children.iter() {
+                    self.render_msg(target, child, &children_state, root, positioned_items);
+                }
+            },
+        }
+    }
+
+    /// Draws the box shadow + content of the frame, returns the clip of the
+    /// frame contents (i.e. the rounded rect of the frame)
+    fn render_frame(&mut self, target: &mut Pixmap, frame: &DisplayListFrame, state: &DrawState) -> Clip {
+
+        let bounds = LogicalRect::new(LogicalPosition::zero(), frame.size);
+        let radii = BorderRadii::new(&frame.border_radius, frame.size);
+        let content_clip = self.intersect_clip(&state.clip, build_rounded_rect_path(bounds, radii), state.transform);
+
+        if let Some(box_shadow) = frame.box_shadow.as_ref() {
+            if box_shadow.clip_mode == BoxShadowClipMode::Outset {
+                self.draw_box_shadow(target, box_shadow, BoxShadowClipMode::Outset, bounds, radii, state.transform, &state.clip);
+            }
+        }
+
+        for content in frame.content.iter() {
+            match content {
+                LayoutRectContent::Text { glyphs, font_instance_key, color, overflow, text_shadow, .. } => {
+                    // overflow = (horizontal_visible, vertical_visible)
+                    let clip = if overflow.0 && overflow.1 { &state.clip } else { &content_clip };
+                    if let Some(text_shadow) = text_shadow.as_ref() {
+                        self.draw_text_shadow(target, glyphs, *font_instance_key, text_shadow, state.transform, clip);
+                    }
+                    self.draw_text(target, glyphs, *font_instance_key, *color, state.transform, clip);
+                },
+                LayoutRectContent::Background { content, size, offset, repeat } => {
+                    self.draw_background(target, content, *size, *offset, *repeat, bounds, state.transform, &content_clip);
+                },
+                LayoutRectContent::Image { size, offset, image_key, .. } => {
+                    let image_bounds = LogicalRect::new(
+                        LogicalPosition::new(bounds.origin.x + offset.x, bounds.origin.y + offset.y),
+                        bounds.size,
+                    );
+                    self.draw_image(target, *image_key, image_bounds, image_bounds.origin, *size, SpreadMode::Repeat, state.transform, &content_clip);
+                },
+                LayoutRectContent::Border { widths, colors, styles } => {
+                    // no clip necessary because the border is always inside of the rect
+                    self.draw_border(target, bounds, radii, widths, colors, styles, state.transform, &state.clip);
+                },
+            }
+        }
+
+        if let Some(box_shadow) = frame.box_shadow.as_ref() {
+            if box_shadow.clip_mode == BoxShadowClipMode::Inset {
+                self.draw_box_shadow(target, box_shadow, BoxShadowClipMode::Inset, bounds, radii, state.transform, &content_clip);
+            }
+        }
+
+        content_clip
+    }
+
+    /// Intersects the clip with the given path (in frame coordinates)
+    fn intersect_clip(&self, clip: &Clip, path: Option<Path>, transform: Transform) -> Clip {
+
+        let path = match path.and_then(|p| p.transform(transform)) {
+            Some(s) => s,
+            None => return Clip::Empty,
+        };
+
+        match clip {
+            Clip::Empty => Clip::Empty,
+            Clip::Unclipped => {
+                let mut mask = ClipMask::new();
+                match mask.set_path(self.width, self.height, &path, FillRule::Winding, true) {
+                    Some(_) => Clip::Mask(mask),
+                    None => Clip::Empty,
+                }
+            },
+            Clip::Mask(m) => {
+                let mut mask = m.clone();
+                match mask.intersect_path(&path, FillRule::Winding, true) {
+                    Some(_) => Clip::Mask(mask),
+                    None => Clip::Empty,
+                }
+            }
+        }
+    }
+
+    fn draw_text(
+        &mut self,
+        target: &mut Pixmap,
+        glyphs: &[GlyphInstance],
+        font_instance_key: FontInstanceKey,
+        color: ColorU,
+        transform: Transform,
+        clip: &Clip,
+    ) {
+        if clip.is_empty() {
+            return;
+        }
+
+        let path = match self.build_glyphs_path(glyphs, font_instance_key) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let mut paint = Paint::default();
+        paint.set_color(translate_color(color));
+        paint.anti_alias = true;
+
+        target.fill_path(&path, &paint, FillRule::Winding, transform, clip.get_mask());
+    }
+
+    fn draw_text_shadow(
+        &mut self,
+        target: &mut Pixmap,
+        glyphs: &[GlyphInstance],
+        font_instance_key: FontInstanceKey,
+        text_shadow: &StyleBoxShadow,
+        transform: Transform,
+        clip: &Clip,
+    ) {
+        if clip.is_empty() {
+            return;
+        }
+
+        let shadow_transform = transform.pre_translate(
+            text_shadow.offset[0].to_pixels(),
+            text_shadow.offset[1].to_pixels(),
+        );
+
+        let path = match self.build_glyphs_path(glyphs, font_instance_key).and_then(|p| p.transform(shadow_transform)) {
+            Some(s) => s,
+            None => return,
+        };
+
+        fill_blurred_path(target, &path, None, text_shadow.color, text_shadow.blur_radius.to_pixels(), clip);
+    }
+
+    /// Returns the outlines of all glyphs as one path, in frame coordinates
+    fn build_glyphs_path(&mut self, glyphs: &[GlyphInstance], font_instance_key: FontInstanceKey) -> Option<Path> {
+
+        let renderer_resources = self.renderer_resources;
+
+        let (face, font_size_px) = self.fonts.entry(font_instance_key).or_insert_with(|| {
+            let (font_ref, au) = renderer_resources.get_font_instance(&font_instance_key)?;
+            let font_data = font_ref.get_data();
+            let face = Face::from_slice(font_data.bytes.as_ref(), font_data.font_index).ok()?;
+            Some((face, au.into_px()))
+        }).as_ref()?;
+
+        let scale = *font_size_px / face.units_per_em() as f32;
+        let mut path_builder = PathBuilder::new();
+
+        for glyph in glyphs.iter() {
+            let mut glyph_builder = GlyphPathBuilder {
+                path_builder: &mut path_builder,
+                // glyph position = origin of the glyph on the baseline
+                origin_x: glyph.point.x,
+                origin_y: glyph.point.y,
+                scale,
+            };
+            face.outline_glyph(GlyphId(glyph.index as u16), &mut glyph_builder);
+        }
+
+        path_builder.finish()
+    }
+
+    fn draw_background(
+        &mut self,
+        target: &mut Pixmap,
+        background: &RectBackground,
+        background_size: Option<StyleBackgroundSize>,
+        background_position: Option<StyleBackgroundPosition>,
+        background_repeat: Option<StyleBackgroundRepeat>,
+        bounds: LogicalRect,
+        transform: Transform,
+        clip: &Clip,
+    ) {
+        if clip.is_empty() {
+            return;
+        }
+
+        let tile_size = calculate_background_size(bounds.size, background_size, background.get_content_size());
+        if tile_size.width <= 0.0 || tile_size.height <= 0.0 {
+            return;
+        }
+
+        let tile_offset = calculate_background_position(bounds.size, background_position.unwrap_or_default(), tile_size);
+        let tile_origin = LogicalPosition::new(bounds.origin.x + tile_offset.x, bounds.origin.y + tile_offset.y);
+        let tile_rect = LogicalRect::new(tile_origin, tile_size);
+        let background_repeat = background_repeat.unwrap_or_default();
+        let fill_rect = get_background_fill_rect(bounds, tile_rect, background_repeat);
+
+        match background {
+            RectBackground::Color(c) => {
+                let mut paint = Paint::default();
+                paint.set_color(translate_color(*c));
+                paint.anti_alias = true;
+                if let Some(rect) = translate_rect(fill_rect) {
+                    target.fill_rect(rect, &paint, transform, clip.get_mask());
+                }
+            },
+            RectBackground::Image((image_key, _)) => {
+                self.draw_image(target, *image_key, fill_rect, tile_origin, tile_size, SpreadMode::Repeat, transform, clip);
+            },
+            RectBackground::LinearGradient(lg) => {
+                if let Some(tile) = render_linear_gradient_tile(lg, tile_size) {
+                    draw_tile(target, &tile, fill_rect, tile_origin, tile_size, SpreadMode::Repeat, transform, clip);
+                }
+            },
+            RectBackground::RadialGradient(rg) => {
+                if let Some(tile) = render_radial_gradient_tile(rg, tile_size) {
+                    draw_tile(target, &tile, fill_rect, tile_origin, tile_size, SpreadMode::Repeat, transform, clip);
+                }
+            },
+            RectBackground::ConicGradient(cg) => {
+                if let Some(tile) = render_conic_gradient_tile(cg, tile_size) {
+                    draw_tile(target, &tile, fill_rect, tile_origin, tile_size, SpreadMode::Repeat, transform, clip);
+                }
+            },
+        }
+    }
+
+    /// Draws the image scaled to `tile_size`, starting at `tile_origin`, filling the `fill_rect`
+    fn draw_image(
+        &mut self,
+        target: &mut Pixmap,
+        image_key: ImageKey,
+        fill_rect: LogicalRect,
+        tile_origin: LogicalPosition,
+        tile_size: LogicalSize,
+        spread_mode: SpreadMode,
+        transform: Transform,
+        clip: &Clip,
+    ) {
+        if clip.is_empty() {
+            return;
+        }
+
+        if let Some(image) = self.images.get(&image_key) {
+            draw_tile(target, image, fill_rect, tile_origin, tile_size, spread_mode, transform, clip);
+        }
+    }
+
+    /// Multiplies the layer with the alpha channel of the clip mask image
+    fn apply_image_mask(&self, layer: &mut Pixmap, image_mask: &DisplayListImageMask, transform: Transform) {
+
+        let mask_image = match self.images.get(&image_mask.image) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let mut mask = match Pixmap::new(self.width, self.height) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let spread_mode = if image_mask.repeat { SpreadMode::Repeat } else { SpreadMode::Pad };
+        let fill_rect = if image_mask.repeat {
+            LogicalRect::new(LogicalPosition::zero(), LogicalSize::new(self.width as f32, self.height as f32))
+        } else {
+            image_mask.rect
+        };
+
+        draw_tile(&mut mask, mask_image, fill_rect, image_mask.rect.origin, image_mask.rect.size, spread_mode, transform, &Clip::Unclipped);
+
+        for (dst, mask) in layer.data_mut().chunks_exact_mut(4).zip(mask.data().chunks_exact(4)) {
+            let a = mask[3] as u32;
+            dst[0] = ((dst[0] as u32 * a + 127) / 255) as u8;
+            dst[1] = ((dst[1] as u32 * a + 127) / 255) as u8;
+            dst[2] = ((dst[2] as u32 * a + 127) / 255) as u8;
+            dst[3] = ((dst[3] as u32 * a + 127) / 255) as u8;
+        }
+    }
+
+    fn draw_border(
+        &mut self,
+        target: &mut Pixmap,
+        bounds: LogicalRect,
+        radii: BorderRadii,
+        widths: &StyleBorderWidths,
+        colors: &StyleBorderColors,
+        styles: &StyleBorderStyles,
+        transform: Transform,
+        clip: &Clip,
+    ) {
+        if clip.is_empty() {
+            return;
+        }
+
+        let top = widths.top.and_then(|w| w.get_property_or_default()).map(|w| w.inner.to_pixels(bounds.size.height)).unwrap_or(0.0);
+        let right = widths.right.and_then(|w| w.get_property_or_default()).map(|w| w.inner.to_pixels(bounds.size.width)).unwrap_or(0.0);
+        let bottom = widths.bottom.and_then(|w| w.get_property_or_default()).map(|w| w.inner.to_pixels(bounds.size.height)).unwrap_or(0.0);
+        let left = widths.left.and_then(|w| w.get_property_or_default()).map(|w| w.inner.to_pixels(bounds.size.width)).unwrap_or(0.0);
+
+        fn get_style<T: Copy + Default>(s: Option<CssPropertyValue<T>>, f: fn(T) -> BorderStyle) -> BorderStyle {
+            s.and_then(|s| s.get_property_or_default()).map(f).unwrap_or(BorderStyle::None)
+        }
+
+        fn get_color<T: Copy + Default>(c: Option<CssPropertyValue<T>>, f: fn(T) -> ColorU) -> ColorU {
+            c.and_then(|c| c.get_property_or_default()).map(f).unwrap_or_default()
+        }
+
+        let sides = [
+            (BorderSide::Top, top, get_style(styles.top, |s| s.inner), get_color(colors.top, |c| c.inner)),
+            (BorderSide::Right, right, get_style(styles.right, |s| s.inner), get_color(colors.right, |c| c.inner)),
+            (BorderSide::Bottom, bottom, get_style(styles.bottom, |s| s.inner), get_color(colors.bottom, |c| c.inner)),
+            (BorderSide::Left, left, get_style(styles.left, |s| s.inner), get_color(colors.left, |c| c.inner)),
+        ];
+
+        // ring between the outer and the inner edge of the border
+        let inner_rect = LogicalRect::new(
+            LogicalPosition::new(bounds.origin.x + left, bounds.origin.y + top),
+            LogicalSize::new((bounds.size.width - left - right).max(0.0), (bounds.size.height - top - bottom).max(0.0)),
+        );
+        let inner_radii = radii.adjust(-top, -right, -bottom, -left);
+
+        let mut ring = PathBuilder::new();
+        push_rounded_rect(&mut ring, bounds, radii);
+        push_rounded_rect(&mut ring, inner_rect, inner_radii);
+        let ring = match ring.finish().and_then(|p| p.transform(transform)) {
+            Some(s) => s,
+            None => return,
+        };
+
+        let mut ring_clip = match clip {
+            Clip::Empty => return,
+            Clip::Unclipped => ClipMask::new(),
+            Clip::Mask(m) => m.clone(),
+        };
+
+        let ring_clip_ok = match clip {
+            Clip::Unclipped => ring_clip.set_path(self.width, self.height, &ring, FillRule::EvenOdd, true),
+            _ => ring_clip.intersect_path(&ring, FillRule::EvenOdd, true),
+        };
+
+        if ring_clip_ok.is_none() {
+            return;
+        }
+
+        for (side, width, style, color) in sides.iter() {
+
+            if *width <= 0.0 {
+                continue;
+            }
+
+            // each side is a trapezoid from the outer corners to the inner corners,
+            // so that the corners are split diagonally between two sides
+            let (x0, y0) = (bounds.origin.x, bounds.origin.y);
+            let (x1, y1) = (bounds.origin.x + bounds.size.width, bounds.origin.y + bounds.size.height);
+            let (ix0, iy0) = (inner_rect.origin.x, inner_rect.origin.y);
+            let (ix1, iy1) = (inner_rect.origin.x + inner_rect.size.width, inner_rect.origin.y + inner_rect.size.height);
+
+            let points = match side {
+                BorderSide::Top => [(x0, y0), (x1, y0), (ix1, iy0), (ix0, iy0)],
+                BorderSide::Right => [(x1, y0), (x1, y1), (ix1, iy1), (ix1, iy0)],
+                BorderSide::Bottom => [(x1, y1), (x0, y1), (ix0, iy1), (ix1, iy1)],
+                BorderSide::Left => [(x0, y1), (x0, y0), (ix0, iy0), (ix0, iy1)],
+            };
+
+            let mut trapezoid = PathBuilder::new();
+            trapezoid.move_to(points[0].0, points[0].1);
+            for (x, y) in points[1..].iter() {
+                trapezoid.line_to(*x, *y);
+            }
+            trapezoid.close();
+
+            let trapezoid = match trapezoid.finish() {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let mut paint = Paint::default();
+            paint.anti_alias = true;
+
+            match style {
+                BorderStyle::None | BorderStyle::Hidden => { },
+                BorderStyle::Dotted | BorderStyle::Dashed => {
+                    // stroke the center line of the side with a dash pattern
+                    let mut side_clip = ring_clip.clone();
+                    if trapezoid.clone().transform(transform).and_then(|t| side_clip.intersect_path(&t, FillRule::Winding, true)).is_none() {
+                        continue;
+                    }
+
+                    let (start, end) = match side {
+                        BorderSide::Top => ((x0, y0 + width / 2.0), (x1, y0 + width / 2.0)),
+                        BorderSide::Right => ((x1 - width / 2.0, y0), (x1 - width / 2.0, y1)),
+                        BorderSide::Bottom => ((x1, y1 - width / 2.0), (x0, y1 - width / 2.0)),
+                        BorderSide::Left => ((x0 + width / 2.0, y1), (x0 + width / 2.0, y0)),
+                    };
+
+                    let mut line = PathBuilder::new();
+                    line.move_to(start.0, start.1);
+                    line.line_to(end.0, end.1);
+                    let line = match line.finish() {
+                        Some(s) => s,
+                        None => continue,
+                    };
+
+                    let dash_len = if *style == BorderStyle::Dotted { *width } else { *width * 3.0 };
+                    let mut stroke = Stroke::default();
+                    stroke.width = *width;
+                    stroke.dash = StrokeDash::new(vec![dash_len, *width], 0.0);
+
+                    paint.set_color(translate_color(*color));
+                    target.stroke_path(&line, &paint, &stroke, transform, Some(&side_clip));
+                },
+                BorderStyle::Double => {
+                    // two lines with a gap in between, each 1/3 of the border width
+                    paint.set_color(translate_color(*color));
+                    let mut gap_clip = ring_clip.clone();
+                    let third_top = top / 3.0;
+                    let third_right = right / 3.0;
+                    let third_bottom = bottom / 3.0;
+                    let third_left = left / 3.0;
+                    let outer_gap_rect = shrink_rect(bounds, third_top, third_right, third_bottom, third_left);
+                    let inner_gap_rect = shrink_rect(bounds, third_top * 2.0, third_right * 2.0, third_bottom * 2.0, third_left * 2.0);
+                    let mut gap = PathBuilder::new();
+                    push_rect(&mut gap, bounds);
+                    push_rounded_rect(&mut gap, outer_gap_rect, radii.adjust(-third_top, -third_right, -third_bottom, -third_left));
+                    push_rounded_rect(&mut gap, inner_gap_rect, radii.adjust(-third_top * 2.0, -third_right * 2.0, -third_bottom * 2.0, -third_left * 2.0));
+                    if gap.finish().and_then(|g| g.transform(transform)).and_then(|g| gap_clip.intersect_path(&g, FillRule::EvenOdd, true)).is_some() {
+                        target.fill_path(&trapezoid, &paint, FillRule::Winding, transform, Some(&gap_clip));
+                    }
+                },
+                BorderStyle::Groove | BorderStyle::Ridge | BorderStyle::Inset | BorderStyle::Outset => {
+                    // 3D borders: top-left and bottom-right sides get a different shade
+                    let is_top_left = *side == BorderSide::Top || *side == BorderSide::Left;
+                    let darken = match style {
+                        BorderStyle::Inset | BorderStyle::Groove => is_top_left,
+                        _ => !is_top_left,
+                    };
+                    let shaded = if darken { shade_color(*color, 0.5) } else { *color };
+                    paint.set_color(translate_color(shaded));
+                    target.fill_path(&trapezoid, &paint, FillRule::Winding, transform, Some(&ring_clip));
+                },
+                BorderStyle::Solid => {
+                    paint.set_color(translate_color(*color));
+                    target.fill_path(&trapezoid, &paint, FillRule::Winding, transform, Some(&ring_clip));
+                },
+            }
+        }
+    }
+
+    fn draw_box_shadow(
+        &mut self,
+        target: &mut Pixmap,
+        box_shadow: &BoxShadow,
+        shadow_type: BoxShadowClipMode,
+        bounds: LogicalRect,
+        radii: BorderRadii,
+        transform: Transform,
+        clip: &Clip,
+    ) {
+        if clip.is_empty() {
+            return;
+        }
+
+        let BoxShadow { top, right, bottom, left, .. } = box_shadow;
+        let get_shadow = |s: &Option<CssPropertyValue<StyleBoxShadow>>| s.and_then(|s| s.get_property().cloned());
+        let (top, right, bottom, left) = (get_shadow(top), get_shadow(right), get_shadow(bottom), get_shadow(left));
+
+        match (top, right, bottom, left) {
+            // assumes that all box shadows are the same, so just use the top shadow
+            (Some(t), Some(_), Some(_), Some(_)) => {
+                self.draw_single_box_shadow(target, &t, shadow_type, bounds, radii, None, transform, clip);
+            },
+            (t, r, b, l) => {
+                // shadows on single sides: only draw the part of the shadow on that side
+                let sides = [(BorderSide::Top, t), (BorderSide::Right, r), (BorderSide::Bottom, b), (BorderSide::Left, l)];
+                for (side, shadow) in sides.iter() {
+                    let shadow = match shadow {
+                        Some(s) => s,
+                        None => continue,
+                    };
+                    let side_rect = get_box_shadow_side_rect(shadow, *side, bounds);
+                    self.draw_single_box_shadow(target, shadow, shadow_type, bounds, radii, Some(side_rect), transform, clip);
+                }
+            }
+        }
+    }
+
+    fn draw_single_box_shadow(
+        &mut self,
+        target: &mut Pixmap,
+        shadow: &StyleBoxShadow,
+        shadow_type: BoxShadowClipMode,
+        bounds: LogicalRect,
+        radii: BorderRadii,
+        side_rect: Option<LogicalRect>,
+        transform: Transform,
+        clip: &Clip,
+    ) {
+        if shadow.clip_mode != shadow_type {
+            return;
+        }
+
+        let offset_x = shadow.offset[0].to_pixels();
+        let offset_y = shadow.offset[1].to_pixels();
+        let blur_radius = shadow.blur_radius.to_pixels();
+        let spread = shadow.spread_radius.to_pixels();
+
+        let clip = match side_rect {
+            Some(r) => self.intersect_clip(clip, build_rect_path(r), transform),
+            None => clip.clone(),
+        };
+
+        let element_path = match build_rounded_rect_path(bounds, radii).and_then(|p| p.transform(transform)) {
+            Some(s) => s,
+            None => return,
+        };
+
+        match shadow_type {
+            BoxShadowClipMode::Outset => {
+                let shadow_rect = shrink_rect(
+                    LogicalRect::new(LogicalPosition::new(bounds.origin.x + offset_x, bounds.origin.y + offset_y), bounds.size),
+                    -spread, -spread, -spread, -spread,
+                );
+                let shadow_radii = radii.adjust(spread, spread, spread, spread);
+                let shadow_path = match build_rounded_rect_path(shadow_rect, shadow_radii).and_then(|p| p.transform(transform)) {
+                    Some(s) => s,
+                    None => return,
+                };
+                // outset shadows are not drawn below the element itself
+                fill_blurred_path(target, &shadow_path, Some(&element_path), shadow.color, blur_radius, &clip);
+            },
+            BoxShadowClipMode::Inset => {
+                // the shadow is everything inside of the element, except for the
+                // (shrunk and offset) inner rect, clipped to the element
+                let hole_rect = shrink_rect(
+                    LogicalRect::new(LogicalPosition::new(bounds.origin.x + offset_x, bounds.origin.y + offset_y), bounds.size),
+                    spread, spread, spread, spread,
+                );
+                let hole_radii = radii.adjust(-spread, -spread, -spread, -spread);
+                let extent = blur_radius * 2.0 + spread.abs() + offset_x.abs() + offset_y.abs();
+                let outer_rect = shrink_rect(bounds, -extent, -extent, -extent, -extent);
+
+                let mut shadow_path = PathBuilder::new();
+                push_rect(&mut shadow_path, outer_rect);
+                push_rounded_rect(&mut shadow_path, hole_rect, hole_radii);
+                let shadow_path = match shadow_path.finish().and_then(|p| p.transform(transform)) {
+                    Some(s) => s,
+                    None => return,
+                };
+
+                let clip = match clip {
+                    Clip::Empty => return,
+                    Clip::Unclipped => {
+                        let mut mask = ClipMask::new();
+                        if mask.set_path(self.width, self.height, &element_path, FillRule::Winding, true).is_none() { return; }
+                        Clip::Mask(mask)
+                    },
+                    Clip::Mask(mut m) => {
+                        if m.intersect_path(&element_path, FillRule::Winding, true).is_none() { return; }
+                        Clip::Mask(m)
+                    },
+                };
+
+                fill_blurred_path_with_rule(target, &shadow_path, FillRule::EvenOdd, None, shadow.color, blur_radius, &clip);
+            },
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum BorderSide {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+/// Forwards the glyph outline to the path builder, flipping the y axis
+/// (font units are y-up) and scaling the outline to the font size
+struct GlyphPathBuilder<'a> {
+    path_builder: &'a mut PathBuilder,
+    origin_x: f32,
+    origin_y: f32,
+    scale: f32,
+}
+
+impl<'a> GlyphPathBuilder<'a> {
+    #[inline]
+    fn x(&self, x: f32) -> f32 { self.origin_x + x * self.scale }
+    #[inline]
+    fn y(&self, y: f32) -> f32 { self.origin_y - y * self.scale }
+}
+
+impl<'a> OutlineBuilder for GlyphPathBuilder<'a> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = (self.x(x), self.y(y));
+        self.path_builder.move_to(x, y);
+    }
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = (self.x(x), self.y(y));
+        self.path_builder.line_to(x, y);
+    }
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x1, y1, x, y) = (self.x(x1), self.y(y1), self.x(x), self.y(y));
+        self.path_builder.quad_to(x1, y1, x, y);
+    }
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x1, y1, x2, y2, x, y) = (self.x(x1), self.y(y1), self.x(x2), self.y(y2), self.x(x), self.y(y));
+        self.path_builder.cubic_to(x1, y1, x2, y2, x, y);
+    }
+    fn close(&mut self) {
+        self.path_builder.close();
+    }
+}
+
+/// Fills the `pixmap` into the `fill_rect`, scaled to `tile_size` and starting at `tile_origin`
+fn draw_tile(
+    target: &mut Pixmap,
+    pixmap: &Pixmap,
+    fill_rect: LogicalRect,
+    tile_origin: LogicalPosition,
+    tile_size: LogicalSize,
+    spread_mode: SpreadMode,
+    transform: Transform,
+    clip: &Clip,
+) {
+    if clip.is_empty() || tile_size.width <= 0.0 || tile_size.height <= 0.0 {
+        return;
+    }
+
+    let rect = match translate_rect(fill_rect) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let pattern_transform = Transform::from_translate(tile_origin.x, tile_origin.y)
+        .pre_scale(tile_size.width / pixmap.width() as f32, tile_size.height / pixmap.height() as f32);
+
+    let mut paint = Paint::default();
+    paint.anti_alias = true;
+    paint.shader = Pattern::new(pixmap.as_ref(), spread_mode, FilterQuality::Bilinear, 1.0, pattern_transform);
+
+    target.fill_rect(rect, &paint, transform, clip.get_mask());
+}
+
+/// Fills the (device space) path with a blurred color, optionally cutting out the `knockout` path
+fn fill_blurred_path(
+    target: &mut Pixmap,
+    path: &Path,
+    knockout: Option<&Path>,
+    color: ColorU,
+    blur_radius: f32,
+    clip: &Clip,
+) {
+    fill_blurred_path_with_rule(target, path, FillRule::Winding, knockout, color, blur_radius, clip)
+}
+
+fn fill_blurred_path_with_rule(
+    target: &mut Pixmap,
+    path: &Path,
+    fill_rule: FillRule,
+    knockout: Option<&Path>,
+    color: ColorU,
+    blur_radius: f32,
+    clip: &Clip,
+) {
+    if clip.is_empty() {
+        return;
+    }
+
+    // CSS: the blur radius is twice the standard deviation of the gaussian blur
+    let sigma = (blur_radius / 2.0).max(0.0);
+    let margin = (sigma * 3.0).ceil();
+
+    let bounds = path.bounds();
+    let x0 = ((bounds.left() - margin).floor().max(0.0)) as i32;
+    let y0 = ((bounds.top() - margin).floor().max(0.0)) as i32;
+    let x1 = ((bounds.right() + margin).ceil().min(target.width() as f32)) as i32;
+    let y1 = ((bounds.bottom() + margin).ceil().min(target.height() as f32)) as i32;
+
+    if x1 <= x0 || y1 <= y0 {
+        return;
+    }
+
+    let layer_width = (x1 - x0) as u32;
+    let layer_height = (y1 - y0) as u32;
+    let to_layer = Transform::from_translate(-x0 as f32, -y0 as f32);
+
+    // render the coverage of the path into the alpha channel
+    let mut coverage = match Pixmap::new(layer_width, layer_height) {
+        Some(s) => s,
+        None => return,
+    };
+
+    let mut paint = Paint::default();
+    paint.anti_alias = true;
+    paint.set_color(Color::BLACK);
+    coverage.fill_path(path, &paint, fill_rule, to_layer, None);
+
+    let mut alpha = coverage.data().chunks_exact(4).map(|px| px[3]).collect::<Vec<u8>>();
+    box_blur_alpha(&mut alpha, layer_width as usize, layer_height as usize, sigma);
+
+    if let Some(knockout) = knockout {
+        let mut knockout_coverage = match Pixmap::new(layer_width, layer_height) {
+            Some(s) => s,
+            None => return,
+        };
+        knockout_coverage.fill_path(knockout, &paint, FillRule::Winding, to_layer, None);
+        for (a, k) in alpha.iter_mut().zip(knockout_coverage.data().chunks_exact(4)) {
+            *a = ((*a as u32 * (255 - k[3] as u32) + 127) / 255) as u8;
+        }
+    }
+
+    let mut layer = match Pixmap::new(layer_width, layer_height) {
+        Some(s) => s,
+        None => return,
+    };
+
+    for (dst, a) in layer.data_mut().chunks_exact_mut(4).zip(alpha.iter()) {
+        let a = (*a as u32 * color.a as u32 + 127) / 255;
+        dst[0] = ((color.r as u32 * a + 127) / 255) as u8;
+        dst[1] = ((color.g as u32 * a + 127) / 255) as u8;
+        dst[2] = ((color.b as u32 * a + 127) / 255) as u8;
+        dst[3] = a as u8;
+    }
+
+    target.draw_pixmap(x0, y0, layer.as_ref(), &PixmapPaint::default(), Transform::identity(), clip.get_mask());
+}
+
+/// Approximates a gaussian blur with three successive box blurs
+fn box_blur_alpha(alpha: &mut [u8], width: usize, height: usize, sigma: f32) {
+
+    if sigma <= 0.0 || width == 0 || height == 0 {
+        return;
+    }
+
+    // box size for three passes, see http://www.w3.org/TR/SVG11/filters.html#feGaussianBlurElement
+    let d = (sigma * 3.0 * (2.0 * core::f32::consts::PI).sqrt() / 4.0 + 0.5).floor() as usize;
+    let radius = d / 2;
+
+    if radius == 0 {
+        return;
+    }
+
+    let mut scratch = vec![0_u8; alpha.len()];
+
+    for _ in 0..3 {
+        box_blur_pass(alpha, &mut scratch, width, height, radius, true);
+        box_blur_pass(&scratch, alpha, width, height, radius, false);
+    }
+}
+
+/// Single horizontal (or vertical) box blur pass with a sliding window
+fn box_blur_pass(src: &[u8], dst: &mut [u8], width: usize, height: usize, radius: usize, horizontal: bool) {
+
+    let (lines, line_len) = if horizontal { (height, width) } else { (width, height) };
+    let index = |line: usize, i: usize| if horizontal { line * width + i } else { i * width + line };
+    let window = (radius * 2 + 1) as u32;
+
+    for line in 0..lines {
+        let mut sum = 0_u32;
+        for i in 0..=radius.min(line_len - 1) {
+            sum += src[index(line, i)] as u32;
+        }
+        for i in 0..line_len {
+            dst[index(line, i)] = ((sum + window / 2) / window) as u8;
+            if i + radius + 1 < line_len {
+                sum += src[index(line, i + radius + 1)] as u32;
+            }
+            if i >= radius {
+                sum -= src[index(line, i - radius)] as u32;
+            }
+        }
+    }
+}
+
+fn render_linear_gradient_tile(linear_gradient: &LinearGradient, tile_size: LogicalSize) -> Option<Pixmap> {
+
+    use azul_css::{LayoutRect, LayoutPoint, LayoutSize};
+
+    let mut tile = Pixmap::new(tile_size.width.ceil() as u32, tile_size.height.ceil() as u32)?;
+
+    let stops = linear_gradient.stops.iter().map(|s| {
+        GradientStop::new(s.offset.get() / 100.0, translate_color(s.color))
+    }).collect::<Vec<_>>();
+
+    let tile_rect = LayoutRect::new(
+        LayoutPoint::new(0, 0),
+        LayoutSize::new(tile_size.width.round() as isize, tile_size.height.round() as isize),
+    );
+    let (start, end) = linear_gradient.direction.to_points(&tile_rect);
+
+    let shader = SkLinearGradient::new(
+        Point::from_xy(start.x as f32, start.y as f32),
+        Point::from_xy(end.x as f32, end.y as f32),
+        stops,
+        translate_extend_mode(linear_gradient.extend_mode),
+        Transform::identity(),
+    )?;
+
+    fill_tile(&mut tile, shader);
+    Some(tile)
+}
+
+fn render_radial_gradient_tile(radial_gradient: &RadialGradient, tile_size: LogicalSize) -> Option<Pixmap> {
+
+    use azul_css::{RadialGradientSize, Shape};
+
+    let mut tile = Pixmap::new(tile_size.width.ceil() as u32, tile_size.height.ceil() as u32)?;
+
+    let center = calculate_background_position(tile_size, radial_gradient.position, LogicalSize::zero());
+    let (cx, cy) = (center.x, center.y);
+
+    // distances from the center to the sides of the tile
+    let closest_x = cx.abs().min((tile_size.width - cx).abs());
+    let closest_y = cy.abs().min((tile_size.height - cy).abs());
+    let farthest_x = cx.abs().max((tile_size.width - cx).abs());
+    let farthest_y = cy.abs().max((tile_size.height - cy).abs());
+
+    let (rx, ry) = match (radial_gradient.shape, &radial_gradient.size) {
+        (Shape::Circle, RadialGradientSize::ClosestSide) => (closest_x.min(closest_y), closest_x.min(closest_y)),
+        (Shape::Circle, RadialGradientSize::FarthestSide) => (farthest_x.max(farthest_y), farthest_x.max(farthest_y)),
+        (Shape::Circle, RadialGradientSize::ClosestCorner) => {
+            let r = closest_x.hypot(closest_y);
+            (r, r)
+        },
+        (Shape::Circle, RadialGradientSize::FarthestCorner) => {
+            let r = farthest_x.hypot(farthest_y);
+            (r, r)
+        },
+        (Shape::Ellipse, RadialGradientSize::ClosestSide) => (closest_x, closest_y),
+        (Shape::Ellipse, RadialGradientSize::FarthestSide) => (farthest_x, farthest_y),
+        // the ellipse keeps the aspect ratio of the "side" variant, but passes through the corner
+        (Shape::Ellipse, RadialGradientSize::ClosestCorner) => (closest_x * core::f32::consts::SQRT_2, closest_y * core::f32::consts::SQRT_2),
+        (Shape::Ellipse, RadialGradientSize::FarthestCorner) => (farthest_x * core::f32::consts::SQRT_2, farthest_y * core::f32::consts::SQRT_2),
+    };
+
+    if rx <= 0.0 || ry <= 0.0 {
+        return None;
+    }
+
+    let stops = radial_gradient.stops.iter().map(|s| {
+        GradientStop::new(s.offset.normalized(), translate_color(s.color))
+    }).collect::<Vec<_>>();
+
+    // gradient is a circle with radius rx, scaled vertically to the ellipse
+    let gradient_transform = Transform::from_translate(cx, cy)
+        .pre_scale(1.0, ry / rx)
+        .pre_translate(-cx, -cy);
+
+    let shader = SkRadialGradient::new(
+        Point::from_xy(cx, cy),
+        Point::from_xy(cx, cy),
+        rx,
+        stops,
+        translate_extend_mode(radial_gradient.extend_mode),
+        gradient_transform,
+    )?;
+
+    fill_tile(&mut tile, shader);
+    Some(tile)
+}
+
+/// tiny-skia has no sweep gradient shader, so the conic gradient is computed per pixel
+fn render_conic_gradient_tile(conic_gradient: &ConicGradient, tile_size: LogicalSize) -> Option<Pixmap> {
+
+    let mut tile = Pixmap::new(tile_size.width.ceil() as u32, tile_size.height.ceil() as u32)?;
+
+    // to_degrees() wraps 360deg to 0deg, so the stops have to be unwrapped again
+    let mut last_stop = 0.0;
+    let stops = conic_gradient.stops.iter().map(|s| {
+        let mut t = s.angle.to_degrees() / 360.0;
+        while t < last_stop { t += 1.0; }
+        last_stop = t;
+        (t, s.color)
+    }).collect::<Vec<_>>();
+    if stops.is_empty() {
+        return None;
+    }
+
+    let center = calculate_background_position(tile_size, conic_gradient.center, LogicalSize::zero());
+    let start_angle = conic_gradient.angle.to_degrees();
+    let tile_width = tile.width() as usize;
+
+    for (i, px) in tile.data_mut().chunks_exact_mut(4).enumerate() {
+
+        let x = (i % tile_width) as f32 + 0.5 - center.x;
+        let y = (i / tile_width) as f32 + 0.5 - center.y;
+
+        // 0deg = top, clockwise
+        let angle = x.atan2(-y).to_degrees() - start_angle;
+        let mut t = angle.rem_euclid(360.0) / 360.0;
+        if conic_gradient.extend_mode == ExtendMode::Repeat {
+            let first = stops[0].0;
+            let last = stops[stops.len() - 1].0;
+            if last > first {
+                t = first + (t - first).rem_euclid(last - first);
+            }
+        }
+
+        let color = interpolate_stops(&stops, t);
+        let a = color.a as u32;
+        px[0] = ((color.r as u32 * a + 127) / 255) as u8;
+        px[1] = ((color.g as u32 * a + 127) / 255) as u8;
+        px[2] = ((color.b as u32 * a + 127) / 255) as u8;
+        px[3] = color.a;
+    }
+
+    Some(tile)
+}
+
+fn interpolate_stops(stops: &[(f32, ColorU)], t: f32) -> ColorU {
+
+    let first = stops[0];
+    let last = stops[stops.len() - 1];
+
+    if t <= first.0 {
+        return first.1;
+    }
+
+    if t >= last.0 {
+        return last.1;
+    }
+
+    for w in stops.windows(2) {
+        let (t0, c0) = w[0];
+        let (t1, c1) = w[1];
+        if t >= t0 && t <= t1 {
+            let f = if t1 > t0 { (t - t0) / (t1 - t0) } else { 0.0 };
+            let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * f).round() as u8;
+            return ColorU { r: lerp(c0.r, c1.r), g: lerp(c0.g, c1.g), b: lerp(c0.b, c1.b), a: lerp(c0.a, c1.a) };
+        }
+    }
+
+    last.1
+}
+
+fn fill_tile(tile: &mut Pixmap, shader: Shader) {
+    let rect = match Rect::from_xywh(0.0, 0.0, tile.width() as f32, tile.height() as f32) {
+        Some(s) => s,
+        None => return,
+    };
+    let mut paint = Paint::default();
+    paint.shader = shader;
+    tile.fill_rect(rect, &paint, Transform::identity(), None);
+}
+
+/// Transform a background size such as "cover" or "contain" into actual pixels
+fn calculate_background_size(
+    rect_size: LogicalSize,
+    background_size: Option<StyleBackgroundSize>,
+    content_size: Option<(f32, f32)>,
+) -> LogicalSize {
+
+    let content_size = content_size.unwrap_or((rect_size.width, rect_size.height));
+
+    let background_size = match background_size {
+        None => return LogicalSize::new(content_size.0, content_size.1),
+        Some(s) => s,
+    };
+
+    if content_size.0 <= 0.0 || content_size.1 <= 0.0 {
+        return LogicalSize::zero();
+    }
+
+    let ratio_width = rect_size.width / content_size.0;
+    let ratio_height = rect_size.height / content_size.1;
+
+    let ratio = match background_size {
+        StyleBackgroundSize::ExactSize([w, h]) => {
+            return LogicalSize::new(w.to_pixels(rect_size.width), h.to_pixels(rect_size.height));
+        },
+        StyleBackgroundSize::Contain => ratio_width.min(ratio_height),
+        StyleBackgroundSize::Cover => ratio_width.max(ratio_height),
+    };
+
+    LogicalSize::new(content_size.0 * ratio, content_size.1 * ratio)
+}
+
+/// Transform a background-position attribute into pixel coordinates
+fn calculate_background_position(
+    rect_size: LogicalSize,
+    background_position: StyleBackgroundPosition,
+    background_size: LogicalSize,
+) -> LogicalPosition {
+
+    use azul_css::{BackgroundPositionHorizontal, BackgroundPositionVertical};
+
+    let width = rect_size.width;
+    let height = rect_size.height;
+
+    let horizontal_offset = match background_position.horizontal {
+        BackgroundPositionHorizontal::Left => 0.0,
+        BackgroundPositionHorizontal::Center => (width - background_size.width) / 2.0,
+        BackgroundPositionHorizontal::Right => width - background_size.width,
+        BackgroundPositionHorizontal::Exact(e) => e.to_pixels(width),
+    };
+
+    let vertical_offset = match background_position.vertical {
+        BackgroundPositionVertical::Top => 0.0,
+        BackgroundPositionVertical::Center => (height - background_size.height) / 2.0,
+        BackgroundPositionVertical::Bottom => height - background_size.height,
+        BackgroundPositionVertical::Exact(e) => e.to_pixels(height),
+    };
+
+    LogicalPosition::new(horizontal_offset, vertical_offset)
+}
+
+/// Returns the area that a (possibly repeated) background tile covers
+fn get_background_fill_rect(bounds: LogicalRect, tile_rect: LogicalRect, background_repeat: StyleBackgroundRepeat) -> LogicalRect {
+    match background_repeat {
+        StyleBackgroundRepeat::NoRepeat => tile_rect,
+        StyleBackgroundRepeat::Repeat => bounds,
+        StyleBackgroundRepeat::RepeatX => LogicalRect::new(
+            LogicalPosition::new(bounds.origin.x, tile_rect.origin.y),
+            LogicalSize::new(bounds.size.width, tile_rect.size.height),
+        ),
+        StyleBackgroundRepeat::RepeatY => LogicalRect::new(
+            LogicalPosition::new(tile_rect.origin.x, bounds.origin.y),
+            LogicalSize::new(tile_rect.size.width, bounds.size.height),
+        ),
+    }
+}
+
+/// Returns the part of the box shadow that is visible for a shadow on a single side
+fn get_box_shadow_side_rect(shadow: &StyleBoxShadow, side: BorderSide, bounds: LogicalRect) -> LogicalRect {
+
+    let displace = (shadow.spread_radius.to_pixels() + shadow.blur_radius.to_pixels()) * 2.0
+        + shadow.offset[0].to_pixels().abs() + shadow.offset[1].to_pixels().abs();
+
+    let (x, y) = (bounds.origin.x, bounds.origin.y);
+    let (w, h) = (bounds.size.width, bounds.size.height);
+    let is_inset = shadow.clip_mode == BoxShadowClipMode::Inset;
+
+    let (origin, size) = match (side, is_inset) {
+        (BorderSide::Top, true) => ((x, y), (w, displace)),
+        (BorderSide::Bottom, true) => ((x, y + h - displace), (w, displace)),
+        (BorderSide::Left, true) => ((x, y), (displace, h)),
+        (BorderSide::Right, true) => ((x + w - displace, y), (displace, h)),
+        (BorderSide::Top, false) => ((x - displace, y - displace), (w + displace * 2.0, displace)),
+        (BorderSide::Bottom, false) => ((x - displace, y + h), (w + displace * 2.0, displace)),
+        (BorderSide::Left, false) => ((x - displace, y - displace), (displace, h + displace * 2.0)),
+        (BorderSide::Right, false) => ((x + w, y - displace), (displace, h + displace * 2.0)),
+    };
+
+    LogicalRect::new(LogicalPosition::new(origin.0, origin.1), LogicalSize::new(size.0, size.1))
+}
+
+/// Shrinks the rect by the given amounts (grows the rect if the amounts are negative)
+fn shrink_rect(rect: LogicalRect, top: f32, right: f32, bottom: f32, left: f32) -> LogicalRect {
+    LogicalRect::new(
+        LogicalPosition::new(rect.origin.x + left, rect.origin.y + top),
+        LogicalSize::new((rect.size.width - left - right).max(0.0), (rect.size.height - top - bottom).max(0.0)),
+    )
+}
+
+fn build_rect_path(rect: LogicalRect) -> Option<Path> {
+    let mut path_builder = PathBuilder::new();
+    push_rect(&mut path_builder, rect);
+    path_builder.finish()
+}
+
+fn build_rounded_rect_path(rect: LogicalRect, radii: BorderRadii) -> Option<Path> {
+    let mut path_builder = PathBuilder::new();
+    push_rounded_rect(&mut path_builder, rect, radii);
+    path_builder.finish()
+}
+
+fn push_rect(path_builder: &mut PathBuilder, rect: LogicalRect) {
+    if rect.size.width <= 0.0 || rect.size.height <= 0.0 {
+        return;
+    }
+    path_builder.push_rect(rect.origin.x, rect.origin.y, rect.size.width, rect.size.height);
+}
+
+fn push_rounded_rect(path_builder: &mut PathBuilder, rect: LogicalRect, radii: BorderRadii) {
+
+    if radii.is_zero() {
+        push_rect(path_builder, rect);
+        return;
+    }
+
+    let (x, y) = (rect.origin.x, rect.origin.y);
+    let (w, h) = (rect.size.width, rect.size.height);
+
+    if w <= 0.0 || h <= 0.0 {
+        return;
+    }
+
+    // scale down all radii if adjacent radii overlap (CSS Backgrounds 3, 5.5)
+    let BorderRadii { top_left: tl, top_right: tr, bottom_right: br, bottom_left: bl } = radii;
+    let f = [
+        w / (tl.0 + tr.0),
+        w / (bl.0 + br.0),
+        h / (tl.1 + bl.1),
+        h / (tr.1 + br.1),
+    ].iter().fold(1.0_f32, |acc, f| if f.is_finite() { acc.min(*f) } else { acc });
+
+    let (tl, tr, br, bl) = (
+        (tl.0 * f, tl.1 * f),
+        (tr.0 * f, tr.1 * f),
+        (br.0 * f, br.1 * f),
+        (bl.0 * f, bl.1 * f),
+    );
+
+    path_builder.move_to(x + tl.0, y);
+    path_builder.line_to(x + w - tr.0, y);
+    path_builder.cubic_to(x + w - tr.0 * (1.0 - KAPPA), y, x + w, y + tr.1 * (1.0 - KAPPA), x + w, y + tr.1);
+    path_builder.line_to(x + w, y + h - br.1);
+    path_builder.cubic_to(x + w, y + h - br.1 * (1.0 - KAPPA), x + w - br.0 * (1.0 - KAPPA), y + h, x + w - br.0, y + h);
+    path_builder.line_to(x + bl.0, y + h);
+    path_builder.cubic_to(x + bl.0 * (1.0 - KAPPA), y + h, x, y + h - bl.1 * (1.0 - KAPPA), x, y + h - bl.1);
+    path_builder.line_to(x, y + tl.1);
+    path_builder.cubic_to(x, y + tl.1 * (1.0 - KAPPA), x + tl.0 * (1.0 - KAPPA), y, x + tl.0, y);
+    path_builder.close();
+}
+
+/// Darkens the color for 3D border styles (inset, outset, groove, ridge)
+fn shade_color(color: ColorU, factor: f32) -> ColorU {
+    ColorU {
+        r: (color.r as f32 * factor) as u8,
+        g: (color.g as f32 * factor) as u8,
+        b: (color.b as f32 * factor) as u8,
+        a: color.a,
+    }
+}
+
+#[inline]
+fn translate_rect(rect: LogicalRect) -> Option<Rect> {
+    Rect::from_xywh(rect.origin.x, rect.origin.y, rect.size.width, rect.size.height)
+}
+
+#[inline]
+fn translate_color(i: ColorU) -> Color {
+    Color::from_rgba8(i.r, i.g, i.b, i.a)
+}
+
+#[inline]
+fn translate_extend_mode(e: ExtendMode) -> SpreadMode {
+    match e {
+        ExtendMode::Clamp => SpreadMode::Pad,
+        ExtendMode::Repeat => SpreadMode::Repeat,
+    }
+}
+
+/// Only the 2D part of the transform is used, 3D transforms are flattened
+#[inline]
+fn translate_transform(t: &ComputedTransform3D) -> Transform {
+    Transform::from_row(t.m[0][0], t.m[0][1], t.m[1][0], t.m[1][1], t.m[3][0], t.m[3][1])
+}
+
+fn translate_mix_blend_mode(m: StyleMixBlendMode) -> BlendMode {
+    match m {
+        StyleMixBlendMode::Normal => BlendMode::SourceOver,
+        StyleMixBlendMode::Multiply => BlendMode::Multiply,
+        StyleMixBlendMode::Screen => BlendMode::Screen,
+        StyleMixBlendMode::Overlay => BlendMode::Overlay,
+        StyleMixBlendMode::Darken => BlendMode::Darken,
+        StyleMixBlendMode::Lighten => BlendMode::Lighten,
+        StyleMixBlendMode::ColorDodge => BlendMode::ColorDodge,
+        StyleMixBlendMode::ColorBurn => BlendMode::ColorBurn,
+        StyleMixBlendMode::HardLight => BlendMode::HardLight,
+        StyleMixBlendMode::SoftLight => BlendMode::SoftLight,
+        StyleMixBlendMode::Difference => BlendMode::Difference,
+        StyleMixBlendMode::Exclusion => BlendMode::Exclusion,
+        StyleMixBlendMode::Hue => BlendMode::Hue,
+        StyleMixBlendMode::Saturation => BlendMode::Saturation,
+        StyleMixBlendMode::Color => BlendMode::Color,
+        StyleMixBlendMode::Luminosity => BlendMode::Luminosity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use azul_core::{
+        app_resources::{
+            add_resources, AddFontInstance, AddFontMsg, Au, FontKey,
+            IdNamespace, PrimitiveFlags,
+        },
+        styled_dom::StyleFontFamilyHash,
+        ui_solver::PositionInfoInner,
+    };
+    use azul_css::{
+        FontData, FontRef, LayoutBorderBottomWidth, LayoutBorderLeftWidth,
+        LayoutBorderRightWidth, LayoutBorderTopWidth, PixelValue, PixelValueNoPercent,
+        StyleBackgroundContent, StyleBorderBottomColor, StyleBorderBottomLeftRadius,
+        StyleBorderBottomRightRadius, StyleBorderBottomStyle, StyleBorderLeftColor,
+        StyleBorderLeftStyle, StyleBorderRightColor, StyleBorderRightStyle,
+        StyleBorderTopColor, StyleBorderTopLeftRadius, StyleBorderTopRightRadius,
+        StyleBorderTopStyle, U8Vec,
+    };
+
+    const FONT: &[u8] = include_bytes!("../../examples/assets/fonts/SourceSerifPro-Regular.ttf");
+
+    fn frame(origin: (f32, f32), size: (f32, f32), content: Vec<LayoutRectContent>, children: Vec<DisplayListMsg>) -> DisplayListFrame {
+        DisplayListFrame {
+            size: LogicalSize::new(size.0, size.1),
+            position: PositionInfo::Static(PositionInfoInner {
+                x_offset: origin.0,
+                y_offset: origin.1,
+                static_x_offset: origin.0,
+                static_y_offset: origin.1,
+            }),
+            flags: PrimitiveFlags {
+                is_backface_visible: true,
+                is_scrollbar_container: false,
+                is_scrollbar_thumb: false,
+                prefer_compositor_surface: false,
+                supports_external_compositor_surface: false,
+            },
+            mix_blend_mode: None,
+            clip_children: None,
+            clip_mask: None,
+            border_radius: StyleBorderRadius::default(),
+            tag: None,
+            box_shadow: None,
+            transform: None,
+            opacity: None,
+            content,
+            children,
+        }
+    }
+
+    fn background(color: ColorU) -> LayoutRectContent {
+        LayoutRectContent::Background {
+            content: RectBackground::Color(color),
+            size: None,
+            offset: None,
+            repeat: None,
+        }
+    }
+
+    fn border(width: f32, style: BorderStyle, color: ColorU) -> LayoutRectContent {
+        LayoutRectContent::Border {
+            widths: StyleBorderWidths {
+                top: Some(CssPropertyValue::Exact(LayoutBorderTopWidth::px(width))),
+                right: Some(CssPropertyValue::Exact(LayoutBorderRightWidth::px(width))),
+                bottom: Some(CssPropertyValue::Exact(LayoutBorderBottomWidth::px(width))),
+                left: Some(CssPropertyValue::Exact(LayoutBorderLeftWidth::px(width))),
+            },
+            colors: StyleBorderColors {
+                top: Some(CssPropertyValue::Exact(StyleBorderTopColor { inner: color })),
+                right: Some(CssPropertyValue::Exact(StyleBorderRightColor { inner: color })),
+                bottom: Some(CssPropertyValue::Exact(StyleBorderBottomColor { inner: color })),
+                left: Some(CssPropertyValue::Exact(StyleBorderLeftColor { inner: color })),
+            },
+            styles: StyleBorderStyles {
+                top: Some(CssPropertyValue::Exact(StyleBorderTopStyle { inner: style })),
+                right: Some(CssPropertyValue::Exact(StyleBorderRightStyle { inner: style })),
+                bottom: Some(CssPropertyValue::Exact(StyleBorderBottomStyle { inner: style })),
+                left: Some(CssPropertyValue::Exact(StyleBorderLeftStyle { inner: style })),
+            },
+        }
+    }
+
+    fn border_radius(radius: f32) -> StyleBorderRadius {
+        StyleBorderRadius {
+            top_left: Some(CssPropertyValue::Exact(StyleBorderTopLeftRadius::px(radius))),
+            top_right: Some(CssPropertyValue::Exact(StyleBorderTopRightRadius::px(radius))),
+            bottom_left: Some(CssPropertyValue::Exact(StyleBorderBottomLeftRadius::px(radius))),
+            bottom_right: Some(CssPropertyValue::Exact(StyleBorderBottomRightRadius::px(radius))),
+        }
+    }
+
+    fn box_shadow(clip_mode: BoxShadowClipMode, offset: f32, spread: f32, color: ColorU) -> BoxShadow {
+        let shadow = CssPropertyValue::Exact(StyleBoxShadow {
+            offset: [PixelValueNoPercent { inner: PixelValue::px(offset) }; 2],
+            color,
+            blur_radius: PixelValueNoPercent { inner: PixelValue::px(0.0) },
+            spread_radius: PixelValueNoPercent { inner: PixelValue::px(spread) },
+            clip_mode,
+        });
+        BoxShadow {
+            clip_mode,
+            top: Some(shadow),
+            right: Some(shadow),
+            bottom: Some(shadow),
+            left: Some(shadow),
+        }
+    }
+
+    fn render(root: DisplayListFrame, renderer_resources: &RendererResources) -> RawImage {
+        let display_list = CachedDisplayList {
+            root_size: root.size,
+            root: DisplayListMsg::Frame(root),
+        };
+        render_display_list(&display_list, renderer_resources, &ImageCache::new(), &[], ColorU::WHITE).unwrap()
+    }
+
+    fn pixel(image: &RawImage, x: usize, y: usize) -> ColorU {
+        let pixels = match &image.pixels {
+            RawImageData::U8(u) => u.as_ref(),
+            _ => panic!("expected RGBA8 image"),
+        };
+        let i = (y * image.width + x) * 4;
+        ColorU { r: pixels[i], g: pixels[i + 1], b: pixels[i + 2], a: pixels[i + 3] }
+    }
+
+    #[test]
+    fn zero_sized_display_list_renders_nothing() {
+        let display_list = CachedDisplayList::empty();
+        assert!(render_display_list(&display_list, &RendererResources::default(), &ImageCache::new(), &[], ColorU::WHITE).is_none());
+    }
+
+    #[test]
+    fn background_color_fills_the_rect() {
+        let child = frame((5.0, 5.0), (10.0, 10.0), vec![background(ColorU::RED)], vec![]);
+        let root = frame((0.0, 0.0), (20.0, 20.0), vec![], vec![DisplayListMsg::Frame(child)]);
+        let image = render(root, &RendererResources::default());
+
+        assert_eq!(image.width, 20);
+        assert_eq!(image.height, 20);
+        assert_eq!(pixel(&image, 5, 5), ColorU::RED);
+        assert_eq!(pixel(&image, 14, 14), ColorU::RED);
+        assert_eq!(pixel(&image, 4, 4), ColorU::WHITE);
+        assert_eq!(pixel(&image, 15, 15), ColorU::WHITE);
+    }
+
+    #[test]
+    fn solid_border_is_drawn_inside_the_rect() {
+        let root = frame((0.0, 0.0), (20.0, 20.0), vec![border(4.0, BorderStyle::Solid, ColorU::BLUE)], vec![]);
+        let image = render(root, &RendererResources::default());
+
+        assert_eq!(pixel(&image, 0, 10), ColorU::BLUE);
+        assert_eq!(pixel(&image, 3, 10), ColorU::BLUE);
+        assert_eq!(pixel(&image, 10, 0), ColorU::BLUE);
+        assert_eq!(pixel(&image, 19, 10), ColorU::BLUE);
+        assert_eq!(pixel(&image, 10, 19), ColorU::BLUE);
+        assert_eq!(pixel(&image, 4, 10), ColorU::WHITE);
+        assert_eq!(pixel(&image, 10, 10), ColorU::WHITE);
+    }
+
+    #[test]
+    fn inset_border_darkens_the_top_left_sides() {
+        let color = ColorU { r: 200, g: 200, b: 200, a: 255 };
+        let root = frame((0.0, 0.0), (20.0, 20.0), vec![border(4.0, BorderStyle::Inset, color)], vec![]);
+        let image = render(root, &RendererResources::default());
+
+        assert_eq!(pixel(&image, 10, 1), ColorU { r: 100, g: 100, b: 100, a: 255 });
+        assert_eq!(pixel(&image, 1, 10), ColorU { r: 100, g: 100, b: 100, a: 255 });
+        assert_eq!(pixel(&image, 10, 18), color);
+        assert_eq!(pixel(&image, 18, 10), color);
+    }
+
+    #[test]
+    fn border_radius_cuts_off_the_corners() {
+        let mut root = frame((0.0, 0.0), (20.0, 20.0), vec![background(ColorU::RED)], vec![]);
+        root.border_radius = border_radius(10.0);
+        let image = render(root, &RendererResources::default());
+
+        // the corners are outside of the circle
+        assert_eq!(pixel(&image, 0, 0), ColorU::WHITE);
+        assert_eq!(pixel(&image, 19, 0), ColorU::WHITE);
+        assert_eq!(pixel(&image, 0, 19), ColorU::WHITE);
+        assert_eq!(pixel(&image, 19, 19), ColorU::WHITE);
+        // the edge centers and the center are inside
+        assert_eq!(pixel(&image, 10, 1), ColorU::RED);
+        assert_eq!(pixel(&image, 1, 10), ColorU::RED);
+        assert_eq!(pixel(&image, 10, 10), ColorU::RED);
+    }
+
+    #[test]
+    fn rounded_border_follows_the_radius() {
+        let mut root = frame((0.0, 0.0), (40.0, 40.0), vec![border(2.0, BorderStyle::Solid, ColorU::BLUE)], vec![]);
+        root.border_radius = border_radius(20.0);
+        let image = render(root, &RendererResources::default());
+
+        assert_eq!(pixel(&image, 0, 0), ColorU::WHITE);
+        assert_eq!(pixel(&image, 20, 1), ColorU::BLUE);
+        assert_eq!(pixel(&image, 1, 20), ColorU::BLUE);
+        assert_eq!(pixel(&image, 20, 20), ColorU::WHITE);
+    }
+
+    #[test]
+    fn outset_box_shadow_is_not_drawn_below_the_element() {
+        let mut child = frame((10.0, 10.0), (20.0, 20.0), vec![], vec![]);
+        child.box_shadow = Some(box_shadow(BoxShadowClipMode::Outset, 5.0, 0.0, ColorU::BLACK));
+        let root = frame((0.0, 0.0), (40.0, 40.0), vec![], vec![DisplayListMsg::Frame(child)]);
+        let image = render(root, &RendererResources::default());
+
+        // shadow = element rect moved by 5px to the bottom right
+        assert_eq!(pixel(&image, 32, 32), ColorU::BLACK);
+        assert_eq!(pixel(&image, 32, 20), ColorU::BLACK);
+        // no shadow below the (transparent) element or outside of the shadow
+        assert_eq!(pixel(&image, 20, 20), ColorU::WHITE);
+        assert_eq!(pixel(&image, 5, 5), ColorU::WHITE);
+        assert_eq!(pixel(&image, 36, 36), ColorU::WHITE);
+    }
+
+    #[test]
+    fn outset_box_shadow_spread_grows_the_shadow() {
+        let mut child = frame((10.0, 10.0), (20.0, 20.0), vec![], vec![]);
+        child.box_shadow = Some(box_shadow(BoxShadowClipMode::Outset, 0.0, 4.0, ColorU::BLACK));
+        let root = frame((0.0, 0.0), (40.0, 40.0), vec![], vec![DisplayListMsg::Frame(child)]);
+        let image = render(root, &RendererResources::default());
+
+        assert_eq!(pixel(&image, 7, 20), ColorU::BLACK);
+        assert_eq!(pixel(&image, 32, 20), ColorU::BLACK);
+        assert_eq!(pixel(&image, 5, 20), ColorU::WHITE);
+        assert_eq!(pixel(&image, 20, 20), ColorU::WHITE);
+    }
+
+    #[test]
+    fn inset_box_shadow_is_clipped_to_the_element() {
+        let mut child = frame((10.0, 10.0), (20.0, 20.0), vec![], vec![]);
+        child.box_shadow = Some(box_shadow(BoxShadowClipMode::Inset, 0.0, 4.0, ColorU::BLACK));
+        let root = frame((0.0, 0.0), (40.0, 40.0), vec![], vec![DisplayListMsg::Frame(child)]);
+        let image = render(root, &RendererResources::default());
+
+        assert_eq!(pixel(&image, 11, 20), ColorU::BLACK);
+        assert_eq!(pixel(&image, 20, 11), ColorU::BLACK);
+        assert_eq!(pixel(&image, 20, 20), ColorU::WHITE);
+        assert_eq!(pixel(&image, 8, 20), ColorU::WHITE);
+    }
+
+    #[test]
+    fn linear_gradient_interpolates_between_the_stops() {
+        let gradient = match azul_css_parser::parse_style_background_content("linear-gradient(to right, #ff0000, #0000ff)") {
+            Ok(StyleBackgroundContent::LinearGradient(lg)) => lg,
+            other => panic!("unexpected background: {:?}", other),
+        };
+        let content = LayoutRectContent::Background {
+            content: RectBackground::LinearGradient(gradient),
+            size: None,
+            offset: None,
+            repeat: None,
+        };
+        let root = frame((0.0, 0.0), (100.0, 10.0), vec![content], vec![]);
+        let image = render(root, &RendererResources::default());
+
+        let left = pixel(&image, 0, 5);
+        let center = pixel(&image, 50, 5);
+        let right = pixel(&image, 99, 5);
+
+        assert!(left.r > 245 && left.b < 10, "{:?}", left);
+        assert!(right.b > 245 && right.r < 10, "{:?}", right);
+        assert!((center.r as i32 - 127).abs() < 10 && (center.b as i32 - 127).abs() < 10, "{:?}", center);
+        assert_eq!(left.a, 255);
+    }
+
+    #[test]
+    fn overflow_hidden_clips_the_children() {
+        let make_root = |clip_children: bool| {
+            let child = frame((0.0, 0.0), (20.0, 20.0), vec![background(ColorU::RED)], vec![]);
+            let mut parent = frame((0.0, 0.0), (10.0, 10.0), vec![], vec![DisplayListMsg::Frame(child)]);
+            if clip_children {
+                parent.clip_children = Some(parent.size);
+            }
+            frame((0.0, 0.0), (20.0, 20.0), vec![], vec![DisplayListMsg::Frame(parent)])
+        };
+
+        let clipped = render(make_root(true), &RendererResources::default());
+        assert_eq!(pixel(&clipped, 5, 5), ColorU::RED);
+        assert_eq!(pixel(&clipped, 15, 15), ColorU::WHITE);
+
+        let unclipped = render(make_root(false), &RendererResources::default());
+        assert_eq!(pixel(&unclipped, 5, 5), ColorU::RED);
+        assert_eq!(pixel(&unclipped, 15, 15), ColorU::RED);
+    }
+
+    #[test]
+    fn rounded_clip_clips_the_children_to_the_radius() {
+        let child = frame((0.0, 0.0), (20.0, 20.0), vec![background(ColorU::RED)], vec![]);
+        let mut parent = frame((0.0, 0.0), (20.0, 20.0), vec![], vec![DisplayListMsg::Frame(child)]);
+        parent.clip_children = Some(parent.size);
+        parent.border_radius = border_radius(10.0);
+        let image = render(parent, &RendererResources::default());
+
+        assert_eq!(pixel(&image, 0, 0), ColorU::WHITE);
+        assert_eq!(pixel(&image, 10, 10), ColorU::RED);
+    }
+
+    #[test]
+    fn glyphs_are_rendered_with_the_registered_font() {
+
+        let font_ref = FontRef::new(FontData {
+            bytes: U8Vec::from_const_slice(FONT),
+            font_index: 0,
+            parsed: core::ptr::null(),
+            parsed_destructor: |_| { },
+        });
+
+        let id_namespace = IdNamespace(0);
+        let font_key = FontKey::unique(id_namespace);
+        let font_instance_key = FontInstanceKey::unique(id_namespace);
+        let font_size = Au::from_px(100.0);
+
+        let mut renderer_resources = RendererResources::default();
+        add_resources(
+            &mut renderer_resources,
+            &mut Vec::new(),
+            vec![
+                (StyleFontFamilyHash(0), AddFontMsg::Font(font_key, StyleFontFamilyHash(0), font_ref)),
+                (StyleFontFamilyHash(0), AddFontMsg::Instance(AddFontInstance {
+                    key: font_instance_key,
+                    font_key,
+                    glyph_size: font_size,
+                    options: None,
+                    platform_options: None,
+                    variations: Vec::new(),
+                }, font_size)),
+            ],
+            Vec::new(),
+        );
+
+        assert_eq!(renderer_resources.get_font_instance(&font_instance_key).map(|(_, au)| au), Some(font_size));
+
+        let face = Face::from_slice(FONT, 0).unwrap();
+        let glyph_id = face.glyph_index('I').unwrap();
+        let bbox = face.glyph_bounding_box(glyph_id).unwrap();
+        let scale = 100.0 / face.units_per_em() as f32;
+        let baseline = LogicalPosition::new(20.0, 100.0);
+
+        let text = |font_instance_key: FontInstanceKey| LayoutRectContent::Text {
+            glyphs: vec![GlyphInstance {
+                index: glyph_id.0 as u32,
+                font_index: 0,
+                point: baseline,
+                size: LogicalSize::zero(),
+            }],
+            font_instance_key,
+            color: ColorU::BLACK,
+            glyph_options: None,
+            overflow: (true, true),
+            text_shadow: None,
+        };
+
+        // center of the stem of the "I"
+        let stem_x = (baseline.x + (bbox.x_min as f32 + bbox.x_max as f32) / 2.0 * scale) as usize;
+        let stem_y = (baseline.y - (bbox.y_min as f32 + bbox.y_max as f32) / 2.0 * scale) as usize;
+
+        let image = render(frame((0.0, 0.0), (120.0, 120.0), vec![text(font_instance_key)], vec![]), &renderer_resources);
+        assert_eq!(pixel(&image, stem_x, stem_y), ColorU::BLACK);
+        assert_eq!(pixel(&image, 5, 5), ColorU::WHITE);
+        assert_eq!(pixel(&image, stem_x, 110), ColorU::WHITE);
+
+        // font instances that are not registered are skipped
+        let unregistered = FontInstanceKey::unique(id_namespace);
+        let image = render(frame((0.0, 0.0), (120.0, 120.0), vec![text(unregistered)], vec![]), &renderer_resources);
+        assert_eq!(pixel(&image, stem_x, stem_y), ColorU::WHITE);
+    }
+}
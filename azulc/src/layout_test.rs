@@ -0,0 +1,669 @@
+//! Runner for the declarative layout tests in `/tests/*.xml`
+//!
+//! Each test file contains one or more `<test name="...">` nodes, each with
+//! an `<html>` node (the DOM + CSS to lay out) and one or more
+//! `<output size="WIDTHxHEIGHT">` nodes that contain the expected layout:
+//!
+//! ```xml
+//! <test name="simple-width">
+//!     <html>
+//!         <body>
+//!             <div id="foo" />
+//!         </body>
+//!         <style>
+//!             #foo { width: 100px; height: 50px; }
+//!         </style>
+//!     </html>
+//!     <output size="600x100">
+//!         Frame(
+//!             rect: 600x100 @ (0, 0),
+//!             children: [
+//!                 Frame(
+//!                     rect: 100x50 @ (0, 0),
+//!                 ),
+//!             ],
+//!         )
+//!     </output>
+//! </test>
+//! ```
+//!
+//! The frames are listed in rendering order (the same order as in the display list),
+//! the position of each frame is relative to its parent frame.
+
+use core::fmt;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::path::{Path, PathBuf};
+use azul_core::{
+    xml::{XmlNode, XmlComponentMap},
+    window::{FullWindowState, LogicalSize, LogicalPosition, LogicalRect},
+    styled_dom::{StyledDom, ContentGroup},
+    callbacks::DocumentId,
+    ui_solver::{LayoutResult, PositionInfo},
+    app_resources::{IdNamespace, Epoch, RendererResources, ImageCache},
+    display_list::{SolvedLayout, RenderCallbacks},
+};
+use rust_fontconfig::FcFontCache;
+use crate::xml::{XmlError, parse_xml_string, str_to_dom};
+
+/// Maximum difference (in pixels) between the expected and the actual layout
+const LAYOUT_TEST_EPSILON: f32 = 0.01;
+
+/// Single `<test>` node of a test file
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutTest {
+    /// Name of the test, i.e. `<test name="simple-width">`
+    pub name: String,
+    /// The `<html>` node to lay out
+    pub html: XmlNode,
+    /// Expected layouts at different window sizes
+    pub outputs: Vec<LayoutTestOutput>,
+}
+
+/// Expected result of a `<output size="WIDTHxHEIGHT">` node
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutTestOutput {
+    pub size: LogicalSize,
+    pub expected: LayoutTestFrame,
+}
+
+/// Positioned rectangle of the layout, either parsed from the
+/// expected output or built from the `LayoutResult`
+#[derive(Debug, Clone)]
+pub struct LayoutTestFrame {
+    pub size: LogicalSize,
+    /// Position relative to the parent frame
+    pub position: LogicalPosition,
+    /// If the frame overflows and is scrollable: size + position of the scrolled content
+    pub scroll_content: Option<LogicalRect>,
+    pub children: Vec<LayoutTestFrame>,
+}
+
+impl PartialEq for LayoutTestFrame {
+    fn eq(&self, other: &Self) -> bool {
+        let approx_eq = |a: f32, b: f32| (a - b).abs() < LAYOUT_TEST_EPSILON;
+        let rect_approx_eq = |a: LogicalRect, b: LogicalRect| {
+            approx_eq(a.size.width, b.size.width) &&
+            approx_eq(a.size.height, b.size.height) &&
+            approx_eq(a.origin.x, b.origin.x) &&
+            approx_eq(a.origin.y, b.origin.y)
+        };
+        rect_approx_eq(self.get_rect(), other.get_rect()) &&
+        match (self.scroll_content, other.scroll_content) {
+            (None, None) => true,
+            (Some(a), Some(b)) => rect_approx_eq(a, b),
+            _ => false,
+        } &&
+        self.children == other.children
+    }
+}
+
+impl fmt::Display for LayoutTestFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+impl LayoutTestFrame {
+
+    fn get_rect(&self) -> LogicalRect {
+        LogicalRect::new(self.position, self.size)
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter, indent: usize) -> fmt::Result {
+
+        let tabs = "    ".repeat(indent);
+
+        match self.scroll_content {
+            None => {
+                writeln!(f, "Frame(")?;
+                self.fmt_fields(f, indent + 1)?;
+                write!(f, "{})", tabs)
+            },
+            Some(content_rect) => {
+                // same nesting as the Debug output of a DisplayListScrollFrame
+                writeln!(f, "ScrollFrame(")?;
+                writeln!(f, "{}    DisplayListScrollFrame {{", tabs)?;
+                writeln!(f, "{}        content_rect: {},", tabs, fmt_rect(content_rect))?;
+                writeln!(f, "{}        frame: DisplayListFrame {{", tabs)?;
+                self.fmt_fields(f, indent + 3)?;
+                writeln!(f, "{}        }},", tabs)?;
+                writeln!(f, "{}    }},", tabs)?;
+                write!(f, "{})", tabs)
+            }
+        }
+    }
+
+    fn fmt_fields(&self, f: &mut fmt::Formatter, indent: usize) -> fmt::Result {
+        let tabs = "    ".repeat(indent);
+        writeln!(f, "{}rect: {},", tabs, fmt_rect(self.get_rect()))?;
+        if !self.children.is_empty() {
+            writeln!(f, "{}children: [", tabs)?;
+            for child in self.children.iter() {
+                write!(f, "{}    ", tabs)?;
+                child.fmt_indented(f, indent + 1)?;
+                writeln!(f, ",")?;
+            }
+            writeln!(f, "{}],", tabs)?;
+        }
+        Ok(())
+    }
+
+    /// Builds the frame tree from the solved layout, in rendering order
+    pub fn from_layout_result(layout_result: &LayoutResult) -> Self {
+        let root = layout_result.styled_dom.get_rects_in_rendering_order();
+        Self::from_content_group(&root, layout_result)
+    }
+
+    fn from_content_group(group: &ContentGroup, layout_result: &LayoutResult) -> Self {
+
+        let scroll_content = layout_result.scrollable_nodes.overflowing_nodes
+            .get(&group.root)
+            .map(|scroll_node| scroll_node.child_rect);
+
+        let (size, position) = match group.root.into_crate_internal() {
+            Some(node_id) => {
+                let rect = &layout_result.rects.as_ref()[node_id];
+                let position = match rect.position {
+                    PositionInfo::Static(p) | PositionInfo::Relative(p) |
+                    PositionInfo::Absolute(p) | PositionInfo::Fixed(p) => LogicalPosition::new(p.x_offset, p.y_offset),
+                };
+                (rect.size, position)
+            },
+            None => (LogicalSize::zero(), LogicalPosition::zero()),
+        };
+
+        Self {
+            size,
+            position,
+            scroll_content,
+            children: group.children.as_ref().iter().map(|c| Self::from_content_group(c, layout_result)).collect(),
+        }
+    }
+}
+
+/// Formats the rect as "WIDTHxHEIGHT @ (X, Y)", prints 100.0 as "100", but 100.5 as "100.5"
+fn fmt_rect(rect: LogicalRect) -> String {
+    let round_px = |f: f32| (f * 100.0).round() / 100.0;
+    format!("{}x{} @ ({}, {})",
+        round_px(rect.size.width), round_px(rect.size.height),
+        round_px(rect.origin.x), round_px(rect.origin.y),
+    )
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutTestParseError {
+    /// The file is not valid XML
+    Xml(XmlError),
+    /// `<test>` node without a `name` attribute
+    MissingTestName,
+    /// `<test>` node without a `<html>` node
+    MissingHtml(String),
+    /// `<output>` node without a valid `size="WIDTHxHEIGHT"` attribute
+    InvalidOutputSize(String),
+    /// The expected output could not be parsed (test name, error)
+    InvalidOutput(String, FrameParseError),
+}
+
+impl_display!(LayoutTestParseError, {
+    Xml(e) => format!("invalid XML: {}", e),
+    MissingTestName => format!("<test> node has no name=\"...\" attribute"),
+    MissingHtml(test) => format!("test \"{}\": missing <html> node", test),
+    InvalidOutputSize(test) => format!("test \"{}\": <output> node has no valid size=\"WIDTHxHEIGHT\" attribute", test),
+    InvalidOutput(test, e) => format!("test \"{}\": could not parse expected output: {}", test, e),
+});
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrameParseError {
+    /// Expected a token, but found something else (expected, found)
+    Expected(&'static str, String),
+    /// Could not parse a number
+    InvalidNumber(String),
+    /// Unknown field in a `Frame(...)`
+    UnknownField(String),
+    /// `Frame(...)` without a `rect: ...` field
+    MissingRect,
+    /// `ScrollFrame(...)` without a `frame: DisplayListFrame { ... }` field
+    MissingFrame,
+    /// Trailing content after the root frame
+    TrailingContent(String),
+}
+
+impl_display!(FrameParseError, {
+    Expected(e, found) => format!("expected {}, found \"{}\"", e, found),
+    InvalidNumber(n) => format!("invalid number: \"{}\"", n),
+    UnknownField(f) => format!("unknown field: \"{}\"", f),
+    MissingRect => format!("Frame(...) has no \"rect: WIDTHxHEIGHT @ (X, Y)\" field"),
+    MissingFrame => format!("ScrollFrame(...) has no \"frame: DisplayListFrame {{ ... }}\" field"),
+    TrailingContent(c) => format!("unexpected content after root frame: \"{}\"", c),
+});
+
+/// Parses all `<test>` nodes of a test file
+pub fn parse_layout_tests(xml: &str) -> Result<Vec<LayoutTest>, LayoutTestParseError> {
+
+    let root_nodes = parse_xml_string(xml).map_err(LayoutTestParseError::Xml)?;
+
+    root_nodes.as_ref().iter()
+    .filter(|node| node.node_type.as_str() == "test")
+    .map(|test_node| {
+
+        let name = test_node.attributes.get_key("name")
+            .ok_or(LayoutTestParseError::MissingTestName)?
+            .as_str().to_string();
+
+        let html = test_node.children.as_ref().iter()
+            .find(|n| n.node_type.as_str() == "html")
+            .ok_or_else(|| LayoutTestParseError::MissingHtml(name.clone()))?;
+
+        let outputs = test_node.children.as_ref().iter()
+            .filter(|n| n.node_type.as_str() == "output")
+            .map(|output_node| {
+                let size = output_node.attributes.get_key("size")
+                    .and_then(|s| crate::parse_display_list_size(s.as_str()))
+                    .ok_or_else(|| LayoutTestParseError::InvalidOutputSize(name.clone()))?;
+                let text = output_node.text.as_ref().map(|s| s.as_str()).unwrap_or("");
+                let expected = parse_layout_test_frame(text)
+                    .map_err(|e| LayoutTestParseError::InvalidOutput(name.clone(), e))?;
+                Ok(LayoutTestOutput { size: LogicalSize::new(size.0, size.1), expected })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(LayoutTest {
+            name,
+            html: normalize_test_html(html),
+            outputs,
+        })
+    }).collect()
+}
+
+/// The test files put the `<style>` node next to the `<body>`, but
+/// `str_to_dom` expects the style inside of a `<head>` node
+fn normalize_test_html(html: &XmlNode) -> XmlNode {
+
+    let (styles, mut children): (Vec<XmlNode>, Vec<XmlNode>) = html.children.as_ref().iter()
+        .cloned()
+        .partition(|n| n.node_type.as_str() == "style");
+
+    if !styles.is_empty() {
+        let mut head = XmlNode::new("head");
+        head.children = styles.into();
+        children.insert(0, head);
+    }
+
+    XmlNode {
+        children: children.into(),
+        .. html.clone()
+    }
+}
+
+/// Parses the expected `Frame(rect: WxH @ (X, Y), children: [...])` output
+pub fn parse_layout_test_frame(s: &str) -> Result<LayoutTestFrame, FrameParseError> {
+    let mut tokens = FrameTokenizer { s: s.trim() };
+    let frame = parse_frame(&mut tokens)?;
+    let rest = tokens.s.trim();
+    if !rest.is_empty() {
+        return Err(FrameParseError::TrailingContent(rest.to_string()));
+    }
+    Ok(frame)
+}
+
+struct FrameTokenizer<'a> {
+    s: &'a str,
+}
+
+impl<'a> FrameTokenizer<'a> {
+
+    fn peek(&mut self) -> &'a str {
+        self.s = self.s.trim_start();
+        let len = match self.s.chars().next() {
+            None => 0,
+            Some(c) if c.is_alphanumeric() || c == '.' || c == '-' || c == '_' => {
+                self.s.find(|c: char| !(c.is_alphanumeric() || c == '.' || c == '-' || c == '_')).unwrap_or(self.s.len())
+            },
+            Some(c) => c.len_utf8(),
+        };
+        &self.s[..len]
+    }
+
+    fn next(&mut self) -> &'a str {
+        let token = self.peek();
+        self.s = &self.s[token.len()..];
+        token
+    }
+
+    fn expect(&mut self, expected: &'static str) -> Result<(), FrameParseError> {
+        match self.next() {
+            t if t == expected => Ok(()),
+            t => Err(FrameParseError::Expected(expected, t.to_string())),
+        }
+    }
+
+    /// Skips the next token if it is equal to `token`
+    fn skip(&mut self, token: &str) -> bool {
+        if self.peek() == token {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn number(&mut self) -> Result<f32, FrameParseError> {
+        let n = self.next();
+        n.parse::<f32>().map_err(|_| FrameParseError::InvalidNumber(n.to_string()))
+    }
+}
+
+/// Parses either a `Frame(...)` or a `ScrollFrame(DisplayListScrollFrame { ... })`
+fn parse_frame(tokens: &mut FrameTokenizer) -> Result<LayoutTestFrame, FrameParseError> {
+    match tokens.next() {
+        "Frame" => {
+            tokens.expect("(")?;
+            parse_frame_fields(tokens, ")")?.into_frame()
+        },
+        "ScrollFrame" => {
+            tokens.expect("(")?;
+            tokens.expect("DisplayListScrollFrame")?;
+            tokens.expect("{")?;
+            let fields = parse_frame_fields(tokens, "}")?;
+            tokens.skip(",");
+            tokens.expect(")")?;
+            let content_rect = fields.content_rect.ok_or(FrameParseError::MissingRect)?;
+            let mut frame = fields.frame.ok_or(FrameParseError::MissingFrame)?;
+            frame.scroll_content = Some(content_rect);
+            Ok(frame)
+        },
+        other => Err(FrameParseError::Expected("Frame or ScrollFrame", other.to_string())),
+    }
+}
+
+#[derive(Default)]
+struct FrameFields {
+    rect: Option<LogicalRect>,
+    content_rect: Option<LogicalRect>,
+    frame: Option<LayoutTestFrame>,
+    children: Vec<LayoutTestFrame>,
+}
+
+impl FrameFields {
+    fn into_frame(self) -> Result<LayoutTestFrame, FrameParseError> {
+        let rect = self.rect.ok_or(FrameParseError::MissingRect)?;
+        Ok(LayoutTestFrame {
+            size: rect.size,
+            position: rect.origin,
+            scroll_content: None,
+            children: self.children,
+        })
+    }
+}
+
+/// Parses the comma-separated (commas are optional) fields until the closing token
+fn parse_frame_fields(tokens: &mut FrameTokenizer, close: &'static str) -> Result<FrameFields, FrameParseError> {
+
+    let mut fields = FrameFields::default();
+
+    loop {
+        match tokens.next() {
+            t if t == close => break,
+            "rect" => {
+                tokens.expect(":")?;
+                fields.rect = Some(parse_rect(tokens)?);
+            },
+            "content_rect" => {
+                tokens.expect(":")?;
+                fields.content_rect = Some(parse_rect(tokens)?);
+            },
+            "parent_rect" => {
+                // same as the rect of the frame
+                tokens.expect(":")?;
+                parse_rect(tokens)?;
+            },
+            "tag" | "scroll_tag" => {
+                // tag IDs are not stable between layouts: "tag: 1" or "scroll_tag: ScrollTagId(1)"
+                tokens.expect(":")?;
+                tokens.next();
+                if tokens.skip("(") {
+                    tokens.next();
+                    tokens.expect(")")?;
+                }
+            },
+            "frame" => {
+                tokens.expect(":")?;
+                tokens.expect("DisplayListFrame")?;
+                tokens.expect("{")?;
+                fields.frame = Some(parse_frame_fields(tokens, "}")?.into_frame()?);
+            },
+            "children" => {
+                tokens.expect(":")?;
+                tokens.expect("[")?;
+                while tokens.peek() != "]" {
+                    fields.children.push(parse_frame(tokens)?);
+                    tokens.skip(",");
+                }
+                tokens.expect("]")?;
+            },
+            other => return Err(FrameParseError::UnknownField(other.to_string())),
+        }
+
+        tokens.skip(",");
+    }
+
+    Ok(fields)
+}
+
+/// Parses "WIDTHxHEIGHT @ (X, Y)"
+fn parse_rect(tokens: &mut FrameTokenizer) -> Result<LogicalRect, FrameParseError> {
+    // "100x50" is tokenized as one token
+    let size = tokens.next();
+    let (w, h) = crate::parse_display_list_size(size)
+        .ok_or_else(|| FrameParseError::InvalidNumber(size.to_string()))?;
+    tokens.expect("@")?;
+    tokens.expect("(")?;
+    let x = tokens.number()?;
+    tokens.expect(",")?;
+    let y = tokens.number()?;
+    tokens.expect(")")?;
+    Ok(LogicalRect::new(LogicalPosition::new(x, y), LogicalSize::new(w, h)))
+}
+
+/// Layout of a test did not match the expected output
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutTestFailure {
+    pub file: Option<PathBuf>,
+    pub test_name: String,
+    pub size: LogicalSize,
+    pub error: LayoutTestError,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutTestError {
+    /// The test file could not be read
+    Io(String),
+    /// The test file could not be parsed
+    Parse(LayoutTestParseError),
+    /// The `<html>` node could not be converted to a DOM
+    Dom(String),
+    /// The layout did not match the expected output
+    Mismatch { expected: LayoutTestFrame, actual: LayoutTestFrame },
+}
+
+impl fmt::Display for LayoutTestFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        if let Some(file) = self.file.as_ref() {
+            write!(f, "{}: ", file.display())?;
+        }
+
+        match &self.error {
+            LayoutTestError::Io(e) => write!(f, "could not read file: {}", e),
+            LayoutTestError::Parse(e) => write!(f, "{}", e),
+            LayoutTestError::Dom(e) => write!(f, "test \"{}\": could not build DOM: {}", self.test_name, e),
+            LayoutTestError::Mismatch { expected, actual } => {
+                writeln!(f, "test \"{}\" @ {}x{}: layout does not match (- expected, + actual):",
+                    self.test_name, self.size.width, self.size.height)?;
+                write!(f, "{}", diff_lines(&expected.to_string(), &actual.to_string()))
+            },
+        }
+    }
+}
+
+/// Line-based diff of the expected and actual output (longest common subsequence)
+fn diff_lines(expected: &str, actual: &str) -> String {
+
+    let a = expected.lines().collect::<Vec<_>>();
+    let b = actual.lines().collect::<Vec<_>>();
+
+    // lcs[i][j] = length of the LCS of a[i..] and b[j..]
+    let mut lcs = vec![vec![0_usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            diff.push_str(&format!("  {}\n", a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push_str(&format!("- {}\n", a[i]));
+            i += 1;
+        } else {
+            diff.push_str(&format!("+ {}\n", b[j]));
+            j += 1;
+        }
+    }
+
+    diff
+}
+
+/// Lays out the test at all output sizes and compares the
+/// result with the expected output, returns all mismatches
+pub fn run_layout_test(test: &LayoutTest, fc_cache: &FcFontCache) -> Vec<LayoutTestFailure> {
+
+    let mut failures = Vec::new();
+
+    for output in test.outputs.iter() {
+
+        let failure = |error| LayoutTestFailure {
+            file: None,
+            test_name: test.name.clone(),
+            size: output.size,
+            error,
+        };
+
+        // the DOM has to be rebuilt for every size, since solving the layout consumes it
+        let styled_dom = match str_to_dom(core::slice::from_ref(&test.html), &mut XmlComponentMap::default()) {
+            Ok(o) => o,
+            Err(e) => {
+                failures.push(failure(LayoutTestError::Dom(format!("{}", e))));
+                break;
+            }
+        };
+
+        let layout_result = solve_layout(styled_dom, output.size, fc_cache);
+        let actual = LayoutTestFrame::from_layout_result(&layout_result);
+
+        if actual != output.expected {
+            failures.push(failure(LayoutTestError::Mismatch {
+                expected: output.expected.clone(),
+                actual,
+            }));
+        }
+    }
+
+    failures
+}
+
+/// Runs all tests in the given test file
+pub fn run_layout_test_file(path: &Path, fc_cache: &FcFontCache) -> Vec<LayoutTestFailure> {
+
+    let with_file = |mut f: LayoutTestFailure| { f.file = Some(path.to_path_buf()); f };
+
+    let failure = |error| LayoutTestFailure {
+        file: Some(path.to_path_buf()),
+        test_name: String::new(),
+        size: LogicalSize::zero(),
+        error,
+    };
+
+    let xml = match std::fs::read_to_string(path) {
+        Ok(o) => o,
+        Err(e) => return vec![failure(LayoutTestError::Io(format!("{}", e)))],
+    };
+
+    let tests = match parse_layout_tests(&xml) {
+        Ok(o) => o,
+        Err(e) => return vec![failure(LayoutTestError::Parse(e))],
+    };
+
+    tests.iter().flat_map(|test| run_layout_test(test, fc_cache).into_iter().map(with_file)).collect()
+}
+
+/// Runs all `*.xml` test files in the given directory (sorted by file name)
+pub fn run_layout_test_directory(dir: &Path, fc_cache: &FcFontCache) -> Vec<LayoutTestFailure> {
+
+    let mut files = match std::fs::read_dir(dir) {
+        Ok(o) => o
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().map(|e| e == "xml").unwrap_or(false))
+            .collect::<Vec<_>>(),
+        Err(e) => return vec![LayoutTestFailure {
+            file: Some(dir.to_path_buf()),
+            test_name: String::new(),
+            size: LogicalSize::zero(),
+            error: LayoutTestError::Io(format!("{}", e)),
+        }],
+    };
+
+    files.sort();
+
+    files.iter().flat_map(|file| run_layout_test_file(file, fc_cache)).collect()
+}
+
+/// Solves the layout of a single DOM at the given window size
+pub fn solve_layout(styled_dom: StyledDom, size: LogicalSize, fc_cache: &FcFontCache) -> LayoutResult {
+
+    let epoch = Epoch::new();
+    let document_id = DocumentId {
+        namespace_id: IdNamespace(0),
+        id: 0,
+    };
+
+    let mut fake_window_state = FullWindowState::default();
+    fake_window_state.size.dimensions = size;
+
+    let image_cache = ImageCache::default();
+    let mut renderer_resources = RendererResources::default();
+    let callbacks = RenderCallbacks {
+        insert_into_active_gl_textures_fn: azul_core::gl::insert_into_active_gl_textures,
+        layout_fn: azul_layout::do_the_layout,
+        load_font_fn: crate::font_loading::font_source_get_bytes,
+        parse_font_fn: azul_layout::parse_font_fn,
+        font_has_glyph_fn: azul_layout::font_has_glyph_fn,
+    };
+
+    let mut resource_updates = Vec::new();
+    let mut solved_layout = SolvedLayout::new(
+        styled_dom,
+        epoch,
+        &document_id,
+        &fake_window_state,
+        &mut resource_updates,
+        IdNamespace(0),
+        &image_cache,
+        fc_cache,
+        &callbacks,
+        &mut renderer_resources,
+    );
+
+    solved_layout.layout_results.remove(0)
+}
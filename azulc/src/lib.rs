@@ -39,10 +39,18 @@ pub mod layout {
     pub use azul_layout::*;
 }
 
+/// CPU rasterizer for display lists (renders without a GPU)
+#[cfg(feature = "cpurender")]
+pub mod cpurender;
+
 /// Module for decoding and loading fonts
 #[cfg(all(feature = "std", feature ="font_loading"))]
 pub mod font_loading;
 
+/// Runner for the declarative layout tests (`/tests/*.xml`)
+#[cfg(all(feature = "std", feature = "xml", feature = "font_loading", feature = "text_layout"))]
+pub mod layout_test;
+
 /// Parse a string in the format of "600x100" -> (600, 100)
 pub fn parse_display_list_size(output_size: &str) -> Option<(f32, f32)> {
     let output_size = output_size.trim();
@@ -6,6 +6,8 @@ use std::fs;
 use std::path::Path;
 use std::process::exit;
 
+use azulc_lib::image::encode::ResultU8VecEncodeImageError;
+
 use azul_core::{
     gl::OptionGlContextPtr,
     window::FullWindowState,
@@ -32,12 +34,13 @@ enum Action {
     PrintHtmlCode,
     PrintStyledDom,
     PrintRustCode,
-    PrintCCode, // unimplemented, does nothing
-    PrintCppCode, // unimplemented, does nothing
-    PrintPythonCode, // unimplemented, does nothing
+    PrintCCode,
+    PrintCppCode,
+    PrintPythonCode,
     PrintDebugLayout(LogicalSize),
     PrintScrollClips(LogicalSize),
     PrintDisplayList(LogicalSize),
+    RenderToPng(LogicalSize, String),
 }
 
 fn print_help() {
@@ -48,6 +51,7 @@ fn print_help() {
     eprintln!("    --debug-layout WIDTHxHEIGHT: print a debug output of the layout solver");
     eprintln!("    --display-list WIDTHxHEIGHT: print the display list given WIDTH and HEIGHT");
     eprintln!("    --scroll-clips WIDTHxHEIGHT: print the overflowing scroll clips given WIDTH and HEIGHT");
+    eprintln!("    --render-png WIDTHxHEIGHT out.png: render the file to a PNG image (without a GPU)");
     eprintln!("    --cascade: print the cascaded styled DOM");
     eprintln!("");
     eprintln!("If OPTIONS is empty, the file will be printed to Rust code");
@@ -113,6 +117,26 @@ fn main() {
             };
             Action::PrintDisplayList(LogicalSize::new(size_parsed.0, size_parsed.1))
         },
+        Some("--render-png")            => {
+            let size = env::args().nth(2).expect("no output size specified for png rendering");
+            let size_parsed = match azulc_lib::parse_display_list_size(&size) {
+                Some(s) => s,
+                None => {
+                    eprintln!("error: png size \"{}\" could not be parsed", size);
+                    print_help();
+                    exit(-1);
+                }
+            };
+            let output_path = match args.get(3) {
+                Some(s) if args.len() > 4 => s.clone(),
+                _ => {
+                    eprintln!("error: no output file specified for png rendering");
+                    print_help();
+                    exit(-1);
+                }
+            };
+            Action::RenderToPng(LogicalSize::new(size_parsed.0, size_parsed.1), output_path)
+        },
         _ => Action::PrintRustCode,
     };
 
@@ -251,8 +275,58 @@ fn process(action: Action, file: Option<&String>) {
 
             println!("{:#?}", display_list.root);
         },
+        Action::RenderToPng(size, output_path) => {
+            let epoch = Epoch::new();
+            let document_id = DocumentId {
+                namespace_id: IdNamespace(0),
+                id: 0,
+            };
+            let dom_id = DomId { inner: 0 };
+            let mut fake_window_state = FullWindowState::default();
+            fake_window_state.size.dimensions = size;
+            let mut renderer_resources = RendererResources::default();
+            let image_cache = ImageCache::default();
+            let layout = solve_layout(styled_dom, size, document_id, epoch, &fake_window_state, &mut renderer_resources);
+            let layout_results = [layout];
+            let display_list = LayoutResult::get_cached_display_list(
+                &document_id,
+                dom_id,
+                epoch,
+                &layout_results,
+                &fake_window_state,
+                &GlTextureCache::default(),
+                &renderer_resources,
+                &image_cache,
+            );
+
+            let image = match azulc_lib::cpurender::render_display_list(
+                &display_list,
+                &renderer_resources,
+                &image_cache,
+                &layout_results,
+                fake_window_state.background_color,
+            ) {
+                Some(s) => s,
+                None => {
+                    eprintln!("error: could not render image of size {}x{}", size.width, size.height);
+                    exit(-1);
+                }
+            };
+
+            let png = match azulc_lib::image::encode::encode_png(&image) {
+                ResultU8VecEncodeImageError::Ok(o) => o,
+                ResultU8VecEncodeImageError::Err(e) => {
+                    eprintln!("error: could not encode png: {:?}", e);
+                    exit(-1);
+                }
+            };
+
+            if let Err(e) = fs::write(&output_path, png.as_ref()) {
+                eprintln!("error: could not write file: \"{}\" - error:\r\n{}", output_path, e);
+                exit(-1);
+            }
+        },
         // Action::DisplayFile => // TODO: open window and show the file,
-        // Action::RenderToPng(output_path) -- TODO!
     }
 }
 
@@ -272,6 +346,7 @@ fn solve_layout(
         layout_fn: azul_layout::do_the_layout,
         load_font_fn: azulc_lib::font_loading::font_source_get_bytes, // needs feature="font_loading"
         parse_font_fn: azul_layout::parse_font_fn, // needs feature="text_layout"
+        font_has_glyph_fn: azul_layout::font_has_glyph_fn, // needs feature="text_layout"
     };
 
     // Solve the layout (the extra parameters are necessary because of IFrame recursion)
@@ -335,13 +410,13 @@ fn get_rust_code(root_nodes: &[XmlNode]) -> Result<String, String> {
 }
 
 fn get_c_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    Ok(String::new()) // TODO
+    azul_core::xml::str_to_c_code(root_nodes, &mut XmlComponentMap::default()).map_err(|e| format!("{}", e))
 }
 
 fn get_cpp_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    Ok(String::new()) // TODO
+    azul_core::xml::str_to_cpp_code(root_nodes, &mut XmlComponentMap::default()).map_err(|e| format!("{}", e))
 }
 
 fn get_python_code(root_nodes: &[XmlNode]) -> Result<String, String> {
-    Ok(String::new()) // TODO
+    azul_core::xml::str_to_python_code(root_nodes, &mut XmlComponentMap::default()).map_err(|e| format!("{}", e))
 }
\ No newline at end of file
@@ -0,0 +1,140 @@
+//! Compiles a small XML document to C, C++ and Python source code
+
+extern crate azulc_lib;
+extern crate azul_core;
+
+use azul_core::xml::{
+    str_to_c_code, str_to_cpp_code, str_to_python_code,
+    XmlComponentMap, XmlNode,
+};
+use azulc_lib::xml::parse_xml_string;
+
+const XML: &str = r#"
+<html>
+    <head>
+        <style>
+            #title { font-size: 20px; }
+        </style>
+        <component name="greeting" args="name: String, count: u32, visible: bool" accepts_text="true">
+            <style>
+                p { color: red; }
+            </style>
+            <p>Hello {name}, you have {count} "new" messages</p>
+        </component>
+    </head>
+    <body>
+        <p id="title" class="big" tabindex="3">Say "hi" \o/ 100%</p>
+        <Greeting name="World" count="5" visible="true">text with {braces}</Greeting>
+    </body>
+</html>
+"#;
+
+fn compile(f: for<'a> fn(&'a [XmlNode], &'a mut XmlComponentMap) -> Result<String, azul_core::xml::CompileError<'a>>) -> String {
+    let root_nodes = parse_xml_string(XML).unwrap();
+    let mut component_map = XmlComponentMap::default();
+    let code = f(root_nodes.as_ref(), &mut component_map).map_err(|e| e.to_string()).unwrap();
+    code.replace("\r\n", "\n")
+}
+
+/// Returns the name of the CSS constant that contains the given style
+fn css_constant<'a>(code: &'a str, prefix: &str, style: &str) -> &'a str {
+    let line = code.lines()
+        .find(|l| l.starts_with(prefix) && l.contains(style))
+        .unwrap_or_else(|| panic!("no CSS constant containing {:?}:\n{}", style, code));
+    let name_start = line.find("CSS_MATCH_").unwrap();
+    let name_len = line[name_start..].find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap();
+    &line[name_start..(name_start + name_len)]
+}
+
+#[test]
+fn compile_xml_to_c() {
+
+    let code = compile(str_to_c_code);
+
+    assert!(code.starts_with("// Auto-generated UI source code\n#include \"azul.h\""));
+
+    // <style> of the <head> and of the component are compiled to constants
+    let title_css = css_constant(&code, "#define ", "font-size: 20px;");
+    let text_css = css_constant(&code, "#define ", "color: #ff0000ff;");
+
+    // string arguments are const char*, text is the first argument
+    assert!(code.contains("AzDom greeting_render(const char* text, const char* name, uint32_t count, bool visible);"));
+
+    // variables are formatted into a buffer at runtime, "%" and quotes are escaped,
+    // the buffer is sized by the first snprintf call (no fixed size, no truncation)
+    let format = "\"Hello %s, you have %llu \\\"new\\\" messages\", name, (unsigned long long)count";
+    assert!(code.contains(&format!("int buffer_1_len = snprintf(NULL, 0, {});", format)));
+    assert!(code.contains("char* buffer_1 = (char*)malloc(buffer_1_len + 1);"));
+    assert!(code.contains(&format!("snprintf(buffer_1, buffer_1_len + 1, {});", format)));
+    assert!(code.contains("AzDom text_2 = AzDom_text(az_string(buffer_1));"));
+    assert!(code.contains("    free(buffer_1);\n    return div_0;\n}"));
+    assert!(code.contains(&format!("AzDom_setInlineStyle(&text_2, az_string({}));", text_css)));
+
+    // literal arguments are passed as literals, unknown variables stay in the string
+    assert!(code.contains("AzDom greeting_2 = greeting_render(\"text with {braces}\", \"World\", 5, true);"));
+    assert!(code.contains("AzDom text_1 = AzDom_text(az_string(\"Say \\\"hi\\\" \\\\o/ 100%\"));"));
+    assert!(code.contains("AzDom_addId(&text_1, az_string(\"title\"));"));
+    assert!(code.contains("AzDom_addClass(&text_1, az_string(\"big\"));"));
+    assert!(code.contains(&format!("AzDom_setInlineStyle(&text_1, az_string({}));", title_css)));
+    assert!(code.contains("AzTabIndex text_1_tab_index = AzTabIndex_OverrideInParent(3);"));
+    assert!(code.contains("AzDom_addChild(&body_0, greeting_2);"));
+}
+
+#[test]
+fn compile_xml_to_cpp() {
+
+    let code = compile(str_to_cpp_code);
+
+    assert!(code.starts_with("// Auto-generated UI source code\n#include \"azul.hpp\""));
+    assert!(code.contains("using namespace dll;"));
+
+    let title_css = css_constant(&code, "static constexpr const char ", "font-size: 20px;");
+    let text_css = css_constant(&code, "static constexpr const char ", "color: #ff0000ff;");
+
+    assert!(code.contains("Dom greeting_render(std::string const& text, std::string const& name, uint32_t count, bool visible);"));
+
+    // strings with variables are concatenated as std::strings
+    assert!(code.contains("Dom text_1 = Dom_text(az_string(std::string(\"Hello \") + name + \", you have \" + std::to_string(count) + \" \\\"new\\\" messages\"));"));
+    assert!(code.contains(&format!("Dom_setInlineStyle(&text_1, az_string({}));", text_css)));
+    assert!(code.contains("Dom_addChild(&div_0, std::move(text_1));"));
+
+    // the DOM is built with the free functions of the header, not with methods
+    assert!(code.contains("Dom greeting_2 = greeting_render(\"text with {braces}\", \"World\", 5, true);"));
+    assert!(code.contains("Dom text_1 = Dom_text(az_string(\"Say \\\"hi\\\" \\\\o/ 100%\"));"));
+    assert!(code.contains("Dom_addId(&text_1, az_string(\"title\"));"));
+    assert!(code.contains("Dom_addClass(&text_1, az_string(\"big\"));"));
+    assert!(code.contains(&format!("Dom_setInlineStyle(&text_1, az_string({}));", title_css)));
+    assert!(code.contains("Dom_setTabIndex(&text_1, TabIndex { .OverrideInParent = { .tag = TabIndexTag::OverrideInParent, .payload = 3 } });"));
+    assert!(code.contains("Dom_addChild(&body_0, std::move(greeting_2));"));
+    assert!(!code.contains("Dom::") && !code.contains(".with"));
+
+    // `restrict` is not a C++ keyword
+    assert!(code.contains("StyledDom layout(RefAny* data, LayoutCallbackInfo* info) {"));
+    assert!(!code.contains("restrict"));
+    assert!(code.contains("App app = App_new(std::move(refany), AppConfig_new(LayoutSolver::Default));"));
+}
+
+#[test]
+fn compile_xml_to_python() {
+
+    let code = compile(str_to_python_code);
+
+    assert!(code.starts_with("# Auto-generated UI source code\nfrom azul import *"));
+
+    let title_css = css_constant(&code, "CSS_MATCH_", "font-size: 20px;");
+    let text_css = css_constant(&code, "CSS_MATCH_", "color: #ff0000ff;");
+
+    // arguments have type annotations and default values
+    assert!(code.contains("def greeting_render(text: str = \"\", name: str = \"\", count: int = 0, visible: bool = False):"));
+
+    // strings with variables are compiled to f-strings
+    assert!(code.contains("text_1 = Dom.text(f\"Hello {name}, you have {count} \\\"new\\\" messages\")"));
+    assert!(code.contains(&format!("text_1.set_inline_style({})", text_css)));
+
+    // booleans are converted to Python literals, braces in strings are kept as-is
+    assert!(code.contains("greeting_2 = greeting_render(\"text with {braces}\", \"World\", 5, True)"));
+    assert!(code.contains("text_1 = Dom.text(\"Say \\\"hi\\\" \\\\o/ 100%\")"));
+    assert!(code.contains("text_1.add_id(\"title\")"));
+    assert!(code.contains(&format!("text_1.set_inline_style({})", title_css)));
+    assert!(code.contains("body_0.add_child(greeting_2)"));
+}
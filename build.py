@@ -1305,7 +1305,45 @@ def generate_python_api(api_data, structs_map, functions_map):
         ("widgets", "NumberInput", "set_on_focus_lost"),
         ("widgets", "TabHeader", "with_on_click"),
         ("widgets", "TabHeader", "set_on_click"),
-        ("widgets", "Ribbon", "dom"),
+        ("widgets", "TabHeader", "with_on_close"),
+        ("widgets", "TabHeader", "set_on_close"),
+        ("widgets", "TabHeader", "with_on_reorder"),
+        ("widgets", "TabHeader", "set_on_reorder"),
+        ("widgets", "TabHeader", "with_on_context_menu"),
+        ("widgets", "TabHeader", "set_on_context_menu"),
+        ("widgets", "TreeView", "set_on_expand"),
+        ("widgets", "TreeView", "with_on_expand"),
+        ("widgets", "TreeView", "set_on_select"),
+        ("widgets", "TreeView", "with_on_select"),
+        ("widgets", "TreeView", "set_on_double_click"),
+        ("widgets", "TreeView", "with_on_double_click"),
+        ("widgets", "TreeView", "set_on_context_menu"),
+        ("widgets", "TreeView", "with_on_context_menu"),
+        ("widgets", "ListView", "set_row_provider"),
+        ("widgets", "ListView", "with_row_provider"),
+        ("widgets", "ListView", "set_on_lazy_load_scroll"),
+        ("widgets", "ListView", "with_on_lazy_load_scroll"),
+        ("widgets", "ListView", "set_on_column_click"),
+        ("widgets", "ListView", "with_on_column_click"),
+        ("widgets", "ListView", "set_on_column_resize"),
+        ("widgets", "ListView", "with_on_column_resize"),
+        ("widgets", "ListView", "set_on_row_click"),
+        ("widgets", "ListView", "with_on_row_click"),
+        ("widgets", "TextArea", "set_on_text_change"),
+        ("widgets", "TextArea", "with_on_text_change"),
+        ("widgets", "DropDown", "set_on_choice_change"),
+        ("widgets", "DropDown", "with_on_choice_change"),
+        ("widgets", "Ribbon", "set_on_tab_clicked"),
+        ("widgets", "Ribbon", "with_on_tab_clicked"),
+        ("widgets", "RibbonItem", "set_on_click"),
+        ("widgets", "RibbonItem", "with_on_click"),
+        ("widgets", "Splitter", "set_on_resize"),
+        ("widgets", "Splitter", "with_on_resize"),
+        ("widgets", "Slider", "set_on_value_change"),
+        ("widgets", "Slider", "with_on_value_change"),
+        ("widgets", "RangeSlider", "set_on_value_change"),
+        ("widgets", "RangeSlider", "with_on_value_change"),
+        ("dom", "Tooltip", "dom"),
 
         # unnecessary due to Python string wrappers
         ("str", "String", "as_refstr"),
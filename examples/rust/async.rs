@@ -110,7 +110,7 @@ extern "C" fn render_ui(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> Styled
                     Dom::div()
                     .with_children(vec![
                         Dom::text("Loading data...".into()),
-                        ProgressBar::new(*percent_done).dom()
+                        ProgressBar::new(*percent_done).with_display_percentage(true).dom()
                     ].into())
                 },
                 LoadingFinished => {
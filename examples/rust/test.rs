@@ -1,25 +1,62 @@
 use azul::prelude::*;
 use azul::str::String as AzString;
-use azul::widgets::{Ribbon, RibbonOnTabClickedCallback};
+use azul::widgets::{
+    Ribbon, RibbonTab, RibbonGroup,
+    RibbonItem, RibbonItemKind, RibbonItemState,
+};
 
 struct DataModel {
     current_active_tab: i32,
+    bold: bool,
 }
 
-extern "C" fn myLayoutFunc(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom {
+extern "C" fn myLayoutFunc(data: &mut RefAny, info: &mut LayoutCallbackInfo) -> StyledDom {
 
     println!("myLayoutFunc!");
 
     let data_clone = data.clone();
-    let data = match data.downcast_ref::<DataModel>() {
-        Some(s) => s,
+    let (current_active_tab, bold) = match data.downcast_ref::<DataModel>() {
+        Some(s) => (s.current_active_tab, s.bold),
         None => return StyledDom::default(),
     };
 
-    let mut ribbon = Ribbon { tab_active: data.current_active_tab };
+    let clipboard = RibbonGroup::new(AzString::from_const_str("Clipboard"), vec![
+        RibbonItem::new(RibbonItemKind::SplitButton, AzString::from_const_str("Paste"))
+        .with_choices(vec![AzString::from_const_str("Paste as text")].into())
+        .with_tooltip(AzString::from_const_str("Paste the contents of the clipboard"))
+        .with_on_click(data_clone.clone(), on_item_click),
+        RibbonItem::new(RibbonItemKind::SmallButton, AzString::from_const_str("Cut"))
+        .with_key_tip(AzString::from_const_str("X"))
+        .with_on_click(data_clone.clone(), on_item_click),
+        RibbonItem::new(RibbonItemKind::SmallButton, AzString::from_const_str("Copy"))
+        .with_key_tip(AzString::from_const_str("C"))
+        .with_on_click(data_clone.clone(), on_item_click),
+    ].into());
+
+    let font = RibbonGroup::new(AzString::from_const_str("Font"), vec![
+        RibbonItem::new(RibbonItemKind::DropDown, AzString::from_const_str("Font size"))
+        .with_choices(vec![AzString::from_const_str("11"), AzString::from_const_str("12")].into())
+        .with_on_click(data_clone.clone(), on_item_click),
+        RibbonItem::new(RibbonItemKind::Toggle, AzString::from_const_str("Bold"))
+        .with_toggled(bold)
+        .with_key_tip(AzString::from_const_str("1"))
+        .with_on_click(data_clone.clone(), on_bold_click),
+    ].into());
+
+    let tabs = vec![
+        RibbonTab::new(AzString::from_const_str("HOME"), vec![clipboard, font].into())
+        .with_key_tip(AzString::from_const_str("H")),
+        RibbonTab::new(AzString::from_const_str("VIEW"), Vec::new().into())
+        .with_key_tip(AzString::from_const_str("W")),
+    ];
+
+    let mut ribbon = Ribbon::new(tabs.into())
+    .with_tab_active(current_active_tab)
+    .with_available_width(info.window_size.dimensions.width)
+    .with_on_tab_clicked(data_clone, update_tab);
 
     Dom::body()
-    .with_child(ribbon.dom(RibbonOnTabClickedCallback { cb: update_tab }, data_clone))
+    .with_child(ribbon.dom())
     .style(Css::empty())
 }
 
@@ -36,9 +73,24 @@ extern "C" fn update_tab(data: &mut RefAny, info: &mut CallbackInfo, new_tab: i3
     Update::RefreshDom
 }
 
+extern "C" fn on_item_click(data: &mut RefAny, info: &mut CallbackInfo, state: &RibbonItemState) -> Update {
+    Update::DoNothing
+}
+
+extern "C" fn on_bold_click(data: &mut RefAny, info: &mut CallbackInfo, state: &RibbonItemState) -> Update {
+    let mut data = match data.downcast_mut::<DataModel>() {
+        Some(s) => s,
+        None => return Update::DoNothing,
+    };
+
+    data.bold = state.toggled;
+
+    Update::DoNothing
+}
+
 fn main() {
-    let data = DataModel { current_active_tab: 3 };
+    let data = DataModel { current_active_tab: 0, bold: false };
     let app = App::new(RefAny::new(data), AppConfig::new(LayoutSolver::Default));
     let mut window = WindowCreateOptions::new(myLayoutFunc);
     app.run(window);
-}
\ No newline at end of file
+}
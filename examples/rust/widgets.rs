@@ -60,6 +60,15 @@ extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom
                     ProgressBar::new(20.0).dom()
                     .with_inline_style("margin-bottom: 5px;".into()),
 
+                    ProgressBar::indeterminate()
+                    .with_style(ProgressBarStyle::Stripes)
+                    .with_text_format("Connecting...".into())
+                    .dom()
+                    .with_inline_style("margin-bottom: 5px;".into()),
+
+                    Spinner::new().dom()
+                    .with_inline_style("margin-bottom: 5px;".into()),
+
                     ColorInput::new(ColorU { r: 0, g: 0, b: 0, a: 255 }).dom()
                     .with_inline_style("margin-bottom: 5px;".into()),
 
@@ -71,6 +80,16 @@ extern "C" fn layout(data: &mut RefAny, _: &mut LayoutCallbackInfo) -> StyledDom
                     NumberInput::new(5.0).dom()
                     .with_inline_style("margin-bottom: 5px;".into()),
 
+                    Slider::new(SliderOrientation::Horizontal, 40.0, 0.0, 100.0)
+                    .with_step(5.0)
+                    .with_tick_interval(25.0)
+                    .dom()
+                    .with_inline_style("margin-bottom: 5px;".into()),
+
+                    RangeSlider::new(SliderOrientation::Horizontal, 20.0, 80.0, 0.0, 100.0)
+                    .dom()
+                    .with_inline_style("margin-bottom: 5px;".into()),
+
                     Dom::div()
                     .with_inline_style("flex-direction: row;".into())
                     .with_children(vec![